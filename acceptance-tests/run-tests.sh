@@ -20,6 +20,9 @@ done
 
 KEEP_CONTAINERS="${KEEP_CONTAINERS:-false}"
 
+# The API makes this account an admin when the tests register it
+export ADMIN_EMAIL="${ADMIN_EMAIL:-acceptance-admin@example.com}"
+
 cleanup() {
     if [ "$KEEP_CONTAINERS" != "true" ]; then
         echo -e "${YELLOW}${BOLD}Cleaning up E2E containers (api, frontend, seed)...${NC}"
@@ -3,6 +3,7 @@ import { Actor } from '../screenplay/actor.js';
 import { BrowseTheWeb } from '../screenplay/abilities/browse-the-web.js';
 import { CallApi } from '../screenplay/abilities/call-api.js';
 import { QueryDatabase } from '../screenplay/abilities/query-database.js';
+import { SignIn } from '../screenplay/tasks/auth-tasks.js';
 import { getPool } from '../db/client.js';
 
 type Fixtures = {
@@ -16,6 +17,8 @@ export const test = base.extend<Fixtures>({
       CallApi.at(process.env.API_URL || 'http://localhost:8000'),
       QueryDatabase.using(getPool()),
     );
+    // Draft, session and trade writes need a signed-in commissioner or above
+    await actor.attemptsTo(SignIn.asAdmin());
     await use(actor);
   },
 });
@@ -9,6 +9,8 @@ export interface ApiResponse<T = unknown> {
 const DEFAULT_TIMEOUT_MS = 30_000;
 
 export class CallApi implements Ability {
+  private token: string | null = null;
+
   private constructor(
     private readonly baseUrl: string,
     private readonly timeoutMs: number,
@@ -18,8 +20,14 @@ export class CallApi implements Ability {
     return new CallApi(baseUrl, timeoutMs);
   }
 
+  /** Send this access token as a bearer token on every later request. */
+  authenticateWith(token: string): void {
+    this.token = token;
+  }
+
   async get<T = unknown>(path: string): Promise<ApiResponse<T>> {
     const res = await fetch(`${this.baseUrl}${path}`, {
+      headers: this.headers(),
       signal: AbortSignal.timeout(this.timeoutMs),
     });
     return this.parseResponse<T>(res);
@@ -28,13 +36,17 @@ export class CallApi implements Ability {
   async post<T = unknown>(path: string, body?: unknown): Promise<ApiResponse<T>> {
     const res = await fetch(`${this.baseUrl}${path}`, {
       method: 'POST',
-      headers: { 'Content-Type': 'application/json' },
+      headers: { 'Content-Type': 'application/json', ...this.headers() },
       body: body !== undefined ? JSON.stringify(body) : undefined,
       signal: AbortSignal.timeout(this.timeoutMs),
     });
     return this.parseResponse<T>(res);
   }
 
+  private headers(): Record<string, string> {
+    return this.token ? { Authorization: `Bearer ${this.token}` } : {};
+  }
+
   private async parseResponse<T>(res: Response): Promise<ApiResponse<T>> {
     const data = res.headers.get('content-type')?.includes('application/json')
       ? await res.json()
@@ -0,0 +1,69 @@
+import type { Actor, Task } from '../actor.js';
+import { BrowseTheWeb } from '../abilities/browse-the-web.js';
+import { CallApi } from '../abilities/call-api.js';
+
+interface TokenResponse {
+  access_token: string;
+  user: {
+    id: string;
+    email: string;
+    role: string;
+  };
+}
+
+// Must match ACCESS_TOKEN_STORAGE_KEY in the front-end API client
+const FRONTEND_TOKEN_KEY = 'nfl-draft-access-token';
+
+// The API makes the account registered with ADMIN_EMAIL an admin; run-tests.sh
+// starts it with the same default
+const DEFAULT_ADMIN_EMAIL = 'acceptance-admin@example.com';
+const DEFAULT_ADMIN_PASSWORD = 'acceptance-admin-password';
+
+class SignInTask implements Task {
+  constructor(
+    private readonly email: string,
+    private readonly password: string,
+  ) {}
+
+  async performAs(actor: Actor): Promise<void> {
+    const api = actor.abilityTo(CallApi);
+
+    let res = await api.post<TokenResponse>('/api/v1/auth/login', {
+      email: this.email,
+      password: this.password,
+    });
+    // First run against a fresh database: create the account
+    if (res.status === 401) {
+      res = await api.post<TokenResponse>('/api/v1/auth/register', {
+        email: this.email,
+        display_name: 'Acceptance Admin',
+        password: this.password,
+      });
+    }
+    if (!res.ok) throw new Error(`Sign in failed: ${res.status}`);
+    if (res.data.user.role !== 'admin') {
+      throw new Error(
+        `${this.email} signed in as ${res.data.user.role}; start the API with ADMIN_EMAIL=${this.email}`,
+      );
+    }
+
+    const token = res.data.access_token;
+    api.authenticateWith(token);
+
+    // The front end reads the token from localStorage on every page load
+    const page = actor.abilityTo(BrowseTheWeb).getPage();
+    await page.addInitScript(
+      ([key, value]) => window.localStorage.setItem(key, value),
+      [FRONTEND_TOKEN_KEY, token] as const,
+    );
+  }
+}
+
+export const SignIn = {
+  asAdmin(): SignInTask {
+    return new SignInTask(
+      process.env.ADMIN_EMAIL || DEFAULT_ADMIN_EMAIL,
+      process.env.ADMIN_PASSWORD || DEFAULT_ADMIN_PASSWORD,
+    );
+  },
+};
@@ -0,0 +1,103 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use uuid::Uuid;
+
+use domain::errors::DomainResult;
+use domain::models::{DraftPick, Page, PageRequest};
+use domain::repositories::DraftPickRepository;
+
+use super::{is_recording, record_change};
+
+/// Entity type recorded for draft picks
+pub(super) const ENTITY_TYPE: &str = "picks";
+
+/// Reports every change to a single pick to the audit log, with the pick
+/// as it was before. Bulk creates and deletes are left to the route's row.
+pub struct AuditedDraftPickRepository {
+    inner: Arc<dyn DraftPickRepository>,
+}
+
+impl AuditedDraftPickRepository {
+    pub fn new(inner: Arc<dyn DraftPickRepository>) -> Self {
+        Self { inner }
+    }
+
+    async fn previous(&self, id: Uuid) -> DomainResult<Option<DraftPick>> {
+        if !is_recording() {
+            return Ok(None);
+        }
+        self.inner.find_by_id(id).await
+    }
+}
+
+#[async_trait]
+impl DraftPickRepository for AuditedDraftPickRepository {
+    async fn create(&self, pick: &DraftPick) -> DomainResult<DraftPick> {
+        let created = self.inner.create(pick).await?;
+        record_change(ENTITY_TYPE, created.id, None, Some(&created));
+        Ok(created)
+    }
+
+    async fn create_many(&self, picks: &[DraftPick]) -> DomainResult<Vec<DraftPick>> {
+        self.inner.create_many(picks).await
+    }
+
+    async fn find_by_id(&self, id: Uuid) -> DomainResult<Option<DraftPick>> {
+        self.inner.find_by_id(id).await
+    }
+
+    async fn find_by_draft_id(&self, draft_id: Uuid) -> DomainResult<Vec<DraftPick>> {
+        self.inner.find_by_draft_id(draft_id).await
+    }
+
+    async fn find_page_by_draft_id(
+        &self,
+        draft_id: Uuid,
+        page: PageRequest,
+    ) -> DomainResult<Page<DraftPick>> {
+        self.inner.find_page_by_draft_id(draft_id, page).await
+    }
+
+    async fn find_by_draft_and_round(
+        &self,
+        draft_id: Uuid,
+        round: i32,
+    ) -> DomainResult<Vec<DraftPick>> {
+        self.inner.find_by_draft_and_round(draft_id, round).await
+    }
+
+    async fn find_by_draft_and_team(
+        &self,
+        draft_id: Uuid,
+        team_id: Uuid,
+    ) -> DomainResult<Vec<DraftPick>> {
+        self.inner.find_by_draft_and_team(draft_id, team_id).await
+    }
+
+    async fn find_next_pick(&self, draft_id: Uuid) -> DomainResult<Option<DraftPick>> {
+        self.inner.find_next_pick(draft_id).await
+    }
+
+    async fn find_available_picks(&self, draft_id: Uuid) -> DomainResult<Vec<DraftPick>> {
+        self.inner.find_available_picks(draft_id).await
+    }
+
+    async fn update(&self, pick: &DraftPick) -> DomainResult<DraftPick> {
+        let before = self.previous(pick.id).await?;
+        let updated = self.inner.update(pick).await?;
+        record_change(ENTITY_TYPE, updated.id, before.as_ref(), Some(&updated));
+        Ok(updated)
+    }
+
+    async fn delete(&self, id: Uuid) -> DomainResult<()> {
+        let before = self.previous(id).await?;
+        self.inner.delete(id).await?;
+        record_change(ENTITY_TYPE, id, before.as_ref(), None);
+        Ok(())
+    }
+
+    async fn delete_by_draft_id(&self, draft_id: Uuid) -> DomainResult<()> {
+        self.inner.delete_by_draft_id(draft_id).await
+    }
+}
@@ -0,0 +1,259 @@
+//! Audit log of writes made through the API.
+//!
+//! The `audit_writes` middleware wraps every non-GET route. It notes who
+//! made the request (from the bearer token or seed API key) and which route
+//! it hit, and once the response is ready writes one `audit_log` row per
+//! entity change the request's repositories reported through
+//! `record_change`. Requests that changed nothing reported — a failed
+//! write, or a route whose repositories aren't hooked — still get one row,
+//! naming the resource and id from the route itself. A user acting from a
+//! session seat is named by that seat once the handler reports it through
+//! `record_participant`.
+//!
+//! Repository hooks are decorators around the repositories whose history
+//! commissioners ask about; see `AuditedDraftPickRepository`,
+//! `AuditedSessionRepository`, `AuditedTradeRepository`,
+//! `AuditedTeamRepository` and `AuditedPlayerRepository`.
+
+mod draft_pick;
+mod player;
+mod session;
+mod team;
+mod trade;
+
+pub use draft_pick::AuditedDraftPickRepository;
+pub use player::AuditedPlayerRepository;
+pub use session::AuditedSessionRepository;
+pub use team::AuditedTeamRepository;
+pub use trade::AuditedTradeRepository;
+
+use std::sync::{Arc, Mutex};
+
+use axum::extract::{MatchedPath, RawPathParams, Request, State};
+use axum::http::Method;
+use axum::middleware::Next;
+use axum::response::Response;
+use axum::RequestExt;
+use chrono::Utc;
+use serde::Serialize;
+use serde_json::Value;
+use uuid::Uuid;
+
+use domain::models::{AuditEntry, SessionParticipant};
+
+use crate::auth::{verify_api_key, AuthUser, SEED_API_KEY_HEADER};
+use crate::request_id;
+use crate::state::AppState;
+
+/// Prefix stripped from matched routes to find the resource they write
+const API_PREFIX: &str = "/api/v1/";
+
+/// One entity written while handling a request
+#[derive(Debug, Clone, PartialEq)]
+struct EntityChange {
+    entity_type: String,
+    entity_id: Option<Uuid>,
+    before: Option<Value>,
+    after: Option<Value>,
+}
+
+/// What the handler reported while serving one request
+#[derive(Debug, Default)]
+struct RequestAudit {
+    changes: Vec<EntityChange>,
+    /// The session seat the signed-in user acted from: its id and display name
+    participant: Option<(Uuid, String)>,
+}
+
+tokio::task_local! {
+    static REQUEST: Arc<Mutex<RequestAudit>>;
+}
+
+/// Whether the current task is handling an audited request. Hooks check
+/// this before loading an entity's previous state.
+pub fn is_recording() -> bool {
+    REQUEST.try_with(|_| ()).is_ok()
+}
+
+/// Report an entity written on behalf of the current request. Outside an
+/// audited request, such as in the session scheduler, this does nothing.
+pub fn record_change<T: Serialize>(
+    entity_type: &'static str,
+    entity_id: Uuid,
+    before: Option<&T>,
+    after: Option<&T>,
+) {
+    let _ = REQUEST.try_with(|request| {
+        let change = EntityChange {
+            entity_type: entity_type.to_string(),
+            entity_id: Some(entity_id),
+            before: before.and_then(|value| serde_json::to_value(value).ok()),
+            after: after.and_then(|value| serde_json::to_value(value).ok()),
+        };
+        request
+            .lock()
+            .expect("audit request poisoned")
+            .changes
+            .push(change);
+    });
+}
+
+/// Report the session seat the signed-in user is acting from, so the
+/// request's rows name the participant rather than only the account.
+/// Outside an audited request this does nothing.
+pub fn record_participant(participant: &SessionParticipant) {
+    let _ = REQUEST.try_with(|request| {
+        request.lock().expect("audit request poisoned").participant =
+            Some((participant.id, participant.display_name.clone()));
+    });
+}
+
+/// Middleware that writes the audit log. Reads are passed straight
+/// through; so is GraphQL, which only serves queries. A failure to write
+/// the log is logged and doesn't affect the response.
+pub async fn audit_writes(
+    State(state): State<AppState>,
+    mut request: Request,
+    next: Next,
+) -> Response {
+    if matches!(
+        *request.method(),
+        Method::GET | Method::HEAD | Method::OPTIONS
+    ) {
+        return next.run(request).await;
+    }
+    let Some(route) = request
+        .extensions()
+        .get::<MatchedPath>()
+        .map(|path| path.as_str().to_string())
+    else {
+        return next.run(request).await;
+    };
+    if route.ends_with("/graphql") {
+        return next.run(request).await;
+    }
+
+    let method = request.method().to_string();
+    let (actor_user_id, actor) = actor(&request, &state);
+    let route_entity_id = request
+        .extract_parts::<RawPathParams>()
+        .await
+        .ok()
+        .and_then(|params| {
+            params
+                .iter()
+                .find_map(|(_, value)| Uuid::parse_str(value).ok())
+        });
+
+    let audit = Arc::new(Mutex::new(RequestAudit::default()));
+    let response = REQUEST.scope(audit.clone(), next.run(request)).await;
+
+    let RequestAudit {
+        mut changes,
+        participant,
+    } = std::mem::take(&mut *audit.lock().expect("audit request poisoned"));
+    let (actor_participant_id, actor) = match participant {
+        Some((id, name)) => (Some(id), name),
+        None => (None, actor),
+    };
+    if changes.is_empty() {
+        changes.push(EntityChange {
+            entity_type: route_resource(&route),
+            entity_id: route_entity_id,
+            before: None,
+            after: None,
+        });
+    }
+
+    let request_id = request_id::current();
+    let created_at = Utc::now();
+    let entries: Vec<AuditEntry> = changes
+        .into_iter()
+        .map(|change| AuditEntry {
+            id: Uuid::new_v4(),
+            actor_user_id,
+            actor_participant_id,
+            actor: actor.clone(),
+            method: method.clone(),
+            route: route.clone(),
+            entity_type: change.entity_type,
+            entity_id: change.entity_id,
+            before: change.before,
+            after: change.after,
+            status_code: i32::from(response.status().as_u16()),
+            request_id: request_id.clone(),
+            created_at,
+        })
+        .collect();
+
+    if let Err(e) = state.audit_log_repo.record(&entries).await {
+        tracing::error!("Failed to write audit log for {} {}: {}", method, route, e);
+    }
+    response
+}
+
+/// Who is making the request: a signed-in user, the seed API key, or
+/// nobody we can identify
+fn actor(request: &Request, state: &AppState) -> (Option<Uuid>, String) {
+    if let Ok(Some(user)) = AuthUser::from_headers(request.headers(), state) {
+        return (Some(user.user_id), user.email);
+    }
+    let key_matches = state.seed_api_key.as_deref().is_some_and(|expected| {
+        request
+            .headers()
+            .get(SEED_API_KEY_HEADER)
+            .and_then(|value| value.to_str().ok())
+            .is_some_and(|provided| verify_api_key(provided, expected))
+    });
+    if key_matches {
+        return (None, "api-key".to_string());
+    }
+    (None, "anonymous".to_string())
+}
+
+/// The resource a route writes, named after its first path segment past
+/// the API prefix (and past `admin/` for maintenance routes):
+/// `/api/v1/trades/{id}/accept` writes `trades`
+fn route_resource(route: &str) -> String {
+    let path = route.strip_prefix(API_PREFIX).unwrap_or(route);
+    let path = path.strip_prefix("admin/").unwrap_or(path);
+    path.trim_start_matches('/')
+        .split('/')
+        .next()
+        .unwrap_or_default()
+        .to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_route_resource() {
+        assert_eq!(route_resource("/api/v1/trades/{id}/accept"), "trades");
+        assert_eq!(route_resource("/api/v1/picks/{id}/make"), "picks");
+        assert_eq!(route_resource("/api/v1/admin/seed-players"), "seed-players");
+        assert_eq!(route_resource("/api/v1/sessions"), "sessions");
+    }
+
+    #[tokio::test]
+    async fn test_changes_recorded_only_inside_a_request() {
+        let id = Uuid::new_v4();
+        record_change("picks", id, None, Some(&"ignored"));
+        assert!(!is_recording());
+
+        let audit = Arc::new(Mutex::new(RequestAudit::default()));
+        REQUEST
+            .scope(audit.clone(), async {
+                assert!(is_recording());
+                record_change("picks", id, Some(&1), Some(&2));
+            })
+            .await;
+
+        let changes = &audit.lock().unwrap().changes;
+        assert_eq!(changes.len(), 1);
+        assert_eq!(changes[0].entity_id, Some(id));
+        assert_eq!(changes[0].before, Some(Value::from(1)));
+        assert_eq!(changes[0].after, Some(Value::from(2)));
+    }
+}
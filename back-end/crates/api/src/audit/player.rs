@@ -0,0 +1,121 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use uuid::Uuid;
+
+use domain::errors::DomainResult;
+use domain::models::{Page, PageRequest, Player, PlayerFilter, PlayerSortField, Position, Sort};
+use domain::repositories::PlayerRepository;
+
+use super::{is_recording, record_change};
+
+/// Entity type recorded for players
+const ENTITY_TYPE: &str = "players";
+
+/// Reports every change to a single player to the audit log, with the
+/// player as they were before. Bulk creates from seeding are left to the
+/// route's row.
+pub struct AuditedPlayerRepository {
+    inner: Arc<dyn PlayerRepository>,
+}
+
+impl AuditedPlayerRepository {
+    pub fn new(inner: Arc<dyn PlayerRepository>) -> Self {
+        Self { inner }
+    }
+
+    async fn previous(&self, id: Uuid) -> DomainResult<Option<Player>> {
+        if !is_recording() {
+            return Ok(None);
+        }
+        self.inner.find_by_id(id).await
+    }
+}
+
+#[async_trait]
+impl PlayerRepository for AuditedPlayerRepository {
+    async fn create(&self, player: &Player) -> DomainResult<Player> {
+        let created = self.inner.create(player).await?;
+        record_change(ENTITY_TYPE, created.id, None, Some(&created));
+        Ok(created)
+    }
+
+    async fn create_many(&self, players: &[Player]) -> DomainResult<Vec<Player>> {
+        self.inner.create_many(players).await
+    }
+
+    async fn find_by_id(&self, id: Uuid) -> DomainResult<Option<Player>> {
+        self.inner.find_by_id(id).await
+    }
+
+    async fn find_by_ids(&self, ids: &[Uuid]) -> DomainResult<Vec<Player>> {
+        self.inner.find_by_ids(ids).await
+    }
+
+    async fn find_all(&self) -> DomainResult<Vec<Player>> {
+        self.inner.find_all().await
+    }
+
+    async fn find_filtered(
+        &self,
+        filter: &PlayerFilter,
+        sort: &Sort<PlayerSortField>,
+        page: Option<PageRequest>,
+    ) -> DomainResult<Page<Player>> {
+        self.inner.find_filtered(filter, sort, page).await
+    }
+
+    async fn find_by_position(&self, position: Position) -> DomainResult<Vec<Player>> {
+        self.inner.find_by_position(position).await
+    }
+
+    async fn find_by_draft_year(&self, year: i32) -> DomainResult<Vec<Player>> {
+        self.inner.find_by_draft_year(year).await
+    }
+
+    async fn find_by_draft_year_after(
+        &self,
+        year: i32,
+        after_id: Option<Uuid>,
+        limit: i64,
+    ) -> DomainResult<Vec<Player>> {
+        self.inner
+            .find_by_draft_year_after(year, after_id, limit)
+            .await
+    }
+
+    async fn find_draft_eligible(&self, year: i32) -> DomainResult<Vec<Player>> {
+        self.inner.find_draft_eligible(year).await
+    }
+
+    async fn find_available_for_draft(&self, draft_id: Uuid) -> DomainResult<Vec<Player>> {
+        self.inner.find_available_for_draft(draft_id).await
+    }
+
+    async fn update(&self, player: &Player) -> DomainResult<Player> {
+        let before = self.previous(player.id).await?;
+        let updated = self.inner.update(player).await?;
+        record_change(ENTITY_TYPE, updated.id, before.as_ref(), Some(&updated));
+        Ok(updated)
+    }
+
+    async fn delete(&self, id: Uuid) -> DomainResult<()> {
+        let before = self.previous(id).await?;
+        self.inner.delete(id).await?;
+        record_change(ENTITY_TYPE, id, before.as_ref(), None);
+        Ok(())
+    }
+
+    async fn soft_delete(&self, id: Uuid) -> DomainResult<()> {
+        let before = self.previous(id).await?;
+        self.inner.soft_delete(id).await?;
+        record_change(ENTITY_TYPE, id, before.as_ref(), None);
+        Ok(())
+    }
+
+    async fn restore(&self, id: Uuid) -> DomainResult<Player> {
+        let restored = self.inner.restore(id).await?;
+        record_change(ENTITY_TYPE, restored.id, None, Some(&restored));
+        Ok(restored)
+    }
+}
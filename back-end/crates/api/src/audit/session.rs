@@ -0,0 +1,158 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use uuid::Uuid;
+
+use domain::errors::DomainResult;
+use domain::models::{Draft, DraftEvent, DraftPick, DraftSession, SessionBundle, TradeProposal};
+use domain::repositories::{DraftPickRepository, SessionRepository};
+
+use super::draft_pick::ENTITY_TYPE as PICK_ENTITY_TYPE;
+use super::{is_recording, record_change};
+
+/// Entity type recorded for sessions
+const ENTITY_TYPE: &str = "sessions";
+
+/// Reports every change to a session to the audit log, with the session as
+/// it was before. A pick recorded through a session is reported as a pick
+/// change too, which is what answers "who made that pick".
+pub struct AuditedSessionRepository {
+    inner: Arc<dyn SessionRepository>,
+    /// Looks up a pick's previous state; writes go through `inner`
+    picks: Arc<dyn DraftPickRepository>,
+}
+
+impl AuditedSessionRepository {
+    pub fn new(inner: Arc<dyn SessionRepository>, picks: Arc<dyn DraftPickRepository>) -> Self {
+        Self { inner, picks }
+    }
+
+    async fn previous(&self, id: Uuid) -> DomainResult<Option<DraftSession>> {
+        if !is_recording() {
+            return Ok(None);
+        }
+        self.inner.find_by_id(id).await
+    }
+}
+
+#[async_trait]
+impl SessionRepository for AuditedSessionRepository {
+    async fn create(&self, session: &DraftSession) -> DomainResult<DraftSession> {
+        let created = self.inner.create(session).await?;
+        record_change(ENTITY_TYPE, created.id, None, Some(&created));
+        Ok(created)
+    }
+
+    async fn find_by_id(&self, id: Uuid) -> DomainResult<Option<DraftSession>> {
+        self.inner.find_by_id(id).await
+    }
+
+    async fn find_by_draft_id(&self, draft_id: Uuid) -> DomainResult<Option<DraftSession>> {
+        self.inner.find_by_draft_id(draft_id).await
+    }
+
+    async fn update(&self, session: &DraftSession) -> DomainResult<DraftSession> {
+        let before = self.previous(session.id).await?;
+        let updated = self.inner.update(session).await?;
+        record_change(ENTITY_TYPE, updated.id, before.as_ref(), Some(&updated));
+        Ok(updated)
+    }
+
+    async fn delete(&self, id: Uuid) -> DomainResult<()> {
+        let before = self.previous(id).await?;
+        self.inner.delete(id).await?;
+        record_change(ENTITY_TYPE, id, before.as_ref(), None);
+        Ok(())
+    }
+
+    async fn list(&self) -> DomainResult<Vec<DraftSession>> {
+        self.inner.list().await
+    }
+
+    async fn list_by_status(&self, status: &str) -> DomainResult<Vec<DraftSession>> {
+        self.inner.list_by_status(status).await
+    }
+
+    async fn list_due_to_start(&self, now: DateTime<Utc>) -> DomainResult<Vec<DraftSession>> {
+        self.inner.list_due_to_start(now).await
+    }
+
+    async fn update_with_events(
+        &self,
+        session: &DraftSession,
+        events: &[DraftEvent],
+    ) -> DomainResult<DraftSession> {
+        let before = self.previous(session.id).await?;
+        let updated = self.inner.update_with_events(session, events).await?;
+        record_change(ENTITY_TYPE, updated.id, before.as_ref(), Some(&updated));
+        Ok(updated)
+    }
+
+    async fn start_session_with_draft(
+        &self,
+        session: &DraftSession,
+        draft: Option<&Draft>,
+        event: &DraftEvent,
+    ) -> DomainResult<DraftSession> {
+        let before = self.previous(session.id).await?;
+        let started = self
+            .inner
+            .start_session_with_draft(session, draft, event)
+            .await?;
+        record_change(ENTITY_TYPE, started.id, before.as_ref(), Some(&started));
+        Ok(started)
+    }
+
+    async fn record_pick(
+        &self,
+        pick: &DraftPick,
+        session: &DraftSession,
+        event: &DraftEvent,
+    ) -> DomainResult<DraftPick> {
+        let (pick_before, session_before) = if is_recording() {
+            (
+                self.picks.find_by_id(pick.id).await?,
+                self.inner.find_by_id(session.id).await?,
+            )
+        } else {
+            (None, None)
+        };
+        let recorded = self.inner.record_pick(pick, session, event).await?;
+        record_change(
+            PICK_ENTITY_TYPE,
+            recorded.id,
+            pick_before.as_ref(),
+            Some(&recorded),
+        );
+        record_change(
+            ENTITY_TYPE,
+            session.id,
+            session_before.as_ref(),
+            Some(session),
+        );
+        Ok(recorded)
+    }
+
+    async fn rewind_session(
+        &self,
+        session: &DraftSession,
+        draft: Option<&Draft>,
+        reversed_trades: &[TradeProposal],
+        event: &DraftEvent,
+    ) -> DomainResult<DraftSession> {
+        let before = self.previous(session.id).await?;
+        let rewound = self
+            .inner
+            .rewind_session(session, draft, reversed_trades, event)
+            .await?;
+        record_change(ENTITY_TYPE, rewound.id, before.as_ref(), Some(&rewound));
+        Ok(rewound)
+    }
+
+    async fn import_bundle(&self, bundle: &SessionBundle) -> DomainResult<DraftSession> {
+        let imported = self.inner.import_bundle(bundle).await?;
+        record_change(ENTITY_TYPE, imported.id, None, Some(&imported));
+        Ok(imported)
+    }
+}
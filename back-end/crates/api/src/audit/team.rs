@@ -0,0 +1,99 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use uuid::Uuid;
+
+use domain::errors::DomainResult;
+use domain::models::{Page, PageRequest, Sort, Team, TeamDependents, TeamSortField};
+use domain::repositories::TeamRepository;
+
+use super::{is_recording, record_change};
+
+/// Entity type recorded for teams
+const ENTITY_TYPE: &str = "teams";
+
+/// Reports every change to a team to the audit log, with the team as it
+/// was before. A restored team had no readable previous state.
+pub struct AuditedTeamRepository {
+    inner: Arc<dyn TeamRepository>,
+}
+
+impl AuditedTeamRepository {
+    pub fn new(inner: Arc<dyn TeamRepository>) -> Self {
+        Self { inner }
+    }
+
+    async fn previous(&self, id: Uuid) -> DomainResult<Option<Team>> {
+        if !is_recording() {
+            return Ok(None);
+        }
+        self.inner.find_by_id(id).await
+    }
+}
+
+#[async_trait]
+impl TeamRepository for AuditedTeamRepository {
+    async fn create(&self, team: &Team) -> DomainResult<Team> {
+        let created = self.inner.create(team).await?;
+        record_change(ENTITY_TYPE, created.id, None, Some(&created));
+        Ok(created)
+    }
+
+    async fn find_by_id(&self, id: Uuid) -> DomainResult<Option<Team>> {
+        self.inner.find_by_id(id).await
+    }
+
+    async fn find_by_abbreviation(&self, abbreviation: &str) -> DomainResult<Option<Team>> {
+        self.inner.find_by_abbreviation(abbreviation).await
+    }
+
+    async fn find_all(&self) -> DomainResult<Vec<Team>> {
+        self.inner.find_all().await
+    }
+
+    async fn find_sorted(
+        &self,
+        sort: &Sort<TeamSortField>,
+        page: Option<PageRequest>,
+    ) -> DomainResult<Page<Team>> {
+        self.inner.find_sorted(sort, page).await
+    }
+
+    async fn update(&self, team: &Team) -> DomainResult<Team> {
+        let before = self.previous(team.id).await?;
+        let updated = self.inner.update(team).await?;
+        record_change(ENTITY_TYPE, updated.id, before.as_ref(), Some(&updated));
+        Ok(updated)
+    }
+
+    async fn delete(&self, id: Uuid) -> DomainResult<()> {
+        let before = self.previous(id).await?;
+        self.inner.delete(id).await?;
+        record_change(ENTITY_TYPE, id, before.as_ref(), None);
+        Ok(())
+    }
+
+    async fn soft_delete(&self, id: Uuid) -> DomainResult<()> {
+        let before = self.previous(id).await?;
+        self.inner.soft_delete(id).await?;
+        record_change(ENTITY_TYPE, id, before.as_ref(), None);
+        Ok(())
+    }
+
+    async fn restore(&self, id: Uuid) -> DomainResult<Team> {
+        let restored = self.inner.restore(id).await?;
+        record_change(ENTITY_TYPE, restored.id, None, Some(&restored));
+        Ok(restored)
+    }
+
+    async fn count_dependents(&self, id: Uuid) -> DomainResult<TeamDependents> {
+        self.inner.count_dependents(id).await
+    }
+
+    async fn delete_with_dependents(&self, id: Uuid) -> DomainResult<()> {
+        let before = self.previous(id).await?;
+        self.inner.delete_with_dependents(id).await?;
+        record_change(ENTITY_TYPE, id, before.as_ref(), None);
+        Ok(())
+    }
+}
@@ -0,0 +1,167 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use uuid::Uuid;
+
+use domain::errors::DomainResult;
+use domain::models::{ChartType, DraftEvent, DraftPick, PickTrade, TradeProposal};
+use domain::repositories::{DraftPickRepository, TradeRepository};
+
+use super::draft_pick::ENTITY_TYPE as PICK_ENTITY_TYPE;
+use super::{is_recording, record_change};
+
+/// Entity type recorded for trades
+const ENTITY_TYPE: &str = "trades";
+
+/// Reports every change to a trade to the audit log, with the trade as it
+/// was before. Picks that change hands are reported as pick changes too,
+/// which is what answers "how did this team end up with that pick".
+pub struct AuditedTradeRepository {
+    inner: Arc<dyn TradeRepository>,
+    /// Looks up the traded picks around a transfer; writes go through `inner`
+    picks: Arc<dyn DraftPickRepository>,
+}
+
+impl AuditedTradeRepository {
+    pub fn new(inner: Arc<dyn TradeRepository>, picks: Arc<dyn DraftPickRepository>) -> Self {
+        Self { inner, picks }
+    }
+
+    async fn previous(&self, id: Uuid) -> DomainResult<Option<PickTrade>> {
+        if !is_recording() {
+            return Ok(None);
+        }
+        self.inner.find_by_id(id).await
+    }
+
+    async fn picks_before(&self, ids: &[Uuid]) -> DomainResult<Vec<(Uuid, Option<DraftPick>)>> {
+        let mut picks = Vec::with_capacity(ids.len());
+        if !is_recording() {
+            return Ok(picks);
+        }
+        for id in ids {
+            picks.push((*id, self.picks.find_by_id(*id).await?));
+        }
+        Ok(picks)
+    }
+
+    /// Reports each traded pick as it is now against its state before
+    async fn record_picks(&self, before: Vec<(Uuid, Option<DraftPick>)>) -> DomainResult<()> {
+        for (id, before) in before {
+            let after = self.picks.find_by_id(id).await?;
+            record_change(PICK_ENTITY_TYPE, id, before.as_ref(), after.as_ref());
+        }
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl TradeRepository for AuditedTradeRepository {
+    async fn create_trade(
+        &self,
+        proposal: &TradeProposal,
+        chart_type: ChartType,
+    ) -> DomainResult<TradeProposal> {
+        let created = self.inner.create_trade(proposal, chart_type).await?;
+        record_change(ENTITY_TYPE, created.trade.id, None, Some(&created));
+        Ok(created)
+    }
+
+    async fn create_trade_with_event(
+        &self,
+        proposal: &TradeProposal,
+        chart_type: ChartType,
+        event: &DraftEvent,
+    ) -> DomainResult<TradeProposal> {
+        let created = self
+            .inner
+            .create_trade_with_event(proposal, chart_type, event)
+            .await?;
+        record_change(ENTITY_TYPE, created.trade.id, None, Some(&created));
+        Ok(created)
+    }
+
+    async fn find_by_id(&self, id: Uuid) -> DomainResult<Option<PickTrade>> {
+        self.inner.find_by_id(id).await
+    }
+
+    async fn find_trade_with_details(&self, id: Uuid) -> DomainResult<Option<TradeProposal>> {
+        self.inner.find_trade_with_details(id).await
+    }
+
+    async fn find_by_session(&self, session_id: Uuid) -> DomainResult<Vec<PickTrade>> {
+        self.inner.find_by_session(session_id).await
+    }
+
+    async fn find_proposals_by_session(
+        &self,
+        session_id: Uuid,
+    ) -> DomainResult<Vec<TradeProposal>> {
+        self.inner.find_proposals_by_session(session_id).await
+    }
+
+    async fn find_pending_for_team(&self, team_id: Uuid) -> DomainResult<Vec<TradeProposal>> {
+        self.inner.find_pending_for_team(team_id).await
+    }
+
+    async fn update(&self, trade: &PickTrade) -> DomainResult<PickTrade> {
+        let before = self.previous(trade.id).await?;
+        let updated = self.inner.update(trade).await?;
+        record_change(ENTITY_TYPE, updated.id, before.as_ref(), Some(&updated));
+        Ok(updated)
+    }
+
+    async fn update_with_event(
+        &self,
+        trade: &PickTrade,
+        event: &DraftEvent,
+    ) -> DomainResult<PickTrade> {
+        let before = self.previous(trade.id).await?;
+        let updated = self.inner.update_with_event(trade, event).await?;
+        record_change(ENTITY_TYPE, updated.id, before.as_ref(), Some(&updated));
+        Ok(updated)
+    }
+
+    async fn is_pick_in_active_trade(
+        &self,
+        pick_id: Uuid,
+        exclude_trade_id: Option<Uuid>,
+    ) -> DomainResult<bool> {
+        self.inner
+            .is_pick_in_active_trade(pick_id, exclude_trade_id)
+            .await
+    }
+
+    async fn transfer_picks(
+        &self,
+        from_team_id: Uuid,
+        to_team_id: Uuid,
+        from_team_picks: &[Uuid],
+        to_team_picks: &[Uuid],
+    ) -> DomainResult<()> {
+        let picks = [from_team_picks, to_team_picks].concat();
+        let before = self.picks_before(&picks).await?;
+        self.inner
+            .transfer_picks(from_team_id, to_team_id, from_team_picks, to_team_picks)
+            .await?;
+        self.record_picks(before).await
+    }
+
+    async fn execute_trade(
+        &self,
+        proposal: &TradeProposal,
+        event: &DraftEvent,
+    ) -> DomainResult<PickTrade> {
+        let before = self.previous(proposal.trade.id).await?;
+        let picks = [
+            proposal.from_team_picks.as_slice(),
+            proposal.to_team_picks.as_slice(),
+        ]
+        .concat();
+        let picks_before = self.picks_before(&picks).await?;
+        let executed = self.inner.execute_trade(proposal, event).await?;
+        record_change(ENTITY_TYPE, executed.id, before.as_ref(), Some(&executed));
+        self.record_picks(picks_before).await?;
+        Ok(executed)
+    }
+}
@@ -1,8 +1,35 @@
+use std::sync::OnceLock;
+use std::time::Duration;
+
+use argon2::password_hash::rand_core::{OsRng, RngCore};
+use argon2::password_hash::{PasswordHash, PasswordHasher, PasswordVerifier, SaltString};
+use argon2::Argon2;
+use axum::extract::{FromRequestParts, OptionalFromRequestParts, Request, State};
+use axum::http::header::AUTHORIZATION;
+use axum::http::request::Parts;
+use axum::http::HeaderMap;
+use axum::middleware::Next;
+use axum::response::Response;
+use chrono::Utc;
+use jsonwebtoken::{decode, encode, Algorithm, DecodingKey, EncodingKey, Header, Validation};
+use serde::{Deserialize, Serialize};
 use subtle::ConstantTimeEq;
+use uuid::Uuid;
+
+use domain::models::Role;
+
+use crate::error::{ApiError, ApiResult};
+use crate::state::AppState;
+
+/// How long an access token stays valid when no lifetime is configured
+pub const DEFAULT_TOKEN_TTL: Duration = Duration::from_secs(24 * 60 * 60);
+
+/// Header carrying the server's seed API key for scripted admin access
+pub const SEED_API_KEY_HEADER: &str = "X-Seed-Api-Key";
 
 /// Constant-time comparison for API keys to prevent timing attacks.
 ///
-/// Used by all admin endpoints that require the `X-Seed-Api-Key` header.
+/// Used to check the `X-Seed-Api-Key` header on admin endpoints.
 pub fn verify_api_key(provided: &str, expected: &str) -> bool {
     // Reject empty keys outright — a misconfigured server should not
     // accidentally authenticate requests with an empty header.
@@ -25,6 +52,245 @@ pub fn verify_api_key(provided: &str, expected: &str) -> bool {
     provided_bytes.ct_eq(expected_bytes).into()
 }
 
+/// Hashes a password with Argon2id and a random salt, producing a PHC string.
+/// Hashing is deliberately slow, so it runs off the async runtime.
+pub async fn hash_password(password: String) -> ApiResult<String> {
+    tokio::task::spawn_blocking(move || hash_password_blocking(&password))
+        .await
+        .map_err(|e| ApiError::InternalError(format!("Password hashing task failed: {}", e)))?
+}
+
+fn hash_password_blocking(password: &str) -> ApiResult<String> {
+    let salt = SaltString::generate(&mut OsRng);
+    Argon2::default()
+        .hash_password(password.as_bytes(), &salt)
+        .map(|hash| hash.to_string())
+        .map_err(|e| ApiError::InternalError(format!("Failed to hash password: {}", e)))
+}
+
+/// Checks a password against a stored hash. Passing `None` still performs a
+/// full verification against a throwaway hash, so a login for an unknown
+/// email takes as long as one with a wrong password.
+pub async fn verify_password(password: String, stored_hash: Option<String>) -> bool {
+    tokio::task::spawn_blocking(move || {
+        static DUMMY_HASH: OnceLock<String> = OnceLock::new();
+        let dummy = DUMMY_HASH.get_or_init(|| {
+            hash_password_blocking("not a real password").expect("hashing a constant succeeds")
+        });
+        let matched = PasswordHash::new(stored_hash.as_deref().unwrap_or(dummy))
+            .map(|hash| {
+                Argon2::default()
+                    .verify_password(password.as_bytes(), &hash)
+                    .is_ok()
+            })
+            .unwrap_or(false);
+        matched && stored_hash.is_some()
+    })
+    .await
+    .unwrap_or(false)
+}
+
+/// Claims carried by an access token
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Claims {
+    /// The user's id
+    pub sub: Uuid,
+    pub email: String,
+    /// Role at the time the token was issued; a changed role applies from
+    /// the user's next sign-in
+    pub role: Role,
+    pub iat: i64,
+    pub exp: i64,
+}
+
+/// Signing and verification keys for HS256 access tokens
+pub struct JwtKeys {
+    encoding: EncodingKey,
+    decoding: DecodingKey,
+    ttl: Duration,
+}
+
+impl JwtKeys {
+    pub fn new(secret: &[u8], ttl: Duration) -> Self {
+        Self {
+            encoding: EncodingKey::from_secret(secret),
+            decoding: DecodingKey::from_secret(secret),
+            ttl,
+        }
+    }
+
+    /// Keys from a freshly generated secret. Tokens stop validating when the
+    /// process restarts, which is only acceptable in development.
+    pub fn random(ttl: Duration) -> Self {
+        let mut secret = [0u8; 32];
+        OsRng.fill_bytes(&mut secret);
+        Self::new(&secret, ttl)
+    }
+
+    pub fn ttl(&self) -> Duration {
+        self.ttl
+    }
+
+    pub fn issue(&self, user_id: Uuid, email: &str, role: Role) -> ApiResult<String> {
+        let now = Utc::now().timestamp();
+        let claims = Claims {
+            sub: user_id,
+            email: email.to_string(),
+            role,
+            iat: now,
+            exp: now + self.ttl.as_secs() as i64,
+        };
+        encode(&Header::new(Algorithm::HS256), &claims, &self.encoding)
+            .map_err(|e| ApiError::InternalError(format!("Failed to sign token: {}", e)))
+    }
+
+    pub fn verify(&self, token: &str) -> ApiResult<Claims> {
+        decode::<Claims>(token, &self.decoding, &Validation::new(Algorithm::HS256))
+            .map(|data| data.claims)
+            .map_err(|_| ApiError::Unauthorized("Invalid or expired access token".to_string()))
+    }
+}
+
+/// The signed-in user, taken from an `Authorization: Bearer` access token.
+/// Handlers that act on behalf of a user take this as an argument, so
+/// requests without a valid token are rejected with 401 before they run.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AuthUser {
+    pub user_id: Uuid,
+    pub email: String,
+    pub role: Role,
+}
+
+impl AuthUser {
+    pub(crate) fn from_headers(headers: &HeaderMap, state: &AppState) -> ApiResult<Option<Self>> {
+        let Some(value) = headers.get(AUTHORIZATION) else {
+            return Ok(None);
+        };
+        let token = value
+            .to_str()
+            .ok()
+            .and_then(|value| value.strip_prefix("Bearer "))
+            .map(str::trim)
+            .filter(|token| !token.is_empty())
+            .ok_or_else(|| ApiError::Unauthorized("Malformed bearer token".to_string()))?;
+
+        Self::from_token(token, state).map(Some)
+    }
+
+    /// The user an access token was issued to. WebSocket clients send the
+    /// token in their messages, since browsers cannot set headers there.
+    pub(crate) fn from_token(token: &str, state: &AppState) -> ApiResult<Self> {
+        let claims = state.jwt.verify(token)?;
+        Ok(Self {
+            user_id: claims.sub,
+            email: claims.email,
+            role: claims.role,
+        })
+    }
+}
+
+impl FromRequestParts<AppState> for AuthUser {
+    type Rejection = ApiError;
+
+    async fn from_request_parts(parts: &mut Parts, state: &AppState) -> ApiResult<Self> {
+        // Already checked by a role guard on this route
+        if let Some(user) = parts.extensions.get::<AuthUser>() {
+            return Ok(user.clone());
+        }
+
+        Self::from_headers(&parts.headers, state)?
+            .ok_or_else(|| ApiError::Unauthorized("Missing bearer token".to_string()))
+    }
+}
+
+/// For handlers that work for anyone but add to the response for a signed-in
+/// user. No token gives `None`; a bad token is still rejected with 401.
+impl OptionalFromRequestParts<AppState> for AuthUser {
+    type Rejection = ApiError;
+
+    async fn from_request_parts(parts: &mut Parts, state: &AppState) -> ApiResult<Option<Self>> {
+        if let Some(user) = parts.extensions.get::<AuthUser>() {
+            return Ok(Some(user.clone()));
+        }
+
+        Self::from_headers(&parts.headers, state)
+    }
+}
+
+/// Route guard for seeding and maintenance endpoints. Lets through an admin
+/// token, or the seed API key so deployment scripts keep working. Everyone
+/// else is treated as before roles existed: 404 when no seed key is
+/// configured, so the admin surface stays hidden, and 401 otherwise.
+pub async fn require_admin(
+    State(state): State<AppState>,
+    mut request: Request,
+    next: Next,
+) -> Result<Response, ApiError> {
+    let admin = AuthUser::from_headers(request.headers(), &state)
+        .ok()
+        .flatten()
+        .filter(|user| user.role.includes(Role::Admin));
+    if let Some(admin) = admin {
+        request.extensions_mut().insert(admin);
+        return Ok(next.run(request).await);
+    }
+
+    let Some(expected) = state.seed_api_key.as_deref() else {
+        return Err(ApiError::NotFound("Not found".to_string()));
+    };
+    let provided = request
+        .headers()
+        .get(SEED_API_KEY_HEADER)
+        .and_then(|value| value.to_str().ok())
+        .unwrap_or("");
+    if !verify_api_key(provided, expected) {
+        return Err(ApiError::Unauthorized(
+            "Invalid or missing API key".to_string(),
+        ));
+    }
+
+    Ok(next.run(request).await)
+}
+
+/// Route guard for running draft sessions
+pub async fn require_commissioner(
+    State(state): State<AppState>,
+    request: Request,
+    next: Next,
+) -> Result<Response, ApiError> {
+    authorize(&state, request, next, Role::Commissioner).await
+}
+
+/// Route guard for making picks and trades
+pub async fn require_participant(
+    State(state): State<AppState>,
+    request: Request,
+    next: Next,
+) -> Result<Response, ApiError> {
+    authorize(&state, request, next, Role::Participant).await
+}
+
+/// Rejects the request unless it carries a valid token whose role includes
+/// `required`; otherwise hands the verified user on to the handler.
+async fn authorize(
+    state: &AppState,
+    mut request: Request,
+    next: Next,
+    required: Role,
+) -> Result<Response, ApiError> {
+    let user = AuthUser::from_headers(request.headers(), state)?
+        .ok_or_else(|| ApiError::Unauthorized("Missing bearer token".to_string()))?;
+    if !user.role.includes(required) {
+        return Err(ApiError::Forbidden(format!(
+            "Requires the {} role",
+            required
+        )));
+    }
+
+    request.extensions_mut().insert(user);
+    Ok(next.run(request).await)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -58,4 +324,47 @@ mod tests {
     fn test_empty_provided_nonempty_expected() {
         assert!(!verify_api_key("", "secret"));
     }
+
+    #[tokio::test]
+    async fn test_password_round_trip() {
+        let hash = hash_password("correct horse".to_string()).await.unwrap();
+        assert!(hash.starts_with("$argon2id$"));
+        assert!(verify_password("correct horse".to_string(), Some(hash.clone())).await);
+        assert!(!verify_password("wrong horse".to_string(), Some(hash)).await);
+        assert!(!verify_password("correct horse".to_string(), None).await);
+    }
+
+    #[test]
+    fn test_token_round_trip() {
+        let keys = JwtKeys::new(b"test-secret", DEFAULT_TOKEN_TTL);
+        let user_id = Uuid::new_v4();
+        let token = keys
+            .issue(user_id, "coach@example.com", Role::Commissioner)
+            .unwrap();
+
+        let claims = keys.verify(&token).unwrap();
+        assert_eq!(claims.sub, user_id);
+        assert_eq!(claims.email, "coach@example.com");
+        assert_eq!(claims.role, Role::Commissioner);
+    }
+
+    #[test]
+    fn test_token_rejected_with_other_secret_or_expired() {
+        let keys = JwtKeys::new(b"test-secret", DEFAULT_TOKEN_TTL);
+        let other = JwtKeys::new(b"other-secret", DEFAULT_TOKEN_TTL);
+        let token = keys
+            .issue(Uuid::new_v4(), "coach@example.com", Role::Participant)
+            .unwrap();
+        assert!(other.verify(&token).is_err());
+
+        let expired = Claims {
+            sub: Uuid::new_v4(),
+            email: "coach@example.com".to_string(),
+            role: Role::Participant,
+            iat: 0,
+            exp: 1,
+        };
+        let token = encode(&Header::new(Algorithm::HS256), &expired, &keys.encoding).unwrap();
+        assert!(keys.verify(&token).is_err());
+    }
 }
@@ -0,0 +1,86 @@
+//! `?ids=` batch lookups for collection endpoints.
+//!
+//! After a WebSocket event the UI holds a handful of IDs and needs the full
+//! records; one list request with `ids=a,b,c` replaces a GET per ID. IDs
+//! that do not exist are left out of the response rather than failing it.
+
+use serde::Deserialize;
+use utoipa::IntoParams;
+use uuid::Uuid;
+
+use crate::error::{ApiError, ApiResult};
+
+/// Most IDs one request may ask for
+pub const MAX_BATCH_IDS: usize = 200;
+
+#[derive(Debug, Default, Deserialize, IntoParams)]
+pub struct IdsParams {
+    /// Comma-separated IDs to fetch (at most 200); every item when omitted
+    pub ids: Option<String>,
+}
+
+impl IdsParams {
+    /// The requested IDs without duplicates, or `None` when the client did
+    /// not ask for specific items
+    pub fn parse(&self) -> ApiResult<Option<Vec<Uuid>>> {
+        let Some(ids) = &self.ids else {
+            return Ok(None);
+        };
+
+        let mut parsed: Vec<Uuid> = Vec::new();
+        for part in ids.split(',').map(str::trim).filter(|p| !p.is_empty()) {
+            let id = Uuid::parse_str(part)
+                .map_err(|_| ApiError::BadRequest(format!("Invalid id '{}'", part)))?;
+            if !parsed.contains(&id) {
+                parsed.push(id);
+            }
+        }
+        if parsed.is_empty() {
+            return Err(ApiError::BadRequest(
+                "ids must contain at least one id".to_string(),
+            ));
+        }
+        if parsed.len() > MAX_BATCH_IDS {
+            return Err(ApiError::BadRequest(format!(
+                "At most {} ids may be requested at once",
+                MAX_BATCH_IDS
+            )));
+        }
+        Ok(Some(parsed))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn params(ids: Option<&str>) -> IdsParams {
+        IdsParams {
+            ids: ids.map(str::to_string),
+        }
+    }
+
+    #[test]
+    fn test_no_ids_means_everything() {
+        assert_eq!(params(None).parse().unwrap(), None);
+    }
+
+    #[test]
+    fn test_ids_parsed_and_deduplicated() {
+        let a = Uuid::new_v4();
+        let b = Uuid::new_v4();
+        let ids = params(Some(&format!("{a}, {b},{a}"))).parse().unwrap();
+        assert_eq!(ids, Some(vec![a, b]));
+    }
+
+    #[test]
+    fn test_invalid_empty_or_too_many_ids_rejected() {
+        assert!(params(Some("not-a-uuid")).parse().is_err());
+        assert!(params(Some(",")).parse().is_err());
+
+        let many: Vec<String> = (0..=MAX_BATCH_IDS)
+            .map(|_| Uuid::new_v4().to_string())
+            .collect();
+        assert!(params(Some(&many.join(","))).parse().is_err());
+    }
+}
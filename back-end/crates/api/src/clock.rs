@@ -0,0 +1,95 @@
+//! Background task that runs the pick clock for live sessions. It sends a
+//! `ClockTick` to each watched session every interval and a `ClockWarning`
+//! when the clock passes two minutes and thirty seconds.
+
+use std::collections::HashMap;
+use std::time::Duration;
+
+use chrono::Utc;
+use tokio::task::JoinHandle;
+use uuid::Uuid;
+
+use crate::error::ApiResult;
+use crate::state::AppState;
+use websocket::{ClockWarningLevel, ServerMessage};
+
+/// How often the clock broadcasts the time remaining
+pub const CLOCK_TICK_INTERVAL: Duration = Duration::from_secs(1);
+
+/// The last reading sent for each session, used to spot when the clock
+/// crosses a warning threshold between ticks
+#[derive(Debug, Default)]
+pub struct ClockTracker {
+    last: HashMap<Uuid, (i32, i32)>,
+}
+
+impl ClockTracker {
+    /// Records a reading and returns the warning it triggers, if any. The
+    /// first reading for a pick never warns since there is nothing to compare.
+    fn observe(
+        &mut self,
+        session_id: Uuid,
+        pick_number: i32,
+        time_remaining: i32,
+    ) -> Option<ClockWarningLevel> {
+        let previous = self
+            .last
+            .insert(session_id, (pick_number, time_remaining))
+            .filter(|(previous_pick, _)| *previous_pick == pick_number);
+        previous.and_then(|(_, previous_remaining)| {
+            ClockWarningLevel::crossed(previous_remaining, time_remaining)
+        })
+    }
+}
+
+/// Spawns the clock loop. It runs until the runtime shuts down.
+pub fn spawn_session_clock(state: AppState, interval: Duration) -> JoinHandle<()> {
+    tokio::spawn(async move {
+        let mut ticker = tokio::time::interval(interval);
+        ticker.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Skip);
+        let mut tracker = ClockTracker::default();
+        loop {
+            ticker.tick().await;
+            if let Err(e) = tick_session_clocks(&state, &mut tracker).await {
+                tracing::warn!("Session clock tick failed: {:?}", e);
+            }
+        }
+    })
+}
+
+/// Broadcasts the time remaining for every in-progress session that has at
+/// least one viewer. Paused sessions stop ticking until they resume.
+pub async fn tick_session_clocks(state: &AppState, tracker: &mut ClockTracker) -> ApiResult<()> {
+    let now = Utc::now();
+    let sessions = state.session_repo.list_by_status("InProgress").await?;
+
+    let mut live = Vec::with_capacity(sessions.len());
+    for session in sessions {
+        if state.ws_manager.session_connection_count(session.id) == 0 {
+            continue;
+        }
+        live.push(session.id);
+
+        let time_remaining = session.time_remaining_at(now);
+        let pick_number = session.current_pick_number;
+
+        let tick = ServerMessage::clock_tick(session.id, time_remaining, pick_number);
+        state
+            .ws_manager
+            .broadcast_to_session(session.id, tick)
+            .await;
+
+        if let Some(level) = tracker.observe(session.id, pick_number, time_remaining) {
+            let warning =
+                ServerMessage::clock_warning(session.id, level, time_remaining, pick_number);
+            state
+                .ws_manager
+                .broadcast_to_session(session.id, warning)
+                .await;
+        }
+    }
+
+    // Forget sessions that finished, paused or lost their viewers
+    tracker.last.retain(|id, _| live.contains(id));
+    Ok(())
+}
@@ -1,5 +1,9 @@
+use domain::models::SourcePrecedence;
 use serde::Deserialize;
 
+use crate::auth::{JwtKeys, DEFAULT_TOKEN_TTL};
+use crate::rate_limit::RateLimitSettings;
+
 #[derive(Debug, Clone, Deserialize)]
 pub struct Config {
     pub server: ServerConfig,
@@ -8,6 +12,15 @@ pub struct Config {
     /// Comma-separated list of allowed CORS origins.
     /// If empty or unset, defaults to common development origins.
     pub cors_origins: Vec<String>,
+    pub websocket: WebSocketConfig,
+    pub rate_limit: RateLimitConfig,
+    pub auth: AuthConfig,
+    /// Which testing source wins when a player has combine, pro day and
+    /// school results for the same measurement
+    #[serde(default)]
+    pub source_precedence: SourcePrecedence,
+    #[serde(default)]
+    pub cache: CacheConfig,
 }
 
 #[derive(Debug, Clone, Deserialize)]
@@ -21,6 +34,216 @@ pub struct ServerConfig {
 #[derive(Debug, Clone, Deserialize)]
 pub struct DatabaseConfig {
     pub url: String,
+    /// Read replica for collection reads of reference data; every query
+    /// goes to `url` when unset
+    #[serde(default)]
+    pub read_url: Option<String>,
+    /// Apply pending migrations before the server starts listening
+    #[serde(default = "default_run_migrations")]
+    pub run_migrations: bool,
+    #[serde(default)]
+    pub pool: PoolConfig,
+    /// Repository calls slower than this log a warning; zero turns the
+    /// warning off
+    #[serde(default = "default_slow_query_ms")]
+    pub slow_query_ms: u64,
+}
+
+impl DatabaseConfig {
+    pub fn slow_query_threshold(&self) -> Option<std::time::Duration> {
+        (self.slow_query_ms > 0).then(|| std::time::Duration::from_millis(self.slow_query_ms))
+    }
+}
+
+/// Connection pool sizing and timeouts, applied to the primary and the
+/// read replica alike
+#[derive(Debug, Clone, Deserialize)]
+pub struct PoolConfig {
+    #[serde(default = "default_pool_max_connections")]
+    pub max_connections: u32,
+    #[serde(default = "default_pool_min_connections")]
+    pub min_connections: u32,
+    #[serde(default = "default_pool_acquire_timeout_secs")]
+    pub acquire_timeout_secs: u64,
+    /// Zero keeps idle connections open
+    #[serde(default = "default_pool_idle_timeout_secs")]
+    pub idle_timeout_secs: u64,
+    /// Zero leaves the server's statement timeout in place
+    #[serde(default)]
+    pub statement_timeout_secs: u64,
+}
+
+impl PoolConfig {
+    /// Rejects sizes the pool could never satisfy: no connections at all, or
+    /// more kept open than it may hold
+    pub fn validate(&self) -> anyhow::Result<()> {
+        anyhow::ensure!(
+            self.max_connections > 0,
+            "DB_MAX_CONNECTIONS must be at least 1"
+        );
+        anyhow::ensure!(
+            self.min_connections <= self.max_connections,
+            "DB_MIN_CONNECTIONS ({}) must not exceed DB_MAX_CONNECTIONS ({})",
+            self.min_connections,
+            self.max_connections
+        );
+        Ok(())
+    }
+
+    pub fn settings(&self) -> db::PoolSettings {
+        let secs = |secs| (secs > 0).then(|| std::time::Duration::from_secs(secs));
+        db::PoolSettings {
+            max_connections: self.max_connections,
+            min_connections: self.min_connections,
+            acquire_timeout: std::time::Duration::from_secs(self.acquire_timeout_secs),
+            idle_timeout: secs(self.idle_timeout_secs),
+            statement_timeout: secs(self.statement_timeout_secs),
+        }
+    }
+}
+
+impl Default for PoolConfig {
+    fn default() -> Self {
+        Self {
+            max_connections: default_pool_max_connections(),
+            min_connections: default_pool_min_connections(),
+            acquire_timeout_secs: default_pool_acquire_timeout_secs(),
+            idle_timeout_secs: default_pool_idle_timeout_secs(),
+            statement_timeout_secs: 0,
+        }
+    }
+}
+
+/// Heartbeat and send queue settings for WebSocket connections
+#[derive(Debug, Clone, Deserialize)]
+pub struct WebSocketConfig {
+    #[serde(default = "default_heartbeat_interval_secs")]
+    pub heartbeat_interval_secs: u64,
+    #[serde(default = "default_heartbeat_max_missed")]
+    pub heartbeat_max_missed: u32,
+    #[serde(default = "default_send_queue_capacity")]
+    pub send_queue_capacity: usize,
+    #[serde(default)]
+    pub slow_consumer_policy: websocket::SlowConsumerPolicy,
+    /// Relay session broadcasts to other API instances over Postgres
+    /// LISTEN/NOTIFY
+    #[serde(default = "default_pg_notify")]
+    pub pg_notify: bool,
+}
+
+impl WebSocketConfig {
+    pub fn heartbeat(&self) -> websocket::HeartbeatConfig {
+        websocket::HeartbeatConfig::new(
+            std::time::Duration::from_secs(self.heartbeat_interval_secs),
+            self.heartbeat_max_missed,
+        )
+    }
+
+    pub fn send_queue(&self) -> websocket::SendQueueConfig {
+        websocket::SendQueueConfig::new(self.send_queue_capacity, self.slow_consumer_policy)
+    }
+}
+
+impl Default for WebSocketConfig {
+    fn default() -> Self {
+        Self {
+            heartbeat_interval_secs: default_heartbeat_interval_secs(),
+            heartbeat_max_missed: default_heartbeat_max_missed(),
+            send_queue_capacity: default_send_queue_capacity(),
+            slow_consumer_policy: websocket::SlowConsumerPolicy::default(),
+            pg_notify: default_pg_notify(),
+        }
+    }
+}
+
+/// Access token settings for user accounts
+#[derive(Debug, Clone, Deserialize)]
+pub struct AuthConfig {
+    /// HMAC secret for signing tokens. When unset a random secret is used,
+    /// which signs everyone out on every restart.
+    pub jwt_secret: Option<String>,
+    #[serde(default = "default_token_ttl_secs")]
+    pub token_ttl_secs: u64,
+    /// Accounts registered with this email start as admins, so a fresh
+    /// deployment has someone who can grant the other roles
+    pub admin_email: Option<String>,
+}
+
+impl AuthConfig {
+    /// Token keys, or `None` when no secret is configured
+    pub fn jwt_keys(&self) -> Option<JwtKeys> {
+        self.jwt_secret.as_ref().map(|secret| {
+            JwtKeys::new(
+                secret.as_bytes(),
+                std::time::Duration::from_secs(self.token_ttl_secs),
+            )
+        })
+    }
+}
+
+/// Optional Redis cache for teams, rankings and combine percentiles
+#[derive(Debug, Clone, Deserialize)]
+pub struct CacheConfig {
+    /// The cache is off unless a server is configured
+    pub redis_url: Option<String>,
+    #[serde(default = "default_cache_ttl_secs")]
+    pub ttl_secs: u64,
+}
+
+impl CacheConfig {
+    pub fn ttl(&self) -> std::time::Duration {
+        std::time::Duration::from_secs(self.ttl_secs)
+    }
+}
+
+impl Default for CacheConfig {
+    fn default() -> Self {
+        Self {
+            redis_url: None,
+            ttl_secs: default_cache_ttl_secs(),
+        }
+    }
+}
+
+/// Per-client request limits for the REST API. Off unless enabled, since
+/// everything behind a single proxy shares one address unless `trust_proxy`
+/// is set.
+#[derive(Debug, Clone, Deserialize)]
+pub struct RateLimitConfig {
+    #[serde(default)]
+    pub enabled: bool,
+    #[serde(default = "default_rate_limit_window_secs")]
+    pub window_secs: u64,
+    #[serde(default = "default_rate_limit_ip_requests")]
+    pub ip_requests: usize,
+    #[serde(default = "default_rate_limit_api_key_requests")]
+    pub api_key_requests: usize,
+    #[serde(default)]
+    pub trust_proxy: bool,
+}
+
+impl RateLimitConfig {
+    /// Limiter settings, or `None` when rate limiting is disabled
+    pub fn settings(&self) -> Option<RateLimitSettings> {
+        self.enabled.then(|| RateLimitSettings {
+            ip_requests: self.ip_requests,
+            api_key_requests: self.api_key_requests,
+            window: std::time::Duration::from_secs(self.window_secs),
+            trust_proxy: self.trust_proxy,
+        })
+    }
+}
+
+impl Default for RateLimitConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            window_secs: default_rate_limit_window_secs(),
+            ip_requests: default_rate_limit_ip_requests(),
+            api_key_requests: default_rate_limit_api_key_requests(),
+            trust_proxy: false,
+        }
+    }
 }
 
 fn default_host() -> String {
@@ -31,11 +254,115 @@ fn default_port() -> u16 {
     8000
 }
 
+fn default_heartbeat_interval_secs() -> u64 {
+    30
+}
+
+fn default_heartbeat_max_missed() -> u32 {
+    3
+}
+
+fn default_send_queue_capacity() -> usize {
+    256
+}
+
+fn default_run_migrations() -> bool {
+    true
+}
+
+fn default_slow_query_ms() -> u64 {
+    db::DEFAULT_SLOW_QUERY_THRESHOLD.as_millis() as u64
+}
+
+fn default_pool_max_connections() -> u32 {
+    5
+}
+
+fn default_pool_min_connections() -> u32 {
+    1
+}
+
+fn default_pool_acquire_timeout_secs() -> u64 {
+    3
+}
+
+fn default_pool_idle_timeout_secs() -> u64 {
+    600
+}
+
+fn default_pg_notify() -> bool {
+    true
+}
+
+fn default_rate_limit_window_secs() -> u64 {
+    60
+}
+
+fn default_rate_limit_ip_requests() -> usize {
+    300
+}
+
+fn default_rate_limit_api_key_requests() -> usize {
+    3000
+}
+
+fn default_token_ttl_secs() -> u64 {
+    DEFAULT_TOKEN_TTL.as_secs()
+}
+
+fn default_cache_ttl_secs() -> u64 {
+    600
+}
+
+fn env_flag(name: &str) -> bool {
+    std::env::var(name)
+        .map(|value| {
+            matches!(
+                value.trim().to_ascii_lowercase().as_str(),
+                "1" | "true" | "yes"
+            )
+        })
+        .unwrap_or(false)
+}
+
 impl Config {
     pub fn from_env() -> anyhow::Result<Self> {
         dotenvy::dotenv().ok();
 
         let database_url = std::env::var("DATABASE_URL").expect("DATABASE_URL must be set");
+        let database_read_url = std::env::var("DATABASE_READ_URL")
+            .ok()
+            .filter(|s| !s.is_empty());
+        let run_migrations = std::env::var("RUN_MIGRATIONS")
+            .map(|_| env_flag("RUN_MIGRATIONS"))
+            .unwrap_or_else(|_| default_run_migrations());
+        let pool = PoolConfig {
+            max_connections: std::env::var("DB_MAX_CONNECTIONS")
+                .unwrap_or_else(|_| default_pool_max_connections().to_string())
+                .parse()
+                .expect("DB_MAX_CONNECTIONS must be a valid number"),
+            min_connections: std::env::var("DB_MIN_CONNECTIONS")
+                .unwrap_or_else(|_| default_pool_min_connections().to_string())
+                .parse()
+                .expect("DB_MIN_CONNECTIONS must be a valid number"),
+            acquire_timeout_secs: std::env::var("DB_ACQUIRE_TIMEOUT_SECS")
+                .unwrap_or_else(|_| default_pool_acquire_timeout_secs().to_string())
+                .parse()
+                .expect("DB_ACQUIRE_TIMEOUT_SECS must be a valid number"),
+            idle_timeout_secs: std::env::var("DB_IDLE_TIMEOUT_SECS")
+                .unwrap_or_else(|_| default_pool_idle_timeout_secs().to_string())
+                .parse()
+                .expect("DB_IDLE_TIMEOUT_SECS must be a valid number"),
+            statement_timeout_secs: std::env::var("DB_STATEMENT_TIMEOUT_SECS")
+                .unwrap_or_else(|_| "0".to_string())
+                .parse()
+                .expect("DB_STATEMENT_TIMEOUT_SECS must be a valid number"),
+        };
+        pool.validate()?;
+        let slow_query_ms = std::env::var("DB_SLOW_QUERY_MS")
+            .unwrap_or_else(|_| default_slow_query_ms().to_string())
+            .parse()
+            .expect("DB_SLOW_QUERY_MS must be a valid number");
 
         let host = std::env::var("API_HOST").unwrap_or_else(|_| default_host());
         let port = std::env::var("API_PORT")
@@ -57,11 +384,95 @@ impl Config {
                 ]
             });
 
+        let heartbeat_interval_secs = std::env::var("WS_HEARTBEAT_INTERVAL_SECS")
+            .unwrap_or_else(|_| default_heartbeat_interval_secs().to_string())
+            .parse()
+            .expect("WS_HEARTBEAT_INTERVAL_SECS must be a valid number");
+        let heartbeat_max_missed = std::env::var("WS_HEARTBEAT_MAX_MISSED")
+            .unwrap_or_else(|_| default_heartbeat_max_missed().to_string())
+            .parse()
+            .expect("WS_HEARTBEAT_MAX_MISSED must be a valid number");
+        let send_queue_capacity = std::env::var("WS_SEND_QUEUE_CAPACITY")
+            .unwrap_or_else(|_| default_send_queue_capacity().to_string())
+            .parse()
+            .expect("WS_SEND_QUEUE_CAPACITY must be a valid number");
+        let slow_consumer_policy = std::env::var("WS_SLOW_CONSUMER_POLICY")
+            .map(|policy| {
+                policy
+                    .parse()
+                    .expect("WS_SLOW_CONSUMER_POLICY must be 'coalesce' or 'disconnect'")
+            })
+            .unwrap_or_default();
+        let pg_notify = std::env::var("WS_PG_NOTIFY")
+            .map(|_| env_flag("WS_PG_NOTIFY"))
+            .unwrap_or_else(|_| default_pg_notify());
+
+        let rate_limit = RateLimitConfig {
+            enabled: env_flag("RATE_LIMIT_ENABLED"),
+            window_secs: std::env::var("RATE_LIMIT_WINDOW_SECS")
+                .unwrap_or_else(|_| default_rate_limit_window_secs().to_string())
+                .parse()
+                .expect("RATE_LIMIT_WINDOW_SECS must be a valid number"),
+            ip_requests: std::env::var("RATE_LIMIT_IP_REQUESTS")
+                .unwrap_or_else(|_| default_rate_limit_ip_requests().to_string())
+                .parse()
+                .expect("RATE_LIMIT_IP_REQUESTS must be a valid number"),
+            api_key_requests: std::env::var("RATE_LIMIT_API_KEY_REQUESTS")
+                .unwrap_or_else(|_| default_rate_limit_api_key_requests().to_string())
+                .parse()
+                .expect("RATE_LIMIT_API_KEY_REQUESTS must be a valid number"),
+            trust_proxy: env_flag("RATE_LIMIT_TRUST_PROXY"),
+        };
+
+        let auth = AuthConfig {
+            jwt_secret: std::env::var("JWT_SECRET").ok().filter(|s| !s.is_empty()),
+            token_ttl_secs: std::env::var("JWT_TTL_SECS")
+                .unwrap_or_else(|_| default_token_ttl_secs().to_string())
+                .parse()
+                .expect("JWT_TTL_SECS must be a valid number"),
+            admin_email: std::env::var("ADMIN_EMAIL").ok().filter(|s| !s.is_empty()),
+        };
+
+        let source_precedence = std::env::var("RAS_SOURCE_PRECEDENCE")
+            .ok()
+            .filter(|s| !s.is_empty())
+            .map(|order| {
+                order.parse().expect(
+                    "RAS_SOURCE_PRECEDENCE must be a comma-separated list of combine, pro_day and school",
+                )
+            })
+            .unwrap_or_default();
+
+        let cache = CacheConfig {
+            redis_url: std::env::var("REDIS_URL").ok().filter(|s| !s.is_empty()),
+            ttl_secs: std::env::var("CACHE_TTL_SECS")
+                .unwrap_or_else(|_| default_cache_ttl_secs().to_string())
+                .parse()
+                .expect("CACHE_TTL_SECS must be a valid number"),
+        };
+
         Ok(Config {
             server: ServerConfig { host, port },
-            database: DatabaseConfig { url: database_url },
+            database: DatabaseConfig {
+                url: database_url,
+                read_url: database_read_url,
+                run_migrations,
+                pool,
+                slow_query_ms,
+            },
             seed_api_key,
             cors_origins,
+            websocket: WebSocketConfig {
+                heartbeat_interval_secs,
+                heartbeat_max_missed,
+                send_queue_capacity,
+                slow_consumer_policy,
+                pg_notify,
+            },
+            rate_limit,
+            auth,
+            source_precedence,
+            cache,
         })
     }
 
@@ -78,6 +489,70 @@ mod tests {
     fn test_default_values() {
         assert_eq!(default_host(), "0.0.0.0");
         assert_eq!(default_port(), 8000);
+        assert_eq!(default_heartbeat_interval_secs(), 30);
+        assert_eq!(default_heartbeat_max_missed(), 3);
+        assert_eq!(default_send_queue_capacity(), 256);
+        assert_eq!(default_rate_limit_window_secs(), 60);
+        assert_eq!(default_rate_limit_ip_requests(), 300);
+        assert_eq!(default_rate_limit_api_key_requests(), 3000);
+        assert_eq!(default_token_ttl_secs(), 86400);
+        assert_eq!(default_cache_ttl_secs(), 600);
+    }
+
+    #[test]
+    fn test_pool_settings_zero_disables_timeouts() {
+        let mut config = PoolConfig::default();
+        let settings = config.settings();
+        assert_eq!(settings, db::PoolSettings::default());
+
+        config.max_connections = 20;
+        config.idle_timeout_secs = 0;
+        config.statement_timeout_secs = 30;
+        let settings = config.settings();
+        assert_eq!(settings.max_connections, 20);
+        assert_eq!(settings.idle_timeout, None);
+        assert_eq!(
+            settings.statement_timeout,
+            Some(std::time::Duration::from_secs(30))
+        );
+    }
+
+    #[test]
+    fn test_pool_validation() {
+        assert!(PoolConfig::default().validate().is_ok());
+
+        let empty = PoolConfig {
+            max_connections: 0,
+            min_connections: 0,
+            ..PoolConfig::default()
+        };
+        assert!(empty.validate().is_err());
+
+        let inverted = PoolConfig {
+            max_connections: 2,
+            min_connections: 3,
+            ..PoolConfig::default()
+        };
+        let err = inverted.validate().unwrap_err();
+        assert!(err.to_string().contains("DB_MIN_CONNECTIONS (3)"));
+
+        let fixed = PoolConfig {
+            max_connections: 3,
+            min_connections: 3,
+            ..PoolConfig::default()
+        };
+        assert!(fixed.validate().is_ok());
+    }
+
+    #[test]
+    fn test_rate_limit_settings_only_when_enabled() {
+        let mut config = RateLimitConfig::default();
+        assert_eq!(config.settings(), None);
+
+        config.enabled = true;
+        let settings = config.settings().unwrap();
+        assert_eq!(settings.ip_requests, 300);
+        assert_eq!(settings.window, std::time::Duration::from_secs(60));
     }
 
     #[test]
@@ -89,9 +564,22 @@ mod tests {
             },
             database: DatabaseConfig {
                 url: "postgresql://localhost/test".to_string(),
+                read_url: None,
+                run_migrations: false,
+                pool: PoolConfig::default(),
+                slow_query_ms: default_slow_query_ms(),
             },
             seed_api_key: None,
             cors_origins: vec!["http://localhost:5173".to_string()],
+            websocket: WebSocketConfig::default(),
+            rate_limit: RateLimitConfig::default(),
+            auth: AuthConfig {
+                jwt_secret: None,
+                token_ttl_secs: default_token_ttl_secs(),
+                admin_email: None,
+            },
+            source_precedence: SourcePrecedence::default(),
+            cache: CacheConfig::default(),
         };
 
         assert_eq!(config.server_address(), "127.0.0.1:3000");
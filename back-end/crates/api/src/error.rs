@@ -1,70 +1,187 @@
-use axum::http::StatusCode;
+use axum::http::header::RETRY_AFTER;
+use axum::http::{HeaderValue, StatusCode};
 use axum::response::{IntoResponse, Response};
 use axum::Json;
-use serde_json::json;
+use serde::Serialize;
+use utoipa::ToSchema;
 
 #[derive(Debug)]
 pub enum ApiError {
     NotFound(String),
     BadRequest(String),
     Unauthorized(String),
+    Forbidden(String),
+    /// Client exceeded its request allowance; reported with `Retry-After`
+    TooManyRequests {
+        retry_after_secs: u64,
+    },
     InternalError(String),
     DomainError(domain::errors::DomainError),
 }
 
 pub type ApiResult<T> = Result<T, ApiError>;
 
+/// Machine-readable reason for a failed request, sent as `code` in every
+/// error body so clients can branch without matching on the message
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, ToSchema)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum ErrorCode {
+    /// The resource does not exist
+    NotFound,
+    /// A query parameter or request body could not be used
+    BadRequest,
+    /// The request was understood but broke a domain rule
+    ValidationFailed,
+    /// No valid access token was sent
+    Unauthorized,
+    /// The caller may not perform this action
+    Forbidden,
+    /// Too many requests; retry after the `Retry-After` header
+    RateLimited,
+    /// The resource already exists
+    DuplicateEntry,
+    /// The resource is not in a state that allows the action
+    InvalidState,
+    /// The player has already been picked in this draft
+    PlayerAlreadyDrafted,
+    /// The proposed trade falls outside the session's fairness threshold
+    TradeUnfair,
+    /// The action needs the draft session to be running
+    SessionNotInProgress,
+    /// Other records still refer to the resource, so it cannot be removed
+    ResourceInUse,
+    /// Something failed on the server; details are in its logs
+    InternalError,
+}
+
+impl ErrorCode {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            ErrorCode::NotFound => "NOT_FOUND",
+            ErrorCode::BadRequest => "BAD_REQUEST",
+            ErrorCode::ValidationFailed => "VALIDATION_FAILED",
+            ErrorCode::Unauthorized => "UNAUTHORIZED",
+            ErrorCode::Forbidden => "FORBIDDEN",
+            ErrorCode::RateLimited => "RATE_LIMITED",
+            ErrorCode::DuplicateEntry => "DUPLICATE_ENTRY",
+            ErrorCode::InvalidState => "INVALID_STATE",
+            ErrorCode::PlayerAlreadyDrafted => "PLAYER_ALREADY_DRAFTED",
+            ErrorCode::TradeUnfair => "TRADE_UNFAIR",
+            ErrorCode::SessionNotInProgress => "SESSION_NOT_IN_PROGRESS",
+            ErrorCode::ResourceInUse => "RESOURCE_IN_USE",
+            ErrorCode::InternalError => "INTERNAL_ERROR",
+        }
+    }
+}
+
+/// Body of every error response
+#[derive(Debug, Serialize, ToSchema)]
+pub struct ErrorResponse {
+    /// Human-readable description; may change between releases
+    pub error: String,
+    pub code: ErrorCode,
+    /// HTTP status code, repeated for clients that only see the body
+    pub status: u16,
+    /// ID of the failed request, for quoting to whoever reads the logs
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub request_id: Option<String>,
+}
+
 impl From<domain::errors::DomainError> for ApiError {
     fn from(err: domain::errors::DomainError) -> Self {
         ApiError::DomainError(err)
     }
 }
 
-impl IntoResponse for ApiError {
-    fn into_response(self) -> Response {
-        let (status, message) = match self {
-            ApiError::NotFound(msg) => (StatusCode::NOT_FOUND, msg),
-            ApiError::BadRequest(msg) => (StatusCode::BAD_REQUEST, msg),
-            ApiError::Unauthorized(msg) => (StatusCode::UNAUTHORIZED, msg),
+impl ApiError {
+    /// HTTP status, error code and client-facing message. Internal details
+    /// are logged and replaced with a generic message.
+    pub(crate) fn into_parts(self) -> (StatusCode, ErrorCode, String) {
+        match self {
+            ApiError::NotFound(msg) => (StatusCode::NOT_FOUND, ErrorCode::NotFound, msg),
+            ApiError::BadRequest(msg) => (StatusCode::BAD_REQUEST, ErrorCode::BadRequest, msg),
+            ApiError::Unauthorized(msg) => (StatusCode::UNAUTHORIZED, ErrorCode::Unauthorized, msg),
+            ApiError::Forbidden(msg) => (StatusCode::FORBIDDEN, ErrorCode::Forbidden, msg),
+            ApiError::TooManyRequests { retry_after_secs } => (
+                StatusCode::TOO_MANY_REQUESTS,
+                ErrorCode::RateLimited,
+                format!("Rate limit exceeded; retry in {} seconds", retry_after_secs),
+            ),
             ApiError::InternalError(msg) => {
                 tracing::error!("Internal error: {}", msg);
-                (
-                    StatusCode::INTERNAL_SERVER_ERROR,
-                    "Internal server error".to_string(),
-                )
+                internal_error()
             }
             ApiError::DomainError(err) => {
                 use domain::errors::DomainError;
                 match err {
-                    DomainError::NotFound(msg) => (StatusCode::NOT_FOUND, msg),
-                    DomainError::ValidationError(msg) => (StatusCode::BAD_REQUEST, msg),
-                    DomainError::DuplicateEntry(msg) => (StatusCode::CONFLICT, msg),
-                    DomainError::InvalidState(msg) => (StatusCode::BAD_REQUEST, msg),
+                    DomainError::NotFound(msg) => (StatusCode::NOT_FOUND, ErrorCode::NotFound, msg),
+                    DomainError::ValidationError(msg) => {
+                        (StatusCode::BAD_REQUEST, ErrorCode::ValidationFailed, msg)
+                    }
+                    DomainError::DuplicateEntry(msg) => {
+                        (StatusCode::CONFLICT, ErrorCode::DuplicateEntry, msg)
+                    }
+                    DomainError::InvalidState(msg) => {
+                        (StatusCode::BAD_REQUEST, ErrorCode::InvalidState, msg)
+                    }
                     DomainError::InternalError(msg) => {
                         tracing::error!("Internal error: {}", msg);
-                        (
-                            StatusCode::INTERNAL_SERVER_ERROR,
-                            "Internal server error".to_string(),
-                        )
+                        internal_error()
                     }
                     DomainError::DatabaseError(msg) => {
                         tracing::error!("Database error: {}", msg);
-                        (
-                            StatusCode::INTERNAL_SERVER_ERROR,
-                            "Internal server error".to_string(),
-                        )
+                        internal_error()
+                    }
+                    DomainError::PlayerAlreadyDrafted(msg) => {
+                        (StatusCode::CONFLICT, ErrorCode::PlayerAlreadyDrafted, msg)
+                    }
+                    DomainError::TradeUnfair(msg) => {
+                        (StatusCode::BAD_REQUEST, ErrorCode::TradeUnfair, msg)
+                    }
+                    DomainError::SessionNotInProgress(msg) => (
+                        StatusCode::BAD_REQUEST,
+                        ErrorCode::SessionNotInProgress,
+                        msg,
+                    ),
+                    DomainError::ResourceInUse(msg) => {
+                        (StatusCode::CONFLICT, ErrorCode::ResourceInUse, msg)
                     }
-                    DomainError::PlayerAlreadyDrafted(msg) => (StatusCode::CONFLICT, msg),
                 }
             }
+        }
+    }
+}
+
+fn internal_error() -> (StatusCode, ErrorCode, String) {
+    (
+        StatusCode::INTERNAL_SERVER_ERROR,
+        ErrorCode::InternalError,
+        "Internal server error".to_string(),
+    )
+}
+
+impl IntoResponse for ApiError {
+    fn into_response(self) -> Response {
+        let retry_after = match &self {
+            ApiError::TooManyRequests { retry_after_secs } => Some(*retry_after_secs),
+            _ => None,
         };
+        let (status, code, message) = self.into_parts();
 
-        let body = Json(json!({
-            "error": message,
-            "status": status.as_u16()
-        }));
+        let body = ErrorResponse {
+            error: message,
+            code,
+            status: status.as_u16(),
+            request_id: crate::request_id::current(),
+        };
 
-        (status, body).into_response()
+        let mut response = (status, Json(body)).into_response();
+        if let Some(secs) = retry_after {
+            response
+                .headers_mut()
+                .insert(RETRY_AFTER, HeaderValue::from(secs));
+        }
+        response
     }
 }
 
@@ -87,6 +204,23 @@ mod tests {
         assert_eq!(response.status(), StatusCode::BAD_REQUEST);
     }
 
+    #[test]
+    fn test_forbidden_error() {
+        let error = ApiError::Forbidden("Not your pick".to_string());
+        let response = error.into_response();
+        assert_eq!(response.status(), StatusCode::FORBIDDEN);
+    }
+
+    #[test]
+    fn test_too_many_requests_sets_retry_after() {
+        let error = ApiError::TooManyRequests {
+            retry_after_secs: 12,
+        };
+        let response = error.into_response();
+        assert_eq!(response.status(), StatusCode::TOO_MANY_REQUESTS);
+        assert_eq!(response.headers()[RETRY_AFTER], "12");
+    }
+
     #[test]
     fn test_domain_error_conversion() {
         let domain_err = DomainError::ValidationError("Invalid data".to_string());
@@ -95,6 +229,34 @@ mod tests {
         assert_eq!(response.status(), StatusCode::BAD_REQUEST);
     }
 
+    #[tokio::test]
+    async fn test_body_carries_error_code() {
+        let domain_err = DomainError::TradeUnfair("Trade is not fair".to_string());
+        let response = ApiError::from(domain_err).into_response();
+        assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+
+        let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        let body: serde_json::Value = serde_json::from_slice(&body).unwrap();
+        assert_eq!(body["code"], "TRADE_UNFAIR");
+        assert_eq!(body["error"], "Trade is not fair");
+        assert_eq!(body["status"], 400);
+    }
+
+    #[test]
+    fn test_error_code_names_match_serialization() {
+        for code in [
+            ErrorCode::NotFound,
+            ErrorCode::PlayerAlreadyDrafted,
+            ErrorCode::SessionNotInProgress,
+            ErrorCode::ResourceInUse,
+            ErrorCode::InternalError,
+        ] {
+            assert_eq!(serde_json::to_value(code).unwrap(), code.as_str());
+        }
+    }
+
     #[test]
     fn test_duplicate_entry_error() {
         let domain_err = DomainError::DuplicateEntry("Team exists".to_string());
@@ -0,0 +1,109 @@
+//! Weak ETags and `If-None-Match` handling for read-heavy list endpoints.
+//!
+//! The tag is a digest of the response body and its total count, so it
+//! changes exactly when what the client would receive changes. Handlers still
+//! run for conditional requests; what a matching request saves is the payload,
+//! which dominates for the full player and ranking lists that clients refetch
+//! on every view.
+
+use axum::body::{to_bytes, Body};
+use axum::extract::Request;
+use axum::http::header::{CONTENT_LENGTH, ETAG, IF_NONE_MATCH};
+use axum::http::{HeaderValue, Method, StatusCode};
+use axum::middleware::Next;
+use axum::response::{IntoResponse, Response};
+use sha2::{Digest, Sha256};
+
+use crate::pagination::TOTAL_COUNT_HEADER;
+
+/// Largest body the middleware will buffer in order to tag it
+const MAX_TAGGED_BODY_BYTES: usize = 32 * 1024 * 1024;
+
+/// Middleware that tags successful GET responses and answers 304 Not
+/// Modified when the client already holds the current representation
+pub async fn conditional_get(request: Request, next: Next) -> Response {
+    if request.method() != Method::GET {
+        return next.run(request).await;
+    }
+    let if_none_match = request.headers().get(IF_NONE_MATCH).cloned();
+
+    let response = next.run(request).await;
+    if response.status() != StatusCode::OK {
+        return response;
+    }
+
+    let (mut parts, body) = response.into_parts();
+    let bytes = match to_bytes(body, MAX_TAGGED_BODY_BYTES).await {
+        Ok(bytes) => bytes,
+        Err(e) => {
+            tracing::error!("Failed to buffer response body for ETag: {}", e);
+            return StatusCode::INTERNAL_SERVER_ERROR.into_response();
+        }
+    };
+
+    let etag = weak_etag(&bytes, parts.headers.get(TOTAL_COUNT_HEADER));
+    parts.headers.insert(ETAG, etag.clone());
+
+    if if_none_match.is_some_and(|header| matches_etag(&header, &etag)) {
+        parts.status = StatusCode::NOT_MODIFIED;
+        parts.headers.remove(CONTENT_LENGTH);
+        return Response::from_parts(parts, Body::empty());
+    }
+
+    Response::from_parts(parts, Body::from(bytes))
+}
+
+fn weak_etag(body: &[u8], total_count: Option<&HeaderValue>) -> HeaderValue {
+    let mut hasher = Sha256::new();
+    hasher.update(body);
+    if let Some(total_count) = total_count {
+        hasher.update(b"\n");
+        hasher.update(total_count.as_bytes());
+    }
+    let digest = hasher.finalize();
+    let hex: String = digest[..16].iter().map(|b| format!("{:02x}", b)).collect();
+    HeaderValue::from_str(&format!("W/\"{}\"", hex)).expect("hex digest is a valid header value")
+}
+
+/// Weak comparison against each tag listed in an `If-None-Match` header
+fn matches_etag(if_none_match: &HeaderValue, etag: &HeaderValue) -> bool {
+    let Ok(candidates) = if_none_match.to_str() else {
+        return false;
+    };
+    let Ok(etag) = etag.to_str() else {
+        return false;
+    };
+    let opaque = |tag: &str| tag.trim().trim_start_matches("W/").to_string();
+    candidates
+        .split(',')
+        .any(|candidate| candidate.trim() == "*" || opaque(candidate) == opaque(etag))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_etag_depends_on_body_and_total() {
+        let a = weak_etag(b"[1,2]", None);
+        assert_eq!(a, weak_etag(b"[1,2]", None));
+        assert_ne!(a, weak_etag(b"[1,3]", None));
+
+        let total = HeaderValue::from_static("10");
+        assert_ne!(a, weak_etag(b"[1,2]", Some(&total)));
+        assert!(a.to_str().unwrap().starts_with("W/\""));
+    }
+
+    #[test]
+    fn test_matches_etag() {
+        let etag = HeaderValue::from_static("W/\"abc\"");
+        assert!(matches_etag(&HeaderValue::from_static("W/\"abc\""), &etag));
+        assert!(matches_etag(&HeaderValue::from_static("\"abc\""), &etag));
+        assert!(matches_etag(
+            &HeaderValue::from_static("W/\"xyz\", W/\"abc\""),
+            &etag
+        ));
+        assert!(matches_etag(&HeaderValue::from_static("*"), &etag));
+        assert!(!matches_etag(&HeaderValue::from_static("W/\"xyz\""), &etag));
+    }
+}
@@ -0,0 +1,145 @@
+//! `?fields=` sparse fieldsets for list endpoints.
+//!
+//! Clients name the response fields they render, comma separated, and each
+//! item is trimmed to just those fields; CSV downloads get one column per
+//! field, in the order given. Names are checked against the response schema,
+//! so a typo is a 400 rather than a silently missing column.
+
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+use utoipa::openapi::{RefOr, Schema};
+use utoipa::{IntoParams, ToSchema};
+
+use crate::error::{ApiError, ApiResult};
+
+#[derive(Debug, Default, Deserialize, IntoParams)]
+pub struct FieldsParams {
+    /// Comma-separated response fields to return, e.g.
+    /// `first_name,last_name,position`; every field when omitted
+    pub fields: Option<String>,
+}
+
+impl FieldsParams {
+    /// The fields requested from a list of `T`, or `None` for all of them
+    pub fn selection<T: ToSchema>(&self) -> ApiResult<Option<FieldSelection>> {
+        let Some(fields) = &self.fields else {
+            return Ok(None);
+        };
+
+        let known = schema_fields::<T>();
+        let mut selected: Vec<String> = Vec::new();
+        for name in fields.split(',').map(str::trim).filter(|n| !n.is_empty()) {
+            if !known.iter().any(|k| k == name) {
+                return Err(ApiError::BadRequest(format!(
+                    "Unknown field '{}'. Available fields: {}",
+                    name,
+                    known.join(", ")
+                )));
+            }
+            if !selected.iter().any(|s| s == name) {
+                selected.push(name.to_string());
+            }
+        }
+        if selected.is_empty() {
+            return Err(ApiError::BadRequest(
+                "fields must name at least one field".to_string(),
+            ));
+        }
+        Ok(Some(FieldSelection(selected)))
+    }
+}
+
+/// Validated field names, in the order the client listed them
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FieldSelection(Vec<String>);
+
+impl FieldSelection {
+    pub fn names(&self) -> &[String] {
+        &self.0
+    }
+
+    /// `item` reduced to the selected fields, as a JSON object
+    pub fn object<T: Serialize>(&self, item: &T) -> Map<String, Value> {
+        let mut full = as_object(item);
+        self.0
+            .iter()
+            .map(|name| (name.clone(), full.remove(name).unwrap_or(Value::Null)))
+            .collect()
+    }
+
+    /// `item`'s values for the selected fields, in order, as a CSV row
+    pub fn values<T: Serialize>(&self, item: &T) -> Vec<Value> {
+        let mut full = as_object(item);
+        self.0
+            .iter()
+            .map(|name| full.remove(name).unwrap_or(Value::Null))
+            .collect()
+    }
+}
+
+fn as_object<T: Serialize>(item: &T) -> Map<String, Value> {
+    match serde_json::to_value(item) {
+        Ok(Value::Object(map)) => map,
+        _ => Map::new(),
+    }
+}
+
+/// Property names of `T`'s OpenAPI schema, which follow its serde names
+fn schema_fields<T: ToSchema>() -> Vec<String> {
+    match T::schema() {
+        RefOr::T(Schema::Object(object)) => object.properties.keys().cloned().collect(),
+        _ => Vec::new(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Serialize, ToSchema)]
+    struct Row {
+        name: String,
+        rank: i32,
+        college: Option<String>,
+    }
+
+    fn params(fields: Option<&str>) -> FieldsParams {
+        FieldsParams {
+            fields: fields.map(str::to_string),
+        }
+    }
+
+    #[test]
+    fn test_no_fields_means_everything() {
+        assert_eq!(params(None).selection::<Row>().unwrap(), None);
+    }
+
+    #[test]
+    fn test_selection_keeps_order_and_drops_duplicates() {
+        let selection = params(Some("rank, name,rank"))
+            .selection::<Row>()
+            .unwrap()
+            .unwrap();
+        assert_eq!(selection.names(), ["rank", "name"]);
+
+        let row = Row {
+            name: "Lee".to_string(),
+            rank: 2,
+            college: None,
+        };
+        assert_eq!(
+            Value::Object(selection.object(&row)),
+            serde_json::json!({ "rank": 2, "name": "Lee" })
+        );
+        assert_eq!(
+            selection.values(&row),
+            vec![serde_json::json!(2), serde_json::json!("Lee")]
+        );
+    }
+
+    #[test]
+    fn test_unknown_or_empty_fields_rejected() {
+        assert!(params(Some("name,salary")).selection::<Row>().is_err());
+        assert!(params(Some(" , ")).selection::<Row>().is_err());
+    }
+}
@@ -0,0 +1,76 @@
+//! Batch loaders for the lookups nested fields repeat once per parent
+
+use std::collections::HashMap;
+
+use async_graphql::dataloader::Loader;
+use uuid::Uuid;
+
+use domain::models::{Player, PlayerRankingWithSource, Team};
+
+use super::graphql_error;
+use crate::state::AppState;
+
+/// Players by ID, for the player on each pick
+pub struct PlayerLoader(pub AppState);
+
+impl Loader<Uuid> for PlayerLoader {
+    type Value = Player;
+    type Error = async_graphql::Error;
+
+    async fn load(&self, keys: &[Uuid]) -> Result<HashMap<Uuid, Player>, Self::Error> {
+        let players = self
+            .0
+            .player_repo
+            .find_by_ids(keys)
+            .await
+            .map_err(graphql_error)?;
+        Ok(players.into_iter().map(|p| (p.id, p)).collect())
+    }
+}
+
+/// Teams by ID, for the current and original team on each pick. There are
+/// only ever 32, so a batch reads them all.
+pub struct TeamLoader(pub AppState);
+
+impl Loader<Uuid> for TeamLoader {
+    type Value = Team;
+    type Error = async_graphql::Error;
+
+    async fn load(&self, keys: &[Uuid]) -> Result<HashMap<Uuid, Team>, Self::Error> {
+        let teams = self.0.team_repo.find_all().await.map_err(graphql_error)?;
+        Ok(teams
+            .into_iter()
+            .filter(|team| keys.contains(&team.id))
+            .map(|team| (team.id, team))
+            .collect())
+    }
+}
+
+/// Every source's ranking of a player, keyed by player ID
+pub struct RankingLoader(pub AppState);
+
+impl Loader<Uuid> for RankingLoader {
+    type Value = Vec<PlayerRankingWithSource>;
+    type Error = async_graphql::Error;
+
+    async fn load(
+        &self,
+        keys: &[Uuid],
+    ) -> Result<HashMap<Uuid, Vec<PlayerRankingWithSource>>, Self::Error> {
+        let rankings = self
+            .0
+            .prospect_ranking_repo
+            .find_for_players_with_source(keys)
+            .await
+            .map_err(graphql_error)?;
+
+        let mut by_player: HashMap<Uuid, Vec<PlayerRankingWithSource>> = HashMap::new();
+        for ranking in rankings {
+            by_player
+                .entry(ranking.player_id)
+                .or_default()
+                .push(ranking);
+        }
+        Ok(by_player)
+    }
+}
@@ -0,0 +1,173 @@
+//! Read-only GraphQL endpoint over the domain repositories.
+//!
+//! The draft board needs players, teams, picks and rankings together, which
+//! over REST is one request per collection plus one per lookup. Here a
+//! client asks for the whole tree at once; nested players, teams and
+//! rankings are fetched through DataLoaders, so a board of a few hundred
+//! picks still costs a handful of queries. Writes stay on the REST API.
+
+mod loaders;
+mod types;
+
+use async_graphql::dataloader::DataLoader;
+use async_graphql::http::GraphiQLSource;
+use async_graphql::{Context, EmptyMutation, EmptySubscription, ErrorExtensions, Object, Schema};
+use axum::response::{Html, IntoResponse};
+use uuid::Uuid;
+
+use domain::models::{PageRequest, PlayerFilter, Sort};
+
+use crate::error::ApiError;
+use crate::state::AppState;
+
+use loaders::{PlayerLoader, RankingLoader, TeamLoader};
+use types::{parse_wire_name, DraftNode, PlayerNode, RankingSourceNode, TeamNode};
+
+pub type ApiSchema = Schema<QueryRoot, EmptyMutation, EmptySubscription>;
+
+/// Path the endpoint is mounted at, for the GraphiQL page
+const GRAPHQL_PATH: &str = "/api/v1/graphql";
+
+/// Deepest nesting a query may use; the schema has no cycles deeper than
+/// draft → picks → team → picks → player → rankings
+const MAX_DEPTH: usize = 10;
+
+/// Upper bound on fields selected per query, to keep a single request from
+/// doing unbounded work
+const MAX_COMPLEXITY: usize = 500;
+
+pub fn build_schema(state: AppState) -> ApiSchema {
+    Schema::build(QueryRoot, EmptyMutation, EmptySubscription)
+        .data(DataLoader::new(PlayerLoader(state.clone()), tokio::spawn))
+        .data(DataLoader::new(TeamLoader(state.clone()), tokio::spawn))
+        .data(DataLoader::new(RankingLoader(state.clone()), tokio::spawn))
+        .data(state)
+        .limit_depth(MAX_DEPTH)
+        .limit_complexity(MAX_COMPLEXITY)
+        .finish()
+}
+
+/// GET /api/v1/graphql - Interactive GraphiQL explorer
+pub async fn graphiql() -> impl IntoResponse {
+    Html(GraphiQLSource::build().endpoint(GRAPHQL_PATH).finish())
+}
+
+/// Reports failures the way the REST API does: internal details are logged
+/// and hidden, and the error code and HTTP status the REST endpoint would
+/// have returned go in the `code` and `status` extensions
+fn graphql_error(err: impl Into<ApiError>) -> async_graphql::Error {
+    let (status, code, message) = err.into().into_parts();
+    async_graphql::Error::new(message).extend_with(|_, e| {
+        e.set("code", code.as_str());
+        e.set("status", status.as_u16());
+    })
+}
+
+fn state<'a>(ctx: &Context<'a>) -> &'a AppState {
+    ctx.data_unchecked::<AppState>()
+}
+
+pub struct QueryRoot;
+
+#[Object]
+impl QueryRoot {
+    /// Players, optionally filtered by position (e.g. "QB") and draft year,
+    /// ordered by name
+    async fn players(
+        &self,
+        ctx: &Context<'_>,
+        position: Option<String>,
+        draft_year: Option<i32>,
+        page: Option<u32>,
+        per_page: Option<u32>,
+    ) -> async_graphql::Result<Vec<PlayerNode>> {
+        let filter = PlayerFilter {
+            position: position
+                .as_deref()
+                .map(parse_wire_name)
+                .transpose()
+                .map_err(graphql_error)?,
+            draft_year,
+            ..PlayerFilter::default()
+        };
+        filter.validate().map_err(graphql_error)?;
+        let page = match (page, per_page) {
+            (None, None) => None,
+            (page, per_page) => Some(
+                PageRequest::new(
+                    page.unwrap_or(1),
+                    per_page.unwrap_or(crate::pagination::DEFAULT_PER_PAGE),
+                )
+                .map_err(graphql_error)?,
+            ),
+        };
+
+        let players = state(ctx)
+            .player_repo
+            .find_filtered(&filter, &Sort::default(), page)
+            .await
+            .map_err(graphql_error)?;
+        Ok(players.items.into_iter().map(PlayerNode::from).collect())
+    }
+
+    async fn player(
+        &self,
+        ctx: &Context<'_>,
+        id: Uuid,
+    ) -> async_graphql::Result<Option<PlayerNode>> {
+        let player = state(ctx)
+            .player_repo
+            .find_by_id(id)
+            .await
+            .map_err(graphql_error)?;
+        Ok(player.map(PlayerNode::from))
+    }
+
+    async fn teams(&self, ctx: &Context<'_>) -> async_graphql::Result<Vec<TeamNode>> {
+        let teams = state(ctx)
+            .team_repo
+            .find_all()
+            .await
+            .map_err(graphql_error)?;
+        Ok(teams.into_iter().map(TeamNode::from).collect())
+    }
+
+    async fn team(&self, ctx: &Context<'_>, id: Uuid) -> async_graphql::Result<Option<TeamNode>> {
+        let team = state(ctx)
+            .team_repo
+            .find_by_id(id)
+            .await
+            .map_err(graphql_error)?;
+        Ok(team.map(TeamNode::from))
+    }
+
+    async fn drafts(&self, ctx: &Context<'_>) -> async_graphql::Result<Vec<DraftNode>> {
+        let drafts = state(ctx)
+            .draft_repo
+            .find_all()
+            .await
+            .map_err(graphql_error)?;
+        Ok(drafts.into_iter().map(DraftNode::from).collect())
+    }
+
+    async fn draft(&self, ctx: &Context<'_>, id: Uuid) -> async_graphql::Result<Option<DraftNode>> {
+        let draft = state(ctx)
+            .draft_repo
+            .find_by_id(id)
+            .await
+            .map_err(graphql_error)?;
+        Ok(draft.map(DraftNode::from))
+    }
+
+    async fn ranking_sources(
+        &self,
+        ctx: &Context<'_>,
+    ) -> async_graphql::Result<Vec<RankingSourceNode>> {
+        let sources = state(ctx)
+            .ranking_source_repo
+            .find_all()
+            .await
+            .map_err(graphql_error)?;
+        Ok(sources.into_iter().map(RankingSourceNode::from).collect())
+    }
+}
@@ -0,0 +1,316 @@
+//! GraphQL object types. Scalar fields mirror the REST response bodies;
+//! relationships are resolved lazily, so a query only pays for the parts of
+//! the graph it selects.
+
+use async_graphql::dataloader::DataLoader;
+use async_graphql::{ComplexObject, Context, SimpleObject};
+use chrono::{DateTime, NaiveDate, Utc};
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use uuid::Uuid;
+
+use domain::errors::{DomainError, DomainResult};
+use domain::models::{
+    Draft, DraftPick, Player, PlayerRankingWithSource, RankingSource, Team, TeamNeed,
+};
+
+use super::loaders::{PlayerLoader, RankingLoader, TeamLoader};
+use super::{graphql_error, state};
+
+/// Domain enums are exposed as strings in the same spelling the REST API
+/// uses (`"QB"`, `"AFC East"`, `"InProgress"`), rather than as GraphQL
+/// enums whose names could not carry the spaces
+fn wire_name<T: Serialize>(value: &T) -> String {
+    match serde_json::to_value(value) {
+        Ok(serde_json::Value::String(name)) => name,
+        _ => String::new(),
+    }
+}
+
+/// Parses an argument given in the REST spelling of a domain enum
+pub(super) fn parse_wire_name<T: DeserializeOwned>(name: &str) -> DomainResult<T> {
+    serde_json::from_value(serde_json::Value::String(name.to_string()))
+        .map_err(|_| DomainError::ValidationError(format!("Unknown value '{}'", name)))
+}
+
+#[derive(SimpleObject)]
+#[graphql(name = "Player", complex)]
+pub struct PlayerNode {
+    id: Uuid,
+    first_name: String,
+    last_name: String,
+    position: String,
+    college: Option<String>,
+    height_inches: Option<i32>,
+    weight_pounds: Option<i32>,
+    draft_year: i32,
+    draft_eligible: bool,
+    injury_status: String,
+    injury_body_part: Option<String>,
+    injury_expected_return: Option<NaiveDate>,
+    medical_flag: bool,
+    profile_url: Option<String>,
+    headshot_url: Option<String>,
+}
+
+impl From<Player> for PlayerNode {
+    fn from(player: Player) -> Self {
+        Self {
+            id: player.id,
+            position: wire_name(&player.position),
+            injury_status: wire_name(&player.injury_status),
+            first_name: player.first_name,
+            last_name: player.last_name,
+            college: player.college,
+            height_inches: player.height_inches,
+            weight_pounds: player.weight_pounds,
+            draft_year: player.draft_year,
+            draft_eligible: player.draft_eligible,
+            injury_body_part: player.injury_body_part,
+            injury_expected_return: player.injury_expected_return,
+            medical_flag: player.medical_flag,
+            profile_url: player.profile_url,
+            headshot_url: player.headshot_url,
+        }
+    }
+}
+
+#[ComplexObject]
+impl PlayerNode {
+    /// This player's rank in each source that lists them
+    async fn rankings(&self, ctx: &Context<'_>) -> async_graphql::Result<Vec<RankingNode>> {
+        let rankings = ctx
+            .data_unchecked::<DataLoader<RankingLoader>>()
+            .load_one(self.id)
+            .await?
+            .unwrap_or_default();
+        Ok(rankings.into_iter().map(RankingNode::from).collect())
+    }
+}
+
+#[derive(SimpleObject)]
+#[graphql(name = "Ranking")]
+pub struct RankingNode {
+    source_id: Uuid,
+    source_name: String,
+    rank: i32,
+    scraped_at: NaiveDate,
+}
+
+impl From<PlayerRankingWithSource> for RankingNode {
+    fn from(ranking: PlayerRankingWithSource) -> Self {
+        Self {
+            source_id: ranking.source_id,
+            source_name: ranking.source_name,
+            rank: ranking.rank,
+            scraped_at: ranking.scraped_at,
+        }
+    }
+}
+
+#[derive(SimpleObject)]
+#[graphql(name = "RankingSource")]
+pub struct RankingSourceNode {
+    id: Uuid,
+    name: String,
+    abbreviation: String,
+    url: Option<String>,
+    description: Option<String>,
+}
+
+impl From<RankingSource> for RankingSourceNode {
+    fn from(source: RankingSource) -> Self {
+        Self {
+            id: source.id,
+            name: source.name,
+            abbreviation: source.abbreviation,
+            url: source.url,
+            description: source.description,
+        }
+    }
+}
+
+#[derive(SimpleObject)]
+#[graphql(name = "Team", complex)]
+pub struct TeamNode {
+    id: Uuid,
+    name: String,
+    abbreviation: String,
+    city: String,
+    conference: String,
+    division: String,
+}
+
+impl From<Team> for TeamNode {
+    fn from(team: Team) -> Self {
+        Self {
+            id: team.id,
+            conference: wire_name(&team.conference),
+            division: wire_name(&team.division),
+            name: team.name,
+            abbreviation: team.abbreviation,
+            city: team.city,
+        }
+    }
+}
+
+#[ComplexObject]
+impl TeamNode {
+    /// Positional needs, highest priority first
+    async fn needs(&self, ctx: &Context<'_>) -> async_graphql::Result<Vec<TeamNeedNode>> {
+        let needs = state(ctx)
+            .team_need_repo
+            .find_by_team_id(self.id)
+            .await
+            .map_err(graphql_error)?;
+        Ok(needs.into_iter().map(TeamNeedNode::from).collect())
+    }
+
+    /// The picks this team currently holds in a draft
+    async fn picks(
+        &self,
+        ctx: &Context<'_>,
+        draft_id: Uuid,
+    ) -> async_graphql::Result<Vec<DraftPickNode>> {
+        let picks = state(ctx)
+            .draft_pick_repo
+            .find_by_draft_and_team(draft_id, self.id)
+            .await
+            .map_err(graphql_error)?;
+        Ok(picks.into_iter().map(DraftPickNode::from).collect())
+    }
+}
+
+#[derive(SimpleObject)]
+#[graphql(name = "TeamNeed")]
+pub struct TeamNeedNode {
+    id: Uuid,
+    position: String,
+    priority: i32,
+}
+
+impl From<TeamNeed> for TeamNeedNode {
+    fn from(need: TeamNeed) -> Self {
+        Self {
+            id: need.id,
+            position: wire_name(&need.position),
+            priority: need.priority,
+        }
+    }
+}
+
+#[derive(SimpleObject)]
+#[graphql(name = "Draft", complex)]
+pub struct DraftNode {
+    id: Uuid,
+    name: String,
+    year: i32,
+    status: String,
+    rounds: i32,
+    picks_per_round: Option<i32>,
+}
+
+impl From<Draft> for DraftNode {
+    fn from(draft: Draft) -> Self {
+        Self {
+            id: draft.id,
+            status: wire_name(&draft.status),
+            name: draft.name,
+            year: draft.year,
+            rounds: draft.rounds,
+            picks_per_round: draft.picks_per_round,
+        }
+    }
+}
+
+#[ComplexObject]
+impl DraftNode {
+    /// Every pick in the draft in overall order, optionally limited to one
+    /// round
+    async fn picks(
+        &self,
+        ctx: &Context<'_>,
+        round: Option<i32>,
+    ) -> async_graphql::Result<Vec<DraftPickNode>> {
+        let repo = &state(ctx).draft_pick_repo;
+        let picks = match round {
+            Some(round) => repo.find_by_draft_and_round(self.id, round).await,
+            None => repo.find_by_draft_id(self.id).await,
+        }
+        .map_err(graphql_error)?;
+        Ok(picks.into_iter().map(DraftPickNode::from).collect())
+    }
+}
+
+#[derive(SimpleObject)]
+#[graphql(name = "DraftPick", complex)]
+pub struct DraftPickNode {
+    id: Uuid,
+    draft_id: Uuid,
+    round: i32,
+    pick_number: i32,
+    overall_pick: i32,
+    team_id: Uuid,
+    player_id: Option<Uuid>,
+    picked_at: Option<DateTime<Utc>>,
+    original_team_id: Option<Uuid>,
+    is_compensatory: bool,
+    notes: Option<String>,
+}
+
+impl From<DraftPick> for DraftPickNode {
+    fn from(pick: DraftPick) -> Self {
+        Self {
+            id: pick.id,
+            draft_id: pick.draft_id,
+            round: pick.round,
+            pick_number: pick.pick_number,
+            overall_pick: pick.overall_pick,
+            team_id: pick.team_id,
+            player_id: pick.player_id,
+            picked_at: pick.picked_at,
+            original_team_id: pick.original_team_id,
+            is_compensatory: pick.is_compensatory,
+            notes: pick.notes,
+        }
+    }
+}
+
+#[ComplexObject]
+impl DraftPickNode {
+    /// The team currently holding the pick
+    async fn team(&self, ctx: &Context<'_>) -> async_graphql::Result<Option<TeamNode>> {
+        load_team(ctx, Some(self.team_id)).await
+    }
+
+    /// The team the pick was first assigned to, when it has been traded
+    async fn original_team(&self, ctx: &Context<'_>) -> async_graphql::Result<Option<TeamNode>> {
+        load_team(ctx, self.original_team_id).await
+    }
+
+    /// The player selected, once the pick has been made
+    async fn player(&self, ctx: &Context<'_>) -> async_graphql::Result<Option<PlayerNode>> {
+        let Some(player_id) = self.player_id else {
+            return Ok(None);
+        };
+        let player = ctx
+            .data_unchecked::<DataLoader<PlayerLoader>>()
+            .load_one(player_id)
+            .await?;
+        Ok(player.map(PlayerNode::from))
+    }
+}
+
+async fn load_team(
+    ctx: &Context<'_>,
+    team_id: Option<Uuid>,
+) -> async_graphql::Result<Option<TeamNode>> {
+    let Some(team_id) = team_id else {
+        return Ok(None);
+    };
+    let team = ctx
+        .data_unchecked::<DataLoader<TeamLoader>>()
+        .load_one(team_id)
+        .await?;
+    Ok(team.map(TeamNode::from))
+}
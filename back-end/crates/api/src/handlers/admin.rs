@@ -0,0 +1,86 @@
+use axum::{extract::State, Json};
+use chrono::Utc;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::error::{ApiError, ApiResult};
+use crate::state::AppState;
+
+/// Longest announcement an administrator may send
+const MAX_ANNOUNCEMENT_LENGTH: usize = 500;
+
+#[derive(Debug, Deserialize)]
+pub struct AdminBroadcastRequest {
+    pub message: String,
+    /// Limit the announcement to one session; omit to reach every session
+    /// with connected clients
+    #[serde(default)]
+    pub session_id: Option<Uuid>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct AdminBroadcastResponse {
+    pub sessions: usize,
+    pub connections: usize,
+}
+
+/// POST /api/v1/admin/broadcast
+/// Pushes an `AdminAnnouncement` to every client watching the target
+/// sessions. Admin only.
+pub async fn broadcast_announcement(
+    State(state): State<AppState>,
+    Json(payload): Json<AdminBroadcastRequest>,
+) -> ApiResult<Json<AdminBroadcastResponse>> {
+    let message = payload.message.trim().to_string();
+    if message.is_empty() {
+        return Err(ApiError::BadRequest(
+            "Announcement cannot be empty".to_string(),
+        ));
+    }
+    if message.chars().count() > MAX_ANNOUNCEMENT_LENGTH {
+        return Err(ApiError::BadRequest(format!(
+            "Announcement cannot exceed {} characters",
+            MAX_ANNOUNCEMENT_LENGTH
+        )));
+    }
+
+    let session_ids = match payload.session_id {
+        Some(session_id) => {
+            state
+                .session_repo
+                .find_by_id(session_id)
+                .await?
+                .ok_or_else(|| {
+                    domain::errors::DomainError::NotFound(format!("Session {}", session_id))
+                })?;
+            vec![session_id]
+        }
+        None => state.ws_manager.active_session_ids(),
+    };
+
+    let sent_at = Utc::now().to_rfc3339();
+    let mut connections = 0;
+    for session_id in &session_ids {
+        connections += state.ws_manager.session_connection_count(*session_id);
+        let announcement = websocket::ServerMessage::admin_announcement(
+            *session_id,
+            message.clone(),
+            sent_at.clone(),
+        );
+        state
+            .ws_manager
+            .broadcast_to_session(*session_id, announcement)
+            .await;
+    }
+
+    tracing::info!(
+        sessions = session_ids.len(),
+        connections,
+        "Sent admin announcement"
+    );
+
+    Ok(Json(AdminBroadcastResponse {
+        sessions: session_ids.len(),
+        connections,
+    }))
+}
@@ -0,0 +1,105 @@
+use std::collections::HashMap;
+
+use axum::extract::{Query, State};
+use serde::{Deserialize, Serialize};
+use utoipa::{IntoParams, ToSchema};
+use uuid::Uuid;
+
+use domain::models::{Page, PlayerFilter, Position, Sort};
+use domain::services::AdpService;
+
+use crate::error::ApiResult;
+use crate::pagination::{Paginated, PaginationParams};
+use crate::state::AppState;
+
+/// Record a just-completed draft's picks for average draft position.
+/// Called from every path that completes a draft.
+pub(crate) async fn record_adp(state: &AppState, draft_id: Uuid, year: i32) -> ApiResult<()> {
+    let picks = state.draft_pick_repo.find_by_draft_id(draft_id).await?;
+    let entries = AdpService::entries_for_draft(year, &picks);
+    state.adp_repo.record(&entries).await?;
+    Ok(())
+}
+
+#[derive(Debug, Default, Deserialize, IntoParams)]
+pub struct AdpQuery {
+    /// Only prospects in this draft class
+    pub year: Option<i32>,
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct PlayerAdpResponse {
+    pub player_id: Uuid,
+    pub first_name: String,
+    pub last_name: String,
+    pub position: Position,
+    pub college: Option<String>,
+    /// Completed mock drafts the player was picked in
+    pub times_drafted: usize,
+    /// Share of completed mock drafts the player was picked in, 0-1
+    pub draft_rate: f64,
+    pub average_pick: f64,
+    pub min_pick: i32,
+    pub max_pick: i32,
+    /// Average over the newer half of the player's drafts
+    pub recent_average: Option<f64>,
+    /// Older-half average minus newer-half average; positive means the
+    /// player is going earlier than they used to
+    pub trend: Option<f64>,
+}
+
+/// GET /api/v1/adp - Average draft position
+///
+/// Where each prospect went across every completed mock draft, with the
+/// earliest and latest slot and how their position has moved over time.
+#[utoipa::path(
+    get,
+    path = "/api/v1/adp",
+    params(AdpQuery, PaginationParams),
+    responses(
+        (status = 200, description = "Players by average draft position, earliest first; total count in X-Total-Count", body = Vec<PlayerAdpResponse>),
+        (status = 400, description = "Invalid pagination parameters")
+    ),
+    tag = "rankings"
+)]
+pub async fn get_adp(
+    State(state): State<AppState>,
+    Query(query): Query<AdpQuery>,
+    Query(pagination): Query<PaginationParams>,
+) -> ApiResult<Paginated<PlayerAdpResponse>> {
+    let request = pagination.page_request()?;
+
+    let filter = PlayerFilter {
+        draft_year: query.year,
+        ..Default::default()
+    };
+    let sort = Sort::default();
+    let (entries, players) = tokio::join!(
+        state.adp_repo.find_by_year(query.year),
+        state.player_repo.find_filtered(&filter, &sort, None),
+    );
+    let mut players: HashMap<Uuid, _> = players?.items.into_iter().map(|p| (p.id, p)).collect();
+
+    let entries: Vec<PlayerAdpResponse> = AdpService::summarize(&entries?)
+        .into_iter()
+        .filter_map(|adp| {
+            let player = players.remove(&adp.player_id)?;
+            Some(PlayerAdpResponse {
+                player_id: adp.player_id,
+                first_name: player.first_name,
+                last_name: player.last_name,
+                position: player.position,
+                college: player.college,
+                times_drafted: adp.times_drafted,
+                draft_rate: adp.draft_rate,
+                average_pick: adp.average_pick,
+                min_pick: adp.min_pick,
+                max_pick: adp.max_pick,
+                recent_average: adp.recent_average,
+                trend: adp.trend,
+            })
+        })
+        .collect();
+
+    Ok(Paginated::page(Page::from_all(entries, request), request))
+}
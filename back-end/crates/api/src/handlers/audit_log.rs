@@ -0,0 +1,108 @@
+use axum::extract::{Query, State};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use utoipa::{IntoParams, ToSchema};
+use uuid::Uuid;
+
+use domain::models::{AuditEntry, AuditLogFilter, PageRequest};
+
+use crate::error::ApiResult;
+use crate::pagination::{Paginated, PaginationParams, DEFAULT_PER_PAGE};
+use crate::state::AppState;
+
+#[derive(Debug, Default, Deserialize, IntoParams)]
+pub struct AuditLogParams {
+    /// Only writes to this kind of entity, such as `picks` or `sessions`
+    pub entity_type: Option<String>,
+    /// Only writes to this entity
+    pub entity_id: Option<Uuid>,
+    /// Only writes made by this user
+    pub actor_user_id: Option<Uuid>,
+    /// Only writes at or after this time (RFC 3339)
+    pub since: Option<DateTime<Utc>>,
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct AuditEntryResponse {
+    pub id: Uuid,
+    pub actor_user_id: Option<Uuid>,
+    /// The user's seat in the session written to, when they act from one
+    pub actor_participant_id: Option<Uuid>,
+    /// The participant's display name, else the user's email, `api-key` for
+    /// the seed API key, or `anonymous`
+    pub actor: String,
+    pub method: String,
+    /// Matched route template, such as `/api/v1/picks/{id}/make`
+    pub route: String,
+    pub entity_type: String,
+    pub entity_id: Option<Uuid>,
+    /// The entity before the write, when its repository records changes
+    #[schema(value_type = Option<Object>)]
+    pub before: Option<Value>,
+    /// The entity after the write, when its repository records changes
+    #[schema(value_type = Option<Object>)]
+    pub after: Option<Value>,
+    pub status_code: i32,
+    pub request_id: Option<String>,
+    pub created_at: DateTime<Utc>,
+}
+
+impl From<AuditEntry> for AuditEntryResponse {
+    fn from(entry: AuditEntry) -> Self {
+        Self {
+            id: entry.id,
+            actor_user_id: entry.actor_user_id,
+            actor_participant_id: entry.actor_participant_id,
+            actor: entry.actor,
+            method: entry.method,
+            route: entry.route,
+            entity_type: entry.entity_type,
+            entity_id: entry.entity_id,
+            before: entry.before,
+            after: entry.after,
+            status_code: entry.status_code,
+            request_id: entry.request_id,
+            created_at: entry.created_at,
+        }
+    }
+}
+
+/// GET /api/v1/admin/audit-log - Search the log of writes, newest first
+///
+/// Answers "who made that pick and when": filter by `entity_type=picks` and
+/// the pick's `entity_id`. Always paged, 50 entries per page by default.
+#[utoipa::path(
+    get,
+    path = "/api/v1/admin/audit-log",
+    params(AuditLogParams, PaginationParams),
+    responses(
+        (status = 200, description = "Matching audit entries; total count in X-Total-Count", body = Vec<AuditEntryResponse>),
+        (status = 400, description = "Invalid filter or pagination parameters"),
+        (status = 401, description = "Missing or invalid credentials"),
+        (status = 403, description = "Caller is not a commissioner")
+    ),
+    tag = "admin"
+)]
+pub async fn list_audit_log(
+    State(state): State<AppState>,
+    Query(params): Query<AuditLogParams>,
+    Query(pagination): Query<PaginationParams>,
+) -> ApiResult<Paginated<AuditEntryResponse>> {
+    let request = match pagination.page_request()? {
+        Some(request) => request,
+        None => PageRequest::new(1, DEFAULT_PER_PAGE)?,
+    };
+    let filter = AuditLogFilter {
+        entity_type: params.entity_type,
+        entity_id: params.entity_id,
+        actor_user_id: params.actor_user_id,
+        since: params.since,
+    };
+
+    let page = state.audit_log_repo.find(&filter, request).await?;
+    Ok(Paginated::page(
+        page.map(AuditEntryResponse::from),
+        Some(request),
+    ))
+}
@@ -0,0 +1,193 @@
+use axum::extract::{Path, State};
+use axum::http::StatusCode;
+use axum::Json;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+use domain::models::{Role, User};
+
+use crate::auth::{hash_password, verify_password, AuthUser};
+use crate::error::{ApiError, ApiResult};
+use crate::state::AppState;
+
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct RegisterRequest {
+    pub email: String,
+    pub display_name: String,
+    pub password: String,
+}
+
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct LoginRequest {
+    pub email: String,
+    pub password: String,
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct UserResponse {
+    pub id: Uuid,
+    pub email: String,
+    pub display_name: String,
+    pub role: Role,
+    pub created_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct UpdateRoleRequest {
+    pub role: Role,
+}
+
+impl From<User> for UserResponse {
+    fn from(user: User) -> Self {
+        Self {
+            id: user.id,
+            email: user.email,
+            display_name: user.display_name,
+            role: user.role,
+            created_at: user.created_at,
+        }
+    }
+}
+
+/// An access token to send as `Authorization: Bearer <token>`
+#[derive(Debug, Serialize, ToSchema)]
+pub struct TokenResponse {
+    pub access_token: String,
+    pub token_type: String,
+    /// Seconds until the token expires
+    pub expires_in: u64,
+    pub user: UserResponse,
+}
+
+fn token_response(state: &AppState, user: User) -> ApiResult<TokenResponse> {
+    let access_token = state.jwt.issue(user.id, &user.email, user.role)?;
+    Ok(TokenResponse {
+        access_token,
+        token_type: "Bearer".to_string(),
+        expires_in: state.jwt.ttl().as_secs(),
+        user: user.into(),
+    })
+}
+
+/// POST /api/v1/auth/register - Create an account and sign in
+#[utoipa::path(
+    post,
+    path = "/api/v1/auth/register",
+    request_body = RegisterRequest,
+    responses(
+        (status = 201, description = "Account created", body = TokenResponse),
+        (status = 400, description = "Invalid email, display name or password"),
+        (status = 409, description = "Email already registered")
+    ),
+    tag = "auth"
+)]
+pub async fn register(
+    State(state): State<AppState>,
+    Json(req): Json<RegisterRequest>,
+) -> ApiResult<(StatusCode, Json<TokenResponse>)> {
+    User::validate_password(&req.password)?;
+    let password_hash = hash_password(req.password).await?;
+    let mut user = User::new(&req.email, &req.display_name, password_hash)?;
+    if state.admin_email.as_deref() == Some(user.email.as_str()) {
+        user.role = Role::Admin;
+    }
+
+    let created = state.user_repo.create(&user).await?;
+    Ok((StatusCode::CREATED, Json(token_response(&state, created)?)))
+}
+
+/// POST /api/v1/auth/login - Exchange credentials for an access token
+#[utoipa::path(
+    post,
+    path = "/api/v1/auth/login",
+    request_body = LoginRequest,
+    responses(
+        (status = 200, description = "Signed in", body = TokenResponse),
+        (status = 401, description = "Wrong email or password")
+    ),
+    tag = "auth"
+)]
+pub async fn login(
+    State(state): State<AppState>,
+    Json(req): Json<LoginRequest>,
+) -> ApiResult<Json<TokenResponse>> {
+    let user = state
+        .user_repo
+        .find_by_email(&User::normalize_email(&req.email))
+        .await?;
+
+    let stored_hash = user.as_ref().map(|user| user.password_hash.clone());
+    let verified = verify_password(req.password, stored_hash).await;
+    match user {
+        Some(user) if verified => Ok(Json(token_response(&state, user)?)),
+        _ => Err(ApiError::Unauthorized(
+            "Invalid email or password".to_string(),
+        )),
+    }
+}
+
+/// GET /api/v1/auth/me - The account behind the presented token
+#[utoipa::path(
+    get,
+    path = "/api/v1/auth/me",
+    responses(
+        (status = 200, description = "Signed-in user", body = UserResponse),
+        (status = 401, description = "Missing, invalid or expired token")
+    ),
+    tag = "auth"
+)]
+pub async fn me(State(state): State<AppState>, user: AuthUser) -> ApiResult<Json<UserResponse>> {
+    let user = state
+        .user_repo
+        .find_by_id(user.user_id)
+        .await?
+        .ok_or_else(|| ApiError::Unauthorized("Account no longer exists".to_string()))?;
+
+    Ok(Json(user.into()))
+}
+
+/// PUT /api/v1/admin/users/{id}/role - Grant a user a different role.
+/// Commissioners may move accounts between spectator and participant, so
+/// players who registered can be let into a draft; every other change needs
+/// an admin.
+#[utoipa::path(
+    put,
+    path = "/api/v1/admin/users/{id}/role",
+    params(
+        ("id" = Uuid, Path, description = "User ID")
+    ),
+    request_body = UpdateRoleRequest,
+    responses(
+        (status = 200, description = "Role updated; applies from the user's next sign-in", body = UserResponse),
+        (status = 401, description = "Missing or invalid credentials"),
+        (status = 403, description = "Caller is not a commissioner, or the change needs an admin"),
+        (status = 404, description = "User not found")
+    ),
+    tag = "admin"
+)]
+pub async fn update_user_role(
+    State(state): State<AppState>,
+    caller: AuthUser,
+    Path(id): Path<Uuid>,
+    Json(req): Json<UpdateRoleRequest>,
+) -> ApiResult<Json<UserResponse>> {
+    if !caller.role.includes(Role::Admin) {
+        let user = state
+            .user_repo
+            .find_by_id(id)
+            .await?
+            .ok_or_else(|| domain::errors::DomainError::NotFound(format!("User {}", id)))?;
+        if req.role > Role::Participant || user.role > Role::Participant {
+            return Err(ApiError::Forbidden(
+                "Only an admin can grant or revoke roles above participant".to_string(),
+            ));
+        }
+    }
+
+    let user = state.user_repo.update_role(id, req.role).await?;
+    tracing::info!(user_id = %user.id, role = %user.role, "Updated user role");
+
+    Ok(Json(user.into()))
+}
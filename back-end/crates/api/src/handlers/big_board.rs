@@ -0,0 +1,203 @@
+use std::cmp::Ordering;
+use std::collections::{HashMap, HashSet};
+
+use axum::extract::{Query, State};
+use serde::{Deserialize, Serialize};
+use utoipa::{IntoParams, ToSchema};
+use uuid::Uuid;
+
+use domain::models::{BigBoardWeights, Page, PlayerFilter, Position, ScoutingReportFilter, Sort};
+use domain::services::RasScoringService;
+
+use crate::error::{ApiError, ApiResult};
+use crate::pagination::{Paginated, PaginationParams};
+use crate::state::AppState;
+
+#[derive(Debug, Default, Deserialize, IntoParams)]
+pub struct BigBoardQuery {
+    /// Only prospects in this draft class
+    pub year: Option<i32>,
+    /// Relative weights as `consensus:0.5,grade:0.3,ras:0.2` (the default);
+    /// inputs left out weigh nothing
+    pub weights: Option<String>,
+    /// Use this team's scouting grades; every team's grades are averaged
+    /// when omitted
+    pub team_id: Option<Uuid>,
+}
+
+impl BigBoardQuery {
+    fn parse_weights(&self) -> ApiResult<BigBoardWeights> {
+        let Some(spec) = &self.weights else {
+            return Ok(BigBoardWeights::default());
+        };
+
+        let mut weights = BigBoardWeights {
+            consensus: 0.0,
+            grade: 0.0,
+            ras: 0.0,
+        };
+        for part in spec.split(',').map(str::trim).filter(|p| !p.is_empty()) {
+            let (name, value) = part.split_once(':').ok_or_else(|| {
+                ApiError::BadRequest(format!("Invalid weight '{}'; expected name:value", part))
+            })?;
+            let value: f64 = value
+                .trim()
+                .parse()
+                .map_err(|_| ApiError::BadRequest(format!("Invalid weight value in '{}'", part)))?;
+            match name.trim() {
+                "consensus" => weights.consensus = value,
+                "grade" => weights.grade = value,
+                "ras" => weights.ras = value,
+                other => {
+                    return Err(ApiError::BadRequest(format!(
+                        "Unknown weight '{}'. Available weights: consensus, grade, ras",
+                        other
+                    )))
+                }
+            }
+        }
+        weights.validate()?;
+        Ok(weights)
+    }
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct BigBoardEntryResponse {
+    /// 1-based position on the blended board
+    pub board_rank: i64,
+    pub player_id: Uuid,
+    pub first_name: String,
+    pub last_name: String,
+    pub position: Position,
+    pub college: Option<String>,
+    /// Weighted 0-10 blend of the inputs below that the player has
+    pub composite_score: f64,
+    pub consensus_rank: Option<i64>,
+    /// Average scouting grade (0-10)
+    pub scouting_grade: Option<f64>,
+    /// Overall RAS (0-10)
+    pub ras_score: Option<f64>,
+}
+
+/// GET /api/v1/big-board - Blended big board
+///
+/// Ranks prospects by one composite score built from consensus rank across
+/// ranking sources, scouting grade and RAS. Each input is put on a 0-10
+/// scale and weighted by `weights=`; inputs a prospect lacks are left out
+/// of their average rather than counted as zero.
+#[utoipa::path(
+    get,
+    path = "/api/v1/big-board",
+    params(BigBoardQuery, PaginationParams),
+    responses(
+        (status = 200, description = "Blended big board, best composite score first; total count in X-Total-Count", body = Vec<BigBoardEntryResponse>),
+        (status = 400, description = "Invalid weights or pagination parameters")
+    ),
+    tag = "rankings"
+)]
+pub async fn get_big_board(
+    State(state): State<AppState>,
+    Query(query): Query<BigBoardQuery>,
+    Query(pagination): Query<PaginationParams>,
+) -> ApiResult<Paginated<BigBoardEntryResponse>> {
+    let weights = query.parse_weights()?;
+    let request = pagination.page_request()?;
+
+    let filter = PlayerFilter {
+        draft_year: query.year,
+        ..Default::default()
+    };
+    let players = state
+        .player_repo
+        .find_filtered(&filter, &Sort::default(), None)
+        .await?
+        .items;
+
+    let consensus = state
+        .prospect_ranking_repo
+        .find_consensus(query.year)
+        .await?;
+    let board_size = consensus.len();
+    let consensus: HashMap<Uuid, i64> = consensus
+        .into_iter()
+        .map(|c| (c.player_id, c.consensus_rank))
+        .collect();
+
+    let report_filter = ScoutingReportFilter {
+        team_id: query.team_id,
+        ..Default::default()
+    };
+    let reports = state
+        .scouting_report_repo
+        .find_filtered(&report_filter, None)
+        .await?
+        .items;
+    let mut grade_totals: HashMap<Uuid, (f64, u32)> = HashMap::new();
+    for report in reports {
+        let entry = grade_totals.entry(report.player_id).or_default();
+        entry.0 += report.grade;
+        entry.1 += 1;
+    }
+
+    // Merged testing results per player, as in the RAS endpoints
+    let combine_results = state.combine_results_repo.find_all().await?;
+    let percentiles = state.combine_percentile_repo.find_all().await?;
+    let combine_by_player = state
+        .ras_service
+        .select_combine_results_by_player(combine_results);
+
+    let mut entries: Vec<BigBoardEntryResponse> = Vec::new();
+    let mut seen = HashSet::new();
+    for player in players {
+        if !seen.insert(player.id) {
+            continue;
+        }
+        let consensus_rank = consensus.get(&player.id).copied();
+        let scouting_grade = grade_totals
+            .get(&player.id)
+            .map(|(total, count)| ((total / *count as f64) * 100.0).round() / 100.0);
+        let ras_score = combine_by_player.get(&player.id).and_then(|results| {
+            RasScoringService::calculate_ras_with_percentiles(&player, results, &percentiles)
+                .overall_score
+        });
+
+        let Some(composite_score) = weights.composite(
+            consensus_rank.map(|rank| BigBoardWeights::consensus_score(rank, board_size)),
+            scouting_grade,
+            ras_score,
+        ) else {
+            continue;
+        };
+
+        entries.push(BigBoardEntryResponse {
+            board_rank: 0,
+            player_id: player.id,
+            first_name: player.first_name,
+            last_name: player.last_name,
+            position: player.position,
+            college: player.college,
+            composite_score,
+            consensus_rank,
+            scouting_grade,
+            ras_score,
+        });
+    }
+
+    entries.sort_by(|a, b| {
+        b.composite_score
+            .partial_cmp(&a.composite_score)
+            .unwrap_or(Ordering::Equal)
+            .then_with(|| match (a.consensus_rank, b.consensus_rank) {
+                (Some(a), Some(b)) => a.cmp(&b),
+                (Some(_), None) => Ordering::Less,
+                (None, Some(_)) => Ordering::Greater,
+                (None, None) => Ordering::Equal,
+            })
+            .then_with(|| a.last_name.cmp(&b.last_name))
+    });
+    for (index, entry) in entries.iter_mut().enumerate() {
+        entry.board_rank = index as i64 + 1;
+    }
+
+    Ok(Paginated::page(Page::from_all(entries, request), request))
+}
@@ -0,0 +1,135 @@
+use axum::{
+    extract::{Path, Query, State},
+    Json,
+};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::auth::AuthUser;
+use crate::error::{ApiError, ApiResult};
+use crate::handlers::participants::{ensure_not_participant_name, participant_for_user};
+use crate::state::AppState;
+use domain::models::ChatMessage;
+
+/// Messages returned when the history request gives no limit
+const DEFAULT_HISTORY_LIMIT: i64 = 50;
+const MAX_HISTORY_LIMIT: i64 = 200;
+
+/// Name shown for spectators who do not choose one
+const SPECTATOR_NAME: &str = "Spectator";
+
+#[derive(Debug, Deserialize)]
+pub struct ChatHistoryQuery {
+    /// Only return messages sent before this time, for paging backwards
+    pub before: Option<DateTime<Utc>>,
+    pub limit: Option<i64>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ChatMessageResponse {
+    pub id: Uuid,
+    pub session_id: Uuid,
+    pub participant_id: Option<Uuid>,
+    pub display_name: String,
+    pub body: String,
+    pub created_at: String,
+}
+
+impl From<ChatMessage> for ChatMessageResponse {
+    fn from(message: ChatMessage) -> Self {
+        Self {
+            id: message.id,
+            session_id: message.session_id,
+            participant_id: message.participant_id,
+            display_name: message.display_name,
+            body: message.body,
+            created_at: message.created_at.to_rfc3339(),
+        }
+    }
+}
+
+/// Stores a chat message and broadcasts it to everyone subscribed to the
+/// session. An access token, when given, must belong to a participant of the
+/// session and the message is posted under that participant's name. Without
+/// one the sender is a spectator and may not borrow a participant's name.
+pub(crate) async fn post_chat_message(
+    state: &AppState,
+    session_id: Uuid,
+    body: String,
+    display_name: Option<String>,
+    access_token: Option<&str>,
+) -> ApiResult<ChatMessage> {
+    state
+        .session_repo
+        .find_by_id(session_id)
+        .await?
+        .ok_or_else(|| domain::errors::DomainError::NotFound(format!("Session {}", session_id)))?;
+
+    let (participant_id, name) = match access_token {
+        Some(token) => {
+            let user = AuthUser::from_token(token, state)?;
+            let participant = participant_for_user(state, session_id, user.user_id)
+                .await?
+                .ok_or_else(|| {
+                    ApiError::Forbidden(format!(
+                        "{} has not joined session {}",
+                        user.email, session_id
+                    ))
+                })?;
+            (Some(participant.id), participant.display_name)
+        }
+        None => {
+            let name = display_name.unwrap_or_else(|| SPECTATOR_NAME.to_string());
+            ensure_not_participant_name(state, session_id, &name).await?;
+            (None, name)
+        }
+    };
+
+    let message = ChatMessage::new(session_id, participant_id, name, body)?;
+    let created = state.chat_repo.create(&message).await?;
+
+    let broadcast = websocket::ServerMessage::chat_message(
+        session_id,
+        created.id,
+        created.participant_id,
+        created.display_name.clone(),
+        created.body.clone(),
+        created.created_at.to_rfc3339(),
+    );
+    state
+        .ws_manager
+        .broadcast_to_session(session_id, broadcast)
+        .await;
+
+    Ok(created)
+}
+
+/// GET /api/v1/sessions/:id/chat?before=<rfc3339>&limit=<n>
+/// Returns the latest chat messages, oldest first. Pass the `created_at` of
+/// the first message as `before` to load the page before it.
+pub async fn get_chat_history(
+    State(state): State<AppState>,
+    Path(session_id): Path<Uuid>,
+    Query(query): Query<ChatHistoryQuery>,
+) -> ApiResult<Json<Vec<ChatMessageResponse>>> {
+    state
+        .session_repo
+        .find_by_id(session_id)
+        .await?
+        .ok_or_else(|| domain::errors::DomainError::NotFound(format!("Session {}", session_id)))?;
+
+    let limit = query.limit.unwrap_or(DEFAULT_HISTORY_LIMIT);
+    if !(1..=MAX_HISTORY_LIMIT).contains(&limit) {
+        return Err(ApiError::BadRequest(format!(
+            "limit must be between 1 and {}",
+            MAX_HISTORY_LIMIT
+        )));
+    }
+
+    let messages = state
+        .chat_repo
+        .list_by_session(session_id, query.before, limit)
+        .await?;
+    Ok(Json(messages.into_iter().map(Into::into).collect()))
+}
@@ -0,0 +1,66 @@
+use axum::extract::{Path, State};
+use axum::Json;
+use serde::Serialize;
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+use domain::models::{CollegeStats, DefenseLine, PassingLine, ReceivingLine, RushingLine};
+
+use crate::error::ApiResult;
+use crate::state::AppState;
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct CollegeStatsResponse {
+    pub id: Uuid,
+    pub player_id: Uuid,
+    pub season: i32,
+    pub school: Option<String>,
+    pub games_played: i32,
+    pub passing: Option<PassingLine>,
+    pub rushing: Option<RushingLine>,
+    pub receiving: Option<ReceivingLine>,
+    pub defense: Option<DefenseLine>,
+}
+
+impl From<CollegeStats> for CollegeStatsResponse {
+    fn from(stats: CollegeStats) -> Self {
+        Self {
+            id: stats.id,
+            player_id: stats.player_id,
+            season: stats.season,
+            school: stats.school,
+            games_played: stats.games_played,
+            passing: stats.passing,
+            rushing: stats.rushing,
+            receiving: stats.receiving,
+            defense: stats.defense,
+        }
+    }
+}
+
+/// GET /api/v1/players/:player_id/college-stats - Get a player's college production, oldest season first
+#[utoipa::path(
+    get,
+    path = "/api/v1/players/{player_id}/college-stats",
+    responses(
+        (status = 200, description = "College seasons for player", body = Vec<CollegeStatsResponse>)
+    ),
+    params(
+        ("player_id" = Uuid, Path, description = "Player ID")
+    ),
+    tag = "college-stats"
+)]
+pub async fn get_player_college_stats(
+    State(state): State<AppState>,
+    Path(player_id): Path<Uuid>,
+) -> ApiResult<Json<Vec<CollegeStatsResponse>>> {
+    let seasons = state
+        .college_stats_repo
+        .find_by_player_id(player_id)
+        .await?;
+    let response: Vec<CollegeStatsResponse> = seasons
+        .into_iter()
+        .map(CollegeStatsResponse::from)
+        .collect();
+    Ok(Json(response))
+}
@@ -4,7 +4,9 @@ use serde::{Deserialize, Serialize};
 use utoipa::ToSchema;
 use uuid::Uuid;
 
-use crate::auth::verify_api_key;
+use domain::models::{PlayerFilter, Sort};
+use domain::services::PercentileBaselineService;
+
 use crate::error::{ApiError, ApiResult};
 use crate::state::AppState;
 
@@ -149,28 +151,8 @@ pub async fn get_combine_percentiles(
 )]
 pub async fn seed_percentiles(
     State(state): State<AppState>,
-    headers: axum::http::HeaderMap,
     Json(req): Json<BulkUpsertPercentilesRequest>,
 ) -> ApiResult<Json<BulkUpsertResponse>> {
-    // Validate seed API key using constant-time comparison
-    let expected_key = match &state.seed_api_key {
-        Some(key) => key,
-        None => {
-            return Err(ApiError::NotFound("Not found".to_string()));
-        }
-    };
-
-    let provided_key = headers
-        .get("X-Seed-Api-Key")
-        .and_then(|v| v.to_str().ok())
-        .unwrap_or("");
-
-    if !verify_api_key(provided_key, expected_key) {
-        return Err(ApiError::Unauthorized(
-            "Invalid or missing API key".to_string(),
-        ));
-    }
-
     if req.percentiles.len() > 1000 {
         return Err(ApiError::BadRequest(format!(
             "Bulk upsert request exceeds maximum size of 1000 percentiles (received {})",
@@ -254,6 +236,127 @@ pub async fn seed_percentiles(
     }))
 }
 
+#[derive(Debug, Default, Deserialize, ToSchema)]
+pub struct ComputePercentilesRequest {
+    /// Only this position group (e.g. WR, IOL, EDGE); every group when omitted
+    pub position: Option<String>,
+    /// First combine year to sample; the earliest stored year when omitted
+    pub years_start: Option<i32>,
+    /// Last combine year to sample; the latest stored year when omitted
+    pub years_end: Option<i32>,
+    /// Fewer values than this leave the existing row alone
+    #[serde(default = "default_min_sample_size")]
+    pub min_sample_size: usize,
+    /// Compute and return the percentiles without saving them
+    #[serde(default)]
+    pub dry_run: bool,
+}
+
+fn default_min_sample_size() -> usize {
+    10
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct ComputePercentilesResponse {
+    pub message: String,
+    pub upserted_count: usize,
+    /// `POSITION measurement (n)` for pairs with too few samples
+    pub skipped: Vec<String>,
+    pub percentiles: Vec<CombinePercentileResponse>,
+}
+
+/// POST /api/v1/admin/percentiles/compute - Derive percentiles from stored combine results
+///
+/// Builds percentile breakpoints per position group and measurement from the
+/// combine_results table instead of the static template, and upserts them
+/// unless `dry_run` is set.
+#[utoipa::path(
+    post,
+    path = "/api/v1/admin/percentiles/compute",
+    request_body = ComputePercentilesRequest,
+    responses(
+        (status = 200, description = "Percentiles computed", body = ComputePercentilesResponse),
+        (status = 400, description = "Invalid position or year range"),
+        (status = 401, description = "Unauthorized")
+    ),
+    tag = "admin"
+)]
+pub async fn compute_percentiles(
+    State(state): State<AppState>,
+    Json(req): Json<ComputePercentilesRequest>,
+) -> ApiResult<Json<ComputePercentilesResponse>> {
+    if let Some(position) = &req.position {
+        domain::models::CombinePercentile::new(
+            position.clone(),
+            domain::models::Measurement::FortyYardDash,
+        )
+        .map_err(|_| ApiError::BadRequest(format!("Invalid position group: {}", position)))?;
+    }
+
+    let filter = PlayerFilter::default();
+    let sort = Sort::default();
+    let (results, players) = tokio::join!(
+        state.combine_results_repo.find_all(),
+        state.player_repo.find_filtered(&filter, &sort, None),
+    );
+    let results = results?;
+    let players = players?.items;
+
+    let years = results.iter().map(|r| r.year);
+    let years_start = req
+        .years_start
+        .or_else(|| years.clone().min())
+        .unwrap_or(default_years_start());
+    let years_end = req
+        .years_end
+        .or_else(|| years.max())
+        .unwrap_or(default_years_end());
+
+    let baselines = PercentileBaselineService::compute(
+        &players,
+        &results,
+        req.position.as_deref(),
+        years_start,
+        years_end,
+        req.min_sample_size,
+    )
+    .map_err(|e| ApiError::BadRequest(e.to_string()))?;
+
+    let mut upserted_count = 0;
+    let mut percentiles = Vec::new();
+    for percentile in baselines.percentiles {
+        if req.dry_run {
+            percentiles.push(CombinePercentileResponse::from(percentile));
+        } else {
+            let saved = state.combine_percentile_repo.upsert(&percentile).await?;
+            upserted_count += 1;
+            percentiles.push(CombinePercentileResponse::from(saved));
+        }
+    }
+    let skipped: Vec<String> = baselines
+        .skipped
+        .iter()
+        .map(|s| format!("{} {} ({})", s.position, s.measurement, s.sample_size))
+        .collect();
+
+    let message = format!(
+        "Computed {} percentiles from {}-{} combine results{}: {} upserted, {} skipped",
+        percentiles.len(),
+        years_start,
+        years_end,
+        if req.dry_run { " (dry run)" } else { "" },
+        upserted_count,
+        skipped.len()
+    );
+
+    Ok(Json(ComputePercentilesResponse {
+        message,
+        upserted_count,
+        skipped,
+        percentiles,
+    }))
+}
+
 /// DELETE /api/v1/admin/percentiles - Delete all percentile data
 #[utoipa::path(
     delete,
@@ -266,27 +369,7 @@ pub async fn seed_percentiles(
 )]
 pub async fn delete_all_percentiles(
     State(state): State<AppState>,
-    headers: axum::http::HeaderMap,
 ) -> ApiResult<Json<BulkUpsertResponse>> {
-    // Validate seed API key using constant-time comparison
-    let expected_key = match &state.seed_api_key {
-        Some(key) => key,
-        None => {
-            return Err(ApiError::NotFound("Not found".to_string()));
-        }
-    };
-
-    let provided_key = headers
-        .get("X-Seed-Api-Key")
-        .and_then(|v| v.to_str().ok())
-        .unwrap_or("");
-
-    if !verify_api_key(provided_key, expected_key) {
-        return Err(ApiError::Unauthorized(
-            "Invalid or missing API key".to_string(),
-        ));
-    }
-
     let deleted = state.combine_percentile_repo.delete_all().await?;
 
     Ok(Json(BulkUpsertResponse {
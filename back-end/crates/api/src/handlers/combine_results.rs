@@ -1,13 +1,14 @@
 use std::collections::HashMap;
 
-use axum::extract::{Path, State};
+use axum::extract::{Path, Query, State};
 use axum::http::StatusCode;
 use axum::Json;
+use chrono::NaiveDate;
 use serde::{Deserialize, Serialize};
-use utoipa::ToSchema;
+use utoipa::{IntoParams, ToSchema};
 use uuid::Uuid;
 
-use domain::models::{CombineResults, CombineSource, Position};
+use domain::models::{CombineResults, CombineSource, MeasurementMark, Player, Position};
 
 use crate::error::{ApiError, ApiResult};
 use crate::state::AppState;
@@ -18,6 +19,9 @@ pub struct CreateCombineResultsRequest {
     pub year: i32,
     #[serde(default)]
     pub source: Option<String>,
+    /// Day of the workout; lets a player have several events per source
+    #[serde(default)]
+    pub event_date: Option<NaiveDate>,
     pub forty_yard_dash: Option<f64>,
     pub bench_press: Option<i32>,
     pub vertical_jump: Option<f64>,
@@ -33,6 +37,7 @@ pub struct CreateCombineResultsRequest {
 
 #[derive(Debug, Serialize, Deserialize, ToSchema)]
 pub struct UpdateCombineResultsRequest {
+    pub event_date: Option<NaiveDate>,
     pub forty_yard_dash: Option<f64>,
     pub bench_press: Option<i32>,
     pub vertical_jump: Option<f64>,
@@ -52,6 +57,7 @@ pub struct CombineResultsResponse {
     pub player_id: Uuid,
     pub year: i32,
     pub source: String,
+    pub event_date: Option<NaiveDate>,
     pub forty_yard_dash: Option<f64>,
     pub bench_press: Option<i32>,
     pub vertical_jump: Option<f64>,
@@ -72,6 +78,7 @@ impl From<CombineResults> for CombineResultsResponse {
             player_id: results.player_id,
             year: results.year,
             source: results.source.to_string(),
+            event_date: results.event_date,
             forty_yard_dash: results.forty_yard_dash,
             bench_press: results.bench_press,
             vertical_jump: results.vertical_jump,
@@ -97,6 +104,7 @@ pub struct CombineResultsWithPlayerResponse {
     pub college: Option<String>,
     pub year: i32,
     pub source: String,
+    pub event_date: Option<NaiveDate>,
     pub forty_yard_dash: Option<f64>,
     pub bench_press: Option<i32>,
     pub vertical_jump: Option<f64>,
@@ -110,18 +118,68 @@ pub struct CombineResultsWithPlayerResponse {
     pub twenty_yard_split: Option<f64>,
 }
 
-/// GET /api/v1/combine-results - List all combine results with player info
+#[derive(Debug, Default, Deserialize, IntoParams)]
+pub struct CombineResultsFilterParams {
+    /// Only results from this combine year
+    pub year: Option<i32>,
+    /// Only results for players at this position
+    pub position: Option<Position>,
+    /// Minimum 40-yard dash time in seconds
+    pub min_forty: Option<f64>,
+    /// Maximum 40-yard dash time in seconds
+    pub max_forty: Option<f64>,
+}
+
+impl CombineResultsFilterParams {
+    fn validate(&self) -> ApiResult<()> {
+        if let (Some(min), Some(max)) = (self.min_forty, self.max_forty) {
+            if min > max {
+                return Err(ApiError::BadRequest(
+                    "min_forty cannot exceed max_forty".to_string(),
+                ));
+            }
+        }
+        Ok(())
+    }
+
+    /// Results without a 40 time never match a 40 time bound
+    fn matches(&self, results: &CombineResults, player: &Player) -> bool {
+        let forty_in_range = match results.forty_yard_dash {
+            Some(forty) => {
+                self.min_forty.is_none_or(|min| forty >= min)
+                    && self.max_forty.is_none_or(|max| forty <= max)
+            }
+            None => self.min_forty.is_none() && self.max_forty.is_none(),
+        };
+
+        self.year.is_none_or(|year| results.year == year)
+            && self
+                .position
+                .is_none_or(|position| player.position == position)
+            && forty_in_range
+    }
+}
+
+/// GET /api/v1/combine-results - List combine results with player info
+///
+/// Filterable by combine `year`, player `position` and a `min_forty`/`max_forty`
+/// range; results without a 40 time are left out when a range is given.
 #[utoipa::path(
     get,
     path = "/api/v1/combine-results",
+    params(CombineResultsFilterParams),
     responses(
-        (status = 200, description = "List of all combine results with player info", body = Vec<CombineResultsWithPlayerResponse>)
+        (status = 200, description = "Combine results with player info", body = Vec<CombineResultsWithPlayerResponse>),
+        (status = 400, description = "Invalid filter parameters")
     ),
     tag = "combine-results"
 )]
 pub async fn list_combine_results(
     State(state): State<AppState>,
+    Query(filter): Query<CombineResultsFilterParams>,
 ) -> ApiResult<Json<Vec<CombineResultsWithPlayerResponse>>> {
+    filter.validate()?;
+
     let combine_results = state.combine_results_repo.find_all().await?;
     let players = state.player_repo.find_all().await?;
 
@@ -131,6 +189,9 @@ pub async fn list_combine_results(
         .into_iter()
         .filter_map(|cr| {
             let player = player_map.get(&cr.player_id)?;
+            if !filter.matches(&cr, player) {
+                return None;
+            }
             Some(CombineResultsWithPlayerResponse {
                 id: cr.id,
                 player_id: cr.player_id,
@@ -140,6 +201,7 @@ pub async fn list_combine_results(
                 college: player.college.clone(),
                 year: cr.year,
                 source: cr.source.to_string(),
+                event_date: cr.event_date,
                 forty_yard_dash: cr.forty_yard_dash,
                 bench_press: cr.bench_press,
                 vertical_jump: cr.vertical_jump,
@@ -165,8 +227,9 @@ pub async fn list_combine_results(
     request_body = CreateCombineResultsRequest,
     responses(
         (status = 201, description = "Combine results created successfully", body = CombineResultsResponse),
+        (status = 401, description = "Unauthorized"),
         (status = 400, description = "Invalid request"),
-        (status = 409, description = "Combine results for this player and year already exist")
+        (status = 409, description = "Combine results for this player, year, source and date already exist")
     ),
     tag = "combine-results"
 )]
@@ -182,6 +245,9 @@ pub async fn create_combine_results(
     };
 
     let mut results = CombineResults::new(req.player_id, req.year)?.with_source(source);
+    if let Some(date) = req.event_date {
+        results = results.with_event_date(date);
+    }
 
     if let Some(time) = req.forty_yard_dash {
         results = results.with_forty_yard_dash(time)?;
@@ -278,6 +344,61 @@ pub async fn get_player_combine_results(
     Ok(Json(response))
 }
 
+#[derive(Debug, Serialize, ToSchema)]
+pub struct MeasurementMarkResponse {
+    pub measurement: String,
+    pub value: f64,
+    /// Combine results row the mark was taken from
+    pub combine_results_id: Uuid,
+    pub source: String,
+    pub year: i32,
+    pub event_date: Option<NaiveDate>,
+}
+
+impl From<MeasurementMark> for MeasurementMarkResponse {
+    fn from(mark: MeasurementMark) -> Self {
+        Self {
+            measurement: mark.measurement.to_string(),
+            value: mark.value,
+            combine_results_id: mark.results_id,
+            source: mark.source.to_string(),
+            year: mark.year,
+            event_date: mark.event_date,
+        }
+    }
+}
+
+/// GET /api/v1/players/:player_id/combine-results/best - Best mark per measurement
+///
+/// Looks across every combine, pro day and school event for the player: the
+/// best time or distance for each drill and the most recent body
+/// measurements, each with the event it came from.
+#[utoipa::path(
+    get,
+    path = "/api/v1/players/{player_id}/combine-results/best",
+    responses(
+        (status = 200, description = "Best mark per measurement", body = Vec<MeasurementMarkResponse>)
+    ),
+    params(
+        ("player_id" = Uuid, Path, description = "Player ID")
+    ),
+    tag = "combine-results"
+)]
+pub async fn get_player_best_marks(
+    State(state): State<AppState>,
+    Path(player_id): Path<Uuid>,
+) -> ApiResult<Json<Vec<MeasurementMarkResponse>>> {
+    let results = state
+        .combine_results_repo
+        .find_by_player_id(player_id)
+        .await?;
+    let response: Vec<MeasurementMarkResponse> = CombineResults::best_marks(&results)
+        .into_iter()
+        .map(MeasurementMarkResponse::from)
+        .collect();
+    Ok(Json(response))
+}
+
 /// PUT /api/v1/combine-results/:id - Update combine results
 #[utoipa::path(
     put,
@@ -285,6 +406,7 @@ pub async fn get_player_combine_results(
     request_body = UpdateCombineResultsRequest,
     responses(
         (status = 200, description = "Combine results updated successfully", body = CombineResultsResponse),
+        (status = 401, description = "Unauthorized"),
         (status = 404, description = "Combine results not found"),
         (status = 400, description = "Invalid request")
     ),
@@ -305,6 +427,7 @@ pub async fn update_combine_results(
         .ok_or_else(|| ApiError::NotFound(format!("Combine results with id {} not found", id)))?;
 
     // Update fields with validation
+    results.update_event_date(req.event_date);
     results.update_forty_yard_dash(req.forty_yard_dash)?;
     results.update_bench_press(req.bench_press)?;
     results.update_vertical_jump(req.vertical_jump)?;
@@ -328,6 +451,7 @@ pub async fn update_combine_results(
     path = "/api/v1/combine-results/{id}",
     responses(
         (status = 204, description = "Combine results deleted successfully"),
+        (status = 401, description = "Unauthorized"),
         (status = 404, description = "Combine results not found")
     ),
     params(
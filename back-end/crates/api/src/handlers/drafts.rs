@@ -4,12 +4,28 @@ use axum::extract::{Path, Query, State};
 use axum::http::StatusCode;
 use axum::Json;
 use serde::{Deserialize, Serialize};
-use utoipa::ToSchema;
+use utoipa::{IntoParams, ToSchema};
 use uuid::Uuid;
 
-use domain::models::{Draft, DraftPick, FitGrade, Position};
+use domain::errors::DomainError;
+use domain::models::{
+    ChartType, Draft, DraftPick, DraftSortField, DraftStatus, FitGrade, Page, Position,
+};
+use domain::services::{
+    MockAccuracyService, PickValue, PositionalScarcityService, SurplusValueService,
+    ValueOverSlotService,
+};
 
+use crate::auth::AuthUser;
+use crate::batch::IdsParams;
 use crate::error::{ApiError, ApiResult};
+use crate::fields::FieldsParams;
+use crate::handlers::adp::record_adp;
+use crate::handlers::participants::authorize_participant;
+use crate::handlers::sessions::{complete_if_final_pick, publish_pick_made, record_session_pick};
+use crate::pagination::{Paginated, PaginationParams};
+use crate::response_format::FormatParams;
+use crate::sorting::SortParams;
 use crate::state::AppState;
 
 #[derive(Debug, Serialize, Deserialize, ToSchema)]
@@ -97,6 +113,7 @@ pub struct MakePickRequest {
     request_body = CreateDraftRequest,
     responses(
         (status = 201, description = "Draft created successfully", body = DraftResponse),
+        (status = 401, description = "Missing or invalid access token"),
         (status = 400, description = "Invalid request")
     ),
     tag = "drafts"
@@ -124,18 +141,28 @@ pub async fn create_draft(
 }
 
 /// GET /api/v1/drafts - List all drafts
+///
+/// Sortable by `name`, `year`, `status` and `created_at`.
 #[utoipa::path(
     get,
     path = "/api/v1/drafts",
+    params(SortParams, PaginationParams),
     responses(
-        (status = 200, description = "List of all drafts", body = Vec<DraftResponse>)
+        (status = 200, description = "List of all drafts; total count in X-Total-Count", body = Vec<DraftResponse>),
+        (status = 400, description = "Invalid sort or pagination parameters")
     ),
     tag = "drafts"
 )]
-pub async fn list_drafts(State(state): State<AppState>) -> ApiResult<Json<Vec<DraftResponse>>> {
-    let drafts = state.draft_engine.get_all_drafts().await?;
-    let response: Vec<DraftResponse> = drafts.into_iter().map(DraftResponse::from).collect();
-    Ok(Json(response))
+pub async fn list_drafts(
+    State(state): State<AppState>,
+    Query(sort): Query<SortParams>,
+    Query(pagination): Query<PaginationParams>,
+) -> ApiResult<Paginated<DraftResponse>> {
+    let sort = sort.parse::<DraftSortField>()?;
+    let request = pagination.page_request()?;
+
+    let page = state.draft_engine.get_drafts_sorted(&sort, request).await?;
+    Ok(Paginated::page(page.map(DraftResponse::from), request))
 }
 
 /// GET /api/v1/drafts/:id - Get draft by ID
@@ -170,6 +197,7 @@ pub async fn get_draft(
     path = "/api/v1/drafts/{id}/initialize",
     responses(
         (status = 201, description = "Picks initialized successfully", body = Vec<DraftPickResponse>),
+        (status = 401, description = "Missing or invalid access token"),
         (status = 404, description = "Draft not found")
     ),
     params(
@@ -254,25 +282,50 @@ async fn initialize_realistic_picks(
     Ok(picks)
 }
 
-/// GET /api/v1/drafts/:id/picks - Get all picks for a draft
+/// GET /api/v1/drafts/:id/picks - Get all picks for a draft, as JSON or,
+/// with `format=csv`, a spreadsheet download; `fields=` limits the columns
+/// and `ids=` fetches a batch of the draft's picks by ID
 #[utoipa::path(
     get,
     path = "/api/v1/drafts/{id}/picks",
     responses(
-        (status = 200, description = "List of all picks for the draft", body = Vec<DraftPickResponse>)
+        (status = 200, description = "List of all picks for the draft; total count in X-Total-Count. CSV when format=csv; only the requested fields when fields is set", body = Vec<DraftPickResponse>),
+        (status = 400, description = "Invalid ids, pagination or field parameters")
     ),
     params(
-        ("id" = Uuid, Path, description = "Draft ID")
+        ("id" = Uuid, Path, description = "Draft ID"),
+        IdsParams,
+        PaginationParams,
+        FormatParams,
+        FieldsParams
     ),
     tag = "drafts"
 )]
 pub async fn get_draft_picks(
     State(state): State<AppState>,
     Path(id): Path<Uuid>,
-) -> ApiResult<Json<Vec<DraftPickResponse>>> {
-    let picks = state.draft_engine.get_all_picks(id).await?;
-    let response: Vec<DraftPickResponse> = picks.into_iter().map(DraftPickResponse::from).collect();
-    Ok(Json(response))
+    Query(ids): Query<IdsParams>,
+    Query(params): Query<PaginationParams>,
+    Query(format): Query<FormatParams>,
+    Query(fields): Query<FieldsParams>,
+) -> ApiResult<Paginated<DraftPickResponse>> {
+    let fields = fields.selection::<DraftPickResponse>()?;
+    let request = params.page_request()?;
+
+    let page = match (ids.parse()?, request) {
+        // A draft has a few hundred picks at most, so pick the batch out of
+        // all of them
+        (Some(ids), _) => {
+            let picks = state.draft_engine.get_all_picks(id).await?;
+            let picks = picks.into_iter().filter(|p| ids.contains(&p.id)).collect();
+            Page::from_all(picks, request)
+        }
+        (None, Some(request)) => state.draft_engine.get_picks_page(id, request).await?,
+        (None, None) => Page::from_all(state.draft_engine.get_all_picks(id).await?, None),
+    };
+    Ok(Paginated::page(page.map(DraftPickResponse::from), request)
+        .with_format(format.format, "draft-picks.csv")
+        .with_fields(fields))
 }
 
 /// GET /api/v1/drafts/:id/picks/next - Get next available pick
@@ -295,25 +348,30 @@ pub async fn get_next_pick(
     Ok(Json(pick.map(DraftPickResponse::from)))
 }
 
-/// GET /api/v1/drafts/:id/picks/available - Get all available picks
+/// GET /api/v1/drafts/:id/picks/available - Get all available picks;
+/// `fields=` limits the columns
 #[utoipa::path(
     get,
     path = "/api/v1/drafts/{id}/picks/available",
     responses(
-        (status = 200, description = "List of all available (unmade) picks", body = Vec<DraftPickResponse>)
+        (status = 200, description = "List of all available (unmade) picks; only the requested fields when fields is set", body = Vec<DraftPickResponse>),
+        (status = 400, description = "Invalid field parameters")
     ),
     params(
-        ("id" = Uuid, Path, description = "Draft ID")
+        ("id" = Uuid, Path, description = "Draft ID"),
+        FieldsParams
     ),
     tag = "drafts"
 )]
 pub async fn get_available_picks(
     State(state): State<AppState>,
     Path(id): Path<Uuid>,
-) -> ApiResult<Json<Vec<DraftPickResponse>>> {
+    Query(fields): Query<FieldsParams>,
+) -> ApiResult<Paginated<DraftPickResponse>> {
+    let fields = fields.selection::<DraftPickResponse>()?;
     let picks = state.draft_engine.get_available_picks(id).await?;
     let response: Vec<DraftPickResponse> = picks.into_iter().map(DraftPickResponse::from).collect();
-    Ok(Json(response))
+    Ok(Paginated::all(response).with_fields(fields))
 }
 
 /// POST /api/v1/picks/:id/make - Make a draft pick
@@ -323,8 +381,10 @@ pub async fn get_available_picks(
     request_body = MakePickRequest,
     responses(
         (status = 200, description = "Pick made successfully", body = DraftPickResponse),
+        (status = 401, description = "Missing or invalid access token"),
         (status = 404, description = "Pick not found"),
-        (status = 400, description = "Invalid request or player already drafted")
+        (status = 400, description = "Invalid request or player already drafted"),
+        (status = 409, description = "Another pick in the draft is still being made")
     ),
     params(
         ("id" = Uuid, Path, description = "Pick ID")
@@ -333,10 +393,48 @@ pub async fn get_available_picks(
 )]
 pub async fn make_pick(
     State(state): State<AppState>,
+    user: AuthUser,
     Path(id): Path<Uuid>,
     Json(payload): Json<MakePickRequest>,
 ) -> ApiResult<Json<DraftPickResponse>> {
-    let pick = state.draft_engine.make_pick(id, payload.player_id).await?;
+    let pick = state
+        .draft_pick_repo
+        .find_by_id(id)
+        .await?
+        .ok_or_else(|| domain::errors::DomainError::NotFound(format!("Pick {}", id)))?;
+
+    // In multi-user sessions only the participant controlling the team may pick
+    let mut session = state.session_repo.find_by_draft_id(pick.draft_id).await?;
+    if let Some(session) = &session {
+        authorize_participant(&state, &user, session.id, Some(pick.team_id)).await?;
+    }
+
+    let pick = match &mut session {
+        Some(session) => {
+            // Making the current pick advances the session; both are saved
+            // with the PickMade event in one transaction
+            let mut advanced = session.clone();
+            if advanced.status == domain::models::SessionStatus::InProgress
+                && advanced.current_pick_number == pick.overall_pick
+            {
+                advanced.advance_pick()?;
+            }
+            let pick = state
+                .draft_engine
+                .make_pick_with(id, payload.player_id, |made| {
+                    record_session_pick(&state, &advanced, made)
+                })
+                .await?;
+            *session = advanced;
+            publish_pick_made(&state, session.id, &pick, None).await?;
+            pick
+        }
+        None => state.draft_engine.make_pick(id, payload.player_id).await?,
+    };
+
+    // Making the final pick finishes the draft without a separate complete call
+    complete_if_final_pick(&state, session.as_mut(), pick.draft_id).await?;
+
     Ok(Json(DraftPickResponse::from(pick)))
 }
 
@@ -346,6 +444,7 @@ pub async fn make_pick(
     path = "/api/v1/drafts/{id}/start",
     responses(
         (status = 200, description = "Draft started successfully", body = DraftResponse),
+        (status = 401, description = "Missing or invalid access token"),
         (status = 404, description = "Draft not found")
     ),
     params(
@@ -367,6 +466,7 @@ pub async fn start_draft(
     path = "/api/v1/drafts/{id}/pause",
     responses(
         (status = 200, description = "Draft paused successfully", body = DraftResponse),
+        (status = 401, description = "Missing or invalid access token"),
         (status = 404, description = "Draft not found")
     ),
     params(
@@ -388,6 +488,7 @@ pub async fn pause_draft(
     path = "/api/v1/drafts/{id}/complete",
     responses(
         (status = 200, description = "Draft completed successfully", body = DraftResponse),
+        (status = 401, description = "Missing or invalid access token"),
         (status = 404, description = "Draft not found")
     ),
     params(
@@ -400,6 +501,7 @@ pub async fn complete_draft(
     Path(id): Path<Uuid>,
 ) -> ApiResult<Json<DraftResponse>> {
     let draft = state.draft_engine.complete_draft(id).await?;
+    record_adp(&state, draft.id, draft.year).await?;
     Ok(Json(DraftResponse::from(draft)))
 }
 
@@ -439,6 +541,8 @@ pub struct AvailablePlayerResponse {
     pub rankings: Vec<RankingBadgeResponse>,
     // Feldman Freaks list entry (if player is on the list)
     pub feldman_freak: Option<FeldmanFreakResponse>,
+    // On the signed-in user's watchlist; always false without a token
+    pub watched: bool,
 }
 
 #[derive(Debug, Deserialize)]
@@ -449,12 +553,14 @@ pub struct AvailablePlayersQuery {
 /// GET /api/v1/drafts/:id/available-players?team_id=<uuid>
 ///
 /// Returns all undrafted players for the given draft, each enriched with
-/// scouting report data (for `team_id`) and big-board ranking badges.
+/// scouting report data (for `team_id`) and big-board ranking badges. With a
+/// bearer token, players on the caller's watchlist are flagged `watched`.
 #[utoipa::path(
     get,
     path = "/api/v1/drafts/{id}/available-players",
     responses(
         (status = 200, description = "Consolidated available players", body = Vec<AvailablePlayerResponse>),
+        (status = 401, description = "Invalid or expired token"),
         (status = 404, description = "Draft not found")
     ),
     params(
@@ -467,26 +573,22 @@ pub async fn get_available_players(
     State(state): State<AppState>,
     Path(draft_id): Path<Uuid>,
     Query(params): Query<AvailablePlayersQuery>,
+    user: Option<AuthUser>,
 ) -> ApiResult<Json<Vec<AvailablePlayerResponse>>> {
-    // 1. Verify draft exists and get picked player IDs concurrently
-    let (draft_result, picks_result) = tokio::join!(
-        state.draft_repo.find_by_id(draft_id),
-        state.draft_pick_repo.find_by_draft_id(draft_id),
-    );
-
-    let draft = draft_result?
+    // 1. Verify draft exists
+    let draft = state
+        .draft_repo
+        .find_by_id(draft_id)
+        .await?
         .ok_or_else(|| ApiError::NotFound(format!("Draft with id {} not found", draft_id)))?;
 
-    let picks = picks_result?;
-    let picked_ids: HashSet<Uuid> = picks.iter().filter_map(|p| p.player_id).collect();
-
-    // 2. Fetch players (scoped to draft year), rankings, sources, freaks, and optionally scouting reports concurrently
-    let players_fut = state.player_repo.find_by_draft_year(draft.year);
+    // 2. Fetch undrafted players, rankings, sources, freaks, and optionally scouting reports concurrently
+    let players_fut = state.player_repo.find_available_for_draft(draft_id);
     let rankings_fut = state.prospect_ranking_repo.find_all_with_source();
     let sources_fut = state.ranking_source_repo.find_all();
     let freaks_fut = state.feldman_freak_repo.find_by_year(draft.year);
 
-    let (all_players, all_rankings, sources, scouting_map, freaks) =
+    let (available, all_rankings, sources, scouting_map, freaks) =
         if let Some(team_id) = params.team_id {
             let scouting_fut = state.scouting_report_repo.find_by_team_id(team_id);
             let (players_res, rankings_res, sources_res, scouting_res, freaks_res) = tokio::join!(
@@ -513,17 +615,22 @@ pub async fn get_available_players(
             )
         };
 
+    let watched_ids: HashSet<Uuid> = match &user {
+        Some(user) => state
+            .watchlist_repo
+            .find_by_user_id(user.user_id)
+            .await?
+            .into_iter()
+            .map(|entry| entry.player_id)
+            .collect(),
+        None => HashSet::new(),
+    };
+
     // Build freaks lookup by player_id
     let freaks_map: HashMap<Uuid, domain::models::FeldmanFreak> =
         freaks.into_iter().map(|f| (f.player_id, f)).collect();
 
-    // 3. Filter out already-picked players
-    let available: Vec<_> = all_players
-        .into_iter()
-        .filter(|p| !picked_ids.contains(&p.id))
-        .collect();
-
-    // 4. Build abbreviation lookup and rankings map
+    // 3. Build abbreviation lookup and rankings map
     let abbreviation_map: HashMap<String, String> = sources
         .into_iter()
         .map(|s| (s.name.clone(), s.abbreviation.clone()))
@@ -555,7 +662,7 @@ pub async fn get_available_players(
         badges.sort_by_key(|b| b.rank);
     }
 
-    // 5. Assemble response, sorted by scouting grade desc (graded first)
+    // 4. Assemble response, sorted by scouting grade desc (graded first)
     let mut response: Vec<AvailablePlayerResponse> = available
         .into_iter()
         .map(|player| {
@@ -582,6 +689,7 @@ pub async fn get_available_players(
                 character_concern: report.map(|r| r.character_concern),
                 rankings,
                 feldman_freak,
+                watched: watched_ids.contains(&player.id),
             }
         })
         .collect();
@@ -599,3 +707,689 @@ pub async fn get_available_players(
 
     Ok(Json(response))
 }
+
+fn default_chart_type() -> ChartType {
+    ChartType::JimmyJohnson
+}
+
+#[derive(Debug, Deserialize, IntoParams)]
+pub struct DraftClassQuery {
+    /// Trade value chart used to price the picks spent (default JimmyJohnson)
+    #[serde(default = "default_chart_type")]
+    #[param(value_type = Option<ChartType>)]
+    pub chart_type: ChartType,
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct ClassSelectionResponse {
+    pub pick_id: Uuid,
+    pub round: i32,
+    pub pick_number: i32,
+    pub overall_pick: i32,
+    pub player_id: Uuid,
+    pub first_name: String,
+    pub last_name: String,
+    pub position: Position,
+    pub college: Option<String>,
+    /// The team's own scouting grade for the player
+    pub scouting_grade: Option<f64>,
+    pub consensus_rank: Option<i64>,
+    /// Overall pick minus consensus rank; positive is a steal, negative a reach
+    pub rank_delta: Option<i64>,
+    pub chart_value: i32,
+    /// The player's position is on the team's needs list
+    pub fills_need: bool,
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct TeamDraftClassResponse {
+    pub draft_id: Uuid,
+    pub team_id: Uuid,
+    pub chart_type: ChartType,
+    /// Selections in draft order
+    pub selections: Vec<ClassSelectionResponse>,
+    pub average_grade: Option<f64>,
+    pub average_rank_delta: Option<f64>,
+    /// Need positions the class addressed, highest priority first
+    pub needs_filled: Vec<Position>,
+    /// Need positions still open, highest priority first
+    pub needs_unfilled: Vec<Position>,
+    /// Chart value of the picks used on the selections
+    pub total_chart_value: i32,
+}
+
+fn average(values: impl Iterator<Item = f64>) -> Option<f64> {
+    let (sum, count) = values.fold((0.0, 0), |(sum, count), v| (sum + v, count + 1));
+    (count > 0).then(|| (sum / count as f64 * 100.0).round() / 100.0)
+}
+
+/// GET /api/v1/drafts/:id/teams/:team_id/class - Team draft class recap
+///
+/// One team's selections in a draft with its own scouting grades, how each
+/// pick compares to the consensus board for the draft year, which needs were
+/// filled and the trade chart value spent.
+#[utoipa::path(
+    get,
+    path = "/api/v1/drafts/{id}/teams/{team_id}/class",
+    responses(
+        (status = 200, description = "Team draft class summary", body = TeamDraftClassResponse),
+        (status = 404, description = "Draft or team not found")
+    ),
+    params(
+        ("id" = Uuid, Path, description = "Draft ID"),
+        ("team_id" = Uuid, Path, description = "Team ID"),
+        DraftClassQuery
+    ),
+    tag = "drafts"
+)]
+pub async fn get_team_draft_class(
+    State(state): State<AppState>,
+    Path((draft_id, team_id)): Path<(Uuid, Uuid)>,
+    Query(query): Query<DraftClassQuery>,
+) -> ApiResult<Json<TeamDraftClassResponse>> {
+    let draft = state
+        .draft_repo
+        .find_by_id(draft_id)
+        .await?
+        .ok_or_else(|| ApiError::NotFound(format!("Draft with id {} not found", draft_id)))?;
+    state
+        .team_repo
+        .find_by_id(team_id)
+        .await?
+        .ok_or_else(|| ApiError::NotFound(format!("Team with id {} not found", team_id)))?;
+
+    let mut picks: Vec<DraftPick> = state
+        .draft_pick_repo
+        .find_by_draft_and_team(draft_id, team_id)
+        .await?
+        .into_iter()
+        .filter(|p| p.player_id.is_some())
+        .collect();
+    picks.sort_by_key(|p| p.overall_pick);
+
+    let player_ids: Vec<Uuid> = picks.iter().filter_map(|p| p.player_id).collect();
+    let (players, reports, consensus, needs) = tokio::join!(
+        state.player_repo.find_by_ids(&player_ids),
+        state.scouting_report_repo.find_by_team_id(team_id),
+        state.prospect_ranking_repo.find_consensus(Some(draft.year)),
+        state.team_need_repo.find_by_team_id(team_id),
+    );
+    let players: HashMap<Uuid, _> = players?.into_iter().map(|p| (p.id, p)).collect();
+    let grades: HashMap<Uuid, f64> = reports?
+        .into_iter()
+        .map(|r| (r.player_id, r.grade))
+        .collect();
+    let consensus: HashMap<Uuid, i64> = consensus?
+        .into_iter()
+        .map(|c| (c.player_id, c.consensus_rank))
+        .collect();
+    let mut needs = needs?;
+    needs.sort_by_key(|n| n.priority);
+    let need_positions: HashSet<Position> = needs.iter().map(|n| n.position).collect();
+
+    let chart = query.chart_type.create_chart();
+    let mut selections = Vec::with_capacity(picks.len());
+    for pick in picks {
+        let Some(player) = pick.player_id.and_then(|id| players.get(&id)) else {
+            continue;
+        };
+        let consensus_rank = consensus.get(&player.id).copied();
+        selections.push(ClassSelectionResponse {
+            pick_id: pick.id,
+            round: pick.round,
+            pick_number: pick.pick_number,
+            overall_pick: pick.overall_pick,
+            player_id: player.id,
+            first_name: player.first_name.clone(),
+            last_name: player.last_name.clone(),
+            position: player.position,
+            college: player.college.clone(),
+            scouting_grade: grades.get(&player.id).copied(),
+            consensus_rank,
+            rank_delta: consensus_rank
+                .map(|rank| ValueOverSlotService::value_over_slot(pick.overall_pick, rank)),
+            chart_value: chart.calculate_pick_value(pick.overall_pick)?,
+            fills_need: need_positions.contains(&player.position),
+        });
+    }
+
+    let drafted_positions: HashSet<Position> = selections.iter().map(|s| s.position).collect();
+    let (needs_filled, needs_unfilled): (Vec<_>, Vec<_>) = needs
+        .iter()
+        .map(|n| n.position)
+        .partition(|position| drafted_positions.contains(position));
+
+    Ok(Json(TeamDraftClassResponse {
+        draft_id,
+        team_id,
+        chart_type: query.chart_type,
+        average_grade: average(selections.iter().filter_map(|s| s.scouting_grade)),
+        average_rank_delta: average(
+            selections
+                .iter()
+                .filter_map(|s| s.rank_delta.map(|d| d as f64)),
+        ),
+        total_chart_value: selections.iter().map(|s| s.chart_value).sum(),
+        needs_filled,
+        needs_unfilled,
+        selections,
+    }))
+}
+
+/// Most steals and reaches a value analysis may list
+const MAX_VALUE_LEADERS: usize = 50;
+
+fn default_value_leaders() -> usize {
+    10
+}
+
+#[derive(Debug, Deserialize, IntoParams)]
+pub struct ValueAnalysisQuery {
+    /// How many steals and reaches to list (1-50, default 10)
+    #[serde(default = "default_value_leaders")]
+    pub limit: usize,
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct PickValueResponse {
+    pub pick_id: Uuid,
+    pub round: i32,
+    pub overall_pick: i32,
+    pub team_id: Uuid,
+    pub team_abbreviation: Option<String>,
+    pub player_id: Uuid,
+    pub first_name: Option<String>,
+    pub last_name: Option<String>,
+    pub position: Option<Position>,
+    pub consensus_rank: i64,
+    /// Overall pick minus consensus rank; positive is a steal, negative a reach
+    pub value_over_slot: i64,
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct TeamValueResponse {
+    pub team_id: Uuid,
+    pub team_abbreviation: Option<String>,
+    pub picks_analyzed: usize,
+    pub total_value_over_slot: i64,
+    pub average_value_over_slot: f64,
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct ValueAnalysisResponse {
+    pub draft_id: Uuid,
+    /// Every made pick of a player on the consensus board, in draft order
+    pub picks: Vec<PickValueResponse>,
+    /// Made picks whose player is not on the consensus board
+    pub unranked_picks: usize,
+    /// Biggest steals first
+    pub steals: Vec<PickValueResponse>,
+    /// Biggest reaches first
+    pub reaches: Vec<PickValueResponse>,
+    /// Teams by total value over slot, best first
+    pub teams: Vec<TeamValueResponse>,
+}
+
+/// GET /api/v1/drafts/:id/value-analysis - Steals and reaches
+///
+/// Compares every made pick with the player's place on the consensus board
+/// for the draft year and lists the league-wide steals, reaches and team
+/// totals.
+#[utoipa::path(
+    get,
+    path = "/api/v1/drafts/{id}/value-analysis",
+    responses(
+        (status = 200, description = "Value over slot analysis", body = ValueAnalysisResponse),
+        (status = 400, description = "Invalid limit"),
+        (status = 404, description = "Draft not found")
+    ),
+    params(
+        ("id" = Uuid, Path, description = "Draft ID"),
+        ValueAnalysisQuery
+    ),
+    tag = "drafts"
+)]
+pub async fn get_value_analysis(
+    State(state): State<AppState>,
+    Path(draft_id): Path<Uuid>,
+    Query(query): Query<ValueAnalysisQuery>,
+) -> ApiResult<Json<ValueAnalysisResponse>> {
+    if query.limit == 0 || query.limit > MAX_VALUE_LEADERS {
+        return Err(ApiError::BadRequest(format!(
+            "limit must be between 1 and {}",
+            MAX_VALUE_LEADERS
+        )));
+    }
+
+    let draft = state
+        .draft_repo
+        .find_by_id(draft_id)
+        .await?
+        .ok_or_else(|| ApiError::NotFound(format!("Draft with id {} not found", draft_id)))?;
+
+    let (picks, consensus, teams) = tokio::join!(
+        state.draft_pick_repo.find_by_draft_id(draft_id),
+        state.prospect_ranking_repo.find_consensus(Some(draft.year)),
+        state.team_repo.find_all(),
+    );
+    let consensus = consensus?;
+    let analysis = ValueOverSlotService::analyze(&picks?, &consensus, query.limit);
+
+    let abbreviations: HashMap<Uuid, String> =
+        teams?.into_iter().map(|t| (t.id, t.abbreviation)).collect();
+    let players: HashMap<Uuid, _> = consensus.iter().map(|c| (c.player_id, c)).collect();
+    let pick_response = |value: PickValue| {
+        let player = players.get(&value.player_id);
+        PickValueResponse {
+            pick_id: value.pick_id,
+            round: value.round,
+            overall_pick: value.overall_pick,
+            team_id: value.team_id,
+            team_abbreviation: abbreviations.get(&value.team_id).cloned(),
+            player_id: value.player_id,
+            first_name: player.map(|p| p.first_name.clone()),
+            last_name: player.map(|p| p.last_name.clone()),
+            position: player.map(|p| p.position),
+            consensus_rank: value.consensus_rank,
+            value_over_slot: value.value_over_slot,
+        }
+    };
+
+    Ok(Json(ValueAnalysisResponse {
+        draft_id,
+        picks: analysis.picks.into_iter().map(pick_response).collect(),
+        unranked_picks: analysis.unranked_picks,
+        steals: analysis.steals.into_iter().map(pick_response).collect(),
+        reaches: analysis.reaches.into_iter().map(pick_response).collect(),
+        teams: analysis
+            .teams
+            .into_iter()
+            .map(|t| TeamValueResponse {
+                team_id: t.team_id,
+                team_abbreviation: abbreviations.get(&t.team_id).cloned(),
+                picks_analyzed: t.picks_analyzed,
+                total_value_over_slot: t.total_value_over_slot,
+                average_value_over_slot: t.average_value_over_slot,
+            })
+            .collect(),
+    }))
+}
+
+fn default_performance_chart() -> ChartType {
+    ChartType::PffWar
+}
+
+#[derive(Debug, Deserialize, IntoParams)]
+pub struct SurplusReportQuery {
+    /// Performance chart that prices each player's expected production
+    /// (default PffWar; SurplusValue is rejected since it already nets out cost)
+    #[serde(default = "default_performance_chart")]
+    #[param(value_type = Option<ChartType>)]
+    pub chart_type: ChartType,
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct PickSurplusResponse {
+    pub pick_id: Uuid,
+    pub round: i32,
+    pub overall_pick: i32,
+    pub team_id: Uuid,
+    pub team_abbreviation: Option<String>,
+    pub player_id: Uuid,
+    pub first_name: Option<String>,
+    pub last_name: Option<String>,
+    pub position: Option<Position>,
+    pub consensus_rank: Option<i64>,
+    /// Four-year rookie contract value for the slot, in millions
+    pub rookie_cost: f64,
+    /// Expected four-year production of a typical player at the slot, in millions
+    pub slot_value: f64,
+    /// Expected four-year production of this player, valued at the consensus
+    /// rank when there is one, in millions
+    pub player_value: f64,
+    /// Player value minus rookie cost, in millions
+    pub surplus: f64,
+    /// The SurplusValue trade chart's points for the slot
+    pub surplus_chart_value: i32,
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct TeamSurplusResponse {
+    pub team_id: Uuid,
+    pub team_abbreviation: Option<String>,
+    pub picks: usize,
+    pub total_rookie_cost: f64,
+    pub total_player_value: f64,
+    pub total_surplus: f64,
+    pub total_surplus_chart_value: i32,
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct SurplusReportResponse {
+    pub draft_id: Uuid,
+    pub chart_type: ChartType,
+    /// Every made pick, in draft order
+    pub picks: Vec<PickSurplusResponse>,
+    /// Teams by total surplus captured, best first
+    pub teams: Vec<TeamSurplusResponse>,
+}
+
+/// GET /api/v1/drafts/:id/surplus-report - Surplus value captured by each class
+///
+/// Prices every made pick's rookie contract with the wage scale and the
+/// player's expected production with a performance chart, then totals the
+/// surplus by team.
+#[utoipa::path(
+    get,
+    path = "/api/v1/drafts/{id}/surplus-report",
+    responses(
+        (status = 200, description = "Surplus value by pick and team", body = SurplusReportResponse),
+        (status = 400, description = "Chart is not a performance chart"),
+        (status = 404, description = "Draft not found")
+    ),
+    params(
+        ("id" = Uuid, Path, description = "Draft ID"),
+        SurplusReportQuery
+    ),
+    tag = "drafts"
+)]
+pub async fn get_surplus_report(
+    State(state): State<AppState>,
+    Path(draft_id): Path<Uuid>,
+    Query(query): Query<SurplusReportQuery>,
+) -> ApiResult<Json<SurplusReportResponse>> {
+    let service = SurplusValueService::new(query.chart_type)?;
+
+    let draft = state
+        .draft_repo
+        .find_by_id(draft_id)
+        .await?
+        .ok_or_else(|| ApiError::NotFound(format!("Draft with id {} not found", draft_id)))?;
+
+    let (picks, consensus, teams) = tokio::join!(
+        state.draft_pick_repo.find_by_draft_id(draft_id),
+        state.prospect_ranking_repo.find_consensus(Some(draft.year)),
+        state.team_repo.find_all(),
+    );
+    let picks = picks?;
+    let report = service.report(&picks, &consensus?)?;
+
+    let player_ids: Vec<Uuid> = report.picks.iter().map(|p| p.player_id).collect();
+    let players: HashMap<Uuid, _> = state
+        .player_repo
+        .find_by_ids(&player_ids)
+        .await?
+        .into_iter()
+        .map(|p| (p.id, p))
+        .collect();
+    let abbreviations: HashMap<Uuid, String> =
+        teams?.into_iter().map(|t| (t.id, t.abbreviation)).collect();
+
+    Ok(Json(SurplusReportResponse {
+        draft_id,
+        chart_type: query.chart_type,
+        picks: report
+            .picks
+            .into_iter()
+            .map(|p| {
+                let player = players.get(&p.player_id);
+                PickSurplusResponse {
+                    pick_id: p.pick_id,
+                    round: p.round,
+                    overall_pick: p.overall_pick,
+                    team_id: p.team_id,
+                    team_abbreviation: abbreviations.get(&p.team_id).cloned(),
+                    player_id: p.player_id,
+                    first_name: player.map(|pl| pl.first_name.clone()),
+                    last_name: player.map(|pl| pl.last_name.clone()),
+                    position: player.map(|pl| pl.position),
+                    consensus_rank: p.consensus_rank,
+                    rookie_cost: p.rookie_cost,
+                    slot_value: p.slot_value,
+                    player_value: p.player_value,
+                    surplus: p.surplus,
+                    surplus_chart_value: p.surplus_chart_value,
+                }
+            })
+            .collect(),
+        teams: report
+            .teams
+            .into_iter()
+            .map(|t| TeamSurplusResponse {
+                team_id: t.team_id,
+                team_abbreviation: abbreviations.get(&t.team_id).cloned(),
+                picks: t.picks,
+                total_rookie_cost: t.total_rookie_cost,
+                total_player_value: t.total_player_value,
+                total_surplus: t.total_surplus,
+                total_surplus_chart_value: t.total_surplus_chart_value,
+            })
+            .collect(),
+    }))
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct ScarcityTierResponse {
+    pub round: i32,
+    /// Undrafted players at the position with this round's grade
+    pub remaining: usize,
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct PositionScarcityResponse {
+    pub position: Position,
+    pub remaining: usize,
+    /// One entry per draft round, by consensus-rank grade
+    pub tiers: Vec<ScarcityTierResponse>,
+    /// Ranked below the last round of the draft
+    pub undrafted_grade: usize,
+    /// Not on the consensus board
+    pub unranked: usize,
+    pub best_consensus_rank: Option<i64>,
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct DraftScarcityResponse {
+    pub draft_id: Uuid,
+    pub positions: Vec<PositionScarcityResponse>,
+}
+
+/// GET /api/v1/drafts/:id/scarcity - Talent left by position
+///
+/// Undrafted players in the draft class grouped by position and bucketed by
+/// the round their consensus rank grades them into, e.g. two first-round
+/// OTs left. Recomputed on every request.
+#[utoipa::path(
+    get,
+    path = "/api/v1/drafts/{id}/scarcity",
+    responses(
+        (status = 200, description = "Remaining players by position and round grade", body = DraftScarcityResponse),
+        (status = 404, description = "Draft not found")
+    ),
+    params(
+        ("id" = Uuid, Path, description = "Draft ID")
+    ),
+    tag = "drafts"
+)]
+pub async fn get_draft_scarcity(
+    State(state): State<AppState>,
+    Path(draft_id): Path<Uuid>,
+) -> ApiResult<Json<DraftScarcityResponse>> {
+    let draft = state
+        .draft_repo
+        .find_by_id(draft_id)
+        .await?
+        .ok_or_else(|| ApiError::NotFound(format!("Draft with id {} not found", draft_id)))?;
+
+    let (picks, available, consensus) = tokio::join!(
+        state.draft_pick_repo.find_by_draft_id(draft_id),
+        state.player_repo.find_available_for_draft(draft_id),
+        state.prospect_ranking_repo.find_consensus(Some(draft.year)),
+    );
+    let (picks, available) = (picks?, available?);
+
+    let cutoffs =
+        PositionalScarcityService::round_cutoffs(&picks, draft.rounds, draft.picks_per_round);
+    let positions = PositionalScarcityService::compute(&available, &consensus?, &cutoffs)
+        .into_iter()
+        .map(|s| PositionScarcityResponse {
+            position: s.position,
+            remaining: s.remaining,
+            tiers: s
+                .by_round
+                .into_iter()
+                .enumerate()
+                .map(|(index, remaining)| ScarcityTierResponse {
+                    round: index as i32 + 1,
+                    remaining,
+                })
+                .collect(),
+            undrafted_grade: s.undrafted_grade,
+            unranked: s.unranked,
+            best_consensus_rank: s.best_consensus_rank,
+        })
+        .collect();
+
+    Ok(Json(DraftScarcityResponse {
+        draft_id,
+        positions,
+    }))
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct PickAccuracyResponse {
+    pub pick_id: Uuid,
+    pub round: i32,
+    pub overall_pick: i32,
+    pub team_id: Uuid,
+    pub team_abbreviation: Option<String>,
+    pub player_id: Uuid,
+    pub first_name: Option<String>,
+    pub last_name: Option<String>,
+    /// Where the player really went; all `None` if they went undrafted
+    pub actual_overall_pick: Option<i32>,
+    pub actual_round: Option<i32>,
+    pub actual_team_id: Option<Uuid>,
+    pub actual_team_abbreviation: Option<String>,
+    pub exact_pick: bool,
+    pub correct_team: bool,
+    pub correct_round: bool,
+    pub points: u32,
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct DraftAccuracyResponse {
+    pub draft_id: Uuid,
+    pub year: i32,
+    /// Mock picks whose player went at exactly that slot
+    pub exact_picks: usize,
+    /// Mock picks whose player went to the same team
+    pub correct_teams: usize,
+    /// Mock picks whose player went in the same round
+    pub correct_rounds: usize,
+    /// Mock picks whose player was drafted anywhere
+    pub players_drafted: usize,
+    pub points: u32,
+    pub max_points: u32,
+    pub score_percent: f64,
+    pub picks: Vec<PickAccuracyResponse>,
+}
+
+/// GET /api/v1/drafts/:id/accuracy - Score a mock against the real draft
+///
+/// Compares every pick of a completed mock draft with the real results for
+/// the draft year, loaded with `seed-data actual-results load`. Each pick
+/// earns 3 points for the exact slot, 2 for the right team and 1 for the
+/// right round.
+#[utoipa::path(
+    get,
+    path = "/api/v1/drafts/{id}/accuracy",
+    responses(
+        (status = 200, description = "Mock draft accuracy", body = DraftAccuracyResponse),
+        (status = 400, description = "Draft is not completed"),
+        (status = 404, description = "Draft not found or no real results for its year")
+    ),
+    params(
+        ("id" = Uuid, Path, description = "Draft ID")
+    ),
+    tag = "drafts"
+)]
+pub async fn get_draft_accuracy(
+    State(state): State<AppState>,
+    Path(draft_id): Path<Uuid>,
+) -> ApiResult<Json<DraftAccuracyResponse>> {
+    let draft = state
+        .draft_repo
+        .find_by_id(draft_id)
+        .await?
+        .ok_or_else(|| ApiError::NotFound(format!("Draft with id {} not found", draft_id)))?;
+    if draft.status != DraftStatus::Completed {
+        return Err(DomainError::InvalidState(format!(
+            "Draft {} must be completed before it can be scored",
+            draft_id
+        ))
+        .into());
+    }
+
+    let actual = state
+        .actual_draft_pick_repo
+        .find_by_year(draft.year)
+        .await?;
+    if actual.is_empty() {
+        return Err(ApiError::NotFound(format!(
+            "No actual draft results loaded for {}",
+            draft.year
+        )));
+    }
+
+    let (picks, players, teams) = tokio::join!(
+        state.draft_pick_repo.find_by_draft_id(draft_id),
+        state.player_repo.find_by_draft_year(draft.year),
+        state.team_repo.find_all(),
+    );
+    let accuracy = MockAccuracyService::score(&picks?, &actual);
+
+    let abbreviations: HashMap<Uuid, String> =
+        teams?.into_iter().map(|t| (t.id, t.abbreviation)).collect();
+    let players: HashMap<Uuid, _> = players?.into_iter().map(|p| (p.id, p)).collect();
+
+    Ok(Json(DraftAccuracyResponse {
+        draft_id,
+        year: draft.year,
+        exact_picks: accuracy.exact_picks,
+        correct_teams: accuracy.correct_teams,
+        correct_rounds: accuracy.correct_rounds,
+        players_drafted: accuracy.players_drafted,
+        points: accuracy.points,
+        max_points: accuracy.max_points,
+        score_percent: accuracy.score_percent,
+        picks: accuracy
+            .picks
+            .into_iter()
+            .map(|pick| {
+                let player = players.get(&pick.player_id);
+                PickAccuracyResponse {
+                    pick_id: pick.pick_id,
+                    round: pick.round,
+                    overall_pick: pick.overall_pick,
+                    team_id: pick.team_id,
+                    team_abbreviation: abbreviations.get(&pick.team_id).cloned(),
+                    player_id: pick.player_id,
+                    first_name: player.map(|p| p.first_name.clone()),
+                    last_name: player.map(|p| p.last_name.clone()),
+                    actual_overall_pick: pick.actual.as_ref().map(|a| a.overall_pick),
+                    actual_round: pick.actual.as_ref().map(|a| a.round),
+                    actual_team_id: pick.actual.as_ref().map(|a| a.team_id),
+                    actual_team_abbreviation: pick
+                        .actual
+                        .as_ref()
+                        .and_then(|a| abbreviations.get(&a.team_id).cloned()),
+                    exact_pick: pick.exact_pick,
+                    correct_team: pick.correct_team,
+                    correct_round: pick.correct_round,
+                    points: pick.points,
+                }
+            })
+            .collect(),
+    }))
+}
@@ -1,10 +1,17 @@
+use std::collections::HashMap;
+
 use axum::extract::{Query, State};
 use axum::Json;
 use serde::{Deserialize, Serialize};
 use utoipa::ToSchema;
 use uuid::Uuid;
 
+use domain::models::Position;
+use domain::services::RasScoringService;
+
 use crate::error::ApiResult;
+use crate::handlers::combine_results::CombineResultsResponse;
+use crate::handlers::ras::RasScoreResponse;
 use crate::state::AppState;
 
 #[derive(Debug, Deserialize)]
@@ -39,3 +46,86 @@ pub async fn list_feldman_freaks(
 
     Ok(Json(response))
 }
+
+#[derive(Debug, Deserialize)]
+pub struct FreakProfilesQuery {
+    /// Only this year's list; every year when omitted
+    pub year: Option<i32>,
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct FreakProfileResponse {
+    pub year: i32,
+    pub rank: i32,
+    pub description: String,
+    pub article_url: Option<String>,
+    pub player_id: Uuid,
+    pub first_name: String,
+    pub last_name: String,
+    pub position: Position,
+    pub college: Option<String>,
+    pub height_inches: Option<i32>,
+    pub weight_pounds: Option<i32>,
+    /// Merged testing results the RAS is scored from
+    pub combine: Option<CombineResultsResponse>,
+    pub ras: Option<RasScoreResponse>,
+}
+
+/// GET /api/v1/players/freaks?year=2026
+///
+/// The curated Feldman Freaks list with each player's measurables, combine
+/// results and RAS, in list order.
+pub async fn list_freak_profiles(
+    State(state): State<AppState>,
+    Query(query): Query<FreakProfilesQuery>,
+) -> ApiResult<Json<Vec<FreakProfileResponse>>> {
+    let freaks = match query.year {
+        Some(year) => state.feldman_freak_repo.find_by_year(year).await?,
+        None => state.feldman_freak_repo.find_all().await?,
+    };
+    let player_ids: Vec<Uuid> = freaks.iter().map(|f| f.player_id).collect();
+
+    let (players, combine_results, percentiles) = tokio::join!(
+        state.player_repo.find_by_ids(&player_ids),
+        state.combine_results_repo.find_all(),
+        state.combine_percentile_repo.find_all(),
+    );
+    let players: HashMap<Uuid, _> = players?.into_iter().map(|p| (p.id, p)).collect();
+    let percentiles = percentiles?;
+    let mut combine_by_player = state
+        .ras_service
+        .select_combine_results_by_player(combine_results?);
+
+    let response = freaks
+        .into_iter()
+        .filter_map(|freak| {
+            let player = players.get(&freak.player_id)?;
+            let combine = combine_by_player.remove(&freak.player_id);
+            let ras = combine.as_ref().map(|results| {
+                RasScoreResponse::from(RasScoringService::calculate_ras_with_percentiles(
+                    player,
+                    results,
+                    &percentiles,
+                ))
+            });
+
+            Some(FreakProfileResponse {
+                year: freak.year,
+                rank: freak.rank,
+                description: freak.description,
+                article_url: freak.article_url,
+                player_id: player.id,
+                first_name: player.first_name.clone(),
+                last_name: player.last_name.clone(),
+                position: player.position,
+                college: player.college.clone(),
+                height_inches: player.height_inches,
+                weight_pounds: player.weight_pounds,
+                combine: combine.map(CombineResultsResponse::from),
+                ras,
+            })
+        })
+        .collect();
+
+    Ok(Json(response))
+}
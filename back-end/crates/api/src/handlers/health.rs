@@ -1,5 +1,11 @@
+use axum::extract::State;
+use axum::http::StatusCode;
 use axum::Json;
+use serde::Serialize;
 use serde_json::{json, Value};
+use utoipa::ToSchema;
+
+use crate::state::AppState;
 
 /// Health check endpoint
 /// Returns 200 OK with status information
@@ -19,6 +25,293 @@ pub async fn health_check() -> Json<Value> {
     }))
 }
 
+/// Liveness probe: the process is up and serving requests. Deliberately
+/// touches nothing else, so a database outage doesn't get the API restarted.
+#[utoipa::path(
+    get,
+    path = "/health/live",
+    responses(
+        (status = 200, description = "Process is running", body = Value)
+    ),
+    tag = "health"
+)]
+pub async fn liveness() -> Json<Value> {
+    Json(json!({
+        "status": "alive",
+        "service": "nfl-draft-api",
+        "version": env!("CARGO_PKG_VERSION")
+    }))
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum ComponentState {
+    Up,
+    Down,
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct ComponentStatus {
+    pub name: String,
+    pub status: ComponentState,
+    pub detail: String,
+}
+
+impl ComponentStatus {
+    fn up(name: &str, detail: String) -> Self {
+        Self {
+            name: name.to_string(),
+            status: ComponentState::Up,
+            detail,
+        }
+    }
+
+    fn down(name: &str, detail: String) -> Self {
+        Self {
+            name: name.to_string(),
+            status: ComponentState::Down,
+            detail,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum Readiness {
+    Ready,
+    NotReady,
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct ReadinessResponse {
+    pub status: Readiness,
+    pub components: Vec<ComponentStatus>,
+}
+
+/// Readiness probe: the API can do useful work. Checks that the database is
+/// reachable, every migration in this build has been applied, and teams and
+/// players have been seeded. Returns 503 until all of them pass.
+#[utoipa::path(
+    get,
+    path = "/health/ready",
+    responses(
+        (status = 200, description = "Ready to serve traffic", body = ReadinessResponse),
+        (status = 503, description = "A component is down", body = ReadinessResponse)
+    ),
+    tag = "health"
+)]
+pub async fn readiness(State(state): State<AppState>) -> (StatusCode, Json<ReadinessResponse>) {
+    let pool = state.pool();
+    let components = match db::health::ping(pool).await {
+        Ok(()) => vec![
+            ComponentStatus::up("database", "Connected".to_string()),
+            check_migrations(pool).await,
+            check_seed_data(pool).await,
+        ],
+        Err(e) => {
+            tracing::warn!("Readiness check could not reach the database: {}", e);
+            let unavailable = || "Database unreachable".to_string();
+            vec![
+                ComponentStatus::down("database", unavailable()),
+                ComponentStatus::down("migrations", unavailable()),
+                ComponentStatus::down("seed_data", unavailable()),
+            ]
+        }
+    };
+
+    let ready = components
+        .iter()
+        .all(|component| component.status == ComponentState::Up);
+    let (code, status) = if ready {
+        (StatusCode::OK, Readiness::Ready)
+    } else {
+        (StatusCode::SERVICE_UNAVAILABLE, Readiness::NotReady)
+    };
+    (code, Json(ReadinessResponse { status, components }))
+}
+
+async fn check_migrations(pool: &sqlx::PgPool) -> ComponentStatus {
+    match db::health::migration_status(pool).await {
+        Ok(status) if status.is_current() => ComponentStatus::up(
+            "migrations",
+            format!("{} of {} applied", status.applied, status.expected),
+        ),
+        Ok(status) => {
+            let pending: Vec<String> = status.pending.iter().map(i64::to_string).collect();
+            ComponentStatus::down(
+                "migrations",
+                format!("{} pending: {}", pending.len(), pending.join(", ")),
+            )
+        }
+        Err(e) => {
+            tracing::warn!("Readiness check could not read migrations: {}", e);
+            ComponentStatus::down("migrations", "Could not read migration history".to_string())
+        }
+    }
+}
+
+async fn check_seed_data(pool: &sqlx::PgPool) -> ComponentStatus {
+    match db::health::seed_data_status(pool).await {
+        Ok(status) => {
+            let detail = format!("{} teams, {} players", status.teams, status.players);
+            if status.is_present() {
+                ComponentStatus::up("seed_data", detail)
+            } else {
+                ComponentStatus::down("seed_data", detail)
+            }
+        }
+        Err(e) => {
+            tracing::warn!("Readiness check could not count seed data: {}", e);
+            ComponentStatus::down("seed_data", "Could not count seed data".to_string())
+        }
+    }
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct PoolUtilization {
+    /// `primary`, or `replica` for the read replica
+    pub name: String,
+    /// Open connections, idle or in use
+    pub size: u32,
+    pub idle: u32,
+    pub in_use: u32,
+    pub max_connections: u32,
+}
+
+impl PoolUtilization {
+    fn of(name: &str, pool: &sqlx::PgPool) -> Self {
+        let stats = db::health::pool_stats(pool);
+        Self {
+            name: name.to_string(),
+            size: stats.size,
+            idle: stats.idle,
+            in_use: stats.in_use(),
+            max_connections: stats.max_connections,
+        }
+    }
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct MigrationReport {
+    pub applied: usize,
+    pub expected: usize,
+    /// Versions this build expects that have not been applied
+    pub pending: Vec<i64>,
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct TableRowCount {
+    pub table: String,
+    /// Estimate from the statistics collector, not an exact count
+    pub rows: i64,
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct ReplicationStatus {
+    /// How far the read replica's replay trails the primary
+    pub lag_ms: u64,
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct DatabaseHealthResponse {
+    pub status: ComponentState,
+    pub pools: Vec<PoolUtilization>,
+    /// Missing when the database is down or the history could not be read
+    pub migrations: Option<MigrationReport>,
+    /// Missing when the database is down or the statistics could not be read
+    pub row_counts: Option<Vec<TableRowCount>>,
+    /// Present only when reads go to a replica
+    pub replication: Option<ReplicationStatus>,
+}
+
+/// Database report for ops dashboards: connection pool utilization, pending
+/// migrations, estimated row counts of the key tables and, when reads go to
+/// a replica, how far it lags the primary. Admin only, or the seed API key.
+/// Returns 503 when the primary is unreachable; a part that cannot be read
+/// is left out rather than failing the report.
+#[utoipa::path(
+    get,
+    path = "/health/db",
+    responses(
+        (status = 200, description = "Database report", body = DatabaseHealthResponse),
+        (status = 401, description = "Unauthorized - invalid or missing API key"),
+        (status = 404, description = "Not found - not an admin and no seed API key configured"),
+        (status = 503, description = "Database unreachable", body = DatabaseHealthResponse)
+    ),
+    tag = "health"
+)]
+pub async fn database_health(
+    State(state): State<AppState>,
+) -> (StatusCode, Json<DatabaseHealthResponse>) {
+    let pool = state.pool();
+    let mut pools = vec![PoolUtilization::of("primary", pool)];
+
+    if let Err(e) = db::health::ping(pool).await {
+        tracing::warn!("Database health check could not reach the database: {}", e);
+        return (
+            StatusCode::SERVICE_UNAVAILABLE,
+            Json(DatabaseHealthResponse {
+                status: ComponentState::Down,
+                pools,
+                migrations: None,
+                row_counts: None,
+                replication: None,
+            }),
+        );
+    }
+
+    let (migrations, row_counts, lag) = tokio::join!(
+        db::health::migration_status(pool),
+        db::health::table_row_counts(pool),
+        db::health::replication_lag(state.read_pool()),
+    );
+
+    let migrations = migrations
+        .map_err(|e| tracing::warn!("Database health check could not read migrations: {}", e))
+        .ok()
+        .map(|status| MigrationReport {
+            applied: status.applied,
+            expected: status.expected,
+            pending: status.pending,
+        });
+    let row_counts = row_counts
+        .map_err(|e| tracing::warn!("Database health check could not count rows: {}", e))
+        .ok()
+        .map(|counts| {
+            counts
+                .into_iter()
+                .map(|(table, rows)| TableRowCount {
+                    table: table.to_string(),
+                    rows,
+                })
+                .collect()
+        });
+    let replication = match lag {
+        Ok(Some(lag)) => {
+            pools.push(PoolUtilization::of("replica", state.read_pool()));
+            Some(ReplicationStatus {
+                lag_ms: lag.as_millis() as u64,
+            })
+        }
+        Ok(None) => None,
+        Err(e) => {
+            tracing::warn!("Database health check could not read replica lag: {}", e);
+            None
+        }
+    };
+
+    (
+        StatusCode::OK,
+        Json(DatabaseHealthResponse {
+            status: ComponentState::Up,
+            pools,
+            migrations,
+            row_counts,
+            replication,
+        }),
+    )
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -32,4 +325,10 @@ mod tests {
         assert_eq!(value["service"], "nfl-draft-api");
         assert!(value["version"].is_string());
     }
+
+    #[tokio::test]
+    async fn test_liveness() {
+        let value = liveness().await.0;
+        assert_eq!(value["status"], "alive");
+    }
 }
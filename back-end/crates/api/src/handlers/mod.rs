@@ -1,8 +1,18 @@
+pub mod admin;
+pub mod adp;
+pub mod audit_log;
+pub mod auth;
+pub mod big_board;
+pub mod chat;
+pub mod college_stats;
 pub mod combine_percentiles;
 pub mod combine_results;
 pub mod drafts;
 pub mod feldman_freaks;
 pub mod health;
+pub mod participants;
+pub mod player_notes;
+pub mod player_tags;
 pub mod players;
 pub mod prospect_profiles;
 pub mod rankings;
@@ -14,4 +24,5 @@ pub mod team_needs;
 pub mod team_seasons;
 pub mod teams;
 pub mod trades;
+pub mod watchlist;
 pub mod websocket;
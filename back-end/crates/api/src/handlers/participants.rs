@@ -0,0 +1,234 @@
+use axum::{
+    extract::{Path, State},
+    http::StatusCode,
+    Json,
+};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::audit;
+use crate::auth::AuthUser;
+use crate::error::{ApiError, ApiResult};
+use crate::state::AppState;
+use domain::models::{Role, SessionParticipant, SessionStatus};
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct JoinSessionRequest {
+    pub display_name: String,
+    #[serde(default)]
+    pub team_ids: Vec<Uuid>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ParticipantResponse {
+    pub id: Uuid,
+    pub session_id: Uuid,
+    pub user_id: Uuid,
+    pub display_name: String,
+    pub team_ids: Vec<Uuid>,
+    pub joined_at: DateTime<Utc>,
+}
+
+impl From<SessionParticipant> for ParticipantResponse {
+    fn from(p: SessionParticipant) -> Self {
+        Self {
+            id: p.id,
+            session_id: p.session_id,
+            user_id: p.user_id,
+            display_name: p.display_name,
+            team_ids: p.team_ids,
+            joined_at: p.joined_at,
+        }
+    }
+}
+
+/// Checks that the signed-in user may act on a session. Commissioners and
+/// admins run sessions and always may. Sessions without participants keep
+/// the single-operator behaviour and allow every user. Otherwise the user
+/// must have joined the session and, when `team_id` is given, control that
+/// team. The user's seat, when they hold one, is returned and named in the
+/// request's audit rows.
+pub(crate) async fn authorize_participant(
+    state: &AppState,
+    user: &AuthUser,
+    session_id: Uuid,
+    team_id: Option<Uuid>,
+) -> ApiResult<Option<SessionParticipant>> {
+    let participants = state.participant_repo.list_by_session(session_id).await?;
+    let seat = participants
+        .iter()
+        .find(|p| p.user_id == user.user_id)
+        .cloned();
+    if let Some(seat) = &seat {
+        audit::record_participant(seat);
+    }
+
+    if user.role.includes(Role::Commissioner) || participants.is_empty() {
+        return Ok(seat);
+    }
+
+    let participant = seat.ok_or_else(|| {
+        ApiError::Forbidden(format!(
+            "{} has not joined session {}",
+            user.email, session_id
+        ))
+    })?;
+
+    if let Some(team_id) = team_id {
+        if !participant.controls_team(team_id) {
+            return Err(ApiError::Forbidden(format!(
+                "Participant '{}' does not control team {}",
+                participant.display_name, team_id
+            )));
+        }
+    }
+
+    Ok(Some(participant))
+}
+
+/// Looks up the seat `user_id` holds in a session. Returns `None` when the
+/// user has not joined it.
+pub(crate) async fn participant_for_user(
+    state: &AppState,
+    session_id: Uuid,
+    user_id: Uuid,
+) -> ApiResult<Option<SessionParticipant>> {
+    let participants = state.participant_repo.list_by_session(session_id).await?;
+    Ok(participants.into_iter().find(|p| p.user_id == user_id))
+}
+
+/// Rejects a self-chosen name that matches, ignoring case, the name of one of
+/// the session's participants, so nobody can pass for them in chat or
+/// presence without signing in as them
+pub(crate) async fn ensure_not_participant_name(
+    state: &AppState,
+    session_id: Uuid,
+    name: &str,
+) -> ApiResult<()> {
+    let name = name.trim();
+    let participants = state.participant_repo.list_by_session(session_id).await?;
+    if participants
+        .iter()
+        .any(|p| p.display_name.eq_ignore_ascii_case(name))
+    {
+        return Err(domain::errors::DomainError::ValidationError(format!(
+            "'{}' is a participant name; sign in as them to use it",
+            name
+        ))
+        .into());
+    }
+    Ok(())
+}
+
+/// POST /api/v1/sessions/:id/participants
+/// Joins the signed-in user to a session and claims teams. The claimed teams
+/// become user-controlled so auto-pick leaves them alone.
+pub async fn join_session(
+    State(state): State<AppState>,
+    user: AuthUser,
+    Path(session_id): Path<Uuid>,
+    Json(payload): Json<JoinSessionRequest>,
+) -> ApiResult<(StatusCode, Json<ParticipantResponse>)> {
+    let mut session = state
+        .session_repo
+        .find_by_id(session_id)
+        .await?
+        .ok_or_else(|| domain::errors::DomainError::NotFound(format!("Session {}", session_id)))?;
+
+    if session.status == SessionStatus::Completed {
+        return Err(domain::errors::DomainError::InvalidState(
+            "Cannot join a completed session".to_string(),
+        )
+        .into());
+    }
+
+    let participant = SessionParticipant::new(
+        session_id,
+        user.user_id,
+        payload.display_name,
+        payload.team_ids,
+    )?;
+
+    for team_id in &participant.team_ids {
+        state.team_repo.find_by_id(*team_id).await?.ok_or_else(|| {
+            domain::errors::DomainError::NotFound(format!("Team {} not found", team_id))
+        })?;
+    }
+
+    let existing = state.participant_repo.list_by_session(session_id).await?;
+    for other in &existing {
+        let shared = participant.shared_teams(other);
+        if let Some(team_id) = shared.first() {
+            return Err(domain::errors::DomainError::DuplicateEntry(format!(
+                "Team {} is already controlled by '{}'",
+                team_id, other.display_name
+            ))
+            .into());
+        }
+    }
+
+    let created = state.participant_repo.create(&participant).await?;
+    audit::record_participant(&created);
+
+    let newly_controlled: Vec<Uuid> = created
+        .team_ids
+        .iter()
+        .filter(|id| !session.is_team_controlled(**id))
+        .copied()
+        .collect();
+    if !newly_controlled.is_empty() {
+        session.controlled_team_ids.extend(newly_controlled);
+        session.updated_at = Utc::now();
+        state.session_repo.update(&session).await?;
+    }
+
+    Ok((StatusCode::CREATED, Json(created.into())))
+}
+
+/// GET /api/v1/sessions/:id/participants
+pub async fn list_participants(
+    State(state): State<AppState>,
+    Path(session_id): Path<Uuid>,
+) -> ApiResult<Json<Vec<ParticipantResponse>>> {
+    state
+        .session_repo
+        .find_by_id(session_id)
+        .await?
+        .ok_or_else(|| domain::errors::DomainError::NotFound(format!("Session {}", session_id)))?;
+
+    let participants = state.participant_repo.list_by_session(session_id).await?;
+    Ok(Json(participants.into_iter().map(Into::into).collect()))
+}
+
+/// DELETE /api/v1/sessions/:id/participants/:participant_id
+/// A participant may only remove itself; a commissioner may remove anyone.
+/// The participant's teams go back to the session and are no longer
+/// user-controlled.
+pub async fn leave_session(
+    State(state): State<AppState>,
+    user: AuthUser,
+    Path((session_id, participant_id)): Path<(Uuid, Uuid)>,
+) -> ApiResult<StatusCode> {
+    let participant = state
+        .participant_repo
+        .find_by_id(participant_id)
+        .await?
+        .filter(|p| p.session_id == session_id)
+        .ok_or_else(|| {
+            domain::errors::DomainError::NotFound(format!("Participant {}", participant_id))
+        })?;
+
+    if participant.user_id != user.user_id && !user.role.includes(Role::Commissioner) {
+        return Err(ApiError::Forbidden(format!(
+            "Only '{}' or a commissioner may remove this participant",
+            participant.display_name
+        )));
+    }
+
+    if participant.user_id == user.user_id {
+        audit::record_participant(&participant);
+    }
+    state.participant_repo.leave(&participant).await?;
+    Ok(StatusCode::NO_CONTENT)
+}
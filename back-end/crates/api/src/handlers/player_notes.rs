@@ -0,0 +1,189 @@
+use axum::extract::{Path, State};
+use axum::http::StatusCode;
+use axum::Json;
+use chrono::NaiveDate;
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+use domain::models::{NoteTag, PlayerNote};
+
+use crate::auth::AuthUser;
+use crate::error::{ApiError, ApiResult};
+use crate::state::AppState;
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct CreatePlayerNoteRequest {
+    pub source: String,
+    pub note_date: NaiveDate,
+    #[serde(default)]
+    pub tag: NoteTag,
+    pub content: String,
+}
+
+/// Replaces every editable field of the note
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct UpdatePlayerNoteRequest {
+    pub source: String,
+    pub note_date: NaiveDate,
+    pub tag: NoteTag,
+    pub content: String,
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct PlayerNoteResponse {
+    pub id: Uuid,
+    pub player_id: Uuid,
+    pub source: String,
+    pub note_date: NaiveDate,
+    pub tag: NoteTag,
+    pub content: String,
+}
+
+impl From<PlayerNote> for PlayerNoteResponse {
+    fn from(note: PlayerNote) -> Self {
+        Self {
+            id: note.id,
+            player_id: note.player_id,
+            source: note.source,
+            note_date: note.note_date,
+            tag: note.tag,
+            content: note.content,
+        }
+    }
+}
+
+/// POST /api/v1/players/:player_id/notes - Add a note to a player
+#[utoipa::path(
+    post,
+    path = "/api/v1/players/{player_id}/notes",
+    request_body = CreatePlayerNoteRequest,
+    responses(
+        (status = 201, description = "Player note created successfully", body = PlayerNoteResponse),
+        (status = 401, description = "Missing or invalid access token"),
+        (status = 400, description = "Invalid request"),
+        (status = 404, description = "Player not found")
+    ),
+    params(
+        ("player_id" = Uuid, Path, description = "Player ID")
+    ),
+    tag = "player-notes"
+)]
+pub async fn create_player_note(
+    State(state): State<AppState>,
+    _user: AuthUser,
+    Path(player_id): Path<Uuid>,
+    Json(req): Json<CreatePlayerNoteRequest>,
+) -> ApiResult<(StatusCode, Json<PlayerNoteResponse>)> {
+    let note = PlayerNote::new(player_id, req.source, req.note_date, req.tag, req.content)?;
+
+    let created = state.player_note_repo.create(&note).await?;
+
+    Ok((StatusCode::CREATED, Json(PlayerNoteResponse::from(created))))
+}
+
+/// GET /api/v1/players/:player_id/notes - Get all notes for a player, newest first
+#[utoipa::path(
+    get,
+    path = "/api/v1/players/{player_id}/notes",
+    responses(
+        (status = 200, description = "List of notes for player", body = Vec<PlayerNoteResponse>)
+    ),
+    params(
+        ("player_id" = Uuid, Path, description = "Player ID")
+    ),
+    tag = "player-notes"
+)]
+pub async fn list_player_notes(
+    State(state): State<AppState>,
+    Path(player_id): Path<Uuid>,
+) -> ApiResult<Json<Vec<PlayerNoteResponse>>> {
+    let notes = state.player_note_repo.find_by_player_id(player_id).await?;
+    let response: Vec<PlayerNoteResponse> =
+        notes.into_iter().map(PlayerNoteResponse::from).collect();
+    Ok(Json(response))
+}
+
+/// GET /api/v1/player-notes/:id - Get player note by ID
+#[utoipa::path(
+    get,
+    path = "/api/v1/player-notes/{id}",
+    responses(
+        (status = 200, description = "Player note found", body = PlayerNoteResponse),
+        (status = 404, description = "Player note not found")
+    ),
+    params(
+        ("id" = Uuid, Path, description = "Player note ID")
+    ),
+    tag = "player-notes"
+)]
+pub async fn get_player_note(
+    State(state): State<AppState>,
+    Path(id): Path<Uuid>,
+) -> ApiResult<Json<PlayerNoteResponse>> {
+    let note = state
+        .player_note_repo
+        .find_by_id(id)
+        .await?
+        .ok_or_else(|| ApiError::NotFound(format!("Player note with id {} not found", id)))?;
+
+    Ok(Json(PlayerNoteResponse::from(note)))
+}
+
+/// PUT /api/v1/player-notes/:id - Update player note
+#[utoipa::path(
+    put,
+    path = "/api/v1/player-notes/{id}",
+    request_body = UpdatePlayerNoteRequest,
+    responses(
+        (status = 200, description = "Player note updated successfully", body = PlayerNoteResponse),
+        (status = 401, description = "Missing or invalid access token"),
+        (status = 404, description = "Player note not found"),
+        (status = 400, description = "Invalid request")
+    ),
+    params(
+        ("id" = Uuid, Path, description = "Player note ID")
+    ),
+    tag = "player-notes"
+)]
+pub async fn update_player_note(
+    State(state): State<AppState>,
+    _user: AuthUser,
+    Path(id): Path<Uuid>,
+    Json(req): Json<UpdatePlayerNoteRequest>,
+) -> ApiResult<Json<PlayerNoteResponse>> {
+    let mut note = state
+        .player_note_repo
+        .find_by_id(id)
+        .await?
+        .ok_or_else(|| ApiError::NotFound(format!("Player note with id {} not found", id)))?;
+
+    note.update(req.source, req.note_date, req.tag, req.content)?;
+
+    let updated = state.player_note_repo.update(&note).await?;
+
+    Ok(Json(PlayerNoteResponse::from(updated)))
+}
+
+/// DELETE /api/v1/player-notes/:id - Delete player note
+#[utoipa::path(
+    delete,
+    path = "/api/v1/player-notes/{id}",
+    responses(
+        (status = 204, description = "Player note deleted successfully"),
+        (status = 401, description = "Missing or invalid access token"),
+        (status = 404, description = "Player note not found")
+    ),
+    params(
+        ("id" = Uuid, Path, description = "Player note ID")
+    ),
+    tag = "player-notes"
+)]
+pub async fn delete_player_note(
+    State(state): State<AppState>,
+    _user: AuthUser,
+    Path(id): Path<Uuid>,
+) -> ApiResult<StatusCode> {
+    state.player_note_repo.delete(id).await?;
+    Ok(StatusCode::NO_CONTENT)
+}
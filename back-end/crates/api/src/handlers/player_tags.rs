@@ -0,0 +1,145 @@
+use axum::extract::{Path, State};
+use axum::http::StatusCode;
+use axum::Json;
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+use domain::models::{PlayerTag, TagCount};
+
+use crate::auth::AuthUser;
+use crate::error::{ApiError, ApiResult};
+use crate::state::AppState;
+
+/// Tags are trimmed and lowercased, so "Team Captain" and "team captain" are the same tag
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct AddPlayerTagRequest {
+    pub tag: String,
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct PlayerTagResponse {
+    pub player_id: Uuid,
+    pub tag: String,
+}
+
+impl From<PlayerTag> for PlayerTagResponse {
+    fn from(tag: PlayerTag) -> Self {
+        Self {
+            player_id: tag.player_id,
+            tag: tag.tag,
+        }
+    }
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct TagCountResponse {
+    pub tag: String,
+    pub player_count: i64,
+}
+
+impl From<TagCount> for TagCountResponse {
+    fn from(count: TagCount) -> Self {
+        Self {
+            tag: count.tag,
+            player_count: count.player_count,
+        }
+    }
+}
+
+/// GET /api/v1/players/:player_id/tags - Get a player's tags, alphabetically
+#[utoipa::path(
+    get,
+    path = "/api/v1/players/{player_id}/tags",
+    responses(
+        (status = 200, description = "List of tags for player", body = Vec<PlayerTagResponse>)
+    ),
+    params(
+        ("player_id" = Uuid, Path, description = "Player ID")
+    ),
+    tag = "player-tags"
+)]
+pub async fn list_player_tags(
+    State(state): State<AppState>,
+    Path(player_id): Path<Uuid>,
+) -> ApiResult<Json<Vec<PlayerTagResponse>>> {
+    let tags = state.player_tag_repo.find_by_player_id(player_id).await?;
+    let response: Vec<PlayerTagResponse> = tags.into_iter().map(PlayerTagResponse::from).collect();
+    Ok(Json(response))
+}
+
+/// POST /api/v1/players/:player_id/tags - Tag a player; tagging twice is a no-op
+#[utoipa::path(
+    post,
+    path = "/api/v1/players/{player_id}/tags",
+    request_body = AddPlayerTagRequest,
+    responses(
+        (status = 201, description = "Tag added to player", body = PlayerTagResponse),
+        (status = 401, description = "Missing or invalid access token"),
+        (status = 400, description = "Invalid tag"),
+        (status = 404, description = "Player not found")
+    ),
+    params(
+        ("player_id" = Uuid, Path, description = "Player ID")
+    ),
+    tag = "player-tags"
+)]
+pub async fn add_player_tag(
+    State(state): State<AppState>,
+    _user: AuthUser,
+    Path(player_id): Path<Uuid>,
+    Json(req): Json<AddPlayerTagRequest>,
+) -> ApiResult<(StatusCode, Json<PlayerTagResponse>)> {
+    let tag = PlayerTag::new(player_id, &req.tag)?;
+
+    let added = state.player_tag_repo.add(&tag).await?;
+
+    Ok((StatusCode::CREATED, Json(PlayerTagResponse::from(added))))
+}
+
+/// DELETE /api/v1/players/:player_id/tags/:tag - Remove a tag from a player
+#[utoipa::path(
+    delete,
+    path = "/api/v1/players/{player_id}/tags/{tag}",
+    responses(
+        (status = 204, description = "Tag removed from player"),
+        (status = 401, description = "Missing or invalid access token"),
+        (status = 404, description = "Player does not carry the tag")
+    ),
+    params(
+        ("player_id" = Uuid, Path, description = "Player ID"),
+        ("tag" = String, Path, description = "Tag to remove")
+    ),
+    tag = "player-tags"
+)]
+pub async fn remove_player_tag(
+    State(state): State<AppState>,
+    _user: AuthUser,
+    Path((player_id, tag)): Path<(Uuid, String)>,
+) -> ApiResult<StatusCode> {
+    let tag = PlayerTag::normalize(&tag)?;
+
+    if !state.player_tag_repo.remove(player_id, &tag).await? {
+        return Err(ApiError::NotFound(format!(
+            "Player {} has no tag '{}'",
+            player_id, tag
+        )));
+    }
+
+    Ok(StatusCode::NO_CONTENT)
+}
+
+/// GET /api/v1/player-tags - List every tag in use, most used first
+#[utoipa::path(
+    get,
+    path = "/api/v1/player-tags",
+    responses(
+        (status = 200, description = "Tags with the number of players carrying each", body = Vec<TagCountResponse>)
+    ),
+    tag = "player-tags"
+)]
+pub async fn list_tags(State(state): State<AppState>) -> ApiResult<Json<Vec<TagCountResponse>>> {
+    let counts = state.player_tag_repo.list_tags().await?;
+    let response: Vec<TagCountResponse> = counts.into_iter().map(TagCountResponse::from).collect();
+    Ok(Json(response))
+}
@@ -1,13 +1,22 @@
-use axum::extract::{Path, State};
+use axum::extract::{Path, Query, State};
 use axum::http::StatusCode;
 use axum::Json;
-use serde::{Deserialize, Serialize};
-use utoipa::ToSchema;
+use chrono::NaiveDate;
+use serde::{Deserialize, Deserializer, Serialize};
+use utoipa::{IntoParams, ToSchema};
 use uuid::Uuid;
 
-use domain::models::{Player, Position};
+use domain::models::{
+    InjuryStatus, Player, PlayerFilter, PlayerSortField, PlayerTag, PlayerUpdate, Position,
+};
 
+use crate::batch::IdsParams;
 use crate::error::{ApiError, ApiResult};
+use crate::fields::FieldsParams;
+use crate::handlers::player_notes::PlayerNoteResponse;
+use crate::pagination::{Paginated, PaginationParams};
+use crate::response_format::FormatParams;
+use crate::sorting::SortParams;
 use crate::state::AppState;
 
 #[derive(Debug, Serialize, Deserialize, ToSchema)]
@@ -19,6 +28,70 @@ pub struct CreatePlayerRequest {
     pub height_inches: Option<i32>,
     pub weight_pounds: Option<i32>,
     pub draft_year: i32,
+    #[serde(default)]
+    pub injury_status: InjuryStatus,
+    pub injury_body_part: Option<String>,
+    pub injury_expected_return: Option<NaiveDate>,
+    #[serde(default)]
+    pub medical_flag: bool,
+}
+
+/// Sparse player update. Omitted fields are left alone; `college`,
+/// `height_inches`, `weight_pounds`, `injury_body_part` and
+/// `injury_expected_return` may be set to `null` to clear them.
+#[derive(Debug, Default, Deserialize, ToSchema)]
+pub struct UpdatePlayerRequest {
+    pub first_name: Option<String>,
+    pub last_name: Option<String>,
+    pub position: Option<Position>,
+    #[serde(default, deserialize_with = "double_option")]
+    #[schema(value_type = Option<String>, nullable)]
+    pub college: Option<Option<String>>,
+    #[serde(default, deserialize_with = "double_option")]
+    #[schema(value_type = Option<i32>, nullable)]
+    pub height_inches: Option<Option<i32>>,
+    #[serde(default, deserialize_with = "double_option")]
+    #[schema(value_type = Option<i32>, nullable)]
+    pub weight_pounds: Option<Option<i32>>,
+    pub draft_year: Option<i32>,
+    pub draft_eligible: Option<bool>,
+    pub injury_status: Option<InjuryStatus>,
+    #[serde(default, deserialize_with = "double_option")]
+    #[schema(value_type = Option<String>, nullable)]
+    pub injury_body_part: Option<Option<String>>,
+    #[serde(default, deserialize_with = "double_option")]
+    #[schema(value_type = Option<NaiveDate>, nullable)]
+    pub injury_expected_return: Option<Option<NaiveDate>>,
+    pub medical_flag: Option<bool>,
+}
+
+impl From<UpdatePlayerRequest> for PlayerUpdate {
+    fn from(req: UpdatePlayerRequest) -> Self {
+        PlayerUpdate {
+            first_name: req.first_name,
+            last_name: req.last_name,
+            position: req.position,
+            college: req.college,
+            height_inches: req.height_inches,
+            weight_pounds: req.weight_pounds,
+            draft_year: req.draft_year,
+            draft_eligible: req.draft_eligible,
+            injury_status: req.injury_status,
+            injury_body_part: req.injury_body_part,
+            injury_expected_return: req.injury_expected_return,
+            medical_flag: req.medical_flag,
+        }
+    }
+}
+
+/// Distinguishes an explicit `null` (`Some(None)`) from an omitted field,
+/// which `#[serde(default)]` leaves as `None`
+fn double_option<'de, D, T>(deserializer: D) -> Result<Option<Option<T>>, D::Error>
+where
+    D: Deserializer<'de>,
+    T: Deserialize<'de>,
+{
+    Option::<T>::deserialize(deserializer).map(Some)
 }
 
 #[derive(Debug, Serialize, ToSchema)]
@@ -32,6 +105,13 @@ pub struct PlayerResponse {
     pub weight_pounds: Option<i32>,
     pub draft_year: i32,
     pub draft_eligible: bool,
+    pub injury_status: InjuryStatus,
+    pub injury_body_part: Option<String>,
+    pub injury_expected_return: Option<NaiveDate>,
+    pub medical_flag: bool,
+    /// Player page on the ranking source the player was scraped from
+    pub profile_url: Option<String>,
+    pub headshot_url: Option<String>,
 }
 
 impl From<Player> for PlayerResponse {
@@ -46,23 +126,137 @@ impl From<Player> for PlayerResponse {
             weight_pounds: player.weight_pounds,
             draft_year: player.draft_year,
             draft_eligible: player.draft_eligible,
+            injury_status: player.injury_status,
+            injury_body_part: player.injury_body_part,
+            injury_expected_return: player.injury_expected_return,
+            medical_flag: player.medical_flag,
+            profile_url: player.profile_url,
+            headshot_url: player.headshot_url,
+        }
+    }
+}
+
+/// Single-player view: the player plus their news and notes, newest first,
+/// and their tags
+#[derive(Debug, Serialize, ToSchema)]
+pub struct PlayerDetailResponse {
+    #[serde(flatten)]
+    pub player: PlayerResponse,
+    pub notes: Vec<PlayerNoteResponse>,
+    pub tags: Vec<String>,
+}
+
+#[derive(Debug, Default, Deserialize, IntoParams)]
+pub struct PlayerFilterParams {
+    /// Only players at this position
+    pub position: Option<Position>,
+    /// Case-insensitive substring match on the player's college
+    pub school: Option<String>,
+    /// Only players in this draft class
+    pub draft_year: Option<i32>,
+    /// Only draft eligible (true) or ineligible (false) players
+    pub draft_eligible: Option<bool>,
+    /// Minimum height in inches
+    pub min_height: Option<i32>,
+    /// Maximum height in inches
+    pub max_height: Option<i32>,
+    /// Minimum weight in pounds
+    pub min_weight: Option<i32>,
+    /// Maximum weight in pounds
+    pub max_weight: Option<i32>,
+    /// Only players with (true) or without (false) combine results
+    pub has_combine_results: Option<bool>,
+    /// Comma-separated tags; only players carrying all of them
+    pub tags: Option<String>,
+}
+
+impl PlayerFilterParams {
+    /// The requested tags normalized and without duplicates, or `None` when
+    /// the client did not filter by tag
+    fn parse_tags(&self) -> ApiResult<Option<Vec<String>>> {
+        let Some(tags) = &self.tags else {
+            return Ok(None);
+        };
+
+        let mut parsed: Vec<String> = Vec::new();
+        for part in tags.split(',').filter(|p| !p.trim().is_empty()) {
+            let tag = PlayerTag::normalize(part)?;
+            if !parsed.contains(&tag) {
+                parsed.push(tag);
+            }
         }
+        if parsed.is_empty() {
+            return Err(ApiError::BadRequest(
+                "tags must contain at least one tag".to_string(),
+            ));
+        }
+        Ok(Some(parsed))
     }
 }
 
-/// GET /api/v1/players - List all players
+impl From<PlayerFilterParams> for PlayerFilter {
+    fn from(params: PlayerFilterParams) -> Self {
+        PlayerFilter {
+            position: params.position,
+            college: params
+                .school
+                .map(|school| school.trim().to_string())
+                .filter(|school| !school.is_empty()),
+            draft_year: params.draft_year,
+            draft_eligible: params.draft_eligible,
+            min_height_inches: params.min_height,
+            max_height_inches: params.max_height,
+            min_weight_pounds: params.min_weight,
+            max_weight_pounds: params.max_weight,
+            has_combine_results: params.has_combine_results,
+            tags: None,
+            ids: None,
+        }
+    }
+}
+
+/// GET /api/v1/players - List players, optionally filtered
+///
+/// Sortable by `first_name`, `last_name`, `position`, `college`,
+/// `height_inches`, `weight_pounds` and `draft_year`. `format=csv` downloads
+/// the same rows as a spreadsheet; `fields=` limits the columns returned and
+/// `ids=` fetches a batch of players by ID.
 #[utoipa::path(
     get,
     path = "/api/v1/players",
+    params(PlayerFilterParams, IdsParams, SortParams, PaginationParams, FormatParams, FieldsParams),
     responses(
-        (status = 200, description = "List of all players", body = Vec<PlayerResponse>)
+        (status = 200, description = "Players matching the filters; total count in X-Total-Count. CSV when format=csv; only the requested fields when fields is set", body = Vec<PlayerResponse>),
+        (status = 400, description = "Invalid filter, ids, sort, pagination or field parameters")
     ),
     tag = "players"
 )]
-pub async fn list_players(State(state): State<AppState>) -> ApiResult<Json<Vec<PlayerResponse>>> {
-    let players = state.player_repo.find_all().await?;
-    let response: Vec<PlayerResponse> = players.into_iter().map(PlayerResponse::from).collect();
-    Ok(Json(response))
+pub async fn list_players(
+    State(state): State<AppState>,
+    Query(filters): Query<PlayerFilterParams>,
+    Query(ids): Query<IdsParams>,
+    Query(sort): Query<SortParams>,
+    Query(pagination): Query<PaginationParams>,
+    Query(format): Query<FormatParams>,
+    Query(fields): Query<FieldsParams>,
+) -> ApiResult<Paginated<PlayerResponse>> {
+    let filter = PlayerFilter {
+        ids: ids.parse()?,
+        tags: filters.parse_tags()?,
+        ..PlayerFilter::from(filters)
+    };
+    filter.validate()?;
+    let sort = sort.parse::<PlayerSortField>()?;
+    let request = pagination.page_request()?;
+    let fields = fields.selection::<PlayerResponse>()?;
+
+    let page = state
+        .player_repo
+        .find_filtered(&filter, &sort, request)
+        .await?;
+    Ok(Paginated::page(page.map(PlayerResponse::from), request)
+        .with_format(format.format, "players.csv")
+        .with_fields(fields))
 }
 
 /// GET /api/v1/players/:id - Get player by ID
@@ -70,7 +264,7 @@ pub async fn list_players(State(state): State<AppState>) -> ApiResult<Json<Vec<P
     get,
     path = "/api/v1/players/{id}",
     responses(
-        (status = 200, description = "Player found", body = PlayerResponse),
+        (status = 200, description = "Player found, with their notes and tags", body = PlayerDetailResponse),
         (status = 404, description = "Player not found")
     ),
     params(
@@ -81,14 +275,20 @@ pub async fn list_players(State(state): State<AppState>) -> ApiResult<Json<Vec<P
 pub async fn get_player(
     State(state): State<AppState>,
     Path(id): Path<Uuid>,
-) -> ApiResult<Json<PlayerResponse>> {
+) -> ApiResult<Json<PlayerDetailResponse>> {
     let player = state
         .player_repo
         .find_by_id(id)
         .await?
         .ok_or_else(|| ApiError::NotFound(format!("Player with id {} not found", id)))?;
+    let notes = state.player_note_repo.find_by_player_id(id).await?;
+    let tags = state.player_tag_repo.find_by_player_id(id).await?;
 
-    Ok(Json(PlayerResponse::from(player)))
+    Ok(Json(PlayerDetailResponse {
+        player: PlayerResponse::from(player),
+        notes: notes.into_iter().map(PlayerNoteResponse::from).collect(),
+        tags: tags.into_iter().map(|t| t.tag).collect(),
+    }))
 }
 
 /// POST /api/v1/players - Create a new player
@@ -98,6 +298,7 @@ pub async fn get_player(
     request_body = CreatePlayerRequest,
     responses(
         (status = 201, description = "Player created successfully", body = PlayerResponse),
+        (status = 401, description = "Unauthorized"),
         (status = 400, description = "Invalid request")
     ),
     tag = "players"
@@ -122,6 +323,95 @@ pub async fn create_player(
         player = player.with_physical_stats(height, weight)?;
     }
 
+    player = player
+        .with_injury(
+            payload.injury_status,
+            payload.injury_body_part,
+            payload.injury_expected_return,
+        )?
+        .with_medical_flag(payload.medical_flag);
+
     let created = state.player_repo.create(&player).await?;
     Ok((StatusCode::CREATED, Json(PlayerResponse::from(created))))
 }
+
+/// PATCH /api/v1/players/:id - Correct individual fields of a player
+#[utoipa::path(
+    patch,
+    path = "/api/v1/players/{id}",
+    request_body = UpdatePlayerRequest,
+    responses(
+        (status = 200, description = "Player updated successfully", body = PlayerResponse),
+        (status = 401, description = "Unauthorized"),
+        (status = 400, description = "Invalid request"),
+        (status = 404, description = "Player not found"),
+        (status = 409, description = "Another player in the draft class has the same name")
+    ),
+    params(
+        ("id" = Uuid, Path, description = "Player ID")
+    ),
+    tag = "players"
+)]
+pub async fn update_player(
+    State(state): State<AppState>,
+    Path(id): Path<Uuid>,
+    Json(payload): Json<UpdatePlayerRequest>,
+) -> ApiResult<Json<PlayerResponse>> {
+    let mut player = state
+        .player_repo
+        .find_by_id(id)
+        .await?
+        .ok_or_else(|| ApiError::NotFound(format!("Player with id {} not found", id)))?;
+
+    player.apply_update(&PlayerUpdate::from(payload))?;
+    let updated = state.player_repo.update(&player).await?;
+
+    Ok(Json(PlayerResponse::from(updated)))
+}
+
+/// DELETE /api/v1/players/:id - Delete a player
+///
+/// The player is soft-deleted: they drop out of every read while their
+/// rankings, reports and picks stay in place, and an admin can restore them.
+#[utoipa::path(
+    delete,
+    path = "/api/v1/players/{id}",
+    responses(
+        (status = 204, description = "Player deleted successfully"),
+        (status = 401, description = "Unauthorized"),
+        (status = 404, description = "Player not found")
+    ),
+    params(
+        ("id" = Uuid, Path, description = "Player ID")
+    ),
+    tag = "players"
+)]
+pub async fn delete_player(
+    State(state): State<AppState>,
+    Path(id): Path<Uuid>,
+) -> ApiResult<StatusCode> {
+    state.player_repo.soft_delete(id).await?;
+    Ok(StatusCode::NO_CONTENT)
+}
+
+/// POST /api/v1/admin/players/:id/restore - Restore a soft-deleted player
+#[utoipa::path(
+    post,
+    path = "/api/v1/admin/players/{id}/restore",
+    params(
+        ("id" = Uuid, Path, description = "Player ID")
+    ),
+    responses(
+        (status = 200, description = "Player restored", body = PlayerResponse),
+        (status = 401, description = "Unauthorized"),
+        (status = 404, description = "No deleted player with this ID")
+    ),
+    tag = "admin"
+)]
+pub async fn restore_player(
+    State(state): State<AppState>,
+    Path(id): Path<Uuid>,
+) -> ApiResult<Json<PlayerResponse>> {
+    let player = state.player_repo.restore(id).await?;
+    Ok(Json(PlayerResponse::from(player)))
+}
@@ -1,13 +1,19 @@
-use axum::extract::{Path, State};
+use axum::extract::{Path, Query, State};
 use axum::Json;
 use chrono::NaiveDate;
-use serde::Serialize;
-use utoipa::ToSchema;
+use serde::{Deserialize, Serialize};
+use utoipa::{IntoParams, ToSchema};
 use uuid::Uuid;
 
-use domain::models::{PlayerRankingWithSource, ProspectRanking, RankingSource};
+use domain::models::{
+    ConsensusRanking, Page, PlayerRankingWithSource, Position, ProspectRanking, RankingSortField,
+    RankingSource,
+};
 
 use crate::error::ApiResult;
+use crate::pagination::{Paginated, PaginationParams};
+use crate::response_format::FormatParams;
+use crate::sorting::SortParams;
 use crate::state::AppState;
 
 #[derive(Debug, Serialize, ToSchema)]
@@ -86,6 +92,45 @@ impl From<PlayerRankingWithSource> for AllRankingEntry {
     }
 }
 
+#[derive(Debug, Default, Deserialize, IntoParams)]
+pub struct ConsensusQuery {
+    /// Only prospects in this draft class
+    pub year: Option<i32>,
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct ConsensusRankingResponse {
+    pub consensus_rank: i64,
+    pub player_id: Uuid,
+    pub first_name: String,
+    pub last_name: String,
+    pub position: Position,
+    /// Number of sources that rank the player
+    pub source_count: i64,
+    pub average_rank: f64,
+    pub best_rank: i32,
+    pub worst_rank: i32,
+    /// How much the sources disagree; 0 when only one source ranks the player
+    pub rank_std_dev: f64,
+}
+
+impl From<ConsensusRanking> for ConsensusRankingResponse {
+    fn from(r: ConsensusRanking) -> Self {
+        Self {
+            consensus_rank: r.consensus_rank,
+            player_id: r.player_id,
+            first_name: r.first_name,
+            last_name: r.last_name,
+            position: r.position,
+            source_count: r.source_count,
+            average_rank: r.average_rank,
+            best_rank: r.best_rank,
+            worst_rank: r.worst_rank,
+            rank_std_dev: r.rank_std_dev,
+        }
+    }
+}
+
 /// GET /api/v1/ranking-sources - List all ranking sources
 #[utoipa::path(
     get,
@@ -177,20 +222,64 @@ pub async fn get_source_rankings(
 }
 
 /// GET /api/v1/rankings - Get all rankings across all sources in one request
+///
+/// Sortable by `source_name`, `rank` and `scraped_at`. `format=csv` downloads
+/// the same rows as a spreadsheet.
 #[utoipa::path(
     get,
     path = "/api/v1/rankings",
+    params(SortParams, PaginationParams, FormatParams),
     responses(
-        (status = 200, description = "All rankings across all sources", body = Vec<AllRankingEntry>)
+        (status = 200, description = "All rankings across all sources; total count in X-Total-Count. CSV when format=csv", body = Vec<AllRankingEntry>),
+        (status = 400, description = "Invalid sort or pagination parameters")
     ),
     tag = "rankings"
 )]
 pub async fn get_all_rankings(
     State(state): State<AppState>,
-) -> ApiResult<Json<Vec<AllRankingEntry>>> {
-    let rankings = state.prospect_ranking_repo.find_all_with_source().await?;
+    Query(sort): Query<SortParams>,
+    Query(pagination): Query<PaginationParams>,
+    Query(format): Query<FormatParams>,
+) -> ApiResult<Paginated<AllRankingEntry>> {
+    let sort = sort.parse::<RankingSortField>()?;
+    let request = pagination.page_request()?;
+
+    let page = state
+        .prospect_ranking_repo
+        .find_sorted_with_source(&sort, request)
+        .await?;
+    Ok(Paginated::page(page.map(AllRankingEntry::from), request)
+        .with_format(format.format, "rankings.csv"))
+}
 
-    let response: Vec<AllRankingEntry> = rankings.into_iter().map(AllRankingEntry::from).collect();
+/// GET /api/v1/rankings/consensus - Consensus big board across all sources
+///
+/// Each prospect's ranks from every source are averaged, best average first;
+/// ties go to the better best rank.
+#[utoipa::path(
+    get,
+    path = "/api/v1/rankings/consensus",
+    params(ConsensusQuery, PaginationParams),
+    responses(
+        (status = 200, description = "Consensus big board; total count in X-Total-Count", body = Vec<ConsensusRankingResponse>),
+        (status = 400, description = "Invalid pagination parameters")
+    ),
+    tag = "rankings"
+)]
+pub async fn get_consensus_rankings(
+    State(state): State<AppState>,
+    Query(query): Query<ConsensusQuery>,
+    Query(pagination): Query<PaginationParams>,
+) -> ApiResult<Paginated<ConsensusRankingResponse>> {
+    let request = pagination.page_request()?;
 
-    Ok(Json(response))
+    let board = state
+        .prospect_ranking_repo
+        .find_consensus(query.year)
+        .await?;
+    let page = Page::from_all(board, request);
+    Ok(Paginated::page(
+        page.map(ConsensusRankingResponse::from),
+        request,
+    ))
 }
@@ -85,22 +85,95 @@ pub async fn get_player_ras(
         .await?
         .ok_or_else(|| ApiError::NotFound(format!("Player with id {} not found", player_id)))?;
 
-    // Get combine results (use first available)
+    // Merge combine, pro day and school results by source precedence
     let combine_list = state
         .combine_results_repo
         .find_by_player_id(player_id)
         .await?;
 
-    let combine = combine_list.first().ok_or_else(|| {
-        ApiError::NotFound(format!("No combine results found for player {}", player_id))
-    })?;
+    let combine = state
+        .ras_service
+        .select_combine_results(&combine_list)
+        .ok_or_else(|| {
+            ApiError::NotFound(format!("No combine results found for player {}", player_id))
+        })?;
 
     // Calculate RAS
-    let ras = state.ras_service.calculate_ras(&player, combine).await;
+    let ras = state.ras_service.calculate_ras(&player, &combine).await;
 
     Ok(Json(RasScoreResponse::from(ras)))
 }
 
+#[derive(Debug, Serialize, ToSchema)]
+pub struct PercentileProfileResponse {
+    pub player_id: Uuid,
+    /// Position group the percentiles are drawn from (e.g. EDGE, IOL)
+    pub position_group: String,
+    pub measurements: Vec<MeasurementScoreResponse>,
+}
+
+/// GET /api/v1/players/:player_id/percentiles - Positional percentile per measurement
+///
+/// One entry per measurement with percentile data for the player's position
+/// group, timed events already inverted so higher is always better. Suited to
+/// spider-chart rendering.
+#[utoipa::path(
+    get,
+    path = "/api/v1/players/{player_id}/percentiles",
+    responses(
+        (status = 200, description = "Percentile profile", body = PercentileProfileResponse),
+        (status = 404, description = "Player not found or no combine data")
+    ),
+    params(
+        ("player_id" = Uuid, Path, description = "Player ID")
+    ),
+    tag = "combine-results"
+)]
+pub async fn get_player_percentiles(
+    State(state): State<AppState>,
+    Path(player_id): Path<Uuid>,
+) -> ApiResult<Json<PercentileProfileResponse>> {
+    let player = state
+        .player_repo
+        .find_by_id(player_id)
+        .await?
+        .ok_or_else(|| ApiError::NotFound(format!("Player with id {} not found", player_id)))?;
+
+    // Same merged results as RAS
+    let combine_list = state
+        .combine_results_repo
+        .find_by_player_id(player_id)
+        .await?;
+    let combine = state
+        .ras_service
+        .select_combine_results(&combine_list)
+        .ok_or_else(|| {
+            ApiError::NotFound(format!("No combine results found for player {}", player_id))
+        })?;
+
+    let position_group = RasScoringService::map_position(&player.position);
+    let percentiles = state
+        .combine_percentile_repo
+        .find_by_position(&position_group)
+        .await?;
+
+    let measurements = RasScoringService::percentile_profile(&player, &combine, &percentiles)
+        .into_iter()
+        .map(|s| MeasurementScoreResponse {
+            measurement: s.measurement,
+            raw_value: s.raw_value,
+            percentile: s.percentile,
+            score: s.score,
+        })
+        .collect();
+
+    Ok(Json(PercentileProfileResponse {
+        player_id,
+        position_group,
+        measurements,
+    }))
+}
+
 /// GET /api/v1/combine-results/ras - Get RAS scores for all players with combine data
 #[utoipa::path(
     get,
@@ -119,20 +192,19 @@ pub async fn get_all_ras(State(state): State<AppState>) -> ApiResult<Json<Vec<Ra
     // Build a player map for O(1) lookup
     let player_map: HashMap<Uuid, _> = all_players.into_iter().map(|p| (p.id, p)).collect();
 
-    // Deduplicate: use first combine result per player (consistent with get_player_ras)
-    let mut seen_players = std::collections::HashSet::new();
+    // One merged set of results per player (consistent with get_player_ras)
+    let combine_by_player = state
+        .ras_service
+        .select_combine_results_by_player(combine_results);
     let mut ras_scores = Vec::new();
-    for cr in &combine_results {
-        if !seen_players.insert(cr.player_id) {
-            continue;
-        }
-        if let Some(player) = player_map.get(&cr.player_id) {
+    for (player_id, cr) in &combine_by_player {
+        if let Some(player) = player_map.get(player_id) {
             let ras =
                 RasScoringService::calculate_ras_with_percentiles(player, cr, &all_percentiles);
             ras_scores.push(RasScoreResponse::from(ras));
         } else {
             tracing::warn!(
-                player_id = %cr.player_id,
+                player_id = %player_id,
                 "Combine result references player_id not found in players table"
             );
         }
@@ -1,13 +1,14 @@
-use axum::extract::{Path, State};
+use axum::extract::{Path, Query, State};
 use axum::http::StatusCode;
 use axum::Json;
 use serde::{Deserialize, Serialize};
-use utoipa::ToSchema;
+use utoipa::{IntoParams, ToSchema};
 use uuid::Uuid;
 
-use domain::models::{FitGrade, ScoutingReport};
+use domain::models::{FitGrade, ScoutingReport, ScoutingReportFilter};
 
 use crate::error::{ApiError, ApiResult};
+use crate::pagination::{Paginated, PaginationParams};
 use crate::state::AppState;
 
 #[derive(Debug, Serialize, Deserialize, ToSchema)]
@@ -57,6 +58,60 @@ impl From<ScoutingReport> for ScoutingReportResponse {
     }
 }
 
+#[derive(Debug, Default, Deserialize, IntoParams)]
+pub struct ScoutingReportFilterParams {
+    /// Only reports written by this team
+    pub team_id: Option<Uuid>,
+    /// Only reports graded at least this high (0.0 - 10.0)
+    pub min_grade: Option<f64>,
+    /// Only reports with this scheme fit grade
+    pub fit_grade: Option<FitGrade>,
+    /// Only reports flagging an injury or character concern (true) or
+    /// flagging neither (false)
+    pub concerns: Option<bool>,
+}
+
+impl From<ScoutingReportFilterParams> for ScoutingReportFilter {
+    fn from(params: ScoutingReportFilterParams) -> Self {
+        ScoutingReportFilter {
+            team_id: params.team_id,
+            min_grade: params.min_grade,
+            fit_grade: params.fit_grade,
+            concerns: params.concerns,
+        }
+    }
+}
+
+/// GET /api/v1/scouting-reports - Search scouting reports, highest grade first
+#[utoipa::path(
+    get,
+    path = "/api/v1/scouting-reports",
+    params(ScoutingReportFilterParams, PaginationParams),
+    responses(
+        (status = 200, description = "Scouting reports matching the filters; total count in X-Total-Count", body = Vec<ScoutingReportResponse>),
+        (status = 400, description = "Invalid filter or pagination parameters")
+    ),
+    tag = "scouting-reports"
+)]
+pub async fn list_scouting_reports(
+    State(state): State<AppState>,
+    Query(filters): Query<ScoutingReportFilterParams>,
+    Query(pagination): Query<PaginationParams>,
+) -> ApiResult<Paginated<ScoutingReportResponse>> {
+    let filter = ScoutingReportFilter::from(filters);
+    filter.validate()?;
+    let request = pagination.page_request()?;
+
+    let page = state
+        .scouting_report_repo
+        .find_filtered(&filter, request)
+        .await?;
+    Ok(Paginated::page(
+        page.map(ScoutingReportResponse::from),
+        request,
+    ))
+}
+
 /// POST /api/v1/scouting-reports - Create new scouting report
 #[utoipa::path(
     post,
@@ -64,6 +119,7 @@ impl From<ScoutingReport> for ScoutingReportResponse {
     request_body = CreateScoutingReportRequest,
     responses(
         (status = 201, description = "Scouting report created successfully", body = ScoutingReportResponse),
+        (status = 401, description = "Missing or invalid access token"),
         (status = 400, description = "Invalid request"),
         (status = 409, description = "Scouting report for this team and player already exists")
     ),
@@ -180,6 +236,7 @@ pub async fn get_player_scouting_reports(
     request_body = UpdateScoutingReportRequest,
     responses(
         (status = 200, description = "Scouting report updated successfully", body = ScoutingReportResponse),
+        (status = 401, description = "Missing or invalid access token"),
         (status = 404, description = "Scouting report not found"),
         (status = 400, description = "Invalid request")
     ),
@@ -222,11 +279,14 @@ pub async fn update_scouting_report(
 }
 
 /// DELETE /api/v1/scouting-reports/:id - Delete scouting report
+///
+/// The report is soft-deleted and can be restored by an admin.
 #[utoipa::path(
     delete,
     path = "/api/v1/scouting-reports/{id}",
     responses(
         (status = 204, description = "Scouting report deleted successfully"),
+        (status = 401, description = "Unauthorized"),
         (status = 404, description = "Scouting report not found")
     ),
     params(
@@ -238,6 +298,29 @@ pub async fn delete_scouting_report(
     State(state): State<AppState>,
     Path(id): Path<Uuid>,
 ) -> ApiResult<StatusCode> {
-    state.scouting_report_repo.delete(id).await?;
+    state.scouting_report_repo.soft_delete(id).await?;
     Ok(StatusCode::NO_CONTENT)
 }
+
+/// POST /api/v1/admin/scouting-reports/:id/restore - Restore a soft-deleted
+/// scouting report
+#[utoipa::path(
+    post,
+    path = "/api/v1/admin/scouting-reports/{id}/restore",
+    params(
+        ("id" = Uuid, Path, description = "Scouting report ID")
+    ),
+    responses(
+        (status = 200, description = "Scouting report restored", body = ScoutingReportResponse),
+        (status = 401, description = "Unauthorized"),
+        (status = 404, description = "No deleted scouting report with this ID")
+    ),
+    tag = "admin"
+)]
+pub async fn restore_scouting_report(
+    State(state): State<AppState>,
+    Path(id): Path<Uuid>,
+) -> ApiResult<Json<ScoutingReportResponse>> {
+    let report = state.scouting_report_repo.restore(id).await?;
+    Ok(Json(ScoutingReportResponse::from(report)))
+}
@@ -1,21 +1,23 @@
-use axum::extract::State;
-use axum::http::HeaderMap;
+use axum::body::Bytes;
+use axum::extract::{Query, State};
 use axum::Json;
-use serde::Serialize;
-use utoipa::ToSchema;
+use seed_data::load_mode::LoadMode;
+use serde::{Deserialize, Serialize};
+use utoipa::{IntoParams, ToSchema};
 
-use crate::auth::verify_api_key;
 use crate::error::{ApiError, ApiResult};
 use crate::state::AppState;
 
 const PLAYERS_2026_JSON: &str = include_str!("../../../../data/players_2026.json");
 const TEAMS_NFL_JSON: &str = include_str!("../../../../data/teams_nfl.json");
+const TEAM_NEEDS_2026_JSON: &str = include_str!("../../../../data/team_needs_2026.json");
 const TEAM_SEASONS_2025_JSON: &str = include_str!("../../../../data/team_seasons_2025.json");
 const RANKINGS_TANKATHON_JSON: &str = include_str!("../../../../data/rankings/tankathon_2026.json");
 const RANKINGS_WALTERFOOTBALL_JSON: &str =
     include_str!("../../../../data/rankings/walterfootball_2026.json");
 const COMBINE_PERCENTILES_JSON: &str = include_str!("../../../../data/combine_percentiles.json");
 const COMBINE_2026_JSON: &str = include_str!("../../../../data/combine_2026.json");
+const DRAFT_ORDER_2026_JSON: &str = include_str!("../../../../data/draft_order_2026.json");
 const FELDMAN_FREAKS_2026_JSON: &str = include_str!("../../../../data/feldman_freaks_2026.json");
 
 #[derive(Debug, Serialize, ToSchema)]
@@ -28,16 +30,52 @@ pub struct SeedResponse {
     pub validation_warnings: Vec<String>,
 }
 
-/// Seed the database with embedded 2026 player data
+#[derive(Debug, Default, Deserialize, IntoParams)]
+pub struct SeedQuery {
+    /// `insert` (default) skips rows that already exist; `upsert` updates
+    /// them to match the seed file
+    #[param(value_type = Option<String>)]
+    pub mode: Option<LoadMode>,
+}
+
+/// Parses the seed file posted as the request body, falling back to the
+/// embedded file when the body is empty
+fn posted_or_embedded<T>(
+    body: &Bytes,
+    embedded: &str,
+    what: &str,
+    parse: fn(&str) -> anyhow::Result<T>,
+) -> ApiResult<T> {
+    if body.is_empty() {
+        return parse(embedded).map_err(|e| {
+            ApiError::InternalError(format!("Failed to parse embedded {} data: {}", what, e))
+        });
+    }
+    parse_posted(body, what, parse)
+}
+
+/// Parses a seed file posted as the request body
+fn parse_posted<T>(body: &Bytes, what: &str, parse: fn(&str) -> anyhow::Result<T>) -> ApiResult<T> {
+    let json = std::str::from_utf8(body)
+        .map_err(|_| ApiError::BadRequest(format!("The {} file must be UTF-8 JSON", what)))?;
+    parse(json).map_err(|e| ApiError::BadRequest(format!("Invalid {} file: {}", what, e)))
+}
+
+/// Seed the database with player data
+///
+/// Loads the players file posted as the request body, in the `players_2026.json` schema, or the embedded 2026 data when the body is empty.
 ///
-/// Requires the `X-Seed-Api-Key` header matching the server's `SEED_API_KEY` environment variable.
-/// Returns 404 if `SEED_API_KEY` is not configured (endpoint is hidden).
+/// Requires an admin access token, or the `X-Seed-Api-Key` header matching the server's `SEED_API_KEY` environment variable.
+/// Other callers get 404 when `SEED_API_KEY` is not configured (endpoint is hidden).
 #[utoipa::path(
     post,
     path = "/api/v1/admin/seed-players",
     tag = "admin",
+    params(SeedQuery),
+    request_body(content = Option<serde_json::Value>, description = "Players file; the embedded data is used when empty"),
     responses(
         (status = 200, description = "Players seeded successfully", body = SeedResponse),
+        (status = 400, description = "The posted file could not be parsed"),
         (status = 401, description = "Unauthorized - invalid or missing API key"),
         (status = 404, description = "Not found - endpoint not enabled"),
         (status = 500, description = "Internal server error"),
@@ -48,32 +86,15 @@ pub struct SeedResponse {
 )]
 pub async fn seed_players(
     State(state): State<AppState>,
-    headers: HeaderMap,
+    Query(query): Query<SeedQuery>,
+    body: Bytes,
 ) -> ApiResult<Json<SeedResponse>> {
-    // If SEED_API_KEY is not configured, hide the endpoint entirely
-    let expected_key = match &state.seed_api_key {
-        Some(key) => key,
-        None => {
-            return Err(ApiError::NotFound("Not found".to_string()));
-        }
-    };
-
-    // Validate the API key from the request header using constant-time comparison
-    let provided_key = headers
-        .get("X-Seed-Api-Key")
-        .and_then(|v| v.to_str().ok())
-        .unwrap_or("");
-
-    if !verify_api_key(provided_key, expected_key) {
-        return Err(ApiError::Unauthorized(
-            "Invalid or missing API key".to_string(),
-        ));
-    }
-
-    // Parse the embedded player data
-    let data = seed_data::loader::parse_player_json(PLAYERS_2026_JSON).map_err(|e| {
-        ApiError::InternalError(format!("Failed to parse embedded player data: {}", e))
-    })?;
+    let data = posted_or_embedded(
+        &body,
+        PLAYERS_2026_JSON,
+        "player",
+        seed_data::loader::parse_player_json,
+    )?;
 
     // Validate the data
     let validation = seed_data::validator::validate_player_data(&data);
@@ -91,20 +112,26 @@ pub async fn seed_players(
     }
 
     // Load players into the database
-    let stats = seed_data::loader::load_players(&data, state.player_repo.as_ref())
-        .await
-        .map_err(|e| ApiError::InternalError(format!("Failed to load players: {}", e)))?;
+    let stats = seed_data::loader::load_players(
+        &data,
+        state.player_repo.as_ref(),
+        query.mode.unwrap_or_default(),
+    )
+    .await
+    .map_err(|e| ApiError::InternalError(format!("Failed to load players: {}", e)))?;
 
     let message = format!(
-        "Seeding complete: {} succeeded, {} skipped, {} errors",
+        "Seeding complete: {} succeeded, {} updated, {} unchanged, {} skipped, {} errors",
         stats.success,
+        stats.updated,
+        stats.unchanged,
         stats.skipped,
         stats.errors.len()
     );
 
     Ok(Json(SeedResponse {
         message,
-        success_count: stats.success,
+        success_count: stats.success + stats.updated,
         skipped_count: stats.skipped,
         error_count: stats.errors.len(),
         errors: stats.errors,
@@ -112,16 +139,21 @@ pub async fn seed_players(
     }))
 }
 
-/// Seed the database with embedded NFL team data
+/// Seed the database with NFL team data
+///
+/// Loads the teams file posted as the request body, in the `teams_nfl.json` schema, or the embedded data when the body is empty.
 ///
-/// Requires the `X-Seed-Api-Key` header matching the server's `SEED_API_KEY` environment variable.
-/// Returns 404 if `SEED_API_KEY` is not configured (endpoint is hidden).
+/// Requires an admin access token, or the `X-Seed-Api-Key` header matching the server's `SEED_API_KEY` environment variable.
+/// Other callers get 404 when `SEED_API_KEY` is not configured (endpoint is hidden).
 #[utoipa::path(
     post,
     path = "/api/v1/admin/seed-teams",
     tag = "admin",
+    params(SeedQuery),
+    request_body(content = Option<serde_json::Value>, description = "Teams file; the embedded data is used when empty"),
     responses(
         (status = 200, description = "Teams seeded successfully", body = SeedResponse),
+        (status = 400, description = "The posted file could not be parsed"),
         (status = 401, description = "Unauthorized - invalid or missing API key"),
         (status = 404, description = "Not found - endpoint not enabled"),
         (status = 500, description = "Internal server error"),
@@ -132,32 +164,15 @@ pub async fn seed_players(
 )]
 pub async fn seed_teams(
     State(state): State<AppState>,
-    headers: HeaderMap,
+    Query(query): Query<SeedQuery>,
+    body: Bytes,
 ) -> ApiResult<Json<SeedResponse>> {
-    // If SEED_API_KEY is not configured, hide the endpoint entirely
-    let expected_key = match &state.seed_api_key {
-        Some(key) => key,
-        None => {
-            return Err(ApiError::NotFound("Not found".to_string()));
-        }
-    };
-
-    // Validate the API key from the request header using constant-time comparison
-    let provided_key = headers
-        .get("X-Seed-Api-Key")
-        .and_then(|v| v.to_str().ok())
-        .unwrap_or("");
-
-    if !verify_api_key(provided_key, expected_key) {
-        return Err(ApiError::Unauthorized(
-            "Invalid or missing API key".to_string(),
-        ));
-    }
-
-    // Parse the embedded team data
-    let data = seed_data::team_loader::parse_team_json(TEAMS_NFL_JSON).map_err(|e| {
-        ApiError::InternalError(format!("Failed to parse embedded team data: {}", e))
-    })?;
+    let data = posted_or_embedded(
+        &body,
+        TEAMS_NFL_JSON,
+        "team",
+        seed_data::team_loader::parse_team_json,
+    )?;
 
     // Validate the data
     let validation = seed_data::team_validator::validate_team_data(&data);
@@ -175,20 +190,26 @@ pub async fn seed_teams(
     }
 
     // Load teams into the database
-    let stats = seed_data::team_loader::load_teams(&data, state.team_repo.as_ref())
-        .await
-        .map_err(|e| ApiError::InternalError(format!("Failed to load teams: {}", e)))?;
+    let stats = seed_data::team_loader::load_teams(
+        &data,
+        state.team_repo.as_ref(),
+        query.mode.unwrap_or_default(),
+    )
+    .await
+    .map_err(|e| ApiError::InternalError(format!("Failed to load teams: {}", e)))?;
 
     let message = format!(
-        "Seeding complete: {} succeeded, {} skipped, {} errors",
+        "Seeding complete: {} succeeded, {} updated, {} unchanged, {} skipped, {} errors",
         stats.success,
+        stats.updated,
+        stats.unchanged,
         stats.skipped,
         stats.errors.len()
     );
 
     Ok(Json(SeedResponse {
         message,
-        success_count: stats.success,
+        success_count: stats.success + stats.updated,
         skipped_count: stats.skipped,
         error_count: stats.errors.len(),
         errors: stats.errors,
@@ -196,16 +217,20 @@ pub async fn seed_teams(
     }))
 }
 
-/// Seed the database with embedded 2025 team season data
+/// Seed the database with team season data
+///
+/// Loads the team seasons file posted as the request body, in the `team_seasons_2025.json` schema, or the embedded 2025 data when the body is empty. Seasons are upserted by team and year.
 ///
-/// Requires the `X-Seed-Api-Key` header matching the server's `SEED_API_KEY` environment variable.
-/// Returns 404 if `SEED_API_KEY` is not configured (endpoint is hidden).
+/// Requires an admin access token, or the `X-Seed-Api-Key` header matching the server's `SEED_API_KEY` environment variable.
+/// Other callers get 404 when `SEED_API_KEY` is not configured (endpoint is hidden).
 #[utoipa::path(
     post,
     path = "/api/v1/admin/seed-team-seasons",
     tag = "admin",
+    request_body(content = Option<serde_json::Value>, description = "Team seasons file; the embedded data is used when empty"),
     responses(
         (status = 200, description = "Team seasons seeded successfully", body = SeedResponse),
+        (status = 400, description = "The posted file could not be parsed"),
         (status = 401, description = "Unauthorized - invalid or missing API key"),
         (status = 404, description = "Not found - endpoint not enabled"),
         (status = 500, description = "Internal server error"),
@@ -216,33 +241,14 @@ pub async fn seed_teams(
 )]
 pub async fn seed_team_seasons(
     State(state): State<AppState>,
-    headers: HeaderMap,
+    body: Bytes,
 ) -> ApiResult<Json<SeedResponse>> {
-    // If SEED_API_KEY is not configured, hide the endpoint entirely
-    let expected_key = match &state.seed_api_key {
-        Some(key) => key,
-        None => {
-            return Err(ApiError::NotFound("Not found".to_string()));
-        }
-    };
-
-    // Validate the API key from the request header using constant-time comparison
-    let provided_key = headers
-        .get("X-Seed-Api-Key")
-        .and_then(|v| v.to_str().ok())
-        .unwrap_or("");
-
-    if !verify_api_key(provided_key, expected_key) {
-        return Err(ApiError::Unauthorized(
-            "Invalid or missing API key".to_string(),
-        ));
-    }
-
-    // Parse the embedded team season data
-    let data = seed_data::team_season_loader::parse_team_season_json(TEAM_SEASONS_2025_JSON)
-        .map_err(|e| {
-            ApiError::InternalError(format!("Failed to parse embedded team season data: {}", e))
-        })?;
+    let data = posted_or_embedded(
+        &body,
+        TEAM_SEASONS_2025_JSON,
+        "team season",
+        seed_data::team_season_loader::parse_team_season_json,
+    )?;
 
     // Validate the data
     let validation = seed_data::team_season_validator::validate_team_season_data(&data);
@@ -286,16 +292,92 @@ pub async fn seed_team_seasons(
     }))
 }
 
-/// Seed the database with embedded prospect ranking data (Tankathon + WalterFootball)
+/// Seed the database with team need data
+///
+/// Loads the team needs file posted as the request body, in the `team_needs_2026.json` schema, or the embedded 2026 data when the body is empty. Each listed team's needs are replaced.
+///
+/// Requires an admin access token, or the `X-Seed-Api-Key` header matching the server's `SEED_API_KEY` environment variable.
+/// Other callers get 404 when `SEED_API_KEY` is not configured (endpoint is hidden).
+#[utoipa::path(
+    post,
+    path = "/api/v1/admin/seed-team-needs",
+    tag = "admin",
+    request_body(content = Option<serde_json::Value>, description = "Team needs file; the embedded data is used when empty"),
+    responses(
+        (status = 200, description = "Team needs seeded successfully", body = SeedResponse),
+        (status = 400, description = "The posted file could not be parsed"),
+        (status = 401, description = "Unauthorized - invalid or missing API key"),
+        (status = 404, description = "Not found - endpoint not enabled"),
+        (status = 500, description = "Internal server error"),
+    ),
+    security(
+        ("api_key" = [])
+    )
+)]
+pub async fn seed_team_needs(
+    State(state): State<AppState>,
+    body: Bytes,
+) -> ApiResult<Json<SeedResponse>> {
+    let data = posted_or_embedded(
+        &body,
+        TEAM_NEEDS_2026_JSON,
+        "team need",
+        seed_data::team_need_loader::parse_team_need_json,
+    )?;
+
+    let validation = seed_data::team_need_validator::validate_team_need_data(&data);
+    let validation_warnings = validation.warnings;
+
+    if !validation.valid {
+        return Ok(Json(SeedResponse {
+            message: "Seeding aborted due to validation errors".to_string(),
+            success_count: 0,
+            skipped_count: 0,
+            error_count: validation.errors.len(),
+            errors: validation.errors,
+            validation_warnings,
+        }));
+    }
+
+    let stats = seed_data::team_need_loader::load_team_needs(
+        &data,
+        state.team_repo.as_ref(),
+        state.team_need_repo.as_ref(),
+    )
+    .await
+    .map_err(|e| ApiError::InternalError(format!("Failed to load team needs: {}", e)))?;
+
+    let message = format!(
+        "Seeding complete: {} teams processed, {} needs created, {} errors",
+        stats.teams_processed,
+        stats.needs_created,
+        stats.errors.len()
+    );
+
+    Ok(Json(SeedResponse {
+        message,
+        success_count: stats.needs_created,
+        skipped_count: stats.teams_skipped,
+        error_count: stats.errors.len(),
+        errors: stats.errors,
+        validation_warnings,
+    }))
+}
+
+/// Seed the database with prospect ranking data
+///
+/// Loads the rankings file posted as the request body, under the source named in its `meta.source`, or the embedded Tankathon and WalterFootball data when the body is empty.
 ///
-/// Requires the `X-Seed-Api-Key` header matching the server's `SEED_API_KEY` environment variable.
-/// Returns 404 if `SEED_API_KEY` is not configured (endpoint is hidden).
+/// Requires an admin access token, or the `X-Seed-Api-Key` header matching the server's `SEED_API_KEY` environment variable.
+/// Other callers get 404 when `SEED_API_KEY` is not configured (endpoint is hidden).
 #[utoipa::path(
     post,
     path = "/api/v1/admin/seed-rankings",
     tag = "admin",
+    request_body(content = Option<serde_json::Value>, description = "Rankings file; the embedded data is used when empty"),
     responses(
         (status = 200, description = "Rankings seeded successfully", body = SeedResponse),
+        (status = 400, description = "The posted file could not be parsed"),
         (status = 401, description = "Unauthorized - invalid or missing API key"),
         (status = 404, description = "Not found - endpoint not enabled"),
         (status = 500, description = "Internal server error"),
@@ -306,50 +388,44 @@ pub async fn seed_team_seasons(
 )]
 pub async fn seed_rankings(
     State(state): State<AppState>,
-    headers: HeaderMap,
+    body: Bytes,
 ) -> ApiResult<Json<SeedResponse>> {
-    // If SEED_API_KEY is not configured, hide the endpoint entirely
-    let expected_key = match &state.seed_api_key {
-        Some(key) => key,
-        None => {
-            return Err(ApiError::NotFound("Not found".to_string()));
+    // A posted file is loaded under its own source; otherwise the embedded
+    // Tankathon and WalterFootball boards are
+    let ranking_files = if body.is_empty() {
+        let mut files = Vec::new();
+        for (label, json) in [
+            ("Tankathon", RANKINGS_TANKATHON_JSON),
+            ("WalterFootball", RANKINGS_WALTERFOOTBALL_JSON),
+        ] {
+            let data =
+                seed_data::scouting_report_loader::parse_ranking_json(json).map_err(|e| {
+                    ApiError::InternalError(format!(
+                        "Failed to parse embedded {} ranking data: {}",
+                        label, e
+                    ))
+                })?;
+            files.push((label.to_string(), data));
         }
+        files
+    } else {
+        let data = parse_posted(
+            &body,
+            "rankings",
+            seed_data::scouting_report_loader::parse_ranking_json,
+        )?;
+        vec![(data.meta.source.clone(), data)]
     };
 
-    // Validate the API key from the request header using constant-time comparison
-    let provided_key = headers
-        .get("X-Seed-Api-Key")
-        .and_then(|v| v.to_str().ok())
-        .unwrap_or("");
-
-    if !verify_api_key(provided_key, expected_key) {
-        return Err(ApiError::Unauthorized(
-            "Invalid or missing API key".to_string(),
-        ));
-    }
-
-    let ranking_files = [
-        ("Tankathon", RANKINGS_TANKATHON_JSON),
-        ("WalterFootball", RANKINGS_WALTERFOOTBALL_JSON),
-    ];
-
     let mut total_rankings_inserted: usize = 0;
     let mut total_prospects_matched: usize = 0;
     let mut total_prospects_discovered: usize = 0;
     let mut all_errors: Vec<String> = Vec::new();
     let mut all_warnings: Vec<String> = Vec::new();
 
-    for (label, json) in &ranking_files {
-        // Parse the embedded ranking data
-        let data = seed_data::scouting_report_loader::parse_ranking_json(json).map_err(|e| {
-            ApiError::InternalError(format!(
-                "Failed to parse embedded {} ranking data: {}",
-                label, e
-            ))
-        })?;
-
+    for (label, data) in &ranking_files {
         // Validate the data
-        let validation = seed_data::rankings_validator::validate_ranking_data(&data);
+        let validation = seed_data::rankings_validator::validate_ranking_data(data);
         all_warnings.extend(
             validation
                 .warnings
@@ -369,12 +445,15 @@ pub async fn seed_rankings(
 
         // Load rankings into the database
         let stats = seed_data::rankings_loader::load_rankings(
-            &data,
+            data,
             state.pool(),
+            db::DEFAULT_BATCH_SIZE,
             state.player_repo.as_ref(),
             state.team_repo.as_ref(),
             state.ranking_source_repo.as_ref(),
             state.scouting_report_repo.as_ref(),
+            &seed_data::grade_generator::GradeProfiles::default(),
+            seed_data::DEFAULT_CONCURRENCY,
         )
         .await
         .map_err(|e| {
@@ -392,6 +471,11 @@ pub async fn seed_rankings(
         );
     }
 
+    // The loader writes rankings in its own transaction, around the cache
+    if let Some(cache) = &state.cache {
+        cache.invalidate(db::cache::RANKINGS).await;
+    }
+
     let message = format!(
         "Rankings seeding complete: {} rankings inserted, {} prospects matched, {} new prospects discovered, {} errors",
         total_rankings_inserted,
@@ -426,26 +510,7 @@ pub async fn seed_rankings(
 )]
 pub async fn seed_combine_percentiles(
     State(state): State<AppState>,
-    headers: HeaderMap,
 ) -> ApiResult<Json<SeedResponse>> {
-    let expected_key = match &state.seed_api_key {
-        Some(key) => key,
-        None => {
-            return Err(ApiError::NotFound("Not found".to_string()));
-        }
-    };
-
-    let provided_key = headers
-        .get("X-Seed-Api-Key")
-        .and_then(|v| v.to_str().ok())
-        .unwrap_or("");
-
-    if !verify_api_key(provided_key, expected_key) {
-        return Err(ApiError::Unauthorized(
-            "Invalid or missing API key".to_string(),
-        ));
-    }
-
     let data = seed_data::percentile_loader::parse_percentile_json(COMBINE_PERCENTILES_JSON)
         .map_err(|e| ApiError::InternalError(format!("Failed to parse percentile data: {}", e)))?;
 
@@ -472,13 +537,17 @@ pub async fn seed_combine_percentiles(
     }))
 }
 
-/// Seed the database with embedded 2026 NFL Combine results
+/// Seed the database with NFL Combine results
+///
+/// Loads the combine file posted as the request body, in the `combine_2026.json` schema, or the embedded 2026 data when the body is empty.
 #[utoipa::path(
     post,
     path = "/api/v1/admin/seed-combine-data",
     tag = "admin",
+    request_body(content = Option<serde_json::Value>, description = "Combine file; the embedded data is used when empty"),
     responses(
         (status = 200, description = "Combine data seeded successfully", body = SeedResponse),
+        (status = 400, description = "The posted file could not be parsed"),
         (status = 401, description = "Unauthorized - invalid or missing API key"),
         (status = 404, description = "Not found - endpoint not enabled"),
     ),
@@ -488,28 +557,14 @@ pub async fn seed_combine_percentiles(
 )]
 pub async fn seed_combine_data(
     State(state): State<AppState>,
-    headers: HeaderMap,
+    body: Bytes,
 ) -> ApiResult<Json<SeedResponse>> {
-    let expected_key = match &state.seed_api_key {
-        Some(key) => key,
-        None => {
-            return Err(ApiError::NotFound("Not found".to_string()));
-        }
-    };
-
-    let provided_key = headers
-        .get("X-Seed-Api-Key")
-        .and_then(|v| v.to_str().ok())
-        .unwrap_or("");
-
-    if !verify_api_key(provided_key, expected_key) {
-        return Err(ApiError::Unauthorized(
-            "Invalid or missing API key".to_string(),
-        ));
-    }
-
-    let data = seed_data::combine_loader::parse_combine_json(COMBINE_2026_JSON)
-        .map_err(|e| ApiError::InternalError(format!("Failed to parse combine data: {}", e)))?;
+    let data = posted_or_embedded(
+        &body,
+        COMBINE_2026_JSON,
+        "combine",
+        seed_data::combine_loader::parse_combine_json,
+    )?;
 
     let stats = seed_data::combine_loader::load_combine_data(
         &data,
@@ -537,16 +592,20 @@ pub async fn seed_combine_data(
     }))
 }
 
-/// Seed the database with embedded Feldman Freaks list data for 2026
+/// Seed the database with the draft order
 ///
-/// Requires the `X-Seed-Api-Key` header matching the server's `SEED_API_KEY` environment variable.
-/// Returns 404 if `SEED_API_KEY` is not configured (endpoint is hidden).
+/// Loads the draft order file posted as the request body, in the `draft_order_2026.json` schema, or the embedded 2026 data when the body is empty. The year's not-started realistic draft has its picks replaced; without one, a new realistic draft is created.
+///
+/// Requires an admin access token, or the `X-Seed-Api-Key` header matching the server's `SEED_API_KEY` environment variable.
+/// Other callers get 404 when `SEED_API_KEY` is not configured (endpoint is hidden).
 #[utoipa::path(
     post,
-    path = "/api/v1/admin/seed-feldman-freaks",
+    path = "/api/v1/admin/seed-draft-order",
     tag = "admin",
+    request_body(content = Option<serde_json::Value>, description = "Draft order file; the embedded data is used when empty"),
     responses(
-        (status = 200, description = "Feldman Freaks seeded successfully", body = SeedResponse),
+        (status = 200, description = "Draft order seeded successfully", body = SeedResponse),
+        (status = 400, description = "The posted file could not be parsed"),
         (status = 401, description = "Unauthorized - invalid or missing API key"),
         (status = 404, description = "Not found - endpoint not enabled"),
         (status = 500, description = "Internal server error"),
@@ -555,28 +614,76 @@ pub async fn seed_combine_data(
         ("api_key" = [])
     )
 )]
-pub async fn seed_feldman_freaks(
+pub async fn seed_draft_order(
     State(state): State<AppState>,
-    headers: HeaderMap,
+    body: Bytes,
 ) -> ApiResult<Json<SeedResponse>> {
-    let expected_key = match &state.seed_api_key {
-        Some(key) => key,
-        None => {
-            return Err(ApiError::NotFound("Not found".to_string()));
-        }
-    };
-
-    let provided_key = headers
-        .get("X-Seed-Api-Key")
-        .and_then(|v| v.to_str().ok())
-        .unwrap_or("");
+    let data = posted_or_embedded(
+        &body,
+        DRAFT_ORDER_2026_JSON,
+        "draft order",
+        seed_data::draft_order_loader::parse_draft_order_json,
+    )?;
+
+    let validation = seed_data::draft_order_validator::validate_draft_order_data(&data);
+    let validation_warnings = validation.warnings;
 
-    if !verify_api_key(provided_key, expected_key) {
-        return Err(ApiError::Unauthorized(
-            "Invalid or missing API key".to_string(),
-        ));
+    if !validation.valid {
+        return Ok(Json(SeedResponse {
+            message: "Seeding aborted due to validation errors".to_string(),
+            success_count: 0,
+            skipped_count: 0,
+            error_count: validation.errors.len(),
+            errors: validation.errors,
+            validation_warnings,
+        }));
     }
 
+    let stats = seed_data::draft_order_loader::load_draft_order(
+        &data,
+        state.team_repo.as_ref(),
+        state.draft_repo.as_ref(),
+        state.draft_pick_repo.as_ref(),
+    )
+    .await
+    .map_err(|e| ApiError::InternalError(format!("Failed to load draft order: {}", e)))?;
+
+    let message = format!(
+        "Draft order seeding complete: {} picks created, {} teams skipped, {} errors",
+        stats.picks_created,
+        stats.teams_skipped,
+        stats.errors.len()
+    );
+
+    Ok(Json(SeedResponse {
+        message,
+        success_count: stats.picks_created,
+        skipped_count: stats.teams_skipped,
+        error_count: stats.errors.len(),
+        errors: stats.errors,
+        validation_warnings,
+    }))
+}
+
+/// Seed the database with embedded Feldman Freaks list data for 2026
+///
+/// Requires an admin access token, or the `X-Seed-Api-Key` header matching the server's `SEED_API_KEY` environment variable.
+/// Other callers get 404 when `SEED_API_KEY` is not configured (endpoint is hidden).
+#[utoipa::path(
+    post,
+    path = "/api/v1/admin/seed-feldman-freaks",
+    tag = "admin",
+    responses(
+        (status = 200, description = "Feldman Freaks seeded successfully", body = SeedResponse),
+        (status = 401, description = "Unauthorized - invalid or missing API key"),
+        (status = 404, description = "Not found - endpoint not enabled"),
+        (status = 500, description = "Internal server error"),
+    ),
+    security(
+        ("api_key" = [])
+    )
+)]
+pub async fn seed_feldman_freaks(State(state): State<AppState>) -> ApiResult<Json<SeedResponse>> {
     // Parse the embedded Feldman Freaks data
     let data = seed_data::feldman_freak_loader::parse_freaks_json(FELDMAN_FREAKS_2026_JSON)
         .map_err(|e| {
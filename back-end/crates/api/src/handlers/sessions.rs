@@ -1,18 +1,34 @@
 use axum::{
-    extract::{Path, State},
+    extract::{Path, Query, State},
     http::StatusCode,
     Json,
 };
+use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 use std::sync::atomic::{AtomicBool, Ordering};
 use std::sync::Arc;
 use uuid::Uuid;
 
-use crate::error::ApiResult;
+use crate::auth::AuthUser;
+use crate::error::{ApiError, ApiResult};
+use crate::handlers::adp::record_adp;
 use crate::handlers::drafts::DraftPickResponse;
+use crate::handlers::participants::authorize_participant;
+use crate::outbox;
 use crate::state::AppState;
-use domain::models::{ChartType, DraftEvent, DraftSession};
+use domain::models::{
+    BundlePlayer, BundleTeam, ChartType, DraftEvent, DraftPick, DraftSession, SessionBundle,
+    SessionSettingsUpdate, Team, TradeStatus, SESSION_BUNDLE_VERSION,
+};
+use domain::services::{ProjectionMismatch, SessionProjector};
+
+/// Events returned per page when the feed is read with a cursor but no limit
+const DEFAULT_EVENTS_LIMIT: i64 = 100;
+const MAX_EVENTS_LIMIT: i64 = 1000;
+
+/// How long an auto-pick run holds picks before sending them as one batch
+const PICK_BATCH_FLUSH_INTERVAL: std::time::Duration = std::time::Duration::from_secs(1);
 
 // DTOs for session endpoints
 
@@ -29,6 +45,9 @@ pub struct CreateSessionRequest {
     pub chart_type: ChartType,
     #[serde(default)]
     pub controlled_team_ids: Vec<Uuid>,
+    /// Start the session automatically at this time
+    #[serde(default)]
+    pub scheduled_start_at: Option<DateTime<Utc>>,
 }
 
 #[derive(Debug, Serialize, Deserialize)]
@@ -43,11 +62,19 @@ pub struct SessionResponse {
     pub controlled_team_ids: Vec<Uuid>,
     pub started_at: Option<String>,
     pub completed_at: Option<String>,
+    pub scheduled_start_at: Option<String>,
+    /// Time the current pick has spent paused, including an ongoing pause
+    pub elapsed_paused_ms: i64,
+    /// Seconds left on the current pick's clock
+    pub time_remaining_seconds: i32,
 }
 
 impl From<DraftSession> for SessionResponse {
     fn from(session: DraftSession) -> Self {
+        let now = Utc::now();
         Self {
+            elapsed_paused_ms: session.paused_ms_at(now),
+            time_remaining_seconds: session.time_remaining_at(now),
             id: session.id,
             draft_id: session.draft_id,
             status: session.status.to_string(),
@@ -58,6 +85,7 @@ impl From<DraftSession> for SessionResponse {
             controlled_team_ids: session.controlled_team_ids,
             started_at: session.started_at.map(|dt| dt.to_rfc3339()),
             completed_at: session.completed_at.map(|dt| dt.to_rfc3339()),
+            scheduled_start_at: session.scheduled_start_at.map(|dt| dt.to_rfc3339()),
         }
     }
 }
@@ -83,11 +111,50 @@ impl From<DraftEvent> for EventResponse {
     }
 }
 
+#[derive(Debug, Serialize, Deserialize)]
+pub struct PresenceResponse {
+    pub session_id: Uuid,
+    #[serde(flatten)]
+    pub presence: websocket::SessionPresence,
+}
+
+/// All fields are optional; omitted settings keep their current value
+#[derive(Debug, Serialize, Deserialize)]
+pub struct UpdateSessionRequest {
+    pub time_per_pick_seconds: Option<i32>,
+    pub auto_pick_enabled: Option<bool>,
+    pub chart_type: Option<ChartType>,
+    pub controlled_team_ids: Option<Vec<Uuid>>,
+}
+
+/// Validate all controlled team IDs are participants in this draft
+async fn validate_controlled_teams(
+    state: &AppState,
+    draft_id: Uuid,
+    team_ids: &[Uuid],
+) -> ApiResult<()> {
+    for team_id in team_ids {
+        let picks = state
+            .draft_pick_repo
+            .find_by_draft_and_team(draft_id, *team_id)
+            .await?;
+        if picks.is_empty() {
+            return Err(domain::errors::DomainError::ValidationError(format!(
+                "Team {} does not have picks in draft {}",
+                team_id, draft_id
+            ))
+            .into());
+        }
+    }
+    Ok(())
+}
+
 // Handlers
 
 /// POST /api/v1/sessions
 pub async fn create_session(
     State(state): State<AppState>,
+    _user: AuthUser,
     Json(req): Json<CreateSessionRequest>,
 ) -> ApiResult<(StatusCode, Json<SessionResponse>)> {
     // Validate draft exists
@@ -111,29 +178,19 @@ pub async fn create_session(
         .into());
     }
 
-    // Validate all controlled team IDs are participants in this draft
-    for team_id in &req.controlled_team_ids {
-        let picks = state
-            .draft_pick_repo
-            .find_by_draft_and_team(req.draft_id, *team_id)
-            .await?;
-        if picks.is_empty() {
-            return Err(domain::errors::DomainError::ValidationError(format!(
-                "Team {} does not have picks in draft {}",
-                team_id, req.draft_id
-            ))
-            .into());
-        }
-    }
+    validate_controlled_teams(&state, req.draft_id, &req.controlled_team_ids).await?;
 
     // Create session
-    let session = DraftSession::new(
+    let mut session = DraftSession::new(
         req.draft_id,
         req.time_per_pick_seconds,
         req.auto_pick_enabled,
         req.chart_type,
         req.controlled_team_ids.clone(),
     )?;
+    if let Some(at) = req.scheduled_start_at {
+        session.schedule_start(at)?;
+    }
 
     let created = state.session_repo.create(&session).await?;
 
@@ -146,6 +203,7 @@ pub async fn create_session(
             "auto_pick_enabled": req.auto_pick_enabled,
             "chart_type": req.chart_type,
             "controlled_team_ids": req.controlled_team_ids,
+            "scheduled_start_at": req.scheduled_start_at,
         }),
     );
     state.event_repo.create(&event).await?;
@@ -183,11 +241,101 @@ pub async fn get_session(
     Ok(Json(session.into()))
 }
 
+/// PATCH /api/v1/sessions/:id
+/// Changes session settings before the draft starts or while it is paused.
+pub async fn update_session(
+    State(state): State<AppState>,
+    user: AuthUser,
+    Path(id): Path<Uuid>,
+    Json(req): Json<UpdateSessionRequest>,
+) -> ApiResult<Json<SessionResponse>> {
+    authorize_participant(&state, &user, id, None).await?;
+
+    let lock = state
+        .session_locks
+        .entry(id)
+        .or_insert_with(|| std::sync::Arc::new(tokio::sync::Mutex::new(())))
+        .clone();
+    let _guard = lock.try_lock().map_err(|_| {
+        domain::errors::DomainError::InvalidState(
+            "Session is being modified by another request".to_string(),
+        )
+    })?;
+
+    let mut session = state
+        .session_repo
+        .find_by_id(id)
+        .await?
+        .ok_or_else(|| domain::errors::DomainError::NotFound(format!("Session {}", id)))?;
+
+    if let Some(team_ids) = &req.controlled_team_ids {
+        validate_controlled_teams(&state, session.draft_id, team_ids).await?;
+
+        // Teams claimed by participants must stay user-controlled
+        for participant in state.participant_repo.list_by_session(id).await? {
+            if let Some(team_id) = participant
+                .team_ids
+                .iter()
+                .find(|team_id| !team_ids.contains(team_id))
+            {
+                return Err(domain::errors::DomainError::ValidationError(format!(
+                    "Team {} is controlled by participant '{}'",
+                    team_id, participant.display_name
+                ))
+                .into());
+            }
+        }
+    }
+
+    let update = SessionSettingsUpdate {
+        time_per_pick_seconds: req.time_per_pick_seconds,
+        auto_pick_enabled: req.auto_pick_enabled,
+        chart_type: req.chart_type,
+        controlled_team_ids: req.controlled_team_ids,
+    };
+    session.update_settings(&update)?;
+
+    let mut changes = serde_json::Map::new();
+    if let Some(seconds) = update.time_per_pick_seconds {
+        changes.insert("time_per_pick_seconds".to_string(), seconds.into());
+    }
+    if let Some(enabled) = update.auto_pick_enabled {
+        changes.insert("auto_pick_enabled".to_string(), enabled.into());
+    }
+    if let Some(chart_type) = update.chart_type {
+        changes.insert("chart_type".to_string(), serde_json::json!(chart_type));
+    }
+    if update.controlled_team_ids.is_some() {
+        changes.insert(
+            "controlled_team_ids".to_string(),
+            serde_json::json!(session.controlled_team_ids),
+        );
+    }
+    let event = DraftEvent::settings_changed(id, changes.into());
+    let updated = state
+        .session_repo
+        .update_with_events(&session, &[event])
+        .await?;
+
+    Ok(Json(updated.into()))
+}
+
 /// POST /api/v1/sessions/:id/start
 pub async fn start_session(
     State(state): State<AppState>,
+    user: AuthUser,
     Path(id): Path<Uuid>,
 ) -> ApiResult<Json<SessionResponse>> {
+    authorize_participant(&state, &user, id, None).await?;
+
+    let updated = begin_session(&state, id).await?;
+    Ok(Json(updated.into()))
+}
+
+/// Starts or resumes a session, moving its draft to InProgress on the first
+/// start, then records the event and notifies connected clients. Shared by
+/// the start endpoint and the scheduled-start task.
+pub(crate) async fn begin_session(state: &AppState, id: Uuid) -> ApiResult<DraftSession> {
     let lock = state
         .session_locks
         .entry(id)
@@ -220,33 +368,43 @@ pub async fn start_session(
         draft.start()?;
     }
 
-    // Atomically update both draft status and session in a single transaction
+    // Atomically update both draft status and session, and record the
+    // session started event, in a single transaction
     let draft_ref = if draft_needs_start {
         Some(&draft)
     } else {
         None
     };
+    let event = DraftEvent::session_started(id);
     let updated = state
         .session_repo
-        .start_session_with_draft(&session, draft_ref)
+        .start_session_with_draft(&session, draft_ref, &event)
         .await?;
 
-    // Record session started event
-    let event = DraftEvent::session_started(id);
-    state.event_repo.create(&event).await?;
-
     // Broadcast status update via WebSocket
     let message = websocket::ServerMessage::draft_status(id, "InProgress".to_string());
     state.ws_manager.broadcast_to_session(id, message).await;
 
-    Ok(Json(updated.into()))
+    // Resuming picks the clock back up where the pause left it
+    let clock = websocket::ServerMessage::clock_update(
+        id,
+        updated.time_remaining(),
+        updated.current_pick_number,
+    );
+    state.ws_manager.broadcast_to_session(id, clock).await;
+    outbox::mark_published(state, &[event.id]).await;
+
+    Ok(updated)
 }
 
 /// POST /api/v1/sessions/:id/pause
 pub async fn pause_session(
     State(state): State<AppState>,
+    user: AuthUser,
     Path(id): Path<Uuid>,
 ) -> ApiResult<Json<SessionResponse>> {
+    authorize_participant(&state, &user, id, None).await?;
+
     // Signal any running auto-pick-run to stop
     if let Some(cancel_flag) = state.auto_pick_cancel.get(&id) {
         cancel_flag.store(true, Ordering::SeqCst);
@@ -274,25 +432,82 @@ pub async fn pause_session(
         .ok_or_else(|| domain::errors::DomainError::NotFound(format!("Session {}", id)))?;
 
     session.pause()?;
-    let updated = state.session_repo.update(&session).await?;
 
-    // Record session paused event
+    // Save the session with its paused event
     let event = DraftEvent::session_paused(id);
-    state.event_repo.create(&event).await?;
+    let updated = state
+        .session_repo
+        .update_with_events(&session, std::slice::from_ref(&event))
+        .await?;
 
     // Broadcast status update via WebSocket
     let message = websocket::ServerMessage::draft_status(id, "Paused".to_string());
     state.ws_manager.broadcast_to_session(id, message).await;
+    outbox::mark_published(&state, &[event.id]).await;
 
     Ok(Json(updated.into()))
 }
 
-/// GET /api/v1/sessions/:id/events
+/// GET /api/v1/sessions/:id/presence
+/// Who is connected to the session over WebSocket right now.
+pub async fn get_session_presence(
+    State(state): State<AppState>,
+    Path(id): Path<Uuid>,
+) -> ApiResult<Json<PresenceResponse>> {
+    state
+        .session_repo
+        .find_by_id(id)
+        .await?
+        .ok_or_else(|| domain::errors::DomainError::NotFound(format!("Session {}", id)))?;
+
+    Ok(Json(PresenceResponse {
+        session_id: id,
+        presence: state.ws_manager.session_presence(id),
+    }))
+}
+
+#[derive(Debug, Deserialize)]
+pub struct EventFeedQuery {
+    /// Only return events recorded after this one, for tailing the feed
+    pub after_id: Option<Uuid>,
+    pub limit: Option<i64>,
+}
+
+/// GET /api/v1/sessions/:id/events?after_id=<uuid>&limit=<n>
+/// Returns the session's events, oldest first. Without either parameter the
+/// whole log is returned; otherwise one page is, and passing the `id` of
+/// its last event as `after_id` fetches the next.
 pub async fn get_session_events(
     State(state): State<AppState>,
     Path(id): Path<Uuid>,
+    Query(query): Query<EventFeedQuery>,
 ) -> ApiResult<Json<Vec<EventResponse>>> {
-    let events = state.event_repo.list_by_session(id).await?;
+    if query.after_id.is_none() && query.limit.is_none() {
+        let events = state.event_repo.list_by_session(id).await?;
+        return Ok(Json(events.into_iter().map(Into::into).collect()));
+    }
+
+    let limit = query.limit.unwrap_or(DEFAULT_EVENTS_LIMIT);
+    if !(1..=MAX_EVENTS_LIMIT).contains(&limit) {
+        return Err(ApiError::BadRequest(format!(
+            "limit must be between 1 and {}",
+            MAX_EVENTS_LIMIT
+        )));
+    }
+    if let Some(after_id) = query.after_id {
+        let cursor = state.event_repo.find_by_id(after_id).await?;
+        if cursor.is_none_or(|event| event.session_id != id) {
+            return Err(ApiError::BadRequest(format!(
+                "Event {} is not in session {}",
+                after_id, id
+            )));
+        }
+    }
+
+    let events = state
+        .event_repo
+        .list_by_session_after(id, query.after_id, limit)
+        .await?;
     let responses = events.into_iter().map(Into::into).collect();
     Ok(Json(responses))
 }
@@ -308,8 +523,11 @@ pub struct AutoPickRunResponse {
 /// or cancellation (e.g., from a pause request).
 pub async fn auto_pick_run(
     State(state): State<AppState>,
+    user: AuthUser,
     Path(id): Path<Uuid>,
 ) -> ApiResult<Json<AutoPickRunResponse>> {
+    authorize_participant(&state, &user, id, None).await?;
+
     // Acquire per-session lock to prevent concurrent auto-pick-run requests
     let lock = state
         .session_locks
@@ -334,7 +552,7 @@ pub async fn auto_pick_run(
 
     if session.status != domain::models::SessionStatus::InProgress {
         state.auto_pick_cancel.remove(&id);
-        return Err(domain::errors::DomainError::InvalidState(
+        return Err(domain::errors::DomainError::SessionNotInProgress(
             "Session is not in progress".to_string(),
         )
         .into());
@@ -342,125 +560,112 @@ pub async fn auto_pick_run(
 
     let mut picks_made = Vec::new();
     // Cache teams to avoid N+1 queries inside the loop
-    let mut team_cache: HashMap<Uuid, domain::models::Team> = HashMap::new();
+    let mut team_cache: HashMap<Uuid, Team> = HashMap::new();
 
-    // Cache draft outside the loop (fetch once)
-    let draft = state
+    // The draft must exist before any pick is attempted
+    state
         .draft_engine
         .get_draft(session.draft_id)
         .await?
         .ok_or_else(|| domain::errors::DomainError::NotFound("Draft not found".to_string()))?;
 
-    loop {
-        // Check for cancellation (e.g., pause was requested)
-        if cancel_flag.load(Ordering::SeqCst) {
-            tracing::info!(session_id = %id, "Auto-pick run cancelled (pause requested)");
-            break;
-        }
+    // Picks made faster than the flush interval reach clients as one batch
+    let mut batcher = websocket::PickBatcher::new(id, PICK_BATCH_FLUSH_INTERVAL);
 
-        // Get the next unmade pick
-        let next_pick = state.draft_engine.get_next_pick(session.draft_id).await?;
-        let Some(pick) = next_pick else {
-            // No more picks — draft complete
-            break;
-        };
+    let run: ApiResult<()> = async {
+        loop {
+            // Check for cancellation (e.g., pause was requested)
+            if cancel_flag.load(Ordering::SeqCst) {
+                tracing::info!(session_id = %id, "Auto-pick run cancelled (pause requested)");
+                break;
+            }
 
-        // Stop if this pick is user-controlled
-        if !session.should_auto_pick(pick.team_id) {
-            break;
-        }
+            // Get the next unmade pick
+            let next_pick = state.draft_engine.get_next_pick(session.draft_id).await?;
+            let Some(pick) = next_pick else {
+                // No more picks — draft complete
+                break;
+            };
 
-        // Execute auto-pick (with fallback on failure)
-        let made_pick = match state.draft_engine.execute_auto_pick(pick.id).await {
-            Ok(p) => p,
-            Err(e) => {
-                // Fallback: pick first available player
-                tracing::warn!("Auto-pick failed, using fallback: {}", e);
-                let available = state
-                    .draft_engine
-                    .get_available_players(session.draft_id, draft.year)
-                    .await?;
-                let first = available.first().ok_or_else(|| {
-                    domain::errors::DomainError::ValidationError("No players available".to_string())
-                })?;
-                state.draft_engine.make_pick(pick.id, first.id).await?
+            // Stop if this pick is user-controlled
+            if !session.should_auto_pick(pick.team_id) {
+                break;
             }
-        };
 
-        // Advance session pick number in memory
-        session.advance_pick()?;
+            // The pick, the session advanced past it and its PickMade event are
+            // written in one transaction
+            let mut advanced = session.clone();
+            advanced.advance_pick()?;
+            let (state_ref, advanced_ref) = (&state, &advanced);
+            let save = move |made| record_session_pick(state_ref, advanced_ref, made);
+
+            // Execute auto-pick (with fallback on failure)
+            let made_pick = match state
+                .draft_engine
+                .execute_auto_pick_with(pick.id, save)
+                .await
+            {
+                Ok(p) => p,
+                Err(e) => {
+                    // Fallback: pick first available player
+                    tracing::warn!("Auto-pick failed, using fallback: {}", e);
+                    let available = state
+                        .draft_engine
+                        .get_available_players(session.draft_id)
+                        .await?;
+                    let first = available.first().ok_or_else(|| {
+                        domain::errors::DomainError::ValidationError(
+                            "No players available".to_string(),
+                        )
+                    })?;
+                    state
+                        .draft_engine
+                        .make_pick_with(pick.id, first.id, save)
+                        .await?
+                }
+            };
+            session = advanced;
 
-        // Broadcast pick_made via WebSocket (only fetch team/player if player was assigned)
-        if let Some(player_id) = made_pick.player_id {
             // Use cached team data to avoid repeated DB lookups
             if let std::collections::hash_map::Entry::Vacant(e) = team_cache.entry(pick.team_id) {
                 if let Some(t) = state.team_repo.find_by_id(pick.team_id).await? {
                     e.insert(t);
                 }
             }
-            let team = team_cache.get(&pick.team_id);
-            let player = state.player_repo.find_by_id(player_id).await?;
-
-            // Persist PickMade event for audit trail
-            let event = DraftEvent::pick_made(
-                id,
-                pick.id,
-                pick.team_id,
-                player_id,
-                pick.round,
-                pick.pick_number,
-            );
-            state.event_repo.create(&event).await?;
-
-            if let (Some(team), Some(player)) = (team, player) {
-                let ws_msg = websocket::ServerMessage::pick_made(
-                    id,
-                    pick.id,
-                    pick.team_id,
-                    player_id,
-                    pick.round,
-                    pick.pick_number,
-                    format!("{} {}", player.first_name, player.last_name),
-                    format!("{} {}", team.city, team.name),
-                );
-                state.ws_manager.broadcast_to_session(id, ws_msg).await;
+            if let Some(made) =
+                pick_made_message(&state, &made_pick, team_cache.get(&pick.team_id)).await?
+            {
+                if let Some(message) = batcher.push(made) {
+                    state.ws_manager.broadcast_to_session(id, message).await;
+                }
             }
-        }
 
-        picks_made.push(DraftPickResponse::from(made_pick));
+            picks_made.push(DraftPickResponse::from(made_pick));
 
-        // Brief pause between picks so WS notifications arrive one at a time.
-        // Keep this short (200ms) to stay within proxy timeouts (nginx 60s, Vite ~120s).
-        // With 224 picks this completes in ~45s.
-        tokio::time::sleep(std::time::Duration::from_millis(200)).await;
+            // Brief pause between picks so clients can follow the run as it happens.
+            // Keep this short (200ms) to stay within proxy timeouts (nginx 60s, Vite ~120s).
+            // With 224 picks this completes in ~45s.
+            tokio::time::sleep(std::time::Duration::from_millis(200)).await;
+        }
+        Ok(())
     }
+    .await;
 
-    // Clean up cancellation flag
-    state.auto_pick_cancel.remove(&id);
-
-    // Check if draft is complete (no more picks available)
-    let remaining = state.draft_engine.get_next_pick(session.draft_id).await?;
-    if remaining.is_none() {
-        session.complete()?;
-        let event = DraftEvent::session_completed(id);
-        state.event_repo.create(&event).await?;
-
-        // Also mark the draft itself as completed
-        let mut draft = state
-            .draft_engine
-            .get_draft(session.draft_id)
-            .await?
-            .ok_or_else(|| domain::errors::DomainError::NotFound("Draft not found".to_string()))?;
-        draft.complete()?;
-        state.draft_repo.update(&draft).await?;
-
-        // Broadcast completion via WebSocket
-        let message = websocket::ServerMessage::draft_status(id, "Completed".to_string());
+    // Announce picks still waiting in the batch, even if the run stopped on an error
+    if let Some(message) = batcher.flush() {
         state.ws_manager.broadcast_to_session(id, message).await;
     }
+    let announced: Vec<Uuid> = picks_made.iter().map(|p| p.id).collect();
+    outbox::mark_picks_published(&state, id, &announced).await;
 
-    // Batch session update — single DB write after all picks
-    state.session_repo.update(&session).await?;
+    // Clean up cancellation flag
+    state.auto_pick_cancel.remove(&id);
+    run?;
+
+    // Each pick already saved the advanced session; this completes (and
+    // persists) it if the last pick was just made
+    let draft_id = session.draft_id;
+    complete_if_final_pick(&state, Some(&mut session), draft_id).await?;
 
     // Release lock and clean up DashMap entry for completed sessions
     drop(_guard);
@@ -474,13 +679,147 @@ pub async fn auto_pick_run(
     }))
 }
 
+/// Saves a pick made in a session along with `session`, normally just
+/// advanced past the pick, and the pick's `PickMade` event, all in one
+/// transaction
+pub(crate) async fn record_session_pick(
+    state: &AppState,
+    session: &DraftSession,
+    pick: DraftPick,
+) -> domain::errors::DomainResult<DraftPick> {
+    let player_id = pick.player_id.ok_or_else(|| {
+        domain::errors::DomainError::InvalidState("Pick has no player".to_string())
+    })?;
+    let event = DraftEvent::pick_made(
+        session.id,
+        pick.id,
+        pick.team_id,
+        player_id,
+        pick.round,
+        pick.pick_number,
+        pick.overall_pick,
+    );
+    state.session_repo.record_pick(&pick, session, &event).await
+}
+
+/// Broadcasts a selection made in a session to connected clients. `team` is
+/// the picking team when the caller already has it; otherwise it is looked
+/// up. Picks without a player are ignored.
+pub(crate) async fn publish_pick_made(
+    state: &AppState,
+    session_id: Uuid,
+    pick: &DraftPick,
+    team: Option<&Team>,
+) -> ApiResult<()> {
+    if let Some(made) = pick_made_message(state, pick, team).await? {
+        state
+            .ws_manager
+            .broadcast_to_session(session_id, made.into_pick_made(session_id))
+            .await;
+    }
+    outbox::mark_picks_published(state, session_id, &[pick.id]).await;
+    Ok(())
+}
+
+/// The pick as it should be shown to clients, or `None` if there is nothing
+/// to announce
+async fn pick_made_message(
+    state: &AppState,
+    pick: &DraftPick,
+    team: Option<&Team>,
+) -> ApiResult<Option<websocket::BatchedPick>> {
+    let Some(player_id) = pick.player_id else {
+        return Ok(None);
+    };
+
+    let team = match team {
+        Some(team) => Some(team.clone()),
+        None => state.team_repo.find_by_id(pick.team_id).await?,
+    };
+    let player = state.player_repo.find_by_id(player_id).await?;
+    Ok(team
+        .zip(player)
+        .map(|(team, player)| websocket::BatchedPick {
+            pick_id: pick.id,
+            team_id: pick.team_id,
+            player_id,
+            round: pick.round,
+            pick_number: pick.pick_number,
+            player_name: format!("{} {}", player.first_name, player.last_name),
+            team_name: format!("{} {}", team.city, team.name),
+        }))
+}
+
+/// Completes the draft, and the session running it, once no unmade picks
+/// remain. Records `SessionCompleted` and `DraftCompleted` and broadcasts the
+/// new status. Returns true if the session was completed and persisted here.
+pub(crate) async fn complete_if_final_pick(
+    state: &AppState,
+    session: Option<&mut DraftSession>,
+    draft_id: Uuid,
+) -> ApiResult<bool> {
+    if state.draft_engine.get_next_pick(draft_id).await?.is_some() {
+        return Ok(false);
+    }
+
+    let mut draft = state
+        .draft_engine
+        .get_draft(draft_id)
+        .await?
+        .ok_or_else(|| domain::errors::DomainError::NotFound("Draft not found".to_string()))?;
+    let draft_completed = matches!(
+        draft.status,
+        domain::models::DraftStatus::InProgress | domain::models::DraftStatus::Paused
+    );
+    if draft_completed {
+        draft.complete()?;
+        state.draft_repo.update(&draft).await?;
+        record_adp(state, draft_id, draft.year).await?;
+    }
+
+    let Some(session) = session else {
+        return Ok(false);
+    };
+    if !matches!(
+        session.status,
+        domain::models::SessionStatus::InProgress | domain::models::SessionStatus::Paused
+    ) {
+        return Ok(false);
+    }
+
+    session.complete()?;
+
+    let mut events = vec![DraftEvent::session_completed(session.id)];
+    if draft_completed {
+        events.push(DraftEvent::draft_completed(session.id, draft_id));
+    }
+    state
+        .session_repo
+        .update_with_events(session, &events)
+        .await?;
+
+    let message = websocket::ServerMessage::draft_status(session.id, "Completed".to_string());
+    state
+        .ws_manager
+        .broadcast_to_session(session.id, message)
+        .await;
+    let event_ids: Vec<Uuid> = events.iter().map(|e| e.id).collect();
+    outbox::mark_published(state, &event_ids).await;
+
+    Ok(true)
+}
+
 /// POST /api/v1/sessions/:id/advance-pick
 /// Advance the session's current_pick_number by one.
 /// Validates that the current pick has been made (has a player assigned) before advancing.
+/// Records a `PickAdvanced` event with the session change so replays keep up.
 pub async fn advance_pick(
     State(state): State<AppState>,
+    user: AuthUser,
     Path(id): Path<Uuid>,
 ) -> ApiResult<Json<SessionResponse>> {
+    authorize_participant(&state, &user, id, None).await?;
+
     let lock = state
         .session_locks
         .entry(id)
@@ -509,8 +848,349 @@ pub async fn advance_pick(
         }
     }
 
+    let from_pick_number = session.current_pick_number;
     session.advance_pick()?;
-    let updated = state.session_repo.update(&session).await?;
+    let event = DraftEvent::pick_advanced(id, from_pick_number, session.current_pick_number);
+    let updated = state
+        .session_repo
+        .update_with_events(&session, std::slice::from_ref(&event))
+        .await?;
+
+    // The next pick is on the clock with a fresh timer
+    let clock = websocket::ServerMessage::clock_update(
+        id,
+        updated.time_remaining(),
+        updated.current_pick_number,
+    );
+    state.ws_manager.broadcast_to_session(id, clock).await;
+    outbox::mark_published(&state, &[event.id]).await;
 
     Ok(Json(updated.into()))
 }
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct RewindSessionRequest {
+    pub target_pick_number: i32,
+}
+
+/// POST /api/v1/sessions/:id/rewind
+/// Rolls the session back so `target_pick_number` is on the clock again.
+/// Selections from that pick onward are cleared and trades accepted after the
+/// previous pick was made are reversed. The session is left paused.
+pub async fn rewind_session(
+    State(state): State<AppState>,
+    user: AuthUser,
+    Path(id): Path<Uuid>,
+    Json(payload): Json<RewindSessionRequest>,
+) -> ApiResult<Json<SessionResponse>> {
+    authorize_participant(&state, &user, id, None).await?;
+
+    let lock = state
+        .session_locks
+        .entry(id)
+        .or_insert_with(|| std::sync::Arc::new(tokio::sync::Mutex::new(())))
+        .clone();
+    let _guard = lock.try_lock().map_err(|_| {
+        domain::errors::DomainError::InvalidState(
+            "Session is being modified by another request".to_string(),
+        )
+    })?;
+
+    let mut session = state
+        .session_repo
+        .find_by_id(id)
+        .await?
+        .ok_or_else(|| domain::errors::DomainError::NotFound(format!("Session {}", id)))?;
+
+    let from_pick_number = session.current_pick_number;
+    let target = payload.target_pick_number;
+    session.rewind_to(target)?;
+
+    let picks = state
+        .draft_pick_repo
+        .find_by_draft_id(session.draft_id)
+        .await?;
+    if !picks.iter().any(|p| p.overall_pick == target) {
+        return Err(domain::errors::DomainError::ValidationError(format!(
+            "Pick {} does not exist in this draft",
+            target
+        ))
+        .into());
+    }
+
+    let cleared_pick_ids: Vec<Uuid> = picks
+        .iter()
+        .filter(|p| p.overall_pick >= target && p.is_picked())
+        .map(|p| p.id)
+        .collect();
+
+    // Target pick came on the clock when the previous selection was made
+    // (or when the session started, for the first pick)
+    let cutoff = picks
+        .iter()
+        .filter(|p| p.overall_pick < target)
+        .filter_map(|p| p.picked_at)
+        .max()
+        .or(session.started_at);
+
+    let mut reversed_trades: Vec<_> = state
+        .trade_repo
+        .find_proposals_by_session(id)
+        .await?
+        .into_iter()
+        .filter(|p| p.trade.status == TradeStatus::Accepted)
+        .filter(|p| match (p.trade.responded_at, cutoff) {
+            (Some(responded_at), Some(cutoff)) => responded_at > cutoff,
+            _ => false,
+        })
+        .collect();
+    // Undo the most recent trade first so chained trades unwind correctly
+    reversed_trades.sort_by_key(|p| std::cmp::Reverse(p.trade.responded_at));
+    let reversed_trade_ids: Vec<Uuid> = reversed_trades.iter().map(|p| p.trade.id).collect();
+
+    let mut draft = state
+        .draft_engine
+        .get_draft(session.draft_id)
+        .await?
+        .ok_or_else(|| domain::errors::DomainError::NotFound("Draft not found".to_string()))?;
+    let draft_changed = draft.status != domain::models::DraftStatus::Paused;
+    draft.reopen()?;
+
+    let event = DraftEvent::session_rewound(
+        id,
+        from_pick_number,
+        target,
+        &cleared_pick_ids,
+        &reversed_trade_ids,
+    );
+    let updated = state
+        .session_repo
+        .rewind_session(
+            &session,
+            draft_changed.then_some(&draft),
+            &reversed_trades,
+            &event,
+        )
+        .await?;
+
+    let message = websocket::ServerMessage::session_rewound(id, target, reversed_trade_ids);
+    state.ws_manager.broadcast_to_session(id, message).await;
+
+    // The clock restarts from a full allotment for the target pick
+    let clock = websocket::ServerMessage::clock_update(id, updated.time_per_pick_seconds, target);
+    state.ws_manager.broadcast_to_session(id, clock).await;
+    outbox::mark_published(&state, &[event.id]).await;
+
+    Ok(Json(updated.into()))
+}
+
+#[derive(Debug, Serialize)]
+pub struct ProjectionMismatchResponse {
+    pub field: String,
+    pub stored: String,
+    pub projected: String,
+}
+
+impl From<ProjectionMismatch> for ProjectionMismatchResponse {
+    fn from(m: ProjectionMismatch) -> Self {
+        Self {
+            field: m.field,
+            stored: m.stored,
+            projected: m.projected,
+        }
+    }
+}
+
+#[derive(Debug, Serialize)]
+pub struct ProjectionVerificationResponse {
+    pub session_id: Uuid,
+    pub consistent: bool,
+    pub events_applied: usize,
+    pub last_event_id: Option<Uuid>,
+    pub projected_status: String,
+    pub projected_current_pick_number: i32,
+    pub projected_picks_made: usize,
+    pub mismatches: Vec<ProjectionMismatchResponse>,
+}
+
+/// GET /api/v1/admin/sessions/:id/verify-projection
+/// Rebuilds the session from its event stream and reports every place the
+/// projection disagrees with the draft_sessions and draft_picks tables.
+/// Admin only.
+pub async fn verify_session_projection(
+    State(state): State<AppState>,
+    Path(id): Path<Uuid>,
+) -> ApiResult<Json<ProjectionVerificationResponse>> {
+    let session = state
+        .session_repo
+        .find_by_id(id)
+        .await?
+        .ok_or_else(|| domain::errors::DomainError::NotFound(format!("Session {}", id)))?;
+
+    let events = state.event_repo.list_by_session(id).await?;
+    let projection = SessionProjector::project(id, &events)?;
+    let picks = state
+        .draft_pick_repo
+        .find_by_draft_id(session.draft_id)
+        .await?;
+    let mismatches = SessionProjector::verify(&projection, &session, &picks);
+
+    Ok(Json(ProjectionVerificationResponse {
+        session_id: id,
+        consistent: mismatches.is_empty(),
+        events_applied: projection.events_applied,
+        last_event_id: projection.last_event_id,
+        projected_status: projection.status.to_string(),
+        projected_current_pick_number: projection.current_pick_number,
+        projected_picks_made: projection.picks_made(),
+        mismatches: mismatches.into_iter().map(Into::into).collect(),
+    }))
+}
+
+/// GET /api/v1/sessions/:id/export
+/// Bundles the session with its draft, picks, trades, event log and the
+/// picking team's scouting grade for every selection.
+pub async fn export_session(
+    State(state): State<AppState>,
+    Path(id): Path<Uuid>,
+) -> ApiResult<Json<SessionBundle>> {
+    let session = state
+        .session_repo
+        .find_by_id(id)
+        .await?
+        .ok_or_else(|| domain::errors::DomainError::NotFound(format!("Session {}", id)))?;
+    let draft = state
+        .draft_repo
+        .find_by_id(session.draft_id)
+        .await?
+        .ok_or_else(|| {
+            domain::errors::DomainError::NotFound(format!("Draft {}", session.draft_id))
+        })?;
+
+    let picks = state.draft_pick_repo.find_by_draft_id(draft.id).await?;
+    let trades = state.trade_repo.find_proposals_by_session(id).await?;
+    let events = state.event_repo.list_by_session(id).await?;
+
+    let mut grades = Vec::new();
+    for pick in &picks {
+        if let Some(player_id) = pick.player_id {
+            if let Some(report) = state
+                .scouting_report_repo
+                .find_by_team_and_player(pick.team_id, player_id)
+                .await?
+            {
+                grades.push(report);
+            }
+        }
+    }
+
+    let mut team_ids: Vec<Uuid> = picks
+        .iter()
+        .flat_map(|p| std::iter::once(p.team_id).chain(p.original_team_id))
+        .chain(session.controlled_team_ids.iter().copied())
+        .chain(
+            trades
+                .iter()
+                .flat_map(|t| [t.trade.from_team_id, t.trade.to_team_id]),
+        )
+        .collect();
+    team_ids.sort();
+    team_ids.dedup();
+    let mut teams = Vec::with_capacity(team_ids.len());
+    for team_id in team_ids {
+        let team =
+            state.team_repo.find_by_id(team_id).await?.ok_or_else(|| {
+                domain::errors::DomainError::NotFound(format!("Team {}", team_id))
+            })?;
+        teams.push(BundleTeam {
+            id: team.id,
+            abbreviation: team.abbreviation,
+        });
+    }
+
+    let mut players = Vec::new();
+    for player_id in picks.iter().filter_map(|p| p.player_id) {
+        let player = state
+            .player_repo
+            .find_by_id(player_id)
+            .await?
+            .ok_or_else(|| {
+                domain::errors::DomainError::NotFound(format!("Player {}", player_id))
+            })?;
+        players.push(BundlePlayer {
+            id: player.id,
+            first_name: player.first_name,
+            last_name: player.last_name,
+            draft_year: player.draft_year,
+        });
+    }
+
+    Ok(Json(SessionBundle {
+        version: SESSION_BUNDLE_VERSION,
+        exported_at: chrono::Utc::now(),
+        draft,
+        session,
+        picks,
+        trades,
+        events,
+        grades,
+        teams,
+        players,
+    }))
+}
+
+/// POST /api/v1/sessions/import
+/// Recreates an exported session under new IDs. Teams are matched by
+/// abbreviation and players by name and draft year, so bundles can move
+/// between installations seeded independently.
+pub async fn import_session(
+    State(state): State<AppState>,
+    _user: AuthUser,
+    Json(bundle): Json<SessionBundle>,
+) -> ApiResult<(StatusCode, Json<SessionResponse>)> {
+    bundle.validate()?;
+
+    let mut references = HashMap::new();
+    for team in &bundle.teams {
+        let local = state
+            .team_repo
+            .find_by_abbreviation(&team.abbreviation)
+            .await?
+            .ok_or_else(|| {
+                domain::errors::DomainError::ValidationError(format!(
+                    "Team {} does not exist in this installation",
+                    team.abbreviation
+                ))
+            })?;
+        references.insert(team.id, local.id);
+    }
+
+    let mut years: Vec<i32> = bundle.players.iter().map(|p| p.draft_year).collect();
+    years.sort_unstable();
+    years.dedup();
+    let mut local_players = Vec::new();
+    for year in years {
+        local_players.extend(state.player_repo.find_by_draft_year(year).await?);
+    }
+    for player in &bundle.players {
+        let local = local_players
+            .iter()
+            .find(|p| {
+                p.draft_year == player.draft_year
+                    && p.first_name.eq_ignore_ascii_case(&player.first_name)
+                    && p.last_name.eq_ignore_ascii_case(&player.last_name)
+            })
+            .ok_or_else(|| {
+                domain::errors::DomainError::ValidationError(format!(
+                    "Player {} {} ({}) does not exist in this installation",
+                    player.first_name, player.last_name, player.draft_year
+                ))
+            })?;
+        references.insert(player.id, local.id);
+    }
+
+    let imported = bundle.remap(&references)?;
+    let session = state.session_repo.import_bundle(&imported).await?;
+
+    Ok((StatusCode::CREATED, Json(session.into())))
+}
@@ -48,6 +48,7 @@ impl From<TeamNeed> for TeamNeedResponse {
     request_body = CreateTeamNeedRequest,
     responses(
         (status = 201, description = "Team need created successfully", body = TeamNeedResponse),
+        (status = 401, description = "Unauthorized"),
         (status = 400, description = "Invalid request"),
         (status = 409, description = "Team need for this team and position already exists")
     ),
@@ -118,6 +119,7 @@ pub async fn list_team_needs(
     request_body = UpdateTeamNeedRequest,
     responses(
         (status = 200, description = "Team need updated successfully", body = TeamNeedResponse),
+        (status = 401, description = "Unauthorized"),
         (status = 404, description = "Team need not found"),
         (status = 400, description = "Invalid request")
     ),
@@ -151,6 +153,7 @@ pub async fn update_team_need(
     path = "/api/v1/team-needs/{id}",
     responses(
         (status = 204, description = "Team need deleted successfully"),
+        (status = 401, description = "Unauthorized"),
         (status = 404, description = "Team need not found")
     ),
     params(
@@ -1,13 +1,17 @@
-use axum::extract::{Path, State};
+use axum::extract::{Path, Query, State};
 use axum::http::StatusCode;
 use axum::Json;
 use serde::{Deserialize, Serialize};
-use utoipa::ToSchema;
+use utoipa::{IntoParams, ToSchema};
 use uuid::Uuid;
 
-use domain::models::{Conference, Division, Team};
+use domain::errors::DomainError;
+use domain::models::{Conference, Division, Page, Team, TeamSortField, TeamUpdate};
 
+use crate::batch::IdsParams;
 use crate::error::{ApiError, ApiResult};
+use crate::pagination::{Paginated, PaginationParams};
+use crate::sorting::SortParams;
 use crate::state::AppState;
 
 #[derive(Debug, Serialize, Deserialize, ToSchema)]
@@ -19,6 +23,49 @@ pub struct CreateTeamRequest {
     pub division: Division,
 }
 
+/// Sparse team update; omitted fields are left alone
+#[derive(Debug, Default, Deserialize, ToSchema)]
+pub struct UpdateTeamRequest {
+    pub name: Option<String>,
+    pub abbreviation: Option<String>,
+    pub city: Option<String>,
+    pub conference: Option<Conference>,
+    pub division: Option<Division>,
+}
+
+/// A PUT body replaces the team, so every field is set
+impl From<CreateTeamRequest> for TeamUpdate {
+    fn from(req: CreateTeamRequest) -> Self {
+        TeamUpdate {
+            name: Some(req.name),
+            abbreviation: Some(req.abbreviation),
+            city: Some(req.city),
+            conference: Some(req.conference),
+            division: Some(req.division),
+        }
+    }
+}
+
+impl From<UpdateTeamRequest> for TeamUpdate {
+    fn from(req: UpdateTeamRequest) -> Self {
+        TeamUpdate {
+            name: req.name,
+            abbreviation: req.abbreviation,
+            city: req.city,
+            conference: req.conference,
+            division: req.division,
+        }
+    }
+}
+
+#[derive(Debug, Default, Deserialize, IntoParams)]
+pub struct DeleteTeamParams {
+    /// Delete the team for good together with its needs, its trades and
+    /// the picks it holds, rather than soft-deleting it
+    #[serde(default)]
+    pub cascade: bool,
+}
+
 #[derive(Debug, Serialize, ToSchema)]
 pub struct TeamResponse {
     pub id: Uuid,
@@ -43,18 +90,38 @@ impl From<Team> for TeamResponse {
 }
 
 /// GET /api/v1/teams - List all teams
+///
+/// Sortable by `name`, `abbreviation`, `city`, `conference` and `division`.
+/// `ids=` fetches a batch of teams by ID.
 #[utoipa::path(
     get,
     path = "/api/v1/teams",
+    params(IdsParams, SortParams, PaginationParams),
     responses(
-        (status = 200, description = "List of all teams", body = Vec<TeamResponse>)
+        (status = 200, description = "List of all teams; total count in X-Total-Count", body = Vec<TeamResponse>),
+        (status = 400, description = "Invalid ids, sort or pagination parameters")
     ),
     tag = "teams"
 )]
-pub async fn list_teams(State(state): State<AppState>) -> ApiResult<Json<Vec<TeamResponse>>> {
-    let teams = state.team_repo.find_all().await?;
-    let response: Vec<TeamResponse> = teams.into_iter().map(TeamResponse::from).collect();
-    Ok(Json(response))
+pub async fn list_teams(
+    State(state): State<AppState>,
+    Query(ids): Query<IdsParams>,
+    Query(sort): Query<SortParams>,
+    Query(pagination): Query<PaginationParams>,
+) -> ApiResult<Paginated<TeamResponse>> {
+    let sort = sort.parse::<TeamSortField>()?;
+    let request = pagination.page_request()?;
+
+    let page = match ids.parse()? {
+        // There are only ever 32 teams, so pick the batch out of all of them
+        Some(ids) => {
+            let teams = state.team_repo.find_sorted(&sort, None).await?.items;
+            let teams = teams.into_iter().filter(|t| ids.contains(&t.id)).collect();
+            Page::from_all(teams, request)
+        }
+        None => state.team_repo.find_sorted(&sort, request).await?,
+    };
+    Ok(Paginated::page(page.map(TeamResponse::from), request))
 }
 
 /// GET /api/v1/teams/:id - Get team by ID
@@ -90,6 +157,7 @@ pub async fn get_team(
     request_body = CreateTeamRequest,
     responses(
         (status = 201, description = "Team created successfully", body = TeamResponse),
+        (status = 401, description = "Unauthorized"),
         (status = 400, description = "Invalid request")
     ),
     tag = "teams"
@@ -109,3 +177,136 @@ pub async fn create_team(
     let created = state.team_repo.create(&team).await?;
     Ok((StatusCode::CREATED, Json(TeamResponse::from(created))))
 }
+
+/// PATCH /api/v1/teams/:id - Correct individual fields of a team
+#[utoipa::path(
+    patch,
+    path = "/api/v1/teams/{id}",
+    request_body = UpdateTeamRequest,
+    responses(
+        (status = 200, description = "Team updated successfully", body = TeamResponse),
+        (status = 401, description = "Unauthorized"),
+        (status = 400, description = "Invalid request"),
+        (status = 404, description = "Team not found"),
+        (status = 409, description = "Abbreviation already in use")
+    ),
+    params(
+        ("id" = Uuid, Path, description = "Team ID")
+    ),
+    tag = "teams"
+)]
+pub async fn update_team(
+    State(state): State<AppState>,
+    Path(id): Path<Uuid>,
+    Json(payload): Json<UpdateTeamRequest>,
+) -> ApiResult<Json<TeamResponse>> {
+    let mut team = state
+        .team_repo
+        .find_by_id(id)
+        .await?
+        .ok_or_else(|| ApiError::NotFound(format!("Team with id {} not found", id)))?;
+
+    team.apply_update(&TeamUpdate::from(payload))?;
+    let updated = state.team_repo.update(&team).await?;
+
+    Ok(Json(TeamResponse::from(updated)))
+}
+
+/// PUT /api/v1/teams/:id - Replace every field of a team
+#[utoipa::path(
+    put,
+    path = "/api/v1/teams/{id}",
+    request_body = CreateTeamRequest,
+    responses(
+        (status = 200, description = "Team replaced successfully", body = TeamResponse),
+        (status = 401, description = "Unauthorized"),
+        (status = 400, description = "Invalid request"),
+        (status = 404, description = "Team not found"),
+        (status = 409, description = "Abbreviation already in use")
+    ),
+    params(
+        ("id" = Uuid, Path, description = "Team ID")
+    ),
+    tag = "teams"
+)]
+pub async fn replace_team(
+    State(state): State<AppState>,
+    Path(id): Path<Uuid>,
+    Json(payload): Json<CreateTeamRequest>,
+) -> ApiResult<Json<TeamResponse>> {
+    let mut team = state
+        .team_repo
+        .find_by_id(id)
+        .await?
+        .ok_or_else(|| ApiError::NotFound(format!("Team with id {} not found", id)))?;
+
+    team.apply_update(&TeamUpdate::from(payload))?;
+    let updated = state.team_repo.update(&team).await?;
+
+    Ok(Json(TeamResponse::from(updated)))
+}
+
+/// DELETE /api/v1/teams/:id - Delete a team
+///
+/// The team is soft-deleted: it drops out of every read while its picks,
+/// needs and trades stay in place, and an admin can restore it.
+/// `cascade=true` deletes it for good along with those. Either way the team
+/// can't be deleted while a draft that hasn't completed holds its picks.
+#[utoipa::path(
+    delete,
+    path = "/api/v1/teams/{id}",
+    params(
+        ("id" = Uuid, Path, description = "Team ID"),
+        DeleteTeamParams
+    ),
+    responses(
+        (status = 204, description = "Team deleted successfully"),
+        (status = 401, description = "Unauthorized"),
+        (status = 404, description = "Team not found"),
+        (status = 409, description = "A draft in progress holds the team's picks")
+    ),
+    tag = "teams"
+)]
+pub async fn delete_team(
+    State(state): State<AppState>,
+    Path(id): Path<Uuid>,
+    Query(params): Query<DeleteTeamParams>,
+) -> ApiResult<StatusCode> {
+    let dependents = state.team_repo.count_dependents(id).await?;
+    if dependents.live_drafts > 0 {
+        return Err(DomainError::ResourceInUse(format!(
+            "Team {} holds picks in {} draft(s) that have not completed",
+            id, dependents.live_drafts
+        ))
+        .into());
+    }
+
+    if params.cascade {
+        state.team_repo.delete_with_dependents(id).await?;
+    } else {
+        state.team_repo.soft_delete(id).await?;
+    }
+    Ok(StatusCode::NO_CONTENT)
+}
+
+/// POST /api/v1/admin/teams/:id/restore - Restore a soft-deleted team
+#[utoipa::path(
+    post,
+    path = "/api/v1/admin/teams/{id}/restore",
+    params(
+        ("id" = Uuid, Path, description = "Team ID")
+    ),
+    responses(
+        (status = 200, description = "Team restored", body = TeamResponse),
+        (status = 401, description = "Unauthorized"),
+        (status = 404, description = "No deleted team with this ID")
+    ),
+    tag = "admin"
+)]
+pub async fn restore_team(
+    State(state): State<AppState>,
+    Path(id): Path<Uuid>,
+) -> ApiResult<Json<TeamResponse>> {
+    let team = state.team_repo.restore(id).await?;
+    Ok(Json(TeamResponse::from(team)))
+}
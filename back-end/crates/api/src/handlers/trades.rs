@@ -1,4 +1,7 @@
+use crate::auth::AuthUser;
 use crate::error::ApiResult;
+use crate::handlers::participants::authorize_participant;
+use crate::outbox;
 use crate::state::AppState;
 use axum::extract::{Path, State};
 use axum::http::StatusCode;
@@ -76,6 +79,7 @@ pub struct TradeActionRequest {
     request_body = ProposeTradeRequest,
     responses(
         (status = 201, description = "Trade proposed", body = TradeProposalResponse),
+        (status = 401, description = "Missing or invalid access token"),
         (status = 400, description = "Invalid trade"),
         (status = 409, description = "Pick already in trade")
     ),
@@ -83,6 +87,7 @@ pub struct TradeActionRequest {
 )]
 pub async fn propose_trade(
     State(state): State<AppState>,
+    user: AuthUser,
     Json(payload): Json<ProposeTradeRequest>,
 ) -> ApiResult<(StatusCode, Json<TradeProposalResponse>)> {
     // Fetch session to get its chart type
@@ -94,28 +99,35 @@ pub async fn propose_trade(
             crate::error::ApiError::NotFound(format!("Session {} not found", payload.session_id))
         })?;
 
+    // The TradeProposed event is stored with the proposal, in one transaction
+    authorize_participant(&state, &user, session.id, Some(payload.from_team_id)).await?;
+
     let chart_type = payload.chart_type.unwrap_or(session.chart_type);
-    let proposal = state
+    let trade_repo = &state.trade_repo;
+    let (proposal, event) = state
         .trade_engine
-        .propose_trade(
+        .propose_trade_with(
             payload.session_id,
             payload.from_team_id,
             payload.to_team_id,
             payload.from_team_picks.clone(),
             payload.to_team_picks.clone(),
             Some(chart_type),
+            |proposal, chart_type| async move {
+                let event = DraftEvent::trade_proposed(
+                    proposal.trade.session_id,
+                    proposal.trade.id,
+                    proposal.trade.from_team_id,
+                    proposal.trade.to_team_id,
+                );
+                let created = trade_repo
+                    .create_trade_with_event(&proposal, chart_type, &event)
+                    .await?;
+                Ok((created, event))
+            },
         )
         .await?;
 
-    // Create and store draft event for audit trail
-    let event = DraftEvent::trade_proposed(
-        payload.session_id,
-        proposal.trade.id,
-        payload.from_team_id,
-        payload.to_team_id,
-    );
-    state.event_repo.create(&event).await?;
-
     // Fetch team names for the WebSocket message
     let from_team = state
         .team_repo
@@ -154,6 +166,7 @@ pub async fn propose_trade(
             ),
         )
         .await;
+    outbox::mark_published(&state, &[event.id]).await;
 
     Ok((StatusCode::CREATED, Json(proposal.into())))
 }
@@ -163,20 +176,44 @@ pub async fn propose_trade(
     path = "/api/v1/trades/{id}/accept",
     request_body = TradeActionRequest,
     responses(
-        (status = 200, description = "Trade accepted and executed", body = TradeResponse)
+        (status = 200, description = "Trade accepted and executed", body = TradeResponse),
+        (status = 401, description = "Missing or invalid access token")
     ),
     tag = "trades"
 )]
 pub async fn accept_trade(
     State(state): State<AppState>,
+    user: AuthUser,
     Path(id): Path<Uuid>,
     Json(payload): Json<TradeActionRequest>,
 ) -> ApiResult<Json<TradeResponse>> {
-    let trade = state.trade_engine.accept_trade(id, payload.team_id).await?;
+    // Only the seat holding the receiving team may answer a trade
+    let pending = state
+        .trade_repo
+        .find_by_id(id)
+        .await?
+        .ok_or_else(|| crate::error::ApiError::NotFound(format!("Trade {} not found", id)))?;
+    authorize_participant(&state, &user, pending.session_id, Some(payload.team_id)).await?;
 
-    // Create and store draft event
-    let event = DraftEvent::trade_executed(trade.session_id, trade.id);
-    state.event_repo.create(&event).await?;
+    // The picks move, the trade is marked accepted and the TradeExecuted
+    // event is stored in one transaction. The event records which picks
+    // moved so the event stream alone can replay ownership.
+    let trade_repo = &state.trade_repo;
+    let (trade, event) = state
+        .trade_engine
+        .accept_trade_with(id, payload.team_id, |proposal| async move {
+            let event = DraftEvent::trade_executed(
+                proposal.trade.session_id,
+                proposal.trade.id,
+                proposal.trade.from_team_id,
+                proposal.trade.to_team_id,
+                &proposal.from_team_picks,
+                &proposal.to_team_picks,
+            );
+            let trade = trade_repo.execute_trade(&proposal, &event).await?;
+            Ok((trade, event))
+        })
+        .await?;
 
     // Broadcast trade execution to session
     state
@@ -191,6 +228,7 @@ pub async fn accept_trade(
             ),
         )
         .await;
+    outbox::mark_published(&state, &[event.id]).await;
 
     Ok(Json(trade.into()))
 }
@@ -199,19 +237,36 @@ pub async fn accept_trade(
     post,
     path = "/api/v1/trades/{id}/reject",
     request_body = TradeActionRequest,
-    responses((status = 200, description = "Trade rejected", body = TradeResponse)),
+    responses(
+        (status = 200, description = "Trade rejected", body = TradeResponse),
+        (status = 401, description = "Missing or invalid access token")
+    ),
     tag = "trades"
 )]
 pub async fn reject_trade(
     State(state): State<AppState>,
+    user: AuthUser,
     Path(id): Path<Uuid>,
     Json(payload): Json<TradeActionRequest>,
 ) -> ApiResult<Json<TradeResponse>> {
-    let trade = state.trade_engine.reject_trade(id, payload.team_id).await?;
+    // Only the seat holding the receiving team may answer a trade
+    let pending = state
+        .trade_repo
+        .find_by_id(id)
+        .await?
+        .ok_or_else(|| crate::error::ApiError::NotFound(format!("Trade {} not found", id)))?;
+    authorize_participant(&state, &user, pending.session_id, Some(payload.team_id)).await?;
 
-    // Create and store draft event for rejection
-    let event = DraftEvent::trade_rejected(trade.session_id, trade.id, payload.team_id);
-    state.event_repo.create(&event).await?;
+    // The rejection and its event are stored in one transaction
+    let trade_repo = &state.trade_repo;
+    let (trade, event) = state
+        .trade_engine
+        .reject_trade_with(id, payload.team_id, |trade| async move {
+            let event = DraftEvent::trade_rejected(trade.session_id, trade.id, payload.team_id);
+            let trade = trade_repo.update_with_event(&trade, &event).await?;
+            Ok((trade, event))
+        })
+        .await?;
 
     // Broadcast trade rejection to session
     state
@@ -221,6 +276,7 @@ pub async fn reject_trade(
             ServerMessage::trade_rejected(trade.session_id, trade.id, payload.team_id),
         )
         .await;
+    outbox::mark_published(&state, &[event.id]).await;
 
     Ok(Json(trade.into()))
 }
@@ -0,0 +1,144 @@
+use std::collections::HashMap;
+
+use axum::extract::{Path, State};
+use axum::http::StatusCode;
+use axum::Json;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+use domain::models::{Player, Position, WatchlistEntry};
+
+use crate::auth::AuthUser;
+use crate::error::{ApiError, ApiResult};
+use crate::state::AppState;
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct AddToWatchlistRequest {
+    pub player_id: Uuid,
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct WatchlistEntryResponse {
+    pub player_id: Uuid,
+    pub first_name: String,
+    pub last_name: String,
+    pub position: Position,
+    pub college: Option<String>,
+    pub draft_year: i32,
+    pub added_at: DateTime<Utc>,
+}
+
+impl WatchlistEntryResponse {
+    fn new(entry: WatchlistEntry, player: Player) -> Self {
+        Self {
+            player_id: entry.player_id,
+            first_name: player.first_name,
+            last_name: player.last_name,
+            position: player.position,
+            college: player.college,
+            draft_year: player.draft_year,
+            added_at: entry.created_at,
+        }
+    }
+}
+
+/// GET /api/v1/me/watchlist - The signed-in user's watchlist, most recently added first
+#[utoipa::path(
+    get,
+    path = "/api/v1/me/watchlist",
+    responses(
+        (status = 200, description = "Players on the caller's watchlist", body = Vec<WatchlistEntryResponse>),
+        (status = 401, description = "Missing, invalid or expired token")
+    ),
+    tag = "watchlist"
+)]
+pub async fn get_watchlist(
+    State(state): State<AppState>,
+    user: AuthUser,
+) -> ApiResult<Json<Vec<WatchlistEntryResponse>>> {
+    let entries = state.watchlist_repo.find_by_user_id(user.user_id).await?;
+
+    let ids: Vec<Uuid> = entries.iter().map(|entry| entry.player_id).collect();
+    let mut players: HashMap<Uuid, Player> = state
+        .player_repo
+        .find_by_ids(&ids)
+        .await?
+        .into_iter()
+        .map(|player| (player.id, player))
+        .collect();
+
+    let response = entries
+        .into_iter()
+        .filter_map(|entry| {
+            let player = players.remove(&entry.player_id)?;
+            Some(WatchlistEntryResponse::new(entry, player))
+        })
+        .collect();
+
+    Ok(Json(response))
+}
+
+/// POST /api/v1/me/watchlist - Watch a player; watching twice is a no-op
+#[utoipa::path(
+    post,
+    path = "/api/v1/me/watchlist",
+    request_body = AddToWatchlistRequest,
+    responses(
+        (status = 201, description = "Player added to the watchlist", body = WatchlistEntryResponse),
+        (status = 401, description = "Missing, invalid or expired token"),
+        (status = 404, description = "Player not found")
+    ),
+    tag = "watchlist"
+)]
+pub async fn add_to_watchlist(
+    State(state): State<AppState>,
+    user: AuthUser,
+    Json(req): Json<AddToWatchlistRequest>,
+) -> ApiResult<(StatusCode, Json<WatchlistEntryResponse>)> {
+    let player = state
+        .player_repo
+        .find_by_id(req.player_id)
+        .await?
+        .ok_or_else(|| ApiError::NotFound(format!("Player with id {} not found", req.player_id)))?;
+
+    let entry = state
+        .watchlist_repo
+        .add(&WatchlistEntry::new(user.user_id, player.id))
+        .await?;
+
+    Ok((
+        StatusCode::CREATED,
+        Json(WatchlistEntryResponse::new(entry, player)),
+    ))
+}
+
+/// DELETE /api/v1/me/watchlist/:player_id - Stop watching a player
+#[utoipa::path(
+    delete,
+    path = "/api/v1/me/watchlist/{player_id}",
+    responses(
+        (status = 204, description = "Player removed from the watchlist"),
+        (status = 401, description = "Missing, invalid or expired token"),
+        (status = 404, description = "Player is not on the watchlist")
+    ),
+    params(
+        ("player_id" = Uuid, Path, description = "Player ID")
+    ),
+    tag = "watchlist"
+)]
+pub async fn remove_from_watchlist(
+    State(state): State<AppState>,
+    user: AuthUser,
+    Path(player_id): Path<Uuid>,
+) -> ApiResult<StatusCode> {
+    if !state.watchlist_repo.remove(user.user_id, player_id).await? {
+        return Err(ApiError::NotFound(format!(
+            "Player {} is not on your watchlist",
+            player_id
+        )));
+    }
+
+    Ok(StatusCode::NO_CONTENT)
+}
@@ -1,38 +1,132 @@
 use axum::{
     extract::{
         ws::{Message, WebSocket, WebSocketUpgrade},
-        State,
+        Query, State,
     },
     response::Response,
+    Json,
 };
+use domain::models::{DraftEvent, TradeStatus};
 use futures::{SinkExt, StreamExt};
+use serde::Deserialize;
 use tracing::{error, info, warn};
 use uuid::Uuid;
-use websocket::{ClientMessage, ServerMessage};
+use websocket::{
+    ClientMessage, ErrorCode, PendingTrade, RateLimiter, SendQueueStats, ServerMessage,
+    SyncedEvent, WsSender,
+};
 
+use crate::auth::AuthUser;
+use crate::error::ApiResult;
+use crate::handlers::chat::post_chat_message;
+use crate::handlers::participants::{ensure_not_participant_name, participant_for_user};
 use crate::state::AppState;
 
+/// Number of trailing events replayed when the client has no usable `last_event_id`
+const STATE_SYNC_EVENT_LIMIT: usize = 50;
+
+/// Chat messages a single connection may send per `CHAT_RATE_WINDOW`
+const CHAT_RATE_LIMIT: usize = 5;
+const CHAT_RATE_WINDOW: std::time::Duration = std::time::Duration::from_secs(10);
+
+/// Messages of any kind a single connection may send per `MESSAGE_RATE_WINDOW`
+const MESSAGE_RATE_LIMIT: usize = 30;
+const MESSAGE_RATE_WINDOW: std::time::Duration = std::time::Duration::from_secs(10);
+
+/// Rejected messages in a row after which a flooding client is disconnected
+const MAX_RATE_LIMIT_STRIKES: u32 = 20;
+
+/// Longest display name shown in presence
+const MAX_DISPLAY_NAME_CHARS: usize = 100;
+
+/// Optional query parameters on `/ws` that subscribe the connection immediately
+#[derive(Debug, Deserialize)]
+pub struct WsConnectParams {
+    pub session_id: Option<Uuid>,
+    pub last_event_id: Option<Uuid>,
+    pub display_name: Option<String>,
+    pub access_token: Option<String>,
+}
+
 /// WebSocket upgrade handler
 ///
 /// Accepts WebSocket connections at `/ws`, registers them with the ConnectionManager
 /// on Subscribe, and multiplexes inbound client messages with outbound server-push
-/// messages via an mpsc channel.
-pub async fn ws_handler(ws: WebSocketUpgrade, State(state): State<AppState>) -> Response {
-    ws.on_upgrade(move |socket| handle_socket(socket, state))
+/// messages via an mpsc channel. Connecting with `?session_id=<uuid>&last_event_id=<uuid>`
+/// subscribes straight away, which is how clients resume after a disconnect.
+/// `display_name` or `access_token` may be added to name the viewer in presence.
+pub async fn ws_handler(
+    ws: WebSocketUpgrade,
+    State(state): State<AppState>,
+    Query(params): Query<WsConnectParams>,
+) -> Response {
+    ws.on_upgrade(move |socket| handle_socket(socket, state, params))
 }
 
-async fn handle_socket(socket: WebSocket, state: AppState) {
+/// GET /ws/stats
+/// Outbound queue depth across connections, plus how many messages were
+/// skipped and clients dropped for falling behind
+pub async fn ws_stats(State(state): State<AppState>) -> Json<SendQueueStats> {
+    Json(state.ws_manager.send_queue_stats())
+}
+
+async fn handle_socket(socket: WebSocket, state: AppState, params: WsConnectParams) {
     let connection_id = Uuid::new_v4();
     info!(connection_id = %connection_id, "WebSocket connection established");
 
     let (mut ws_sender, mut ws_receiver) = socket.split();
 
     // Channel for server-push messages (ConnectionManager → this handler → WS client)
-    let (tx, mut rx) = tokio::sync::mpsc::unbounded_channel::<String>();
+    let (tx, mut rx) = tokio::sync::mpsc::channel::<String>(state.ws_manager.send_queue().capacity);
     let mut subscribed_session_id: Option<Uuid> = None;
+    let mut chat_limiter = RateLimiter::new(CHAT_RATE_LIMIT, CHAT_RATE_WINDOW);
+    let mut message_limiter = RateLimiter::new(MESSAGE_RATE_LIMIT, MESSAGE_RATE_WINDOW);
+    let mut rate_limit_strikes: u32 = 0;
+
+    if let Some(session_id) = params.session_id {
+        info!(connection_id = %connection_id, session_id = %session_id, "Client subscribing to session on connect");
+        subscribed_session_id = Some(session_id);
+        let viewer = Viewer {
+            display_name: params.display_name,
+            access_token: params.access_token,
+        };
+        for response in subscribe(
+            &state,
+            connection_id,
+            session_id,
+            params.last_event_id,
+            viewer,
+            &tx,
+        )
+        .await
+        {
+            if let Ok(json) = response.to_json() {
+                if let Err(e) = ws_sender.send(Message::Text(json.into())).await {
+                    error!(connection_id = %connection_id, error = %e, "Failed to send subscription response");
+                    disconnect(&state, connection_id).await;
+                    return;
+                }
+            }
+        }
+    }
+
+    // The first tick fires immediately; skip it so the first ping waits a full interval
+    let mut heartbeat = tokio::time::interval(state.ws_manager.heartbeat().interval);
+    heartbeat.tick().await;
 
     loop {
         tokio::select! {
+            // Heartbeat: ping the client, and stop if the reaper dropped us
+            _ = heartbeat.tick() => {
+                if subscribed_session_id.is_some() && !state.ws_manager.is_connected(connection_id) {
+                    info!(connection_id = %connection_id, "WebSocket connection reaped after missed heartbeats");
+                    break;
+                }
+                if let Err(e) = ws_sender.send(Message::Ping(Vec::new().into())).await {
+                    error!(connection_id = %connection_id, error = %e, "Failed to send heartbeat ping");
+                    break;
+                }
+            }
             // Outbound: forward server-push messages to the WS client
             Some(msg) = rx.recv() => {
                 if let Err(e) = ws_sender.send(Message::Text(msg.into())).await {
@@ -42,26 +136,54 @@ async fn handle_socket(socket: WebSocket, state: AppState) {
             }
             // Inbound: handle messages from the WS client
             msg = ws_receiver.next() => {
+                if matches!(msg, Some(Ok(_))) {
+                    state.ws_manager.record_heartbeat(connection_id);
+                }
                 match msg {
                     Some(Ok(Message::Text(text))) => {
+                        if !message_limiter.try_acquire() {
+                            rate_limit_strikes += 1;
+                            if rate_limit_strikes > MAX_RATE_LIMIT_STRIKES {
+                                warn!(connection_id = %connection_id, "Closing WebSocket connection that kept exceeding the rate limit");
+                                break;
+                            }
+                            let response = ServerMessage::rate_limited(
+                                "Too many messages, slow down".to_string(),
+                                message_limiter.retry_after(),
+                            );
+                            if let Ok(json) = response.to_json() {
+                                let _ = ws_sender.send(Message::Text(json.into())).await;
+                            }
+                            continue;
+                        }
+                        rate_limit_strikes = 0;
+
                         match ClientMessage::from_json(&text) {
                             Ok(client_msg) => {
                                 match client_msg {
-                                    ClientMessage::Subscribe { session_id } => {
+                                    ClientMessage::Subscribe { session_id, last_event_id, display_name, access_token } => {
                                         info!(connection_id = %connection_id, session_id = %session_id, "Client subscribing to session");
-
-                                        // Register with ConnectionManager
-                                        state.ws_manager.add_connection(connection_id, session_id, tx.clone());
+                                        // Switching sessions leaves the old one
+                                        if subscribed_session_id.is_some_and(|id| id != session_id) {
+                                            disconnect(&state, connection_id).await;
+                                        }
                                         subscribed_session_id = Some(session_id);
+                                        let viewer = Viewer { display_name, access_token };
 
-                                        // Send Subscribed confirmation directly
-                                        let response = ServerMessage::subscribed(session_id);
-                                        if let Ok(json) = response.to_json() {
-                                            if let Err(e) = ws_sender.send(Message::Text(json.into())).await {
-                                                error!(connection_id = %connection_id, error = %e, "Failed to send Subscribed response");
-                                                break;
+                                        // Send Subscribed confirmation and state sync directly
+                                        let mut send_failed = false;
+                                        for response in subscribe(&state, connection_id, session_id, last_event_id, viewer, &tx).await {
+                                            if let Ok(json) = response.to_json() {
+                                                if let Err(e) = ws_sender.send(Message::Text(json.into())).await {
+                                                    error!(connection_id = %connection_id, error = %e, "Failed to send subscription response");
+                                                    send_failed = true;
+                                                    break;
+                                                }
                                             }
                                         }
+                                        if send_failed {
+                                            break;
+                                        }
                                     }
                                     ClientMessage::Ping => {
                                         let response = ServerMessage::pong();
@@ -72,9 +194,35 @@ async fn handle_socket(socket: WebSocket, state: AppState) {
                                             }
                                         }
                                     }
+                                    ClientMessage::ChatMessage { session_id, body, display_name, access_token } => {
+                                        let rejection = if subscribed_session_id != Some(session_id) {
+                                            Some(ServerMessage::error_with_code(
+                                                ErrorCode::NotSubscribed,
+                                                "Subscribe to the session before sending chat messages".to_string(),
+                                            ))
+                                        } else if !chat_limiter.try_acquire() {
+                                            Some(ServerMessage::rate_limited(
+                                                "Too many chat messages, slow down".to_string(),
+                                                chat_limiter.retry_after(),
+                                            ))
+                                        } else {
+                                            // On success the broadcast echoes the message back to this connection too
+                                            post_chat_message(&state, session_id, body, display_name, access_token.as_deref())
+                                                .await
+                                                .err()
+                                                .map(|e| ServerMessage::error(e.into_parts().2))
+                                        };
+                                        if let Some(response) = rejection {
+                                            warn!(connection_id = %connection_id, session_id = %session_id, reason = ?response, "Chat message rejected");
+                                            if let Ok(json) = response.to_json() {
+                                                let _ = ws_sender.send(Message::Text(json.into())).await;
+                                            }
+                                        }
+                                    }
                                     ClientMessage::MakePick { .. } => {
                                         warn!(connection_id = %connection_id, "MakePick not implemented via WebSocket");
-                                        let response = ServerMessage::error(
+                                        let response = ServerMessage::error_with_code(
+                                            ErrorCode::Unsupported,
                                             "MakePick is not yet implemented via WebSocket. Please use the REST API endpoint: POST /api/v1/sessions/:id/picks".to_string()
                                         );
                                         if let Ok(json) = response.to_json() {
@@ -83,7 +231,8 @@ async fn handle_socket(socket: WebSocket, state: AppState) {
                                     }
                                     ClientMessage::ProposeTrade { .. } => {
                                         warn!(connection_id = %connection_id, "ProposeTrade not implemented via WebSocket");
-                                        let response = ServerMessage::error(
+                                        let response = ServerMessage::error_with_code(
+                                            ErrorCode::Unsupported,
                                             "ProposeTrade is not yet implemented via WebSocket. Please use the REST API endpoint: POST /api/v1/trades".to_string()
                                         );
                                         if let Ok(json) = response.to_json() {
@@ -94,7 +243,10 @@ async fn handle_socket(socket: WebSocket, state: AppState) {
                             }
                             Err(e) => {
                                 warn!(connection_id = %connection_id, error = %e, "Failed to parse client message");
-                                let error_msg = ServerMessage::error(format!("Invalid message format: {}", e));
+                                let error_msg = ServerMessage::error_with_code(
+                                    ErrorCode::InvalidMessage,
+                                    format!("Invalid message format: {}", e),
+                                );
                                 if let Ok(json) = error_msg.to_json() {
                                     let _ = ws_sender.send(Message::Text(json.into())).await;
                                 }
@@ -130,7 +282,211 @@ async fn handle_socket(socket: WebSocket, state: AppState) {
 
     // Clean up connection on disconnect
     if subscribed_session_id.is_some() {
-        state.ws_manager.remove_connection(connection_id);
+        disconnect(&state, connection_id).await;
     }
     info!(connection_id = %connection_id, "WebSocket connection closed");
 }
+
+/// How a subscribing client would like to appear in presence
+struct Viewer {
+    display_name: Option<String>,
+    access_token: Option<String>,
+}
+
+impl Viewer {
+    /// A signed-in participant of the session is named after their seat;
+    /// otherwise the self-chosen display name is used, if any. A chosen name
+    /// that belongs to a participant is refused, as it is in chat.
+    async fn resolve_name(self, state: &AppState, session_id: Uuid) -> ApiResult<Option<String>> {
+        if let Some(token) = self.access_token {
+            let participant = match AuthUser::from_token(&token, state) {
+                Ok(user) => participant_for_user(state, session_id, user.user_id).await,
+                Err(e) => Err(e),
+            };
+            match participant {
+                Ok(Some(participant)) => return Ok(Some(participant.display_name)),
+                Ok(None) => {}
+                Err(e) => {
+                    warn!(session_id = %session_id, error = ?e, "Failed to look up participant for access token")
+                }
+            }
+        }
+        let name = self
+            .display_name
+            .map(|name| {
+                name.trim()
+                    .chars()
+                    .take(MAX_DISPLAY_NAME_CHARS)
+                    .collect::<String>()
+            })
+            .filter(|name| !name.is_empty());
+        if let Some(name) = &name {
+            ensure_not_participant_name(state, session_id, name).await?;
+        }
+        Ok(name)
+    }
+}
+
+/// Remove the connection and tell the sessions it left who is still watching
+async fn disconnect(state: &AppState, connection_id: Uuid) {
+    for session_id in state.ws_manager.remove_connection(connection_id) {
+        state.ws_manager.broadcast_presence(session_id).await;
+    }
+}
+
+/// Register the connection with the session and build the messages to send back:
+/// the `Subscribed` confirmation followed by a `StateSync` snapshot. Everyone
+/// else in the session gets an updated `Presence`.
+async fn subscribe(
+    state: &AppState,
+    connection_id: Uuid,
+    session_id: Uuid,
+    last_event_id: Option<Uuid>,
+    viewer: Viewer,
+    tx: &WsSender,
+) -> Vec<ServerMessage> {
+    // A refused name leaves the viewer anonymous rather than unsubscribed
+    let (display_name, refusal) = match viewer.resolve_name(state, session_id).await {
+        Ok(name) => (name, None),
+        Err(e) => {
+            warn!(connection_id = %connection_id, session_id = %session_id, error = ?e, "Display name refused");
+            (None, Some(ServerMessage::error(e.into_parts().2)))
+        }
+    };
+    state
+        .ws_manager
+        .set_display_name(connection_id, display_name);
+    state
+        .ws_manager
+        .add_connection(connection_id, session_id, tx.clone());
+    state.ws_manager.broadcast_presence(session_id).await;
+
+    let sync = match build_state_sync(state, session_id, last_event_id).await {
+        Ok(Some(sync)) => sync,
+        Ok(None) => ServerMessage::error(format!("Session {} not found", session_id)),
+        Err(e) => {
+            error!(connection_id = %connection_id, session_id = %session_id, error = ?e, "Failed to build state sync");
+            ServerMessage::error("Failed to load session state".to_string())
+        }
+    };
+
+    let mut messages = vec![ServerMessage::subscribed(session_id), sync];
+    messages.extend(refusal);
+    messages
+}
+
+/// Build a `StateSync` message for a session, or `None` if the session does not exist.
+async fn build_state_sync(
+    state: &AppState,
+    session_id: Uuid,
+    last_event_id: Option<Uuid>,
+) -> ApiResult<Option<ServerMessage>> {
+    let Some(session) = state.session_repo.find_by_id(session_id).await? else {
+        return Ok(None);
+    };
+
+    let (events_result, trades_result) = tokio::join!(
+        state.event_repo.list_by_session(session_id),
+        state.trade_repo.find_proposals_by_session(session_id),
+    );
+
+    let recent_events = events_since(events_result?, last_event_id, STATE_SYNC_EVENT_LIMIT)
+        .into_iter()
+        .map(|event| SyncedEvent {
+            id: event.id,
+            event_type: event.event_type.to_string(),
+            event_data: event.event_data,
+            created_at: event.created_at.to_rfc3339(),
+        })
+        .collect();
+
+    let pending_trades = trades_result?
+        .into_iter()
+        .filter(|proposal| proposal.trade.status == TradeStatus::Proposed)
+        .map(|proposal| PendingTrade {
+            trade_id: proposal.trade.id,
+            from_team_id: proposal.trade.from_team_id,
+            to_team_id: proposal.trade.to_team_id,
+            from_team_picks: proposal.from_team_picks,
+            to_team_picks: proposal.to_team_picks,
+            from_team_value: proposal.trade.from_team_value,
+            to_team_value: proposal.trade.to_team_value,
+        })
+        .collect();
+
+    Ok(Some(ServerMessage::state_sync(
+        session_id,
+        session.status.to_string(),
+        session.current_pick_number,
+        session.time_remaining(),
+        recent_events,
+        pending_trades,
+    )))
+}
+
+/// Select the events a client has not seen yet.
+///
+/// Returns everything after `last_event_id` when it is present in the stream;
+/// otherwise (first connect, or an id we no longer recognise) falls back to the
+/// trailing `limit` events.
+fn events_since(
+    events: Vec<DraftEvent>,
+    last_event_id: Option<Uuid>,
+    limit: usize,
+) -> Vec<DraftEvent> {
+    if let Some(last_id) = last_event_id {
+        if let Some(pos) = events.iter().position(|e| e.id == last_id) {
+            return events.into_iter().skip(pos + 1).collect();
+        }
+    }
+
+    let skip = events.len().saturating_sub(limit);
+    events.into_iter().skip(skip).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn make_events(session_id: Uuid, count: usize) -> Vec<DraftEvent> {
+        (0..count)
+            .map(|i| DraftEvent::clock_update(session_id, i as i32))
+            .collect()
+    }
+
+    #[test]
+    fn test_events_since_known_id_returns_later_events() {
+        let events = make_events(Uuid::new_v4(), 5);
+        let last_seen = events[2].id;
+        let expected: Vec<Uuid> = events[3..].iter().map(|e| e.id).collect();
+
+        let result = events_since(events, Some(last_seen), 50);
+
+        assert_eq!(result.iter().map(|e| e.id).collect::<Vec<_>>(), expected);
+    }
+
+    #[test]
+    fn test_events_since_latest_id_returns_nothing() {
+        let events = make_events(Uuid::new_v4(), 3);
+        let last_seen = events[2].id;
+
+        assert!(events_since(events, Some(last_seen), 50).is_empty());
+    }
+
+    #[test]
+    fn test_events_since_unknown_id_falls_back_to_tail() {
+        let events = make_events(Uuid::new_v4(), 10);
+        let expected: Vec<Uuid> = events[7..].iter().map(|e| e.id).collect();
+
+        let result = events_since(events, Some(Uuid::new_v4()), 3);
+
+        assert_eq!(result.iter().map(|e| e.id).collect::<Vec<_>>(), expected);
+    }
+
+    #[test]
+    fn test_events_since_without_id_caps_at_limit() {
+        let events = make_events(Uuid::new_v4(), 2);
+
+        assert_eq!(events_since(events, None, 50).len(), 2);
+    }
+}
@@ -1,9 +1,23 @@
+pub mod audit;
 pub mod auth;
+pub mod batch;
+pub mod clock;
 pub mod config;
 pub mod error;
+pub mod etag;
+pub mod fields;
+pub mod graphql;
 pub mod handlers;
+pub mod notify;
 pub mod openapi;
+pub mod outbox;
+pub mod pagination;
+pub mod rate_limit;
+pub mod request_id;
+pub mod response_format;
 pub mod routes;
+pub mod scheduler;
+pub mod sorting;
 pub mod state;
 
 pub use config::Config;
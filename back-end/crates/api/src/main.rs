@@ -1,6 +1,14 @@
+use std::net::SocketAddr;
+use std::sync::Arc;
+
 use tracing_subscriber::{layer::SubscriberExt, util::SubscriberInitExt};
 
+use api::notify::PgNotifyRelay;
 use api::{AppState, Config};
+use db::cache::{Cache, RedisCache};
+
+/// Apply pending migrations and exit without starting the server
+const MIGRATE_ONLY_FLAG: &str = "--migrate-only";
 
 #[tokio::main]
 async fn main() -> anyhow::Result<()> {
@@ -15,15 +23,98 @@ async fn main() -> anyhow::Result<()> {
 
     // Load configuration
     let config = Config::from_env()?;
+    let migrate_only = std::env::args().any(|arg| arg == MIGRATE_ONLY_FLAG);
+
+    // Create database pools
+    let pools = db::create_pools(
+        &config.database.url,
+        config.database.read_url.as_deref(),
+        &config.database.pool.settings(),
+    )
+    .await?;
+    tracing::info!(
+        "Database connection pool created (max {} connections)",
+        config.database.pool.max_connections
+    );
+    if config.database.read_url.is_some() {
+        tracing::info!("Sending reference data reads to the read replica");
+    }
+    db::set_slow_query_threshold(config.database.slow_query_threshold());
+
+    // Bring the schema up to date; a failed migration stops startup rather
+    // than serving against a schema the code doesn't expect
+    if migrate_only || config.database.run_migrations {
+        let applied = db::run_migrations(&pools.write).await?;
+        tracing::info!("Applied {} pending database migrations", applied);
+    }
+    if migrate_only {
+        return Ok(());
+    }
+
     tracing::info!("Starting NFL Draft API server");
     tracing::info!("Server will listen on: {}", config.server_address());
 
-    // Create database pool
-    let pool = db::create_pool(&config.database.url).await?;
-    tracing::info!("Database connection pool created");
+    // Connect to the optional Redis cache
+    let cache: Option<Arc<dyn Cache>> = match &config.cache.redis_url {
+        Some(url) => {
+            let cache = RedisCache::connect(url, config.cache.ttl()).await?;
+            tracing::info!("Caching teams, rankings and percentiles in Redis");
+            Some(Arc::new(cache))
+        }
+        None => None,
+    };
 
     // Create application state
-    let state = AppState::new(pool, config.seed_api_key.clone());
+    let relay_pool = pools.write.clone();
+    let mut state = AppState::with_pools(
+        pools,
+        config.seed_api_key.clone(),
+        config.source_precedence.clone(),
+        cache,
+    )
+    .with_heartbeat(config.websocket.heartbeat())
+    .with_send_queue(config.websocket.send_queue());
+    match config.auth.jwt_keys() {
+        Some(keys) => state = state.with_jwt(keys),
+        None => tracing::warn!(
+            "JWT_SECRET is not set; using a random secret, so tokens will not survive a restart"
+        ),
+    }
+    if let Some(email) = config.auth.admin_email.as_deref() {
+        tracing::info!("Registering {} will create an admin account", email);
+        state = state.with_admin_email(email);
+    }
+    if let Some(settings) = config.rate_limit.settings() {
+        tracing::info!(
+            "Rate limiting REST requests to {} per {:?} per client",
+            settings.ip_requests,
+            settings.window
+        );
+        state = state.with_rate_limit(settings);
+    }
+
+    // Share session broadcasts with the other API instances
+    if config.websocket.pg_notify {
+        let instance_id = uuid::Uuid::new_v4();
+        state = state.with_broadcast_relay(Arc::new(PgNotifyRelay::spawn(relay_pool, instance_id)));
+        api::notify::spawn_notify_listener(
+            config.database.url.clone(),
+            state.ws_manager.clone(),
+            instance_id,
+        );
+    }
+
+    // Drop WebSocket connections that stop answering heartbeats
+    state.ws_manager.spawn_reaper();
+
+    // Start sessions that were scheduled for a set time
+    api::scheduler::spawn_session_scheduler(state.clone(), api::scheduler::SCHEDULER_INTERVAL);
+
+    // Broadcast the pick clock so clients don't run their own countdowns
+    api::clock::spawn_session_clock(state.clone(), api::clock::CLOCK_TICK_INTERVAL);
+
+    // Publish draft events whose broadcast was lost, e.g. to a restart
+    api::outbox::spawn_outbox_relay(state.clone(), api::outbox::OUTBOX_RELAY_INTERVAL);
 
     // Create router with configured CORS origins
     let app = api::routes::create_router_with_cors(state, &config.cors_origins);
@@ -33,7 +124,12 @@ async fn main() -> anyhow::Result<()> {
     tracing::info!("Server listening on {}", config.server_address());
 
     // Run the server
-    axum::serve(listener, app).await?;
+    // Keep the peer address available for per-client rate limiting
+    axum::serve(
+        listener,
+        app.into_make_service_with_connect_info::<SocketAddr>(),
+    )
+    .await?;
 
     Ok(())
 }
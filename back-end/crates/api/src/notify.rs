@@ -0,0 +1,129 @@
+//! Relays session broadcasts between API instances over Postgres
+//! LISTEN/NOTIFY, so clients see the same events whichever instance behind
+//! the load balancer they are connected to.
+//!
+//! Messages are published where they are broadcast rather than from the
+//! repositories, so other instances receive the finished message, team and
+//! player names included, without repeating the lookups. Clock and presence
+//! messages stay on the instance that produced them: each instance runs the
+//! pick clock for the sessions it is serving and only knows its own viewers.
+
+use std::time::Duration;
+
+use serde::{Deserialize, Serialize};
+use sqlx::postgres::PgListener;
+use sqlx::PgPool;
+use tokio::sync::mpsc;
+use tokio::task::JoinHandle;
+use uuid::Uuid;
+use websocket::{BroadcastRelay, ConnectionManager, ServerMessage};
+
+/// Channel the instances publish and listen on
+pub const NOTIFY_CHANNEL: &str = "session_broadcasts";
+
+/// Postgres rejects NOTIFY payloads of 8000 bytes or more
+const MAX_PAYLOAD_BYTES: usize = 7999;
+
+/// How long the listener waits before reconnecting after losing the database
+const LISTENER_RETRY_INTERVAL: Duration = Duration::from_secs(1);
+
+#[derive(Debug, Serialize, Deserialize)]
+struct RelayedBroadcast {
+    /// Instance that made the broadcast, which has already delivered it
+    origin: Uuid,
+    session_id: Uuid,
+    message: ServerMessage,
+}
+
+/// Publishes this instance's session broadcasts with `pg_notify`
+pub struct PgNotifyRelay {
+    origin: Uuid,
+    sender: mpsc::UnboundedSender<String>,
+}
+
+impl PgNotifyRelay {
+    /// Starts the publishing task. One task sends every notification so they
+    /// go out in the order they were broadcast.
+    pub fn spawn(pool: PgPool, origin: Uuid) -> Self {
+        let (sender, mut receiver) = mpsc::unbounded_channel::<String>();
+        tokio::spawn(async move {
+            while let Some(payload) = receiver.recv().await {
+                if let Err(e) = sqlx::query("SELECT pg_notify($1, $2)")
+                    .bind(NOTIFY_CHANNEL)
+                    .bind(&payload)
+                    .execute(&pool)
+                    .await
+                {
+                    tracing::warn!("Failed to relay session broadcast: {}", e);
+                }
+            }
+        });
+        Self { origin, sender }
+    }
+}
+
+impl BroadcastRelay for PgNotifyRelay {
+    fn publish(&self, session_id: Uuid, message: &ServerMessage) {
+        let relayed = RelayedBroadcast {
+            origin: self.origin,
+            session_id,
+            message: message.clone(),
+        };
+        let payload = match serde_json::to_string(&relayed) {
+            Ok(payload) => payload,
+            Err(e) => {
+                tracing::error!("Failed to serialize relayed broadcast: {}", e);
+                return;
+            }
+        };
+        if payload.len() > MAX_PAYLOAD_BYTES {
+            tracing::warn!(
+                session_id = %session_id,
+                "Broadcast of {} bytes is too large to relay to other instances",
+                payload.len()
+            );
+            return;
+        }
+        // Only fails once the runtime is shutting down
+        let _ = self.sender.send(payload);
+    }
+}
+
+/// Spawns the listener that delivers broadcasts relayed by other instances to
+/// this instance's connections. It has its own database connection, outside
+/// the pool, and reconnects if it is lost. Runs until the runtime shuts down.
+pub fn spawn_notify_listener(
+    database_url: String,
+    ws_manager: ConnectionManager,
+    origin: Uuid,
+) -> JoinHandle<()> {
+    tokio::spawn(async move {
+        loop {
+            if let Err(e) = listen(&database_url, &ws_manager, origin).await {
+                tracing::warn!("Session broadcast listener failed: {}", e);
+            }
+            tokio::time::sleep(LISTENER_RETRY_INTERVAL).await;
+        }
+    })
+}
+
+async fn listen(
+    database_url: &str,
+    ws_manager: &ConnectionManager,
+    origin: Uuid,
+) -> Result<(), sqlx::Error> {
+    let mut listener = PgListener::connect(database_url).await?;
+    listener.listen(NOTIFY_CHANNEL).await?;
+    loop {
+        let notification = listener.recv().await?;
+        match serde_json::from_str::<RelayedBroadcast>(notification.payload()) {
+            Ok(relayed) if relayed.origin != origin => {
+                ws_manager
+                    .deliver_to_session(relayed.session_id, relayed.message)
+                    .await;
+            }
+            Ok(_) => {}
+            Err(e) => tracing::warn!("Ignoring unreadable relayed broadcast: {}", e),
+        }
+    }
+}
@@ -1,7 +1,12 @@
 use utoipa::OpenApi;
 
-use crate::handlers::{drafts, health, players, seed, teams, trades};
-use domain::models::{ChartType, Conference, Division, DraftStatus, Position};
+use crate::handlers::{
+    audit_log, auth, drafts, health, player_notes, player_tags, players, seed, teams, trades,
+    watchlist,
+};
+use domain::models::{
+    ChartType, Conference, Division, DraftStatus, InjuryStatus, NoteTag, Position, Role,
+};
 
 #[derive(OpenApi)]
 #[openapi(
@@ -17,16 +22,43 @@ use domain::models::{ChartType, Conference, Division, DraftStatus, Position};
     paths(
         // Health
         health::health_check,
+        health::liveness,
+        health::readiness,
+        health::database_health,
+
+        // Auth
+        auth::register,
+        auth::login,
+        auth::me,
+
+        // Watchlist
+        watchlist::get_watchlist,
+        watchlist::add_to_watchlist,
+        watchlist::remove_from_watchlist,
 
         // Teams
         teams::list_teams,
         teams::get_team,
         teams::create_team,
+        teams::update_team,
+        teams::replace_team,
+        teams::delete_team,
 
         // Players
         players::list_players,
         players::get_player,
         players::create_player,
+        players::update_player,
+        players::delete_player,
+        player_notes::create_player_note,
+        player_notes::list_player_notes,
+        player_notes::get_player_note,
+        player_notes::update_player_note,
+        player_notes::delete_player_note,
+        player_tags::list_player_tags,
+        player_tags::add_player_tag,
+        player_tags::remove_player_tag,
+        player_tags::list_tags,
 
         // Drafts
         drafts::create_draft,
@@ -39,6 +71,11 @@ use domain::models::{ChartType, Conference, Division, DraftStatus, Position};
         drafts::start_draft,
         drafts::pause_draft,
         drafts::complete_draft,
+        drafts::get_team_draft_class,
+        drafts::get_value_analysis,
+        drafts::get_surplus_report,
+        drafts::get_draft_scarcity,
+        drafts::get_draft_accuracy,
 
         // Picks
         drafts::make_pick,
@@ -57,7 +94,14 @@ use domain::models::{ChartType, Conference, Division, DraftStatus, Position};
         // Admin
         seed::seed_players,
         seed::seed_teams,
+        seed::seed_team_seasons,
+        seed::seed_team_needs,
+        seed::seed_draft_order,
         seed::seed_feldman_freaks,
+        auth::update_user_role,
+        players::restore_player,
+        teams::restore_team,
+        audit_log::list_audit_log,
     ),
     components(
         schemas(
@@ -67,20 +111,72 @@ use domain::models::{ChartType, Conference, Division, DraftStatus, Position};
             Division,
             Position,
             DraftStatus,
+            InjuryStatus,
+            NoteTag,
+            Role,
+
+            // Error types
+            crate::error::ErrorResponse,
+            crate::error::ErrorCode,
+
+            // Health types
+            health::ComponentState,
+            health::ComponentStatus,
+            health::Readiness,
+            health::ReadinessResponse,
+            health::PoolUtilization,
+            health::MigrationReport,
+            health::TableRowCount,
+            health::ReplicationStatus,
+            health::DatabaseHealthResponse,
+
+            // Auth types
+            auth::RegisterRequest,
+            auth::LoginRequest,
+            auth::TokenResponse,
+            auth::UserResponse,
+            auth::UpdateRoleRequest,
+            audit_log::AuditEntryResponse,
+
+            // Watchlist types
+            watchlist::AddToWatchlistRequest,
+            watchlist::WatchlistEntryResponse,
 
             // Team types
             teams::TeamResponse,
             teams::CreateTeamRequest,
+            teams::UpdateTeamRequest,
 
             // Player types
             players::PlayerResponse,
             players::CreatePlayerRequest,
+            players::UpdatePlayerRequest,
+            players::PlayerDetailResponse,
+            player_notes::PlayerNoteResponse,
+            player_notes::CreatePlayerNoteRequest,
+            player_notes::UpdatePlayerNoteRequest,
+            player_tags::PlayerTagResponse,
+            player_tags::AddPlayerTagRequest,
+            player_tags::TagCountResponse,
 
             // Draft types
             drafts::DraftResponse,
             drafts::CreateDraftRequest,
             drafts::DraftPickResponse,
             drafts::MakePickRequest,
+            drafts::TeamDraftClassResponse,
+            drafts::ClassSelectionResponse,
+            drafts::ValueAnalysisResponse,
+            drafts::PickValueResponse,
+            drafts::TeamValueResponse,
+            drafts::SurplusReportResponse,
+            drafts::PickSurplusResponse,
+            drafts::TeamSurplusResponse,
+            drafts::DraftScarcityResponse,
+            drafts::PositionScarcityResponse,
+            drafts::ScarcityTierResponse,
+            drafts::DraftAccuracyResponse,
+            drafts::PickAccuracyResponse,
 
             // Trade types
             trades::TradeResponse,
@@ -99,8 +195,12 @@ use domain::models::{ChartType, Conference, Division, DraftStatus, Position};
     ),
     tags(
         (name = "health", description = "Health check endpoints"),
+        (name = "auth", description = "User registration and sign-in"),
+        (name = "watchlist", description = "Players the signed-in user is tracking across sessions"),
         (name = "teams", description = "NFL team management"),
         (name = "players", description = "Player management and scouting"),
+        (name = "player-notes", description = "Dated player news: injuries, visits, risers and fallers"),
+        (name = "player-tags", description = "Free-form player labels such as team captain or early declare"),
         (name = "drafts", description = "Draft management and lifecycle"),
         (name = "picks", description = "Draft pick operations"),
         (name = "trades", description = "Draft pick trading operations"),
@@ -0,0 +1,250 @@
+//! Background relay that publishes draft events left in the outbox.
+//!
+//! Every draft event is written with an outbox entry in the same transaction
+//! as the change it records. The request that made the change broadcasts it
+//! straight away and marks the entry published; the relay picks up entries
+//! still pending after a grace period, typically because the instance
+//! restarted between the commit and the broadcast, and broadcasts them from
+//! the event data. Delivery is at least once: an event broadcast just before
+//! a crash can be sent again, so clients must apply messages idempotently.
+//! Events with nothing to announce, such as settings changes, are marked
+//! published without a broadcast.
+
+use std::time::Duration;
+
+use chrono::Utc;
+use serde::de::DeserializeOwned;
+use serde::Deserialize;
+use tokio::task::JoinHandle;
+use uuid::Uuid;
+use websocket::ServerMessage;
+
+use domain::models::{DraftEvent, EventType};
+
+use crate::error::{ApiError, ApiResult};
+use crate::state::AppState;
+
+/// How often the relay looks for pending events
+pub const OUTBOX_RELAY_INTERVAL: Duration = Duration::from_secs(5);
+
+/// How long an event is left to the request that recorded it before the
+/// relay publishes it, and how long a claimed event waits before a retry
+pub const OUTBOX_GRACE_PERIOD: Duration = Duration::from_secs(10);
+
+/// Most events published in one pass
+const RELAY_BATCH_SIZE: i64 = 100;
+
+/// Spawns the relay loop. It runs until the runtime shuts down.
+pub fn spawn_outbox_relay(state: AppState, interval: Duration) -> JoinHandle<()> {
+    tokio::spawn(async move {
+        let mut ticker = tokio::time::interval(interval);
+        ticker.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Delay);
+        loop {
+            ticker.tick().await;
+            if let Err(e) = relay_pending_events(&state, OUTBOX_GRACE_PERIOD).await {
+                tracing::warn!("Outbox relay pass failed: {:?}", e);
+            }
+        }
+    })
+}
+
+/// Publishes events that have been pending for at least `grace` and returns
+/// how many were published. An event whose message cannot be built is logged
+/// and retried once `grace` has passed again.
+pub async fn relay_pending_events(state: &AppState, grace: Duration) -> ApiResult<usize> {
+    let grace = chrono::Duration::from_std(grace)
+        .map_err(|e| ApiError::InternalError(format!("Invalid outbox grace period: {}", e)))?;
+    let events = state
+        .event_repo
+        .claim_unpublished(Utc::now() - grace, RELAY_BATCH_SIZE)
+        .await?;
+
+    let mut published = Vec::with_capacity(events.len());
+    for event in events {
+        match event_message(state, &event).await {
+            Ok(message) => {
+                if let Some(message) = message {
+                    state
+                        .ws_manager
+                        .broadcast_to_session(event.session_id, message)
+                        .await;
+                }
+                published.push(event.id);
+            }
+            Err(e) => {
+                tracing::warn!(event_id = %event.id, "Failed to relay outbox event: {:?}", e);
+            }
+        }
+    }
+
+    if !published.is_empty() {
+        state.event_repo.mark_published(&published).await?;
+        tracing::info!("Relayed {} pending draft events", published.len());
+    }
+    Ok(published.len())
+}
+
+/// Marks events published once the request that recorded them has broadcast
+/// them. A failure only means the relay sends them again, so it is logged
+/// rather than failing the request.
+pub(crate) async fn mark_published(state: &AppState, event_ids: &[Uuid]) {
+    if let Err(e) = state.event_repo.mark_published(event_ids).await {
+        tracing::warn!("Failed to mark draft events published: {:?}", e);
+    }
+}
+
+/// Marks the `PickMade` events for picks made in a session published once
+/// they have been broadcast
+pub(crate) async fn mark_picks_published(state: &AppState, session_id: Uuid, pick_ids: &[Uuid]) {
+    if let Err(e) = state
+        .event_repo
+        .mark_picks_published(session_id, pick_ids)
+        .await
+    {
+        tracing::warn!(session_id = %session_id, "Failed to mark picks published: {:?}", e);
+    }
+}
+
+#[derive(Deserialize)]
+struct PickMadeData {
+    pick_id: Uuid,
+    team_id: Uuid,
+    player_id: Uuid,
+    round: i32,
+    pick_number: i32,
+}
+
+#[derive(Deserialize)]
+struct TradeProposedData {
+    trade_id: Uuid,
+}
+
+#[derive(Deserialize)]
+struct TradeExecutedData {
+    trade_id: Uuid,
+    from_team_id: Uuid,
+    to_team_id: Uuid,
+}
+
+#[derive(Deserialize)]
+struct TradeRejectedData {
+    trade_id: Uuid,
+    rejecting_team_id: Uuid,
+}
+
+#[derive(Deserialize)]
+struct SessionRewoundData {
+    to_pick_number: i32,
+    reversed_trade_ids: Vec<Uuid>,
+}
+
+fn event_data<T: DeserializeOwned>(event: &DraftEvent) -> ApiResult<T> {
+    serde_json::from_value(event.event_data.clone()).map_err(|e| {
+        ApiError::InternalError(format!(
+            "Unreadable {} event {}: {}",
+            event.event_type, event.id, e
+        ))
+    })
+}
+
+/// The message the request that recorded `event` broadcast for it, rebuilt
+/// from the event data. Names are looked up again, so a team or player
+/// deleted since leaves nothing to announce.
+async fn event_message(state: &AppState, event: &DraftEvent) -> ApiResult<Option<ServerMessage>> {
+    let session_id = event.session_id;
+    let message = match event.event_type {
+        EventType::SessionStarted | EventType::SessionResumed => Some(ServerMessage::draft_status(
+            session_id,
+            "InProgress".to_string(),
+        )),
+        EventType::SessionPaused => Some(ServerMessage::draft_status(
+            session_id,
+            "Paused".to_string(),
+        )),
+        EventType::SessionCompleted => Some(ServerMessage::draft_status(
+            session_id,
+            "Completed".to_string(),
+        )),
+        EventType::PickMade => {
+            let data: PickMadeData = event_data(event)?;
+            let (team, player) = tokio::join!(
+                state.team_repo.find_by_id(data.team_id),
+                state.player_repo.find_by_id(data.player_id),
+            );
+            team?.zip(player?).map(|(team, player)| {
+                ServerMessage::pick_made(
+                    session_id,
+                    data.pick_id,
+                    data.team_id,
+                    data.player_id,
+                    data.round,
+                    data.pick_number,
+                    format!("{} {}", player.first_name, player.last_name),
+                    format!("{} {}", team.city, team.name),
+                )
+            })
+        }
+        EventType::TradeProposed => {
+            let data: TradeProposedData = event_data(event)?;
+            match state
+                .trade_repo
+                .find_trade_with_details(data.trade_id)
+                .await?
+            {
+                Some(proposal) => {
+                    let trade = proposal.trade;
+                    let (from_team, to_team) = tokio::join!(
+                        state.team_repo.find_by_id(trade.from_team_id),
+                        state.team_repo.find_by_id(trade.to_team_id),
+                    );
+                    from_team?.zip(to_team?).map(|(from_team, to_team)| {
+                        ServerMessage::trade_proposed(
+                            session_id,
+                            trade.id,
+                            trade.from_team_id,
+                            trade.to_team_id,
+                            from_team.name,
+                            to_team.name,
+                            proposal.from_team_picks,
+                            proposal.to_team_picks,
+                            trade.from_team_value,
+                            trade.to_team_value,
+                        )
+                    })
+                }
+                None => None,
+            }
+        }
+        EventType::TradeExecuted => {
+            let data: TradeExecutedData = event_data(event)?;
+            Some(ServerMessage::trade_executed(
+                session_id,
+                data.trade_id,
+                data.from_team_id,
+                data.to_team_id,
+            ))
+        }
+        EventType::TradeRejected => {
+            let data: TradeRejectedData = event_data(event)?;
+            Some(ServerMessage::trade_rejected(
+                session_id,
+                data.trade_id,
+                data.rejecting_team_id,
+            ))
+        }
+        EventType::SessionRewound => {
+            let data: SessionRewoundData = event_data(event)?;
+            Some(ServerMessage::session_rewound(
+                session_id,
+                data.to_pick_number,
+                data.reversed_trade_ids,
+            ))
+        }
+        EventType::SessionCreated
+        | EventType::ClockUpdate
+        | EventType::PickAdvanced
+        | EventType::DraftCompleted
+        | EventType::SettingsChanged => None,
+    };
+    Ok(message)
+}
@@ -0,0 +1,170 @@
+//! Opt-in `page`/`per_page` pagination for collection endpoints.
+//!
+//! List handlers keep returning a plain JSON array so existing clients are
+//! unaffected. The size of the whole collection is reported in the
+//! `X-Total-Count` header; when the client asked for a page, `X-Page` and
+//! `X-Per-Page` echo back the page that was served.
+
+use axum::http::{HeaderMap, HeaderName, HeaderValue};
+use axum::response::{IntoResponse, Response};
+use axum::Json;
+use serde::{Deserialize, Serialize};
+use utoipa::IntoParams;
+
+use domain::models::{Page, PageRequest};
+
+use crate::error::ApiResult;
+use crate::fields::FieldSelection;
+use crate::response_format::{csv_columns_response, csv_response, ResponseFormat};
+
+/// Page size used when only `page` is supplied
+pub const DEFAULT_PER_PAGE: u32 = 50;
+
+pub const TOTAL_COUNT_HEADER: &str = "x-total-count";
+pub const PAGE_HEADER: &str = "x-page";
+pub const PER_PAGE_HEADER: &str = "x-per-page";
+
+#[derive(Debug, Default, Deserialize, IntoParams)]
+pub struct PaginationParams {
+    /// 1-based page number; defaults to 1 when `per_page` is given
+    pub page: Option<u32>,
+    /// Items per page (1-500); defaults to 50 when `page` is given
+    pub per_page: Option<u32>,
+}
+
+impl PaginationParams {
+    /// The requested page, or `None` when the client wants the whole collection
+    pub fn page_request(&self) -> ApiResult<Option<PageRequest>> {
+        if self.page.is_none() && self.per_page.is_none() {
+            return Ok(None);
+        }
+        let page = PageRequest::new(
+            self.page.unwrap_or(1),
+            self.per_page.unwrap_or(DEFAULT_PER_PAGE),
+        )?;
+        Ok(Some(page))
+    }
+}
+
+/// A list response carrying pagination headers alongside the JSON array
+#[derive(Debug)]
+pub struct Paginated<T> {
+    items: Vec<T>,
+    total: i64,
+    page: Option<PageRequest>,
+    /// Set when the client asked for a CSV download
+    csv_filename: Option<&'static str>,
+    /// Set when the client asked for only some fields of each item
+    fields: Option<FieldSelection>,
+}
+
+impl<T> Paginated<T> {
+    /// The full collection, unpaged
+    pub fn all(items: Vec<T>) -> Self {
+        let total = items.len() as i64;
+        Self {
+            items,
+            total,
+            page: None,
+            csv_filename: None,
+            fields: None,
+        }
+    }
+
+    /// Results of a repository query, which holds one page when the
+    /// client asked for one and the whole collection otherwise
+    pub fn page(page: Page<T>, request: Option<PageRequest>) -> Self {
+        Self {
+            items: page.items,
+            total: page.total,
+            page: request,
+            csv_filename: None,
+            fields: None,
+        }
+    }
+
+    /// Serve the items as a CSV attachment named `filename` when that is
+    /// the requested format; the pagination headers are sent either way
+    pub fn with_format(mut self, format: ResponseFormat, filename: &'static str) -> Self {
+        if format == ResponseFormat::Csv {
+            self.csv_filename = Some(filename);
+        }
+        self
+    }
+
+    /// Trim each item to the selected fields, when there is a selection
+    pub fn with_fields(mut self, fields: Option<FieldSelection>) -> Self {
+        self.fields = fields;
+        self
+    }
+}
+
+impl<T: Serialize + Send + 'static> IntoResponse for Paginated<T> {
+    fn into_response(self) -> Response {
+        let mut headers = HeaderMap::new();
+        headers.insert(
+            HeaderName::from_static(TOTAL_COUNT_HEADER),
+            HeaderValue::from(self.total),
+        );
+        if let Some(page) = self.page {
+            headers.insert(
+                HeaderName::from_static(PAGE_HEADER),
+                HeaderValue::from(page.page),
+            );
+            headers.insert(
+                HeaderName::from_static(PER_PAGE_HEADER),
+                HeaderValue::from(page.per_page),
+            );
+        }
+        match (self.fields, self.csv_filename) {
+            (None, Some(filename)) => csv_response(self.items, headers, filename),
+            (None, None) => (headers, Json(self.items)).into_response(),
+            (Some(fields), Some(filename)) => {
+                let rows = self.items.iter().map(|item| fields.values(item)).collect();
+                csv_columns_response(fields.names().to_vec(), rows, headers, filename)
+            }
+            (Some(fields), None) => {
+                let items: Vec<_> = self.items.iter().map(|item| fields.object(item)).collect();
+                (headers, Json(items)).into_response()
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_no_params_means_unpaged() {
+        assert_eq!(PaginationParams::default().page_request().unwrap(), None);
+    }
+
+    #[test]
+    fn test_missing_values_use_defaults() {
+        let params = PaginationParams {
+            page: Some(3),
+            per_page: None,
+        };
+        let page = params.page_request().unwrap().unwrap();
+        assert_eq!(page.page, 3);
+        assert_eq!(page.per_page, DEFAULT_PER_PAGE);
+
+        let params = PaginationParams {
+            page: None,
+            per_page: Some(10),
+        };
+        let page = params.page_request().unwrap().unwrap();
+        assert_eq!(page.page, 1);
+        assert_eq!(page.per_page, 10);
+    }
+
+    #[test]
+    fn test_invalid_values_rejected() {
+        let params = PaginationParams {
+            page: Some(0),
+            per_page: None,
+        };
+        assert!(params.page_request().is_err());
+    }
+}
@@ -0,0 +1,220 @@
+//! Per-client request limits for the REST API.
+//!
+//! Requests that present a valid `X-Seed-Api-Key` are counted against that
+//! key, which gets its own (usually larger) allowance; everything else is
+//! counted against the client IP. Unverified keys fall back to the IP bucket
+//! so a client cannot dodge its limit by inventing new keys.
+
+use std::net::{IpAddr, SocketAddr};
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+use axum::extract::{ConnectInfo, Request, State};
+use axum::middleware::Next;
+use axum::response::{IntoResponse, Response};
+use dashmap::DashMap;
+use websocket::RateLimiter;
+
+use crate::auth::{verify_api_key, SEED_API_KEY_HEADER};
+use crate::error::ApiError;
+use crate::state::AppState;
+
+/// Header set by the reverse proxy with the original client address
+const REAL_IP_HEADER: &str = "x-real-ip";
+
+/// Number of tracked clients above which idle limiters are dropped
+const PRUNE_THRESHOLD: usize = 10_000;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RateLimitSettings {
+    /// Requests allowed per window for each client IP
+    pub ip_requests: usize,
+    /// Requests allowed per window for each API key
+    pub api_key_requests: usize,
+    pub window: Duration,
+    /// Take the client address from `X-Real-IP` instead of the socket. Only
+    /// enable this behind a proxy that sets the header itself.
+    pub trust_proxy: bool,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+enum ClientKey {
+    Ip(IpAddr),
+    ApiKey(String),
+}
+
+/// Sliding-window limiters keyed by client, shared by every request
+#[derive(Clone)]
+pub struct ApiRateLimiter {
+    settings: RateLimitSettings,
+    clients: Arc<DashMap<ClientKey, RateLimiter>>,
+}
+
+impl ApiRateLimiter {
+    pub fn new(settings: RateLimitSettings) -> Self {
+        Self {
+            settings,
+            clients: Arc::new(DashMap::new()),
+        }
+    }
+
+    /// Counts a request from `client`, or returns how long it must wait
+    fn check_at(&self, client: ClientKey, now: Instant) -> Result<(), Duration> {
+        if self.clients.len() >= PRUNE_THRESHOLD {
+            self.clients.retain(|_, limiter| !limiter.is_idle_at(now));
+        }
+
+        let limit = match client {
+            ClientKey::Ip(_) => self.settings.ip_requests,
+            ClientKey::ApiKey(_) => self.settings.api_key_requests,
+        };
+        let mut limiter = self
+            .clients
+            .entry(client)
+            .or_insert_with(|| RateLimiter::new(limit, self.settings.window));
+        if limiter.try_acquire_at(now) {
+            Ok(())
+        } else {
+            Err(limiter.retry_after_at(now))
+        }
+    }
+}
+
+/// Middleware that answers 429 Too Many Requests, with `Retry-After`, once a
+/// client has used up its allowance for the current window
+pub async fn rate_limit(State(state): State<AppState>, request: Request, next: Next) -> Response {
+    let Some(limiter) = state.rate_limiter.as_ref() else {
+        return next.run(request).await;
+    };
+
+    let Some(client) = client_key(
+        &request,
+        state.seed_api_key.as_deref(),
+        limiter.settings.trust_proxy,
+    ) else {
+        tracing::warn!("Cannot determine client address; skipping rate limit");
+        return next.run(request).await;
+    };
+
+    match limiter.check_at(client, Instant::now()) {
+        Ok(()) => next.run(request).await,
+        Err(retry_after) => ApiError::TooManyRequests {
+            retry_after_secs: retry_after.as_secs_f64().ceil().max(1.0) as u64,
+        }
+        .into_response(),
+    }
+}
+
+fn client_key(
+    request: &Request,
+    seed_api_key: Option<&str>,
+    trust_proxy: bool,
+) -> Option<ClientKey> {
+    let headers = request.headers();
+
+    if let (Some(expected), Some(provided)) = (
+        seed_api_key,
+        headers
+            .get(SEED_API_KEY_HEADER)
+            .and_then(|value| value.to_str().ok()),
+    ) {
+        if verify_api_key(provided, expected) {
+            return Some(ClientKey::ApiKey(provided.to_string()));
+        }
+    }
+
+    if trust_proxy {
+        if let Some(ip) = headers
+            .get(REAL_IP_HEADER)
+            .and_then(|value| value.to_str().ok())
+            .and_then(|value| value.trim().parse().ok())
+        {
+            return Some(ClientKey::Ip(ip));
+        }
+    }
+
+    request
+        .extensions()
+        .get::<ConnectInfo<SocketAddr>>()
+        .map(|ConnectInfo(addr)| ClientKey::Ip(addr.ip()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use axum::body::Body;
+
+    fn settings() -> RateLimitSettings {
+        RateLimitSettings {
+            ip_requests: 2,
+            api_key_requests: 3,
+            window: Duration::from_secs(60),
+            trust_proxy: false,
+        }
+    }
+
+    fn request_from(addr: &str) -> Request {
+        let mut request = Request::new(Body::empty());
+        request
+            .extensions_mut()
+            .insert(ConnectInfo::<SocketAddr>(addr.parse().unwrap()));
+        request
+    }
+
+    #[test]
+    fn test_clients_have_separate_allowances() {
+        let limiter = ApiRateLimiter::new(settings());
+        let now = Instant::now();
+        let a = ClientKey::Ip("10.0.0.1".parse().unwrap());
+        let b = ClientKey::Ip("10.0.0.2".parse().unwrap());
+
+        assert!(limiter.check_at(a.clone(), now).is_ok());
+        assert!(limiter.check_at(a.clone(), now).is_ok());
+        assert_eq!(
+            limiter.check_at(a.clone(), now),
+            Err(Duration::from_secs(60))
+        );
+        assert!(limiter.check_at(b, now).is_ok());
+
+        let key = ClientKey::ApiKey("secret".to_string());
+        for _ in 0..3 {
+            assert!(limiter.check_at(key.clone(), now).is_ok());
+        }
+        assert!(limiter.check_at(key, now).is_err());
+    }
+
+    #[test]
+    fn test_client_key_uses_verified_api_key() {
+        let mut request = request_from("10.0.0.1:5000");
+        request
+            .headers_mut()
+            .insert("X-Seed-Api-Key", "secret".parse().unwrap());
+
+        assert_eq!(
+            client_key(&request, Some("secret"), false),
+            Some(ClientKey::ApiKey("secret".to_string()))
+        );
+        assert_eq!(
+            client_key(&request, Some("other"), false),
+            Some(ClientKey::Ip("10.0.0.1".parse().unwrap()))
+        );
+    }
+
+    #[test]
+    fn test_client_key_trusts_real_ip_only_when_configured() {
+        let mut request = request_from("127.0.0.1:5000");
+        request
+            .headers_mut()
+            .insert(REAL_IP_HEADER, "203.0.113.7".parse().unwrap());
+
+        assert_eq!(
+            client_key(&request, None, false),
+            Some(ClientKey::Ip("127.0.0.1".parse().unwrap()))
+        );
+        assert_eq!(
+            client_key(&request, None, true),
+            Some(ClientKey::Ip("203.0.113.7".parse().unwrap()))
+        );
+        assert_eq!(client_key(&Request::new(Body::empty()), None, false), None);
+    }
+}
@@ -0,0 +1,96 @@
+//! `X-Request-Id` generation and propagation.
+//!
+//! Every request gets an id: the caller's own when it sends a usable one
+//! (so a gateway's id carries through), otherwise a fresh UUID. The id is
+//! echoed on the response, recorded on the request's tracing span, and added
+//! to error bodies, so a user reporting a failure can quote the id an
+//! operator then searches the logs for.
+
+use axum::extract::Request;
+use axum::http::{HeaderName, HeaderValue};
+use axum::middleware::Next;
+use axum::response::Response;
+use uuid::Uuid;
+
+pub const REQUEST_ID_HEADER: &str = "x-request-id";
+
+/// Longest caller-supplied id that is accepted rather than replaced
+const MAX_REQUEST_ID_LENGTH: usize = 128;
+
+tokio::task_local! {
+    static REQUEST_ID: String;
+}
+
+/// The id of the request being handled on this task, if any
+pub fn current() -> Option<String> {
+    REQUEST_ID.try_with(Clone::clone).ok()
+}
+
+/// Middleware that assigns the request id. It must wrap the trace layer so
+/// the span can pick the id up from the request headers.
+pub async fn request_id(mut request: Request, next: Next) -> Response {
+    let header = HeaderName::from_static(REQUEST_ID_HEADER);
+    let id = request
+        .headers()
+        .get(&header)
+        .and_then(|value| value.to_str().ok())
+        .filter(|value| is_acceptable(value))
+        .map(str::to_string)
+        .unwrap_or_else(|| Uuid::new_v4().to_string());
+    let value = HeaderValue::from_str(&id).expect("request ids are visible ASCII");
+    request.headers_mut().insert(header.clone(), value.clone());
+
+    let mut response = REQUEST_ID.scope(id, next.run(request)).await;
+    response.headers_mut().insert(header, value);
+    response
+}
+
+/// Only short, printable ids are trusted; anything else could be used to
+/// inject noise into the logs
+fn is_acceptable(id: &str) -> bool {
+    !id.is_empty()
+        && id.len() <= MAX_REQUEST_ID_LENGTH
+        && id
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || matches!(c, '-' | '_' | '.' | ':'))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_acceptable_ids() {
+        assert!(is_acceptable("3f2b6c1e-8a4d-4b9e-9f1a-0c2d3e4f5a6b"));
+        assert!(is_acceptable("gateway.req_42:7"));
+        assert!(!is_acceptable(""));
+        assert!(!is_acceptable("has space"));
+        assert!(!is_acceptable("line\nbreak"));
+        assert!(!is_acceptable(&"a".repeat(MAX_REQUEST_ID_LENGTH + 1)));
+    }
+
+    #[tokio::test]
+    async fn test_current_is_scoped_to_the_request() {
+        assert_eq!(current(), None);
+        let seen = REQUEST_ID
+            .scope("abc".to_string(), async { current() })
+            .await;
+        assert_eq!(seen.as_deref(), Some("abc"));
+    }
+
+    #[tokio::test]
+    async fn test_error_body_carries_request_id() {
+        use axum::body::to_bytes;
+        use axum::response::IntoResponse;
+
+        let response = REQUEST_ID
+            .scope("abc".to_string(), async {
+                crate::ApiError::NotFound("Team not found".to_string()).into_response()
+            })
+            .await;
+        let body = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+        let body: serde_json::Value = serde_json::from_slice(&body).unwrap();
+        assert_eq!(body["request_id"], "abc");
+        assert_eq!(body["error"], "Team not found");
+    }
+}
@@ -1,14 +1,26 @@
-use axum::http::header::{AUTHORIZATION, CONTENT_TYPE};
-use axum::http::{HeaderValue, Method};
-use axum::routing::{delete, get, post};
+use async_graphql_axum::GraphQL;
+use axum::extract::Request;
+use axum::http::header::{
+    AUTHORIZATION, CONTENT_DISPOSITION, CONTENT_TYPE, ETAG, IF_NONE_MATCH, RETRY_AFTER,
+};
+use axum::http::{HeaderName, HeaderValue, Method};
+use axum::middleware;
+use axum::routing::{delete, get, patch, post, put};
 use axum::Router;
 use tower_http::cors::{AllowOrigin, CorsLayer};
 use tower_http::trace::TraceLayer;
 use utoipa::OpenApi;
 use utoipa_swagger_ui::SwaggerUi;
 
+use crate::audit::audit_writes;
+use crate::auth::{require_admin, require_commissioner, require_participant};
+use crate::etag::conditional_get;
+use crate::graphql;
 use crate::handlers;
 use crate::openapi::ApiDoc;
+use crate::pagination::{PAGE_HEADER, PER_PAGE_HEADER, TOTAL_COUNT_HEADER};
+use crate::rate_limit::rate_limit;
+use crate::request_id::{request_id, REQUEST_ID_HEADER};
 use crate::state::AppState;
 
 pub fn create_router(state: AppState) -> Router {
@@ -21,10 +33,28 @@ pub fn create_router_with_cors(state: AppState, cors_origins: &[String]) -> Rout
         Method::GET,
         Method::POST,
         Method::PUT,
+        Method::PATCH,
         Method::DELETE,
         Method::OPTIONS,
     ];
-    let allowed_headers = [CONTENT_TYPE, AUTHORIZATION, seed_api_key_header];
+    let allowed_headers = [
+        CONTENT_TYPE,
+        AUTHORIZATION,
+        IF_NONE_MATCH,
+        seed_api_key_header,
+        HeaderName::from_static(REQUEST_ID_HEADER),
+    ];
+    // Let browser clients read the pagination, caching, rate limit, request
+    // id and download headers on cross-origin requests
+    let exposed_headers = [
+        ETAG,
+        CONTENT_DISPOSITION,
+        RETRY_AFTER,
+        HeaderName::from_static(REQUEST_ID_HEADER),
+        HeaderName::from_static(TOTAL_COUNT_HEADER),
+        HeaderName::from_static(PAGE_HEADER),
+        HeaderName::from_static(PER_PAGE_HEADER),
+    ];
 
     let cors = if cors_origins.is_empty() {
         // Default development origins
@@ -41,6 +71,7 @@ pub fn create_router_with_cors(state: AppState, cors_origins: &[String]) -> Rout
             .allow_origin(AllowOrigin::list(origins))
             .allow_methods(allowed_methods)
             .allow_headers(allowed_headers)
+            .expose_headers(exposed_headers)
     } else {
         let origins: Vec<HeaderValue> =
             cors_origins.iter().filter_map(|o| o.parse().ok()).collect();
@@ -49,14 +80,27 @@ pub fn create_router_with_cors(state: AppState, cors_origins: &[String]) -> Rout
             .allow_origin(AllowOrigin::list(origins))
             .allow_methods(allowed_methods)
             .allow_headers(allowed_headers)
+            .expose_headers(exposed_headers)
     };
 
     // API v1 routes
     let api_routes = Router::new()
+        // Auth
+        .route("/auth/register", post(handlers::auth::register))
+        .route("/auth/login", post(handlers::auth::login))
+        .route("/auth/me", get(handlers::auth::me))
+        .route(
+            "/me/watchlist",
+            get(handlers::watchlist::get_watchlist).post(handlers::watchlist::add_to_watchlist),
+        )
+        .route(
+            "/me/watchlist/{player_id}",
+            delete(handlers::watchlist::remove_from_watchlist),
+        )
         // Teams
         .route(
             "/teams",
-            get(handlers::teams::list_teams).post(handlers::teams::create_team),
+            get(handlers::teams::list_teams).layer(middleware::from_fn(conditional_get)),
         )
         .route("/teams/{id}", get(handlers::teams::get_team))
         .route(
@@ -74,13 +118,21 @@ pub fn create_router_with_cors(state: AppState, cors_origins: &[String]) -> Rout
         // Players
         .route(
             "/players",
-            get(handlers::players::list_players).post(handlers::players::create_player),
+            get(handlers::players::list_players).layer(middleware::from_fn(conditional_get)),
+        )
+        .route(
+            "/players/freaks",
+            get(handlers::feldman_freaks::list_freak_profiles),
         )
         .route("/players/{id}", get(handlers::players::get_player))
         .route(
             "/players/{player_id}/combine-results",
             get(handlers::combine_results::get_player_combine_results),
         )
+        .route(
+            "/players/{player_id}/combine-results/best",
+            get(handlers::combine_results::get_player_best_marks),
+        )
         .route(
             "/players/{player_id}/scouting-reports",
             get(handlers::scouting_reports::get_player_scouting_reports),
@@ -93,24 +145,38 @@ pub fn create_router_with_cors(state: AppState, cors_origins: &[String]) -> Rout
             "/players/{player_id}/ras",
             get(handlers::ras::get_player_ras),
         )
+        .route(
+            "/players/{player_id}/percentiles",
+            get(handlers::ras::get_player_percentiles),
+        )
         .route(
             "/players/{player_id}/profile",
             get(handlers::prospect_profiles::get_player_profile),
         )
         .route(
-            "/prospect-profiles",
-            get(handlers::prospect_profiles::list_prospect_profiles),
+            "/players/{player_id}/college-stats",
+            get(handlers::college_stats::get_player_college_stats),
         )
-        // Drafts
         .route(
-            "/drafts",
-            get(handlers::drafts::list_drafts).post(handlers::drafts::create_draft),
+            "/players/{player_id}/notes",
+            get(handlers::player_notes::list_player_notes),
         )
-        .route("/drafts/{id}", get(handlers::drafts::get_draft))
         .route(
-            "/drafts/{id}/initialize",
-            post(handlers::drafts::initialize_draft_picks),
+            "/player-notes/{id}",
+            get(handlers::player_notes::get_player_note),
+        )
+        .route(
+            "/players/{player_id}/tags",
+            get(handlers::player_tags::list_player_tags),
+        )
+        .route("/player-tags", get(handlers::player_tags::list_tags))
+        .route(
+            "/prospect-profiles",
+            get(handlers::prospect_profiles::list_prospect_profiles),
         )
+        // Drafts
+        .route("/drafts", get(handlers::drafts::list_drafts))
+        .route("/drafts/{id}", get(handlers::drafts::get_draft))
         .route("/drafts/{id}/picks", get(handlers::drafts::get_draft_picks))
         .route(
             "/drafts/{id}/picks/next",
@@ -125,77 +191,73 @@ pub fn create_router_with_cors(state: AppState, cors_origins: &[String]) -> Rout
             get(handlers::drafts::get_available_players),
         )
         .route(
-            "/drafts/{id}/session",
-            get(handlers::sessions::get_session_by_draft),
+            "/drafts/{id}/teams/{team_id}/class",
+            get(handlers::drafts::get_team_draft_class),
         )
-        .route("/drafts/{id}/start", post(handlers::drafts::start_draft))
-        .route("/drafts/{id}/pause", post(handlers::drafts::pause_draft))
         .route(
-            "/drafts/{id}/complete",
-            post(handlers::drafts::complete_draft),
+            "/drafts/{id}/value-analysis",
+            get(handlers::drafts::get_value_analysis),
         )
-        // Draft Picks
-        .route("/picks/{id}/make", post(handlers::drafts::make_pick))
-        // Draft Sessions
-        .route("/sessions", post(handlers::sessions::create_session))
-        .route("/sessions/{id}", get(handlers::sessions::get_session))
         .route(
-            "/sessions/{id}/start",
-            post(handlers::sessions::start_session),
+            "/drafts/{id}/surplus-report",
+            get(handlers::drafts::get_surplus_report),
         )
         .route(
-            "/sessions/{id}/pause",
-            post(handlers::sessions::pause_session),
+            "/drafts/{id}/scarcity",
+            get(handlers::drafts::get_draft_scarcity),
+        )
+        .route(
+            "/drafts/{id}/accuracy",
+            get(handlers::drafts::get_draft_accuracy),
         )
+        .route(
+            "/drafts/{id}/session",
+            get(handlers::sessions::get_session_by_draft),
+        )
+        // Draft Sessions
+        .route("/sessions/{id}", get(handlers::sessions::get_session))
         .route(
             "/sessions/{id}/events",
             get(handlers::sessions::get_session_events),
         )
         .route(
-            "/sessions/{id}/auto-pick-run",
-            post(handlers::sessions::auto_pick_run),
+            "/sessions/{id}/presence",
+            get(handlers::sessions::get_session_presence),
         )
         .route(
-            "/sessions/{id}/advance-pick",
-            post(handlers::sessions::advance_pick),
+            "/sessions/{id}/export",
+            get(handlers::sessions::export_session),
         )
+        // Session Participants
+        .route(
+            "/sessions/{id}/participants",
+            get(handlers::participants::list_participants),
+        )
+        // Session Chat
+        .route("/sessions/{id}/chat", get(handlers::chat::get_chat_history))
         // Combine Results
         .route(
             "/combine-results",
-            get(handlers::combine_results::list_combine_results)
-                .post(handlers::combine_results::create_combine_results),
+            get(handlers::combine_results::list_combine_results),
         )
         .route("/combine-results/ras", get(handlers::ras::get_all_ras))
         .route(
             "/combine-results/{id}",
-            get(handlers::combine_results::get_combine_results)
-                .put(handlers::combine_results::update_combine_results)
-                .delete(handlers::combine_results::delete_combine_results),
+            get(handlers::combine_results::get_combine_results),
         )
         // Scouting Reports
         .route(
             "/scouting-reports",
-            post(handlers::scouting_reports::create_scouting_report),
+            get(handlers::scouting_reports::list_scouting_reports),
         )
         .route(
             "/scouting-reports/{id}",
-            get(handlers::scouting_reports::get_scouting_report)
-                .put(handlers::scouting_reports::update_scouting_report)
-                .delete(handlers::scouting_reports::delete_scouting_report),
+            get(handlers::scouting_reports::get_scouting_report),
         )
         // Team Needs
-        .route("/team-needs", post(handlers::team_needs::create_team_need))
-        .route(
-            "/team-needs/{id}",
-            get(handlers::team_needs::get_team_need)
-                .put(handlers::team_needs::update_team_need)
-                .delete(handlers::team_needs::delete_team_need),
-        )
+        .route("/team-needs/{id}", get(handlers::team_needs::get_team_need))
         // Trades
-        .route("/trades", post(handlers::trades::propose_trade))
         .route("/trades/{id}", get(handlers::trades::get_trade))
-        .route("/trades/{id}/accept", post(handlers::trades::accept_trade))
-        .route("/trades/{id}/reject", post(handlers::trades::reject_trade))
         .route(
             "/teams/{team_id}/pending-trades",
             get(handlers::trades::get_pending_trades),
@@ -211,14 +273,25 @@ pub fn create_router_with_cors(state: AppState, cors_origins: &[String]) -> Rout
         )
         .route("/draft-order", get(handlers::team_seasons::get_draft_order))
         // Rankings
-        .route("/rankings", get(handlers::rankings::get_all_rankings))
+        .route(
+            "/rankings",
+            get(handlers::rankings::get_all_rankings).layer(middleware::from_fn(conditional_get)),
+        )
+        .route(
+            "/rankings/consensus",
+            get(handlers::rankings::get_consensus_rankings),
+        )
+        .route("/big-board", get(handlers::big_board::get_big_board))
+        .route("/adp", get(handlers::adp::get_adp))
         .route(
             "/ranking-sources",
-            get(handlers::rankings::list_ranking_sources),
+            get(handlers::rankings::list_ranking_sources)
+                .layer(middleware::from_fn(conditional_get)),
         )
         .route(
             "/ranking-sources/{source_id}/rankings",
-            get(handlers::rankings::get_source_rankings),
+            get(handlers::rankings::get_source_rankings)
+                .layer(middleware::from_fn(conditional_get)),
         )
         // Feldman Freaks
         .route(
@@ -230,13 +303,154 @@ pub fn create_router_with_cors(state: AppState, cors_origins: &[String]) -> Rout
             "/combine-percentiles",
             get(handlers::combine_percentiles::get_combine_percentiles),
         )
-        // Admin
+        // GraphQL (read-only)
+        .route(
+            "/graphql",
+            get(graphql::graphiql).post_service(GraphQL::new(graphql::build_schema(state.clone()))),
+        );
+
+    // Picks, trades, session seats and scouting notes, tags and reports
+    // need a signed-in participant
+    let participant_routes = Router::new()
+        .route(
+            "/sessions/{id}/participants",
+            post(handlers::participants::join_session),
+        )
+        .route(
+            "/sessions/{id}/participants/{participant_id}",
+            delete(handlers::participants::leave_session),
+        )
+        .route("/picks/{id}/make", post(handlers::drafts::make_pick))
+        .route("/trades", post(handlers::trades::propose_trade))
+        .route("/trades/{id}/accept", post(handlers::trades::accept_trade))
+        .route("/trades/{id}/reject", post(handlers::trades::reject_trade))
+        .route(
+            "/players/{player_id}/notes",
+            post(handlers::player_notes::create_player_note),
+        )
+        .route(
+            "/player-notes/{id}",
+            put(handlers::player_notes::update_player_note)
+                .delete(handlers::player_notes::delete_player_note),
+        )
+        .route(
+            "/players/{player_id}/tags",
+            post(handlers::player_tags::add_player_tag),
+        )
+        .route(
+            "/players/{player_id}/tags/{tag}",
+            delete(handlers::player_tags::remove_player_tag),
+        )
+        .route(
+            "/scouting-reports",
+            post(handlers::scouting_reports::create_scouting_report),
+        )
+        .route(
+            "/scouting-reports/{id}",
+            put(handlers::scouting_reports::update_scouting_report),
+        )
+        .route_layer(middleware::from_fn_with_state(
+            state.clone(),
+            require_participant,
+        ));
+
+    // Creating and driving drafts and sessions is up to a commissioner
+    let commissioner_routes = Router::new()
+        .route("/drafts", post(handlers::drafts::create_draft))
+        .route(
+            "/drafts/{id}/initialize",
+            post(handlers::drafts::initialize_draft_picks),
+        )
+        .route("/drafts/{id}/start", post(handlers::drafts::start_draft))
+        .route("/drafts/{id}/pause", post(handlers::drafts::pause_draft))
+        .route(
+            "/drafts/{id}/complete",
+            post(handlers::drafts::complete_draft),
+        )
+        .route("/sessions", post(handlers::sessions::create_session))
+        .route("/sessions/import", post(handlers::sessions::import_session))
+        .route("/sessions/{id}", patch(handlers::sessions::update_session))
+        .route(
+            "/sessions/{id}/start",
+            post(handlers::sessions::start_session),
+        )
+        .route(
+            "/sessions/{id}/pause",
+            post(handlers::sessions::pause_session),
+        )
+        .route(
+            "/sessions/{id}/auto-pick-run",
+            post(handlers::sessions::auto_pick_run),
+        )
+        .route(
+            "/sessions/{id}/advance-pick",
+            post(handlers::sessions::advance_pick),
+        )
+        .route(
+            "/sessions/{id}/rewind",
+            post(handlers::sessions::rewind_session),
+        )
+        // Commissioners are the ones asked who made a pick, so the audit
+        // log is open to them as well as admins
+        .route("/admin/audit-log", get(handlers::audit_log::list_audit_log))
+        // Commissioners let registered players into drafts; the handler
+        // keeps every other role change for admins
+        .route(
+            "/admin/users/{id}/role",
+            put(handlers::auth::update_user_role),
+        )
+        .route_layer(middleware::from_fn_with_state(
+            state.clone(),
+            require_commissioner,
+        ));
+
+    // Seeding and maintenance, including edits to reference data
+    let admin_routes = Router::new()
+        .route("/teams", post(handlers::teams::create_team))
+        .route(
+            "/teams/{id}",
+            put(handlers::teams::replace_team)
+                .patch(handlers::teams::update_team)
+                .delete(handlers::teams::delete_team),
+        )
+        .route("/players", post(handlers::players::create_player))
+        .route(
+            "/players/{id}",
+            patch(handlers::players::update_player).delete(handlers::players::delete_player),
+        )
+        .route(
+            "/combine-results",
+            post(handlers::combine_results::create_combine_results),
+        )
+        .route(
+            "/combine-results/{id}",
+            put(handlers::combine_results::update_combine_results)
+                .delete(handlers::combine_results::delete_combine_results),
+        )
+        .route(
+            "/scouting-reports/{id}",
+            delete(handlers::scouting_reports::delete_scouting_report),
+        )
+        .route("/team-needs", post(handlers::team_needs::create_team_need))
+        .route(
+            "/team-needs/{id}",
+            put(handlers::team_needs::update_team_need)
+                .delete(handlers::team_needs::delete_team_need),
+        )
+        .route(
+            "/admin/broadcast",
+            post(handlers::admin::broadcast_announcement),
+        )
         .route("/admin/seed-players", post(handlers::seed::seed_players))
         .route("/admin/seed-teams", post(handlers::seed::seed_teams))
         .route(
             "/admin/seed-team-seasons",
             post(handlers::seed::seed_team_seasons),
         )
+        .route(
+            "/admin/seed-team-needs",
+            post(handlers::seed::seed_team_needs),
+        )
         .route("/admin/seed-rankings", post(handlers::seed::seed_rankings))
         .route(
             "/admin/seed-combine-percentiles",
@@ -246,6 +460,10 @@ pub fn create_router_with_cors(state: AppState, cors_origins: &[String]) -> Rout
             "/admin/seed-combine-data",
             post(handlers::seed::seed_combine_data),
         )
+        .route(
+            "/admin/seed-draft-order",
+            post(handlers::seed::seed_draft_order),
+        )
         .route(
             "/admin/seed-feldman-freaks",
             post(handlers::seed::seed_feldman_freaks),
@@ -257,12 +475,51 @@ pub fn create_router_with_cors(state: AppState, cors_origins: &[String]) -> Rout
         .route(
             "/admin/percentiles",
             delete(handlers::combine_percentiles::delete_all_percentiles),
-        );
+        )
+        .route(
+            "/admin/percentiles/compute",
+            post(handlers::combine_percentiles::compute_percentiles),
+        )
+        .route(
+            "/admin/sessions/{id}/verify-projection",
+            get(handlers::sessions::verify_session_projection),
+        )
+        .route(
+            "/admin/players/{id}/restore",
+            post(handlers::players::restore_player),
+        )
+        .route(
+            "/admin/teams/{id}/restore",
+            post(handlers::teams::restore_team),
+        )
+        .route(
+            "/admin/scouting-reports/{id}/restore",
+            post(handlers::scouting_reports::restore_scouting_report),
+        )
+        .route_layer(middleware::from_fn_with_state(state.clone(), require_admin));
+
+    let api_routes = api_routes
+        .merge(participant_routes)
+        .merge(commissioner_routes)
+        .merge(admin_routes)
+        .layer(middleware::from_fn_with_state(state.clone(), audit_writes));
+
+    // Limits apply to the REST API only; WebSocket traffic has its own
+    // per-connection limits
+    let api_routes = api_routes.layer(middleware::from_fn_with_state(state.clone(), rate_limit));
 
     // Create stateful routes
     let stateful_router = Router::new()
         .route("/health", get(handlers::health::health_check))
+        .route("/health/live", get(handlers::health::liveness))
+        .route("/health/ready", get(handlers::health::readiness))
+        .route(
+            "/health/db",
+            get(handlers::health::database_health)
+                .route_layer(middleware::from_fn_with_state(state.clone(), require_admin)),
+        )
         .route("/ws", get(handlers::websocket::ws_handler))
+        .route("/ws/stats", get(handlers::websocket::ws_stats))
         .nest("/api/v1", api_routes)
         .with_state(state);
 
@@ -275,5 +532,21 @@ pub fn create_router_with_cors(state: AppState, cors_origins: &[String]) -> Rout
     stateful_router
         .merge(swagger_router)
         .layer(cors)
-        .layer(TraceLayer::new_for_http())
+        .layer(
+            TraceLayer::new_for_http().make_span_with(|request: &Request| {
+                let request_id = request
+                    .headers()
+                    .get(REQUEST_ID_HEADER)
+                    .and_then(|value| value.to_str().ok())
+                    .unwrap_or_default();
+                tracing::info_span!(
+                    "request",
+                    method = %request.method(),
+                    uri = %request.uri(),
+                    request_id,
+                )
+            }),
+        )
+        // Outermost, so the trace span and every error body see the id
+        .layer(middleware::from_fn(request_id))
 }
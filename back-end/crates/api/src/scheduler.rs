@@ -0,0 +1,51 @@
+//! Background task that starts sessions at their `scheduled_start_at` time.
+
+use std::time::Duration;
+
+use chrono::Utc;
+use tokio::task::JoinHandle;
+use uuid::Uuid;
+
+use crate::error::ApiResult;
+use crate::handlers::sessions::begin_session;
+use crate::state::AppState;
+
+/// How often the scheduler looks for sessions that are due to start
+pub const SCHEDULER_INTERVAL: Duration = Duration::from_secs(5);
+
+/// Spawns the scheduler loop. It runs until the runtime shuts down.
+pub fn spawn_session_scheduler(state: AppState, interval: Duration) -> JoinHandle<()> {
+    tokio::spawn(async move {
+        let mut ticker = tokio::time::interval(interval);
+        ticker.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Delay);
+        loop {
+            ticker.tick().await;
+            if let Err(e) = start_due_sessions(&state).await {
+                tracing::warn!("Scheduled session check failed: {:?}", e);
+            }
+        }
+    })
+}
+
+/// Starts every session whose scheduled time has passed and returns the IDs
+/// that were started. A session that fails to start (for example because
+/// someone is starting it by hand at the same moment) is logged and retried
+/// on the next pass if it is still waiting.
+pub async fn start_due_sessions(state: &AppState) -> ApiResult<Vec<Uuid>> {
+    let now = Utc::now();
+    let due = state.session_repo.list_due_to_start(now).await?;
+
+    let mut started = Vec::with_capacity(due.len());
+    for session in due.into_iter().filter(|s| s.is_start_due(now)) {
+        match begin_session(state, session.id).await {
+            Ok(_) => {
+                tracing::info!(session_id = %session.id, "Started scheduled session");
+                started.push(session.id);
+            }
+            Err(e) => {
+                tracing::warn!(session_id = %session.id, "Scheduled start failed: {:?}", e);
+            }
+        }
+    }
+    Ok(started)
+}
@@ -0,0 +1,28 @@
+//! `sort=` support for collection endpoints.
+//!
+//! Each endpoint accepts its own whitelist of field names, defined next to
+//! the domain model. Unknown fields are rejected with 400 rather than
+//! silently ignored.
+
+use serde::Deserialize;
+use utoipa::IntoParams;
+
+use domain::models::{Sort, SortField};
+
+use crate::error::ApiResult;
+
+#[derive(Debug, Default, Deserialize, IntoParams)]
+pub struct SortParams {
+    /// Comma separated fields to sort by, most significant first; prefix a
+    /// field with `-` for descending order (e.g. `-draft_year,last_name`)
+    pub sort: Option<String>,
+}
+
+impl SortParams {
+    pub fn parse<F: SortField>(&self) -> ApiResult<Sort<F>> {
+        match &self.sort {
+            Some(spec) => Ok(Sort::parse(spec)?),
+            None => Ok(Sort::default()),
+        }
+    }
+}
@@ -5,30 +5,50 @@ use std::sync::Arc;
 use tokio::sync::Mutex;
 use uuid::Uuid;
 
+use db::cache::{
+    Cache, CachedCombinePercentileRepository, CachedProspectRankingRepository, CachedTeamRepository,
+};
 use db::repositories::{
-    EventRepo, SessionRepo, SqlxCombinePercentileRepository, SqlxCombineResultsRepository,
+    EventRepo, SessionRepo, SqlxActualDraftPickRepository, SqlxAdpRepository,
+    SqlxAuditLogRepository, SqlxChatMessageRepository, SqlxCollegeStatsRepository,
+    SqlxCombinePercentileRepository, SqlxCombineResultsRepository, SqlxDraftLockRepository,
     SqlxDraftPickRepository, SqlxDraftRepository, SqlxDraftStrategyRepository,
-    SqlxFeldmanFreakRepository, SqlxPlayerRepository, SqlxProspectProfileRepository,
-    SqlxProspectRankingRepository, SqlxRankingSourceRepository, SqlxScoutingReportRepository,
+    SqlxFeldmanFreakRepository, SqlxPlayerNoteRepository, SqlxPlayerRepository,
+    SqlxPlayerTagRepository, SqlxProspectProfileRepository, SqlxProspectRankingRepository,
+    SqlxRankingSourceRepository, SqlxScoutingReportRepository, SqlxSessionParticipantRepository,
     SqlxTeamNeedRepository, SqlxTeamRepository, SqlxTeamSeasonRepository, SqlxTradeRepository,
+    SqlxUserRepository, SqlxWatchlistRepository,
 };
+use db::DbPools;
+use domain::models::{SourcePrecedence, User};
 use domain::repositories::{
-    CombinePercentileRepository, CombineResultsRepository, DraftPickRepository, DraftRepository,
-    DraftStrategyRepository, EventRepository, FeldmanFreakRepository, PlayerRepository,
+    ActualDraftPickRepository, AdpRepository, AuditLogRepository, ChatMessageRepository,
+    CollegeStatsRepository, CombinePercentileRepository, CombineResultsRepository,
+    DraftPickRepository, DraftRepository, DraftStrategyRepository, EventRepository,
+    FeldmanFreakRepository, PlayerNoteRepository, PlayerRepository, PlayerTagRepository,
     ProspectProfileRepository, ProspectRankingRepository, RankingSourceRepository,
-    ScoutingReportRepository, SessionRepository, TeamNeedRepository, TeamRepository,
-    TeamSeasonRepository, TradeRepository,
+    ScoutingReportRepository, SessionParticipantRepository, SessionRepository, TeamNeedRepository,
+    TeamRepository, TeamSeasonRepository, TradeRepository, UserRepository, WatchlistRepository,
 };
 use domain::services::{
     AutoPickService, DraftEngine, DraftStrategyService, PlayerEvaluationService, RasScoringService,
     TradeEngine,
 };
-use websocket::ConnectionManager;
+use websocket::{BroadcastRelay, ConnectionManager, HeartbeatConfig, SendQueueConfig};
+
+use crate::audit::{
+    AuditedDraftPickRepository, AuditedPlayerRepository, AuditedSessionRepository,
+    AuditedTeamRepository, AuditedTradeRepository,
+};
+use crate::auth::{JwtKeys, DEFAULT_TOKEN_TTL};
+use crate::rate_limit::{ApiRateLimiter, RateLimitSettings};
 
 /// Application state shared across all handlers
 #[derive(Clone)]
 pub struct AppState {
     pool: PgPool,
+    /// Pool collection reads go to; the same pool as `pool` without a replica
+    read_pool: PgPool,
     pub team_repo: Arc<dyn TeamRepository>,
     pub player_repo: Arc<dyn PlayerRepository>,
     pub draft_repo: Arc<dyn DraftRepository>,
@@ -39,21 +59,40 @@ pub struct AppState {
     pub team_need_repo: Arc<dyn TeamNeedRepository>,
     pub team_season_repo: Arc<dyn TeamSeasonRepository>,
     pub session_repo: Arc<dyn SessionRepository>,
+    pub participant_repo: Arc<dyn SessionParticipantRepository>,
+    pub chat_repo: Arc<dyn ChatMessageRepository>,
     pub event_repo: Arc<dyn EventRepository>,
     pub trade_repo: Arc<dyn TradeRepository>,
     pub ranking_source_repo: Arc<dyn RankingSourceRepository>,
     pub prospect_ranking_repo: Arc<dyn ProspectRankingRepository>,
     pub feldman_freak_repo: Arc<dyn FeldmanFreakRepository>,
     pub prospect_profile_repo: Arc<dyn ProspectProfileRepository>,
+    pub user_repo: Arc<dyn UserRepository>,
+    pub actual_draft_pick_repo: Arc<dyn ActualDraftPickRepository>,
+    pub adp_repo: Arc<dyn AdpRepository>,
+    pub player_note_repo: Arc<dyn PlayerNoteRepository>,
+    pub player_tag_repo: Arc<dyn PlayerTagRepository>,
+    pub college_stats_repo: Arc<dyn CollegeStatsRepository>,
+    pub watchlist_repo: Arc<dyn WatchlistRepository>,
+    pub audit_log_repo: Arc<dyn AuditLogRepository>,
     pub ras_service: Arc<RasScoringService>,
     pub draft_engine: Arc<DraftEngine>,
     pub trade_engine: Arc<TradeEngine>,
     pub ws_manager: ConnectionManager,
     pub seed_api_key: Option<String>,
+    /// Keys for signing and checking user access tokens
+    pub jwt: Arc<JwtKeys>,
+    /// Normalized email whose registration is made an admin
+    pub admin_email: Option<String>,
+    /// Per-client REST request limits; `None` leaves the API unlimited
+    pub rate_limiter: Option<ApiRateLimiter>,
     /// Per-session mutex to prevent concurrent auto-pick-run requests
     pub session_locks: Arc<DashMap<Uuid, Arc<Mutex<()>>>>,
     /// Per-session cancellation flags for cooperative auto-pick-run shutdown
     pub auto_pick_cancel: Arc<DashMap<Uuid, Arc<AtomicBool>>>,
+    /// Cache behind the team, ranking and percentile repositories, for
+    /// writes that go around them
+    pub cache: Option<Arc<dyn Cache>>,
 }
 
 impl AppState {
@@ -63,38 +102,185 @@ impl AppState {
         &self.pool
     }
 
+    /// Access the pool reference data reads are sent to, for reporting on
+    /// the read replica
+    pub(crate) fn read_pool(&self) -> &PgPool {
+        &self.read_pool
+    }
+
+    /// Replace the WebSocket heartbeat settings. Call before any client
+    /// connects, since it swaps in a fresh connection manager.
+    pub fn with_heartbeat(mut self, heartbeat: HeartbeatConfig) -> Self {
+        self.ws_manager = ConnectionManager::with_settings(heartbeat, self.ws_manager.send_queue());
+        self
+    }
+
+    /// Replace the per-connection send queue settings. Like `with_heartbeat`,
+    /// call this before any client connects.
+    pub fn with_send_queue(mut self, send_queue: SendQueueConfig) -> Self {
+        self.ws_manager = ConnectionManager::with_settings(self.ws_manager.heartbeat(), send_queue);
+        self
+    }
+
+    /// Relay session broadcasts to other server instances. Call after
+    /// `with_heartbeat` and `with_send_queue`, which swap in a fresh
+    /// connection manager.
+    pub fn with_broadcast_relay(mut self, relay: Arc<dyn BroadcastRelay>) -> Self {
+        self.ws_manager = self.ws_manager.with_relay(relay);
+        self
+    }
+
+    /// Replace the access token keys. Without this, tokens are signed with a
+    /// random per-process secret.
+    pub fn with_jwt(mut self, keys: JwtKeys) -> Self {
+        self.jwt = Arc::new(keys);
+        self
+    }
+
+    /// Register the account with this email as an admin, to bootstrap the
+    /// first admin of a deployment
+    pub fn with_admin_email(mut self, email: &str) -> Self {
+        self.admin_email = Some(User::normalize_email(email));
+        self
+    }
+
+    /// Limit how many REST requests each client may make per window
+    pub fn with_rate_limit(mut self, settings: RateLimitSettings) -> Self {
+        self.rate_limiter = Some(ApiRateLimiter::new(settings));
+        self
+    }
+
     pub fn new(pool: PgPool, seed_api_key: Option<String>) -> Self {
-        let team_repo: Arc<dyn TeamRepository> = Arc::new(SqlxTeamRepository::new(pool.clone()));
-        let player_repo: Arc<dyn PlayerRepository> =
-            Arc::new(SqlxPlayerRepository::new(pool.clone()));
+        Self::with_source_precedence(pool, seed_api_key, SourcePrecedence::default())
+    }
+
+    /// Like `new`, with the order in which combine, pro day and school results
+    /// are trusted when scoring RAS. It has to be known up front because the
+    /// draft engine's auto-pick scoring shares the RAS service.
+    pub fn with_source_precedence(
+        pool: PgPool,
+        seed_api_key: Option<String>,
+        source_precedence: SourcePrecedence,
+    ) -> Self {
+        Self::with_cache(pool, seed_api_key, source_precedence, None)
+    }
+
+    /// Like `with_source_precedence`, reading teams, prospect rankings and
+    /// combine percentiles through `cache` when one is given. The
+    /// repositories are wrapped before the services that share them are built.
+    pub fn with_cache(
+        pool: PgPool,
+        seed_api_key: Option<String>,
+        source_precedence: SourcePrecedence,
+        cache: Option<Arc<dyn Cache>>,
+    ) -> Self {
+        Self::with_pools(
+            DbPools::single(pool),
+            seed_api_key,
+            source_precedence,
+            cache,
+        )
+    }
+
+    /// Like `with_cache`, sending the reference data repositories' collection
+    /// reads (players, teams, rankings, combine results and the like) to
+    /// `pools.read` so simulation and analytics traffic stays off the
+    /// primary. Live draft state is always read from the primary, which
+    /// `pool()` also returns.
+    pub fn with_pools(
+        pools: DbPools,
+        seed_api_key: Option<String>,
+        source_precedence: SourcePrecedence,
+        cache: Option<Arc<dyn Cache>>,
+    ) -> Self {
+        let DbPools {
+            write: pool,
+            read: read_pool,
+        } = pools;
+        let mut team_repo: Arc<dyn TeamRepository> =
+            Arc::new(SqlxTeamRepository::new(pool.clone()).with_read_pool(read_pool.clone()));
+        let player_repo: Arc<dyn PlayerRepository> = Arc::new(AuditedPlayerRepository::new(
+            Arc::new(SqlxPlayerRepository::new(pool.clone()).with_read_pool(read_pool.clone())),
+        ));
         let draft_repo: Arc<dyn DraftRepository> = Arc::new(SqlxDraftRepository::new(pool.clone()));
-        let draft_pick_repo: Arc<dyn DraftPickRepository> =
-            Arc::new(SqlxDraftPickRepository::new(pool.clone()));
-        let combine_results_repo: Arc<dyn CombineResultsRepository> =
-            Arc::new(SqlxCombineResultsRepository::new(pool.clone()));
-        let combine_percentile_repo: Arc<dyn CombinePercentileRepository> =
-            Arc::new(SqlxCombinePercentileRepository::new(pool.clone()));
-        let scouting_report_repo: Arc<dyn ScoutingReportRepository> =
-            Arc::new(SqlxScoutingReportRepository::new(pool.clone()));
+        let draft_pick_repo: Arc<dyn DraftPickRepository> = Arc::new(
+            AuditedDraftPickRepository::new(Arc::new(SqlxDraftPickRepository::new(pool.clone()))),
+        );
+        let combine_results_repo: Arc<dyn CombineResultsRepository> = Arc::new(
+            SqlxCombineResultsRepository::new(pool.clone()).with_read_pool(read_pool.clone()),
+        );
+        let mut combine_percentile_repo: Arc<dyn CombinePercentileRepository> = Arc::new(
+            SqlxCombinePercentileRepository::new(pool.clone()).with_read_pool(read_pool.clone()),
+        );
+        let scouting_report_repo: Arc<dyn ScoutingReportRepository> = Arc::new(
+            SqlxScoutingReportRepository::new(pool.clone()).with_read_pool(read_pool.clone()),
+        );
         let team_need_repo: Arc<dyn TeamNeedRepository> =
-            Arc::new(SqlxTeamNeedRepository::new(pool.clone()));
+            Arc::new(SqlxTeamNeedRepository::new(pool.clone()).with_read_pool(read_pool.clone()));
         let team_season_repo: Arc<dyn TeamSeasonRepository> =
-            Arc::new(SqlxTeamSeasonRepository::new(pool.clone()));
-        let session_repo: Arc<dyn SessionRepository> = Arc::new(SessionRepo::new(pool.clone()));
+            Arc::new(SqlxTeamSeasonRepository::new(pool.clone()).with_read_pool(read_pool.clone()));
+        let session_repo: Arc<dyn SessionRepository> = Arc::new(AuditedSessionRepository::new(
+            Arc::new(SessionRepo::new(pool.clone())),
+            draft_pick_repo.clone(),
+        ));
+        let participant_repo: Arc<dyn SessionParticipantRepository> =
+            Arc::new(SqlxSessionParticipantRepository::new(pool.clone()));
+        let chat_repo: Arc<dyn ChatMessageRepository> =
+            Arc::new(SqlxChatMessageRepository::new(pool.clone()));
         let event_repo: Arc<dyn EventRepository> = Arc::new(EventRepo::new(pool.clone()));
-        let trade_repo: Arc<dyn TradeRepository> = Arc::new(SqlxTradeRepository::new(pool.clone()));
-        let ranking_source_repo: Arc<dyn RankingSourceRepository> =
-            Arc::new(SqlxRankingSourceRepository::new(pool.clone()));
-        let prospect_ranking_repo: Arc<dyn ProspectRankingRepository> =
-            Arc::new(SqlxProspectRankingRepository::new(pool.clone()));
-        let feldman_freak_repo: Arc<dyn FeldmanFreakRepository> =
-            Arc::new(SqlxFeldmanFreakRepository::new(pool.clone()));
-        let prospect_profile_repo: Arc<dyn ProspectProfileRepository> =
-            Arc::new(SqlxProspectProfileRepository::new(pool.clone()));
+        let trade_repo: Arc<dyn TradeRepository> = Arc::new(AuditedTradeRepository::new(
+            Arc::new(SqlxTradeRepository::new(pool.clone())),
+            draft_pick_repo.clone(),
+        ));
+        let ranking_source_repo: Arc<dyn RankingSourceRepository> = Arc::new(
+            SqlxRankingSourceRepository::new(pool.clone()).with_read_pool(read_pool.clone()),
+        );
+        let mut prospect_ranking_repo: Arc<dyn ProspectRankingRepository> = Arc::new(
+            SqlxProspectRankingRepository::new(pool.clone()).with_read_pool(read_pool.clone()),
+        );
+        let feldman_freak_repo: Arc<dyn FeldmanFreakRepository> = Arc::new(
+            SqlxFeldmanFreakRepository::new(pool.clone()).with_read_pool(read_pool.clone()),
+        );
+        let prospect_profile_repo: Arc<dyn ProspectProfileRepository> = Arc::new(
+            SqlxProspectProfileRepository::new(pool.clone()).with_read_pool(read_pool.clone()),
+        );
+        let user_repo: Arc<dyn UserRepository> = Arc::new(SqlxUserRepository::new(pool.clone()));
+        let actual_draft_pick_repo: Arc<dyn ActualDraftPickRepository> = Arc::new(
+            SqlxActualDraftPickRepository::new(pool.clone()).with_read_pool(read_pool.clone()),
+        );
+        let adp_repo: Arc<dyn AdpRepository> =
+            Arc::new(SqlxAdpRepository::new(pool.clone()).with_read_pool(read_pool.clone()));
+        let player_note_repo: Arc<dyn PlayerNoteRepository> =
+            Arc::new(SqlxPlayerNoteRepository::new(pool.clone()));
+        let player_tag_repo: Arc<dyn PlayerTagRepository> =
+            Arc::new(SqlxPlayerTagRepository::new(pool.clone()));
+        let college_stats_repo: Arc<dyn CollegeStatsRepository> = Arc::new(
+            SqlxCollegeStatsRepository::new(pool.clone()).with_read_pool(read_pool.clone()),
+        );
+        let watchlist_repo: Arc<dyn WatchlistRepository> =
+            Arc::new(SqlxWatchlistRepository::new(pool.clone()));
         let draft_strategy_repo: Arc<dyn DraftStrategyRepository> =
             Arc::new(SqlxDraftStrategyRepository::new(pool.clone()));
+        let audit_log_repo: Arc<dyn AuditLogRepository> =
+            Arc::new(SqlxAuditLogRepository::new(pool.clone()));
 
-        let ras_service = Arc::new(RasScoringService::new(combine_percentile_repo.clone()));
+        if let Some(cache) = &cache {
+            team_repo = Arc::new(CachedTeamRepository::new(team_repo, cache.clone()));
+            combine_percentile_repo = Arc::new(CachedCombinePercentileRepository::new(
+                combine_percentile_repo,
+                cache.clone(),
+            ));
+            prospect_ranking_repo = Arc::new(CachedProspectRankingRepository::new(
+                prospect_ranking_repo,
+                cache.clone(),
+            ));
+        }
+        let team_repo: Arc<dyn TeamRepository> = Arc::new(AuditedTeamRepository::new(team_repo));
+
+        let ras_service = Arc::new(
+            RasScoringService::new(combine_percentile_repo.clone())
+                .with_source_precedence(source_precedence),
+        );
 
         let player_eval_service = Arc::new(
             PlayerEvaluationService::new(
@@ -124,7 +310,8 @@ impl AppState {
                 player_repo.clone(),
             )
             .with_team_season_repo(team_season_repo.clone())
-            .with_auto_pick(auto_pick_service),
+            .with_auto_pick(auto_pick_service)
+            .with_draft_lock(Arc::new(SqlxDraftLockRepository::new(pool.clone()))),
         );
 
         let trade_engine = Arc::new(TradeEngine::with_default_chart(
@@ -139,6 +326,7 @@ impl AppState {
 
         Self {
             pool,
+            read_pool,
             team_repo,
             player_repo,
             draft_repo,
@@ -149,19 +337,33 @@ impl AppState {
             team_need_repo,
             team_season_repo,
             session_repo,
+            participant_repo,
+            chat_repo,
             event_repo,
             trade_repo,
             ranking_source_repo,
             prospect_ranking_repo,
             feldman_freak_repo,
             prospect_profile_repo,
+            user_repo,
+            actual_draft_pick_repo,
+            adp_repo,
+            player_note_repo,
+            player_tag_repo,
+            college_stats_repo,
+            watchlist_repo,
+            audit_log_repo,
             ras_service,
             draft_engine,
             trade_engine,
             ws_manager,
             seed_api_key,
+            jwt: Arc::new(JwtKeys::random(DEFAULT_TOKEN_TTL)),
+            admin_email: None,
+            rate_limiter: None,
             session_locks,
             auto_pick_cancel,
+            cache,
         }
     }
 }
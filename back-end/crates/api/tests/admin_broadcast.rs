@@ -0,0 +1,82 @@
+//! Admin announcement broadcast acceptance tests
+
+use reqwest::StatusCode;
+use serde_json::{json, Value};
+use uuid::Uuid;
+
+const ADMIN_KEY: &str = "test-admin-key";
+
+#[tokio::test]
+async fn test_broadcast_requires_api_key() {
+    let (app_url, _pool) = test_support::spawn_app_with_seed_key(ADMIN_KEY).await;
+    let client = test_support::create_client();
+
+    let response = client
+        .post(format!("{}/api/v1/admin/broadcast", app_url))
+        .json(&json!({ "message": "Draft resumes in 5 minutes" }))
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+
+    let response = client
+        .post(format!("{}/api/v1/admin/broadcast", app_url))
+        .header("X-Seed-Api-Key", ADMIN_KEY)
+        .json(&json!({ "message": "   " }))
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+
+    let response = client
+        .post(format!("{}/api/v1/admin/broadcast", app_url))
+        .header("X-Seed-Api-Key", ADMIN_KEY)
+        .json(&json!({ "message": "Hello", "session_id": Uuid::new_v4() }))
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::NOT_FOUND);
+}
+
+#[tokio::test]
+async fn test_broadcast_reaches_connected_sessions() {
+    let pool = test_support::setup_test_pool().await;
+
+    let state = api::AppState::new(pool.clone(), Some(ADMIN_KEY.to_string()));
+    let app_url = test_support::spawn_app_with_state(state.clone()).await;
+    let client = test_support::create_client();
+
+    let first_session = Uuid::new_v4();
+    let second_session = Uuid::new_v4();
+    let (first_tx, mut first_rx) = tokio::sync::mpsc::channel(16);
+    let (second_tx, mut second_rx) = tokio::sync::mpsc::channel(16);
+    state
+        .ws_manager
+        .add_connection(Uuid::new_v4(), first_session, first_tx);
+    state
+        .ws_manager
+        .add_connection(Uuid::new_v4(), second_session, second_tx);
+
+    let response = client
+        .post(format!("{}/api/v1/admin/broadcast", app_url))
+        .header("X-Seed-Api-Key", ADMIN_KEY)
+        .json(&json!({ "message": "Draft resumes in 5 minutes" }))
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+
+    let body: Value = response.json().await.unwrap();
+    assert_eq!(body["sessions"], 2);
+    assert_eq!(body["connections"], 2);
+
+    for (rx, session_id) in [
+        (&mut first_rx, first_session),
+        (&mut second_rx, second_session),
+    ] {
+        let message: Value = serde_json::from_str(&rx.try_recv().unwrap()).unwrap();
+        assert_eq!(message["type"], "admin_announcement");
+        assert_eq!(message["session_id"], session_id.to_string());
+        assert_eq!(message["message"], "Draft resumes in 5 minutes");
+    }
+}
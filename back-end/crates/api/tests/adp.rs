@@ -0,0 +1,138 @@
+//! Average draft position acceptance tests
+
+use reqwest::StatusCode;
+use serde_json::{json, Value};
+
+async fn post(client: &reqwest::Client, url: String, body: Value) -> Value {
+    let response = client
+        .post(url)
+        .json(&body)
+        .send()
+        .await
+        .expect("Failed to send request");
+    assert!(response.status().is_success(), "{}", response.status());
+    response.json().await.unwrap()
+}
+
+/// Creates, initializes and starts a one-round draft of two picks
+async fn start_draft(client: &reqwest::Client, base_url: &str, name: &str) -> (String, Vec<Value>) {
+    let draft = post(
+        client,
+        format!("{}/api/v1/drafts", base_url),
+        json!({ "name": name, "year": 2026, "rounds": 1, "picks_per_round": 2 }),
+    )
+    .await;
+    let draft_id = draft["id"].as_str().unwrap().to_string();
+    let picks = post(
+        client,
+        format!("{}/api/v1/drafts/{}/initialize", base_url, draft_id),
+        json!({}),
+    )
+    .await;
+    post(
+        client,
+        format!("{}/api/v1/drafts/{}/start", base_url, draft_id),
+        json!({}),
+    )
+    .await;
+    (draft_id, picks.as_array().unwrap().clone())
+}
+
+async fn make_pick(client: &reqwest::Client, base_url: &str, pick: &Value, player: &Value) {
+    post(
+        client,
+        format!(
+            "{}/api/v1/picks/{}/make",
+            base_url,
+            pick["id"].as_str().unwrap()
+        ),
+        json!({ "player_id": player["id"] }),
+    )
+    .await;
+}
+
+#[tokio::test]
+async fn test_get_adp() {
+    let (base_url, _pool) = test_support::spawn_app().await;
+    let client = test_support::create_client_with_role(domain::models::Role::Admin);
+
+    for abbreviation in ["ADA", "ADB"] {
+        post(
+            &client,
+            format!("{}/api/v1/teams", base_url),
+            json!({
+                "name": format!("ADP {}", abbreviation),
+                "abbreviation": abbreviation,
+                "city": "ADP City",
+                "conference": "AFC",
+                "division": "AFC East"
+            }),
+        )
+        .await;
+    }
+    let mut players = Vec::new();
+    for (last_name, position) in [("Alpha", "WR"), ("Bravo", "QB"), ("Charlie", "RB")] {
+        players.push(
+            post(
+                &client,
+                format!("{}/api/v1/players", base_url),
+                json!({
+                    "first_name": "Adp",
+                    "last_name": last_name,
+                    "position": position,
+                    "draft_year": 2026
+                }),
+            )
+            .await,
+        );
+    }
+
+    // First mock: Alpha 1, Bravo 2; the final pick completes it
+    let (_, picks) = start_draft(&client, &base_url, "ADP Mock 1").await;
+    make_pick(&client, &base_url, &picks[0], &players[0]).await;
+    make_pick(&client, &base_url, &picks[1], &players[1]).await;
+
+    // Second mock: Bravo 1, then completed early with pick 2 unmade
+    let (draft_id, picks) = start_draft(&client, &base_url, "ADP Mock 2").await;
+    make_pick(&client, &base_url, &picks[0], &players[1]).await;
+    post(
+        &client,
+        format!("{}/api/v1/drafts/{}/complete", base_url, draft_id),
+        json!({}),
+    )
+    .await;
+
+    let response = client
+        .get(format!("{}/api/v1/adp?year=2026", base_url))
+        .send()
+        .await
+        .expect("Failed to get ADP");
+    assert_eq!(response.status(), StatusCode::OK);
+    assert_eq!(response.headers()["x-total-count"], "2");
+    let adp: Value = response.json().await.unwrap();
+    let adp = adp.as_array().unwrap();
+
+    assert_eq!(adp[0]["last_name"], "Alpha");
+    assert_eq!(adp[0]["times_drafted"], 1);
+    assert_eq!(adp[0]["draft_rate"], 0.5);
+    assert_eq!(adp[0]["average_pick"], 1.0);
+    assert!(adp[0]["trend"].is_null());
+
+    // Bravo went 2nd then 1st: rising by a pick
+    assert_eq!(adp[1]["last_name"], "Bravo");
+    assert_eq!(adp[1]["times_drafted"], 2);
+    assert_eq!(adp[1]["draft_rate"], 1.0);
+    assert_eq!(adp[1]["average_pick"], 1.5);
+    assert_eq!(adp[1]["min_pick"], 1);
+    assert_eq!(adp[1]["max_pick"], 2);
+    assert_eq!(adp[1]["recent_average"], 1.0);
+    assert_eq!(adp[1]["trend"], 1.0);
+
+    let response = client
+        .get(format!("{}/api/v1/adp?year=2027", base_url))
+        .send()
+        .await
+        .expect("Failed to get ADP");
+    let adp: Value = response.json().await.unwrap();
+    assert!(adp.as_array().unwrap().is_empty());
+}
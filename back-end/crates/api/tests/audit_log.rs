@@ -0,0 +1,259 @@
+use std::time::Duration;
+
+use reqwest::{Client, StatusCode};
+use serde_json::{json, Value};
+use uuid::Uuid;
+
+/// A commissioner client whose user id the test knows
+fn commissioner(user_id: Uuid) -> Client {
+    let token = test_support::test_jwt_keys()
+        .issue(
+            user_id,
+            "commish@example.com",
+            domain::models::Role::Commissioner,
+        )
+        .unwrap();
+    let mut headers = reqwest::header::HeaderMap::new();
+    headers.insert(
+        reqwest::header::AUTHORIZATION,
+        format!("Bearer {}", token).parse().unwrap(),
+    );
+    Client::builder()
+        .timeout(Duration::from_secs(30))
+        .default_headers(headers)
+        .build()
+        .unwrap()
+}
+
+async fn audit_entries(client: &Client, app_url: &str, query: &str) -> Vec<Value> {
+    let response = client
+        .get(format!("{}/api/v1/admin/audit-log?{}", app_url, query))
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+    response.json().await.unwrap()
+}
+
+/// Ids from `seed_session`
+struct SessionFixture {
+    session_id: Uuid,
+    team_id: Uuid,
+    pick_id: Uuid,
+    player_id: Uuid,
+}
+
+/// An in-progress session whose first pick belongs to one team
+async fn seed_session(pool: &sqlx::PgPool) -> SessionFixture {
+    let draft_id = Uuid::new_v4();
+    let session_id = Uuid::new_v4();
+    let team_id = Uuid::new_v4();
+    let pick_id = Uuid::new_v4();
+    let player_id = Uuid::new_v4();
+
+    sqlx::query(
+        "INSERT INTO drafts (id, year, status, rounds, picks_per_round) VALUES ($1, 2026, 'InProgress', 1, 2)",
+    )
+    .bind(draft_id)
+    .execute(pool)
+    .await
+    .unwrap();
+    sqlx::query(
+        "INSERT INTO teams (id, name, city, abbreviation, conference, division) VALUES ($1, 'Audit Team', 'Test', 'AUD', 'NFC', 'NFC East')",
+    )
+    .bind(team_id)
+    .execute(pool)
+    .await
+    .unwrap();
+    sqlx::query(
+        "INSERT INTO players (id, first_name, last_name, position, draft_year) VALUES ($1, 'Audited', 'Pick', 'QB', 2026)",
+    )
+    .bind(player_id)
+    .execute(pool)
+    .await
+    .unwrap();
+    // A second pick keeps the session from completing after the first
+    for (id, number) in [(pick_id, 1), (Uuid::new_v4(), 2)] {
+        sqlx::query(
+            "INSERT INTO draft_picks (id, draft_id, round, pick_number, overall_pick, team_id) VALUES ($1, $2, 1, $3, $3, $4)",
+        )
+        .bind(id)
+        .bind(draft_id)
+        .bind(number)
+        .bind(team_id)
+        .execute(pool)
+        .await
+        .unwrap();
+    }
+    sqlx::query(
+        "INSERT INTO draft_sessions (id, draft_id, status, current_pick_number, time_per_pick_seconds, auto_pick_enabled, controlled_team_ids) VALUES ($1, $2, 'InProgress', 1, 300, false, $3)",
+    )
+    .bind(session_id)
+    .bind(draft_id)
+    .bind(vec![team_id])
+    .execute(pool)
+    .await
+    .unwrap();
+
+    SessionFixture {
+        session_id,
+        team_id,
+        pick_id,
+        player_id,
+    }
+}
+
+#[tokio::test]
+async fn test_pick_made_in_session_is_audited_with_actor() {
+    let (app_url, pool) = test_support::spawn_app().await;
+    let user_id = Uuid::new_v4();
+    let client = commissioner(user_id);
+
+    let SessionFixture {
+        session_id,
+        pick_id,
+        player_id,
+        ..
+    } = seed_session(&pool).await;
+
+    let response = client
+        .post(format!("{}/api/v1/picks/{}/make", app_url, pick_id))
+        .json(&json!({ "player_id": player_id }))
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+    let request_id = response
+        .headers()
+        .get("x-request-id")
+        .unwrap()
+        .to_str()
+        .unwrap()
+        .to_string();
+
+    let entries = audit_entries(
+        &client,
+        &app_url,
+        &format!("entity_type=picks&entity_id={}", pick_id),
+    )
+    .await;
+    assert_eq!(entries.len(), 1);
+    let entry = &entries[0];
+    assert_eq!(entry["actor"], "commish@example.com");
+    assert_eq!(entry["actor_user_id"], user_id.to_string());
+    assert_eq!(entry["method"], "POST");
+    assert_eq!(entry["route"], "/api/v1/picks/{id}/make");
+    assert_eq!(entry["status_code"], 200);
+    assert_eq!(entry["request_id"], request_id);
+    assert_eq!(entry["before"]["player_id"], Value::Null);
+    assert_eq!(entry["after"]["player_id"], player_id.to_string());
+
+    let entries = audit_entries(
+        &client,
+        &app_url,
+        &format!("entity_type=sessions&entity_id={}", session_id),
+    )
+    .await;
+    assert_eq!(entries.len(), 1);
+    assert_eq!(entries[0]["before"]["current_pick_number"], 1);
+    assert_eq!(entries[0]["after"]["current_pick_number"], 2);
+
+    let entries = audit_entries(&client, &app_url, &format!("actor_user_id={}", user_id)).await;
+    assert_eq!(entries.len(), 2);
+}
+
+#[tokio::test]
+async fn test_participant_pick_is_audited_under_their_seat() {
+    let (app_url, pool) = test_support::spawn_app().await;
+    let fx = seed_session(&pool).await;
+    let (user_id, client) =
+        test_support::create_user_client(&pool, domain::models::Role::Participant).await;
+
+    let response = client
+        .post(format!(
+            "{}/api/v1/sessions/{}/participants",
+            app_url, fx.session_id
+        ))
+        .json(&json!({ "display_name": "Alice", "team_ids": [fx.team_id] }))
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::CREATED);
+    let seat: Value = response.json().await.unwrap();
+
+    let response = client
+        .post(format!("{}/api/v1/picks/{}/make", app_url, fx.pick_id))
+        .json(&json!({ "player_id": fx.player_id }))
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+
+    let admin = test_support::create_client();
+    let entries = audit_entries(
+        &admin,
+        &app_url,
+        &format!("entity_type=picks&entity_id={}", fx.pick_id),
+    )
+    .await;
+    assert_eq!(entries.len(), 1);
+    assert_eq!(entries[0]["actor"], "Alice");
+    assert_eq!(entries[0]["actor_user_id"], user_id.to_string());
+    assert_eq!(entries[0]["actor_participant_id"], seat["id"]);
+}
+
+#[tokio::test]
+async fn test_team_update_is_audited_with_before_and_after_and_reads_are_not() {
+    let (app_url, pool) = test_support::spawn_app().await;
+    let client = test_support::create_client_with_role(domain::models::Role::Admin);
+
+    let team_id = Uuid::new_v4();
+    sqlx::query(
+        "INSERT INTO teams (id, name, city, abbreviation, conference, division) VALUES ($1, 'Audit Team', 'Test', 'AUD', 'NFC', 'NFC East')",
+    )
+    .bind(team_id)
+    .execute(&pool)
+    .await
+    .unwrap();
+
+    let response = client
+        .patch(format!("{}/api/v1/teams/{}", app_url, team_id))
+        .json(&json!({ "city": "Elsewhere" }))
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+    let response = client
+        .get(format!("{}/api/v1/teams/{}", app_url, team_id))
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+
+    let entries = audit_entries(&client, &app_url, &format!("entity_id={}", team_id)).await;
+    assert_eq!(entries.len(), 1);
+    assert_eq!(entries[0]["actor"], "tester@example.com");
+    assert_eq!(entries[0]["method"], "PATCH");
+    assert_eq!(entries[0]["entity_type"], "teams");
+    assert_eq!(entries[0]["before"]["city"], "Test");
+    assert_eq!(entries[0]["after"]["city"], "Elsewhere");
+}
+
+#[tokio::test]
+async fn test_audit_log_requires_commissioner() {
+    let (app_url, _pool) = test_support::spawn_app().await;
+
+    let response = test_support::create_client_with_role(domain::models::Role::Participant)
+        .get(format!("{}/api/v1/admin/audit-log", app_url))
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::FORBIDDEN);
+
+    let response = test_support::create_anonymous_client()
+        .get(format!("{}/api/v1/admin/audit-log", app_url))
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+}
@@ -0,0 +1,157 @@
+//! User registration, sign-in and access token acceptance tests
+
+use reqwest::StatusCode;
+use serde_json::{json, Value};
+use uuid::Uuid;
+
+#[tokio::test]
+async fn test_register_login_and_me() {
+    let (base_url, _pool) = test_support::spawn_app().await;
+    let client = test_support::create_anonymous_client();
+
+    let response = client
+        .post(format!("{}/api/v1/auth/register", base_url))
+        .json(&json!({
+            "email": "Coach@Example.com",
+            "display_name": "Coach",
+            "password": "correct horse"
+        }))
+        .send()
+        .await
+        .expect("Failed to register");
+    assert_eq!(response.status(), StatusCode::CREATED);
+    let registered: Value = response.json().await.unwrap();
+    assert_eq!(registered["token_type"], "Bearer");
+    assert_eq!(registered["user"]["email"], "coach@example.com");
+    assert!(registered["user"].get("password_hash").is_none());
+
+    let response = client
+        .post(format!("{}/api/v1/auth/register", base_url))
+        .json(&json!({
+            "email": "coach@example.com",
+            "display_name": "Someone Else",
+            "password": "another password"
+        }))
+        .send()
+        .await
+        .expect("Failed to register");
+    assert_eq!(response.status(), StatusCode::CONFLICT);
+
+    let response = client
+        .post(format!("{}/api/v1/auth/login", base_url))
+        .json(&json!({ "email": "coach@example.com", "password": "wrong horse" }))
+        .send()
+        .await
+        .expect("Failed to log in");
+    assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+
+    let response = client
+        .post(format!("{}/api/v1/auth/login", base_url))
+        .json(&json!({ "email": "nobody@example.com", "password": "correct horse" }))
+        .send()
+        .await
+        .expect("Failed to log in");
+    assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+
+    let response = client
+        .post(format!("{}/api/v1/auth/login", base_url))
+        .json(&json!({ "email": " COACH@example.com", "password": "correct horse" }))
+        .send()
+        .await
+        .expect("Failed to log in");
+    assert_eq!(response.status(), StatusCode::OK);
+    let login: Value = response.json().await.unwrap();
+    let token = login["access_token"].as_str().unwrap();
+
+    let response = client
+        .get(format!("{}/api/v1/auth/me", base_url))
+        .bearer_auth(token)
+        .send()
+        .await
+        .expect("Failed to fetch current user");
+    assert_eq!(response.status(), StatusCode::OK);
+    let me: Value = response.json().await.unwrap();
+    assert_eq!(me["id"], registered["user"]["id"]);
+    assert_eq!(me["display_name"], "Coach");
+
+    let response = client
+        .get(format!("{}/api/v1/auth/me", base_url))
+        .bearer_auth("not-a-token")
+        .send()
+        .await
+        .expect("Failed to fetch current user");
+    assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+}
+
+#[tokio::test]
+async fn test_register_rejects_short_password() {
+    let (base_url, _pool) = test_support::spawn_app().await;
+    let client = test_support::create_anonymous_client();
+
+    let response = client
+        .post(format!("{}/api/v1/auth/register", base_url))
+        .json(&json!({
+            "email": "coach@example.com",
+            "display_name": "Coach",
+            "password": "short"
+        }))
+        .send()
+        .await
+        .expect("Failed to register");
+    assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+}
+
+#[tokio::test]
+async fn test_session_pick_and_trade_changes_require_sign_in() {
+    let (base_url, _pool) = test_support::spawn_app().await;
+    let client = test_support::create_anonymous_client();
+    let id = Uuid::new_v4();
+
+    let requests = [
+        (
+            format!("{}/api/v1/sessions", base_url),
+            json!({ "draft_id": id, "time_per_pick_seconds": 60, "auto_pick_enabled": false }),
+        ),
+        (
+            format!("{}/api/v1/sessions/{}/start", base_url, id),
+            json!({}),
+        ),
+        (
+            format!("{}/api/v1/sessions/{}/pause", base_url, id),
+            json!({}),
+        ),
+        (
+            format!("{}/api/v1/picks/{}/make", base_url, id),
+            json!({ "player_id": id }),
+        ),
+        (
+            format!("{}/api/v1/trades/{}/accept", base_url, id),
+            json!({ "team_id": id }),
+        ),
+    ];
+    for (url, body) in requests {
+        let response = client
+            .post(&url)
+            .json(&body)
+            .send()
+            .await
+            .expect("Failed to send request");
+        assert_eq!(response.status(), StatusCode::UNAUTHORIZED, "{}", url);
+    }
+
+    // Reads stay public
+    let response = client
+        .get(format!("{}/api/v1/sessions/{}", base_url, id))
+        .send()
+        .await
+        .expect("Failed to fetch session");
+    assert_eq!(response.status(), StatusCode::NOT_FOUND);
+
+    // A signed-in client gets past authentication to the handler
+    let response = test_support::create_client()
+        .post(format!("{}/api/v1/sessions/{}/start", base_url, id))
+        .send()
+        .await
+        .expect("Failed to start session");
+    assert_eq!(response.status(), StatusCode::NOT_FOUND);
+}
@@ -0,0 +1,342 @@
+//! Role-based access control acceptance tests
+
+use reqwest::StatusCode;
+use serde_json::{json, Value};
+use uuid::Uuid;
+
+use domain::models::Role;
+
+#[tokio::test]
+async fn test_session_control_requires_commissioner() {
+    let (base_url, _pool) = test_support::spawn_app().await;
+    let id = Uuid::new_v4();
+
+    let response = test_support::create_client_with_role(Role::Participant)
+        .post(format!("{}/api/v1/sessions/{}/start", base_url, id))
+        .send()
+        .await
+        .expect("Failed to start session");
+    assert_eq!(response.status(), StatusCode::FORBIDDEN);
+    let body: Value = response.json().await.unwrap();
+    assert_eq!(body["error"], "Requires the commissioner role");
+
+    let response = test_support::create_client_with_role(Role::Commissioner)
+        .post(format!("{}/api/v1/sessions/{}/start", base_url, id))
+        .send()
+        .await
+        .expect("Failed to start session");
+    assert_eq!(response.status(), StatusCode::NOT_FOUND);
+}
+
+#[tokio::test]
+async fn test_picks_and_trades_require_participant() {
+    let (base_url, _pool) = test_support::spawn_app().await;
+    let id = Uuid::new_v4();
+    let spectator = test_support::create_client_with_role(Role::Spectator);
+
+    let response = spectator
+        .post(format!("{}/api/v1/picks/{}/make", base_url, id))
+        .json(&json!({ "player_id": id }))
+        .send()
+        .await
+        .expect("Failed to make pick");
+    assert_eq!(response.status(), StatusCode::FORBIDDEN);
+
+    let response = spectator
+        .post(format!("{}/api/v1/trades/{}/reject", base_url, id))
+        .json(&json!({ "team_id": id }))
+        .send()
+        .await
+        .expect("Failed to reject trade");
+    assert_eq!(response.status(), StatusCode::FORBIDDEN);
+
+    // Spectators can still read
+    let response = spectator
+        .get(format!("{}/api/v1/teams", base_url))
+        .send()
+        .await
+        .expect("Failed to list teams");
+    assert_eq!(response.status(), StatusCode::OK);
+
+    let response = test_support::create_client_with_role(Role::Participant)
+        .post(format!("{}/api/v1/picks/{}/make", base_url, id))
+        .json(&json!({ "player_id": id }))
+        .send()
+        .await
+        .expect("Failed to make pick");
+    assert_eq!(response.status(), StatusCode::NOT_FOUND);
+}
+
+#[tokio::test]
+async fn test_reference_data_writes_require_admin() {
+    let (base_url, _pool) = test_support::spawn_app().await;
+    let id = Uuid::new_v4();
+
+    // Without a seed key the admin surface is hidden from commissioners
+    let response = test_support::create_client()
+        .delete(format!("{}/api/v1/teams/{}?cascade=true", base_url, id))
+        .send()
+        .await
+        .expect("Failed to delete team");
+    assert_eq!(response.status(), StatusCode::NOT_FOUND);
+
+    let response = test_support::create_client()
+        .patch(format!("{}/api/v1/players/{}", base_url, id))
+        .json(&json!({ "first_name": "Renamed" }))
+        .send()
+        .await
+        .expect("Failed to update player");
+    assert_eq!(response.status(), StatusCode::NOT_FOUND);
+
+    let response = test_support::create_client_with_role(Role::Admin)
+        .delete(format!("{}/api/v1/scouting-reports/{}", base_url, id))
+        .send()
+        .await
+        .expect("Failed to delete scouting report");
+    assert_eq!(response.status(), StatusCode::NOT_FOUND);
+    let body: Value = response.json().await.unwrap();
+    assert_eq!(body["code"], "NOT_FOUND");
+
+    // Scouting notes need a signed-in participant
+    let response = test_support::create_anonymous_client()
+        .post(format!("{}/api/v1/players/{}/notes", base_url, id))
+        .json(&json!({ "body": "Quick feet" }))
+        .send()
+        .await
+        .expect("Failed to add note");
+    assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+
+    let response = test_support::create_client_with_role(Role::Spectator)
+        .delete(format!("{}/api/v1/players/{}/tags/sleeper", base_url, id))
+        .send()
+        .await
+        .expect("Failed to remove tag");
+    assert_eq!(response.status(), StatusCode::FORBIDDEN);
+}
+
+#[tokio::test]
+async fn test_admin_grants_role_without_seed_key() {
+    // No seed key configured, so role changes need a signed-in commissioner
+    // or admin
+    let (base_url, _pool) = test_support::spawn_app().await;
+    let client = test_support::create_anonymous_client();
+
+    let response = client
+        .post(format!("{}/api/v1/auth/register", base_url))
+        .json(&json!({
+            "email": "commish@example.com",
+            "display_name": "Commish",
+            "password": "correct horse"
+        }))
+        .send()
+        .await
+        .expect("Failed to register");
+    assert_eq!(response.status(), StatusCode::CREATED);
+    let registered: Value = response.json().await.unwrap();
+    assert_eq!(registered["user"]["role"], "spectator");
+    let user_id = registered["user"]["id"].as_str().unwrap().to_string();
+    let role_url = format!("{}/api/v1/admin/users/{}/role", base_url, user_id);
+
+    // Participants cannot let anyone in
+    let response = test_support::create_client_with_role(Role::Participant)
+        .put(&role_url)
+        .json(&json!({ "role": "participant" }))
+        .send()
+        .await
+        .expect("Failed to update role");
+    assert_eq!(response.status(), StatusCode::FORBIDDEN);
+
+    // A commissioner grants participant, but nothing above it
+    let commissioner = test_support::create_client();
+    let response = commissioner
+        .put(&role_url)
+        .json(&json!({ "role": "participant" }))
+        .send()
+        .await
+        .expect("Failed to update role");
+    assert_eq!(response.status(), StatusCode::OK);
+    let updated: Value = response.json().await.unwrap();
+    assert_eq!(updated["role"], "participant");
+
+    let response = commissioner
+        .put(&role_url)
+        .json(&json!({ "role": "admin" }))
+        .send()
+        .await
+        .expect("Failed to update role");
+    assert_eq!(response.status(), StatusCode::FORBIDDEN);
+
+    let admin = test_support::create_client_with_role(Role::Admin);
+    let response = admin
+        .put(&role_url)
+        .json(&json!({ "role": "commissioner" }))
+        .send()
+        .await
+        .expect("Failed to update role");
+    assert_eq!(response.status(), StatusCode::OK);
+    let updated: Value = response.json().await.unwrap();
+    assert_eq!(updated["role"], "commissioner");
+
+    // Nor can a commissioner demote another commissioner
+    let response = commissioner
+        .put(&role_url)
+        .json(&json!({ "role": "spectator" }))
+        .send()
+        .await
+        .expect("Failed to update role");
+    assert_eq!(response.status(), StatusCode::FORBIDDEN);
+
+    let response = admin
+        .put(format!(
+            "{}/api/v1/admin/users/{}/role",
+            base_url,
+            Uuid::new_v4()
+        ))
+        .json(&json!({ "role": "commissioner" }))
+        .send()
+        .await
+        .expect("Failed to update role");
+    assert_eq!(response.status(), StatusCode::NOT_FOUND);
+
+    // The new role is in tokens issued from the next sign-in
+    let response = client
+        .post(format!("{}/api/v1/auth/login", base_url))
+        .json(&json!({ "email": "commish@example.com", "password": "correct horse" }))
+        .send()
+        .await
+        .expect("Failed to log in");
+    let login: Value = response.json().await.unwrap();
+    let token = login["access_token"].as_str().unwrap();
+
+    let response = client
+        .post(format!(
+            "{}/api/v1/sessions/{}/pause",
+            base_url,
+            Uuid::new_v4()
+        ))
+        .bearer_auth(token)
+        .send()
+        .await
+        .expect("Failed to pause session");
+    assert_eq!(response.status(), StatusCode::NOT_FOUND);
+
+    // Admin tokens reach the other admin endpoints too
+    let response = admin
+        .post(format!("{}/api/v1/admin/broadcast", base_url))
+        .json(&json!({ "message": "   " }))
+        .send()
+        .await
+        .expect("Failed to broadcast");
+    assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+}
+
+#[tokio::test]
+async fn test_draft_and_reference_writes_reject_anonymous() {
+    // With a seed key configured the admin routes answer 401 as well
+    let (base_url, _pool) = test_support::spawn_app_with_seed_key("test-seed-key").await;
+    let client = test_support::create_anonymous_client();
+    let id = Uuid::new_v4();
+
+    let writes = [
+        ("POST", "/drafts".to_string()),
+        ("POST", format!("/drafts/{}/initialize", id)),
+        ("POST", format!("/drafts/{}/start", id)),
+        ("POST", format!("/drafts/{}/pause", id)),
+        ("POST", format!("/drafts/{}/complete", id)),
+        ("POST", "/teams".to_string()),
+        ("POST", "/players".to_string()),
+        ("POST", "/team-needs".to_string()),
+        ("PUT", format!("/team-needs/{}", id)),
+        ("DELETE", format!("/team-needs/{}", id)),
+        ("POST", "/scouting-reports".to_string()),
+        ("PUT", format!("/scouting-reports/{}", id)),
+    ];
+    for (method, path) in writes {
+        let url = format!("{}/api/v1{}", base_url, path);
+        let request = match method {
+            "POST" => client.post(&url),
+            "PUT" => client.put(&url),
+            _ => client.delete(&url),
+        };
+        let response = request
+            .json(&json!({}))
+            .send()
+            .await
+            .expect("Failed to send request");
+        assert_eq!(
+            response.status(),
+            StatusCode::UNAUTHORIZED,
+            "{} {} should need a signed-in caller",
+            method,
+            path
+        );
+    }
+
+    // Completing a draft records ADP, so participants cannot do it either
+    let response = test_support::create_client_with_role(Role::Participant)
+        .post(format!("{}/api/v1/drafts/{}/complete", base_url, id))
+        .send()
+        .await
+        .expect("Failed to complete draft");
+    assert_eq!(response.status(), StatusCode::FORBIDDEN);
+
+    // Spectators may read scouting reports but not write them
+    let response = test_support::create_client_with_role(Role::Spectator)
+        .post(format!("{}/api/v1/scouting-reports", base_url))
+        .json(&json!({}))
+        .send()
+        .await
+        .expect("Failed to create scouting report");
+    assert_eq!(response.status(), StatusCode::FORBIDDEN);
+}
+
+#[tokio::test]
+async fn test_admin_email_registers_as_admin() {
+    let pool = test_support::setup_test_pool().await;
+    let state = api::AppState::new(pool, None).with_admin_email("Owner@Example.com");
+    let base_url = test_support::spawn_app_with_state(state).await;
+    let client = test_support::create_anonymous_client();
+
+    let response = client
+        .post(format!("{}/api/v1/auth/register", base_url))
+        .json(&json!({
+            "email": "fan@example.com",
+            "display_name": "Fan",
+            "password": "correct horse"
+        }))
+        .send()
+        .await
+        .expect("Failed to register");
+    assert_eq!(response.status(), StatusCode::CREATED);
+    let fan: Value = response.json().await.unwrap();
+    assert_eq!(fan["user"]["role"], "spectator");
+
+    let response = client
+        .post(format!("{}/api/v1/auth/register", base_url))
+        .json(&json!({
+            "email": " owner@example.com",
+            "display_name": "Owner",
+            "password": "correct horse"
+        }))
+        .send()
+        .await
+        .expect("Failed to register");
+    assert_eq!(response.status(), StatusCode::CREATED);
+    let owner: Value = response.json().await.unwrap();
+    assert_eq!(owner["user"]["role"], "admin");
+    let token = owner["access_token"].as_str().unwrap();
+
+    // The bootstrapped admin can let the first commissioner in
+    let response = client
+        .put(format!(
+            "{}/api/v1/admin/users/{}/role",
+            base_url,
+            fan["user"]["id"].as_str().unwrap()
+        ))
+        .bearer_auth(token)
+        .json(&json!({ "role": "commissioner" }))
+        .send()
+        .await
+        .expect("Failed to update role");
+    assert_eq!(response.status(), StatusCode::OK);
+}
@@ -2,8 +2,6 @@ use reqwest::StatusCode;
 use serde_json::Value;
 use uuid::Uuid;
 
-mod common;
-
 /// Helper: insert a team with the given ID. Uses raw sqlx::query (not macro) to avoid
 /// type-inference issues when called in loops.
 async fn insert_team(
@@ -89,8 +87,8 @@ async fn insert_draft_pick(
 /// broadcasts PickMade via WebSocket for each pick made.
 #[tokio::test]
 async fn test_auto_pick_run_stores_pick_made_events() {
-    let (app_url, pool) = common::spawn_app().await;
-    let client = common::create_client();
+    let (app_url, pool) = test_support::spawn_app().await;
+    let client = test_support::create_client();
 
     // Setup: draft, 2 teams, 2 players, 2 picks, session (no controlled teams)
     let draft_id = Uuid::new_v4();
@@ -227,8 +225,6 @@ async fn test_auto_pick_run_stores_pick_made_events() {
             "event_data should contain pick_number"
         );
     }
-
-    common::cleanup_database(&pool).await;
 }
 
 /// Test: auto-pick-run response includes detailed pick information
@@ -237,8 +233,8 @@ async fn test_auto_pick_run_stores_pick_made_events() {
 /// picked_at timestamp, and that session.current_pick_number advances past all picks.
 #[tokio::test]
 async fn test_auto_pick_run_response_includes_pick_details() {
-    let (app_url, pool) = common::spawn_app().await;
-    let client = common::create_client();
+    let (app_url, pool) = test_support::spawn_app().await;
+    let client = test_support::create_client();
 
     let draft_id = Uuid::new_v4();
     let team_1_id = Uuid::new_v4();
@@ -355,8 +351,6 @@ async fn test_auto_pick_run_response_includes_pick_details() {
         result["session"]["current_pick_number"], 3,
         "current_pick_number should be total_picks + 1 (past the last pick)"
     );
-
-    common::cleanup_database(&pool).await;
 }
 
 /// Test: auto-pick-run assigns unique players to each pick (no duplicates)
@@ -365,8 +359,8 @@ async fn test_auto_pick_run_response_includes_pick_details() {
 /// each player to exactly one pick.
 #[tokio::test]
 async fn test_auto_pick_run_all_picks_unique_players() {
-    let (app_url, pool) = common::spawn_app().await;
-    let client = common::create_client();
+    let (app_url, pool) = test_support::spawn_app().await;
+    let client = test_support::create_client();
 
     let draft_id = Uuid::new_v4();
     let session_id = Uuid::new_v4();
@@ -502,19 +496,17 @@ async fn test_auto_pick_run_all_picks_unique_players() {
         4,
         "All 4 DB picks should have unique player_ids"
     );
-
-    common::cleanup_database(&pool).await;
 }
 
 /// Test: auto-pick-run completes draft and stores a SessionCompleted event
 ///
 /// With a small draft (1 round, 2 teams, 2 picks) and no controlled teams,
 /// auto-pick should complete the entire draft and record completion in both
-/// the session and draft tables, plus store a SessionCompleted event.
+/// the session and draft tables, plus store SessionCompleted and DraftCompleted events.
 #[tokio::test]
 async fn test_auto_pick_run_completes_draft_and_stores_completion_event() {
-    let (app_url, pool) = common::spawn_app().await;
-    let client = common::create_client();
+    let (app_url, pool) = test_support::spawn_app().await;
+    let client = test_support::create_client();
 
     let draft_id = Uuid::new_v4();
     let team_1_id = Uuid::new_v4();
@@ -636,7 +628,19 @@ async fn test_auto_pick_run_completes_draft_and_stores_completion_event() {
         "Should have exactly 1 SessionCompleted event"
     );
 
-    common::cleanup_database(&pool).await;
+    // Assert DB: draft_events has a DraftCompleted event
+    let draft_completed_count = sqlx::query!(
+        "SELECT COUNT(*) as count FROM draft_events WHERE session_id = $1 AND event_type = 'DraftCompleted'",
+        session_id
+    )
+    .fetch_one(&pool)
+    .await
+    .unwrap();
+    assert_eq!(
+        draft_completed_count.count.unwrap(),
+        1,
+        "Should have exactly 1 DraftCompleted event"
+    );
 }
 
 /// Test: auto-pick-run picks have sequential timestamps
@@ -645,8 +649,8 @@ async fn test_auto_pick_run_completes_draft_and_stores_completion_event() {
 /// order (pick 1 <= pick 2 <= pick 3), ensuring picks are recorded sequentially.
 #[tokio::test]
 async fn test_auto_pick_run_picks_have_sequential_timestamps() {
-    let (app_url, pool) = common::spawn_app().await;
-    let client = common::create_client();
+    let (app_url, pool) = test_support::spawn_app().await;
+    let client = test_support::create_client();
 
     let draft_id = Uuid::new_v4();
     let session_id = Uuid::new_v4();
@@ -758,6 +762,4 @@ async fn test_auto_pick_run_picks_have_sequential_timestamps() {
             prev_time
         );
     }
-
-    common::cleanup_database(&pool).await;
 }
@@ -1,8 +1,17 @@
+//! Integration tests for auto-pick functionality with real database
+//!
+//! These tests verify the complete auto-pick flow:
+//! 1. Database setup with teams, players, scouting reports, needs
+//! 2. Auto-pick decision execution
+//! 3. Database state verification
+//!
+//! Run with: cargo test -p api --test auto_pick_integration
+
 use std::sync::Arc;
 
 use domain::models::{
-    CombineResults, Conference, Division, Draft, DraftStrategy, FitGrade, Player, Position,
-    ScoutingReport, Team, TeamNeed,
+    CombineResults, Draft, DraftStrategy, FitGrade, Player, Position, ScoutingReport, Team,
+    TeamNeed,
 };
 use domain::repositories::{
     CombinePercentileRepository, CombineResultsRepository, DraftPickRepository, DraftRepository,
@@ -18,21 +27,11 @@ use db::repositories::{
     SqlxDraftRepository, SqlxDraftStrategyRepository, SqlxPlayerRepository,
     SqlxScoutingReportRepository, SqlxTeamNeedRepository, SqlxTeamRepository,
 };
-
-/// Integration tests for auto-pick functionality with real database
-///
-/// These tests verify the complete auto-pick flow:
-/// 1. Database setup with teams, players, scouting reports, needs
-/// 2. Auto-pick decision execution
-/// 3. Database state verification
-///
-/// Run with: cargo test -p api --test auto_pick_integration -- --test-threads=1
-mod common;
+use test_support::{PlayerBuilder, TeamBuilder};
 
 struct TestContext {
     pool: sqlx::PgPool,
     draft_engine: DraftEngine,
-    team_repo: Arc<SqlxTeamRepository>,
     player_repo: Arc<SqlxPlayerRepository>,
     draft_repo: Arc<SqlxDraftRepository>,
     pick_repo: Arc<SqlxDraftPickRepository>,
@@ -45,8 +44,7 @@ struct TestContext {
 
 impl TestContext {
     async fn new() -> Self {
-        let pool = common::setup_test_pool().await;
-        common::cleanup_database(&pool).await;
+        let pool = test_support::setup_test_pool().await;
 
         let team_repo = Arc::new(SqlxTeamRepository::new(pool.clone()));
         let player_repo = Arc::new(SqlxPlayerRepository::new(pool.clone()));
@@ -82,7 +80,7 @@ impl TestContext {
         let draft_engine = DraftEngine::new(
             draft_repo.clone() as Arc<dyn DraftRepository>,
             pick_repo.clone() as Arc<dyn DraftPickRepository>,
-            team_repo.clone() as Arc<dyn TeamRepository>,
+            team_repo as Arc<dyn TeamRepository>,
             player_repo.clone() as Arc<dyn PlayerRepository>,
         )
         .with_auto_pick(auto_pick_service);
@@ -90,7 +88,6 @@ impl TestContext {
         Self {
             pool,
             draft_engine,
-            team_repo,
             player_repo,
             draft_repo,
             pick_repo,
@@ -102,25 +99,21 @@ impl TestContext {
         }
     }
 
-    async fn cleanup(&self) {
-        common::cleanup_database(&self.pool).await;
-    }
-
     async fn create_team(&self, abbr: &str) -> Team {
-        let team = Team::new(
-            format!("Team {}", abbr),
-            abbr.to_string(),
-            "Test City".to_string(),
-            Conference::AFC,
-            Division::AFCEast,
-        )
-        .unwrap();
-        self.team_repo.create(&team).await.unwrap()
+        TeamBuilder::new()
+            .name(format!("Team {}", abbr))
+            .abbreviation(abbr)
+            .create(&self.pool)
+            .await
     }
 
     async fn create_player(&self, name: &str, position: Position) -> Player {
-        let player = Player::new(name.to_string(), "Test".to_string(), position, 2026).unwrap();
-        self.player_repo.create(&player).await.unwrap()
+        PlayerBuilder::new()
+            .first_name(name)
+            .last_name("Test")
+            .position(position)
+            .create(&self.pool)
+            .await
     }
 
     async fn create_scouting_report(
@@ -366,8 +359,6 @@ async fn test_auto_pick_bpa_heavy_strategy() {
     // Verify in database
     let db_pick = ctx.pick_repo.find_by_id(pick.id).await.unwrap().unwrap();
     assert_eq!(db_pick.player_id, Some(qb.id));
-
-    ctx.cleanup().await;
 }
 
 #[tokio::test]
@@ -421,8 +412,6 @@ async fn test_auto_pick_need_heavy_strategy() {
     // Verify in database
     let db_pick = ctx.pick_repo.find_by_id(pick.id).await.unwrap().unwrap();
     assert_eq!(db_pick.player_id, Some(rb.id));
-
-    ctx.cleanup().await;
 }
 
 #[tokio::test]
@@ -466,8 +455,6 @@ async fn test_auto_pick_position_value_matters() {
 
     // Verify QB was selected (higher position value: 1.5 vs 1.0)
     assert_eq!(updated_pick.player_id, Some(qb.id));
-
-    ctx.cleanup().await;
 }
 
 #[tokio::test]
@@ -509,8 +496,6 @@ async fn test_auto_pick_concern_penalties() {
 
     // Verify clean QB was selected
     assert_eq!(updated_pick.player_id, Some(clean_qb.id));
-
-    ctx.cleanup().await;
 }
 
 #[tokio::test]
@@ -570,8 +555,6 @@ async fn test_auto_pick_with_combine_data() {
 
     // Verify athletic WR was selected (combine boosts BPA score)
     assert_eq!(updated_pick.player_id, Some(athletic_wr.id));
-
-    ctx.cleanup().await;
 }
 
 #[tokio::test]
@@ -600,8 +583,6 @@ async fn test_auto_pick_no_available_players() {
     // Execute auto-pick should fail
     let result = ctx.draft_engine.execute_auto_pick(pick.id).await;
     assert!(result.is_err());
-
-    ctx.cleanup().await;
 }
 
 #[tokio::test]
@@ -631,8 +612,6 @@ async fn test_auto_pick_without_scouting_reports() {
     // Execute auto-pick should fail (no scouting reports)
     let result = ctx.draft_engine.execute_auto_pick(pick.id).await;
     assert!(result.is_err());
-
-    ctx.cleanup().await;
 }
 
 #[tokio::test]
@@ -676,8 +655,6 @@ async fn test_auto_pick_uses_default_strategy_if_none_exists() {
     let strategy = strategy.unwrap();
     assert_eq!(strategy.bpa_weight, 60);
     assert_eq!(strategy.need_weight, 40);
-
-    ctx.cleanup().await;
 }
 
 #[tokio::test]
@@ -775,6 +752,4 @@ async fn test_auto_pick_considers_ras_with_percentiles() {
         Some(elite_wr.id),
         "Elite WR should be picked over average WR when RAS percentiles are available"
     );
-
-    ctx.cleanup().await;
 }
@@ -1,15 +1,13 @@
 //! Acceptance tests for GET /api/v1/drafts/:id/available-players
 
-mod common;
-
 use reqwest::StatusCode;
 use serde_json::Value;
 use uuid::Uuid;
 
 #[tokio::test]
 async fn test_available_players_returns_all_when_no_picks_made() {
-    let (app_url, pool) = common::spawn_app().await;
-    let client = common::create_client();
+    let (app_url, pool) = test_support::spawn_app().await;
+    let client = test_support::create_client();
 
     // Create draft
     let draft_id = Uuid::new_v4();
@@ -57,14 +55,12 @@ async fn test_available_players_returns_all_when_no_picks_made() {
     for p in &players {
         assert!(p["rankings"].is_array());
     }
-
-    common::cleanup_database(&pool).await;
 }
 
 #[tokio::test]
 async fn test_available_players_excludes_drafted_players() {
-    let (app_url, pool) = common::spawn_app().await;
-    let client = common::create_client();
+    let (app_url, pool) = test_support::spawn_app().await;
+    let client = test_support::create_client();
 
     // Create team and draft
     let team_id = Uuid::new_v4();
@@ -133,14 +129,12 @@ async fn test_available_players_excludes_drafted_players() {
     assert_eq!(players.len(), 1);
     assert_eq!(players[0]["id"], available_player_id.to_string());
     assert_eq!(players[0]["first_name"], "Available");
-
-    common::cleanup_database(&pool).await;
 }
 
 #[tokio::test]
 async fn test_available_players_includes_rankings() {
-    let (app_url, pool) = common::spawn_app().await;
-    let client = common::create_client();
+    let (app_url, pool) = test_support::spawn_app().await;
+    let client = test_support::create_client();
 
     // Create draft
     let draft_id = Uuid::new_v4();
@@ -201,14 +195,12 @@ async fn test_available_players_includes_rankings() {
     assert_eq!(rankings.len(), 1);
     assert_eq!(rankings[0]["source_name"], "Test Source");
     assert_eq!(rankings[0]["rank"], 1);
-
-    common::cleanup_database(&pool).await;
 }
 
 #[tokio::test]
 async fn test_available_players_includes_scouting_data_for_team() {
-    let (app_url, pool) = common::spawn_app().await;
-    let client = common::create_client();
+    let (app_url, pool) = test_support::spawn_app().await;
+    let client = test_support::create_client();
 
     // Create team and draft
     let team_id = Uuid::new_v4();
@@ -268,14 +260,12 @@ async fn test_available_players_includes_scouting_data_for_team() {
     assert_eq!(players[0]["fit_grade"], "A");
     assert_eq!(players[0]["injury_concern"], false);
     assert_eq!(players[0]["character_concern"], false);
-
-    common::cleanup_database(&pool).await;
 }
 
 #[tokio::test]
 async fn test_available_players_returns_404_for_nonexistent_draft() {
-    let (app_url, _pool) = common::spawn_app().await;
-    let client = common::create_client();
+    let (app_url, _pool) = test_support::spawn_app().await;
+    let client = test_support::create_client();
 
     let fake_draft_id = Uuid::new_v4();
 
@@ -0,0 +1,160 @@
+//! `ids=` batch lookup acceptance tests
+
+use reqwest::StatusCode;
+use serde_json::{json, Value};
+use uuid::Uuid;
+
+async fn ids_of(response: reqwest::Response) -> Vec<String> {
+    assert_eq!(response.status(), StatusCode::OK);
+    let items: Vec<Value> = response.json().await.unwrap();
+    items
+        .iter()
+        .map(|item| item["id"].as_str().unwrap().to_string())
+        .collect()
+}
+
+#[tokio::test]
+async fn test_players_and_teams_by_ids() {
+    let (base_url, _pool) = test_support::spawn_app().await;
+    let client = test_support::create_client_with_role(domain::models::Role::Admin);
+
+    let mut player_ids = Vec::new();
+    for first_name in ["Ada", "Bo", "Cy"] {
+        let response = client
+            .post(format!("{}/api/v1/players", base_url))
+            .json(&json!({
+                "first_name": first_name,
+                "last_name": "Batch",
+                "position": "WR",
+                "draft_year": 2026
+            }))
+            .send()
+            .await
+            .expect("Failed to create player");
+        let player: Value = response.json().await.unwrap();
+        player_ids.push(player["id"].as_str().unwrap().to_string());
+    }
+
+    let response = client
+        .get(format!(
+            "{}/api/v1/players?ids={},{},{}&sort=first_name",
+            base_url,
+            player_ids[2],
+            player_ids[0],
+            Uuid::new_v4()
+        ))
+        .send()
+        .await
+        .expect("Failed to list players");
+    assert_eq!(response.headers()["x-total-count"], "2");
+    assert_eq!(
+        ids_of(response).await,
+        vec![player_ids[0].clone(), player_ids[2].clone()]
+    );
+
+    let mut team_ids = Vec::new();
+    for i in 0..3 {
+        let response = client
+            .post(format!("{}/api/v1/teams", base_url))
+            .json(&json!({
+                "name": format!("Batch Team {}", i),
+                "abbreviation": format!("BT{}", i),
+                "city": format!("City {}", i),
+                "conference": "AFC",
+                "division": "AFC West"
+            }))
+            .send()
+            .await
+            .expect("Failed to create team");
+        let team: Value = response.json().await.unwrap();
+        team_ids.push(team["id"].as_str().unwrap().to_string());
+    }
+
+    let response = client
+        .get(format!(
+            "{}/api/v1/teams?ids={},{}&sort=-name",
+            base_url, team_ids[0], team_ids[1]
+        ))
+        .send()
+        .await
+        .expect("Failed to list teams");
+    assert_eq!(
+        ids_of(response).await,
+        vec![team_ids[1].clone(), team_ids[0].clone()]
+    );
+
+    let response = client
+        .get(format!("{}/api/v1/teams?ids=not-a-uuid", base_url))
+        .send()
+        .await
+        .expect("Failed to list teams");
+    assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+}
+
+#[tokio::test]
+async fn test_draft_picks_by_ids() {
+    let (base_url, _pool) = test_support::spawn_app().await;
+    let client = test_support::create_client_with_role(domain::models::Role::Admin);
+
+    let response = client
+        .post(format!("{}/api/v1/teams", base_url))
+        .json(&json!({
+            "name": "Batch Pick Team",
+            "abbreviation": "BPT",
+            "city": "Pick City",
+            "conference": "NFC",
+            "division": "NFC South"
+        }))
+        .send()
+        .await
+        .expect("Failed to create team");
+    assert_eq!(response.status(), StatusCode::CREATED);
+
+    let response = client
+        .post(format!("{}/api/v1/drafts", base_url))
+        .json(&json!({
+            "name": "Batch Draft",
+            "year": 2026,
+            "rounds": 3,
+            "picks_per_round": 1
+        }))
+        .send()
+        .await
+        .expect("Failed to create draft");
+    let draft: Value = response.json().await.unwrap();
+    let draft_id = draft["id"].as_str().unwrap();
+
+    let response = client
+        .post(format!(
+            "{}/api/v1/drafts/{}/initialize",
+            base_url, draft_id
+        ))
+        .send()
+        .await
+        .expect("Failed to initialize picks");
+    let picks: Vec<Value> = response.json().await.unwrap();
+    let pick_ids: Vec<&str> = picks.iter().map(|p| p["id"].as_str().unwrap()).collect();
+
+    let response = client
+        .get(format!(
+            "{}/api/v1/drafts/{}/picks?ids={},{}",
+            base_url, draft_id, pick_ids[2], pick_ids[0]
+        ))
+        .send()
+        .await
+        .expect("Failed to list picks");
+    assert_eq!(ids_of(response).await, vec![pick_ids[0], pick_ids[2]]);
+
+    // IDs are matched within the draft only
+    let response = client
+        .get(format!(
+            "{}/api/v1/drafts/{}/picks?ids={}",
+            base_url,
+            Uuid::new_v4(),
+            pick_ids[0]
+        ))
+        .send()
+        .await
+        .expect("Failed to list picks");
+    assert!(ids_of(response).await.is_empty());
+}
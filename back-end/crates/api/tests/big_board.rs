@@ -0,0 +1,154 @@
+//! Blended big board acceptance tests
+
+use reqwest::StatusCode;
+use serde_json::{json, Value};
+use uuid::Uuid;
+
+async fn create_player(client: &reqwest::Client, base_url: &str, last_name: &str) -> Uuid {
+    let response = client
+        .post(format!("{}/api/v1/players", base_url))
+        .json(&json!({
+            "first_name": "Board",
+            "last_name": last_name,
+            "position": "WR",
+            "college": "Alabama",
+            "draft_year": 2026
+        }))
+        .send()
+        .await
+        .expect("Failed to create player");
+    assert_eq!(response.status(), StatusCode::CREATED);
+    let player: Value = response.json().await.unwrap();
+    Uuid::parse_str(player["id"].as_str().unwrap()).unwrap()
+}
+
+async fn create_team(client: &reqwest::Client, base_url: &str, abbreviation: &str) -> Uuid {
+    let response = client
+        .post(format!("{}/api/v1/teams", base_url))
+        .json(&json!({
+            "name": format!("{} Team", abbreviation),
+            "abbreviation": abbreviation,
+            "city": "Board City",
+            "conference": "AFC",
+            "division": "AFC East"
+        }))
+        .send()
+        .await
+        .expect("Failed to create team");
+    assert_eq!(response.status(), StatusCode::CREATED);
+    let team: Value = response.json().await.unwrap();
+    Uuid::parse_str(team["id"].as_str().unwrap()).unwrap()
+}
+
+async fn create_report(
+    client: &reqwest::Client,
+    base_url: &str,
+    team_id: Uuid,
+    player_id: Uuid,
+    grade: f64,
+) {
+    let response = client
+        .post(format!("{}/api/v1/scouting-reports", base_url))
+        .json(&json!({
+            "team_id": team_id,
+            "player_id": player_id,
+            "grade": grade
+        }))
+        .send()
+        .await
+        .expect("Failed to create scouting report");
+    assert_eq!(response.status(), StatusCode::CREATED);
+}
+
+async fn board(client: &reqwest::Client, url: String) -> Vec<Value> {
+    let response = client
+        .get(url)
+        .send()
+        .await
+        .expect("Failed to get big board");
+    assert_eq!(response.status(), StatusCode::OK);
+    response.json().await.unwrap()
+}
+
+fn last_names(board: &[Value]) -> Vec<&str> {
+    board
+        .iter()
+        .map(|entry| entry["last_name"].as_str().unwrap())
+        .collect()
+}
+
+#[tokio::test]
+async fn test_get_big_board() {
+    let (base_url, pool) = test_support::spawn_app().await;
+    let client = test_support::create_client_with_role(domain::models::Role::Admin);
+
+    let adams = create_player(&client, &base_url, "Adams").await;
+    let baker = create_player(&client, &base_url, "Baker").await;
+    let clark = create_player(&client, &base_url, "Clark").await;
+
+    let source_id = Uuid::new_v4();
+    sqlx::query("INSERT INTO ranking_sources (id, name) VALUES ($1, 'Tankathon')")
+        .bind(source_id)
+        .execute(&pool)
+        .await
+        .expect("Failed to insert ranking source");
+    for (player_id, rank) in [(adams, 1), (baker, 2)] {
+        sqlx::query(
+            "INSERT INTO prospect_rankings (id, ranking_source_id, player_id, rank, scraped_at) VALUES ($1, $2, $3, $4, '2026-02-11')",
+        )
+        .bind(Uuid::new_v4())
+        .bind(source_id)
+        .bind(player_id)
+        .bind(rank)
+        .execute(&pool)
+        .await
+        .expect("Failed to insert ranking");
+    }
+
+    let bills = create_team(&client, &base_url, "BBA").await;
+    let jets = create_team(&client, &base_url, "BBB").await;
+    create_report(&client, &base_url, bills, adams, 6.0).await;
+    create_report(&client, &base_url, bills, baker, 9.0).await;
+    create_report(&client, &base_url, bills, clark, 8.0).await;
+    create_report(&client, &base_url, jets, baker, 5.0).await;
+
+    // Default weights, no RAS: Adams (0.5 * 10 + 0.3 * 6) / 0.8 = 8.5,
+    // Clark grade only = 8.0, Baker (0.5 * 5 + 0.3 * 7) / 0.8 = 5.75
+    let entries = board(&client, format!("{}/api/v1/big-board?year=2026", base_url)).await;
+    assert_eq!(last_names(&entries), vec!["Adams", "Clark", "Baker"]);
+    assert_eq!(entries[0]["board_rank"], 1);
+    assert_eq!(entries[0]["composite_score"], 8.5);
+    assert_eq!(entries[0]["consensus_rank"], 1);
+    assert_eq!(entries[0]["ras_score"], Value::Null);
+    assert_eq!(entries[1]["consensus_rank"], Value::Null);
+    assert_eq!(entries[2]["scouting_grade"], 7.0);
+    assert_eq!(entries[2]["composite_score"], 5.75);
+
+    let entries = board(
+        &client,
+        format!("{}/api/v1/big-board?year=2026&weights=grade:1", base_url),
+    )
+    .await;
+    assert_eq!(last_names(&entries), vec!["Clark", "Baker", "Adams"]);
+
+    // One team's grades only; prospects that team has not graded drop off
+    let entries = board(
+        &client,
+        format!(
+            "{}/api/v1/big-board?year=2026&weights=grade:1&team_id={}",
+            base_url, jets
+        ),
+    )
+    .await;
+    assert_eq!(last_names(&entries), vec!["Baker"]);
+    assert_eq!(entries[0]["composite_score"], 5.0);
+
+    for weights in ["speed:1", "grade:high", "grade:-1", "grade:0"] {
+        let response = client
+            .get(format!("{}/api/v1/big-board?weights={}", base_url, weights))
+            .send()
+            .await
+            .expect("Failed to get big board");
+        assert_eq!(response.status(), StatusCode::BAD_REQUEST, "{}", weights);
+    }
+}
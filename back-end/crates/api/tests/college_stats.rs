@@ -0,0 +1,81 @@
+//! College production stats acceptance tests
+
+use reqwest::StatusCode;
+use serde_json::{json, Value};
+
+#[tokio::test]
+async fn test_get_player_college_stats() {
+    let (base_url, pool) = test_support::spawn_app().await;
+    let client = test_support::create_client_with_role(domain::models::Role::Admin);
+
+    let response = client
+        .post(format!("{}/api/v1/players", base_url))
+        .json(&json!({
+            "first_name": "Stat",
+            "last_name": format!("Line{}", uuid::Uuid::new_v4().simple()),
+            "position": "RB",
+            "draft_year": 2026
+        }))
+        .send()
+        .await
+        .expect("Failed to create player");
+    assert_eq!(response.status(), StatusCode::CREATED);
+    let player: Value = response.json().await.unwrap();
+    let player_id = uuid::Uuid::parse_str(player["id"].as_str().unwrap()).unwrap();
+
+    // Newer season inserted first to check ordering
+    sqlx::query(
+        "INSERT INTO college_stats (player_id, season, games_played, rush_attempts, rush_yards, rush_touchdowns, receptions, receiving_yards, receiving_touchdowns)
+         VALUES ($1, 2025, 12, 199, 1372, 18, 20, 250, 1)",
+    )
+    .bind(player_id)
+    .execute(&pool)
+    .await
+    .expect("Failed to insert 2025 season");
+    sqlx::query(
+        "INSERT INTO college_stats (player_id, season, school, games_played, rush_attempts, rush_yards, rush_touchdowns)
+         VALUES ($1, 2024, 'Transfer U', 16, 163, 1125, 17)",
+    )
+    .bind(player_id)
+    .execute(&pool)
+    .await
+    .expect("Failed to insert 2024 season");
+
+    let response = client
+        .get(format!(
+            "{}/api/v1/players/{}/college-stats",
+            base_url, player_id
+        ))
+        .send()
+        .await
+        .expect("Failed to get college stats");
+    assert_eq!(response.status(), StatusCode::OK);
+    let seasons: Vec<Value> = response.json().await.unwrap();
+    assert_eq!(seasons.len(), 2);
+
+    assert_eq!(seasons[0]["season"], 2024);
+    assert_eq!(seasons[0]["school"], "Transfer U");
+    assert_eq!(seasons[0]["rushing"]["yards"], 1125);
+    assert!(seasons[0]["receiving"].is_null());
+    assert!(seasons[0]["passing"].is_null());
+
+    assert_eq!(seasons[1]["season"], 2025);
+    assert!(seasons[1]["school"].is_null());
+    assert_eq!(seasons[1]["games_played"], 12);
+    assert_eq!(seasons[1]["receiving"]["receptions"], 20);
+    assert!(seasons[1]["defense"].is_null());
+
+    // Players without stats get an empty list
+    let response = client
+        .get(format!(
+            "{}/api/v1/players/{}/college-stats",
+            base_url,
+            uuid::Uuid::new_v4()
+        ))
+        .send()
+        .await
+        .expect("Failed to get college stats");
+    assert_eq!(response.status(), StatusCode::OK);
+    let seasons: Vec<Value> = response.json().await.unwrap();
+    assert!(seasons.is_empty());
+}
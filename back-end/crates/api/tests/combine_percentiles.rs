@@ -1,11 +1,9 @@
-mod common;
-
 use serde_json::json;
 
 #[tokio::test]
 async fn test_seed_and_get_percentiles() {
-    let (base_url, _pool) = common::spawn_app_with_seed_key("test-key").await;
-    let client = common::create_client();
+    let (base_url, _pool) = test_support::spawn_app_with_seed_key("test-key").await;
+    let client = test_support::create_client();
 
     // Seed some percentile data
     let body = json!({
@@ -60,8 +58,8 @@ async fn test_seed_and_get_percentiles() {
 
 #[tokio::test]
 async fn test_get_percentiles_by_position() {
-    let (base_url, _pool) = common::spawn_app_with_seed_key("test-key").await;
-    let client = common::create_client();
+    let (base_url, _pool) = test_support::spawn_app_with_seed_key("test-key").await;
+    let client = test_support::create_client();
 
     // Seed data for QB and WR
     let body = json!({
@@ -143,8 +141,8 @@ async fn test_get_percentiles_by_position() {
 
 #[tokio::test]
 async fn test_get_all_percentiles() {
-    let (base_url, _pool) = common::spawn_app_with_seed_key("test-key").await;
-    let client = common::create_client();
+    let (base_url, _pool) = test_support::spawn_app_with_seed_key("test-key").await;
+    let client = test_support::create_client();
 
     // Seed QB + WR data
     let body = json!({
@@ -195,8 +193,8 @@ async fn test_get_all_percentiles() {
 
 #[tokio::test]
 async fn test_percentile_data_structure() {
-    let (base_url, _pool) = common::spawn_app_with_seed_key("test-key").await;
-    let client = common::create_client();
+    let (base_url, _pool) = test_support::spawn_app_with_seed_key("test-key").await;
+    let client = test_support::create_client();
 
     let body = json!({
         "percentiles": [
@@ -259,8 +257,8 @@ async fn test_percentile_data_structure() {
 
 #[tokio::test]
 async fn test_seed_percentiles_requires_auth() {
-    let (base_url, _pool) = common::spawn_app_with_seed_key("test-key").await;
-    let client = common::create_client();
+    let (base_url, _pool) = test_support::spawn_app_with_seed_key("test-key").await;
+    let client = test_support::create_client();
 
     let body = json!({
         "percentiles": []
@@ -288,8 +286,8 @@ async fn test_seed_percentiles_requires_auth() {
 
 #[tokio::test]
 async fn test_seed_percentiles_upsert_overwrites() {
-    let (base_url, _pool) = common::spawn_app_with_seed_key("test-key").await;
-    let client = common::create_client();
+    let (base_url, _pool) = test_support::spawn_app_with_seed_key("test-key").await;
+    let client = test_support::create_client();
 
     // Seed initial data
     let body = json!({
@@ -357,8 +355,8 @@ async fn test_seed_percentiles_upsert_overwrites() {
 
 #[tokio::test]
 async fn test_seed_percentiles_invalid_data() {
-    let (base_url, _pool) = common::spawn_app_with_seed_key("test-key").await;
-    let client = common::create_client();
+    let (base_url, _pool) = test_support::spawn_app_with_seed_key("test-key").await;
+    let client = test_support::create_client();
 
     let body = json!({
         "percentiles": [
@@ -401,8 +399,8 @@ async fn test_seed_percentiles_invalid_data() {
 
 #[tokio::test]
 async fn test_delete_all_percentiles() {
-    let (base_url, _pool) = common::spawn_app_with_seed_key("test-key").await;
-    let client = common::create_client();
+    let (base_url, _pool) = test_support::spawn_app_with_seed_key("test-key").await;
+    let client = test_support::create_client();
 
     // Seed data first
     let body = json!({
@@ -446,3 +444,110 @@ async fn test_delete_all_percentiles() {
     let all: Vec<serde_json::Value> = resp.json().await.unwrap();
     assert!(all.is_empty());
 }
+
+#[tokio::test]
+async fn test_compute_percentiles_from_combine_results() {
+    let (base_url, _pool) = test_support::spawn_app_with_seed_key("test-key").await;
+    let client = test_support::create_client_with_role(domain::models::Role::Admin);
+
+    // Five WRs running 4.30-4.70, one in 2024 outside the later year range
+    for (index, (forty, year)) in [
+        (4.30, 2024),
+        (4.40, 2026),
+        (4.50, 2026),
+        (4.60, 2026),
+        (4.70, 2026),
+    ]
+    .into_iter()
+    .enumerate()
+    {
+        let player: serde_json::Value = client
+            .post(format!("{}/api/v1/players", base_url))
+            .json(&json!({
+                "first_name": "Baseline",
+                "last_name": format!("Receiver{}", index),
+                "position": "WR",
+                "draft_year": 2026
+            }))
+            .send()
+            .await
+            .unwrap()
+            .json()
+            .await
+            .unwrap();
+        let resp = client
+            .post(format!("{}/api/v1/combine-results", base_url))
+            .json(&json!({
+                "player_id": player["id"],
+                "year": year,
+                "forty_yard_dash": forty
+            }))
+            .send()
+            .await
+            .unwrap();
+        assert!(resp.status().is_success());
+    }
+
+    let compute = |body: serde_json::Value| {
+        client
+            .post(format!("{}/api/v1/admin/percentiles/compute", base_url))
+            .header("X-Seed-Api-Key", "test-key")
+            .json(&body)
+            .send()
+    };
+
+    // Dry run over every stored year saves nothing
+    let resp = compute(json!({ "min_sample_size": 5, "dry_run": true }))
+        .await
+        .unwrap();
+    assert_eq!(resp.status(), 200);
+    let result: serde_json::Value = resp.json().await.unwrap();
+    assert_eq!(result["upserted_count"], 0);
+    assert_eq!(result["percentiles"][0]["position"], "WR");
+    assert_eq!(result["percentiles"][0]["measurement"], "forty_yard_dash");
+    assert_eq!(result["percentiles"][0]["sample_size"], 5);
+    assert_eq!(result["percentiles"][0]["p50"], 4.5);
+    assert_eq!(result["percentiles"][0]["years_start"], 2024);
+
+    let resp = client
+        .get(format!("{}/api/v1/combine-percentiles", base_url))
+        .send()
+        .await
+        .unwrap();
+    let all: Vec<serde_json::Value> = resp.json().await.unwrap();
+    assert!(all.is_empty());
+
+    // 2026 only has four samples
+    let resp = compute(json!({ "years_start": 2026, "years_end": 2026, "min_sample_size": 5 }))
+        .await
+        .unwrap();
+    let result: serde_json::Value = resp.json().await.unwrap();
+    assert_eq!(result["upserted_count"], 0);
+    assert_eq!(result["skipped"], json!(["WR forty_yard_dash (4)"]));
+
+    let resp = compute(json!({ "position": "WR", "years_start": 2026, "min_sample_size": 4 }))
+        .await
+        .unwrap();
+    let result: serde_json::Value = resp.json().await.unwrap();
+    assert_eq!(result["upserted_count"], 1);
+
+    let resp = client
+        .get(format!(
+            "{}/api/v1/combine-percentiles?position=WR",
+            base_url
+        ))
+        .send()
+        .await
+        .unwrap();
+    let all: Vec<serde_json::Value> = resp.json().await.unwrap();
+    assert_eq!(all.len(), 1);
+    assert_eq!(all[0]["min_value"], 4.4);
+    assert_eq!(all[0]["p50"], 4.55);
+
+    let resp = compute(json!({ "position": "XX" })).await.unwrap();
+    assert_eq!(resp.status(), 400);
+    let resp = compute(json!({ "years_start": 2026, "years_end": 2020 }))
+        .await
+        .unwrap();
+    assert_eq!(resp.status(), 400);
+}
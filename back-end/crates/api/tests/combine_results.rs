@@ -1,17 +1,14 @@
 //! Combine Results CRUD acceptance tests
 
-mod common;
-
 use serde_json::json;
 use std::time::Duration;
 
 #[tokio::test]
 async fn test_create_and_get_combine_results() {
-    let (base_url, pool) = common::spawn_app().await;
-    let client = common::create_client();
+    let (base_url, pool) = test_support::spawn_app().await;
+    let client = test_support::create_client_with_role(domain::models::Role::Admin);
 
     // Cleanup
-    common::cleanup_database(&pool).await;
 
     // Create a player first
     let player_response = client
@@ -108,16 +105,12 @@ async fn test_create_and_get_combine_results() {
         results["forty_yard_dash"].as_f64().unwrap(),
         db_results.forty_yard_dash.unwrap()
     );
-
-    common::cleanup_database(&pool).await;
 }
 
 #[tokio::test]
 async fn test_create_combine_results_with_source() {
-    let (base_url, pool) = common::spawn_app().await;
-    let client = common::create_client();
-
-    common::cleanup_database(&pool).await;
+    let (base_url, pool) = test_support::spawn_app().await;
+    let client = test_support::create_client_with_role(domain::models::Role::Admin);
 
     // Create a player
     let player_response = client
@@ -165,16 +158,12 @@ async fn test_create_combine_results_with_source() {
     .expect("Not found in database");
 
     assert_eq!(db_results.source, "pro_day");
-
-    common::cleanup_database(&pool).await;
 }
 
 #[tokio::test]
 async fn test_create_combine_and_pro_day_same_player_year() {
-    let (base_url, pool) = common::spawn_app().await;
-    let client = common::create_client();
-
-    common::cleanup_database(&pool).await;
+    let (base_url, pool) = test_support::spawn_app().await;
+    let client = test_support::create_client_with_role(domain::models::Role::Admin);
 
     // Create a player
     let player_response = client
@@ -234,16 +223,12 @@ async fn test_create_combine_and_pro_day_same_player_year() {
     .expect("Failed to count");
 
     assert_eq!(db_count.count, Some(2));
-
-    common::cleanup_database(&pool).await;
 }
 
 #[tokio::test]
 async fn test_create_combine_results_with_new_measurables() {
-    let (base_url, pool) = common::spawn_app().await;
-    let client = common::create_client();
-
-    common::cleanup_database(&pool).await;
+    let (base_url, pool) = test_support::spawn_app().await;
+    let client = test_support::create_client_with_role(domain::models::Role::Admin);
 
     // Create a player
     let player_response = client
@@ -308,8 +293,6 @@ async fn test_create_combine_results_with_new_measurables() {
     assert_eq!(db_results.wingspan, Some(bigdecimal_to_f64(78.5)));
     assert_eq!(db_results.ten_yard_split, Some(bigdecimal_to_f64(1.55)));
     assert_eq!(db_results.twenty_yard_split, Some(bigdecimal_to_f64(2.65)));
-
-    common::cleanup_database(&pool).await;
 }
 
 /// Helper: SQLx returns DECIMAL columns as bigdecimal::BigDecimal in query!.
@@ -320,10 +303,8 @@ fn bigdecimal_to_f64(val: f64) -> f64 {
 
 #[tokio::test]
 async fn test_source_defaults_to_combine() {
-    let (base_url, pool) = common::spawn_app().await;
-    let client = common::create_client();
-
-    common::cleanup_database(&pool).await;
+    let (base_url, pool) = test_support::spawn_app().await;
+    let client = test_support::create_client_with_role(domain::models::Role::Admin);
 
     // Create a player
     let player_response = client
@@ -370,16 +351,12 @@ async fn test_source_defaults_to_combine() {
     .expect("Not found in database");
 
     assert_eq!(db_results.source, "combine");
-
-    common::cleanup_database(&pool).await;
 }
 
 #[tokio::test]
 async fn test_get_player_combine_results() {
-    let (base_url, pool) = common::spawn_app().await;
-    let client = common::create_client();
-
-    common::cleanup_database(&pool).await;
+    let (base_url, pool) = test_support::spawn_app().await;
+    let client = test_support::create_client_with_role(domain::models::Role::Admin);
 
     // Create a player
     let player_response = client
@@ -454,16 +431,12 @@ async fn test_get_player_combine_results() {
     // Verify ordering (most recent year first)
     assert_eq!(results_list[0]["year"], 2026);
     assert_eq!(results_list[1]["year"], 2025);
-
-    common::cleanup_database(&pool).await;
 }
 
 #[tokio::test]
 async fn test_update_combine_results() {
-    let (base_url, pool) = common::spawn_app().await;
-    let client = common::create_client();
-
-    common::cleanup_database(&pool).await;
+    let (base_url, pool) = test_support::spawn_app().await;
+    let client = test_support::create_client_with_role(domain::models::Role::Admin);
 
     // Create player and combine results
     let player_response = client
@@ -532,16 +505,12 @@ async fn test_update_combine_results() {
     assert_eq!(db_results.forty_yard_dash, Some(4.52));
     assert_eq!(db_results.bench_press, Some(25));
     assert_eq!(db_results.vertical_jump, Some(38.0));
-
-    common::cleanup_database(&pool).await;
 }
 
 #[tokio::test]
 async fn test_delete_combine_results() {
-    let (base_url, pool) = common::spawn_app().await;
-    let client = common::create_client();
-
-    common::cleanup_database(&pool).await;
+    let (base_url, pool) = test_support::spawn_app().await;
+    let client = test_support::create_client_with_role(domain::models::Role::Admin);
 
     // Create player and combine results
     let player_response = client
@@ -611,16 +580,12 @@ async fn test_delete_combine_results() {
         .expect("Failed to get combine results");
 
     assert_eq!(get_response.status(), 404);
-
-    common::cleanup_database(&pool).await;
 }
 
 #[tokio::test]
 async fn test_duplicate_player_year_error() {
-    let (base_url, pool) = common::spawn_app().await;
-    let client = common::create_client();
-
-    common::cleanup_database(&pool).await;
+    let (base_url, pool) = test_support::spawn_app().await;
+    let client = test_support::create_client_with_role(domain::models::Role::Admin);
 
     // Create player
     let player_response = client
@@ -679,16 +644,12 @@ async fn test_duplicate_player_year_error() {
     .expect("Failed to count combine results");
 
     assert_eq!(db_count.count, Some(1));
-
-    common::cleanup_database(&pool).await;
 }
 
 #[tokio::test]
 async fn test_list_all_combine_results() {
-    let (base_url, pool) = common::spawn_app().await;
-    let client = common::create_client();
-
-    common::cleanup_database(&pool).await;
+    let (base_url, _pool) = test_support::spawn_app().await;
+    let client = test_support::create_client_with_role(domain::models::Role::Admin);
 
     // Create two players
     let player1_response = client
@@ -782,6 +743,194 @@ async fn test_list_all_combine_results() {
     assert_eq!(beta["position"].as_str(), Some("WR"));
     assert_eq!(beta["college"].as_str(), Some("Ohio State"));
     assert_eq!(beta["forty_yard_dash"].as_f64(), Some(4.38));
+}
+
+#[tokio::test]
+async fn test_list_combine_results_with_filters() {
+    let (base_url, _pool) = test_support::spawn_app().await;
+    let client = test_support::create_client_with_role(domain::models::Role::Admin);
+
+    // (last name, position, combine year, 40 time)
+    let rows = [
+        ("Burner", "WR", 2026, Some(4.31)),
+        ("Glider", "WR", 2026, Some(4.52)),
+        ("Anchor", "OT", 2026, Some(5.21)),
+        ("Skipper", "WR", 2026, None),
+        ("Veteran", "WR", 2025, Some(4.40)),
+    ];
+    for (last_name, position, year, forty) in rows {
+        let response = client
+            .post(format!("{}/api/v1/players", base_url))
+            .json(&json!({
+                "first_name": "Test",
+                "last_name": last_name,
+                "position": position,
+                "draft_year": 2026
+            }))
+            .timeout(Duration::from_secs(5))
+            .send()
+            .await
+            .expect("Failed to create player");
+        let player: serde_json::Value = response.json().await.expect("Failed to parse JSON");
+
+        let response = client
+            .post(format!("{}/api/v1/combine-results", base_url))
+            .json(&json!({
+                "player_id": player["id"],
+                "year": year,
+                "forty_yard_dash": forty
+            }))
+            .timeout(Duration::from_secs(5))
+            .send()
+            .await
+            .expect("Failed to create combine results");
+        assert_eq!(response.status(), 201);
+    }
+
+    let last_names = |results: Vec<serde_json::Value>| {
+        let mut names: Vec<String> = results
+            .iter()
+            .map(|r| r["player_last_name"].as_str().unwrap().to_string())
+            .collect();
+        names.sort();
+        names
+    };
+
+    let response = client
+        .get(format!(
+            "{}/api/v1/combine-results?year=2026&position=WR",
+            base_url
+        ))
+        .timeout(Duration::from_secs(5))
+        .send()
+        .await
+        .expect("Failed to list combine results");
+    assert_eq!(response.status(), 200);
+    let results: Vec<serde_json::Value> = response.json().await.expect("Failed to parse JSON");
+    assert_eq!(last_names(results), ["Burner", "Glider", "Skipper"]);
+
+    // A 40 range leaves out results without a 40 time
+    let response = client
+        .get(format!(
+            "{}/api/v1/combine-results?min_forty=4.35&max_forty=5.0",
+            base_url
+        ))
+        .timeout(Duration::from_secs(5))
+        .send()
+        .await
+        .expect("Failed to list combine results");
+    assert_eq!(response.status(), 200);
+    let results: Vec<serde_json::Value> = response.json().await.expect("Failed to parse JSON");
+    assert_eq!(last_names(results), ["Glider", "Veteran"]);
+
+    let response = client
+        .get(format!("{}/api/v1/combine-results", base_url))
+        .timeout(Duration::from_secs(5))
+        .send()
+        .await
+        .expect("Failed to list combine results");
+    let results: Vec<serde_json::Value> = response.json().await.expect("Failed to parse JSON");
+    assert_eq!(results.len(), 5);
 
-    common::cleanup_database(&pool).await;
+    let response = client
+        .get(format!(
+            "{}/api/v1/combine-results?min_forty=4.6&max_forty=4.4",
+            base_url
+        ))
+        .timeout(Duration::from_secs(5))
+        .send()
+        .await
+        .expect("Failed to list combine results");
+    assert_eq!(response.status(), 400);
+}
+
+#[tokio::test]
+async fn test_multiple_events_and_best_marks() {
+    let (base_url, _pool) = test_support::spawn_app().await;
+    let client = test_support::create_client_with_role(domain::models::Role::Admin);
+
+    let player: serde_json::Value = client
+        .post(format!("{}/api/v1/players", base_url))
+        .json(&json!({
+            "first_name": "Pro",
+            "last_name": "Dayer",
+            "position": "WR",
+            "draft_year": 2026
+        }))
+        .send()
+        .await
+        .expect("Failed to create player")
+        .json()
+        .await
+        .unwrap();
+    let player_id = player["id"].as_str().unwrap().to_string();
+
+    let events = [
+        json!({ "source": "combine", "forty_yard_dash": 4.52, "vertical_jump": 37.0, "arm_length": 32.25 }),
+        json!({ "source": "pro_day", "event_date": "2026-03-12", "forty_yard_dash": 4.47, "vertical_jump": 35.5 }),
+        json!({ "source": "pro_day", "event_date": "2026-04-02", "forty_yard_dash": 4.49, "arm_length": 32.0 }),
+        json!({ "source": "school", "event_date": "2026-01-20", "bench_press": 17 }),
+    ];
+    for mut event in events {
+        event["player_id"] = json!(player_id);
+        event["year"] = json!(2026);
+        let response = client
+            .post(format!("{}/api/v1/combine-results", base_url))
+            .json(&event)
+            .send()
+            .await
+            .unwrap();
+        assert_eq!(response.status(), 201, "Failed to create {}", event);
+    }
+
+    // A second event with the same source and date is still a duplicate
+    let duplicate = client
+        .post(format!("{}/api/v1/combine-results", base_url))
+        .json(&json!({
+            "player_id": player_id,
+            "year": 2026,
+            "source": "pro_day",
+            "event_date": "2026-03-12"
+        }))
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(duplicate.status(), 409);
+
+    let all: Vec<serde_json::Value> = client
+        .get(format!(
+            "{}/api/v1/players/{}/combine-results",
+            base_url, player_id
+        ))
+        .send()
+        .await
+        .unwrap()
+        .json()
+        .await
+        .unwrap();
+    assert_eq!(all.len(), 4);
+
+    let marks: Vec<serde_json::Value> = client
+        .get(format!(
+            "{}/api/v1/players/{}/combine-results/best",
+            base_url, player_id
+        ))
+        .send()
+        .await
+        .unwrap()
+        .json()
+        .await
+        .unwrap();
+    let mark = |name: &str| {
+        marks
+            .iter()
+            .find(|m| m["measurement"] == name)
+            .unwrap_or_else(|| panic!("missing {}", name))
+    };
+    assert_eq!(marks.len(), 4);
+    assert_eq!(mark("forty_yard_dash")["value"], 4.47);
+    assert_eq!(mark("forty_yard_dash")["event_date"], "2026-03-12");
+    assert_eq!(mark("vertical_jump")["source"], "combine");
+    assert_eq!(mark("arm_length")["value"], 32.0);
+    assert_eq!(mark("bench_press")["source"], "school");
 }
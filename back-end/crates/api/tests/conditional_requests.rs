@@ -0,0 +1,83 @@
+//! ETag / If-None-Match acceptance tests
+
+use reqwest::StatusCode;
+use serde_json::json;
+use std::time::Duration;
+
+#[tokio::test]
+async fn test_list_endpoints_honor_if_none_match() {
+    let (base_url, _pool) = test_support::spawn_app().await;
+    let client = test_support::create_client_with_role(domain::models::Role::Admin);
+
+    let create_player = |last_name: &'static str| {
+        client
+            .post(format!("{}/api/v1/players", base_url))
+            .json(&json!({
+                "first_name": "Sam",
+                "last_name": last_name,
+                "position": "QB",
+                "draft_year": 2026
+            }))
+            .timeout(Duration::from_secs(5))
+            .send()
+    };
+    create_player("Adams")
+        .await
+        .expect("Failed to create player");
+
+    let response = client
+        .get(format!("{}/api/v1/players", base_url))
+        .timeout(Duration::from_secs(5))
+        .send()
+        .await
+        .expect("Failed to list players");
+    assert_eq!(response.status(), StatusCode::OK);
+    let etag = response.headers()["etag"].to_str().unwrap().to_string();
+    assert!(etag.starts_with("W/\""));
+
+    // Unchanged data: 304 with no body but the same tag
+    let response = client
+        .get(format!("{}/api/v1/players", base_url))
+        .header("If-None-Match", &etag)
+        .timeout(Duration::from_secs(5))
+        .send()
+        .await
+        .expect("Failed to list players");
+    assert_eq!(response.status(), StatusCode::NOT_MODIFIED);
+    assert_eq!(response.headers()["etag"].to_str().unwrap(), etag);
+    assert!(response.bytes().await.unwrap().is_empty());
+
+    // Changed data: the stale tag no longer matches
+    create_player("Baker")
+        .await
+        .expect("Failed to create player");
+    let response = client
+        .get(format!("{}/api/v1/players", base_url))
+        .header("If-None-Match", &etag)
+        .timeout(Duration::from_secs(5))
+        .send()
+        .await
+        .expect("Failed to list players");
+    assert_eq!(response.status(), StatusCode::OK);
+    assert_ne!(response.headers()["etag"].to_str().unwrap(), etag);
+    let players: Vec<serde_json::Value> = response.json().await.unwrap();
+    assert_eq!(players.len(), 2);
+
+    for path in ["teams", "rankings", "ranking-sources"] {
+        let response = client
+            .get(format!("{}/api/v1/{}", base_url, path))
+            .timeout(Duration::from_secs(5))
+            .send()
+            .await
+            .expect("Failed to list");
+        assert_eq!(response.status(), StatusCode::OK, "path {}", path);
+        assert!(response.headers().contains_key("etag"), "path {}", path);
+    }
+
+    // Writes are never tagged
+    let response = create_player("Clark")
+        .await
+        .expect("Failed to create player");
+    assert_eq!(response.status(), StatusCode::CREATED);
+    assert!(!response.headers().contains_key("etag"));
+}
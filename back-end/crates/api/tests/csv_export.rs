@@ -0,0 +1,138 @@
+//! CSV download acceptance tests
+
+use reqwest::StatusCode;
+use serde_json::{json, Value};
+
+#[tokio::test]
+async fn test_players_as_csv() {
+    let (base_url, _pool) = test_support::spawn_app().await;
+    let client = test_support::create_client_with_role(domain::models::Role::Admin);
+
+    for (first_name, college) in [("Alpha", Some("Ohio State")), ("Beta", None)] {
+        let response = client
+            .post(format!("{}/api/v1/players", base_url))
+            .json(&json!({
+                "first_name": first_name,
+                "last_name": "Prospect",
+                "position": "QB",
+                "college": college,
+                "draft_year": 2026
+            }))
+            .send()
+            .await
+            .expect("Failed to create player");
+        assert_eq!(response.status(), StatusCode::CREATED);
+    }
+
+    let response = client
+        .get(format!(
+            "{}/api/v1/players?format=csv&sort=first_name",
+            base_url
+        ))
+        .send()
+        .await
+        .expect("Failed to export players");
+    assert_eq!(response.status(), StatusCode::OK);
+    assert_eq!(
+        response.headers()["content-type"],
+        "text/csv; charset=utf-8"
+    );
+    assert_eq!(
+        response.headers()["content-disposition"],
+        "attachment; filename=\"players.csv\""
+    );
+    assert_eq!(response.headers()["x-total-count"], "2");
+
+    let body = response.text().await.unwrap();
+    let lines: Vec<&str> = body.lines().collect();
+    assert_eq!(lines.len(), 3);
+    assert_eq!(
+        lines[0],
+        "id,first_name,last_name,position,college,height_inches,weight_pounds,draft_year,draft_eligible,injury_status,injury_body_part,injury_expected_return,medical_flag,profile_url,headshot_url"
+    );
+    assert!(lines[1].contains(",Alpha,Prospect,QB,Ohio State,,,2026,true,healthy,,,false"));
+    assert!(lines[2].contains(",Beta,Prospect,QB,,,,2026,true,healthy,,,false"));
+
+    // JSON is still the default
+    let response = client
+        .get(format!("{}/api/v1/players", base_url))
+        .send()
+        .await
+        .expect("Failed to list players");
+    let players: Vec<Value> = response.json().await.unwrap();
+    assert_eq!(players.len(), 2);
+
+    let response = client
+        .get(format!("{}/api/v1/players?format=xml", base_url))
+        .send()
+        .await
+        .expect("Failed to list players");
+    assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+}
+
+#[tokio::test]
+async fn test_draft_picks_as_csv() {
+    let (base_url, _pool) = test_support::spawn_app().await;
+    let client = test_support::create_client_with_role(domain::models::Role::Admin);
+
+    for i in 0..2 {
+        let response = client
+            .post(format!("{}/api/v1/teams", base_url))
+            .json(&json!({
+                "name": format!("CSV Team {}", i),
+                "abbreviation": format!("CS{}", i),
+                "city": format!("City {}", i),
+                "conference": "AFC",
+                "division": "AFC East"
+            }))
+            .send()
+            .await
+            .expect("Failed to create team");
+        assert_eq!(response.status(), StatusCode::CREATED);
+    }
+
+    let response = client
+        .post(format!("{}/api/v1/drafts", base_url))
+        .json(&json!({
+            "name": "CSV Draft",
+            "year": 2026,
+            "rounds": 2,
+            "picks_per_round": 2
+        }))
+        .send()
+        .await
+        .expect("Failed to create draft");
+    let draft: Value = response.json().await.unwrap();
+    let draft_id = draft["id"].as_str().unwrap();
+
+    let response = client
+        .post(format!(
+            "{}/api/v1/drafts/{}/initialize",
+            base_url, draft_id
+        ))
+        .send()
+        .await
+        .expect("Failed to initialize picks");
+    assert_eq!(response.status(), StatusCode::CREATED);
+
+    let response = client
+        .get(format!(
+            "{}/api/v1/drafts/{}/picks?format=csv&page=2&per_page=3",
+            base_url, draft_id
+        ))
+        .send()
+        .await
+        .expect("Failed to export picks");
+    assert_eq!(response.status(), StatusCode::OK);
+    assert_eq!(
+        response.headers()["content-disposition"],
+        "attachment; filename=\"draft-picks.csv\""
+    );
+    assert_eq!(response.headers()["x-page"], "2");
+
+    let body = response.text().await.unwrap();
+    let lines: Vec<&str> = body.lines().collect();
+    assert_eq!(lines.len(), 2);
+    assert!(lines[0].starts_with("id,draft_id,round,pick_number,overall_pick,team_id"));
+    assert!(lines[1].contains(&format!(",{},2,2,4,", draft_id)));
+}
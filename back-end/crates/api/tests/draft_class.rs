@@ -0,0 +1,415 @@
+//! Draft recap acceptance tests: team draft classes, value over slot,
+//! surplus value and mock accuracy against the real draft
+
+use reqwest::StatusCode;
+use serde_json::{json, Value};
+use uuid::Uuid;
+
+async fn post(client: &reqwest::Client, url: String, body: Value) -> Value {
+    let response = client
+        .post(url)
+        .json(&body)
+        .send()
+        .await
+        .expect("Failed to send request");
+    assert!(response.status().is_success(), "{}", response.status());
+    response.json().await.unwrap()
+}
+
+/// Two teams, a 2x2 draft taking Alpha, Bravo, Charlie and Delta in that
+/// order, and a consensus board of Bravo 1, Charlie 2, Alpha 3, Delta 4.
+/// Returns the draft, the team holding picks 1 and 3, and the players in
+/// draft order.
+async fn run_draft(
+    client: &reqwest::Client,
+    base_url: &str,
+    pool: &sqlx::PgPool,
+) -> (String, String, Vec<Uuid>) {
+    for (name, abbreviation) in [("Class Team A", "CTA"), ("Class Team B", "CTB")] {
+        post(
+            client,
+            format!("{}/api/v1/teams", base_url),
+            json!({
+                "name": name,
+                "abbreviation": abbreviation,
+                "city": "Class City",
+                "conference": "AFC",
+                "division": "AFC North"
+            }),
+        )
+        .await;
+    }
+
+    let mut player_ids = Vec::new();
+    for (last_name, position) in [
+        ("Alpha", "WR"),
+        ("Bravo", "QB"),
+        ("Charlie", "RB"),
+        ("Delta", "TE"),
+    ] {
+        let player = post(
+            client,
+            format!("{}/api/v1/players", base_url),
+            json!({
+                "first_name": "Class",
+                "last_name": last_name,
+                "position": position,
+                "draft_year": 2026
+            }),
+        )
+        .await;
+        player_ids.push(Uuid::parse_str(player["id"].as_str().unwrap()).unwrap());
+    }
+
+    // One source: Bravo 1, Charlie 2, Alpha 3, Delta 4
+    let source_id = Uuid::new_v4();
+    sqlx::query("INSERT INTO ranking_sources (id, name) VALUES ($1, 'Class Board')")
+        .bind(source_id)
+        .execute(pool)
+        .await
+        .expect("Failed to insert ranking source");
+    for (player_id, rank) in [
+        (player_ids[1], 1),
+        (player_ids[2], 2),
+        (player_ids[0], 3),
+        (player_ids[3], 4),
+    ] {
+        sqlx::query(
+            "INSERT INTO prospect_rankings (id, ranking_source_id, player_id, rank, scraped_at) VALUES ($1, $2, $3, $4, '2026-02-11')",
+        )
+        .bind(Uuid::new_v4())
+        .bind(source_id)
+        .bind(player_id)
+        .bind(rank)
+        .execute(pool)
+        .await
+        .expect("Failed to insert ranking");
+    }
+
+    let draft = post(
+        client,
+        format!("{}/api/v1/drafts", base_url),
+        json!({ "name": "Class Draft", "year": 2026, "rounds": 2, "picks_per_round": 2 }),
+    )
+    .await;
+    let draft_id = draft["id"].as_str().unwrap();
+    let picks = post(
+        client,
+        format!("{}/api/v1/drafts/{}/initialize", base_url, draft_id),
+        json!({}),
+    )
+    .await;
+    let picks = picks.as_array().unwrap();
+    // Same order every round, so the team on the clock first holds picks 1 and 3
+    let team_id = picks[0]["team_id"].as_str().unwrap();
+
+    post(
+        client,
+        format!("{}/api/v1/drafts/{}/start", base_url, draft_id),
+        json!({}),
+    )
+    .await;
+    for (pick, player_id) in picks.iter().zip(&player_ids) {
+        post(
+            client,
+            format!(
+                "{}/api/v1/picks/{}/make",
+                base_url,
+                pick["id"].as_str().unwrap()
+            ),
+            json!({ "player_id": player_id }),
+        )
+        .await;
+    }
+
+    (draft_id.to_string(), team_id.to_string(), player_ids)
+}
+
+#[tokio::test]
+async fn test_get_team_draft_class() {
+    let (base_url, pool) = test_support::spawn_app().await;
+    let client = test_support::create_client_with_role(domain::models::Role::Admin);
+    let (draft_id, team_id, player_ids) = run_draft(&client, &base_url, &pool).await;
+
+    for (player_id, grade) in [(player_ids[0], 8.0), (player_ids[2], 7.0)] {
+        post(
+            &client,
+            format!("{}/api/v1/scouting-reports", base_url),
+            json!({ "team_id": team_id, "player_id": player_id, "grade": grade }),
+        )
+        .await;
+    }
+    for (position, priority) in [("WR", 1), ("CB", 2), ("RB", 3)] {
+        post(
+            &client,
+            format!("{}/api/v1/team-needs", base_url),
+            json!({ "team_id": team_id, "position": position, "priority": priority }),
+        )
+        .await;
+    }
+
+    let response = client
+        .get(format!(
+            "{}/api/v1/drafts/{}/teams/{}/class",
+            base_url, draft_id, team_id
+        ))
+        .send()
+        .await
+        .expect("Failed to get draft class");
+    assert_eq!(response.status(), StatusCode::OK);
+    let class: Value = response.json().await.unwrap();
+
+    assert_eq!(class["chart_type"], "JimmyJohnson");
+    let selections = class["selections"].as_array().unwrap();
+    assert_eq!(selections.len(), 2);
+    assert_eq!(selections[0]["last_name"], "Alpha");
+    assert_eq!(selections[0]["overall_pick"], 1);
+    assert_eq!(selections[0]["scouting_grade"], 8.0);
+    assert_eq!(selections[0]["consensus_rank"], 3);
+    assert_eq!(selections[0]["rank_delta"], -2);
+    assert_eq!(selections[0]["chart_value"], 3000);
+    assert_eq!(selections[0]["fills_need"], true);
+    assert_eq!(selections[1]["last_name"], "Charlie");
+    assert_eq!(selections[1]["rank_delta"], 1);
+
+    assert_eq!(class["average_grade"], 7.5);
+    assert_eq!(class["average_rank_delta"], -0.5);
+    assert_eq!(class["needs_filled"], json!(["WR", "RB"]));
+    assert_eq!(class["needs_unfilled"], json!(["CB"]));
+    assert_eq!(class["total_chart_value"], 3000 + 2200);
+
+    let response = client
+        .get(format!(
+            "{}/api/v1/drafts/{}/teams/{}/class?chart_type=RichHill",
+            base_url, draft_id, team_id
+        ))
+        .send()
+        .await
+        .expect("Failed to get draft class");
+    let class: Value = response.json().await.unwrap();
+    assert_eq!(class["chart_type"], "RichHill");
+    assert!(class["total_chart_value"].as_i64().unwrap() < 3000 + 2200);
+
+    let response = client
+        .get(format!(
+            "{}/api/v1/drafts/{}/teams/{}/class",
+            base_url,
+            draft_id,
+            Uuid::new_v4()
+        ))
+        .send()
+        .await
+        .expect("Failed to get draft class");
+    assert_eq!(response.status(), StatusCode::NOT_FOUND);
+}
+
+#[tokio::test]
+async fn test_get_value_analysis() {
+    let (base_url, pool) = test_support::spawn_app().await;
+    let client = test_support::create_client_with_role(domain::models::Role::Admin);
+    let (draft_id, team_id, _) = run_draft(&client, &base_url, &pool).await;
+
+    let response = client
+        .get(format!(
+            "{}/api/v1/drafts/{}/value-analysis",
+            base_url, draft_id
+        ))
+        .send()
+        .await
+        .expect("Failed to get value analysis");
+    assert_eq!(response.status(), StatusCode::OK);
+    let analysis: Value = response.json().await.unwrap();
+
+    // Alpha 1 vs 3: -2, Bravo 2 vs 1: +1, Charlie 3 vs 2: +1, Delta 4 vs 4: 0
+    let values: Vec<i64> = analysis["picks"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|p| p["value_over_slot"].as_i64().unwrap())
+        .collect();
+    assert_eq!(values, vec![-2, 1, 1, 0]);
+    assert_eq!(analysis["unranked_picks"], 0);
+
+    let steals = analysis["steals"].as_array().unwrap();
+    assert_eq!(steals.len(), 2);
+    assert_eq!(steals[0]["last_name"], "Bravo");
+    assert_eq!(steals[0]["team_abbreviation"], "CTB");
+    assert_eq!(analysis["reaches"][0]["last_name"], "Alpha");
+
+    // Team B: +1 + 0 = 1; the team with picks 1 and 3: -2 + 1 = -1
+    let teams = analysis["teams"].as_array().unwrap();
+    assert_eq!(teams[0]["total_value_over_slot"], 1);
+    assert_eq!(teams[1]["team_id"], team_id);
+    assert_eq!(teams[1]["average_value_over_slot"], -0.5);
+
+    let response = client
+        .get(format!(
+            "{}/api/v1/drafts/{}/value-analysis?limit=1",
+            base_url, draft_id
+        ))
+        .send()
+        .await
+        .expect("Failed to get value analysis");
+    let analysis: Value = response.json().await.unwrap();
+    assert_eq!(analysis["steals"].as_array().unwrap().len(), 1);
+
+    let response = client
+        .get(format!(
+            "{}/api/v1/drafts/{}/value-analysis?limit=0",
+            base_url, draft_id
+        ))
+        .send()
+        .await
+        .expect("Failed to get value analysis");
+    assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+}
+
+#[tokio::test]
+async fn test_get_surplus_report() {
+    let (base_url, pool) = test_support::spawn_app().await;
+    let client = test_support::create_client_with_role(domain::models::Role::Admin);
+    let (draft_id, team_id, _) = run_draft(&client, &base_url, &pool).await;
+
+    let response = client
+        .get(format!(
+            "{}/api/v1/drafts/{}/surplus-report",
+            base_url, draft_id
+        ))
+        .send()
+        .await
+        .expect("Failed to get surplus report");
+    assert_eq!(response.status(), StatusCode::OK);
+    let report: Value = response.json().await.unwrap();
+    assert_eq!(report["chart_type"], "PffWar");
+
+    let picks = report["picks"].as_array().unwrap();
+    assert_eq!(picks.len(), 4);
+    // The first overall pick's contract is the most expensive in the draft
+    assert_eq!(picks[0]["rookie_cost"], 48.8);
+    assert_eq!(picks[0]["last_name"], "Alpha");
+    // Bravo was the board's No. 1 player, so worth more than slot 2
+    let bravo = &picks[1];
+    assert_eq!(bravo["consensus_rank"], 1);
+    assert!(bravo["player_value"].as_f64().unwrap() > bravo["slot_value"].as_f64().unwrap());
+    for pick in picks {
+        let surplus =
+            pick["player_value"].as_f64().unwrap() - pick["rookie_cost"].as_f64().unwrap();
+        assert!((pick["surplus"].as_f64().unwrap() - surplus).abs() < 0.011);
+        assert!(pick["surplus_chart_value"].as_i64().unwrap() > 0);
+    }
+
+    // The team that reached for Alpha at No. 1 captured less than Team B
+    let teams = report["teams"].as_array().unwrap();
+    assert_eq!(teams.len(), 2);
+    assert_eq!(teams[0]["team_abbreviation"], "CTB");
+    assert_eq!(teams[1]["team_id"], team_id);
+    assert_eq!(teams[1]["picks"], 2);
+
+    let response = client
+        .get(format!(
+            "{}/api/v1/drafts/{}/surplus-report?chart_type=SurplusValue",
+            base_url, draft_id
+        ))
+        .send()
+        .await
+        .expect("Failed to get surplus report");
+    assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+
+    let response = client
+        .get(format!(
+            "{}/api/v1/drafts/{}/surplus-report",
+            base_url,
+            Uuid::new_v4()
+        ))
+        .send()
+        .await
+        .expect("Failed to get surplus report");
+    assert_eq!(response.status(), StatusCode::NOT_FOUND);
+}
+
+#[tokio::test]
+async fn test_get_draft_accuracy() {
+    let (base_url, pool) = test_support::spawn_app().await;
+    let client = test_support::create_client_with_role(domain::models::Role::Admin);
+    let (draft_id, team_id, player_ids) = run_draft(&client, &base_url, &pool).await;
+    let accuracy_url = format!("{}/api/v1/drafts/{}/accuracy", base_url, draft_id);
+
+    // Drafts must be completed before they're scored
+    let unfinished = post(
+        &client,
+        format!("{}/api/v1/drafts", base_url),
+        json!({ "name": "Unfinished Draft", "year": 2026, "rounds": 1 }),
+    )
+    .await;
+    let response = client
+        .get(format!(
+            "{}/api/v1/drafts/{}/accuracy",
+            base_url,
+            unfinished["id"].as_str().unwrap()
+        ))
+        .send()
+        .await
+        .expect("Failed to get accuracy");
+    assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+
+    // Making the last pick completed the mock, but no real results are
+    // loaded for 2026 yet
+    let response = client
+        .get(&accuracy_url)
+        .send()
+        .await
+        .expect("Failed to get accuracy");
+    assert_eq!(response.status(), StatusCode::NOT_FOUND);
+
+    // Real draft: Alpha 1 to the same team, Charlie 2 and Bravo 4 to Team B,
+    // Delta undrafted
+    let (team_b,): (Uuid,) = sqlx::query_as("SELECT id FROM teams WHERE abbreviation = 'CTB'")
+        .fetch_one(&pool)
+        .await
+        .expect("Failed to find team");
+    let team_a = Uuid::parse_str(&team_id).unwrap();
+    for (round, overall_pick, team, player_id) in [
+        (1, 1, team_a, player_ids[0]),
+        (1, 2, team_b, player_ids[2]),
+        (2, 4, team_b, player_ids[1]),
+    ] {
+        sqlx::query(
+            "INSERT INTO actual_draft_picks (year, round, pick_number, overall_pick, team_id, player_id) VALUES (2026, $1, $2, $2, $3, $4)",
+        )
+        .bind(round)
+        .bind(overall_pick)
+        .bind(team)
+        .bind(player_id)
+        .execute(&pool)
+        .await
+        .expect("Failed to insert actual pick");
+    }
+
+    let response = client
+        .get(&accuracy_url)
+        .send()
+        .await
+        .expect("Failed to get accuracy");
+    assert_eq!(response.status(), StatusCode::OK);
+    let accuracy: Value = response.json().await.unwrap();
+
+    // Alpha: exact slot, team and round (6); Bravo: right team (2);
+    // Charlie: drafted elsewhere (0); Delta: undrafted (0)
+    let points: Vec<i64> = accuracy["picks"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|p| p["points"].as_i64().unwrap())
+        .collect();
+    assert_eq!(points, vec![6, 2, 0, 0]);
+    assert_eq!(accuracy["exact_picks"], 1);
+    assert_eq!(accuracy["correct_teams"], 2);
+    assert_eq!(accuracy["correct_rounds"], 1);
+    assert_eq!(accuracy["players_drafted"], 3);
+    assert_eq!(accuracy["points"], 8);
+    assert_eq!(accuracy["max_points"], 24);
+    assert_eq!(accuracy["score_percent"], 33.3);
+    assert_eq!(accuracy["picks"][2]["actual_overall_pick"], 2);
+    assert_eq!(accuracy["picks"][2]["actual_team_abbreviation"], "CTB");
+    assert!(accuracy["picks"][3]["actual_overall_pick"].is_null());
+}
@@ -0,0 +1,154 @@
+//! Positional scarcity acceptance tests
+
+use reqwest::StatusCode;
+use serde_json::{json, Value};
+use uuid::Uuid;
+
+async fn post(client: &reqwest::Client, url: String, body: Value) -> Value {
+    let response = client
+        .post(url)
+        .json(&body)
+        .send()
+        .await
+        .expect("Failed to send request");
+    assert!(response.status().is_success(), "{}", response.status());
+    response.json().await.unwrap()
+}
+
+#[tokio::test]
+async fn test_get_draft_scarcity() {
+    let (base_url, pool) = test_support::spawn_app().await;
+    let client = test_support::create_client_with_role(domain::models::Role::Admin);
+
+    for abbreviation in ["SCA", "SCB"] {
+        post(
+            &client,
+            format!("{}/api/v1/teams", base_url),
+            json!({
+                "name": format!("Scarcity {}", abbreviation),
+                "abbreviation": abbreviation,
+                "city": "Scarcity City",
+                "conference": "NFC",
+                "division": "NFC West"
+            }),
+        )
+        .await;
+    }
+
+    let mut player_ids = Vec::new();
+    for (last_name, position) in [
+        ("Anchor", "OT"),
+        ("Bookend", "OT"),
+        ("Column", "OT"),
+        ("Dropback", "QB"),
+        ("Empty", "WR"),
+    ] {
+        let player = post(
+            &client,
+            format!("{}/api/v1/players", base_url),
+            json!({
+                "first_name": "Scarce",
+                "last_name": last_name,
+                "position": position,
+                "draft_year": 2026
+            }),
+        )
+        .await;
+        player_ids.push(Uuid::parse_str(player["id"].as_str().unwrap()).unwrap());
+    }
+
+    // Board: the three OTs 1-3, the QB 4; the WR is unranked
+    let source_id = Uuid::new_v4();
+    sqlx::query("INSERT INTO ranking_sources (id, name) VALUES ($1, 'Scarcity Board')")
+        .bind(source_id)
+        .execute(&pool)
+        .await
+        .expect("Failed to insert ranking source");
+    for (player_id, rank) in player_ids.iter().take(4).zip([1, 2, 3, 4]) {
+        sqlx::query(
+            "INSERT INTO prospect_rankings (id, ranking_source_id, player_id, rank, scraped_at) VALUES ($1, $2, $3, $4, '2026-02-11')",
+        )
+        .bind(Uuid::new_v4())
+        .bind(source_id)
+        .bind(player_id)
+        .bind(rank)
+        .execute(&pool)
+        .await
+        .expect("Failed to insert ranking");
+    }
+
+    // Two rounds of two picks: round-one grade is rank 1-2, round two 3-4
+    let draft = post(
+        &client,
+        format!("{}/api/v1/drafts", base_url),
+        json!({ "name": "Scarcity Draft", "year": 2026, "rounds": 2, "picks_per_round": 2 }),
+    )
+    .await;
+    let draft_id = draft["id"].as_str().unwrap();
+    let picks = post(
+        &client,
+        format!("{}/api/v1/drafts/{}/initialize", base_url, draft_id),
+        json!({}),
+    )
+    .await;
+    post(
+        &client,
+        format!("{}/api/v1/drafts/{}/start", base_url, draft_id),
+        json!({}),
+    )
+    .await;
+    post(
+        &client,
+        format!(
+            "{}/api/v1/picks/{}/make",
+            base_url,
+            picks[0]["id"].as_str().unwrap()
+        ),
+        json!({ "player_id": player_ids[0] }),
+    )
+    .await;
+
+    let response = client
+        .get(format!("{}/api/v1/drafts/{}/scarcity", base_url, draft_id))
+        .send()
+        .await
+        .expect("Failed to get scarcity");
+    assert_eq!(response.status(), StatusCode::OK);
+    let scarcity: Value = response.json().await.unwrap();
+    let position = |name: &str| {
+        scarcity["positions"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .find(|p| p["position"] == name)
+            .unwrap()
+            .clone()
+    };
+
+    let ot = position("OT");
+    assert_eq!(ot["remaining"], 2);
+    assert_eq!(
+        ot["tiers"],
+        json!([{ "round": 1, "remaining": 1 }, { "round": 2, "remaining": 1 }])
+    );
+    assert_eq!(ot["best_consensus_rank"], 2);
+
+    let qb = position("QB");
+    assert_eq!(qb["remaining"], 1);
+    assert_eq!(qb["tiers"][1]["remaining"], 1);
+
+    let wr = position("WR");
+    assert_eq!(wr["unranked"], 1);
+    assert_eq!(position("K")["remaining"], 0);
+
+    let response = client
+        .get(format!(
+            "{}/api/v1/drafts/{}/scarcity",
+            base_url,
+            Uuid::new_v4()
+        ))
+        .send()
+        .await
+        .expect("Failed to get scarcity");
+    assert_eq!(response.status(), StatusCode::NOT_FOUND);
+}
@@ -1,7 +1,5 @@
 //! Draft state transition acceptance tests — invalid transitions should return 400
 
-mod common;
-
 use serde_json::json;
 use std::time::Duration;
 use uuid::Uuid;
@@ -22,8 +20,8 @@ async fn create_draft_with_status(pool: &sqlx::PgPool, status: &str) -> Uuid {
 
 #[tokio::test]
 async fn test_pause_not_started_draft_returns_400() {
-    let (base_url, pool) = common::spawn_app().await;
-    let client = common::create_client();
+    let (base_url, pool) = test_support::spawn_app().await;
+    let client = test_support::create_client();
 
     let draft_id = create_draft_with_status(&pool, "NotStarted").await;
 
@@ -46,8 +44,8 @@ async fn test_pause_not_started_draft_returns_400() {
 
 #[tokio::test]
 async fn test_complete_not_started_draft_returns_400() {
-    let (base_url, pool) = common::spawn_app().await;
-    let client = common::create_client();
+    let (base_url, pool) = test_support::spawn_app().await;
+    let client = test_support::create_client();
 
     let draft_id = create_draft_with_status(&pool, "NotStarted").await;
 
@@ -69,8 +67,8 @@ async fn test_complete_not_started_draft_returns_400() {
 
 #[tokio::test]
 async fn test_start_already_in_progress_draft_returns_400() {
-    let (base_url, pool) = common::spawn_app().await;
-    let client = common::create_client();
+    let (base_url, pool) = test_support::spawn_app().await;
+    let client = test_support::create_client();
 
     let draft_id = create_draft_with_status(&pool, "InProgress").await;
 
@@ -92,8 +90,8 @@ async fn test_start_already_in_progress_draft_returns_400() {
 
 #[tokio::test]
 async fn test_pause_already_paused_draft_returns_400() {
-    let (base_url, pool) = common::spawn_app().await;
-    let client = common::create_client();
+    let (base_url, pool) = test_support::spawn_app().await;
+    let client = test_support::create_client();
 
     let draft_id = create_draft_with_status(&pool, "Paused").await;
 
@@ -115,8 +113,8 @@ async fn test_pause_already_paused_draft_returns_400() {
 
 #[tokio::test]
 async fn test_start_completed_draft_returns_400() {
-    let (base_url, pool) = common::spawn_app().await;
-    let client = common::create_client();
+    let (base_url, pool) = test_support::spawn_app().await;
+    let client = test_support::create_client();
 
     let draft_id = create_draft_with_status(&pool, "Completed").await;
 
@@ -138,8 +136,8 @@ async fn test_start_completed_draft_returns_400() {
 
 #[tokio::test]
 async fn test_reinitialize_picks_returns_400() {
-    let (base_url, pool) = common::spawn_app().await;
-    let client = common::create_client();
+    let (base_url, pool) = test_support::spawn_app().await;
+    let client = test_support::create_client_with_role(domain::models::Role::Admin);
 
     // Create 2 teams
     for (name, abbr) in [("Team X", "TMX"), ("Team Y", "TMY")] {
@@ -1,14 +1,12 @@
 //! Draft creation validation acceptance tests
 
-mod common;
-
 use serde_json::json;
 use std::time::Duration;
 
 #[tokio::test]
 async fn test_create_draft_invalid_year() {
-    let (base_url, _pool) = common::spawn_app().await;
-    let client = common::create_client();
+    let (base_url, _pool) = test_support::spawn_app().await;
+    let client = test_support::create_client();
 
     let response = client
         .post(&format!("{}/api/v1/drafts", base_url))
@@ -28,8 +26,8 @@ async fn test_create_draft_invalid_year() {
 
 #[tokio::test]
 async fn test_create_draft_invalid_rounds() {
-    let (base_url, _pool) = common::spawn_app().await;
-    let client = common::create_client();
+    let (base_url, _pool) = test_support::spawn_app().await;
+    let client = test_support::create_client();
 
     let response = client
         .post(&format!("{}/api/v1/drafts", base_url))
@@ -49,8 +47,8 @@ async fn test_create_draft_invalid_rounds() {
 
 #[tokio::test]
 async fn test_create_draft_invalid_picks_per_round() {
-    let (base_url, _pool) = common::spawn_app().await;
-    let client = common::create_client();
+    let (base_url, _pool) = test_support::spawn_app().await;
+    let client = test_support::create_client();
 
     let response = client
         .post(&format!("{}/api/v1/drafts", base_url))
@@ -70,8 +68,8 @@ async fn test_create_draft_invalid_picks_per_round() {
 
 #[tokio::test]
 async fn test_create_multiple_drafts_same_year() {
-    let (base_url, pool) = common::spawn_app().await;
-    let client = common::create_client();
+    let (base_url, pool) = test_support::spawn_app().await;
+    let client = test_support::create_client();
 
     // Create first draft
     let first_response = client
@@ -1,14 +1,12 @@
 //! Draft flow acceptance tests
 
-mod common;
-
 use serde_json::json;
 use std::time::Duration;
 
 #[tokio::test]
 async fn test_draft_flow() {
-    let (base_url, pool) = common::spawn_app().await;
-    let client = common::create_client();
+    let (base_url, pool) = test_support::spawn_app().await;
+    let client = test_support::create_client_with_role(domain::models::Role::Admin);
 
     // Create two teams
     let team1_response = client
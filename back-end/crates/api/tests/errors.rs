@@ -1,14 +1,12 @@
 //! Error handling acceptance tests
 
-mod common;
-
 use serde_json::json;
 use std::time::Duration;
 
 #[tokio::test]
 async fn test_error_handling() {
-    let (base_url, pool) = common::spawn_app().await;
-    let client = common::create_client();
+    let (base_url, pool) = test_support::spawn_app().await;
+    let client = test_support::create_client_with_role(domain::models::Role::Admin);
 
     // Try to get non-existent team
     let response = client
@@ -21,6 +19,8 @@ async fn test_error_handling() {
         .await
         .expect("Failed to execute request");
     assert_eq!(response.status(), 404);
+    let body: serde_json::Value = response.json().await.unwrap();
+    assert_eq!(body["code"], "NOT_FOUND");
 
     // Try to create team with invalid data
     let response = client
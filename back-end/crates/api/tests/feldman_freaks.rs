@@ -1,14 +1,12 @@
 //! Feldman Freaks endpoint acceptance tests
 
-mod common;
-
 use serde_json::json;
 use std::time::Duration;
 
 #[tokio::test]
 async fn test_list_feldman_freaks_empty() {
-    let (base_url, _pool) = common::spawn_app().await;
-    let client = common::create_client();
+    let (base_url, _pool) = test_support::spawn_app().await;
+    let client = test_support::create_client();
 
     let response = client
         .get(&format!("{}/api/v1/feldman-freaks?year=2026", base_url))
@@ -25,8 +23,8 @@ async fn test_list_feldman_freaks_empty() {
 
 #[tokio::test]
 async fn test_list_feldman_freaks_with_data() {
-    let (base_url, pool) = common::spawn_app().await;
-    let client = common::create_client();
+    let (base_url, pool) = test_support::spawn_app().await;
+    let client = test_support::create_client_with_role(domain::models::Role::Admin);
 
     // Create a player first
     let player_response = client
@@ -95,8 +93,8 @@ async fn test_list_feldman_freaks_with_data() {
 
 #[tokio::test]
 async fn test_list_feldman_freaks_wrong_year_returns_empty() {
-    let (base_url, pool) = common::spawn_app().await;
-    let client = common::create_client();
+    let (base_url, pool) = test_support::spawn_app().await;
+    let client = test_support::create_client_with_role(domain::models::Role::Admin);
 
     // Create a player and feldman freak for 2026
     let player_response = client
@@ -142,8 +140,8 @@ async fn test_list_feldman_freaks_wrong_year_returns_empty() {
 
 #[tokio::test]
 async fn test_list_feldman_freaks_multiple_entries_ordered_by_rank() {
-    let (base_url, pool) = common::spawn_app().await;
-    let client = common::create_client();
+    let (base_url, pool) = test_support::spawn_app().await;
+    let client = test_support::create_client_with_role(domain::models::Role::Admin);
 
     // Create two players
     let player1_response = client
@@ -228,8 +226,8 @@ async fn test_list_feldman_freaks_multiple_entries_ordered_by_rank() {
 
 #[tokio::test]
 async fn test_seed_feldman_freaks_succeeds() {
-    let (base_url, pool) = common::spawn_app_with_seed_key("test-seed-key").await;
-    let client = common::create_client();
+    let (base_url, pool) = test_support::spawn_app_with_seed_key("test-seed-key").await;
+    let client = test_support::create_client();
 
     // Seed players first (feldman_freaks references players by name matching)
     let seed_players_response = client
@@ -286,3 +284,115 @@ async fn test_seed_feldman_freaks_succeeds() {
     assert!(first["rank"].is_number());
     assert!(first["description"].is_string());
 }
+
+#[tokio::test]
+async fn test_list_freak_profiles_merges_combine_and_ras() {
+    let (base_url, pool) = test_support::spawn_app_with_seed_key("test-key").await;
+    let client = test_support::create_client_with_role(domain::models::Role::Admin);
+
+    let mut player_ids = Vec::new();
+    for (last_name, position) in [("Burner", "CB"), ("Mauler", "OT")] {
+        let response = client
+            .post(format!("{}/api/v1/players", base_url))
+            .json(&json!({
+                "first_name": "Freak",
+                "last_name": last_name,
+                "position": position,
+                "draft_year": 2026,
+                "height_inches": 72,
+                "weight_pounds": 195
+            }))
+            .timeout(Duration::from_secs(5))
+            .send()
+            .await
+            .expect("Failed to create player");
+        assert_eq!(response.status(), 201);
+        let player: serde_json::Value = response.json().await.unwrap();
+        player_ids.push(
+            player["id"]
+                .as_str()
+                .unwrap()
+                .parse::<uuid::Uuid>()
+                .unwrap(),
+        );
+    }
+
+    // Only the CB has combine results and percentile data to score them
+    let response = client
+        .post(format!("{}/api/v1/combine-results", base_url))
+        .json(&json!({ "player_id": player_ids[0], "year": 2026, "forty_yard_dash": 4.28 }))
+        .timeout(Duration::from_secs(5))
+        .send()
+        .await
+        .expect("Failed to create combine results");
+    assert_eq!(response.status(), 201);
+    let response = client
+        .post(format!("{}/api/v1/admin/seed-percentiles", base_url))
+        .header("X-Seed-Api-Key", "test-key")
+        .json(&json!({
+            "percentiles": [{
+                "position": "CB",
+                "measurement": "forty_yard_dash",
+                "sample_size": 100,
+                "min_value": 4.25,
+                "p10": 4.35, "p20": 4.38, "p30": 4.41, "p40": 4.44,
+                "p50": 4.47, "p60": 4.5, "p70": 4.53, "p80": 4.56,
+                "p90": 4.6,
+                "max_value": 4.8
+            }]
+        }))
+        .send()
+        .await
+        .expect("Failed to seed percentiles");
+    assert_eq!(response.status(), 200);
+
+    for (player_id, rank, description) in [
+        (player_ids[1], 2, "Squats 700 pounds"),
+        (player_ids[0], 1, "Ran a 4.28"),
+    ] {
+        sqlx::query!(
+            "INSERT INTO feldman_freaks (player_id, year, rank, description) VALUES ($1, $2, $3, $4)",
+            player_id,
+            2026,
+            rank,
+            description
+        )
+        .execute(&pool)
+        .await
+        .unwrap();
+    }
+
+    let response = client
+        .get(format!("{}/api/v1/players/freaks?year=2026", base_url))
+        .timeout(Duration::from_secs(5))
+        .send()
+        .await
+        .expect("Failed to send request");
+    assert_eq!(response.status(), 200);
+    let body: Vec<serde_json::Value> = response.json().await.unwrap();
+    assert_eq!(body.len(), 2);
+
+    let burner = &body[0];
+    assert_eq!(burner["rank"], 1);
+    assert_eq!(burner["last_name"], "Burner");
+    assert_eq!(burner["position"], "CB");
+    assert_eq!(burner["height_inches"], 72);
+    assert_eq!(burner["combine"]["forty_yard_dash"], 4.28);
+    let forty = &burner["ras"]["individual_scores"][0];
+    assert_eq!(forty["measurement"], "forty_yard_dash");
+    assert!(forty["percentile"].as_f64().unwrap() > 90.0);
+
+    let mauler = &body[1];
+    assert_eq!(mauler["description"], "Squats 700 pounds");
+    assert!(mauler["combine"].is_null());
+    assert!(mauler["ras"].is_null());
+
+    let response = client
+        .get(format!("{}/api/v1/players/freaks?year=2025", base_url))
+        .timeout(Duration::from_secs(5))
+        .send()
+        .await
+        .expect("Failed to send request");
+    let body: Vec<serde_json::Value> = response.json().await.unwrap();
+    assert!(body.is_empty());
+}
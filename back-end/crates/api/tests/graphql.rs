@@ -0,0 +1,197 @@
+//! GraphQL endpoint acceptance tests
+
+use reqwest::StatusCode;
+use serde_json::{json, Value};
+use uuid::Uuid;
+
+async fn post_json(client: &reqwest::Client, url: String, body: Value) -> Value {
+    let response = client
+        .post(url)
+        .json(&body)
+        .send()
+        .await
+        .expect("Failed to send request");
+    assert!(response.status().is_success(), "{}", response.status());
+    response.json().await.unwrap()
+}
+
+async fn graphql(client: &reqwest::Client, base_url: &str, query: &str) -> Value {
+    let response = client
+        .post(format!("{}/api/v1/graphql", base_url))
+        .json(&json!({ "query": query }))
+        .send()
+        .await
+        .expect("Failed to run query");
+    assert_eq!(response.status(), StatusCode::OK);
+    response.json().await.unwrap()
+}
+
+#[tokio::test]
+async fn test_nested_draft_board_query() {
+    let (base_url, pool) = test_support::spawn_app().await;
+    let client = test_support::create_client_with_role(domain::models::Role::Admin);
+
+    let team = post_json(
+        &client,
+        format!("{}/api/v1/teams", base_url),
+        json!({
+            "name": "Graph Team",
+            "abbreviation": "GQL",
+            "city": "Graph City",
+            "conference": "AFC",
+            "division": "AFC East"
+        }),
+    )
+    .await;
+    let team_id = team["id"].as_str().unwrap();
+
+    post_json(
+        &client,
+        format!("{}/api/v1/team-needs", base_url),
+        json!({ "team_id": team_id, "position": "QB", "priority": 1 }),
+    )
+    .await;
+
+    let player = post_json(
+        &client,
+        format!("{}/api/v1/players", base_url),
+        json!({
+            "first_name": "Graph",
+            "last_name": "Prospect",
+            "position": "QB",
+            "draft_year": 2026
+        }),
+    )
+    .await;
+    let player_id: Uuid = player["id"].as_str().unwrap().parse().unwrap();
+
+    let source_id = Uuid::new_v4();
+    sqlx::query("INSERT INTO ranking_sources (id, name) VALUES ($1, 'Graph Board')")
+        .bind(source_id)
+        .execute(&pool)
+        .await
+        .expect("Failed to insert ranking source");
+    sqlx::query(
+        "INSERT INTO prospect_rankings (id, ranking_source_id, player_id, rank, scraped_at) \
+         VALUES ($1, $2, $3, 7, '2026-02-11')",
+    )
+    .bind(Uuid::new_v4())
+    .bind(source_id)
+    .bind(player_id)
+    .execute(&pool)
+    .await
+    .expect("Failed to insert ranking");
+
+    let draft = post_json(
+        &client,
+        format!("{}/api/v1/drafts", base_url),
+        json!({ "name": "Graph Draft", "year": 2026, "rounds": 1, "picks_per_round": 1 }),
+    )
+    .await;
+    let draft_id = draft["id"].as_str().unwrap();
+    post_json(
+        &client,
+        format!("{}/api/v1/drafts/{}/initialize", base_url, draft_id),
+        json!({}),
+    )
+    .await;
+    sqlx::query("UPDATE draft_picks SET player_id = $1, picked_at = NOW()")
+        .bind(player_id)
+        .execute(&pool)
+        .await
+        .expect("Failed to make pick");
+
+    let body = graphql(
+        &client,
+        &base_url,
+        &format!(
+            r#"{{
+                draft(id: "{draft_id}") {{
+                    name
+                    status
+                    picks {{
+                        overallPick
+                        team {{ abbreviation division needs {{ position priority }} }}
+                        originalTeam {{ abbreviation }}
+                        player {{ lastName position rankings {{ sourceName rank scrapedAt }} }}
+                    }}
+                }}
+                team(id: "{team_id}") {{
+                    picks(draftId: "{draft_id}") {{ round pickNumber }}
+                }}
+            }}"#
+        ),
+    )
+    .await;
+    assert!(body.get("errors").is_none(), "{}", body);
+
+    let draft = &body["data"]["draft"];
+    assert_eq!(draft["name"], "Graph Draft");
+    assert_eq!(draft["status"], "NotStarted");
+    let pick = &draft["picks"][0];
+    assert_eq!(pick["overallPick"], 1);
+    assert_eq!(pick["team"]["abbreviation"], "GQL");
+    assert_eq!(pick["team"]["division"], "AFC East");
+    assert_eq!(
+        pick["team"]["needs"],
+        json!([{ "position": "QB", "priority": 1 }])
+    );
+    assert_eq!(pick["originalTeam"], Value::Null);
+    assert_eq!(pick["player"]["lastName"], "Prospect");
+    assert_eq!(
+        pick["player"]["rankings"],
+        json!([{ "sourceName": "Graph Board", "rank": 7, "scrapedAt": "2026-02-11" }])
+    );
+    assert_eq!(
+        body["data"]["team"]["picks"],
+        json!([{ "round": 1, "pickNumber": 1 }])
+    );
+}
+
+#[tokio::test]
+async fn test_players_query_and_errors() {
+    let (base_url, _pool) = test_support::spawn_app().await;
+    let client = test_support::create_client_with_role(domain::models::Role::Admin);
+
+    for (first_name, position) in [("Quinn", "QB"), ("Wade", "WR")] {
+        post_json(
+            &client,
+            format!("{}/api/v1/players", base_url),
+            json!({
+                "first_name": first_name,
+                "last_name": "Prospect",
+                "position": position,
+                "draft_year": 2026
+            }),
+        )
+        .await;
+    }
+
+    let body = graphql(
+        &client,
+        &base_url,
+        r#"{ players(position: "WR", draftYear: 2026) { firstName position } }"#,
+    )
+    .await;
+    assert_eq!(
+        body["data"]["players"],
+        json!([{ "firstName": "Wade", "position": "WR" }])
+    );
+
+    // Bad arguments report the status the REST API would have used
+    let body = graphql(&client, &base_url, r#"{ players(position: "XX") { id } }"#).await;
+    assert_eq!(body["errors"][0]["message"], "Unknown value 'XX'");
+    assert_eq!(body["errors"][0]["extensions"]["status"], 400);
+
+    let body = graphql(&client, &base_url, "mutation { noop }").await;
+    assert!(body["errors"].is_array());
+
+    // GET serves the GraphiQL explorer
+    let response = client
+        .get(format!("{}/api/v1/graphql", base_url))
+        .send()
+        .await
+        .expect("Failed to load GraphiQL");
+    assert_eq!(response.status(), StatusCode::OK);
+    assert!(response.text().await.unwrap().contains("graphiql"));
+}
@@ -1,13 +1,11 @@
 //! Health check acceptance tests
 
-mod common;
-
 use std::time::Duration;
 
 #[tokio::test]
 async fn test_health_check() {
-    let (base_url, _pool) = common::spawn_app().await;
-    let client = common::create_client();
+    let (base_url, _pool) = test_support::spawn_app().await;
+    let client = test_support::create_client();
 
     let response = client
         .get(&format!("{}/health", base_url))
@@ -21,3 +19,147 @@ async fn test_health_check() {
     let body: serde_json::Value = response.json().await.expect("Failed to parse JSON");
     assert_eq!(body["status"], "healthy");
 }
+
+#[tokio::test]
+async fn test_liveness_probe() {
+    let (base_url, _pool) = test_support::spawn_app().await;
+
+    let response = test_support::create_anonymous_client()
+        .get(format!("{}/health/live", base_url))
+        .send()
+        .await
+        .expect("Failed to check liveness");
+    assert_eq!(response.status(), 200);
+    let body: serde_json::Value = response.json().await.unwrap();
+    assert_eq!(body["status"], "alive");
+}
+
+#[tokio::test]
+async fn test_readiness_probe_reports_components() {
+    let (base_url, pool) = test_support::spawn_app().await;
+    let client = test_support::create_anonymous_client();
+
+    let component = |body: &serde_json::Value, name: &str| {
+        body["components"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .find(|c| c["name"] == name)
+            .cloned()
+            .unwrap_or_else(|| panic!("missing {} component", name))
+    };
+
+    // Nothing seeded yet, so the API is not ready
+    let response = client
+        .get(format!("{}/health/ready", base_url))
+        .send()
+        .await
+        .expect("Failed to check readiness");
+    assert_eq!(response.status(), 503);
+    let body: serde_json::Value = response.json().await.unwrap();
+    assert_eq!(body["status"], "not_ready");
+    assert_eq!(component(&body, "database")["status"], "up");
+    assert_eq!(component(&body, "seed_data")["status"], "down");
+    assert!(component(&body, "migrations")["detail"].is_string());
+
+    sqlx::query(
+        "INSERT INTO teams (id, name, city, abbreviation, conference, division) VALUES ($1, 'Titans', 'Tennessee', 'TEN', 'AFC', 'AFC South')",
+    )
+    .bind(uuid::Uuid::new_v4())
+    .execute(&pool)
+    .await
+    .unwrap();
+    sqlx::query(
+        "INSERT INTO players (id, first_name, last_name, position, draft_year) VALUES ($1, 'Test', 'Player', 'QB', 2026)",
+    )
+    .bind(uuid::Uuid::new_v4())
+    .execute(&pool)
+    .await
+    .unwrap();
+
+    let response = client
+        .get(format!("{}/health/ready", base_url))
+        .send()
+        .await
+        .expect("Failed to check readiness");
+    let status = response.status();
+    let body: serde_json::Value = response.json().await.unwrap();
+    assert_eq!(component(&body, "seed_data")["status"], "up");
+    // Overall readiness then only hinges on the migration history
+    let migrations_up = component(&body, "migrations")["status"] == "up";
+    assert_eq!(status == 200, migrations_up);
+    assert_eq!(body["status"] == "ready", migrations_up);
+}
+
+#[tokio::test]
+async fn test_database_health_report() {
+    let (base_url, pool) = test_support::spawn_app().await;
+    test_support::TeamBuilder::new().create(&pool).await;
+
+    // Without a seed key the report is hidden from everyone but admins
+    let response = test_support::create_client()
+        .get(format!("{}/health/db", base_url))
+        .send()
+        .await
+        .expect("Failed to fetch database health");
+    assert_eq!(response.status(), 404);
+
+    let response = test_support::create_client_with_role(domain::models::Role::Admin)
+        .get(format!("{}/health/db", base_url))
+        .send()
+        .await
+        .expect("Failed to fetch database health");
+    assert_eq!(response.status(), 200);
+    let body: serde_json::Value = response.json().await.unwrap();
+    assert_eq!(body["status"], "up");
+
+    let pools = body["pools"].as_array().unwrap();
+    assert_eq!(pools.len(), 1);
+    assert_eq!(pools[0]["name"], "primary");
+    assert_eq!(pools[0]["max_connections"], 5);
+    assert!(pools[0]["in_use"].as_u64().unwrap() <= pools[0]["size"].as_u64().unwrap());
+
+    // Each test schema is migrated by this build
+    assert_eq!(body["migrations"]["pending"], serde_json::json!([]));
+    assert_eq!(
+        body["migrations"]["applied"],
+        body["migrations"]["expected"]
+    );
+
+    let rows = |table: &str| {
+        body["row_counts"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .find(|count| count["table"] == table)
+            .map(|count| count["rows"].as_i64().unwrap())
+    };
+    // Estimates from the statistics collector, which may not have caught up
+    // with the team just inserted
+    assert!(rows("teams").is_some_and(|rows| (0..=1).contains(&rows)));
+    assert_eq!(rows("players"), Some(0));
+
+    // No read replica is configured
+    assert!(body["replication"].is_null());
+}
+
+#[tokio::test]
+async fn test_database_health_accepts_seed_key() {
+    let (base_url, _pool) = test_support::spawn_app_with_seed_key("health-key").await;
+    let client = test_support::create_anonymous_client();
+
+    let response = client
+        .get(format!("{}/health/db", base_url))
+        .send()
+        .await
+        .expect("Failed to fetch database health");
+    assert_eq!(response.status(), 401);
+
+    let response = client
+        .get(format!("{}/health/db", base_url))
+        .header("X-Seed-Api-Key", "health-key")
+        .send()
+        .await
+        .expect("Failed to fetch database health");
+    assert_eq!(response.status(), 200);
+}
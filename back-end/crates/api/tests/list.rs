@@ -1,14 +1,12 @@
 //! List endpoints acceptance tests
 
-mod common;
-
 use serde_json::json;
 use std::time::Duration;
 
 #[tokio::test]
 async fn test_list_endpoints() {
-    let (base_url, pool) = common::spawn_app().await;
-    let client = common::create_client();
+    let (base_url, pool) = test_support::spawn_app().await;
+    let client = test_support::create_client_with_role(domain::models::Role::Admin);
 
     // Create some test data
     client
@@ -107,3 +105,142 @@ async fn test_list_endpoints() {
         .expect("Failed to count drafts");
     assert_eq!(drafts.len() as i64, db_draft_count.count.unwrap());
 }
+
+#[tokio::test]
+async fn test_list_endpoints_paginate() {
+    let (base_url, _pool) = test_support::spawn_app().await;
+    let client = test_support::create_client_with_role(domain::models::Role::Admin);
+
+    for last_name in ["Adams", "Baker", "Clark"] {
+        client
+            .post(format!("{}/api/v1/players", base_url))
+            .json(&json!({
+                "first_name": "Sam",
+                "last_name": last_name,
+                "position": "QB",
+                "draft_year": 2026
+            }))
+            .timeout(Duration::from_secs(5))
+            .send()
+            .await
+            .expect("Failed to create player");
+    }
+
+    // Second page of two holds only the last player
+    let response = client
+        .get(format!("{}/api/v1/players?page=2&per_page=2", base_url))
+        .timeout(Duration::from_secs(5))
+        .send()
+        .await
+        .expect("Failed to list players");
+    assert_eq!(response.status(), 200);
+    assert_eq!(response.headers()["x-total-count"], "3");
+    assert_eq!(response.headers()["x-page"], "2");
+    assert_eq!(response.headers()["x-per-page"], "2");
+    let players: Vec<serde_json::Value> = response.json().await.expect("Failed to parse JSON");
+    assert_eq!(players.len(), 1);
+    assert_eq!(players[0]["last_name"], "Clark");
+
+    // Unpaged requests still return everything along with the total
+    let response = client
+        .get(format!("{}/api/v1/players", base_url))
+        .timeout(Duration::from_secs(5))
+        .send()
+        .await
+        .expect("Failed to list players");
+    assert_eq!(response.headers()["x-total-count"], "3");
+    assert!(response.headers().get("x-page").is_none());
+    let players: Vec<serde_json::Value> = response.json().await.expect("Failed to parse JSON");
+    assert_eq!(players.len(), 3);
+
+    // Out-of-range page sizes are rejected
+    for query in ["per_page=0", "per_page=501", "page=0"] {
+        let response = client
+            .get(format!("{}/api/v1/teams?{}", base_url, query))
+            .timeout(Duration::from_secs(5))
+            .send()
+            .await
+            .expect("Failed to list teams");
+        assert_eq!(response.status(), 400, "query {}", query);
+    }
+}
+
+#[tokio::test]
+async fn test_list_endpoints_sort() {
+    let (base_url, _pool) = test_support::spawn_app().await;
+    let client = test_support::create_client_with_role(domain::models::Role::Admin);
+
+    for (last_name, draft_year) in [("Adams", 2027), ("Baker", 2026), ("Clark", 2026)] {
+        client
+            .post(format!("{}/api/v1/players", base_url))
+            .json(&json!({
+                "first_name": "Sam",
+                "last_name": last_name,
+                "position": "QB",
+                "draft_year": draft_year
+            }))
+            .timeout(Duration::from_secs(5))
+            .send()
+            .await
+            .expect("Failed to create player");
+    }
+
+    let last_names = |players: Vec<serde_json::Value>| -> Vec<String> {
+        players
+            .iter()
+            .map(|p| p["last_name"].as_str().unwrap().to_string())
+            .collect()
+    };
+
+    for (query, expected) in [
+        ("sort=-last_name", vec!["Clark", "Baker", "Adams"]),
+        (
+            "sort=draft_year,-last_name",
+            vec!["Clark", "Baker", "Adams"],
+        ),
+        (
+            "sort=-draft_year,last_name",
+            vec!["Adams", "Baker", "Clark"],
+        ),
+        ("sort=-last_name&page=2&per_page=2", vec!["Adams"]),
+    ] {
+        let response = client
+            .get(format!("{}/api/v1/players?{}", base_url, query))
+            .timeout(Duration::from_secs(5))
+            .send()
+            .await
+            .expect("Failed to list players");
+        assert_eq!(response.status(), 200, "query {}", query);
+        let players: Vec<serde_json::Value> = response.json().await.expect("Failed to parse JSON");
+        assert_eq!(last_names(players), expected, "query {}", query);
+    }
+
+    // Fields outside an endpoint's whitelist are rejected
+    for path in [
+        "players?sort=grade",
+        "teams?sort=-last_name",
+        "drafts?sort=id",
+        "rankings?sort=player_id",
+        "players?sort=last_name,-last_name",
+    ] {
+        let response = client
+            .get(format!("{}/api/v1/{}", base_url, path))
+            .timeout(Duration::from_secs(5))
+            .send()
+            .await
+            .expect("Failed to list");
+        assert_eq!(response.status(), 400, "path {}", path);
+    }
+
+    let response = client
+        .get(format!("{}/api/v1/players?sort=grade", base_url))
+        .timeout(Duration::from_secs(5))
+        .send()
+        .await
+        .expect("Failed to list players");
+    let body: serde_json::Value = response.json().await.expect("Failed to parse JSON");
+    assert!(body["error"]
+        .as_str()
+        .unwrap()
+        .contains("sortable fields are: first_name"));
+}
@@ -1,15 +1,13 @@
 //! Make pick error case acceptance tests
 
-mod common;
-
 use serde_json::json;
 use std::time::Duration;
 use uuid::Uuid;
 
 #[tokio::test]
 async fn test_make_pick_nonexistent_pick_returns_404() {
-    let (base_url, _pool) = common::spawn_app().await;
-    let client = common::create_client();
+    let (base_url, _pool) = test_support::spawn_app().await;
+    let client = test_support::create_client();
 
     let random_pick_id = Uuid::new_v4();
     let random_player_id = Uuid::new_v4();
@@ -30,8 +28,8 @@ async fn test_make_pick_nonexistent_pick_returns_404() {
 
 #[tokio::test]
 async fn test_make_pick_nonexistent_player_returns_404() {
-    let (base_url, pool) = common::spawn_app().await;
-    let client = common::create_client();
+    let (base_url, pool) = test_support::spawn_app().await;
+    let client = test_support::create_client();
 
     // Create a team
     let team_id = Uuid::new_v4();
@@ -86,8 +84,8 @@ async fn test_make_pick_nonexistent_player_returns_404() {
 
 #[tokio::test]
 async fn test_make_pick_already_made_returns_400() {
-    let (base_url, pool) = common::spawn_app().await;
-    let client = common::create_client();
+    let (base_url, pool) = test_support::spawn_app().await;
+    let client = test_support::create_client();
 
     // Create team
     let team_id = Uuid::new_v4();
@@ -162,8 +160,8 @@ async fn test_make_pick_already_made_returns_400() {
 
 #[tokio::test]
 async fn test_make_pick_wrong_draft_year_returns_400() {
-    let (base_url, pool) = common::spawn_app().await;
-    let client = common::create_client();
+    let (base_url, pool) = test_support::spawn_app().await;
+    let client = test_support::create_client();
 
     // Create team
     let team_id = Uuid::new_v4();
@@ -219,8 +217,8 @@ async fn test_make_pick_wrong_draft_year_returns_400() {
 
 #[tokio::test]
 async fn test_make_pick_ineligible_player_returns_400() {
-    let (base_url, pool) = common::spawn_app().await;
-    let client = common::create_client();
+    let (base_url, pool) = test_support::spawn_app().await;
+    let client = test_support::create_client();
 
     // Create team
     let team_id = Uuid::new_v4();
@@ -276,8 +274,8 @@ async fn test_make_pick_ineligible_player_returns_400() {
 
 #[tokio::test]
 async fn test_make_pick_player_already_drafted_returns_409() {
-    let (base_url, pool) = common::spawn_app().await;
-    let client = common::create_client();
+    let (base_url, pool) = test_support::spawn_app().await;
+    let client = test_support::create_client();
 
     // Create team
     let team_id = Uuid::new_v4();
@@ -342,6 +340,8 @@ async fn test_make_pick_player_already_drafted_returns_409() {
         .await
         .expect("Failed to send second pick request");
     assert_eq!(second_response.status(), 409);
+    let body: serde_json::Value = second_response.json().await.unwrap();
+    assert_eq!(body["code"], "PLAYER_ALREADY_DRAFTED");
 
     // Verify pick 2 is still unmade in the database
     let db_pick = sqlx::query!("SELECT player_id FROM draft_picks WHERE id = $1", pick2_id)
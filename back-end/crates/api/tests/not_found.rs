@@ -1,15 +1,13 @@
 //! 404 Not Found acceptance tests for individual resource GET endpoints
 
-mod common;
-
 use std::time::Duration;
 
 const FAKE_UUID: &str = "00000000-0000-0000-0000-000000000000";
 
 #[tokio::test]
 async fn test_get_nonexistent_draft_returns_404() {
-    let (base_url, _pool) = common::spawn_app().await;
-    let client = common::create_client();
+    let (base_url, _pool) = test_support::spawn_app().await;
+    let client = test_support::create_client();
 
     let response = client
         .get(&format!("{}/api/v1/drafts/{}", base_url, FAKE_UUID))
@@ -23,8 +21,8 @@ async fn test_get_nonexistent_draft_returns_404() {
 
 #[tokio::test]
 async fn test_get_nonexistent_player_returns_404() {
-    let (base_url, _pool) = common::spawn_app().await;
-    let client = common::create_client();
+    let (base_url, _pool) = test_support::spawn_app().await;
+    let client = test_support::create_client();
 
     let response = client
         .get(&format!("{}/api/v1/players/{}", base_url, FAKE_UUID))
@@ -38,8 +36,8 @@ async fn test_get_nonexistent_player_returns_404() {
 
 #[tokio::test]
 async fn test_get_nonexistent_combine_results_returns_404() {
-    let (base_url, _pool) = common::spawn_app().await;
-    let client = common::create_client();
+    let (base_url, _pool) = test_support::spawn_app().await;
+    let client = test_support::create_client();
 
     let response = client
         .get(&format!(
@@ -56,8 +54,8 @@ async fn test_get_nonexistent_combine_results_returns_404() {
 
 #[tokio::test]
 async fn test_get_nonexistent_scouting_report_returns_404() {
-    let (base_url, _pool) = common::spawn_app().await;
-    let client = common::create_client();
+    let (base_url, _pool) = test_support::spawn_app().await;
+    let client = test_support::create_client();
 
     let response = client
         .get(&format!(
@@ -74,8 +72,8 @@ async fn test_get_nonexistent_scouting_report_returns_404() {
 
 #[tokio::test]
 async fn test_get_nonexistent_team_need_returns_404() {
-    let (base_url, _pool) = common::spawn_app().await;
-    let client = common::create_client();
+    let (base_url, _pool) = test_support::spawn_app().await;
+    let client = test_support::create_client();
 
     let response = client
         .get(&format!("{}/api/v1/team-needs/{}", base_url, FAKE_UUID))
@@ -89,8 +87,8 @@ async fn test_get_nonexistent_team_need_returns_404() {
 
 #[tokio::test]
 async fn test_get_nonexistent_trade_returns_404() {
-    let (base_url, _pool) = common::spawn_app().await;
-    let client = common::create_client();
+    let (base_url, _pool) = test_support::spawn_app().await;
+    let client = test_support::create_client();
 
     let response = client
         .get(&format!("{}/api/v1/trades/{}", base_url, FAKE_UUID))
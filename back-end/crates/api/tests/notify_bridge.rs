@@ -0,0 +1,59 @@
+use std::sync::Arc;
+use std::time::Duration;
+
+use api::notify::{spawn_notify_listener, PgNotifyRelay};
+use tokio::sync::mpsc;
+use uuid::Uuid;
+use websocket::{ConnectionManager, ServerMessage};
+
+#[tokio::test]
+async fn test_broadcast_reaches_connections_on_other_instances() {
+    let database_url = test_support::database_url();
+    let pool = test_support::setup_test_pool().await;
+    let session_id = Uuid::new_v4();
+
+    // Two instances, each with one client watching the session
+    let first_id = Uuid::new_v4();
+    let first =
+        ConnectionManager::new().with_relay(Arc::new(PgNotifyRelay::spawn(pool.clone(), first_id)));
+    let (first_tx, mut first_rx) = mpsc::channel(16);
+    first.add_connection(Uuid::new_v4(), session_id, first_tx);
+    spawn_notify_listener(database_url.clone(), first.clone(), first_id);
+
+    let second_id = Uuid::new_v4();
+    let second = ConnectionManager::new()
+        .with_relay(Arc::new(PgNotifyRelay::spawn(pool.clone(), second_id)));
+    let (second_tx, mut second_rx) = mpsc::channel(16);
+    second.add_connection(Uuid::new_v4(), session_id, second_tx);
+    spawn_notify_listener(database_url, second.clone(), second_id);
+
+    // Give both listeners time to subscribe
+    tokio::time::sleep(Duration::from_millis(500)).await;
+
+    first
+        .broadcast_to_session(
+            session_id,
+            ServerMessage::DraftStatus {
+                session_id,
+                status: "Paused".to_string(),
+            },
+        )
+        .await;
+
+    let relayed = tokio::time::timeout(Duration::from_secs(5), second_rx.recv())
+        .await
+        .expect("broadcast was not relayed")
+        .unwrap();
+    let relayed = ServerMessage::from_json(&relayed).unwrap();
+    assert!(matches!(relayed, ServerMessage::DraftStatus { status, .. } if status == "Paused"));
+
+    // The origin delivers once, directly, and ignores its own notification
+    assert!(first_rx.recv().await.is_some());
+    tokio::time::sleep(Duration::from_millis(300)).await;
+    assert!(first_rx.try_recv().is_err());
+
+    // Presence describes one instance's viewers and is not relayed
+    first.broadcast_presence(session_id).await;
+    tokio::time::sleep(Duration::from_millis(300)).await;
+    assert!(second_rx.try_recv().is_err());
+}
@@ -1,7 +1,5 @@
 //! Next/available pick endpoint acceptance tests
 
-mod common;
-
 use serde_json::json;
 use std::time::Duration;
 
@@ -71,8 +69,8 @@ async fn setup_draft_with_picks(
 
 #[tokio::test]
 async fn test_get_next_pick() {
-    let (base_url, _pool) = common::spawn_app().await;
-    let client = common::create_client();
+    let (base_url, _pool) = test_support::spawn_app().await;
+    let client = test_support::create_client_with_role(domain::models::Role::Admin);
 
     let (draft_id, _pick_ids) = setup_draft_with_picks(&base_url, &client, 2, 1).await;
 
@@ -94,8 +92,8 @@ async fn test_get_next_pick() {
 
 #[tokio::test]
 async fn test_get_next_pick_advances_after_made() {
-    let (base_url, _pool) = common::spawn_app().await;
-    let client = common::create_client();
+    let (base_url, _pool) = test_support::spawn_app().await;
+    let client = test_support::create_client_with_role(domain::models::Role::Admin);
 
     let (draft_id, pick_ids) = setup_draft_with_picks(&base_url, &client, 2, 1).await;
 
@@ -145,8 +143,8 @@ async fn test_get_next_pick_advances_after_made() {
 
 #[tokio::test]
 async fn test_get_next_pick_returns_null_when_all_made() {
-    let (base_url, _pool) = common::spawn_app().await;
-    let client = common::create_client();
+    let (base_url, _pool) = test_support::spawn_app().await;
+    let client = test_support::create_client_with_role(domain::models::Role::Admin);
 
     let (draft_id, pick_ids) = setup_draft_with_picks(&base_url, &client, 1, 1).await;
 
@@ -196,8 +194,8 @@ async fn test_get_next_pick_returns_null_when_all_made() {
 
 #[tokio::test]
 async fn test_get_available_picks() {
-    let (base_url, _pool) = common::spawn_app().await;
-    let client = common::create_client();
+    let (base_url, _pool) = test_support::spawn_app().await;
+    let client = test_support::create_client_with_role(domain::models::Role::Admin);
 
     let (draft_id, _pick_ids) = setup_draft_with_picks(&base_url, &client, 2, 1).await;
 
@@ -219,8 +217,8 @@ async fn test_get_available_picks() {
 
 #[tokio::test]
 async fn test_get_available_picks_decrements() {
-    let (base_url, _pool) = common::spawn_app().await;
-    let client = common::create_client();
+    let (base_url, _pool) = test_support::spawn_app().await;
+    let client = test_support::create_client_with_role(domain::models::Role::Admin);
 
     let (draft_id, pick_ids) = setup_draft_with_picks(&base_url, &client, 2, 1).await;
 
@@ -269,8 +267,8 @@ async fn test_get_available_picks_decrements() {
 
 #[tokio::test]
 async fn test_get_all_draft_picks_ordered() {
-    let (base_url, _pool) = common::spawn_app().await;
-    let client = common::create_client();
+    let (base_url, _pool) = test_support::spawn_app().await;
+    let client = test_support::create_client_with_role(domain::models::Role::Admin);
 
     let (draft_id, _pick_ids) = setup_draft_with_picks(&base_url, &client, 2, 2).await;
 
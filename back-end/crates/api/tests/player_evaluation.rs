@@ -2,17 +2,13 @@
 //!
 //! Tests cross-feature workflows involving players, combine results, and scouting reports.
 
-mod common;
-
 use serde_json::json;
 use std::time::Duration;
 
 #[tokio::test]
 async fn test_complete_player_evaluation_workflow() {
-    let (base_url, pool) = common::spawn_app().await;
-    let client = common::create_client();
-
-    common::cleanup_database(&pool).await;
+    let (base_url, pool) = test_support::spawn_app().await;
+    let client = test_support::create_client_with_role(domain::models::Role::Admin);
 
     // Step 1: Create a player
     let player_response = client
@@ -221,16 +217,12 @@ async fn test_complete_player_evaluation_workflow() {
     .expect("Failed to count scouting reports");
 
     assert_eq!(db_reports_count.count, Some(2));
-
-    common::cleanup_database(&pool).await;
 }
 
 #[tokio::test]
 async fn test_player_deletion_cascades_to_combine_and_scouting() {
-    let (base_url, pool) = common::spawn_app().await;
-    let client = common::create_client();
-
-    common::cleanup_database(&pool).await;
+    let (base_url, pool) = test_support::spawn_app().await;
+    let client = test_support::create_client_with_role(domain::models::Role::Admin);
 
     // Create player
     let player_response = client
@@ -407,16 +399,12 @@ async fn test_player_deletion_cascades_to_combine_and_scouting() {
         .expect("Failed to get scouting report");
 
     assert_eq!(report_get.status(), 404);
-
-    common::cleanup_database(&pool).await;
 }
 
 #[tokio::test]
 async fn test_query_player_with_all_related_data() {
-    let (base_url, pool) = common::spawn_app().await;
-    let client = common::create_client();
-
-    common::cleanup_database(&pool).await;
+    let (base_url, pool) = test_support::spawn_app().await;
+    let client = test_support::create_client_with_role(domain::models::Role::Admin);
 
     // Create player
     let player_response = client
@@ -619,16 +607,12 @@ async fn test_query_player_with_all_related_data() {
     .expect("Failed to count scouting reports");
 
     assert_eq!(db_reports_count.count, Some(3));
-
-    common::cleanup_database(&pool).await;
 }
 
 #[tokio::test]
 async fn test_multiple_combine_years_for_player() {
-    let (base_url, pool) = common::spawn_app().await;
-    let client = common::create_client();
-
-    common::cleanup_database(&pool).await;
+    let (base_url, pool) = test_support::spawn_app().await;
+    let client = test_support::create_client_with_role(domain::models::Role::Admin);
 
     // Create player
     let player_response = client
@@ -711,16 +695,12 @@ async fn test_multiple_combine_years_for_player() {
     .expect("Failed to get 2026 results");
 
     assert_eq!(results_2026.forty_yard_dash, Some(4.48));
-
-    common::cleanup_database(&pool).await;
 }
 
 #[tokio::test]
 async fn test_player_without_combine_results() {
-    let (base_url, pool) = common::spawn_app().await;
-    let client = common::create_client();
-
-    common::cleanup_database(&pool).await;
+    let (base_url, pool) = test_support::spawn_app().await;
+    let client = test_support::create_client_with_role(domain::models::Role::Admin);
 
     // Create player without combine results (didn't attend combine)
     let player_response = client
@@ -838,6 +818,4 @@ async fn test_player_without_combine_results() {
     .expect("Failed to count scouting reports");
 
     assert_eq!(db_reports_count.count, Some(1));
-
-    common::cleanup_database(&pool).await;
 }
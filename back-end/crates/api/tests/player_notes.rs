@@ -0,0 +1,165 @@
+//! Player notes CRUD acceptance tests
+
+use reqwest::StatusCode;
+use serde_json::{json, Value};
+
+async fn create_player(client: &reqwest::Client, base_url: &str) -> String {
+    let response = client
+        .post(format!("{}/api/v1/players", base_url))
+        .json(&json!({
+            "first_name": "Note",
+            "last_name": "Subject",
+            "position": "WR",
+            "draft_year": 2026
+        }))
+        .send()
+        .await
+        .expect("Failed to create player");
+    assert_eq!(response.status(), StatusCode::CREATED);
+    let player: Value = response.json().await.unwrap();
+    player["id"].as_str().unwrap().to_string()
+}
+
+#[tokio::test]
+async fn test_player_note_crud() {
+    let (base_url, pool) = test_support::spawn_app().await;
+    let client = test_support::create_client_with_role(domain::models::Role::Admin);
+    let player_id = create_player(&client, &base_url).await;
+
+    let create_response = client
+        .post(format!("{}/api/v1/players/{}/notes", base_url, player_id))
+        .json(&json!({
+            "source": "Beat reporter",
+            "note_date": "2026-03-12",
+            "tag": "visit",
+            "content": "Top-30 visit with Chicago"
+        }))
+        .send()
+        .await
+        .expect("Failed to create note");
+    assert_eq!(create_response.status(), StatusCode::CREATED);
+    let created: Value = create_response.json().await.unwrap();
+    let note_id = created["id"].as_str().unwrap().to_string();
+    assert_eq!(created["player_id"], player_id.as_str());
+    assert_eq!(created["tag"], "visit");
+    assert_eq!(created["note_date"], "2026-03-12");
+
+    let db_note =
+        sqlx::query_as::<_, (String, String)>("SELECT tag, source FROM player_notes WHERE id = $1")
+            .bind(uuid::Uuid::parse_str(&note_id).unwrap())
+            .fetch_one(&pool)
+            .await
+            .expect("Note not found in database");
+    assert_eq!(db_note, ("visit".to_string(), "Beat reporter".to_string()));
+
+    let update_response = client
+        .put(format!("{}/api/v1/player-notes/{}", base_url, note_id))
+        .json(&json!({
+            "source": "Insider",
+            "note_date": "2026-03-14",
+            "tag": "riser",
+            "content": "Climbing boards after the visit"
+        }))
+        .send()
+        .await
+        .expect("Failed to update note");
+    assert_eq!(update_response.status(), StatusCode::OK);
+
+    let get_response = client
+        .get(format!("{}/api/v1/player-notes/{}", base_url, note_id))
+        .send()
+        .await
+        .expect("Failed to get note");
+    assert_eq!(get_response.status(), StatusCode::OK);
+    let note: Value = get_response.json().await.unwrap();
+    assert_eq!(note["source"], "Insider");
+    assert_eq!(note["tag"], "riser");
+    assert_eq!(note["note_date"], "2026-03-14");
+
+    let delete_response = client
+        .delete(format!("{}/api/v1/player-notes/{}", base_url, note_id))
+        .send()
+        .await
+        .expect("Failed to delete note");
+    assert_eq!(delete_response.status(), StatusCode::NO_CONTENT);
+
+    let missing = client
+        .get(format!("{}/api/v1/player-notes/{}", base_url, note_id))
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(missing.status(), StatusCode::NOT_FOUND);
+}
+
+#[tokio::test]
+async fn test_player_detail_includes_notes_newest_first() {
+    let (base_url, _pool) = test_support::spawn_app().await;
+    let client = test_support::create_client_with_role(domain::models::Role::Admin);
+    let player_id = create_player(&client, &base_url).await;
+
+    for (date, tag) in [("2026-02-01", "injury"), ("2026-04-01", "faller")] {
+        let response = client
+            .post(format!("{}/api/v1/players/{}/notes", base_url, player_id))
+            .json(&json!({
+                "source": "Team report",
+                "note_date": date,
+                "tag": tag,
+                "content": format!("{} note", tag)
+            }))
+            .send()
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::CREATED);
+    }
+
+    let player: Value = client
+        .get(format!("{}/api/v1/players/{}", base_url, player_id))
+        .send()
+        .await
+        .unwrap()
+        .json()
+        .await
+        .unwrap();
+    assert_eq!(player["first_name"], "Note");
+    let notes = player["notes"].as_array().expect("notes array");
+    assert_eq!(notes.len(), 2);
+    assert_eq!(notes[0]["tag"], "faller");
+    assert_eq!(notes[1]["tag"], "injury");
+
+    let listed: Value = client
+        .get(format!("{}/api/v1/players/{}/notes", base_url, player_id))
+        .send()
+        .await
+        .unwrap()
+        .json()
+        .await
+        .unwrap();
+    assert_eq!(listed.as_array().unwrap().len(), 2);
+}
+
+#[tokio::test]
+async fn test_create_player_note_validation() {
+    let (base_url, _pool) = test_support::spawn_app().await;
+    let client = test_support::create_client_with_role(domain::models::Role::Admin);
+    let player_id = create_player(&client, &base_url).await;
+
+    let empty_content = client
+        .post(format!("{}/api/v1/players/{}/notes", base_url, player_id))
+        .json(&json!({ "source": "Insider", "note_date": "2026-03-01", "content": " " }))
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(empty_content.status(), StatusCode::BAD_REQUEST);
+
+    let unknown_player = client
+        .post(format!(
+            "{}/api/v1/players/{}/notes",
+            base_url,
+            uuid::Uuid::new_v4()
+        ))
+        .json(&json!({ "source": "Insider", "note_date": "2026-03-01", "content": "News" }))
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(unknown_player.status(), StatusCode::NOT_FOUND);
+}
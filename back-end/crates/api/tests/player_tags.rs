@@ -0,0 +1,187 @@
+//! Player tag management and tag filter acceptance tests
+
+use reqwest::StatusCode;
+use serde_json::{json, Value};
+
+async fn create_player(client: &reqwest::Client, base_url: &str, last_name: &str) -> String {
+    let response = client
+        .post(format!("{}/api/v1/players", base_url))
+        .json(&json!({
+            "first_name": "Tag",
+            "last_name": last_name,
+            "position": "LB",
+            "draft_year": 2026
+        }))
+        .send()
+        .await
+        .expect("Failed to create player");
+    assert_eq!(response.status(), StatusCode::CREATED);
+    let player: Value = response.json().await.unwrap();
+    player["id"].as_str().unwrap().to_string()
+}
+
+async fn add_tag(client: &reqwest::Client, base_url: &str, player_id: &str, tag: &str) -> Value {
+    let response = client
+        .post(format!("{}/api/v1/players/{}/tags", base_url, player_id))
+        .json(&json!({ "tag": tag }))
+        .send()
+        .await
+        .expect("Failed to add tag");
+    assert_eq!(response.status(), StatusCode::CREATED);
+    response.json().await.unwrap()
+}
+
+#[tokio::test]
+async fn test_player_tag_management() {
+    let (base_url, pool) = test_support::spawn_app().await;
+    let client = test_support::create_client_with_role(domain::models::Role::Admin);
+    let player_id = create_player(&client, &base_url, "Captain").await;
+
+    let added = add_tag(&client, &base_url, &player_id, "  Team   Captain ").await;
+    assert_eq!(added["tag"], "team captain");
+    assert_eq!(added["player_id"], player_id.as_str());
+    add_tag(&client, &base_url, &player_id, "team captain").await;
+    add_tag(&client, &base_url, &player_id, "early declare").await;
+
+    let tags: Value = client
+        .get(format!("{}/api/v1/players/{}/tags", base_url, player_id))
+        .send()
+        .await
+        .unwrap()
+        .json()
+        .await
+        .unwrap();
+    let names: Vec<&str> = tags
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|t| t["tag"].as_str().unwrap())
+        .collect();
+    assert_eq!(names, vec!["early declare", "team captain"]);
+
+    let player: Value = client
+        .get(format!("{}/api/v1/players/{}", base_url, player_id))
+        .send()
+        .await
+        .unwrap()
+        .json()
+        .await
+        .unwrap();
+    assert_eq!(player["tags"], json!(["early declare", "team captain"]));
+
+    let removed = client
+        .delete(format!(
+            "{}/api/v1/players/{}/tags/Team%20Captain",
+            base_url, player_id
+        ))
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(removed.status(), StatusCode::NO_CONTENT);
+
+    let removed_again = client
+        .delete(format!(
+            "{}/api/v1/players/{}/tags/team%20captain",
+            base_url, player_id
+        ))
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(removed_again.status(), StatusCode::NOT_FOUND);
+
+    let db_tags =
+        sqlx::query_as::<_, (String,)>("SELECT tag FROM player_tags WHERE player_id = $1")
+            .bind(uuid::Uuid::parse_str(&player_id).unwrap())
+            .fetch_all(&pool)
+            .await
+            .unwrap();
+    assert_eq!(db_tags, vec![("early declare".to_string(),)]);
+}
+
+#[tokio::test]
+async fn test_filter_players_by_tags() {
+    let (base_url, _pool) = test_support::spawn_app().await;
+    let client = test_support::create_client_with_role(domain::models::Role::Admin);
+    let both = create_player(&client, &base_url, "Both").await;
+    let small_school = create_player(&client, &base_url, "Smallschool").await;
+    create_player(&client, &base_url, "Untagged").await;
+
+    add_tag(&client, &base_url, &both, "small-school").await;
+    add_tag(&client, &base_url, &both, "team captain").await;
+    add_tag(&client, &base_url, &small_school, "small-school").await;
+
+    let filtered: Value = client
+        .get(format!(
+            "{}/api/v1/players?tags=Small-School,team%20captain",
+            base_url
+        ))
+        .send()
+        .await
+        .unwrap()
+        .json()
+        .await
+        .unwrap();
+    let players = filtered.as_array().unwrap();
+    assert_eq!(players.len(), 1);
+    assert_eq!(players[0]["id"], both.as_str());
+
+    let filtered: Value = client
+        .get(format!("{}/api/v1/players?tags=small-school", base_url))
+        .send()
+        .await
+        .unwrap()
+        .json()
+        .await
+        .unwrap();
+    assert_eq!(filtered.as_array().unwrap().len(), 2);
+
+    let counts: Value = client
+        .get(format!("{}/api/v1/player-tags", base_url))
+        .send()
+        .await
+        .unwrap()
+        .json()
+        .await
+        .unwrap();
+    assert_eq!(
+        counts,
+        json!([
+            { "tag": "small-school", "player_count": 2 },
+            { "tag": "team captain", "player_count": 1 }
+        ])
+    );
+
+    let empty_filter = client
+        .get(format!("{}/api/v1/players?tags=,", base_url))
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(empty_filter.status(), StatusCode::BAD_REQUEST);
+}
+
+#[tokio::test]
+async fn test_add_player_tag_validation() {
+    let (base_url, _pool) = test_support::spawn_app().await;
+    let client = test_support::create_client_with_role(domain::models::Role::Admin);
+    let player_id = create_player(&client, &base_url, "Invalid").await;
+
+    let blank = client
+        .post(format!("{}/api/v1/players/{}/tags", base_url, player_id))
+        .json(&json!({ "tag": "   " }))
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(blank.status(), StatusCode::BAD_REQUEST);
+
+    let unknown_player = client
+        .post(format!(
+            "{}/api/v1/players/{}/tags",
+            base_url,
+            uuid::Uuid::new_v4()
+        ))
+        .json(&json!({ "tag": "sleeper" }))
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(unknown_player.status(), StatusCode::NOT_FOUND);
+}
@@ -1,14 +1,12 @@
 //! Player CRUD acceptance tests
 
-mod common;
-
 use serde_json::json;
 use std::time::Duration;
 
 #[tokio::test]
 async fn test_create_and_get_player() {
-    let (base_url, pool) = common::spawn_app().await;
-    let client = common::create_client();
+    let (base_url, pool) = test_support::spawn_app().await;
+    let client = test_support::create_client_with_role(domain::models::Role::Admin);
 
     // Create player
     let create_response = client
@@ -73,8 +71,8 @@ async fn test_create_and_get_player() {
 
 #[tokio::test]
 async fn test_create_player_minimal() {
-    let (base_url, pool) = common::spawn_app().await;
-    let client = common::create_client();
+    let (base_url, pool) = test_support::spawn_app().await;
+    let client = test_support::create_client_with_role(domain::models::Role::Admin);
 
     // Create player with only required fields (all optional fields null)
     let create_response = client
@@ -122,3 +120,395 @@ async fn test_create_player_minimal() {
     assert_eq!(db_player.height_inches, None);
     assert_eq!(db_player.weight_pounds, None);
 }
+
+#[tokio::test]
+async fn test_list_players_filters() {
+    let (base_url, pool) = test_support::spawn_app().await;
+    let client = test_support::create_client_with_role(domain::models::Role::Admin);
+
+    let mut ids = Vec::new();
+    for (last_name, position, college, height, weight) in [
+        ("Adams", "QB", "Ohio State", 77, 230),
+        ("Baker", "QB", "Oregon", 71, 205),
+        ("Clark", "WR", "Ohio State", 73, 190),
+    ] {
+        let response = client
+            .post(format!("{}/api/v1/players", base_url))
+            .json(&json!({
+                "first_name": "Sam",
+                "last_name": last_name,
+                "position": position,
+                "college": college,
+                "height_inches": height,
+                "weight_pounds": weight,
+                "draft_year": 2026
+            }))
+            .timeout(Duration::from_secs(5))
+            .send()
+            .await
+            .expect("Failed to create player");
+        let player: serde_json::Value = response.json().await.expect("Failed to parse JSON");
+        ids.push(uuid::Uuid::parse_str(player["id"].as_str().unwrap()).unwrap());
+    }
+
+    sqlx::query!(
+        "INSERT INTO combine_results (player_id, year) VALUES ($1, 2026)",
+        ids[1]
+    )
+    .execute(&pool)
+    .await
+    .expect("Failed to insert combine results");
+
+    let last_names = |players: Vec<serde_json::Value>| -> Vec<String> {
+        players
+            .iter()
+            .map(|p| p["last_name"].as_str().unwrap().to_string())
+            .collect()
+    };
+
+    for (query, expected) in [
+        ("position=QB", vec!["Adams", "Baker"]),
+        ("school=ohio&min_height=74", vec!["Adams"]),
+        ("max_weight=210&draft_year=2026", vec!["Baker", "Clark"]),
+        ("has_combine_results=true", vec!["Baker"]),
+        ("has_combine_results=false&position=WR", vec!["Clark"]),
+        ("draft_year=2027", vec![]),
+    ] {
+        let response = client
+            .get(format!("{}/api/v1/players?{}", base_url, query))
+            .timeout(Duration::from_secs(5))
+            .send()
+            .await
+            .expect("Failed to list players");
+        assert_eq!(response.status(), 200, "query {}", query);
+        let players: Vec<serde_json::Value> = response.json().await.expect("Failed to parse JSON");
+        assert_eq!(last_names(players), expected, "query {}", query);
+    }
+
+    // Filters combine with pagination; the total counts every match, not just the page
+    let response = client
+        .get(format!(
+            "{}/api/v1/players?position=QB&page=1&per_page=1",
+            base_url
+        ))
+        .timeout(Duration::from_secs(5))
+        .send()
+        .await
+        .expect("Failed to list players");
+    assert_eq!(response.headers()["x-total-count"], "2");
+    let players: Vec<serde_json::Value> = response.json().await.expect("Failed to parse JSON");
+    assert_eq!(last_names(players), vec!["Adams"]);
+
+    for query in ["min_height=76&max_height=70", "position=XX"] {
+        let response = client
+            .get(format!("{}/api/v1/players?{}", base_url, query))
+            .timeout(Duration::from_secs(5))
+            .send()
+            .await
+            .expect("Failed to list players");
+        assert_eq!(response.status(), 400, "query {}", query);
+    }
+}
+
+#[tokio::test]
+async fn test_patch_player() {
+    let (base_url, pool) = test_support::spawn_app().await;
+    let client = test_support::create_client_with_role(domain::models::Role::Admin);
+
+    let response = client
+        .post(format!("{}/api/v1/players", base_url))
+        .json(&json!({
+            "first_name": "John",
+            "last_name": "Doe",
+            "position": "QB",
+            "college": "Texas",
+            "height_inches": 75,
+            "weight_pounds": 220,
+            "draft_year": 2026
+        }))
+        .timeout(Duration::from_secs(5))
+        .send()
+        .await
+        .expect("Failed to create player");
+    let created: serde_json::Value = response.json().await.expect("Failed to parse JSON");
+    let player_id = created["id"].as_str().unwrap();
+
+    // Only the supplied fields change; an explicit null clears college
+    let response = client
+        .patch(format!("{}/api/v1/players/{}", base_url, player_id))
+        .json(&json!({ "weight_pounds": 225, "college": null }))
+        .timeout(Duration::from_secs(5))
+        .send()
+        .await
+        .expect("Failed to patch player");
+    assert_eq!(response.status(), 200);
+    let player: serde_json::Value = response.json().await.expect("Failed to parse JSON");
+    assert_eq!(player["weight_pounds"], 225);
+    assert!(player["college"].is_null());
+    assert_eq!(player["height_inches"], 75);
+    assert_eq!(player["last_name"], "Doe");
+
+    let db_player = sqlx::query!(
+        "SELECT college, height_inches, weight_pounds FROM players WHERE id = $1",
+        uuid::Uuid::parse_str(player_id).unwrap()
+    )
+    .fetch_one(&pool)
+    .await
+    .expect("Player not found in database");
+    assert_eq!(db_player.college, None);
+    assert_eq!(db_player.height_inches, Some(75));
+    assert_eq!(db_player.weight_pounds, Some(225));
+
+    for body in [
+        json!({}),
+        json!({ "height_inches": 40 }),
+        json!({ "first_name": "" }),
+    ] {
+        let response = client
+            .patch(format!("{}/api/v1/players/{}", base_url, player_id))
+            .json(&body)
+            .timeout(Duration::from_secs(5))
+            .send()
+            .await
+            .expect("Failed to patch player");
+        assert_eq!(response.status(), 400, "body {}", body);
+    }
+
+    let response = client
+        .patch(format!(
+            "{}/api/v1/players/{}",
+            base_url,
+            uuid::Uuid::new_v4()
+        ))
+        .json(&json!({ "last_name": "Smith" }))
+        .timeout(Duration::from_secs(5))
+        .send()
+        .await
+        .expect("Failed to patch player");
+    assert_eq!(response.status(), 404);
+}
+
+#[tokio::test]
+async fn test_delete_and_restore_player() {
+    let (base_url, pool) = test_support::spawn_app().await;
+    let client = test_support::create_client();
+    let admin = test_support::create_client_with_role(domain::models::Role::Admin);
+
+    let response = admin
+        .post(format!("{}/api/v1/players", base_url))
+        .json(&json!({
+            "first_name": "John",
+            "last_name": "Doe",
+            "position": "QB",
+            "draft_year": 2026
+        }))
+        .timeout(Duration::from_secs(5))
+        .send()
+        .await
+        .expect("Failed to create player");
+    let created: serde_json::Value = response.json().await.expect("Failed to parse JSON");
+    let player_id = created["id"].as_str().unwrap();
+
+    // Deleting is an admin write, hidden from commissioners
+    let response = client
+        .delete(format!("{}/api/v1/players/{}", base_url, player_id))
+        .timeout(Duration::from_secs(5))
+        .send()
+        .await
+        .expect("Failed to delete player");
+    assert_eq!(response.status(), 404);
+
+    let response = admin
+        .delete(format!("{}/api/v1/players/{}", base_url, player_id))
+        .timeout(Duration::from_secs(5))
+        .send()
+        .await
+        .expect("Failed to delete player");
+    assert_eq!(response.status(), 204);
+
+    // The row stays, but reads no longer see it
+    let deleted_at: Option<chrono::DateTime<chrono::Utc>> =
+        sqlx::query_scalar("SELECT deleted_at FROM players WHERE id = $1")
+            .bind(uuid::Uuid::parse_str(player_id).unwrap())
+            .fetch_one(&pool)
+            .await
+            .expect("Player row was removed");
+    assert!(deleted_at.is_some());
+
+    let response = client
+        .get(format!("{}/api/v1/players/{}", base_url, player_id))
+        .timeout(Duration::from_secs(5))
+        .send()
+        .await
+        .expect("Failed to get player");
+    assert_eq!(response.status(), 404);
+
+    let response = client
+        .get(format!("{}/api/v1/players", base_url))
+        .timeout(Duration::from_secs(5))
+        .send()
+        .await
+        .expect("Failed to list players");
+    let players: Vec<serde_json::Value> = response.json().await.expect("Failed to parse JSON");
+    assert!(players.is_empty());
+
+    let response = admin
+        .delete(format!("{}/api/v1/players/{}", base_url, player_id))
+        .timeout(Duration::from_secs(5))
+        .send()
+        .await
+        .expect("Failed to delete player");
+    assert_eq!(response.status(), 404);
+
+    // Admin routes stay hidden from everyone else
+    let response = client
+        .post(format!(
+            "{}/api/v1/admin/players/{}/restore",
+            base_url, player_id
+        ))
+        .timeout(Duration::from_secs(5))
+        .send()
+        .await
+        .expect("Failed to restore player");
+    assert_eq!(response.status(), 404);
+
+    let response = admin
+        .post(format!(
+            "{}/api/v1/admin/players/{}/restore",
+            base_url, player_id
+        ))
+        .timeout(Duration::from_secs(5))
+        .send()
+        .await
+        .expect("Failed to restore player");
+    assert_eq!(response.status(), 200);
+    let player: serde_json::Value = response.json().await.expect("Failed to parse JSON");
+    assert_eq!(player["id"], player_id);
+
+    let response = client
+        .get(format!("{}/api/v1/players/{}", base_url, player_id))
+        .timeout(Duration::from_secs(5))
+        .send()
+        .await
+        .expect("Failed to get player");
+    assert_eq!(response.status(), 200);
+}
+
+#[tokio::test]
+async fn test_player_injury_fields() {
+    let (base_url, pool) = test_support::spawn_app().await;
+    let client = test_support::create_client_with_role(domain::models::Role::Admin);
+    let last_name = format!("Injured{}", uuid::Uuid::new_v4().simple());
+
+    let response = client
+        .post(format!("{}/api/v1/players", base_url))
+        .json(&json!({
+            "first_name": "Hurt",
+            "last_name": last_name,
+            "position": "RB",
+            "draft_year": 2026,
+            "injury_status": "out",
+            "injury_body_part": "knee",
+            "injury_expected_return": "2026-09-01",
+            "medical_flag": true
+        }))
+        .timeout(Duration::from_secs(5))
+        .send()
+        .await
+        .expect("Failed to create player");
+    assert_eq!(response.status(), 201);
+    let created: serde_json::Value = response.json().await.expect("Failed to parse JSON");
+    let player_id = created["id"].as_str().unwrap();
+    assert_eq!(created["injury_status"], "out");
+    assert_eq!(created["injury_body_part"], "knee");
+    assert_eq!(created["injury_expected_return"], "2026-09-01");
+    assert_eq!(created["medical_flag"], true);
+
+    // Cleared to return: status back to healthy, body part and date cleared
+    let response = client
+        .patch(format!("{}/api/v1/players/{}", base_url, player_id))
+        .json(&json!({
+            "injury_status": "healthy",
+            "injury_body_part": null,
+            "injury_expected_return": null
+        }))
+        .timeout(Duration::from_secs(5))
+        .send()
+        .await
+        .expect("Failed to patch player");
+    assert_eq!(response.status(), 200);
+    let player: serde_json::Value = response.json().await.expect("Failed to parse JSON");
+    assert_eq!(player["injury_status"], "healthy");
+    assert!(player["injury_body_part"].is_null());
+    assert!(player["injury_expected_return"].is_null());
+    assert_eq!(player["medical_flag"], true);
+
+    let db_player = sqlx::query_as::<_, (String, Option<String>, bool)>(
+        "SELECT injury_status, injury_body_part, medical_flag FROM players WHERE id = $1",
+    )
+    .bind(uuid::Uuid::parse_str(player_id).unwrap())
+    .fetch_one(&pool)
+    .await
+    .expect("Player not found in database");
+    assert_eq!(db_player, ("healthy".to_string(), None, true));
+
+    let response = client
+        .patch(format!("{}/api/v1/players/{}", base_url, player_id))
+        .json(&json!({ "injury_status": "probable" }))
+        .timeout(Duration::from_secs(5))
+        .send()
+        .await
+        .expect("Failed to patch player");
+    assert_eq!(response.status(), 422);
+}
+
+#[tokio::test]
+async fn test_player_profile_urls() {
+    let (base_url, pool) = test_support::spawn_app().await;
+    let client = test_support::create_client_with_role(domain::models::Role::Admin);
+    let last_name = format!("Linked{}", uuid::Uuid::new_v4().simple());
+
+    let response = client
+        .post(format!("{}/api/v1/players", base_url))
+        .json(&json!({
+            "first_name": "Pictured",
+            "last_name": last_name,
+            "position": "WR",
+            "draft_year": 2026
+        }))
+        .timeout(Duration::from_secs(5))
+        .send()
+        .await
+        .expect("Failed to create player");
+    assert_eq!(response.status(), 201);
+    let created: serde_json::Value = response.json().await.expect("Failed to parse JSON");
+    let player_id = created["id"].as_str().unwrap();
+    assert!(created["profile_url"].is_null());
+    assert!(created["headshot_url"].is_null());
+
+    // Links are only set by the rankings loader
+    sqlx::query("UPDATE players SET profile_url = $2, headshot_url = $3 WHERE id = $1")
+        .bind(uuid::Uuid::parse_str(player_id).unwrap())
+        .bind("https://www.espn.com/college-football/player/_/id/4432577")
+        .bind("https://a.espncdn.com/i/headshots/college-football/players/full/4432577.png")
+        .execute(&pool)
+        .await
+        .expect("Failed to set profile urls");
+
+    let response = client
+        .get(format!("{}/api/v1/players/{}", base_url, player_id))
+        .timeout(Duration::from_secs(5))
+        .send()
+        .await
+        .expect("Failed to get player");
+    assert_eq!(response.status(), 200);
+    let player: serde_json::Value = response.json().await.expect("Failed to parse JSON");
+    assert_eq!(
+        player["profile_url"],
+        "https://www.espn.com/college-football/player/_/id/4432577"
+    );
+    assert_eq!(
+        player["headshot_url"],
+        "https://a.espncdn.com/i/headshots/college-football/players/full/4432577.png"
+    );
+}
@@ -1,7 +1,5 @@
 //! Acceptance tests for ranking endpoints
 
-mod common;
-
 use serde_json::json;
 use std::time::Duration;
 
@@ -84,8 +82,8 @@ async fn insert_ranking(
 
 #[tokio::test]
 async fn test_list_ranking_sources_empty() {
-    let (base_url, _pool) = common::spawn_app().await;
-    let client = common::create_client();
+    let (base_url, _pool) = test_support::spawn_app().await;
+    let client = test_support::create_client();
 
     let resp = client
         .get(&format!("{}/api/v1/ranking-sources", base_url))
@@ -101,8 +99,8 @@ async fn test_list_ranking_sources_empty() {
 
 #[tokio::test]
 async fn test_list_ranking_sources_with_data() {
-    let (base_url, pool) = common::spawn_app().await;
-    let client = common::create_client();
+    let (base_url, pool) = test_support::spawn_app().await;
+    let client = test_support::create_client();
 
     let source_id = insert_ranking_source(&pool, "Tankathon", Some("https://tankathon.com")).await;
 
@@ -124,8 +122,8 @@ async fn test_list_ranking_sources_with_data() {
 
 #[tokio::test]
 async fn test_get_player_rankings_empty() {
-    let (base_url, _pool) = common::spawn_app().await;
-    let client = common::create_client();
+    let (base_url, _pool) = test_support::spawn_app().await;
+    let client = test_support::create_client_with_role(domain::models::Role::Admin);
 
     let player_id = create_player(&client, &base_url).await;
 
@@ -146,8 +144,8 @@ async fn test_get_player_rankings_empty() {
 
 #[tokio::test]
 async fn test_get_player_rankings_with_data() {
-    let (base_url, pool) = common::spawn_app().await;
-    let client = common::create_client();
+    let (base_url, pool) = test_support::spawn_app().await;
+    let client = test_support::create_client_with_role(domain::models::Role::Admin);
 
     let player_id = create_player(&client, &base_url).await;
     let player_uuid = uuid::Uuid::parse_str(&player_id).unwrap();
@@ -181,8 +179,8 @@ async fn test_get_player_rankings_with_data() {
 
 #[tokio::test]
 async fn test_get_source_rankings() {
-    let (base_url, pool) = common::spawn_app().await;
-    let client = common::create_client();
+    let (base_url, pool) = test_support::spawn_app().await;
+    let client = test_support::create_client_with_role(domain::models::Role::Admin);
 
     let player1_id = create_player(&client, &base_url).await;
     let player2_id = create_player2(&client, &base_url).await;
@@ -217,8 +215,8 @@ async fn test_get_source_rankings() {
 
 #[tokio::test]
 async fn test_get_source_rankings_nonexistent_source_returns_404() {
-    let (base_url, _pool) = common::spawn_app().await;
-    let client = common::create_client();
+    let (base_url, _pool) = test_support::spawn_app().await;
+    let client = test_support::create_client();
 
     let fake_id = uuid::Uuid::new_v4();
 
@@ -237,8 +235,8 @@ async fn test_get_source_rankings_nonexistent_source_returns_404() {
 
 #[tokio::test]
 async fn test_get_all_rankings() {
-    let (base_url, pool) = common::spawn_app().await;
-    let client = common::create_client();
+    let (base_url, pool) = test_support::spawn_app().await;
+    let client = test_support::create_client_with_role(domain::models::Role::Admin);
 
     let player1_id = create_player(&client, &base_url).await;
     let player2_id = create_player2(&client, &base_url).await;
@@ -285,8 +283,8 @@ async fn test_get_all_rankings() {
 
 #[tokio::test]
 async fn test_get_all_rankings_empty() {
-    let (base_url, _pool) = common::spawn_app().await;
-    let client = common::create_client();
+    let (base_url, _pool) = test_support::spawn_app().await;
+    let client = test_support::create_client();
 
     let resp = client
         .get(&format!("{}/api/v1/rankings", base_url))
@@ -302,8 +300,8 @@ async fn test_get_all_rankings_empty() {
 
 #[tokio::test]
 async fn test_ranking_sources_database_consistency() {
-    let (base_url, pool) = common::spawn_app().await;
-    let client = common::create_client();
+    let (base_url, pool) = test_support::spawn_app().await;
+    let client = test_support::create_client();
 
     let source_id = insert_ranking_source(&pool, "Test Source", Some("https://example.com")).await;
 
@@ -334,3 +332,109 @@ async fn test_ranking_sources_database_consistency() {
     );
     assert_eq!(body[0]["id"].as_str().unwrap(), source_id.to_string());
 }
+
+#[tokio::test]
+async fn test_get_consensus_rankings() {
+    let (base_url, pool) = test_support::spawn_app().await;
+    let client = test_support::create_client_with_role(domain::models::Role::Admin);
+
+    let sanders = uuid::Uuid::parse_str(&create_player(&client, &base_url).await).unwrap();
+    let ward = uuid::Uuid::parse_str(&create_player2(&client, &base_url).await).unwrap();
+    let resp = client
+        .post(format!("{}/api/v1/players", base_url))
+        .json(&json!({
+            "first_name": "Arch",
+            "last_name": "Manning",
+            "position": "QB",
+            "college": "Texas",
+            "draft_year": 2027
+        }))
+        .timeout(Duration::from_secs(5))
+        .send()
+        .await
+        .expect("Failed to create player");
+    let body: serde_json::Value = resp.json().await.unwrap();
+    let manning = uuid::Uuid::parse_str(body["id"].as_str().unwrap()).unwrap();
+
+    let tankathon = insert_ranking_source(&pool, "Tankathon", None).await;
+    let walter = insert_ranking_source(&pool, "Walter Football", None).await;
+    let pff = insert_ranking_source(&pool, "PFF", None).await;
+
+    // Sanders: 1, 4, 1 -> average 2.0; Ward: 2, 2, 3 -> average 2.33
+    insert_ranking(&pool, tankathon, sanders, 1).await;
+    insert_ranking(&pool, walter, sanders, 4).await;
+    insert_ranking(&pool, pff, sanders, 1).await;
+    insert_ranking(&pool, tankathon, ward, 2).await;
+    insert_ranking(&pool, walter, ward, 2).await;
+    insert_ranking(&pool, pff, ward, 3).await;
+    insert_ranking(&pool, tankathon, manning, 3).await;
+
+    let resp = client
+        .get(format!("{}/api/v1/rankings/consensus?year=2026", base_url))
+        .timeout(Duration::from_secs(5))
+        .send()
+        .await
+        .expect("Failed to get consensus rankings");
+    assert_eq!(resp.status(), 200);
+    assert_eq!(resp.headers()["x-total-count"], "2");
+    let board: Vec<serde_json::Value> = resp.json().await.unwrap();
+
+    assert_eq!(board[0]["consensus_rank"], 1);
+    assert_eq!(board[0]["last_name"], "Sanders");
+    assert_eq!(board[0]["source_count"], 3);
+    assert_eq!(board[0]["average_rank"], 2.0);
+    assert_eq!(board[0]["best_rank"], 1);
+    assert_eq!(board[0]["worst_rank"], 4);
+    assert!((board[0]["rank_std_dev"].as_f64().unwrap() - 2f64.sqrt()).abs() < 1e-9);
+
+    assert_eq!(board[1]["consensus_rank"], 2);
+    assert_eq!(board[1]["last_name"], "Ward");
+    assert_eq!(board[1]["best_rank"], 2);
+
+    // Without a year every class is on the board, single-source prospects included
+    let resp = client
+        .get(format!("{}/api/v1/rankings/consensus", base_url))
+        .timeout(Duration::from_secs(5))
+        .send()
+        .await
+        .expect("Failed to get consensus rankings");
+    let board: Vec<serde_json::Value> = resp.json().await.unwrap();
+    assert_eq!(board.len(), 3);
+    assert_eq!(board[2]["last_name"], "Manning");
+    assert_eq!(board[2]["source_count"], 1);
+    assert_eq!(board[2]["rank_std_dev"], 0.0);
+}
+
+#[tokio::test]
+async fn test_source_board_pages_by_rank_then_id() {
+    use domain::repositories::ProspectRankingRepository;
+
+    let pool = test_support::setup_test_pool().await;
+    let repo = db::repositories::SqlxProspectRankingRepository::new(pool.clone());
+    let source_id = insert_ranking_source(&pool, "Tankathon", None).await;
+
+    // Two players share a rank, so the ID decides their order
+    for rank in [1, 2, 2, 3, 4] {
+        let player = test_support::PlayerBuilder::new().create(&pool).await;
+        insert_ranking(&pool, source_id, player.id, rank).await;
+    }
+    let mut board = repo.find_by_source(source_id).await.unwrap();
+    board.sort_by_key(|ranking| (ranking.rank, ranking.id));
+    let board: Vec<uuid::Uuid> = board.into_iter().map(|ranking| ranking.id).collect();
+
+    let mut walked = Vec::new();
+    let mut cursor = None;
+    loop {
+        let page = repo
+            .find_by_source_after(source_id, cursor, 2)
+            .await
+            .unwrap();
+        if page.is_empty() {
+            break;
+        }
+        assert!(page.len() <= 2);
+        cursor = page.last().map(|ranking| ranking.id);
+        walked.extend(page.into_iter().map(|ranking| ranking.id));
+    }
+    assert_eq!(walked, board);
+}
@@ -1,5 +1,3 @@
-mod common;
-
 use serde_json::json;
 
 /// Helper to create a player and return their ID
@@ -173,8 +171,8 @@ async fn seed_percentiles(client: &reqwest::Client, base_url: &str) {
 
 #[tokio::test]
 async fn test_get_ras_score_for_player() {
-    let (base_url, _pool) = common::spawn_app_with_seed_key("test-key").await;
-    let client = common::create_client();
+    let (base_url, _pool) = test_support::spawn_app_with_seed_key("test-key").await;
+    let client = test_support::create_client_with_role(domain::models::Role::Admin);
 
     seed_percentiles(&client, &base_url).await;
     let player_id = create_player(&client, &base_url).await;
@@ -218,8 +216,8 @@ async fn test_get_ras_score_for_player() {
 
 #[tokio::test]
 async fn test_ras_score_requires_minimum_measurements() {
-    let (base_url, _pool) = common::spawn_app_with_seed_key("test-key").await;
-    let client = common::create_client();
+    let (base_url, _pool) = test_support::spawn_app_with_seed_key("test-key").await;
+    let client = test_support::create_client_with_role(domain::models::Role::Admin);
 
     seed_percentiles(&client, &base_url).await;
     let player_id = create_player(&client, &base_url).await;
@@ -255,8 +253,8 @@ async fn test_ras_score_requires_minimum_measurements() {
 
 #[tokio::test]
 async fn test_ras_score_includes_category_breakdown() {
-    let (base_url, _pool) = common::spawn_app_with_seed_key("test-key").await;
-    let client = common::create_client();
+    let (base_url, _pool) = test_support::spawn_app_with_seed_key("test-key").await;
+    let client = test_support::create_client_with_role(domain::models::Role::Admin);
 
     seed_percentiles(&client, &base_url).await;
     let player_id = create_player(&client, &base_url).await;
@@ -297,8 +295,8 @@ async fn test_ras_score_includes_category_breakdown() {
 
 #[tokio::test]
 async fn test_ras_elite_athlete() {
-    let (base_url, _pool) = common::spawn_app_with_seed_key("test-key").await;
-    let client = common::create_client();
+    let (base_url, _pool) = test_support::spawn_app_with_seed_key("test-key").await;
+    let client = test_support::create_client_with_role(domain::models::Role::Admin);
 
     seed_percentiles(&client, &base_url).await;
 
@@ -358,8 +356,8 @@ async fn test_ras_elite_athlete() {
 
 #[tokio::test]
 async fn test_ras_player_not_found() {
-    let (base_url, _pool) = common::spawn_app().await;
-    let client = common::create_client();
+    let (base_url, _pool) = test_support::spawn_app().await;
+    let client = test_support::create_client();
 
     let fake_id = uuid::Uuid::new_v4();
     let resp = client
@@ -372,8 +370,8 @@ async fn test_ras_player_not_found() {
 
 #[tokio::test]
 async fn test_get_all_ras_scores_empty() {
-    let (base_url, _pool) = common::spawn_app().await;
-    let client = common::create_client();
+    let (base_url, _pool) = test_support::spawn_app().await;
+    let client = test_support::create_client();
 
     let resp = client
         .get(format!("{}/api/v1/combine-results/ras", base_url))
@@ -391,8 +389,8 @@ async fn test_get_all_ras_scores_empty() {
 
 #[tokio::test]
 async fn test_get_all_ras_scores_with_data() {
-    let (base_url, _pool) = common::spawn_app_with_seed_key("test-key").await;
-    let client = common::create_client();
+    let (base_url, _pool) = test_support::spawn_app_with_seed_key("test-key").await;
+    let client = test_support::create_client_with_role(domain::models::Role::Admin);
 
     seed_percentiles(&client, &base_url).await;
     let player_id = create_player(&client, &base_url).await;
@@ -424,8 +422,8 @@ async fn test_get_all_ras_scores_with_data() {
 
 #[tokio::test]
 async fn test_get_all_ras_scores_insufficient_measurements() {
-    let (base_url, _pool) = common::spawn_app_with_seed_key("test-key").await;
-    let client = common::create_client();
+    let (base_url, _pool) = test_support::spawn_app_with_seed_key("test-key").await;
+    let client = test_support::create_client_with_role(domain::models::Role::Admin);
 
     seed_percentiles(&client, &base_url).await;
     let player_id = create_player(&client, &base_url).await;
@@ -463,3 +461,56 @@ async fn test_get_all_ras_scores_insufficient_measurements() {
         "Should have explanation for insufficient measurements"
     );
 }
+
+#[tokio::test]
+async fn test_get_player_percentiles() {
+    let (base_url, _pool) = test_support::spawn_app_with_seed_key("test-key").await;
+    let client = test_support::create_client_with_role(domain::models::Role::Admin);
+
+    seed_percentiles(&client, &base_url).await;
+    let player_id = create_player(&client, &base_url).await;
+
+    // No combine results yet
+    let resp = client
+        .get(format!(
+            "{}/api/v1/players/{}/percentiles",
+            base_url, player_id
+        ))
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(resp.status(), 404);
+
+    create_combine_results(&client, &base_url, player_id).await;
+
+    let resp = client
+        .get(format!(
+            "{}/api/v1/players/{}/percentiles",
+            base_url, player_id
+        ))
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(resp.status(), 200);
+
+    let profile: serde_json::Value = resp.json().await.unwrap();
+    assert_eq!(profile["player_id"], player_id.to_string());
+    assert_eq!(profile["position_group"], "CB");
+
+    // Height, weight and every combine measurement, frame measurements included
+    let measurements = profile["measurements"].as_array().unwrap();
+    assert_eq!(measurements.len(), 13);
+    let percentile_of = |name: &str| {
+        measurements
+            .iter()
+            .find(|m| m["measurement"] == name)
+            .unwrap_or_else(|| panic!("Missing {}", name))["percentile"]
+            .as_f64()
+            .unwrap()
+    };
+    assert!((percentile_of("hand_size") - 50.0).abs() < 1e-9);
+    assert!((percentile_of("wingspan") - 60.0).abs() < 1e-9);
+    // Timed events are inverted: 4.38 sits between p20 and p30, so 70-80
+    let forty = percentile_of("forty_yard_dash");
+    assert!(forty > 70.0 && forty < 80.0, "got {}", forty);
+}
@@ -0,0 +1,82 @@
+//! REST API rate limiting acceptance tests
+
+use reqwest::StatusCode;
+use serde_json::Value;
+use std::time::Duration;
+
+use api::rate_limit::RateLimitSettings;
+
+const API_KEY: &str = "test-rate-limit-key";
+
+#[tokio::test]
+async fn test_requests_over_limit_get_429_with_retry_after() {
+    let pool = test_support::setup_test_pool().await;
+
+    let state =
+        api::AppState::new(pool, Some(API_KEY.to_string())).with_rate_limit(RateLimitSettings {
+            ip_requests: 2,
+            api_key_requests: 3,
+            window: Duration::from_secs(60),
+            trust_proxy: false,
+        });
+    let base_url = test_support::spawn_app_with_state(state).await;
+    let client = test_support::create_client();
+
+    for _ in 0..2 {
+        let response = client
+            .get(format!("{}/api/v1/teams", base_url))
+            .send()
+            .await
+            .expect("Failed to list teams");
+        assert_eq!(response.status(), StatusCode::OK);
+    }
+
+    let response = client
+        .get(format!("{}/api/v1/teams", base_url))
+        .send()
+        .await
+        .expect("Failed to list teams");
+    assert_eq!(response.status(), StatusCode::TOO_MANY_REQUESTS);
+    let retry_after: u64 = response.headers()["retry-after"]
+        .to_str()
+        .unwrap()
+        .parse()
+        .unwrap();
+    assert!((1..=60).contains(&retry_after));
+    let body: Value = response.json().await.unwrap();
+    assert_eq!(body["status"], 429);
+
+    // Health checks sit outside the API routes and are never limited
+    let response = client
+        .get(format!("{}/health", base_url))
+        .send()
+        .await
+        .expect("Failed to check health");
+    assert_eq!(response.status(), StatusCode::OK);
+
+    // A valid API key has its own allowance; a wrong one shares the IP's
+    for _ in 0..3 {
+        let response = client
+            .get(format!("{}/api/v1/teams", base_url))
+            .header("X-Seed-Api-Key", API_KEY)
+            .send()
+            .await
+            .expect("Failed to list teams");
+        assert_eq!(response.status(), StatusCode::OK);
+    }
+    let response = client
+        .get(format!("{}/api/v1/teams", base_url))
+        .header("X-Seed-Api-Key", API_KEY)
+        .send()
+        .await
+        .expect("Failed to list teams");
+    assert_eq!(response.status(), StatusCode::TOO_MANY_REQUESTS);
+
+    let response = client
+        .get(format!("{}/api/v1/teams", base_url))
+        .header("X-Seed-Api-Key", "not-the-key")
+        .send()
+        .await
+        .expect("Failed to list teams");
+    assert_eq!(response.status(), StatusCode::TOO_MANY_REQUESTS);
+}
@@ -4,8 +4,6 @@
 //! the picks come from the draft order JSON data (with trade metadata, compensatory
 //! picks, etc.) rather than from a simple standings-based team ordering.
 
-mod common;
-
 use serde_json::json;
 use std::time::Duration;
 use uuid::Uuid;
@@ -145,8 +143,8 @@ async fn seed_all_nfl_teams(client: &reqwest::Client, base_url: &str) {
 
 #[tokio::test]
 async fn test_realistic_draft_initialize_uses_trade_data() {
-    let (base_url, pool) = common::spawn_app().await;
-    let client = common::create_client();
+    let (base_url, pool) = test_support::spawn_app().await;
+    let client = test_support::create_client_with_role(domain::models::Role::Admin);
 
     // 1. Seed all 32 NFL teams
     seed_all_nfl_teams(&client, &base_url).await;
@@ -289,8 +287,8 @@ async fn test_realistic_draft_initialize_uses_trade_data() {
 
 #[tokio::test]
 async fn test_custom_draft_initialize_unchanged() {
-    let (base_url, _pool) = common::spawn_app().await;
-    let client = common::create_client();
+    let (base_url, _pool) = test_support::spawn_app().await;
+    let client = test_support::create_client_with_role(domain::models::Role::Admin);
 
     // Create two teams
     for (name, abbr, city) in [("Team A", "TMA", "City A"), ("Team B", "TMB", "City B")] {
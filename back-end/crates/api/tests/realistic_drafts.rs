@@ -1,15 +1,13 @@
 //! Realistic draft flow acceptance tests
 
-mod common;
-
 use serde_json::json;
 use std::time::Duration;
 use uuid::Uuid;
 
 #[tokio::test]
 async fn test_create_realistic_draft() {
-    let (base_url, pool) = common::spawn_app().await;
-    let client = common::create_client();
+    let (base_url, pool) = test_support::spawn_app().await;
+    let client = test_support::create_client();
 
     // Create draft without picks_per_round → realistic draft
     let response = client
@@ -49,8 +47,8 @@ async fn test_create_realistic_draft() {
 
 #[tokio::test]
 async fn test_initialize_picks_for_custom_draft_with_two_teams() {
-    let (base_url, pool) = common::spawn_app().await;
-    let client = common::create_client();
+    let (base_url, pool) = test_support::spawn_app().await;
+    let client = test_support::create_client_with_role(domain::models::Role::Admin);
 
     // Create two teams
     for (name, abbr, city) in [("Team A", "TMA", "City A"), ("Team B", "TMB", "City B")] {
@@ -114,8 +112,8 @@ async fn test_initialize_picks_for_custom_draft_with_two_teams() {
 
 #[tokio::test]
 async fn test_realistic_draft_picks_with_trade_metadata() {
-    let (base_url, pool) = common::spawn_app().await;
-    let client = common::create_client();
+    let (base_url, pool) = test_support::spawn_app().await;
+    let client = test_support::create_client();
 
     // Create two teams
     let team1_id = Uuid::new_v4();
@@ -182,8 +180,8 @@ async fn test_realistic_draft_picks_with_trade_metadata() {
 
 #[tokio::test]
 async fn test_realistic_draft_pick_not_traded() {
-    let (base_url, pool) = common::spawn_app().await;
-    let client = common::create_client();
+    let (base_url, pool) = test_support::spawn_app().await;
+    let client = test_support::create_client();
 
     // Create team
     let team_id = Uuid::new_v4();
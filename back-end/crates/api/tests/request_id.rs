@@ -0,0 +1,71 @@
+//! X-Request-Id propagation acceptance tests
+
+use reqwest::StatusCode;
+use serde_json::Value;
+use uuid::Uuid;
+
+#[tokio::test]
+async fn test_request_id_is_generated_and_echoed() {
+    let (base_url, _pool) = test_support::spawn_app().await;
+    let client = test_support::create_client();
+
+    let response = client
+        .get(format!("{}/api/v1/teams", base_url))
+        .send()
+        .await
+        .expect("Failed to list teams");
+    assert_eq!(response.status(), StatusCode::OK);
+    let generated = response.headers()["x-request-id"].to_str().unwrap();
+    assert!(Uuid::parse_str(generated).is_ok());
+
+    let response = client
+        .get(format!("{}/api/v1/teams", base_url))
+        .header("X-Request-Id", "gateway-req-42")
+        .send()
+        .await
+        .expect("Failed to list teams");
+    assert_eq!(response.headers()["x-request-id"], "gateway-req-42");
+
+    // Ids that could pollute the logs are replaced
+    let response = client
+        .get(format!("{}/api/v1/teams", base_url))
+        .header("X-Request-Id", "not allowed")
+        .send()
+        .await
+        .expect("Failed to list teams");
+    assert_ne!(response.headers()["x-request-id"], "not allowed");
+}
+
+#[tokio::test]
+async fn test_error_body_includes_request_id() {
+    let (base_url, _pool) = test_support::spawn_app().await;
+
+    let response = test_support::create_client()
+        .get(format!("{}/api/v1/teams/{}", base_url, Uuid::new_v4()))
+        .header("X-Request-Id", "support-ticket-7")
+        .send()
+        .await
+        .expect("Failed to fetch team");
+    assert_eq!(response.status(), StatusCode::NOT_FOUND);
+    assert_eq!(response.headers()["x-request-id"], "support-ticket-7");
+    let body: Value = response.json().await.unwrap();
+    assert_eq!(body["request_id"], "support-ticket-7");
+
+    // Errors raised by middleware carry it too
+    let response = test_support::create_anonymous_client()
+        .post(format!(
+            "{}/api/v1/sessions/{}/start",
+            base_url,
+            Uuid::new_v4()
+        ))
+        .send()
+        .await
+        .expect("Failed to start session");
+    assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+    let header = response.headers()["x-request-id"]
+        .to_str()
+        .unwrap()
+        .to_string();
+    let body: Value = response.json().await.unwrap();
+    assert_eq!(body["request_id"], header);
+}
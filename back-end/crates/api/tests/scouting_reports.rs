@@ -1,16 +1,12 @@
 //! Scouting Reports CRUD acceptance tests
 
-mod common;
-
 use serde_json::json;
 use std::time::Duration;
 
 #[tokio::test]
 async fn test_create_and_get_scouting_report() {
-    let (base_url, pool) = common::spawn_app().await;
-    let client = common::create_client();
-
-    common::cleanup_database(&pool).await;
+    let (base_url, pool) = test_support::spawn_app().await;
+    let client = test_support::create_client_with_role(domain::models::Role::Admin);
 
     // Create a team
     let team_response = client
@@ -122,16 +118,12 @@ async fn test_create_and_get_scouting_report() {
         report["injury_concern"].as_bool().unwrap(),
         db_report.injury_concern
     );
-
-    common::cleanup_database(&pool).await;
 }
 
 #[tokio::test]
 async fn test_get_team_scouting_reports() {
-    let (base_url, pool) = common::spawn_app().await;
-    let client = common::create_client();
-
-    common::cleanup_database(&pool).await;
+    let (base_url, pool) = test_support::spawn_app().await;
+    let client = test_support::create_client_with_role(domain::models::Role::Admin);
 
     // Create a team
     let team_response = client
@@ -240,16 +232,12 @@ async fn test_get_team_scouting_reports() {
     // Verify ordering (highest grade first)
     assert_eq!(reports_list[0]["grade"], 9.0);
     assert_eq!(reports_list[1]["grade"], 7.5);
-
-    common::cleanup_database(&pool).await;
 }
 
 #[tokio::test]
 async fn test_get_player_scouting_reports() {
-    let (base_url, pool) = common::spawn_app().await;
-    let client = common::create_client();
-
-    common::cleanup_database(&pool).await;
+    let (base_url, pool) = test_support::spawn_app().await;
+    let client = test_support::create_client_with_role(domain::models::Role::Admin);
 
     // Create two teams
     let team1_response = client
@@ -355,16 +343,12 @@ async fn test_get_player_scouting_reports() {
     .expect("Failed to count scouting reports");
 
     assert_eq!(db_count.count, Some(2));
-
-    common::cleanup_database(&pool).await;
 }
 
 #[tokio::test]
 async fn test_update_scouting_report() {
-    let (base_url, pool) = common::spawn_app().await;
-    let client = common::create_client();
-
-    common::cleanup_database(&pool).await;
+    let (base_url, pool) = test_support::spawn_app().await;
+    let client = test_support::create_client_with_role(domain::models::Role::Admin);
 
     // Create team and player
     let team_response = client
@@ -456,16 +440,12 @@ async fn test_update_scouting_report() {
     );
     assert_eq!(db_report.fit_grade, Some("A".to_string()));
     assert_eq!(db_report.injury_concern, true);
-
-    common::cleanup_database(&pool).await;
 }
 
 #[tokio::test]
 async fn test_delete_scouting_report() {
-    let (base_url, pool) = common::spawn_app().await;
-    let client = common::create_client();
-
-    common::cleanup_database(&pool).await;
+    let (base_url, pool) = test_support::spawn_app().await;
+    let client = test_support::create_client_with_role(domain::models::Role::Admin);
 
     // Create team and player
     let team_response = client
@@ -530,16 +510,15 @@ async fn test_delete_scouting_report() {
 
     assert_eq!(delete_response.status(), 204);
 
-    // Verify deletion in database
-    let db_result = sqlx::query!(
-        "SELECT id FROM scouting_reports WHERE id = $1",
-        uuid::Uuid::parse_str(report_id).unwrap()
-    )
-    .fetch_optional(&pool)
-    .await
-    .expect("Database query failed");
+    // The row is kept and marked deleted
+    let deleted_at: Option<chrono::DateTime<chrono::Utc>> =
+        sqlx::query_scalar("SELECT deleted_at FROM scouting_reports WHERE id = $1")
+            .bind(uuid::Uuid::parse_str(report_id).unwrap())
+            .fetch_one(&pool)
+            .await
+            .expect("Database query failed");
 
-    assert!(db_result.is_none());
+    assert!(deleted_at.is_some());
 
     // Verify 404 on subsequent GET
     let get_response = client
@@ -553,16 +532,12 @@ async fn test_delete_scouting_report() {
         .expect("Failed to get scouting report");
 
     assert_eq!(get_response.status(), 404);
-
-    common::cleanup_database(&pool).await;
 }
 
 #[tokio::test]
 async fn test_duplicate_team_player_error() {
-    let (base_url, pool) = common::spawn_app().await;
-    let client = common::create_client();
-
-    common::cleanup_database(&pool).await;
+    let (base_url, pool) = test_support::spawn_app().await;
+    let client = test_support::create_client_with_role(domain::models::Role::Admin);
 
     // Create team and player
     let team_response = client
@@ -639,6 +614,126 @@ async fn test_duplicate_team_player_error() {
     .expect("Failed to count scouting reports");
 
     assert_eq!(db_count.count, Some(1));
+}
 
-    common::cleanup_database(&pool).await;
+#[tokio::test]
+async fn test_search_scouting_reports() {
+    let (base_url, _pool) = test_support::spawn_app().await;
+    let client = test_support::create_client_with_role(domain::models::Role::Admin);
+
+    let mut team_ids = Vec::new();
+    for (name, abbreviation) in [("Search Bears", "SBR"), ("Search Lions", "SLN")] {
+        let response = client
+            .post(format!("{}/api/v1/teams", base_url))
+            .json(&json!({
+                "name": name,
+                "abbreviation": abbreviation,
+                "city": "Search City",
+                "conference": "NFC",
+                "division": "NFC North"
+            }))
+            .timeout(Duration::from_secs(5))
+            .send()
+            .await
+            .expect("Failed to create team");
+        let team: serde_json::Value = response.json().await.expect("Failed to parse JSON");
+        team_ids.push(team["id"].as_str().unwrap().to_string());
+    }
+
+    // (team, last name, grade, fit grade, injury concern)
+    let reports = [
+        (0, "Ace", 9.1, "A", false),
+        (0, "Brittle", 8.4, "A", true),
+        (0, "Middling", 6.0, "C", false),
+        (1, "Ace", 7.5, "B", false),
+    ];
+    let mut player_ids = std::collections::HashMap::new();
+    for (team, last_name, grade, fit_grade, injury_concern) in reports {
+        if !player_ids.contains_key(last_name) {
+            let response = client
+                .post(format!("{}/api/v1/players", base_url))
+                .json(&json!({
+                    "first_name": "Board",
+                    "last_name": last_name,
+                    "position": "CB",
+                    "draft_year": 2026
+                }))
+                .timeout(Duration::from_secs(5))
+                .send()
+                .await
+                .expect("Failed to create player");
+            let player: serde_json::Value = response.json().await.expect("Failed to parse JSON");
+            player_ids.insert(last_name, player["id"].as_str().unwrap().to_string());
+        }
+
+        let response = client
+            .post(format!("{}/api/v1/scouting-reports", base_url))
+            .json(&json!({
+                "player_id": player_ids[last_name],
+                "team_id": team_ids[team],
+                "grade": grade,
+                "fit_grade": fit_grade,
+                "injury_concern": injury_concern
+            }))
+            .timeout(Duration::from_secs(5))
+            .send()
+            .await
+            .expect("Failed to create scouting report");
+        assert_eq!(response.status(), 201);
+    }
+
+    let grades = |reports: Vec<serde_json::Value>| -> Vec<f64> {
+        reports
+            .iter()
+            .map(|r| r["grade"].as_f64().unwrap())
+            .collect()
+    };
+
+    // A team's board, highest grade first, one page at a time
+    let response = client
+        .get(format!(
+            "{}/api/v1/scouting-reports?team_id={}&per_page=2",
+            base_url, team_ids[0]
+        ))
+        .timeout(Duration::from_secs(5))
+        .send()
+        .await
+        .expect("Failed to search scouting reports");
+    assert_eq!(response.status(), 200);
+    assert_eq!(response.headers()["x-total-count"], "3");
+    let reports: Vec<serde_json::Value> = response.json().await.expect("Failed to parse JSON");
+    assert_eq!(grades(reports), [9.1, 8.4]);
+
+    let response = client
+        .get(format!(
+            "{}/api/v1/scouting-reports?min_grade=7.0&fit_grade=A&concerns=false",
+            base_url
+        ))
+        .timeout(Duration::from_secs(5))
+        .send()
+        .await
+        .expect("Failed to search scouting reports");
+    let reports: Vec<serde_json::Value> = response.json().await.expect("Failed to parse JSON");
+    assert_eq!(grades(reports), [9.1]);
+
+    let response = client
+        .get(format!(
+            "{}/api/v1/scouting-reports?concerns=true",
+            base_url
+        ))
+        .timeout(Duration::from_secs(5))
+        .send()
+        .await
+        .expect("Failed to search scouting reports");
+    let reports: Vec<serde_json::Value> = response.json().await.expect("Failed to parse JSON");
+    assert_eq!(reports.len(), 1);
+    assert_eq!(reports[0]["player_id"], player_ids["Brittle"].as_str());
+
+    let response = client
+        .get(format!("{}/api/v1/scouting-reports?min_grade=11", base_url))
+        .timeout(Duration::from_secs(5))
+        .send()
+        .await
+        .expect("Failed to search scouting reports");
+    assert_eq!(response.status(), 400);
 }
@@ -1,14 +1,12 @@
 //! Admin seed endpoint acceptance tests
 
-mod common;
-
 use std::time::Duration;
 
 #[tokio::test]
 async fn test_seed_teams_404_when_no_key_configured() {
     // Standard spawn_app() passes None for seed_api_key
-    let (base_url, _pool) = common::spawn_app().await;
-    let client = common::create_client();
+    let (base_url, _pool) = test_support::spawn_app().await;
+    let client = test_support::create_client();
 
     let response = client
         .post(&format!("{}/api/v1/admin/seed-teams", base_url))
@@ -22,8 +20,8 @@ async fn test_seed_teams_404_when_no_key_configured() {
 
 #[tokio::test]
 async fn test_seed_teams_401_with_wrong_key() {
-    let (base_url, _pool) = common::spawn_app_with_seed_key("correct-key").await;
-    let client = common::create_client();
+    let (base_url, _pool) = test_support::spawn_app_with_seed_key("correct-key").await;
+    let client = test_support::create_client();
 
     let response = client
         .post(&format!("{}/api/v1/admin/seed-teams", base_url))
@@ -38,8 +36,8 @@ async fn test_seed_teams_401_with_wrong_key() {
 
 #[tokio::test]
 async fn test_seed_teams_401_with_missing_key() {
-    let (base_url, _pool) = common::spawn_app_with_seed_key("correct-key").await;
-    let client = common::create_client();
+    let (base_url, _pool) = test_support::spawn_app_with_seed_key("correct-key").await;
+    let client = test_support::create_client();
 
     // No X-Seed-Api-Key header
     let response = client
@@ -54,8 +52,8 @@ async fn test_seed_teams_401_with_missing_key() {
 
 #[tokio::test]
 async fn test_seed_teams_succeeds() {
-    let (base_url, pool) = common::spawn_app_with_seed_key("test-seed-key").await;
-    let client = common::create_client();
+    let (base_url, pool) = test_support::spawn_app_with_seed_key("test-seed-key").await;
+    let client = test_support::create_client();
 
     let response = client
         .post(&format!("{}/api/v1/admin/seed-teams", base_url))
@@ -83,8 +81,8 @@ async fn test_seed_teams_succeeds() {
 
 #[tokio::test]
 async fn test_seed_players_succeeds() {
-    let (base_url, pool) = common::spawn_app_with_seed_key("test-seed-key").await;
-    let client = common::create_client();
+    let (base_url, pool) = test_support::spawn_app_with_seed_key("test-seed-key").await;
+    let client = test_support::create_client();
 
     let response = client
         .post(&format!("{}/api/v1/admin/seed-players", base_url))
@@ -112,8 +110,8 @@ async fn test_seed_players_succeeds() {
 
 #[tokio::test]
 async fn test_seed_team_seasons_succeeds() {
-    let (base_url, pool) = common::spawn_app_with_seed_key("test-seed-key").await;
-    let client = common::create_client();
+    let (base_url, pool) = test_support::spawn_app_with_seed_key("test-seed-key").await;
+    let client = test_support::create_client();
 
     // Seed teams first (team_seasons requires teams)
     let seed_teams_response = client
@@ -152,8 +150,8 @@ async fn test_seed_team_seasons_succeeds() {
 
 #[tokio::test]
 async fn test_seed_teams_idempotent() {
-    let (base_url, pool) = common::spawn_app_with_seed_key("test-seed-key").await;
-    let client = common::create_client();
+    let (base_url, pool) = test_support::spawn_app_with_seed_key("test-seed-key").await;
+    let client = test_support::create_client();
 
     // First seed
     let first_response = client
@@ -205,3 +203,108 @@ async fn test_seed_teams_idempotent() {
         .unwrap();
     assert_eq!(count_after_first, count_after_second);
 }
+
+#[tokio::test]
+async fn test_seed_teams_from_posted_file_upserts() {
+    let (base_url, pool) = test_support::spawn_app_with_seed_key("test-seed-key").await;
+    let client = test_support::create_client();
+    let teams_file = |city: &str| {
+        serde_json::json!({
+            "meta": {
+                "version": "1.0.0",
+                "last_updated": "2026-10-17",
+                "sources": ["Test"],
+                "total_teams": 1
+            },
+            "teams": [{
+                "name": "Buffalo Bills",
+                "abbreviation": "BUF",
+                "city": city,
+                "conference": "AFC",
+                "division": "AFC East"
+            }]
+        })
+    };
+
+    let response = client
+        .post(format!("{}/api/v1/admin/seed-teams", base_url))
+        .header("X-Seed-Api-Key", "test-seed-key")
+        .json(&teams_file("Buffalo"))
+        .timeout(Duration::from_secs(30))
+        .send()
+        .await
+        .expect("Failed to send request");
+    assert_eq!(response.status(), 200);
+    let body: serde_json::Value = response.json().await.unwrap();
+    assert_eq!(body["success_count"], 1);
+
+    let response = client
+        .post(format!("{}/api/v1/admin/seed-teams?mode=upsert", base_url))
+        .header("X-Seed-Api-Key", "test-seed-key")
+        .json(&teams_file("Orchard Park"))
+        .timeout(Duration::from_secs(30))
+        .send()
+        .await
+        .expect("Failed to send request");
+    assert_eq!(response.status(), 200);
+    let body: serde_json::Value = response.json().await.unwrap();
+    assert_eq!(body["success_count"], 1);
+    assert_eq!(body["skipped_count"], 0);
+
+    let cities: Vec<String> = sqlx::query_scalar("SELECT city FROM teams")
+        .fetch_all(&pool)
+        .await
+        .unwrap();
+    assert_eq!(cities, vec!["Orchard Park".to_string()]);
+}
+
+#[tokio::test]
+async fn test_seed_players_rejects_malformed_file() {
+    let (base_url, _pool) = test_support::spawn_app_with_seed_key("test-seed-key").await;
+    let client = test_support::create_client();
+
+    let response = client
+        .post(format!("{}/api/v1/admin/seed-players", base_url))
+        .header("X-Seed-Api-Key", "test-seed-key")
+        .json(&serde_json::json!({ "players": [] }))
+        .timeout(Duration::from_secs(10))
+        .send()
+        .await
+        .expect("Failed to send request");
+
+    assert_eq!(response.status(), 400);
+}
+
+#[tokio::test]
+async fn test_seed_team_needs_succeeds() {
+    let (base_url, pool) = test_support::spawn_app_with_seed_key("test-seed-key").await;
+    let client = test_support::create_client();
+
+    let seed_teams_response = client
+        .post(format!("{}/api/v1/admin/seed-teams", base_url))
+        .header("X-Seed-Api-Key", "test-seed-key")
+        .timeout(Duration::from_secs(30))
+        .send()
+        .await
+        .expect("Failed to seed teams");
+    assert_eq!(seed_teams_response.status(), 200);
+
+    let response = client
+        .post(format!("{}/api/v1/admin/seed-team-needs", base_url))
+        .header("X-Seed-Api-Key", "test-seed-key")
+        .timeout(Duration::from_secs(30))
+        .send()
+        .await
+        .expect("Failed to send request");
+    assert_eq!(response.status(), 200);
+
+    let body: serde_json::Value = response.json().await.unwrap();
+    let created = body["success_count"].as_u64().unwrap();
+    assert!(created > 0, "Expected some team needs to be seeded");
+
+    let db_count: i64 = sqlx::query_scalar("SELECT COUNT(*) FROM team_needs")
+        .fetch_one(&pool)
+        .await
+        .unwrap();
+    assert_eq!(db_count as u64, created);
+}
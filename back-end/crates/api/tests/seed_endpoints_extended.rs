@@ -1,14 +1,12 @@
 //! Extended admin seed endpoint acceptance tests
-//! Covers: seed-rankings, seed-combine-percentiles, seed-combine-data
-
-mod common;
+//! Covers: seed-rankings, seed-combine-percentiles, seed-combine-data, seed-draft-order
 
 use std::time::Duration;
 
 #[tokio::test]
 async fn test_seed_rankings_succeeds() {
-    let (base_url, pool) = common::spawn_app_with_seed_key("test-seed-key").await;
-    let client = common::create_client();
+    let (base_url, pool) = test_support::spawn_app_with_seed_key("test-seed-key").await;
+    let client = test_support::create_client();
 
     // Seed players first (rankings match by player name)
     let seed_players_response = client
@@ -71,8 +69,8 @@ async fn test_seed_rankings_succeeds() {
 
 #[tokio::test]
 async fn test_seed_rankings_401_without_key() {
-    let (base_url, _pool) = common::spawn_app_with_seed_key("correct-key").await;
-    let client = common::create_client();
+    let (base_url, _pool) = test_support::spawn_app_with_seed_key("correct-key").await;
+    let client = test_support::create_client();
 
     let response = client
         .post(&format!("{}/api/v1/admin/seed-rankings", base_url))
@@ -84,10 +82,62 @@ async fn test_seed_rankings_401_without_key() {
     assert_eq!(response.status(), 401);
 }
 
+#[tokio::test]
+async fn test_seed_rankings_from_posted_file() {
+    let (base_url, pool) = test_support::spawn_app_with_seed_key("test-seed-key").await;
+    let client = test_support::create_client();
+
+    // Seed teams (new prospects get a scouting report per team)
+    let seed_teams_response = client
+        .post(format!("{}/api/v1/admin/seed-teams", base_url))
+        .header("X-Seed-Api-Key", "test-seed-key")
+        .timeout(Duration::from_secs(30))
+        .send()
+        .await
+        .expect("Failed to seed teams");
+    assert_eq!(seed_teams_response.status(), 200);
+
+    let response = client
+        .post(format!("{}/api/v1/admin/seed-rankings", base_url))
+        .header("X-Seed-Api-Key", "test-seed-key")
+        .json(&serde_json::json!({
+            "meta": {
+                "version": "1.0.0",
+                "source": "espn",
+                "source_url": "https://www.espn.com/nfl/draft/bestavailable",
+                "draft_year": 2026,
+                "scraped_at": "2026-10-17",
+                "total_prospects": 1
+            },
+            "rankings": [{
+                "rank": 1,
+                "first_name": "Fernando",
+                "last_name": "Mendoza",
+                "position": "QB",
+                "school": "Indiana"
+            }]
+        }))
+        .timeout(Duration::from_secs(30))
+        .send()
+        .await
+        .expect("Failed to seed rankings");
+
+    assert_eq!(response.status(), 200);
+    let body: serde_json::Value = response.json().await.unwrap();
+    assert_eq!(body["success_count"], 1);
+
+    // Only the posted board is loaded, under its own source
+    let sources: Vec<String> = sqlx::query_scalar("SELECT name FROM ranking_sources")
+        .fetch_all(&pool)
+        .await
+        .unwrap();
+    assert_eq!(sources, vec!["espn".to_string()]);
+}
+
 #[tokio::test]
 async fn test_seed_combine_percentiles_succeeds() {
-    let (base_url, pool) = common::spawn_app_with_seed_key("test-seed-key").await;
-    let client = common::create_client();
+    let (base_url, pool) = test_support::spawn_app_with_seed_key("test-seed-key").await;
+    let client = test_support::create_client();
 
     let response = client
         .post(&format!(
@@ -118,8 +168,8 @@ async fn test_seed_combine_percentiles_succeeds() {
 
 #[tokio::test]
 async fn test_seed_combine_percentiles_401_without_key() {
-    let (base_url, _pool) = common::spawn_app_with_seed_key("correct-key").await;
-    let client = common::create_client();
+    let (base_url, _pool) = test_support::spawn_app_with_seed_key("correct-key").await;
+    let client = test_support::create_client();
 
     let response = client
         .post(&format!(
@@ -136,8 +186,8 @@ async fn test_seed_combine_percentiles_401_without_key() {
 
 #[tokio::test]
 async fn test_seed_combine_data_succeeds() {
-    let (base_url, pool) = common::spawn_app_with_seed_key("test-seed-key").await;
-    let client = common::create_client();
+    let (base_url, pool) = test_support::spawn_app_with_seed_key("test-seed-key").await;
+    let client = test_support::create_client();
 
     // Seed players first (combine data matches by player name)
     let seed_players_response = client
@@ -176,8 +226,8 @@ async fn test_seed_combine_data_succeeds() {
 
 #[tokio::test]
 async fn test_seed_combine_data_401_without_key() {
-    let (base_url, _pool) = common::spawn_app_with_seed_key("correct-key").await;
-    let client = common::create_client();
+    let (base_url, _pool) = test_support::spawn_app_with_seed_key("correct-key").await;
+    let client = test_support::create_client();
 
     let response = client
         .post(&format!("{}/api/v1/admin/seed-combine-data", base_url))
@@ -188,3 +238,95 @@ async fn test_seed_combine_data_401_without_key() {
 
     assert_eq!(response.status(), 401);
 }
+
+#[tokio::test]
+async fn test_seed_combine_data_rejects_malformed_file() {
+    let (base_url, _pool) = test_support::spawn_app_with_seed_key("test-seed-key").await;
+    let client = test_support::create_client();
+
+    let response = client
+        .post(format!("{}/api/v1/admin/seed-combine-data", base_url))
+        .header("X-Seed-Api-Key", "test-seed-key")
+        .json(&serde_json::json!({ "combine_results": [] }))
+        .timeout(Duration::from_secs(10))
+        .send()
+        .await
+        .expect("Failed to send request");
+
+    assert_eq!(response.status(), 400);
+}
+
+#[tokio::test]
+async fn test_seed_draft_order_succeeds() {
+    let (base_url, pool) = test_support::spawn_app_with_seed_key("test-seed-key").await;
+    let client = test_support::create_client();
+
+    // Seed teams first (picks match by team abbreviation)
+    let seed_teams_response = client
+        .post(format!("{}/api/v1/admin/seed-teams", base_url))
+        .header("X-Seed-Api-Key", "test-seed-key")
+        .timeout(Duration::from_secs(30))
+        .send()
+        .await
+        .expect("Failed to seed teams");
+    assert_eq!(seed_teams_response.status(), 200);
+
+    let response = client
+        .post(format!("{}/api/v1/admin/seed-draft-order", base_url))
+        .header("X-Seed-Api-Key", "test-seed-key")
+        .timeout(Duration::from_secs(30))
+        .send()
+        .await
+        .expect("Failed to seed draft order");
+
+    assert_eq!(response.status(), 200);
+
+    let body: serde_json::Value = response.json().await.unwrap();
+    assert_eq!(
+        body["error_count"], 0,
+        "Unexpected errors: {}",
+        body["errors"]
+    );
+    let created = body["success_count"].as_u64().unwrap();
+    assert!(created > 0, "Expected draft picks to be created");
+
+    let db_count: i64 = sqlx::query_scalar(
+        "SELECT COUNT(*) FROM draft_picks p JOIN drafts d ON d.id = p.draft_id WHERE d.year = 2026",
+    )
+    .fetch_one(&pool)
+    .await
+    .unwrap();
+    assert_eq!(db_count as u64, created);
+}
+
+#[tokio::test]
+async fn test_seed_draft_order_401_without_key() {
+    let (base_url, _pool) = test_support::spawn_app_with_seed_key("correct-key").await;
+    let client = test_support::create_client();
+
+    let response = client
+        .post(format!("{}/api/v1/admin/seed-draft-order", base_url))
+        .timeout(Duration::from_secs(10))
+        .send()
+        .await
+        .expect("Failed to send request");
+
+    assert_eq!(response.status(), 401);
+}
+
+#[tokio::test]
+async fn test_seed_draft_order_rejects_malformed_file() {
+    let (base_url, _pool) = test_support::spawn_app_with_seed_key("test-seed-key").await;
+    let client = test_support::create_client();
+
+    let response = client
+        .post(format!("{}/api/v1/admin/seed-draft-order", base_url))
+        .header("X-Seed-Api-Key", "test-seed-key")
+        .json(&serde_json::json!({ "draft_order": [] }))
+        .timeout(Duration::from_secs(10))
+        .send()
+        .await
+        .expect("Failed to send request");
+
+    assert_eq!(response.status(), 400);
+}
@@ -0,0 +1,110 @@
+//! Session chat history tests
+//! Covers: GET /api/v1/sessions/{id}/chat
+
+use reqwest::StatusCode;
+use serde_json::{json, Value};
+use uuid::Uuid;
+
+async fn create_session(app_url: &str, client: &reqwest::Client, pool: &sqlx::PgPool) -> Uuid {
+    let draft_id = Uuid::new_v4();
+    sqlx::query!(
+        "INSERT INTO drafts (id, year, status, rounds, picks_per_round) VALUES ($1, 2026, 'NotStarted', 1, 2::INTEGER)",
+        draft_id
+    )
+    .execute(pool)
+    .await
+    .unwrap();
+
+    let response = client
+        .post(format!("{}/api/v1/sessions", app_url))
+        .json(&json!({
+            "draft_id": draft_id,
+            "time_per_pick_seconds": 300,
+            "auto_pick_enabled": false
+        }))
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::CREATED);
+    let session: Value = response.json().await.unwrap();
+    Uuid::parse_str(session["id"].as_str().unwrap()).unwrap()
+}
+
+async fn insert_messages(pool: &sqlx::PgPool, session_id: Uuid, count: i32) {
+    for i in 0..count {
+        sqlx::query!(
+            "INSERT INTO chat_messages (session_id, display_name, body, created_at) VALUES ($1, 'Alice', $2, NOW() - INTERVAL '1 minute' + make_interval(secs => $3))",
+            session_id,
+            format!("msg {}", i),
+            i as f64
+        )
+        .execute(pool)
+        .await
+        .unwrap();
+    }
+}
+
+#[tokio::test]
+async fn test_chat_history_pages_backwards() {
+    let (app_url, pool) = test_support::spawn_app().await;
+    let client = test_support::create_client();
+    let session_id = create_session(&app_url, &client, &pool).await;
+    insert_messages(&pool, session_id, 5).await;
+
+    let response = client
+        .get(format!(
+            "{}/api/v1/sessions/{}/chat?limit=3",
+            app_url, session_id
+        ))
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+    let latest: Vec<Value> = response.json().await.unwrap();
+    let bodies: Vec<&str> = latest.iter().map(|m| m["body"].as_str().unwrap()).collect();
+    assert_eq!(bodies, vec!["msg 2", "msg 3", "msg 4"]);
+    assert_eq!(latest[0]["display_name"], "Alice");
+    assert!(latest[0]["participant_id"].is_null());
+
+    let response = client
+        .get(format!("{}/api/v1/sessions/{}/chat", app_url, session_id))
+        .query(&[("before", latest[0]["created_at"].as_str().unwrap())])
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+    let earlier: Vec<Value> = response.json().await.unwrap();
+    let bodies: Vec<&str> = earlier
+        .iter()
+        .map(|m| m["body"].as_str().unwrap())
+        .collect();
+    assert_eq!(bodies, vec!["msg 0", "msg 1"]);
+}
+
+#[tokio::test]
+async fn test_chat_history_validation() {
+    let (app_url, pool) = test_support::spawn_app().await;
+    let client = test_support::create_client();
+    let session_id = create_session(&app_url, &client, &pool).await;
+
+    let response = client
+        .get(format!(
+            "{}/api/v1/sessions/{}/chat?limit=0",
+            app_url, session_id
+        ))
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+
+    let response = client
+        .get(format!(
+            "{}/api/v1/sessions/{}/chat",
+            app_url,
+            Uuid::new_v4()
+        ))
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::NOT_FOUND);
+}
@@ -0,0 +1,253 @@
+//! Session export/import tests
+//! Covers: GET /api/v1/sessions/{id}/export, POST /api/v1/sessions/import
+
+use reqwest::StatusCode;
+use serde_json::{json, Value};
+use uuid::Uuid;
+
+const SEED_KEY: &str = "test-seed-key";
+
+/// Two-pick draft where auto-pick has made the first selection, backed by a
+/// scouting grade, and stopped at the user-controlled second pick
+async fn create_session_with_pick(
+    app_url: &str,
+    client: &reqwest::Client,
+    pool: &sqlx::PgPool,
+) -> (Uuid, Uuid) {
+    let draft_id = Uuid::new_v4();
+    let team_a = Uuid::new_v4();
+    let team_b = Uuid::new_v4();
+    let pick_a = Uuid::new_v4();
+    let player_id = Uuid::new_v4();
+
+    sqlx::query!(
+        "INSERT INTO drafts (id, name, year, status, rounds, picks_per_round) VALUES ($1, 'Shared Mock', 2026, 'NotStarted', 1, 2::INTEGER)",
+        draft_id
+    )
+    .execute(pool)
+    .await
+    .unwrap();
+
+    sqlx::query!(
+        "INSERT INTO teams (id, name, city, abbreviation, conference, division) VALUES ($1, 'Team A', 'Test', 'TMA', 'AFC', 'AFC East'), ($2, 'Team B', 'Test', 'TMB', 'NFC', 'NFC East')",
+        team_a,
+        team_b
+    )
+    .execute(pool)
+    .await
+    .unwrap();
+
+    sqlx::query!(
+        "INSERT INTO draft_picks (id, draft_id, round, pick_number, overall_pick, team_id) VALUES ($1, $2, 1, 1, 1, $3), ($4, $2, 1, 2, 2, $5)",
+        pick_a,
+        draft_id,
+        team_a,
+        Uuid::new_v4(),
+        team_b
+    )
+    .execute(pool)
+    .await
+    .unwrap();
+
+    sqlx::query!(
+        "INSERT INTO players (id, first_name, last_name, position, draft_year) VALUES ($1, 'Player', 'One', 'QB', 2026)",
+        player_id
+    )
+    .execute(pool)
+    .await
+    .unwrap();
+
+    sqlx::query!(
+        "INSERT INTO scouting_reports (id, player_id, team_id, grade) VALUES ($1, $2, $3, 8.5)",
+        Uuid::new_v4(),
+        player_id,
+        team_a
+    )
+    .execute(pool)
+    .await
+    .unwrap();
+
+    let response = client
+        .post(format!("{}/api/v1/sessions", app_url))
+        .json(&json!({
+            "draft_id": draft_id,
+            "time_per_pick_seconds": 300,
+            "auto_pick_enabled": true,
+            "controlled_team_ids": [team_b]
+        }))
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::CREATED);
+    let session: Value = response.json().await.unwrap();
+    let session_id = Uuid::parse_str(session["id"].as_str().unwrap()).unwrap();
+
+    let response = client
+        .post(format!("{}/api/v1/sessions/{}/start", app_url, session_id))
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+
+    let response = client
+        .post(format!(
+            "{}/api/v1/sessions/{}/auto-pick-run",
+            app_url, session_id
+        ))
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+    let run: Value = response.json().await.unwrap();
+    assert_eq!(run["picks_made"][0]["id"], pick_a.to_string());
+
+    (session_id, player_id)
+}
+
+#[tokio::test]
+async fn test_export_session_bundle() {
+    let (app_url, pool) = test_support::spawn_app().await;
+    let client = test_support::create_client();
+    let (session_id, player_id) = create_session_with_pick(&app_url, &client, &pool).await;
+
+    let response = client
+        .get(format!("{}/api/v1/sessions/{}/export", app_url, session_id))
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+
+    let bundle: Value = response.json().await.unwrap();
+    assert_eq!(bundle["version"], 1);
+    assert_eq!(bundle["session"]["id"], session_id.to_string());
+    assert_eq!(bundle["draft"]["name"], "Shared Mock");
+    assert_eq!(bundle["picks"].as_array().unwrap().len(), 2);
+    assert_eq!(bundle["teams"].as_array().unwrap().len(), 2);
+    assert_eq!(bundle["players"][0]["id"], player_id.to_string());
+    assert_eq!(bundle["grades"].as_array().unwrap().len(), 1);
+    assert_eq!(bundle["grades"][0]["grade"], 8.5);
+    let event_types: Vec<&str> = bundle["events"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|e| e["event_type"].as_str().unwrap())
+        .collect();
+    assert!(event_types.contains(&"PickMade"));
+}
+
+#[tokio::test]
+async fn test_import_session_bundle_round_trip() {
+    let (app_url, pool) = test_support::spawn_app_with_seed_key(SEED_KEY).await;
+    let client = test_support::create_client();
+    let (session_id, player_id) = create_session_with_pick(&app_url, &client, &pool).await;
+
+    let bundle: Value = client
+        .get(format!("{}/api/v1/sessions/{}/export", app_url, session_id))
+        .send()
+        .await
+        .unwrap()
+        .json()
+        .await
+        .unwrap();
+
+    let response = client
+        .post(format!("{}/api/v1/sessions/import", app_url))
+        .json(&bundle)
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::CREATED);
+
+    let imported: Value = response.json().await.unwrap();
+    let imported_id = Uuid::parse_str(imported["id"].as_str().unwrap()).unwrap();
+    let imported_draft = Uuid::parse_str(imported["draft_id"].as_str().unwrap()).unwrap();
+    assert_ne!(imported_id, session_id);
+    assert_ne!(imported["draft_id"], bundle["draft"]["id"]);
+    assert_eq!(imported["status"], "InProgress");
+    assert_eq!(
+        imported["controlled_team_ids"],
+        bundle["session"]["controlled_team_ids"]
+    );
+
+    let picks = sqlx::query!(
+        "SELECT overall_pick, player_id FROM draft_picks WHERE draft_id = $1 ORDER BY overall_pick",
+        imported_draft
+    )
+    .fetch_all(&pool)
+    .await
+    .unwrap();
+    assert_eq!(picks.len(), 2);
+    assert_eq!(picks[0].player_id, Some(player_id));
+    assert_eq!(picks[1].player_id, None);
+
+    // The rewritten event log still explains the imported tables
+    let response = client
+        .get(format!(
+            "{}/api/v1/admin/sessions/{}/verify-projection",
+            app_url, imported_id
+        ))
+        .header("X-Seed-Api-Key", SEED_KEY)
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+    let verification: Value = response.json().await.unwrap();
+    assert_eq!(
+        verification["consistent"], true,
+        "unexpected mismatches: {}",
+        verification
+    );
+    assert_eq!(
+        verification["events_applied"].as_u64().unwrap() as usize,
+        bundle["events"].as_array().unwrap().len()
+    );
+    assert_eq!(verification["projected_picks_made"], 1);
+}
+
+#[tokio::test]
+async fn test_import_rejects_unknown_team() {
+    let (app_url, pool) = test_support::spawn_app().await;
+    let client = test_support::create_client();
+    let (session_id, _) = create_session_with_pick(&app_url, &client, &pool).await;
+
+    let mut bundle: Value = client
+        .get(format!("{}/api/v1/sessions/{}/export", app_url, session_id))
+        .send()
+        .await
+        .unwrap()
+        .json()
+        .await
+        .unwrap();
+    bundle["teams"][0]["abbreviation"] = json!("ZZZ");
+
+    let response = client
+        .post(format!("{}/api/v1/sessions/import", app_url))
+        .json(&bundle)
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+
+    let count = sqlx::query!("SELECT COUNT(*) as count FROM draft_sessions")
+        .fetch_one(&pool)
+        .await
+        .unwrap();
+    assert_eq!(count.count.unwrap(), 1);
+}
+
+#[tokio::test]
+async fn test_export_missing_session() {
+    let (app_url, _pool) = test_support::spawn_app().await;
+    let client = test_support::create_client();
+
+    let response = client
+        .get(format!(
+            "{}/api/v1/sessions/{}/export",
+            app_url,
+            Uuid::new_v4()
+        ))
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::NOT_FOUND);
+}
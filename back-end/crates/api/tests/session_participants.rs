@@ -0,0 +1,332 @@
+//! Multi-user session tests
+//! Covers: joining sessions, listing participants, leaving, and per-team pick authorization
+
+use domain::models::Role;
+use reqwest::StatusCode;
+use serde_json::{json, Value};
+use uuid::Uuid;
+
+struct Fixture {
+    session_id: Uuid,
+    team_a: Uuid,
+    team_b: Uuid,
+    pick_a: Uuid,
+    player_id: Uuid,
+}
+
+/// Two-team, one-round draft with a session that has not started yet
+async fn setup(app_url: &str, client: &reqwest::Client, pool: &sqlx::PgPool) -> Fixture {
+    let draft_id = Uuid::new_v4();
+    let team_a = Uuid::new_v4();
+    let team_b = Uuid::new_v4();
+    let pick_a = Uuid::new_v4();
+    let pick_b = Uuid::new_v4();
+    let player_id = Uuid::new_v4();
+
+    sqlx::query!(
+        "INSERT INTO drafts (id, year, status, rounds, picks_per_round) VALUES ($1, 2026, 'NotStarted', 1, 2::INTEGER)",
+        draft_id
+    )
+    .execute(pool)
+    .await
+    .unwrap();
+
+    sqlx::query!(
+        "INSERT INTO teams (id, name, city, abbreviation, conference, division) VALUES ($1, 'Team A', 'Test', 'TMA', 'AFC', 'AFC East'), ($2, 'Team B', 'Test', 'TMB', 'NFC', 'NFC East')",
+        team_a,
+        team_b
+    )
+    .execute(pool)
+    .await
+    .unwrap();
+
+    sqlx::query!(
+        "INSERT INTO draft_picks (id, draft_id, round, pick_number, overall_pick, team_id) VALUES ($1, $2, 1, 1, 1, $3), ($4, $2, 1, 2, 2, $5)",
+        pick_a,
+        draft_id,
+        team_a,
+        pick_b,
+        team_b
+    )
+    .execute(pool)
+    .await
+    .unwrap();
+
+    sqlx::query!(
+        "INSERT INTO players (id, first_name, last_name, position, draft_year) VALUES ($1, 'Player', 'One', 'QB', 2026)",
+        player_id
+    )
+    .execute(pool)
+    .await
+    .unwrap();
+
+    let response = client
+        .post(format!("{}/api/v1/sessions", app_url))
+        .json(&json!({
+            "draft_id": draft_id,
+            "time_per_pick_seconds": 300,
+            "auto_pick_enabled": false
+        }))
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::CREATED);
+    let session: Value = response.json().await.unwrap();
+    let session_id = Uuid::parse_str(session["id"].as_str().unwrap()).unwrap();
+
+    Fixture {
+        session_id,
+        team_a,
+        team_b,
+        pick_a,
+        player_id,
+    }
+}
+
+async fn join(
+    app_url: &str,
+    client: &reqwest::Client,
+    session_id: Uuid,
+    name: &str,
+    team_id: Uuid,
+) -> reqwest::Response {
+    client
+        .post(format!(
+            "{}/api/v1/sessions/{}/participants",
+            app_url, session_id
+        ))
+        .json(&json!({ "display_name": name, "team_ids": [team_id] }))
+        .send()
+        .await
+        .unwrap()
+}
+
+/// Joins as a new participant-role user and returns that user's client and
+/// participant id
+async fn join_as(
+    app_url: &str,
+    pool: &sqlx::PgPool,
+    session_id: Uuid,
+    name: &str,
+    team_id: Uuid,
+) -> (reqwest::Client, String) {
+    let (_, client) = test_support::create_user_client(pool, Role::Participant).await;
+    let response = join(app_url, &client, session_id, name, team_id).await;
+    assert_eq!(response.status(), StatusCode::CREATED);
+    let body: Value = response.json().await.unwrap();
+    (client, body["id"].as_str().unwrap().to_string())
+}
+
+async fn controlled_team_ids(app_url: &str, session_id: Uuid) -> Vec<Value> {
+    let session: Value = test_support::create_client()
+        .get(format!("{}/api/v1/sessions/{}", app_url, session_id))
+        .send()
+        .await
+        .unwrap()
+        .json()
+        .await
+        .unwrap();
+    session["controlled_team_ids"].as_array().unwrap().clone()
+}
+
+#[tokio::test]
+async fn test_join_and_list_participants() {
+    let (app_url, pool) = test_support::spawn_app().await;
+    let client = test_support::create_client();
+    let fx = setup(&app_url, &client, &pool).await;
+    let (alice_id, alice) = test_support::create_user_client(&pool, Role::Participant).await;
+
+    let response = join(&app_url, &alice, fx.session_id, "Alice", fx.team_a).await;
+    assert_eq!(response.status(), StatusCode::CREATED);
+    let body: Value = response.json().await.unwrap();
+    assert_eq!(body["display_name"], "Alice");
+    assert_eq!(body["user_id"], alice_id.to_string());
+    assert_eq!(body["team_ids"][0], fx.team_a.to_string());
+
+    let response = client
+        .get(format!(
+            "{}/api/v1/sessions/{}/participants",
+            app_url, fx.session_id
+        ))
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+    let list: Vec<Value> = response.json().await.unwrap();
+    assert_eq!(list.len(), 1);
+
+    // Claimed teams become user-controlled on the session
+    let controlled = controlled_team_ids(&app_url, fx.session_id).await;
+    assert!(controlled.contains(&json!(fx.team_a.to_string())));
+}
+
+#[tokio::test]
+async fn test_join_requires_sign_in() {
+    let (app_url, pool) = test_support::spawn_app().await;
+    let client = test_support::create_client();
+    let fx = setup(&app_url, &client, &pool).await;
+
+    let anonymous = test_support::create_anonymous_client();
+    let response = join(&app_url, &anonymous, fx.session_id, "Mallory", fx.team_a).await;
+    assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+
+    let (_, spectator) = test_support::create_user_client(&pool, Role::Spectator).await;
+    let response = join(&app_url, &spectator, fx.session_id, "Mallory", fx.team_a).await;
+    assert_eq!(response.status(), StatusCode::FORBIDDEN);
+}
+
+#[tokio::test]
+async fn test_join_rejects_team_claimed_by_other_participant() {
+    let (app_url, pool) = test_support::spawn_app().await;
+    let client = test_support::create_client();
+    let fx = setup(&app_url, &client, &pool).await;
+
+    join_as(&app_url, &pool, fx.session_id, "Alice", fx.team_a).await;
+
+    let (_, bob) = test_support::create_user_client(&pool, Role::Participant).await;
+    let response = join(&app_url, &bob, fx.session_id, "Bob", fx.team_a).await;
+    assert_eq!(response.status(), StatusCode::CONFLICT);
+}
+
+#[tokio::test]
+async fn test_user_joins_session_once() {
+    let (app_url, pool) = test_support::spawn_app().await;
+    let client = test_support::create_client();
+    let fx = setup(&app_url, &client, &pool).await;
+
+    let (alice, _) = join_as(&app_url, &pool, fx.session_id, "Alice", fx.team_a).await;
+
+    let response = join(&app_url, &alice, fx.session_id, "Alice Again", fx.team_b).await;
+    assert_eq!(response.status(), StatusCode::CONFLICT);
+}
+
+#[tokio::test]
+async fn test_make_pick_enforces_team_ownership() {
+    let (app_url, pool) = test_support::spawn_app().await;
+    let client = test_support::create_client();
+    let fx = setup(&app_url, &client, &pool).await;
+
+    let (alice, _) = join_as(&app_url, &pool, fx.session_id, "Alice", fx.team_a).await;
+    let (bob, _) = join_as(&app_url, &pool, fx.session_id, "Bob", fx.team_b).await;
+
+    let response = client
+        .post(format!(
+            "{}/api/v1/sessions/{}/start",
+            app_url, fx.session_id
+        ))
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+
+    let pick_url = format!("{}/api/v1/picks/{}/make", app_url, fx.pick_a);
+    let body = json!({ "player_id": fx.player_id });
+
+    // Not signed in
+    let response = test_support::create_anonymous_client()
+        .post(&pick_url)
+        .json(&body)
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+
+    // Signed in but never joined
+    let (_, outsider) = test_support::create_user_client(&pool, Role::Participant).await;
+    let response = outsider.post(&pick_url).json(&body).send().await.unwrap();
+    assert_eq!(response.status(), StatusCode::FORBIDDEN);
+
+    // Bob does not control Team A
+    let response = bob.post(&pick_url).json(&body).send().await.unwrap();
+    assert_eq!(response.status(), StatusCode::FORBIDDEN);
+
+    let response = alice.post(&pick_url).json(&body).send().await.unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+    let pick: Value = response.json().await.unwrap();
+    assert_eq!(pick["player_id"], fx.player_id.to_string());
+}
+
+#[tokio::test]
+async fn test_commissioner_keeps_control_after_participants_join() {
+    let (app_url, pool) = test_support::spawn_app().await;
+    let client = test_support::create_client();
+    let fx = setup(&app_url, &client, &pool).await;
+
+    join_as(&app_url, &pool, fx.session_id, "Alice", fx.team_a).await;
+
+    for action in ["start", "pause"] {
+        let response = client
+            .post(format!(
+                "{}/api/v1/sessions/{}/{}",
+                app_url, fx.session_id, action
+            ))
+            .send()
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::OK, "{} failed", action);
+    }
+}
+
+#[tokio::test]
+async fn test_leave_session_requires_own_account() {
+    let (app_url, pool) = test_support::spawn_app().await;
+    let client = test_support::create_client();
+    let fx = setup(&app_url, &client, &pool).await;
+
+    let (alice, alice_id) = join_as(&app_url, &pool, fx.session_id, "Alice", fx.team_a).await;
+    let (bob, _) = join_as(&app_url, &pool, fx.session_id, "Bob", fx.team_b).await;
+
+    let leave_url = format!(
+        "{}/api/v1/sessions/{}/participants/{}",
+        app_url, fx.session_id, alice_id
+    );
+
+    let response = bob.delete(&leave_url).send().await.unwrap();
+    assert_eq!(response.status(), StatusCode::FORBIDDEN);
+
+    let response = alice.delete(&leave_url).send().await.unwrap();
+    assert_eq!(response.status(), StatusCode::NO_CONTENT);
+
+    let list: Vec<Value> = client
+        .get(format!(
+            "{}/api/v1/sessions/{}/participants",
+            app_url, fx.session_id
+        ))
+        .send()
+        .await
+        .unwrap()
+        .json()
+        .await
+        .unwrap();
+    assert_eq!(list.len(), 1);
+    assert_eq!(list[0]["display_name"], "Bob");
+}
+
+#[tokio::test]
+async fn test_leave_session_releases_teams() {
+    let (app_url, pool) = test_support::spawn_app().await;
+    let client = test_support::create_client();
+    let fx = setup(&app_url, &client, &pool).await;
+
+    let (alice, alice_id) = join_as(&app_url, &pool, fx.session_id, "Alice", fx.team_a).await;
+    join_as(&app_url, &pool, fx.session_id, "Bob", fx.team_b).await;
+    let controlled = controlled_team_ids(&app_url, fx.session_id).await;
+    assert!(controlled.contains(&json!(fx.team_a.to_string())));
+
+    let response = alice
+        .delete(format!(
+            "{}/api/v1/sessions/{}/participants/{}",
+            app_url, fx.session_id, alice_id
+        ))
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::NO_CONTENT);
+
+    let controlled = controlled_team_ids(&app_url, fx.session_id).await;
+    assert!(!controlled.contains(&json!(fx.team_a.to_string())));
+    assert!(controlled.contains(&json!(fx.team_b.to_string())));
+
+    // The released team can be claimed again
+    join_as(&app_url, &pool, fx.session_id, "Carol", fx.team_a).await;
+}
@@ -0,0 +1,159 @@
+//! Event-sourced projection verification tests
+//! Covers: GET /api/v1/admin/sessions/{id}/verify-projection
+
+use reqwest::StatusCode;
+use serde_json::{json, Value};
+use uuid::Uuid;
+
+const SEED_KEY: &str = "test-seed-key";
+
+async fn create_started_session(
+    app_url: &str,
+    client: &reqwest::Client,
+    pool: &sqlx::PgPool,
+) -> (Uuid, Uuid) {
+    let draft_id = Uuid::new_v4();
+    let team_id = Uuid::new_v4();
+    let pick_id = Uuid::new_v4();
+
+    sqlx::query!(
+        "INSERT INTO drafts (id, year, status, rounds, picks_per_round) VALUES ($1, 2026, 'NotStarted', 1, 1::INTEGER)",
+        draft_id
+    )
+    .execute(pool)
+    .await
+    .unwrap();
+
+    sqlx::query!(
+        "INSERT INTO teams (id, name, city, abbreviation, conference, division) VALUES ($1, 'Team A', 'Test', 'TMA', 'AFC', 'AFC East')",
+        team_id
+    )
+    .execute(pool)
+    .await
+    .unwrap();
+
+    sqlx::query!(
+        "INSERT INTO draft_picks (id, draft_id, round, pick_number, overall_pick, team_id) VALUES ($1, $2, 1, 1, 1, $3)",
+        pick_id,
+        draft_id,
+        team_id
+    )
+    .execute(pool)
+    .await
+    .unwrap();
+
+    let response = client
+        .post(format!("{}/api/v1/sessions", app_url))
+        .json(&json!({
+            "draft_id": draft_id,
+            "time_per_pick_seconds": 300,
+            "auto_pick_enabled": false
+        }))
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::CREATED);
+    let session: Value = response.json().await.unwrap();
+    let session_id = Uuid::parse_str(session["id"].as_str().unwrap()).unwrap();
+
+    let response = client
+        .post(format!("{}/api/v1/sessions/{}/start", app_url, session_id))
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+
+    (session_id, pick_id)
+}
+
+#[tokio::test]
+async fn test_verify_projection_consistent_session() {
+    let (app_url, pool) = test_support::spawn_app_with_seed_key(SEED_KEY).await;
+    let client = test_support::create_client();
+
+    let (session_id, _) = create_started_session(&app_url, &client, &pool).await;
+
+    let response = client
+        .get(format!(
+            "{}/api/v1/admin/sessions/{}/verify-projection",
+            app_url, session_id
+        ))
+        .header("X-Seed-Api-Key", SEED_KEY)
+        .send()
+        .await
+        .unwrap();
+
+    assert_eq!(response.status(), StatusCode::OK);
+
+    let body: Value = response.json().await.unwrap();
+    assert_eq!(body["consistent"], true, "unexpected mismatches: {}", body);
+    assert_eq!(body["events_applied"], 2);
+    assert_eq!(body["projected_status"], "InProgress");
+    assert_eq!(body["projected_current_pick_number"], 1);
+    assert!(body["mismatches"].as_array().unwrap().is_empty());
+}
+
+#[tokio::test]
+async fn test_verify_projection_reports_drift() {
+    let (app_url, pool) = test_support::spawn_app_with_seed_key(SEED_KEY).await;
+    let client = test_support::create_client();
+
+    let (session_id, pick_id) = create_started_session(&app_url, &client, &pool).await;
+
+    // Write a selection straight to the table without recording an event
+    let player_id = Uuid::new_v4();
+    sqlx::query!(
+        "INSERT INTO players (id, first_name, last_name, position, draft_year) VALUES ($1, 'Player', 'One', 'QB', 2026)",
+        player_id
+    )
+    .execute(&pool)
+    .await
+    .unwrap();
+    sqlx::query!(
+        "UPDATE draft_picks SET player_id = $1, picked_at = NOW() WHERE id = $2",
+        player_id,
+        pick_id
+    )
+    .execute(&pool)
+    .await
+    .unwrap();
+
+    let response = client
+        .get(format!(
+            "{}/api/v1/admin/sessions/{}/verify-projection",
+            app_url, session_id
+        ))
+        .header("X-Seed-Api-Key", SEED_KEY)
+        .send()
+        .await
+        .unwrap();
+
+    assert_eq!(response.status(), StatusCode::OK);
+
+    let body: Value = response.json().await.unwrap();
+    assert_eq!(body["consistent"], false);
+    let mismatches = body["mismatches"].as_array().unwrap();
+    assert_eq!(mismatches.len(), 1);
+    assert_eq!(mismatches[0]["field"], "pick 1 player_id");
+    assert_eq!(mismatches[0]["stored"], player_id.to_string());
+    assert_eq!(mismatches[0]["projected"], "none");
+}
+
+#[tokio::test]
+async fn test_verify_projection_requires_api_key() {
+    let (app_url, _pool) = test_support::spawn_app_with_seed_key(SEED_KEY).await;
+    let client = test_support::create_client();
+
+    let response = client
+        .get(format!(
+            "{}/api/v1/admin/sessions/{}/verify-projection",
+            app_url,
+            Uuid::new_v4()
+        ))
+        .header("X-Seed-Api-Key", "wrong-key")
+        .send()
+        .await
+        .unwrap();
+
+    assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+}
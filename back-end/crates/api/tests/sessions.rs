@@ -2,12 +2,10 @@ use reqwest::StatusCode;
 use serde_json::{json, Value};
 use uuid::Uuid;
 
-mod common;
-
 #[tokio::test]
 async fn test_create_session() {
-    let (app_url, pool) = common::spawn_app().await;
-    let client = common::create_client();
+    let (app_url, pool) = test_support::spawn_app().await;
+    let client = test_support::create_client();
 
     // Create a draft first
     let draft_id = Uuid::new_v4();
@@ -65,14 +63,12 @@ async fn test_create_session() {
     .unwrap();
 
     assert_eq!(event_count.count.unwrap(), 1);
-
-    common::cleanup_database(&pool).await;
 }
 
 #[tokio::test]
 async fn test_get_session() {
-    let (app_url, pool) = common::spawn_app().await;
-    let client = common::create_client();
+    let (app_url, pool) = test_support::spawn_app().await;
+    let client = test_support::create_client();
 
     // Create draft and session directly in database
     let draft_id = Uuid::new_v4();
@@ -108,14 +104,12 @@ async fn test_get_session() {
     assert_eq!(session["id"], session_id.to_string());
     assert_eq!(session["draft_id"], draft_id.to_string());
     assert_eq!(session["status"], "NotStarted");
-
-    common::cleanup_database(&pool).await;
 }
 
 #[tokio::test]
 async fn test_start_session() {
-    let (app_url, pool) = common::spawn_app().await;
-    let client = common::create_client();
+    let (app_url, pool) = test_support::spawn_app().await;
+    let client = test_support::create_client();
 
     // Create draft and session
     let draft_id = Uuid::new_v4();
@@ -169,14 +163,12 @@ async fn test_start_session() {
     .unwrap();
 
     assert_eq!(event_count.count.unwrap(), 1);
-
-    common::cleanup_database(&pool).await;
 }
 
 #[tokio::test]
 async fn test_pause_session() {
-    let (app_url, pool) = common::spawn_app().await;
-    let client = common::create_client();
+    let (app_url, pool) = test_support::spawn_app().await;
+    let client = test_support::create_client();
 
     // Create draft and session in progress
     let draft_id = Uuid::new_v4();
@@ -229,14 +221,12 @@ async fn test_pause_session() {
     .unwrap();
 
     assert_eq!(event_count.count.unwrap(), 1);
-
-    common::cleanup_database(&pool).await;
 }
 
 #[tokio::test]
 async fn test_get_session_events() {
-    let (app_url, pool) = common::spawn_app().await;
-    let client = common::create_client();
+    let (app_url, pool) = test_support::spawn_app().await;
+    let client = test_support::create_client();
 
     // Create draft and session
     let draft_id = Uuid::new_v4();
@@ -294,14 +284,91 @@ async fn test_get_session_events() {
     assert_eq!(events.len(), 2);
     assert_eq!(events[0]["event_type"], "SessionCreated");
     assert_eq!(events[1]["event_type"], "SessionStarted");
+}
+
+#[tokio::test]
+async fn test_get_session_events_with_cursor() {
+    let (app_url, pool) = test_support::spawn_app().await;
+    let client = test_support::create_client();
+
+    let draft_id = Uuid::new_v4();
+    let session_id = Uuid::new_v4();
+
+    sqlx::query!(
+        "INSERT INTO drafts (id, year, status, rounds, picks_per_round) VALUES ($1, 2026, 'NotStarted', 7, 32::INTEGER)",
+        draft_id
+    )
+    .execute(&pool)
+    .await
+    .unwrap();
+
+    sqlx::query!(
+        "INSERT INTO draft_sessions (id, draft_id, status, current_pick_number, time_per_pick_seconds, auto_pick_enabled) VALUES ($1, $2, 'NotStarted', 1, 300, false)",
+        session_id,
+        draft_id
+    )
+    .execute(&pool)
+    .await
+    .unwrap();
+
+    for seconds in 0..5 {
+        sqlx::query(
+            "INSERT INTO draft_events (session_id, event_type, event_data, created_at) \
+             VALUES ($1, 'ClockUpdate', $2, NOW() + make_interval(secs => $3))",
+        )
+        .bind(session_id)
+        .bind(json!({ "time_remaining": seconds }))
+        .bind(seconds as f64)
+        .execute(&pool)
+        .await
+        .unwrap();
+    }
+
+    let events_url = format!("{}/api/v1/sessions/{}/events", app_url, session_id);
+
+    // Tail the feed two events at a time
+    let mut seen = Vec::new();
+    let mut after_id: Option<String> = None;
+    loop {
+        let mut url = format!("{}?limit=2", events_url);
+        if let Some(id) = &after_id {
+            url.push_str(&format!("&after_id={}", id));
+        }
+        let response = client.get(&url).send().await.unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+        let page: Vec<Value> = response.json().await.unwrap();
+        if page.is_empty() {
+            break;
+        }
+        assert!(page.len() <= 2);
+        after_id = page.last().map(|e| e["id"].as_str().unwrap().to_string());
+        seen.extend(
+            page.iter()
+                .map(|e| e["event_data"]["time_remaining"].clone()),
+        );
+    }
+    assert_eq!(seen, vec![json!(0), json!(1), json!(2), json!(3), json!(4)]);
+
+    // A cursor from another session, or an out-of-range limit, is rejected
+    let response = client
+        .get(format!("{}?after_id={}", events_url, Uuid::new_v4()))
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::BAD_REQUEST);
 
-    common::cleanup_database(&pool).await;
+    let response = client
+        .get(format!("{}?limit=0", events_url))
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::BAD_REQUEST);
 }
 
 #[tokio::test]
 async fn test_session_lifecycle() {
-    let (app_url, pool) = common::spawn_app().await;
-    let client = common::create_client();
+    let (app_url, pool) = test_support::spawn_app().await;
+    let client = test_support::create_client();
 
     // Create a draft
     let draft_id = Uuid::new_v4();
@@ -362,14 +429,12 @@ async fn test_session_lifecycle() {
 
     let events: Vec<Value> = events_response.json().await.unwrap();
     assert_eq!(events.len(), 3); // Created, Started, Paused
-
-    common::cleanup_database(&pool).await;
 }
 
 #[tokio::test]
 async fn test_create_session_with_default_chart() {
-    let (app_url, pool) = common::spawn_app().await;
-    let client = common::create_client();
+    let (app_url, pool) = test_support::spawn_app().await;
+    let client = test_support::create_client();
 
     // Create a draft first
     let draft_id = Uuid::new_v4();
@@ -412,14 +477,12 @@ async fn test_create_session_with_default_chart() {
     .unwrap();
 
     assert_eq!(db_session.chart_type, "JimmyJohnson");
-
-    common::cleanup_database(&pool).await;
 }
 
 #[tokio::test]
 async fn test_create_session_with_explicit_chart() {
-    let (app_url, pool) = common::spawn_app().await;
-    let client = common::create_client();
+    let (app_url, pool) = test_support::spawn_app().await;
+    let client = test_support::create_client();
 
     // Create a draft first
     let draft_id = Uuid::new_v4();
@@ -463,14 +526,12 @@ async fn test_create_session_with_explicit_chart() {
     .unwrap();
 
     assert_eq!(db_session.chart_type, "RichHill");
-
-    common::cleanup_database(&pool).await;
 }
 
 #[tokio::test]
 async fn test_create_session_with_all_chart_types() {
-    let (app_url, pool) = common::spawn_app().await;
-    let client = common::create_client();
+    let (app_url, pool) = test_support::spawn_app().await;
+    let client = test_support::create_client();
 
     let charts = vec![
         "JimmyJohnson",
@@ -481,10 +542,6 @@ async fn test_create_session_with_all_chart_types() {
         "SurplusValue",
     ];
 
-    // Note: Drafts are created in the loop for simplicity. If the test panics,
-    // cleanup_database() won't run and these drafts will remain. This is acceptable
-    // since cleanup_database() is comprehensive and cleans all tables. A more robust
-    // pattern would create all drafts upfront, but this matches the existing test style.
     for (idx, chart) in charts.iter().enumerate() {
         // Create a draft with unique year
         let draft_id = Uuid::new_v4();
@@ -518,14 +575,12 @@ async fn test_create_session_with_all_chart_types() {
         let session: Value = response.json().await.unwrap();
         assert_eq!(session["chart_type"], *chart);
     }
-
-    common::cleanup_database(&pool).await;
 }
 
 #[tokio::test]
 async fn test_create_session_with_controlled_teams() {
-    let (app_url, pool) = common::spawn_app().await;
-    let client = common::create_client();
+    let (app_url, pool) = test_support::spawn_app().await;
+    let client = test_support::create_client();
 
     // Create a draft and some teams
     let draft_id = Uuid::new_v4();
@@ -619,14 +674,12 @@ async fn test_create_session_with_controlled_teams() {
     let event_team_ids: Vec<String> =
         serde_json::from_value(settings["controlled_team_ids"].clone()).unwrap();
     assert_eq!(event_team_ids.len(), 2);
-
-    common::cleanup_database(&pool).await;
 }
 
 #[tokio::test]
 async fn test_controlled_teams_persist_through_lifecycle() {
-    let (app_url, pool) = common::spawn_app().await;
-    let client = common::create_client();
+    let (app_url, pool) = test_support::spawn_app().await;
+    let client = test_support::create_client();
 
     // Create a draft and a team
     let draft_id = Uuid::new_v4();
@@ -716,14 +769,12 @@ async fn test_controlled_teams_persist_through_lifecycle() {
         serde_json::from_value(fetched["controlled_team_ids"].clone()).unwrap();
     assert_eq!(controlled_ids.len(), 1);
     assert_eq!(controlled_ids[0], team_id.to_string());
-
-    common::cleanup_database(&pool).await;
 }
 
 #[tokio::test]
 async fn test_advance_pick() {
-    let (app_url, pool) = common::spawn_app().await;
-    let client = common::create_client();
+    let (app_url, pool) = test_support::spawn_app().await;
+    let client = test_support::create_client();
 
     // Create draft, team, and session in InProgress state
     let draft_id = Uuid::new_v4();
@@ -772,13 +823,23 @@ async fn test_advance_pick() {
 
     assert_eq!(db_session.current_pick_number, 2);
 
-    common::cleanup_database(&pool).await;
+    // Recorded with the change, so a replay of the events lands on pick 2
+    let events: Vec<(String, Value)> =
+        sqlx::query_as("SELECT event_type, event_data FROM draft_events WHERE session_id = $1")
+            .bind(session_id)
+            .fetch_all(&pool)
+            .await
+            .unwrap();
+    assert_eq!(events.len(), 1);
+    assert_eq!(events[0].0, "PickAdvanced");
+    assert_eq!(events[0].1["from_pick_number"], 1);
+    assert_eq!(events[0].1["to_pick_number"], 2);
 }
 
 #[tokio::test]
 async fn test_advance_pick_requires_in_progress() {
-    let (app_url, pool) = common::spawn_app().await;
-    let client = common::create_client();
+    let (app_url, pool) = test_support::spawn_app().await;
+    let client = test_support::create_client();
 
     // Create draft and session in NotStarted state
     let draft_id = Uuid::new_v4();
@@ -826,14 +887,12 @@ async fn test_advance_pick_requires_in_progress() {
     .unwrap();
 
     assert_eq!(db_session.current_pick_number, 1);
-
-    common::cleanup_database(&pool).await;
 }
 
 #[tokio::test]
 async fn test_auto_pick_run_stops_at_controlled_team() {
-    let (app_url, pool) = common::spawn_app().await;
-    let client = common::create_client();
+    let (app_url, pool) = test_support::spawn_app().await;
+    let client = test_support::create_client();
 
     // Create draft, two teams, players, picks, session with controlled_team_ids
     let draft_id = Uuid::new_v4();
@@ -938,14 +997,12 @@ async fn test_auto_pick_run_stops_at_controlled_team() {
         .await
         .unwrap();
     assert!(db_pick_2.player_id.is_none());
-
-    common::cleanup_database(&pool).await;
 }
 
 #[tokio::test]
 async fn test_auto_pick_run_empty_when_user_controlled_first() {
-    let (app_url, pool) = common::spawn_app().await;
-    let client = common::create_client();
+    let (app_url, pool) = test_support::spawn_app().await;
+    let client = test_support::create_client();
 
     // First pick belongs to user-controlled team — auto-pick-run should do nothing
     let draft_id = Uuid::new_v4();
@@ -1016,14 +1073,12 @@ async fn test_auto_pick_run_empty_when_user_controlled_first() {
         .await
         .unwrap();
     assert!(db_pick.player_id.is_none());
-
-    common::cleanup_database(&pool).await;
 }
 
 #[tokio::test]
 async fn test_start_session_transitions_draft_to_in_progress() {
-    let (app_url, pool) = common::spawn_app().await;
-    let client = common::create_client();
+    let (app_url, pool) = test_support::spawn_app().await;
+    let client = test_support::create_client();
 
     // Create draft in NotStarted state
     let draft_id = Uuid::new_v4();
@@ -1080,14 +1135,12 @@ async fn test_start_session_transitions_draft_to_in_progress() {
         .await
         .unwrap();
     assert_eq!(db_draft.status, "InProgress");
-
-    common::cleanup_database(&pool).await;
 }
 
 #[tokio::test]
 async fn test_start_session_skips_draft_already_in_progress() {
-    let (app_url, pool) = common::spawn_app().await;
-    let client = common::create_client();
+    let (app_url, pool) = test_support::spawn_app().await;
+    let client = test_support::create_client();
 
     // Create draft already InProgress (e.g., resumed after pause)
     let draft_id = Uuid::new_v4();
@@ -1126,14 +1179,12 @@ async fn test_start_session_skips_draft_already_in_progress() {
         .await
         .unwrap();
     assert_eq!(db_draft.status, "InProgress");
-
-    common::cleanup_database(&pool).await;
 }
 
 #[tokio::test]
 async fn test_create_session_with_nonexistent_draft() {
-    let (app_url, pool) = common::spawn_app().await;
-    let client = common::create_client();
+    let (app_url, _pool) = test_support::spawn_app().await;
+    let client = test_support::create_client();
 
     // Try to create session with a draft_id that doesn't exist
     let fake_draft_id = Uuid::new_v4();
@@ -1152,14 +1203,12 @@ async fn test_create_session_with_nonexistent_draft() {
         .unwrap();
 
     assert_eq!(response.status(), StatusCode::NOT_FOUND);
-
-    common::cleanup_database(&pool).await;
 }
 
 #[tokio::test]
 async fn test_create_session_with_nonexistent_team() {
-    let (app_url, pool) = common::spawn_app().await;
-    let client = common::create_client();
+    let (app_url, pool) = test_support::spawn_app().await;
+    let client = test_support::create_client();
 
     // Create a draft but use a fake team_id in controlled_team_ids
     let draft_id = Uuid::new_v4();
@@ -1188,14 +1237,12 @@ async fn test_create_session_with_nonexistent_team() {
         .unwrap();
 
     assert_eq!(response.status(), StatusCode::BAD_REQUEST);
-
-    common::cleanup_database(&pool).await;
 }
 
 #[tokio::test]
 async fn test_get_session_by_draft_id() {
-    let (app_url, pool) = common::spawn_app().await;
-    let client = common::create_client();
+    let (app_url, pool) = test_support::spawn_app().await;
+    let client = test_support::create_client();
 
     let draft_id = Uuid::new_v4();
     sqlx::query!(
@@ -1248,14 +1295,12 @@ async fn test_get_session_by_draft_id() {
         .unwrap();
 
     assert_eq!(missing.status(), StatusCode::NOT_FOUND);
-
-    common::cleanup_database(&pool).await;
 }
 
 #[tokio::test]
 async fn test_create_duplicate_session_returns_409() {
-    let (app_url, pool) = common::spawn_app().await;
-    let client = common::create_client();
+    let (app_url, pool) = test_support::spawn_app().await;
+    let client = test_support::create_client();
 
     // Create a draft
     let draft_id = Uuid::new_v4();
@@ -1303,14 +1348,12 @@ async fn test_create_duplicate_session_returns_409() {
     .unwrap();
 
     assert_eq!(session_count.count.unwrap(), 1);
-
-    common::cleanup_database(&pool).await;
 }
 
 #[tokio::test]
 async fn test_get_nonexistent_session() {
-    let (app_url, pool) = common::spawn_app().await;
-    let client = common::create_client();
+    let (app_url, _pool) = test_support::spawn_app().await;
+    let client = test_support::create_client();
 
     let nonexistent_id = Uuid::new_v4();
 
@@ -1321,14 +1364,12 @@ async fn test_get_nonexistent_session() {
         .unwrap();
 
     assert_eq!(response.status(), StatusCode::NOT_FOUND);
-
-    common::cleanup_database(&pool).await;
 }
 
 #[tokio::test]
 async fn test_propose_trade_nonexistent_team() {
-    let (app_url, pool) = common::spawn_app().await;
-    let client = common::create_client();
+    let (app_url, _pool) = test_support::spawn_app().await;
+    let client = test_support::create_client();
 
     // Create draft via API
     let draft_response = client
@@ -1378,6 +1419,953 @@ async fn test_propose_trade_nonexistent_team() {
         .unwrap();
 
     assert_eq!(response.status(), StatusCode::NOT_FOUND);
+}
+
+#[tokio::test]
+async fn test_rewind_session_clears_picks_and_reverses_trades() {
+    let (app_url, pool) = test_support::spawn_app().await;
+    let client = test_support::create_client();
+
+    let draft_id = Uuid::new_v4();
+    let session_id = Uuid::new_v4();
+    let team_a_id = Uuid::new_v4();
+    let team_b_id = Uuid::new_v4();
+    let pick_ids = [Uuid::new_v4(), Uuid::new_v4(), Uuid::new_v4()];
+    let player_1_id = Uuid::new_v4();
+    let player_2_id = Uuid::new_v4();
+    let trade_id = Uuid::new_v4();
+
+    sqlx::query!(
+        "INSERT INTO drafts (id, year, status, rounds, picks_per_round) VALUES ($1, 2026, 'InProgress', 1, 3::INTEGER)",
+        draft_id
+    )
+    .execute(&pool)
+    .await
+    .unwrap();
+
+    sqlx::query!(
+        "INSERT INTO teams (id, name, city, abbreviation, conference, division) VALUES ($1, 'Team A', 'Test', 'TMA', 'AFC', 'AFC East'), ($2, 'Team B', 'Test', 'TMB', 'NFC', 'NFC East')",
+        team_a_id,
+        team_b_id
+    )
+    .execute(&pool)
+    .await
+    .unwrap();
+
+    sqlx::query!(
+        "INSERT INTO players (id, first_name, last_name, position, draft_year) VALUES ($1, 'Player', 'One', 'QB', 2026), ($2, 'Player', 'Two', 'RB', 2026)",
+        player_1_id,
+        player_2_id
+    )
+    .execute(&pool)
+    .await
+    .unwrap();
+
+    // Pick 1 made by team A, then team A sends pick 3 to team B for pick 2,
+    // then team A uses pick 2. Session is now on pick 3.
+    sqlx::query!(
+        "INSERT INTO draft_picks (id, draft_id, round, pick_number, overall_pick, team_id, player_id, picked_at) VALUES ($1, $2, 1, 1, 1, $3, $4, NOW() - INTERVAL '30 minutes')",
+        pick_ids[0],
+        draft_id,
+        team_a_id,
+        player_1_id
+    )
+    .execute(&pool)
+    .await
+    .unwrap();
+
+    sqlx::query!(
+        "INSERT INTO draft_picks (id, draft_id, round, pick_number, overall_pick, team_id, player_id, picked_at) VALUES ($1, $2, 1, 2, 2, $3, $4, NOW() - INTERVAL '10 minutes')",
+        pick_ids[1],
+        draft_id,
+        team_a_id,
+        player_2_id
+    )
+    .execute(&pool)
+    .await
+    .unwrap();
+
+    sqlx::query!(
+        "INSERT INTO draft_picks (id, draft_id, round, pick_number, overall_pick, team_id) VALUES ($1, $2, 1, 3, 3, $3)",
+        pick_ids[2],
+        draft_id,
+        team_b_id
+    )
+    .execute(&pool)
+    .await
+    .unwrap();
+
+    sqlx::query!(
+        "INSERT INTO draft_sessions (id, draft_id, status, current_pick_number, time_per_pick_seconds, auto_pick_enabled, started_at) VALUES ($1, $2, 'InProgress', 3, 300, false, NOW() - INTERVAL '1 hour')",
+        session_id,
+        draft_id
+    )
+    .execute(&pool)
+    .await
+    .unwrap();
+
+    sqlx::query!(
+        "INSERT INTO pick_trades (id, session_id, from_team_id, to_team_id, status, from_team_value, to_team_value, value_difference, responded_at) VALUES ($1, $2, $3, $4, 'Accepted', 2200, 2600, 400, NOW() - INTERVAL '20 minutes')",
+        trade_id,
+        session_id,
+        team_a_id,
+        team_b_id
+    )
+    .execute(&pool)
+    .await
+    .unwrap();
+
+    sqlx::query!(
+        "INSERT INTO pick_trade_details (trade_id, pick_id, direction, pick_value) VALUES ($1, $2, 'FromTeam', 2200), ($1, $3, 'ToTeam', 2600)",
+        trade_id,
+        pick_ids[2],
+        pick_ids[1]
+    )
+    .execute(&pool)
+    .await
+    .unwrap();
+
+    let response = client
+        .post(format!("{}/api/v1/sessions/{}/rewind", app_url, session_id))
+        .json(&json!({ "target_pick_number": 2 }))
+        .send()
+        .await
+        .unwrap();
+
+    assert_eq!(response.status(), StatusCode::OK);
+
+    let session: Value = response.json().await.unwrap();
+    assert_eq!(session["current_pick_number"], 2);
+    assert_eq!(session["status"], "Paused");
+
+    // Pick 1 is untouched, pick 2 is cleared
+    let picks = sqlx::query!(
+        "SELECT id, team_id, player_id FROM draft_picks WHERE draft_id = $1 ORDER BY overall_pick",
+        draft_id
+    )
+    .fetch_all(&pool)
+    .await
+    .unwrap();
+    assert_eq!(picks[0].player_id, Some(player_1_id));
+    assert!(picks[1].player_id.is_none());
+
+    // The trade made while pick 2 was on the clock is undone
+    assert_eq!(picks[1].team_id, team_b_id);
+    assert_eq!(picks[2].team_id, team_a_id);
+
+    let trade_count = sqlx::query!(
+        "SELECT COUNT(*) as count FROM pick_trades WHERE id = $1",
+        trade_id
+    )
+    .fetch_one(&pool)
+    .await
+    .unwrap();
+    assert_eq!(trade_count.count, Some(0));
+
+    let db_draft = sqlx::query!("SELECT status FROM drafts WHERE id = $1", draft_id)
+        .fetch_one(&pool)
+        .await
+        .unwrap();
+    assert_eq!(db_draft.status, "Paused");
+
+    let event = sqlx::query!(
+        "SELECT event_data FROM draft_events WHERE session_id = $1 AND event_type = 'SessionRewound'",
+        session_id
+    )
+    .fetch_one(&pool)
+    .await
+    .unwrap();
+    assert_eq!(event.event_data["from_pick_number"], 3);
+    assert_eq!(event.event_data["to_pick_number"], 2);
+    assert_eq!(
+        event.event_data["reversed_trade_ids"][0],
+        trade_id.to_string()
+    );
+}
+
+#[tokio::test]
+async fn test_rewind_session_rejects_forward_target() {
+    let (app_url, pool) = test_support::spawn_app().await;
+    let client = test_support::create_client();
+
+    let draft_id = Uuid::new_v4();
+    let session_id = Uuid::new_v4();
+
+    sqlx::query!(
+        "INSERT INTO drafts (id, year, status, rounds, picks_per_round) VALUES ($1, 2026, 'InProgress', 7, 32::INTEGER)",
+        draft_id
+    )
+    .execute(&pool)
+    .await
+    .unwrap();
+
+    sqlx::query!(
+        "INSERT INTO draft_sessions (id, draft_id, status, current_pick_number, time_per_pick_seconds, auto_pick_enabled) VALUES ($1, $2, 'InProgress', 3, 300, false)",
+        session_id,
+        draft_id
+    )
+    .execute(&pool)
+    .await
+    .unwrap();
+
+    let response = client
+        .post(format!("{}/api/v1/sessions/{}/rewind", app_url, session_id))
+        .json(&json!({ "target_pick_number": 5 }))
+        .send()
+        .await
+        .unwrap();
+
+    assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+
+    let db_session = sqlx::query!(
+        "SELECT status, current_pick_number FROM draft_sessions WHERE id = $1",
+        session_id
+    )
+    .fetch_one(&pool)
+    .await
+    .unwrap();
+    assert_eq!(db_session.status, "InProgress");
+    assert_eq!(db_session.current_pick_number, 3);
+}
+
+#[tokio::test]
+async fn test_manual_final_pick_completes_session_and_draft() {
+    let (app_url, pool) = test_support::spawn_app().await;
+    let client = test_support::create_client();
+
+    let draft_id = Uuid::new_v4();
+    let session_id = Uuid::new_v4();
+    let team_id = Uuid::new_v4();
+    let pick_id = Uuid::new_v4();
+    let player_id = Uuid::new_v4();
+
+    sqlx::query!(
+        "INSERT INTO drafts (id, year, status, rounds, picks_per_round) VALUES ($1, 2026, 'InProgress', 1, 1::INTEGER)",
+        draft_id
+    )
+    .execute(&pool)
+    .await
+    .unwrap();
+
+    sqlx::query!(
+        "INSERT INTO teams (id, name, city, abbreviation, conference, division) VALUES ($1, 'User Team', 'Test', 'USR', 'NFC', 'NFC East')",
+        team_id
+    )
+    .execute(&pool)
+    .await
+    .unwrap();
+
+    sqlx::query!(
+        "INSERT INTO players (id, first_name, last_name, position, draft_year) VALUES ($1, 'Last', 'Pick', 'K', 2026)",
+        player_id
+    )
+    .execute(&pool)
+    .await
+    .unwrap();
 
-    common::cleanup_database(&pool).await;
+    sqlx::query!(
+        "INSERT INTO draft_picks (id, draft_id, round, pick_number, overall_pick, team_id) VALUES ($1, $2, 1, 1, 1, $3)",
+        pick_id,
+        draft_id,
+        team_id
+    )
+    .execute(&pool)
+    .await
+    .unwrap();
+
+    sqlx::query!(
+        "INSERT INTO draft_sessions (id, draft_id, status, current_pick_number, time_per_pick_seconds, auto_pick_enabled, controlled_team_ids) VALUES ($1, $2, 'InProgress', 1, 300, false, $3)",
+        session_id,
+        draft_id,
+        &[team_id]
+    )
+    .execute(&pool)
+    .await
+    .unwrap();
+
+    let response = client
+        .post(format!("{}/api/v1/picks/{}/make", app_url, pick_id))
+        .json(&json!({ "player_id": player_id }))
+        .send()
+        .await
+        .unwrap();
+
+    assert_eq!(response.status(), StatusCode::OK);
+
+    let db_session = sqlx::query!(
+        "SELECT status, completed_at FROM draft_sessions WHERE id = $1",
+        session_id
+    )
+    .fetch_one(&pool)
+    .await
+    .unwrap();
+    assert_eq!(db_session.status, "Completed");
+    assert!(db_session.completed_at.is_some());
+
+    let db_draft = sqlx::query!("SELECT status FROM drafts WHERE id = $1", draft_id)
+        .fetch_one(&pool)
+        .await
+        .unwrap();
+    assert_eq!(db_draft.status, "Completed");
+
+    let events = sqlx::query!(
+        "SELECT event_type FROM draft_events WHERE session_id = $1 ORDER BY created_at",
+        session_id
+    )
+    .fetch_all(&pool)
+    .await
+    .unwrap();
+    let event_types: Vec<&str> = events.iter().map(|e| e.event_type.as_str()).collect();
+    assert_eq!(
+        event_types,
+        vec!["PickMade", "SessionCompleted", "DraftCompleted"]
+    );
+}
+
+#[tokio::test]
+async fn test_manual_pick_advances_session_with_event() {
+    let (app_url, pool) = test_support::spawn_app().await;
+    let client = test_support::create_client();
+
+    let draft_id = Uuid::new_v4();
+    let session_id = Uuid::new_v4();
+    let team_id = Uuid::new_v4();
+    let first_pick_id = Uuid::new_v4();
+    let second_pick_id = Uuid::new_v4();
+    let player_id = Uuid::new_v4();
+
+    sqlx::query(
+        "INSERT INTO drafts (id, year, status, rounds, picks_per_round) VALUES ($1, 2026, 'InProgress', 1, 2)",
+    )
+    .bind(draft_id)
+    .execute(&pool)
+    .await
+    .unwrap();
+
+    sqlx::query(
+        "INSERT INTO teams (id, name, city, abbreviation, conference, division) VALUES ($1, 'User Team', 'Test', 'USR', 'NFC', 'NFC East')",
+    )
+    .bind(team_id)
+    .execute(&pool)
+    .await
+    .unwrap();
+
+    sqlx::query(
+        "INSERT INTO players (id, first_name, last_name, position, draft_year) VALUES ($1, 'First', 'Pick', 'QB', 2026)",
+    )
+    .bind(player_id)
+    .execute(&pool)
+    .await
+    .unwrap();
+
+    for (pick_id, number) in [(first_pick_id, 1), (second_pick_id, 2)] {
+        sqlx::query(
+            "INSERT INTO draft_picks (id, draft_id, round, pick_number, overall_pick, team_id) VALUES ($1, $2, 1, $3, $3, $4)",
+        )
+        .bind(pick_id)
+        .bind(draft_id)
+        .bind(number)
+        .bind(team_id)
+        .execute(&pool)
+        .await
+        .unwrap();
+    }
+
+    sqlx::query(
+        "INSERT INTO draft_sessions (id, draft_id, status, current_pick_number, time_per_pick_seconds, auto_pick_enabled, controlled_team_ids) VALUES ($1, $2, 'InProgress', 1, 300, false, $3)",
+    )
+    .bind(session_id)
+    .bind(draft_id)
+    .bind(vec![team_id])
+    .execute(&pool)
+    .await
+    .unwrap();
+
+    let response = client
+        .post(format!("{}/api/v1/picks/{}/make", app_url, first_pick_id))
+        .json(&json!({ "player_id": player_id }))
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+
+    let current_pick: i32 =
+        sqlx::query_scalar("SELECT current_pick_number FROM draft_sessions WHERE id = $1")
+            .bind(session_id)
+            .fetch_one(&pool)
+            .await
+            .unwrap();
+    assert_eq!(current_pick, 2);
+
+    // Taking the same player again rolls back without advancing or recording
+    let response = client
+        .post(format!("{}/api/v1/picks/{}/make", app_url, second_pick_id))
+        .json(&json!({ "player_id": player_id }))
+        .send()
+        .await
+        .unwrap();
+    assert!(response.status().is_client_error());
+
+    let current_pick: i32 =
+        sqlx::query_scalar("SELECT current_pick_number FROM draft_sessions WHERE id = $1")
+            .bind(session_id)
+            .fetch_one(&pool)
+            .await
+            .unwrap();
+    assert_eq!(current_pick, 2);
+
+    let event_types: Vec<String> = sqlx::query_scalar(
+        "SELECT event_type FROM draft_events WHERE session_id = $1 ORDER BY created_at",
+    )
+    .bind(session_id)
+    .fetch_all(&pool)
+    .await
+    .unwrap();
+    assert_eq!(event_types, vec!["PickMade"]);
+}
+
+#[tokio::test]
+async fn test_update_session_settings_while_paused() {
+    let (app_url, pool) = test_support::spawn_app().await;
+    let client = test_support::create_client();
+
+    let draft_id = Uuid::new_v4();
+    let session_id = Uuid::new_v4();
+    let team_id = Uuid::new_v4();
+
+    sqlx::query!(
+        "INSERT INTO drafts (id, year, status, rounds, picks_per_round) VALUES ($1, 2026, 'InProgress', 1, 1::INTEGER)",
+        draft_id
+    )
+    .execute(&pool)
+    .await
+    .unwrap();
+
+    sqlx::query!(
+        "INSERT INTO teams (id, name, city, abbreviation, conference, division) VALUES ($1, 'Titans', 'Tennessee', 'TEN', 'AFC', 'AFC South')",
+        team_id
+    )
+    .execute(&pool)
+    .await
+    .unwrap();
+
+    sqlx::query!(
+        "INSERT INTO draft_picks (id, draft_id, round, pick_number, overall_pick, team_id) VALUES ($1, $2, 1, 1, 1, $3)",
+        Uuid::new_v4(),
+        draft_id,
+        team_id
+    )
+    .execute(&pool)
+    .await
+    .unwrap();
+
+    sqlx::query!(
+        "INSERT INTO draft_sessions (id, draft_id, status, current_pick_number, time_per_pick_seconds, auto_pick_enabled) VALUES ($1, $2, 'Paused', 1, 300, false)",
+        session_id,
+        draft_id
+    )
+    .execute(&pool)
+    .await
+    .unwrap();
+
+    let response = client
+        .patch(format!("{}/api/v1/sessions/{}", app_url, session_id))
+        .json(&json!({
+            "time_per_pick_seconds": 90,
+            "auto_pick_enabled": true,
+            "chart_type": "RichHill",
+            "controlled_team_ids": [team_id]
+        }))
+        .send()
+        .await
+        .unwrap();
+
+    assert_eq!(response.status(), StatusCode::OK);
+
+    let session: Value = response.json().await.unwrap();
+    assert_eq!(session["status"], "Paused");
+    assert_eq!(session["time_per_pick_seconds"], 90);
+    assert_eq!(session["auto_pick_enabled"], true);
+    assert_eq!(session["chart_type"], "RichHill");
+    assert_eq!(session["controlled_team_ids"], json!([team_id]));
+
+    // Partial update leaves other settings untouched
+    let response = client
+        .patch(format!("{}/api/v1/sessions/{}", app_url, session_id))
+        .json(&json!({ "auto_pick_enabled": false }))
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+    let session: Value = response.json().await.unwrap();
+    assert_eq!(session["auto_pick_enabled"], false);
+    assert_eq!(session["time_per_pick_seconds"], 90);
+
+    let events = sqlx::query!(
+        "SELECT event_data FROM draft_events WHERE session_id = $1 AND event_type = 'SettingsChanged' ORDER BY created_at",
+        session_id
+    )
+    .fetch_all(&pool)
+    .await
+    .unwrap();
+
+    assert_eq!(events.len(), 2);
+    assert_eq!(events[0].event_data["changes"]["time_per_pick_seconds"], 90);
+    assert_eq!(
+        events[1].event_data["changes"],
+        json!({ "auto_pick_enabled": false })
+    );
+}
+
+#[tokio::test]
+async fn test_update_session_settings_validation() {
+    let (app_url, pool) = test_support::spawn_app().await;
+    let client = test_support::create_client();
+
+    let draft_id = Uuid::new_v4();
+    let session_id = Uuid::new_v4();
+
+    sqlx::query!(
+        "INSERT INTO drafts (id, year, status, rounds, picks_per_round) VALUES ($1, 2026, 'InProgress', 7, 32::INTEGER)",
+        draft_id
+    )
+    .execute(&pool)
+    .await
+    .unwrap();
+
+    sqlx::query!(
+        "INSERT INTO draft_sessions (id, draft_id, status, current_pick_number, time_per_pick_seconds, auto_pick_enabled) VALUES ($1, $2, 'InProgress', 1, 300, false)",
+        session_id,
+        draft_id
+    )
+    .execute(&pool)
+    .await
+    .unwrap();
+
+    let url = format!("{}/api/v1/sessions/{}", app_url, session_id);
+
+    // Settings are locked while the clock is running
+    let response = client
+        .patch(&url)
+        .json(&json!({ "time_per_pick_seconds": 60 }))
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+
+    sqlx::query!(
+        "UPDATE draft_sessions SET status = 'Paused' WHERE id = $1",
+        session_id
+    )
+    .execute(&pool)
+    .await
+    .unwrap();
+
+    let response = client
+        .patch(&url)
+        .json(&json!({ "time_per_pick_seconds": 5 }))
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+
+    // Team without picks in this draft
+    let response = client
+        .patch(&url)
+        .json(&json!({ "controlled_team_ids": [Uuid::new_v4()] }))
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+
+    let response = client.patch(&url).json(&json!({})).send().await.unwrap();
+    assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+
+    let response = client
+        .patch(format!("{}/api/v1/sessions/{}", app_url, Uuid::new_v4()))
+        .json(&json!({ "auto_pick_enabled": true }))
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::NOT_FOUND);
+}
+
+#[tokio::test]
+async fn test_resume_restores_remaining_pick_time() {
+    let (app_url, pool) = test_support::spawn_app().await;
+    let client = test_support::create_client();
+
+    let draft_id = Uuid::new_v4();
+    let session_id = Uuid::new_v4();
+
+    sqlx::query!(
+        "INSERT INTO drafts (id, year, status, rounds, picks_per_round) VALUES ($1, 2026, 'Paused', 7, 32::INTEGER)",
+        draft_id
+    )
+    .execute(&pool)
+    .await
+    .unwrap();
+
+    // Pick 1 ran for 50 seconds before a pause that started 50 seconds ago
+    sqlx::query!(
+        "INSERT INTO draft_sessions (id, draft_id, status, current_pick_number, time_per_pick_seconds, auto_pick_enabled, started_at, pick_started_at, paused_at) VALUES ($1, $2, 'Paused', 1, 300, false, NOW() - INTERVAL '100 seconds', NOW() - INTERVAL '100 seconds', NOW() - INTERVAL '50 seconds')",
+        session_id,
+        draft_id
+    )
+    .execute(&pool)
+    .await
+    .unwrap();
+
+    let session: Value = client
+        .get(format!("{}/api/v1/sessions/{}", app_url, session_id))
+        .send()
+        .await
+        .unwrap()
+        .json()
+        .await
+        .unwrap();
+    assert!(session["elapsed_paused_ms"].as_i64().unwrap() >= 50_000);
+    assert_eq!(session["time_remaining_seconds"], 250);
+
+    let response = client
+        .post(format!("{}/api/v1/sessions/{}/start", app_url, session_id))
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+
+    let session: Value = response.json().await.unwrap();
+    assert_eq!(session["status"], "InProgress");
+    assert!(session["elapsed_paused_ms"].as_i64().unwrap() >= 50_000);
+    let remaining = session["time_remaining_seconds"].as_i64().unwrap();
+    assert!((248..=250).contains(&remaining), "remaining {}", remaining);
+
+    let db_session = sqlx::query!(
+        "SELECT paused_at, elapsed_paused_ms FROM draft_sessions WHERE id = $1",
+        session_id
+    )
+    .fetch_one(&pool)
+    .await
+    .unwrap();
+    assert!(db_session.paused_at.is_none());
+    assert!(db_session.elapsed_paused_ms >= 50_000);
+}
+
+#[tokio::test]
+async fn test_create_session_with_scheduled_start() {
+    let (app_url, pool) = test_support::spawn_app().await;
+    let client = test_support::create_client();
+
+    let draft_id = Uuid::new_v4();
+    sqlx::query!(
+        "INSERT INTO drafts (id, year, status, rounds, picks_per_round) VALUES ($1, 2026, 'NotStarted', 7, 32::INTEGER)",
+        draft_id
+    )
+    .execute(&pool)
+    .await
+    .unwrap();
+
+    // A start time in the past is rejected
+    let response = client
+        .post(format!("{}/api/v1/sessions", app_url))
+        .json(&json!({
+            "draft_id": draft_id,
+            "time_per_pick_seconds": 300,
+            "auto_pick_enabled": false,
+            "scheduled_start_at": (chrono::Utc::now() - chrono::Duration::hours(1)).to_rfc3339()
+        }))
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+
+    let at = chrono::Utc::now() + chrono::Duration::days(2);
+    let response = client
+        .post(format!("{}/api/v1/sessions", app_url))
+        .json(&json!({
+            "draft_id": draft_id,
+            "time_per_pick_seconds": 300,
+            "auto_pick_enabled": false,
+            "scheduled_start_at": at.to_rfc3339()
+        }))
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::CREATED);
+
+    let session: Value = response.json().await.unwrap();
+    assert_eq!(session["status"], "NotStarted");
+    let scheduled =
+        chrono::DateTime::parse_from_rfc3339(session["scheduled_start_at"].as_str().unwrap())
+            .unwrap();
+    assert_eq!(scheduled.timestamp(), at.timestamp());
+
+    // Not due yet, so the scheduler leaves it alone
+    let state = api::AppState::new(pool.clone(), None);
+    let session_id = Uuid::parse_str(session["id"].as_str().unwrap()).unwrap();
+    let started = api::scheduler::start_due_sessions(&state).await.unwrap();
+    assert!(!started.contains(&session_id));
+}
+
+#[tokio::test]
+async fn test_scheduler_starts_due_session() {
+    let (_app_url, pool) = test_support::spawn_app().await;
+
+    let draft_id = Uuid::new_v4();
+    let session_id = Uuid::new_v4();
+
+    sqlx::query!(
+        "INSERT INTO drafts (id, year, status, rounds, picks_per_round) VALUES ($1, 2026, 'NotStarted', 7, 32::INTEGER)",
+        draft_id
+    )
+    .execute(&pool)
+    .await
+    .unwrap();
+
+    sqlx::query!(
+        "INSERT INTO draft_sessions (id, draft_id, status, current_pick_number, time_per_pick_seconds, auto_pick_enabled, scheduled_start_at) VALUES ($1, $2, 'NotStarted', 1, 300, false, NOW() - INTERVAL '1 second')",
+        session_id,
+        draft_id
+    )
+    .execute(&pool)
+    .await
+    .unwrap();
+
+    let state = api::AppState::new(pool.clone(), None);
+    let started = api::scheduler::start_due_sessions(&state).await.unwrap();
+    assert!(started.contains(&session_id));
+
+    let db_session = sqlx::query!(
+        "SELECT status, started_at, scheduled_start_at FROM draft_sessions WHERE id = $1",
+        session_id
+    )
+    .fetch_one(&pool)
+    .await
+    .unwrap();
+    assert_eq!(db_session.status, "InProgress");
+    assert!(db_session.started_at.is_some());
+    assert!(db_session.scheduled_start_at.is_none());
+
+    let db_draft = sqlx::query!("SELECT status FROM drafts WHERE id = $1", draft_id)
+        .fetch_one(&pool)
+        .await
+        .unwrap();
+    assert_eq!(db_draft.status, "InProgress");
+
+    let events = sqlx::query!(
+        "SELECT event_type FROM draft_events WHERE session_id = $1",
+        session_id
+    )
+    .fetch_all(&pool)
+    .await
+    .unwrap();
+    assert!(events.iter().any(|e| e.event_type == "SessionStarted"));
+
+    // A second pass finds nothing left to start
+    let started = api::scheduler::start_due_sessions(&state).await.unwrap();
+    assert!(!started.contains(&session_id));
+}
+
+#[tokio::test]
+async fn test_session_presence_without_viewers() {
+    let (app_url, pool) = test_support::spawn_app().await;
+    let client = test_support::create_client();
+
+    let draft_id = Uuid::new_v4();
+    let session_id = Uuid::new_v4();
+    sqlx::query!(
+        "INSERT INTO drafts (id, year, status, rounds, picks_per_round) VALUES ($1, 2026, 'NotStarted', 7, 32::INTEGER)",
+        draft_id
+    )
+    .execute(&pool)
+    .await
+    .unwrap();
+    sqlx::query!(
+        "INSERT INTO draft_sessions (id, draft_id) VALUES ($1, $2)",
+        session_id,
+        draft_id
+    )
+    .execute(&pool)
+    .await
+    .unwrap();
+
+    let response = client
+        .get(format!(
+            "{}/api/v1/sessions/{}/presence",
+            app_url, session_id
+        ))
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+    let presence: Value = response.json().await.unwrap();
+    assert_eq!(presence["session_id"], session_id.to_string());
+    assert_eq!(presence["connection_count"], 0);
+    assert_eq!(presence["anonymous_count"], 0);
+    assert_eq!(presence["display_names"], json!([]));
+
+    let response = client
+        .get(format!(
+            "{}/api/v1/sessions/{}/presence",
+            app_url,
+            Uuid::new_v4()
+        ))
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::NOT_FOUND);
+}
+
+#[tokio::test]
+async fn test_session_clock_ticks_and_warns() {
+    let (_app_url, pool) = test_support::spawn_app().await;
+
+    let draft_id = Uuid::new_v4();
+    let session_id = Uuid::new_v4();
+
+    sqlx::query!(
+        "INSERT INTO drafts (id, year, status, rounds, picks_per_round) VALUES ($1, 2026, 'InProgress', 7, 32::INTEGER)",
+        draft_id
+    )
+    .execute(&pool)
+    .await
+    .unwrap();
+
+    // 32 seconds left on a 300 second clock
+    sqlx::query!(
+        "INSERT INTO draft_sessions (id, draft_id, status, current_pick_number, time_per_pick_seconds, auto_pick_enabled, pick_started_at) VALUES ($1, $2, 'InProgress', 3, 300, false, NOW() - INTERVAL '268 seconds')",
+        session_id,
+        draft_id
+    )
+    .execute(&pool)
+    .await
+    .unwrap();
+
+    let state = api::AppState::new(pool.clone(), None);
+    let (tx, mut rx) = tokio::sync::mpsc::channel(16);
+    state
+        .ws_manager
+        .add_connection(Uuid::new_v4(), session_id, tx);
+
+    let mut tracker = api::clock::ClockTracker::default();
+    api::clock::tick_session_clocks(&state, &mut tracker)
+        .await
+        .unwrap();
+
+    let tick: Value = serde_json::from_str(&rx.try_recv().unwrap()).unwrap();
+    assert_eq!(tick["type"], "clock_tick");
+    assert_eq!(tick["current_pick_number"], 3);
+    assert!(tick["time_remaining"].as_i64().unwrap() > 30);
+    assert!(rx.try_recv().is_err());
+
+    // Move the clock past the thirty second mark
+    sqlx::query!(
+        "UPDATE draft_sessions SET pick_started_at = pick_started_at - INTERVAL '5 seconds' WHERE id = $1",
+        session_id
+    )
+    .execute(&pool)
+    .await
+    .unwrap();
+
+    api::clock::tick_session_clocks(&state, &mut tracker)
+        .await
+        .unwrap();
+
+    let tick: Value = serde_json::from_str(&rx.try_recv().unwrap()).unwrap();
+    assert_eq!(tick["type"], "clock_tick");
+    let warning: Value = serde_json::from_str(&rx.try_recv().unwrap()).unwrap();
+    assert_eq!(warning["type"], "clock_warning");
+    assert_eq!(warning["level"], "thirty_seconds");
+    assert!(warning["time_remaining"].as_i64().unwrap() <= 30);
+
+    // The threshold only fires once per pick
+    api::clock::tick_session_clocks(&state, &mut tracker)
+        .await
+        .unwrap();
+    let tick: Value = serde_json::from_str(&rx.try_recv().unwrap()).unwrap();
+    assert_eq!(tick["type"], "clock_tick");
+    assert!(rx.try_recv().is_err());
+}
+
+#[tokio::test]
+async fn test_outbox_relay_publishes_lost_events() {
+    let (_app_url, pool) = test_support::spawn_app().await;
+
+    let draft_id = Uuid::new_v4();
+    let session_id = Uuid::new_v4();
+
+    sqlx::query!(
+        "INSERT INTO drafts (id, year, status, rounds, picks_per_round) VALUES ($1, 2026, 'NotStarted', 7, 32::INTEGER)",
+        draft_id
+    )
+    .execute(&pool)
+    .await
+    .unwrap();
+
+    sqlx::query!(
+        "INSERT INTO draft_sessions (id, draft_id, status, current_pick_number, time_per_pick_seconds, auto_pick_enabled, scheduled_start_at) VALUES ($1, $2, 'NotStarted', 1, 300, false, NOW() - INTERVAL '1 second')",
+        session_id,
+        draft_id
+    )
+    .execute(&pool)
+    .await
+    .unwrap();
+
+    let state = api::AppState::new(pool.clone(), None);
+    let (tx, mut rx) = tokio::sync::mpsc::channel(16);
+    state
+        .ws_manager
+        .add_connection(Uuid::new_v4(), session_id, tx);
+
+    // Starting the session records its event with the change and publishes
+    // it on the spot
+    let started = api::scheduler::start_due_sessions(&state).await.unwrap();
+    assert!(started.contains(&session_id));
+    let status: Value = serde_json::from_str(&rx.try_recv().unwrap()).unwrap();
+    assert_eq!(status["type"], "draft_status");
+    assert_eq!(status["status"], "InProgress");
+    while rx.try_recv().is_ok() {}
+
+    let pending = sqlx::query_scalar!(
+        r#"SELECT COUNT(*) as "count!" FROM event_outbox o JOIN draft_events e ON e.id = o.event_id WHERE e.session_id = $1 AND o.published_at IS NULL"#,
+        session_id
+    )
+    .fetch_one(&pool)
+    .await
+    .unwrap();
+    assert_eq!(pending, 0);
+
+    // An event recorded without its broadcast, as if the instance stopped
+    // between the two, waits out the grace period and is then relayed
+    let event = domain::models::DraftEvent::session_paused(session_id);
+    state.event_repo.create(&event).await.unwrap();
+
+    let relayed = api::outbox::relay_pending_events(&state, std::time::Duration::from_secs(60))
+        .await
+        .unwrap();
+    assert_eq!(relayed, 0);
+    assert!(rx.try_recv().is_err());
+
+    let relayed = api::outbox::relay_pending_events(&state, std::time::Duration::ZERO)
+        .await
+        .unwrap();
+    assert_eq!(relayed, 1);
+    let status: Value = serde_json::from_str(&rx.try_recv().unwrap()).unwrap();
+    assert_eq!(status["type"], "draft_status");
+    assert_eq!(status["status"], "Paused");
+
+    let published_at = sqlx::query_scalar!(
+        "SELECT published_at FROM event_outbox WHERE event_id = $1",
+        event.id
+    )
+    .fetch_one(&pool)
+    .await
+    .unwrap();
+    assert!(published_at.is_some());
+
+    // Published events are not sent again
+    let relayed = api::outbox::relay_pending_events(&state, std::time::Duration::ZERO)
+        .await
+        .unwrap();
+    assert_eq!(relayed, 0);
 }
@@ -0,0 +1,139 @@
+//! `fields=` field selection acceptance tests
+
+use reqwest::StatusCode;
+use serde_json::{json, Value};
+
+#[tokio::test]
+async fn test_players_with_selected_fields() {
+    let (base_url, _pool) = test_support::spawn_app().await;
+    let client = test_support::create_client_with_role(domain::models::Role::Admin);
+
+    for first_name in ["Alpha", "Beta"] {
+        let response = client
+            .post(format!("{}/api/v1/players", base_url))
+            .json(&json!({
+                "first_name": first_name,
+                "last_name": "Prospect",
+                "position": "QB",
+                "college": "Ohio State",
+                "draft_year": 2026
+            }))
+            .send()
+            .await
+            .expect("Failed to create player");
+        assert_eq!(response.status(), StatusCode::CREATED);
+    }
+
+    let response = client
+        .get(format!(
+            "{}/api/v1/players?fields=last_name,first_name,position&sort=first_name",
+            base_url
+        ))
+        .send()
+        .await
+        .expect("Failed to list players");
+    assert_eq!(response.status(), StatusCode::OK);
+    assert_eq!(response.headers()["x-total-count"], "2");
+    let players: Vec<Value> = response.json().await.unwrap();
+    assert_eq!(
+        players,
+        vec![
+            json!({ "first_name": "Alpha", "last_name": "Prospect", "position": "QB" }),
+            json!({ "first_name": "Beta", "last_name": "Prospect", "position": "QB" }),
+        ]
+    );
+
+    // CSV columns follow the order the fields were listed in
+    let response = client
+        .get(format!(
+            "{}/api/v1/players?format=csv&fields=last_name,first_name&sort=first_name",
+            base_url
+        ))
+        .send()
+        .await
+        .expect("Failed to export players");
+    assert_eq!(response.status(), StatusCode::OK);
+    assert_eq!(
+        response.text().await.unwrap(),
+        "last_name,first_name\nProspect,Alpha\nProspect,Beta\n"
+    );
+
+    let response = client
+        .get(format!(
+            "{}/api/v1/players?fields=first_name,salary",
+            base_url
+        ))
+        .send()
+        .await
+        .expect("Failed to list players");
+    assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+    let body: Value = response.json().await.unwrap();
+    assert!(body["error"]
+        .as_str()
+        .unwrap()
+        .contains("Unknown field 'salary'"));
+}
+
+#[tokio::test]
+async fn test_draft_picks_with_selected_fields() {
+    let (base_url, _pool) = test_support::spawn_app().await;
+    let client = test_support::create_client_with_role(domain::models::Role::Admin);
+
+    let response = client
+        .post(format!("{}/api/v1/teams", base_url))
+        .json(&json!({
+            "name": "Fields Team",
+            "abbreviation": "FLD",
+            "city": "Field City",
+            "conference": "NFC",
+            "division": "NFC North"
+        }))
+        .send()
+        .await
+        .expect("Failed to create team");
+    assert_eq!(response.status(), StatusCode::CREATED);
+
+    let response = client
+        .post(format!("{}/api/v1/drafts", base_url))
+        .json(&json!({
+            "name": "Fields Draft",
+            "year": 2026,
+            "rounds": 2,
+            "picks_per_round": 1
+        }))
+        .send()
+        .await
+        .expect("Failed to create draft");
+    let draft: Value = response.json().await.unwrap();
+    let draft_id = draft["id"].as_str().unwrap();
+
+    let response = client
+        .post(format!(
+            "{}/api/v1/drafts/{}/initialize",
+            base_url, draft_id
+        ))
+        .send()
+        .await
+        .expect("Failed to initialize picks");
+    assert_eq!(response.status(), StatusCode::CREATED);
+
+    for path in ["picks", "picks/available"] {
+        let response = client
+            .get(format!(
+                "{}/api/v1/drafts/{}/{}?fields=overall_pick,player_id",
+                base_url, draft_id, path
+            ))
+            .send()
+            .await
+            .expect("Failed to list picks");
+        assert_eq!(response.status(), StatusCode::OK);
+        let picks: Vec<Value> = response.json().await.unwrap();
+        assert_eq!(
+            picks,
+            vec![
+                json!({ "overall_pick": 1, "player_id": null }),
+                json!({ "overall_pick": 2, "player_id": null }),
+            ]
+        );
+    }
+}
@@ -1,15 +1,13 @@
 //! Filtered sub-resource endpoint acceptance tests
 
-mod common;
-
 use serde_json::json;
 use std::time::Duration;
 use uuid::Uuid;
 
 #[tokio::test]
 async fn test_get_pending_trades_for_team() {
-    let (base_url, pool) = common::spawn_app().await;
-    let client = common::create_client();
+    let (base_url, pool) = test_support::spawn_app().await;
+    let client = test_support::create_client();
 
     // Create two teams
     let team1_id = Uuid::new_v4();
@@ -118,8 +116,8 @@ async fn test_get_pending_trades_for_team() {
 
 #[tokio::test]
 async fn test_get_pending_trades_empty() {
-    let (base_url, pool) = common::spawn_app().await;
-    let client = common::create_client();
+    let (base_url, pool) = test_support::spawn_app().await;
+    let client = test_support::create_client();
 
     let team_id = Uuid::new_v4();
     sqlx::query!(
@@ -148,8 +146,8 @@ async fn test_get_pending_trades_empty() {
 
 #[tokio::test]
 async fn test_get_team_scouting_reports_empty() {
-    let (base_url, pool) = common::spawn_app().await;
-    let client = common::create_client();
+    let (base_url, pool) = test_support::spawn_app().await;
+    let client = test_support::create_client();
 
     let team_id = Uuid::new_v4();
     sqlx::query!(
@@ -178,8 +176,8 @@ async fn test_get_team_scouting_reports_empty() {
 
 #[tokio::test]
 async fn test_get_player_combine_results_empty() {
-    let (base_url, pool) = common::spawn_app().await;
-    let client = common::create_client();
+    let (base_url, pool) = test_support::spawn_app().await;
+    let client = test_support::create_client();
 
     let player_id = Uuid::new_v4();
     sqlx::query!(
@@ -1,16 +1,12 @@
 //! Team Needs CRUD acceptance tests
 
-mod common;
-
 use serde_json::json;
 use std::time::Duration;
 
 #[tokio::test]
 async fn test_create_and_get_team_need() {
-    let (base_url, pool) = common::spawn_app().await;
-    let client = common::create_client();
-
-    common::cleanup_database(&pool).await;
+    let (base_url, pool) = test_support::spawn_app().await;
+    let client = test_support::create_client_with_role(domain::models::Role::Admin);
 
     // Create a team
     let team_response = client
@@ -80,16 +76,12 @@ async fn test_create_and_get_team_need() {
     // Verify API response matches database
     assert_eq!(need["position"].as_str().unwrap(), db_need.position);
     assert_eq!(need["priority"].as_i64().unwrap(), db_need.priority as i64);
-
-    common::cleanup_database(&pool).await;
 }
 
 #[tokio::test]
 async fn test_list_team_needs() {
-    let (base_url, pool) = common::spawn_app().await;
-    let client = common::create_client();
-
-    common::cleanup_database(&pool).await;
+    let (base_url, pool) = test_support::spawn_app().await;
+    let client = test_support::create_client_with_role(domain::models::Role::Admin);
 
     // Create a team
     let team_response = client
@@ -178,16 +170,12 @@ async fn test_list_team_needs() {
     assert_eq!(needs_list[1]["position"], "WR");
     assert_eq!(needs_list[2]["priority"], 10);
     assert_eq!(needs_list[2]["position"], "QB");
-
-    common::cleanup_database(&pool).await;
 }
 
 #[tokio::test]
 async fn test_update_team_need() {
-    let (base_url, pool) = common::spawn_app().await;
-    let client = common::create_client();
-
-    common::cleanup_database(&pool).await;
+    let (base_url, pool) = test_support::spawn_app().await;
+    let client = test_support::create_client_with_role(domain::models::Role::Admin);
 
     // Create team and team need
     let team_response = client
@@ -250,16 +238,12 @@ async fn test_update_team_need() {
 
     assert_eq!(db_need.priority, 3);
     assert_eq!(db_need.position, "RB");
-
-    common::cleanup_database(&pool).await;
 }
 
 #[tokio::test]
 async fn test_delete_team_need() {
-    let (base_url, pool) = common::spawn_app().await;
-    let client = common::create_client();
-
-    common::cleanup_database(&pool).await;
+    let (base_url, pool) = test_support::spawn_app().await;
+    let client = test_support::create_client_with_role(domain::models::Role::Admin);
 
     // Create team and team need
     let team_response = client
@@ -324,16 +308,12 @@ async fn test_delete_team_need() {
         .expect("Failed to get team need");
 
     assert_eq!(get_response.status(), 404);
-
-    common::cleanup_database(&pool).await;
 }
 
 #[tokio::test]
 async fn test_duplicate_team_position_error() {
-    let (base_url, pool) = common::spawn_app().await;
-    let client = common::create_client();
-
-    common::cleanup_database(&pool).await;
+    let (base_url, pool) = test_support::spawn_app().await;
+    let client = test_support::create_client_with_role(domain::models::Role::Admin);
 
     // Create team
     let team_response = client
@@ -393,16 +373,12 @@ async fn test_duplicate_team_position_error() {
     .expect("Failed to count team needs");
 
     assert_eq!(db_count.count, Some(1));
-
-    common::cleanup_database(&pool).await;
 }
 
 #[tokio::test]
 async fn test_invalid_priority_validation() {
-    let (base_url, pool) = common::spawn_app().await;
-    let client = common::create_client();
-
-    common::cleanup_database(&pool).await;
+    let (base_url, pool) = test_support::spawn_app().await;
+    let client = test_support::create_client_with_role(domain::models::Role::Admin);
 
     // Create team
     let team_response = client
@@ -462,16 +438,12 @@ async fn test_invalid_priority_validation() {
     .expect("Failed to count team needs");
 
     assert_eq!(db_count.count, Some(0));
-
-    common::cleanup_database(&pool).await;
 }
 
 #[tokio::test]
 async fn test_team_needs_cascade_delete() {
-    let (base_url, pool) = common::spawn_app().await;
-    let client = common::create_client();
-
-    common::cleanup_database(&pool).await;
+    let (base_url, pool) = test_support::spawn_app().await;
+    let client = test_support::create_client_with_role(domain::models::Role::Admin);
 
     // Create team
     let team_response = client
@@ -534,6 +506,4 @@ async fn test_team_needs_cascade_delete() {
     .expect("Failed to count team needs");
 
     assert_eq!(count_after.count, Some(0));
-
-    common::cleanup_database(&pool).await;
 }
@@ -2,17 +2,13 @@
 //!
 //! Tests cross-feature workflows involving teams, team needs, and scouting reports.
 
-mod common;
-
 use serde_json::json;
 use std::time::Duration;
 
 #[tokio::test]
 async fn test_team_needs_to_scouting_workflow() {
-    let (base_url, pool) = common::spawn_app().await;
-    let client = common::create_client();
-
-    common::cleanup_database(&pool).await;
+    let (base_url, pool) = test_support::spawn_app().await;
+    let client = test_support::create_client_with_role(domain::models::Role::Admin);
 
     // Step 1: Create a team
     let team_response = client
@@ -275,16 +271,12 @@ async fn test_team_needs_to_scouting_workflow() {
 
     assert_eq!(wr_reports.len(), 1);
     assert_eq!(wr_reports[0].grade, 8.8);
-
-    common::cleanup_database(&pool).await;
 }
 
 #[tokio::test]
 async fn test_multiple_teams_scouting_same_player() {
-    let (base_url, pool) = common::spawn_app().await;
-    let client = common::create_client();
-
-    common::cleanup_database(&pool).await;
+    let (base_url, pool) = test_support::spawn_app().await;
+    let client = test_support::create_client_with_role(domain::models::Role::Admin);
 
     // Create a highly-rated player
     let player_response = client
@@ -466,16 +458,12 @@ async fn test_multiple_teams_scouting_same_player() {
     assert_eq!(team1_data.len(), 1);
     assert_eq!(team1_data[0]["grade"], 9.5);
     assert_eq!(team1_data[0]["fit_grade"], "A");
-
-    common::cleanup_database(&pool).await;
 }
 
 #[tokio::test]
 async fn test_team_scouting_by_position_matching() {
-    let (base_url, pool) = common::spawn_app().await;
-    let client = common::create_client();
-
-    common::cleanup_database(&pool).await;
+    let (base_url, pool) = test_support::spawn_app().await;
+    let client = test_support::create_client_with_role(domain::models::Role::Admin);
 
     // Create team
     let team_response = client
@@ -707,16 +695,12 @@ async fn test_team_scouting_by_position_matching() {
 
     assert_eq!(cb_scouts.len(), 1);
     assert_eq!(cb_scouts[0].grade, 8.9);
-
-    common::cleanup_database(&pool).await;
 }
 
 #[tokio::test]
 async fn test_team_draft_board_generation() {
-    let (base_url, pool) = common::spawn_app().await;
-    let client = common::create_client();
-
-    common::cleanup_database(&pool).await;
+    let (base_url, pool) = test_support::spawn_app().await;
+    let client = test_support::create_client_with_role(domain::models::Role::Admin);
 
     // Create team
     let team_response = client
@@ -917,6 +901,4 @@ async fn test_team_draft_board_generation() {
         .expect("Failed to get reports");
 
     assert_eq!(reports_response.status(), 200);
-
-    common::cleanup_database(&pool).await;
 }
@@ -1,7 +1,5 @@
 //! Team seasons and draft order acceptance tests
 
-mod common;
-
 use std::time::Duration;
 use uuid::Uuid;
 
@@ -50,8 +48,8 @@ async fn insert_team_season(
 
 #[tokio::test]
 async fn test_list_team_seasons_by_year() {
-    let (base_url, pool) = common::spawn_app().await;
-    let client = common::create_client();
+    let (base_url, pool) = test_support::spawn_app().await;
+    let client = test_support::create_client();
 
     let team1_id = create_team(&pool, "Seasons Team A", "STA", "Alpha").await;
     let team2_id = create_team(&pool, "Seasons Team B", "STB", "Beta").await;
@@ -83,8 +81,8 @@ async fn test_list_team_seasons_by_year() {
 
 #[tokio::test]
 async fn test_list_team_seasons_empty_year() {
-    let (base_url, _pool) = common::spawn_app().await;
-    let client = common::create_client();
+    let (base_url, _pool) = test_support::spawn_app().await;
+    let client = test_support::create_client();
 
     let response = client
         .get(&format!("{}/api/v1/team-seasons?year=1999", base_url))
@@ -101,8 +99,8 @@ async fn test_list_team_seasons_empty_year() {
 
 #[tokio::test]
 async fn test_get_team_season() {
-    let (base_url, pool) = common::spawn_app().await;
-    let client = common::create_client();
+    let (base_url, pool) = test_support::spawn_app().await;
+    let client = test_support::create_client();
 
     let team_id = create_team(&pool, "Season Get Team", "SGT", "GetCity").await;
     insert_team_season(&pool, team_id, 2025, 12, 5, 0, Some(20)).await;
@@ -130,8 +128,8 @@ async fn test_get_team_season() {
 
 #[tokio::test]
 async fn test_get_team_season_not_found() {
-    let (base_url, _pool) = common::spawn_app().await;
-    let client = common::create_client();
+    let (base_url, _pool) = test_support::spawn_app().await;
+    let client = test_support::create_client();
 
     let random_id = Uuid::new_v4();
 
@@ -150,8 +148,8 @@ async fn test_get_team_season_not_found() {
 
 #[tokio::test]
 async fn test_draft_order_by_year() {
-    let (base_url, pool) = common::spawn_app().await;
-    let client = common::create_client();
+    let (base_url, pool) = test_support::spawn_app().await;
+    let client = test_support::create_client();
 
     let team1_id = create_team(&pool, "Order Team 1", "OT1", "City1").await;
     let team2_id = create_team(&pool, "Order Team 2", "OT2", "City2").await;
@@ -184,8 +182,8 @@ async fn test_draft_order_by_year() {
 
 #[tokio::test]
 async fn test_draft_order_empty_year() {
-    let (base_url, _pool) = common::spawn_app().await;
-    let client = common::create_client();
+    let (base_url, _pool) = test_support::spawn_app().await;
+    let client = test_support::create_client();
 
     let response = client
         .get(&format!("{}/api/v1/draft-order?year=2050", base_url))
@@ -1,14 +1,12 @@
 //! Team CRUD acceptance tests
 
-mod common;
-
 use serde_json::json;
 use std::time::Duration;
 
 #[tokio::test]
 async fn test_create_and_get_team() {
-    let (base_url, pool) = common::spawn_app().await;
-    let client = common::create_client();
+    let (base_url, pool) = test_support::spawn_app().await;
+    let client = test_support::create_client_with_role(domain::models::Role::Admin);
 
     // Create team
     let create_response = client
@@ -64,3 +62,294 @@ async fn test_create_and_get_team() {
     assert_eq!(team["name"].as_str().unwrap(), db_team.name);
     assert_eq!(team["abbreviation"].as_str().unwrap(), db_team.abbreviation);
 }
+
+#[tokio::test]
+async fn test_patch_team() {
+    let (base_url, _pool) = test_support::spawn_app().await;
+    let client = test_support::create_client_with_role(domain::models::Role::Admin);
+
+    let mut ids = Vec::new();
+    for (name, abbreviation, city) in [
+        ("Dallas Cowboys", "DAL", "Dallas"),
+        ("New York Giants", "NYG", "New York"),
+    ] {
+        let response = client
+            .post(format!("{}/api/v1/teams", base_url))
+            .json(&json!({
+                "name": name,
+                "abbreviation": abbreviation,
+                "city": city,
+                "conference": "NFC",
+                "division": "NFC East"
+            }))
+            .timeout(Duration::from_secs(5))
+            .send()
+            .await
+            .expect("Failed to create team");
+        let team: serde_json::Value = response.json().await.expect("Failed to parse JSON");
+        ids.push(team["id"].as_str().unwrap().to_string());
+    }
+
+    let response = client
+        .patch(format!("{}/api/v1/teams/{}", base_url, ids[0]))
+        .json(&json!({ "city": "Arlington" }))
+        .timeout(Duration::from_secs(5))
+        .send()
+        .await
+        .expect("Failed to patch team");
+    assert_eq!(response.status(), 200);
+    let team: serde_json::Value = response.json().await.expect("Failed to parse JSON");
+    assert_eq!(team["city"], "Arlington");
+    assert_eq!(team["name"], "Dallas Cowboys");
+    assert_eq!(team["abbreviation"], "DAL");
+
+    // Division alone would no longer match the conference
+    let response = client
+        .patch(format!("{}/api/v1/teams/{}", base_url, ids[0]))
+        .json(&json!({ "division": "AFC East" }))
+        .timeout(Duration::from_secs(5))
+        .send()
+        .await
+        .expect("Failed to patch team");
+    assert_eq!(response.status(), 400);
+
+    let response = client
+        .patch(format!("{}/api/v1/teams/{}", base_url, ids[1]))
+        .json(&json!({ "abbreviation": "DAL" }))
+        .timeout(Duration::from_secs(5))
+        .send()
+        .await
+        .expect("Failed to patch team");
+    assert_eq!(response.status(), 409);
+
+    let response = client
+        .patch(format!(
+            "{}/api/v1/teams/{}",
+            base_url,
+            uuid::Uuid::new_v4()
+        ))
+        .json(&json!({ "city": "Nowhere" }))
+        .timeout(Duration::from_secs(5))
+        .send()
+        .await
+        .expect("Failed to patch team");
+    assert_eq!(response.status(), 404);
+}
+
+#[tokio::test]
+async fn test_put_team() {
+    let (base_url, _pool) = test_support::spawn_app().await;
+    let client = test_support::create_client_with_role(domain::models::Role::Admin);
+
+    let response = client
+        .post(format!("{}/api/v1/teams", base_url))
+        .json(&json!({
+            "name": "Oakland Raiders",
+            "abbreviation": "OAK",
+            "city": "Oakland",
+            "conference": "AFC",
+            "division": "AFC West"
+        }))
+        .timeout(Duration::from_secs(5))
+        .send()
+        .await
+        .expect("Failed to create team");
+    let team: serde_json::Value = response.json().await.expect("Failed to parse JSON");
+    let team_id = team["id"].as_str().unwrap();
+
+    let response = client
+        .put(format!("{}/api/v1/teams/{}", base_url, team_id))
+        .json(&json!({
+            "name": "Las Vegas Raiders",
+            "abbreviation": "LV",
+            "city": "Las Vegas",
+            "conference": "AFC",
+            "division": "AFC West"
+        }))
+        .timeout(Duration::from_secs(5))
+        .send()
+        .await
+        .expect("Failed to put team");
+    assert_eq!(response.status(), 200);
+    let team: serde_json::Value = response.json().await.expect("Failed to parse JSON");
+    assert_eq!(team["id"], team_id);
+    assert_eq!(team["name"], "Las Vegas Raiders");
+    assert_eq!(team["abbreviation"], "LV");
+    assert_eq!(team["city"], "Las Vegas");
+
+    // Every field is required
+    let response = client
+        .put(format!("{}/api/v1/teams/{}", base_url, team_id))
+        .json(&json!({ "city": "Reno" }))
+        .timeout(Duration::from_secs(5))
+        .send()
+        .await
+        .expect("Failed to put team");
+    assert_eq!(response.status(), 422);
+}
+
+#[tokio::test]
+async fn test_delete_team() {
+    let (base_url, pool) = test_support::spawn_app().await;
+    let client = test_support::create_client_with_role(domain::models::Role::Admin);
+
+    let mut ids = Vec::new();
+    for (name, abbreviation) in [("Delete Me", "DLM"), ("Keep Picks", "KPK")] {
+        let response = client
+            .post(format!("{}/api/v1/teams", base_url))
+            .json(&json!({
+                "name": name,
+                "abbreviation": abbreviation,
+                "city": "Somewhere",
+                "conference": "AFC",
+                "division": "AFC North"
+            }))
+            .timeout(Duration::from_secs(5))
+            .send()
+            .await
+            .expect("Failed to create team");
+        let team: serde_json::Value = response.json().await.expect("Failed to parse JSON");
+        ids.push(team["id"].as_str().unwrap().to_string());
+    }
+
+    // A deleted team drops out of reads
+    let response = client
+        .delete(format!("{}/api/v1/teams/{}", base_url, ids[0]))
+        .timeout(Duration::from_secs(5))
+        .send()
+        .await
+        .expect("Failed to delete team");
+    assert_eq!(response.status(), 204);
+
+    let response = client
+        .get(format!("{}/api/v1/teams/{}", base_url, ids[0]))
+        .timeout(Duration::from_secs(5))
+        .send()
+        .await
+        .expect("Failed to get team");
+    assert_eq!(response.status(), 404);
+
+    // Give the other team picks, then try again
+    let response = client
+        .post(format!("{}/api/v1/drafts", base_url))
+        .json(&json!({
+            "name": "Delete Draft",
+            "year": 2026,
+            "rounds": 2,
+            "picks_per_round": 1
+        }))
+        .timeout(Duration::from_secs(5))
+        .send()
+        .await
+        .expect("Failed to create draft");
+    let draft: serde_json::Value = response.json().await.expect("Failed to parse JSON");
+    let draft_id = draft["id"].as_str().unwrap();
+
+    let response = client
+        .post(format!(
+            "{}/api/v1/drafts/{}/initialize",
+            base_url, draft_id
+        ))
+        .timeout(Duration::from_secs(5))
+        .send()
+        .await
+        .expect("Failed to initialize picks");
+    assert_eq!(response.status(), 201);
+
+    // Neither kind of delete goes through while the draft is still to run
+    for query in ["", "?cascade=true"] {
+        let response = client
+            .delete(format!("{}/api/v1/teams/{}{}", base_url, ids[1], query))
+            .timeout(Duration::from_secs(5))
+            .send()
+            .await
+            .expect("Failed to delete team");
+        assert_eq!(response.status(), 409);
+        let body: serde_json::Value = response.json().await.expect("Failed to parse JSON");
+        assert_eq!(body["code"], "RESOURCE_IN_USE");
+    }
+
+    sqlx::query("UPDATE drafts SET status = 'Completed' WHERE id = $1")
+        .bind(uuid::Uuid::parse_str(draft_id).unwrap())
+        .execute(&pool)
+        .await
+        .expect("Failed to complete draft");
+
+    // Deleting a team that holds picks only hides it, so an admin can
+    // bring it back with its picks intact
+    let response = client
+        .delete(format!("{}/api/v1/teams/{}", base_url, ids[1]))
+        .timeout(Duration::from_secs(5))
+        .send()
+        .await
+        .expect("Failed to delete team");
+    assert_eq!(response.status(), 204);
+
+    let picks: i64 = sqlx::query_scalar("SELECT COUNT(*) FROM draft_picks")
+        .fetch_one(&pool)
+        .await
+        .expect("Failed to count picks");
+    assert_eq!(picks, 2);
+
+    let response = test_support::create_client()
+        .post(format!(
+            "{}/api/v1/admin/teams/{}/restore",
+            base_url, ids[1]
+        ))
+        .timeout(Duration::from_secs(5))
+        .send()
+        .await
+        .expect("Failed to restore team");
+    assert_eq!(response.status(), 404);
+
+    let admin = test_support::create_client_with_role(domain::models::Role::Admin);
+    let response = admin
+        .post(format!(
+            "{}/api/v1/admin/teams/{}/restore",
+            base_url, ids[1]
+        ))
+        .timeout(Duration::from_secs(5))
+        .send()
+        .await
+        .expect("Failed to restore team");
+    assert_eq!(response.status(), 200);
+    let team: serde_json::Value = response.json().await.expect("Failed to parse JSON");
+    assert_eq!(team["abbreviation"], "KPK");
+
+    let response = admin
+        .post(format!(
+            "{}/api/v1/admin/teams/{}/restore",
+            base_url, ids[1]
+        ))
+        .timeout(Duration::from_secs(5))
+        .send()
+        .await
+        .expect("Failed to restore team");
+    assert_eq!(response.status(), 404);
+
+    let response = client
+        .delete(format!("{}/api/v1/teams/{}?cascade=true", base_url, ids[1]))
+        .timeout(Duration::from_secs(5))
+        .send()
+        .await
+        .expect("Failed to delete team");
+    assert_eq!(response.status(), 204);
+
+    let picks: i64 = sqlx::query_scalar("SELECT COUNT(*) FROM draft_picks")
+        .fetch_one(&pool)
+        .await
+        .expect("Failed to count picks");
+    assert_eq!(picks, 0);
+
+    let response = client
+        .delete(format!(
+            "{}/api/v1/teams/{}",
+            base_url,
+            uuid::Uuid::new_v4()
+        ))
+        .timeout(Duration::from_secs(5))
+        .send()
+        .await
+        .expect("Failed to delete team");
+    assert_eq!(response.status(), 404);
+}
@@ -1,14 +1,12 @@
 //! Trade acceptance tests - End-to-end HTTP and database validation
 
-mod common;
-
 use serde_json::json;
 use std::time::Duration;
 
 #[tokio::test]
 async fn test_fair_trade_proposal() {
-    let (base_url, pool) = common::spawn_app().await;
-    let client = common::create_client();
+    let (base_url, pool) = test_support::spawn_app().await;
+    let client = test_support::create_client_with_role(domain::models::Role::Admin);
 
     // Setup: Create teams
     let (team1_id, team2_id) = create_two_teams(&base_url, &client).await;
@@ -103,8 +101,8 @@ async fn test_fair_trade_proposal() {
 
 #[tokio::test]
 async fn test_unfair_trade_rejected() {
-    let (base_url, pool) = common::spawn_app().await;
-    let client = common::create_client();
+    let (base_url, pool) = test_support::spawn_app().await;
+    let client = test_support::create_client_with_role(domain::models::Role::Admin);
 
     // Setup
     let (team1_id, team2_id) = create_two_teams(&base_url, &client).await;
@@ -157,6 +155,8 @@ async fn test_unfair_trade_rejected() {
         .expect("Failed to propose trade");
 
     assert_eq!(response.status(), 400);
+    let body: serde_json::Value = response.json().await.unwrap();
+    assert_eq!(body["code"], "TRADE_UNFAIR");
 
     // Verify no trade was created in database
     let trade_count = sqlx::query!("SELECT COUNT(*) as count FROM pick_trades")
@@ -169,8 +169,8 @@ async fn test_unfair_trade_rejected() {
 
 #[tokio::test]
 async fn test_accept_trade_transfers_ownership() {
-    let (base_url, pool) = common::spawn_app().await;
-    let client = common::create_client();
+    let (base_url, pool) = test_support::spawn_app().await;
+    let client = test_support::create_client_with_role(domain::models::Role::Admin);
 
     // Setup
     let (team1_id, team2_id) = create_two_teams(&base_url, &client).await;
@@ -278,12 +278,22 @@ async fn test_accept_trade_transfers_ownership() {
     .await
     .expect("Failed to fetch trade");
     assert_eq!(db_trade.status, "Accepted");
+
+    // Each step's event was stored with it
+    let event_types: Vec<String> = sqlx::query_scalar(
+        "SELECT event_type FROM draft_events WHERE session_id = $1 ORDER BY created_at",
+    )
+    .bind(session_id)
+    .fetch_all(&pool)
+    .await
+    .expect("Failed to fetch events");
+    assert_eq!(event_types, vec!["TradeProposed", "TradeExecuted"]);
 }
 
 #[tokio::test]
 async fn test_reject_trade() {
-    let (base_url, pool) = common::spawn_app().await;
-    let client = common::create_client();
+    let (base_url, pool) = test_support::spawn_app().await;
+    let client = test_support::create_client_with_role(domain::models::Role::Admin);
 
     // Setup
     let (team1_id, team2_id) = create_two_teams(&base_url, &client).await;
@@ -382,12 +392,22 @@ async fn test_reject_trade() {
     .await
     .expect("Failed to fetch trade");
     assert_eq!(db_trade.status, "Rejected");
+
+    // Each step's event was stored with it
+    let event_types: Vec<String> = sqlx::query_scalar(
+        "SELECT event_type FROM draft_events WHERE session_id = $1 ORDER BY created_at",
+    )
+    .bind(session_id)
+    .fetch_all(&pool)
+    .await
+    .expect("Failed to fetch events");
+    assert_eq!(event_types, vec!["TradeProposed", "TradeRejected"]);
 }
 
 #[tokio::test]
 async fn test_pick_in_active_trade_cannot_be_traded_again() {
-    let (base_url, pool) = common::spawn_app().await;
-    let client = common::create_client();
+    let (base_url, pool) = test_support::spawn_app().await;
+    let client = test_support::create_client_with_role(domain::models::Role::Admin);
 
     // Setup
     let (team1_id, team2_id) = create_two_teams(&base_url, &client).await;
@@ -529,8 +549,8 @@ async fn test_pick_in_active_trade_cannot_be_traded_again() {
 
 #[tokio::test]
 async fn test_get_pending_trades_for_team() {
-    let (base_url, pool) = common::spawn_app().await;
-    let client = common::create_client();
+    let (base_url, pool) = test_support::spawn_app().await;
+    let client = test_support::create_client_with_role(domain::models::Role::Admin);
 
     // Setup
     let (team1_id, team2_id) = create_two_teams(&base_url, &client).await;
@@ -631,8 +651,8 @@ async fn test_get_pending_trades_for_team() {
 
 #[tokio::test]
 async fn test_get_trade_details() {
-    let (base_url, pool) = common::spawn_app().await;
-    let client = common::create_client();
+    let (base_url, pool) = test_support::spawn_app().await;
+    let client = test_support::create_client_with_role(domain::models::Role::Admin);
 
     // Setup
     let (team1_id, team2_id) = create_two_teams(&base_url, &client).await;
@@ -709,8 +729,8 @@ async fn test_get_trade_details() {
 
 #[tokio::test]
 async fn test_cannot_accept_trade_as_wrong_team() {
-    let (base_url, pool) = common::spawn_app().await;
-    let client = common::create_client();
+    let (base_url, pool) = test_support::spawn_app().await;
+    let client = test_support::create_client_with_role(domain::models::Role::Admin);
 
     // Setup
     let (team1_id, team2_id) = create_two_teams(&base_url, &client).await;
@@ -783,8 +803,8 @@ async fn test_cannot_accept_trade_as_wrong_team() {
 
 #[tokio::test]
 async fn test_get_trades_by_session() {
-    let (base_url, pool) = common::spawn_app().await;
-    let client = common::create_client();
+    let (base_url, pool) = test_support::spawn_app().await;
+    let client = test_support::create_client_with_role(domain::models::Role::Admin);
 
     // Setup: two teams + draft + session + picks
     let (team1_id, team2_id) = create_two_teams(&base_url, &client).await;
@@ -0,0 +1,170 @@
+//! User watchlist acceptance tests
+
+use reqwest::StatusCode;
+use serde_json::{json, Value};
+use uuid::Uuid;
+
+/// Registers a user and returns their access token
+async fn register(client: &reqwest::Client, base_url: &str, email: &str) -> String {
+    let response = client
+        .post(format!("{}/api/v1/auth/register", base_url))
+        .json(&json!({
+            "email": email,
+            "display_name": "Scout",
+            "password": "correct horse"
+        }))
+        .send()
+        .await
+        .expect("Failed to register");
+    assert_eq!(response.status(), StatusCode::CREATED);
+    let registered: Value = response.json().await.unwrap();
+    registered["access_token"].as_str().unwrap().to_string()
+}
+
+async fn insert_player(pool: &sqlx::PgPool, first_name: &str) -> Uuid {
+    let id = Uuid::new_v4();
+    sqlx::query(
+        "INSERT INTO players (id, first_name, last_name, position, draft_year, draft_eligible) VALUES ($1, $2, 'Player', 'QB', 2026, true)",
+    )
+    .bind(id)
+    .bind(first_name)
+    .execute(pool)
+    .await
+    .unwrap();
+    id
+}
+
+#[tokio::test]
+async fn test_watchlist_add_list_remove() {
+    let (base_url, pool) = test_support::spawn_app().await;
+    let client = test_support::create_anonymous_client();
+
+    let token = register(&client, &base_url, "watcher@example.com").await;
+    let alpha = insert_player(&pool, "Alpha").await;
+    let beta = insert_player(&pool, "Beta").await;
+
+    for player_id in [alpha, beta, alpha] {
+        let response = client
+            .post(format!("{}/api/v1/me/watchlist", base_url))
+            .bearer_auth(&token)
+            .json(&json!({ "player_id": player_id }))
+            .send()
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::CREATED);
+        let entry: Value = response.json().await.unwrap();
+        assert_eq!(entry["player_id"], player_id.to_string());
+    }
+
+    let watchlist: Vec<Value> = client
+        .get(format!("{}/api/v1/me/watchlist", base_url))
+        .bearer_auth(&token)
+        .send()
+        .await
+        .unwrap()
+        .json()
+        .await
+        .unwrap();
+    assert_eq!(watchlist.len(), 2);
+    assert_eq!(watchlist[0]["first_name"], "Beta");
+    assert_eq!(watchlist[1]["first_name"], "Alpha");
+
+    // Each user has their own list
+    let other_token = register(&client, &base_url, "other@example.com").await;
+    let other: Vec<Value> = client
+        .get(format!("{}/api/v1/me/watchlist", base_url))
+        .bearer_auth(&other_token)
+        .send()
+        .await
+        .unwrap()
+        .json()
+        .await
+        .unwrap();
+    assert!(other.is_empty());
+
+    let removed = client
+        .delete(format!("{}/api/v1/me/watchlist/{}", base_url, alpha))
+        .bearer_auth(&token)
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(removed.status(), StatusCode::NO_CONTENT);
+
+    let removed_again = client
+        .delete(format!("{}/api/v1/me/watchlist/{}", base_url, alpha))
+        .bearer_auth(&token)
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(removed_again.status(), StatusCode::NOT_FOUND);
+
+    let unknown_player = client
+        .post(format!("{}/api/v1/me/watchlist", base_url))
+        .bearer_auth(&token)
+        .json(&json!({ "player_id": Uuid::new_v4() }))
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(unknown_player.status(), StatusCode::NOT_FOUND);
+
+    let anonymous = client
+        .get(format!("{}/api/v1/me/watchlist", base_url))
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(anonymous.status(), StatusCode::UNAUTHORIZED);
+}
+
+#[tokio::test]
+async fn test_available_players_flags_watched_players() {
+    let (base_url, pool) = test_support::spawn_app().await;
+    let client = test_support::create_anonymous_client();
+
+    let token = register(&client, &base_url, "board@example.com").await;
+    let draft_id = Uuid::new_v4();
+    sqlx::query(
+        "INSERT INTO drafts (id, year, status, rounds, picks_per_round) VALUES ($1, 2026, 'InProgress', 1, 1::INTEGER)",
+    )
+    .bind(draft_id)
+    .execute(&pool)
+    .await
+    .unwrap();
+    let watched = insert_player(&pool, "Watched").await;
+    insert_player(&pool, "Ignored").await;
+
+    let response = client
+        .post(format!("{}/api/v1/me/watchlist", base_url))
+        .bearer_auth(&token)
+        .json(&json!({ "player_id": watched }))
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::CREATED);
+
+    let url = format!("{}/api/v1/drafts/{}/available-players", base_url, draft_id);
+    let players: Vec<Value> = client
+        .get(&url)
+        .bearer_auth(&token)
+        .send()
+        .await
+        .unwrap()
+        .json()
+        .await
+        .unwrap();
+    assert_eq!(players.len(), 2);
+    for player in &players {
+        let is_watched = player["id"] == watched.to_string();
+        assert_eq!(player["watched"], is_watched);
+    }
+
+    let anonymous: Vec<Value> = client.get(&url).send().await.unwrap().json().await.unwrap();
+    assert!(anonymous.iter().all(|p| p["watched"] == false));
+
+    let bad_token = client
+        .get(&url)
+        .bearer_auth("not-a-token")
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(bad_token.status(), StatusCode::UNAUTHORIZED);
+}
@@ -0,0 +1,155 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use uuid::Uuid;
+
+use domain::errors::DomainResult;
+use domain::models::CombinePercentile;
+use domain::repositories::CombinePercentileRepository;
+
+use super::{get_or_load, Cache, PERCENTILES};
+
+/// `CombinePercentileRepository` that caches every lookup
+pub struct CachedCombinePercentileRepository {
+    inner: Arc<dyn CombinePercentileRepository>,
+    cache: Arc<dyn Cache>,
+}
+
+impl CachedCombinePercentileRepository {
+    pub fn new(inner: Arc<dyn CombinePercentileRepository>, cache: Arc<dyn Cache>) -> Self {
+        Self { inner, cache }
+    }
+}
+
+#[async_trait]
+impl CombinePercentileRepository for CachedCombinePercentileRepository {
+    async fn find_all(&self) -> DomainResult<Vec<CombinePercentile>> {
+        get_or_load(self.cache.as_ref(), PERCENTILES, "all", || {
+            self.inner.find_all()
+        })
+        .await
+    }
+
+    async fn find_by_position(&self, position: &str) -> DomainResult<Vec<CombinePercentile>> {
+        get_or_load(
+            self.cache.as_ref(),
+            PERCENTILES,
+            &format!("position:{}", position),
+            || self.inner.find_by_position(position),
+        )
+        .await
+    }
+
+    async fn find_by_position_and_measurement(
+        &self,
+        position: &str,
+        measurement: &str,
+    ) -> DomainResult<Option<CombinePercentile>> {
+        get_or_load(
+            self.cache.as_ref(),
+            PERCENTILES,
+            &format!("position:{}:measurement:{}", position, measurement),
+            || {
+                self.inner
+                    .find_by_position_and_measurement(position, measurement)
+            },
+        )
+        .await
+    }
+
+    async fn upsert(&self, percentile: &CombinePercentile) -> DomainResult<CombinePercentile> {
+        let result = self.inner.upsert(percentile).await;
+        self.cache.invalidate(PERCENTILES).await;
+        result
+    }
+
+    async fn delete_all(&self) -> DomainResult<u64> {
+        let result = self.inner.delete_all().await;
+        self.cache.invalidate(PERCENTILES).await;
+        result
+    }
+
+    async fn delete(&self, id: Uuid) -> DomainResult<()> {
+        let result = self.inner.delete(id).await;
+        self.cache.invalidate(PERCENTILES).await;
+        result
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    use super::*;
+    use crate::cache::testing::MemoryCache;
+    use domain::models::Measurement;
+
+    /// Serves one fixed percentile and counts reads
+    struct CountingRepo {
+        percentile: CombinePercentile,
+        reads: AtomicUsize,
+    }
+
+    #[async_trait]
+    impl CombinePercentileRepository for CountingRepo {
+        async fn find_all(&self) -> DomainResult<Vec<CombinePercentile>> {
+            self.reads.fetch_add(1, Ordering::SeqCst);
+            Ok(vec![self.percentile.clone()])
+        }
+
+        async fn find_by_position(&self, _position: &str) -> DomainResult<Vec<CombinePercentile>> {
+            self.find_all().await
+        }
+
+        async fn find_by_position_and_measurement(
+            &self,
+            _position: &str,
+            _measurement: &str,
+        ) -> DomainResult<Option<CombinePercentile>> {
+            self.reads.fetch_add(1, Ordering::SeqCst);
+            Ok(Some(self.percentile.clone()))
+        }
+
+        async fn upsert(&self, percentile: &CombinePercentile) -> DomainResult<CombinePercentile> {
+            Ok(percentile.clone())
+        }
+
+        async fn delete_all(&self) -> DomainResult<u64> {
+            Ok(1)
+        }
+
+        async fn delete(&self, _id: Uuid) -> DomainResult<()> {
+            Ok(())
+        }
+    }
+
+    #[tokio::test]
+    async fn test_reads_are_cached_until_a_write() {
+        let percentile =
+            CombinePercentile::new("QB".to_string(), Measurement::FortyYardDash).unwrap();
+        let inner = Arc::new(CountingRepo {
+            percentile: percentile.clone(),
+            reads: AtomicUsize::new(0),
+        });
+        let repo =
+            CachedCombinePercentileRepository::new(inner.clone(), Arc::new(MemoryCache::default()));
+
+        let first = repo.find_all().await.unwrap();
+        let second = repo.find_all().await.unwrap();
+        assert_eq!(first[0].id, percentile.id);
+        assert_eq!(second[0].id, percentile.id);
+        assert_eq!(inner.reads.load(Ordering::SeqCst), 1);
+
+        repo.find_by_position_and_measurement("QB", "forty_yard_dash")
+            .await
+            .unwrap();
+        assert_eq!(inner.reads.load(Ordering::SeqCst), 2);
+
+        repo.upsert(&percentile).await.unwrap();
+        repo.find_all().await.unwrap();
+        repo.find_by_position_and_measurement("QB", "forty_yard_dash")
+            .await
+            .unwrap();
+        assert_eq!(inner.reads.load(Ordering::SeqCst), 4);
+    }
+}
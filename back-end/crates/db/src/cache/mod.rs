@@ -0,0 +1,121 @@
+//! Optional read-through cache for the tables read on every evaluation that
+//! almost never change mid-draft: teams, prospect rankings and combine
+//! percentiles.
+//!
+//! Each cached repository wraps another implementation of the same trait.
+//! Reads are served from the cache when they can be, and every write through
+//! the wrapper drops the whole namespace it belongs to. Entries also expire
+//! after a TTL, which bounds staleness from writes that bypass the wrapper,
+//! such as seed-data loaders and cascading deletes. A cache that can't be
+//! reached is skipped rather than failing the request.
+
+mod combine_percentile;
+mod prospect_ranking;
+mod redis;
+mod team;
+
+use async_trait::async_trait;
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+
+pub use self::redis::RedisCache;
+pub use combine_percentile::CachedCombinePercentileRepository;
+pub use prospect_ranking::CachedProspectRankingRepository;
+pub use team::CachedTeamRepository;
+
+/// Cache namespace for teams
+pub const TEAMS: &str = "teams";
+/// Cache namespace for prospect rankings and the consensus board
+pub const RANKINGS: &str = "rankings";
+/// Cache namespace for combine percentiles
+pub const PERCENTILES: &str = "percentiles";
+
+/// A key-value store of serialized values grouped into namespaces that are
+/// invalidated as a whole. Failures are logged by the implementation and
+/// treated as misses.
+#[async_trait]
+pub trait Cache: Send + Sync {
+    async fn get(&self, namespace: &str, key: &str) -> Option<String>;
+
+    async fn set(&self, namespace: &str, key: &str, value: String);
+
+    /// Drop every entry in a namespace
+    async fn invalidate(&self, namespace: &str);
+}
+
+/// Read `key` from the cache, or load it and cache the result. Values that
+/// fail to load are not cached.
+pub(crate) async fn get_or_load<T, F, Fut>(
+    cache: &dyn Cache,
+    namespace: &str,
+    key: &str,
+    load: F,
+) -> domain::errors::DomainResult<T>
+where
+    T: Serialize + DeserializeOwned,
+    F: FnOnce() -> Fut,
+    Fut: std::future::Future<Output = domain::errors::DomainResult<T>>,
+{
+    if let Some(cached) = cache.get(namespace, key).await {
+        match serde_json::from_str(&cached) {
+            Ok(value) => return Ok(value),
+            Err(e) => tracing::warn!(
+                "Discarding unreadable cache entry {}/{}: {}",
+                namespace,
+                key,
+                e
+            ),
+        }
+    }
+
+    let value = load().await?;
+    match serde_json::to_string(&value) {
+        Ok(serialized) => cache.set(namespace, key, serialized).await,
+        Err(e) => tracing::warn!(
+            "Failed to serialize cache entry {}/{}: {}",
+            namespace,
+            key,
+            e
+        ),
+    }
+    Ok(value)
+}
+
+#[cfg(test)]
+pub(crate) mod testing {
+    use std::collections::HashMap;
+    use std::sync::Mutex;
+
+    use super::*;
+
+    /// In-process `Cache` for exercising the cached repositories
+    #[derive(Default)]
+    pub struct MemoryCache {
+        entries: Mutex<HashMap<(String, String), String>>,
+    }
+
+    #[async_trait]
+    impl Cache for MemoryCache {
+        async fn get(&self, namespace: &str, key: &str) -> Option<String> {
+            self.entries
+                .lock()
+                .unwrap()
+                .get(&(namespace.to_string(), key.to_string()))
+                .cloned()
+        }
+
+        async fn set(&self, namespace: &str, key: &str, value: String) {
+            self.entries
+                .lock()
+                .unwrap()
+                .insert((namespace.to_string(), key.to_string()), value);
+        }
+
+        async fn invalidate(&self, namespace: &str) {
+            self.entries
+                .lock()
+                .unwrap()
+                .retain(|(ns, _), _| ns != namespace);
+        }
+    }
+}
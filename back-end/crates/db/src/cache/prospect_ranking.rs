@@ -0,0 +1,120 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use uuid::Uuid;
+
+use domain::errors::DomainResult;
+use domain::models::{
+    ConsensusRanking, Page, PageRequest, PlayerRankingWithSource, ProspectRanking,
+    RankingSortField, Sort,
+};
+use domain::repositories::ProspectRankingRepository;
+
+use super::{get_or_load, Cache, RANKINGS};
+
+/// `ProspectRankingRepository` that caches per-player and per-source
+/// boards, the full list and the consensus board. Lookups for a set of
+/// players change with every pick, so they, sorted pages and keyset pages go
+/// straight to the wrapped repository.
+pub struct CachedProspectRankingRepository {
+    inner: Arc<dyn ProspectRankingRepository>,
+    cache: Arc<dyn Cache>,
+}
+
+impl CachedProspectRankingRepository {
+    pub fn new(inner: Arc<dyn ProspectRankingRepository>, cache: Arc<dyn Cache>) -> Self {
+        Self { inner, cache }
+    }
+}
+
+#[async_trait]
+impl ProspectRankingRepository for CachedProspectRankingRepository {
+    async fn create_batch(&self, rankings: &[ProspectRanking]) -> DomainResult<usize> {
+        let result = self.inner.create_batch(rankings).await;
+        self.cache.invalidate(RANKINGS).await;
+        result
+    }
+
+    async fn find_by_player_with_source(
+        &self,
+        player_id: Uuid,
+    ) -> DomainResult<Vec<PlayerRankingWithSource>> {
+        get_or_load(
+            self.cache.as_ref(),
+            RANKINGS,
+            &format!("player_with_source:{}", player_id),
+            || self.inner.find_by_player_with_source(player_id),
+        )
+        .await
+    }
+
+    async fn find_all_with_source(&self) -> DomainResult<Vec<PlayerRankingWithSource>> {
+        get_or_load(self.cache.as_ref(), RANKINGS, "all_with_source", || {
+            self.inner.find_all_with_source()
+        })
+        .await
+    }
+
+    async fn find_sorted_with_source(
+        &self,
+        sort: &Sort<RankingSortField>,
+        page: Option<PageRequest>,
+    ) -> DomainResult<Page<PlayerRankingWithSource>> {
+        self.inner.find_sorted_with_source(sort, page).await
+    }
+
+    async fn find_for_players_with_source(
+        &self,
+        player_ids: &[Uuid],
+    ) -> DomainResult<Vec<PlayerRankingWithSource>> {
+        self.inner.find_for_players_with_source(player_ids).await
+    }
+
+    async fn find_consensus(&self, draft_year: Option<i32>) -> DomainResult<Vec<ConsensusRanking>> {
+        let key = match draft_year {
+            Some(year) => format!("consensus:{}", year),
+            None => "consensus:all".to_string(),
+        };
+        get_or_load(self.cache.as_ref(), RANKINGS, &key, || {
+            self.inner.find_consensus(draft_year)
+        })
+        .await
+    }
+
+    async fn find_by_player(&self, player_id: Uuid) -> DomainResult<Vec<ProspectRanking>> {
+        get_or_load(
+            self.cache.as_ref(),
+            RANKINGS,
+            &format!("player:{}", player_id),
+            || self.inner.find_by_player(player_id),
+        )
+        .await
+    }
+
+    async fn find_by_source(&self, source_id: Uuid) -> DomainResult<Vec<ProspectRanking>> {
+        get_or_load(
+            self.cache.as_ref(),
+            RANKINGS,
+            &format!("source:{}", source_id),
+            || self.inner.find_by_source(source_id),
+        )
+        .await
+    }
+
+    async fn find_by_source_after(
+        &self,
+        source_id: Uuid,
+        after_id: Option<Uuid>,
+        limit: i64,
+    ) -> DomainResult<Vec<ProspectRanking>> {
+        self.inner
+            .find_by_source_after(source_id, after_id, limit)
+            .await
+    }
+
+    async fn delete_by_source(&self, source_id: Uuid) -> DomainResult<u64> {
+        let result = self.inner.delete_by_source(source_id).await;
+        self.cache.invalidate(RANKINGS).await;
+        result
+    }
+}
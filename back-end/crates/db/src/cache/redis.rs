@@ -0,0 +1,74 @@
+use std::time::Duration;
+
+use async_trait::async_trait;
+use redis::aio::ConnectionManager;
+use redis::AsyncCommands;
+
+use super::Cache;
+
+/// `Cache` backed by Redis. Each namespace is one hash, so invalidating it
+/// is a single `DEL` that every API instance sharing the server sees.
+#[derive(Clone)]
+pub struct RedisCache {
+    connection: ConnectionManager,
+    key_prefix: String,
+    ttl: Duration,
+}
+
+impl RedisCache {
+    /// Connect to the server at `url`. Entries expire `ttl` after their
+    /// namespace was last written.
+    pub async fn connect(url: &str, ttl: Duration) -> Result<Self, redis::RedisError> {
+        let client = redis::Client::open(url)?;
+        let connection = ConnectionManager::new(client).await?;
+        Ok(Self {
+            connection,
+            key_prefix: "nfl_draft".to_string(),
+            ttl,
+        })
+    }
+
+    fn hash_key(&self, namespace: &str) -> String {
+        format!("{}:{}", self.key_prefix, namespace)
+    }
+}
+
+#[async_trait]
+impl Cache for RedisCache {
+    async fn get(&self, namespace: &str, key: &str) -> Option<String> {
+        let mut connection = self.connection.clone();
+        match connection
+            .hget::<_, _, Option<String>>(self.hash_key(namespace), key)
+            .await
+        {
+            Ok(value) => value,
+            Err(e) => {
+                tracing::warn!("Redis cache read failed: {}", e);
+                None
+            }
+        }
+    }
+
+    async fn set(&self, namespace: &str, key: &str, value: String) {
+        let hash_key = self.hash_key(namespace);
+        let mut connection = self.connection.clone();
+        let result: redis::RedisResult<()> = redis::pipe()
+            .atomic()
+            .hset(&hash_key, key, value)
+            .ignore()
+            .expire(&hash_key, self.ttl.as_secs() as i64)
+            .ignore()
+            .query_async(&mut connection)
+            .await;
+        if let Err(e) = result {
+            tracing::warn!("Redis cache write failed: {}", e);
+        }
+    }
+
+    async fn invalidate(&self, namespace: &str) {
+        let mut connection = self.connection.clone();
+        if let Err(e) = connection.del::<_, ()>(self.hash_key(namespace)).await {
+            tracing::warn!("Redis cache invalidation of {} failed: {}", namespace, e);
+        }
+    }
+}
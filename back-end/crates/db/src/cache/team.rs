@@ -0,0 +1,95 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use uuid::Uuid;
+
+use domain::errors::DomainResult;
+use domain::models::{Page, PageRequest, Sort, Team, TeamDependents, TeamSortField};
+use domain::repositories::TeamRepository;
+
+use super::{get_or_load, Cache, TEAMS};
+
+/// `TeamRepository` that caches lookups by id and abbreviation and the full
+/// list. Sorted pages go straight to the wrapped repository.
+pub struct CachedTeamRepository {
+    inner: Arc<dyn TeamRepository>,
+    cache: Arc<dyn Cache>,
+}
+
+impl CachedTeamRepository {
+    pub fn new(inner: Arc<dyn TeamRepository>, cache: Arc<dyn Cache>) -> Self {
+        Self { inner, cache }
+    }
+}
+
+#[async_trait]
+impl TeamRepository for CachedTeamRepository {
+    async fn create(&self, team: &Team) -> DomainResult<Team> {
+        let result = self.inner.create(team).await;
+        self.cache.invalidate(TEAMS).await;
+        result
+    }
+
+    async fn find_by_id(&self, id: Uuid) -> DomainResult<Option<Team>> {
+        get_or_load(self.cache.as_ref(), TEAMS, &format!("id:{}", id), || {
+            self.inner.find_by_id(id)
+        })
+        .await
+    }
+
+    async fn find_by_abbreviation(&self, abbreviation: &str) -> DomainResult<Option<Team>> {
+        get_or_load(
+            self.cache.as_ref(),
+            TEAMS,
+            &format!("abbreviation:{}", abbreviation),
+            || self.inner.find_by_abbreviation(abbreviation),
+        )
+        .await
+    }
+
+    async fn find_all(&self) -> DomainResult<Vec<Team>> {
+        get_or_load(self.cache.as_ref(), TEAMS, "all", || self.inner.find_all()).await
+    }
+
+    async fn find_sorted(
+        &self,
+        sort: &Sort<TeamSortField>,
+        page: Option<PageRequest>,
+    ) -> DomainResult<Page<Team>> {
+        self.inner.find_sorted(sort, page).await
+    }
+
+    async fn update(&self, team: &Team) -> DomainResult<Team> {
+        let result = self.inner.update(team).await;
+        self.cache.invalidate(TEAMS).await;
+        result
+    }
+
+    async fn delete(&self, id: Uuid) -> DomainResult<()> {
+        let result = self.inner.delete(id).await;
+        self.cache.invalidate(TEAMS).await;
+        result
+    }
+
+    async fn soft_delete(&self, id: Uuid) -> DomainResult<()> {
+        let result = self.inner.soft_delete(id).await;
+        self.cache.invalidate(TEAMS).await;
+        result
+    }
+
+    async fn restore(&self, id: Uuid) -> DomainResult<Team> {
+        let result = self.inner.restore(id).await;
+        self.cache.invalidate(TEAMS).await;
+        result
+    }
+
+    async fn count_dependents(&self, id: Uuid) -> DomainResult<TeamDependents> {
+        self.inner.count_dependents(id).await
+    }
+
+    async fn delete_with_dependents(&self, id: Uuid) -> DomainResult<()> {
+        let result = self.inner.delete_with_dependents(id).await;
+        self.cache.invalidate(TEAMS).await;
+        result
+    }
+}
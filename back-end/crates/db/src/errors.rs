@@ -13,6 +13,9 @@ pub enum DbError {
 
     #[error("Mapping error: {0}")]
     MappingError(String),
+
+    #[error("Migration error: {0}")]
+    MigrationError(#[from] sqlx::migrate::MigrateError),
 }
 
 pub type DbResult<T> = Result<T, DbError>;
@@ -29,6 +32,9 @@ impl From<DbError> for domain::errors::DomainError {
             DbError::MappingError(msg) => {
                 domain::errors::DomainError::InternalError(format!("Mapping error: {}", msg))
             }
+            DbError::MigrationError(e) => {
+                domain::errors::DomainError::InternalError(format!("Migration error: {}", e))
+            }
         }
     }
 }
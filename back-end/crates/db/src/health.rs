@@ -0,0 +1,227 @@
+//! Checks behind the API's readiness probe and database health report
+
+use std::time::Duration;
+
+use sqlx::PgPool;
+
+use crate::errors::{DbError, DbResult};
+use crate::migrations::MIGRATOR;
+
+/// Postgres error code for a missing table
+const UNDEFINED_TABLE: &str = "42P01";
+
+/// How far the database schema is behind this build
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MigrationStatus {
+    pub applied: usize,
+    pub expected: usize,
+    /// Versions this build expects that have not been applied
+    pub pending: Vec<i64>,
+}
+
+impl MigrationStatus {
+    pub fn is_current(&self) -> bool {
+        self.pending.is_empty()
+    }
+}
+
+/// Row counts of the reference data a draft cannot run without
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SeedDataStatus {
+    pub teams: i64,
+    pub players: i64,
+}
+
+impl SeedDataStatus {
+    pub fn is_present(&self) -> bool {
+        self.teams > 0 && self.players > 0
+    }
+}
+
+/// Tables whose sizes the database health report shows
+pub const KEY_TABLES: &[&str] = &[
+    "teams",
+    "players",
+    "drafts",
+    "draft_picks",
+    "draft_sessions",
+    "draft_events",
+    "pick_trades",
+    "scouting_reports",
+    "prospect_rankings",
+    "users",
+];
+
+/// How busy a connection pool is
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PoolStats {
+    /// Open connections, idle or in use
+    pub size: u32,
+    pub idle: u32,
+    pub max_connections: u32,
+}
+
+impl PoolStats {
+    pub fn in_use(&self) -> u32 {
+        self.size.saturating_sub(self.idle)
+    }
+}
+
+/// Round-trips a trivial query to prove a connection can be acquired
+pub async fn ping(pool: &PgPool) -> DbResult<()> {
+    sqlx::query("SELECT 1")
+        .execute(pool)
+        .await
+        .map_err(DbError::DatabaseError)?;
+    Ok(())
+}
+
+/// Compares the versions recorded by `sqlx migrate run` with the migrations
+/// embedded in this build. A database that was never migrated with sqlx has
+/// no bookkeeping table and reports every migration as pending.
+pub async fn migration_status(pool: &PgPool) -> DbResult<MigrationStatus> {
+    let applied: Vec<i64> =
+        sqlx::query_scalar("SELECT version FROM _sqlx_migrations WHERE success")
+            .fetch_all(pool)
+            .await
+            .or_else(|e| match &e {
+                sqlx::Error::Database(db) if db.code().as_deref() == Some(UNDEFINED_TABLE) => {
+                    Ok(Vec::new())
+                }
+                _ => Err(DbError::DatabaseError(e)),
+            })?;
+
+    let expected: Vec<i64> = MIGRATOR
+        .iter()
+        .filter(|migration| migration.migration_type.is_up_migration())
+        .map(|migration| migration.version)
+        .collect();
+    let pending = expected
+        .iter()
+        .copied()
+        .filter(|version| !applied.contains(version))
+        .collect();
+
+    Ok(MigrationStatus {
+        applied: applied.len(),
+        expected: expected.len(),
+        pending,
+    })
+}
+
+pub async fn seed_data_status(pool: &PgPool) -> DbResult<SeedDataStatus> {
+    let (teams, players): (i64, i64) =
+        sqlx::query_as("SELECT (SELECT COUNT(*) FROM teams), (SELECT COUNT(*) FROM players)")
+            .fetch_one(pool)
+            .await
+            .map_err(DbError::DatabaseError)?;
+
+    Ok(SeedDataStatus { teams, players })
+}
+
+pub fn pool_stats(pool: &PgPool) -> PoolStats {
+    PoolStats {
+        size: pool.size(),
+        idle: pool.num_idle() as u32,
+        max_connections: pool.options().get_max_connections(),
+    }
+}
+
+/// Estimated row counts of [`KEY_TABLES`], in that order, from the
+/// statistics collector's live tuple counts. Unlike `COUNT(*)` this reads no
+/// table data, so dashboards can poll it; the figures trail recent writes by
+/// up to a second or so. Tables are resolved through the search path, and
+/// one the collector has not seen yet reports 0.
+pub async fn table_row_counts(pool: &PgPool) -> DbResult<Vec<(&'static str, i64)>> {
+    let counts: Vec<i64> = sqlx::query_scalar(
+        "SELECT COALESCE(s.n_live_tup, 0) \
+         FROM UNNEST($1::text[]) WITH ORDINALITY AS t(name, position) \
+         LEFT JOIN pg_stat_user_tables s ON s.relid = to_regclass(t.name) \
+         ORDER BY t.position",
+    )
+    .bind(KEY_TABLES)
+    .fetch_all(pool)
+    .await
+    .map_err(DbError::DatabaseError)?;
+
+    Ok(KEY_TABLES.iter().copied().zip(counts).collect())
+}
+
+/// How far a standby's replay trails the primary, or `None` when `pool` is
+/// connected to a primary. A standby that has replayed nothing yet reports
+/// no lag.
+pub async fn replication_lag(pool: &PgPool) -> DbResult<Option<Duration>> {
+    let (in_recovery, lag_ms): (bool, Option<f64>) = sqlx::query_as(
+        "SELECT pg_is_in_recovery(), \
+         (EXTRACT(EPOCH FROM (NOW() - pg_last_xact_replay_timestamp())) * 1000)::float8",
+    )
+    .fetch_one(pool)
+    .await
+    .map_err(DbError::DatabaseError)?;
+
+    if !in_recovery {
+        return Ok(None);
+    }
+    let lag_ms = lag_ms.unwrap_or(0.0).max(0.0);
+    Ok(Some(Duration::from_millis(lag_ms as u64)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::get_test_pool;
+
+    #[tokio::test]
+    async fn test_ping_and_seed_data_status() {
+        let pool = get_test_pool().await;
+        assert!(ping(&pool).await.is_ok());
+
+        let status = seed_data_status(&pool).await.unwrap();
+        assert!(status.teams >= 0 && status.players >= 0);
+    }
+
+    #[tokio::test]
+    async fn test_pool_stats_and_row_counts() {
+        let pool = get_test_pool().await;
+        ping(&pool).await.unwrap();
+
+        let stats = pool_stats(&pool);
+        assert_eq!(stats.max_connections, 5);
+        assert!(stats.size >= 1 && stats.in_use() <= stats.size);
+
+        let counts = table_row_counts(&pool).await.unwrap();
+        let tables: Vec<&str> = counts.iter().map(|(table, _)| *table).collect();
+        assert_eq!(tables, KEY_TABLES);
+        assert!(counts.iter().all(|(_, rows)| *rows >= 0));
+
+        // The test database is a primary
+        assert_eq!(replication_lag(&pool).await.unwrap(), None);
+    }
+
+    #[tokio::test]
+    async fn test_migration_status_knows_embedded_migrations() {
+        let pool = get_test_pool().await;
+        let status = migration_status(&pool).await.unwrap();
+        assert!(status.expected > 0);
+        assert!(status.pending.len() <= status.expected);
+    }
+
+    #[test]
+    fn test_seed_data_needs_teams_and_players() {
+        assert!(SeedDataStatus {
+            teams: 32,
+            players: 300
+        }
+        .is_present());
+        assert!(!SeedDataStatus {
+            teams: 32,
+            players: 0
+        }
+        .is_present());
+        assert!(!SeedDataStatus {
+            teams: 0,
+            players: 0
+        }
+        .is_present());
+    }
+}
@@ -0,0 +1,217 @@
+//! Tracing spans around repository calls
+//!
+//! Every repository method runs inside a `db_query` span that records the
+//! method name, the rows it returned or changed and how long it took. Calls
+//! slower than the slow-query threshold log a warning.
+
+use std::future::Future;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::time::{Duration, Instant};
+
+use domain::errors::DomainResult;
+use domain::models::{
+    ActualDraftPick, AdpEntry, AuditEntry, ChatMessage, CollegeStats, CombinePercentile,
+    CombineResults, ConsensusRanking, Draft, DraftEvent, DraftPick, DraftSession, DraftStrategy,
+    FeldmanFreak, Page, PickTrade, Player, PlayerNote, PlayerRankingWithSource, PlayerTag,
+    ProspectProfile, ProspectRanking, RankingSource, ScoutingReport, SessionParticipant, TagCount,
+    Team, TeamDependents, TeamNeed, TeamSeason, TradeProposal, User, WatchlistEntry,
+};
+use domain::repositories::DraftLockGuard;
+use tracing::{field, Instrument};
+
+/// Repository calls taking longer than this log a warning unless the
+/// threshold is changed with [`set_slow_query_threshold`]
+pub const DEFAULT_SLOW_QUERY_THRESHOLD: Duration = Duration::from_millis(500);
+
+static SLOW_QUERY_THRESHOLD_MS: AtomicU64 =
+    AtomicU64::new(DEFAULT_SLOW_QUERY_THRESHOLD.as_millis() as u64);
+
+/// Sets how long a repository call may take before it logs a warning;
+/// `None` turns the warning off
+pub fn set_slow_query_threshold(threshold: Option<Duration>) {
+    let millis = threshold.map_or(0, |t| (t.as_millis() as u64).max(1));
+    SLOW_QUERY_THRESHOLD_MS.store(millis, Ordering::Relaxed);
+}
+
+fn slow_query_threshold() -> Option<Duration> {
+    match SLOW_QUERY_THRESHOLD_MS.load(Ordering::Relaxed) {
+        0 => None,
+        millis => Some(Duration::from_millis(millis)),
+    }
+}
+
+/// Rows a repository call returned, or changed for writes that report a
+/// count, as recorded on its span
+pub(crate) trait RowCount {
+    fn row_count(&self) -> u64;
+}
+
+impl<T> RowCount for Vec<T> {
+    fn row_count(&self) -> u64 {
+        self.len() as u64
+    }
+}
+
+impl<T> RowCount for Option<T> {
+    fn row_count(&self) -> u64 {
+        self.is_some() as u64
+    }
+}
+
+impl<T> RowCount for Page<T> {
+    fn row_count(&self) -> u64 {
+        self.items.len() as u64
+    }
+}
+
+impl RowCount for () {
+    fn row_count(&self) -> u64 {
+        0
+    }
+}
+
+impl RowCount for u64 {
+    fn row_count(&self) -> u64 {
+        *self
+    }
+}
+
+impl RowCount for usize {
+    fn row_count(&self) -> u64 {
+        *self as u64
+    }
+}
+
+impl RowCount for bool {
+    fn row_count(&self) -> u64 {
+        *self as u64
+    }
+}
+
+/// A COUNT(*) comes back as a single row
+impl RowCount for i64 {
+    fn row_count(&self) -> u64 {
+        1
+    }
+}
+
+impl RowCount for Box<dyn DraftLockGuard> {
+    fn row_count(&self) -> u64 {
+        1
+    }
+}
+
+macro_rules! single_row {
+    ($($model:ty),* $(,)?) => {
+        $(
+            impl RowCount for $model {
+                fn row_count(&self) -> u64 {
+                    1
+                }
+            }
+        )*
+    };
+}
+
+single_row!(
+    ActualDraftPick,
+    AdpEntry,
+    AuditEntry,
+    ChatMessage,
+    CollegeStats,
+    CombinePercentile,
+    CombineResults,
+    ConsensusRanking,
+    Draft,
+    DraftEvent,
+    DraftPick,
+    DraftSession,
+    DraftStrategy,
+    FeldmanFreak,
+    PickTrade,
+    Player,
+    PlayerNote,
+    PlayerRankingWithSource,
+    PlayerTag,
+    ProspectProfile,
+    ProspectRanking,
+    RankingSource,
+    ScoutingReport,
+    SessionParticipant,
+    TagCount,
+    Team,
+    TeamDependents,
+    TeamNeed,
+    TeamSeason,
+    TradeProposal,
+    User,
+    WatchlistEntry,
+);
+
+/// Runs a repository call inside a `db_query` span named after `query`,
+/// recording its row count and latency and warning when it is slow
+pub(crate) async fn traced<T, F>(query: &'static str, call: F) -> DomainResult<T>
+where
+    T: RowCount,
+    F: Future<Output = DomainResult<T>>,
+{
+    let span = tracing::info_span!(
+        "db_query",
+        query,
+        rows = field::Empty,
+        elapsed_ms = field::Empty
+    );
+    let started = Instant::now();
+    let result = call.instrument(span.clone()).await;
+    let elapsed = started.elapsed();
+
+    let rows = result.as_ref().ok().map(RowCount::row_count);
+    let elapsed_ms = elapsed.as_millis() as u64;
+    span.record("rows", rows);
+    span.record("elapsed_ms", elapsed_ms);
+
+    match slow_query_threshold() {
+        Some(threshold) if elapsed >= threshold => tracing::warn!(
+            parent: &span,
+            query,
+            rows,
+            elapsed_ms,
+            threshold_ms = threshold.as_millis() as u64,
+            failed = result.is_err(),
+            "Slow query"
+        ),
+        _ => {
+            tracing::debug!(parent: &span, rows, elapsed_ms, failed = result.is_err(), "Query finished")
+        }
+    }
+
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use domain::errors::DomainError;
+
+    #[tokio::test]
+    async fn test_traced_returns_the_call_result() {
+        let rows = traced("test.rows", async { Ok(vec![1, 2, 3]) }).await;
+        assert_eq!(rows.unwrap(), vec![1, 2, 3]);
+
+        let failed: DomainResult<Option<u8>> = traced("test.failed", async {
+            Err(DomainError::NotFound("missing".to_string()))
+        })
+        .await;
+        assert!(matches!(failed, Err(DomainError::NotFound(_))));
+    }
+
+    #[test]
+    fn test_row_counts() {
+        assert_eq!(vec!['a', 'b'].row_count(), 2);
+        assert_eq!(Some(1).row_count(), 1);
+        assert_eq!(None::<u8>.row_count(), 0);
+        assert_eq!(7u64.row_count(), 7);
+        assert_eq!(42i64.row_count(), 1);
+        assert_eq!(().row_count(), 0);
+    }
+}
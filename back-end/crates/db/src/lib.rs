@@ -1,10 +1,18 @@
+pub mod cache;
 pub mod errors;
+pub mod health;
+mod instrument;
+pub mod migrations;
 pub mod models;
 pub mod pool;
+mod query;
 pub mod repositories;
 
 pub use errors::{DbError, DbResult};
-pub use pool::create_pool;
+pub use instrument::{set_slow_query_threshold, DEFAULT_SLOW_QUERY_THRESHOLD};
+pub use migrations::run_migrations;
+pub use pool::{create_pool, create_pool_with, create_pools, DbPools, PoolSettings};
+pub use query::DEFAULT_BATCH_SIZE;
 
 #[cfg(test)]
 pub async fn get_test_pool() -> sqlx::PgPool {
@@ -0,0 +1,50 @@
+use chrono::{DateTime, Utc};
+use sqlx::FromRow;
+use uuid::Uuid;
+
+use domain::models::ActualDraftPick;
+
+use crate::errors::DbResult;
+
+/// Database model for actual_draft_picks table
+#[derive(Debug, Clone, FromRow)]
+pub struct ActualDraftPickDb {
+    pub id: Uuid,
+    pub year: i32,
+    pub round: i32,
+    pub pick_number: i32,
+    pub overall_pick: i32,
+    pub team_id: Uuid,
+    pub player_id: Uuid,
+    pub created_at: DateTime<Utc>,
+}
+
+impl ActualDraftPickDb {
+    /// Convert from domain ActualDraftPick to database ActualDraftPickDb
+    pub fn from_domain(pick: &ActualDraftPick) -> Self {
+        Self {
+            id: pick.id,
+            year: pick.year,
+            round: pick.round,
+            pick_number: pick.pick_number,
+            overall_pick: pick.overall_pick,
+            team_id: pick.team_id,
+            player_id: pick.player_id,
+            created_at: pick.created_at,
+        }
+    }
+
+    /// Convert from database ActualDraftPickDb to domain ActualDraftPick
+    pub fn to_domain(&self) -> DbResult<ActualDraftPick> {
+        Ok(ActualDraftPick {
+            id: self.id,
+            year: self.year,
+            round: self.round,
+            pick_number: self.pick_number,
+            overall_pick: self.overall_pick,
+            team_id: self.team_id,
+            player_id: self.player_id,
+            created_at: self.created_at,
+        })
+    }
+}
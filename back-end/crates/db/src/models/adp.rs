@@ -0,0 +1,47 @@
+use chrono::{DateTime, Utc};
+use sqlx::FromRow;
+use uuid::Uuid;
+
+use domain::models::AdpEntry;
+
+use crate::errors::DbResult;
+
+/// Database model for adp table
+#[derive(Debug, Clone, FromRow)]
+pub struct AdpEntryDb {
+    pub id: Uuid,
+    pub draft_id: Uuid,
+    pub year: i32,
+    pub player_id: Uuid,
+    pub round: i32,
+    pub overall_pick: i32,
+    pub recorded_at: DateTime<Utc>,
+}
+
+impl AdpEntryDb {
+    /// Convert from domain AdpEntry to database AdpEntryDb
+    pub fn from_domain(entry: &AdpEntry) -> Self {
+        Self {
+            id: entry.id,
+            draft_id: entry.draft_id,
+            year: entry.year,
+            player_id: entry.player_id,
+            round: entry.round,
+            overall_pick: entry.overall_pick,
+            recorded_at: entry.recorded_at,
+        }
+    }
+
+    /// Convert from database AdpEntryDb to domain AdpEntry
+    pub fn to_domain(&self) -> DbResult<AdpEntry> {
+        Ok(AdpEntry {
+            id: self.id,
+            draft_id: self.draft_id,
+            year: self.year,
+            player_id: self.player_id,
+            round: self.round,
+            overall_pick: self.overall_pick,
+            recorded_at: self.recorded_at,
+        })
+    }
+}
@@ -0,0 +1,64 @@
+use chrono::{DateTime, Utc};
+use serde_json::Value;
+use sqlx::FromRow;
+use uuid::Uuid;
+
+use domain::models::AuditEntry;
+
+/// Database model for audit_log table
+#[derive(Debug, Clone, FromRow)]
+pub struct AuditEntryDb {
+    pub id: Uuid,
+    pub actor_user_id: Option<Uuid>,
+    pub actor_participant_id: Option<Uuid>,
+    pub actor: String,
+    pub method: String,
+    pub route: String,
+    pub entity_type: String,
+    pub entity_id: Option<Uuid>,
+    pub before: Option<Value>,
+    pub after: Option<Value>,
+    pub status_code: i32,
+    pub request_id: Option<String>,
+    pub created_at: DateTime<Utc>,
+}
+
+impl AuditEntryDb {
+    /// Convert from domain AuditEntry to database AuditEntryDb
+    pub fn from_domain(entry: &AuditEntry) -> Self {
+        Self {
+            id: entry.id,
+            actor_user_id: entry.actor_user_id,
+            actor_participant_id: entry.actor_participant_id,
+            actor: entry.actor.clone(),
+            method: entry.method.clone(),
+            route: entry.route.clone(),
+            entity_type: entry.entity_type.clone(),
+            entity_id: entry.entity_id,
+            before: entry.before.clone(),
+            after: entry.after.clone(),
+            status_code: entry.status_code,
+            request_id: entry.request_id.clone(),
+            created_at: entry.created_at,
+        }
+    }
+
+    /// Convert from database AuditEntryDb to domain AuditEntry
+    pub fn to_domain(self) -> AuditEntry {
+        AuditEntry {
+            id: self.id,
+            actor_user_id: self.actor_user_id,
+            actor_participant_id: self.actor_participant_id,
+            actor: self.actor,
+            method: self.method,
+            route: self.route,
+            entity_type: self.entity_type,
+            entity_id: self.entity_id,
+            before: self.before,
+            after: self.after,
+            status_code: self.status_code,
+            request_id: self.request_id,
+            created_at: self.created_at,
+        }
+    }
+}
@@ -0,0 +1,42 @@
+use chrono::{DateTime, Utc};
+use sqlx::FromRow;
+use uuid::Uuid;
+
+use domain::models::ChatMessage;
+
+/// Database model for chat_messages table
+#[derive(Debug, Clone, FromRow)]
+pub struct ChatMessageDb {
+    pub id: Uuid,
+    pub session_id: Uuid,
+    pub participant_id: Option<Uuid>,
+    pub display_name: String,
+    pub body: String,
+    pub created_at: DateTime<Utc>,
+}
+
+impl ChatMessageDb {
+    /// Convert from domain ChatMessage to database ChatMessageDb
+    pub fn from_domain(message: &ChatMessage) -> Self {
+        Self {
+            id: message.id,
+            session_id: message.session_id,
+            participant_id: message.participant_id,
+            display_name: message.display_name.clone(),
+            body: message.body.clone(),
+            created_at: message.created_at,
+        }
+    }
+
+    /// Convert from database ChatMessageDb to domain ChatMessage
+    pub fn to_domain(self) -> ChatMessage {
+        ChatMessage {
+            id: self.id,
+            session_id: self.session_id,
+            participant_id: self.participant_id,
+            display_name: self.display_name,
+            body: self.body,
+            created_at: self.created_at,
+        }
+    }
+}
@@ -0,0 +1,193 @@
+use chrono::{DateTime, Utc};
+use sqlx::FromRow;
+use uuid::Uuid;
+
+use domain::models::{CollegeStats, DefenseLine, PassingLine, ReceivingLine, RushingLine};
+
+use crate::errors::DbResult;
+
+/// Database model for college_stats table. Stat lines are flattened into
+/// nullable columns; a line is present when all of its columns are set.
+#[derive(Debug, Clone, FromRow)]
+pub struct CollegeStatsDb {
+    pub id: Uuid,
+    pub player_id: Uuid,
+    pub season: i32,
+    pub school: Option<String>,
+    pub games_played: i32,
+    pub pass_completions: Option<i32>,
+    pub pass_attempts: Option<i32>,
+    pub pass_yards: Option<i32>,
+    pub pass_touchdowns: Option<i32>,
+    pub pass_interceptions: Option<i32>,
+    pub rush_attempts: Option<i32>,
+    pub rush_yards: Option<i32>,
+    pub rush_touchdowns: Option<i32>,
+    pub receptions: Option<i32>,
+    pub receiving_yards: Option<i32>,
+    pub receiving_touchdowns: Option<i32>,
+    pub tackles: Option<i32>,
+    pub tackles_for_loss: Option<f64>,
+    pub sacks: Option<f64>,
+    pub def_interceptions: Option<i32>,
+    pub passes_defended: Option<i32>,
+    pub forced_fumbles: Option<i32>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl CollegeStatsDb {
+    /// Convert from domain CollegeStats to database CollegeStatsDb
+    pub fn from_domain(stats: &CollegeStats) -> Self {
+        let passing = stats.passing.as_ref();
+        let rushing = stats.rushing.as_ref();
+        let receiving = stats.receiving.as_ref();
+        let defense = stats.defense.as_ref();
+
+        Self {
+            id: stats.id,
+            player_id: stats.player_id,
+            season: stats.season,
+            school: stats.school.clone(),
+            games_played: stats.games_played,
+            pass_completions: passing.map(|p| p.completions),
+            pass_attempts: passing.map(|p| p.attempts),
+            pass_yards: passing.map(|p| p.yards),
+            pass_touchdowns: passing.map(|p| p.touchdowns),
+            pass_interceptions: passing.map(|p| p.interceptions),
+            rush_attempts: rushing.map(|r| r.attempts),
+            rush_yards: rushing.map(|r| r.yards),
+            rush_touchdowns: rushing.map(|r| r.touchdowns),
+            receptions: receiving.map(|r| r.receptions),
+            receiving_yards: receiving.map(|r| r.yards),
+            receiving_touchdowns: receiving.map(|r| r.touchdowns),
+            tackles: defense.map(|d| d.tackles),
+            tackles_for_loss: defense.map(|d| d.tackles_for_loss),
+            sacks: defense.map(|d| d.sacks),
+            def_interceptions: defense.map(|d| d.interceptions),
+            passes_defended: defense.map(|d| d.passes_defended),
+            forced_fumbles: defense.map(|d| d.forced_fumbles),
+            created_at: stats.created_at,
+            updated_at: stats.updated_at,
+        }
+    }
+
+    /// Convert from database CollegeStatsDb to domain CollegeStats
+    pub fn to_domain(&self) -> DbResult<CollegeStats> {
+        let passing = match (
+            self.pass_completions,
+            self.pass_attempts,
+            self.pass_yards,
+            self.pass_touchdowns,
+            self.pass_interceptions,
+        ) {
+            (
+                Some(completions),
+                Some(attempts),
+                Some(yards),
+                Some(touchdowns),
+                Some(interceptions),
+            ) => Some(PassingLine {
+                completions,
+                attempts,
+                yards,
+                touchdowns,
+                interceptions,
+            }),
+            _ => None,
+        };
+
+        let rushing = match (self.rush_attempts, self.rush_yards, self.rush_touchdowns) {
+            (Some(attempts), Some(yards), Some(touchdowns)) => Some(RushingLine {
+                attempts,
+                yards,
+                touchdowns,
+            }),
+            _ => None,
+        };
+
+        let receiving = match (
+            self.receptions,
+            self.receiving_yards,
+            self.receiving_touchdowns,
+        ) {
+            (Some(receptions), Some(yards), Some(touchdowns)) => Some(ReceivingLine {
+                receptions,
+                yards,
+                touchdowns,
+            }),
+            _ => None,
+        };
+
+        let defense = match (
+            self.tackles,
+            self.tackles_for_loss,
+            self.sacks,
+            self.def_interceptions,
+            self.passes_defended,
+            self.forced_fumbles,
+        ) {
+            (
+                Some(tackles),
+                Some(tackles_for_loss),
+                Some(sacks),
+                Some(interceptions),
+                Some(passes_defended),
+                Some(forced_fumbles),
+            ) => Some(DefenseLine {
+                tackles,
+                tackles_for_loss,
+                sacks,
+                interceptions,
+                passes_defended,
+                forced_fumbles,
+            }),
+            _ => None,
+        };
+
+        Ok(CollegeStats {
+            id: self.id,
+            player_id: self.player_id,
+            season: self.season,
+            school: self.school.clone(),
+            games_played: self.games_played,
+            passing,
+            rushing,
+            receiving,
+            defense,
+            created_at: self.created_at,
+            updated_at: self.updated_at,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_round_trip_keeps_only_recorded_lines() {
+        let stats = CollegeStats::new(Uuid::new_v4(), 2025, 12)
+            .unwrap()
+            .with_receiving(ReceivingLine {
+                receptions: 70,
+                yards: 1010,
+                touchdowns: 9,
+            })
+            .unwrap()
+            .with_rushing(RushingLine {
+                attempts: 6,
+                yards: 41,
+                touchdowns: 0,
+            })
+            .unwrap();
+
+        let stats_db = CollegeStatsDb::from_domain(&stats);
+        assert_eq!(stats_db.receptions, Some(70));
+        assert_eq!(stats_db.pass_attempts, None);
+        assert_eq!(stats_db.tackles, None);
+
+        let round_trip = stats_db.to_domain().unwrap();
+        assert_eq!(round_trip, stats);
+    }
+}
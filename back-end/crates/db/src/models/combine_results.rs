@@ -1,4 +1,4 @@
-use chrono::{DateTime, Utc};
+use chrono::{DateTime, NaiveDate, Utc};
 use sqlx::FromRow;
 use uuid::Uuid;
 
@@ -13,6 +13,7 @@ pub struct CombineResultsDb {
     pub player_id: Uuid,
     pub year: i32,
     pub source: String,
+    pub event_date: Option<NaiveDate>,
     pub forty_yard_dash: Option<f64>,
     pub bench_press: Option<i32>,
     pub vertical_jump: Option<f64>,
@@ -36,6 +37,7 @@ impl CombineResultsDb {
             player_id: results.player_id,
             year: results.year,
             source: results.source.to_string(),
+            event_date: results.event_date,
             forty_yard_dash: results.forty_yard_dash,
             bench_press: results.bench_press,
             vertical_jump: results.vertical_jump,
@@ -66,6 +68,7 @@ impl CombineResultsDb {
             player_id: self.player_id,
             year: self.year,
             source,
+            event_date: self.event_date,
             forty_yard_dash: self.forty_yard_dash,
             bench_press: self.bench_press,
             vertical_jump: self.vertical_jump,
@@ -113,6 +116,7 @@ mod tests {
             player_id: Uuid::new_v4(),
             year: 2026,
             source: "combine".to_string(),
+            event_date: None,
             forty_yard_dash: Some(4.52),
             bench_press: Some(20),
             vertical_jump: Some(35.5),
@@ -1,29 +1,49 @@
+pub mod actual_draft_pick;
+pub mod adp;
+pub mod audit_entry;
+pub mod chat_message;
+pub mod college_stats;
 pub mod combine_percentile;
 pub mod combine_results;
 pub mod draft;
 pub mod draft_strategy;
 pub mod feldman_freak;
 pub mod player;
+pub mod player_note;
+pub mod player_tag;
 pub mod prospect_profile;
 pub mod prospect_ranking;
 pub mod ranking_source;
 pub mod scouting_report;
+pub mod session_participant;
 pub mod team;
 pub mod team_need;
 pub mod team_season;
 pub mod trade;
+pub mod user;
+pub mod watchlist;
 
+pub use actual_draft_pick::ActualDraftPickDb;
+pub use adp::AdpEntryDb;
+pub use audit_entry::AuditEntryDb;
+pub use chat_message::ChatMessageDb;
+pub use college_stats::CollegeStatsDb;
 pub use combine_percentile::CombinePercentileDb;
 pub use combine_results::CombineResultsDb;
 pub use draft::{DraftDb, DraftPickDb};
 pub use draft_strategy::DraftStrategyDb;
 pub use feldman_freak::FeldmanFreakDb;
 pub use player::PlayerDb;
+pub use player_note::PlayerNoteDb;
+pub use player_tag::PlayerTagDb;
 pub use prospect_profile::ProspectProfileDb;
 pub use prospect_ranking::ProspectRankingDb;
 pub use ranking_source::RankingSourceDb;
 pub use scouting_report::ScoutingReportDb;
+pub use session_participant::SessionParticipantDb;
 pub use team::TeamDb;
 pub use team_need::TeamNeedDb;
 pub use team_season::TeamSeasonDb;
 pub use trade::{PickTradeDb, PickTradeDetailDb};
+pub use user::UserDb;
+pub use watchlist::WatchlistEntryDb;
@@ -1,8 +1,8 @@
-use chrono::{DateTime, Utc};
+use chrono::{DateTime, NaiveDate, Utc};
 use sqlx::FromRow;
 use uuid::Uuid;
 
-use domain::models::{Player, Position};
+use domain::models::{InjuryStatus, Player, Position};
 
 use crate::errors::{DbError, DbResult};
 
@@ -18,6 +18,12 @@ pub struct PlayerDb {
     pub weight_pounds: Option<i32>,
     pub draft_year: i32,
     pub draft_eligible: bool,
+    pub injury_status: String,
+    pub injury_body_part: Option<String>,
+    pub injury_expected_return: Option<NaiveDate>,
+    pub medical_flag: bool,
+    pub profile_url: Option<String>,
+    pub headshot_url: Option<String>,
     pub created_at: DateTime<Utc>,
     pub updated_at: DateTime<Utc>,
 }
@@ -35,6 +41,12 @@ impl PlayerDb {
             weight_pounds: player.weight_pounds,
             draft_year: player.draft_year,
             draft_eligible: player.draft_eligible,
+            injury_status: player.injury_status.to_string(),
+            injury_body_part: player.injury_body_part.clone(),
+            injury_expected_return: player.injury_expected_return,
+            medical_flag: player.medical_flag,
+            profile_url: player.profile_url.clone(),
+            headshot_url: player.headshot_url.clone(),
             created_at: player.created_at,
             updated_at: player.updated_at,
         }
@@ -42,6 +54,13 @@ impl PlayerDb {
 
     /// Convert from database PlayerDb to domain Player
     pub fn to_domain(&self) -> DbResult<Player> {
+        let injury_status: InjuryStatus = self.injury_status.parse().map_err(|_| {
+            DbError::MappingError(format!(
+                "Invalid injury status in database: '{}'",
+                self.injury_status
+            ))
+        })?;
+
         Ok(Player {
             id: self.id,
             first_name: self.first_name.clone(),
@@ -52,6 +71,12 @@ impl PlayerDb {
             weight_pounds: self.weight_pounds,
             draft_year: self.draft_year,
             draft_eligible: self.draft_eligible,
+            injury_status,
+            injury_body_part: self.injury_body_part.clone(),
+            injury_expected_return: self.injury_expected_return,
+            medical_flag: self.medical_flag,
+            profile_url: self.profile_url.clone(),
+            headshot_url: self.headshot_url.clone(),
             created_at: self.created_at,
             updated_at: self.updated_at,
         })
@@ -163,6 +188,12 @@ mod tests {
             weight_pounds: Some(220),
             draft_year: 2026,
             draft_eligible: true,
+            injury_status: "limited".to_string(),
+            injury_body_part: Some("shoulder".to_string()),
+            injury_expected_return: None,
+            medical_flag: false,
+            profile_url: None,
+            headshot_url: None,
             created_at: Utc::now(),
             updated_at: Utc::now(),
         };
@@ -174,5 +205,7 @@ mod tests {
         assert_eq!(player.first_name, "John");
         assert_eq!(player.position, Position::QB);
         assert_eq!(player.college, Some("Texas".to_string()));
+        assert_eq!(player.injury_status, InjuryStatus::Limited);
+        assert_eq!(player.injury_body_part, Some("shoulder".to_string()));
     }
 }
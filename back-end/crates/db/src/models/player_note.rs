@@ -0,0 +1,94 @@
+use chrono::{DateTime, NaiveDate, Utc};
+use sqlx::FromRow;
+use uuid::Uuid;
+
+use domain::models::{NoteTag, PlayerNote};
+
+use crate::errors::{DbError, DbResult};
+
+/// Database model for player_notes table
+#[derive(Debug, Clone, FromRow)]
+pub struct PlayerNoteDb {
+    pub id: Uuid,
+    pub player_id: Uuid,
+    pub source: String,
+    pub note_date: NaiveDate,
+    pub tag: String,
+    pub content: String,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl PlayerNoteDb {
+    /// Convert from domain PlayerNote to database PlayerNoteDb
+    pub fn from_domain(note: &PlayerNote) -> Self {
+        Self {
+            id: note.id,
+            player_id: note.player_id,
+            source: note.source.clone(),
+            note_date: note.note_date,
+            tag: note.tag.to_string(),
+            content: note.content.clone(),
+            created_at: note.created_at,
+            updated_at: note.updated_at,
+        }
+    }
+
+    /// Convert from database PlayerNoteDb to domain PlayerNote
+    pub fn to_domain(&self) -> DbResult<PlayerNote> {
+        let tag: NoteTag = self.tag.parse().map_err(|_| {
+            DbError::MappingError(format!("Invalid note tag in database: '{}'", self.tag))
+        })?;
+
+        Ok(PlayerNote {
+            id: self.id,
+            player_id: self.player_id,
+            source: self.source.clone(),
+            note_date: self.note_date,
+            tag,
+            content: self.content.clone(),
+            created_at: self.created_at,
+            updated_at: self.updated_at,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_domain_to_db_conversion() {
+        let note = PlayerNote::new(
+            Uuid::new_v4(),
+            "Beat reporter".to_string(),
+            NaiveDate::from_ymd_opt(2026, 4, 1).unwrap(),
+            NoteTag::Injury,
+            "Hamstring tightness at pro day".to_string(),
+        )
+        .unwrap();
+
+        let note_db = PlayerNoteDb::from_domain(&note);
+        assert_eq!(note_db.player_id, note.player_id);
+        assert_eq!(note_db.tag, "injury");
+    }
+
+    #[test]
+    fn test_db_to_domain_conversion() {
+        let mut note_db = PlayerNoteDb {
+            id: Uuid::new_v4(),
+            player_id: Uuid::new_v4(),
+            source: "Insider".to_string(),
+            note_date: NaiveDate::from_ymd_opt(2026, 4, 1).unwrap(),
+            tag: "faller".to_string(),
+            content: "Slipping after interviews".to_string(),
+            created_at: Utc::now(),
+            updated_at: Utc::now(),
+        };
+
+        assert_eq!(note_db.to_domain().unwrap().tag, NoteTag::Faller);
+
+        note_db.tag = "rumor".to_string();
+        assert!(note_db.to_domain().is_err());
+    }
+}
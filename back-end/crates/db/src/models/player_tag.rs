@@ -0,0 +1,33 @@
+use chrono::{DateTime, Utc};
+use sqlx::FromRow;
+use uuid::Uuid;
+
+use domain::models::PlayerTag;
+
+/// Database model for player_tags table
+#[derive(Debug, Clone, FromRow)]
+pub struct PlayerTagDb {
+    pub player_id: Uuid,
+    pub tag: String,
+    pub created_at: DateTime<Utc>,
+}
+
+impl PlayerTagDb {
+    /// Convert from domain PlayerTag to database PlayerTagDb
+    pub fn from_domain(tag: &PlayerTag) -> Self {
+        Self {
+            player_id: tag.player_id,
+            tag: tag.tag.clone(),
+            created_at: tag.created_at,
+        }
+    }
+
+    /// Convert from database PlayerTagDb to domain PlayerTag
+    pub fn to_domain(self) -> PlayerTag {
+        PlayerTag {
+            player_id: self.player_id,
+            tag: self.tag,
+            created_at: self.created_at,
+        }
+    }
+}
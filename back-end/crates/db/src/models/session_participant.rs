@@ -0,0 +1,68 @@
+use chrono::{DateTime, Utc};
+use sqlx::FromRow;
+use uuid::Uuid;
+
+use domain::models::SessionParticipant;
+
+/// Database model for session_participants table
+#[derive(Debug, Clone, FromRow)]
+pub struct SessionParticipantDb {
+    pub id: Uuid,
+    pub session_id: Uuid,
+    pub user_id: Uuid,
+    pub display_name: String,
+    pub team_ids: Vec<Uuid>,
+    pub joined_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl SessionParticipantDb {
+    /// Convert from domain SessionParticipant to database SessionParticipantDb
+    pub fn from_domain(participant: &SessionParticipant) -> Self {
+        Self {
+            id: participant.id,
+            session_id: participant.session_id,
+            user_id: participant.user_id,
+            display_name: participant.display_name.clone(),
+            team_ids: participant.team_ids.clone(),
+            joined_at: participant.joined_at,
+            updated_at: participant.updated_at,
+        }
+    }
+
+    /// Convert from database SessionParticipantDb to domain SessionParticipant
+    pub fn to_domain(self) -> SessionParticipant {
+        SessionParticipant {
+            id: self.id,
+            session_id: self.session_id,
+            user_id: self.user_id,
+            display_name: self.display_name,
+            team_ids: self.team_ids,
+            joined_at: self.joined_at,
+            updated_at: self.updated_at,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_round_trip_conversion() {
+        let team_id = Uuid::new_v4();
+        let participant = SessionParticipant::new(
+            Uuid::new_v4(),
+            Uuid::new_v4(),
+            "Alice".to_string(),
+            vec![team_id],
+        )
+        .unwrap();
+
+        let db = SessionParticipantDb::from_domain(&participant);
+        assert_eq!(db.team_ids, vec![team_id]);
+        assert_eq!(db.user_id, participant.user_id);
+
+        assert_eq!(db.to_domain(), participant);
+    }
+}
@@ -0,0 +1,48 @@
+use chrono::{DateTime, Utc};
+use sqlx::FromRow;
+use uuid::Uuid;
+
+use domain::models::User;
+
+use crate::errors::{DbError, DbResult};
+
+/// Database model for users table
+#[derive(Debug, Clone, FromRow)]
+pub struct UserDb {
+    pub id: Uuid,
+    pub email: String,
+    pub display_name: String,
+    pub password_hash: String,
+    pub role: String,
+    pub created_at: DateTime<Utc>,
+}
+
+impl UserDb {
+    /// Convert from domain User to database UserDb
+    pub fn from_domain(user: &User) -> Self {
+        Self {
+            id: user.id,
+            email: user.email.clone(),
+            display_name: user.display_name.clone(),
+            password_hash: user.password_hash.clone(),
+            role: user.role.to_string(),
+            created_at: user.created_at,
+        }
+    }
+
+    /// Convert from database UserDb to domain User
+    pub fn to_domain(self) -> DbResult<User> {
+        let role = self.role.parse().map_err(|_| {
+            DbError::MappingError(format!("Invalid role in database: '{}'", self.role))
+        })?;
+
+        Ok(User {
+            id: self.id,
+            email: self.email,
+            display_name: self.display_name,
+            password_hash: self.password_hash,
+            role,
+            created_at: self.created_at,
+        })
+    }
+}
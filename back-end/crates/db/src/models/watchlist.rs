@@ -0,0 +1,33 @@
+use chrono::{DateTime, Utc};
+use sqlx::FromRow;
+use uuid::Uuid;
+
+use domain::models::WatchlistEntry;
+
+/// Database model for user_watchlists table
+#[derive(Debug, Clone, FromRow)]
+pub struct WatchlistEntryDb {
+    pub user_id: Uuid,
+    pub player_id: Uuid,
+    pub created_at: DateTime<Utc>,
+}
+
+impl WatchlistEntryDb {
+    /// Convert from domain WatchlistEntry to database WatchlistEntryDb
+    pub fn from_domain(entry: &WatchlistEntry) -> Self {
+        Self {
+            user_id: entry.user_id,
+            player_id: entry.player_id,
+            created_at: entry.created_at,
+        }
+    }
+
+    /// Convert from database WatchlistEntryDb to domain WatchlistEntry
+    pub fn to_domain(self) -> WatchlistEntry {
+        WatchlistEntry {
+            user_id: self.user_id,
+            player_id: self.player_id,
+            created_at: self.created_at,
+        }
+    }
+}
@@ -0,0 +1,49 @@
+//! Helpers for queries assembled at runtime with `QueryBuilder`
+
+use domain::models::{PageRequest, Sort, SortField};
+use sqlx::{Postgres, QueryBuilder};
+
+/// Appends an ORDER BY for the requested sort keys followed by the
+/// repository's default ordering, which breaks any remaining ties.
+/// Column names come from the repository's whitelist, never from input.
+pub(crate) fn push_order_by<F: SortField>(
+    builder: &mut QueryBuilder<'_, Postgres>,
+    sort: &Sort<F>,
+    column: impl Fn(F) -> &'static str,
+    default_order: &str,
+) {
+    builder.push(" ORDER BY ");
+    for key in sort.keys() {
+        builder.push(column(key.field));
+        builder.push(if key.descending {
+            " DESC NULLS LAST, "
+        } else {
+            " ASC, "
+        });
+    }
+    builder.push(default_order);
+}
+
+/// Rows written per statement by the bulk inserts unless a repository is
+/// given another batch size
+pub const DEFAULT_BATCH_SIZE: usize = 500;
+
+/// Postgres caps a statement at 65,535 bind parameters
+const MAX_BIND_PARAMS: usize = 65_535;
+
+/// Rows per multi-row INSERT: at least one, and few enough that
+/// `columns` binds per row stay under the parameter limit
+pub(crate) fn rows_per_insert(batch_size: usize, columns: usize) -> usize {
+    batch_size.clamp(1, MAX_BIND_PARAMS / columns)
+}
+
+/// Appends LIMIT/OFFSET when a page was requested
+pub(crate) fn push_page(builder: &mut QueryBuilder<'_, Postgres>, page: Option<PageRequest>) {
+    if let Some(page) = page {
+        builder
+            .push(" LIMIT ")
+            .push_bind(page.limit())
+            .push(" OFFSET ")
+            .push_bind(page.offset());
+    }
+}
@@ -0,0 +1,128 @@
+use async_trait::async_trait;
+use sqlx::PgPool;
+
+use domain::errors::DomainResult;
+use domain::models::ActualDraftPick;
+use domain::repositories::ActualDraftPickRepository;
+
+use crate::errors::DbError;
+use crate::instrument::traced;
+use crate::models::ActualDraftPickDb;
+
+/// SQLx implementation of ActualDraftPickRepository
+pub struct SqlxActualDraftPickRepository {
+    pool: PgPool,
+    /// Serves collection reads; the primary unless a replica is set
+    read_pool: PgPool,
+}
+
+impl SqlxActualDraftPickRepository {
+    pub fn new(pool: PgPool) -> Self {
+        Self {
+            read_pool: pool.clone(),
+            pool,
+        }
+    }
+
+    /// Send collection reads to a read replica
+    pub fn with_read_pool(mut self, read_pool: PgPool) -> Self {
+        self.read_pool = read_pool;
+        self
+    }
+}
+
+#[async_trait]
+impl ActualDraftPickRepository for SqlxActualDraftPickRepository {
+    async fn find_by_year(&self, year: i32) -> DomainResult<Vec<ActualDraftPick>> {
+        traced("actual_draft_pick.find_by_year", async move {
+            let results = sqlx::query_as!(
+                ActualDraftPickDb,
+                r#"
+                SELECT id, year, round, pick_number, overall_pick, team_id, player_id, created_at
+                FROM actual_draft_picks
+                WHERE year = $1
+                ORDER BY overall_pick ASC
+                "#,
+                year
+            )
+            .fetch_all(&self.read_pool)
+            .await
+            .map_err(DbError::DatabaseError)?;
+
+            results
+                .into_iter()
+                .map(|r| r.to_domain().map_err(Into::into))
+                .collect()
+        })
+        .await
+    }
+
+    async fn replace_year(&self, year: i32, picks: &[ActualDraftPick]) -> DomainResult<u64> {
+        traced("actual_draft_pick.replace_year", async move {
+            let mut tx = self.pool.begin().await.map_err(DbError::DatabaseError)?;
+
+            sqlx::query!("DELETE FROM actual_draft_picks WHERE year = $1", year)
+                .execute(&mut *tx)
+                .await
+                .map_err(DbError::DatabaseError)?;
+
+            for pick in picks {
+                let pick_db = ActualDraftPickDb::from_domain(pick);
+
+                sqlx::query!(
+                    r#"
+                    INSERT INTO actual_draft_picks
+                    (id, year, round, pick_number, overall_pick, team_id, player_id, created_at)
+                    VALUES ($1, $2, $3, $4, $5, $6, $7, $8)
+                    "#,
+                    pick_db.id,
+                    pick_db.year,
+                    pick_db.round,
+                    pick_db.pick_number,
+                    pick_db.overall_pick,
+                    pick_db.team_id,
+                    pick_db.player_id,
+                    pick_db.created_at
+                )
+                .execute(&mut *tx)
+                .await
+                .map_err(|e| {
+                    if let sqlx::Error::Database(db_err) = &e {
+                        if db_err.is_unique_violation() {
+                            return DbError::DuplicateEntry(format!(
+                                "Pick {} or its player is listed twice for year {}",
+                                pick.overall_pick, pick.year
+                            ));
+                        }
+                        if db_err.is_foreign_key_violation() {
+                            return DbError::NotFound("Team or player not found".to_string());
+                        }
+                    }
+                    DbError::DatabaseError(e)
+                })?;
+            }
+
+            tx.commit().await.map_err(DbError::DatabaseError)?;
+
+            Ok(picks.len() as u64)
+        })
+        .await
+    }
+
+    async fn delete_by_year(&self, year: i32) -> DomainResult<u64> {
+        traced("actual_draft_pick.delete_by_year", async move {
+            let result = sqlx::query!(
+                r#"
+                DELETE FROM actual_draft_picks WHERE year = $1
+                "#,
+                year
+            )
+            .execute(&self.pool)
+            .await
+            .map_err(DbError::DatabaseError)?;
+
+            Ok(result.rows_affected())
+        })
+        .await
+    }
+}
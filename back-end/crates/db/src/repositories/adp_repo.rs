@@ -0,0 +1,95 @@
+use async_trait::async_trait;
+use sqlx::PgPool;
+
+use domain::errors::DomainResult;
+use domain::models::AdpEntry;
+use domain::repositories::AdpRepository;
+
+use crate::errors::DbError;
+use crate::instrument::traced;
+use crate::models::AdpEntryDb;
+
+/// SQLx implementation of AdpRepository
+pub struct SqlxAdpRepository {
+    pool: PgPool,
+    /// Serves collection reads; the primary unless a replica is set
+    read_pool: PgPool,
+}
+
+impl SqlxAdpRepository {
+    pub fn new(pool: PgPool) -> Self {
+        Self {
+            read_pool: pool.clone(),
+            pool,
+        }
+    }
+
+    /// Send collection reads to a read replica
+    pub fn with_read_pool(mut self, read_pool: PgPool) -> Self {
+        self.read_pool = read_pool;
+        self
+    }
+}
+
+#[async_trait]
+impl AdpRepository for SqlxAdpRepository {
+    async fn record(&self, entries: &[AdpEntry]) -> DomainResult<u64> {
+        traced("adp.record", async move {
+            let mut tx = self.pool.begin().await.map_err(DbError::DatabaseError)?;
+
+            let mut recorded = 0;
+            for entry in entries {
+                let entry_db = AdpEntryDb::from_domain(entry);
+
+                let result = sqlx::query!(
+                    r#"
+                    INSERT INTO adp (id, draft_id, year, player_id, round, overall_pick, recorded_at)
+                    VALUES ($1, $2, $3, $4, $5, $6, $7)
+                    ON CONFLICT (draft_id, player_id) DO NOTHING
+                    "#,
+                    entry_db.id,
+                    entry_db.draft_id,
+                    entry_db.year,
+                    entry_db.player_id,
+                    entry_db.round,
+                    entry_db.overall_pick,
+                    entry_db.recorded_at
+                )
+                .execute(&mut *tx)
+                .await
+                .map_err(DbError::DatabaseError)?;
+
+                recorded += result.rows_affected();
+            }
+
+            tx.commit().await.map_err(DbError::DatabaseError)?;
+
+            Ok(recorded)
+        })
+        .await
+    }
+
+    async fn find_by_year(&self, year: Option<i32>) -> DomainResult<Vec<AdpEntry>> {
+        traced("adp.find_by_year", async move {
+            let results = sqlx::query_as!(
+                AdpEntryDb,
+                r#"
+                SELECT id, draft_id, year, player_id, round, overall_pick, recorded_at
+                FROM adp
+                WHERE $1::int IS NULL OR year = $1
+                ORDER BY recorded_at ASC
+                "#,
+                year
+            )
+            .fetch_all(&self.read_pool)
+            .await
+            .map_err(DbError::DatabaseError)?;
+
+            results
+                .into_iter()
+                .map(|r| r.to_domain().map_err(Into::into))
+                .collect()
+        })
+        .await
+    }
+}
@@ -0,0 +1,164 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use sqlx::PgPool;
+use uuid::Uuid;
+
+use domain::errors::DomainResult;
+use domain::models::ChatMessage;
+use domain::repositories::ChatMessageRepository;
+
+use crate::errors::DbError;
+use crate::instrument::traced;
+use crate::models::ChatMessageDb;
+
+/// SQLx implementation of ChatMessageRepository
+pub struct SqlxChatMessageRepository {
+    pool: PgPool,
+}
+
+impl SqlxChatMessageRepository {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl ChatMessageRepository for SqlxChatMessageRepository {
+    async fn create(&self, message: &ChatMessage) -> DomainResult<ChatMessage> {
+        traced("chat_message.create", async move {
+            let message_db = ChatMessageDb::from_domain(message);
+
+            let result = sqlx::query_as!(
+                ChatMessageDb,
+                r#"
+                INSERT INTO chat_messages
+                    (id, session_id, participant_id, display_name, body, created_at)
+                VALUES ($1, $2, $3, $4, $5, $6)
+                RETURNING id, session_id, participant_id, display_name, body, created_at
+                "#,
+                message_db.id,
+                message_db.session_id,
+                message_db.participant_id,
+                message_db.display_name,
+                message_db.body,
+                message_db.created_at
+            )
+            .fetch_one(&self.pool)
+            .await
+            .map_err(|e| {
+                if let sqlx::Error::Database(db_err) = &e {
+                    if db_err.is_foreign_key_violation() {
+                        return DbError::NotFound(format!(
+                            "Session with id {} not found",
+                            message.session_id
+                        ));
+                    }
+                }
+                DbError::DatabaseError(e)
+            })?;
+
+            Ok(result.to_domain())
+        })
+        .await
+    }
+
+    async fn list_by_session(
+        &self,
+        session_id: Uuid,
+        before: Option<DateTime<Utc>>,
+        limit: i64,
+    ) -> DomainResult<Vec<ChatMessage>> {
+        traced("chat_message.list_by_session", async move {
+            // Take the newest page, then flip it so callers get reading order
+            let mut results = sqlx::query_as!(
+                ChatMessageDb,
+                r#"
+                SELECT id, session_id, participant_id, display_name, body, created_at
+                FROM chat_messages
+                WHERE session_id = $1 AND ($2::timestamptz IS NULL OR created_at < $2)
+                ORDER BY created_at DESC
+                LIMIT $3
+                "#,
+                session_id,
+                before,
+                limit
+            )
+            .fetch_all(&self.pool)
+            .await
+            .map_err(DbError::DatabaseError)?;
+
+            results.reverse();
+            Ok(results.into_iter().map(ChatMessageDb::to_domain).collect())
+        })
+        .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::get_test_pool;
+
+    async fn create_test_session(pool: &PgPool) -> Uuid {
+        let draft_id = Uuid::new_v4();
+        let session_id = Uuid::new_v4();
+
+        sqlx::query!(
+            "INSERT INTO drafts (id, year, status, rounds, picks_per_round) VALUES ($1, 2026, 'NotStarted', 1, 2)",
+            draft_id
+        )
+        .execute(pool)
+        .await
+        .unwrap();
+
+        sqlx::query!(
+            "INSERT INTO draft_sessions (id, draft_id) VALUES ($1, $2)",
+            session_id,
+            draft_id
+        )
+        .execute(pool)
+        .await
+        .unwrap();
+
+        session_id
+    }
+
+    async fn cleanup_session(pool: &PgPool, session_id: Uuid) {
+        sqlx::query!(
+            "DELETE FROM drafts WHERE id = (SELECT draft_id FROM draft_sessions WHERE id = $1)",
+            session_id
+        )
+        .execute(pool)
+        .await
+        .unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_create_and_page_chat_history() {
+        let pool = get_test_pool().await;
+        let repo = SqlxChatMessageRepository::new(pool.clone());
+        let session_id = create_test_session(&pool).await;
+
+        let base = Utc::now() - chrono::Duration::minutes(10);
+        for i in 0..5 {
+            let mut message =
+                ChatMessage::new(session_id, None, "Alice".to_string(), format!("msg {}", i))
+                    .unwrap();
+            message.created_at = base + chrono::Duration::seconds(i);
+            repo.create(&message).await.unwrap();
+        }
+
+        let latest = repo.list_by_session(session_id, None, 3).await.unwrap();
+        let bodies: Vec<&str> = latest.iter().map(|m| m.body.as_str()).collect();
+        assert_eq!(bodies, vec!["msg 2", "msg 3", "msg 4"]);
+
+        let earlier = repo
+            .list_by_session(session_id, Some(latest[0].created_at), 10)
+            .await
+            .unwrap();
+        let bodies: Vec<&str> = earlier.iter().map(|m| m.body.as_str()).collect();
+        assert_eq!(bodies, vec!["msg 0", "msg 1"]);
+
+        cleanup_session(&pool, session_id).await;
+    }
+}
@@ -0,0 +1,171 @@
+use async_trait::async_trait;
+use sqlx::PgPool;
+use uuid::Uuid;
+
+use domain::errors::DomainResult;
+use domain::models::CollegeStats;
+use domain::repositories::CollegeStatsRepository;
+
+use crate::errors::DbError;
+use crate::instrument::traced;
+use crate::models::CollegeStatsDb;
+
+/// SQLx implementation of CollegeStatsRepository
+pub struct SqlxCollegeStatsRepository {
+    pool: PgPool,
+    /// Serves collection reads; the primary unless a replica is set
+    read_pool: PgPool,
+}
+
+impl SqlxCollegeStatsRepository {
+    pub fn new(pool: PgPool) -> Self {
+        Self {
+            read_pool: pool.clone(),
+            pool,
+        }
+    }
+
+    /// Send collection reads to a read replica
+    pub fn with_read_pool(mut self, read_pool: PgPool) -> Self {
+        self.read_pool = read_pool;
+        self
+    }
+}
+
+#[async_trait]
+impl CollegeStatsRepository for SqlxCollegeStatsRepository {
+    async fn upsert(&self, stats: &CollegeStats) -> DomainResult<CollegeStats> {
+        traced("college_stats.upsert", async move {
+            let stats_db = CollegeStatsDb::from_domain(stats);
+
+            let result = sqlx::query_as!(
+                CollegeStatsDb,
+                r#"
+                INSERT INTO college_stats (
+                    id, player_id, season, school, games_played,
+                    pass_completions, pass_attempts, pass_yards, pass_touchdowns, pass_interceptions,
+                    rush_attempts, rush_yards, rush_touchdowns,
+                    receptions, receiving_yards, receiving_touchdowns,
+                    tackles, tackles_for_loss, sacks, def_interceptions, passes_defended, forced_fumbles,
+                    created_at, updated_at
+                )
+                VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12, $13, $14, $15, $16, $17, $18, $19, $20, $21, $22, $23, $24)
+                ON CONFLICT (player_id, season) DO UPDATE SET
+                    school = EXCLUDED.school,
+                    games_played = EXCLUDED.games_played,
+                    pass_completions = EXCLUDED.pass_completions,
+                    pass_attempts = EXCLUDED.pass_attempts,
+                    pass_yards = EXCLUDED.pass_yards,
+                    pass_touchdowns = EXCLUDED.pass_touchdowns,
+                    pass_interceptions = EXCLUDED.pass_interceptions,
+                    rush_attempts = EXCLUDED.rush_attempts,
+                    rush_yards = EXCLUDED.rush_yards,
+                    rush_touchdowns = EXCLUDED.rush_touchdowns,
+                    receptions = EXCLUDED.receptions,
+                    receiving_yards = EXCLUDED.receiving_yards,
+                    receiving_touchdowns = EXCLUDED.receiving_touchdowns,
+                    tackles = EXCLUDED.tackles,
+                    tackles_for_loss = EXCLUDED.tackles_for_loss,
+                    sacks = EXCLUDED.sacks,
+                    def_interceptions = EXCLUDED.def_interceptions,
+                    passes_defended = EXCLUDED.passes_defended,
+                    forced_fumbles = EXCLUDED.forced_fumbles,
+                    updated_at = NOW()
+                RETURNING id, player_id, season, school, games_played,
+                    pass_completions, pass_attempts, pass_yards, pass_touchdowns, pass_interceptions,
+                    rush_attempts, rush_yards, rush_touchdowns,
+                    receptions, receiving_yards, receiving_touchdowns,
+                    tackles, tackles_for_loss, sacks, def_interceptions, passes_defended, forced_fumbles,
+                    created_at, updated_at
+                "#,
+                stats_db.id,
+                stats_db.player_id,
+                stats_db.season,
+                stats_db.school,
+                stats_db.games_played,
+                stats_db.pass_completions,
+                stats_db.pass_attempts,
+                stats_db.pass_yards,
+                stats_db.pass_touchdowns,
+                stats_db.pass_interceptions,
+                stats_db.rush_attempts,
+                stats_db.rush_yards,
+                stats_db.rush_touchdowns,
+                stats_db.receptions,
+                stats_db.receiving_yards,
+                stats_db.receiving_touchdowns,
+                stats_db.tackles,
+                stats_db.tackles_for_loss,
+                stats_db.sacks,
+                stats_db.def_interceptions,
+                stats_db.passes_defended,
+                stats_db.forced_fumbles,
+                stats_db.created_at,
+                stats_db.updated_at
+            )
+            .fetch_one(&self.pool)
+            .await
+            .map_err(|e| {
+                if let sqlx::Error::Database(db_err) = &e {
+                    if db_err.is_foreign_key_violation() {
+                        return DbError::NotFound(format!(
+                            "Player with id {} not found",
+                            stats.player_id
+                        ));
+                    }
+                }
+                DbError::DatabaseError(e)
+            })?;
+
+            result.to_domain().map_err(Into::into)
+        })
+        .await
+    }
+
+    async fn find_by_player_id(&self, player_id: Uuid) -> DomainResult<Vec<CollegeStats>> {
+        traced("college_stats.find_by_player_id", async move {
+            let results = sqlx::query_as!(
+                CollegeStatsDb,
+                r#"
+                SELECT id, player_id, season, school, games_played,
+                    pass_completions, pass_attempts, pass_yards, pass_touchdowns, pass_interceptions,
+                    rush_attempts, rush_yards, rush_touchdowns,
+                    receptions, receiving_yards, receiving_touchdowns,
+                    tackles, tackles_for_loss, sacks, def_interceptions, passes_defended, forced_fumbles,
+                    created_at, updated_at
+                FROM college_stats
+                WHERE player_id = $1
+                ORDER BY season ASC
+                "#,
+                player_id
+            )
+            .fetch_all(&self.read_pool)
+            .await
+            .map_err(DbError::DatabaseError)?;
+
+            results
+                .into_iter()
+                .map(|r| r.to_domain().map_err(Into::into))
+                .collect()
+        })
+        .await
+    }
+
+    async fn delete_by_draft_year(&self, draft_year: i32) -> DomainResult<u64> {
+        traced("college_stats.delete_by_draft_year", async move {
+            let result = sqlx::query!(
+                r#"
+                DELETE FROM college_stats
+                WHERE player_id IN (SELECT id FROM players WHERE draft_year = $1)
+                "#,
+                draft_year
+            )
+            .execute(&self.pool)
+            .await
+            .map_err(DbError::DatabaseError)?;
+
+            Ok(result.rows_affected())
+        })
+        .await
+    }
+}
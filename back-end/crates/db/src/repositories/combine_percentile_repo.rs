@@ -7,60 +7,78 @@ use domain::models::CombinePercentile;
 use domain::repositories::CombinePercentileRepository;
 
 use crate::errors::DbError;
+use crate::instrument::traced;
 use crate::models::CombinePercentileDb;
 
 pub struct SqlxCombinePercentileRepository {
     pool: PgPool,
+    /// Serves collection reads; the primary unless a replica is set
+    read_pool: PgPool,
 }
 
 impl SqlxCombinePercentileRepository {
     pub fn new(pool: PgPool) -> Self {
-        Self { pool }
+        Self {
+            read_pool: pool.clone(),
+            pool,
+        }
+    }
+
+    /// Send collection reads to a read replica
+    pub fn with_read_pool(mut self, read_pool: PgPool) -> Self {
+        self.read_pool = read_pool;
+        self
     }
 }
 
 #[async_trait]
 impl CombinePercentileRepository for SqlxCombinePercentileRepository {
     async fn find_all(&self) -> DomainResult<Vec<CombinePercentile>> {
-        let rows = sqlx::query_as!(
-            CombinePercentileDb,
-            r#"
-            SELECT id, position, measurement, sample_size, min_value,
-                   p10, p20, p30, p40, p50, p60, p70, p80, p90,
-                   max_value, years_start, years_end, created_at, updated_at
-            FROM combine_percentiles
-            ORDER BY position, measurement
-            "#
-        )
-        .fetch_all(&self.pool)
-        .await
-        .map_err(DbError::DatabaseError)?;
+        traced("combine_percentile.find_all", async move {
+            let rows = sqlx::query_as!(
+                CombinePercentileDb,
+                r#"
+                SELECT id, position, measurement, sample_size, min_value,
+                       p10, p20, p30, p40, p50, p60, p70, p80, p90,
+                       max_value, years_start, years_end, created_at, updated_at
+                FROM combine_percentiles
+                ORDER BY position, measurement
+                "#
+            )
+            .fetch_all(&self.read_pool)
+            .await
+            .map_err(DbError::DatabaseError)?;
 
-        rows.into_iter()
-            .map(|r| r.to_domain().map_err(Into::into))
-            .collect()
+            rows.into_iter()
+                .map(|r| r.to_domain().map_err(Into::into))
+                .collect()
+        })
+        .await
     }
 
     async fn find_by_position(&self, position: &str) -> DomainResult<Vec<CombinePercentile>> {
-        let rows = sqlx::query_as!(
-            CombinePercentileDb,
-            r#"
-            SELECT id, position, measurement, sample_size, min_value,
-                   p10, p20, p30, p40, p50, p60, p70, p80, p90,
-                   max_value, years_start, years_end, created_at, updated_at
-            FROM combine_percentiles
-            WHERE position = $1
-            ORDER BY measurement
-            "#,
-            position
-        )
-        .fetch_all(&self.pool)
-        .await
-        .map_err(DbError::DatabaseError)?;
+        traced("combine_percentile.find_by_position", async move {
+            let rows = sqlx::query_as!(
+                CombinePercentileDb,
+                r#"
+                SELECT id, position, measurement, sample_size, min_value,
+                       p10, p20, p30, p40, p50, p60, p70, p80, p90,
+                       max_value, years_start, years_end, created_at, updated_at
+                FROM combine_percentiles
+                WHERE position = $1
+                ORDER BY measurement
+                "#,
+                position
+            )
+            .fetch_all(&self.read_pool)
+            .await
+            .map_err(DbError::DatabaseError)?;
 
-        rows.into_iter()
-            .map(|r| r.to_domain().map_err(Into::into))
-            .collect()
+            rows.into_iter()
+                .map(|r| r.to_domain().map_err(Into::into))
+                .collect()
+        })
+        .await
     }
 
     async fn find_by_position_and_measurement(
@@ -68,107 +86,124 @@ impl CombinePercentileRepository for SqlxCombinePercentileRepository {
         position: &str,
         measurement: &str,
     ) -> DomainResult<Option<CombinePercentile>> {
-        let row = sqlx::query_as!(
-            CombinePercentileDb,
-            r#"
-            SELECT id, position, measurement, sample_size, min_value,
-                   p10, p20, p30, p40, p50, p60, p70, p80, p90,
-                   max_value, years_start, years_end, created_at, updated_at
-            FROM combine_percentiles
-            WHERE position = $1 AND measurement = $2
-            "#,
-            position,
-            measurement
+        traced(
+            "combine_percentile.find_by_position_and_measurement",
+            async move {
+                let row = sqlx::query_as!(
+                    CombinePercentileDb,
+                    r#"
+                SELECT id, position, measurement, sample_size, min_value,
+                       p10, p20, p30, p40, p50, p60, p70, p80, p90,
+                       max_value, years_start, years_end, created_at, updated_at
+                FROM combine_percentiles
+                WHERE position = $1 AND measurement = $2
+                "#,
+                    position,
+                    measurement
+                )
+                .fetch_optional(&self.pool)
+                .await
+                .map_err(DbError::DatabaseError)?;
+
+                match row {
+                    Some(r) => Ok(Some(r.to_domain()?)),
+                    None => Ok(None),
+                }
+            },
         )
-        .fetch_optional(&self.pool)
         .await
-        .map_err(DbError::DatabaseError)?;
-
-        match row {
-            Some(r) => Ok(Some(r.to_domain()?)),
-            None => Ok(None),
-        }
     }
 
     async fn upsert(&self, percentile: &CombinePercentile) -> DomainResult<CombinePercentile> {
-        let db = CombinePercentileDb::from_domain(percentile);
-
-        let row = sqlx::query_as!(
-            CombinePercentileDb,
-            r#"
-            INSERT INTO combine_percentiles (
-                id, position, measurement, sample_size, min_value,
-                p10, p20, p30, p40, p50, p60, p70, p80, p90,
-                max_value, years_start, years_end
-            ) VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12, $13, $14, $15, $16, $17)
-            ON CONFLICT (position, measurement)
-            DO UPDATE SET
-                sample_size = EXCLUDED.sample_size,
-                min_value = EXCLUDED.min_value,
-                p10 = EXCLUDED.p10,
-                p20 = EXCLUDED.p20,
-                p30 = EXCLUDED.p30,
-                p40 = EXCLUDED.p40,
-                p50 = EXCLUDED.p50,
-                p60 = EXCLUDED.p60,
-                p70 = EXCLUDED.p70,
-                p80 = EXCLUDED.p80,
-                p90 = EXCLUDED.p90,
-                max_value = EXCLUDED.max_value,
-                years_start = EXCLUDED.years_start,
-                years_end = EXCLUDED.years_end,
-                updated_at = NOW()
-            RETURNING id, position, measurement, sample_size, min_value,
-                      p10, p20, p30, p40, p50, p60, p70, p80, p90,
-                      max_value, years_start, years_end, created_at, updated_at
-            "#,
-            db.id,
-            db.position,
-            db.measurement,
-            db.sample_size,
-            db.min_value,
-            db.p10,
-            db.p20,
-            db.p30,
-            db.p40,
-            db.p50,
-            db.p60,
-            db.p70,
-            db.p80,
-            db.p90,
-            db.max_value,
-            db.years_start,
-            db.years_end,
-        )
-        .fetch_one(&self.pool)
-        .await
-        .map_err(DbError::DatabaseError)?;
+        traced("combine_percentile.upsert", async move {
+            let db = CombinePercentileDb::from_domain(percentile);
+
+            let row = sqlx::query_as!(
+                CombinePercentileDb,
+                r#"
+                INSERT INTO combine_percentiles (
+                    id, position, measurement, sample_size, min_value,
+                    p10, p20, p30, p40, p50, p60, p70, p80, p90,
+                    max_value, years_start, years_end
+                ) VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12, $13, $14, $15, $16, $17)
+                ON CONFLICT (position, measurement)
+                DO UPDATE SET
+                    sample_size = EXCLUDED.sample_size,
+                    min_value = EXCLUDED.min_value,
+                    p10 = EXCLUDED.p10,
+                    p20 = EXCLUDED.p20,
+                    p30 = EXCLUDED.p30,
+                    p40 = EXCLUDED.p40,
+                    p50 = EXCLUDED.p50,
+                    p60 = EXCLUDED.p60,
+                    p70 = EXCLUDED.p70,
+                    p80 = EXCLUDED.p80,
+                    p90 = EXCLUDED.p90,
+                    max_value = EXCLUDED.max_value,
+                    years_start = EXCLUDED.years_start,
+                    years_end = EXCLUDED.years_end,
+                    updated_at = NOW()
+                RETURNING id, position, measurement, sample_size, min_value,
+                          p10, p20, p30, p40, p50, p60, p70, p80, p90,
+                          max_value, years_start, years_end, created_at, updated_at
+                "#,
+                db.id,
+                db.position,
+                db.measurement,
+                db.sample_size,
+                db.min_value,
+                db.p10,
+                db.p20,
+                db.p30,
+                db.p40,
+                db.p50,
+                db.p60,
+                db.p70,
+                db.p80,
+                db.p90,
+                db.max_value,
+                db.years_start,
+                db.years_end,
+            )
+            .fetch_one(&self.pool)
+            .await
+            .map_err(DbError::DatabaseError)?;
 
-        row.to_domain().map_err(Into::into)
+            row.to_domain().map_err(Into::into)
+        })
+        .await
     }
 
     async fn delete_all(&self) -> DomainResult<u64> {
-        let result = sqlx::query!("DELETE FROM combine_percentiles")
-            .execute(&self.pool)
-            .await
-            .map_err(DbError::DatabaseError)?;
-
-        Ok(result.rows_affected())
+        traced("combine_percentile.delete_all", async move {
+            let result = sqlx::query!("DELETE FROM combine_percentiles")
+                .execute(&self.pool)
+                .await
+                .map_err(DbError::DatabaseError)?;
+
+            Ok(result.rows_affected())
+        })
+        .await
     }
 
     async fn delete(&self, id: Uuid) -> DomainResult<()> {
-        let result = sqlx::query!("DELETE FROM combine_percentiles WHERE id = $1", id)
-            .execute(&self.pool)
-            .await
-            .map_err(DbError::DatabaseError)?;
-
-        if result.rows_affected() == 0 {
-            return Err(
-                DbError::NotFound(format!("Combine percentile with id {} not found", id)).into(),
-            );
-        }
-
-        Ok(())
+        traced("combine_percentile.delete", async move {
+            let result = sqlx::query!("DELETE FROM combine_percentiles WHERE id = $1", id)
+                .execute(&self.pool)
+                .await
+                .map_err(DbError::DatabaseError)?;
+
+            if result.rows_affected() == 0 {
+                return Err(DbError::NotFound(format!(
+                    "Combine percentile with id {} not found",
+                    id
+                ))
+                .into());
+            }
+
+            Ok(())
+        })
+        .await
     }
 }
 
@@ -7,124 +7,176 @@ use domain::models::CombineResults;
 use domain::repositories::CombineResultsRepository;
 
 use crate::errors::DbError;
+use crate::instrument::traced;
 use crate::models::CombineResultsDb;
 
 /// SQLx implementation of CombineResultsRepository
 pub struct SqlxCombineResultsRepository {
     pool: PgPool,
+    /// Serves collection reads; the primary unless a replica is set
+    read_pool: PgPool,
 }
 
 impl SqlxCombineResultsRepository {
     pub fn new(pool: PgPool) -> Self {
-        Self { pool }
+        Self {
+            read_pool: pool.clone(),
+            pool,
+        }
+    }
+
+    /// Send collection reads to a read replica
+    pub fn with_read_pool(mut self, read_pool: PgPool) -> Self {
+        self.read_pool = read_pool;
+        self
     }
 }
 
 #[async_trait]
 impl CombineResultsRepository for SqlxCombineResultsRepository {
     async fn create(&self, results: &CombineResults) -> DomainResult<CombineResults> {
-        let results_db = CombineResultsDb::from_domain(results);
-
-        let result = sqlx::query_as!(
-            CombineResultsDb,
-            r#"
-            INSERT INTO combine_results
-            (id, player_id, year, source, forty_yard_dash, bench_press, vertical_jump,
-             broad_jump, three_cone_drill, twenty_yard_shuttle,
-             arm_length, hand_size, wingspan, ten_yard_split, twenty_yard_split,
-             created_at, updated_at)
-            VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12, $13, $14, $15, $16, $17)
-            RETURNING id, player_id, year, source, forty_yard_dash, bench_press, vertical_jump,
-                      broad_jump, three_cone_drill, twenty_yard_shuttle,
-                      arm_length, hand_size, wingspan, ten_yard_split, twenty_yard_split,
-                      created_at, updated_at
-            "#,
-            results_db.id,
-            results_db.player_id,
-            results_db.year,
-            results_db.source,
-            results_db.forty_yard_dash,
-            results_db.bench_press,
-            results_db.vertical_jump,
-            results_db.broad_jump,
-            results_db.three_cone_drill,
-            results_db.twenty_yard_shuttle,
-            results_db.arm_length,
-            results_db.hand_size,
-            results_db.wingspan,
-            results_db.ten_yard_split,
-            results_db.twenty_yard_split,
-            results_db.created_at,
-            results_db.updated_at
-        )
-        .fetch_one(&self.pool)
-        .await
-        .map_err(|e| {
-            if let sqlx::Error::Database(db_err) = &e {
-                if db_err.is_unique_violation() {
-                    return DbError::DuplicateEntry(format!(
-                        "Combine results for player {} in year {} with source '{}' already exist",
-                        results.player_id, results.year, results.source
-                    ));
-                }
-                if db_err.is_foreign_key_violation() {
-                    return DbError::NotFound(format!(
-                        "Player with id {} not found",
-                        results.player_id
-                    ));
+        traced("combine_results.create", async move {
+            let results_db = CombineResultsDb::from_domain(results);
+
+            let result = sqlx::query_as!(
+                CombineResultsDb,
+                r#"
+                INSERT INTO combine_results
+                (id, player_id, year, source, event_date, forty_yard_dash, bench_press, vertical_jump,
+                 broad_jump, three_cone_drill, twenty_yard_shuttle,
+                 arm_length, hand_size, wingspan, ten_yard_split, twenty_yard_split,
+                 created_at, updated_at)
+                VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12, $13, $14, $15, $16, $17, $18)
+                RETURNING id, player_id, year, source, event_date, forty_yard_dash, bench_press, vertical_jump,
+                          broad_jump, three_cone_drill, twenty_yard_shuttle,
+                          arm_length, hand_size, wingspan, ten_yard_split, twenty_yard_split,
+                          created_at, updated_at
+                "#,
+                results_db.id,
+                results_db.player_id,
+                results_db.year,
+                results_db.source,
+                results_db.event_date,
+                results_db.forty_yard_dash,
+                results_db.bench_press,
+                results_db.vertical_jump,
+                results_db.broad_jump,
+                results_db.three_cone_drill,
+                results_db.twenty_yard_shuttle,
+                results_db.arm_length,
+                results_db.hand_size,
+                results_db.wingspan,
+                results_db.ten_yard_split,
+                results_db.twenty_yard_split,
+                results_db.created_at,
+                results_db.updated_at
+            )
+            .fetch_one(&self.pool)
+            .await
+            .map_err(|e| {
+                if let sqlx::Error::Database(db_err) = &e {
+                    if db_err.is_unique_violation() {
+                        return DbError::DuplicateEntry(format!(
+                            "Combine results for player {} in year {} with source '{}' on that date already exist",
+                            results.player_id, results.year, results.source
+                        ));
+                    }
+                    if db_err.is_foreign_key_violation() {
+                        return DbError::NotFound(format!(
+                            "Player with id {} not found",
+                            results.player_id
+                        ));
+                    }
                 }
-            }
-            DbError::DatabaseError(e)
-        })?;
+                DbError::DatabaseError(e)
+            })?;
 
-        result.to_domain().map_err(Into::into)
+            result.to_domain().map_err(Into::into)
+        })
+        .await
     }
 
     async fn find_by_id(&self, id: Uuid) -> DomainResult<Option<CombineResults>> {
-        let result = sqlx::query_as!(
-            CombineResultsDb,
-            r#"
-            SELECT id, player_id, year, source, forty_yard_dash, bench_press, vertical_jump,
-                   broad_jump, three_cone_drill, twenty_yard_shuttle,
-                   arm_length, hand_size, wingspan, ten_yard_split, twenty_yard_split,
-                   created_at, updated_at
-            FROM combine_results
-            WHERE id = $1
-            "#,
-            id
-        )
-        .fetch_optional(&self.pool)
-        .await
-        .map_err(DbError::DatabaseError)?;
+        traced("combine_results.find_by_id", async move {
+            let result = sqlx::query_as!(
+                CombineResultsDb,
+                r#"
+                SELECT id, player_id, year, source, event_date, forty_yard_dash, bench_press, vertical_jump,
+                       broad_jump, three_cone_drill, twenty_yard_shuttle,
+                       arm_length, hand_size, wingspan, ten_yard_split, twenty_yard_split,
+                       created_at, updated_at
+                FROM combine_results
+                WHERE id = $1
+                "#,
+                id
+            )
+            .fetch_optional(&self.pool)
+            .await
+            .map_err(DbError::DatabaseError)?;
 
-        match result {
-            Some(results_db) => Ok(Some(results_db.to_domain()?)),
-            None => Ok(None),
-        }
+            match result {
+                Some(results_db) => Ok(Some(results_db.to_domain()?)),
+                None => Ok(None),
+            }
+        })
+        .await
     }
 
     async fn find_by_player_id(&self, player_id: Uuid) -> DomainResult<Vec<CombineResults>> {
-        let results = sqlx::query_as!(
-            CombineResultsDb,
-            r#"
-            SELECT id, player_id, year, source, forty_yard_dash, bench_press, vertical_jump,
-                   broad_jump, three_cone_drill, twenty_yard_shuttle,
-                   arm_length, hand_size, wingspan, ten_yard_split, twenty_yard_split,
-                   created_at, updated_at
-            FROM combine_results
-            WHERE player_id = $1
-            ORDER BY year DESC, source ASC
-            "#,
-            player_id
-        )
-        .fetch_all(&self.pool)
+        traced("combine_results.find_by_player_id", async move {
+            let results = sqlx::query_as!(
+                CombineResultsDb,
+                r#"
+                SELECT id, player_id, year, source, event_date, forty_yard_dash, bench_press, vertical_jump,
+                       broad_jump, three_cone_drill, twenty_yard_shuttle,
+                       arm_length, hand_size, wingspan, ten_yard_split, twenty_yard_split,
+                       created_at, updated_at
+                FROM combine_results
+                WHERE player_id = $1
+                ORDER BY year DESC, source ASC, event_date DESC NULLS LAST
+                "#,
+                player_id
+            )
+            .fetch_all(&self.read_pool)
+            .await
+            .map_err(DbError::DatabaseError)?;
+
+            results
+                .into_iter()
+                .map(|r| r.to_domain().map_err(Into::into))
+                .collect()
+        })
         .await
-        .map_err(DbError::DatabaseError)?;
+    }
+
+    async fn find_combine_results_for_players(
+        &self,
+        player_ids: &[Uuid],
+    ) -> DomainResult<Vec<CombineResults>> {
+        traced("combine_results.find_combine_results_for_players", async move {
+            let results = sqlx::query_as!(
+                CombineResultsDb,
+                r#"
+                SELECT id, player_id, year, source, event_date, forty_yard_dash, bench_press, vertical_jump,
+                       broad_jump, three_cone_drill, twenty_yard_shuttle,
+                       arm_length, hand_size, wingspan, ten_yard_split, twenty_yard_split,
+                       created_at, updated_at
+                FROM combine_results
+                WHERE player_id = ANY($1)
+                ORDER BY player_id, year DESC, source ASC, event_date DESC NULLS LAST
+                "#,
+                player_ids
+            )
+            .fetch_all(&self.read_pool)
+            .await
+            .map_err(DbError::DatabaseError)?;
 
-        results
-            .into_iter()
-            .map(|r| r.to_domain().map_err(Into::into))
-            .collect()
+            results
+                .into_iter()
+                .map(|r| r.to_domain().map_err(Into::into))
+                .collect()
+        })
+        .await
     }
 
     async fn find_by_player_and_year(
@@ -132,29 +184,32 @@ impl CombineResultsRepository for SqlxCombineResultsRepository {
         player_id: Uuid,
         year: i32,
     ) -> DomainResult<Option<CombineResults>> {
-        let result = sqlx::query_as!(
-            CombineResultsDb,
-            r#"
-            SELECT id, player_id, year, source, forty_yard_dash, bench_press, vertical_jump,
-                   broad_jump, three_cone_drill, twenty_yard_shuttle,
-                   arm_length, hand_size, wingspan, ten_yard_split, twenty_yard_split,
-                   created_at, updated_at
-            FROM combine_results
-            WHERE player_id = $1 AND year = $2
-            ORDER BY source ASC
-            LIMIT 1
-            "#,
-            player_id,
-            year
-        )
-        .fetch_optional(&self.pool)
-        .await
-        .map_err(DbError::DatabaseError)?;
+        traced("combine_results.find_by_player_and_year", async move {
+            let result = sqlx::query_as!(
+                CombineResultsDb,
+                r#"
+                SELECT id, player_id, year, source, event_date, forty_yard_dash, bench_press, vertical_jump,
+                       broad_jump, three_cone_drill, twenty_yard_shuttle,
+                       arm_length, hand_size, wingspan, ten_yard_split, twenty_yard_split,
+                       created_at, updated_at
+                FROM combine_results
+                WHERE player_id = $1 AND year = $2
+                ORDER BY source ASC, event_date DESC NULLS LAST
+                LIMIT 1
+                "#,
+                player_id,
+                year
+            )
+            .fetch_optional(&self.pool)
+            .await
+            .map_err(DbError::DatabaseError)?;
 
-        match result {
-            Some(results_db) => Ok(Some(results_db.to_domain()?)),
-            None => Ok(None),
-        }
+            match result {
+                Some(results_db) => Ok(Some(results_db.to_domain()?)),
+                None => Ok(None),
+            }
+        })
+        .await
     }
 
     async fn find_by_player_year_source(
@@ -163,126 +218,155 @@ impl CombineResultsRepository for SqlxCombineResultsRepository {
         year: i32,
         source: &str,
     ) -> DomainResult<Option<CombineResults>> {
-        let result = sqlx::query_as!(
-            CombineResultsDb,
-            r#"
-            SELECT id, player_id, year, source, forty_yard_dash, bench_press, vertical_jump,
-                   broad_jump, three_cone_drill, twenty_yard_shuttle,
-                   arm_length, hand_size, wingspan, ten_yard_split, twenty_yard_split,
-                   created_at, updated_at
-            FROM combine_results
-            WHERE player_id = $1 AND year = $2 AND source = $3
-            "#,
-            player_id,
-            year,
-            source
-        )
-        .fetch_optional(&self.pool)
-        .await
-        .map_err(DbError::DatabaseError)?;
+        traced("combine_results.find_by_player_year_source", async move {
+            let result = sqlx::query_as!(
+                CombineResultsDb,
+                r#"
+                SELECT id, player_id, year, source, event_date, forty_yard_dash, bench_press, vertical_jump,
+                       broad_jump, three_cone_drill, twenty_yard_shuttle,
+                       arm_length, hand_size, wingspan, ten_yard_split, twenty_yard_split,
+                       created_at, updated_at
+                FROM combine_results
+                WHERE player_id = $1 AND year = $2 AND source = $3
+                ORDER BY event_date DESC NULLS LAST
+                LIMIT 1
+                "#,
+                player_id,
+                year,
+                source
+            )
+            .fetch_optional(&self.pool)
+            .await
+            .map_err(DbError::DatabaseError)?;
 
-        match result {
-            Some(results_db) => Ok(Some(results_db.to_domain()?)),
-            None => Ok(None),
-        }
+            match result {
+                Some(results_db) => Ok(Some(results_db.to_domain()?)),
+                None => Ok(None),
+            }
+        })
+        .await
     }
 
     async fn update(&self, results: &CombineResults) -> DomainResult<CombineResults> {
-        let results_db = CombineResultsDb::from_domain(results);
-
-        let result = sqlx::query_as!(
-            CombineResultsDb,
-            r#"
-            UPDATE combine_results
-            SET forty_yard_dash = $2,
-                bench_press = $3,
-                vertical_jump = $4,
-                broad_jump = $5,
-                three_cone_drill = $6,
-                twenty_yard_shuttle = $7,
-                arm_length = $8,
-                hand_size = $9,
-                wingspan = $10,
-                ten_yard_split = $11,
-                twenty_yard_split = $12,
-                updated_at = $13
-            WHERE id = $1
-            RETURNING id, player_id, year, source, forty_yard_dash, bench_press, vertical_jump,
-                   broad_jump, three_cone_drill, twenty_yard_shuttle,
-                   arm_length, hand_size, wingspan, ten_yard_split, twenty_yard_split,
-                   created_at, updated_at
-            "#,
-            results_db.id,
-            results_db.forty_yard_dash,
-            results_db.bench_press,
-            results_db.vertical_jump,
-            results_db.broad_jump,
-            results_db.three_cone_drill,
-            results_db.twenty_yard_shuttle,
-            results_db.arm_length,
-            results_db.hand_size,
-            results_db.wingspan,
-            results_db.ten_yard_split,
-            results_db.twenty_yard_split,
-            results_db.updated_at
-        )
-        .fetch_one(&self.pool)
-        .await
-        .map_err(DbError::DatabaseError)?;
+        traced("combine_results.update", async move {
+            let results_db = CombineResultsDb::from_domain(results);
+
+            let result = sqlx::query_as!(
+                CombineResultsDb,
+                r#"
+                UPDATE combine_results
+                SET event_date = $2,
+                    forty_yard_dash = $3,
+                    bench_press = $4,
+                    vertical_jump = $5,
+                    broad_jump = $6,
+                    three_cone_drill = $7,
+                    twenty_yard_shuttle = $8,
+                    arm_length = $9,
+                    hand_size = $10,
+                    wingspan = $11,
+                    ten_yard_split = $12,
+                    twenty_yard_split = $13,
+                    updated_at = $14
+                WHERE id = $1
+                RETURNING id, player_id, year, source, event_date, forty_yard_dash, bench_press, vertical_jump,
+                       broad_jump, three_cone_drill, twenty_yard_shuttle,
+                       arm_length, hand_size, wingspan, ten_yard_split, twenty_yard_split,
+                       created_at, updated_at
+                "#,
+                results_db.id,
+                results_db.event_date,
+                results_db.forty_yard_dash,
+                results_db.bench_press,
+                results_db.vertical_jump,
+                results_db.broad_jump,
+                results_db.three_cone_drill,
+                results_db.twenty_yard_shuttle,
+                results_db.arm_length,
+                results_db.hand_size,
+                results_db.wingspan,
+                results_db.ten_yard_split,
+                results_db.twenty_yard_split,
+                results_db.updated_at
+            )
+            .fetch_one(&self.pool)
+            .await
+            .map_err(|e| {
+                if let sqlx::Error::Database(db_err) = &e {
+                    if db_err.is_unique_violation() {
+                        return DbError::DuplicateEntry(format!(
+                            "Combine results for player {} in year {} with source '{}' on that date already exist",
+                            results.player_id, results.year, results.source
+                        ));
+                    }
+                }
+                DbError::DatabaseError(e)
+            })?;
 
-        result.to_domain().map_err(Into::into)
+            result.to_domain().map_err(Into::into)
+        })
+        .await
     }
 
     async fn delete(&self, id: Uuid) -> DomainResult<()> {
-        sqlx::query!(
-            r#"
-            DELETE FROM combine_results WHERE id = $1
-            "#,
-            id
-        )
-        .execute(&self.pool)
-        .await
-        .map_err(DbError::DatabaseError)?;
+        traced("combine_results.delete", async move {
+            sqlx::query!(
+                r#"
+                DELETE FROM combine_results WHERE id = $1
+                "#,
+                id
+            )
+            .execute(&self.pool)
+            .await
+            .map_err(DbError::DatabaseError)?;
 
-        Ok(())
+            Ok(())
+        })
+        .await
     }
 
     async fn count_by_year(&self, year: i32) -> DomainResult<i64> {
-        let row = sqlx::query_scalar!(
-            r#"
-            SELECT COUNT(*) as "count!"
-            FROM combine_results
-            WHERE year = $1
-            "#,
-            year
-        )
-        .fetch_one(&self.pool)
-        .await
-        .map_err(DbError::DatabaseError)?;
+        traced("combine_results.count_by_year", async move {
+            let row = sqlx::query_scalar!(
+                r#"
+                SELECT COUNT(*) as "count!"
+                FROM combine_results
+                WHERE year = $1
+                "#,
+                year
+            )
+            .fetch_one(&self.read_pool)
+            .await
+            .map_err(DbError::DatabaseError)?;
 
-        Ok(row)
+            Ok(row)
+        })
+        .await
     }
 
     async fn find_all(&self) -> DomainResult<Vec<CombineResults>> {
-        let results = sqlx::query_as!(
-            CombineResultsDb,
-            r#"
-            SELECT id, player_id, year, source, forty_yard_dash, bench_press, vertical_jump,
-                   broad_jump, three_cone_drill, twenty_yard_shuttle,
-                   arm_length, hand_size, wingspan, ten_yard_split, twenty_yard_split,
-                   created_at, updated_at
-            FROM combine_results
-            ORDER BY year DESC, source ASC
-            "#
-        )
-        .fetch_all(&self.pool)
-        .await
-        .map_err(DbError::DatabaseError)?;
+        traced("combine_results.find_all", async move {
+            let results = sqlx::query_as!(
+                CombineResultsDb,
+                r#"
+                SELECT id, player_id, year, source, event_date, forty_yard_dash, bench_press, vertical_jump,
+                       broad_jump, three_cone_drill, twenty_yard_shuttle,
+                       arm_length, hand_size, wingspan, ten_yard_split, twenty_yard_split,
+                       created_at, updated_at
+                FROM combine_results
+                ORDER BY year DESC, source ASC, event_date DESC NULLS LAST
+                "#
+            )
+            .fetch_all(&self.read_pool)
+            .await
+            .map_err(DbError::DatabaseError)?;
 
-        results
-            .into_iter()
-            .map(|r: CombineResultsDb| r.to_domain().map_err(Into::into))
-            .collect()
+            results
+                .into_iter()
+                .map(|r: CombineResultsDb| r.to_domain().map_err(Into::into))
+                .collect()
+        })
+        .await
     }
 }
 
@@ -409,6 +493,53 @@ mod tests {
         cleanup_players(&pool).await;
     }
 
+    #[tokio::test]
+    async fn test_multiple_pro_days_same_year() {
+        let pool = setup_test_pool().await;
+        cleanup_combine_results(&pool).await;
+        cleanup_players(&pool).await;
+
+        let player = create_test_player(&pool).await;
+        let repo = SqlxCombineResultsRepository::new(pool.clone());
+
+        let first = CombineResults::new(player.id, 2026)
+            .unwrap()
+            .with_source(CombineSource::ProDay)
+            .with_event_date(chrono::NaiveDate::from_ymd_opt(2026, 3, 10).unwrap())
+            .with_forty_yard_dash(4.55)
+            .unwrap();
+        let second = CombineResults::new(player.id, 2026)
+            .unwrap()
+            .with_source(CombineSource::ProDay)
+            .with_event_date(chrono::NaiveDate::from_ymd_opt(2026, 4, 2).unwrap())
+            .with_forty_yard_dash(4.49)
+            .unwrap();
+        repo.create(&first).await.unwrap();
+        repo.create(&second).await.unwrap();
+
+        // Same source and date is still a duplicate
+        let same_day = CombineResults::new(player.id, 2026)
+            .unwrap()
+            .with_source(CombineSource::ProDay)
+            .with_event_date(first.event_date.unwrap());
+        assert!(repo.create(&same_day).await.is_err());
+
+        let found = repo.find_by_player_id(player.id).await.unwrap();
+        assert_eq!(found.len(), 2);
+        assert_eq!(found[0].id, second.id);
+        assert_eq!(found[0].event_date, second.event_date);
+
+        let latest = repo
+            .find_by_player_year_source(player.id, 2026, "pro_day")
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(latest.id, second.id);
+
+        cleanup_combine_results(&pool).await;
+        cleanup_players(&pool).await;
+    }
+
     #[tokio::test]
     async fn test_create_with_new_measurables() {
         let pool = setup_test_pool().await;
@@ -494,6 +625,52 @@ mod tests {
         cleanup_players(&pool).await;
     }
 
+    #[tokio::test]
+    async fn test_find_combine_results_for_players() {
+        let pool = setup_test_pool().await;
+        cleanup_combine_results(&pool).await;
+        cleanup_players(&pool).await;
+
+        let player_repo = SqlxPlayerRepository::new(pool.clone());
+        let mut players = Vec::new();
+        for last_name in ["One", "Two", "Three"] {
+            let player = Player::new(
+                "Test".to_string(),
+                last_name.to_string(),
+                domain::models::Position::QB,
+                2026,
+            )
+            .unwrap();
+            players.push(player_repo.create(&player).await.unwrap());
+        }
+        let [first, second, untested] = players.try_into().unwrap();
+        let repo = SqlxCombineResultsRepository::new(pool.clone());
+
+        repo.create(&CombineResults::new(first.id, 2025).unwrap())
+            .await
+            .unwrap();
+        repo.create(&CombineResults::new(first.id, 2026).unwrap())
+            .await
+            .unwrap();
+        repo.create(&CombineResults::new(second.id, 2026).unwrap())
+            .await
+            .unwrap();
+
+        let found = repo
+            .find_combine_results_for_players(&[first.id, untested.id])
+            .await
+            .unwrap();
+        assert_eq!(found.len(), 2);
+        assert!(found.iter().all(|r| r.player_id == first.id));
+        assert_eq!(found[0].year, 2026);
+
+        let found = repo.find_combine_results_for_players(&[]).await.unwrap();
+        assert!(found.is_empty());
+
+        cleanup_combine_results(&pool).await;
+        cleanup_players(&pool).await;
+    }
+
     #[tokio::test]
     async fn test_find_by_player_and_year() {
         let pool = setup_test_pool().await;
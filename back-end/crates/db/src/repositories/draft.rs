@@ -1,13 +1,26 @@
 use async_trait::async_trait;
-use sqlx::PgPool;
+use sqlx::{PgPool, QueryBuilder};
 use uuid::Uuid;
 
 use domain::errors::DomainResult;
-use domain::models::{Draft, DraftPick, DraftStatus};
+use domain::models::{Draft, DraftPick, DraftSortField, DraftStatus, Page, PageRequest, Sort};
 use domain::repositories::{DraftPickRepository, DraftRepository};
 
 use crate::errors::DbError;
+use crate::instrument::traced;
 use crate::models::{DraftDb, DraftPickDb};
+use crate::query::{push_order_by, push_page, rows_per_insert, DEFAULT_BATCH_SIZE};
+
+const DRAFT_PICK_COLUMNS: &str = "id, draft_id, round, pick_number, overall_pick, team_id, player_id, picked_at, original_team_id, is_compensatory, notes, created_at, updated_at";
+
+fn draft_sort_column(field: DraftSortField) -> &'static str {
+    match field {
+        DraftSortField::Name => "name",
+        DraftSortField::Year => "year",
+        DraftSortField::Status => "status",
+        DraftSortField::CreatedAt => "created_at",
+    }
+}
 
 /// SQLx implementation of DraftRepository
 pub struct SqlxDraftRepository {
@@ -23,218 +36,246 @@ impl SqlxDraftRepository {
 #[async_trait]
 impl DraftRepository for SqlxDraftRepository {
     async fn create(&self, draft: &Draft) -> DomainResult<Draft> {
-        let draft_db = DraftDb::from_domain(draft);
-
-        let result = sqlx::query_as!(
-            DraftDb,
-            r#"
-            INSERT INTO drafts (id, name, year, status, rounds, picks_per_round, created_at, updated_at)
-            VALUES ($1, $2, $3, $4, $5, $6, $7, $8)
-            RETURNING id, name, year, status, rounds, picks_per_round, created_at, updated_at
-            "#,
-            draft_db.id,
-            draft_db.name,
-            draft_db.year,
-            draft_db.status,
-            draft_db.rounds,
-            draft_db.picks_per_round,
-            draft_db.created_at,
-            draft_db.updated_at
-        )
-        .fetch_one(&self.pool)
-        .await
-        .map_err(DbError::DatabaseError)?;
+        traced("draft.create", async move {
+            let draft_db = DraftDb::from_domain(draft);
+
+            let result = sqlx::query_as!(
+                DraftDb,
+                r#"
+                INSERT INTO drafts (id, name, year, status, rounds, picks_per_round, created_at, updated_at)
+                VALUES ($1, $2, $3, $4, $5, $6, $7, $8)
+                RETURNING id, name, year, status, rounds, picks_per_round, created_at, updated_at
+                "#,
+                draft_db.id,
+                draft_db.name,
+                draft_db.year,
+                draft_db.status,
+                draft_db.rounds,
+                draft_db.picks_per_round,
+                draft_db.created_at,
+                draft_db.updated_at
+            )
+            .fetch_one(&self.pool)
+            .await
+            .map_err(DbError::DatabaseError)?;
 
-        result.to_domain().map_err(Into::into)
+            result.to_domain().map_err(Into::into)
+        })
+        .await
     }
 
     async fn find_by_id(&self, id: Uuid) -> DomainResult<Option<Draft>> {
-        let result = sqlx::query_as!(
-            DraftDb,
-            r#"
-            SELECT id, name, year, status, rounds, picks_per_round, created_at, updated_at
-            FROM drafts
-            WHERE id = $1
-            "#,
-            id
-        )
-        .fetch_optional(&self.pool)
-        .await
-        .map_err(DbError::DatabaseError)?;
+        traced("draft.find_by_id", async move {
+            let result = sqlx::query_as!(
+                DraftDb,
+                r#"
+                SELECT id, name, year, status, rounds, picks_per_round, created_at, updated_at
+                FROM drafts
+                WHERE id = $1
+                "#,
+                id
+            )
+            .fetch_optional(&self.pool)
+            .await
+            .map_err(DbError::DatabaseError)?;
 
-        match result {
-            Some(draft_db) => Ok(Some(draft_db.to_domain()?)),
-            None => Ok(None),
-        }
+            match result {
+                Some(draft_db) => Ok(Some(draft_db.to_domain()?)),
+                None => Ok(None),
+            }
+        })
+        .await
     }
 
     async fn find_by_year(&self, year: i32) -> DomainResult<Vec<Draft>> {
-        let results = sqlx::query_as!(
-            DraftDb,
-            r#"
-            SELECT id, name, year, status, rounds, picks_per_round, created_at, updated_at
-            FROM drafts
-            WHERE year = $1
-            ORDER BY created_at DESC
-            "#,
-            year
-        )
-        .fetch_all(&self.pool)
-        .await
-        .map_err(DbError::DatabaseError)?;
+        traced("draft.find_by_year", async move {
+            let results = sqlx::query_as!(
+                DraftDb,
+                r#"
+                SELECT id, name, year, status, rounds, picks_per_round, created_at, updated_at
+                FROM drafts
+                WHERE year = $1
+                ORDER BY created_at DESC
+                "#,
+                year
+            )
+            .fetch_all(&self.pool)
+            .await
+            .map_err(DbError::DatabaseError)?;
 
-        results
-            .into_iter()
-            .map(|db| db.to_domain())
-            .collect::<Result<Vec<_>, _>>()
-            .map_err(Into::into)
+            results
+                .into_iter()
+                .map(|db| db.to_domain())
+                .collect::<Result<Vec<_>, _>>()
+                .map_err(Into::into)
+        })
+        .await
     }
 
     async fn find_all(&self) -> DomainResult<Vec<Draft>> {
-        let results = sqlx::query_as!(
-            DraftDb,
-            r#"
-            SELECT id, name, year, status, rounds, picks_per_round, created_at, updated_at
-            FROM drafts
-            ORDER BY year DESC
-            "#
-        )
-        .fetch_all(&self.pool)
+        traced("draft.find_all", async move {
+            let results = sqlx::query_as!(
+                DraftDb,
+                r#"
+                SELECT id, name, year, status, rounds, picks_per_round, created_at, updated_at
+                FROM drafts
+                ORDER BY year DESC
+                "#
+            )
+            .fetch_all(&self.pool)
+            .await
+            .map_err(DbError::DatabaseError)?;
+
+            results
+                .into_iter()
+                .map(|db| db.to_domain())
+                .collect::<Result<Vec<_>, _>>()
+                .map_err(Into::into)
+        })
         .await
-        .map_err(DbError::DatabaseError)?;
+    }
 
-        results
-            .into_iter()
-            .map(|db| db.to_domain())
-            .collect::<Result<Vec<_>, _>>()
-            .map_err(Into::into)
+    async fn find_sorted(
+        &self,
+        sort: &Sort<DraftSortField>,
+        page: Option<PageRequest>,
+    ) -> DomainResult<Page<Draft>> {
+        traced("draft.find_sorted", async move {
+            let mut query = QueryBuilder::new(
+                "SELECT id, name, year, status, rounds, picks_per_round, created_at, updated_at FROM drafts",
+            );
+            push_order_by(
+                &mut query,
+                sort,
+                draft_sort_column,
+                "year DESC, created_at DESC, id",
+            );
+            push_page(&mut query, page);
+
+            let results = query
+                .build_query_as::<DraftDb>()
+                .fetch_all(&self.pool)
+                .await
+                .map_err(DbError::DatabaseError)?;
+
+            let items = results
+                .into_iter()
+                .map(|db| db.to_domain())
+                .collect::<Result<Vec<_>, _>>()?;
+
+            let total = match page {
+                Some(_) => sqlx::query_scalar!(r#"SELECT COUNT(*) as "count!" FROM drafts"#)
+                    .fetch_one(&self.pool)
+                    .await
+                    .map_err(DbError::DatabaseError)?,
+                None => items.len() as i64,
+            };
+
+            Ok(Page { items, total })
+        })
+        .await
     }
 
     async fn find_by_status(&self, status: DraftStatus) -> DomainResult<Vec<Draft>> {
-        let status_str = status.to_string();
-        let results = sqlx::query_as!(
-            DraftDb,
-            r#"
-            SELECT id, name, year, status, rounds, picks_per_round, created_at, updated_at
-            FROM drafts
-            WHERE status = $1
-            ORDER BY year DESC
-            "#,
-            status_str
-        )
-        .fetch_all(&self.pool)
-        .await
-        .map_err(DbError::DatabaseError)?;
+        traced("draft.find_by_status", async move {
+            let status_str = status.to_string();
+            let results = sqlx::query_as!(
+                DraftDb,
+                r#"
+                SELECT id, name, year, status, rounds, picks_per_round, created_at, updated_at
+                FROM drafts
+                WHERE status = $1
+                ORDER BY year DESC
+                "#,
+                status_str
+            )
+            .fetch_all(&self.pool)
+            .await
+            .map_err(DbError::DatabaseError)?;
 
-        results
-            .into_iter()
-            .map(|db| db.to_domain())
-            .collect::<Result<Vec<_>, _>>()
-            .map_err(Into::into)
+            results
+                .into_iter()
+                .map(|db| db.to_domain())
+                .collect::<Result<Vec<_>, _>>()
+                .map_err(Into::into)
+        })
+        .await
     }
 
     async fn update(&self, draft: &Draft) -> DomainResult<Draft> {
-        let draft_db = DraftDb::from_domain(draft);
-
-        let result = sqlx::query_as!(
-            DraftDb,
-            r#"
-            UPDATE drafts
-            SET name = $2, status = $3, updated_at = $4
-            WHERE id = $1
-            RETURNING id, name, year, status, rounds, picks_per_round, created_at, updated_at
-            "#,
-            draft_db.id,
-            draft_db.name,
-            draft_db.status,
-            draft_db.updated_at
-        )
-        .fetch_optional(&self.pool)
-        .await
-        .map_err(DbError::DatabaseError)?
-        .ok_or_else(|| DbError::NotFound(format!("Draft with id {} not found", draft_db.id)))?;
+        traced("draft.update", async move {
+            let draft_db = DraftDb::from_domain(draft);
 
-        result.to_domain().map_err(Into::into)
+            let result = sqlx::query_as!(
+                DraftDb,
+                r#"
+                UPDATE drafts
+                SET name = $2, status = $3, updated_at = $4
+                WHERE id = $1
+                RETURNING id, name, year, status, rounds, picks_per_round, created_at, updated_at
+                "#,
+                draft_db.id,
+                draft_db.name,
+                draft_db.status,
+                draft_db.updated_at
+            )
+            .fetch_optional(&self.pool)
+            .await
+            .map_err(DbError::DatabaseError)?
+            .ok_or_else(|| DbError::NotFound(format!("Draft with id {} not found", draft_db.id)))?;
+
+            result.to_domain().map_err(Into::into)
+        })
+        .await
     }
 
     async fn delete(&self, id: Uuid) -> DomainResult<()> {
-        let result = sqlx::query!(
-            r#"
-            DELETE FROM drafts WHERE id = $1
-            "#,
-            id
-        )
-        .execute(&self.pool)
-        .await
-        .map_err(DbError::DatabaseError)?;
+        traced("draft.delete", async move {
+            let result = sqlx::query!(
+                r#"
+                DELETE FROM drafts WHERE id = $1
+                "#,
+                id
+            )
+            .execute(&self.pool)
+            .await
+            .map_err(DbError::DatabaseError)?;
 
-        if result.rows_affected() == 0 {
-            return Err(DbError::NotFound(format!("Draft with id {} not found", id)).into());
-        }
+            if result.rows_affected() == 0 {
+                return Err(DbError::NotFound(format!("Draft with id {} not found", id)).into());
+            }
 
-        Ok(())
+            Ok(())
+        })
+        .await
     }
 }
 
 /// SQLx implementation of DraftPickRepository
 pub struct SqlxDraftPickRepository {
     pool: PgPool,
+    batch_size: usize,
 }
 
 impl SqlxDraftPickRepository {
     pub fn new(pool: PgPool) -> Self {
-        Self { pool }
+        Self {
+            pool,
+            batch_size: DEFAULT_BATCH_SIZE,
+        }
+    }
+
+    /// Rows per INSERT statement in `create_many`
+    pub fn with_batch_size(mut self, batch_size: usize) -> Self {
+        self.batch_size = batch_size;
+        self
     }
 }
 
 #[async_trait]
 impl DraftPickRepository for SqlxDraftPickRepository {
     async fn create(&self, pick: &DraftPick) -> DomainResult<DraftPick> {
-        let pick_db = DraftPickDb::from_domain(pick);
-
-        let result = sqlx::query_as!(
-            DraftPickDb,
-            r#"
-            INSERT INTO draft_picks (id, draft_id, round, pick_number, overall_pick, team_id, player_id, picked_at, original_team_id, is_compensatory, notes, created_at, updated_at)
-            VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12, $13)
-            RETURNING id, draft_id, round, pick_number, overall_pick, team_id, player_id, picked_at, original_team_id, is_compensatory, notes, created_at, updated_at
-            "#,
-            pick_db.id,
-            pick_db.draft_id,
-            pick_db.round,
-            pick_db.pick_number,
-            pick_db.overall_pick,
-            pick_db.team_id,
-            pick_db.player_id,
-            pick_db.picked_at,
-            pick_db.original_team_id,
-            pick_db.is_compensatory,
-            pick_db.notes,
-            pick_db.created_at,
-            pick_db.updated_at
-        )
-        .fetch_one(&self.pool)
-        .await
-        .map_err(|e| {
-            if let sqlx::Error::Database(db_err) = &e {
-                if db_err.is_unique_violation() {
-                    return DbError::DuplicateEntry(
-                        "Draft pick with this round/pick number already exists".to_string()
-                    );
-                }
-            }
-            DbError::DatabaseError(e)
-        })?;
-
-        result.to_domain().map_err(Into::into)
-    }
-
-    async fn create_many(&self, picks: &[DraftPick]) -> DomainResult<Vec<DraftPick>> {
-        let mut tx = self.pool.begin().await.map_err(DbError::DatabaseError)?;
-
-        let mut created_picks = Vec::new();
-
-        for pick in picks {
+        traced("draft_pick.create", async move {
             let pick_db = DraftPickDb::from_domain(pick);
 
             let result = sqlx::query_as!(
@@ -258,58 +299,156 @@ impl DraftPickRepository for SqlxDraftPickRepository {
                 pick_db.created_at,
                 pick_db.updated_at
             )
-            .fetch_one(&mut *tx)
+            .fetch_one(&self.pool)
             .await
-            .map_err(DbError::DatabaseError)?;
+            .map_err(|e| {
+                if let sqlx::Error::Database(db_err) = &e {
+                    if db_err.is_unique_violation() {
+                        return DbError::DuplicateEntry(
+                            "Draft pick with this round/pick number already exists".to_string()
+                        );
+                    }
+                }
+                DbError::DatabaseError(e)
+            })?;
 
-            created_picks.push(result.to_domain()?);
-        }
+            result.to_domain().map_err(Into::into)
+        })
+        .await
+    }
 
-        tx.commit().await.map_err(DbError::DatabaseError)?;
+    async fn create_many(&self, picks: &[DraftPick]) -> DomainResult<Vec<DraftPick>> {
+        traced("draft_pick.create_many", async move {
+            let mut tx = self.pool.begin().await.map_err(DbError::DatabaseError)?;
+
+            let mut created_picks = Vec::with_capacity(picks.len());
+            for chunk in picks.chunks(rows_per_insert(self.batch_size, 13)) {
+                let mut builder = QueryBuilder::new("INSERT INTO draft_picks (");
+                builder.push(DRAFT_PICK_COLUMNS).push(") ");
+                builder.push_values(
+                    chunk.iter().map(DraftPickDb::from_domain),
+                    |mut row, pick| {
+                        row.push_bind(pick.id)
+                            .push_bind(pick.draft_id)
+                            .push_bind(pick.round)
+                            .push_bind(pick.pick_number)
+                            .push_bind(pick.overall_pick)
+                            .push_bind(pick.team_id)
+                            .push_bind(pick.player_id)
+                            .push_bind(pick.picked_at)
+                            .push_bind(pick.original_team_id)
+                            .push_bind(pick.is_compensatory)
+                            .push_bind(pick.notes)
+                            .push_bind(pick.created_at)
+                            .push_bind(pick.updated_at);
+                    },
+                );
+                builder.push(" RETURNING ").push(DRAFT_PICK_COLUMNS);
+
+                let rows = builder
+                    .build_query_as::<DraftPickDb>()
+                    .fetch_all(&mut *tx)
+                    .await
+                    .map_err(DbError::DatabaseError)?;
+                for row in rows {
+                    created_picks.push(row.to_domain()?);
+                }
+            }
+
+            tx.commit().await.map_err(DbError::DatabaseError)?;
 
-        Ok(created_picks)
+            Ok(created_picks)
+        })
+        .await
     }
 
     async fn find_by_id(&self, id: Uuid) -> DomainResult<Option<DraftPick>> {
-        let result = sqlx::query_as!(
-            DraftPickDb,
-            r#"
-            SELECT id, draft_id, round, pick_number, overall_pick, team_id, player_id, picked_at, original_team_id, is_compensatory, notes, created_at, updated_at
-            FROM draft_picks
-            WHERE id = $1
-            "#,
-            id
-        )
-        .fetch_optional(&self.pool)
-        .await
-        .map_err(DbError::DatabaseError)?;
+        traced("draft_pick.find_by_id", async move {
+            let result = sqlx::query_as!(
+                DraftPickDb,
+                r#"
+                SELECT id, draft_id, round, pick_number, overall_pick, team_id, player_id, picked_at, original_team_id, is_compensatory, notes, created_at, updated_at
+                FROM draft_picks
+                WHERE id = $1
+                "#,
+                id
+            )
+            .fetch_optional(&self.pool)
+            .await
+            .map_err(DbError::DatabaseError)?;
 
-        match result {
-            Some(pick_db) => Ok(Some(pick_db.to_domain()?)),
-            None => Ok(None),
-        }
+            match result {
+                Some(pick_db) => Ok(Some(pick_db.to_domain()?)),
+                None => Ok(None),
+            }
+        })
+        .await
     }
 
     async fn find_by_draft_id(&self, draft_id: Uuid) -> DomainResult<Vec<DraftPick>> {
-        let results = sqlx::query_as!(
-            DraftPickDb,
-            r#"
-            SELECT id, draft_id, round, pick_number, overall_pick, team_id, player_id, picked_at, original_team_id, is_compensatory, notes, created_at, updated_at
-            FROM draft_picks
-            WHERE draft_id = $1
-            ORDER BY overall_pick ASC
-            "#,
-            draft_id
-        )
-        .fetch_all(&self.pool)
+        traced("draft_pick.find_by_draft_id", async move {
+            let results = sqlx::query_as!(
+                DraftPickDb,
+                r#"
+                SELECT id, draft_id, round, pick_number, overall_pick, team_id, player_id, picked_at, original_team_id, is_compensatory, notes, created_at, updated_at
+                FROM draft_picks
+                WHERE draft_id = $1
+                ORDER BY overall_pick ASC
+                "#,
+                draft_id
+            )
+            .fetch_all(&self.pool)
+            .await
+            .map_err(DbError::DatabaseError)?;
+
+            results
+                .into_iter()
+                .map(|db| db.to_domain())
+                .collect::<Result<Vec<_>, _>>()
+                .map_err(Into::into)
+        })
         .await
-        .map_err(DbError::DatabaseError)?;
+    }
+
+    async fn find_page_by_draft_id(
+        &self,
+        draft_id: Uuid,
+        page: PageRequest,
+    ) -> DomainResult<Page<DraftPick>> {
+        traced("draft_pick.find_page_by_draft_id", async move {
+            let total = sqlx::query_scalar!(
+                r#"SELECT COUNT(*) as "count!" FROM draft_picks WHERE draft_id = $1"#,
+                draft_id
+            )
+            .fetch_one(&self.pool)
+            .await
+            .map_err(DbError::DatabaseError)?;
+
+            let results = sqlx::query_as!(
+                DraftPickDb,
+                r#"
+                SELECT id, draft_id, round, pick_number, overall_pick, team_id, player_id, picked_at, original_team_id, is_compensatory, notes, created_at, updated_at
+                FROM draft_picks
+                WHERE draft_id = $1
+                ORDER BY overall_pick ASC
+                LIMIT $2 OFFSET $3
+                "#,
+                draft_id,
+                page.limit(),
+                page.offset()
+            )
+            .fetch_all(&self.pool)
+            .await
+            .map_err(DbError::DatabaseError)?;
 
-        results
-            .into_iter()
-            .map(|db| db.to_domain())
-            .collect::<Result<Vec<_>, _>>()
-            .map_err(Into::into)
+            let items = results
+                .into_iter()
+                .map(|db| db.to_domain())
+                .collect::<Result<Vec<_>, _>>()?;
+
+            Ok(Page { items, total })
+        })
+        .await
     }
 
     async fn find_by_draft_and_round(
@@ -317,26 +456,29 @@ impl DraftPickRepository for SqlxDraftPickRepository {
         draft_id: Uuid,
         round: i32,
     ) -> DomainResult<Vec<DraftPick>> {
-        let results = sqlx::query_as!(
-            DraftPickDb,
-            r#"
-            SELECT id, draft_id, round, pick_number, overall_pick, team_id, player_id, picked_at, original_team_id, is_compensatory, notes, created_at, updated_at
-            FROM draft_picks
-            WHERE draft_id = $1 AND round = $2
-            ORDER BY pick_number ASC
-            "#,
-            draft_id,
-            round
-        )
-        .fetch_all(&self.pool)
-        .await
-        .map_err(DbError::DatabaseError)?;
+        traced("draft_pick.find_by_draft_and_round", async move {
+            let results = sqlx::query_as!(
+                DraftPickDb,
+                r#"
+                SELECT id, draft_id, round, pick_number, overall_pick, team_id, player_id, picked_at, original_team_id, is_compensatory, notes, created_at, updated_at
+                FROM draft_picks
+                WHERE draft_id = $1 AND round = $2
+                ORDER BY pick_number ASC
+                "#,
+                draft_id,
+                round
+            )
+            .fetch_all(&self.pool)
+            .await
+            .map_err(DbError::DatabaseError)?;
 
-        results
-            .into_iter()
-            .map(|db| db.to_domain())
-            .collect::<Result<Vec<_>, _>>()
-            .map_err(Into::into)
+            results
+                .into_iter()
+                .map(|db| db.to_domain())
+                .collect::<Result<Vec<_>, _>>()
+                .map_err(Into::into)
+        })
+        .await
     }
 
     async fn find_by_draft_and_team(
@@ -344,126 +486,146 @@ impl DraftPickRepository for SqlxDraftPickRepository {
         draft_id: Uuid,
         team_id: Uuid,
     ) -> DomainResult<Vec<DraftPick>> {
-        let results = sqlx::query_as!(
-            DraftPickDb,
-            r#"
-            SELECT id, draft_id, round, pick_number, overall_pick, team_id, player_id, picked_at, original_team_id, is_compensatory, notes, created_at, updated_at
-            FROM draft_picks
-            WHERE draft_id = $1 AND team_id = $2
-            ORDER BY overall_pick ASC
-            "#,
-            draft_id,
-            team_id
-        )
-        .fetch_all(&self.pool)
-        .await
-        .map_err(DbError::DatabaseError)?;
+        traced("draft_pick.find_by_draft_and_team", async move {
+            let results = sqlx::query_as!(
+                DraftPickDb,
+                r#"
+                SELECT id, draft_id, round, pick_number, overall_pick, team_id, player_id, picked_at, original_team_id, is_compensatory, notes, created_at, updated_at
+                FROM draft_picks
+                WHERE draft_id = $1 AND team_id = $2
+                ORDER BY overall_pick ASC
+                "#,
+                draft_id,
+                team_id
+            )
+            .fetch_all(&self.pool)
+            .await
+            .map_err(DbError::DatabaseError)?;
 
-        results
-            .into_iter()
-            .map(|db| db.to_domain())
-            .collect::<Result<Vec<_>, _>>()
-            .map_err(Into::into)
+            results
+                .into_iter()
+                .map(|db| db.to_domain())
+                .collect::<Result<Vec<_>, _>>()
+                .map_err(Into::into)
+        })
+        .await
     }
 
     async fn find_next_pick(&self, draft_id: Uuid) -> DomainResult<Option<DraftPick>> {
-        let result = sqlx::query_as!(
-            DraftPickDb,
-            r#"
-            SELECT id, draft_id, round, pick_number, overall_pick, team_id, player_id, picked_at, original_team_id, is_compensatory, notes, created_at, updated_at
-            FROM draft_picks
-            WHERE draft_id = $1 AND player_id IS NULL
-            ORDER BY overall_pick ASC
-            LIMIT 1
-            "#,
-            draft_id
-        )
-        .fetch_optional(&self.pool)
-        .await
-        .map_err(DbError::DatabaseError)?;
+        traced("draft_pick.find_next_pick", async move {
+            let result = sqlx::query_as!(
+                DraftPickDb,
+                r#"
+                SELECT id, draft_id, round, pick_number, overall_pick, team_id, player_id, picked_at, original_team_id, is_compensatory, notes, created_at, updated_at
+                FROM draft_picks
+                WHERE draft_id = $1 AND player_id IS NULL
+                ORDER BY overall_pick ASC
+                LIMIT 1
+                "#,
+                draft_id
+            )
+            .fetch_optional(&self.pool)
+            .await
+            .map_err(DbError::DatabaseError)?;
 
-        match result {
-            Some(pick_db) => Ok(Some(pick_db.to_domain()?)),
-            None => Ok(None),
-        }
+            match result {
+                Some(pick_db) => Ok(Some(pick_db.to_domain()?)),
+                None => Ok(None),
+            }
+        })
+        .await
     }
 
     async fn find_available_picks(&self, draft_id: Uuid) -> DomainResult<Vec<DraftPick>> {
-        let results = sqlx::query_as!(
-            DraftPickDb,
-            r#"
-            SELECT id, draft_id, round, pick_number, overall_pick, team_id, player_id, picked_at, original_team_id, is_compensatory, notes, created_at, updated_at
-            FROM draft_picks
-            WHERE draft_id = $1 AND player_id IS NULL
-            ORDER BY overall_pick ASC
-            "#,
-            draft_id
-        )
-        .fetch_all(&self.pool)
-        .await
-        .map_err(DbError::DatabaseError)?;
+        traced("draft_pick.find_available_picks", async move {
+            let results = sqlx::query_as!(
+                DraftPickDb,
+                r#"
+                SELECT id, draft_id, round, pick_number, overall_pick, team_id, player_id, picked_at, original_team_id, is_compensatory, notes, created_at, updated_at
+                FROM draft_picks
+                WHERE draft_id = $1 AND player_id IS NULL
+                ORDER BY overall_pick ASC
+                "#,
+                draft_id
+            )
+            .fetch_all(&self.pool)
+            .await
+            .map_err(DbError::DatabaseError)?;
 
-        results
-            .into_iter()
-            .map(|db| db.to_domain())
-            .collect::<Result<Vec<_>, _>>()
-            .map_err(Into::into)
+            results
+                .into_iter()
+                .map(|db| db.to_domain())
+                .collect::<Result<Vec<_>, _>>()
+                .map_err(Into::into)
+        })
+        .await
     }
 
     async fn update(&self, pick: &DraftPick) -> DomainResult<DraftPick> {
-        let pick_db = DraftPickDb::from_domain(pick);
-
-        let result = sqlx::query_as!(
-            DraftPickDb,
-            r#"
-            UPDATE draft_picks
-            SET player_id = $2, picked_at = $3, updated_at = $4
-            WHERE id = $1
-            RETURNING id, draft_id, round, pick_number, overall_pick, team_id, player_id, picked_at, original_team_id, is_compensatory, notes, created_at, updated_at
-            "#,
-            pick_db.id,
-            pick_db.player_id,
-            pick_db.picked_at,
-            pick_db.updated_at
-        )
-        .fetch_optional(&self.pool)
-        .await
-        .map_err(DbError::DatabaseError)?
-        .ok_or_else(|| DbError::NotFound(format!("Draft pick with id {} not found", pick_db.id)))?;
+        traced("draft_pick.update", async move {
+            let pick_db = DraftPickDb::from_domain(pick);
 
-        result.to_domain().map_err(Into::into)
+            let result = sqlx::query_as!(
+                DraftPickDb,
+                r#"
+                UPDATE draft_picks
+                SET player_id = $2, picked_at = $3, updated_at = $4
+                WHERE id = $1
+                RETURNING id, draft_id, round, pick_number, overall_pick, team_id, player_id, picked_at, original_team_id, is_compensatory, notes, created_at, updated_at
+                "#,
+                pick_db.id,
+                pick_db.player_id,
+                pick_db.picked_at,
+                pick_db.updated_at
+            )
+            .fetch_optional(&self.pool)
+            .await
+            .map_err(DbError::DatabaseError)?
+            .ok_or_else(|| DbError::NotFound(format!("Draft pick with id {} not found", pick_db.id)))?;
+
+            result.to_domain().map_err(Into::into)
+        })
+        .await
     }
 
     async fn delete(&self, id: Uuid) -> DomainResult<()> {
-        let result = sqlx::query!(
-            r#"
-            DELETE FROM draft_picks WHERE id = $1
-            "#,
-            id
-        )
-        .execute(&self.pool)
-        .await
-        .map_err(DbError::DatabaseError)?;
+        traced("draft_pick.delete", async move {
+            let result = sqlx::query!(
+                r#"
+                DELETE FROM draft_picks WHERE id = $1
+                "#,
+                id
+            )
+            .execute(&self.pool)
+            .await
+            .map_err(DbError::DatabaseError)?;
 
-        if result.rows_affected() == 0 {
-            return Err(DbError::NotFound(format!("Draft pick with id {} not found", id)).into());
-        }
+            if result.rows_affected() == 0 {
+                return Err(
+                    DbError::NotFound(format!("Draft pick with id {} not found", id)).into(),
+                );
+            }
 
-        Ok(())
+            Ok(())
+        })
+        .await
     }
 
     async fn delete_by_draft_id(&self, draft_id: Uuid) -> DomainResult<()> {
-        sqlx::query!(
-            r#"
-            DELETE FROM draft_picks WHERE draft_id = $1
-            "#,
-            draft_id
-        )
-        .execute(&self.pool)
-        .await
-        .map_err(DbError::DatabaseError)?;
+        traced("draft_pick.delete_by_draft_id", async move {
+            sqlx::query!(
+                r#"
+                DELETE FROM draft_picks WHERE draft_id = $1
+                "#,
+                draft_id
+            )
+            .execute(&self.pool)
+            .await
+            .map_err(DbError::DatabaseError)?;
 
-        Ok(())
+            Ok(())
+        })
+        .await
     }
 }
 
@@ -617,6 +779,47 @@ mod tests {
         assert_eq!(created_pick.pick_number, 1);
     }
 
+    #[tokio::test]
+    async fn test_create_many_picks_in_batches() {
+        let pool = setup_test_pool().await;
+        cleanup(&pool).await;
+
+        let draft_repo = SqlxDraftRepository::new(pool.clone());
+        let draft = Draft::new("Test Draft".to_string(), 2026, 1, 5).unwrap();
+        let created_draft = draft_repo.create(&draft).await.unwrap();
+
+        let team_repo = SqlxTeamRepository::new(pool.clone());
+        let team = Team::new(
+            "Dallas Cowboys".to_string(),
+            "DAL".to_string(),
+            "Dallas".to_string(),
+            Conference::NFC,
+            Division::NFCEast,
+        )
+        .unwrap();
+        let created_team = team_repo.create(&team).await.unwrap();
+
+        let pick_repo = SqlxDraftPickRepository::new(pool).with_batch_size(2);
+        let picks: Vec<DraftPick> = (1..=5)
+            .map(|number| {
+                DraftPick::new(created_draft.id, 1, number, number, created_team.id).unwrap()
+            })
+            .collect();
+
+        let created = pick_repo.create_many(&picks).await.unwrap();
+        let mut overall: Vec<i32> = created.iter().map(|p| p.overall_pick).collect();
+        overall.sort();
+        assert_eq!(overall, vec![1, 2, 3, 4, 5]);
+        assert_eq!(
+            pick_repo
+                .find_by_draft_id(created_draft.id)
+                .await
+                .unwrap()
+                .len(),
+            5
+        );
+    }
+
     #[tokio::test]
     async fn test_find_next_pick() {
         let pool = setup_test_pool().await;
@@ -654,4 +857,40 @@ mod tests {
         assert!(next.is_some());
         assert_eq!(next.unwrap().overall_pick, 1);
     }
+
+    #[tokio::test]
+    async fn test_find_page_by_draft_id() {
+        let pool = setup_test_pool().await;
+        cleanup(&pool).await;
+
+        let draft_repo = SqlxDraftRepository::new(pool.clone());
+        let draft = Draft::new("Test Draft".to_string(), 2026, 1, 3).unwrap();
+        let created_draft = draft_repo.create(&draft).await.unwrap();
+
+        let team_repo = SqlxTeamRepository::new(pool.clone());
+        let team = Team::new(
+            "Dallas Cowboys".to_string(),
+            "DAL".to_string(),
+            "Dallas".to_string(),
+            Conference::NFC,
+            Division::NFCEast,
+        )
+        .unwrap();
+        let created_team = team_repo.create(&team).await.unwrap();
+
+        let pick_repo = SqlxDraftPickRepository::new(pool);
+        for number in 1..=3 {
+            let pick =
+                DraftPick::new(created_draft.id, 1, number, number, created_team.id).unwrap();
+            pick_repo.create(&pick).await.unwrap();
+        }
+
+        let page = pick_repo
+            .find_page_by_draft_id(created_draft.id, PageRequest::new(1, 2).unwrap())
+            .await
+            .unwrap();
+        assert_eq!(page.total, 3);
+        let overall: Vec<i32> = page.items.iter().map(|p| p.overall_pick).collect();
+        assert_eq!(overall, vec![1, 2]);
+    }
 }
@@ -0,0 +1,115 @@
+use std::time::Duration;
+
+use async_trait::async_trait;
+use sqlx::{PgPool, Postgres, Transaction};
+use uuid::Uuid;
+
+use domain::errors::{DomainError, DomainResult};
+use domain::repositories::{DraftLockGuard, DraftLockRepository};
+
+use crate::errors::DbError;
+use crate::instrument::traced;
+
+/// How long to wait for a draft lock before giving up
+const LOCK_TIMEOUT: Duration = Duration::from_secs(10);
+const LOCK_RETRY_INTERVAL: Duration = Duration::from_millis(20);
+
+/// Draft locks backed by transaction-level Postgres advisory locks, keyed by
+/// a hash of the draft id. Waiters poll with `pg_try_advisory_xact_lock`
+/// rather than blocking, so they don't sit on connections from the small
+/// pool that the lock holder needs to save its pick.
+pub struct SqlxDraftLockRepository {
+    pool: PgPool,
+}
+
+impl SqlxDraftLockRepository {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl DraftLockRepository for SqlxDraftLockRepository {
+    async fn acquire(&self, draft_id: Uuid) -> DomainResult<Box<dyn DraftLockGuard>> {
+        traced("draft_lock.acquire", async move {
+            let deadline = tokio::time::Instant::now() + LOCK_TIMEOUT;
+            loop {
+                let mut tx = self.pool.begin().await.map_err(DbError::DatabaseError)?;
+                let locked = sqlx::query_scalar!(
+                    r#"SELECT pg_try_advisory_xact_lock(hashtextextended($1::text, 0)) AS "locked!""#,
+                    draft_id.to_string()
+                )
+                .fetch_one(&mut *tx)
+                .await
+                .map_err(DbError::DatabaseError)?;
+
+                if locked {
+                    let guard: Box<dyn DraftLockGuard> = Box::new(SqlxDraftLockGuard { tx });
+                    return Ok(guard);
+                }
+                drop(tx);
+
+                if tokio::time::Instant::now() >= deadline {
+                    return Err(DomainError::ResourceInUse(format!(
+                        "Draft {} is busy with another pick; try again",
+                        draft_id
+                    )));
+                }
+                tokio::time::sleep(LOCK_RETRY_INTERVAL).await;
+            }
+        })
+        .await
+    }
+}
+
+/// Holds the transaction that owns the advisory lock. Ending the transaction
+/// frees the lock, and a dropped transaction is rolled back.
+struct SqlxDraftLockGuard {
+    tx: Transaction<'static, Postgres>,
+}
+
+#[async_trait]
+impl DraftLockGuard for SqlxDraftLockGuard {
+    async fn release(self: Box<Self>) -> DomainResult<()> {
+        self.tx.commit().await.map_err(DbError::DatabaseError)?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::get_test_pool;
+
+    #[tokio::test]
+    async fn test_draft_lock_is_exclusive_until_released() {
+        let pool = get_test_pool().await;
+        let repo = SqlxDraftLockRepository::new(pool.clone());
+        let draft_id = Uuid::new_v4();
+
+        let held = repo.acquire(draft_id).await.unwrap();
+
+        // Another draft is not blocked
+        let other = repo.acquire(Uuid::new_v4()).await.unwrap();
+        other.release().await.unwrap();
+
+        let waiter = {
+            let repo = SqlxDraftLockRepository::new(pool.clone());
+            tokio::spawn(async move { repo.acquire(draft_id).await.map(|_| ()) })
+        };
+        tokio::time::sleep(Duration::from_millis(100)).await;
+        assert!(!waiter.is_finished());
+
+        held.release().await.unwrap();
+        waiter.await.unwrap().unwrap();
+
+        // A guard dropped without release still frees the lock
+        drop(repo.acquire(draft_id).await.unwrap());
+        repo.acquire(draft_id)
+            .await
+            .unwrap()
+            .release()
+            .await
+            .unwrap();
+    }
+}
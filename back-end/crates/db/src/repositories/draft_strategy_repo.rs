@@ -7,6 +7,7 @@ use domain::models::DraftStrategy;
 use domain::repositories::DraftStrategyRepository;
 
 use crate::errors::DbError;
+use crate::instrument::traced;
 use crate::models::DraftStrategyDb;
 
 /// SQLx implementation of DraftStrategyRepository
@@ -23,64 +24,70 @@ impl SqlxDraftStrategyRepository {
 #[async_trait]
 impl DraftStrategyRepository for SqlxDraftStrategyRepository {
     async fn create(&self, strategy: &DraftStrategy) -> DomainResult<DraftStrategy> {
-        let strategy_db = DraftStrategyDb::from_domain(strategy)?;
-
-        let result = sqlx::query_as!(
-            DraftStrategyDb,
-            r#"
-            INSERT INTO draft_strategies
-            (id, team_id, draft_id, bpa_weight, need_weight, position_values, risk_tolerance, created_at, updated_at)
-            VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9)
-            RETURNING id, team_id, draft_id, bpa_weight, need_weight, position_values, risk_tolerance, created_at, updated_at
-            "#,
-            strategy_db.id,
-            strategy_db.team_id,
-            strategy_db.draft_id,
-            strategy_db.bpa_weight,
-            strategy_db.need_weight,
-            strategy_db.position_values,
-            strategy_db.risk_tolerance,
-            strategy_db.created_at,
-            strategy_db.updated_at
-        )
-        .fetch_one(&self.pool)
-        .await
-        .map_err(|e| {
-            if let sqlx::Error::Database(db_err) = &e {
-                if db_err.is_unique_violation() {
-                    return DbError::DuplicateEntry(format!(
-                        "Draft strategy for team {} and draft {} already exists",
-                        strategy.team_id, strategy.draft_id
-                    ));
-                }
-                if db_err.is_foreign_key_violation() {
-                    return DbError::NotFound("Team or draft not found".to_string());
+        traced("draft_strategy.create", async move {
+            let strategy_db = DraftStrategyDb::from_domain(strategy)?;
+
+            let result = sqlx::query_as!(
+                DraftStrategyDb,
+                r#"
+                INSERT INTO draft_strategies
+                (id, team_id, draft_id, bpa_weight, need_weight, position_values, risk_tolerance, created_at, updated_at)
+                VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9)
+                RETURNING id, team_id, draft_id, bpa_weight, need_weight, position_values, risk_tolerance, created_at, updated_at
+                "#,
+                strategy_db.id,
+                strategy_db.team_id,
+                strategy_db.draft_id,
+                strategy_db.bpa_weight,
+                strategy_db.need_weight,
+                strategy_db.position_values,
+                strategy_db.risk_tolerance,
+                strategy_db.created_at,
+                strategy_db.updated_at
+            )
+            .fetch_one(&self.pool)
+            .await
+            .map_err(|e| {
+                if let sqlx::Error::Database(db_err) = &e {
+                    if db_err.is_unique_violation() {
+                        return DbError::DuplicateEntry(format!(
+                            "Draft strategy for team {} and draft {} already exists",
+                            strategy.team_id, strategy.draft_id
+                        ));
+                    }
+                    if db_err.is_foreign_key_violation() {
+                        return DbError::NotFound("Team or draft not found".to_string());
+                    }
                 }
-            }
-            DbError::DatabaseError(e)
-        })?;
+                DbError::DatabaseError(e)
+            })?;
 
-        result.to_domain().map_err(Into::into)
+            result.to_domain().map_err(Into::into)
+        })
+        .await
     }
 
     async fn find_by_id(&self, id: Uuid) -> DomainResult<Option<DraftStrategy>> {
-        let result = sqlx::query_as!(
-            DraftStrategyDb,
-            r#"
-            SELECT id, team_id, draft_id, bpa_weight, need_weight, position_values, risk_tolerance, created_at, updated_at
-            FROM draft_strategies
-            WHERE id = $1
-            "#,
-            id
-        )
-        .fetch_optional(&self.pool)
-        .await
-        .map_err(DbError::DatabaseError)?;
+        traced("draft_strategy.find_by_id", async move {
+            let result = sqlx::query_as!(
+                DraftStrategyDb,
+                r#"
+                SELECT id, team_id, draft_id, bpa_weight, need_weight, position_values, risk_tolerance, created_at, updated_at
+                FROM draft_strategies
+                WHERE id = $1
+                "#,
+                id
+            )
+            .fetch_optional(&self.pool)
+            .await
+            .map_err(DbError::DatabaseError)?;
 
-        match result {
-            Some(strategy_db) => Ok(Some(strategy_db.to_domain()?)),
-            None => Ok(None),
-        }
+            match result {
+                Some(strategy_db) => Ok(Some(strategy_db.to_domain()?)),
+                None => Ok(None),
+            }
+        })
+        .await
     }
 
     async fn find_by_team_and_draft(
@@ -88,87 +95,99 @@ impl DraftStrategyRepository for SqlxDraftStrategyRepository {
         team_id: Uuid,
         draft_id: Uuid,
     ) -> DomainResult<Option<DraftStrategy>> {
-        let result = sqlx::query_as!(
-            DraftStrategyDb,
-            r#"
-            SELECT id, team_id, draft_id, bpa_weight, need_weight, position_values, risk_tolerance, created_at, updated_at
-            FROM draft_strategies
-            WHERE team_id = $1 AND draft_id = $2
-            "#,
-            team_id,
-            draft_id
-        )
-        .fetch_optional(&self.pool)
-        .await
-        .map_err(DbError::DatabaseError)?;
+        traced("draft_strategy.find_by_team_and_draft", async move {
+            let result = sqlx::query_as!(
+                DraftStrategyDb,
+                r#"
+                SELECT id, team_id, draft_id, bpa_weight, need_weight, position_values, risk_tolerance, created_at, updated_at
+                FROM draft_strategies
+                WHERE team_id = $1 AND draft_id = $2
+                "#,
+                team_id,
+                draft_id
+            )
+            .fetch_optional(&self.pool)
+            .await
+            .map_err(DbError::DatabaseError)?;
 
-        match result {
-            Some(strategy_db) => Ok(Some(strategy_db.to_domain()?)),
-            None => Ok(None),
-        }
+            match result {
+                Some(strategy_db) => Ok(Some(strategy_db.to_domain()?)),
+                None => Ok(None),
+            }
+        })
+        .await
     }
 
     async fn find_by_draft_id(&self, draft_id: Uuid) -> DomainResult<Vec<DraftStrategy>> {
-        let results = sqlx::query_as!(
-            DraftStrategyDb,
-            r#"
-            SELECT id, team_id, draft_id, bpa_weight, need_weight, position_values, risk_tolerance, created_at, updated_at
-            FROM draft_strategies
-            WHERE draft_id = $1
-            "#,
-            draft_id
-        )
-        .fetch_all(&self.pool)
-        .await
-        .map_err(DbError::DatabaseError)?;
+        traced("draft_strategy.find_by_draft_id", async move {
+            let results = sqlx::query_as!(
+                DraftStrategyDb,
+                r#"
+                SELECT id, team_id, draft_id, bpa_weight, need_weight, position_values, risk_tolerance, created_at, updated_at
+                FROM draft_strategies
+                WHERE draft_id = $1
+                "#,
+                draft_id
+            )
+            .fetch_all(&self.pool)
+            .await
+            .map_err(DbError::DatabaseError)?;
 
-        results
-            .into_iter()
-            .map(|r| r.to_domain().map_err(Into::into))
-            .collect()
+            results
+                .into_iter()
+                .map(|r| r.to_domain().map_err(Into::into))
+                .collect()
+        })
+        .await
     }
 
     async fn update(&self, strategy: &DraftStrategy) -> DomainResult<DraftStrategy> {
-        let strategy_db = DraftStrategyDb::from_domain(strategy)?;
-
-        let result = sqlx::query_as!(
-            DraftStrategyDb,
-            r#"
-            UPDATE draft_strategies
-            SET bpa_weight = $2,
-                need_weight = $3,
-                position_values = $4,
-                risk_tolerance = $5,
-                updated_at = $6
-            WHERE id = $1
-            RETURNING id, team_id, draft_id, bpa_weight, need_weight, position_values, risk_tolerance, created_at, updated_at
-            "#,
-            strategy_db.id,
-            strategy_db.bpa_weight,
-            strategy_db.need_weight,
-            strategy_db.position_values,
-            strategy_db.risk_tolerance,
-            strategy_db.updated_at
-        )
-        .fetch_one(&self.pool)
-        .await
-        .map_err(DbError::DatabaseError)?;
+        traced("draft_strategy.update", async move {
+            let strategy_db = DraftStrategyDb::from_domain(strategy)?;
+
+            let result = sqlx::query_as!(
+                DraftStrategyDb,
+                r#"
+                UPDATE draft_strategies
+                SET bpa_weight = $2,
+                    need_weight = $3,
+                    position_values = $4,
+                    risk_tolerance = $5,
+                    updated_at = $6
+                WHERE id = $1
+                RETURNING id, team_id, draft_id, bpa_weight, need_weight, position_values, risk_tolerance, created_at, updated_at
+                "#,
+                strategy_db.id,
+                strategy_db.bpa_weight,
+                strategy_db.need_weight,
+                strategy_db.position_values,
+                strategy_db.risk_tolerance,
+                strategy_db.updated_at
+            )
+            .fetch_one(&self.pool)
+            .await
+            .map_err(DbError::DatabaseError)?;
 
-        result.to_domain().map_err(Into::into)
+            result.to_domain().map_err(Into::into)
+        })
+        .await
     }
 
     async fn delete(&self, id: Uuid) -> DomainResult<()> {
-        sqlx::query!(
-            r#"
-            DELETE FROM draft_strategies WHERE id = $1
-            "#,
-            id
-        )
-        .execute(&self.pool)
-        .await
-        .map_err(DbError::DatabaseError)?;
+        traced("draft_strategy.delete", async move {
+            sqlx::query!(
+                r#"
+                DELETE FROM draft_strategies WHERE id = $1
+                "#,
+                id
+            )
+            .execute(&self.pool)
+            .await
+            .map_err(DbError::DatabaseError)?;
 
-        Ok(())
+            Ok(())
+        })
+        .await
     }
 }
 
@@ -1,7 +1,7 @@
 use async_trait::async_trait;
 use chrono::{DateTime, Utc};
 use serde_json::Value as JsonValue;
-use sqlx::PgPool;
+use sqlx::{PgConnection, PgPool};
 use std::str::FromStr;
 use uuid::Uuid;
 
@@ -9,6 +9,8 @@ use domain::errors::{DomainError, DomainResult};
 use domain::models::{DraftEvent, EventType};
 use domain::repositories::EventRepository;
 
+use crate::instrument::traced;
+
 #[derive(Debug, Clone, sqlx::FromRow)]
 struct DraftEventDb {
     id: Uuid,
@@ -34,6 +36,42 @@ impl TryFrom<DraftEventDb> for DraftEvent {
     }
 }
 
+/// Appends an event and its outbox entry. Callers pass the transaction
+/// making the change the event records, so the event is published if and
+/// only if the change commits.
+pub(crate) async fn insert_event(
+    conn: &mut PgConnection,
+    event: &DraftEvent,
+) -> DomainResult<DraftEvent> {
+    let db_event = sqlx::query_as!(
+        DraftEventDb,
+        r#"
+        INSERT INTO draft_events (id, session_id, event_type, event_data, created_at)
+        VALUES ($1, $2, $3, $4, $5)
+        RETURNING id, session_id, event_type, event_data, created_at
+        "#,
+        event.id,
+        event.session_id,
+        event.event_type.to_string(),
+        event.event_data,
+        event.created_at,
+    )
+    .fetch_one(&mut *conn)
+    .await
+    .map_err(|e| DomainError::DatabaseError(e.to_string()))?;
+
+    sqlx::query!(
+        "INSERT INTO event_outbox (event_id, available_at) VALUES ($1, $2)",
+        event.id,
+        event.created_at,
+    )
+    .execute(&mut *conn)
+    .await
+    .map_err(|e| DomainError::DatabaseError(e.to_string()))?;
+
+    db_event.try_into()
+}
+
 pub struct EventRepo {
     pool: PgPool,
 }
@@ -47,65 +85,133 @@ impl EventRepo {
 #[async_trait]
 impl EventRepository for EventRepo {
     async fn create(&self, event: &DraftEvent) -> DomainResult<DraftEvent> {
-        let db_event = sqlx::query_as!(
-            DraftEventDb,
-            r#"
-            INSERT INTO draft_events (id, session_id, event_type, event_data, created_at)
-            VALUES ($1, $2, $3, $4, $5)
-            RETURNING id, session_id, event_type, event_data, created_at
-            "#,
-            event.id,
-            event.session_id,
-            event.event_type.to_string(),
-            event.event_data,
-            event.created_at,
-        )
-        .fetch_one(&self.pool)
+        traced("event.create", async move {
+            let mut tx = self
+                .pool
+                .begin()
+                .await
+                .map_err(|e| DomainError::DatabaseError(e.to_string()))?;
+            let created = insert_event(&mut tx, event).await?;
+            tx.commit()
+                .await
+                .map_err(|e| DomainError::DatabaseError(e.to_string()))?;
+            Ok(created)
+        })
         .await
-        .map_err(|e| DomainError::DatabaseError(e.to_string()))?;
-
-        db_event.try_into()
     }
 
     async fn find_by_id(&self, id: Uuid) -> DomainResult<Option<DraftEvent>> {
-        let result = sqlx::query_as!(
-            DraftEventDb,
-            r#"
-            SELECT id, session_id, event_type, event_data, created_at
-            FROM draft_events
-            WHERE id = $1
-            "#,
-            id
-        )
-        .fetch_optional(&self.pool)
-        .await
-        .map_err(|e| DomainError::DatabaseError(e.to_string()))?;
+        traced("event.find_by_id", async move {
+            let result = sqlx::query_as!(
+                DraftEventDb,
+                r#"
+                SELECT id, session_id, event_type, event_data, created_at
+                FROM draft_events
+                WHERE id = $1
+                "#,
+                id
+            )
+            .fetch_optional(&self.pool)
+            .await
+            .map_err(|e| DomainError::DatabaseError(e.to_string()))?;
 
-        match result {
-            Some(db_event) => Ok(Some(db_event.try_into()?)),
-            None => Ok(None),
-        }
+            match result {
+                Some(db_event) => Ok(Some(db_event.try_into()?)),
+                None => Ok(None),
+            }
+        })
+        .await
     }
 
     async fn list_by_session(&self, session_id: Uuid) -> DomainResult<Vec<DraftEvent>> {
-        let events = sqlx::query_as!(
-            DraftEventDb,
-            r#"
-            SELECT id, session_id, event_type, event_data, created_at
-            FROM draft_events
-            WHERE session_id = $1
-            ORDER BY created_at ASC
-            "#,
-            session_id
-        )
-        .fetch_all(&self.pool)
+        traced("event.list_by_session", async move {
+            let events = sqlx::query_as!(
+                DraftEventDb,
+                r#"
+                SELECT id, session_id, event_type, event_data, created_at
+                FROM draft_events
+                WHERE session_id = $1
+                ORDER BY created_at ASC
+                "#,
+                session_id
+            )
+            .fetch_all(&self.pool)
+            .await
+            .map_err(|e| DomainError::DatabaseError(e.to_string()))?;
+
+            events
+                .into_iter()
+                .map(|db| db.try_into())
+                .collect::<Result<Vec<_>, _>>()
+        })
         .await
-        .map_err(|e| DomainError::DatabaseError(e.to_string()))?;
+    }
 
-        events
-            .into_iter()
-            .map(|db| db.try_into())
-            .collect::<Result<Vec<_>, _>>()
+    async fn list_by_session_after(
+        &self,
+        session_id: Uuid,
+        after_id: Option<Uuid>,
+        limit: i64,
+    ) -> DomainResult<Vec<DraftEvent>> {
+        traced("event.list_by_session_after", async move {
+            let events = sqlx::query_as!(
+                DraftEventDb,
+                r#"
+                SELECT id, session_id, event_type, event_data, created_at
+                FROM draft_events
+                WHERE session_id = $1
+                  AND ($2::uuid IS NULL OR (created_at, id) > (
+                      SELECT created_at, id FROM draft_events WHERE id = $2
+                  ))
+                ORDER BY created_at ASC, id ASC
+                LIMIT $3
+                "#,
+                session_id,
+                after_id,
+                limit
+            )
+            .fetch_all(&self.pool)
+            .await
+            .map_err(|e| DomainError::DatabaseError(e.to_string()))?;
+
+            events
+                .into_iter()
+                .map(|db| db.try_into())
+                .collect::<Result<Vec<_>, _>>()
+        })
+        .await
+    }
+
+    async fn list_after(
+        &self,
+        after_id: Option<Uuid>,
+        limit: i64,
+    ) -> DomainResult<Vec<DraftEvent>> {
+        traced("event.list_after", async move {
+            let events = sqlx::query_as!(
+                DraftEventDb,
+                r#"
+                SELECT id, session_id, event_type, event_data, created_at
+                FROM draft_events
+                WHERE $1::uuid IS NULL OR (created_at, id) > (
+                    SELECT created_at, id FROM draft_events WHERE id = $1
+                )
+                ORDER BY created_at ASC, id ASC
+                LIMIT $2
+                "#,
+                after_id,
+                limit
+            )
+            .fetch_all(&self.pool)
+            .await
+            .map_err(|e| DomainError::DatabaseError(e.to_string()))?;
+
+            events
+                .into_iter()
+                .map(|db| db.try_into())
+                .collect::<Result<Vec<_>, _>>()
+        })
+        .await
     }
 
     async fn list_by_session_and_type(
@@ -113,41 +219,132 @@ impl EventRepository for EventRepo {
         session_id: Uuid,
         event_type: &str,
     ) -> DomainResult<Vec<DraftEvent>> {
-        let events = sqlx::query_as!(
-            DraftEventDb,
-            r#"
-            SELECT id, session_id, event_type, event_data, created_at
-            FROM draft_events
-            WHERE session_id = $1 AND event_type = $2
-            ORDER BY created_at ASC
-            "#,
-            session_id,
-            event_type
-        )
-        .fetch_all(&self.pool)
-        .await
-        .map_err(|e| DomainError::DatabaseError(e.to_string()))?;
+        traced("event.list_by_session_and_type", async move {
+            let events = sqlx::query_as!(
+                DraftEventDb,
+                r#"
+                SELECT id, session_id, event_type, event_data, created_at
+                FROM draft_events
+                WHERE session_id = $1 AND event_type = $2
+                ORDER BY created_at ASC
+                "#,
+                session_id,
+                event_type
+            )
+            .fetch_all(&self.pool)
+            .await
+            .map_err(|e| DomainError::DatabaseError(e.to_string()))?;
 
-        events
-            .into_iter()
-            .map(|db| db.try_into())
-            .collect::<Result<Vec<_>, _>>()
+            events
+                .into_iter()
+                .map(|db| db.try_into())
+                .collect::<Result<Vec<_>, _>>()
+        })
+        .await
     }
 
     async fn count_by_session(&self, session_id: Uuid) -> DomainResult<i64> {
-        let result = sqlx::query!(
-            r#"
-            SELECT COUNT(*) as "count!"
-            FROM draft_events
-            WHERE session_id = $1
-            "#,
-            session_id
-        )
-        .fetch_one(&self.pool)
+        traced("event.count_by_session", async move {
+            let result = sqlx::query!(
+                r#"
+                SELECT COUNT(*) as "count!"
+                FROM draft_events
+                WHERE session_id = $1
+                "#,
+                session_id
+            )
+            .fetch_one(&self.pool)
+            .await
+            .map_err(|e| DomainError::DatabaseError(e.to_string()))?;
+
+            Ok(result.count)
+        })
+        .await
+    }
+
+    async fn claim_unpublished(
+        &self,
+        due_by: DateTime<Utc>,
+        limit: i64,
+    ) -> DomainResult<Vec<DraftEvent>> {
+        traced("event.claim_unpublished", async move {
+            let events = sqlx::query_as!(
+                DraftEventDb,
+                r#"
+                WITH claimed AS (
+                    UPDATE event_outbox
+                    SET available_at = NOW(), attempts = attempts + 1
+                    WHERE event_id IN (
+                        SELECT event_id FROM event_outbox
+                        WHERE published_at IS NULL AND available_at <= $1
+                        ORDER BY available_at
+                        LIMIT $2
+                        FOR UPDATE SKIP LOCKED
+                    )
+                    RETURNING event_id
+                )
+                SELECT e.id, e.session_id, e.event_type, e.event_data, e.created_at
+                FROM draft_events e
+                JOIN claimed c ON c.event_id = e.id
+                ORDER BY e.created_at ASC, e.id ASC
+                "#,
+                due_by,
+                limit
+            )
+            .fetch_all(&self.pool)
+            .await
+            .map_err(|e| DomainError::DatabaseError(e.to_string()))?;
+
+            events
+                .into_iter()
+                .map(|db| db.try_into())
+                .collect::<Result<Vec<_>, _>>()
+        })
         .await
-        .map_err(|e| DomainError::DatabaseError(e.to_string()))?;
+    }
+
+    async fn mark_published(&self, event_ids: &[Uuid]) -> DomainResult<u64> {
+        traced("event.mark_published", async move {
+            let result = sqlx::query!(
+                r#"
+                UPDATE event_outbox
+                SET published_at = NOW()
+                WHERE event_id = ANY($1) AND published_at IS NULL
+                "#,
+                event_ids
+            )
+            .execute(&self.pool)
+            .await
+            .map_err(|e| DomainError::DatabaseError(e.to_string()))?;
 
-        Ok(result.count)
+            Ok(result.rows_affected())
+        })
+        .await
+    }
+
+    async fn mark_picks_published(&self, session_id: Uuid, pick_ids: &[Uuid]) -> DomainResult<u64> {
+        traced("event.mark_picks_published", async move {
+            let result = sqlx::query!(
+                r#"
+                UPDATE event_outbox o
+                SET published_at = NOW()
+                FROM draft_events e
+                WHERE o.event_id = e.id
+                  AND o.published_at IS NULL
+                  AND e.session_id = $1
+                  AND e.event_type = 'PickMade'
+                  AND (e.event_data->>'pick_id')::uuid = ANY($2)
+                "#,
+                session_id,
+                pick_ids
+            )
+            .execute(&self.pool)
+            .await
+            .map_err(|e| DomainError::DatabaseError(e.to_string()))?;
+
+            Ok(result.rows_affected())
+        })
+        .await
     }
 }
 
@@ -287,6 +484,89 @@ mod tests {
             .unwrap();
     }
 
+    #[tokio::test]
+    async fn test_list_by_session_after() {
+        let pool = get_test_pool().await;
+        cleanup_events(&pool).await;
+
+        let repo = EventRepo::new(pool.clone());
+
+        let draft_id = Uuid::new_v4();
+        let session_id = Uuid::new_v4();
+        let draft_year = 2026
+            + (std::time::SystemTime::now()
+                .duration_since(std::time::UNIX_EPOCH)
+                .unwrap()
+                .as_millis()
+                % 100) as i32;
+
+        sqlx::query!(
+            "INSERT INTO drafts (id, year, status, rounds, picks_per_round) VALUES ($1, $2, 'NotStarted', 7, 32::INTEGER)",
+            draft_id,
+            draft_year
+        )
+        .execute(&pool)
+        .await
+        .unwrap();
+
+        sqlx::query!(
+            "INSERT INTO draft_sessions (id, draft_id, status, current_pick_number, time_per_pick_seconds, auto_pick_enabled) VALUES ($1, $2, 'NotStarted', 1, 300, false)",
+            session_id,
+            draft_id
+        )
+        .execute(&pool)
+        .await
+        .unwrap();
+
+        // Five events, the last three recorded in the same instant
+        let start = Utc::now();
+        for i in 0..5 {
+            let mut event = DraftEvent::clock_update(session_id, i);
+            event.created_at = start + chrono::Duration::milliseconds(i.min(2) as i64);
+            repo.create(&event).await.unwrap();
+        }
+        let all_ids: Vec<Uuid> = repo
+            .list_by_session_after(session_id, None, 100)
+            .await
+            .unwrap()
+            .into_iter()
+            .map(|e| e.id)
+            .collect();
+        assert_eq!(all_ids.len(), 5);
+
+        // Walking the feed two at a time visits every event exactly once
+        let mut walked = Vec::new();
+        let mut cursor = None;
+        loop {
+            let page = repo
+                .list_by_session_after(session_id, cursor, 2)
+                .await
+                .unwrap();
+            if page.is_empty() {
+                break;
+            }
+            assert!(page.len() <= 2);
+            cursor = page.last().map(|e| e.id);
+            walked.extend(page.into_iter().map(|e| e.id));
+        }
+        assert_eq!(walked, all_ids);
+
+        // No other session has events, so the global feed walks the same ones
+        let first = repo.list_after(None, 3).await.unwrap();
+        let rest = repo
+            .list_after(first.last().map(|e| e.id), 3)
+            .await
+            .unwrap();
+        let global: Vec<Uuid> = first.iter().chain(&rest).map(|e| e.id).collect();
+        assert_eq!(global, all_ids);
+
+        cleanup_events(&pool).await;
+        sqlx::query!("DELETE FROM drafts WHERE id = $1", draft_id)
+            .execute(&pool)
+            .await
+            .unwrap();
+    }
+
     #[tokio::test]
     async fn test_list_by_session_and_type() {
         let pool = get_test_pool().await;
@@ -348,4 +628,84 @@ mod tests {
             .await
             .unwrap();
     }
+
+    #[tokio::test]
+    async fn test_outbox_claim_and_publish() {
+        let pool = get_test_pool().await;
+        cleanup_events(&pool).await;
+
+        let repo = EventRepo::new(pool.clone());
+
+        let draft_id = Uuid::new_v4();
+        let session_id = Uuid::new_v4();
+        let draft_year = 2026
+            + (std::time::SystemTime::now()
+                .duration_since(std::time::UNIX_EPOCH)
+                .unwrap()
+                .as_millis()
+                % 100) as i32;
+
+        sqlx::query!(
+            "INSERT INTO drafts (id, year, status, rounds, picks_per_round) VALUES ($1, $2, 'NotStarted', 7, 32::INTEGER)",
+            draft_id,
+            draft_year
+        )
+        .execute(&pool)
+        .await
+        .unwrap();
+
+        sqlx::query!(
+            "INSERT INTO draft_sessions (id, draft_id, status, current_pick_number, time_per_pick_seconds, auto_pick_enabled) VALUES ($1, $2, 'NotStarted', 1, 300, false)",
+            session_id,
+            draft_id
+        )
+        .execute(&pool)
+        .await
+        .unwrap();
+
+        let started = DraftEvent::session_started(session_id);
+        let pick_id = Uuid::new_v4();
+        let pick =
+            DraftEvent::pick_made(session_id, pick_id, Uuid::new_v4(), Uuid::new_v4(), 1, 1, 1);
+        repo.create(&started).await.unwrap();
+        repo.create(&pick).await.unwrap();
+
+        // Nothing is due before the events were recorded
+        let before = started.created_at - chrono::Duration::seconds(1);
+        assert!(repo.claim_unpublished(before, 10).await.unwrap().is_empty());
+
+        // Claiming hands out each event once until it is due again
+        let due_by = Utc::now() + chrono::Duration::seconds(1);
+        let claimed: Vec<Uuid> = repo
+            .claim_unpublished(due_by, 10)
+            .await
+            .unwrap()
+            .into_iter()
+            .map(|e| e.id)
+            .collect();
+        assert_eq!(claimed, vec![started.id, pick.id]);
+        assert!(repo
+            .claim_unpublished(started.created_at, 10)
+            .await
+            .unwrap()
+            .is_empty());
+
+        // Published events are never claimed again
+        assert_eq!(repo.mark_published(&[started.id]).await.unwrap(), 1);
+        assert_eq!(repo.mark_published(&[started.id]).await.unwrap(), 0);
+        assert_eq!(
+            repo.mark_picks_published(session_id, &[pick_id])
+                .await
+                .unwrap(),
+            1
+        );
+        let later = Utc::now() + chrono::Duration::minutes(1);
+        assert!(repo.claim_unpublished(later, 10).await.unwrap().is_empty());
+
+        cleanup_events(&pool).await;
+        sqlx::query!("DELETE FROM drafts WHERE id = $1", draft_id)
+            .execute(&pool)
+            .await
+            .unwrap();
+    }
 }
@@ -7,131 +7,158 @@ use domain::models::FeldmanFreak;
 use domain::repositories::FeldmanFreakRepository;
 
 use crate::errors::DbError;
+use crate::instrument::traced;
 use crate::models::FeldmanFreakDb;
 
 /// SQLx implementation of FeldmanFreakRepository
 pub struct SqlxFeldmanFreakRepository {
     pool: PgPool,
+    /// Serves collection reads; the primary unless a replica is set
+    read_pool: PgPool,
 }
 
 impl SqlxFeldmanFreakRepository {
     pub fn new(pool: PgPool) -> Self {
-        Self { pool }
+        Self {
+            read_pool: pool.clone(),
+            pool,
+        }
+    }
+
+    /// Send collection reads to a read replica
+    pub fn with_read_pool(mut self, read_pool: PgPool) -> Self {
+        self.read_pool = read_pool;
+        self
     }
 }
 
 #[async_trait]
 impl FeldmanFreakRepository for SqlxFeldmanFreakRepository {
     async fn create(&self, freak: &FeldmanFreak) -> DomainResult<FeldmanFreak> {
-        let freak_db = FeldmanFreakDb::from_domain(freak);
-
-        let result = sqlx::query_as!(
-            FeldmanFreakDb,
-            r#"
-            INSERT INTO feldman_freaks
-            (id, player_id, year, rank, description, article_url, created_at)
-            VALUES ($1, $2, $3, $4, $5, $6, $7)
-            RETURNING id, player_id, year, rank, description, article_url, created_at
-            "#,
-            freak_db.id,
-            freak_db.player_id,
-            freak_db.year,
-            freak_db.rank,
-            freak_db.description,
-            freak_db.article_url,
-            freak_db.created_at
-        )
-        .fetch_one(&self.pool)
-        .await
-        .map_err(|e| {
-            if let sqlx::Error::Database(db_err) = &e {
-                if db_err.is_unique_violation() {
-                    return DbError::DuplicateEntry(format!(
-                        "Feldman Freak entry for player {} in year {} already exists",
-                        freak.player_id, freak.year
-                    ));
-                }
-                if db_err.is_foreign_key_violation() {
-                    return DbError::NotFound("Player not found".to_string());
+        traced("feldman_freak.create", async move {
+            let freak_db = FeldmanFreakDb::from_domain(freak);
+
+            let result = sqlx::query_as!(
+                FeldmanFreakDb,
+                r#"
+                INSERT INTO feldman_freaks
+                (id, player_id, year, rank, description, article_url, created_at)
+                VALUES ($1, $2, $3, $4, $5, $6, $7)
+                RETURNING id, player_id, year, rank, description, article_url, created_at
+                "#,
+                freak_db.id,
+                freak_db.player_id,
+                freak_db.year,
+                freak_db.rank,
+                freak_db.description,
+                freak_db.article_url,
+                freak_db.created_at
+            )
+            .fetch_one(&self.pool)
+            .await
+            .map_err(|e| {
+                if let sqlx::Error::Database(db_err) = &e {
+                    if db_err.is_unique_violation() {
+                        return DbError::DuplicateEntry(format!(
+                            "Feldman Freak entry for player {} in year {} already exists",
+                            freak.player_id, freak.year
+                        ));
+                    }
+                    if db_err.is_foreign_key_violation() {
+                        return DbError::NotFound("Player not found".to_string());
+                    }
                 }
-            }
-            DbError::DatabaseError(e)
-        })?;
+                DbError::DatabaseError(e)
+            })?;
 
-        result.to_domain().map_err(Into::into)
+            result.to_domain().map_err(Into::into)
+        })
+        .await
     }
 
     async fn find_by_player(&self, player_id: Uuid) -> DomainResult<Option<FeldmanFreak>> {
-        let result = sqlx::query_as!(
-            FeldmanFreakDb,
-            r#"
-            SELECT id, player_id, year, rank, description, article_url, created_at
-            FROM feldman_freaks
-            WHERE player_id = $1
-            "#,
-            player_id
-        )
-        .fetch_optional(&self.pool)
-        .await
-        .map_err(DbError::DatabaseError)?;
+        traced("feldman_freak.find_by_player", async move {
+            let result = sqlx::query_as!(
+                FeldmanFreakDb,
+                r#"
+                SELECT id, player_id, year, rank, description, article_url, created_at
+                FROM feldman_freaks
+                WHERE player_id = $1
+                "#,
+                player_id
+            )
+            .fetch_optional(&self.pool)
+            .await
+            .map_err(DbError::DatabaseError)?;
 
-        match result {
-            Some(freak_db) => Ok(Some(freak_db.to_domain()?)),
-            None => Ok(None),
-        }
+            match result {
+                Some(freak_db) => Ok(Some(freak_db.to_domain()?)),
+                None => Ok(None),
+            }
+        })
+        .await
     }
 
     async fn find_by_year(&self, year: i32) -> DomainResult<Vec<FeldmanFreak>> {
-        let results = sqlx::query_as!(
-            FeldmanFreakDb,
-            r#"
-            SELECT id, player_id, year, rank, description, article_url, created_at
-            FROM feldman_freaks
-            WHERE year = $1
-            ORDER BY rank ASC
-            "#,
-            year
-        )
-        .fetch_all(&self.pool)
-        .await
-        .map_err(DbError::DatabaseError)?;
+        traced("feldman_freak.find_by_year", async move {
+            let results = sqlx::query_as!(
+                FeldmanFreakDb,
+                r#"
+                SELECT id, player_id, year, rank, description, article_url, created_at
+                FROM feldman_freaks
+                WHERE year = $1
+                ORDER BY rank ASC
+                "#,
+                year
+            )
+            .fetch_all(&self.read_pool)
+            .await
+            .map_err(DbError::DatabaseError)?;
 
-        results
-            .into_iter()
-            .map(|r| r.to_domain().map_err(Into::into))
-            .collect()
+            results
+                .into_iter()
+                .map(|r| r.to_domain().map_err(Into::into))
+                .collect()
+        })
+        .await
     }
 
     async fn find_all(&self) -> DomainResult<Vec<FeldmanFreak>> {
-        let results = sqlx::query_as!(
-            FeldmanFreakDb,
-            r#"
-            SELECT id, player_id, year, rank, description, article_url, created_at
-            FROM feldman_freaks
-            ORDER BY year DESC, rank ASC
-            "#
-        )
-        .fetch_all(&self.pool)
-        .await
-        .map_err(DbError::DatabaseError)?;
+        traced("feldman_freak.find_all", async move {
+            let results = sqlx::query_as!(
+                FeldmanFreakDb,
+                r#"
+                SELECT id, player_id, year, rank, description, article_url, created_at
+                FROM feldman_freaks
+                ORDER BY year DESC, rank ASC
+                "#
+            )
+            .fetch_all(&self.read_pool)
+            .await
+            .map_err(DbError::DatabaseError)?;
 
-        results
-            .into_iter()
-            .map(|r| r.to_domain().map_err(Into::into))
-            .collect()
+            results
+                .into_iter()
+                .map(|r| r.to_domain().map_err(Into::into))
+                .collect()
+        })
+        .await
     }
 
     async fn delete_by_year(&self, year: i32) -> DomainResult<u64> {
-        let result = sqlx::query!(
-            r#"
-            DELETE FROM feldman_freaks WHERE year = $1
-            "#,
-            year
-        )
-        .execute(&self.pool)
-        .await
-        .map_err(DbError::DatabaseError)?;
+        traced("feldman_freak.delete_by_year", async move {
+            let result = sqlx::query!(
+                r#"
+                DELETE FROM feldman_freaks WHERE year = $1
+                "#,
+                year
+            )
+            .execute(&self.pool)
+            .await
+            .map_err(DbError::DatabaseError)?;
 
-        Ok(result.rows_affected())
+            Ok(result.rows_affected())
+        })
+        .await
     }
 }
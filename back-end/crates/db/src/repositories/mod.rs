@@ -1,33 +1,55 @@
+pub mod actual_draft_pick_repo;
+pub mod adp_repo;
+pub mod audit_log_repo;
+pub mod chat_message_repo;
+pub mod college_stats_repo;
 pub mod combine_percentile_repo;
 pub mod combine_results_repo;
 pub mod draft;
+pub mod draft_lock_repo;
 pub mod draft_strategy_repo;
 pub mod event_repo;
 pub mod feldman_freak_repo;
 pub mod player;
+pub mod player_note_repo;
+pub mod player_tag_repo;
 pub mod prospect_profile_repo;
 pub mod prospect_ranking_repo;
 pub mod ranking_source_repo;
 pub mod scouting_report_repo;
+pub mod session_participant_repo;
 pub mod session_repo;
 pub mod team;
 pub mod team_need_repo;
 pub mod team_season_repo;
 pub mod trade_repo;
+pub mod user_repo;
+pub mod watchlist_repo;
 
+pub use actual_draft_pick_repo::SqlxActualDraftPickRepository;
+pub use adp_repo::SqlxAdpRepository;
+pub use audit_log_repo::SqlxAuditLogRepository;
+pub use chat_message_repo::SqlxChatMessageRepository;
+pub use college_stats_repo::SqlxCollegeStatsRepository;
 pub use combine_percentile_repo::SqlxCombinePercentileRepository;
 pub use combine_results_repo::SqlxCombineResultsRepository;
 pub use draft::{SqlxDraftPickRepository, SqlxDraftRepository};
+pub use draft_lock_repo::SqlxDraftLockRepository;
 pub use draft_strategy_repo::SqlxDraftStrategyRepository;
 pub use event_repo::EventRepo;
 pub use feldman_freak_repo::SqlxFeldmanFreakRepository;
 pub use player::SqlxPlayerRepository;
+pub use player_note_repo::SqlxPlayerNoteRepository;
+pub use player_tag_repo::SqlxPlayerTagRepository;
 pub use prospect_profile_repo::SqlxProspectProfileRepository;
 pub use prospect_ranking_repo::SqlxProspectRankingRepository;
 pub use ranking_source_repo::SqlxRankingSourceRepository;
 pub use scouting_report_repo::SqlxScoutingReportRepository;
+pub use session_participant_repo::SqlxSessionParticipantRepository;
 pub use session_repo::SessionRepo;
 pub use team::SqlxTeamRepository;
 pub use team_need_repo::SqlxTeamNeedRepository;
 pub use team_season_repo::SqlxTeamSeasonRepository;
 pub use trade_repo::SqlxTradeRepository;
+pub use user_repo::SqlxUserRepository;
+pub use watchlist_repo::SqlxWatchlistRepository;
@@ -1,212 +1,578 @@
 use async_trait::async_trait;
-use sqlx::PgPool;
+use sqlx::{PgPool, Postgres, QueryBuilder};
 use uuid::Uuid;
 
 use domain::errors::DomainResult;
-use domain::models::{Player, Position};
+use domain::models::{Page, PageRequest, Player, PlayerFilter, PlayerSortField, Position, Sort};
 use domain::repositories::PlayerRepository;
 
 use crate::errors::DbError;
+use crate::instrument::traced;
 use crate::models::PlayerDb;
+use crate::query::{push_order_by, push_page, rows_per_insert, DEFAULT_BATCH_SIZE};
+
+const PLAYER_COLUMNS: &str = "id, first_name, last_name, position, college, height_inches, weight_pounds, draft_year, draft_eligible, injury_status, injury_body_part, injury_expected_return, medical_flag, profile_url, headshot_url, created_at, updated_at";
+
+/// Appends a WHERE clause with one bound condition per set filter field
+fn push_filter(builder: &mut QueryBuilder<'_, Postgres>, filter: &PlayerFilter) {
+    builder.push(" WHERE deleted_at IS NULL");
+    if let Some(position) = &filter.position {
+        builder
+            .push(" AND position = ")
+            .push_bind(crate::models::player::position_to_string(position));
+    }
+    if let Some(college) = &filter.college {
+        builder
+            .push(" AND college ILIKE ")
+            .push_bind(format!("%{}%", escape_like(college)));
+    }
+    if let Some(draft_year) = filter.draft_year {
+        builder.push(" AND draft_year = ").push_bind(draft_year);
+    }
+    if let Some(draft_eligible) = filter.draft_eligible {
+        builder
+            .push(" AND draft_eligible = ")
+            .push_bind(draft_eligible);
+    }
+    if let Some(min) = filter.min_height_inches {
+        builder.push(" AND height_inches >= ").push_bind(min);
+    }
+    if let Some(max) = filter.max_height_inches {
+        builder.push(" AND height_inches <= ").push_bind(max);
+    }
+    if let Some(min) = filter.min_weight_pounds {
+        builder.push(" AND weight_pounds >= ").push_bind(min);
+    }
+    if let Some(max) = filter.max_weight_pounds {
+        builder.push(" AND weight_pounds <= ").push_bind(max);
+    }
+    if let Some(has_combine_results) = filter.has_combine_results {
+        builder.push(if has_combine_results {
+            " AND EXISTS"
+        } else {
+            " AND NOT EXISTS"
+        });
+        builder.push(" (SELECT 1 FROM combine_results cr WHERE cr.player_id = players.id)");
+    }
+    if let Some(tags) = &filter.tags {
+        builder
+            .push(" AND (SELECT COUNT(DISTINCT pt.tag) FROM player_tags pt WHERE pt.player_id = players.id AND pt.tag = ANY(")
+            .push_bind(tags.clone())
+            .push(")) = ")
+            .push_bind(tags.len() as i64);
+    }
+    if let Some(ids) = &filter.ids {
+        builder
+            .push(" AND id = ANY(")
+            .push_bind(ids.clone())
+            .push(")");
+    }
+}
+
+fn sort_column(field: PlayerSortField) -> &'static str {
+    match field {
+        PlayerSortField::FirstName => "first_name",
+        PlayerSortField::LastName => "last_name",
+        PlayerSortField::Position => "position",
+        PlayerSortField::College => "college",
+        PlayerSortField::HeightInches => "height_inches",
+        PlayerSortField::WeightPounds => "weight_pounds",
+        PlayerSortField::DraftYear => "draft_year",
+    }
+}
+
+/// Escapes LIKE wildcards so user input only matches literally
+fn escape_like(value: &str) -> String {
+    value
+        .replace('\\', "\\\\")
+        .replace('%', "\\%")
+        .replace('_', "\\_")
+}
 
 /// SQLx implementation of PlayerRepository
 pub struct SqlxPlayerRepository {
     pool: PgPool,
+    /// Serves collection reads; the primary unless a replica is set
+    read_pool: PgPool,
+    batch_size: usize,
 }
 
 impl SqlxPlayerRepository {
     pub fn new(pool: PgPool) -> Self {
-        Self { pool }
+        Self {
+            read_pool: pool.clone(),
+            pool,
+            batch_size: DEFAULT_BATCH_SIZE,
+        }
+    }
+
+    /// Rows per INSERT statement in `create_many`
+    pub fn with_batch_size(mut self, batch_size: usize) -> Self {
+        self.batch_size = batch_size;
+        self
+    }
+
+    /// Send collection reads to a read replica
+    pub fn with_read_pool(mut self, read_pool: PgPool) -> Self {
+        self.read_pool = read_pool;
+        self
     }
 }
 
 #[async_trait]
 impl PlayerRepository for SqlxPlayerRepository {
     async fn create(&self, player: &Player) -> DomainResult<Player> {
-        let player_db = PlayerDb::from_domain(player);
-
-        let result = sqlx::query_as!(
-            PlayerDb,
-            r#"
-            INSERT INTO players (id, first_name, last_name, position, college, height_inches, weight_pounds, draft_year, draft_eligible, created_at, updated_at)
-            VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11)
-            RETURNING id, first_name, last_name, position, college, height_inches, weight_pounds, draft_year, draft_eligible, created_at, updated_at
-            "#,
-            player_db.id,
-            player_db.first_name,
-            player_db.last_name,
-            player_db.position,
-            player_db.college,
-            player_db.height_inches,
-            player_db.weight_pounds,
-            player_db.draft_year,
-            player_db.draft_eligible,
-            player_db.created_at,
-            player_db.updated_at
-        )
-        .fetch_one(&self.pool)
+        traced("player.create", async move {
+            let player_db = PlayerDb::from_domain(player);
+
+            let result = sqlx::query_as!(
+                PlayerDb,
+                r#"
+                INSERT INTO players (id, first_name, last_name, position, college, height_inches, weight_pounds, draft_year, draft_eligible, injury_status, injury_body_part, injury_expected_return, medical_flag, profile_url, headshot_url, created_at, updated_at)
+                VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12, $13, $14, $15, $16, $17)
+                RETURNING id, first_name, last_name, position, college, height_inches, weight_pounds, draft_year, draft_eligible, injury_status, injury_body_part, injury_expected_return, medical_flag, profile_url, headshot_url, created_at, updated_at
+                "#,
+                player_db.id,
+                player_db.first_name,
+                player_db.last_name,
+                player_db.position,
+                player_db.college,
+                player_db.height_inches,
+                player_db.weight_pounds,
+                player_db.draft_year,
+                player_db.draft_eligible,
+                player_db.injury_status,
+                player_db.injury_body_part,
+                player_db.injury_expected_return,
+                player_db.medical_flag,
+                player_db.profile_url,
+                player_db.headshot_url,
+                player_db.created_at,
+                player_db.updated_at
+            )
+            .fetch_one(&self.pool)
+            .await
+            .map_err(DbError::DatabaseError)?;
+
+            result.to_domain().map_err(Into::into)
+        })
         .await
-        .map_err(DbError::DatabaseError)?;
+    }
+
+    async fn create_many(&self, players: &[Player]) -> DomainResult<Vec<Player>> {
+        traced("player.create_many", async move {
+            let mut tx = self.pool.begin().await.map_err(DbError::DatabaseError)?;
+
+            let mut created = Vec::with_capacity(players.len());
+            for chunk in players.chunks(rows_per_insert(self.batch_size, 17)) {
+                let mut builder = QueryBuilder::new("INSERT INTO players (");
+                builder.push(PLAYER_COLUMNS).push(") ");
+                builder.push_values(
+                    chunk.iter().map(PlayerDb::from_domain),
+                    |mut row, player| {
+                        row.push_bind(player.id)
+                            .push_bind(player.first_name)
+                            .push_bind(player.last_name)
+                            .push_bind(player.position)
+                            .push_bind(player.college)
+                            .push_bind(player.height_inches)
+                            .push_bind(player.weight_pounds)
+                            .push_bind(player.draft_year)
+                            .push_bind(player.draft_eligible)
+                            .push_bind(player.injury_status)
+                            .push_bind(player.injury_body_part)
+                            .push_bind(player.injury_expected_return)
+                            .push_bind(player.medical_flag)
+                            .push_bind(player.profile_url)
+                            .push_bind(player.headshot_url)
+                            .push_bind(player.created_at)
+                            .push_bind(player.updated_at);
+                    },
+                );
+                builder.push(" RETURNING ").push(PLAYER_COLUMNS);
+
+                let rows = builder
+                    .build_query_as::<PlayerDb>()
+                    .fetch_all(&mut *tx)
+                    .await
+                    .map_err(DbError::DatabaseError)?;
+                for row in rows {
+                    created.push(row.to_domain()?);
+                }
+            }
+
+            tx.commit().await.map_err(DbError::DatabaseError)?;
 
-        result.to_domain().map_err(Into::into)
+            Ok(created)
+        })
+        .await
     }
 
     async fn find_by_id(&self, id: Uuid) -> DomainResult<Option<Player>> {
-        let result = sqlx::query_as!(
-            PlayerDb,
-            r#"
-            SELECT id, first_name, last_name, position, college, height_inches, weight_pounds, draft_year, draft_eligible, created_at, updated_at
-            FROM players
-            WHERE id = $1
-            "#,
-            id
-        )
-        .fetch_optional(&self.pool)
+        traced("player.find_by_id", async move {
+            let result = sqlx::query_as!(
+                PlayerDb,
+                r#"
+                SELECT id, first_name, last_name, position, college, height_inches, weight_pounds, draft_year, draft_eligible, injury_status, injury_body_part, injury_expected_return, medical_flag, profile_url, headshot_url, created_at, updated_at
+                FROM players
+                WHERE id = $1 AND deleted_at IS NULL
+                "#,
+                id
+            )
+            .fetch_optional(&self.pool)
+            .await
+            .map_err(DbError::DatabaseError)?;
+
+            match result {
+                Some(player_db) => Ok(Some(player_db.to_domain()?)),
+                None => Ok(None),
+            }
+        })
         .await
-        .map_err(DbError::DatabaseError)?;
+    }
 
-        match result {
-            Some(player_db) => Ok(Some(player_db.to_domain()?)),
-            None => Ok(None),
-        }
+    async fn find_by_ids(&self, ids: &[Uuid]) -> DomainResult<Vec<Player>> {
+        traced("player.find_by_ids", async move {
+            let results = sqlx::query_as!(
+                PlayerDb,
+                r#"
+                SELECT id, first_name, last_name, position, college, height_inches, weight_pounds, draft_year, draft_eligible, injury_status, injury_body_part, injury_expected_return, medical_flag, profile_url, headshot_url, created_at, updated_at
+                FROM players
+                WHERE id = ANY($1) AND deleted_at IS NULL
+                "#,
+                ids
+            )
+            .fetch_all(&self.read_pool)
+            .await
+            .map_err(DbError::DatabaseError)?;
+
+            results
+                .into_iter()
+                .map(|player_db| player_db.to_domain().map_err(Into::into))
+                .collect()
+        })
+        .await
     }
 
     async fn find_all(&self) -> DomainResult<Vec<Player>> {
-        let results = sqlx::query_as!(
-            PlayerDb,
-            r#"
-            SELECT id, first_name, last_name, position, college, height_inches, weight_pounds, draft_year, draft_eligible, created_at, updated_at
-            FROM players
-            ORDER BY last_name, first_name
-            "#
-        )
-        .fetch_all(&self.pool)
+        traced("player.find_all", async move {
+            let results = sqlx::query_as!(
+                PlayerDb,
+                r#"
+                SELECT id, first_name, last_name, position, college, height_inches, weight_pounds, draft_year, draft_eligible, injury_status, injury_body_part, injury_expected_return, medical_flag, profile_url, headshot_url, created_at, updated_at
+                FROM players
+                WHERE deleted_at IS NULL
+                ORDER BY last_name, first_name
+                "#
+            )
+            .fetch_all(&self.read_pool)
+            .await
+            .map_err(DbError::DatabaseError)?;
+
+            results
+                .into_iter()
+                .map(|player_db| player_db.to_domain().map_err(Into::into))
+                .collect()
+        })
         .await
-        .map_err(DbError::DatabaseError)?;
+    }
 
-        results
-            .into_iter()
-            .map(|player_db| player_db.to_domain().map_err(Into::into))
-            .collect()
+    async fn find_filtered(
+        &self,
+        filter: &PlayerFilter,
+        sort: &Sort<PlayerSortField>,
+        page: Option<PageRequest>,
+    ) -> DomainResult<Page<Player>> {
+        traced("player.find_filtered", async move {
+            let mut query = QueryBuilder::new(format!("SELECT {} FROM players", PLAYER_COLUMNS));
+            push_filter(&mut query, filter);
+            push_order_by(&mut query, sort, sort_column, "last_name, first_name, id");
+            push_page(&mut query, page);
+
+            let results = query
+                .build_query_as::<PlayerDb>()
+                .fetch_all(&self.read_pool)
+                .await
+                .map_err(DbError::DatabaseError)?;
+
+            let items = results
+                .into_iter()
+                .map(|player_db| player_db.to_domain().map_err(Into::into))
+                .collect::<DomainResult<Vec<_>>>()?;
+
+            let total = match page {
+                Some(_) => {
+                    let mut count = QueryBuilder::new("SELECT COUNT(*) FROM players");
+                    push_filter(&mut count, filter);
+                    count
+                        .build_query_scalar::<i64>()
+                        .fetch_one(&self.read_pool)
+                        .await
+                        .map_err(DbError::DatabaseError)?
+                }
+                None => items.len() as i64,
+            };
+
+            Ok(Page { items, total })
+        })
+        .await
     }
 
     async fn find_by_position(&self, position: Position) -> DomainResult<Vec<Player>> {
-        let position_str = crate::models::player::position_to_string(&position);
-
-        let results = sqlx::query_as!(
-            PlayerDb,
-            r#"
-            SELECT id, first_name, last_name, position, college, height_inches, weight_pounds, draft_year, draft_eligible, created_at, updated_at
-            FROM players
-            WHERE position = $1
-            ORDER BY last_name, first_name
-            "#,
-            position_str
-        )
-        .fetch_all(&self.pool)
-        .await
-        .map_err(DbError::DatabaseError)?;
+        traced("player.find_by_position", async move {
+            let position_str = crate::models::player::position_to_string(&position);
+
+            let results = sqlx::query_as!(
+                PlayerDb,
+                r#"
+                SELECT id, first_name, last_name, position, college, height_inches, weight_pounds, draft_year, draft_eligible, injury_status, injury_body_part, injury_expected_return, medical_flag, profile_url, headshot_url, created_at, updated_at
+                FROM players
+                WHERE position = $1 AND deleted_at IS NULL
+                ORDER BY last_name, first_name
+                "#,
+                position_str
+            )
+            .fetch_all(&self.read_pool)
+            .await
+            .map_err(DbError::DatabaseError)?;
 
-        results
-            .into_iter()
-            .map(|player_db| player_db.to_domain().map_err(Into::into))
-            .collect()
+            results
+                .into_iter()
+                .map(|player_db| player_db.to_domain().map_err(Into::into))
+                .collect()
+        })
+        .await
     }
 
     async fn find_by_draft_year(&self, year: i32) -> DomainResult<Vec<Player>> {
-        let results = sqlx::query_as!(
-            PlayerDb,
-            r#"
-            SELECT id, first_name, last_name, position, college, height_inches, weight_pounds, draft_year, draft_eligible, created_at, updated_at
-            FROM players
-            WHERE draft_year = $1
-            ORDER BY last_name, first_name
-            "#,
-            year
-        )
-        .fetch_all(&self.pool)
+        traced("player.find_by_draft_year", async move {
+            let results = sqlx::query_as!(
+                PlayerDb,
+                r#"
+                SELECT id, first_name, last_name, position, college, height_inches, weight_pounds, draft_year, draft_eligible, injury_status, injury_body_part, injury_expected_return, medical_flag, profile_url, headshot_url, created_at, updated_at
+                FROM players
+                WHERE draft_year = $1 AND deleted_at IS NULL
+                ORDER BY last_name, first_name
+                "#,
+                year
+            )
+            .fetch_all(&self.read_pool)
+            .await
+            .map_err(DbError::DatabaseError)?;
+
+            results
+                .into_iter()
+                .map(|player_db| player_db.to_domain().map_err(Into::into))
+                .collect()
+        })
         .await
-        .map_err(DbError::DatabaseError)?;
+    }
 
-        results
-            .into_iter()
-            .map(|player_db| player_db.to_domain().map_err(Into::into))
-            .collect()
+    async fn find_available_for_draft(&self, draft_id: Uuid) -> DomainResult<Vec<Player>> {
+        traced("player.find_available_for_draft", async move {
+            // Picks are live draft state, so this reads the primary rather
+            // than a replica that may not have the latest pick yet
+            let results = sqlx::query_as!(
+                PlayerDb,
+                r#"
+                SELECT p.id, p.first_name, p.last_name, p.position, p.college, p.height_inches, p.weight_pounds, p.draft_year, p.draft_eligible, p.injury_status, p.injury_body_part, p.injury_expected_return, p.medical_flag, p.profile_url, p.headshot_url, p.created_at, p.updated_at
+                FROM players p
+                JOIN drafts d ON d.id = $1 AND p.draft_year = d.year
+                WHERE p.deleted_at IS NULL
+                  AND NOT EXISTS (
+                      SELECT 1 FROM draft_picks dp
+                      WHERE dp.draft_id = d.id AND dp.player_id = p.id
+                  )
+                ORDER BY p.last_name, p.first_name
+                "#,
+                draft_id
+            )
+            .fetch_all(&self.pool)
+            .await
+            .map_err(DbError::DatabaseError)?;
+
+            results
+                .into_iter()
+                .map(|player_db| player_db.to_domain().map_err(Into::into))
+                .collect()
+        })
+        .await
     }
 
-    async fn find_draft_eligible(&self, year: i32) -> DomainResult<Vec<Player>> {
-        let results = sqlx::query_as!(
-            PlayerDb,
-            r#"
-            SELECT id, first_name, last_name, position, college, height_inches, weight_pounds, draft_year, draft_eligible, created_at, updated_at
-            FROM players
-            WHERE draft_eligible = true AND draft_year = $1
-            ORDER BY last_name, first_name
-            "#,
-            year
-        )
-        .fetch_all(&self.pool)
+    async fn find_by_draft_year_after(
+        &self,
+        year: i32,
+        after_id: Option<Uuid>,
+        limit: i64,
+    ) -> DomainResult<Vec<Player>> {
+        traced("player.find_by_draft_year_after", async move {
+            let results = sqlx::query_as!(
+                PlayerDb,
+                r#"
+                SELECT id, first_name, last_name, position, college, height_inches, weight_pounds, draft_year, draft_eligible, injury_status, injury_body_part, injury_expected_return, medical_flag, profile_url, headshot_url, created_at, updated_at
+                FROM players
+                WHERE draft_year = $1 AND deleted_at IS NULL
+                  AND ($2::uuid IS NULL OR (last_name, first_name, id) > (
+                      SELECT last_name, first_name, id FROM players WHERE id = $2
+                  ))
+                ORDER BY last_name, first_name, id
+                LIMIT $3
+                "#,
+                year,
+                after_id,
+                limit
+            )
+            .fetch_all(&self.read_pool)
+            .await
+            .map_err(DbError::DatabaseError)?;
+
+            results
+                .into_iter()
+                .map(|player_db| player_db.to_domain().map_err(Into::into))
+                .collect()
+        })
         .await
-        .map_err(DbError::DatabaseError)?;
+    }
 
-        results
-            .into_iter()
-            .map(|player_db| player_db.to_domain().map_err(Into::into))
-            .collect()
+    async fn find_draft_eligible(&self, year: i32) -> DomainResult<Vec<Player>> {
+        traced("player.find_draft_eligible", async move {
+            let results = sqlx::query_as!(
+                PlayerDb,
+                r#"
+                SELECT id, first_name, last_name, position, college, height_inches, weight_pounds, draft_year, draft_eligible, injury_status, injury_body_part, injury_expected_return, medical_flag, profile_url, headshot_url, created_at, updated_at
+                FROM players
+                WHERE draft_eligible = true AND draft_year = $1 AND deleted_at IS NULL
+                ORDER BY last_name, first_name
+                "#,
+                year
+            )
+            .fetch_all(&self.read_pool)
+            .await
+            .map_err(DbError::DatabaseError)?;
+
+            results
+                .into_iter()
+                .map(|player_db| player_db.to_domain().map_err(Into::into))
+                .collect()
+        })
+        .await
     }
 
     async fn update(&self, player: &Player) -> DomainResult<Player> {
-        let player_db = PlayerDb::from_domain(player);
-
-        let result = sqlx::query_as!(
-            PlayerDb,
-            r#"
-            UPDATE players
-            SET first_name = $2, last_name = $3, position = $4, college = $5,
-                height_inches = $6, weight_pounds = $7, draft_year = $8,
-                draft_eligible = $9, updated_at = NOW()
-            WHERE id = $1
-            RETURNING id, first_name, last_name, position, college, height_inches, weight_pounds, draft_year, draft_eligible, created_at, updated_at
-            "#,
-            player_db.id,
-            player_db.first_name,
-            player_db.last_name,
-            player_db.position,
-            player_db.college,
-            player_db.height_inches,
-            player_db.weight_pounds,
-            player_db.draft_year,
-            player_db.draft_eligible
-        )
-        .fetch_one(&self.pool)
+        traced("player.update", async move {
+            let player_db = PlayerDb::from_domain(player);
+
+            let result = sqlx::query_as!(
+                PlayerDb,
+                r#"
+                UPDATE players
+                SET first_name = $2, last_name = $3, position = $4, college = $5,
+                    height_inches = $6, weight_pounds = $7, draft_year = $8,
+                    draft_eligible = $9, injury_status = $10, injury_body_part = $11,
+                    injury_expected_return = $12, medical_flag = $13, profile_url = $14,
+                    headshot_url = $15, updated_at = NOW()
+                WHERE id = $1 AND deleted_at IS NULL
+                RETURNING id, first_name, last_name, position, college, height_inches, weight_pounds, draft_year, draft_eligible, injury_status, injury_body_part, injury_expected_return, medical_flag, profile_url, headshot_url, created_at, updated_at
+                "#,
+                player_db.id,
+                player_db.first_name,
+                player_db.last_name,
+                player_db.position,
+                player_db.college,
+                player_db.height_inches,
+                player_db.weight_pounds,
+                player_db.draft_year,
+                player_db.draft_eligible,
+                player_db.injury_status,
+                player_db.injury_body_part,
+                player_db.injury_expected_return,
+                player_db.medical_flag,
+                player_db.profile_url,
+                player_db.headshot_url
+            )
+            .fetch_one(&self.pool)
+            .await
+            .map_err(|e| {
+                if let sqlx::Error::RowNotFound = e {
+                    return DbError::NotFound(format!("Player with id {} not found", player_db.id));
+                }
+                if let sqlx::Error::Database(db_err) = &e {
+                    if db_err.is_unique_violation() {
+                        return DbError::DuplicateEntry(format!(
+                            "Player {} {} already exists in the {} draft class",
+                            player_db.first_name, player_db.last_name, player_db.draft_year
+                        ));
+                    }
+                }
+                DbError::DatabaseError(e)
+            })?;
+
+            result.to_domain().map_err(Into::into)
+        })
         .await
-        .map_err(|e| {
-            if let sqlx::Error::RowNotFound = e {
-                return DbError::NotFound(format!("Player with id {} not found", player_db.id));
+    }
+
+    async fn delete(&self, id: Uuid) -> DomainResult<()> {
+        traced("player.delete", async move {
+            let result = sqlx::query!(
+                r#"
+                DELETE FROM players
+                WHERE id = $1
+                "#,
+                id
+            )
+            .execute(&self.pool)
+            .await
+            .map_err(DbError::DatabaseError)?;
+
+            if result.rows_affected() == 0 {
+                return Err(DbError::NotFound(format!("Player with id {} not found", id)).into());
             }
-            DbError::DatabaseError(e)
-        })?;
 
-        result.to_domain().map_err(Into::into)
+            Ok(())
+        })
+        .await
     }
 
-    async fn delete(&self, id: Uuid) -> DomainResult<()> {
-        let result = sqlx::query!(
-            r#"
-            DELETE FROM players
-            WHERE id = $1
-            "#,
-            id
-        )
-        .execute(&self.pool)
+    async fn soft_delete(&self, id: Uuid) -> DomainResult<()> {
+        traced("player.soft_delete", async move {
+            let result = sqlx::query!(
+                "UPDATE players SET deleted_at = NOW() WHERE id = $1 AND deleted_at IS NULL",
+                id
+            )
+            .execute(&self.pool)
+            .await
+            .map_err(DbError::DatabaseError)?;
+
+            if result.rows_affected() == 0 {
+                return Err(DbError::NotFound(format!("Player with id {} not found", id)).into());
+            }
+
+            Ok(())
+        })
         .await
-        .map_err(DbError::DatabaseError)?;
+    }
 
-        if result.rows_affected() == 0 {
-            return Err(DbError::NotFound(format!("Player with id {} not found", id)).into());
-        }
+    async fn restore(&self, id: Uuid) -> DomainResult<Player> {
+        traced("player.restore", async move {
+            let result = sqlx::query_as!(
+                PlayerDb,
+                r#"
+                UPDATE players
+                SET deleted_at = NULL, updated_at = NOW()
+                WHERE id = $1 AND deleted_at IS NOT NULL
+                RETURNING id, first_name, last_name, position, college, height_inches, weight_pounds, draft_year, draft_eligible, injury_status, injury_body_part, injury_expected_return, medical_flag, profile_url, headshot_url, created_at, updated_at
+                "#,
+                id
+            )
+            .fetch_optional(&self.pool)
+            .await
+            .map_err(DbError::DatabaseError)?
+            .ok_or_else(|| DbError::NotFound(format!("No deleted player with id {}", id)))?;
 
-        Ok(())
+            result.to_domain().map_err(Into::into)
+        })
+        .await
     }
 }
 
@@ -276,6 +642,76 @@ mod tests {
         cleanup_players(&pool).await;
     }
 
+    #[tokio::test]
+    async fn test_find_by_ids() {
+        let pool = setup_test_pool().await;
+        cleanup_players(&pool).await;
+
+        let repo = SqlxPlayerRepository::new(pool.clone());
+
+        let player1 =
+            Player::new("John".to_string(), "Doe".to_string(), Position::QB, 2026).unwrap();
+        let player2 =
+            Player::new("Jane".to_string(), "Smith".to_string(), Position::WR, 2026).unwrap();
+        let player3 =
+            Player::new("Jim".to_string(), "Brown".to_string(), Position::RB, 2026).unwrap();
+        for player in [&player1, &player2, &player3] {
+            repo.create(player).await.unwrap();
+        }
+
+        let mut found: Vec<Uuid> = repo
+            .find_by_ids(&[player1.id, player3.id, Uuid::new_v4()])
+            .await
+            .unwrap()
+            .into_iter()
+            .map(|p| p.id)
+            .collect();
+        found.sort();
+        let mut expected = vec![player1.id, player3.id];
+        expected.sort();
+        assert_eq!(found, expected);
+
+        cleanup_players(&pool).await;
+    }
+
+    #[tokio::test]
+    async fn test_create_many_in_batches() {
+        let pool = setup_test_pool().await;
+        cleanup_players(&pool).await;
+
+        let repo = SqlxPlayerRepository::new(pool.clone()).with_batch_size(2);
+
+        let players: Vec<Player> = (1..=5)
+            .map(|n| {
+                Player::new(
+                    format!("Player{}", n),
+                    "Doe".to_string(),
+                    Position::QB,
+                    2026,
+                )
+                .unwrap()
+                .with_physical_stats(74, 220)
+                .unwrap()
+            })
+            .collect();
+
+        let created = repo.create_many(&players).await.unwrap();
+        assert_eq!(created.len(), 5);
+        assert_eq!(created[0].height_inches, Some(74));
+        assert_eq!(repo.find_by_draft_year(2026).await.unwrap().len(), 5);
+
+        // A failure in a later batch rolls back the earlier ones
+        let retry = vec![
+            Player::new("New".to_string(), "Guy".to_string(), Position::WR, 2026).unwrap(),
+            Player::new("Other".to_string(), "Guy".to_string(), Position::WR, 2026).unwrap(),
+            players[0].clone(),
+        ];
+        assert!(repo.create_many(&retry).await.is_err());
+        assert_eq!(repo.find_by_draft_year(2026).await.unwrap().len(), 5);
+
+        cleanup_players(&pool).await;
+    }
+
     #[tokio::test]
     async fn test_find_all() {
         let pool = setup_test_pool().await;
@@ -297,6 +733,113 @@ mod tests {
         cleanup_players(&pool).await;
     }
 
+    #[tokio::test]
+    async fn test_find_filtered() {
+        let pool = setup_test_pool().await;
+        cleanup_players(&pool).await;
+
+        let repo = SqlxPlayerRepository::new(pool.clone());
+
+        let big_qb = Player::new("Sam".to_string(), "Adams".to_string(), Position::QB, 2026)
+            .unwrap()
+            .with_college("Ohio State".to_string())
+            .unwrap()
+            .with_physical_stats(77, 230)
+            .unwrap();
+        let small_qb = Player::new("Ty".to_string(), "Baker".to_string(), Position::QB, 2026)
+            .unwrap()
+            .with_college("Oregon".to_string())
+            .unwrap()
+            .with_physical_stats(71, 205)
+            .unwrap();
+        let receiver = Player::new("Jo".to_string(), "Clark".to_string(), Position::WR, 2026)
+            .unwrap()
+            .with_college("Ohio State".to_string())
+            .unwrap();
+        for player in [&big_qb, &small_qb, &receiver] {
+            repo.create(player).await.unwrap();
+        }
+        sqlx::query!(
+            "INSERT INTO combine_results (player_id, year) VALUES ($1, 2026)",
+            small_qb.id
+        )
+        .execute(&pool)
+        .await
+        .unwrap();
+
+        let filter = PlayerFilter {
+            position: Some(Position::QB),
+            ..Default::default()
+        };
+        let page = repo
+            .find_filtered(&filter, &Sort::default(), None)
+            .await
+            .unwrap();
+        assert_eq!(page.total, 2);
+
+        let filter = PlayerFilter {
+            college: Some("ohio".to_string()),
+            min_height_inches: Some(74),
+            ..Default::default()
+        };
+        let page = repo
+            .find_filtered(&filter, &Sort::default(), None)
+            .await
+            .unwrap();
+        assert_eq!(page.items.len(), 1);
+        assert_eq!(page.items[0].id, big_qb.id);
+
+        let filter = PlayerFilter {
+            has_combine_results: Some(true),
+            ..Default::default()
+        };
+        let page = repo
+            .find_filtered(&filter, &Sort::default(), None)
+            .await
+            .unwrap();
+        assert_eq!(page.items.len(), 1);
+        assert_eq!(page.items[0].id, small_qb.id);
+
+        let filter = PlayerFilter {
+            has_combine_results: Some(false),
+            ..Default::default()
+        };
+        let page = repo
+            .find_filtered(
+                &filter,
+                &Sort::default(),
+                Some(PageRequest::new(1, 1).unwrap()),
+            )
+            .await
+            .unwrap();
+        assert_eq!(page.total, 2);
+        assert_eq!(page.items.len(), 1);
+        assert_eq!(page.items[0].id, big_qb.id);
+
+        let filter = PlayerFilter {
+            college: Some("%".to_string()),
+            ..Default::default()
+        };
+        let page = repo
+            .find_filtered(&filter, &Sort::default(), None)
+            .await
+            .unwrap();
+        assert!(page.items.is_empty());
+
+        let filter = PlayerFilter {
+            position: Some(Position::QB),
+            ids: Some(vec![big_qb.id, small_qb.id, Uuid::new_v4()]),
+            ..Default::default()
+        };
+        let page = repo
+            .find_filtered(&filter, &Sort::default(), None)
+            .await
+            .unwrap();
+        assert_eq!(page.total, 2);
+
+        cleanup_players(&pool).await;
+    }
+
     #[tokio::test]
     async fn test_find_by_position() {
         let pool = setup_test_pool().await;
@@ -408,4 +951,182 @@ mod tests {
 
         cleanup_players(&pool).await;
     }
+
+    #[tokio::test]
+    async fn test_soft_delete_and_restore_player() {
+        let pool = setup_test_pool().await;
+        cleanup_players(&pool).await;
+
+        let repo = SqlxPlayerRepository::new(pool.clone());
+
+        let player =
+            Player::new("John".to_string(), "Doe".to_string(), Position::QB, 2026).unwrap();
+        let created = repo.create(&player).await.unwrap();
+
+        repo.soft_delete(created.id).await.unwrap();
+
+        // Hidden from reads and updates, but the row is kept
+        assert!(repo.find_by_id(created.id).await.unwrap().is_none());
+        assert!(repo.find_all().await.unwrap().is_empty());
+        assert!(repo.find_by_draft_year(2026).await.unwrap().is_empty());
+        assert!(repo.update(&created).await.is_err());
+        assert!(repo.soft_delete(created.id).await.is_err());
+
+        let restored = repo.restore(created.id).await.unwrap();
+        assert_eq!(restored.id, created.id);
+        assert!(repo.find_by_id(created.id).await.unwrap().is_some());
+
+        // Only deleted players can be restored
+        assert!(repo.restore(created.id).await.is_err());
+        assert!(repo.restore(Uuid::new_v4()).await.is_err());
+
+        cleanup_players(&pool).await;
+    }
+
+    #[tokio::test]
+    async fn test_find_by_draft_year_after() {
+        let pool = setup_test_pool().await;
+        cleanup_players(&pool).await;
+
+        let repo = SqlxPlayerRepository::new(pool.clone());
+
+        for (first, last) in [
+            ("Cam", "Ward"),
+            ("Abdul", "Carter"),
+            ("Shedeur", "Sanders"),
+            ("Travis", "Hunter"),
+        ] {
+            let player =
+                Player::new(first.to_string(), last.to_string(), Position::QB, 2026).unwrap();
+            repo.create(&player).await.unwrap();
+        }
+        let other_year =
+            Player::new("Next".to_string(), "Year".to_string(), Position::QB, 2027).unwrap();
+        repo.create(&other_year).await.unwrap();
+
+        let mut expected = repo.find_by_draft_year(2026).await.unwrap();
+        expected.sort_by(|a, b| {
+            (&a.last_name, &a.first_name, a.id).cmp(&(&b.last_name, &b.first_name, b.id))
+        });
+        let expected: Vec<Uuid> = expected.into_iter().map(|p| p.id).collect();
+        assert_eq!(expected.len(), 4);
+
+        let mut walked = Vec::new();
+        let mut cursor = None;
+        loop {
+            let page = repo
+                .find_by_draft_year_after(2026, cursor, 3)
+                .await
+                .unwrap();
+            if page.is_empty() {
+                break;
+            }
+            cursor = page.last().map(|p| p.id);
+            walked.extend(page.into_iter().map(|p| p.id));
+        }
+        assert_eq!(walked, expected);
+
+        // Soft-deleted players drop out, but still work as a cursor
+        repo.soft_delete(walked[1]).await.unwrap();
+        let page = repo
+            .find_by_draft_year_after(2026, Some(walked[1]), 10)
+            .await
+            .unwrap();
+        assert_eq!(page.iter().map(|p| p.id).collect::<Vec<_>>(), walked[2..]);
+
+        cleanup_players(&pool).await;
+    }
+
+    #[tokio::test]
+    async fn test_find_available_for_draft() {
+        use crate::repositories::{
+            SqlxDraftPickRepository, SqlxDraftRepository, SqlxTeamRepository,
+        };
+        use domain::models::{Conference, Division, Draft, DraftPick, Team};
+        use domain::repositories::{DraftPickRepository, DraftRepository, TeamRepository};
+
+        let pool = setup_test_pool().await;
+        for table in ["draft_picks", "drafts", "teams"] {
+            sqlx::query(&format!("DELETE FROM {}", table))
+                .execute(&pool)
+                .await
+                .expect("Failed to cleanup");
+        }
+        cleanup_players(&pool).await;
+
+        let repo = SqlxPlayerRepository::new(pool.clone());
+        let taken = repo
+            .create(
+                &Player::new("Alan".to_string(), "Taken".to_string(), Position::QB, 2026).unwrap(),
+            )
+            .await
+            .unwrap();
+        let open = repo
+            .create(
+                &Player::new("Olly".to_string(), "Open".to_string(), Position::WR, 2026).unwrap(),
+            )
+            .await
+            .unwrap();
+        let hidden = repo
+            .create(
+                &Player::new("Hugh".to_string(), "Hidden".to_string(), Position::TE, 2026).unwrap(),
+            )
+            .await
+            .unwrap();
+        repo.soft_delete(hidden.id).await.unwrap();
+        repo.create(
+            &Player::new(
+                "Nate".to_string(),
+                "NextYear".to_string(),
+                Position::RB,
+                2027,
+            )
+            .unwrap(),
+        )
+        .await
+        .unwrap();
+
+        let draft = SqlxDraftRepository::new(pool.clone())
+            .create(&Draft::new("Test Draft".to_string(), 2026, 7, 32).unwrap())
+            .await
+            .unwrap();
+        let team = SqlxTeamRepository::new(pool.clone())
+            .create(
+                &Team::new(
+                    "Dallas Cowboys".to_string(),
+                    "DAL".to_string(),
+                    "Dallas".to_string(),
+                    Conference::NFC,
+                    Division::NFCEast,
+                )
+                .unwrap(),
+            )
+            .await
+            .unwrap();
+        let mut pick = DraftPick::new(draft.id, 1, 1, 1, team.id).unwrap();
+        pick.make_pick(taken.id).unwrap();
+        SqlxDraftPickRepository::new(pool.clone())
+            .create(&pick)
+            .await
+            .unwrap();
+
+        // Picked, soft-deleted and other-year players are left out
+        let available = repo.find_available_for_draft(draft.id).await.unwrap();
+        let ids: Vec<Uuid> = available.iter().map(|p| p.id).collect();
+        assert_eq!(ids, vec![open.id]);
+
+        assert!(repo
+            .find_available_for_draft(Uuid::new_v4())
+            .await
+            .unwrap()
+            .is_empty());
+
+        for table in ["draft_picks", "drafts", "teams"] {
+            sqlx::query(&format!("DELETE FROM {}", table))
+                .execute(&pool)
+                .await
+                .expect("Failed to cleanup");
+        }
+        cleanup_players(&pool).await;
+    }
 }
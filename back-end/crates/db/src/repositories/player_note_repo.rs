@@ -0,0 +1,160 @@
+use async_trait::async_trait;
+use sqlx::PgPool;
+use uuid::Uuid;
+
+use domain::errors::DomainResult;
+use domain::models::PlayerNote;
+use domain::repositories::PlayerNoteRepository;
+
+use crate::errors::DbError;
+use crate::instrument::traced;
+use crate::models::PlayerNoteDb;
+
+/// SQLx implementation of PlayerNoteRepository
+pub struct SqlxPlayerNoteRepository {
+    pool: PgPool,
+}
+
+impl SqlxPlayerNoteRepository {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl PlayerNoteRepository for SqlxPlayerNoteRepository {
+    async fn create(&self, note: &PlayerNote) -> DomainResult<PlayerNote> {
+        traced("player_note.create", async move {
+            let note_db = PlayerNoteDb::from_domain(note);
+
+            let result = sqlx::query_as!(
+                PlayerNoteDb,
+                r#"
+                INSERT INTO player_notes (id, player_id, source, note_date, tag, content, created_at, updated_at)
+                VALUES ($1, $2, $3, $4, $5, $6, $7, $8)
+                RETURNING id, player_id, source, note_date, tag, content, created_at, updated_at
+                "#,
+                note_db.id,
+                note_db.player_id,
+                note_db.source,
+                note_db.note_date,
+                note_db.tag,
+                note_db.content,
+                note_db.created_at,
+                note_db.updated_at
+            )
+            .fetch_one(&self.pool)
+            .await
+            .map_err(|e| {
+                if let sqlx::Error::Database(db_err) = &e {
+                    if db_err.is_foreign_key_violation() {
+                        return DbError::NotFound(format!(
+                            "Player with id {} not found",
+                            note.player_id
+                        ));
+                    }
+                }
+                DbError::DatabaseError(e)
+            })?;
+
+            result.to_domain().map_err(Into::into)
+        })
+        .await
+    }
+
+    async fn find_by_id(&self, id: Uuid) -> DomainResult<Option<PlayerNote>> {
+        traced("player_note.find_by_id", async move {
+            let result = sqlx::query_as!(
+                PlayerNoteDb,
+                r#"
+                SELECT id, player_id, source, note_date, tag, content, created_at, updated_at
+                FROM player_notes
+                WHERE id = $1
+                "#,
+                id
+            )
+            .fetch_optional(&self.pool)
+            .await
+            .map_err(DbError::DatabaseError)?;
+
+            match result {
+                Some(note_db) => Ok(Some(note_db.to_domain()?)),
+                None => Ok(None),
+            }
+        })
+        .await
+    }
+
+    async fn find_by_player_id(&self, player_id: Uuid) -> DomainResult<Vec<PlayerNote>> {
+        traced("player_note.find_by_player_id", async move {
+            let results = sqlx::query_as!(
+                PlayerNoteDb,
+                r#"
+                SELECT id, player_id, source, note_date, tag, content, created_at, updated_at
+                FROM player_notes
+                WHERE player_id = $1
+                ORDER BY note_date DESC, created_at DESC
+                "#,
+                player_id
+            )
+            .fetch_all(&self.pool)
+            .await
+            .map_err(DbError::DatabaseError)?;
+
+            results
+                .into_iter()
+                .map(|r| r.to_domain().map_err(Into::into))
+                .collect()
+        })
+        .await
+    }
+
+    async fn update(&self, note: &PlayerNote) -> DomainResult<PlayerNote> {
+        traced("player_note.update", async move {
+            let note_db = PlayerNoteDb::from_domain(note);
+
+            let result = sqlx::query_as!(
+                PlayerNoteDb,
+                r#"
+                UPDATE player_notes
+                SET source = $2,
+                    note_date = $3,
+                    tag = $4,
+                    content = $5,
+                    updated_at = $6
+                WHERE id = $1
+                RETURNING id, player_id, source, note_date, tag, content, created_at, updated_at
+                "#,
+                note_db.id,
+                note_db.source,
+                note_db.note_date,
+                note_db.tag,
+                note_db.content,
+                note_db.updated_at
+            )
+            .fetch_one(&self.pool)
+            .await
+            .map_err(DbError::DatabaseError)?;
+
+            result.to_domain().map_err(Into::into)
+        })
+        .await
+    }
+
+    async fn delete(&self, id: Uuid) -> DomainResult<()> {
+        traced("player_note.delete", async move {
+            sqlx::query!(
+                r#"
+                DELETE FROM player_notes WHERE id = $1
+                "#,
+                id
+            )
+            .execute(&self.pool)
+            .await
+            .map_err(DbError::DatabaseError)?;
+
+            Ok(())
+        })
+        .await
+    }
+}
@@ -1,14 +1,20 @@
 use async_trait::async_trait;
 use chrono::{DateTime, NaiveDate, Utc};
-use sqlx::{FromRow, PgPool};
+use sqlx::{FromRow, PgPool, QueryBuilder};
 use uuid::Uuid;
 
 use domain::errors::DomainResult;
-use domain::models::{PlayerRankingWithSource, ProspectRanking};
+use domain::models::{
+    ConsensusRanking, Page, PageRequest, PlayerRankingWithSource, ProspectRanking,
+    RankingSortField, Sort,
+};
 use domain::repositories::ProspectRankingRepository;
 
-use crate::errors::DbError;
+use crate::errors::{DbError, DbResult};
+use crate::instrument::traced;
+use crate::models::player::string_to_position;
 use crate::models::ProspectRankingDb;
+use crate::query::{push_order_by, push_page, DEFAULT_BATCH_SIZE};
 
 /// Row type for the JOIN query returning ranking + source name
 #[derive(Debug, FromRow)]
@@ -20,180 +26,384 @@ struct PlayerRankingWithSourceRow {
     scraped_at: NaiveDate,
 }
 
+/// Row type for the consensus board aggregate
+#[derive(Debug, FromRow)]
+struct ConsensusRankingRow {
+    consensus_rank: i64,
+    player_id: Uuid,
+    first_name: String,
+    last_name: String,
+    position: String,
+    source_count: i64,
+    average_rank: f64,
+    best_rank: i32,
+    worst_rank: i32,
+    rank_std_dev: f64,
+}
+
+impl ConsensusRankingRow {
+    fn into_domain(self) -> DbResult<ConsensusRanking> {
+        Ok(ConsensusRanking {
+            consensus_rank: self.consensus_rank,
+            player_id: self.player_id,
+            first_name: self.first_name,
+            last_name: self.last_name,
+            position: string_to_position(&self.position)?,
+            source_count: self.source_count,
+            average_rank: self.average_rank,
+            best_rank: self.best_rank,
+            worst_rank: self.worst_rank,
+            rank_std_dev: self.rank_std_dev,
+        })
+    }
+}
+
+fn ranking_sort_column(field: RankingSortField) -> &'static str {
+    match field {
+        RankingSortField::SourceName => "rs.name",
+        RankingSortField::Rank => "pr.rank",
+        RankingSortField::ScrapedAt => "pr.scraped_at",
+    }
+}
+
 /// SQLx implementation of ProspectRankingRepository
 pub struct SqlxProspectRankingRepository {
     pool: PgPool,
+    /// Serves collection reads; the primary unless a replica is set
+    read_pool: PgPool,
+    batch_size: usize,
 }
 
 impl SqlxProspectRankingRepository {
     pub fn new(pool: PgPool) -> Self {
-        Self { pool }
+        Self {
+            read_pool: pool.clone(),
+            pool,
+            batch_size: DEFAULT_BATCH_SIZE,
+        }
+    }
+
+    /// Rows per INSERT statement in `create_batch`
+    pub fn with_batch_size(mut self, batch_size: usize) -> Self {
+        self.batch_size = batch_size;
+        self
+    }
+
+    /// Send collection reads to a read replica
+    pub fn with_read_pool(mut self, read_pool: PgPool) -> Self {
+        self.read_pool = read_pool;
+        self
     }
 }
 
 #[async_trait]
 impl ProspectRankingRepository for SqlxProspectRankingRepository {
     async fn create_batch(&self, rankings: &[ProspectRanking]) -> DomainResult<usize> {
-        if rankings.is_empty() {
-            return Ok(0);
-        }
+        traced("prospect_ranking.create_batch", async move {
+            if rankings.is_empty() {
+                return Ok(0);
+            }
 
-        let ids: Vec<Uuid> = rankings.iter().map(|r| r.id).collect();
-        let source_ids: Vec<Uuid> = rankings.iter().map(|r| r.ranking_source_id).collect();
-        let player_ids: Vec<Uuid> = rankings.iter().map(|r| r.player_id).collect();
-        let ranks: Vec<i32> = rankings.iter().map(|r| r.rank).collect();
-        let scraped_dates: Vec<NaiveDate> = rankings.iter().map(|r| r.scraped_at).collect();
-        let created_dates: Vec<DateTime<Utc>> = rankings.iter().map(|r| r.created_at).collect();
-
-        let result = sqlx::query!(
-            r#"
-            INSERT INTO prospect_rankings (id, ranking_source_id, player_id, rank, scraped_at, created_at)
-            SELECT * FROM UNNEST($1::uuid[], $2::uuid[], $3::uuid[], $4::int4[], $5::date[], $6::timestamptz[])
-            "#,
-            &ids,
-            &source_ids,
-            &player_ids,
-            &ranks,
-            &scraped_dates,
-            &created_dates
-        )
-        .execute(&self.pool)
-        .await
-        .map_err(|e| {
-            if let sqlx::Error::Database(db_err) = &e {
-                if db_err.is_unique_violation() {
-                    return DbError::DuplicateEntry(
-                        "Duplicate ranking entry in batch".to_string(),
-                    );
-                }
+            let mut tx = self.pool.begin().await.map_err(DbError::DatabaseError)?;
+
+            let mut inserted = 0;
+            for chunk in rankings.chunks(self.batch_size.max(1)) {
+                let ids: Vec<Uuid> = chunk.iter().map(|r| r.id).collect();
+                let source_ids: Vec<Uuid> = chunk.iter().map(|r| r.ranking_source_id).collect();
+                let player_ids: Vec<Uuid> = chunk.iter().map(|r| r.player_id).collect();
+                let ranks: Vec<i32> = chunk.iter().map(|r| r.rank).collect();
+                let scraped_dates: Vec<NaiveDate> = chunk.iter().map(|r| r.scraped_at).collect();
+                let created_dates: Vec<DateTime<Utc>> = chunk.iter().map(|r| r.created_at).collect();
+
+                let result = sqlx::query!(
+                    r#"
+                    INSERT INTO prospect_rankings (id, ranking_source_id, player_id, rank, scraped_at, created_at)
+                    SELECT * FROM UNNEST($1::uuid[], $2::uuid[], $3::uuid[], $4::int4[], $5::date[], $6::timestamptz[])
+                    "#,
+                    &ids,
+                    &source_ids,
+                    &player_ids,
+                    &ranks,
+                    &scraped_dates,
+                    &created_dates
+                )
+                .execute(&mut *tx)
+                .await
+                .map_err(|e| {
+                    if let sqlx::Error::Database(db_err) = &e {
+                        if db_err.is_unique_violation() {
+                            return DbError::DuplicateEntry(
+                                "Duplicate ranking entry in batch".to_string(),
+                            );
+                        }
+                    }
+                    DbError::DatabaseError(e)
+                })?;
+
+                inserted += result.rows_affected() as usize;
             }
-            DbError::DatabaseError(e)
-        })?;
 
-        Ok(result.rows_affected() as usize)
+            tx.commit().await.map_err(DbError::DatabaseError)?;
+
+            Ok(inserted)
+        })
+        .await
     }
 
     async fn find_by_player_with_source(
         &self,
         player_id: Uuid,
     ) -> DomainResult<Vec<PlayerRankingWithSource>> {
-        let results = sqlx::query_as!(
-            PlayerRankingWithSourceRow,
-            r#"
-            SELECT pr.player_id, rs.name as source_name, rs.id as source_id, pr.rank, pr.scraped_at
-            FROM prospect_rankings pr
-            JOIN ranking_sources rs ON pr.ranking_source_id = rs.id
-            WHERE pr.player_id = $1
-            ORDER BY pr.rank
-            "#,
-            player_id
-        )
-        .fetch_all(&self.pool)
-        .await
-        .map_err(DbError::DatabaseError)?;
+        traced("prospect_ranking.find_by_player_with_source", async move {
+            let results = sqlx::query_as!(
+                PlayerRankingWithSourceRow,
+                r#"
+                SELECT pr.player_id, rs.name as source_name, rs.id as source_id, pr.rank, pr.scraped_at
+                FROM prospect_rankings pr
+                JOIN ranking_sources rs ON pr.ranking_source_id = rs.id
+                WHERE pr.player_id = $1
+                ORDER BY pr.rank
+                "#,
+                player_id
+            )
+            .fetch_all(&self.pool)
+            .await
+            .map_err(DbError::DatabaseError)?;
 
-        Ok(results.into_iter().map(row_to_domain).collect())
+            Ok(results.into_iter().map(row_to_domain).collect())
+        })
+        .await
     }
 
     async fn find_for_players_with_source(
         &self,
         player_ids: &[Uuid],
     ) -> DomainResult<Vec<PlayerRankingWithSource>> {
-        if player_ids.is_empty() {
-            return Ok(vec![]);
-        }
-        // Use runtime query (no macro) so no SQLx offline cache entry is required.
-        // WHERE player_id = ANY($1) filters at the DB level — only rankings for the
-        // given available players are fetched, avoiding loading all-time rankings.
-        let results = sqlx::query_as::<_, PlayerRankingWithSourceRow>(
-            r#"
-            SELECT pr.player_id, rs.name as source_name, rs.id as source_id, pr.rank, pr.scraped_at
-            FROM prospect_rankings pr
-            JOIN ranking_sources rs ON pr.ranking_source_id = rs.id
-            WHERE pr.player_id = ANY($1::uuid[])
-            ORDER BY rs.name, pr.rank
-            "#,
-        )
-        .bind(player_ids)
-        .fetch_all(&self.pool)
-        .await
-        .map_err(DbError::DatabaseError)?;
+        traced("prospect_ranking.find_for_players_with_source", async move {
+            if player_ids.is_empty() {
+                return Ok(vec![]);
+            }
+            // Use runtime query (no macro) so no SQLx offline cache entry is required.
+            // WHERE player_id = ANY($1) filters at the DB level — only rankings for the
+            // given available players are fetched, avoiding loading all-time rankings.
+            let results = sqlx::query_as::<_, PlayerRankingWithSourceRow>(
+                r#"
+                SELECT pr.player_id, rs.name as source_name, rs.id as source_id, pr.rank, pr.scraped_at
+                FROM prospect_rankings pr
+                JOIN ranking_sources rs ON pr.ranking_source_id = rs.id
+                WHERE pr.player_id = ANY($1::uuid[])
+                ORDER BY rs.name, pr.rank
+                "#,
+            )
+            .bind(player_ids)
+            .fetch_all(&self.read_pool)
+            .await
+            .map_err(DbError::DatabaseError)?;
 
-        Ok(results.into_iter().map(row_to_domain).collect())
+            Ok(results.into_iter().map(row_to_domain).collect())
+        })
+        .await
     }
 
     async fn find_all_with_source(&self) -> DomainResult<Vec<PlayerRankingWithSource>> {
-        let results = sqlx::query_as!(
-            PlayerRankingWithSourceRow,
-            r#"
-            SELECT pr.player_id, rs.name as source_name, rs.id as source_id, pr.rank, pr.scraped_at
-            FROM prospect_rankings pr
-            JOIN ranking_sources rs ON pr.ranking_source_id = rs.id
-            ORDER BY rs.name, pr.rank
-            LIMIT 10000
-            "#
-        )
-        .fetch_all(&self.pool)
+        traced("prospect_ranking.find_all_with_source", async move {
+            let results = sqlx::query_as!(
+                PlayerRankingWithSourceRow,
+                r#"
+                SELECT pr.player_id, rs.name as source_name, rs.id as source_id, pr.rank, pr.scraped_at
+                FROM prospect_rankings pr
+                JOIN ranking_sources rs ON pr.ranking_source_id = rs.id
+                ORDER BY rs.name, pr.rank
+                LIMIT 10000
+                "#
+            )
+            .fetch_all(&self.read_pool)
+            .await
+            .map_err(DbError::DatabaseError)?;
+
+            Ok(results.into_iter().map(row_to_domain).collect())
+        })
         .await
-        .map_err(DbError::DatabaseError)?;
+    }
 
-        Ok(results.into_iter().map(row_to_domain).collect())
+    async fn find_sorted_with_source(
+        &self,
+        sort: &Sort<RankingSortField>,
+        page: Option<PageRequest>,
+    ) -> DomainResult<Page<PlayerRankingWithSource>> {
+        traced("prospect_ranking.find_sorted_with_source", async move {
+            let mut query = QueryBuilder::new(
+                r#"
+                SELECT pr.player_id, rs.name as source_name, rs.id as source_id, pr.rank, pr.scraped_at
+                FROM prospect_rankings pr
+                JOIN ranking_sources rs ON pr.ranking_source_id = rs.id
+                "#,
+            );
+            push_order_by(
+                &mut query,
+                sort,
+                ranking_sort_column,
+                "rs.name, pr.rank, pr.player_id",
+            );
+            push_page(&mut query, page);
+
+            let results = query
+                .build_query_as::<PlayerRankingWithSourceRow>()
+                .fetch_all(&self.read_pool)
+                .await
+                .map_err(DbError::DatabaseError)?;
+            let items: Vec<_> = results.into_iter().map(row_to_domain).collect();
+
+            let total = match page {
+                Some(_) => sqlx::query_scalar!(
+                    r#"
+                    SELECT COUNT(*) as "count!"
+                    FROM prospect_rankings pr
+                    JOIN ranking_sources rs ON pr.ranking_source_id = rs.id
+                    "#
+                )
+                .fetch_one(&self.read_pool)
+                .await
+                .map_err(DbError::DatabaseError)?,
+                None => items.len() as i64,
+            };
+
+            Ok(Page { items, total })
+        })
+        .await
     }
 
-    async fn find_by_player(&self, player_id: Uuid) -> DomainResult<Vec<ProspectRanking>> {
-        let results = sqlx::query_as!(
-            ProspectRankingDb,
-            r#"
-            SELECT id, ranking_source_id, player_id, rank, scraped_at, created_at
-            FROM prospect_rankings
-            WHERE player_id = $1
-            ORDER BY rank
-            "#,
-            player_id
-        )
-        .fetch_all(&self.pool)
+    async fn find_consensus(&self, draft_year: Option<i32>) -> DomainResult<Vec<ConsensusRanking>> {
+        traced("prospect_ranking.find_consensus", async move {
+            // Runtime query (no macro) so no SQLx offline cache entry is required
+            let results = sqlx::query_as::<_, ConsensusRankingRow>(
+                r#"
+                SELECT
+                    ROW_NUMBER() OVER (ORDER BY AVG(pr.rank), MIN(pr.rank), p.last_name, p.id) as consensus_rank,
+                    p.id as player_id, p.first_name, p.last_name, p.position,
+                    COUNT(*) as source_count,
+                    AVG(pr.rank)::float8 as average_rank,
+                    MIN(pr.rank) as best_rank,
+                    MAX(pr.rank) as worst_rank,
+                    COALESCE(STDDEV_POP(pr.rank), 0)::float8 as rank_std_dev
+                FROM prospect_rankings pr
+                JOIN players p ON p.id = pr.player_id
+                WHERE p.deleted_at IS NULL AND ($1::int IS NULL OR p.draft_year = $1)
+                GROUP BY p.id
+                ORDER BY consensus_rank
+                "#,
+            )
+            .bind(draft_year)
+            .fetch_all(&self.read_pool)
+            .await
+            .map_err(DbError::DatabaseError)?;
+
+            results
+                .into_iter()
+                .map(|r| r.into_domain().map_err(Into::into))
+                .collect()
+        })
         .await
-        .map_err(DbError::DatabaseError)?;
+    }
+
+    async fn find_by_player(&self, player_id: Uuid) -> DomainResult<Vec<ProspectRanking>> {
+        traced("prospect_ranking.find_by_player", async move {
+            let results = sqlx::query_as!(
+                ProspectRankingDb,
+                r#"
+                SELECT id, ranking_source_id, player_id, rank, scraped_at, created_at
+                FROM prospect_rankings
+                WHERE player_id = $1
+                ORDER BY rank
+                "#,
+                player_id
+            )
+            .fetch_all(&self.read_pool)
+            .await
+            .map_err(DbError::DatabaseError)?;
 
-        results
-            .into_iter()
-            .map(|r| r.to_domain().map_err(Into::into))
-            .collect()
+            results
+                .into_iter()
+                .map(|r| r.to_domain().map_err(Into::into))
+                .collect()
+        })
+        .await
     }
 
     async fn find_by_source(&self, source_id: Uuid) -> DomainResult<Vec<ProspectRanking>> {
-        let results = sqlx::query_as!(
-            ProspectRankingDb,
-            r#"
-            SELECT id, ranking_source_id, player_id, rank, scraped_at, created_at
-            FROM prospect_rankings
-            WHERE ranking_source_id = $1
-            ORDER BY rank
-            "#,
-            source_id
-        )
-        .fetch_all(&self.pool)
+        traced("prospect_ranking.find_by_source", async move {
+            let results = sqlx::query_as!(
+                ProspectRankingDb,
+                r#"
+                SELECT id, ranking_source_id, player_id, rank, scraped_at, created_at
+                FROM prospect_rankings
+                WHERE ranking_source_id = $1
+                ORDER BY rank
+                "#,
+                source_id
+            )
+            .fetch_all(&self.read_pool)
+            .await
+            .map_err(DbError::DatabaseError)?;
+
+            results
+                .into_iter()
+                .map(|r| r.to_domain().map_err(Into::into))
+                .collect()
+        })
         .await
-        .map_err(DbError::DatabaseError)?;
+    }
+
+    async fn find_by_source_after(
+        &self,
+        source_id: Uuid,
+        after_id: Option<Uuid>,
+        limit: i64,
+    ) -> DomainResult<Vec<ProspectRanking>> {
+        traced("prospect_ranking.find_by_source_after", async move {
+            let results = sqlx::query_as!(
+                ProspectRankingDb,
+                r#"
+                SELECT id, ranking_source_id, player_id, rank, scraped_at, created_at
+                FROM prospect_rankings
+                WHERE ranking_source_id = $1
+                  AND ($2::uuid IS NULL OR (rank, id) > (
+                      SELECT rank, id FROM prospect_rankings WHERE id = $2
+                  ))
+                ORDER BY rank, id
+                LIMIT $3
+                "#,
+                source_id,
+                after_id,
+                limit
+            )
+            .fetch_all(&self.read_pool)
+            .await
+            .map_err(DbError::DatabaseError)?;
 
-        results
-            .into_iter()
-            .map(|r| r.to_domain().map_err(Into::into))
-            .collect()
+            results
+                .into_iter()
+                .map(|r| r.to_domain().map_err(Into::into))
+                .collect()
+        })
+        .await
     }
 
     async fn delete_by_source(&self, source_id: Uuid) -> DomainResult<u64> {
-        let result = sqlx::query!(
-            r#"
-            DELETE FROM prospect_rankings WHERE ranking_source_id = $1
-            "#,
-            source_id
-        )
-        .execute(&self.pool)
-        .await
-        .map_err(DbError::DatabaseError)?;
+        traced("prospect_ranking.delete_by_source", async move {
+            let result = sqlx::query!(
+                r#"
+                DELETE FROM prospect_rankings WHERE ranking_source_id = $1
+                "#,
+                source_id
+            )
+            .execute(&self.pool)
+            .await
+            .map_err(DbError::DatabaseError)?;
 
-        Ok(result.rows_affected())
+            Ok(result.rows_affected())
+        })
+        .await
     }
 }
 
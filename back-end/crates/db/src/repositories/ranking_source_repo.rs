@@ -7,111 +7,135 @@ use domain::models::RankingSource;
 use domain::repositories::RankingSourceRepository;
 
 use crate::errors::DbError;
+use crate::instrument::traced;
 use crate::models::RankingSourceDb;
 
 /// SQLx implementation of RankingSourceRepository
 pub struct SqlxRankingSourceRepository {
     pool: PgPool,
+    /// Serves collection reads; the primary unless a replica is set
+    read_pool: PgPool,
 }
 
 impl SqlxRankingSourceRepository {
     pub fn new(pool: PgPool) -> Self {
-        Self { pool }
+        Self {
+            read_pool: pool.clone(),
+            pool,
+        }
+    }
+
+    /// Send collection reads to a read replica
+    pub fn with_read_pool(mut self, read_pool: PgPool) -> Self {
+        self.read_pool = read_pool;
+        self
     }
 }
 
 #[async_trait]
 impl RankingSourceRepository for SqlxRankingSourceRepository {
     async fn create(&self, source: &RankingSource) -> DomainResult<RankingSource> {
-        let source_db = RankingSourceDb::from_domain(source);
+        traced("ranking_source.create", async move {
+            let source_db = RankingSourceDb::from_domain(source);
 
-        let result = sqlx::query_as!(
-            RankingSourceDb,
-            r#"
-            INSERT INTO ranking_sources (id, name, url, description, created_at, updated_at)
-            VALUES ($1, $2, $3, $4, $5, $6)
-            RETURNING id, name, url, description, created_at, updated_at
-            "#,
-            source_db.id,
-            source_db.name,
-            source_db.url,
-            source_db.description,
-            source_db.created_at,
-            source_db.updated_at
-        )
-        .fetch_one(&self.pool)
-        .await
-        .map_err(|e| {
-            if let sqlx::Error::Database(db_err) = &e {
-                if db_err.is_unique_violation() {
-                    return DbError::DuplicateEntry(format!(
-                        "Ranking source '{}' already exists",
-                        source.name
-                    ));
+            let result = sqlx::query_as!(
+                RankingSourceDb,
+                r#"
+                INSERT INTO ranking_sources (id, name, url, description, created_at, updated_at)
+                VALUES ($1, $2, $3, $4, $5, $6)
+                RETURNING id, name, url, description, created_at, updated_at
+                "#,
+                source_db.id,
+                source_db.name,
+                source_db.url,
+                source_db.description,
+                source_db.created_at,
+                source_db.updated_at
+            )
+            .fetch_one(&self.pool)
+            .await
+            .map_err(|e| {
+                if let sqlx::Error::Database(db_err) = &e {
+                    if db_err.is_unique_violation() {
+                        return DbError::DuplicateEntry(format!(
+                            "Ranking source '{}' already exists",
+                            source.name
+                        ));
+                    }
                 }
-            }
-            DbError::DatabaseError(e)
-        })?;
+                DbError::DatabaseError(e)
+            })?;
 
-        result.to_domain().map_err(Into::into)
+            result.to_domain().map_err(Into::into)
+        })
+        .await
     }
 
     async fn find_by_id(&self, id: Uuid) -> DomainResult<Option<RankingSource>> {
-        let result = sqlx::query_as!(
-            RankingSourceDb,
-            r#"
-            SELECT id, name, url, description, created_at, updated_at
-            FROM ranking_sources
-            WHERE id = $1
-            "#,
-            id
-        )
-        .fetch_optional(&self.pool)
-        .await
-        .map_err(DbError::DatabaseError)?;
+        traced("ranking_source.find_by_id", async move {
+            let result = sqlx::query_as!(
+                RankingSourceDb,
+                r#"
+                SELECT id, name, url, description, created_at, updated_at
+                FROM ranking_sources
+                WHERE id = $1
+                "#,
+                id
+            )
+            .fetch_optional(&self.pool)
+            .await
+            .map_err(DbError::DatabaseError)?;
 
-        match result {
-            Some(db) => Ok(Some(db.to_domain()?)),
-            None => Ok(None),
-        }
+            match result {
+                Some(db) => Ok(Some(db.to_domain()?)),
+                None => Ok(None),
+            }
+        })
+        .await
     }
 
     async fn find_by_name(&self, name: &str) -> DomainResult<Option<RankingSource>> {
-        let result = sqlx::query_as!(
-            RankingSourceDb,
-            r#"
-            SELECT id, name, url, description, created_at, updated_at
-            FROM ranking_sources
-            WHERE name = $1
-            "#,
-            name
-        )
-        .fetch_optional(&self.pool)
-        .await
-        .map_err(DbError::DatabaseError)?;
+        traced("ranking_source.find_by_name", async move {
+            let result = sqlx::query_as!(
+                RankingSourceDb,
+                r#"
+                SELECT id, name, url, description, created_at, updated_at
+                FROM ranking_sources
+                WHERE name = $1
+                "#,
+                name
+            )
+            .fetch_optional(&self.pool)
+            .await
+            .map_err(DbError::DatabaseError)?;
 
-        match result {
-            Some(db) => Ok(Some(db.to_domain()?)),
-            None => Ok(None),
-        }
+            match result {
+                Some(db) => Ok(Some(db.to_domain()?)),
+                None => Ok(None),
+            }
+        })
+        .await
     }
 
     async fn find_all(&self) -> DomainResult<Vec<RankingSource>> {
-        let results = sqlx::query_as!(
-            RankingSourceDb,
-            r#"
-            SELECT id, name, url, description, created_at, updated_at
-            FROM ranking_sources
-            ORDER BY name
-            "#
-        )
-        .fetch_all(&self.pool)
-        .await
-        .map_err(DbError::DatabaseError)?;
+        traced("ranking_source.find_all", async move {
+            let results = sqlx::query_as!(
+                RankingSourceDb,
+                r#"
+                SELECT id, name, url, description, created_at, updated_at
+                FROM ranking_sources
+                ORDER BY name
+                "#
+            )
+            .fetch_all(&self.read_pool)
+            .await
+            .map_err(DbError::DatabaseError)?;
 
-        results
-            .into_iter()
-            .map(|r| r.to_domain().map_err(Into::into))
-            .collect()
+            results
+                .into_iter()
+                .map(|r| r.to_domain().map_err(Into::into))
+                .collect()
+        })
+        .await
     }
 }
@@ -1,129 +1,219 @@
 use async_trait::async_trait;
-use sqlx::PgPool;
+use sqlx::{PgPool, Postgres, QueryBuilder};
 use uuid::Uuid;
 
 use domain::errors::DomainResult;
-use domain::models::ScoutingReport;
+use domain::models::{Page, PageRequest, ScoutingReport, ScoutingReportFilter};
 use domain::repositories::ScoutingReportRepository;
 
 use crate::errors::DbError;
+use crate::instrument::traced;
 use crate::models::ScoutingReportDb;
+use crate::query::push_page;
+
+const REPORT_COLUMNS: &str = "id, player_id, team_id, grade, notes, fit_grade, injury_concern, character_concern, created_at, updated_at";
+
+fn push_filter(builder: &mut QueryBuilder<'_, Postgres>, filter: &ScoutingReportFilter) {
+    builder.push(" WHERE deleted_at IS NULL");
+    if let Some(team_id) = filter.team_id {
+        builder.push(" AND team_id = ").push_bind(team_id);
+    }
+    if let Some(min_grade) = filter.min_grade {
+        builder.push(" AND grade >= ").push_bind(min_grade);
+    }
+    if let Some(fit_grade) = filter.fit_grade {
+        builder
+            .push(" AND fit_grade = ")
+            .push_bind(fit_grade.as_str());
+    }
+    if let Some(concerns) = filter.concerns {
+        builder.push(if concerns {
+            " AND (injury_concern OR character_concern)"
+        } else {
+            " AND NOT (injury_concern OR character_concern)"
+        });
+    }
+}
 
 /// SQLx implementation of ScoutingReportRepository
 pub struct SqlxScoutingReportRepository {
     pool: PgPool,
+    /// Serves collection reads; the primary unless a replica is set
+    read_pool: PgPool,
 }
 
 impl SqlxScoutingReportRepository {
     pub fn new(pool: PgPool) -> Self {
-        Self { pool }
+        Self {
+            read_pool: pool.clone(),
+            pool,
+        }
+    }
+
+    /// Send collection reads to a read replica
+    pub fn with_read_pool(mut self, read_pool: PgPool) -> Self {
+        self.read_pool = read_pool;
+        self
     }
 }
 
 #[async_trait]
 impl ScoutingReportRepository for SqlxScoutingReportRepository {
     async fn create(&self, report: &ScoutingReport) -> DomainResult<ScoutingReport> {
-        let report_db = ScoutingReportDb::from_domain(report);
-
-        let result = sqlx::query_as!(
-            ScoutingReportDb,
-            r#"
-            INSERT INTO scouting_reports
-            (id, player_id, team_id, grade, notes, fit_grade, injury_concern, character_concern, created_at, updated_at)
-            VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10)
-            RETURNING id, player_id, team_id, grade, notes, fit_grade, injury_concern, character_concern, created_at, updated_at
-            "#,
-            report_db.id,
-            report_db.player_id,
-            report_db.team_id,
-            report_db.grade,
-            report_db.notes,
-            report_db.fit_grade,
-            report_db.injury_concern,
-            report_db.character_concern,
-            report_db.created_at,
-            report_db.updated_at
-        )
-        .fetch_one(&self.pool)
-        .await
-        .map_err(|e| {
-            if let sqlx::Error::Database(db_err) = &e {
-                if db_err.is_unique_violation() {
-                    return DbError::DuplicateEntry(format!(
-                        "Scouting report for team {} and player {} already exists",
-                        report.team_id, report.player_id
-                    ));
-                }
-                if db_err.is_foreign_key_violation() {
-                    return DbError::NotFound("Team or player not found".to_string());
+        traced("scouting_report.create", async move {
+            let report_db = ScoutingReportDb::from_domain(report);
+
+            let result = sqlx::query_as!(
+                ScoutingReportDb,
+                r#"
+                INSERT INTO scouting_reports
+                (id, player_id, team_id, grade, notes, fit_grade, injury_concern, character_concern, created_at, updated_at)
+                VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10)
+                RETURNING id, player_id, team_id, grade, notes, fit_grade, injury_concern, character_concern, created_at, updated_at
+                "#,
+                report_db.id,
+                report_db.player_id,
+                report_db.team_id,
+                report_db.grade,
+                report_db.notes,
+                report_db.fit_grade,
+                report_db.injury_concern,
+                report_db.character_concern,
+                report_db.created_at,
+                report_db.updated_at
+            )
+            .fetch_one(&self.pool)
+            .await
+            .map_err(|e| {
+                if let sqlx::Error::Database(db_err) = &e {
+                    if db_err.is_unique_violation() {
+                        return DbError::DuplicateEntry(format!(
+                            "Scouting report for team {} and player {} already exists",
+                            report.team_id, report.player_id
+                        ));
+                    }
+                    if db_err.is_foreign_key_violation() {
+                        return DbError::NotFound("Team or player not found".to_string());
+                    }
                 }
-            }
-            DbError::DatabaseError(e)
-        })?;
+                DbError::DatabaseError(e)
+            })?;
 
-        result.to_domain().map_err(Into::into)
+            result.to_domain().map_err(Into::into)
+        })
+        .await
     }
 
     async fn find_by_id(&self, id: Uuid) -> DomainResult<Option<ScoutingReport>> {
-        let result = sqlx::query_as!(
-            ScoutingReportDb,
-            r#"
-            SELECT id, player_id, team_id, grade, notes, fit_grade, injury_concern, character_concern, created_at, updated_at
-            FROM scouting_reports
-            WHERE id = $1
-            "#,
-            id
-        )
-        .fetch_optional(&self.pool)
-        .await
-        .map_err(DbError::DatabaseError)?;
+        traced("scouting_report.find_by_id", async move {
+            let result = sqlx::query_as!(
+                ScoutingReportDb,
+                r#"
+                SELECT id, player_id, team_id, grade, notes, fit_grade, injury_concern, character_concern, created_at, updated_at
+                FROM scouting_reports
+                WHERE id = $1 AND deleted_at IS NULL
+                "#,
+                id
+            )
+            .fetch_optional(&self.pool)
+            .await
+            .map_err(DbError::DatabaseError)?;
 
-        match result {
-            Some(report_db) => Ok(Some(report_db.to_domain()?)),
-            None => Ok(None),
-        }
+            match result {
+                Some(report_db) => Ok(Some(report_db.to_domain()?)),
+                None => Ok(None),
+            }
+        })
+        .await
     }
 
     async fn find_by_team_id(&self, team_id: Uuid) -> DomainResult<Vec<ScoutingReport>> {
-        let results = sqlx::query_as!(
-            ScoutingReportDb,
-            r#"
-            SELECT id, player_id, team_id, grade, notes, fit_grade, injury_concern, character_concern, created_at, updated_at
-            FROM scouting_reports
-            WHERE team_id = $1
-            ORDER BY grade DESC
-            "#,
-            team_id
-        )
-        .fetch_all(&self.pool)
-        .await
-        .map_err(DbError::DatabaseError)?;
+        traced("scouting_report.find_by_team_id", async move {
+            let results = sqlx::query_as!(
+                ScoutingReportDb,
+                r#"
+                SELECT id, player_id, team_id, grade, notes, fit_grade, injury_concern, character_concern, created_at, updated_at
+                FROM scouting_reports
+                WHERE team_id = $1 AND deleted_at IS NULL
+                ORDER BY grade DESC
+                "#,
+                team_id
+            )
+            .fetch_all(&self.read_pool)
+            .await
+            .map_err(DbError::DatabaseError)?;
 
-        results
-            .into_iter()
-            .map(|r| r.to_domain().map_err(Into::into))
-            .collect()
+            results
+                .into_iter()
+                .map(|r| r.to_domain().map_err(Into::into))
+                .collect()
+        })
+        .await
     }
 
     async fn find_by_player_id(&self, player_id: Uuid) -> DomainResult<Vec<ScoutingReport>> {
-        let results = sqlx::query_as!(
-            ScoutingReportDb,
-            r#"
-            SELECT id, player_id, team_id, grade, notes, fit_grade, injury_concern, character_concern, created_at, updated_at
-            FROM scouting_reports
-            WHERE player_id = $1
-            ORDER BY grade DESC
-            "#,
-            player_id
-        )
-        .fetch_all(&self.pool)
+        traced("scouting_report.find_by_player_id", async move {
+            let results = sqlx::query_as!(
+                ScoutingReportDb,
+                r#"
+                SELECT id, player_id, team_id, grade, notes, fit_grade, injury_concern, character_concern, created_at, updated_at
+                FROM scouting_reports
+                WHERE player_id = $1 AND deleted_at IS NULL
+                ORDER BY grade DESC
+                "#,
+                player_id
+            )
+            .fetch_all(&self.read_pool)
+            .await
+            .map_err(DbError::DatabaseError)?;
+
+            results
+                .into_iter()
+                .map(|r| r.to_domain().map_err(Into::into))
+                .collect()
+        })
         .await
-        .map_err(DbError::DatabaseError)?;
+    }
 
-        results
-            .into_iter()
-            .map(|r| r.to_domain().map_err(Into::into))
-            .collect()
+    async fn find_filtered(
+        &self,
+        filter: &ScoutingReportFilter,
+        page: Option<PageRequest>,
+    ) -> DomainResult<Page<ScoutingReport>> {
+        traced("scouting_report.find_filtered", async move {
+            let mut query =
+                QueryBuilder::new(format!("SELECT {} FROM scouting_reports", REPORT_COLUMNS));
+            push_filter(&mut query, filter);
+            query.push(" ORDER BY grade DESC, id");
+            push_page(&mut query, page);
+
+            let results = query
+                .build_query_as::<ScoutingReportDb>()
+                .fetch_all(&self.read_pool)
+                .await
+                .map_err(DbError::DatabaseError)?;
+
+            let items = results
+                .into_iter()
+                .map(|r| r.to_domain().map_err(Into::into))
+                .collect::<DomainResult<Vec<_>>>()?;
+
+            let total = match page {
+                Some(_) => {
+                    let mut count = QueryBuilder::new("SELECT COUNT(*) FROM scouting_reports");
+                    push_filter(&mut count, filter);
+                    count
+                        .build_query_scalar::<i64>()
+                        .fetch_one(&self.read_pool)
+                        .await
+                        .map_err(DbError::DatabaseError)?
+                }
+                None => items.len() as i64,
+            };
+
+            Ok(Page { items, total })
+        })
+        .await
     }
 
     async fn find_by_team_and_player(
@@ -131,69 +221,150 @@ impl ScoutingReportRepository for SqlxScoutingReportRepository {
         team_id: Uuid,
         player_id: Uuid,
     ) -> DomainResult<Option<ScoutingReport>> {
-        let result = sqlx::query_as!(
-            ScoutingReportDb,
-            r#"
-            SELECT id, player_id, team_id, grade, notes, fit_grade, injury_concern, character_concern, created_at, updated_at
-            FROM scouting_reports
-            WHERE team_id = $1 AND player_id = $2
-            "#,
-            team_id,
-            player_id
-        )
-        .fetch_optional(&self.pool)
+        traced("scouting_report.find_by_team_and_player", async move {
+            let result = sqlx::query_as!(
+                ScoutingReportDb,
+                r#"
+                SELECT id, player_id, team_id, grade, notes, fit_grade, injury_concern, character_concern, created_at, updated_at
+                FROM scouting_reports
+                WHERE team_id = $1 AND player_id = $2 AND deleted_at IS NULL
+                "#,
+                team_id,
+                player_id
+            )
+            .fetch_optional(&self.pool)
+            .await
+            .map_err(DbError::DatabaseError)?;
+
+            match result {
+                Some(report_db) => Ok(Some(report_db.to_domain()?)),
+                None => Ok(None),
+            }
+        })
         .await
-        .map_err(DbError::DatabaseError)?;
+    }
 
-        match result {
-            Some(report_db) => Ok(Some(report_db.to_domain()?)),
-            None => Ok(None),
-        }
+    async fn find_reports_by_team_for_players(
+        &self,
+        team_id: Uuid,
+        player_ids: &[Uuid],
+    ) -> DomainResult<Vec<ScoutingReport>> {
+        traced("scouting_report.find_reports_by_team_for_players", async move {
+            let results = sqlx::query_as!(
+                ScoutingReportDb,
+                r#"
+                SELECT id, player_id, team_id, grade, notes, fit_grade, injury_concern, character_concern, created_at, updated_at
+                FROM scouting_reports
+                WHERE team_id = $1 AND player_id = ANY($2) AND deleted_at IS NULL
+                ORDER BY grade DESC
+                "#,
+                team_id,
+                player_ids
+            )
+            .fetch_all(&self.read_pool)
+            .await
+            .map_err(DbError::DatabaseError)?;
+
+            results
+                .into_iter()
+                .map(|r| r.to_domain().map_err(Into::into))
+                .collect()
+        })
+        .await
     }
 
     async fn update(&self, report: &ScoutingReport) -> DomainResult<ScoutingReport> {
-        let report_db = ScoutingReportDb::from_domain(report);
-
-        let result = sqlx::query_as!(
-            ScoutingReportDb,
-            r#"
-            UPDATE scouting_reports
-            SET grade = $2,
-                notes = $3,
-                fit_grade = $4,
-                injury_concern = $5,
-                character_concern = $6,
-                updated_at = $7
-            WHERE id = $1
-            RETURNING id, player_id, team_id, grade, notes, fit_grade, injury_concern, character_concern, created_at, updated_at
-            "#,
-            report_db.id,
-            report_db.grade,
-            report_db.notes,
-            report_db.fit_grade,
-            report_db.injury_concern,
-            report_db.character_concern,
-            report_db.updated_at
-        )
-        .fetch_one(&self.pool)
-        .await
-        .map_err(DbError::DatabaseError)?;
+        traced("scouting_report.update", async move {
+            let report_db = ScoutingReportDb::from_domain(report);
+
+            let result = sqlx::query_as!(
+                ScoutingReportDb,
+                r#"
+                UPDATE scouting_reports
+                SET grade = $2,
+                    notes = $3,
+                    fit_grade = $4,
+                    injury_concern = $5,
+                    character_concern = $6,
+                    updated_at = $7
+                WHERE id = $1 AND deleted_at IS NULL
+                RETURNING id, player_id, team_id, grade, notes, fit_grade, injury_concern, character_concern, created_at, updated_at
+                "#,
+                report_db.id,
+                report_db.grade,
+                report_db.notes,
+                report_db.fit_grade,
+                report_db.injury_concern,
+                report_db.character_concern,
+                report_db.updated_at
+            )
+            .fetch_one(&self.pool)
+            .await
+            .map_err(DbError::DatabaseError)?;
 
-        result.to_domain().map_err(Into::into)
+            result.to_domain().map_err(Into::into)
+        })
+        .await
     }
 
     async fn delete(&self, id: Uuid) -> DomainResult<()> {
-        sqlx::query!(
-            r#"
-            DELETE FROM scouting_reports WHERE id = $1
-            "#,
-            id
-        )
-        .execute(&self.pool)
+        traced("scouting_report.delete", async move {
+            sqlx::query!(
+                r#"
+                DELETE FROM scouting_reports WHERE id = $1
+                "#,
+                id
+            )
+            .execute(&self.pool)
+            .await
+            .map_err(DbError::DatabaseError)?;
+
+            Ok(())
+        })
         .await
-        .map_err(DbError::DatabaseError)?;
+    }
+
+    async fn soft_delete(&self, id: Uuid) -> DomainResult<()> {
+        traced("scouting_report.soft_delete", async move {
+            let result = sqlx::query!(
+                "UPDATE scouting_reports SET deleted_at = NOW() WHERE id = $1 AND deleted_at IS NULL",
+                id
+            )
+            .execute(&self.pool)
+            .await
+            .map_err(DbError::DatabaseError)?;
+
+            if result.rows_affected() == 0 {
+                return Err(
+                    DbError::NotFound(format!("Scouting report with id {} not found", id)).into(),
+                );
+            }
 
-        Ok(())
+            Ok(())
+        })
+        .await
+    }
+
+    async fn restore(&self, id: Uuid) -> DomainResult<ScoutingReport> {
+        traced("scouting_report.restore", async move {
+            let result = sqlx::query_as!(
+                ScoutingReportDb,
+                r#"
+                UPDATE scouting_reports
+                SET deleted_at = NULL, updated_at = NOW()
+                WHERE id = $1 AND deleted_at IS NOT NULL
+                RETURNING id, player_id, team_id, grade, notes, fit_grade, injury_concern, character_concern, created_at, updated_at
+                "#,
+                id
+            )
+            .fetch_optional(&self.pool)
+            .await
+            .map_err(DbError::DatabaseError)?
+            .ok_or_else(|| DbError::NotFound(format!("No deleted scouting report with id {}", id)))?;
+
+            result.to_domain().map_err(Into::into)
+        })
+        .await
     }
 }
 
@@ -358,6 +529,72 @@ mod tests {
         cleanup_teams(&pool).await;
     }
 
+    #[tokio::test]
+    async fn test_find_filtered() {
+        let pool = setup_test_pool().await;
+        cleanup_scouting_reports(&pool).await;
+        cleanup_players(&pool).await;
+        cleanup_teams(&pool).await;
+
+        let player = create_test_player(&pool).await;
+        let team = create_test_team(&pool, "TST").await;
+        let other_team = create_test_team(&pool, "OTH").await;
+        let repo = SqlxScoutingReportRepository::new(pool.clone());
+
+        repo.create(
+            &ScoutingReport::new(player.id, team.id, 9.0)
+                .unwrap()
+                .with_fit_grade(FitGrade::A),
+        )
+        .await
+        .unwrap();
+        repo.create(
+            &ScoutingReport::new(player.id, other_team.id, 6.5)
+                .unwrap()
+                .with_fit_grade(FitGrade::A)
+                .with_character_concern(true),
+        )
+        .await
+        .unwrap();
+
+        let all = repo
+            .find_filtered(&ScoutingReportFilter::default(), None)
+            .await
+            .unwrap();
+        assert_eq!(all.total, 2);
+        assert_eq!(all.items[0].grade, 9.0);
+
+        let filter = ScoutingReportFilter {
+            fit_grade: Some(FitGrade::A),
+            concerns: Some(true),
+            ..Default::default()
+        };
+        let found = repo.find_filtered(&filter, None).await.unwrap();
+        assert_eq!(found.items.len(), 1);
+        assert_eq!(found.items[0].team_id, other_team.id);
+
+        let filter = ScoutingReportFilter {
+            team_id: Some(team.id),
+            min_grade: Some(9.5),
+            ..Default::default()
+        };
+        assert_eq!(repo.find_filtered(&filter, None).await.unwrap().total, 0);
+
+        let page = repo
+            .find_filtered(
+                &ScoutingReportFilter::default(),
+                Some(PageRequest::new(2, 1).unwrap()),
+            )
+            .await
+            .unwrap();
+        assert_eq!(page.total, 2);
+        assert_eq!(page.items[0].grade, 6.5);
+
+        cleanup_scouting_reports(&pool).await;
+        cleanup_players(&pool).await;
+        cleanup_teams(&pool).await;
+    }
+
     #[tokio::test]
     async fn test_find_by_player_id() {
         let pool = setup_test_pool().await;
@@ -417,6 +654,53 @@ mod tests {
         cleanup_teams(&pool).await;
     }
 
+    #[tokio::test]
+    async fn test_find_reports_by_team_for_players() {
+        let pool = setup_test_pool().await;
+        cleanup_scouting_reports(&pool).await;
+        cleanup_players(&pool).await;
+        cleanup_teams(&pool).await;
+
+        let player_repo = SqlxPlayerRepository::new(pool.clone());
+        let mut players = Vec::new();
+        for last_name in ["One", "Two", "Three"] {
+            let player = Player::new(
+                "Test".to_string(),
+                last_name.to_string(),
+                domain::models::Position::QB,
+                2026,
+            )
+            .unwrap();
+            players.push(player_repo.create(&player).await.unwrap());
+        }
+        let [first, second, other] = players.try_into().unwrap();
+        let team = create_test_team(&pool, "TST").await;
+        let rival = create_test_team(&pool, "RVL").await;
+        let repo = SqlxScoutingReportRepository::new(pool.clone());
+
+        for (player_id, team_id, grade) in [
+            (first.id, team.id, 7.0),
+            (second.id, team.id, 9.0),
+            (other.id, team.id, 8.0),
+            (first.id, rival.id, 5.0),
+        ] {
+            repo.create(&ScoutingReport::new(player_id, team_id, grade).unwrap())
+                .await
+                .unwrap();
+        }
+
+        let found = repo
+            .find_reports_by_team_for_players(team.id, &[first.id, second.id])
+            .await
+            .unwrap();
+        let found: Vec<(Uuid, f64)> = found.iter().map(|r| (r.player_id, r.grade)).collect();
+        assert_eq!(found, vec![(second.id, 9.0), (first.id, 7.0)]);
+
+        cleanup_scouting_reports(&pool).await;
+        cleanup_players(&pool).await;
+        cleanup_teams(&pool).await;
+    }
+
     #[tokio::test]
     async fn test_update_scouting_report() {
         let pool = setup_test_pool().await;
@@ -473,6 +757,39 @@ mod tests {
         cleanup_teams(&pool).await;
     }
 
+    #[tokio::test]
+    async fn test_soft_delete_and_restore_scouting_report() {
+        let pool = setup_test_pool().await;
+        cleanup_scouting_reports(&pool).await;
+        cleanup_players(&pool).await;
+        cleanup_teams(&pool).await;
+
+        let player = create_test_player(&pool).await;
+        let team = create_test_team(&pool, "TST").await;
+        let repo = SqlxScoutingReportRepository::new(pool.clone());
+
+        let report = ScoutingReport::new(player.id, team.id, 8.5).unwrap();
+        let created = repo.create(&report).await.unwrap();
+
+        repo.soft_delete(created.id).await.unwrap();
+
+        assert!(repo.find_by_id(created.id).await.unwrap().is_none());
+        assert!(repo.find_by_team_id(team.id).await.unwrap().is_empty());
+        assert!(repo
+            .find_by_team_and_player(team.id, player.id)
+            .await
+            .unwrap()
+            .is_none());
+
+        let restored = repo.restore(created.id).await.unwrap();
+        assert_eq!(restored.id, created.id);
+        assert!(repo.restore(created.id).await.is_err());
+
+        cleanup_scouting_reports(&pool).await;
+        cleanup_players(&pool).await;
+        cleanup_teams(&pool).await;
+    }
+
     #[tokio::test]
     async fn test_duplicate_team_player() {
         let pool = setup_test_pool().await;
@@ -0,0 +1,424 @@
+use async_trait::async_trait;
+use sqlx::PgPool;
+use uuid::Uuid;
+
+use domain::errors::DomainResult;
+use domain::models::SessionParticipant;
+use domain::repositories::SessionParticipantRepository;
+
+use crate::errors::DbError;
+use crate::instrument::traced;
+use crate::models::SessionParticipantDb;
+
+/// SQLx implementation of SessionParticipantRepository
+pub struct SqlxSessionParticipantRepository {
+    pool: PgPool,
+}
+
+impl SqlxSessionParticipantRepository {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl SessionParticipantRepository for SqlxSessionParticipantRepository {
+    async fn create(&self, participant: &SessionParticipant) -> DomainResult<SessionParticipant> {
+        traced("session_participant.create", async move {
+            let participant_db = SessionParticipantDb::from_domain(participant);
+            let mut tx = self.pool.begin().await.map_err(DbError::DatabaseError)?;
+
+            let result = sqlx::query_as!(
+                SessionParticipantDb,
+                r#"
+                INSERT INTO session_participants
+                    (id, session_id, user_id, display_name, team_ids, joined_at, updated_at)
+                VALUES ($1, $2, $3, $4, $5, $6, $7)
+                RETURNING id, session_id, user_id, display_name, team_ids, joined_at, updated_at
+                "#,
+                participant_db.id,
+                participant_db.session_id,
+                participant_db.user_id,
+                participant_db.display_name,
+                &participant_db.team_ids,
+                participant_db.joined_at,
+                participant_db.updated_at
+            )
+            .fetch_one(&mut *tx)
+            .await
+            .map_err(|e| {
+                if let sqlx::Error::Database(db_err) = &e {
+                    if db_err.is_unique_violation() {
+                        return DbError::DuplicateEntry(format!(
+                            "Participant '{}' or their account has already joined session {}",
+                            participant.display_name, participant.session_id
+                        ));
+                    }
+                    if db_err.is_foreign_key_violation() {
+                        return DbError::NotFound(format!(
+                            "Session {} or user {} not found",
+                            participant.session_id, participant.user_id
+                        ));
+                    }
+                }
+                DbError::DatabaseError(e)
+            })?;
+
+            // The primary key on (session_id, team_id) settles two joins
+            // racing for the same team
+            sqlx::query!(
+                r#"
+                INSERT INTO session_participant_teams (session_id, team_id, participant_id)
+                SELECT $1, team_id, $2 FROM unnest($3::uuid[]) AS team_id
+                "#,
+                participant_db.session_id,
+                participant_db.id,
+                &participant_db.team_ids
+            )
+            .execute(&mut *tx)
+            .await
+            .map_err(|e| {
+                if let sqlx::Error::Database(db_err) = &e {
+                    if db_err.is_unique_violation() {
+                        return DbError::DuplicateEntry(format!(
+                            "A team requested by '{}' is already controlled in session {}",
+                            participant.display_name, participant.session_id
+                        ));
+                    }
+                }
+                DbError::DatabaseError(e)
+            })?;
+
+            tx.commit().await.map_err(DbError::DatabaseError)?;
+
+            Ok(result.to_domain())
+        })
+        .await
+    }
+
+    async fn find_by_id(&self, id: Uuid) -> DomainResult<Option<SessionParticipant>> {
+        traced("session_participant.find_by_id", async move {
+            let result = sqlx::query_as!(
+                SessionParticipantDb,
+                r#"
+                SELECT id, session_id, user_id, display_name, team_ids, joined_at, updated_at
+                FROM session_participants
+                WHERE id = $1
+                "#,
+                id
+            )
+            .fetch_optional(&self.pool)
+            .await
+            .map_err(DbError::DatabaseError)?;
+
+            Ok(result.map(SessionParticipantDb::to_domain))
+        })
+        .await
+    }
+
+    async fn list_by_session(&self, session_id: Uuid) -> DomainResult<Vec<SessionParticipant>> {
+        traced("session_participant.list_by_session", async move {
+            let results = sqlx::query_as!(
+                SessionParticipantDb,
+                r#"
+                SELECT id, session_id, user_id, display_name, team_ids, joined_at, updated_at
+                FROM session_participants
+                WHERE session_id = $1
+                ORDER BY joined_at ASC
+                "#,
+                session_id
+            )
+            .fetch_all(&self.pool)
+            .await
+            .map_err(DbError::DatabaseError)?;
+
+            Ok(results
+                .into_iter()
+                .map(SessionParticipantDb::to_domain)
+                .collect())
+        })
+        .await
+    }
+
+    async fn delete(&self, id: Uuid) -> DomainResult<()> {
+        traced("session_participant.delete", async move {
+            sqlx::query!(
+                r#"
+                DELETE FROM session_participants WHERE id = $1
+                "#,
+                id
+            )
+            .execute(&self.pool)
+            .await
+            .map_err(DbError::DatabaseError)?;
+
+            Ok(())
+        })
+        .await
+    }
+
+    async fn leave(&self, participant: &SessionParticipant) -> DomainResult<()> {
+        traced("session_participant.leave", async move {
+            let mut tx = self.pool.begin().await.map_err(DbError::DatabaseError)?;
+
+            sqlx::query!(
+                r#"
+                DELETE FROM session_participants WHERE id = $1
+                "#,
+                participant.id
+            )
+            .execute(&mut *tx)
+            .await
+            .map_err(DbError::DatabaseError)?;
+
+            sqlx::query!(
+                r#"
+                UPDATE draft_sessions
+                SET controlled_team_ids = ARRAY(
+                        SELECT t FROM unnest(controlled_team_ids) AS t
+                        WHERE t <> ALL($2::uuid[])
+                    ),
+                    updated_at = NOW()
+                WHERE id = $1
+                "#,
+                participant.session_id,
+                &participant.team_ids
+            )
+            .execute(&mut *tx)
+            .await
+            .map_err(DbError::DatabaseError)?;
+
+            tx.commit().await.map_err(DbError::DatabaseError)?;
+
+            Ok(())
+        })
+        .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::get_test_pool;
+
+    async fn create_test_session(pool: &PgPool) -> Uuid {
+        let draft_id = Uuid::new_v4();
+        let session_id = Uuid::new_v4();
+
+        sqlx::query!(
+            "INSERT INTO drafts (id, year, status, rounds, picks_per_round) VALUES ($1, 2026, 'NotStarted', 1, 2)",
+            draft_id
+        )
+        .execute(pool)
+        .await
+        .unwrap();
+
+        sqlx::query!(
+            "INSERT INTO draft_sessions (id, draft_id) VALUES ($1, $2)",
+            session_id,
+            draft_id
+        )
+        .execute(pool)
+        .await
+        .unwrap();
+
+        session_id
+    }
+
+    async fn create_test_user(pool: &PgPool) -> Uuid {
+        let user_id = Uuid::new_v4();
+        sqlx::query!(
+            "INSERT INTO users (id, email, display_name, password_hash) VALUES ($1, $2, 'Tester', 'x')",
+            user_id,
+            format!("{}@example.com", user_id)
+        )
+        .execute(pool)
+        .await
+        .unwrap();
+        user_id
+    }
+
+    async fn cleanup_session(pool: &PgPool, session_id: Uuid) {
+        sqlx::query!(
+            "DELETE FROM drafts WHERE id = (SELECT draft_id FROM draft_sessions WHERE id = $1)",
+            session_id
+        )
+        .execute(pool)
+        .await
+        .unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_create_list_and_delete_participants() {
+        let pool = get_test_pool().await;
+        let repo = SqlxSessionParticipantRepository::new(pool.clone());
+        let session_id = create_test_session(&pool).await;
+
+        let team_id = Uuid::new_v4();
+        let alice = SessionParticipant::new(
+            session_id,
+            create_test_user(&pool).await,
+            "Alice".to_string(),
+            vec![team_id],
+        )
+        .unwrap();
+        let bob = SessionParticipant::new(
+            session_id,
+            create_test_user(&pool).await,
+            "Bob".to_string(),
+            vec![],
+        )
+        .unwrap();
+
+        let created = repo.create(&alice).await.unwrap();
+        assert_eq!(created.id, alice.id);
+        assert_eq!(created.user_id, alice.user_id);
+        repo.create(&bob).await.unwrap();
+
+        let participants = repo.list_by_session(session_id).await.unwrap();
+        assert_eq!(participants.len(), 2);
+        assert_eq!(participants[0].display_name, "Alice");
+        assert_eq!(participants[0].team_ids, vec![team_id]);
+
+        repo.delete(alice.id).await.unwrap();
+        assert!(repo.find_by_id(alice.id).await.unwrap().is_none());
+        assert!(repo.find_by_id(bob.id).await.unwrap().is_some());
+
+        cleanup_session(&pool, session_id).await;
+    }
+
+    #[tokio::test]
+    async fn test_duplicate_display_name_rejected() {
+        let pool = get_test_pool().await;
+        let repo = SqlxSessionParticipantRepository::new(pool.clone());
+        let session_id = create_test_session(&pool).await;
+
+        let first = SessionParticipant::new(
+            session_id,
+            create_test_user(&pool).await,
+            "Alice".to_string(),
+            vec![],
+        )
+        .unwrap();
+        let second = SessionParticipant::new(
+            session_id,
+            create_test_user(&pool).await,
+            "Alice".to_string(),
+            vec![],
+        )
+        .unwrap();
+
+        repo.create(&first).await.unwrap();
+        let result = repo.create(&second).await;
+        assert!(matches!(
+            result,
+            Err(domain::errors::DomainError::DuplicateEntry(_))
+        ));
+
+        cleanup_session(&pool, session_id).await;
+    }
+
+    #[tokio::test]
+    async fn test_user_joins_session_once() {
+        let pool = get_test_pool().await;
+        let repo = SqlxSessionParticipantRepository::new(pool.clone());
+        let session_id = create_test_session(&pool).await;
+        let user_id = create_test_user(&pool).await;
+
+        let first =
+            SessionParticipant::new(session_id, user_id, "Alice".to_string(), vec![]).unwrap();
+        let second =
+            SessionParticipant::new(session_id, user_id, "Alice Again".to_string(), vec![])
+                .unwrap();
+
+        repo.create(&first).await.unwrap();
+        let result = repo.create(&second).await;
+        assert!(matches!(
+            result,
+            Err(domain::errors::DomainError::DuplicateEntry(_))
+        ));
+
+        cleanup_session(&pool, session_id).await;
+    }
+
+    #[tokio::test]
+    async fn test_team_claimed_once_per_session() {
+        let pool = get_test_pool().await;
+        let repo = SqlxSessionParticipantRepository::new(pool.clone());
+        let session_id = create_test_session(&pool).await;
+        let team_id = Uuid::new_v4();
+
+        let alice = SessionParticipant::new(
+            session_id,
+            create_test_user(&pool).await,
+            "Alice".to_string(),
+            vec![team_id],
+        )
+        .unwrap();
+        let bob = SessionParticipant::new(
+            session_id,
+            create_test_user(&pool).await,
+            "Bob".to_string(),
+            vec![Uuid::new_v4(), team_id],
+        )
+        .unwrap();
+
+        // Both joins race past any check the caller makes first
+        let (first, second) = tokio::join!(repo.create(&alice), repo.create(&bob));
+        let results = [first, second];
+        assert_eq!(results.iter().filter(|r| r.is_ok()).count(), 1);
+        assert!(results
+            .iter()
+            .any(|r| matches!(r, Err(domain::errors::DomainError::DuplicateEntry(_)))));
+        assert_eq!(repo.list_by_session(session_id).await.unwrap().len(), 1);
+
+        // Leaving frees the team for someone else
+        let winner = if results[0].is_ok() { &alice } else { &bob };
+        let loser = if results[0].is_ok() { &bob } else { &alice };
+        repo.leave(winner).await.unwrap();
+        repo.create(loser).await.unwrap();
+
+        cleanup_session(&pool, session_id).await;
+    }
+
+    #[tokio::test]
+    async fn test_leave_releases_teams() {
+        let pool = get_test_pool().await;
+        let repo = SqlxSessionParticipantRepository::new(pool.clone());
+        let session_id = create_test_session(&pool).await;
+
+        let kept = Uuid::new_v4();
+        let released = Uuid::new_v4();
+        sqlx::query!(
+            "UPDATE draft_sessions SET controlled_team_ids = $2 WHERE id = $1",
+            session_id,
+            &vec![kept, released]
+        )
+        .execute(&pool)
+        .await
+        .unwrap();
+
+        let alice = SessionParticipant::new(
+            session_id,
+            create_test_user(&pool).await,
+            "Alice".to_string(),
+            vec![released],
+        )
+        .unwrap();
+        repo.create(&alice).await.unwrap();
+
+        repo.leave(&alice).await.unwrap();
+
+        assert!(repo.find_by_id(alice.id).await.unwrap().is_none());
+        let controlled = sqlx::query_scalar!(
+            "SELECT controlled_team_ids FROM draft_sessions WHERE id = $1",
+            session_id
+        )
+        .fetch_one(&pool)
+        .await
+        .unwrap();
+        assert_eq!(controlled, vec![kept]);
+
+        cleanup_session(&pool, session_id).await;
+    }
+}
@@ -4,8 +4,14 @@ use sqlx::PgPool;
 use uuid::Uuid;
 
 use crate::errors::DbError;
+use crate::instrument::traced;
+use crate::models::{DraftDb, DraftPickDb, PickTradeDb, PickTradeDetailDb, ScoutingReportDb};
+use crate::repositories::event_repo::insert_event;
 use domain::errors::{DomainError, DomainResult};
-use domain::models::{ChartType, Draft, DraftSession, SessionStatus};
+use domain::models::{
+    ChartType, Draft, DraftEvent, DraftPick, DraftSession, PickTradeDetail, SessionBundle,
+    SessionStatus, TradeDirection, TradeProposal,
+};
 use domain::repositories::SessionRepository;
 
 #[derive(Debug, Clone, sqlx::FromRow)]
@@ -22,6 +28,10 @@ struct DraftSessionDb {
     updated_at: DateTime<Utc>,
     started_at: Option<DateTime<Utc>>,
     completed_at: Option<DateTime<Utc>>,
+    pick_started_at: Option<DateTime<Utc>>,
+    paused_at: Option<DateTime<Utc>>,
+    elapsed_paused_ms: i64,
+    scheduled_start_at: Option<DateTime<Utc>>,
 }
 
 impl From<DraftSessionDb> for DraftSession {
@@ -49,6 +59,10 @@ impl From<DraftSessionDb> for DraftSession {
             updated_at: db.updated_at,
             started_at: db.started_at,
             completed_at: db.completed_at,
+            pick_started_at: db.pick_started_at,
+            paused_at: db.paused_at,
+            elapsed_paused_ms: db.elapsed_paused_ms,
+            scheduled_start_at: db.scheduled_start_at,
         }
     }
 }
@@ -66,222 +80,758 @@ impl SessionRepo {
 #[async_trait]
 impl SessionRepository for SessionRepo {
     async fn create(&self, session: &DraftSession) -> DomainResult<DraftSession> {
-        let chart_type_str = session.chart_type.to_string();
-
-        let db_session = sqlx::query_as!(
-            DraftSessionDb,
-            r#"
-            INSERT INTO draft_sessions (
-                id, draft_id, status, current_pick_number, time_per_pick_seconds,
-                auto_pick_enabled, chart_type, controlled_team_ids, created_at, updated_at, started_at, completed_at
+        traced("session.create", async move {
+            let chart_type_str = session.chart_type.to_string();
+
+            let db_session = sqlx::query_as!(
+                DraftSessionDb,
+                r#"
+                INSERT INTO draft_sessions (
+                    id, draft_id, status, current_pick_number, time_per_pick_seconds,
+                    auto_pick_enabled, chart_type, controlled_team_ids, created_at, updated_at, started_at, completed_at,
+                    pick_started_at, paused_at, elapsed_paused_ms, scheduled_start_at
+                )
+                VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12, $13, $14, $15, $16)
+                RETURNING *
+                "#,
+                session.id,
+                session.draft_id,
+                session.status.to_string(),
+                session.current_pick_number,
+                session.time_per_pick_seconds,
+                session.auto_pick_enabled,
+                chart_type_str,
+                &session.controlled_team_ids,
+                session.created_at,
+                session.updated_at,
+                session.started_at,
+                session.completed_at,
+                session.pick_started_at,
+                session.paused_at,
+                session.elapsed_paused_ms,
+                session.scheduled_start_at,
             )
-            VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12)
-            RETURNING *
-            "#,
-            session.id,
-            session.draft_id,
-            session.status.to_string(),
-            session.current_pick_number,
-            session.time_per_pick_seconds,
-            session.auto_pick_enabled,
-            chart_type_str,
-            &session.controlled_team_ids,
-            session.created_at,
-            session.updated_at,
-            session.started_at,
-            session.completed_at,
-        )
-        .fetch_one(&self.pool)
-        .await
-        .map_err(|e| {
-            if let sqlx::Error::Database(ref db_err) = e {
-                if db_err.is_unique_violation() {
-                    return DomainError::DuplicateEntry(format!(
-                        "Draft {} already has an active session",
-                        session.draft_id
-                    ));
+            .fetch_one(&self.pool)
+            .await
+            .map_err(|e| {
+                if let sqlx::Error::Database(ref db_err) = e {
+                    if db_err.is_unique_violation() {
+                        return DomainError::DuplicateEntry(format!(
+                            "Draft {} already has an active session",
+                            session.draft_id
+                        ));
+                    }
                 }
-            }
-            DomainError::DatabaseError(e.to_string())
-        })?;
+                DomainError::DatabaseError(e.to_string())
+            })?;
 
-        Ok(db_session.into())
+            Ok(db_session.into())
+        })
+        .await
     }
 
     async fn find_by_id(&self, id: Uuid) -> DomainResult<Option<DraftSession>> {
-        let result = sqlx::query_as!(
-            DraftSessionDb,
-            r#"
-            SELECT * FROM draft_sessions
-            WHERE id = $1
-            "#,
-            id
-        )
-        .fetch_optional(&self.pool)
-        .await
-        .map_err(|e| DomainError::DatabaseError(e.to_string()))?;
+        traced("session.find_by_id", async move {
+            let result = sqlx::query_as!(
+                DraftSessionDb,
+                r#"
+                SELECT * FROM draft_sessions
+                WHERE id = $1
+                "#,
+                id
+            )
+            .fetch_optional(&self.pool)
+            .await
+            .map_err(|e| DomainError::DatabaseError(e.to_string()))?;
 
-        Ok(result.map(Into::into))
+            Ok(result.map(Into::into))
+        })
+        .await
     }
 
     async fn find_by_draft_id(&self, draft_id: Uuid) -> DomainResult<Option<DraftSession>> {
-        let result = sqlx::query_as!(
-            DraftSessionDb,
-            r#"
-            SELECT * FROM draft_sessions
-            WHERE draft_id = $1 AND status != 'Completed'
-            ORDER BY created_at DESC
-            LIMIT 1
-            "#,
-            draft_id
-        )
-        .fetch_optional(&self.pool)
-        .await
-        .map_err(|e| DomainError::DatabaseError(e.to_string()))?;
+        traced("session.find_by_draft_id", async move {
+            let result = sqlx::query_as!(
+                DraftSessionDb,
+                r#"
+                SELECT * FROM draft_sessions
+                WHERE draft_id = $1 AND status != 'Completed'
+                ORDER BY created_at DESC
+                LIMIT 1
+                "#,
+                draft_id
+            )
+            .fetch_optional(&self.pool)
+            .await
+            .map_err(|e| DomainError::DatabaseError(e.to_string()))?;
 
-        Ok(result.map(Into::into))
+            Ok(result.map(Into::into))
+        })
+        .await
     }
 
     async fn update(&self, session: &DraftSession) -> DomainResult<DraftSession> {
-        let chart_type_str = session.chart_type.to_string();
-
-        let db_session = sqlx::query_as!(
-            DraftSessionDb,
-            r#"
-            UPDATE draft_sessions
-            SET status = $2,
-                current_pick_number = $3,
-                time_per_pick_seconds = $4,
-                auto_pick_enabled = $5,
-                chart_type = $6,
-                controlled_team_ids = $7,
-                updated_at = $8,
-                started_at = $9,
-                completed_at = $10
-            WHERE id = $1
-            RETURNING *
-            "#,
-            session.id,
-            session.status.to_string(),
-            session.current_pick_number,
-            session.time_per_pick_seconds,
-            session.auto_pick_enabled,
-            chart_type_str,
-            &session.controlled_team_ids,
-            session.updated_at,
-            session.started_at,
-            session.completed_at,
-        )
-        .fetch_one(&self.pool)
-        .await
-        .map_err(|e| DomainError::DatabaseError(e.to_string()))?;
+        traced("session.update", async move {
+            let chart_type_str = session.chart_type.to_string();
+
+            let db_session = sqlx::query_as!(
+                DraftSessionDb,
+                r#"
+                UPDATE draft_sessions
+                SET status = $2,
+                    current_pick_number = $3,
+                    time_per_pick_seconds = $4,
+                    auto_pick_enabled = $5,
+                    chart_type = $6,
+                    controlled_team_ids = $7,
+                    updated_at = $8,
+                    started_at = $9,
+                    completed_at = $10,
+                    pick_started_at = $11,
+                    paused_at = $12,
+                    elapsed_paused_ms = $13,
+                    scheduled_start_at = $14
+                WHERE id = $1
+                RETURNING *
+                "#,
+                session.id,
+                session.status.to_string(),
+                session.current_pick_number,
+                session.time_per_pick_seconds,
+                session.auto_pick_enabled,
+                chart_type_str,
+                &session.controlled_team_ids,
+                session.updated_at,
+                session.started_at,
+                session.completed_at,
+                session.pick_started_at,
+                session.paused_at,
+                session.elapsed_paused_ms,
+                session.scheduled_start_at,
+            )
+            .fetch_one(&self.pool)
+            .await
+            .map_err(|e| DomainError::DatabaseError(e.to_string()))?;
 
-        Ok(db_session.into())
+            Ok(db_session.into())
+        })
+        .await
     }
 
     async fn delete(&self, id: Uuid) -> DomainResult<()> {
-        sqlx::query!(
-            r#"
-            DELETE FROM draft_sessions
-            WHERE id = $1
-            "#,
-            id
-        )
-        .execute(&self.pool)
-        .await
-        .map_err(|e| DomainError::DatabaseError(e.to_string()))?;
+        traced("session.delete", async move {
+            sqlx::query!(
+                r#"
+                DELETE FROM draft_sessions
+                WHERE id = $1
+                "#,
+                id
+            )
+            .execute(&self.pool)
+            .await
+            .map_err(|e| DomainError::DatabaseError(e.to_string()))?;
 
-        Ok(())
+            Ok(())
+        })
+        .await
     }
 
     async fn list(&self) -> DomainResult<Vec<DraftSession>> {
-        let sessions = sqlx::query_as!(
-            DraftSessionDb,
-            r#"
-            SELECT * FROM draft_sessions
-            ORDER BY created_at DESC
-            "#
-        )
-        .fetch_all(&self.pool)
-        .await
-        .map_err(|e| DomainError::DatabaseError(e.to_string()))?;
+        traced("session.list", async move {
+            let sessions = sqlx::query_as!(
+                DraftSessionDb,
+                r#"
+                SELECT * FROM draft_sessions
+                ORDER BY created_at DESC
+                "#
+            )
+            .fetch_all(&self.pool)
+            .await
+            .map_err(|e| DomainError::DatabaseError(e.to_string()))?;
 
-        Ok(sessions.into_iter().map(Into::into).collect())
+            Ok(sessions.into_iter().map(Into::into).collect())
+        })
+        .await
     }
 
     async fn list_by_status(&self, status: &str) -> DomainResult<Vec<DraftSession>> {
-        let sessions = sqlx::query_as!(
-            DraftSessionDb,
-            r#"
-            SELECT * FROM draft_sessions
-            WHERE status = $1
-            ORDER BY created_at DESC
-            "#,
-            status
-        )
-        .fetch_all(&self.pool)
+        traced("session.list_by_status", async move {
+            let sessions = sqlx::query_as!(
+                DraftSessionDb,
+                r#"
+                SELECT * FROM draft_sessions
+                WHERE status = $1
+                ORDER BY created_at DESC
+                "#,
+                status
+            )
+            .fetch_all(&self.pool)
+            .await
+            .map_err(|e| DomainError::DatabaseError(e.to_string()))?;
+
+            Ok(sessions.into_iter().map(Into::into).collect())
+        })
         .await
-        .map_err(|e| DomainError::DatabaseError(e.to_string()))?;
+    }
+
+    async fn list_due_to_start(&self, now: DateTime<Utc>) -> DomainResult<Vec<DraftSession>> {
+        traced("session.list_due_to_start", async move {
+            let sessions = sqlx::query_as!(
+                DraftSessionDb,
+                r#"
+                SELECT * FROM draft_sessions
+                WHERE status = 'NotStarted' AND scheduled_start_at <= $1
+                ORDER BY scheduled_start_at ASC
+                "#,
+                now
+            )
+            .fetch_all(&self.pool)
+            .await
+            .map_err(|e| DomainError::DatabaseError(e.to_string()))?;
 
-        Ok(sessions.into_iter().map(Into::into).collect())
+            Ok(sessions.into_iter().map(Into::into).collect())
+        })
+        .await
+    }
+
+    async fn update_with_events(
+        &self,
+        session: &DraftSession,
+        events: &[DraftEvent],
+    ) -> DomainResult<DraftSession> {
+        traced("session.update_with_events", async move {
+            let mut tx = self.pool.begin().await.map_err(DbError::DatabaseError)?;
+
+            let db_session = sqlx::query_as!(
+                DraftSessionDb,
+                r#"
+                UPDATE draft_sessions
+                SET status = $2,
+                    current_pick_number = $3,
+                    time_per_pick_seconds = $4,
+                    auto_pick_enabled = $5,
+                    chart_type = $6,
+                    controlled_team_ids = $7,
+                    updated_at = $8,
+                    started_at = $9,
+                    completed_at = $10,
+                    pick_started_at = $11,
+                    paused_at = $12,
+                    elapsed_paused_ms = $13,
+                    scheduled_start_at = $14
+                WHERE id = $1
+                RETURNING *
+                "#,
+                session.id,
+                session.status.to_string(),
+                session.current_pick_number,
+                session.time_per_pick_seconds,
+                session.auto_pick_enabled,
+                session.chart_type.to_string(),
+                &session.controlled_team_ids,
+                session.updated_at,
+                session.started_at,
+                session.completed_at,
+                session.pick_started_at,
+                session.paused_at,
+                session.elapsed_paused_ms,
+                session.scheduled_start_at,
+            )
+            .fetch_one(&mut *tx)
+            .await
+            .map_err(DbError::DatabaseError)?;
+
+            for event in events {
+                insert_event(&mut tx, event).await?;
+            }
+
+            tx.commit().await.map_err(DbError::DatabaseError)?;
+
+            Ok(db_session.into())
+        })
+        .await
     }
 
     async fn start_session_with_draft(
         &self,
         session: &DraftSession,
         draft: Option<&Draft>,
+        event: &DraftEvent,
     ) -> DomainResult<DraftSession> {
-        let mut tx = self.pool.begin().await.map_err(DbError::DatabaseError)?;
+        traced("session.start_session_with_draft", async move {
+            let mut tx = self.pool.begin().await.map_err(DbError::DatabaseError)?;
+
+            if let Some(draft) = draft {
+                sqlx::query!(
+                    "UPDATE drafts SET status = $2, updated_at = $3 WHERE id = $1",
+                    draft.id,
+                    draft.status.to_string(),
+                    draft.updated_at
+                )
+                .execute(&mut *tx)
+                .await
+                .map_err(DbError::DatabaseError)?;
+            }
 
-        if let Some(draft) = draft {
+            let chart_type_str = session.chart_type.to_string();
+            let session_status_str = session.status.to_string();
+            let controlled_ids = &session.controlled_team_ids;
+            let db_session = sqlx::query_as!(
+                DraftSessionDb,
+                r#"
+                UPDATE draft_sessions
+                SET status = $2,
+                    current_pick_number = $3,
+                    time_per_pick_seconds = $4,
+                    auto_pick_enabled = $5,
+                    chart_type = $6,
+                    controlled_team_ids = $7,
+                    updated_at = $8,
+                    started_at = $9,
+                    completed_at = $10,
+                    pick_started_at = $11,
+                    paused_at = $12,
+                    elapsed_paused_ms = $13,
+                    scheduled_start_at = $14
+                WHERE id = $1
+                RETURNING *
+                "#,
+                session.id,
+                session_status_str,
+                session.current_pick_number,
+                session.time_per_pick_seconds,
+                session.auto_pick_enabled,
+                chart_type_str,
+                controlled_ids as &[Uuid],
+                session.updated_at,
+                session.started_at,
+                session.completed_at,
+                session.pick_started_at,
+                session.paused_at,
+                session.elapsed_paused_ms,
+                session.scheduled_start_at,
+            )
+            .fetch_one(&mut *tx)
+            .await
+            .map_err(DbError::DatabaseError)?;
+
+            insert_event(&mut tx, event).await?;
+
+            tx.commit().await.map_err(DbError::DatabaseError)?;
+
+            Ok(db_session.into())
+        })
+        .await
+    }
+
+    async fn record_pick(
+        &self,
+        pick: &DraftPick,
+        session: &DraftSession,
+        event: &DraftEvent,
+    ) -> DomainResult<DraftPick> {
+        traced("session.record_pick", async move {
+            let mut tx = self.pool.begin().await.map_err(DbError::DatabaseError)?;
+
+            let pick_db = DraftPickDb::from_domain(pick);
+            let saved = sqlx::query_as!(
+                DraftPickDb,
+                r#"
+                UPDATE draft_picks
+                SET player_id = $2, picked_at = $3, updated_at = $4
+                WHERE id = $1
+                RETURNING id, draft_id, round, pick_number, overall_pick, team_id, player_id, picked_at, original_team_id, is_compensatory, notes, created_at, updated_at
+                "#,
+                pick_db.id,
+                pick_db.player_id,
+                pick_db.picked_at,
+                pick_db.updated_at
+            )
+            .fetch_optional(&mut *tx)
+            .await
+            .map_err(|e| match &e {
+                sqlx::Error::Database(db_err) if db_err.is_unique_violation() => {
+                    DomainError::PlayerAlreadyDrafted(
+                        "Player has already been drafted in this draft".to_string(),
+                    )
+                }
+                _ => DbError::DatabaseError(e).into(),
+            })?
+            .ok_or_else(|| DbError::NotFound(format!("Draft pick with id {} not found", pick_db.id)))?;
+
+            let chart_type_str = session.chart_type.to_string();
+            let session_status_str = session.status.to_string();
+            let controlled_ids = &session.controlled_team_ids;
             sqlx::query!(
-                "UPDATE drafts SET status = $2, updated_at = $3 WHERE id = $1",
-                draft.id,
-                draft.status.to_string(),
-                draft.updated_at
+                r#"
+                UPDATE draft_sessions
+                SET status = $2,
+                    current_pick_number = $3,
+                    time_per_pick_seconds = $4,
+                    auto_pick_enabled = $5,
+                    chart_type = $6,
+                    controlled_team_ids = $7,
+                    updated_at = $8,
+                    started_at = $9,
+                    completed_at = $10,
+                    pick_started_at = $11,
+                    paused_at = $12,
+                    elapsed_paused_ms = $13,
+                    scheduled_start_at = $14
+                WHERE id = $1
+                "#,
+                session.id,
+                session_status_str,
+                session.current_pick_number,
+                session.time_per_pick_seconds,
+                session.auto_pick_enabled,
+                chart_type_str,
+                controlled_ids as &[Uuid],
+                session.updated_at,
+                session.started_at,
+                session.completed_at,
+                session.pick_started_at,
+                session.paused_at,
+                session.elapsed_paused_ms,
+                session.scheduled_start_at,
             )
             .execute(&mut *tx)
             .await
             .map_err(DbError::DatabaseError)?;
-        }
 
-        let chart_type_str = session.chart_type.to_string();
-        let session_status_str = session.status.to_string();
-        let controlled_ids = &session.controlled_team_ids;
-        let db_session = sqlx::query_as!(
-            DraftSessionDb,
-            r#"
-            UPDATE draft_sessions
-            SET status = $2,
-                current_pick_number = $3,
-                time_per_pick_seconds = $4,
-                auto_pick_enabled = $5,
-                chart_type = $6,
-                controlled_team_ids = $7,
-                updated_at = $8,
-                started_at = $9,
-                completed_at = $10
-            WHERE id = $1
-            RETURNING *
-            "#,
-            session.id,
-            session_status_str,
-            session.current_pick_number,
-            session.time_per_pick_seconds,
-            session.auto_pick_enabled,
-            chart_type_str,
-            controlled_ids as &[Uuid],
-            session.updated_at,
-            session.started_at,
-            session.completed_at
-        )
-        .fetch_one(&mut *tx)
+            insert_event(&mut tx, event).await?;
+
+            tx.commit().await.map_err(DbError::DatabaseError)?;
+
+            saved.to_domain().map_err(Into::into)
+        })
         .await
-        .map_err(DbError::DatabaseError)?;
+    }
 
-        tx.commit().await.map_err(DbError::DatabaseError)?;
+    async fn rewind_session(
+        &self,
+        session: &DraftSession,
+        draft: Option<&Draft>,
+        reversed_trades: &[TradeProposal],
+        event: &DraftEvent,
+    ) -> DomainResult<DraftSession> {
+        traced("session.rewind_session", async move {
+            let mut tx = self.pool.begin().await.map_err(DbError::DatabaseError)?;
+
+            sqlx::query!(
+                r#"
+                UPDATE draft_picks
+                SET player_id = NULL, picked_at = NULL, updated_at = NOW()
+                WHERE draft_id = $1 AND overall_pick >= $2 AND player_id IS NOT NULL
+                "#,
+                session.draft_id,
+                session.current_pick_number
+            )
+            .execute(&mut *tx)
+            .await
+            .map_err(DbError::DatabaseError)?;
 
-        Ok(db_session.into())
+            // Undo each trade by handing every pick back to the team that gave it up
+            for proposal in reversed_trades {
+                let trade = &proposal.trade;
+                for pick_id in &proposal.from_team_picks {
+                    sqlx::query!(
+                        "UPDATE draft_picks SET team_id = $1, updated_at = NOW() WHERE id = $2",
+                        trade.from_team_id,
+                        pick_id
+                    )
+                    .execute(&mut *tx)
+                    .await
+                    .map_err(DbError::DatabaseError)?;
+                }
+                for pick_id in &proposal.to_team_picks {
+                    sqlx::query!(
+                        "UPDATE draft_picks SET team_id = $1, updated_at = NOW() WHERE id = $2",
+                        trade.to_team_id,
+                        pick_id
+                    )
+                    .execute(&mut *tx)
+                    .await
+                    .map_err(DbError::DatabaseError)?;
+                }
+
+                // Trade details cascade with the trade
+                sqlx::query!("DELETE FROM pick_trades WHERE id = $1", trade.id)
+                    .execute(&mut *tx)
+                    .await
+                    .map_err(DbError::DatabaseError)?;
+            }
+
+            if let Some(draft) = draft {
+                sqlx::query!(
+                    "UPDATE drafts SET status = $2, updated_at = $3 WHERE id = $1",
+                    draft.id,
+                    draft.status.to_string(),
+                    draft.updated_at
+                )
+                .execute(&mut *tx)
+                .await
+                .map_err(DbError::DatabaseError)?;
+            }
+
+            let chart_type_str = session.chart_type.to_string();
+            let session_status_str = session.status.to_string();
+            let controlled_ids = &session.controlled_team_ids;
+            let db_session = sqlx::query_as!(
+                DraftSessionDb,
+                r#"
+                UPDATE draft_sessions
+                SET status = $2,
+                    current_pick_number = $3,
+                    time_per_pick_seconds = $4,
+                    auto_pick_enabled = $5,
+                    chart_type = $6,
+                    controlled_team_ids = $7,
+                    updated_at = $8,
+                    started_at = $9,
+                    completed_at = $10,
+                    pick_started_at = $11,
+                    paused_at = $12,
+                    elapsed_paused_ms = $13,
+                    scheduled_start_at = $14
+                WHERE id = $1
+                RETURNING *
+                "#,
+                session.id,
+                session_status_str,
+                session.current_pick_number,
+                session.time_per_pick_seconds,
+                session.auto_pick_enabled,
+                chart_type_str,
+                controlled_ids as &[Uuid],
+                session.updated_at,
+                session.started_at,
+                session.completed_at,
+                session.pick_started_at,
+                session.paused_at,
+                session.elapsed_paused_ms,
+                session.scheduled_start_at,
+            )
+            .fetch_one(&mut *tx)
+            .await
+            .map_err(DbError::DatabaseError)?;
+
+            insert_event(&mut tx, event).await?;
+
+            tx.commit().await.map_err(DbError::DatabaseError)?;
+
+            Ok(db_session.into())
+        })
+        .await
+    }
+
+    async fn import_bundle(&self, bundle: &SessionBundle) -> DomainResult<DraftSession> {
+        traced("session.import_bundle", async move {
+            let mut tx = self.pool.begin().await.map_err(DbError::DatabaseError)?;
+
+            let draft_db = DraftDb::from_domain(&bundle.draft);
+            sqlx::query!(
+                r#"
+                INSERT INTO drafts (id, name, year, status, rounds, picks_per_round, created_at, updated_at)
+                VALUES ($1, $2, $3, $4, $5, $6, $7, $8)
+                "#,
+                draft_db.id,
+                draft_db.name,
+                draft_db.year,
+                draft_db.status,
+                draft_db.rounds,
+                draft_db.picks_per_round,
+                draft_db.created_at,
+                draft_db.updated_at
+            )
+            .execute(&mut *tx)
+            .await
+            .map_err(DbError::DatabaseError)?;
+
+            for pick in &bundle.picks {
+                let pick_db = DraftPickDb::from_domain(pick);
+                sqlx::query!(
+                    r#"
+                    INSERT INTO draft_picks (id, draft_id, round, pick_number, overall_pick, team_id, player_id, picked_at, original_team_id, is_compensatory, notes, created_at, updated_at)
+                    VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12, $13)
+                    "#,
+                    pick_db.id,
+                    pick_db.draft_id,
+                    pick_db.round,
+                    pick_db.pick_number,
+                    pick_db.overall_pick,
+                    pick_db.team_id,
+                    pick_db.player_id,
+                    pick_db.picked_at,
+                    pick_db.original_team_id,
+                    pick_db.is_compensatory,
+                    pick_db.notes,
+                    pick_db.created_at,
+                    pick_db.updated_at
+                )
+                .execute(&mut *tx)
+                .await
+                .map_err(DbError::DatabaseError)?;
+            }
+
+            let session = &bundle.session;
+            let chart_type_str = session.chart_type.to_string();
+            let session_status_str = session.status.to_string();
+            let controlled_ids = &session.controlled_team_ids;
+            let db_session = sqlx::query_as!(
+                DraftSessionDb,
+                r#"
+                INSERT INTO draft_sessions (
+                    id, draft_id, status, current_pick_number, time_per_pick_seconds,
+                    auto_pick_enabled, chart_type, controlled_team_ids, created_at, updated_at, started_at, completed_at,
+                    pick_started_at, paused_at, elapsed_paused_ms, scheduled_start_at
+                )
+                VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12, $13, $14, $15, $16)
+                RETURNING *
+                "#,
+                session.id,
+                session.draft_id,
+                session_status_str,
+                session.current_pick_number,
+                session.time_per_pick_seconds,
+                session.auto_pick_enabled,
+                chart_type_str,
+                controlled_ids as &[Uuid],
+                session.created_at,
+                session.updated_at,
+                session.started_at,
+                session.completed_at,
+                session.pick_started_at,
+                session.paused_at,
+                session.elapsed_paused_ms,
+                session.scheduled_start_at,
+            )
+            .fetch_one(&mut *tx)
+            .await
+            .map_err(DbError::DatabaseError)?;
+
+            // Detail values are recomputed from the session's chart, as when the
+            // trade was first proposed
+            let value_chart = session.chart_type.create_chart();
+            for proposal in &bundle.trades {
+                let trade_db = PickTradeDb::from_domain(&proposal.trade);
+                sqlx::query!(
+                    r#"
+                    INSERT INTO pick_trades (
+                        id, session_id, from_team_id, to_team_id, status,
+                        from_team_value, to_team_value, value_difference,
+                        proposed_at, responded_at, created_at, updated_at
+                    )
+                    VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12)
+                    "#,
+                    trade_db.id,
+                    trade_db.session_id,
+                    trade_db.from_team_id,
+                    trade_db.to_team_id,
+                    trade_db.status,
+                    trade_db.from_team_value,
+                    trade_db.to_team_value,
+                    trade_db.value_difference,
+                    trade_db.proposed_at,
+                    trade_db.responded_at,
+                    trade_db.created_at,
+                    trade_db.updated_at
+                )
+                .execute(&mut *tx)
+                .await
+                .map_err(DbError::DatabaseError)?;
+
+                let details = proposal
+                    .from_team_picks
+                    .iter()
+                    .map(|id| (*id, TradeDirection::FromTeam))
+                    .chain(
+                        proposal
+                            .to_team_picks
+                            .iter()
+                            .map(|id| (*id, TradeDirection::ToTeam)),
+                    );
+                for (pick_id, direction) in details {
+                    let overall_pick = bundle
+                        .picks
+                        .iter()
+                        .find(|p| p.id == pick_id)
+                        .map(|p| p.overall_pick)
+                        .ok_or_else(|| {
+                            DomainError::ValidationError(format!(
+                                "Trade {} references unknown pick {}",
+                                proposal.trade.id, pick_id
+                            ))
+                        })?;
+                    let value = value_chart.calculate_pick_value(overall_pick)?;
+                    let detail = PickTradeDetail::new(proposal.trade.id, pick_id, direction, value);
+                    let detail_db = PickTradeDetailDb::from_domain(&detail);
+                    sqlx::query!(
+                        r#"
+                        INSERT INTO pick_trade_details (id, trade_id, pick_id, direction, pick_value, created_at)
+                        VALUES ($1, $2, $3, $4, $5, $6)
+                        "#,
+                        detail_db.id,
+                        detail_db.trade_id,
+                        detail_db.pick_id,
+                        detail_db.direction,
+                        detail_db.pick_value,
+                        detail_db.created_at
+                    )
+                    .execute(&mut *tx)
+                    .await
+                    .map_err(DbError::DatabaseError)?;
+                }
+            }
+
+            // Imported events are history rather than news, so they skip the outbox
+            for event in &bundle.events {
+                sqlx::query!(
+                    r#"
+                    INSERT INTO draft_events (id, session_id, event_type, event_data, created_at)
+                    VALUES ($1, $2, $3, $4, $5)
+                    "#,
+                    event.id,
+                    event.session_id,
+                    event.event_type.to_string(),
+                    event.event_data,
+                    event.created_at,
+                )
+                .execute(&mut *tx)
+                .await
+                .map_err(DbError::DatabaseError)?;
+            }
+
+            for grade in &bundle.grades {
+                let report_db = ScoutingReportDb::from_domain(grade);
+                sqlx::query!(
+                    r#"
+                    INSERT INTO scouting_reports
+                    (id, player_id, team_id, grade, notes, fit_grade, injury_concern, character_concern, created_at, updated_at)
+                    VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10)
+                    ON CONFLICT (team_id, player_id) DO NOTHING
+                    "#,
+                    report_db.id,
+                    report_db.player_id,
+                    report_db.team_id,
+                    report_db.grade,
+                    report_db.notes,
+                    report_db.fit_grade,
+                    report_db.injury_concern,
+                    report_db.character_concern,
+                    report_db.created_at,
+                    report_db.updated_at
+                )
+                .execute(&mut *tx)
+                .await
+                .map_err(DbError::DatabaseError)?;
+            }
+
+            tx.commit().await.map_err(DbError::DatabaseError)?;
+
+            Ok(db_session.into())
+        })
+        .await
     }
 }
 
@@ -7,112 +7,133 @@ use domain::models::TeamSeason;
 use domain::repositories::TeamSeasonRepository;
 
 use crate::errors::DbError;
+use crate::instrument::traced;
 use crate::models::TeamSeasonDb;
 
 /// SQLx implementation of TeamSeasonRepository
 pub struct SqlxTeamSeasonRepository {
     pool: PgPool,
+    /// Serves collection reads; the primary unless a replica is set
+    read_pool: PgPool,
 }
 
 impl SqlxTeamSeasonRepository {
     pub fn new(pool: PgPool) -> Self {
-        Self { pool }
+        Self {
+            read_pool: pool.clone(),
+            pool,
+        }
+    }
+
+    /// Send collection reads to a read replica
+    pub fn with_read_pool(mut self, read_pool: PgPool) -> Self {
+        self.read_pool = read_pool;
+        self
     }
 }
 
 #[async_trait]
 impl TeamSeasonRepository for SqlxTeamSeasonRepository {
     async fn create(&self, season: &TeamSeason) -> DomainResult<TeamSeason> {
-        let season_db = TeamSeasonDb::from_domain(season);
-
-        let result = sqlx::query_as!(
-            TeamSeasonDb,
-            r#"
-            INSERT INTO team_seasons (id, team_id, season_year, wins, losses, ties, playoff_result, draft_position, created_at, updated_at)
-            VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10)
-            RETURNING id, team_id, season_year, wins, losses, ties, playoff_result, draft_position, created_at, updated_at
-            "#,
-            season_db.id,
-            season_db.team_id,
-            season_db.season_year,
-            season_db.wins,
-            season_db.losses,
-            season_db.ties,
-            season_db.playoff_result,
-            season_db.draft_position,
-            season_db.created_at,
-            season_db.updated_at
-        )
-        .fetch_one(&self.pool)
-        .await
-        .map_err(|e| {
-            if let sqlx::Error::Database(db_err) = &e {
-                if db_err.is_unique_violation() {
-                    return DbError::DuplicateEntry(format!(
-                        "Team season for team {} in year {} already exists",
-                        season_db.team_id, season_db.season_year
-                    ));
+        traced("team_season.create", async move {
+            let season_db = TeamSeasonDb::from_domain(season);
+
+            let result = sqlx::query_as!(
+                TeamSeasonDb,
+                r#"
+                INSERT INTO team_seasons (id, team_id, season_year, wins, losses, ties, playoff_result, draft_position, created_at, updated_at)
+                VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10)
+                RETURNING id, team_id, season_year, wins, losses, ties, playoff_result, draft_position, created_at, updated_at
+                "#,
+                season_db.id,
+                season_db.team_id,
+                season_db.season_year,
+                season_db.wins,
+                season_db.losses,
+                season_db.ties,
+                season_db.playoff_result,
+                season_db.draft_position,
+                season_db.created_at,
+                season_db.updated_at
+            )
+            .fetch_one(&self.pool)
+            .await
+            .map_err(|e| {
+                if let sqlx::Error::Database(db_err) = &e {
+                    if db_err.is_unique_violation() {
+                        return DbError::DuplicateEntry(format!(
+                            "Team season for team {} in year {} already exists",
+                            season_db.team_id, season_db.season_year
+                        ));
+                    }
                 }
-            }
-            DbError::DatabaseError(e)
-        })?;
+                DbError::DatabaseError(e)
+            })?;
 
-        result.to_domain().map_err(Into::into)
+            result.to_domain().map_err(Into::into)
+        })
+        .await
     }
 
     async fn upsert(&self, season: &TeamSeason) -> DomainResult<TeamSeason> {
-        let season_db = TeamSeasonDb::from_domain(season);
-
-        let result = sqlx::query_as!(
-            TeamSeasonDb,
-            r#"
-            INSERT INTO team_seasons (id, team_id, season_year, wins, losses, ties, playoff_result, draft_position, created_at, updated_at)
-            VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10)
-            ON CONFLICT (team_id, season_year) DO UPDATE SET
-                wins = EXCLUDED.wins,
-                losses = EXCLUDED.losses,
-                ties = EXCLUDED.ties,
-                playoff_result = EXCLUDED.playoff_result,
-                draft_position = EXCLUDED.draft_position,
-                updated_at = NOW()
-            RETURNING id, team_id, season_year, wins, losses, ties, playoff_result, draft_position, created_at, updated_at
-            "#,
-            season_db.id,
-            season_db.team_id,
-            season_db.season_year,
-            season_db.wins,
-            season_db.losses,
-            season_db.ties,
-            season_db.playoff_result,
-            season_db.draft_position,
-            season_db.created_at,
-            season_db.updated_at
-        )
-        .fetch_one(&self.pool)
-        .await
-        .map_err(DbError::DatabaseError)?;
+        traced("team_season.upsert", async move {
+            let season_db = TeamSeasonDb::from_domain(season);
+
+            let result = sqlx::query_as!(
+                TeamSeasonDb,
+                r#"
+                INSERT INTO team_seasons (id, team_id, season_year, wins, losses, ties, playoff_result, draft_position, created_at, updated_at)
+                VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10)
+                ON CONFLICT (team_id, season_year) DO UPDATE SET
+                    wins = EXCLUDED.wins,
+                    losses = EXCLUDED.losses,
+                    ties = EXCLUDED.ties,
+                    playoff_result = EXCLUDED.playoff_result,
+                    draft_position = EXCLUDED.draft_position,
+                    updated_at = NOW()
+                RETURNING id, team_id, season_year, wins, losses, ties, playoff_result, draft_position, created_at, updated_at
+                "#,
+                season_db.id,
+                season_db.team_id,
+                season_db.season_year,
+                season_db.wins,
+                season_db.losses,
+                season_db.ties,
+                season_db.playoff_result,
+                season_db.draft_position,
+                season_db.created_at,
+                season_db.updated_at
+            )
+            .fetch_one(&self.pool)
+            .await
+            .map_err(DbError::DatabaseError)?;
 
-        result.to_domain().map_err(Into::into)
+            result.to_domain().map_err(Into::into)
+        })
+        .await
     }
 
     async fn find_by_id(&self, id: Uuid) -> DomainResult<Option<TeamSeason>> {
-        let result = sqlx::query_as!(
-            TeamSeasonDb,
-            r#"
-            SELECT id, team_id, season_year, wins, losses, ties, playoff_result, draft_position, created_at, updated_at
-            FROM team_seasons
-            WHERE id = $1
-            "#,
-            id
-        )
-        .fetch_optional(&self.pool)
-        .await
-        .map_err(DbError::DatabaseError)?;
+        traced("team_season.find_by_id", async move {
+            let result = sqlx::query_as!(
+                TeamSeasonDb,
+                r#"
+                SELECT id, team_id, season_year, wins, losses, ties, playoff_result, draft_position, created_at, updated_at
+                FROM team_seasons
+                WHERE id = $1
+                "#,
+                id
+            )
+            .fetch_optional(&self.pool)
+            .await
+            .map_err(DbError::DatabaseError)?;
 
-        match result {
-            Some(season_db) => Ok(Some(season_db.to_domain()?)),
-            None => Ok(None),
-        }
+            match result {
+                Some(season_db) => Ok(Some(season_db.to_domain()?)),
+                None => Ok(None),
+            }
+        })
+        .await
     }
 
     async fn find_by_team_and_year(
@@ -120,103 +141,120 @@ impl TeamSeasonRepository for SqlxTeamSeasonRepository {
         team_id: Uuid,
         year: i32,
     ) -> DomainResult<Option<TeamSeason>> {
-        let result = sqlx::query_as!(
-            TeamSeasonDb,
-            r#"
-            SELECT id, team_id, season_year, wins, losses, ties, playoff_result, draft_position, created_at, updated_at
-            FROM team_seasons
-            WHERE team_id = $1 AND season_year = $2
-            "#,
-            team_id,
-            year
-        )
-        .fetch_optional(&self.pool)
-        .await
-        .map_err(DbError::DatabaseError)?;
+        traced("team_season.find_by_team_and_year", async move {
+            let result = sqlx::query_as!(
+                TeamSeasonDb,
+                r#"
+                SELECT id, team_id, season_year, wins, losses, ties, playoff_result, draft_position, created_at, updated_at
+                FROM team_seasons
+                WHERE team_id = $1 AND season_year = $2
+                "#,
+                team_id,
+                year
+            )
+            .fetch_optional(&self.pool)
+            .await
+            .map_err(DbError::DatabaseError)?;
 
-        match result {
-            Some(season_db) => Ok(Some(season_db.to_domain()?)),
-            None => Ok(None),
-        }
+            match result {
+                Some(season_db) => Ok(Some(season_db.to_domain()?)),
+                None => Ok(None),
+            }
+        })
+        .await
     }
 
     async fn find_by_year(&self, year: i32) -> DomainResult<Vec<TeamSeason>> {
-        let results = sqlx::query_as!(
-            TeamSeasonDb,
-            r#"
-            SELECT id, team_id, season_year, wins, losses, ties, playoff_result, draft_position, created_at, updated_at
-            FROM team_seasons
-            WHERE season_year = $1
-            ORDER BY draft_position ASC NULLS LAST, wins DESC, losses ASC
-            "#,
-            year
-        )
-        .fetch_all(&self.pool)
-        .await
-        .map_err(DbError::DatabaseError)?;
+        traced("team_season.find_by_year", async move {
+            let results = sqlx::query_as!(
+                TeamSeasonDb,
+                r#"
+                SELECT id, team_id, season_year, wins, losses, ties, playoff_result, draft_position, created_at, updated_at
+                FROM team_seasons
+                WHERE season_year = $1
+                ORDER BY draft_position ASC NULLS LAST, wins DESC, losses ASC
+                "#,
+                year
+            )
+            .fetch_all(&self.read_pool)
+            .await
+            .map_err(DbError::DatabaseError)?;
 
-        results
-            .into_iter()
-            .map(|season_db| season_db.to_domain().map_err(Into::into))
-            .collect()
+            results
+                .into_iter()
+                .map(|season_db| season_db.to_domain().map_err(Into::into))
+                .collect()
+        })
+        .await
     }
 
     async fn find_by_year_ordered_by_draft_position(
         &self,
         year: i32,
     ) -> DomainResult<Vec<TeamSeason>> {
-        let results = sqlx::query_as!(
-            TeamSeasonDb,
-            r#"
-            SELECT id, team_id, season_year, wins, losses, ties, playoff_result, draft_position, created_at, updated_at
-            FROM team_seasons
-            WHERE season_year = $1 AND draft_position IS NOT NULL
-            ORDER BY draft_position ASC
-            "#,
-            year
-        )
-        .fetch_all(&self.pool)
-        .await
-        .map_err(DbError::DatabaseError)?;
+        traced("team_season.find_by_year_ordered_by_draft_position", async move {
+            let results = sqlx::query_as!(
+                TeamSeasonDb,
+                r#"
+                SELECT id, team_id, season_year, wins, losses, ties, playoff_result, draft_position, created_at, updated_at
+                FROM team_seasons
+                WHERE season_year = $1 AND draft_position IS NOT NULL
+                ORDER BY draft_position ASC
+                "#,
+                year
+            )
+            .fetch_all(&self.read_pool)
+            .await
+            .map_err(DbError::DatabaseError)?;
 
-        results
-            .into_iter()
-            .map(|season_db| season_db.to_domain().map_err(Into::into))
-            .collect()
+            results
+                .into_iter()
+                .map(|season_db| season_db.to_domain().map_err(Into::into))
+                .collect()
+        })
+        .await
     }
 
     async fn delete_by_year(&self, year: i32) -> DomainResult<()> {
-        sqlx::query!(
-            r#"
-            DELETE FROM team_seasons
-            WHERE season_year = $1
-            "#,
-            year
-        )
-        .execute(&self.pool)
-        .await
-        .map_err(DbError::DatabaseError)?;
+        traced("team_season.delete_by_year", async move {
+            sqlx::query!(
+                r#"
+                DELETE FROM team_seasons
+                WHERE season_year = $1
+                "#,
+                year
+            )
+            .execute(&self.pool)
+            .await
+            .map_err(DbError::DatabaseError)?;
 
-        Ok(())
+            Ok(())
+        })
+        .await
     }
 
     async fn delete(&self, id: Uuid) -> DomainResult<()> {
-        let result = sqlx::query!(
-            r#"
-            DELETE FROM team_seasons
-            WHERE id = $1
-            "#,
-            id
-        )
-        .execute(&self.pool)
-        .await
-        .map_err(DbError::DatabaseError)?;
+        traced("team_season.delete", async move {
+            let result = sqlx::query!(
+                r#"
+                DELETE FROM team_seasons
+                WHERE id = $1
+                "#,
+                id
+            )
+            .execute(&self.pool)
+            .await
+            .map_err(DbError::DatabaseError)?;
 
-        if result.rows_affected() == 0 {
-            return Err(DbError::NotFound(format!("Team season with id {} not found", id)).into());
-        }
+            if result.rows_affected() == 0 {
+                return Err(
+                    DbError::NotFound(format!("Team season with id {} not found", id)).into(),
+                );
+            }
 
-        Ok(())
+            Ok(())
+        })
+        .await
     }
 }
 
@@ -1,10 +1,14 @@
 use crate::errors::DbError;
+use crate::instrument::traced;
 use crate::models::{PickTradeDb, PickTradeDetailDb};
+use crate::repositories::event_repo::insert_event;
 use async_trait::async_trait;
 use domain::errors::DomainResult;
-use domain::models::{ChartType, PickTrade, PickTradeDetail, TradeDirection, TradeProposal};
+use domain::models::{
+    ChartType, DraftEvent, PickTrade, PickTradeDetail, TradeDirection, TradeProposal,
+};
 use domain::repositories::TradeRepository;
-use sqlx::PgPool;
+use sqlx::{PgConnection, PgPool};
 use uuid::Uuid;
 
 pub struct SqlxTradeRepository {
@@ -24,306 +28,216 @@ impl TradeRepository for SqlxTradeRepository {
         proposal: &TradeProposal,
         chart_type: ChartType,
     ) -> DomainResult<TradeProposal> {
-        let trade_db = PickTradeDb::from_domain(&proposal.trade);
-
-        // Transaction for atomic creation
-        let mut tx = self.pool.begin().await.map_err(DbError::DatabaseError)?;
-
-        // Insert trade
-        let trade_result = sqlx::query_as!(
-            PickTradeDb,
-            r#"
-            INSERT INTO pick_trades (
-                id, session_id, from_team_id, to_team_id, status,
-                from_team_value, to_team_value, value_difference,
-                proposed_at, responded_at, created_at, updated_at
-            )
-            VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12)
-            RETURNING id, session_id, from_team_id, to_team_id, status,
-                      from_team_value, to_team_value, value_difference,
-                      proposed_at, responded_at, created_at, updated_at
-            "#,
-            trade_db.id,
-            trade_db.session_id,
-            trade_db.from_team_id,
-            trade_db.to_team_id,
-            trade_db.status,
-            trade_db.from_team_value,
-            trade_db.to_team_value,
-            trade_db.value_difference,
-            trade_db.proposed_at,
-            trade_db.responded_at,
-            trade_db.created_at,
-            trade_db.updated_at
-        )
-        .fetch_one(&mut *tx)
+        traced("trade.create_trade", async move {
+            let mut tx = self.pool.begin().await.map_err(DbError::DatabaseError)?;
+            let created = insert_trade(&mut tx, proposal, chart_type).await?;
+            tx.commit().await.map_err(DbError::DatabaseError)?;
+            Ok(created)
+        })
         .await
-        .map_err(DbError::DatabaseError)?;
-
-        // Use the specified chart type for calculating individual pick values
-        let value_chart = chart_type.create_chart();
-
-        // Insert trade details for each pick
-        for (pick_id, direction) in proposal
-            .from_team_picks
-            .iter()
-            .map(|id| (*id, TradeDirection::FromTeam))
-            .chain(
-                proposal
-                    .to_team_picks
-                    .iter()
-                    .map(|id| (*id, TradeDirection::ToTeam)),
-            )
-        {
-            // Get pick to determine value
-            let pick = sqlx::query!(
-                "SELECT overall_pick FROM draft_picks WHERE id = $1",
-                pick_id
-            )
-            .fetch_one(&mut *tx)
-            .await
-            .map_err(DbError::DatabaseError)?;
-
-            // Calculate value using trade value chart
-            let value = value_chart
-                .calculate_pick_value(pick.overall_pick)
-                .map_err(|e| {
-                    DbError::MappingError(format!("Failed to calculate pick value: {:?}", e))
-                })?;
-
-            let detail = PickTradeDetail::new(proposal.trade.id, pick_id, direction, value);
-            let detail_db = PickTradeDetailDb::from_domain(&detail);
+    }
 
-            sqlx::query!(
+    async fn find_by_id(&self, id: Uuid) -> DomainResult<Option<PickTrade>> {
+        traced("trade.find_by_id", async move {
+            let result = sqlx::query_as!(
+                PickTradeDb,
                 r#"
-                INSERT INTO pick_trade_details (id, trade_id, pick_id, direction, pick_value, created_at)
-                VALUES ($1, $2, $3, $4, $5, $6)
+                SELECT id, session_id, from_team_id, to_team_id, status,
+                       from_team_value, to_team_value, value_difference,
+                       proposed_at, responded_at, created_at, updated_at
+                FROM pick_trades
+                WHERE id = $1
                 "#,
-                detail_db.id,
-                detail_db.trade_id,
-                detail_db.pick_id,
-                detail_db.direction,
-                detail_db.pick_value,
-                detail_db.created_at
+                id
             )
-            .execute(&mut *tx)
+            .fetch_optional(&self.pool)
             .await
             .map_err(DbError::DatabaseError)?;
-        }
-
-        tx.commit().await.map_err(DbError::DatabaseError)?;
 
-        Ok(TradeProposal {
-            trade: trade_result.to_domain()?,
-            from_team_picks: proposal.from_team_picks.clone(),
-            to_team_picks: proposal.to_team_picks.clone(),
+            match result {
+                Some(db) => Ok(Some(db.to_domain()?)),
+                None => Ok(None),
+            }
         })
-    }
-
-    async fn find_by_id(&self, id: Uuid) -> DomainResult<Option<PickTrade>> {
-        let result = sqlx::query_as!(
-            PickTradeDb,
-            r#"
-            SELECT id, session_id, from_team_id, to_team_id, status,
-                   from_team_value, to_team_value, value_difference,
-                   proposed_at, responded_at, created_at, updated_at
-            FROM pick_trades
-            WHERE id = $1
-            "#,
-            id
-        )
-        .fetch_optional(&self.pool)
         .await
-        .map_err(DbError::DatabaseError)?;
-
-        match result {
-            Some(db) => Ok(Some(db.to_domain()?)),
-            None => Ok(None),
-        }
     }
 
     async fn find_trade_with_details(&self, id: Uuid) -> DomainResult<Option<TradeProposal>> {
-        let trade = match self.find_by_id(id).await? {
-            Some(t) => t,
-            None => return Ok(None),
-        };
-
-        let details = sqlx::query_as!(
-            PickTradeDetailDb,
-            r#"
-            SELECT id, trade_id, pick_id, direction, pick_value, created_at
-            FROM pick_trade_details
-            WHERE trade_id = $1
-            "#,
-            id
-        )
-        .fetch_all(&self.pool)
-        .await
-        .map_err(DbError::DatabaseError)?;
+        traced("trade.find_trade_with_details", async move {
+            let trade = match self.find_by_id(id).await? {
+                Some(t) => t,
+                None => return Ok(None),
+            };
+
+            let details = sqlx::query_as!(
+                PickTradeDetailDb,
+                r#"
+                SELECT id, trade_id, pick_id, direction, pick_value, created_at
+                FROM pick_trade_details
+                WHERE trade_id = $1
+                "#,
+                id
+            )
+            .fetch_all(&self.pool)
+            .await
+            .map_err(DbError::DatabaseError)?;
 
-        let mut from_team_picks = Vec::new();
-        let mut to_team_picks = Vec::new();
+            let mut from_team_picks = Vec::new();
+            let mut to_team_picks = Vec::new();
 
-        for detail_db in details {
-            let detail = detail_db.to_domain()?;
-            match detail.direction {
-                TradeDirection::FromTeam => from_team_picks.push(detail.pick_id),
-                TradeDirection::ToTeam => to_team_picks.push(detail.pick_id),
+            for detail_db in details {
+                let detail = detail_db.to_domain()?;
+                match detail.direction {
+                    TradeDirection::FromTeam => from_team_picks.push(detail.pick_id),
+                    TradeDirection::ToTeam => to_team_picks.push(detail.pick_id),
+                }
             }
-        }
 
-        Ok(Some(TradeProposal {
-            trade,
-            from_team_picks,
-            to_team_picks,
-        }))
+            Ok(Some(TradeProposal {
+                trade,
+                from_team_picks,
+                to_team_picks,
+            }))
+        })
+        .await
     }
 
     async fn find_by_session(&self, session_id: Uuid) -> DomainResult<Vec<PickTrade>> {
-        let results = sqlx::query_as!(
-            PickTradeDb,
-            r#"
-            SELECT id, session_id, from_team_id, to_team_id, status,
-                   from_team_value, to_team_value, value_difference,
-                   proposed_at, responded_at, created_at, updated_at
-            FROM pick_trades
-            WHERE session_id = $1
-            ORDER BY created_at DESC
-            "#,
-            session_id
-        )
-        .fetch_all(&self.pool)
-        .await
-        .map_err(DbError::DatabaseError)?;
+        traced("trade.find_by_session", async move {
+            let results = sqlx::query_as!(
+                PickTradeDb,
+                r#"
+                SELECT id, session_id, from_team_id, to_team_id, status,
+                       from_team_value, to_team_value, value_difference,
+                       proposed_at, responded_at, created_at, updated_at
+                FROM pick_trades
+                WHERE session_id = $1
+                ORDER BY created_at DESC
+                "#,
+                session_id
+            )
+            .fetch_all(&self.pool)
+            .await
+            .map_err(DbError::DatabaseError)?;
 
-        results
-            .into_iter()
-            .map(|db| db.to_domain())
-            .collect::<Result<Vec<_>, _>>()
-            .map_err(Into::into)
+            results
+                .into_iter()
+                .map(|db| db.to_domain())
+                .collect::<Result<Vec<_>, _>>()
+                .map_err(Into::into)
+        })
+        .await
     }
 
     async fn find_proposals_by_session(
         &self,
         session_id: Uuid,
     ) -> DomainResult<Vec<TradeProposal>> {
-        let trade_rows = sqlx::query_as!(
-            PickTradeDb,
-            r#"
-            SELECT id, session_id, from_team_id, to_team_id, status,
-                   from_team_value, to_team_value, value_difference,
-                   proposed_at, responded_at, created_at, updated_at
-            FROM pick_trades
-            WHERE session_id = $1
-            ORDER BY created_at DESC
-            "#,
-            session_id
-        )
-        .fetch_all(&self.pool)
-        .await
-        .map_err(DbError::DatabaseError)?;
+        traced("trade.find_proposals_by_session", async move {
+            let trade_rows = sqlx::query_as!(
+                PickTradeDb,
+                r#"
+                SELECT id, session_id, from_team_id, to_team_id, status,
+                       from_team_value, to_team_value, value_difference,
+                       proposed_at, responded_at, created_at, updated_at
+                FROM pick_trades
+                WHERE session_id = $1
+                ORDER BY created_at DESC
+                "#,
+                session_id
+            )
+            .fetch_all(&self.pool)
+            .await
+            .map_err(DbError::DatabaseError)?;
 
-        if trade_rows.is_empty() {
-            return Ok(Vec::new());
-        }
+            if trade_rows.is_empty() {
+                return Ok(Vec::new());
+            }
 
-        // Fetch every detail row for all trades in this session in a single
-        // round-trip, then bucket them in-memory. Avoids the N+1 pattern of
-        // calling find_trade_with_details per trade.
-        let trade_ids: Vec<Uuid> = trade_rows.iter().map(|t| t.id).collect();
-        let detail_rows = sqlx::query_as!(
-            PickTradeDetailDb,
-            r#"
-            SELECT id, trade_id, pick_id, direction, pick_value, created_at
-            FROM pick_trade_details
-            WHERE trade_id = ANY($1)
-            "#,
-            &trade_ids
-        )
-        .fetch_all(&self.pool)
-        .await
-        .map_err(DbError::DatabaseError)?;
+            // Fetch every detail row for all trades in this session in a single
+            // round-trip, then bucket them in-memory. Avoids the N+1 pattern of
+            // calling find_trade_with_details per trade.
+            let trade_ids: Vec<Uuid> = trade_rows.iter().map(|t| t.id).collect();
+            let detail_rows = sqlx::query_as!(
+                PickTradeDetailDb,
+                r#"
+                SELECT id, trade_id, pick_id, direction, pick_value, created_at
+                FROM pick_trade_details
+                WHERE trade_id = ANY($1)
+                "#,
+                &trade_ids
+            )
+            .fetch_all(&self.pool)
+            .await
+            .map_err(DbError::DatabaseError)?;
 
-        let mut picks_by_trade: std::collections::HashMap<Uuid, (Vec<Uuid>, Vec<Uuid>)> =
-            std::collections::HashMap::with_capacity(trade_rows.len());
-        for detail_db in detail_rows {
-            let detail = detail_db.to_domain()?;
-            let entry = picks_by_trade
-                .entry(detail.trade_id)
-                .or_insert_with(|| (Vec::new(), Vec::new()));
-            match detail.direction {
-                TradeDirection::FromTeam => entry.0.push(detail.pick_id),
-                TradeDirection::ToTeam => entry.1.push(detail.pick_id),
+            let mut picks_by_trade: std::collections::HashMap<Uuid, (Vec<Uuid>, Vec<Uuid>)> =
+                std::collections::HashMap::with_capacity(trade_rows.len());
+            for detail_db in detail_rows {
+                let detail = detail_db.to_domain()?;
+                let entry = picks_by_trade
+                    .entry(detail.trade_id)
+                    .or_insert_with(|| (Vec::new(), Vec::new()));
+                match detail.direction {
+                    TradeDirection::FromTeam => entry.0.push(detail.pick_id),
+                    TradeDirection::ToTeam => entry.1.push(detail.pick_id),
+                }
+            }
+
+            let mut proposals = Vec::with_capacity(trade_rows.len());
+            for trade_db in trade_rows {
+                let trade = trade_db.to_domain()?;
+                let (from_team_picks, to_team_picks) =
+                    picks_by_trade.remove(&trade.id).unwrap_or_default();
+                proposals.push(TradeProposal {
+                    trade,
+                    from_team_picks,
+                    to_team_picks,
+                });
             }
-        }
-
-        let mut proposals = Vec::with_capacity(trade_rows.len());
-        for trade_db in trade_rows {
-            let trade = trade_db.to_domain()?;
-            let (from_team_picks, to_team_picks) =
-                picks_by_trade.remove(&trade.id).unwrap_or_default();
-            proposals.push(TradeProposal {
-                trade,
-                from_team_picks,
-                to_team_picks,
-            });
-        }
 
-        Ok(proposals)
+            Ok(proposals)
+        })
+        .await
     }
 
     async fn find_pending_for_team(&self, team_id: Uuid) -> DomainResult<Vec<TradeProposal>> {
-        let trades = sqlx::query_as!(
-            PickTradeDb,
-            r#"
-            SELECT id, session_id, from_team_id, to_team_id, status,
-                   from_team_value, to_team_value, value_difference,
-                   proposed_at, responded_at, created_at, updated_at
-            FROM pick_trades
-            WHERE to_team_id = $1 AND status = 'Proposed'
-            ORDER BY created_at DESC
-            "#,
-            team_id
-        )
-        .fetch_all(&self.pool)
-        .await
-        .map_err(DbError::DatabaseError)?;
+        traced("trade.find_pending_for_team", async move {
+            let trades = sqlx::query_as!(
+                PickTradeDb,
+                r#"
+                SELECT id, session_id, from_team_id, to_team_id, status,
+                       from_team_value, to_team_value, value_difference,
+                       proposed_at, responded_at, created_at, updated_at
+                FROM pick_trades
+                WHERE to_team_id = $1 AND status = 'Proposed'
+                ORDER BY created_at DESC
+                "#,
+                team_id
+            )
+            .fetch_all(&self.pool)
+            .await
+            .map_err(DbError::DatabaseError)?;
 
-        let mut proposals = Vec::new();
-        for trade_db in trades {
-            let trade = trade_db.to_domain()?;
-            if let Some(proposal) = self.find_trade_with_details(trade.id).await? {
-                proposals.push(proposal);
+            let mut proposals = Vec::new();
+            for trade_db in trades {
+                let trade = trade_db.to_domain()?;
+                if let Some(proposal) = self.find_trade_with_details(trade.id).await? {
+                    proposals.push(proposal);
+                }
             }
-        }
 
-        Ok(proposals)
+            Ok(proposals)
+        })
+        .await
     }
 
     async fn update(&self, trade: &PickTrade) -> DomainResult<PickTrade> {
-        let trade_db = PickTradeDb::from_domain(trade);
-
-        let result = sqlx::query_as!(
-            PickTradeDb,
-            r#"
-            UPDATE pick_trades
-            SET status = $2, responded_at = $3, updated_at = $4
-            WHERE id = $1
-            RETURNING id, session_id, from_team_id, to_team_id, status,
-                      from_team_value, to_team_value, value_difference,
-                      proposed_at, responded_at, created_at, updated_at
-            "#,
-            trade_db.id,
-            trade_db.status,
-            trade_db.responded_at,
-            trade_db.updated_at
-        )
-        .fetch_optional(&self.pool)
+        traced("trade.update", async move {
+            let mut conn = self.pool.acquire().await.map_err(DbError::DatabaseError)?;
+            update_trade_status(&mut conn, trade).await
+        })
         .await
-        .map_err(DbError::DatabaseError)?
-        .ok_or_else(|| DbError::NotFound(format!("Trade {} not found", trade_db.id)))?;
-
-        result.to_domain().map_err(Into::into)
     }
 
     async fn is_pick_in_active_trade(
@@ -331,45 +245,48 @@ impl TradeRepository for SqlxTradeRepository {
         pick_id: Uuid,
         exclude_trade_id: Option<Uuid>,
     ) -> DomainResult<bool> {
-        let exists = match exclude_trade_id {
-            Some(exclude_id) => {
-                let result = sqlx::query!(
-                    r#"
-                    SELECT EXISTS(
-                        SELECT 1
-                        FROM pick_trade_details ptd
-                        JOIN pick_trades pt ON pt.id = ptd.trade_id
-                        WHERE ptd.pick_id = $1 AND pt.status = 'Proposed' AND pt.id != $2
-                    ) as "exists!"
-                    "#,
-                    pick_id,
-                    exclude_id
-                )
-                .fetch_one(&self.pool)
-                .await
-                .map_err(DbError::DatabaseError)?;
-                result.exists
-            }
-            None => {
-                let result = sqlx::query!(
-                    r#"
-                    SELECT EXISTS(
-                        SELECT 1
-                        FROM pick_trade_details ptd
-                        JOIN pick_trades pt ON pt.id = ptd.trade_id
-                        WHERE ptd.pick_id = $1 AND pt.status = 'Proposed'
-                    ) as "exists!"
-                    "#,
-                    pick_id
-                )
-                .fetch_one(&self.pool)
-                .await
-                .map_err(DbError::DatabaseError)?;
-                result.exists
-            }
-        };
-
-        Ok(exists)
+        traced("trade.is_pick_in_active_trade", async move {
+            let exists = match exclude_trade_id {
+                Some(exclude_id) => {
+                    let result = sqlx::query!(
+                        r#"
+                        SELECT EXISTS(
+                            SELECT 1
+                            FROM pick_trade_details ptd
+                            JOIN pick_trades pt ON pt.id = ptd.trade_id
+                            WHERE ptd.pick_id = $1 AND pt.status = 'Proposed' AND pt.id != $2
+                        ) as "exists!"
+                        "#,
+                        pick_id,
+                        exclude_id
+                    )
+                    .fetch_one(&self.pool)
+                    .await
+                    .map_err(DbError::DatabaseError)?;
+                    result.exists
+                }
+                None => {
+                    let result = sqlx::query!(
+                        r#"
+                        SELECT EXISTS(
+                            SELECT 1
+                            FROM pick_trade_details ptd
+                            JOIN pick_trades pt ON pt.id = ptd.trade_id
+                            WHERE ptd.pick_id = $1 AND pt.status = 'Proposed'
+                        ) as "exists!"
+                        "#,
+                        pick_id
+                    )
+                    .fetch_one(&self.pool)
+                    .await
+                    .map_err(DbError::DatabaseError)?;
+                    result.exists
+                }
+            };
+
+            Ok(exists)
+        })
+        .await
     }
 
     async fn transfer_picks(
@@ -379,33 +296,238 @@ impl TradeRepository for SqlxTradeRepository {
         from_team_picks: &[Uuid],
         to_team_picks: &[Uuid],
     ) -> DomainResult<()> {
-        let mut tx = self.pool.begin().await.map_err(DbError::DatabaseError)?;
-
-        // Transfer from_team picks to to_team
-        for pick_id in from_team_picks {
-            sqlx::query!(
-                "UPDATE draft_picks SET team_id = $1, updated_at = NOW() WHERE id = $2",
+        traced("trade.transfer_picks", async move {
+            let mut tx = self.pool.begin().await.map_err(DbError::DatabaseError)?;
+            move_picks(
+                &mut tx,
+                from_team_id,
                 to_team_id,
-                pick_id
+                from_team_picks,
+                to_team_picks,
             )
-            .execute(&mut *tx)
-            .await
-            .map_err(DbError::DatabaseError)?;
-        }
+            .await?;
+            tx.commit().await.map_err(DbError::DatabaseError)?;
+            Ok(())
+        })
+        .await
+    }
 
-        // Transfer to_team picks to from_team
-        for pick_id in to_team_picks {
-            sqlx::query!(
-                "UPDATE draft_picks SET team_id = $1, updated_at = NOW() WHERE id = $2",
-                from_team_id,
-                pick_id
+    async fn create_trade_with_event(
+        &self,
+        proposal: &TradeProposal,
+        chart_type: ChartType,
+        event: &DraftEvent,
+    ) -> DomainResult<TradeProposal> {
+        traced("trade.create_trade_with_event", async move {
+            let mut tx = self.pool.begin().await.map_err(DbError::DatabaseError)?;
+            let created = insert_trade(&mut tx, proposal, chart_type).await?;
+            insert_event(&mut tx, event).await?;
+            tx.commit().await.map_err(DbError::DatabaseError)?;
+            Ok(created)
+        })
+        .await
+    }
+
+    async fn update_with_event(
+        &self,
+        trade: &PickTrade,
+        event: &DraftEvent,
+    ) -> DomainResult<PickTrade> {
+        traced("trade.update_with_event", async move {
+            let mut tx = self.pool.begin().await.map_err(DbError::DatabaseError)?;
+            let updated = update_trade_status(&mut tx, trade).await?;
+            insert_event(&mut tx, event).await?;
+            tx.commit().await.map_err(DbError::DatabaseError)?;
+            Ok(updated)
+        })
+        .await
+    }
+
+    async fn execute_trade(
+        &self,
+        proposal: &TradeProposal,
+        event: &DraftEvent,
+    ) -> DomainResult<PickTrade> {
+        traced("trade.execute_trade", async move {
+            let trade = &proposal.trade;
+            let mut tx = self.pool.begin().await.map_err(DbError::DatabaseError)?;
+            move_picks(
+                &mut tx,
+                trade.from_team_id,
+                trade.to_team_id,
+                &proposal.from_team_picks,
+                &proposal.to_team_picks,
             )
-            .execute(&mut *tx)
-            .await
-            .map_err(DbError::DatabaseError)?;
-        }
+            .await?;
+            let updated = update_trade_status(&mut tx, trade).await?;
+            insert_event(&mut tx, event).await?;
+            tx.commit().await.map_err(DbError::DatabaseError)?;
+            Ok(updated)
+        })
+        .await
+    }
+}
+
+/// Inserts a trade and a detail row for each of its picks on `conn`,
+/// valuing the picks with `chart_type`
+async fn insert_trade(
+    conn: &mut PgConnection,
+    proposal: &TradeProposal,
+    chart_type: ChartType,
+) -> DomainResult<TradeProposal> {
+    let trade_db = PickTradeDb::from_domain(&proposal.trade);
+
+    // Insert trade
+    let trade_result = sqlx::query_as!(
+        PickTradeDb,
+        r#"
+        INSERT INTO pick_trades (
+            id, session_id, from_team_id, to_team_id, status,
+            from_team_value, to_team_value, value_difference,
+            proposed_at, responded_at, created_at, updated_at
+        )
+        VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12)
+        RETURNING id, session_id, from_team_id, to_team_id, status,
+                  from_team_value, to_team_value, value_difference,
+                  proposed_at, responded_at, created_at, updated_at
+        "#,
+        trade_db.id,
+        trade_db.session_id,
+        trade_db.from_team_id,
+        trade_db.to_team_id,
+        trade_db.status,
+        trade_db.from_team_value,
+        trade_db.to_team_value,
+        trade_db.value_difference,
+        trade_db.proposed_at,
+        trade_db.responded_at,
+        trade_db.created_at,
+        trade_db.updated_at
+    )
+    .fetch_one(&mut *conn)
+    .await
+    .map_err(DbError::DatabaseError)?;
+
+    // Use the specified chart type for calculating individual pick values
+    let value_chart = chart_type.create_chart();
+
+    // Insert trade details for each pick
+    for (pick_id, direction) in proposal
+        .from_team_picks
+        .iter()
+        .map(|id| (*id, TradeDirection::FromTeam))
+        .chain(
+            proposal
+                .to_team_picks
+                .iter()
+                .map(|id| (*id, TradeDirection::ToTeam)),
+        )
+    {
+        // Get pick to determine value
+        let pick = sqlx::query!(
+            "SELECT overall_pick FROM draft_picks WHERE id = $1",
+            pick_id
+        )
+        .fetch_one(&mut *conn)
+        .await
+        .map_err(DbError::DatabaseError)?;
+
+        // Calculate value using trade value chart
+        let value = value_chart
+            .calculate_pick_value(pick.overall_pick)
+            .map_err(|e| {
+                DbError::MappingError(format!("Failed to calculate pick value: {:?}", e))
+            })?;
+
+        let detail = PickTradeDetail::new(proposal.trade.id, pick_id, direction, value);
+        let detail_db = PickTradeDetailDb::from_domain(&detail);
 
-        tx.commit().await.map_err(DbError::DatabaseError)?;
-        Ok(())
+        sqlx::query!(
+            r#"
+            INSERT INTO pick_trade_details (id, trade_id, pick_id, direction, pick_value, created_at)
+            VALUES ($1, $2, $3, $4, $5, $6)
+            "#,
+            detail_db.id,
+            detail_db.trade_id,
+            detail_db.pick_id,
+            detail_db.direction,
+            detail_db.pick_value,
+            detail_db.created_at
+        )
+        .execute(&mut *conn)
+        .await
+        .map_err(DbError::DatabaseError)?;
+    }
+
+    Ok(TradeProposal {
+        trade: trade_result.to_domain()?,
+        from_team_picks: proposal.from_team_picks.clone(),
+        to_team_picks: proposal.to_team_picks.clone(),
+    })
+}
+
+/// Saves a trade's status and response time on `conn`
+async fn update_trade_status(
+    conn: &mut PgConnection,
+    trade: &PickTrade,
+) -> DomainResult<PickTrade> {
+    let trade_db = PickTradeDb::from_domain(trade);
+
+    let result = sqlx::query_as!(
+        PickTradeDb,
+        r#"
+        UPDATE pick_trades
+        SET status = $2, responded_at = $3, updated_at = $4
+        WHERE id = $1
+        RETURNING id, session_id, from_team_id, to_team_id, status,
+                  from_team_value, to_team_value, value_difference,
+                  proposed_at, responded_at, created_at, updated_at
+        "#,
+        trade_db.id,
+        trade_db.status,
+        trade_db.responded_at,
+        trade_db.updated_at
+    )
+    .fetch_optional(&mut *conn)
+    .await
+    .map_err(DbError::DatabaseError)?
+    .ok_or_else(|| DbError::NotFound(format!("Trade {} not found", trade_db.id)))?;
+
+    result.to_domain().map_err(Into::into)
+}
+
+/// Hands `from_team_picks` to the receiving team and `to_team_picks` to
+/// the proposing team on `conn`
+async fn move_picks(
+    conn: &mut PgConnection,
+    from_team_id: Uuid,
+    to_team_id: Uuid,
+    from_team_picks: &[Uuid],
+    to_team_picks: &[Uuid],
+) -> DomainResult<()> {
+    // Transfer from_team picks to to_team
+    for pick_id in from_team_picks {
+        sqlx::query!(
+            "UPDATE draft_picks SET team_id = $1, updated_at = NOW() WHERE id = $2",
+            to_team_id,
+            pick_id
+        )
+        .execute(&mut *conn)
+        .await
+        .map_err(DbError::DatabaseError)?;
+    }
+
+    // Transfer to_team picks to from_team
+    for pick_id in to_team_picks {
+        sqlx::query!(
+            "UPDATE draft_picks SET team_id = $1, updated_at = NOW() WHERE id = $2",
+            from_team_id,
+            pick_id
+        )
+        .execute(&mut *conn)
+        .await
+        .map_err(DbError::DatabaseError)?;
     }
+
+    Ok(())
 }
@@ -0,0 +1,171 @@
+use async_trait::async_trait;
+use sqlx::PgPool;
+use uuid::Uuid;
+
+use domain::errors::DomainResult;
+use domain::models::{Role, User};
+use domain::repositories::UserRepository;
+
+use crate::errors::DbError;
+use crate::instrument::traced;
+use crate::models::UserDb;
+
+/// SQLx implementation of UserRepository
+pub struct SqlxUserRepository {
+    pool: PgPool,
+}
+
+impl SqlxUserRepository {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl UserRepository for SqlxUserRepository {
+    async fn create(&self, user: &User) -> DomainResult<User> {
+        traced("user.create", async move {
+            let user_db = UserDb::from_domain(user);
+
+            let result = sqlx::query_as!(
+                UserDb,
+                r#"
+                INSERT INTO users (id, email, display_name, password_hash, role, created_at)
+                VALUES ($1, $2, $3, $4, $5, $6)
+                RETURNING id, email, display_name, password_hash, role, created_at
+                "#,
+                user_db.id,
+                user_db.email,
+                user_db.display_name,
+                user_db.password_hash,
+                user_db.role,
+                user_db.created_at
+            )
+            .fetch_one(&self.pool)
+            .await
+            .map_err(|e| {
+                if let sqlx::Error::Database(db_err) = &e {
+                    if db_err.is_unique_violation() {
+                        return DbError::DuplicateEntry(format!(
+                            "An account with email '{}' already exists",
+                            user_db.email
+                        ));
+                    }
+                }
+                DbError::DatabaseError(e)
+            })?;
+
+            Ok(result.to_domain()?)
+        })
+        .await
+    }
+
+    async fn find_by_id(&self, id: Uuid) -> DomainResult<Option<User>> {
+        traced("user.find_by_id", async move {
+            let result = sqlx::query_as!(
+                UserDb,
+                r#"
+                SELECT id, email, display_name, password_hash, role, created_at
+                FROM users
+                WHERE id = $1
+                "#,
+                id
+            )
+            .fetch_optional(&self.pool)
+            .await
+            .map_err(DbError::DatabaseError)?;
+
+            Ok(result.map(UserDb::to_domain).transpose()?)
+        })
+        .await
+    }
+
+    async fn find_by_email(&self, email: &str) -> DomainResult<Option<User>> {
+        traced("user.find_by_email", async move {
+            let result = sqlx::query_as!(
+                UserDb,
+                r#"
+                SELECT id, email, display_name, password_hash, role, created_at
+                FROM users
+                WHERE email = $1
+                "#,
+                email
+            )
+            .fetch_optional(&self.pool)
+            .await
+            .map_err(DbError::DatabaseError)?;
+
+            Ok(result.map(UserDb::to_domain).transpose()?)
+        })
+        .await
+    }
+
+    async fn update_role(&self, id: Uuid, role: Role) -> DomainResult<User> {
+        traced("user.update_role", async move {
+            let result = sqlx::query_as!(
+                UserDb,
+                r#"
+                UPDATE users
+                SET role = $2
+                WHERE id = $1
+                RETURNING id, email, display_name, password_hash, role, created_at
+                "#,
+                id,
+                role.to_string()
+            )
+            .fetch_optional(&self.pool)
+            .await
+            .map_err(DbError::DatabaseError)?
+            .ok_or_else(|| DbError::NotFound(format!("User with id {} not found", id)))?;
+
+            Ok(result.to_domain()?)
+        })
+        .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::get_test_pool;
+    use domain::errors::DomainError;
+
+    #[tokio::test]
+    async fn test_create_and_find_user() {
+        let pool = get_test_pool().await;
+        let repo = SqlxUserRepository::new(pool.clone());
+
+        let email = format!("{}@example.com", Uuid::new_v4());
+        let user = User::new(&email, "Coach", "hash".to_string()).unwrap();
+        let created = repo.create(&user).await.unwrap();
+        assert_eq!(created.id, user.id);
+        assert_eq!(created.email, user.email);
+
+        assert_eq!(
+            repo.find_by_id(user.id).await.unwrap(),
+            Some(created.clone())
+        );
+        assert_eq!(
+            repo.find_by_email(&user.email).await.unwrap(),
+            Some(created)
+        );
+
+        let duplicate = User::new(&email.to_uppercase(), "Other", "hash".to_string()).unwrap();
+        assert!(matches!(
+            repo.create(&duplicate).await,
+            Err(DomainError::DuplicateEntry(_))
+        ));
+
+        let promoted = repo.update_role(user.id, Role::Commissioner).await.unwrap();
+        assert_eq!(promoted.role, Role::Commissioner);
+        assert!(matches!(
+            repo.update_role(Uuid::new_v4(), Role::Admin).await,
+            Err(DomainError::NotFound(_))
+        ));
+
+        sqlx::query!("DELETE FROM users WHERE id = $1", user.id)
+            .execute(&pool)
+            .await
+            .unwrap();
+    }
+}
@@ -0,0 +1,174 @@
+use async_trait::async_trait;
+use sqlx::PgPool;
+use uuid::Uuid;
+
+use domain::errors::DomainResult;
+use domain::models::WatchlistEntry;
+use domain::repositories::WatchlistRepository;
+
+use crate::errors::DbError;
+use crate::instrument::traced;
+use crate::models::WatchlistEntryDb;
+
+/// SQLx implementation of WatchlistRepository
+pub struct SqlxWatchlistRepository {
+    pool: PgPool,
+}
+
+impl SqlxWatchlistRepository {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl WatchlistRepository for SqlxWatchlistRepository {
+    async fn add(&self, entry: &WatchlistEntry) -> DomainResult<WatchlistEntry> {
+        traced("watchlist.add", async move {
+            let entry_db = WatchlistEntryDb::from_domain(entry);
+
+            // The no-op update lets RETURNING hand back the existing row on conflict
+            let result = sqlx::query_as!(
+                WatchlistEntryDb,
+                r#"
+                INSERT INTO user_watchlists (user_id, player_id, created_at)
+                VALUES ($1, $2, $3)
+                ON CONFLICT (user_id, player_id) DO UPDATE SET player_id = EXCLUDED.player_id
+                RETURNING user_id, player_id, created_at
+                "#,
+                entry_db.user_id,
+                entry_db.player_id,
+                entry_db.created_at
+            )
+            .fetch_one(&self.pool)
+            .await
+            .map_err(|e| {
+                if let sqlx::Error::Database(db_err) = &e {
+                    if db_err.is_foreign_key_violation() {
+                        return if db_err.constraint() == Some("user_watchlists_user_id_fkey") {
+                            DbError::NotFound(format!("User with id {} not found", entry.user_id))
+                        } else {
+                            DbError::NotFound(format!(
+                                "Player with id {} not found",
+                                entry.player_id
+                            ))
+                        };
+                    }
+                }
+                DbError::DatabaseError(e)
+            })?;
+
+            Ok(result.to_domain())
+        })
+        .await
+    }
+
+    async fn remove(&self, user_id: Uuid, player_id: Uuid) -> DomainResult<bool> {
+        traced("watchlist.remove", async move {
+            let result = sqlx::query!(
+                r#"
+                DELETE FROM user_watchlists WHERE user_id = $1 AND player_id = $2
+                "#,
+                user_id,
+                player_id
+            )
+            .execute(&self.pool)
+            .await
+            .map_err(DbError::DatabaseError)?;
+
+            Ok(result.rows_affected() > 0)
+        })
+        .await
+    }
+
+    async fn find_by_user_id(&self, user_id: Uuid) -> DomainResult<Vec<WatchlistEntry>> {
+        traced("watchlist.find_by_user_id", async move {
+            let results = sqlx::query_as!(
+                WatchlistEntryDb,
+                r#"
+                SELECT user_id, player_id, created_at
+                FROM user_watchlists
+                WHERE user_id = $1
+                ORDER BY created_at DESC, player_id
+                "#,
+                user_id
+            )
+            .fetch_all(&self.pool)
+            .await
+            .map_err(DbError::DatabaseError)?;
+
+            Ok(results
+                .into_iter()
+                .map(WatchlistEntryDb::to_domain)
+                .collect())
+        })
+        .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::get_test_pool;
+    use crate::repositories::{SqlxPlayerRepository, SqlxUserRepository};
+    use domain::errors::DomainError;
+    use domain::models::{Player, Position, User};
+    use domain::repositories::{PlayerRepository, UserRepository};
+
+    #[tokio::test]
+    async fn test_watchlist_add_find_remove() {
+        let pool = get_test_pool().await;
+        let repo = SqlxWatchlistRepository::new(pool.clone());
+
+        let user = User::new(
+            &format!("{}@example.com", Uuid::new_v4()),
+            "Scout",
+            "hash".to_string(),
+        )
+        .unwrap();
+        SqlxUserRepository::new(pool.clone())
+            .create(&user)
+            .await
+            .unwrap();
+        let player =
+            Player::new("Watch".to_string(), "Me".to_string(), Position::CB, 2026).unwrap();
+        SqlxPlayerRepository::new(pool.clone())
+            .create(&player)
+            .await
+            .unwrap();
+
+        let added = repo
+            .add(&WatchlistEntry::new(user.id, player.id))
+            .await
+            .unwrap();
+        // Watching again keeps the original entry
+        let again = repo
+            .add(&WatchlistEntry::new(user.id, player.id))
+            .await
+            .unwrap();
+        assert_eq!(again.created_at, added.created_at);
+
+        let entries = repo.find_by_user_id(user.id).await.unwrap();
+        assert_eq!(entries.len(), 1);
+        assert_eq!(entries[0].player_id, player.id);
+
+        assert!(matches!(
+            repo.add(&WatchlistEntry::new(user.id, Uuid::new_v4()))
+                .await,
+            Err(DomainError::NotFound(_))
+        ));
+
+        assert!(repo.remove(user.id, player.id).await.unwrap());
+        assert!(!repo.remove(user.id, player.id).await.unwrap());
+        assert!(repo.find_by_user_id(user.id).await.unwrap().is_empty());
+
+        sqlx::query!("DELETE FROM users WHERE id = $1", user.id)
+            .execute(&pool)
+            .await
+            .unwrap();
+        sqlx::query!("DELETE FROM players WHERE id = $1", player.id)
+            .execute(&pool)
+            .await
+            .unwrap();
+    }
+}
@@ -22,6 +22,15 @@ pub enum DomainError {
 
     #[error("Player already drafted: {0}")]
     PlayerAlreadyDrafted(String),
+
+    #[error("Trade unfair: {0}")]
+    TradeUnfair(String),
+
+    #[error("Session not in progress: {0}")]
+    SessionNotInProgress(String),
+
+    #[error("Resource in use: {0}")]
+    ResourceInUse(String),
 }
 
 pub type DomainResult<T> = Result<T, DomainError>;
@@ -0,0 +1,74 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::errors::{DomainError, DomainResult};
+
+/// A selection from the real NFL draft, used to score mock drafts
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ActualDraftPick {
+    pub id: Uuid,
+    pub year: i32,
+    pub round: i32,
+    pub pick_number: i32,
+    pub overall_pick: i32,
+    pub team_id: Uuid,
+    pub player_id: Uuid,
+    pub created_at: DateTime<Utc>,
+}
+
+impl ActualDraftPick {
+    pub fn new(
+        year: i32,
+        round: i32,
+        pick_number: i32,
+        overall_pick: i32,
+        team_id: Uuid,
+        player_id: Uuid,
+    ) -> DomainResult<Self> {
+        if !(2020..=2030).contains(&year) {
+            return Err(DomainError::ValidationError(format!(
+                "Year must be between 2020 and 2030, got {}",
+                year
+            )));
+        }
+        if round <= 0 || pick_number <= 0 || overall_pick <= 0 {
+            return Err(DomainError::ValidationError(format!(
+                "Round, pick number and overall pick must be positive, got {}/{}/{}",
+                round, pick_number, overall_pick
+            )));
+        }
+
+        Ok(Self {
+            id: Uuid::new_v4(),
+            year,
+            round,
+            pick_number,
+            overall_pick,
+            team_id,
+            player_id,
+            created_at: Utc::now(),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_new_actual_draft_pick() {
+        let pick = ActualDraftPick::new(2026, 1, 3, 3, Uuid::new_v4(), Uuid::new_v4()).unwrap();
+        assert_eq!(pick.year, 2026);
+        assert_eq!(pick.overall_pick, 3);
+    }
+
+    #[test]
+    fn test_new_actual_draft_pick_validation() {
+        let team_id = Uuid::new_v4();
+        let player_id = Uuid::new_v4();
+        assert!(ActualDraftPick::new(2019, 1, 1, 1, team_id, player_id).is_err());
+        assert!(ActualDraftPick::new(2026, 0, 1, 1, team_id, player_id).is_err());
+        assert!(ActualDraftPick::new(2026, 1, 1, 0, team_id, player_id).is_err());
+    }
+}
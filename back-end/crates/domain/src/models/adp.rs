@@ -0,0 +1,58 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::errors::{DomainError, DomainResult};
+
+/// Where one player went in one completed mock draft
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AdpEntry {
+    pub id: Uuid,
+    pub draft_id: Uuid,
+    pub year: i32,
+    pub player_id: Uuid,
+    pub round: i32,
+    pub overall_pick: i32,
+    pub recorded_at: DateTime<Utc>,
+}
+
+impl AdpEntry {
+    pub fn new(
+        draft_id: Uuid,
+        year: i32,
+        player_id: Uuid,
+        round: i32,
+        overall_pick: i32,
+    ) -> DomainResult<Self> {
+        if round <= 0 || overall_pick <= 0 {
+            return Err(DomainError::ValidationError(format!(
+                "Round and overall pick must be positive, got {}/{}",
+                round, overall_pick
+            )));
+        }
+
+        Ok(Self {
+            id: Uuid::new_v4(),
+            draft_id,
+            year,
+            player_id,
+            round,
+            overall_pick,
+            recorded_at: Utc::now(),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_new_adp_entry_validation() {
+        let draft_id = Uuid::new_v4();
+        let player_id = Uuid::new_v4();
+        assert!(AdpEntry::new(draft_id, 2026, player_id, 1, 5).is_ok());
+        assert!(AdpEntry::new(draft_id, 2026, player_id, 0, 5).is_err());
+        assert!(AdpEntry::new(draft_id, 2026, player_id, 1, 0).is_err());
+    }
+}
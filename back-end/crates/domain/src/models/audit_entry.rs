@@ -0,0 +1,42 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use uuid::Uuid;
+
+/// A write made through the API, recorded for commissioners and admins who
+/// need to know who changed what and when
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AuditEntry {
+    pub id: Uuid,
+    /// The signed-in user, when the request carried a valid token
+    pub actor_user_id: Option<Uuid>,
+    /// The user's seat in the session written to, when they act from one
+    pub actor_participant_id: Option<Uuid>,
+    /// The participant's display name, else the user's email, `api-key` for
+    /// the seed API key, or `anonymous`
+    pub actor: String,
+    pub method: String,
+    /// Matched route template, such as `/api/v1/picks/{id}/make`
+    pub route: String,
+    /// Kind of entity written, such as `draft_pick` or `session`
+    pub entity_type: String,
+    pub entity_id: Option<Uuid>,
+    /// The entity before the write; absent for creates and for entities
+    /// whose repositories don't record changes
+    pub before: Option<Value>,
+    /// The entity after the write; absent for deletes and for entities
+    /// whose repositories don't record changes
+    pub after: Option<Value>,
+    pub status_code: i32,
+    pub request_id: Option<String>,
+    pub created_at: DateTime<Utc>,
+}
+
+/// Narrows an audit log query; unset fields match everything
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct AuditLogFilter {
+    pub entity_type: Option<String>,
+    pub entity_id: Option<Uuid>,
+    pub actor_user_id: Option<Uuid>,
+    pub since: Option<DateTime<Utc>>,
+}
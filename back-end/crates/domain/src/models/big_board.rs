@@ -0,0 +1,140 @@
+use crate::errors::{DomainError, DomainResult};
+
+/// How much consensus rank, scouting grade and RAS each count toward a
+/// prospect's composite big board score. Weights are relative to each other
+/// and do not need to add up to 1.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct BigBoardWeights {
+    pub consensus: f64,
+    pub grade: f64,
+    pub ras: f64,
+}
+
+impl Default for BigBoardWeights {
+    fn default() -> Self {
+        Self {
+            consensus: 0.5,
+            grade: 0.3,
+            ras: 0.2,
+        }
+    }
+}
+
+impl BigBoardWeights {
+    pub fn validate(&self) -> DomainResult<()> {
+        for (name, weight) in [
+            ("consensus", self.consensus),
+            ("grade", self.grade),
+            ("ras", self.ras),
+        ] {
+            if !weight.is_finite() || weight < 0.0 {
+                return Err(DomainError::ValidationError(format!(
+                    "Weight for {} must be zero or more",
+                    name
+                )));
+            }
+        }
+        if self.consensus + self.grade + self.ras <= 0.0 {
+            return Err(DomainError::ValidationError(
+                "At least one weight must be above zero".to_string(),
+            ));
+        }
+        Ok(())
+    }
+
+    /// 0-10 score for a place on a consensus board of `board_size`
+    /// prospects; the top prospect scores 10
+    pub fn consensus_score(consensus_rank: i64, board_size: usize) -> f64 {
+        if board_size == 0 {
+            return 0.0;
+        }
+        let size = board_size as f64;
+        10.0 * (size - consensus_rank as f64 + 1.0) / size
+    }
+
+    /// Weighted average of the 0-10 inputs a prospect has. A missing input
+    /// drops out and the other weights are scaled up, so a prospect who
+    /// skipped the combine is not scored as if their RAS were 0. `None` when
+    /// the prospect has none of the weighted inputs.
+    pub fn composite(
+        &self,
+        consensus: Option<f64>,
+        grade: Option<f64>,
+        ras: Option<f64>,
+    ) -> Option<f64> {
+        let inputs = [
+            (self.consensus, consensus),
+            (self.grade, grade),
+            (self.ras, ras),
+        ];
+        let (weighted, total_weight) = inputs
+            .iter()
+            .filter_map(|(weight, value)| value.map(|v| (*weight, v)))
+            .filter(|(weight, _)| *weight > 0.0)
+            .fold((0.0, 0.0), |(sum, total), (weight, value)| {
+                (sum + weight * value, total + weight)
+            });
+
+        if total_weight == 0.0 {
+            return None;
+        }
+        Some((weighted / total_weight * 100.0).round() / 100.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_default_weights_are_valid() {
+        assert!(BigBoardWeights::default().validate().is_ok());
+    }
+
+    #[test]
+    fn test_negative_or_all_zero_weights_rejected() {
+        let negative = BigBoardWeights {
+            grade: -0.1,
+            ..Default::default()
+        };
+        assert!(negative.validate().is_err());
+
+        let zero = BigBoardWeights {
+            consensus: 0.0,
+            grade: 0.0,
+            ras: 0.0,
+        };
+        assert!(zero.validate().is_err());
+    }
+
+    #[test]
+    fn test_consensus_score_scales_board_position() {
+        assert_eq!(BigBoardWeights::consensus_score(1, 4), 10.0);
+        assert_eq!(BigBoardWeights::consensus_score(4, 4), 2.5);
+    }
+
+    #[test]
+    fn test_composite_weights_inputs() {
+        let weights = BigBoardWeights::default();
+        // 0.5 * 10 + 0.3 * 8 + 0.2 * 6 = 8.6
+        assert_eq!(
+            weights.composite(Some(10.0), Some(8.0), Some(6.0)),
+            Some(8.6)
+        );
+    }
+
+    #[test]
+    fn test_composite_rescales_missing_inputs() {
+        let weights = BigBoardWeights::default();
+        // Without RAS: (0.5 * 10 + 0.3 * 8) / 0.8 = 9.25
+        assert_eq!(weights.composite(Some(10.0), Some(8.0), None), Some(9.25));
+        assert_eq!(weights.composite(None, None, None), None);
+
+        let ras_only = BigBoardWeights {
+            consensus: 0.0,
+            grade: 0.0,
+            ras: 1.0,
+        };
+        assert_eq!(ras_only.composite(Some(10.0), Some(8.0), None), None);
+    }
+}
@@ -0,0 +1,110 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::errors::{DomainError, DomainResult};
+
+/// Longest chat message accepted, in characters
+pub const MAX_CHAT_MESSAGE_LENGTH: usize = 500;
+
+/// A chat line posted to a draft session. `participant_id` is set when the
+/// sender joined the session as a participant; spectators post by name only.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ChatMessage {
+    pub id: Uuid,
+    pub session_id: Uuid,
+    pub participant_id: Option<Uuid>,
+    pub display_name: String,
+    pub body: String,
+    pub created_at: DateTime<Utc>,
+}
+
+impl ChatMessage {
+    pub fn new(
+        session_id: Uuid,
+        participant_id: Option<Uuid>,
+        display_name: String,
+        body: String,
+    ) -> DomainResult<Self> {
+        let display_name = display_name.trim().to_string();
+        let body = body.trim().to_string();
+        Self::validate(&display_name, &body)?;
+
+        Ok(Self {
+            id: Uuid::new_v4(),
+            session_id,
+            participant_id,
+            display_name,
+            body,
+            created_at: Utc::now(),
+        })
+    }
+
+    fn validate(display_name: &str, body: &str) -> DomainResult<()> {
+        if display_name.is_empty() {
+            return Err(DomainError::ValidationError(
+                "Display name cannot be empty".to_string(),
+            ));
+        }
+        if display_name.chars().count() > 100 {
+            return Err(DomainError::ValidationError(
+                "Display name cannot exceed 100 characters".to_string(),
+            ));
+        }
+        if body.is_empty() {
+            return Err(DomainError::ValidationError(
+                "Message cannot be empty".to_string(),
+            ));
+        }
+        if body.chars().count() > MAX_CHAT_MESSAGE_LENGTH {
+            return Err(DomainError::ValidationError(format!(
+                "Message cannot exceed {} characters",
+                MAX_CHAT_MESSAGE_LENGTH
+            )));
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_new_chat_message_trims_input() {
+        let session_id = Uuid::new_v4();
+        let message = ChatMessage::new(
+            session_id,
+            None,
+            " Alice ".to_string(),
+            "  on the clock!  ".to_string(),
+        )
+        .unwrap();
+
+        assert_eq!(message.session_id, session_id);
+        assert_eq!(message.display_name, "Alice");
+        assert_eq!(message.body, "on the clock!");
+        assert!(message.participant_id.is_none());
+    }
+
+    #[test]
+    fn test_invalid_chat_message() {
+        let session_id = Uuid::new_v4();
+        assert!(ChatMessage::new(session_id, None, "Alice".to_string(), "  ".to_string()).is_err());
+        assert!(ChatMessage::new(session_id, None, "".to_string(), "hi".to_string()).is_err());
+        assert!(ChatMessage::new(
+            session_id,
+            None,
+            "Alice".to_string(),
+            "x".repeat(MAX_CHAT_MESSAGE_LENGTH + 1)
+        )
+        .is_err());
+        assert!(ChatMessage::new(
+            session_id,
+            None,
+            "Alice".to_string(),
+            "🏈".repeat(MAX_CHAT_MESSAGE_LENGTH)
+        )
+        .is_ok());
+    }
+}
@@ -0,0 +1,237 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+use crate::errors::{DomainError, DomainResult};
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+pub struct PassingLine {
+    pub completions: i32,
+    pub attempts: i32,
+    pub yards: i32,
+    pub touchdowns: i32,
+    pub interceptions: i32,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+pub struct RushingLine {
+    pub attempts: i32,
+    pub yards: i32,
+    pub touchdowns: i32,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+pub struct ReceivingLine {
+    pub receptions: i32,
+    pub yards: i32,
+    pub touchdowns: i32,
+}
+
+/// Sacks and tackles for loss are credited in halves
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, ToSchema)]
+pub struct DefenseLine {
+    pub tackles: i32,
+    pub tackles_for_loss: f64,
+    pub sacks: f64,
+    pub interceptions: i32,
+    pub passes_defended: i32,
+    pub forced_fumbles: i32,
+}
+
+/// One college season of production for a player. Each stat line is only
+/// present when the player recorded stats in that phase.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CollegeStats {
+    pub id: Uuid,
+    pub player_id: Uuid,
+    pub season: i32,
+    /// School played for that season, when it differs from the player's
+    /// current college
+    pub school: Option<String>,
+    pub games_played: i32,
+    pub passing: Option<PassingLine>,
+    pub rushing: Option<RushingLine>,
+    pub receiving: Option<ReceivingLine>,
+    pub defense: Option<DefenseLine>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl CollegeStats {
+    pub fn new(player_id: Uuid, season: i32, games_played: i32) -> DomainResult<Self> {
+        Self::validate_season(season)?;
+        Self::validate_games_played(games_played)?;
+
+        let now = Utc::now();
+        Ok(Self {
+            id: Uuid::new_v4(),
+            player_id,
+            season,
+            school: None,
+            games_played,
+            passing: None,
+            rushing: None,
+            receiving: None,
+            defense: None,
+            created_at: now,
+            updated_at: now,
+        })
+    }
+
+    pub fn with_school(mut self, school: String) -> DomainResult<Self> {
+        if school.trim().is_empty() || school.len() > 100 {
+            return Err(DomainError::ValidationError(
+                "School must be between 1 and 100 characters".to_string(),
+            ));
+        }
+        self.school = Some(school);
+        Ok(self)
+    }
+
+    pub fn with_passing(mut self, line: PassingLine) -> DomainResult<Self> {
+        Self::validate_non_negative(
+            "Passing",
+            &[
+                line.completions,
+                line.attempts,
+                line.touchdowns,
+                line.interceptions,
+            ],
+        )?;
+        if line.completions > line.attempts {
+            return Err(DomainError::ValidationError(
+                "Passing completions cannot exceed attempts".to_string(),
+            ));
+        }
+        self.passing = Some(line);
+        Ok(self)
+    }
+
+    /// Yardage totals may be negative, so only counts are checked
+    pub fn with_rushing(mut self, line: RushingLine) -> DomainResult<Self> {
+        Self::validate_non_negative("Rushing", &[line.attempts, line.touchdowns])?;
+        self.rushing = Some(line);
+        Ok(self)
+    }
+
+    pub fn with_receiving(mut self, line: ReceivingLine) -> DomainResult<Self> {
+        Self::validate_non_negative("Receiving", &[line.receptions, line.touchdowns])?;
+        self.receiving = Some(line);
+        Ok(self)
+    }
+
+    pub fn with_defense(mut self, line: DefenseLine) -> DomainResult<Self> {
+        Self::validate_non_negative(
+            "Defense",
+            &[
+                line.tackles,
+                line.interceptions,
+                line.passes_defended,
+                line.forced_fumbles,
+            ],
+        )?;
+        if line.tackles_for_loss < 0.0 || line.sacks < 0.0 {
+            return Err(DomainError::ValidationError(
+                "Defense stats cannot be negative".to_string(),
+            ));
+        }
+        self.defense = Some(line);
+        Ok(self)
+    }
+
+    fn validate_season(season: i32) -> DomainResult<()> {
+        if !(1990..=2100).contains(&season) {
+            return Err(DomainError::ValidationError(
+                "Season must be between 1990 and 2100".to_string(),
+            ));
+        }
+        Ok(())
+    }
+
+    fn validate_games_played(games_played: i32) -> DomainResult<()> {
+        if !(0..=17).contains(&games_played) {
+            return Err(DomainError::ValidationError(
+                "Games played must be between 0 and 17".to_string(),
+            ));
+        }
+        Ok(())
+    }
+
+    fn validate_non_negative(line: &str, values: &[i32]) -> DomainResult<()> {
+        if values.iter().any(|v| *v < 0) {
+            return Err(DomainError::ValidationError(format!(
+                "{} stats cannot be negative",
+                line
+            )));
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_new_college_stats() {
+        let player_id = Uuid::new_v4();
+        let stats = CollegeStats::new(player_id, 2025, 13)
+            .unwrap()
+            .with_rushing(RushingLine {
+                attempts: 210,
+                yards: 1180,
+                touchdowns: 14,
+            })
+            .unwrap();
+
+        assert_eq!(stats.player_id, player_id);
+        assert_eq!(stats.season, 2025);
+        assert_eq!(stats.rushing.as_ref().unwrap().yards, 1180);
+        assert!(stats.passing.is_none());
+    }
+
+    #[test]
+    fn test_invalid_season_and_games() {
+        let player_id = Uuid::new_v4();
+        assert!(CollegeStats::new(player_id, 1980, 12).is_err());
+        assert!(CollegeStats::new(player_id, 2025, -1).is_err());
+        assert!(CollegeStats::new(player_id, 2025, 18).is_err());
+    }
+
+    #[test]
+    fn test_line_validation() {
+        let stats = CollegeStats::new(Uuid::new_v4(), 2025, 12).unwrap();
+
+        assert!(stats
+            .clone()
+            .with_passing(PassingLine {
+                completions: 250,
+                attempts: 240,
+                yards: 3000,
+                touchdowns: 25,
+                interceptions: 8,
+            })
+            .is_err());
+        assert!(stats
+            .clone()
+            .with_rushing(RushingLine {
+                attempts: 80,
+                yards: -45,
+                touchdowns: 2,
+            })
+            .is_ok());
+        assert!(stats
+            .clone()
+            .with_defense(DefenseLine {
+                tackles: 50,
+                tackles_for_loss: -1.0,
+                sacks: 4.5,
+                interceptions: 0,
+                passes_defended: 2,
+                forced_fumbles: 1,
+            })
+            .is_err());
+        assert!(stats.with_school(" ".to_string()).is_err());
+    }
+}
@@ -1,4 +1,4 @@
-use chrono::{DateTime, Utc};
+use chrono::{DateTime, NaiveDate, Utc};
 use serde::{Deserialize, Serialize};
 use std::fmt;
 use std::str::FromStr;
@@ -8,12 +8,22 @@ use uuid::Uuid;
 use crate::errors::{DomainError, DomainResult};
 
 /// Source of combine/athletic testing data
-#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, Serialize, Deserialize, ToSchema)]
 #[serde(rename_all = "snake_case")]
 pub enum CombineSource {
     #[default]
     Combine,
     ProDay,
+    /// Testing run by the player's school outside of pro day
+    School,
+}
+
+impl CombineSource {
+    pub const ALL: [CombineSource; 3] = [
+        CombineSource::Combine,
+        CombineSource::ProDay,
+        CombineSource::School,
+    ];
 }
 
 impl fmt::Display for CombineSource {
@@ -21,6 +31,7 @@ impl fmt::Display for CombineSource {
         match self {
             CombineSource::Combine => write!(f, "combine"),
             CombineSource::ProDay => write!(f, "pro_day"),
+            CombineSource::School => write!(f, "school"),
         }
     }
 }
@@ -32,8 +43,9 @@ impl FromStr for CombineSource {
         match s {
             "combine" => Ok(CombineSource::Combine),
             "pro_day" => Ok(CombineSource::ProDay),
+            "school" => Ok(CombineSource::School),
             _ => Err(DomainError::ValidationError(format!(
-                "Invalid combine source: '{}'. Must be 'combine' or 'pro_day'",
+                "Invalid combine source: '{}'. Must be 'combine', 'pro_day' or 'school'",
                 s
             ))),
         }
@@ -46,6 +58,8 @@ pub struct CombineResults {
     pub player_id: Uuid,
     pub year: i32,
     pub source: CombineSource,
+    /// Day the testing took place; a player can have several events per source
+    pub event_date: Option<NaiveDate>,
     pub forty_yard_dash: Option<f64>,
     pub bench_press: Option<i32>,
     pub vertical_jump: Option<f64>,
@@ -71,6 +85,7 @@ impl CombineResults {
             player_id,
             year,
             source: CombineSource::default(),
+            event_date: None,
             forty_yard_dash: None,
             bench_press: None,
             vertical_jump: None,
@@ -92,6 +107,11 @@ impl CombineResults {
         self
     }
 
+    pub fn with_event_date(mut self, event_date: NaiveDate) -> Self {
+        self.event_date = Some(event_date);
+        self
+    }
+
     pub fn with_forty_yard_dash(mut self, time: f64) -> DomainResult<Self> {
         Self::validate_forty_dash(time)?;
         self.forty_yard_dash = Some(time);
@@ -159,6 +179,11 @@ impl CombineResults {
     }
 
     // Update methods for modifying existing results
+    pub fn update_event_date(&mut self, event_date: Option<NaiveDate>) {
+        self.event_date = event_date;
+        self.updated_at = Utc::now();
+    }
+
     pub fn update_forty_yard_dash(&mut self, time: Option<f64>) -> DomainResult<()> {
         if let Some(t) = time {
             Self::validate_forty_dash(t)?;
@@ -367,6 +392,193 @@ impl CombineResults {
     }
 }
 
+/// Order in which testing sources are trusted when a measurement was taken at
+/// more than one event. Sources left out rank after the listed ones, in their
+/// default order.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SourcePrecedence(Vec<CombineSource>);
+
+impl SourcePrecedence {
+    pub fn new(order: Vec<CombineSource>) -> DomainResult<Self> {
+        if order.is_empty() {
+            return Err(DomainError::ValidationError(
+                "Source precedence must list at least one source".to_string(),
+            ));
+        }
+        for (i, source) in order.iter().enumerate() {
+            if order[..i].contains(source) {
+                return Err(DomainError::ValidationError(format!(
+                    "Source '{}' is listed more than once in the precedence",
+                    source
+                )));
+            }
+        }
+        Ok(Self(order))
+    }
+
+    pub fn order(&self) -> &[CombineSource] {
+        &self.0
+    }
+
+    fn rank(&self, source: CombineSource) -> usize {
+        self.0.iter().position(|s| *s == source).unwrap_or_else(|| {
+            self.0.len()
+                + CombineSource::ALL
+                    .iter()
+                    .position(|s| *s == source)
+                    .unwrap_or_default()
+        })
+    }
+}
+
+impl Default for SourcePrecedence {
+    fn default() -> Self {
+        Self(CombineSource::ALL.to_vec())
+    }
+}
+
+impl fmt::Display for SourcePrecedence {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let names: Vec<String> = self.0.iter().map(|s| s.to_string()).collect();
+        write!(f, "{}", names.join(","))
+    }
+}
+
+/// Parses a comma-separated list such as `pro_day,combine`
+impl FromStr for SourcePrecedence {
+    type Err = DomainError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let order = s
+            .split(',')
+            .map(|part| part.trim())
+            .filter(|part| !part.is_empty())
+            .map(CombineSource::from_str)
+            .collect::<DomainResult<Vec<_>>>()?;
+        Self::new(order)
+    }
+}
+
+/// One measurement taken from a single testing event
+#[derive(Debug, Clone, PartialEq)]
+pub struct MeasurementMark {
+    pub measurement: &'static str,
+    pub value: f64,
+    pub results_id: Uuid,
+    pub source: CombineSource,
+    pub year: i32,
+    pub event_date: Option<NaiveDate>,
+}
+
+/// Timed events, where the lowest mark is the best one
+const LOWER_IS_BETTER: &[&str] = &[
+    "forty_yard_dash",
+    "three_cone_drill",
+    "twenty_yard_shuttle",
+    "ten_yard_split",
+    "twenty_yard_split",
+];
+
+/// Body measurements rather than performances; the latest one stands
+const BODY_MEASUREMENTS: &[&str] = &["arm_length", "hand_size", "wingspan"];
+
+impl CombineResults {
+    fn measurements(&self) -> [(&'static str, Option<f64>); 11] {
+        [
+            ("forty_yard_dash", self.forty_yard_dash),
+            ("bench_press", self.bench_press.map(f64::from)),
+            ("vertical_jump", self.vertical_jump),
+            ("broad_jump", self.broad_jump.map(f64::from)),
+            ("three_cone_drill", self.three_cone_drill),
+            ("twenty_yard_shuttle", self.twenty_yard_shuttle),
+            ("arm_length", self.arm_length),
+            ("hand_size", self.hand_size),
+            ("wingspan", self.wingspan),
+            ("ten_yard_split", self.ten_yard_split),
+            ("twenty_yard_split", self.twenty_yard_split),
+        ]
+    }
+
+    /// Sort key for "most recent": year, then event date (undated events
+    /// count as older), then last update
+    fn recency_key(&self) -> (i32, Option<NaiveDate>, DateTime<Utc>) {
+        (self.year, self.event_date, self.updated_at)
+    }
+
+    /// Collapse one player's testing events into a single set of results.
+    /// Each measurement comes from the highest-precedence source that
+    /// recorded it, using that source's most recent event. The id, source and
+    /// date are those of the top-ranked event.
+    pub fn merge(
+        results: &[CombineResults],
+        precedence: &SourcePrecedence,
+    ) -> Option<CombineResults> {
+        let mut ordered: Vec<&CombineResults> = results.iter().collect();
+        ordered.sort_by(|a, b| {
+            precedence
+                .rank(a.source)
+                .cmp(&precedence.rank(b.source))
+                .then_with(|| b.recency_key().cmp(&a.recency_key()))
+        });
+
+        let (first, rest) = ordered.split_first()?;
+        let mut merged = (*first).clone();
+        for r in rest {
+            merged.forty_yard_dash = merged.forty_yard_dash.or(r.forty_yard_dash);
+            merged.bench_press = merged.bench_press.or(r.bench_press);
+            merged.vertical_jump = merged.vertical_jump.or(r.vertical_jump);
+            merged.broad_jump = merged.broad_jump.or(r.broad_jump);
+            merged.three_cone_drill = merged.three_cone_drill.or(r.three_cone_drill);
+            merged.twenty_yard_shuttle = merged.twenty_yard_shuttle.or(r.twenty_yard_shuttle);
+            merged.arm_length = merged.arm_length.or(r.arm_length);
+            merged.hand_size = merged.hand_size.or(r.hand_size);
+            merged.wingspan = merged.wingspan.or(r.wingspan);
+            merged.ten_yard_split = merged.ten_yard_split.or(r.ten_yard_split);
+            merged.twenty_yard_split = merged.twenty_yard_split.or(r.twenty_yard_split);
+        }
+        Some(merged)
+    }
+
+    /// The standout mark per measurement across one player's testing events,
+    /// regardless of source: the best performance for drills and the most
+    /// recent value for body measurements. Measurements nobody recorded are
+    /// left out.
+    pub fn best_marks(results: &[CombineResults]) -> Vec<MeasurementMark> {
+        let mut best: Vec<(MeasurementMark, &CombineResults)> = Vec::new();
+        for r in results {
+            for (measurement, value) in r.measurements() {
+                let Some(value) = value else { continue };
+                let candidate = MeasurementMark {
+                    measurement,
+                    value,
+                    results_id: r.id,
+                    source: r.source,
+                    year: r.year,
+                    event_date: r.event_date,
+                };
+
+                match best.iter_mut().find(|(m, _)| m.measurement == measurement) {
+                    None => best.push((candidate, r)),
+                    Some((current, from)) => {
+                        let better = if BODY_MEASUREMENTS.contains(&measurement) {
+                            r.recency_key() > from.recency_key()
+                        } else if LOWER_IS_BETTER.contains(&measurement) {
+                            value < current.value
+                        } else {
+                            value > current.value
+                        };
+                        if better {
+                            *current = candidate;
+                            *from = r;
+                        }
+                    }
+                }
+            }
+        }
+        best.into_iter().map(|(mark, _)| mark).collect()
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -485,6 +697,102 @@ mod tests {
             CombineSource::from_str("pro_day").unwrap(),
             CombineSource::ProDay
         );
+        assert_eq!(
+            CombineSource::from_str("school").unwrap(),
+            CombineSource::School
+        );
         assert!(CombineSource::from_str("invalid").is_err());
     }
+
+    fn event(
+        source: CombineSource,
+        date: &str,
+        forty: Option<f64>,
+        vertical: Option<f64>,
+        arm: Option<f64>,
+    ) -> CombineResults {
+        let mut results = CombineResults::new(Uuid::nil(), 2026)
+            .unwrap()
+            .with_source(source)
+            .with_event_date(date.parse().unwrap());
+        results.forty_yard_dash = forty;
+        results.vertical_jump = vertical;
+        results.arm_length = arm;
+        results
+    }
+
+    #[test]
+    fn test_source_precedence_parsing() {
+        let precedence = SourcePrecedence::from_str("pro_day, combine").unwrap();
+        assert_eq!(
+            precedence.order(),
+            &[CombineSource::ProDay, CombineSource::Combine]
+        );
+        assert_eq!(precedence.rank(CombineSource::School), 4);
+        assert_eq!(
+            SourcePrecedence::default().to_string(),
+            "combine,pro_day,school"
+        );
+
+        assert!(SourcePrecedence::from_str("").is_err());
+        assert!(SourcePrecedence::from_str("combine,combine").is_err());
+        assert!(SourcePrecedence::from_str("combine,scouts").is_err());
+    }
+
+    #[test]
+    fn test_merge_follows_precedence_then_recency() {
+        let combine = event(CombineSource::Combine, "2026-02-28", None, Some(36.0), None);
+        let early_pro_day = event(
+            CombineSource::ProDay,
+            "2026-03-10",
+            Some(4.48),
+            Some(38.0),
+            Some(32.5),
+        );
+        let late_pro_day = event(CombineSource::ProDay, "2026-04-01", Some(4.52), None, None);
+        let results = vec![early_pro_day.clone(), combine.clone(), late_pro_day.clone()];
+
+        let merged = CombineResults::merge(&results, &SourcePrecedence::default()).unwrap();
+        assert_eq!(merged.id, combine.id);
+        assert_eq!(merged.vertical_jump, Some(36.0));
+        // The combine has no 40, so the latest pro day fills it in
+        assert_eq!(merged.forty_yard_dash, Some(4.52));
+        assert_eq!(merged.arm_length, Some(32.5));
+
+        let pro_day_first = SourcePrecedence::from_str("pro_day").unwrap();
+        let merged = CombineResults::merge(&results, &pro_day_first).unwrap();
+        assert_eq!(merged.id, late_pro_day.id);
+        assert_eq!(merged.vertical_jump, Some(38.0));
+
+        assert!(CombineResults::merge(&[], &SourcePrecedence::default()).is_none());
+    }
+
+    #[test]
+    fn test_best_marks() {
+        let combine = event(
+            CombineSource::Combine,
+            "2026-02-28",
+            Some(4.55),
+            Some(36.0),
+            Some(32.25),
+        );
+        let pro_day = event(
+            CombineSource::ProDay,
+            "2026-03-20",
+            Some(4.49),
+            Some(35.0),
+            Some(32.0),
+        );
+        let marks = CombineResults::best_marks(&[combine.clone(), pro_day.clone()]);
+        assert_eq!(marks.len(), 3);
+
+        let mark = |name: &str| marks.iter().find(|m| m.measurement == name).unwrap();
+        assert_eq!(mark("forty_yard_dash").value, 4.49);
+        assert_eq!(mark("forty_yard_dash").source, CombineSource::ProDay);
+        assert_eq!(mark("vertical_jump").value, 36.0);
+        assert_eq!(mark("vertical_jump").results_id, combine.id);
+        // Body measurements take the latest event, not the largest value
+        assert_eq!(mark("arm_length").value, 32.0);
+        assert_eq!(mark("arm_length").results_id, pro_day.id);
+    }
 }
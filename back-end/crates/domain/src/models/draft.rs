@@ -1,9 +1,12 @@
+use std::cmp::Ordering;
+
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 use utoipa::ToSchema;
 use uuid::Uuid;
 
 use crate::errors::{DomainError, DomainResult};
+use crate::models::SortField;
 
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
 pub enum DraftStatus {
@@ -150,6 +153,22 @@ impl Draft {
         }
     }
 
+    /// Returns a started draft to Paused, e.g. after a session is rewound.
+    /// A draft that is already paused is left untouched.
+    pub fn reopen(&mut self) -> DomainResult<()> {
+        match self.status {
+            DraftStatus::InProgress | DraftStatus::Completed => {
+                self.status = DraftStatus::Paused;
+                self.updated_at = Utc::now();
+                Ok(())
+            }
+            DraftStatus::Paused => Ok(()),
+            DraftStatus::NotStarted => Err(DomainError::InvalidState(
+                "Cannot reopen a draft that hasn't started".to_string(),
+            )),
+        }
+    }
+
     /// Returns total picks for custom drafts, None for realistic drafts
     pub fn total_picks(&self) -> Option<i32> {
         self.picks_per_round.map(|ppr| self.rounds * ppr)
@@ -329,6 +348,35 @@ impl DraftPick {
     }
 }
 
+/// Fields the draft list can be sorted by
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DraftSortField {
+    Name,
+    Year,
+    Status,
+    CreatedAt,
+}
+
+impl SortField for DraftSortField {
+    const FIELDS: &'static [(&'static str, Self)] = &[
+        ("name", Self::Name),
+        ("year", Self::Year),
+        ("status", Self::Status),
+        ("created_at", Self::CreatedAt),
+    ];
+}
+
+impl DraftSortField {
+    pub fn compare(self, a: &Draft, b: &Draft) -> Ordering {
+        match self {
+            Self::Name => a.name.cmp(&b.name),
+            Self::Year => a.year.cmp(&b.year),
+            Self::Status => a.status.to_string().cmp(&b.status.to_string()),
+            Self::CreatedAt => a.created_at.cmp(&b.created_at),
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -435,6 +483,23 @@ mod tests {
         assert!(draft.complete().is_err());
     }
 
+    #[test]
+    fn test_draft_reopen() {
+        let mut draft = Draft::new("Test".to_string(), 2026, 7, 32).unwrap();
+
+        // Cannot reopen a draft that never started
+        assert!(draft.reopen().is_err());
+
+        draft.start().unwrap();
+        draft.complete().unwrap();
+        assert!(draft.reopen().is_ok());
+        assert_eq!(draft.status, DraftStatus::Paused);
+
+        // Reopening a paused draft is a no-op
+        assert!(draft.reopen().is_ok());
+        assert_eq!(draft.status, DraftStatus::Paused);
+    }
+
     #[test]
     fn test_create_draft_pick() {
         let draft_id = Uuid::new_v4();
@@ -17,6 +17,10 @@ pub enum EventType {
     TradeProposed,
     TradeExecuted,
     TradeRejected,
+    SessionRewound,
+    DraftCompleted,
+    SettingsChanged,
+    PickAdvanced,
 }
 
 impl std::fmt::Display for EventType {
@@ -32,6 +36,10 @@ impl std::fmt::Display for EventType {
             EventType::TradeProposed => write!(f, "TradeProposed"),
             EventType::TradeExecuted => write!(f, "TradeExecuted"),
             EventType::TradeRejected => write!(f, "TradeRejected"),
+            EventType::SessionRewound => write!(f, "SessionRewound"),
+            EventType::DraftCompleted => write!(f, "DraftCompleted"),
+            EventType::SettingsChanged => write!(f, "SettingsChanged"),
+            EventType::PickAdvanced => write!(f, "PickAdvanced"),
         }
     }
 }
@@ -51,6 +59,10 @@ impl std::str::FromStr for EventType {
             "TradeProposed" => Ok(EventType::TradeProposed),
             "TradeExecuted" => Ok(EventType::TradeExecuted),
             "TradeRejected" => Ok(EventType::TradeRejected),
+            "SessionRewound" => Ok(EventType::SessionRewound),
+            "DraftCompleted" => Ok(EventType::DraftCompleted),
+            "SettingsChanged" => Ok(EventType::SettingsChanged),
+            "PickAdvanced" => Ok(EventType::PickAdvanced),
             _ => Err(DomainError::ValidationError(format!(
                 "Invalid event type: {}",
                 s
@@ -107,6 +119,21 @@ impl DraftEvent {
         )
     }
 
+    pub fn draft_completed(session_id: Uuid, draft_id: Uuid) -> Self {
+        let data = serde_json::json!({
+            "draft_id": draft_id,
+        });
+        Self::new(session_id, EventType::DraftCompleted, data)
+    }
+
+    /// `changes` holds only the settings that were updated, with their new values
+    pub fn settings_changed(session_id: Uuid, changes: JsonValue) -> Self {
+        let data = serde_json::json!({
+            "changes": changes,
+        });
+        Self::new(session_id, EventType::SettingsChanged, data)
+    }
+
     pub fn pick_made(
         session_id: Uuid,
         pick_id: Uuid,
@@ -114,6 +141,7 @@ impl DraftEvent {
         player_id: Uuid,
         round: i32,
         pick_number: i32,
+        overall_pick: i32,
     ) -> Self {
         let data = serde_json::json!({
             "pick_id": pick_id,
@@ -121,10 +149,21 @@ impl DraftEvent {
             "player_id": player_id,
             "round": round,
             "pick_number": pick_number,
+            "overall_pick": overall_pick,
         });
         Self::new(session_id, EventType::PickMade, data)
     }
 
+    /// The clock moved on to `to_pick_number` without a pick being recorded
+    /// in this session, as when the current pick was made elsewhere
+    pub fn pick_advanced(session_id: Uuid, from_pick_number: i32, to_pick_number: i32) -> Self {
+        let data = serde_json::json!({
+            "from_pick_number": from_pick_number,
+            "to_pick_number": to_pick_number,
+        });
+        Self::new(session_id, EventType::PickAdvanced, data)
+    }
+
     pub fn clock_update(session_id: Uuid, time_remaining: i32) -> Self {
         let data = serde_json::json!({
             "time_remaining": time_remaining,
@@ -146,9 +185,20 @@ impl DraftEvent {
         Self::new(session_id, EventType::TradeProposed, data)
     }
 
-    pub fn trade_executed(session_id: Uuid, trade_id: Uuid) -> Self {
+    pub fn trade_executed(
+        session_id: Uuid,
+        trade_id: Uuid,
+        from_team_id: Uuid,
+        to_team_id: Uuid,
+        from_team_picks: &[Uuid],
+        to_team_picks: &[Uuid],
+    ) -> Self {
         let data = serde_json::json!({
             "trade_id": trade_id,
+            "from_team_id": from_team_id,
+            "to_team_id": to_team_id,
+            "from_team_picks": from_team_picks,
+            "to_team_picks": to_team_picks,
         });
         Self::new(session_id, EventType::TradeExecuted, data)
     }
@@ -160,6 +210,22 @@ impl DraftEvent {
         });
         Self::new(session_id, EventType::TradeRejected, data)
     }
+
+    pub fn session_rewound(
+        session_id: Uuid,
+        from_pick_number: i32,
+        to_pick_number: i32,
+        cleared_pick_ids: &[Uuid],
+        reversed_trade_ids: &[Uuid],
+    ) -> Self {
+        let data = serde_json::json!({
+            "from_pick_number": from_pick_number,
+            "to_pick_number": to_pick_number,
+            "cleared_pick_ids": cleared_pick_ids,
+            "reversed_trade_ids": reversed_trade_ids,
+        });
+        Self::new(session_id, EventType::SessionRewound, data)
+    }
 }
 
 #[cfg(test)]
@@ -209,12 +275,13 @@ mod tests {
         let team_id = Uuid::new_v4();
         let player_id = Uuid::new_v4();
 
-        let event = DraftEvent::pick_made(session_id, pick_id, team_id, player_id, 1, 1);
+        let event = DraftEvent::pick_made(session_id, pick_id, team_id, player_id, 2, 1, 33);
 
         assert_eq!(event.session_id, session_id);
         assert_eq!(event.event_type, EventType::PickMade);
-        assert_eq!(event.event_data["round"], 1);
+        assert_eq!(event.event_data["round"], 2);
         assert_eq!(event.event_data["pick_number"], 1);
+        assert_eq!(event.event_data["overall_pick"], 33);
     }
 
     #[test]
@@ -227,6 +294,49 @@ mod tests {
         assert_eq!(event.event_data["time_remaining"], 120);
     }
 
+    #[test]
+    fn test_create_pick_advanced_event() {
+        use std::str::FromStr;
+
+        let session_id = Uuid::new_v4();
+        let event = DraftEvent::pick_advanced(session_id, 4, 5);
+
+        assert_eq!(event.event_type, EventType::PickAdvanced);
+        assert_eq!(event.event_data["from_pick_number"], 4);
+        assert_eq!(event.event_data["to_pick_number"], 5);
+        assert_eq!(
+            EventType::from_str(&EventType::PickAdvanced.to_string()).unwrap(),
+            EventType::PickAdvanced
+        );
+    }
+
+    #[test]
+    fn test_create_trade_executed_event() {
+        let session_id = Uuid::new_v4();
+        let trade_id = Uuid::new_v4();
+        let from_team = Uuid::new_v4();
+        let to_team = Uuid::new_v4();
+        let from_pick = Uuid::new_v4();
+        let to_pick = Uuid::new_v4();
+
+        let event = DraftEvent::trade_executed(
+            session_id,
+            trade_id,
+            from_team,
+            to_team,
+            &[from_pick],
+            &[to_pick],
+        );
+
+        assert_eq!(event.event_type, EventType::TradeExecuted);
+        assert_eq!(event.event_data["trade_id"], trade_id.to_string());
+        assert_eq!(
+            event.event_data["from_team_picks"][0],
+            from_pick.to_string()
+        );
+        assert_eq!(event.event_data["to_team_picks"][0], to_pick.to_string());
+    }
+
     #[test]
     fn test_lifecycle_events() {
         let session_id = Uuid::new_v4();
@@ -242,5 +352,47 @@ mod tests {
 
         let completed = DraftEvent::session_completed(session_id);
         assert_eq!(completed.event_type, EventType::SessionCompleted);
+
+        let draft_id = Uuid::new_v4();
+        let draft_completed = DraftEvent::draft_completed(session_id, draft_id);
+        assert_eq!(draft_completed.event_type, EventType::DraftCompleted);
+        assert_eq!(draft_completed.event_data["draft_id"], draft_id.to_string());
+    }
+
+    #[test]
+    fn test_create_settings_changed_event() {
+        let session_id = Uuid::new_v4();
+        let event = DraftEvent::settings_changed(
+            session_id,
+            serde_json::json!({ "time_per_pick_seconds": 90 }),
+        );
+
+        assert_eq!(event.event_type, EventType::SettingsChanged);
+        assert_eq!(event.event_data["changes"]["time_per_pick_seconds"], 90);
+        assert!(event.event_data["changes"].get("chart_type").is_none());
+    }
+
+    #[test]
+    fn test_create_session_rewound_event() {
+        use std::str::FromStr;
+
+        let session_id = Uuid::new_v4();
+        let pick_id = Uuid::new_v4();
+        let trade_id = Uuid::new_v4();
+
+        let event = DraftEvent::session_rewound(session_id, 12, 5, &[pick_id], &[trade_id]);
+
+        assert_eq!(event.event_type, EventType::SessionRewound);
+        assert_eq!(event.event_data["from_pick_number"], 12);
+        assert_eq!(event.event_data["to_pick_number"], 5);
+        assert_eq!(event.event_data["cleared_pick_ids"][0], pick_id.to_string());
+        assert_eq!(
+            event.event_data["reversed_trade_ids"][0],
+            trade_id.to_string()
+        );
+        assert_eq!(
+            EventType::from_str(&EventType::SessionRewound.to_string()).unwrap(),
+            EventType::SessionRewound
+        );
     }
 }
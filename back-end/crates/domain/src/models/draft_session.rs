@@ -25,6 +25,25 @@ impl std::fmt::Display for SessionStatus {
     }
 }
 
+/// Settings that may be changed after a session is created. `None` leaves the
+/// current value untouched.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SessionSettingsUpdate {
+    pub time_per_pick_seconds: Option<i32>,
+    pub auto_pick_enabled: Option<bool>,
+    pub chart_type: Option<ChartType>,
+    pub controlled_team_ids: Option<Vec<Uuid>>,
+}
+
+impl SessionSettingsUpdate {
+    pub fn is_empty(&self) -> bool {
+        self.time_per_pick_seconds.is_none()
+            && self.auto_pick_enabled.is_none()
+            && self.chart_type.is_none()
+            && self.controlled_team_ids.is_none()
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct DraftSession {
     pub id: Uuid,
@@ -39,6 +58,18 @@ pub struct DraftSession {
     pub updated_at: DateTime<Utc>,
     pub started_at: Option<DateTime<Utc>>,
     pub completed_at: Option<DateTime<Utc>>,
+    /// When the current pick went on the clock
+    #[serde(default)]
+    pub pick_started_at: Option<DateTime<Utc>>,
+    /// Set while the session is paused
+    #[serde(default)]
+    pub paused_at: Option<DateTime<Utc>>,
+    /// Time spent paused during the current pick, excluding an ongoing pause
+    #[serde(default)]
+    pub elapsed_paused_ms: i64,
+    /// When set on a session that has not started, the API starts it then
+    #[serde(default)]
+    pub scheduled_start_at: Option<DateTime<Utc>>,
 }
 
 impl DraftSession {
@@ -65,6 +96,10 @@ impl DraftSession {
             updated_at: now,
             started_at: None,
             completed_at: None,
+            pick_started_at: None,
+            paused_at: None,
+            elapsed_paused_ms: 0,
+            scheduled_start_at: None,
         })
     }
 
@@ -96,10 +131,19 @@ impl DraftSession {
     pub fn start(&mut self) -> DomainResult<()> {
         match self.status {
             SessionStatus::NotStarted | SessionStatus::Paused => {
+                let now = Utc::now();
                 self.status = SessionStatus::InProgress;
-                self.updated_at = Utc::now();
+                self.updated_at = now;
                 if self.started_at.is_none() {
-                    self.started_at = Some(Utc::now());
+                    self.started_at = Some(now);
+                }
+                self.scheduled_start_at = None;
+                // Resuming keeps the pick's clock; only the pause is discounted
+                if let Some(paused_at) = self.paused_at.take() {
+                    self.elapsed_paused_ms += (now - paused_at).num_milliseconds().max(0);
+                }
+                if self.pick_started_at.is_none() {
+                    self.reset_pick_clock(now);
                 }
                 Ok(())
             }
@@ -115,8 +159,10 @@ impl DraftSession {
     pub fn pause(&mut self) -> DomainResult<()> {
         match self.status {
             SessionStatus::InProgress => {
+                let now = Utc::now();
                 self.status = SessionStatus::Paused;
-                self.updated_at = Utc::now();
+                self.updated_at = now;
+                self.paused_at = Some(now);
                 Ok(())
             }
             SessionStatus::NotStarted => Err(DomainError::InvalidState(
@@ -150,15 +196,151 @@ impl DraftSession {
 
     pub fn advance_pick(&mut self) -> DomainResult<()> {
         if self.status != SessionStatus::InProgress {
-            return Err(DomainError::InvalidState(
+            return Err(DomainError::SessionNotInProgress(
                 "Can only advance pick during an active session".to_string(),
             ));
         }
+        let now = Utc::now();
         self.current_pick_number += 1;
+        self.updated_at = now;
+        self.reset_pick_clock(now);
+        Ok(())
+    }
+
+    /// Moves the session back to `pick_number` and leaves it paused so the
+    /// clock does not restart until someone resumes the draft. Completed
+    /// sessions are reopened.
+    pub fn rewind_to(&mut self, pick_number: i32) -> DomainResult<()> {
+        if self.status == SessionStatus::NotStarted {
+            return Err(DomainError::InvalidState(
+                "Cannot rewind a session that hasn't started".to_string(),
+            ));
+        }
+        if pick_number < 1 {
+            return Err(DomainError::ValidationError(
+                "Rewind target must be greater than 0".to_string(),
+            ));
+        }
+        if pick_number > self.current_pick_number {
+            return Err(DomainError::ValidationError(format!(
+                "Cannot rewind forward from pick {} to pick {}",
+                self.current_pick_number, pick_number
+            )));
+        }
+
+        let now = Utc::now();
+        self.current_pick_number = pick_number;
+        self.status = SessionStatus::Paused;
+        self.completed_at = None;
+        self.updated_at = now;
+        self.reset_pick_clock(now);
+        self.paused_at = Some(now);
+        Ok(())
+    }
+
+    /// Applies new settings. Only allowed before the draft starts or while it
+    /// is paused so the clock and auto-pick never change under a live pick.
+    pub fn update_settings(&mut self, update: &SessionSettingsUpdate) -> DomainResult<()> {
+        match self.status {
+            SessionStatus::NotStarted | SessionStatus::Paused => {}
+            SessionStatus::InProgress => {
+                return Err(DomainError::InvalidState(
+                    "Pause the session before changing its settings".to_string(),
+                ))
+            }
+            SessionStatus::Completed => {
+                return Err(DomainError::InvalidState(
+                    "Cannot change settings of a completed session".to_string(),
+                ))
+            }
+        }
+        if update.is_empty() {
+            return Err(DomainError::ValidationError(
+                "No settings provided".to_string(),
+            ));
+        }
+        if let Some(seconds) = update.time_per_pick_seconds {
+            Self::validate_time_per_pick(seconds)?;
+        }
+
+        if let Some(seconds) = update.time_per_pick_seconds {
+            self.time_per_pick_seconds = seconds;
+        }
+        if let Some(enabled) = update.auto_pick_enabled {
+            self.auto_pick_enabled = enabled;
+        }
+        if let Some(chart_type) = update.chart_type {
+            self.chart_type = chart_type;
+        }
+        if let Some(team_ids) = &update.controlled_team_ids {
+            let mut deduped = Vec::with_capacity(team_ids.len());
+            for id in team_ids {
+                if !deduped.contains(id) {
+                    deduped.push(*id);
+                }
+            }
+            self.controlled_team_ids = deduped;
+        }
         self.updated_at = Utc::now();
         Ok(())
     }
 
+    /// Schedules the session to start automatically at `at`, which must be in
+    /// the future. Replaces any earlier schedule.
+    pub fn schedule_start(&mut self, at: DateTime<Utc>) -> DomainResult<()> {
+        if self.status != SessionStatus::NotStarted {
+            return Err(DomainError::InvalidState(
+                "Only sessions that haven't started can be scheduled".to_string(),
+            ));
+        }
+        let now = Utc::now();
+        if at <= now {
+            return Err(DomainError::ValidationError(
+                "Scheduled start must be in the future".to_string(),
+            ));
+        }
+        self.scheduled_start_at = Some(at);
+        self.updated_at = now;
+        Ok(())
+    }
+
+    /// Returns true once a scheduled session has reached its start time
+    pub fn is_start_due(&self, now: DateTime<Utc>) -> bool {
+        self.status == SessionStatus::NotStarted
+            && self.scheduled_start_at.is_some_and(|at| at <= now)
+    }
+
+    /// Milliseconds the current pick has been paused as of `now`, including
+    /// a pause that is still in progress
+    pub fn paused_ms_at(&self, now: DateTime<Utc>) -> i64 {
+        let ongoing = self
+            .paused_at
+            .map(|paused_at| (now - paused_at).num_milliseconds().max(0))
+            .unwrap_or(0);
+        self.elapsed_paused_ms + ongoing
+    }
+
+    /// Seconds left on the current pick's clock as of `now`. Paused time does
+    /// not count against the pick, so the value holds steady while paused.
+    pub fn time_remaining_at(&self, now: DateTime<Utc>) -> i32 {
+        let Some(pick_started_at) = self.pick_started_at else {
+            return self.time_per_pick_seconds;
+        };
+        let active_ms = (now - pick_started_at).num_milliseconds() - self.paused_ms_at(now);
+        let elapsed_secs = (active_ms.max(0) / 1000) as i32;
+        (self.time_per_pick_seconds - elapsed_secs).max(0)
+    }
+
+    pub fn time_remaining(&self) -> i32 {
+        self.time_remaining_at(Utc::now())
+    }
+
+    fn reset_pick_clock(&mut self, now: DateTime<Utc>) {
+        self.pick_started_at = Some(now);
+        self.paused_at = None;
+        self.elapsed_paused_ms = 0;
+    }
+
     pub fn is_active(&self) -> bool {
         self.status == SessionStatus::InProgress
     }
@@ -335,4 +517,152 @@ mod tests {
         session.pause().unwrap();
         assert!(session.advance_pick().is_err());
     }
+
+    #[test]
+    fn test_rewind_to() {
+        let draft_id = Uuid::new_v4();
+        let mut session = DraftSession::new_with_default_chart(draft_id, 300, false).unwrap();
+
+        // Cannot rewind before starting
+        assert!(session.rewind_to(1).is_err());
+
+        session.start().unwrap();
+        session.current_pick_number = 10;
+
+        // Cannot rewind forward or to an invalid pick
+        assert!(session.rewind_to(11).is_err());
+        assert!(session.rewind_to(0).is_err());
+
+        assert!(session.rewind_to(4).is_ok());
+        assert_eq!(session.current_pick_number, 4);
+        assert_eq!(session.status, SessionStatus::Paused);
+
+        // Rewinding a completed session reopens it
+        session.start().unwrap();
+        session.complete().unwrap();
+        assert!(session.rewind_to(2).is_ok());
+        assert_eq!(session.status, SessionStatus::Paused);
+        assert!(session.completed_at.is_none());
+    }
+
+    #[test]
+    fn test_update_settings() {
+        let draft_id = Uuid::new_v4();
+        let team = Uuid::new_v4();
+        let mut session = DraftSession::new_with_default_chart(draft_id, 300, false).unwrap();
+
+        let update = SessionSettingsUpdate {
+            time_per_pick_seconds: Some(60),
+            auto_pick_enabled: Some(true),
+            chart_type: Some(ChartType::RichHill),
+            controlled_team_ids: Some(vec![team, team]),
+        };
+        session.update_settings(&update).unwrap();
+        assert_eq!(session.time_per_pick_seconds, 60);
+        assert!(session.auto_pick_enabled);
+        assert_eq!(session.chart_type, ChartType::RichHill);
+        assert_eq!(session.controlled_team_ids, vec![team]);
+
+        // Omitted fields are left alone
+        let update = SessionSettingsUpdate {
+            auto_pick_enabled: Some(false),
+            ..Default::default()
+        };
+        session.update_settings(&update).unwrap();
+        assert_eq!(session.time_per_pick_seconds, 60);
+        assert!(!session.auto_pick_enabled);
+
+        assert!(session
+            .update_settings(&SessionSettingsUpdate::default())
+            .is_err());
+        let invalid = SessionSettingsUpdate {
+            time_per_pick_seconds: Some(5),
+            ..Default::default()
+        };
+        assert!(session.update_settings(&invalid).is_err());
+        assert_eq!(session.time_per_pick_seconds, 60);
+    }
+
+    #[test]
+    fn test_update_settings_requires_paused_session() {
+        let draft_id = Uuid::new_v4();
+        let mut session = DraftSession::new_with_default_chart(draft_id, 300, false).unwrap();
+        let update = SessionSettingsUpdate {
+            time_per_pick_seconds: Some(120),
+            ..Default::default()
+        };
+
+        session.start().unwrap();
+        assert!(session.update_settings(&update).is_err());
+
+        session.pause().unwrap();
+        assert!(session.update_settings(&update).is_ok());
+
+        session.complete().unwrap();
+        assert!(session.update_settings(&update).is_err());
+    }
+
+    #[test]
+    fn test_pause_does_not_consume_pick_clock() {
+        let draft_id = Uuid::new_v4();
+        let mut session = DraftSession::new_with_default_chart(draft_id, 300, false).unwrap();
+        assert_eq!(session.time_remaining(), 300);
+
+        session.start().unwrap();
+        let started = session.pick_started_at.unwrap();
+
+        // 100s on the clock, then a 50s pause
+        session.pick_started_at = Some(started - chrono::Duration::seconds(150));
+        session.status = SessionStatus::Paused;
+        session.paused_at = Some(started - chrono::Duration::seconds(50));
+        assert_eq!(session.time_remaining_at(started), 200);
+        assert_eq!(session.paused_ms_at(started), 50_000);
+
+        session.start().unwrap();
+        assert!(session.paused_at.is_none());
+        assert!(session.elapsed_paused_ms >= 50_000);
+        assert_eq!(
+            session.pick_started_at,
+            Some(started - chrono::Duration::seconds(150))
+        );
+        let remaining = session.time_remaining();
+        assert!((199..=200).contains(&remaining), "remaining {}", remaining);
+    }
+
+    #[test]
+    fn test_advance_pick_resets_pick_clock() {
+        let draft_id = Uuid::new_v4();
+        let mut session = DraftSession::new_with_default_chart(draft_id, 60, false).unwrap();
+        session.start().unwrap();
+        session.pick_started_at = Some(Utc::now() - chrono::Duration::seconds(90));
+        session.elapsed_paused_ms = 10_000;
+        assert_eq!(session.time_remaining(), 0);
+
+        session.advance_pick().unwrap();
+        assert_eq!(session.elapsed_paused_ms, 0);
+        assert_eq!(session.time_remaining(), 60);
+    }
+
+    #[test]
+    fn test_schedule_start() {
+        let draft_id = Uuid::new_v4();
+        let mut session = DraftSession::new_with_default_chart(draft_id, 60, false).unwrap();
+        let now = Utc::now();
+
+        assert!(session
+            .schedule_start(now - chrono::Duration::minutes(1))
+            .is_err());
+        assert!(!session.is_start_due(now));
+
+        let at = now + chrono::Duration::hours(2);
+        session.schedule_start(at).unwrap();
+        assert_eq!(session.scheduled_start_at, Some(at));
+        assert!(!session.is_start_due(now));
+        assert!(session.is_start_due(at));
+
+        session.start().unwrap();
+        assert!(session.scheduled_start_at.is_none());
+        assert!(!session.is_start_due(at));
+        assert!(session.schedule_start(at).is_err());
+    }
 }
@@ -1,4 +1,10 @@
+pub mod actual_draft_pick;
+pub mod adp;
+pub mod audit_entry;
+pub mod big_board;
 pub mod chart_type;
+pub mod chat_message;
+pub mod college_stats;
 pub mod combine_percentile;
 pub mod combine_results;
 pub mod draft;
@@ -6,32 +12,56 @@ pub mod draft_event;
 pub mod draft_session;
 pub mod draft_strategy;
 pub mod feldman_freak;
+pub mod pagination;
 pub mod player;
+pub mod player_note;
+pub mod player_tag;
 pub mod prospect_profile;
 pub mod prospect_ranking;
 pub mod ranking_source;
 pub mod ras_score;
 pub mod scouting_report;
+pub mod session_bundle;
+pub mod session_participant;
+pub mod sort;
 pub mod team;
 pub mod team_need;
 pub mod team_season;
 pub mod trade;
+pub mod user;
+pub mod watchlist;
 
+pub use actual_draft_pick::ActualDraftPick;
+pub use adp::AdpEntry;
+pub use audit_entry::{AuditEntry, AuditLogFilter};
+pub use big_board::BigBoardWeights;
 pub use chart_type::ChartType;
+pub use chat_message::ChatMessage;
+pub use college_stats::{CollegeStats, DefenseLine, PassingLine, ReceivingLine, RushingLine};
 pub use combine_percentile::{CombinePercentile, Measurement};
-pub use combine_results::{CombineResults, CombineSource};
-pub use draft::{Draft, DraftPick, DraftStatus};
+pub use combine_results::{CombineResults, CombineSource, MeasurementMark, SourcePrecedence};
+pub use draft::{Draft, DraftPick, DraftSortField, DraftStatus};
 pub use draft_event::{DraftEvent, EventType};
-pub use draft_session::{DraftSession, SessionStatus};
+pub use draft_session::{DraftSession, SessionSettingsUpdate, SessionStatus};
 pub use draft_strategy::{DraftStrategy, PositionValueMap};
 pub use feldman_freak::FeldmanFreak;
-pub use player::{Player, Position};
+pub use pagination::{seek_after, Page, PageRequest, MAX_PER_PAGE};
+pub use player::{InjuryStatus, Player, PlayerFilter, PlayerSortField, PlayerUpdate, Position};
+pub use player_note::{NoteTag, PlayerNote};
+pub use player_tag::{PlayerTag, TagCount};
 pub use prospect_profile::ProspectProfile;
-pub use prospect_ranking::{PlayerRankingWithSource, ProspectRanking};
+pub use prospect_ranking::{
+    ConsensusRanking, PlayerRankingWithSource, ProspectRanking, RankingSortField,
+};
 pub use ranking_source::RankingSource;
 pub use ras_score::{MeasurementScore, RasScore};
-pub use scouting_report::{FitGrade, ScoutingReport};
-pub use team::{Conference, Division, Team};
+pub use scouting_report::{FitGrade, ScoutingReport, ScoutingReportFilter};
+pub use session_bundle::{BundlePlayer, BundleTeam, SessionBundle, SESSION_BUNDLE_VERSION};
+pub use session_participant::SessionParticipant;
+pub use sort::{Sort, SortField, SortKey};
+pub use team::{Conference, Division, Team, TeamDependents, TeamSortField, TeamUpdate};
 pub use team_need::TeamNeed;
 pub use team_season::{PlayoffResult, TeamSeason};
 pub use trade::{PickTrade, PickTradeDetail, TradeDirection, TradeProposal, TradeStatus};
+pub use user::{Role, User, MIN_PASSWORD_LENGTH};
+pub use watchlist::WatchlistEntry;
@@ -0,0 +1,143 @@
+use uuid::Uuid;
+
+use crate::errors::{DomainError, DomainResult};
+
+/// Largest page a client may request from a list endpoint
+pub const MAX_PER_PAGE: u32 = 500;
+
+/// A 1-based page of a collection
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PageRequest {
+    pub page: u32,
+    pub per_page: u32,
+}
+
+impl PageRequest {
+    pub fn new(page: u32, per_page: u32) -> DomainResult<Self> {
+        if page == 0 {
+            return Err(DomainError::ValidationError(
+                "page must be 1 or greater".to_string(),
+            ));
+        }
+        if !(1..=MAX_PER_PAGE).contains(&per_page) {
+            return Err(DomainError::ValidationError(format!(
+                "per_page must be between 1 and {}",
+                MAX_PER_PAGE
+            )));
+        }
+        Ok(Self { page, per_page })
+    }
+
+    pub fn limit(&self) -> i64 {
+        i64::from(self.per_page)
+    }
+
+    pub fn offset(&self) -> i64 {
+        i64::from(self.page - 1) * i64::from(self.per_page)
+    }
+
+    /// Cuts this page out of a fully loaded collection
+    pub fn slice<T>(&self, items: Vec<T>) -> Page<T> {
+        let total = items.len() as i64;
+        let items = items
+            .into_iter()
+            .skip(self.offset() as usize)
+            .take(self.per_page as usize)
+            .collect();
+        Page { items, total }
+    }
+}
+
+/// One page of results along with the size of the whole collection
+#[derive(Debug, Clone, PartialEq)]
+pub struct Page<T> {
+    pub items: Vec<T>,
+    pub total: i64,
+}
+
+impl<T> Page<T> {
+    /// Wraps a fully loaded collection, cutting out the requested page if any
+    pub fn from_all(items: Vec<T>, page: Option<PageRequest>) -> Self {
+        match page {
+            Some(page) => page.slice(items),
+            None => Page {
+                total: items.len() as i64,
+                items,
+            },
+        }
+    }
+
+    pub fn map<U>(self, f: impl FnMut(T) -> U) -> Page<U> {
+        Page {
+            items: self.items.into_iter().map(f).collect(),
+            total: self.total,
+        }
+    }
+}
+
+/// Up to `limit` items that follow the item with ID `after_id` in an
+/// already ordered collection, or from the start when it is `None`. Nothing
+/// follows an ID the collection doesn't contain. This is the in-memory
+/// counterpart of a keyset query.
+pub fn seek_after<T>(
+    items: Vec<T>,
+    after_id: Option<Uuid>,
+    limit: i64,
+    id: impl Fn(&T) -> Uuid,
+) -> Vec<T> {
+    let start = match after_id {
+        Some(after_id) => match items.iter().position(|item| id(item) == after_id) {
+            Some(index) => index + 1,
+            None => return Vec::new(),
+        },
+        None => 0,
+    };
+    items
+        .into_iter()
+        .skip(start)
+        .take(limit.max(0) as usize)
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_limit_and_offset() {
+        let page = PageRequest::new(3, 25).unwrap();
+        assert_eq!(page.limit(), 25);
+        assert_eq!(page.offset(), 50);
+    }
+
+    #[test]
+    fn test_slice() {
+        let page = PageRequest::new(2, 2).unwrap().slice(vec![1, 2, 3, 4, 5]);
+        assert_eq!(page.items, vec![3, 4]);
+        assert_eq!(page.total, 5);
+
+        let past_end = PageRequest::new(4, 2).unwrap().slice(vec![1, 2, 3]);
+        assert!(past_end.items.is_empty());
+        assert_eq!(past_end.total, 3);
+    }
+
+    #[test]
+    fn test_seek_after() {
+        let ids: Vec<Uuid> = (0..5).map(|_| Uuid::new_v4()).collect();
+        let seek = |after_id, limit| seek_after(ids.clone(), after_id, limit, |id| *id);
+
+        assert_eq!(seek(None, 2), ids[..2]);
+        assert_eq!(seek(Some(ids[1]), 2), ids[2..4]);
+        assert_eq!(seek(Some(ids[3]), 10), ids[4..]);
+        assert!(seek(Some(ids[4]), 10).is_empty());
+        assert!(seek(Some(Uuid::new_v4()), 10).is_empty());
+    }
+
+    #[test]
+    fn test_rejects_out_of_range_values() {
+        assert!(PageRequest::new(0, 25).is_err());
+        assert!(PageRequest::new(1, 0).is_err());
+        assert!(PageRequest::new(1, MAX_PER_PAGE + 1).is_err());
+        assert!(PageRequest::new(1, MAX_PER_PAGE).is_ok());
+    }
+}
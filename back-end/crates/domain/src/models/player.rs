@@ -1,9 +1,14 @@
-use chrono::{DateTime, Utc};
+use std::cmp::Ordering;
+use std::fmt;
+use std::str::FromStr;
+
+use chrono::{DateTime, NaiveDate, Utc};
 use serde::{Deserialize, Serialize};
 use utoipa::ToSchema;
 use uuid::Uuid;
 
 use crate::errors::{DomainError, DomainResult};
+use crate::models::SortField;
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, ToSchema)]
 pub enum Position {
@@ -26,6 +31,65 @@ pub enum Position {
     P,
 }
 
+/// Current injury status, ordered from least to most severe
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum InjuryStatus {
+    #[default]
+    Healthy,
+    /// Minor knock, expected to be cleared before the draft
+    Questionable,
+    /// Limited in pre-draft workouts
+    Limited,
+    /// Unable to work out before the draft
+    Out,
+    /// Expected to miss a significant part of the rookie season
+    LongTerm,
+}
+
+impl InjuryStatus {
+    /// Multiplier applied to the base injury penalty when evaluating a player
+    pub fn severity(self) -> f64 {
+        match self {
+            InjuryStatus::Healthy => 0.0,
+            InjuryStatus::Questionable => 0.5,
+            InjuryStatus::Limited => 1.0,
+            InjuryStatus::Out => 1.5,
+            InjuryStatus::LongTerm => 2.0,
+        }
+    }
+}
+
+impl fmt::Display for InjuryStatus {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            InjuryStatus::Healthy => write!(f, "healthy"),
+            InjuryStatus::Questionable => write!(f, "questionable"),
+            InjuryStatus::Limited => write!(f, "limited"),
+            InjuryStatus::Out => write!(f, "out"),
+            InjuryStatus::LongTerm => write!(f, "long_term"),
+        }
+    }
+}
+
+impl FromStr for InjuryStatus {
+    type Err = DomainError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "healthy" => Ok(InjuryStatus::Healthy),
+            "questionable" => Ok(InjuryStatus::Questionable),
+            "limited" => Ok(InjuryStatus::Limited),
+            "out" => Ok(InjuryStatus::Out),
+            "long_term" => Ok(InjuryStatus::LongTerm),
+            _ => Err(DomainError::ValidationError(format!(
+                "Invalid injury status: '{}'. Must be one of healthy, questionable, limited, out, long_term",
+                s
+            ))),
+        }
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct Player {
     pub id: Uuid,
@@ -37,6 +101,15 @@ pub struct Player {
     pub weight_pounds: Option<i32>,
     pub draft_year: i32,
     pub draft_eligible: bool,
+    pub injury_status: InjuryStatus,
+    /// Injured body part, e.g. `knee` or `hamstring`
+    pub injury_body_part: Option<String>,
+    pub injury_expected_return: Option<NaiveDate>,
+    /// Red flag raised in medical evaluations, independent of current status
+    pub medical_flag: bool,
+    /// Player page on the ranking source the player was scraped from
+    pub profile_url: Option<String>,
+    pub headshot_url: Option<String>,
     pub created_at: DateTime<Utc>,
     pub updated_at: DateTime<Utc>,
 }
@@ -68,22 +141,19 @@ impl Player {
             weight_pounds: None,
             draft_year,
             draft_eligible: true,
+            injury_status: InjuryStatus::Healthy,
+            injury_body_part: None,
+            injury_expected_return: None,
+            medical_flag: false,
+            profile_url: None,
+            headshot_url: None,
             created_at: now,
             updated_at: now,
         })
     }
 
     pub fn with_college(mut self, college: String) -> DomainResult<Self> {
-        if college.trim().is_empty() {
-            return Err(DomainError::ValidationError(
-                "College name cannot be empty".to_string(),
-            ));
-        }
-        if college.len() > 100 {
-            return Err(DomainError::ValidationError(
-                "College name cannot exceed 100 characters".to_string(),
-            ));
-        }
+        Self::validate_college(&college)?;
         self.college = Some(college);
         Ok(self)
     }
@@ -100,6 +170,119 @@ impl Player {
         Ok(self)
     }
 
+    pub fn with_injury(
+        mut self,
+        status: InjuryStatus,
+        body_part: Option<String>,
+        expected_return: Option<NaiveDate>,
+    ) -> DomainResult<Self> {
+        if let Some(body_part) = &body_part {
+            Self::validate_injury_body_part(body_part)?;
+        }
+        self.injury_status = status;
+        self.injury_body_part = body_part;
+        self.injury_expected_return = expected_return;
+        Ok(self)
+    }
+
+    pub fn with_medical_flag(mut self, medical_flag: bool) -> Self {
+        self.medical_flag = medical_flag;
+        self
+    }
+
+    pub fn with_profile_urls(
+        mut self,
+        profile_url: Option<String>,
+        headshot_url: Option<String>,
+    ) -> DomainResult<Self> {
+        if let Some(url) = &profile_url {
+            Self::validate_url(url, "Profile URL")?;
+        }
+        if let Some(url) = &headshot_url {
+            Self::validate_url(url, "Headshot URL")?;
+        }
+        self.profile_url = profile_url;
+        self.headshot_url = headshot_url;
+        Ok(self)
+    }
+
+    /// Combined injury severity: the status multiplier plus one for a
+    /// medical red flag
+    pub fn injury_severity(&self) -> f64 {
+        let flag = if self.medical_flag { 1.0 } else { 0.0 };
+        self.injury_status.severity() + flag
+    }
+
+    /// Applies a partial update. Every provided field is validated before
+    /// any of them is changed.
+    pub fn apply_update(&mut self, update: &PlayerUpdate) -> DomainResult<()> {
+        if update.is_empty() {
+            return Err(DomainError::ValidationError(
+                "No fields provided".to_string(),
+            ));
+        }
+        if let Some(first_name) = &update.first_name {
+            Self::validate_name(first_name, "First name")?;
+        }
+        if let Some(last_name) = &update.last_name {
+            Self::validate_name(last_name, "Last name")?;
+        }
+        if let Some(Some(college)) = &update.college {
+            Self::validate_college(college)?;
+        }
+        if let Some(Some(height_inches)) = update.height_inches {
+            Self::validate_height(height_inches)?;
+        }
+        if let Some(Some(weight_pounds)) = update.weight_pounds {
+            Self::validate_weight(weight_pounds)?;
+        }
+        if let Some(draft_year) = update.draft_year {
+            Self::validate_draft_year(draft_year)?;
+        }
+        if let Some(Some(body_part)) = &update.injury_body_part {
+            Self::validate_injury_body_part(body_part)?;
+        }
+
+        if let Some(first_name) = &update.first_name {
+            self.first_name = first_name.clone();
+        }
+        if let Some(last_name) = &update.last_name {
+            self.last_name = last_name.clone();
+        }
+        if let Some(position) = update.position {
+            self.position = position;
+        }
+        if let Some(college) = &update.college {
+            self.college = college.clone();
+        }
+        if let Some(height_inches) = update.height_inches {
+            self.height_inches = height_inches;
+        }
+        if let Some(weight_pounds) = update.weight_pounds {
+            self.weight_pounds = weight_pounds;
+        }
+        if let Some(draft_year) = update.draft_year {
+            self.draft_year = draft_year;
+        }
+        if let Some(draft_eligible) = update.draft_eligible {
+            self.draft_eligible = draft_eligible;
+        }
+        if let Some(injury_status) = update.injury_status {
+            self.injury_status = injury_status;
+        }
+        if let Some(body_part) = &update.injury_body_part {
+            self.injury_body_part = body_part.clone();
+        }
+        if let Some(expected_return) = update.injury_expected_return {
+            self.injury_expected_return = expected_return;
+        }
+        if let Some(medical_flag) = update.medical_flag {
+            self.medical_flag = medical_flag;
+        }
+        self.updated_at = Utc::now();
+        Ok(())
+    }
+
     pub fn full_name(&self) -> String {
         format!("{} {}", self.first_name, self.last_name)
     }
@@ -120,6 +303,20 @@ impl Player {
         Ok(())
     }
 
+    fn validate_college(college: &str) -> DomainResult<()> {
+        if college.trim().is_empty() {
+            return Err(DomainError::ValidationError(
+                "College name cannot be empty".to_string(),
+            ));
+        }
+        if college.len() > 100 {
+            return Err(DomainError::ValidationError(
+                "College name cannot exceed 100 characters".to_string(),
+            ));
+        }
+        Ok(())
+    }
+
     fn validate_draft_year(year: i32) -> DomainResult<()> {
         if !(1936..=2100).contains(&year) {
             return Err(DomainError::ValidationError(
@@ -140,6 +337,36 @@ impl Player {
         Ok(())
     }
 
+    fn validate_url(url: &str, field: &str) -> DomainResult<()> {
+        if !(url.starts_with("https://") || url.starts_with("http://")) {
+            return Err(DomainError::ValidationError(format!(
+                "{} must be an http(s) URL",
+                field
+            )));
+        }
+        if url.len() > 500 {
+            return Err(DomainError::ValidationError(format!(
+                "{} cannot exceed 500 characters",
+                field
+            )));
+        }
+        Ok(())
+    }
+
+    fn validate_injury_body_part(body_part: &str) -> DomainResult<()> {
+        if body_part.trim().is_empty() {
+            return Err(DomainError::ValidationError(
+                "Injury body part cannot be empty".to_string(),
+            ));
+        }
+        if body_part.len() > 50 {
+            return Err(DomainError::ValidationError(
+                "Injury body part cannot exceed 50 characters".to_string(),
+            ));
+        }
+        Ok(())
+    }
+
     fn validate_weight(weight_pounds: i32) -> DomainResult<()> {
         if !(Self::MIN_WEIGHT_POUNDS..=Self::MAX_WEIGHT_POUNDS).contains(&weight_pounds) {
             return Err(DomainError::ValidationError(format!(
@@ -152,10 +379,265 @@ impl Player {
     }
 }
 
+/// Corrections to an existing player. `None` leaves a field untouched; for
+/// the nullable fields `Some(None)` clears the stored value.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct PlayerUpdate {
+    pub first_name: Option<String>,
+    pub last_name: Option<String>,
+    pub position: Option<Position>,
+    pub college: Option<Option<String>>,
+    pub height_inches: Option<Option<i32>>,
+    pub weight_pounds: Option<Option<i32>>,
+    pub draft_year: Option<i32>,
+    pub draft_eligible: Option<bool>,
+    pub injury_status: Option<InjuryStatus>,
+    pub injury_body_part: Option<Option<String>>,
+    pub injury_expected_return: Option<Option<NaiveDate>>,
+    pub medical_flag: Option<bool>,
+}
+
+impl PlayerUpdate {
+    pub fn is_empty(&self) -> bool {
+        self.first_name.is_none()
+            && self.last_name.is_none()
+            && self.position.is_none()
+            && self.college.is_none()
+            && self.height_inches.is_none()
+            && self.weight_pounds.is_none()
+            && self.draft_year.is_none()
+            && self.draft_eligible.is_none()
+            && self.injury_status.is_none()
+            && self.injury_body_part.is_none()
+            && self.injury_expected_return.is_none()
+            && self.medical_flag.is_none()
+    }
+}
+
+/// Criteria for narrowing the player list. Every field is optional and the
+/// set fields are combined with AND.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct PlayerFilter {
+    pub position: Option<Position>,
+    /// Case-insensitive substring match on college
+    pub college: Option<String>,
+    pub draft_year: Option<i32>,
+    pub draft_eligible: Option<bool>,
+    pub min_height_inches: Option<i32>,
+    pub max_height_inches: Option<i32>,
+    pub min_weight_pounds: Option<i32>,
+    pub max_weight_pounds: Option<i32>,
+    /// Only players with (or, when false, without) any combine results
+    pub has_combine_results: Option<bool>,
+    /// Only players carrying every one of these tags; expected normalized
+    /// and without duplicates
+    pub tags: Option<Vec<String>>,
+    /// Only these players
+    pub ids: Option<Vec<Uuid>>,
+}
+
+impl PlayerFilter {
+    pub fn validate(&self) -> DomainResult<()> {
+        if let (Some(min), Some(max)) = (self.min_height_inches, self.max_height_inches) {
+            if min > max {
+                return Err(DomainError::ValidationError(
+                    "min_height_inches cannot exceed max_height_inches".to_string(),
+                ));
+            }
+        }
+        if let (Some(min), Some(max)) = (self.min_weight_pounds, self.max_weight_pounds) {
+            if min > max {
+                return Err(DomainError::ValidationError(
+                    "min_weight_pounds cannot exceed max_weight_pounds".to_string(),
+                ));
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Fields the player list can be sorted by
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PlayerSortField {
+    FirstName,
+    LastName,
+    Position,
+    College,
+    HeightInches,
+    WeightPounds,
+    DraftYear,
+}
+
+impl SortField for PlayerSortField {
+    const FIELDS: &'static [(&'static str, Self)] = &[
+        ("first_name", Self::FirstName),
+        ("last_name", Self::LastName),
+        ("position", Self::Position),
+        ("college", Self::College),
+        ("height_inches", Self::HeightInches),
+        ("weight_pounds", Self::WeightPounds),
+        ("draft_year", Self::DraftYear),
+    ];
+}
+
+impl PlayerSortField {
+    pub fn compare(self, a: &Player, b: &Player) -> Ordering {
+        match self {
+            Self::FirstName => a.first_name.cmp(&b.first_name),
+            Self::LastName => a.last_name.cmp(&b.last_name),
+            Self::Position => format!("{:?}", a.position).cmp(&format!("{:?}", b.position)),
+            Self::College => a.college.cmp(&b.college),
+            Self::HeightInches => a.height_inches.cmp(&b.height_inches),
+            Self::WeightPounds => a.weight_pounds.cmp(&b.weight_pounds),
+            Self::DraftYear => a.draft_year.cmp(&b.draft_year),
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_apply_update_changes_only_provided_fields() {
+        let mut player = Player::new("John".to_string(), "Doe".to_string(), Position::QB, 2026)
+            .unwrap()
+            .with_college("Texas".to_string())
+            .unwrap()
+            .with_physical_stats(75, 220)
+            .unwrap();
+
+        player
+            .apply_update(&PlayerUpdate {
+                last_name: Some("Smith".to_string()),
+                college: Some(None),
+                weight_pounds: Some(Some(225)),
+                ..Default::default()
+            })
+            .unwrap();
+
+        assert_eq!(player.first_name, "John");
+        assert_eq!(player.last_name, "Smith");
+        assert_eq!(player.college, None);
+        assert_eq!(player.height_inches, Some(75));
+        assert_eq!(player.weight_pounds, Some(225));
+    }
+
+    #[test]
+    fn test_apply_update_is_all_or_nothing() {
+        let mut player =
+            Player::new("John".to_string(), "Doe".to_string(), Position::QB, 2026).unwrap();
+        let original = player.clone();
+
+        assert!(player.apply_update(&PlayerUpdate::default()).is_err());
+        assert!(player
+            .apply_update(&PlayerUpdate {
+                first_name: Some("Jim".to_string()),
+                height_inches: Some(Some(40)),
+                ..Default::default()
+            })
+            .is_err());
+        assert_eq!(player, original);
+    }
+
+    #[test]
+    fn test_injury_fields_and_severity() {
+        let player =
+            Player::new("John".to_string(), "Doe".to_string(), Position::RB, 2026).unwrap();
+        assert_eq!(player.injury_status, InjuryStatus::Healthy);
+        assert_eq!(player.injury_severity(), 0.0);
+
+        let return_date = NaiveDate::from_ymd_opt(2026, 8, 1).unwrap();
+        let mut player = player
+            .with_injury(
+                InjuryStatus::Out,
+                Some("knee".to_string()),
+                Some(return_date),
+            )
+            .unwrap()
+            .with_medical_flag(true);
+        assert_eq!(player.injury_body_part.as_deref(), Some("knee"));
+        assert_eq!(player.injury_expected_return, Some(return_date));
+        assert_eq!(player.injury_severity(), 2.5);
+
+        player
+            .apply_update(&PlayerUpdate {
+                injury_status: Some(InjuryStatus::Healthy),
+                injury_body_part: Some(None),
+                injury_expected_return: Some(None),
+                medical_flag: Some(false),
+                ..Default::default()
+            })
+            .unwrap();
+        assert_eq!(player.injury_status, InjuryStatus::Healthy);
+        assert_eq!(player.injury_body_part, None);
+        assert_eq!(player.injury_severity(), 0.0);
+
+        assert!(player
+            .clone()
+            .with_injury(InjuryStatus::Limited, Some(" ".to_string()), None)
+            .is_err());
+    }
+
+    #[test]
+    fn test_profile_urls() {
+        let player =
+            Player::new("John".to_string(), "Doe".to_string(), Position::WR, 2026).unwrap();
+        assert_eq!(player.profile_url, None);
+        assert_eq!(player.headshot_url, None);
+
+        let player = player
+            .with_profile_urls(
+                Some("https://www.espn.com/college-football/player/_/id/1".to_string()),
+                Some("https://a.espncdn.com/i/headshots/1.png".to_string()),
+            )
+            .unwrap();
+        assert_eq!(
+            player.headshot_url.as_deref(),
+            Some("https://a.espncdn.com/i/headshots/1.png")
+        );
+
+        assert!(player
+            .clone()
+            .with_profile_urls(Some("javascript:alert(1)".to_string()), None)
+            .is_err());
+        let long = format!("https://example.com/{}", "a".repeat(500));
+        assert!(player.with_profile_urls(None, Some(long)).is_err());
+    }
+
+    #[test]
+    fn test_injury_status_round_trip() {
+        for status in [
+            InjuryStatus::Healthy,
+            InjuryStatus::Questionable,
+            InjuryStatus::Limited,
+            InjuryStatus::Out,
+            InjuryStatus::LongTerm,
+        ] {
+            assert_eq!(status.to_string().parse::<InjuryStatus>().unwrap(), status);
+        }
+        assert!("probable".parse::<InjuryStatus>().is_err());
+    }
+
+    #[test]
+    fn test_player_filter_rejects_inverted_ranges() {
+        assert!(PlayerFilter::default().validate().is_ok());
+
+        let filter = PlayerFilter {
+            min_height_inches: Some(76),
+            max_height_inches: Some(72),
+            ..Default::default()
+        };
+        assert!(filter.validate().is_err());
+
+        let filter = PlayerFilter {
+            min_weight_pounds: Some(200),
+            max_weight_pounds: Some(200),
+            ..Default::default()
+        };
+        assert!(filter.validate().is_ok());
+    }
+
     #[test]
     fn test_create_valid_player() {
         let player = Player::new("John".to_string(), "Doe".to_string(), Position::QB, 2026);
@@ -0,0 +1,224 @@
+use chrono::{DateTime, NaiveDate, Utc};
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::str::FromStr;
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+use crate::errors::{DomainError, DomainResult};
+
+/// What a player note is about
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum NoteTag {
+    #[default]
+    General,
+    Injury,
+    Visit,
+    Riser,
+    Faller,
+}
+
+impl fmt::Display for NoteTag {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            NoteTag::General => write!(f, "general"),
+            NoteTag::Injury => write!(f, "injury"),
+            NoteTag::Visit => write!(f, "visit"),
+            NoteTag::Riser => write!(f, "riser"),
+            NoteTag::Faller => write!(f, "faller"),
+        }
+    }
+}
+
+impl FromStr for NoteTag {
+    type Err = DomainError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "general" => Ok(NoteTag::General),
+            "injury" => Ok(NoteTag::Injury),
+            "visit" => Ok(NoteTag::Visit),
+            "riser" => Ok(NoteTag::Riser),
+            "faller" => Ok(NoteTag::Faller),
+            _ => Err(DomainError::ValidationError(format!(
+                "Invalid note tag: '{}'. Must be one of general, injury, visit, riser, faller",
+                s
+            ))),
+        }
+    }
+}
+
+/// A dated piece of news about a player, attributed to its source
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, ToSchema)]
+pub struct PlayerNote {
+    pub id: Uuid,
+    pub player_id: Uuid,
+    /// Where the news came from, e.g. a reporter or outlet
+    pub source: String,
+    pub note_date: NaiveDate,
+    pub tag: NoteTag,
+    pub content: String,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl PlayerNote {
+    pub fn new(
+        player_id: Uuid,
+        source: String,
+        note_date: NaiveDate,
+        tag: NoteTag,
+        content: String,
+    ) -> DomainResult<Self> {
+        Self::validate_source(&source)?;
+        Self::validate_content(&content)?;
+
+        let now = Utc::now();
+        Ok(Self {
+            id: Uuid::new_v4(),
+            player_id,
+            source,
+            note_date,
+            tag,
+            content,
+            created_at: now,
+            updated_at: now,
+        })
+    }
+
+    pub fn update(
+        &mut self,
+        source: String,
+        note_date: NaiveDate,
+        tag: NoteTag,
+        content: String,
+    ) -> DomainResult<()> {
+        Self::validate_source(&source)?;
+        Self::validate_content(&content)?;
+        self.source = source;
+        self.note_date = note_date;
+        self.tag = tag;
+        self.content = content;
+        self.updated_at = Utc::now();
+        Ok(())
+    }
+
+    fn validate_source(source: &str) -> DomainResult<()> {
+        if source.trim().is_empty() {
+            return Err(DomainError::ValidationError(
+                "Note source cannot be empty".to_string(),
+            ));
+        }
+        if source.len() > 100 {
+            return Err(DomainError::ValidationError(
+                "Note source cannot exceed 100 characters".to_string(),
+            ));
+        }
+        Ok(())
+    }
+
+    fn validate_content(content: &str) -> DomainResult<()> {
+        if content.trim().is_empty() {
+            return Err(DomainError::ValidationError(
+                "Note content cannot be empty".to_string(),
+            ));
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn date() -> NaiveDate {
+        NaiveDate::from_ymd_opt(2026, 3, 10).unwrap()
+    }
+
+    #[test]
+    fn test_new_player_note() {
+        let player_id = Uuid::new_v4();
+        let note = PlayerNote::new(
+            player_id,
+            "Team beat reporter".to_string(),
+            date(),
+            NoteTag::Visit,
+            "Top-30 visit with the Bears".to_string(),
+        )
+        .unwrap();
+
+        assert_eq!(note.player_id, player_id);
+        assert_eq!(note.tag, NoteTag::Visit);
+        assert_eq!(note.note_date, date());
+    }
+
+    #[test]
+    fn test_invalid_source_and_content() {
+        let player_id = Uuid::new_v4();
+        assert!(PlayerNote::new(
+            player_id,
+            "  ".to_string(),
+            date(),
+            NoteTag::General,
+            "x".to_string()
+        )
+        .is_err());
+        assert!(PlayerNote::new(
+            player_id,
+            "a".repeat(101),
+            date(),
+            NoteTag::General,
+            "x".to_string()
+        )
+        .is_err());
+        assert!(PlayerNote::new(
+            player_id,
+            "source".to_string(),
+            date(),
+            NoteTag::General,
+            "".to_string()
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn test_update_note() {
+        let mut note = PlayerNote::new(
+            Uuid::new_v4(),
+            "source".to_string(),
+            date(),
+            NoteTag::General,
+            "Solid pro day".to_string(),
+        )
+        .unwrap();
+
+        note.update(
+            "Insider".to_string(),
+            date(),
+            NoteTag::Riser,
+            "Climbing boards after pro day".to_string(),
+        )
+        .unwrap();
+        assert_eq!(note.tag, NoteTag::Riser);
+        assert_eq!(note.source, "Insider");
+
+        assert!(note
+            .update("".to_string(), date(), NoteTag::Riser, "x".to_string())
+            .is_err());
+    }
+
+    #[test]
+    fn test_note_tag_round_trip() {
+        for tag in [
+            NoteTag::General,
+            NoteTag::Injury,
+            NoteTag::Visit,
+            NoteTag::Riser,
+            NoteTag::Faller,
+        ] {
+            assert_eq!(tag.to_string().parse::<NoteTag>().unwrap(), tag);
+        }
+        assert!("rumor".parse::<NoteTag>().is_err());
+    }
+}
@@ -0,0 +1,84 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::errors::{DomainError, DomainResult};
+
+/// A free-form label on a player, such as "team captain" or "small-school"
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PlayerTag {
+    pub player_id: Uuid,
+    /// Always normalized; see `PlayerTag::normalize`
+    pub tag: String,
+    pub created_at: DateTime<Utc>,
+}
+
+/// A tag in use and how many players carry it
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TagCount {
+    pub tag: String,
+    pub player_count: i64,
+}
+
+impl PlayerTag {
+    pub fn new(player_id: Uuid, tag: &str) -> DomainResult<Self> {
+        Ok(Self {
+            player_id,
+            tag: Self::normalize(tag)?,
+            created_at: Utc::now(),
+        })
+    }
+
+    /// Trims, lowercases and collapses inner whitespace, so "Team  Captain "
+    /// and "team captain" are the same tag. Commas are rejected because the
+    /// player list takes tags as a comma-separated filter.
+    pub fn normalize(tag: &str) -> DomainResult<String> {
+        let tag = tag
+            .split_whitespace()
+            .collect::<Vec<_>>()
+            .join(" ")
+            .to_lowercase();
+        if tag.is_empty() {
+            return Err(DomainError::ValidationError(
+                "Tag cannot be empty".to_string(),
+            ));
+        }
+        if tag.chars().count() > 50 {
+            return Err(DomainError::ValidationError(
+                "Tag cannot exceed 50 characters".to_string(),
+            ));
+        }
+        if tag.contains(',') {
+            return Err(DomainError::ValidationError(
+                "Tag cannot contain a comma".to_string(),
+            ));
+        }
+        Ok(tag)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_new_player_tag_normalizes() {
+        let player_id = Uuid::new_v4();
+        let tag = PlayerTag::new(player_id, "  Team   Captain ").unwrap();
+
+        assert_eq!(tag.player_id, player_id);
+        assert_eq!(tag.tag, "team captain");
+        assert_eq!(
+            PlayerTag::normalize("Small-School").unwrap(),
+            "small-school"
+        );
+    }
+
+    #[test]
+    fn test_invalid_tags() {
+        assert!(PlayerTag::normalize("   ").is_err());
+        assert!(PlayerTag::normalize(&"a".repeat(51)).is_err());
+        assert!(PlayerTag::normalize("captain, leader").is_err());
+        assert!(PlayerTag::normalize(&"a".repeat(50)).is_ok());
+    }
+}
@@ -1,9 +1,13 @@
+use std::cmp::Ordering;
+
 use chrono::{DateTime, NaiveDate, Utc};
+use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 
 use crate::errors::{DomainError, DomainResult};
+use crate::models::{Position, SortField};
 
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct ProspectRanking {
     pub id: Uuid,
     pub ranking_source_id: Uuid,
@@ -14,7 +18,7 @@ pub struct ProspectRanking {
 }
 
 /// A ranking with the source name pre-joined (avoids N+1 queries)
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct PlayerRankingWithSource {
     pub player_id: Uuid,
     pub source_name: String,
@@ -23,6 +27,26 @@ pub struct PlayerRankingWithSource {
     pub scraped_at: NaiveDate,
 }
 
+/// A prospect's place on the consensus board, combining every ranking
+/// source that lists them. Sources that leave a player out do not count
+/// against them.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ConsensusRanking {
+    /// 1-based position on the consensus board
+    pub consensus_rank: i64,
+    pub player_id: Uuid,
+    pub first_name: String,
+    pub last_name: String,
+    pub position: Position,
+    /// Number of sources that rank the player
+    pub source_count: i64,
+    pub average_rank: f64,
+    pub best_rank: i32,
+    pub worst_rank: i32,
+    /// Population standard deviation of the source ranks; 0 with one source
+    pub rank_std_dev: f64,
+}
+
 impl ProspectRanking {
     pub fn new(
         ranking_source_id: Uuid,
@@ -47,6 +71,32 @@ impl ProspectRanking {
     }
 }
 
+/// Fields the combined rankings list can be sorted by
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RankingSortField {
+    SourceName,
+    Rank,
+    ScrapedAt,
+}
+
+impl SortField for RankingSortField {
+    const FIELDS: &'static [(&'static str, Self)] = &[
+        ("source_name", Self::SourceName),
+        ("rank", Self::Rank),
+        ("scraped_at", Self::ScrapedAt),
+    ];
+}
+
+impl RankingSortField {
+    pub fn compare(self, a: &PlayerRankingWithSource, b: &PlayerRankingWithSource) -> Ordering {
+        match self {
+            Self::SourceName => a.source_name.cmp(&b.source_name),
+            Self::Rank => a.rank.cmp(&b.rank),
+            Self::ScrapedAt => a.scraped_at.cmp(&b.scraped_at),
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
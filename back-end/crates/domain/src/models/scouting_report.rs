@@ -144,6 +144,28 @@ impl ScoutingReport {
     }
 }
 
+/// Criteria for searching scouting reports. Every field is optional and the
+/// set fields are combined with AND.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ScoutingReportFilter {
+    pub team_id: Option<Uuid>,
+    /// Only reports graded at least this high
+    pub min_grade: Option<f64>,
+    pub fit_grade: Option<FitGrade>,
+    /// Only reports flagging an injury or character concern (or, when
+    /// false, reports flagging neither)
+    pub concerns: Option<bool>,
+}
+
+impl ScoutingReportFilter {
+    pub fn validate(&self) -> DomainResult<()> {
+        if let Some(min_grade) = self.min_grade {
+            ScoutingReport::validate_grade(min_grade)?;
+        }
+        Ok(())
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -0,0 +1,391 @@
+use std::collections::HashMap;
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use serde_json::Value as JsonValue;
+use uuid::Uuid;
+
+use super::{Draft, DraftEvent, DraftPick, DraftSession, ScoutingReport, TradeProposal};
+use crate::errors::{DomainError, DomainResult};
+
+/// Bumped whenever the bundle layout changes in a way older importers can't read
+pub const SESSION_BUNDLE_VERSION: u32 = 1;
+
+/// Team referenced by a bundle. Teams are matched by abbreviation on import
+/// because IDs differ between installations.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct BundleTeam {
+    pub id: Uuid,
+    pub abbreviation: String,
+}
+
+/// Player referenced by a bundle, matched by name and draft year on import
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct BundlePlayer {
+    pub id: Uuid,
+    pub first_name: String,
+    pub last_name: String,
+    pub draft_year: i32,
+}
+
+/// Everything needed to recreate a mock draft on another installation:
+/// the draft and its picks, the session, its trades and event log, and the
+/// scouting grades behind each selection.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SessionBundle {
+    pub version: u32,
+    pub exported_at: DateTime<Utc>,
+    pub draft: Draft,
+    pub session: DraftSession,
+    pub picks: Vec<DraftPick>,
+    pub trades: Vec<TradeProposal>,
+    pub events: Vec<DraftEvent>,
+    pub grades: Vec<ScoutingReport>,
+    pub teams: Vec<BundleTeam>,
+    pub players: Vec<BundlePlayer>,
+}
+
+impl SessionBundle {
+    /// Checks the bundle is internally consistent: every record belongs to
+    /// the bundled draft or session and every team and player it mentions is
+    /// listed so it can be resolved on import.
+    pub fn validate(&self) -> DomainResult<()> {
+        if self.version != SESSION_BUNDLE_VERSION {
+            return Err(DomainError::ValidationError(format!(
+                "Unsupported bundle version {} (expected {})",
+                self.version, SESSION_BUNDLE_VERSION
+            )));
+        }
+        if self.session.draft_id != self.draft.id {
+            return Err(DomainError::ValidationError(
+                "Session does not belong to the bundled draft".to_string(),
+            ));
+        }
+        if let Some(pick) = self.picks.iter().find(|p| p.draft_id != self.draft.id) {
+            return Err(DomainError::ValidationError(format!(
+                "Pick {} does not belong to the bundled draft",
+                pick.id
+            )));
+        }
+        if let Some(trade) = self
+            .trades
+            .iter()
+            .find(|t| t.trade.session_id != self.session.id)
+        {
+            return Err(DomainError::ValidationError(format!(
+                "Trade {} does not belong to the bundled session",
+                trade.trade.id
+            )));
+        }
+        if let Some(event) = self.events.iter().find(|e| e.session_id != self.session.id) {
+            return Err(DomainError::ValidationError(format!(
+                "Event {} does not belong to the bundled session",
+                event.id
+            )));
+        }
+
+        let pick_ids: Vec<Uuid> = self.picks.iter().map(|p| p.id).collect();
+        for trade in &self.trades {
+            for pick_id in trade.from_team_picks.iter().chain(&trade.to_team_picks) {
+                if !pick_ids.contains(pick_id) {
+                    return Err(DomainError::ValidationError(format!(
+                        "Trade {} references unknown pick {}",
+                        trade.trade.id, pick_id
+                    )));
+                }
+            }
+        }
+
+        let team_ids: Vec<Uuid> = self.teams.iter().map(|t| t.id).collect();
+        let player_ids: Vec<Uuid> = self.players.iter().map(|p| p.id).collect();
+        let referenced_teams = self
+            .picks
+            .iter()
+            .flat_map(|p| std::iter::once(p.team_id).chain(p.original_team_id))
+            .chain(
+                self.trades
+                    .iter()
+                    .flat_map(|t| [t.trade.from_team_id, t.trade.to_team_id]),
+            )
+            .chain(self.grades.iter().map(|g| g.team_id))
+            .chain(self.session.controlled_team_ids.iter().copied());
+        for team_id in referenced_teams {
+            if !team_ids.contains(&team_id) {
+                return Err(DomainError::ValidationError(format!(
+                    "Team {} is not listed in the bundle",
+                    team_id
+                )));
+            }
+        }
+        let referenced_players = self
+            .picks
+            .iter()
+            .filter_map(|p| p.player_id)
+            .chain(self.grades.iter().map(|g| g.player_id));
+        for player_id in referenced_players {
+            if !player_ids.contains(&player_id) {
+                return Err(DomainError::ValidationError(format!(
+                    "Player {} is not listed in the bundle",
+                    player_id
+                )));
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Returns a copy with fresh IDs for the draft, session, picks, trades,
+    /// events and grades, and with team and player IDs translated through
+    /// `references` (bundle ID to local ID). IDs embedded in event payloads
+    /// are rewritten as well so the imported log still projects correctly.
+    pub fn remap(&self, references: &HashMap<Uuid, Uuid>) -> DomainResult<SessionBundle> {
+        let mut ids = references.clone();
+        ids.insert(self.draft.id, Uuid::new_v4());
+        ids.insert(self.session.id, Uuid::new_v4());
+        for pick in &self.picks {
+            ids.insert(pick.id, Uuid::new_v4());
+        }
+        for trade in &self.trades {
+            ids.insert(trade.trade.id, Uuid::new_v4());
+        }
+
+        let map = |id: Uuid| -> DomainResult<Uuid> {
+            ids.get(&id)
+                .copied()
+                .ok_or_else(|| DomainError::ValidationError(format!("Unresolved reference {}", id)))
+        };
+        let map_all =
+            |list: &[Uuid]| -> DomainResult<Vec<Uuid>> { list.iter().map(|id| map(*id)).collect() };
+
+        let mut draft = self.draft.clone();
+        draft.id = map(draft.id)?;
+
+        let mut session = self.session.clone();
+        session.id = map(session.id)?;
+        session.draft_id = draft.id;
+        session.controlled_team_ids = map_all(&session.controlled_team_ids)?;
+
+        let picks = self
+            .picks
+            .iter()
+            .map(|pick| {
+                let mut pick = pick.clone();
+                pick.id = map(pick.id)?;
+                pick.draft_id = draft.id;
+                pick.team_id = map(pick.team_id)?;
+                pick.original_team_id = pick.original_team_id.map(map).transpose()?;
+                pick.player_id = pick.player_id.map(map).transpose()?;
+                Ok(pick)
+            })
+            .collect::<DomainResult<Vec<_>>>()?;
+
+        let trades = self
+            .trades
+            .iter()
+            .map(|proposal| {
+                let mut proposal = proposal.clone();
+                proposal.trade.id = map(proposal.trade.id)?;
+                proposal.trade.session_id = session.id;
+                proposal.trade.from_team_id = map(proposal.trade.from_team_id)?;
+                proposal.trade.to_team_id = map(proposal.trade.to_team_id)?;
+                proposal.from_team_picks = map_all(&proposal.from_team_picks)?;
+                proposal.to_team_picks = map_all(&proposal.to_team_picks)?;
+                Ok(proposal)
+            })
+            .collect::<DomainResult<Vec<_>>>()?;
+
+        let events = self
+            .events
+            .iter()
+            .map(|event| {
+                let mut event = event.clone();
+                event.id = Uuid::new_v4();
+                event.session_id = session.id;
+                remap_json_ids(&mut event.event_data, &ids);
+                event
+            })
+            .collect();
+
+        let grades = self
+            .grades
+            .iter()
+            .map(|grade| {
+                let mut grade = grade.clone();
+                grade.id = Uuid::new_v4();
+                grade.team_id = map(grade.team_id)?;
+                grade.player_id = map(grade.player_id)?;
+                Ok(grade)
+            })
+            .collect::<DomainResult<Vec<_>>>()?;
+
+        let teams = self
+            .teams
+            .iter()
+            .map(|team| {
+                Ok(BundleTeam {
+                    id: map(team.id)?,
+                    abbreviation: team.abbreviation.clone(),
+                })
+            })
+            .collect::<DomainResult<Vec<_>>>()?;
+
+        let players = self
+            .players
+            .iter()
+            .map(|player| {
+                Ok(BundlePlayer {
+                    id: map(player.id)?,
+                    ..player.clone()
+                })
+            })
+            .collect::<DomainResult<Vec<_>>>()?;
+
+        Ok(SessionBundle {
+            version: self.version,
+            exported_at: self.exported_at,
+            draft,
+            session,
+            picks,
+            trades,
+            events,
+            grades,
+            teams,
+            players,
+        })
+    }
+}
+
+/// Replaces every string in `value` that is a UUID found in `ids`
+fn remap_json_ids(value: &mut JsonValue, ids: &HashMap<Uuid, Uuid>) {
+    match value {
+        JsonValue::String(s) => {
+            if let Some(mapped) = Uuid::parse_str(s).ok().and_then(|id| ids.get(&id)) {
+                *s = mapped.to_string();
+            }
+        }
+        JsonValue::Array(items) => {
+            for item in items {
+                remap_json_ids(item, ids);
+            }
+        }
+        JsonValue::Object(map) => {
+            for item in map.values_mut() {
+                remap_json_ids(item, ids);
+            }
+        }
+        _ => {}
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample_bundle() -> SessionBundle {
+        let draft = Draft::new("Mock".to_string(), 2026, 1, 1).unwrap();
+        let team_a = Uuid::new_v4();
+        let team_b = Uuid::new_v4();
+        let player_id = Uuid::new_v4();
+
+        let mut session = DraftSession::new_with_default_chart(draft.id, 300, false).unwrap();
+        session.controlled_team_ids = vec![team_a];
+
+        let mut pick = DraftPick::new(draft.id, 1, 1, 1, team_b).unwrap();
+        pick.original_team_id = Some(team_a);
+        pick.player_id = Some(player_id);
+
+        let trade =
+            TradeProposal::new(session.id, team_a, team_b, vec![pick.id], vec![], 3000, 0).unwrap();
+
+        let event = DraftEvent::pick_made(session.id, pick.id, team_b, player_id, 1, 1, 1);
+
+        SessionBundle {
+            version: SESSION_BUNDLE_VERSION,
+            exported_at: Utc::now(),
+            draft,
+            session,
+            picks: vec![pick],
+            trades: vec![trade],
+            events: vec![event],
+            grades: vec![],
+            teams: vec![
+                BundleTeam {
+                    id: team_a,
+                    abbreviation: "AAA".to_string(),
+                },
+                BundleTeam {
+                    id: team_b,
+                    abbreviation: "BBB".to_string(),
+                },
+            ],
+            players: vec![BundlePlayer {
+                id: player_id,
+                first_name: "Player".to_string(),
+                last_name: "One".to_string(),
+                draft_year: 2026,
+            }],
+        }
+    }
+
+    #[test]
+    fn test_validate_accepts_consistent_bundle() {
+        assert!(sample_bundle().validate().is_ok());
+    }
+
+    #[test]
+    fn test_validate_rejects_unlisted_references() {
+        let mut bundle = sample_bundle();
+        bundle.players.clear();
+        assert!(bundle.validate().is_err());
+
+        let mut bundle = sample_bundle();
+        bundle.teams.remove(0);
+        assert!(bundle.validate().is_err());
+
+        let mut bundle = sample_bundle();
+        bundle.version = SESSION_BUNDLE_VERSION + 1;
+        assert!(bundle.validate().is_err());
+    }
+
+    #[test]
+    fn test_remap_assigns_new_ids_and_rewrites_events() {
+        let bundle = sample_bundle();
+        let local_a = Uuid::new_v4();
+        let local_b = Uuid::new_v4();
+        let local_player = Uuid::new_v4();
+        let references = HashMap::from([
+            (bundle.teams[0].id, local_a),
+            (bundle.teams[1].id, local_b),
+            (bundle.players[0].id, local_player),
+        ]);
+
+        let remapped = bundle.remap(&references).unwrap();
+
+        assert_ne!(remapped.draft.id, bundle.draft.id);
+        assert_ne!(remapped.session.id, bundle.session.id);
+        assert_eq!(remapped.session.draft_id, remapped.draft.id);
+        assert_eq!(remapped.session.controlled_team_ids, vec![local_a]);
+
+        let pick = &remapped.picks[0];
+        assert_ne!(pick.id, bundle.picks[0].id);
+        assert_eq!(pick.team_id, local_b);
+        assert_eq!(pick.original_team_id, Some(local_a));
+        assert_eq!(pick.player_id, Some(local_player));
+
+        let trade = &remapped.trades[0];
+        assert_eq!(trade.trade.session_id, remapped.session.id);
+        assert_eq!(trade.from_team_picks, vec![pick.id]);
+
+        let event = &remapped.events[0];
+        assert_eq!(event.session_id, remapped.session.id);
+        assert_eq!(event.event_data["pick_id"], pick.id.to_string());
+        assert_eq!(event.event_data["player_id"], local_player.to_string());
+        assert!(remapped.validate().is_ok());
+    }
+
+    #[test]
+    fn test_remap_fails_on_unresolved_team() {
+        let bundle = sample_bundle();
+        assert!(bundle.remap(&HashMap::new()).is_err());
+    }
+}
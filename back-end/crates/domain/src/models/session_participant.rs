@@ -0,0 +1,143 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::errors::{DomainError, DomainResult};
+
+/// A user taking part in a shared draft session. Each participant controls
+/// its own set of teams and is the signed-in account `user_id`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SessionParticipant {
+    pub id: Uuid,
+    pub session_id: Uuid,
+    pub user_id: Uuid,
+    pub display_name: String,
+    pub team_ids: Vec<Uuid>,
+    pub joined_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl SessionParticipant {
+    pub fn new(
+        session_id: Uuid,
+        user_id: Uuid,
+        display_name: String,
+        team_ids: Vec<Uuid>,
+    ) -> DomainResult<Self> {
+        let display_name = display_name.trim().to_string();
+        Self::validate_display_name(&display_name)?;
+
+        let now = Utc::now();
+        Ok(Self {
+            id: Uuid::new_v4(),
+            session_id,
+            user_id,
+            display_name,
+            team_ids: Self::dedupe(team_ids),
+            joined_at: now,
+            updated_at: now,
+        })
+    }
+
+    /// Returns true if this participant is allowed to pick for the given team
+    pub fn controls_team(&self, team_id: Uuid) -> bool {
+        self.team_ids.contains(&team_id)
+    }
+
+    /// Returns the teams that both participants claim
+    pub fn shared_teams(&self, other: &SessionParticipant) -> Vec<Uuid> {
+        self.team_ids
+            .iter()
+            .filter(|id| other.controls_team(**id))
+            .copied()
+            .collect()
+    }
+
+    fn dedupe(team_ids: Vec<Uuid>) -> Vec<Uuid> {
+        let mut unique = Vec::with_capacity(team_ids.len());
+        for id in team_ids {
+            if !unique.contains(&id) {
+                unique.push(id);
+            }
+        }
+        unique
+    }
+
+    fn validate_display_name(name: &str) -> DomainResult<()> {
+        if name.is_empty() {
+            return Err(DomainError::ValidationError(
+                "Display name cannot be empty".to_string(),
+            ));
+        }
+        if name.len() > 100 {
+            return Err(DomainError::ValidationError(
+                "Display name cannot exceed 100 characters".to_string(),
+            ));
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_new_participant() {
+        let session_id = Uuid::new_v4();
+        let user_id = Uuid::new_v4();
+        let team_id = Uuid::new_v4();
+
+        let participant = SessionParticipant::new(
+            session_id,
+            user_id,
+            "  Alice ".to_string(),
+            vec![team_id, team_id],
+        )
+        .unwrap();
+
+        assert_eq!(participant.session_id, session_id);
+        assert_eq!(participant.user_id, user_id);
+        assert_eq!(participant.display_name, "Alice");
+        assert_eq!(participant.team_ids, vec![team_id]);
+        assert!(participant.controls_team(team_id));
+        assert!(!participant.controls_team(Uuid::new_v4()));
+    }
+
+    #[test]
+    fn test_invalid_display_name() {
+        let session_id = Uuid::new_v4();
+        assert!(
+            SessionParticipant::new(session_id, Uuid::new_v4(), "   ".to_string(), vec![]).is_err()
+        );
+        assert!(
+            SessionParticipant::new(session_id, Uuid::new_v4(), "x".repeat(101), vec![]).is_err()
+        );
+    }
+
+    #[test]
+    fn test_shared_teams() {
+        let session_id = Uuid::new_v4();
+        let shared = Uuid::new_v4();
+        let alice = SessionParticipant::new(
+            session_id,
+            Uuid::new_v4(),
+            "Alice".to_string(),
+            vec![shared, Uuid::new_v4()],
+        )
+        .unwrap();
+        let bob =
+            SessionParticipant::new(session_id, Uuid::new_v4(), "Bob".to_string(), vec![shared])
+                .unwrap();
+        let carol = SessionParticipant::new(
+            session_id,
+            Uuid::new_v4(),
+            "Carol".to_string(),
+            vec![Uuid::new_v4()],
+        )
+        .unwrap();
+
+        assert_eq!(alice.shared_teams(&bob), vec![shared]);
+        assert!(alice.shared_teams(&carol).is_empty());
+    }
+}
@@ -0,0 +1,148 @@
+use std::cmp::Ordering;
+
+use crate::errors::{DomainError, DomainResult};
+
+/// A column that a collection endpoint lets clients sort by
+pub trait SortField: Copy + PartialEq + 'static {
+    /// Names accepted in a sort specification, paired with their field
+    const FIELDS: &'static [(&'static str, Self)];
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SortKey<F> {
+    pub field: F,
+    pub descending: bool,
+}
+
+/// Client-requested ordering, most significant key first. An empty sort
+/// leaves the repository's default order in place.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Sort<F> {
+    keys: Vec<SortKey<F>>,
+}
+
+impl<F> Default for Sort<F> {
+    fn default() -> Self {
+        Self { keys: Vec::new() }
+    }
+}
+
+impl<F: SortField> Sort<F> {
+    /// Parses a comma separated list of field names such as
+    /// `-draft_year,last_name`, where a leading `-` sorts descending
+    pub fn parse(spec: &str) -> DomainResult<Self> {
+        let mut keys: Vec<SortKey<F>> = Vec::new();
+        for part in spec.split(',').map(str::trim) {
+            if part.is_empty() {
+                continue;
+            }
+            let (name, descending) = match part.strip_prefix('-') {
+                Some(name) => (name, true),
+                None => (part, false),
+            };
+            let field = F::FIELDS
+                .iter()
+                .find(|(field_name, _)| *field_name == name)
+                .map(|(_, field)| *field)
+                .ok_or_else(|| {
+                    let allowed: Vec<&str> = F::FIELDS.iter().map(|(name, _)| *name).collect();
+                    DomainError::ValidationError(format!(
+                        "Cannot sort by '{}'; sortable fields are: {}",
+                        name,
+                        allowed.join(", ")
+                    ))
+                })?;
+            if keys.iter().any(|key| key.field == field) {
+                return Err(DomainError::ValidationError(format!(
+                    "Sort field '{}' given more than once",
+                    name
+                )));
+            }
+            keys.push(SortKey { field, descending });
+        }
+        Ok(Self { keys })
+    }
+
+    pub fn keys(&self) -> &[SortKey<F>] {
+        &self.keys
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.keys.is_empty()
+    }
+
+    /// Orders a loaded collection in memory. The sort is stable, so items
+    /// that tie on every key keep their existing order.
+    pub fn apply<T>(&self, items: &mut [T], compare: impl Fn(F, &T, &T) -> Ordering) {
+        if self.keys.is_empty() {
+            return;
+        }
+        items.sort_by(|a, b| {
+            self.keys
+                .iter()
+                .map(|key| {
+                    let ordering = compare(key.field, a, b);
+                    if key.descending {
+                        ordering.reverse()
+                    } else {
+                        ordering
+                    }
+                })
+                .find(|ordering| ordering.is_ne())
+                .unwrap_or(Ordering::Equal)
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Debug, Clone, Copy, PartialEq)]
+    enum Field {
+        Name,
+        Age,
+    }
+
+    impl SortField for Field {
+        const FIELDS: &'static [(&'static str, Self)] =
+            &[("name", Field::Name), ("age", Field::Age)];
+    }
+
+    #[test]
+    fn test_parse() {
+        let sort = Sort::<Field>::parse("-age, name").unwrap();
+        assert_eq!(
+            sort.keys(),
+            &[
+                SortKey {
+                    field: Field::Age,
+                    descending: true
+                },
+                SortKey {
+                    field: Field::Name,
+                    descending: false
+                },
+            ]
+        );
+        assert!(Sort::<Field>::parse("").unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_parse_rejects_unknown_and_repeated_fields() {
+        let err = Sort::<Field>::parse("height").unwrap_err();
+        assert!(err.to_string().contains("name, age"));
+        assert!(Sort::<Field>::parse("name,-name").is_err());
+    }
+
+    #[test]
+    fn test_apply() {
+        let mut people = vec![("b", 30), ("a", 30), ("c", 25)];
+        let sort = Sort::<Field>::parse("-age,name").unwrap();
+        sort.apply(&mut people, |field, a, b| match field {
+            Field::Name => a.0.cmp(b.0),
+            Field::Age => a.1.cmp(&b.1),
+        });
+        assert_eq!(people, vec![("a", 30), ("b", 30), ("c", 25)]);
+    }
+}
@@ -1,9 +1,12 @@
+use std::cmp::Ordering;
+
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 use utoipa::ToSchema;
 use uuid::Uuid;
 
 use crate::errors::{DomainError, DomainResult};
+use crate::models::SortField;
 
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
 #[serde(rename_all = "UPPERCASE")]
@@ -70,6 +73,49 @@ impl Team {
         })
     }
 
+    /// Applies a partial update. Every provided field is validated, and the
+    /// resulting conference and division must still agree, before any of
+    /// them is changed.
+    pub fn apply_update(&mut self, update: &TeamUpdate) -> DomainResult<()> {
+        if update.is_empty() {
+            return Err(DomainError::ValidationError(
+                "No fields provided".to_string(),
+            ));
+        }
+        if let Some(name) = &update.name {
+            Self::validate_name(name)?;
+        }
+        if let Some(abbreviation) = &update.abbreviation {
+            Self::validate_abbreviation(abbreviation)?;
+        }
+        if let Some(city) = &update.city {
+            Self::validate_city(city)?;
+        }
+        let conference = update
+            .conference
+            .clone()
+            .unwrap_or_else(|| self.conference.clone());
+        let division = update
+            .division
+            .clone()
+            .unwrap_or_else(|| self.division.clone());
+        Self::validate_conference_division(&conference, &division)?;
+
+        if let Some(name) = &update.name {
+            self.name = name.clone();
+        }
+        if let Some(abbreviation) = &update.abbreviation {
+            self.abbreviation = abbreviation.clone();
+        }
+        if let Some(city) = &update.city {
+            self.city = city.clone();
+        }
+        self.conference = conference;
+        self.division = division;
+        self.updated_at = Utc::now();
+        Ok(())
+    }
+
     fn validate_name(name: &str) -> DomainResult<()> {
         if name.trim().is_empty() {
             return Err(DomainError::ValidationError(
@@ -138,6 +184,76 @@ impl Team {
     }
 }
 
+/// Corrections to an existing team. `None` leaves a field untouched.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct TeamUpdate {
+    pub name: Option<String>,
+    pub abbreviation: Option<String>,
+    pub city: Option<String>,
+    pub conference: Option<Conference>,
+    pub division: Option<Division>,
+}
+
+impl TeamUpdate {
+    pub fn is_empty(&self) -> bool {
+        self.name.is_none()
+            && self.abbreviation.is_none()
+            && self.city.is_none()
+            && self.conference.is_none()
+            && self.division.is_none()
+    }
+}
+
+/// Records that still refer to a team and would block deleting it
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct TeamDependents {
+    /// Draft picks the team holds or originally owned
+    pub picks: i64,
+    pub needs: i64,
+    /// Trade proposals the team is part of
+    pub trades: i64,
+    /// Drafts not yet completed that hold any of the team's picks
+    pub live_drafts: i64,
+}
+
+impl TeamDependents {
+    pub fn is_empty(&self) -> bool {
+        self.picks == 0 && self.needs == 0 && self.trades == 0
+    }
+}
+
+/// Fields the team list can be sorted by
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TeamSortField {
+    Name,
+    Abbreviation,
+    City,
+    Conference,
+    Division,
+}
+
+impl SortField for TeamSortField {
+    const FIELDS: &'static [(&'static str, Self)] = &[
+        ("name", Self::Name),
+        ("abbreviation", Self::Abbreviation),
+        ("city", Self::City),
+        ("conference", Self::Conference),
+        ("division", Self::Division),
+    ];
+}
+
+impl TeamSortField {
+    pub fn compare(self, a: &Team, b: &Team) -> Ordering {
+        match self {
+            Self::Name => a.name.cmp(&b.name),
+            Self::Abbreviation => a.abbreviation.cmp(&b.abbreviation),
+            Self::City => a.city.cmp(&b.city),
+            Self::Conference => format!("{:?}", a.conference).cmp(&format!("{:?}", b.conference)),
+            Self::Division => format!("{:?}", a.division).cmp(&format!("{:?}", b.division)),
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -265,6 +381,43 @@ mod tests {
         ));
     }
 
+    #[test]
+    fn test_apply_update() {
+        let mut team = Team::new(
+            "Dallas Cowboys".to_string(),
+            "DAL".to_string(),
+            "Dallas".to_string(),
+            Conference::NFC,
+            Division::NFCEast,
+        )
+        .unwrap();
+
+        team.apply_update(&TeamUpdate {
+            city: Some("Arlington".to_string()),
+            ..Default::default()
+        })
+        .unwrap();
+        assert_eq!(team.city, "Arlington");
+        assert_eq!(team.name, "Dallas Cowboys");
+
+        // Moving divisions alone would leave the conference inconsistent
+        let result = team.apply_update(&TeamUpdate {
+            division: Some(Division::AFCEast),
+            ..Default::default()
+        });
+        assert!(result.is_err());
+        assert_eq!(team.division, Division::NFCEast);
+
+        team.apply_update(&TeamUpdate {
+            conference: Some(Conference::AFC),
+            division: Some(Division::AFCEast),
+            ..Default::default()
+        })
+        .unwrap();
+        assert_eq!(team.conference, Conference::AFC);
+        assert_eq!(team.division, Division::AFCEast);
+    }
+
     #[test]
     fn test_afc_teams_valid() {
         let divisions = vec![
@@ -0,0 +1,188 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::str::FromStr;
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+use crate::errors::{DomainError, DomainResult};
+
+/// Shortest password accepted at registration
+pub const MIN_PASSWORD_LENGTH: usize = 8;
+
+/// What an account may do. Each role includes everything the roles before it
+/// allow: spectators watch, participants make picks and trades, commissioners
+/// run sessions, and admins also seed and maintain reference data.
+#[derive(
+    Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize, ToSchema,
+)]
+#[serde(rename_all = "snake_case")]
+pub enum Role {
+    #[default]
+    Spectator,
+    Participant,
+    Commissioner,
+    Admin,
+}
+
+impl Role {
+    /// Whether this role grants at least the access of `required`
+    pub fn includes(self, required: Role) -> bool {
+        self >= required
+    }
+}
+
+impl fmt::Display for Role {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Role::Spectator => write!(f, "spectator"),
+            Role::Participant => write!(f, "participant"),
+            Role::Commissioner => write!(f, "commissioner"),
+            Role::Admin => write!(f, "admin"),
+        }
+    }
+}
+
+impl FromStr for Role {
+    type Err = DomainError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "spectator" => Ok(Role::Spectator),
+            "participant" => Ok(Role::Participant),
+            "commissioner" => Ok(Role::Commissioner),
+            "admin" => Ok(Role::Admin),
+            _ => Err(DomainError::ValidationError(format!(
+                "Invalid role: '{}'. Must be 'spectator', 'participant', 'commissioner' or 'admin'",
+                s
+            ))),
+        }
+    }
+}
+
+/// A registered account. The password is only ever held as a hash; hashing
+/// happens at the API boundary so the domain stays free of crypto.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct User {
+    pub id: Uuid,
+    /// Stored trimmed and lowercased so sign-in is case-insensitive
+    pub email: String,
+    pub display_name: String,
+    pub password_hash: String,
+    pub role: Role,
+    pub created_at: DateTime<Utc>,
+}
+
+impl User {
+    /// New accounts start as spectators; an admin or commissioner grants
+    /// participant, and only an admin grants anything more
+    pub fn new(email: &str, display_name: &str, password_hash: String) -> DomainResult<Self> {
+        let email = Self::normalize_email(email);
+        let display_name = display_name.trim().to_string();
+        Self::validate_email(&email)?;
+        Self::validate_display_name(&display_name)?;
+
+        Ok(Self {
+            id: Uuid::new_v4(),
+            email,
+            display_name,
+            password_hash,
+            role: Role::default(),
+            created_at: Utc::now(),
+        })
+    }
+
+    /// The form emails are stored and looked up in
+    pub fn normalize_email(email: &str) -> String {
+        email.trim().to_lowercase()
+    }
+
+    /// Checks a plaintext password before it is hashed
+    pub fn validate_password(password: &str) -> DomainResult<()> {
+        if password.chars().count() < MIN_PASSWORD_LENGTH {
+            return Err(DomainError::ValidationError(format!(
+                "Password must be at least {} characters",
+                MIN_PASSWORD_LENGTH
+            )));
+        }
+        Ok(())
+    }
+
+    fn validate_email(email: &str) -> DomainResult<()> {
+        let valid = email.len() <= 255
+            && !email.chars().any(char::is_whitespace)
+            && email
+                .split_once('@')
+                .is_some_and(|(local, domain)| !local.is_empty() && domain.contains('.'));
+        if !valid {
+            return Err(DomainError::ValidationError(format!(
+                "'{}' is not a valid email address",
+                email
+            )));
+        }
+        Ok(())
+    }
+
+    fn validate_display_name(display_name: &str) -> DomainResult<()> {
+        if display_name.is_empty() {
+            return Err(DomainError::ValidationError(
+                "Display name cannot be empty".to_string(),
+            ));
+        }
+        if display_name.chars().count() > 100 {
+            return Err(DomainError::ValidationError(
+                "Display name cannot exceed 100 characters".to_string(),
+            ));
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_new_user_normalizes_input() {
+        let user = User::new("  Coach@Example.COM ", " Coach ", "hash".to_string()).unwrap();
+        assert_eq!(user.email, "coach@example.com");
+        assert_eq!(user.display_name, "Coach");
+        assert_eq!(user.role, Role::Spectator);
+    }
+
+    #[test]
+    fn test_roles_include_lower_roles() {
+        assert!(Role::Admin.includes(Role::Commissioner));
+        assert!(Role::Commissioner.includes(Role::Participant));
+        assert!(Role::Participant.includes(Role::Participant));
+        assert!(!Role::Participant.includes(Role::Commissioner));
+        assert!(!Role::Spectator.includes(Role::Participant));
+    }
+
+    #[test]
+    fn test_role_round_trip() {
+        for role in [
+            Role::Spectator,
+            Role::Participant,
+            Role::Commissioner,
+            Role::Admin,
+        ] {
+            assert_eq!(role.to_string().parse::<Role>().unwrap(), role);
+        }
+        assert!("owner".parse::<Role>().is_err());
+    }
+
+    #[test]
+    fn test_invalid_users() {
+        assert!(User::new("not-an-email", "Coach", "hash".to_string()).is_err());
+        assert!(User::new("@example.com", "Coach", "hash".to_string()).is_err());
+        assert!(User::new("a b@example.com", "Coach", "hash".to_string()).is_err());
+        assert!(User::new("coach@example.com", "  ", "hash".to_string()).is_err());
+    }
+
+    #[test]
+    fn test_validate_password() {
+        assert!(User::validate_password("short").is_err());
+        assert!(User::validate_password("long enough").is_ok());
+    }
+}
@@ -0,0 +1,21 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+/// A player on a user's watchlist
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct WatchlistEntry {
+    pub user_id: Uuid,
+    pub player_id: Uuid,
+    pub created_at: DateTime<Utc>,
+}
+
+impl WatchlistEntry {
+    pub fn new(user_id: Uuid, player_id: Uuid) -> Self {
+        Self {
+            user_id,
+            player_id,
+            created_at: Utc::now(),
+        }
+    }
+}
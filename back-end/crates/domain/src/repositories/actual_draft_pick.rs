@@ -0,0 +1,17 @@
+use async_trait::async_trait;
+
+use crate::errors::DomainResult;
+use crate::models::ActualDraftPick;
+
+/// Repository trait for real draft results
+#[async_trait]
+pub trait ActualDraftPickRepository: Send + Sync {
+    /// Find the real picks for a year, in overall pick order
+    async fn find_by_year(&self, year: i32) -> DomainResult<Vec<ActualDraftPick>>;
+
+    /// Replace every pick for `year` with `picks` in one transaction
+    async fn replace_year(&self, year: i32, picks: &[ActualDraftPick]) -> DomainResult<u64>;
+
+    /// Delete all picks for a year
+    async fn delete_by_year(&self, year: i32) -> DomainResult<u64>;
+}
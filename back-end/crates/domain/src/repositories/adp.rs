@@ -0,0 +1,15 @@
+use async_trait::async_trait;
+
+use crate::errors::DomainResult;
+use crate::models::AdpEntry;
+
+/// Repository trait for average draft position history
+#[async_trait]
+pub trait AdpRepository: Send + Sync {
+    /// Record a completed draft's picks. Entries already recorded for the
+    /// same draft and player are skipped, so recording twice is harmless.
+    async fn record(&self, entries: &[AdpEntry]) -> DomainResult<u64>;
+
+    /// All entries, optionally for one draft year, oldest first
+    async fn find_by_year(&self, year: Option<i32>) -> DomainResult<Vec<AdpEntry>>;
+}
@@ -0,0 +1,18 @@
+use async_trait::async_trait;
+
+use crate::errors::DomainResult;
+use crate::models::{AuditEntry, AuditLogFilter, Page, PageRequest};
+
+/// Repository trait for the append-only audit log
+#[async_trait]
+pub trait AuditLogRepository: Send + Sync {
+    /// Append entries written by one request
+    async fn record(&self, entries: &[AuditEntry]) -> DomainResult<()>;
+
+    /// Find entries matching the filter, newest first
+    async fn find(
+        &self,
+        filter: &AuditLogFilter,
+        page: PageRequest,
+    ) -> DomainResult<Page<AuditEntry>>;
+}
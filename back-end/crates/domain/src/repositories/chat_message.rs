@@ -0,0 +1,22 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use uuid::Uuid;
+
+use crate::errors::DomainResult;
+use crate::models::ChatMessage;
+
+/// Repository trait for session chat history
+#[async_trait]
+pub trait ChatMessageRepository: Send + Sync {
+    /// Store a chat message
+    async fn create(&self, message: &ChatMessage) -> DomainResult<ChatMessage>;
+
+    /// The most recent `limit` messages in a session posted before `before`
+    /// (or up to now when `None`), returned oldest first
+    async fn list_by_session(
+        &self,
+        session_id: Uuid,
+        before: Option<DateTime<Utc>>,
+        limit: i64,
+    ) -> DomainResult<Vec<ChatMessage>>;
+}
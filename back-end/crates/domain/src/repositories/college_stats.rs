@@ -0,0 +1,19 @@
+use async_trait::async_trait;
+use uuid::Uuid;
+
+use crate::errors::DomainResult;
+use crate::models::CollegeStats;
+
+/// Repository trait for CollegeStats data access
+#[async_trait]
+pub trait CollegeStatsRepository: Send + Sync {
+    /// Create or replace a player's stats for a season (upsert)
+    async fn upsert(&self, stats: &CollegeStats) -> DomainResult<CollegeStats>;
+
+    /// Find all seasons for a player, oldest first
+    async fn find_by_player_id(&self, player_id: Uuid) -> DomainResult<Vec<CollegeStats>>;
+
+    /// Delete college stats for every player in a draft class, returning the
+    /// number of seasons removed
+    async fn delete_by_draft_year(&self, draft_year: i32) -> DomainResult<u64>;
+}
@@ -13,9 +13,16 @@ pub trait CombineResultsRepository: Send + Sync {
     /// Find combine results by ID
     async fn find_by_id(&self, id: Uuid) -> DomainResult<Option<CombineResults>>;
 
-    /// Find all combine results for a player
+    /// Find all combine results for a player, one per testing event
     async fn find_by_player_id(&self, player_id: Uuid) -> DomainResult<Vec<CombineResults>>;
 
+    /// Find combine results for several players in one query, one per
+    /// player and testing event
+    async fn find_combine_results_for_players(
+        &self,
+        player_ids: &[Uuid],
+    ) -> DomainResult<Vec<CombineResults>>;
+
     /// Find combine results for a player and year
     async fn find_by_player_and_year(
         &self,
@@ -23,7 +30,8 @@ pub trait CombineResultsRepository: Send + Sync {
         year: i32,
     ) -> DomainResult<Option<CombineResults>>;
 
-    /// Find combine results for a player, year, and source
+    /// Find combine results for a player, year, and source; the most recent
+    /// event when the source has several
     async fn find_by_player_year_source(
         &self,
         player_id: Uuid,
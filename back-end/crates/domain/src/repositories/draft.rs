@@ -2,7 +2,7 @@ use async_trait::async_trait;
 use uuid::Uuid;
 
 use crate::errors::DomainResult;
-use crate::models::{Draft, DraftPick, DraftStatus};
+use crate::models::{Draft, DraftPick, DraftSortField, DraftStatus, Page, PageRequest, Sort};
 
 /// Repository trait for Draft data access
 ///
@@ -22,6 +22,19 @@ pub trait DraftRepository: Send + Sync {
     /// Get all drafts
     async fn find_all(&self) -> DomainResult<Vec<Draft>>;
 
+    /// Get drafts in the requested order, falling back to the `find_all`
+    /// order, optionally cut down to one page.
+    /// Default implementation sorts and pages in memory.
+    async fn find_sorted(
+        &self,
+        sort: &Sort<DraftSortField>,
+        page: Option<PageRequest>,
+    ) -> DomainResult<Page<Draft>> {
+        let mut drafts = self.find_all().await?;
+        sort.apply(&mut drafts, DraftSortField::compare);
+        Ok(Page::from_all(drafts, page))
+    }
+
     /// Get drafts by status
     async fn find_by_status(&self, status: DraftStatus) -> DomainResult<Vec<Draft>>;
 
@@ -50,6 +63,16 @@ pub trait DraftPickRepository: Send + Sync {
     /// Get all picks for a draft
     async fn find_by_draft_id(&self, draft_id: Uuid) -> DomainResult<Vec<DraftPick>>;
 
+    /// Get one page of a draft's picks in overall pick order.
+    /// Default implementation pages in memory.
+    async fn find_page_by_draft_id(
+        &self,
+        draft_id: Uuid,
+        page: PageRequest,
+    ) -> DomainResult<Page<DraftPick>> {
+        Ok(page.slice(self.find_by_draft_id(draft_id).await?))
+    }
+
     /// Get picks for a draft in a specific round
     async fn find_by_draft_and_round(
         &self,
@@ -0,0 +1,20 @@
+use async_trait::async_trait;
+use uuid::Uuid;
+
+use crate::errors::DomainResult;
+
+/// Mutual exclusion over a draft shared by every server process, so
+/// concurrent clients take turns making its picks
+#[async_trait]
+pub trait DraftLockRepository: Send + Sync {
+    /// Wait until no one else holds the draft's lock, then take it. Fails
+    /// with `ResourceInUse` if the lock isn't freed in time.
+    async fn acquire(&self, draft_id: Uuid) -> DomainResult<Box<dyn DraftLockGuard>>;
+}
+
+/// A held draft lock. Dropping the guard without releasing it still frees
+/// the lock.
+#[async_trait]
+pub trait DraftLockGuard: Send {
+    async fn release(self: Box<Self>) -> DomainResult<()>;
+}
@@ -1,4 +1,5 @@
 use async_trait::async_trait;
+use chrono::{DateTime, Utc};
 use uuid::Uuid;
 
 use crate::errors::DomainResult;
@@ -6,7 +7,8 @@ use crate::models::DraftEvent;
 
 #[async_trait]
 pub trait EventRepository: Send + Sync {
-    /// Record a new draft event
+    /// Record a new draft event, with its outbox entry in the same
+    /// transaction
     async fn create(&self, event: &DraftEvent) -> DomainResult<DraftEvent>;
 
     /// Find an event by ID
@@ -15,6 +17,22 @@ pub trait EventRepository: Send + Sync {
     /// List all events for a session (ordered by creation time)
     async fn list_by_session(&self, session_id: Uuid) -> DomainResult<Vec<DraftEvent>>;
 
+    /// List up to `limit` events for a session that come after the event
+    /// `after_id` (or from the start when it is `None`), in feed order:
+    /// creation time, then ID for events recorded in the same instant
+    async fn list_by_session_after(
+        &self,
+        session_id: Uuid,
+        after_id: Option<Uuid>,
+        limit: i64,
+    ) -> DomainResult<Vec<DraftEvent>>;
+
+    /// List up to `limit` events across every session that come after the
+    /// event `after_id` (or from the start when it is `None`), by creation
+    /// time and then ID
+    async fn list_after(&self, after_id: Option<Uuid>, limit: i64)
+        -> DomainResult<Vec<DraftEvent>>;
+
     /// List events by session and type
     async fn list_by_session_and_type(
         &self,
@@ -24,4 +42,22 @@ pub trait EventRepository: Send + Sync {
 
     /// Count events for a session
     async fn count_by_session(&self, session_id: Uuid) -> DomainResult<i64>;
+
+    /// Claim up to `limit` events whose outbox entries are still unpublished
+    /// and became available at or before `due_by`, oldest first. Claiming
+    /// pushes each entry's availability to now, so other relays skip it until
+    /// it is due again.
+    async fn claim_unpublished(
+        &self,
+        due_by: DateTime<Utc>,
+        limit: i64,
+    ) -> DomainResult<Vec<DraftEvent>>;
+
+    /// Mark events' outbox entries as published, returning how many were
+    /// still pending
+    async fn mark_published(&self, event_ids: &[Uuid]) -> DomainResult<u64>;
+
+    /// Mark the outbox entries of a session's `PickMade` events for the given
+    /// picks as published, returning how many were still pending
+    async fn mark_picks_published(&self, session_id: Uuid, pick_ids: &[Uuid]) -> DomainResult<u64>;
 }
@@ -1,33 +1,55 @@
+pub mod actual_draft_pick;
+pub mod adp;
+pub mod audit_log;
+pub mod chat_message;
+pub mod college_stats;
 pub mod combine_percentile;
 pub mod combine_results;
 pub mod draft;
+pub mod draft_lock;
 pub mod draft_strategy;
 pub mod event_repository;
 pub mod feldman_freak;
 pub mod player;
+pub mod player_note;
+pub mod player_tag;
 pub mod prospect_profile;
 pub mod prospect_ranking;
 pub mod ranking_source;
 pub mod scouting_report;
+pub mod session_participant;
 pub mod session_repository;
 pub mod team;
 pub mod team_need;
 pub mod team_season;
 pub mod trade;
+pub mod user;
+pub mod watchlist;
 
+pub use actual_draft_pick::ActualDraftPickRepository;
+pub use adp::AdpRepository;
+pub use audit_log::AuditLogRepository;
+pub use chat_message::ChatMessageRepository;
+pub use college_stats::CollegeStatsRepository;
 pub use combine_percentile::CombinePercentileRepository;
 pub use combine_results::CombineResultsRepository;
 pub use draft::{DraftPickRepository, DraftRepository};
+pub use draft_lock::{DraftLockGuard, DraftLockRepository};
 pub use draft_strategy::DraftStrategyRepository;
 pub use event_repository::EventRepository;
 pub use feldman_freak::FeldmanFreakRepository;
 pub use player::PlayerRepository;
+pub use player_note::PlayerNoteRepository;
+pub use player_tag::PlayerTagRepository;
 pub use prospect_profile::ProspectProfileRepository;
 pub use prospect_ranking::ProspectRankingRepository;
 pub use ranking_source::RankingSourceRepository;
 pub use scouting_report::ScoutingReportRepository;
+pub use session_participant::SessionParticipantRepository;
 pub use session_repository::SessionRepository;
 pub use team::TeamRepository;
 pub use team_need::TeamNeedRepository;
 pub use team_season::TeamSeasonRepository;
 pub use trade::TradeRepository;
+pub use user::UserRepository;
+pub use watchlist::WatchlistRepository;
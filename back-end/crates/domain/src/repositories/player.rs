@@ -2,7 +2,9 @@ use async_trait::async_trait;
 use uuid::Uuid;
 
 use crate::errors::DomainResult;
-use crate::models::{Player, Position};
+use crate::models::{
+    seek_after, Page, PageRequest, Player, PlayerFilter, PlayerSortField, Position, Sort,
+};
 
 /// Repository trait for Player data access
 #[async_trait]
@@ -10,24 +12,82 @@ pub trait PlayerRepository: Send + Sync {
     /// Create a new player
     async fn create(&self, player: &Player) -> DomainResult<Player>;
 
+    /// Create several players at once; either all are created or none.
+    /// Default implementation inserts them one at a time.
+    async fn create_many(&self, players: &[Player]) -> DomainResult<Vec<Player>> {
+        let mut created = Vec::with_capacity(players.len());
+        for player in players {
+            created.push(self.create(player).await?);
+        }
+        Ok(created)
+    }
+
     /// Find a player by ID
     async fn find_by_id(&self, id: Uuid) -> DomainResult<Option<Player>>;
 
+    /// Find the players with the given IDs, in no particular order; unknown
+    /// IDs are skipped
+    async fn find_by_ids(&self, ids: &[Uuid]) -> DomainResult<Vec<Player>> {
+        let mut players = Vec::with_capacity(ids.len());
+        for id in ids {
+            if let Some(player) = self.find_by_id(*id).await? {
+                players.push(player);
+            }
+        }
+        Ok(players)
+    }
+
     /// Get all players
     async fn find_all(&self) -> DomainResult<Vec<Player>>;
 
+    /// Find players matching a filter in the requested order, falling back
+    /// to the `find_all` order. Without a page request every match is returned.
+    async fn find_filtered(
+        &self,
+        filter: &PlayerFilter,
+        sort: &Sort<PlayerSortField>,
+        page: Option<PageRequest>,
+    ) -> DomainResult<Page<Player>>;
+
     /// Find players by position
     async fn find_by_position(&self, position: Position) -> DomainResult<Vec<Player>>;
 
     /// Find players by draft year
     async fn find_by_draft_year(&self, year: i32) -> DomainResult<Vec<Player>>;
 
+    /// Find up to `limit` players of a draft year that come after the player
+    /// `after_id` (or from the start when it is `None`), by name and then ID.
+    /// Default implementation loads the whole class and seeks in memory.
+    async fn find_by_draft_year_after(
+        &self,
+        year: i32,
+        after_id: Option<Uuid>,
+        limit: i64,
+    ) -> DomainResult<Vec<Player>> {
+        let mut players = self.find_by_draft_year(year).await?;
+        players.sort_by(|a, b| {
+            (&a.last_name, &a.first_name, a.id).cmp(&(&b.last_name, &b.first_name, b.id))
+        });
+        Ok(seek_after(players, after_id, limit, |player| player.id))
+    }
+
     /// Find draft eligible players
     async fn find_draft_eligible(&self, year: i32) -> DomainResult<Vec<Player>>;
 
+    /// Find players of a draft's year that no pick in the draft has taken,
+    /// by name. Empty for an unknown draft.
+    async fn find_available_for_draft(&self, draft_id: Uuid) -> DomainResult<Vec<Player>>;
+
     /// Update a player
     async fn update(&self, player: &Player) -> DomainResult<Player>;
 
     /// Delete a player
     async fn delete(&self, id: Uuid) -> DomainResult<()>;
+
+    /// Hide a player from every read until restored, leaving rankings,
+    /// reports and picks that refer to it in place
+    async fn soft_delete(&self, id: Uuid) -> DomainResult<()>;
+
+    /// Bring back a soft-deleted player
+    async fn restore(&self, id: Uuid) -> DomainResult<Player>;
 }
@@ -0,0 +1,24 @@
+use async_trait::async_trait;
+use uuid::Uuid;
+
+use crate::errors::DomainResult;
+use crate::models::PlayerNote;
+
+/// Repository trait for PlayerNote data access
+#[async_trait]
+pub trait PlayerNoteRepository: Send + Sync {
+    /// Create a new player note
+    async fn create(&self, note: &PlayerNote) -> DomainResult<PlayerNote>;
+
+    /// Find a player note by ID
+    async fn find_by_id(&self, id: Uuid) -> DomainResult<Option<PlayerNote>>;
+
+    /// Find all notes for a player, newest first
+    async fn find_by_player_id(&self, player_id: Uuid) -> DomainResult<Vec<PlayerNote>>;
+
+    /// Update a player note
+    async fn update(&self, note: &PlayerNote) -> DomainResult<PlayerNote>;
+
+    /// Delete a player note
+    async fn delete(&self, id: Uuid) -> DomainResult<()>;
+}
@@ -0,0 +1,22 @@
+use async_trait::async_trait;
+use uuid::Uuid;
+
+use crate::errors::DomainResult;
+use crate::models::{PlayerTag, TagCount};
+
+/// Repository trait for PlayerTag data access
+#[async_trait]
+pub trait PlayerTagRepository: Send + Sync {
+    /// Tag a player. Adding a tag the player already has is a no-op that
+    /// returns the existing tag.
+    async fn add(&self, tag: &PlayerTag) -> DomainResult<PlayerTag>;
+
+    /// Remove a tag from a player; returns false when the player didn't have it
+    async fn remove(&self, player_id: Uuid, tag: &str) -> DomainResult<bool>;
+
+    /// Find all tags on a player, alphabetically
+    async fn find_by_player_id(&self, player_id: Uuid) -> DomainResult<Vec<PlayerTag>>;
+
+    /// Every tag in use with its player count, most used first
+    async fn list_tags(&self) -> DomainResult<Vec<TagCount>>;
+}
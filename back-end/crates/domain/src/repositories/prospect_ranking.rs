@@ -2,7 +2,10 @@ use async_trait::async_trait;
 use uuid::Uuid;
 
 use crate::errors::DomainResult;
-use crate::models::{PlayerRankingWithSource, ProspectRanking};
+use crate::models::{
+    seek_after, ConsensusRanking, Page, PageRequest, PlayerRankingWithSource, ProspectRanking,
+    RankingSortField, Sort,
+};
 
 /// Repository trait for ProspectRanking data access
 #[async_trait]
@@ -19,6 +22,19 @@ pub trait ProspectRankingRepository: Send + Sync {
     /// Find all rankings across all sources with source names pre-joined
     async fn find_all_with_source(&self) -> DomainResult<Vec<PlayerRankingWithSource>>;
 
+    /// Get all rankings in the requested order, falling back to the
+    /// `find_all_with_source` order, optionally cut down to one page.
+    /// Default implementation sorts and pages in memory.
+    async fn find_sorted_with_source(
+        &self,
+        sort: &Sort<RankingSortField>,
+        page: Option<PageRequest>,
+    ) -> DomainResult<Page<PlayerRankingWithSource>> {
+        let mut rankings = self.find_all_with_source().await?;
+        sort.apply(&mut rankings, RankingSortField::compare);
+        Ok(Page::from_all(rankings, page))
+    }
+
     /// Find rankings for a specific set of player IDs with source names pre-joined.
     /// Default implementation fetches all and filters in memory.
     /// Override in concrete implementations for DB-level efficiency.
@@ -34,12 +50,30 @@ pub trait ProspectRankingRepository: Send + Sync {
             .collect())
     }
 
+    /// Combine every source's board into one consensus board, best average
+    /// rank first, optionally limited to one draft class
+    async fn find_consensus(&self, draft_year: Option<i32>) -> DomainResult<Vec<ConsensusRanking>>;
+
     /// Find all rankings for a player (across all sources)
     async fn find_by_player(&self, player_id: Uuid) -> DomainResult<Vec<ProspectRanking>>;
 
     /// Find all rankings for a source (the full big board)
     async fn find_by_source(&self, source_id: Uuid) -> DomainResult<Vec<ProspectRanking>>;
 
+    /// Find up to `limit` of a source's rankings that come after the ranking
+    /// `after_id` (or from the start when it is `None`), by rank and then ID.
+    /// Default implementation loads the whole board and seeks in memory.
+    async fn find_by_source_after(
+        &self,
+        source_id: Uuid,
+        after_id: Option<Uuid>,
+        limit: i64,
+    ) -> DomainResult<Vec<ProspectRanking>> {
+        let mut rankings = self.find_by_source(source_id).await?;
+        rankings.sort_by_key(|ranking| (ranking.rank, ranking.id));
+        Ok(seek_after(rankings, after_id, limit, |ranking| ranking.id))
+    }
+
     /// Delete all rankings for a source
     async fn delete_by_source(&self, source_id: Uuid) -> DomainResult<u64>;
 }
@@ -2,7 +2,7 @@ use async_trait::async_trait;
 use uuid::Uuid;
 
 use crate::errors::DomainResult;
-use crate::models::ScoutingReport;
+use crate::models::{Page, PageRequest, ScoutingReport, ScoutingReportFilter};
 
 /// Repository trait for ScoutingReport data access
 #[async_trait]
@@ -19,6 +19,14 @@ pub trait ScoutingReportRepository: Send + Sync {
     /// Find all scouting reports for a player
     async fn find_by_player_id(&self, player_id: Uuid) -> DomainResult<Vec<ScoutingReport>>;
 
+    /// Find reports matching a filter, highest grade first. Without a page
+    /// request every match is returned.
+    async fn find_filtered(
+        &self,
+        filter: &ScoutingReportFilter,
+        page: Option<PageRequest>,
+    ) -> DomainResult<Page<ScoutingReport>>;
+
     /// Find a specific scouting report for a team and player
     async fn find_by_team_and_player(
         &self,
@@ -26,9 +34,22 @@ pub trait ScoutingReportRepository: Send + Sync {
         player_id: Uuid,
     ) -> DomainResult<Option<ScoutingReport>>;
 
+    /// Find a team's scouting reports on the given players in one query
+    async fn find_reports_by_team_for_players(
+        &self,
+        team_id: Uuid,
+        player_ids: &[Uuid],
+    ) -> DomainResult<Vec<ScoutingReport>>;
+
     /// Update a scouting report
     async fn update(&self, report: &ScoutingReport) -> DomainResult<ScoutingReport>;
 
     /// Delete a scouting report
     async fn delete(&self, id: Uuid) -> DomainResult<()>;
+
+    /// Hide a scouting report from every read until restored
+    async fn soft_delete(&self, id: Uuid) -> DomainResult<()>;
+
+    /// Bring back a soft-deleted scouting report
+    async fn restore(&self, id: Uuid) -> DomainResult<ScoutingReport>;
 }
@@ -0,0 +1,25 @@
+use async_trait::async_trait;
+use uuid::Uuid;
+
+use crate::errors::DomainResult;
+use crate::models::SessionParticipant;
+
+/// Repository trait for SessionParticipant data access
+#[async_trait]
+pub trait SessionParticipantRepository: Send + Sync {
+    /// Add a participant to a session
+    async fn create(&self, participant: &SessionParticipant) -> DomainResult<SessionParticipant>;
+
+    /// Find a participant by ID
+    async fn find_by_id(&self, id: Uuid) -> DomainResult<Option<SessionParticipant>>;
+
+    /// List all participants in a session, in join order
+    async fn list_by_session(&self, session_id: Uuid) -> DomainResult<Vec<SessionParticipant>>;
+
+    /// Remove a participant from its session
+    async fn delete(&self, id: Uuid) -> DomainResult<()>;
+
+    /// Remove a participant and hand its teams back to the session, so they
+    /// are no longer user-controlled, in one transaction
+    async fn leave(&self, participant: &SessionParticipant) -> DomainResult<()>;
+}
@@ -1,8 +1,9 @@
 use async_trait::async_trait;
+use chrono::{DateTime, Utc};
 use uuid::Uuid;
 
 use crate::errors::DomainResult;
-use crate::models::{Draft, DraftSession};
+use crate::models::{Draft, DraftEvent, DraftPick, DraftSession, SessionBundle, TradeProposal};
 
 #[async_trait]
 pub trait SessionRepository: Send + Sync {
@@ -27,12 +28,56 @@ pub trait SessionRepository: Send + Sync {
     /// List sessions by status
     async fn list_by_status(&self, status: &str) -> DomainResult<Vec<DraftSession>>;
 
+    /// List sessions that have not started and whose scheduled start is at or
+    /// before `now`, earliest first
+    async fn list_due_to_start(&self, now: DateTime<Utc>) -> DomainResult<Vec<DraftSession>>;
+
+    /// Update a session and append `events`, all in one transaction
+    async fn update_with_events(
+        &self,
+        session: &DraftSession,
+        events: &[DraftEvent],
+    ) -> DomainResult<DraftSession>;
+
     /// Atomically start a session and optionally transition its draft to InProgress.
     /// When `draft` is `Some`, both the draft status and session status are updated
-    /// in a single transaction. When `None`, only the session is updated.
+    /// in a single transaction. When `None`, only the session is updated. The
+    /// `SessionStarted` event is appended in the same transaction.
     async fn start_session_with_draft(
         &self,
         session: &DraftSession,
         draft: Option<&Draft>,
+        event: &DraftEvent,
+    ) -> DomainResult<DraftSession>;
+
+    /// Atomically record a pick made in a session: saves the pick's
+    /// selection, persists the session (normally just advanced past the
+    /// pick) and appends the `PickMade` event, all in one transaction.
+    /// Fails with `PlayerAlreadyDrafted` if another pick in the draft took
+    /// the player first.
+    async fn record_pick(
+        &self,
+        pick: &DraftPick,
+        session: &DraftSession,
+        event: &DraftEvent,
+    ) -> DomainResult<DraftPick>;
+
+    /// Atomically rewind a session to `session.current_pick_number`.
+    /// Clears every selection at or after that pick in the session's draft,
+    /// hands the picks in `reversed_trades` back to their original owners and
+    /// deletes those trades, updates the draft status when `draft` is `Some`,
+    /// persists the session, and appends `event`, all in one transaction.
+    async fn rewind_session(
+        &self,
+        session: &DraftSession,
+        draft: Option<&Draft>,
+        reversed_trades: &[TradeProposal],
+        event: &DraftEvent,
     ) -> DomainResult<DraftSession>;
+
+    /// Atomically insert an imported session bundle: the draft and its picks,
+    /// the session, trades with their pick details, the event log and grades.
+    /// IDs must already be local (see `SessionBundle::remap`). Grades that
+    /// already exist for a team and player are left untouched.
+    async fn import_bundle(&self, bundle: &SessionBundle) -> DomainResult<DraftSession>;
 }
@@ -2,7 +2,7 @@ use async_trait::async_trait;
 use uuid::Uuid;
 
 use crate::errors::DomainResult;
-use crate::models::Team;
+use crate::models::{Page, PageRequest, Sort, Team, TeamDependents, TeamSortField};
 
 /// Repository trait for Team data access
 ///
@@ -22,9 +22,37 @@ pub trait TeamRepository: Send + Sync {
     /// Get all teams
     async fn find_all(&self) -> DomainResult<Vec<Team>>;
 
+    /// Get teams in the requested order, falling back to the `find_all`
+    /// order, optionally cut down to one page.
+    /// Default implementation sorts and pages in memory.
+    async fn find_sorted(
+        &self,
+        sort: &Sort<TeamSortField>,
+        page: Option<PageRequest>,
+    ) -> DomainResult<Page<Team>> {
+        let mut teams = self.find_all().await?;
+        sort.apply(&mut teams, TeamSortField::compare);
+        Ok(Page::from_all(teams, page))
+    }
+
     /// Update a team
     async fn update(&self, team: &Team) -> DomainResult<Team>;
 
     /// Delete a team
     async fn delete(&self, id: Uuid) -> DomainResult<()>;
+
+    /// Hide a team from every read until restored, leaving its picks, needs
+    /// and trades in place
+    async fn soft_delete(&self, id: Uuid) -> DomainResult<()>;
+
+    /// Bring back a soft-deleted team
+    async fn restore(&self, id: Uuid) -> DomainResult<Team>;
+
+    /// Count the picks, needs and trades that refer to a team
+    async fn count_dependents(&self, id: Uuid) -> DomainResult<TeamDependents>;
+
+    /// Delete a team together with its needs, its trades and the picks it
+    /// holds, in one transaction. Picks it traded away stay with their
+    /// current holder and forget their original team.
+    async fn delete_with_dependents(&self, id: Uuid) -> DomainResult<()>;
 }
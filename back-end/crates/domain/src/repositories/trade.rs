@@ -1,5 +1,5 @@
 use crate::errors::DomainResult;
-use crate::models::{ChartType, PickTrade, TradeProposal};
+use crate::models::{ChartType, DraftEvent, PickTrade, TradeProposal};
 use async_trait::async_trait;
 use uuid::Uuid;
 
@@ -16,6 +16,15 @@ pub trait TradeRepository: Send + Sync {
         chart_type: ChartType,
     ) -> DomainResult<TradeProposal>;
 
+    /// Create trade with details like `create_trade` and append `event` in
+    /// the same transaction
+    async fn create_trade_with_event(
+        &self,
+        proposal: &TradeProposal,
+        chart_type: ChartType,
+        event: &DraftEvent,
+    ) -> DomainResult<TradeProposal>;
+
     /// Find trade by ID
     async fn find_by_id(&self, id: Uuid) -> DomainResult<Option<PickTrade>>;
 
@@ -35,6 +44,13 @@ pub trait TradeRepository: Send + Sync {
     /// Update trade status
     async fn update(&self, trade: &PickTrade) -> DomainResult<PickTrade>;
 
+    /// Update trade status and append `event` in the same transaction
+    async fn update_with_event(
+        &self,
+        trade: &PickTrade,
+        event: &DraftEvent,
+    ) -> DomainResult<PickTrade>;
+
     /// Check if pick is in any active (Proposed) trade, optionally excluding a specific trade
     async fn is_pick_in_active_trade(
         &self,
@@ -50,4 +66,12 @@ pub trait TradeRepository: Send + Sync {
         from_team_picks: &[Uuid],
         to_team_picks: &[Uuid],
     ) -> DomainResult<()>;
+
+    /// Execute an accepted trade (atomic): transfer its picks, save its
+    /// status and append `event`
+    async fn execute_trade(
+        &self,
+        proposal: &TradeProposal,
+        event: &DraftEvent,
+    ) -> DomainResult<PickTrade>;
 }
@@ -0,0 +1,20 @@
+use async_trait::async_trait;
+use uuid::Uuid;
+
+use crate::errors::DomainResult;
+use crate::models::{Role, User};
+
+/// Repository trait for user accounts
+#[async_trait]
+pub trait UserRepository: Send + Sync {
+    /// Store a new user; fails with `DuplicateEntry` when the email is taken
+    async fn create(&self, user: &User) -> DomainResult<User>;
+
+    async fn find_by_id(&self, id: Uuid) -> DomainResult<Option<User>>;
+
+    /// Look up a user by an already normalized email
+    async fn find_by_email(&self, email: &str) -> DomainResult<Option<User>>;
+
+    /// Change a user's role; fails with `NotFound` for an unknown id
+    async fn update_role(&self, id: Uuid, role: Role) -> DomainResult<User>;
+}
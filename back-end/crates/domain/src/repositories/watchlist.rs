@@ -0,0 +1,19 @@
+use async_trait::async_trait;
+use uuid::Uuid;
+
+use crate::errors::DomainResult;
+use crate::models::WatchlistEntry;
+
+/// Repository trait for user watchlist data access
+#[async_trait]
+pub trait WatchlistRepository: Send + Sync {
+    /// Add a player to a user's watchlist. Watching a player twice is a no-op
+    /// that returns the existing entry.
+    async fn add(&self, entry: &WatchlistEntry) -> DomainResult<WatchlistEntry>;
+
+    /// Take a player off a user's watchlist; returns false when the player wasn't on it
+    async fn remove(&self, user_id: Uuid, player_id: Uuid) -> DomainResult<bool>;
+
+    /// Find every entry on a user's watchlist, most recently added first
+    async fn find_by_user_id(&self, user_id: Uuid) -> DomainResult<Vec<WatchlistEntry>>;
+}
@@ -0,0 +1,159 @@
+use std::collections::{HashMap, HashSet};
+
+use uuid::Uuid;
+
+use crate::models::{AdpEntry, DraftPick};
+
+/// One player's average draft position across recorded mock drafts
+#[derive(Debug, Clone, PartialEq)]
+pub struct PlayerAdp {
+    pub player_id: Uuid,
+    /// Recorded drafts the player was picked in
+    pub times_drafted: usize,
+    /// `times_drafted` over every recorded draft, 0-1
+    pub draft_rate: f64,
+    pub average_pick: f64,
+    pub min_pick: i32,
+    pub max_pick: i32,
+    /// Average over the newer half of the player's drafts
+    pub recent_average: Option<f64>,
+    /// Older-half average minus newer-half average: positive when the
+    /// player is going earlier than they used to
+    pub trend: Option<f64>,
+}
+
+/// Turns repeated mock drafts into average draft position
+pub struct AdpService;
+
+impl AdpService {
+    /// ADP entries for every made pick of a completed draft
+    pub fn entries_for_draft(year: i32, picks: &[DraftPick]) -> Vec<AdpEntry> {
+        picks
+            .iter()
+            .filter_map(|pick| {
+                let player_id = pick.player_id?;
+                AdpEntry::new(
+                    pick.draft_id,
+                    year,
+                    player_id,
+                    pick.round,
+                    pick.overall_pick,
+                )
+                .ok()
+            })
+            .collect()
+    }
+
+    /// Summarize entries by player, lowest average pick first. Trends need
+    /// at least two drafts; entries should be oldest first.
+    pub fn summarize(entries: &[AdpEntry]) -> Vec<PlayerAdp> {
+        let drafts: HashSet<Uuid> = entries.iter().map(|e| e.draft_id).collect();
+
+        let mut by_player: HashMap<Uuid, Vec<&AdpEntry>> = HashMap::new();
+        for entry in entries {
+            by_player.entry(entry.player_id).or_default().push(entry);
+        }
+
+        let mut summaries: Vec<PlayerAdp> = by_player
+            .into_iter()
+            .map(|(player_id, mut history)| {
+                history.sort_by_key(|e| e.recorded_at);
+                let slots: Vec<i32> = history.iter().map(|e| e.overall_pick).collect();
+
+                let half = slots.len() / 2;
+                let (recent_average, trend) = if half == 0 {
+                    (None, None)
+                } else {
+                    let older = Self::average(&slots[..half]);
+                    let recent = Self::average(&slots[slots.len() - half..]);
+                    (Some(recent), Some(Self::round(older - recent)))
+                };
+
+                PlayerAdp {
+                    player_id,
+                    times_drafted: slots.len(),
+                    draft_rate: Self::round(slots.len() as f64 / drafts.len() as f64),
+                    average_pick: Self::average(&slots),
+                    min_pick: slots.iter().copied().min().unwrap_or_default(),
+                    max_pick: slots.iter().copied().max().unwrap_or_default(),
+                    recent_average,
+                    trend,
+                }
+            })
+            .collect();
+
+        summaries.sort_by(|a, b| {
+            a.average_pick
+                .total_cmp(&b.average_pick)
+                .then_with(|| b.times_drafted.cmp(&a.times_drafted))
+                .then_with(|| a.player_id.cmp(&b.player_id))
+        });
+        summaries
+    }
+
+    fn average(slots: &[i32]) -> f64 {
+        Self::round(slots.iter().map(|&s| s as f64).sum::<f64>() / slots.len() as f64)
+    }
+
+    fn round(value: f64) -> f64 {
+        (value * 100.0).round() / 100.0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::{Duration, Utc};
+
+    fn entry(draft_id: Uuid, player_id: Uuid, overall_pick: i32, days_ago: i64) -> AdpEntry {
+        let mut entry = AdpEntry::new(draft_id, 2026, player_id, 1, overall_pick).unwrap();
+        entry.recorded_at = Utc::now() - Duration::days(days_ago);
+        entry
+    }
+
+    #[test]
+    fn test_entries_for_draft_skips_unmade_picks() {
+        let draft_id = Uuid::new_v4();
+        let team_id = Uuid::new_v4();
+        let mut made = DraftPick::new(draft_id, 1, 1, 1, team_id).unwrap();
+        made.player_id = Some(Uuid::new_v4());
+        let unmade = DraftPick::new(draft_id, 1, 2, 2, team_id).unwrap();
+
+        let entries = AdpService::entries_for_draft(2026, &[made.clone(), unmade]);
+        assert_eq!(entries.len(), 1);
+        assert_eq!(entries[0].player_id, made.player_id.unwrap());
+        assert_eq!(entries[0].year, 2026);
+    }
+
+    #[test]
+    fn test_summarize_average_range_and_trend() {
+        let drafts: Vec<Uuid> = (0..4).map(|_| Uuid::new_v4()).collect();
+        let riser = Uuid::new_v4();
+        let once = Uuid::new_v4();
+
+        // Oldest first: 10, 8, then 4, 2 as the player climbs boards
+        let entries = vec![
+            entry(drafts[0], riser, 10, 4),
+            entry(drafts[1], riser, 8, 3),
+            entry(drafts[2], riser, 4, 2),
+            entry(drafts[3], riser, 2, 1),
+            entry(drafts[3], once, 12, 1),
+        ];
+
+        let summaries = AdpService::summarize(&entries);
+        assert_eq!(summaries.len(), 2);
+
+        let riser = &summaries[0];
+        assert_eq!(riser.times_drafted, 4);
+        assert_eq!(riser.draft_rate, 1.0);
+        assert_eq!(riser.average_pick, 6.0);
+        assert_eq!(riser.min_pick, 2);
+        assert_eq!(riser.max_pick, 10);
+        assert_eq!(riser.recent_average, Some(3.0));
+        assert_eq!(riser.trend, Some(6.0));
+
+        let once = &summaries[1];
+        assert_eq!(once.draft_rate, 0.25);
+        assert_eq!(once.trend, None);
+    }
+}
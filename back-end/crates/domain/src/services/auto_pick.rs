@@ -185,19 +185,19 @@ impl AutoPickService {
             .map(|r| (r.player_id, r))
             .collect();
 
-        // Pre-fetch combine results for all players (N queries, but eliminates 10N RAS sub-queries)
-        let mut combine_by_player: HashMap<Uuid, crate::models::CombineResults> = HashMap::new();
-        for player in players {
-            if let Ok(results) = self
-                .player_eval_service
-                .fetch_player_combine_results(player.id)
-                .await
-            {
-                if let Some(first) = results.into_iter().next() {
-                    combine_by_player.insert(player.id, first);
-                }
-            }
-        }
+        // Pre-fetch combine results for all players (1 query instead of N), merged per player
+        let player_ids: Vec<Uuid> = players.iter().map(|p| p.id).collect();
+        let combine_by_player: HashMap<Uuid, crate::models::CombineResults> = self
+            .player_eval_service
+            .fetch_combine_results_for_players(&player_ids)
+            .await?
+            .into_iter()
+            .filter_map(|(player_id, results)| {
+                self.player_eval_service
+                    .select_combine_results(&results)
+                    .map(|merged| (player_id, merged))
+            })
+            .collect();
 
         // Pre-fetch percentiles for all relevant position groups (~13 queries instead of 10*N)
         let position_groups: HashSet<String> = players
@@ -216,7 +216,6 @@ impl AutoPickService {
         // Pre-fetch prospect rankings for available players (1 query) → normalize to 0-100
         // Normalization: rank 1 → 100, rank 300 → 0 (exactly); average across sources when multiple exist.
         // Denominator 299 = (300 - 1) ensures rank 300 maps to exactly 0.0.
-        let player_ids: Vec<Uuid> = players.iter().map(|p| p.id).collect();
         let ranking_scores: HashMap<Uuid, f64> = if let Some(ranking_repo) = &self.ranking_repo {
             match ranking_repo.find_for_players_with_source(&player_ids).await {
                 Ok(all_rankings) => {
@@ -456,9 +455,17 @@ mod tests {
             async fn find_by_id(&self, id: Uuid) -> DomainResult<Option<ScoutingReport>>;
             async fn find_by_player_id(&self, player_id: Uuid) -> DomainResult<Vec<ScoutingReport>>;
             async fn find_by_team_id(&self, team_id: Uuid) -> DomainResult<Vec<ScoutingReport>>;
+            async fn find_filtered(
+                &self,
+                filter: &crate::models::ScoutingReportFilter,
+                page: Option<crate::models::PageRequest>,
+            ) -> DomainResult<crate::models::Page<ScoutingReport>>;
             async fn find_by_team_and_player(&self, team_id: Uuid, player_id: Uuid) -> DomainResult<Option<ScoutingReport>>;
+            async fn find_reports_by_team_for_players(&self, team_id: Uuid, player_ids: &[Uuid]) -> DomainResult<Vec<ScoutingReport>>;
             async fn update(&self, report: &ScoutingReport) -> DomainResult<ScoutingReport>;
             async fn delete(&self, id: Uuid) -> DomainResult<()>;
+            async fn soft_delete(&self, id: Uuid) -> DomainResult<()>;
+            async fn restore(&self, id: Uuid) -> DomainResult<ScoutingReport>;
         }
     }
 
@@ -470,6 +477,7 @@ mod tests {
             async fn create(&self, results: &CombineResults) -> DomainResult<CombineResults>;
             async fn find_by_id(&self, id: Uuid) -> DomainResult<Option<CombineResults>>;
             async fn find_by_player_id(&self, player_id: Uuid) -> DomainResult<Vec<CombineResults>>;
+            async fn find_combine_results_for_players(&self, player_ids: &[Uuid]) -> DomainResult<Vec<CombineResults>>;
             async fn find_by_player_and_year(&self, player_id: Uuid, year: i32) -> DomainResult<Option<CombineResults>>;
             async fn find_by_player_year_source(&self, player_id: Uuid, year: i32, source: &str) -> DomainResult<Option<CombineResults>>;
             async fn update(&self, results: &CombineResults) -> DomainResult<CombineResults>;
@@ -516,6 +524,7 @@ mod tests {
             async fn find_by_player_with_source(&self, player_id: Uuid) -> DomainResult<Vec<PlayerRankingWithSource>>;
             async fn find_all_with_source(&self) -> DomainResult<Vec<PlayerRankingWithSource>>;
             async fn find_for_players_with_source(&self, player_ids: &[Uuid]) -> DomainResult<Vec<PlayerRankingWithSource>>;
+            async fn find_consensus(&self, draft_year: Option<i32>) -> DomainResult<Vec<crate::models::ConsensusRanking>>;
             async fn find_by_player(&self, player_id: Uuid) -> DomainResult<Vec<crate::models::ProspectRanking>>;
             async fn find_by_source(&self, source_id: Uuid) -> DomainResult<Vec<crate::models::ProspectRanking>>;
             async fn delete_by_source(&self, source_id: Uuid) -> DomainResult<u64>;
@@ -582,9 +591,10 @@ mod tests {
             .expect_find_by_team_id()
             .returning(move |_| Ok(vec![qb_report.clone(), rb_report.clone()]));
 
-        // Combine results: fetched per player
+        // Combine results: fetched once for every player
         combine_mock
-            .expect_find_by_player_id()
+            .expect_find_combine_results_for_players()
+            .times(1)
             .returning(|_| Ok(vec![]));
 
         // Team needs: fetched once
@@ -655,9 +665,9 @@ mod tests {
             .expect_find_by_team_id()
             .returning(move |_| Ok(vec![qb_report.clone(), rb_report.clone()]));
 
-        // Combine results: per player
+        // Combine results: fetched once for every player
         combine_mock
-            .expect_find_by_player_id()
+            .expect_find_combine_results_for_players()
             .returning(|_| Ok(vec![]));
 
         // Team needs: fetched once
@@ -724,9 +734,9 @@ mod tests {
             .expect_find_by_team_id()
             .returning(move |_| Ok(vec![qb_report.clone(), rb_report.clone()]));
 
-        // Combine results: per player
+        // Combine results: fetched once for every player
         combine_mock
-            .expect_find_by_player_id()
+            .expect_find_combine_results_for_players()
             .returning(|_| Ok(vec![]));
 
         // No specific needs
@@ -852,7 +862,7 @@ mod tests {
             .returning(move |_| Ok(vec![top_report.clone(), late_report.clone()]));
 
         combine_mock
-            .expect_find_by_player_id()
+            .expect_find_combine_results_for_players()
             .returning(|_| Ok(vec![]));
 
         need_mock.expect_find_by_team_id().returning(|_| Ok(vec![]));
@@ -954,7 +964,7 @@ mod tests {
             .returning(move |_| Ok(vec![lb_report.clone(), qb_report.clone()]));
 
         combine_mock
-            .expect_find_by_player_id()
+            .expect_find_combine_results_for_players()
             .returning(|_| Ok(vec![]));
 
         need_mock.expect_find_by_team_id().returning(|_| Ok(vec![]));
@@ -1050,7 +1060,7 @@ mod tests {
             .returning(move |_| Ok(vec![qb_report.clone(), rb_report.clone()]));
 
         combine_mock
-            .expect_find_by_player_id()
+            .expect_find_combine_results_for_players()
             .returning(|_| Ok(vec![]));
 
         need_mock.expect_find_by_team_id().returning(|_| Ok(vec![]));
@@ -1,10 +1,12 @@
+use std::future::Future;
 use std::sync::Arc;
 use uuid::Uuid;
 
 use crate::errors::{DomainError, DomainResult};
-use crate::models::{Draft, DraftPick, Player, Team};
+use crate::models::{Draft, DraftPick, DraftSortField, Page, PageRequest, Player, Sort, Team};
 use crate::repositories::{
-    DraftPickRepository, DraftRepository, PlayerRepository, TeamRepository, TeamSeasonRepository,
+    DraftLockGuard, DraftLockRepository, DraftPickRepository, DraftRepository, PlayerRepository,
+    TeamRepository, TeamSeasonRepository,
 };
 use crate::services::AutoPickService;
 
@@ -16,6 +18,7 @@ pub struct DraftEngine {
     player_repo: Arc<dyn PlayerRepository>,
     team_season_repo: Option<Arc<dyn TeamSeasonRepository>>,
     auto_pick_service: Option<Arc<AutoPickService>>,
+    draft_lock: Option<Arc<dyn DraftLockRepository>>,
 }
 
 impl DraftEngine {
@@ -32,6 +35,7 @@ impl DraftEngine {
             player_repo,
             team_season_repo: None,
             auto_pick_service: None,
+            draft_lock: None,
         }
     }
 
@@ -48,6 +52,13 @@ impl DraftEngine {
         self
     }
 
+    /// Serialize picks in a draft across clients and processes. Without a
+    /// lock, concurrent picks are only caught when they collide on a player.
+    pub fn with_draft_lock(mut self, draft_lock: Arc<dyn DraftLockRepository>) -> Self {
+        self.draft_lock = Some(draft_lock);
+        self
+    }
+
     /// Create a new custom draft with fixed picks per round
     pub async fn create_draft(
         &self,
@@ -206,38 +217,62 @@ impl DraftEngine {
         self.pick_repo.find_by_draft_id(draft_id).await
     }
 
-    /// Get available players for drafting (not yet picked in this draft)
-    pub async fn get_available_players(
+    /// Get one page of a draft's picks in overall pick order
+    pub async fn get_picks_page(
         &self,
         draft_id: Uuid,
-        draft_year: i32,
-    ) -> DomainResult<Vec<Player>> {
-        // Get all players eligible for the draft year
-        let all_players = self.player_repo.find_by_draft_year(draft_year).await?;
-
-        // Get all picks for this draft
-        let picks = self.pick_repo.find_by_draft_id(draft_id).await?;
-
-        // Get IDs of already picked players
-        let picked_player_ids: std::collections::HashSet<Uuid> =
-            picks.iter().filter_map(|pick| pick.player_id).collect();
-
-        // Filter out picked players
-        let available_players = all_players
-            .into_iter()
-            .filter(|player| !picked_player_ids.contains(&player.id))
-            .collect();
+        page: PageRequest,
+    ) -> DomainResult<Page<DraftPick>> {
+        self.pick_repo.find_page_by_draft_id(draft_id, page).await
+    }
 
-        Ok(available_players)
+    /// Get available players for drafting (not yet picked in this draft)
+    pub async fn get_available_players(&self, draft_id: Uuid) -> DomainResult<Vec<Player>> {
+        self.player_repo.find_available_for_draft(draft_id).await
     }
 
     /// Make a draft pick
     pub async fn make_pick(&self, pick_id: Uuid, player_id: Uuid) -> DomainResult<DraftPick> {
-        // Get the pick
-        let mut pick =
-            self.pick_repo.find_by_id(pick_id).await?.ok_or_else(|| {
-                DomainError::NotFound(format!("Pick with id {} not found", pick_id))
-            })?;
+        self.make_pick_with(pick_id, player_id, |pick| async move {
+            self.pick_repo.update(&pick).await
+        })
+        .await
+    }
+
+    /// Validate a draft pick and hand the made pick to `save` to persist.
+    /// Sessions use this to write the pick together with the session and its
+    /// event in one transaction. Holds the draft lock throughout.
+    pub async fn make_pick_with<F, Fut>(
+        &self,
+        pick_id: Uuid,
+        player_id: Uuid,
+        save: F,
+    ) -> DomainResult<DraftPick>
+    where
+        F: FnOnce(DraftPick) -> Fut,
+        Fut: Future<Output = DomainResult<DraftPick>>,
+    {
+        let draft_id = self.find_pick(pick_id).await?.draft_id;
+        let lock = self.lock_draft(draft_id).await?;
+        let result = self.make_pick_locked(pick_id, player_id, save).await;
+        Self::unlock_draft(lock).await;
+        result
+    }
+
+    /// The body of `make_pick_with`, for callers already holding the draft
+    /// lock. The pick is read again so a selection made while waiting for
+    /// the lock is seen.
+    async fn make_pick_locked<F, Fut>(
+        &self,
+        pick_id: Uuid,
+        player_id: Uuid,
+        save: F,
+    ) -> DomainResult<DraftPick>
+    where
+        F: FnOnce(DraftPick) -> Fut,
+        Fut: Future<Output = DomainResult<DraftPick>>,
+    {
+        let mut pick = self.find_pick(pick_id).await?;
 
         // Verify player exists
         let player = self
@@ -283,8 +318,31 @@ impl DraftEngine {
         // Make the pick
         pick.make_pick(player_id)?;
 
-        // Update in database
-        self.pick_repo.update(&pick).await
+        save(pick).await
+    }
+
+    async fn find_pick(&self, pick_id: Uuid) -> DomainResult<DraftPick> {
+        self.pick_repo
+            .find_by_id(pick_id)
+            .await?
+            .ok_or_else(|| DomainError::NotFound(format!("Pick with id {} not found", pick_id)))
+    }
+
+    async fn lock_draft(&self, draft_id: Uuid) -> DomainResult<Option<Box<dyn DraftLockGuard>>> {
+        match &self.draft_lock {
+            Some(draft_lock) => Ok(Some(draft_lock.acquire(draft_id).await?)),
+            None => Ok(None),
+        }
+    }
+
+    /// The pick has already been saved or rejected by now, so a failed
+    /// release is only logged; the guard frees the lock when dropped
+    async fn unlock_draft(lock: Option<Box<dyn DraftLockGuard>>) {
+        if let Some(lock) = lock {
+            if let Err(e) = lock.release().await {
+                tracing::warn!("Failed to release draft lock: {}", e);
+            }
+        }
     }
 
     /// Start a draft
@@ -332,18 +390,44 @@ impl DraftEngine {
         self.draft_repo.find_all().await
     }
 
+    /// Get drafts in the requested order (newest year first by default),
+    /// optionally cut down to one page
+    pub async fn get_drafts_sorted(
+        &self,
+        sort: &Sort<DraftSortField>,
+        page: Option<PageRequest>,
+    ) -> DomainResult<Page<Draft>> {
+        self.draft_repo.find_sorted(sort, page).await
+    }
+
     /// Execute an auto-pick decision for a given pick
     /// This uses the AI draft engine to select the best available player
     pub async fn execute_auto_pick(&self, pick_id: Uuid) -> DomainResult<DraftPick> {
+        self.execute_auto_pick_with(
+            pick_id,
+            |pick| async move { self.pick_repo.update(&pick).await },
+        )
+        .await
+    }
+
+    /// Like `execute_auto_pick`, persisting the chosen pick with `save`; see
+    /// `make_pick_with`. The draft lock is held from choosing the player to
+    /// saving the pick, so no one can take the player in between.
+    pub async fn execute_auto_pick_with<F, Fut>(
+        &self,
+        pick_id: Uuid,
+        save: F,
+    ) -> DomainResult<DraftPick>
+    where
+        F: Fn(DraftPick) -> Fut,
+        Fut: Future<Output = DomainResult<DraftPick>>,
+    {
         let auto_pick_service = self.auto_pick_service.as_ref().ok_or_else(|| {
             DomainError::InternalError("Auto-pick service not configured".to_string())
         })?;
 
         // Get the pick
-        let pick =
-            self.pick_repo.find_by_id(pick_id).await?.ok_or_else(|| {
-                DomainError::NotFound(format!("Pick with id {} not found", pick_id))
-            })?;
+        let pick = self.find_pick(pick_id).await?;
 
         // Get the draft
         let draft = self
@@ -352,13 +436,30 @@ impl DraftEngine {
             .await?
             .ok_or_else(|| DomainError::NotFound("Draft not found".to_string()))?;
 
-        // Retry loop: if the chosen player was already drafted (race condition),
-        // re-fetch available players and try again.
+        let lock = self.lock_draft(pick.draft_id).await?;
+        let result = self
+            .auto_pick_locked(auto_pick_service, &pick, &draft, &save)
+            .await;
+        Self::unlock_draft(lock).await;
+        result
+    }
+
+    async fn auto_pick_locked<F, Fut>(
+        &self,
+        auto_pick_service: &AutoPickService,
+        pick: &DraftPick,
+        draft: &Draft,
+        save: &F,
+    ) -> DomainResult<DraftPick>
+    where
+        F: Fn(DraftPick) -> Fut,
+        Fut: Future<Output = DomainResult<DraftPick>>,
+    {
+        // Retry loop: without a draft lock another client can take the chosen
+        // player first, so re-fetch available players and try again.
         const MAX_RETRIES: usize = 3;
         for attempt in 0..MAX_RETRIES {
-            let available_players = self
-                .get_available_players(pick.draft_id, draft.year)
-                .await?;
+            let available_players = self.get_available_players(pick.draft_id).await?;
 
             if available_players.is_empty() {
                 return Err(DomainError::ValidationError(
@@ -378,7 +479,10 @@ impl DraftEngine {
                 .await?;
 
             // Make the pick — retry if player was already drafted (race condition)
-            match self.make_pick(pick_id, selected_player_id).await {
+            match self
+                .make_pick_locked(pick.id, selected_player_id, save)
+                .await
+            {
                 Ok(result) => return Ok(result),
                 Err(DomainError::PlayerAlreadyDrafted(_)) if attempt < MAX_RETRIES - 1 => {
                     tracing::warn!(
@@ -400,7 +504,9 @@ impl DraftEngine {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::models::{Conference, Division, Position, Team};
+    use crate::models::{
+        Conference, Division, PlayerFilter, PlayerSortField, Position, Team, TeamDependents,
+    };
     use mockall::mock;
     use mockall::predicate::*;
 
@@ -446,6 +552,10 @@ mod tests {
             async fn find_all(&self) -> DomainResult<Vec<Team>>;
             async fn update(&self, team: &Team) -> DomainResult<Team>;
             async fn delete(&self, id: Uuid) -> DomainResult<()>;
+            async fn soft_delete(&self, id: Uuid) -> DomainResult<()>;
+            async fn restore(&self, id: Uuid) -> DomainResult<Team>;
+            async fn count_dependents(&self, id: Uuid) -> DomainResult<TeamDependents>;
+            async fn delete_with_dependents(&self, id: Uuid) -> DomainResult<()>;
         }
     }
 
@@ -456,11 +566,20 @@ mod tests {
             async fn create(&self, player: &Player) -> DomainResult<Player>;
             async fn find_by_id(&self, id: Uuid) -> DomainResult<Option<Player>>;
             async fn find_all(&self) -> DomainResult<Vec<Player>>;
+            async fn find_filtered(
+                &self,
+                filter: &PlayerFilter,
+                sort: &Sort<PlayerSortField>,
+                page: Option<PageRequest>,
+            ) -> DomainResult<Page<Player>>;
             async fn find_by_position(&self, position: Position) -> DomainResult<Vec<Player>>;
             async fn find_by_draft_year(&self, year: i32) -> DomainResult<Vec<Player>>;
             async fn find_draft_eligible(&self, year: i32) -> DomainResult<Vec<Player>>;
+            async fn find_available_for_draft(&self, draft_id: Uuid) -> DomainResult<Vec<Player>>;
             async fn update(&self, player: &Player) -> DomainResult<Player>;
             async fn delete(&self, id: Uuid) -> DomainResult<()>;
+            async fn soft_delete(&self, id: Uuid) -> DomainResult<()>;
+            async fn restore(&self, id: Uuid) -> DomainResult<Player>;
         }
     }
 
@@ -639,6 +758,80 @@ mod tests {
         assert_eq!(made_pick.player_id, Some(player_id));
     }
 
+    struct RecordingLock {
+        log: Arc<std::sync::Mutex<Vec<&'static str>>>,
+    }
+
+    struct RecordingGuard {
+        log: Arc<std::sync::Mutex<Vec<&'static str>>>,
+    }
+
+    #[async_trait::async_trait]
+    impl DraftLockRepository for RecordingLock {
+        async fn acquire(&self, _draft_id: Uuid) -> DomainResult<Box<dyn DraftLockGuard>> {
+            self.log.lock().unwrap().push("acquire");
+            Ok(Box::new(RecordingGuard {
+                log: Arc::clone(&self.log),
+            }))
+        }
+    }
+
+    #[async_trait::async_trait]
+    impl DraftLockGuard for RecordingGuard {
+        async fn release(self: Box<Self>) -> DomainResult<()> {
+            self.log.lock().unwrap().push("release");
+            Ok(())
+        }
+    }
+
+    #[tokio::test]
+    async fn test_make_pick_saves_while_holding_draft_lock() {
+        let draft = make_test_draft();
+        let draft_id = draft.id;
+        let pick = DraftPick::new(draft_id, 1, 1, 1, Uuid::new_v4()).unwrap();
+        let pick_id = pick.id;
+        let player = make_test_player(2026, true);
+        let player_id = player.id;
+        let log = Arc::new(std::sync::Mutex::new(Vec::new()));
+
+        let mut pick_repo = MockDraftPickRepo::new();
+        pick_repo
+            .expect_find_by_id()
+            .with(eq(pick_id))
+            .returning(move |_| Ok(Some(pick.clone())));
+        pick_repo
+            .expect_find_by_draft_id()
+            .returning(|_| Ok(vec![]));
+        let update_log = Arc::clone(&log);
+        pick_repo.expect_update().returning(move |p| {
+            update_log.lock().unwrap().push("update");
+            Ok(p.clone())
+        });
+
+        let mut draft_repo = MockDraftRepo::new();
+        draft_repo
+            .expect_find_by_id()
+            .returning(move |_| Ok(Some(draft.clone())));
+
+        let mut player_repo = MockPlayerRepo::new();
+        player_repo
+            .expect_find_by_id()
+            .returning(move |_| Ok(Some(player.clone())));
+
+        let engine = DraftEngine::new(
+            Arc::new(draft_repo),
+            Arc::new(pick_repo),
+            Arc::new(MockTeamRepo::new()),
+            Arc::new(player_repo),
+        )
+        .with_draft_lock(Arc::new(RecordingLock {
+            log: Arc::clone(&log),
+        }));
+
+        engine.make_pick(pick_id, player_id).await.unwrap();
+        assert_eq!(*log.lock().unwrap(), vec!["acquire", "update", "release"]);
+    }
+
     #[tokio::test]
     async fn test_make_pick_pick_not_found() {
         let pick_id = Uuid::new_v4();
@@ -0,0 +1,176 @@
+use std::collections::HashMap;
+
+use uuid::Uuid;
+
+use crate::models::{ActualDraftPick, DraftPick};
+
+/// Points for a mock pick whose player went at exactly that slot
+pub const EXACT_PICK_POINTS: u32 = 3;
+/// Points for a mock pick whose player went to the same team
+pub const CORRECT_TEAM_POINTS: u32 = 2;
+/// Points for a mock pick whose player went in the same round
+pub const CORRECT_ROUND_POINTS: u32 = 1;
+
+const MAX_PICK_POINTS: u32 = EXACT_PICK_POINTS + CORRECT_TEAM_POINTS + CORRECT_ROUND_POINTS;
+
+/// One mock pick compared with where its player really went
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PickAccuracy {
+    pub pick_id: Uuid,
+    pub round: i32,
+    pub overall_pick: i32,
+    pub team_id: Uuid,
+    pub player_id: Uuid,
+    /// `None` when the player was not drafted in the real draft
+    pub actual: Option<ActualDraftPick>,
+    pub exact_pick: bool,
+    pub correct_team: bool,
+    pub correct_round: bool,
+    pub points: u32,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct MockAccuracy {
+    /// Every made mock pick, in draft order
+    pub picks: Vec<PickAccuracy>,
+    pub exact_picks: usize,
+    pub correct_teams: usize,
+    pub correct_rounds: usize,
+    /// Mock picks whose player was drafted anywhere in the real draft
+    pub players_drafted: usize,
+    pub points: u32,
+    pub max_points: u32,
+    /// `points` as a percentage of `max_points`, to one decimal
+    pub score_percent: f64,
+}
+
+/// Scores a mock draft against the real draft results
+pub struct MockAccuracyService;
+
+impl MockAccuracyService {
+    /// Score the made picks of a mock draft. A pick earns points for the
+    /// exact slot, the right team and the right round independently, so a
+    /// perfect pick is worth all three.
+    pub fn score(mock_picks: &[DraftPick], actual: &[ActualDraftPick]) -> MockAccuracy {
+        let actual_by_player: HashMap<Uuid, &ActualDraftPick> =
+            actual.iter().map(|a| (a.player_id, a)).collect();
+
+        let mut picks: Vec<PickAccuracy> = mock_picks
+            .iter()
+            .filter_map(|pick| {
+                let player_id = pick.player_id?;
+                let actual = actual_by_player.get(&player_id).copied();
+                let exact_pick = actual.is_some_and(|a| a.overall_pick == pick.overall_pick);
+                let correct_team = actual.is_some_and(|a| a.team_id == pick.team_id);
+                let correct_round = actual.is_some_and(|a| a.round == pick.round);
+
+                let mut points = 0;
+                if exact_pick {
+                    points += EXACT_PICK_POINTS;
+                }
+                if correct_team {
+                    points += CORRECT_TEAM_POINTS;
+                }
+                if correct_round {
+                    points += CORRECT_ROUND_POINTS;
+                }
+
+                Some(PickAccuracy {
+                    pick_id: pick.id,
+                    round: pick.round,
+                    overall_pick: pick.overall_pick,
+                    team_id: pick.team_id,
+                    player_id,
+                    actual: actual.cloned(),
+                    exact_pick,
+                    correct_team,
+                    correct_round,
+                    points,
+                })
+            })
+            .collect();
+        picks.sort_by_key(|p| p.overall_pick);
+
+        let points: u32 = picks.iter().map(|p| p.points).sum();
+        let max_points = picks.len() as u32 * MAX_PICK_POINTS;
+        let score_percent = if max_points == 0 {
+            0.0
+        } else {
+            (points as f64 / max_points as f64 * 1000.0).round() / 10.0
+        };
+
+        MockAccuracy {
+            exact_picks: picks.iter().filter(|p| p.exact_pick).count(),
+            correct_teams: picks.iter().filter(|p| p.correct_team).count(),
+            correct_rounds: picks.iter().filter(|p| p.correct_round).count(),
+            players_drafted: picks.iter().filter(|p| p.actual.is_some()).count(),
+            picks,
+            points,
+            max_points,
+            score_percent,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn mock_pick(
+        round: i32,
+        overall_pick: i32,
+        team_id: Uuid,
+        player_id: Option<Uuid>,
+    ) -> DraftPick {
+        let mut pick =
+            DraftPick::new(Uuid::new_v4(), round, overall_pick, overall_pick, team_id).unwrap();
+        pick.player_id = player_id;
+        pick
+    }
+
+    #[test]
+    fn test_score_awards_slot_team_and_round_independently() {
+        let team_a = Uuid::new_v4();
+        let team_b = Uuid::new_v4();
+        let players: Vec<Uuid> = (0..4).map(|_| Uuid::new_v4()).collect();
+
+        let actual = vec![
+            ActualDraftPick::new(2026, 1, 1, 1, team_a, players[0]).unwrap(),
+            ActualDraftPick::new(2026, 1, 2, 2, team_a, players[1]).unwrap(),
+            ActualDraftPick::new(2026, 2, 1, 3, team_b, players[2]).unwrap(),
+        ];
+        let mock = vec![
+            mock_pick(1, 1, team_a, Some(players[0])), // exact: 3 + 2 + 1
+            mock_pick(1, 2, team_a, Some(players[2])), // nothing but drafted
+            mock_pick(2, 3, team_a, Some(players[1])), // team only: 2
+            mock_pick(2, 4, team_b, Some(players[3])), // undrafted
+            mock_pick(3, 5, team_a, None),
+        ];
+
+        let accuracy = MockAccuracyService::score(&mock, &actual);
+
+        assert_eq!(accuracy.picks.len(), 4);
+        assert_eq!(accuracy.picks[0].points, 6);
+        assert!(accuracy.picks[0].exact_pick);
+        assert_eq!(accuracy.picks[1].points, 0);
+        assert_eq!(accuracy.picks[1].actual.as_ref().unwrap().overall_pick, 3);
+        assert_eq!(accuracy.picks[2].points, 2);
+        assert!(accuracy.picks[3].actual.is_none());
+
+        assert_eq!(accuracy.exact_picks, 1);
+        assert_eq!(accuracy.correct_teams, 2);
+        assert_eq!(accuracy.correct_rounds, 1);
+        assert_eq!(accuracy.players_drafted, 3);
+        assert_eq!(accuracy.points, 8);
+        assert_eq!(accuracy.max_points, 24);
+        assert_eq!(accuracy.score_percent, 33.3);
+    }
+
+    #[test]
+    fn test_score_without_made_picks() {
+        let accuracy = MockAccuracyService::score(&[], &[]);
+        assert!(accuracy.picks.is_empty());
+        assert_eq!(accuracy.max_points, 0);
+        assert_eq!(accuracy.score_percent, 0.0);
+    }
+}
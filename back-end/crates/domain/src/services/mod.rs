@@ -1,17 +1,35 @@
+pub mod adp;
 pub mod auto_pick;
 pub mod draft_clock;
 pub mod draft_engine;
 pub mod draft_strategy;
+pub mod mock_accuracy;
+pub mod percentile_baseline;
 pub mod player_evaluation;
+pub mod positional_scarcity;
 pub mod ras_scoring;
+pub mod session_projector;
+pub mod surplus_value;
 pub mod trade_engine;
 pub mod trade_value;
+pub mod value_over_slot;
 
+pub use adp::{AdpService, PlayerAdp};
 pub use auto_pick::{AutoPickService, PlayerScore};
 pub use draft_clock::{ClockManager, ClockState, DraftClock};
 pub use draft_engine::DraftEngine;
 pub use draft_strategy::DraftStrategyService;
+pub use mock_accuracy::{MockAccuracy, MockAccuracyService, PickAccuracy};
+pub use percentile_baseline::{PercentileBaselineService, PercentileBaselines, SkippedBaseline};
 pub use player_evaluation::PlayerEvaluationService;
+pub use positional_scarcity::{PositionScarcity, PositionalScarcityService};
 pub use ras_scoring::RasScoringService;
+pub use session_projector::{
+    ProjectedPick, ProjectionMismatch, SessionProjection, SessionProjector,
+};
+pub use surplus_value::{
+    PickSurplus, RookieWageScale, SurplusReport, SurplusValueService, TeamSurplus,
+};
 pub use trade_engine::TradeEngine;
 pub use trade_value::TradeValueChart;
+pub use value_over_slot::{PickValue, TeamValue, ValueAnalysis, ValueOverSlotService};
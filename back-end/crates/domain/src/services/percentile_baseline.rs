@@ -0,0 +1,265 @@
+use std::collections::{BTreeMap, HashMap};
+
+use uuid::Uuid;
+
+use crate::errors::{DomainError, DomainResult};
+use crate::models::{CombinePercentile, CombineResults, CombineSource, Measurement, Player};
+use crate::services::ras_scoring::map_position_for_percentile;
+
+/// Every measurement a percentile row can be built for
+const MEASUREMENTS: [Measurement; 13] = [
+    Measurement::Height,
+    Measurement::Weight,
+    Measurement::FortyYardDash,
+    Measurement::TenYardSplit,
+    Measurement::TwentyYardSplit,
+    Measurement::BenchPress,
+    Measurement::VerticalJump,
+    Measurement::BroadJump,
+    Measurement::ThreeConeDrill,
+    Measurement::TwentyYardShuttle,
+    Measurement::ArmLength,
+    Measurement::HandSize,
+    Measurement::Wingspan,
+];
+
+/// A position group and measurement that had too few samples to compute
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SkippedBaseline {
+    pub position: String,
+    pub measurement: Measurement,
+    pub sample_size: usize,
+}
+
+#[derive(Debug, Clone)]
+pub struct PercentileBaselines {
+    pub percentiles: Vec<CombinePercentile>,
+    pub skipped: Vec<SkippedBaseline>,
+}
+
+/// Derives combine percentile breakpoints from stored combine results,
+/// rather than the static template
+pub struct PercentileBaselineService;
+
+impl PercentileBaselineService {
+    /// Compute breakpoints per position group and measurement from the
+    /// results tested in `years_start..=years_end`. Each player counts once
+    /// per measurement, preferring combine numbers over pro day and newer
+    /// years over older. Height and weight come from the player record.
+    /// Pairs with fewer than `min_sample_size` values are skipped.
+    pub fn compute(
+        players: &[Player],
+        results: &[CombineResults],
+        position: Option<&str>,
+        years_start: i32,
+        years_end: i32,
+        min_sample_size: usize,
+    ) -> DomainResult<PercentileBaselines> {
+        if years_start > years_end {
+            return Err(DomainError::ValidationError(format!(
+                "years_start ({}) must be <= years_end ({})",
+                years_start, years_end
+            )));
+        }
+
+        let players: HashMap<Uuid, &Player> = players.iter().map(|p| (p.id, p)).collect();
+        let mut by_player: HashMap<Uuid, Vec<&CombineResults>> = HashMap::new();
+        for result in results
+            .iter()
+            .filter(|r| (years_start..=years_end).contains(&r.year))
+        {
+            by_player.entry(result.player_id).or_default().push(result);
+        }
+
+        let mut samples: BTreeMap<(String, String), (Measurement, Vec<f64>)> = BTreeMap::new();
+        for (player_id, mut player_results) in by_player {
+            let Some(player) = players.get(&player_id) else {
+                continue;
+            };
+            let group = map_position_for_percentile(&player.position);
+            if position.is_some_and(|p| p != group) {
+                continue;
+            }
+
+            player_results.sort_by_key(|r| (r.source != CombineSource::Combine, -r.year));
+            for measurement in MEASUREMENTS {
+                let value = player_results
+                    .iter()
+                    .find_map(|r| Self::measurement_value(player, r, &measurement));
+                if let Some(value) = value {
+                    samples
+                        .entry((group.clone(), measurement.to_string()))
+                        .or_insert_with(|| (measurement.clone(), Vec::new()))
+                        .1
+                        .push(value);
+                }
+            }
+        }
+
+        let mut baselines = PercentileBaselines {
+            percentiles: Vec::new(),
+            skipped: Vec::new(),
+        };
+        for ((group, _), (measurement, mut values)) in samples {
+            if values.len() < min_sample_size.max(1) {
+                baselines.skipped.push(SkippedBaseline {
+                    position: group,
+                    measurement,
+                    sample_size: values.len(),
+                });
+                continue;
+            }
+
+            values.sort_by(f64::total_cmp);
+            let at = |percentile: f64| Self::breakpoint(&values, percentile);
+            let percentile = CombinePercentile::new(group, measurement)?
+                .with_percentiles(
+                    values.len() as i32,
+                    values[0],
+                    at(10.0),
+                    at(20.0),
+                    at(30.0),
+                    at(40.0),
+                    at(50.0),
+                    at(60.0),
+                    at(70.0),
+                    at(80.0),
+                    at(90.0),
+                    values[values.len() - 1],
+                )?
+                .with_years(years_start, years_end)?;
+            baselines.percentiles.push(percentile);
+        }
+
+        Ok(baselines)
+    }
+
+    fn measurement_value(
+        player: &Player,
+        results: &CombineResults,
+        measurement: &Measurement,
+    ) -> Option<f64> {
+        match measurement {
+            Measurement::Height => player.height_inches.map(|v| v as f64),
+            Measurement::Weight => player.weight_pounds.map(|v| v as f64),
+            Measurement::FortyYardDash => results.forty_yard_dash,
+            Measurement::TenYardSplit => results.ten_yard_split,
+            Measurement::TwentyYardSplit => results.twenty_yard_split,
+            Measurement::BenchPress => results.bench_press.map(|v| v as f64),
+            Measurement::VerticalJump => results.vertical_jump,
+            Measurement::BroadJump => results.broad_jump.map(|v| v as f64),
+            Measurement::ThreeConeDrill => results.three_cone_drill,
+            Measurement::TwentyYardShuttle => results.twenty_yard_shuttle,
+            Measurement::ArmLength => results.arm_length,
+            Measurement::HandSize => results.hand_size,
+            Measurement::Wingspan => results.wingspan,
+        }
+    }
+
+    /// Linearly interpolated value at `percentile` of ascending `sorted`,
+    /// rounded to two decimals
+    fn breakpoint(sorted: &[f64], percentile: f64) -> f64 {
+        let rank = percentile / 100.0 * (sorted.len() - 1) as f64;
+        let low = rank.floor() as usize;
+        let high = rank.ceil() as usize;
+        let value = sorted[low] + (sorted[high] - sorted[low]) * (rank - low as f64);
+        (value * 100.0).round() / 100.0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::Position;
+
+    fn wr_with_forty(forty: f64, year: i32) -> (Player, CombineResults) {
+        let player = Player::new("Test".to_string(), "Player".to_string(), Position::WR, year)
+            .unwrap()
+            .with_physical_stats(72, 190)
+            .unwrap();
+        let results = CombineResults::new(player.id, year)
+            .unwrap()
+            .with_forty_yard_dash(forty)
+            .unwrap();
+        (player, results)
+    }
+
+    #[test]
+    fn test_breakpoint_interpolates() {
+        let values = [1.0, 2.0, 3.0, 4.0, 5.0];
+        assert_eq!(PercentileBaselineService::breakpoint(&values, 50.0), 3.0);
+        assert_eq!(PercentileBaselineService::breakpoint(&values, 10.0), 1.4);
+        assert_eq!(PercentileBaselineService::breakpoint(&[7.0], 90.0), 7.0);
+    }
+
+    #[test]
+    fn test_compute_by_position_and_year_range() {
+        let (players, results): (Vec<_>, Vec<_>) = [
+            (4.30, 2025),
+            (4.40, 2025),
+            (4.50, 2026),
+            (4.60, 2026),
+            (4.70, 2026),
+            (4.20, 2019),
+        ]
+        .into_iter()
+        .map(|(forty, year)| wr_with_forty(forty, year))
+        .unzip();
+
+        let baselines =
+            PercentileBaselineService::compute(&players, &results, None, 2020, 2026, 3).unwrap();
+
+        let forty = baselines
+            .percentiles
+            .iter()
+            .find(|p| p.measurement == Measurement::FortyYardDash)
+            .unwrap();
+        assert_eq!(forty.position, "WR");
+        assert_eq!(forty.sample_size, 5);
+        assert_eq!(forty.min_value, 4.3);
+        assert_eq!(forty.p50, 4.5);
+        assert_eq!(forty.max_value, 4.7);
+        assert_eq!((forty.years_start, forty.years_end), (2020, 2026));
+
+        // Height comes from the player record
+        assert!(baselines
+            .percentiles
+            .iter()
+            .any(|p| p.measurement == Measurement::Height && p.sample_size == 5));
+
+        let only_qbs =
+            PercentileBaselineService::compute(&players, &results, Some("QB"), 2020, 2026, 3)
+                .unwrap();
+        assert!(only_qbs.percentiles.is_empty());
+
+        let too_few =
+            PercentileBaselineService::compute(&players, &results, None, 2020, 2026, 6).unwrap();
+        assert!(too_few.percentiles.is_empty());
+        assert_eq!(too_few.skipped.len(), 3);
+
+        assert!(
+            PercentileBaselineService::compute(&players, &results, None, 2026, 2020, 3).is_err()
+        );
+    }
+
+    #[test]
+    fn test_compute_prefers_combine_over_pro_day() {
+        let (player, combine) = wr_with_forty(4.5, 2026);
+        let pro_day = CombineResults::new(player.id, 2026)
+            .unwrap()
+            .with_source(CombineSource::ProDay)
+            .with_forty_yard_dash(4.3)
+            .unwrap();
+
+        let baselines =
+            PercentileBaselineService::compute(&[player], &[pro_day, combine], None, 2026, 2026, 1)
+                .unwrap();
+        let forty = baselines
+            .percentiles
+            .iter()
+            .find(|p| p.measurement == Measurement::FortyYardDash)
+            .unwrap();
+        assert_eq!(forty.sample_size, 1);
+        assert_eq!(forty.p50, 4.5);
+    }
+}
@@ -1,8 +1,9 @@
+use std::collections::HashMap;
 use std::sync::Arc;
 use uuid::Uuid;
 
 use crate::errors::{DomainError, DomainResult};
-use crate::models::{CombineResults, Player, Position, ScoutingReport};
+use crate::models::{CombineResults, Player, Position, ScoutingReport, SourcePrecedence};
 use crate::repositories::{CombineResultsRepository, ScoutingReportRepository};
 use crate::services::RasScoringService;
 
@@ -50,9 +51,24 @@ impl PlayerEvaluationService {
                 ))
             })?;
 
-        // Get combine results (get first one if multiple)
         let combine_results_list = self.combine_repo.find_by_player_id(player.id).await?;
-        let combine_results = combine_results_list.first();
+
+        Ok(self
+            .legacy_bpa_score(player, &scouting_report, &combine_results_list)
+            .await)
+    }
+
+    /// The legacy BPA formula over a player's scouting report and testing
+    /// results, already fetched
+    async fn legacy_bpa_score(
+        &self,
+        player: &Player,
+        scouting_report: &ScoutingReport,
+        combine_results_list: &[CombineResults],
+    ) -> f64 {
+        // Merge combine, pro day and school results into one set
+        let combine_results = self.select_combine_results(combine_results_list);
+        let combine_results = combine_results.as_ref();
 
         // Calculate combine component: prefer RAS if available, fall back to hardcoded normalization
         let combine_score = match (&self.ras_service, combine_results) {
@@ -79,12 +95,21 @@ impl PlayerEvaluationService {
 
         // Calculate components
         let scouting_component = Self::normalize_scouting_grade(scouting_report.grade) * 0.60;
-        let fit_component = Self::calculate_fit_score(&scouting_report) * 0.15;
-        let concern_penalty = Self::calculate_concern_penalty(&scouting_report);
+        let fit_component = Self::calculate_fit_score(scouting_report) * 0.15;
+        let concern_penalty = Self::calculate_concern_penalty(player, scouting_report);
 
         let bpa_score = scouting_component + combine_component + fit_component - concern_penalty;
 
-        Ok(bpa_score.clamp(0.0, 100.0))
+        bpa_score.clamp(0.0, 100.0)
+    }
+
+    /// Merge a player's testing events using the RAS service's source
+    /// precedence, or the default precedence without one
+    pub fn select_combine_results(&self, results: &[CombineResults]) -> Option<CombineResults> {
+        match &self.ras_service {
+            Some(ras) => ras.select_combine_results(results),
+            None => CombineResults::merge(results, &SourcePrecedence::default()),
+        }
     }
 
     /// Access the RAS service (for pre-fetching percentiles)
@@ -100,12 +125,24 @@ impl PlayerEvaluationService {
         self.scouting_repo.find_by_team_id(team_id).await
     }
 
-    /// Fetch combine results for a player (for pre-loading in batch operations)
-    pub async fn fetch_player_combine_results(
+    /// Fetch combine results for several players in one query (for pre-loading
+    /// in batch operations), grouped by player
+    pub async fn fetch_combine_results_for_players(
         &self,
-        player_id: Uuid,
-    ) -> DomainResult<Vec<CombineResults>> {
-        self.combine_repo.find_by_player_id(player_id).await
+        player_ids: &[Uuid],
+    ) -> DomainResult<HashMap<Uuid, Vec<CombineResults>>> {
+        let mut by_player: HashMap<Uuid, Vec<CombineResults>> = HashMap::new();
+        for results in self
+            .combine_repo
+            .find_combine_results_for_players(player_ids)
+            .await?
+        {
+            by_player
+                .entry(results.player_id)
+                .or_default()
+                .push(results);
+        }
+        Ok(by_player)
     }
 
     /// Calculate BPA score using pre-fetched data (avoids N+1 queries in batch scoring).
@@ -149,7 +186,7 @@ impl PlayerEvaluationService {
         // Consensus ranking: None → neutral 50.0 (no ranking data = no penalty or bonus)
         let ranking_component = consensus_ranking_score.unwrap_or(50.0) * 0.20;
         let fit_component = Self::calculate_fit_score(scouting_report) * 0.10;
-        let concern_penalty = Self::calculate_concern_penalty(scouting_report);
+        let concern_penalty = Self::calculate_concern_penalty(player, scouting_report);
 
         let bpa_score = scouting_component + combine_component + ranking_component + fit_component
             - concern_penalty;
@@ -157,22 +194,40 @@ impl PlayerEvaluationService {
         bpa_score.clamp(0.0, 100.0)
     }
 
-    /// Rank multiple players by BPA score (highest to lowest)
+    /// Rank multiple players by BPA score (highest to lowest). Scouting
+    /// reports and combine results for every player are fetched up front,
+    /// one query each.
     pub async fn rank_players_bpa(
         &self,
         players: &[Player],
         team_id: Uuid,
     ) -> DomainResult<Vec<(Player, f64)>> {
-        let mut scored_players = Vec::new();
+        if players.is_empty() {
+            return Ok(Vec::new());
+        }
 
+        let player_ids: Vec<Uuid> = players.iter().map(|p| p.id).collect();
+        let reports_by_player: HashMap<Uuid, ScoutingReport> = self
+            .scouting_repo
+            .find_reports_by_team_for_players(team_id, &player_ids)
+            .await?
+            .into_iter()
+            .map(|r| (r.player_id, r))
+            .collect();
+        let combine_by_player = self.fetch_combine_results_for_players(&player_ids).await?;
+
+        let mut scored_players = Vec::new();
         for player in players {
-            match self.calculate_bpa_score(player, team_id).await {
-                Ok(score) => scored_players.push((player.clone(), score)),
-                Err(_) => {
-                    // Skip players without scouting reports
-                    continue;
-                }
-            }
+            // Skip players without scouting reports
+            let Some(report) = reports_by_player.get(&player.id) else {
+                continue;
+            };
+            let combine_results = combine_by_player
+                .get(&player.id)
+                .map(Vec::as_slice)
+                .unwrap_or_default();
+            let score = self.legacy_bpa_score(player, report, combine_results).await;
+            scored_players.push((player.clone(), score));
         }
 
         // Sort by score descending
@@ -384,11 +439,16 @@ impl PlayerEvaluationService {
         }
     }
 
-    fn calculate_concern_penalty(scouting_report: &ScoutingReport) -> f64 {
-        let mut penalty = 0.0;
-        if scouting_report.injury_concern {
-            penalty += 5.0;
-        }
+    /// Character concerns cost a flat 5 points. Injury concerns cost 5 points
+    /// scaled by the player's injury severity; a scout's injury flag without
+    /// any recorded injury data counts as severity 1.
+    fn calculate_concern_penalty(player: &Player, scouting_report: &ScoutingReport) -> f64 {
+        let report_severity = if scouting_report.injury_concern {
+            1.0
+        } else {
+            0.0
+        };
+        let mut penalty = 5.0 * player.injury_severity().max(report_severity);
         if scouting_report.character_concern {
             penalty += 5.0;
         }
@@ -399,7 +459,7 @@ impl PlayerEvaluationService {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::models::FitGrade;
+    use crate::models::{FitGrade, InjuryStatus};
     use mockall::mock;
     use mockall::predicate::*;
 
@@ -412,9 +472,17 @@ mod tests {
             async fn find_by_id(&self, id: Uuid) -> DomainResult<Option<ScoutingReport>>;
             async fn find_by_player_id(&self, player_id: Uuid) -> DomainResult<Vec<ScoutingReport>>;
             async fn find_by_team_id(&self, team_id: Uuid) -> DomainResult<Vec<ScoutingReport>>;
+            async fn find_filtered(
+                &self,
+                filter: &crate::models::ScoutingReportFilter,
+                page: Option<crate::models::PageRequest>,
+            ) -> DomainResult<crate::models::Page<ScoutingReport>>;
             async fn find_by_team_and_player(&self, team_id: Uuid, player_id: Uuid) -> DomainResult<Option<ScoutingReport>>;
+            async fn find_reports_by_team_for_players(&self, team_id: Uuid, player_ids: &[Uuid]) -> DomainResult<Vec<ScoutingReport>>;
             async fn update(&self, report: &ScoutingReport) -> DomainResult<ScoutingReport>;
             async fn delete(&self, id: Uuid) -> DomainResult<()>;
+            async fn soft_delete(&self, id: Uuid) -> DomainResult<()>;
+            async fn restore(&self, id: Uuid) -> DomainResult<ScoutingReport>;
         }
     }
 
@@ -426,6 +494,7 @@ mod tests {
             async fn create(&self, results: &CombineResults) -> DomainResult<CombineResults>;
             async fn find_by_id(&self, id: Uuid) -> DomainResult<Option<CombineResults>>;
             async fn find_by_player_id(&self, player_id: Uuid) -> DomainResult<Vec<CombineResults>>;
+            async fn find_combine_results_for_players(&self, player_ids: &[Uuid]) -> DomainResult<Vec<CombineResults>>;
             async fn find_by_player_and_year(&self, player_id: Uuid, year: i32) -> DomainResult<Option<CombineResults>>;
             async fn find_by_player_year_source(&self, player_id: Uuid, year: i32, source: &str) -> DomainResult<Option<CombineResults>>;
             async fn update(&self, results: &CombineResults) -> DomainResult<CombineResults>;
@@ -527,6 +596,91 @@ mod tests {
         assert!(score < 100.0);
     }
 
+    #[tokio::test]
+    async fn test_rank_players_bpa_fetches_once_for_all_players() {
+        let mut scouting_mock = MockScoutingReportRepo::new();
+        let mut combine_mock = MockCombineResultsRepo::new();
+
+        let team_id = Uuid::new_v4();
+        let average = create_test_player(Position::WR);
+        let elite = create_test_player(Position::WR);
+        let unscouted = create_test_player(Position::WR);
+        let players = vec![average.clone(), elite.clone(), unscouted.clone()];
+
+        let reports = vec![
+            create_test_scouting_report(average.id, team_id, 6.0, None, false, false),
+            create_test_scouting_report(elite.id, team_id, 9.0, None, false, false),
+        ];
+        let combine = CombineResults::new(elite.id, 2026)
+            .unwrap()
+            .with_forty_yard_dash(4.35)
+            .unwrap();
+
+        let player_ids: Vec<Uuid> = players.iter().map(|p| p.id).collect();
+        let expected_ids = player_ids.clone();
+        scouting_mock
+            .expect_find_reports_by_team_for_players()
+            .withf(move |team, ids| *team == team_id && ids == expected_ids.as_slice())
+            .times(1)
+            .returning(move |_, _| Ok(reports.clone()));
+        combine_mock
+            .expect_find_combine_results_for_players()
+            .withf(move |ids| ids == player_ids.as_slice())
+            .times(1)
+            .returning(move |_| Ok(vec![combine.clone()]));
+
+        let service = PlayerEvaluationService::new(Arc::new(scouting_mock), Arc::new(combine_mock));
+
+        let ranked = service.rank_players_bpa(&players, team_id).await.unwrap();
+
+        // The player without a report is skipped
+        let ranked_ids: Vec<Uuid> = ranked.iter().map(|(p, _)| p.id).collect();
+        assert_eq!(ranked_ids, vec![elite.id, average.id]);
+        assert!(ranked[0].1 > ranked[1].1);
+    }
+
+    #[test]
+    fn test_injury_penalty_scales_with_severity() {
+        let team_id = Uuid::new_v4();
+        let healthy = create_test_player(Position::QB);
+        let clean_report =
+            create_test_scouting_report(healthy.id, team_id, 8.0, None, false, false);
+        let flagged_report =
+            create_test_scouting_report(healthy.id, team_id, 8.0, None, true, false);
+
+        assert_eq!(
+            PlayerEvaluationService::calculate_concern_penalty(&healthy, &clean_report),
+            0.0
+        );
+        // A scout's flag with no recorded injury keeps the flat 5 points
+        assert_eq!(
+            PlayerEvaluationService::calculate_concern_penalty(&healthy, &flagged_report),
+            5.0
+        );
+
+        let questionable = create_test_player(Position::QB)
+            .with_injury(InjuryStatus::Questionable, Some("ankle".to_string()), None)
+            .unwrap();
+        assert_eq!(
+            PlayerEvaluationService::calculate_concern_penalty(&questionable, &clean_report),
+            2.5
+        );
+        // The scout's flag is a floor, not an addition
+        assert_eq!(
+            PlayerEvaluationService::calculate_concern_penalty(&questionable, &flagged_report),
+            5.0
+        );
+
+        let long_term = create_test_player(Position::QB)
+            .with_injury(InjuryStatus::LongTerm, Some("ACL".to_string()), None)
+            .unwrap()
+            .with_medical_flag(true);
+        assert_eq!(
+            PlayerEvaluationService::calculate_concern_penalty(&long_term, &flagged_report),
+            15.0
+        );
+    }
+
     #[test]
     fn test_normalize_forty_dash() {
         assert!(PlayerEvaluationService::normalize_forty_dash(4.3) > 95.0);
@@ -0,0 +1,201 @@
+use std::collections::{BTreeMap, HashMap};
+
+use uuid::Uuid;
+
+use crate::models::{ConsensusRanking, DraftPick, Player, Position};
+
+/// Positions in the order scarcity is reported
+const POSITIONS: [Position; 14] = [
+    Position::QB,
+    Position::RB,
+    Position::WR,
+    Position::TE,
+    Position::OT,
+    Position::OG,
+    Position::C,
+    Position::DE,
+    Position::DT,
+    Position::LB,
+    Position::CB,
+    Position::S,
+    Position::K,
+    Position::P,
+];
+
+/// Players still on the board at one position, bucketed by the round their
+/// consensus rank puts them in
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PositionScarcity {
+    pub position: Position,
+    pub remaining: usize,
+    /// Remaining players with a round-N grade at index N - 1
+    pub by_round: Vec<usize>,
+    /// Ranked, but below the last round of the draft
+    pub undrafted_grade: usize,
+    /// Not on the consensus board at all
+    pub unranked: usize,
+    pub best_consensus_rank: Option<i64>,
+}
+
+/// Counts how much talent is left at each position so the UI and the trade
+/// logic can tell when a position is about to run dry
+pub struct PositionalScarcityService;
+
+impl PositionalScarcityService {
+    /// Last overall pick of each round, from the draft's picks. Falls back to
+    /// `picks_per_round` (or 32) per round before picks are initialized.
+    pub fn round_cutoffs(
+        picks: &[DraftPick],
+        rounds: i32,
+        picks_per_round: Option<i32>,
+    ) -> Vec<i64> {
+        if picks.is_empty() {
+            let per_round = picks_per_round.unwrap_or(32) as i64;
+            return (1..=rounds as i64).map(|round| round * per_round).collect();
+        }
+
+        let mut last_in_round: BTreeMap<i32, i64> = BTreeMap::new();
+        for pick in picks {
+            let last = last_in_round.entry(pick.round).or_default();
+            *last = (*last).max(pick.overall_pick as i64);
+        }
+        last_in_round.into_values().collect()
+    }
+
+    /// Scarcity for every position. `available` are the players not yet
+    /// drafted; `round_cutoffs` come from [`Self::round_cutoffs`].
+    pub fn compute(
+        available: &[Player],
+        consensus: &[ConsensusRanking],
+        round_cutoffs: &[i64],
+    ) -> Vec<PositionScarcity> {
+        let ranks: HashMap<Uuid, i64> = consensus
+            .iter()
+            .map(|c| (c.player_id, c.consensus_rank))
+            .collect();
+
+        let mut scarcity: Vec<PositionScarcity> = POSITIONS
+            .iter()
+            .map(|&position| PositionScarcity {
+                position,
+                remaining: 0,
+                by_round: vec![0; round_cutoffs.len()],
+                undrafted_grade: 0,
+                unranked: 0,
+                best_consensus_rank: None,
+            })
+            .collect();
+
+        for player in available {
+            let Some(entry) = scarcity.iter_mut().find(|s| s.position == player.position) else {
+                continue;
+            };
+            entry.remaining += 1;
+
+            let Some(&rank) = ranks.get(&player.id) else {
+                entry.unranked += 1;
+                continue;
+            };
+            entry.best_consensus_rank = Some(
+                entry
+                    .best_consensus_rank
+                    .map_or(rank, |best| best.min(rank)),
+            );
+            match round_cutoffs.iter().position(|&cutoff| rank <= cutoff) {
+                Some(round) => entry.by_round[round] += 1,
+                None => entry.undrafted_grade += 1,
+            }
+        }
+
+        scarcity
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn player(position: Position) -> Player {
+        Player::new("Test".to_string(), "Player".to_string(), position, 2026).unwrap()
+    }
+
+    fn ranked(player: &Player, consensus_rank: i64) -> ConsensusRanking {
+        ConsensusRanking {
+            consensus_rank,
+            player_id: player.id,
+            first_name: player.first_name.clone(),
+            last_name: player.last_name.clone(),
+            position: player.position,
+            source_count: 1,
+            average_rank: consensus_rank as f64,
+            best_rank: consensus_rank as i32,
+            worst_rank: consensus_rank as i32,
+            rank_std_dev: 0.0,
+        }
+    }
+
+    #[test]
+    fn test_round_cutoffs_from_picks_or_fallback() {
+        assert_eq!(
+            PositionalScarcityService::round_cutoffs(&[], 3, Some(2)),
+            vec![2, 4, 6]
+        );
+        assert_eq!(
+            PositionalScarcityService::round_cutoffs(&[], 2, None),
+            vec![32, 64]
+        );
+
+        // Uneven rounds, as in realistic drafts with compensatory picks
+        let draft_id = Uuid::new_v4();
+        let team_id = Uuid::new_v4();
+        let picks: Vec<DraftPick> = [(1, 1), (1, 2), (1, 3), (2, 4), (2, 5)]
+            .into_iter()
+            .map(|(round, overall)| {
+                DraftPick::new(draft_id, round, overall, overall, team_id).unwrap()
+            })
+            .collect();
+        assert_eq!(
+            PositionalScarcityService::round_cutoffs(&picks, 2, None),
+            vec![3, 5]
+        );
+    }
+
+    #[test]
+    fn test_compute_buckets_by_round_grade() {
+        let ot1 = player(Position::OT);
+        let ot2 = player(Position::OT);
+        let ot3 = player(Position::OT);
+        let ot4 = player(Position::OT);
+        let qb = player(Position::QB);
+        let consensus = vec![
+            ranked(&ot1, 1),
+            ranked(&ot2, 2),
+            ranked(&ot3, 4),
+            ranked(&qb, 9),
+        ];
+
+        let scarcity =
+            PositionalScarcityService::compute(&[ot1, ot2, ot3, ot4, qb], &consensus, &[2, 4]);
+
+        assert_eq!(scarcity.len(), POSITIONS.len());
+        let ot = scarcity
+            .iter()
+            .find(|s| s.position == Position::OT)
+            .unwrap();
+        assert_eq!(ot.remaining, 4);
+        assert_eq!(ot.by_round, vec![2, 1]);
+        assert_eq!(ot.unranked, 1);
+        assert_eq!(ot.best_consensus_rank, Some(1));
+
+        let qb = scarcity
+            .iter()
+            .find(|s| s.position == Position::QB)
+            .unwrap();
+        assert_eq!(qb.by_round, vec![0, 0]);
+        assert_eq!(qb.undrafted_grade, 1);
+
+        let k = scarcity.iter().find(|s| s.position == Position::K).unwrap();
+        assert_eq!(k.remaining, 0);
+        assert_eq!(k.best_consensus_rank, None);
+    }
+}
@@ -1,12 +1,16 @@
+use std::collections::HashMap;
 use std::sync::Arc;
 
+use uuid::Uuid;
+
 use crate::models::ras_score::{MeasurementScore, RasScore};
-use crate::models::{CombinePercentile, CombineResults, Player};
+use crate::models::{CombinePercentile, CombineResults, Player, SourcePrecedence};
 use crate::repositories::CombinePercentileRepository;
 
 /// Service for calculating RAS (Relative Athletic Score)
 pub struct RasScoringService {
     percentile_repo: Arc<dyn CombinePercentileRepository>,
+    source_precedence: SourcePrecedence,
 }
 
 /// Measurements that are lower-is-better (timed events)
@@ -27,7 +31,44 @@ const AGILITY_MEASUREMENTS: &[&str] = &["three_cone_drill", "twenty_yard_shuttle
 
 impl RasScoringService {
     pub fn new(percentile_repo: Arc<dyn CombinePercentileRepository>) -> Self {
-        Self { percentile_repo }
+        Self {
+            percentile_repo,
+            source_precedence: SourcePrecedence::default(),
+        }
+    }
+
+    /// Choose which testing source wins when a player has measurements from
+    /// more than one event
+    pub fn with_source_precedence(mut self, source_precedence: SourcePrecedence) -> Self {
+        self.source_precedence = source_precedence;
+        self
+    }
+
+    pub fn source_precedence(&self) -> &SourcePrecedence {
+        &self.source_precedence
+    }
+
+    /// Merge a player's testing events into the results RAS is scored from
+    pub fn select_combine_results(&self, results: &[CombineResults]) -> Option<CombineResults> {
+        CombineResults::merge(results, &self.source_precedence)
+    }
+
+    /// `select_combine_results` for results spanning many players
+    pub fn select_combine_results_by_player(
+        &self,
+        results: Vec<CombineResults>,
+    ) -> HashMap<Uuid, CombineResults> {
+        let mut by_player: HashMap<Uuid, Vec<CombineResults>> = HashMap::new();
+        for r in results {
+            by_player.entry(r.player_id).or_default().push(r);
+        }
+        by_player
+            .into_iter()
+            .filter_map(|(player_id, results)| {
+                self.select_combine_results(&results)
+                    .map(|merged| (player_id, merged))
+            })
+            .collect()
     }
 
     /// Calculate RAS score for a player given their combine results.
@@ -210,6 +251,40 @@ impl RasScoringService {
         }
     }
 
+    /// Positional percentile for every measurement the player has, frame
+    /// measurements (arm length, hand size, wingspan) included. Measurements
+    /// without percentile data for the player's position group are skipped.
+    pub fn percentile_profile(
+        player: &Player,
+        combine_results: &CombineResults,
+        percentiles: &[CombinePercentile],
+    ) -> Vec<MeasurementScore> {
+        let position = map_position_for_percentile(&player.position);
+
+        let measurements: Vec<(&str, Option<f64>)> = vec![
+            ("height", player.height_inches.map(|v| v as f64)),
+            ("weight", player.weight_pounds.map(|v| v as f64)),
+            ("forty_yard_dash", combine_results.forty_yard_dash),
+            ("ten_yard_split", combine_results.ten_yard_split),
+            ("twenty_yard_split", combine_results.twenty_yard_split),
+            ("bench_press", combine_results.bench_press.map(|v| v as f64)),
+            ("vertical_jump", combine_results.vertical_jump),
+            ("broad_jump", combine_results.broad_jump.map(|v| v as f64)),
+            ("three_cone_drill", combine_results.three_cone_drill),
+            ("twenty_yard_shuttle", combine_results.twenty_yard_shuttle),
+            ("arm_length", combine_results.arm_length),
+            ("hand_size", combine_results.hand_size),
+            ("wingspan", combine_results.wingspan),
+        ];
+
+        measurements
+            .into_iter()
+            .filter_map(|(name, value)| {
+                score_measurement_from_cache(&position, name, value?, percentiles)
+            })
+            .collect()
+    }
+
     /// Map a Position enum to its percentile position group string
     pub fn map_position(position: &crate::models::Position) -> String {
         map_position_for_percentile(position)
@@ -628,6 +703,38 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_percentile_profile_includes_frame_measurements() {
+        let percentiles = vec![
+            make_percentile("CB", "forty_yard_dash", 4.35, 4.48, 4.62),
+            make_percentile("CB", "arm_length", 30.0, 31.5, 33.0),
+            make_percentile("WR", "vertical_jump", 28.0, 36.0, 41.0),
+        ];
+        let player = crate::models::Player::new(
+            "Travis".to_string(),
+            "Hunter".to_string(),
+            Position::CB,
+            2026,
+        )
+        .unwrap();
+        let combine = crate::models::CombineResults::new(player.id, 2026)
+            .unwrap()
+            .with_forty_yard_dash(4.48)
+            .unwrap()
+            .with_arm_length(33.0)
+            .unwrap()
+            .with_vertical_jump(40.0)
+            .unwrap();
+
+        let profile = RasScoringService::percentile_profile(&player, &combine, &percentiles);
+
+        // The vertical jump has WR data only, so it is left out for a CB
+        let measurements: Vec<&str> = profile.iter().map(|s| s.measurement.as_str()).collect();
+        assert_eq!(measurements, vec!["forty_yard_dash", "arm_length"]);
+        assert!((profile[0].percentile - 50.0).abs() < 1e-9);
+        assert!((profile[1].percentile - 90.0).abs() < 1e-9);
+    }
+
     // --- Async unit tests for RasScoringService.calculate_ras (Fix M6) ---
 
     use crate::errors::DomainResult;
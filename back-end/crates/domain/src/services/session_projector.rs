@@ -0,0 +1,451 @@
+use std::collections::{BTreeMap, HashMap};
+
+use serde_json::Value as JsonValue;
+use uuid::Uuid;
+
+use crate::errors::{DomainError, DomainResult};
+use crate::models::{DraftEvent, DraftPick, DraftSession, EventType, SessionStatus};
+
+/// A pick as seen by the event stream. Only picks that some event touched
+/// appear in a projection; `team_id` is set once a trade moves the pick.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ProjectedPick {
+    pub pick_id: Uuid,
+    pub overall_pick: Option<i32>,
+    pub team_id: Option<Uuid>,
+    pub player_id: Option<Uuid>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct ExecutedTrade {
+    from_team_id: Uuid,
+    to_team_id: Uuid,
+    from_team_picks: Vec<Uuid>,
+    to_team_picks: Vec<Uuid>,
+}
+
+/// Session and pick state rebuilt purely from `draft_events`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SessionProjection {
+    pub session_id: Uuid,
+    pub draft_id: Option<Uuid>,
+    pub status: SessionStatus,
+    pub current_pick_number: i32,
+    pub picks: BTreeMap<Uuid, ProjectedPick>,
+    pub events_applied: usize,
+    pub last_event_id: Option<Uuid>,
+    trades: HashMap<Uuid, ExecutedTrade>,
+}
+
+impl SessionProjection {
+    fn new(session_id: Uuid) -> Self {
+        Self {
+            session_id,
+            draft_id: None,
+            status: SessionStatus::NotStarted,
+            current_pick_number: 1,
+            picks: BTreeMap::new(),
+            events_applied: 0,
+            last_event_id: None,
+            trades: HashMap::new(),
+        }
+    }
+
+    pub fn picks_made(&self) -> usize {
+        self.picks
+            .values()
+            .filter(|p| p.player_id.is_some())
+            .count()
+    }
+
+    fn pick_mut(&mut self, pick_id: Uuid) -> &mut ProjectedPick {
+        self.picks.entry(pick_id).or_insert(ProjectedPick {
+            pick_id,
+            overall_pick: None,
+            team_id: None,
+            player_id: None,
+        })
+    }
+
+    fn assign_picks(&mut self, pick_ids: &[Uuid], team_id: Uuid) {
+        for pick_id in pick_ids {
+            self.pick_mut(*pick_id).team_id = Some(team_id);
+        }
+    }
+}
+
+/// A single difference between the projection and the stored tables
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ProjectionMismatch {
+    pub field: String,
+    pub stored: String,
+    pub projected: String,
+}
+
+impl ProjectionMismatch {
+    fn new(field: impl Into<String>, stored: impl ToString, projected: impl ToString) -> Self {
+        Self {
+            field: field.into(),
+            stored: stored.to_string(),
+            projected: projected.to_string(),
+        }
+    }
+}
+
+/// Folds a session's event stream into session and pick state, and compares
+/// that state against what the tables currently hold.
+pub struct SessionProjector;
+
+impl SessionProjector {
+    /// Replay `events` (oldest first) into a fresh projection
+    pub fn project(session_id: Uuid, events: &[DraftEvent]) -> DomainResult<SessionProjection> {
+        let mut projection = SessionProjection::new(session_id);
+        for event in events {
+            Self::apply(&mut projection, event)?;
+        }
+        Ok(projection)
+    }
+
+    /// Apply a single event to an existing projection
+    pub fn apply(projection: &mut SessionProjection, event: &DraftEvent) -> DomainResult<()> {
+        if event.session_id != projection.session_id {
+            return Err(DomainError::ValidationError(format!(
+                "Event {} belongs to session {}, not {}",
+                event.id, event.session_id, projection.session_id
+            )));
+        }
+
+        match event.event_type {
+            EventType::SessionCreated => {
+                projection.draft_id = Some(uuid_field(event, "draft_id")?);
+                projection.status = SessionStatus::NotStarted;
+                projection.current_pick_number = 1;
+            }
+            EventType::SessionStarted | EventType::SessionResumed => {
+                projection.status = SessionStatus::InProgress;
+            }
+            EventType::SessionPaused => {
+                projection.status = SessionStatus::Paused;
+            }
+            EventType::SessionCompleted => {
+                projection.status = SessionStatus::Completed;
+            }
+            EventType::PickMade => {
+                let pick_id = uuid_field(event, "pick_id")?;
+                let team_id = uuid_field(event, "team_id")?;
+                let player_id = uuid_field(event, "player_id")?;
+                // Events written before overall_pick was recorded cannot move the clock
+                let overall_pick = event.event_data["overall_pick"].as_i64().map(|n| n as i32);
+
+                let pick = projection.pick_mut(pick_id);
+                pick.team_id = Some(team_id);
+                pick.player_id = Some(player_id);
+                if let Some(overall_pick) = overall_pick {
+                    pick.overall_pick = Some(overall_pick);
+                    projection.current_pick_number = overall_pick + 1;
+                }
+            }
+            EventType::PickAdvanced => {
+                projection.current_pick_number = int_field(event, "to_pick_number")?;
+            }
+            EventType::TradeExecuted => {
+                let trade_id = uuid_field(event, "trade_id")?;
+                // Older events only carry the trade ID, so there is nothing to replay
+                if event.event_data.get("from_team_picks").is_some() {
+                    let trade = ExecutedTrade {
+                        from_team_id: uuid_field(event, "from_team_id")?,
+                        to_team_id: uuid_field(event, "to_team_id")?,
+                        from_team_picks: uuid_list(event, "from_team_picks")?,
+                        to_team_picks: uuid_list(event, "to_team_picks")?,
+                    };
+                    projection.assign_picks(&trade.from_team_picks, trade.to_team_id);
+                    projection.assign_picks(&trade.to_team_picks, trade.from_team_id);
+                    projection.trades.insert(trade_id, trade);
+                }
+            }
+            EventType::SessionRewound => {
+                let to_pick_number = int_field(event, "to_pick_number")?;
+                for pick_id in uuid_list(event, "cleared_pick_ids")? {
+                    projection.pick_mut(pick_id).player_id = None;
+                }
+                for trade_id in uuid_list(event, "reversed_trade_ids")? {
+                    if let Some(trade) = projection.trades.remove(&trade_id) {
+                        projection.assign_picks(&trade.from_team_picks, trade.from_team_id);
+                        projection.assign_picks(&trade.to_team_picks, trade.to_team_id);
+                    }
+                }
+                projection.current_pick_number = to_pick_number;
+                projection.status = SessionStatus::Paused;
+            }
+            EventType::ClockUpdate
+            | EventType::TradeProposed
+            | EventType::TradeRejected
+            | EventType::DraftCompleted
+            | EventType::SettingsChanged => {}
+        }
+
+        projection.events_applied += 1;
+        projection.last_event_id = Some(event.id);
+        Ok(())
+    }
+
+    /// Compare a projection against the stored session and its draft's picks.
+    /// Every stored selection must be explained by the events, and every pick
+    /// the events touched must agree with the table.
+    pub fn verify(
+        projection: &SessionProjection,
+        session: &DraftSession,
+        picks: &[DraftPick],
+    ) -> Vec<ProjectionMismatch> {
+        let mut mismatches = Vec::new();
+
+        if let Some(draft_id) = projection.draft_id {
+            if draft_id != session.draft_id {
+                mismatches.push(ProjectionMismatch::new(
+                    "draft_id",
+                    session.draft_id,
+                    draft_id,
+                ));
+            }
+        }
+        if projection.status != session.status {
+            mismatches.push(ProjectionMismatch::new(
+                "status",
+                &session.status,
+                &projection.status,
+            ));
+        }
+        if projection.current_pick_number != session.current_pick_number {
+            mismatches.push(ProjectionMismatch::new(
+                "current_pick_number",
+                session.current_pick_number,
+                projection.current_pick_number,
+            ));
+        }
+
+        for pick in picks {
+            let projected = projection.picks.get(&pick.id);
+            let projected_player = projected.and_then(|p| p.player_id);
+            if projected_player != pick.player_id {
+                mismatches.push(ProjectionMismatch::new(
+                    format!("pick {} player_id", pick.overall_pick),
+                    display_option(pick.player_id),
+                    display_option(projected_player),
+                ));
+            }
+            if let Some(team_id) = projected.and_then(|p| p.team_id) {
+                if team_id != pick.team_id {
+                    mismatches.push(ProjectionMismatch::new(
+                        format!("pick {} team_id", pick.overall_pick),
+                        pick.team_id,
+                        team_id,
+                    ));
+                }
+            }
+        }
+
+        mismatches
+    }
+}
+
+fn field<'a>(event: &'a DraftEvent, key: &str) -> DomainResult<&'a JsonValue> {
+    event.event_data.get(key).ok_or_else(|| {
+        DomainError::ValidationError(format!(
+            "{} event {} is missing '{}'",
+            event.event_type, event.id, key
+        ))
+    })
+}
+
+fn uuid_field(event: &DraftEvent, key: &str) -> DomainResult<Uuid> {
+    field(event, key)?
+        .as_str()
+        .and_then(|s| Uuid::parse_str(s).ok())
+        .ok_or_else(|| {
+            DomainError::ValidationError(format!(
+                "{} event {} has an invalid '{}'",
+                event.event_type, event.id, key
+            ))
+        })
+}
+
+fn int_field(event: &DraftEvent, key: &str) -> DomainResult<i32> {
+    field(event, key)?
+        .as_i64()
+        .map(|n| n as i32)
+        .ok_or_else(|| {
+            DomainError::ValidationError(format!(
+                "{} event {} has an invalid '{}'",
+                event.event_type, event.id, key
+            ))
+        })
+}
+
+fn uuid_list(event: &DraftEvent, key: &str) -> DomainResult<Vec<Uuid>> {
+    let invalid = || {
+        DomainError::ValidationError(format!(
+            "{} event {} has an invalid '{}'",
+            event.event_type, event.id, key
+        ))
+    };
+    field(event, key)?
+        .as_array()
+        .ok_or_else(invalid)?
+        .iter()
+        .map(|v| {
+            v.as_str()
+                .and_then(|s| Uuid::parse_str(s).ok())
+                .ok_or_else(invalid)
+        })
+        .collect()
+}
+
+fn display_option(id: Option<Uuid>) -> String {
+    id.map(|id| id.to_string())
+        .unwrap_or_else(|| "none".to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn made_pick(draft_id: Uuid, overall_pick: i32, team_id: Uuid, player_id: Uuid) -> DraftPick {
+        let mut pick = DraftPick::new(draft_id, 1, overall_pick, overall_pick, team_id).unwrap();
+        pick.make_pick(player_id).unwrap();
+        pick
+    }
+
+    #[test]
+    fn test_project_lifecycle_and_picks() {
+        let session_id = Uuid::new_v4();
+        let draft_id = Uuid::new_v4();
+        let pick_id = Uuid::new_v4();
+        let team_id = Uuid::new_v4();
+        let player_id = Uuid::new_v4();
+
+        let events = vec![
+            DraftEvent::session_created(session_id, draft_id, serde_json::json!({})),
+            DraftEvent::session_started(session_id),
+            DraftEvent::pick_made(session_id, pick_id, team_id, player_id, 1, 1, 1),
+            DraftEvent::clock_update(session_id, 90),
+            DraftEvent::session_paused(session_id),
+        ];
+
+        let projection = SessionProjector::project(session_id, &events).unwrap();
+
+        assert_eq!(projection.draft_id, Some(draft_id));
+        assert_eq!(projection.status, SessionStatus::Paused);
+        assert_eq!(projection.current_pick_number, 2);
+        assert_eq!(projection.picks_made(), 1);
+        assert_eq!(projection.picks[&pick_id].player_id, Some(player_id));
+        assert_eq!(projection.events_applied, 5);
+        assert_eq!(projection.last_event_id, Some(events[4].id));
+    }
+
+    #[test]
+    fn test_project_trade_and_rewind() {
+        let session_id = Uuid::new_v4();
+        let draft_id = Uuid::new_v4();
+        let team_a = Uuid::new_v4();
+        let team_b = Uuid::new_v4();
+        let pick_1 = Uuid::new_v4();
+        let pick_2 = Uuid::new_v4();
+        let trade_id = Uuid::new_v4();
+        let player_id = Uuid::new_v4();
+
+        let events = vec![
+            DraftEvent::session_created(session_id, draft_id, serde_json::json!({})),
+            DraftEvent::session_started(session_id),
+            DraftEvent::trade_executed(session_id, trade_id, team_a, team_b, &[pick_1], &[pick_2]),
+            DraftEvent::pick_made(session_id, pick_1, team_b, player_id, 1, 1, 1),
+        ];
+        let projection = SessionProjector::project(session_id, &events).unwrap();
+        assert_eq!(projection.picks[&pick_1].team_id, Some(team_b));
+        assert_eq!(projection.picks[&pick_2].team_id, Some(team_a));
+
+        let mut events = events;
+        events.push(DraftEvent::session_rewound(
+            session_id,
+            2,
+            1,
+            &[pick_1],
+            &[trade_id],
+        ));
+        let projection = SessionProjector::project(session_id, &events).unwrap();
+
+        assert_eq!(projection.status, SessionStatus::Paused);
+        assert_eq!(projection.current_pick_number, 1);
+        assert_eq!(projection.picks_made(), 0);
+        assert_eq!(projection.picks[&pick_1].team_id, Some(team_a));
+        assert_eq!(projection.picks[&pick_2].team_id, Some(team_b));
+    }
+
+    #[test]
+    fn test_project_pick_advanced_moves_clock() {
+        let session_id = Uuid::new_v4();
+        let events = vec![
+            DraftEvent::session_created(session_id, Uuid::new_v4(), serde_json::json!({})),
+            DraftEvent::session_started(session_id),
+            DraftEvent::pick_advanced(session_id, 1, 2),
+        ];
+
+        let projection = SessionProjector::project(session_id, &events).unwrap();
+
+        assert_eq!(projection.current_pick_number, 2);
+        assert_eq!(projection.picks_made(), 0);
+    }
+
+    #[test]
+    fn test_project_rejects_foreign_and_malformed_events() {
+        let session_id = Uuid::new_v4();
+
+        let foreign = DraftEvent::session_started(Uuid::new_v4());
+        assert!(SessionProjector::project(session_id, &[foreign]).is_err());
+
+        let malformed = DraftEvent::new(session_id, EventType::PickMade, serde_json::json!({}));
+        assert!(SessionProjector::project(session_id, &[malformed]).is_err());
+    }
+
+    #[test]
+    fn test_verify_matching_state() {
+        let draft_id = Uuid::new_v4();
+        let team_id = Uuid::new_v4();
+        let player_id = Uuid::new_v4();
+        let mut session = DraftSession::new_with_default_chart(draft_id, 300, false).unwrap();
+        session.start().unwrap();
+        session.advance_pick().unwrap();
+
+        let pick = made_pick(draft_id, 1, team_id, player_id);
+        let events = vec![
+            DraftEvent::session_created(session.id, draft_id, serde_json::json!({})),
+            DraftEvent::session_started(session.id),
+            DraftEvent::pick_made(session.id, pick.id, team_id, player_id, 1, 1, 1),
+        ];
+        let projection = SessionProjector::project(session.id, &events).unwrap();
+
+        assert!(SessionProjector::verify(&projection, &session, &[pick]).is_empty());
+    }
+
+    #[test]
+    fn test_verify_reports_unexplained_pick() {
+        let draft_id = Uuid::new_v4();
+        let team_id = Uuid::new_v4();
+        let player_id = Uuid::new_v4();
+        let mut session = DraftSession::new_with_default_chart(draft_id, 300, false).unwrap();
+        session.start().unwrap();
+
+        // The pick exists in the table but no PickMade event was ever recorded
+        let pick = made_pick(draft_id, 1, team_id, player_id);
+        let events = vec![
+            DraftEvent::session_created(session.id, draft_id, serde_json::json!({})),
+            DraftEvent::session_started(session.id),
+        ];
+        let projection = SessionProjector::project(session.id, &events).unwrap();
+
+        let mismatches = SessionProjector::verify(&projection, &session, &[pick]);
+        assert_eq!(mismatches.len(), 1);
+        assert_eq!(mismatches[0].field, "pick 1 player_id");
+        assert_eq!(mismatches[0].stored, player_id.to_string());
+        assert_eq!(mismatches[0].projected, "none");
+    }
+}
@@ -0,0 +1,333 @@
+//! # Draft Pick Surplus Value
+//!
+//! Surplus value is what a drafted player produces on a rookie deal minus
+//! what that deal costs. The trade-side `SurplusValueChart` prices a slot
+//! before the pick; this module measures what each team actually captured
+//! once the picks are in.
+//!
+//! Both sides are in millions of dollars over the four years of a rookie
+//! contract:
+//!
+//! - **Cost** comes from the rookie wage scale, which fixes a contract's
+//!   value by overall pick.
+//! - **Production** comes from a performance chart (PFF WAR by default),
+//!   priced so the expected output of the first overall pick is worth
+//!   `MARKET_VALUE_OF_FIRST_PICK` on the open market. A player on the
+//!   consensus board is valued at their rank rather than the slot, so a
+//!   steal captures more surplus than the slot and a reach less.
+
+use std::collections::HashMap;
+
+use uuid::Uuid;
+
+use crate::errors::{DomainError, DomainResult};
+use crate::models::{ChartType, ConsensusRanking, DraftPick};
+use crate::services::TradeValueChart;
+
+/// Open-market price, in millions over four years, of the production
+/// expected from the first overall pick: about $15M a season, the going
+/// rate for a solid veteran starter
+pub const MARKET_VALUE_OF_FIRST_PICK: f64 = 60.0;
+
+/// Four-year rookie contract value in millions at anchor picks, from the
+/// slotted rookie wage scale. Picks between anchors are interpolated.
+const WAGE_SCALE_ANCHORS: [(i32, f64); 11] = [
+    (1, 48.8),
+    (5, 39.0),
+    (10, 26.0),
+    (16, 20.0),
+    (32, 14.0),
+    (33, 10.5),
+    (64, 6.8),
+    (96, 5.6),
+    (128, 4.9),
+    (160, 4.6),
+    (224, 4.3),
+];
+
+/// The slotted cost of a rookie contract by overall pick
+pub struct RookieWageScale;
+
+impl RookieWageScale {
+    /// Four-year contract value in millions. Compensatory picks past the
+    /// last anchor are paid like the end of the seventh round.
+    pub fn four_year_cost(overall_pick: i32) -> DomainResult<f64> {
+        if overall_pick < 1 {
+            return Err(DomainError::ValidationError(format!(
+                "Invalid pick number: {}",
+                overall_pick
+            )));
+        }
+
+        let mut previous = WAGE_SCALE_ANCHORS[0];
+        for anchor in WAGE_SCALE_ANCHORS {
+            if overall_pick <= anchor.0 {
+                if anchor.0 == previous.0 {
+                    return Ok(anchor.1);
+                }
+                let progress = (overall_pick - previous.0) as f64 / (anchor.0 - previous.0) as f64;
+                return Ok(previous.1 + (anchor.1 - previous.1) * progress);
+            }
+            previous = anchor;
+        }
+        Ok(previous.1)
+    }
+}
+
+/// One made pick with what the player is expected to produce and cost
+#[derive(Debug, Clone, PartialEq)]
+pub struct PickSurplus {
+    pub pick_id: Uuid,
+    pub round: i32,
+    pub overall_pick: i32,
+    pub team_id: Uuid,
+    pub player_id: Uuid,
+    pub consensus_rank: Option<i64>,
+    /// Rookie contract value for the slot, in millions
+    pub rookie_cost: f64,
+    /// Expected production of a typical player taken at the slot, in millions
+    pub slot_value: f64,
+    /// Expected production of this player, in millions; the slot value when
+    /// the player is not on the consensus board
+    pub player_value: f64,
+    /// `player_value` minus `rookie_cost`
+    pub surplus: f64,
+    /// The trade chart's surplus value points for the slot
+    pub surplus_chart_value: i32,
+}
+
+/// One team's class summed up
+#[derive(Debug, Clone, PartialEq)]
+pub struct TeamSurplus {
+    pub team_id: Uuid,
+    pub picks: usize,
+    pub total_rookie_cost: f64,
+    pub total_player_value: f64,
+    pub total_surplus: f64,
+    pub total_surplus_chart_value: i32,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct SurplusReport {
+    /// Every made pick, in draft order
+    pub picks: Vec<PickSurplus>,
+    /// Teams by total surplus, best first
+    pub teams: Vec<TeamSurplus>,
+}
+
+/// Prices made picks with the rookie wage scale and a performance chart
+pub struct SurplusValueService {
+    performance_chart: Box<dyn TradeValueChart>,
+    surplus_chart: Box<dyn TradeValueChart>,
+}
+
+impl SurplusValueService {
+    /// `performance_chart` turns a pick into expected production. The
+    /// surplus value chart is rejected since it already nets out cost.
+    pub fn new(performance_chart: ChartType) -> DomainResult<Self> {
+        if performance_chart == ChartType::SurplusValue {
+            return Err(DomainError::ValidationError(
+                "SurplusValue already subtracts contract cost; choose a performance chart"
+                    .to_string(),
+            ));
+        }
+        Ok(Self {
+            performance_chart: performance_chart.create_chart(),
+            surplus_chart: ChartType::SurplusValue.create_chart(),
+        })
+    }
+
+    /// Expected production of the player taken at a slot, in millions
+    pub fn performance_value(&self, overall_pick: i32) -> DomainResult<f64> {
+        let top = self.performance_chart.calculate_pick_value(1)? as f64;
+        let value = self.performance_chart.calculate_pick_value(overall_pick)? as f64;
+        Ok(value / top * MARKET_VALUE_OF_FIRST_PICK)
+    }
+
+    pub fn report(
+        &self,
+        picks: &[DraftPick],
+        consensus: &[ConsensusRanking],
+    ) -> DomainResult<SurplusReport> {
+        let ranks: HashMap<Uuid, i64> = consensus
+            .iter()
+            .map(|c| (c.player_id, c.consensus_rank))
+            .collect();
+
+        let mut values = Vec::new();
+        for pick in picks {
+            let Some(player_id) = pick.player_id else {
+                continue;
+            };
+            let consensus_rank = ranks.get(&player_id).copied();
+            let rookie_cost = RookieWageScale::four_year_cost(pick.overall_pick)?;
+            let slot_value = self.performance_value(pick.overall_pick)?;
+            let player_value = match consensus_rank {
+                Some(rank) => self.performance_value(rank.clamp(1, i32::MAX as i64) as i32)?,
+                None => slot_value,
+            };
+            values.push(PickSurplus {
+                pick_id: pick.id,
+                round: pick.round,
+                overall_pick: pick.overall_pick,
+                team_id: pick.team_id,
+                player_id,
+                consensus_rank,
+                rookie_cost: round_millions(rookie_cost),
+                slot_value: round_millions(slot_value),
+                player_value: round_millions(player_value),
+                surplus: round_millions(player_value - rookie_cost),
+                surplus_chart_value: self.surplus_chart.calculate_pick_value(pick.overall_pick)?,
+            });
+        }
+        values.sort_by_key(|v| v.overall_pick);
+
+        let mut totals: HashMap<Uuid, TeamSurplus> = HashMap::new();
+        for value in &values {
+            let team = totals.entry(value.team_id).or_insert_with(|| TeamSurplus {
+                team_id: value.team_id,
+                picks: 0,
+                total_rookie_cost: 0.0,
+                total_player_value: 0.0,
+                total_surplus: 0.0,
+                total_surplus_chart_value: 0,
+            });
+            team.picks += 1;
+            team.total_rookie_cost += value.rookie_cost;
+            team.total_player_value += value.player_value;
+            team.total_surplus += value.surplus;
+            team.total_surplus_chart_value += value.surplus_chart_value;
+        }
+        let mut teams: Vec<TeamSurplus> = totals
+            .into_values()
+            .map(|mut team| {
+                team.total_rookie_cost = round_millions(team.total_rookie_cost);
+                team.total_player_value = round_millions(team.total_player_value);
+                team.total_surplus = round_millions(team.total_surplus);
+                team
+            })
+            .collect();
+        teams.sort_by(|a, b| {
+            b.total_surplus
+                .total_cmp(&a.total_surplus)
+                .then_with(|| a.team_id.cmp(&b.team_id))
+        });
+
+        Ok(SurplusReport {
+            picks: values,
+            teams,
+        })
+    }
+}
+
+fn round_millions(value: f64) -> f64 {
+    (value * 100.0).round() / 100.0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::Position;
+
+    fn made_pick(overall_pick: i32, team_id: Uuid, player_id: Option<Uuid>) -> DraftPick {
+        let mut pick =
+            DraftPick::new(Uuid::new_v4(), 1, overall_pick, overall_pick, team_id).unwrap();
+        pick.player_id = player_id;
+        pick
+    }
+
+    fn ranked(player_id: Uuid, consensus_rank: i64) -> ConsensusRanking {
+        ConsensusRanking {
+            consensus_rank,
+            player_id,
+            first_name: "Test".to_string(),
+            last_name: "Player".to_string(),
+            position: Position::WR,
+            source_count: 1,
+            average_rank: consensus_rank as f64,
+            best_rank: consensus_rank as i32,
+            worst_rank: consensus_rank as i32,
+            rank_std_dev: 0.0,
+        }
+    }
+
+    #[test]
+    fn test_wage_scale_interpolates_and_declines() {
+        assert_eq!(RookieWageScale::four_year_cost(1).unwrap(), 48.8);
+        assert_eq!(RookieWageScale::four_year_cost(33).unwrap(), 10.5);
+        assert!((RookieWageScale::four_year_cost(3).unwrap() - 43.9).abs() < 1e-9);
+        assert_eq!(RookieWageScale::four_year_cost(250).unwrap(), 4.3);
+        assert!(RookieWageScale::four_year_cost(0).is_err());
+
+        for pick in 1..260 {
+            assert!(
+                RookieWageScale::four_year_cost(pick + 1).unwrap()
+                    <= RookieWageScale::four_year_cost(pick).unwrap()
+            );
+        }
+    }
+
+    #[test]
+    fn test_slot_surplus_beats_first_pick_later_in_round_one() {
+        let service = SurplusValueService::new(ChartType::PffWar).unwrap();
+        let surplus = |pick| {
+            service.performance_value(pick).unwrap()
+                - RookieWageScale::four_year_cost(pick).unwrap()
+        };
+
+        assert_eq!(
+            service.performance_value(1).unwrap(),
+            MARKET_VALUE_OF_FIRST_PICK
+        );
+        assert!(surplus(10) > surplus(1));
+        assert!(surplus(224) < 0.0);
+    }
+
+    #[test]
+    fn test_surplus_value_chart_rejected() {
+        assert!(SurplusValueService::new(ChartType::SurplusValue).is_err());
+    }
+
+    #[test]
+    fn test_report_values_players_at_consensus_rank() {
+        let team_a = Uuid::new_v4();
+        let team_b = Uuid::new_v4();
+        let steal = Uuid::new_v4();
+        let reach = Uuid::new_v4();
+        let unranked = Uuid::new_v4();
+
+        let picks = vec![
+            made_pick(2, team_b, Some(reach)),
+            made_pick(1, team_a, Some(steal)),
+            made_pick(3, team_a, Some(unranked)),
+            made_pick(4, team_b, None),
+        ];
+        let consensus = vec![ranked(steal, 1), ranked(reach, 40)];
+
+        let service = SurplusValueService::new(ChartType::PffWar).unwrap();
+        let report = service.report(&picks, &consensus).unwrap();
+
+        let slots: Vec<i32> = report.picks.iter().map(|p| p.overall_pick).collect();
+        assert_eq!(slots, vec![1, 2, 3]);
+
+        let reach_pick = &report.picks[1];
+        assert_eq!(reach_pick.consensus_rank, Some(40));
+        assert!(reach_pick.player_value < reach_pick.slot_value);
+        assert!(reach_pick.surplus < 0.0);
+
+        let unranked_pick = &report.picks[2];
+        assert_eq!(unranked_pick.player_value, unranked_pick.slot_value);
+        assert_eq!(
+            unranked_pick.surplus_chart_value,
+            ChartType::SurplusValue
+                .create_chart()
+                .calculate_pick_value(3)
+                .unwrap()
+        );
+
+        assert_eq!(report.teams.len(), 2);
+        assert_eq!(report.teams[0].team_id, team_a);
+        assert_eq!(report.teams[0].picks, 2);
+        assert_eq!(report.teams[1].team_id, team_b);
+    }
+}
@@ -2,6 +2,7 @@ use crate::errors::{DomainError, DomainResult};
 use crate::models::{ChartType, PickTrade, TradeProposal};
 use crate::repositories::{DraftPickRepository, TeamRepository, TradeRepository};
 use crate::services::trade_value::TradeValueChart;
+use std::future::Future;
 use std::sync::Arc;
 use uuid::Uuid;
 
@@ -48,6 +49,38 @@ impl TradeEngine {
         to_team_picks: Vec<Uuid>,
         chart_type: Option<ChartType>,
     ) -> DomainResult<TradeProposal> {
+        self.propose_trade_with(
+            session_id,
+            from_team_id,
+            to_team_id,
+            from_team_picks,
+            to_team_picks,
+            chart_type,
+            |proposal, chart_type| async move {
+                self.trade_repo.create_trade(&proposal, chart_type).await
+            },
+        )
+        .await
+    }
+
+    /// Validate a trade proposal and hand it, with the chart type its
+    /// values came from, to `save` to persist. The API uses this to store
+    /// the proposal together with its event in one transaction.
+    #[allow(clippy::too_many_arguments)]
+    pub async fn propose_trade_with<F, Fut, T>(
+        &self,
+        session_id: Uuid,
+        from_team_id: Uuid,
+        to_team_id: Uuid,
+        from_team_picks: Vec<Uuid>,
+        to_team_picks: Vec<Uuid>,
+        chart_type: Option<ChartType>,
+        save: F,
+    ) -> DomainResult<T>
+    where
+        F: FnOnce(TradeProposal, ChartType) -> Fut,
+        Fut: Future<Output = DomainResult<T>>,
+    {
         // Validate teams exist
         self.validate_team_exists(from_team_id).await?;
         self.validate_team_exists(to_team_id).await?;
@@ -80,7 +113,7 @@ impl TradeEngine {
             to_team_value,
             self.fairness_threshold_percent,
         ) {
-            return Err(DomainError::ValidationError(format!(
+            return Err(DomainError::TradeUnfair(format!(
                 "Trade is not fair using {} chart: {} points vs {} points (threshold: {}%)",
                 value_chart.name(),
                 from_team_value,
@@ -100,8 +133,8 @@ impl TradeEngine {
             to_team_value,
         )?;
 
-        // Save to database, passing the chart type used for value calculation
-        self.trade_repo.create_trade(&proposal, chart_type).await
+        // Save, passing the chart type used for value calculation
+        save(proposal, chart_type).await
     }
 
     /// Accept trade and auto-execute (transfer picks)
@@ -110,6 +143,33 @@ impl TradeEngine {
         trade_id: Uuid,
         accepting_team_id: Uuid,
     ) -> DomainResult<PickTrade> {
+        self.accept_trade_with(trade_id, accepting_team_id, |proposal| async move {
+            let trade = &proposal.trade;
+            self.trade_repo
+                .transfer_picks(
+                    trade.from_team_id,
+                    trade.to_team_id,
+                    &proposal.from_team_picks,
+                    &proposal.to_team_picks,
+                )
+                .await?;
+            self.trade_repo.update(trade).await
+        })
+        .await
+    }
+
+    /// Accept a trade and hand the accepted proposal to `save`, which must
+    /// transfer its picks and store its new status
+    pub async fn accept_trade_with<F, Fut, T>(
+        &self,
+        trade_id: Uuid,
+        accepting_team_id: Uuid,
+        save: F,
+    ) -> DomainResult<T>
+    where
+        F: FnOnce(TradeProposal) -> Fut,
+        Fut: Future<Output = DomainResult<T>>,
+    {
         // Get trade with details
         let mut trade_proposal = self
             .trade_repo
@@ -137,18 +197,8 @@ impl TradeEngine {
         )
         .await?;
 
-        // Execute trade (atomic pick transfer)
-        self.trade_repo
-            .transfer_picks(
-                trade_proposal.trade.from_team_id,
-                trade_proposal.trade.to_team_id,
-                &trade_proposal.from_team_picks,
-                &trade_proposal.to_team_picks,
-            )
-            .await?;
-
-        // Update trade status to Accepted
-        self.trade_repo.update(&trade_proposal.trade).await
+        // Execute trade: transfer the picks and save the Accepted status
+        save(trade_proposal).await
     }
 
     /// Reject a trade
@@ -157,6 +207,23 @@ impl TradeEngine {
         trade_id: Uuid,
         rejecting_team_id: Uuid,
     ) -> DomainResult<PickTrade> {
+        self.reject_trade_with(trade_id, rejecting_team_id, |trade| async move {
+            self.trade_repo.update(&trade).await
+        })
+        .await
+    }
+
+    /// Reject a trade and hand it to `save` to store its new status
+    pub async fn reject_trade_with<F, Fut, T>(
+        &self,
+        trade_id: Uuid,
+        rejecting_team_id: Uuid,
+        save: F,
+    ) -> DomainResult<T>
+    where
+        F: FnOnce(PickTrade) -> Fut,
+        Fut: Future<Output = DomainResult<T>>,
+    {
         let mut trade = self
             .trade_repo
             .find_by_id(trade_id)
@@ -170,7 +237,7 @@ impl TradeEngine {
         }
 
         trade.reject()?;
-        self.trade_repo.update(&trade).await
+        save(trade).await
     }
 
     /// Get pending trades for a team
@@ -295,7 +362,9 @@ impl TradeEngine {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::models::{Conference, Division, DraftPick, PickTrade, Team, TradeProposal};
+    use crate::models::{
+        Conference, Division, DraftEvent, DraftPick, PickTrade, Team, TeamDependents, TradeProposal,
+    };
     use mockall::mock;
     use mockall::predicate::*;
 
@@ -304,14 +373,17 @@ mod tests {
         #[async_trait::async_trait]
         impl TradeRepository for TradeRepo {
             async fn create_trade(&self, proposal: &TradeProposal, chart_type: ChartType) -> DomainResult<TradeProposal>;
+            async fn create_trade_with_event(&self, proposal: &TradeProposal, chart_type: ChartType, event: &DraftEvent) -> DomainResult<TradeProposal>;
             async fn find_by_id(&self, id: Uuid) -> DomainResult<Option<PickTrade>>;
             async fn find_trade_with_details(&self, id: Uuid) -> DomainResult<Option<TradeProposal>>;
             async fn find_by_session(&self, session_id: Uuid) -> DomainResult<Vec<PickTrade>>;
             async fn find_proposals_by_session(&self, session_id: Uuid) -> DomainResult<Vec<TradeProposal>>;
             async fn find_pending_for_team(&self, team_id: Uuid) -> DomainResult<Vec<TradeProposal>>;
             async fn update(&self, trade: &PickTrade) -> DomainResult<PickTrade>;
+            async fn update_with_event(&self, trade: &PickTrade, event: &DraftEvent) -> DomainResult<PickTrade>;
             async fn is_pick_in_active_trade(&self, pick_id: Uuid, exclude_trade_id: Option<Uuid>) -> DomainResult<bool>;
             async fn transfer_picks(&self, from_team_id: Uuid, to_team_id: Uuid, from_team_picks: &[Uuid], to_team_picks: &[Uuid]) -> DomainResult<()>;
+            async fn execute_trade(&self, proposal: &TradeProposal, event: &DraftEvent) -> DomainResult<PickTrade>;
         }
     }
 
@@ -343,6 +415,10 @@ mod tests {
             async fn find_all(&self) -> DomainResult<Vec<Team>>;
             async fn update(&self, team: &Team) -> DomainResult<Team>;
             async fn delete(&self, id: Uuid) -> DomainResult<()>;
+            async fn soft_delete(&self, id: Uuid) -> DomainResult<()>;
+            async fn restore(&self, id: Uuid) -> DomainResult<Team>;
+            async fn count_dependents(&self, id: Uuid) -> DomainResult<TeamDependents>;
+            async fn delete_with_dependents(&self, id: Uuid) -> DomainResult<()>;
         }
     }
 
@@ -722,8 +798,8 @@ mod tests {
 
         assert!(result.is_err());
         match result.unwrap_err() {
-            DomainError::ValidationError(msg) => assert!(msg.contains("not fair")),
-            e => panic!("Expected ValidationError, got {:?}", e),
+            DomainError::TradeUnfair(msg) => assert!(msg.contains("not fair")),
+            e => panic!("Expected TradeUnfair, got {:?}", e),
         }
     }
 
@@ -0,0 +1,206 @@
+use std::collections::HashMap;
+
+use uuid::Uuid;
+
+use crate::models::{ConsensusRanking, DraftPick};
+
+/// A made pick measured against the consensus board
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PickValue {
+    pub pick_id: Uuid,
+    pub round: i32,
+    pub overall_pick: i32,
+    pub team_id: Uuid,
+    pub player_id: Uuid,
+    pub consensus_rank: i64,
+    /// Positive when the player went later than the board had them (a
+    /// steal), negative when earlier (a reach)
+    pub value_over_slot: i64,
+}
+
+/// One team's picks summed up
+#[derive(Debug, Clone, PartialEq)]
+pub struct TeamValue {
+    pub team_id: Uuid,
+    pub picks_analyzed: usize,
+    pub total_value_over_slot: i64,
+    pub average_value_over_slot: f64,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ValueAnalysis {
+    /// Every made pick of a ranked player, in draft order
+    pub picks: Vec<PickValue>,
+    /// Made picks whose player is not on the consensus board
+    pub unranked_picks: usize,
+    /// Biggest steals first
+    pub steals: Vec<PickValue>,
+    /// Biggest reaches first
+    pub reaches: Vec<PickValue>,
+    /// Teams by total value over slot, best first
+    pub teams: Vec<TeamValue>,
+}
+
+/// Compares where players were drafted with where the consensus board
+/// ranked them
+pub struct ValueOverSlotService;
+
+impl ValueOverSlotService {
+    /// Slot minus consensus rank: taking the board's No. 3 player at pick 10
+    /// is worth +7
+    pub fn value_over_slot(overall_pick: i32, consensus_rank: i64) -> i64 {
+        overall_pick as i64 - consensus_rank
+    }
+
+    /// Analyze a draft's picks; `leaders` caps the steals and reaches lists
+    pub fn analyze(
+        picks: &[DraftPick],
+        consensus: &[ConsensusRanking],
+        leaders: usize,
+    ) -> ValueAnalysis {
+        let ranks: HashMap<Uuid, i64> = consensus
+            .iter()
+            .map(|c| (c.player_id, c.consensus_rank))
+            .collect();
+
+        let mut values = Vec::new();
+        let mut unranked_picks = 0;
+        for pick in picks {
+            let Some(player_id) = pick.player_id else {
+                continue;
+            };
+            let Some(&consensus_rank) = ranks.get(&player_id) else {
+                unranked_picks += 1;
+                continue;
+            };
+            values.push(PickValue {
+                pick_id: pick.id,
+                round: pick.round,
+                overall_pick: pick.overall_pick,
+                team_id: pick.team_id,
+                player_id,
+                consensus_rank,
+                value_over_slot: Self::value_over_slot(pick.overall_pick, consensus_rank),
+            });
+        }
+        values.sort_by_key(|v| v.overall_pick);
+
+        let mut steals: Vec<PickValue> = values
+            .iter()
+            .filter(|v| v.value_over_slot > 0)
+            .cloned()
+            .collect();
+        steals.sort_by_key(|v| (-v.value_over_slot, v.overall_pick));
+        steals.truncate(leaders);
+
+        let mut reaches: Vec<PickValue> = values
+            .iter()
+            .filter(|v| v.value_over_slot < 0)
+            .cloned()
+            .collect();
+        reaches.sort_by_key(|v| (v.value_over_slot, v.overall_pick));
+        reaches.truncate(leaders);
+
+        let mut totals: HashMap<Uuid, (usize, i64)> = HashMap::new();
+        for value in &values {
+            let entry = totals.entry(value.team_id).or_default();
+            entry.0 += 1;
+            entry.1 += value.value_over_slot;
+        }
+        let mut teams: Vec<TeamValue> = totals
+            .into_iter()
+            .map(|(team_id, (count, total))| TeamValue {
+                team_id,
+                picks_analyzed: count,
+                total_value_over_slot: total,
+                average_value_over_slot: (total as f64 / count as f64 * 100.0).round() / 100.0,
+            })
+            .collect();
+        teams.sort_by(|a, b| {
+            b.total_value_over_slot
+                .cmp(&a.total_value_over_slot)
+                .then_with(|| a.team_id.cmp(&b.team_id))
+        });
+
+        ValueAnalysis {
+            picks: values,
+            unranked_picks,
+            steals,
+            reaches,
+            teams,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::Position;
+
+    fn made_pick(overall_pick: i32, team_id: Uuid, player_id: Option<Uuid>) -> DraftPick {
+        let mut pick =
+            DraftPick::new(Uuid::new_v4(), 1, overall_pick, overall_pick, team_id).unwrap();
+        pick.player_id = player_id;
+        pick
+    }
+
+    fn ranked(player_id: Uuid, consensus_rank: i64) -> ConsensusRanking {
+        ConsensusRanking {
+            consensus_rank,
+            player_id,
+            first_name: "Test".to_string(),
+            last_name: "Player".to_string(),
+            position: Position::WR,
+            source_count: 1,
+            average_rank: consensus_rank as f64,
+            best_rank: consensus_rank as i32,
+            worst_rank: consensus_rank as i32,
+            rank_std_dev: 0.0,
+        }
+    }
+
+    #[test]
+    fn test_value_over_slot_sign() {
+        assert_eq!(ValueOverSlotService::value_over_slot(10, 3), 7);
+        assert_eq!(ValueOverSlotService::value_over_slot(3, 10), -7);
+    }
+
+    #[test]
+    fn test_analyze_ranks_steals_reaches_and_teams() {
+        let team_a = Uuid::new_v4();
+        let team_b = Uuid::new_v4();
+        let players: Vec<Uuid> = (0..5).map(|_| Uuid::new_v4()).collect();
+
+        let picks = vec![
+            made_pick(1, team_a, Some(players[0])), // rank 4: -3
+            made_pick(2, team_b, Some(players[1])), // rank 1: +1
+            made_pick(3, team_a, Some(players[2])), // unranked
+            made_pick(4, team_b, Some(players[3])), // rank 2: +2
+            made_pick(5, team_a, Some(players[4])), // rank 5: 0
+            made_pick(6, team_b, None),
+        ];
+        let consensus = vec![
+            ranked(players[1], 1),
+            ranked(players[3], 2),
+            ranked(players[0], 4),
+            ranked(players[4], 5),
+        ];
+
+        let analysis = ValueOverSlotService::analyze(&picks, &consensus, 1);
+
+        let slots: Vec<i32> = analysis.picks.iter().map(|p| p.overall_pick).collect();
+        assert_eq!(slots, vec![1, 2, 4, 5]);
+        assert_eq!(analysis.unranked_picks, 1);
+
+        assert_eq!(analysis.steals.len(), 1);
+        assert_eq!(analysis.steals[0].overall_pick, 4);
+        assert_eq!(analysis.reaches.len(), 1);
+        assert_eq!(analysis.reaches[0].value_over_slot, -3);
+
+        assert_eq!(analysis.teams[0].team_id, team_b);
+        assert_eq!(analysis.teams[0].total_value_over_slot, 3);
+        assert_eq!(analysis.teams[0].average_value_over_slot, 1.5);
+        assert_eq!(analysis.teams[1].picks_analyzed, 2);
+        assert_eq!(analysis.teams[1].total_value_over_slot, -3);
+    }
+}
@@ -0,0 +1,316 @@
+use std::collections::{HashMap, HashSet};
+
+use anyhow::Result;
+use domain::models::{ActualDraftPick, Player};
+use domain::repositories::{ActualDraftPickRepository, PlayerRepository, TeamRepository};
+use serde::Deserialize;
+
+use crate::rankings_loader::normalize_name;
+
+#[derive(Debug, Deserialize)]
+pub struct ActualResultsData {
+    pub meta: ActualResultsMeta,
+    pub picks: Vec<ActualPickEntry>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct ActualResultsMeta {
+    pub year: i32,
+    pub source: String,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct ActualPickEntry {
+    pub round: i32,
+    pub pick_in_round: i32,
+    pub overall_pick: i32,
+    pub team_abbreviation: String,
+    pub first_name: String,
+    pub last_name: String,
+    pub position: String,
+    pub college: String,
+}
+
+pub fn parse_actual_results_file(path: &str) -> Result<ActualResultsData> {
+    let content = std::fs::read_to_string(path)?;
+    parse_actual_results_json(&content)
+}
+
+pub fn parse_actual_results_json(json: &str) -> Result<ActualResultsData> {
+    let data: ActualResultsData = serde_json::from_str(json)?;
+    Ok(data)
+}
+
+/// Structural checks that don't need the database: positive, unique pick
+/// numbers and no player listed twice
+pub fn validate_actual_results(data: &ActualResultsData) -> Vec<String> {
+    let mut errors = Vec::new();
+    let mut overall_picks = HashSet::new();
+    let mut players = HashSet::new();
+
+    for entry in &data.picks {
+        if entry.round <= 0 || entry.pick_in_round <= 0 || entry.overall_pick <= 0 {
+            errors.push(format!(
+                "Pick {}: round, pick_in_round and overall_pick must be positive",
+                entry.overall_pick
+            ));
+        }
+        if !overall_picks.insert(entry.overall_pick) {
+            errors.push(format!("Pick {} is listed twice", entry.overall_pick));
+        }
+        let name = (
+            normalize_name(&entry.first_name),
+            normalize_name(&entry.last_name),
+        );
+        if !players.insert(name) {
+            errors.push(format!(
+                "{} {} is drafted twice",
+                entry.first_name, entry.last_name
+            ));
+        }
+    }
+
+    errors
+}
+
+#[derive(Debug, Default)]
+pub struct ActualResultsLoadStats {
+    pub matched: usize,
+    pub unmatched: usize,
+    pub inserted: usize,
+    pub errors: Vec<String>,
+    pub unmatched_names: Vec<String>,
+}
+
+impl ActualResultsLoadStats {
+    pub fn print_summary(&self) {
+        println!("\nActual Draft Results Load Summary:");
+        println!("  Picks matched:      {}", self.matched);
+        println!("  Picks unmatched:    {}", self.unmatched);
+        println!("  Picks inserted:     {}", self.inserted);
+        println!("  Errors:             {}", self.errors.len());
+
+        if !self.unmatched_names.is_empty() {
+            println!("\nUnmatched picks (team or player not found in database):");
+            for name in &self.unmatched_names {
+                println!("  - {}", name);
+            }
+        }
+
+        if !self.errors.is_empty() {
+            println!("\nErrors:");
+            for error in &self.errors {
+                println!("  - {}", error);
+            }
+        }
+    }
+}
+
+pub fn load_actual_results_dry_run(data: &ActualResultsData) -> Result<ActualResultsLoadStats> {
+    let mut stats = ActualResultsLoadStats {
+        errors: validate_actual_results(data),
+        ..Default::default()
+    };
+
+    println!(
+        "[DRY RUN] Would load {} actual picks for year {} from '{}'",
+        data.picks.len(),
+        data.meta.year,
+        data.meta.source
+    );
+
+    for entry in &data.picks {
+        println!(
+            "[DRY RUN] Pick {} (round {}): {} - {} {} ({}, {})",
+            entry.overall_pick,
+            entry.round,
+            entry.team_abbreviation,
+            entry.first_name,
+            entry.last_name,
+            entry.position,
+            entry.college
+        );
+        stats.matched += 1;
+        stats.inserted += 1;
+    }
+
+    Ok(stats)
+}
+
+/// Replace the real draft results for the file's year. Picks whose team or
+/// player can't be matched are reported and skipped; nothing is written if
+/// the file fails validation.
+pub async fn load_actual_results(
+    data: &ActualResultsData,
+    team_repo: &dyn TeamRepository,
+    player_repo: &dyn PlayerRepository,
+    actual_repo: &dyn ActualDraftPickRepository,
+) -> Result<ActualResultsLoadStats> {
+    let mut stats = ActualResultsLoadStats {
+        errors: validate_actual_results(data),
+        ..Default::default()
+    };
+    if !stats.errors.is_empty() {
+        return Ok(stats);
+    }
+
+    println!(
+        "Loading {} actual draft picks for year {}...",
+        data.picks.len(),
+        data.meta.year
+    );
+
+    let teams = team_repo
+        .find_all()
+        .await
+        .map_err(|e| anyhow::anyhow!("Failed to fetch teams: {}", e))?;
+    let team_map: HashMap<String, _> = teams
+        .iter()
+        .map(|t| (t.abbreviation.to_uppercase(), t.id))
+        .collect();
+
+    let players = player_repo
+        .find_by_draft_year(data.meta.year)
+        .await
+        .map_err(|e| anyhow::anyhow!("Failed to fetch players: {}", e))?;
+    let player_map: HashMap<(String, String), &Player> = players
+        .iter()
+        .map(|p| {
+            (
+                (normalize_name(&p.first_name), normalize_name(&p.last_name)),
+                p,
+            )
+        })
+        .collect();
+
+    let mut picks = Vec::new();
+    for entry in &data.picks {
+        let team_id = team_map.get(&entry.team_abbreviation.to_uppercase());
+        let player = player_map.get(&(
+            normalize_name(&entry.first_name),
+            normalize_name(&entry.last_name),
+        ));
+
+        let (Some(&team_id), Some(player)) = (team_id, player) else {
+            let name = format!(
+                "Pick {}: {} - {} {} ({}, {})",
+                entry.overall_pick,
+                entry.team_abbreviation,
+                entry.first_name,
+                entry.last_name,
+                entry.position,
+                entry.college
+            );
+            tracing::warn!("No matching team or player for {}", name);
+            stats.unmatched += 1;
+            stats.unmatched_names.push(name);
+            continue;
+        };
+        stats.matched += 1;
+
+        let pick = ActualDraftPick::new(
+            data.meta.year,
+            entry.round,
+            entry.pick_in_round,
+            entry.overall_pick,
+            team_id,
+            player.id,
+        )
+        .map_err(|e| anyhow::anyhow!("Invalid pick {}: {}", entry.overall_pick, e))?;
+        picks.push(pick);
+    }
+
+    let inserted = actual_repo
+        .replace_year(data.meta.year, &picks)
+        .await
+        .map_err(|e| anyhow::anyhow!("Failed to store actual draft picks: {}", e))?;
+    stats.inserted = inserted as usize;
+
+    println!(
+        "  Matched {} picks, inserted {} picks",
+        stats.matched, stats.inserted
+    );
+
+    Ok(stats)
+}
+
+pub async fn clear_actual_results(
+    year: i32,
+    actual_repo: &dyn ActualDraftPickRepository,
+) -> Result<u64> {
+    let deleted = actual_repo
+        .delete_by_year(year)
+        .await
+        .map_err(|e| anyhow::anyhow!("Failed to delete actual draft picks: {}", e))?;
+    Ok(deleted)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample_json() -> &'static str {
+        r#"{
+            "meta": { "year": 2026, "source": "NFL.com" },
+            "picks": [
+                {
+                    "round": 1,
+                    "pick_in_round": 1,
+                    "overall_pick": 1,
+                    "team_abbreviation": "LV",
+                    "first_name": "Fernando",
+                    "last_name": "Mendoza",
+                    "position": "QB",
+                    "college": "Indiana"
+                },
+                {
+                    "round": 1,
+                    "pick_in_round": 2,
+                    "overall_pick": 2,
+                    "team_abbreviation": "NYJ",
+                    "first_name": "Arvell",
+                    "last_name": "Reese",
+                    "position": "LB",
+                    "college": "Ohio State"
+                }
+            ]
+        }"#
+    }
+
+    #[test]
+    fn test_parse_actual_results_json() {
+        let data = parse_actual_results_json(sample_json()).unwrap();
+        assert_eq!(data.meta.year, 2026);
+        assert_eq!(data.meta.source, "NFL.com");
+        assert_eq!(data.picks.len(), 2);
+        assert_eq!(data.picks[0].team_abbreviation, "LV");
+        assert_eq!(data.picks[1].overall_pick, 2);
+        assert_eq!(data.picks[1].last_name, "Reese");
+    }
+
+    #[test]
+    fn test_parse_actual_results_json_invalid() {
+        assert!(parse_actual_results_json("not valid json").is_err());
+    }
+
+    #[test]
+    fn test_validate_actual_results_flags_duplicates() {
+        let mut data = parse_actual_results_json(sample_json()).unwrap();
+        assert!(validate_actual_results(&data).is_empty());
+
+        data.picks[1].overall_pick = 1;
+        data.picks[1].first_name = "fernando".to_string();
+        data.picks[1].last_name = "Mendoza".to_string();
+        let errors = validate_actual_results(&data);
+        assert_eq!(errors.len(), 2);
+    }
+
+    #[test]
+    fn test_dry_run() {
+        let data = parse_actual_results_json(sample_json()).unwrap();
+        let stats = load_actual_results_dry_run(&data).unwrap();
+        assert_eq!(stats.matched, 2);
+        assert_eq!(stats.inserted, 2);
+        assert!(stats.errors.is_empty());
+    }
+}
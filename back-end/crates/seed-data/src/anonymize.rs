@@ -0,0 +1,325 @@
+//! Replaces real player names and schools in seed files with fake ones
+//!
+//! Lets a production-like dataset be attached to a bug report without
+//! sharing real people's data. Only identities change: positions, sizes,
+//! ranks, combine numbers and injury details are kept, so the data behaves
+//! the same in the draft engine. Profile and headshot links on rankings
+//! point at the real player, so they are dropped.
+//!
+//! The mapping is consistent within a run. A player gets the same fake name
+//! in the players, combine and rankings files, and every player from one
+//! school moves to the same fake school, so the class's school counts are
+//! unchanged.
+
+use std::collections::{HashMap, HashSet};
+
+use anyhow::{bail, Result};
+
+use crate::combine_loader::CombineFileData;
+use crate::loader::PlayerData;
+use crate::prospect_generator::{unique_name, SplitMix64};
+use crate::rankings_loader::normalize_name;
+use crate::scouting_report_loader::RankingData;
+
+const SCHOOL_PLACES: &[&str] = &[
+    "Ashford",
+    "Bayridge",
+    "Blue Hollow",
+    "Brookmere",
+    "Cedar Falls",
+    "Coldwater",
+    "Crestview",
+    "Eastbrook",
+    "Fairhaven",
+    "Glenmoor",
+    "Granite Bluff",
+    "Harlow",
+    "Ironwood",
+    "Kingsbury",
+    "Lakeshore",
+    "Maple Ridge",
+    "Northfield",
+    "Oakmont",
+    "Pine Valley",
+    "Redstone",
+    "Riverton",
+    "Silver Creek",
+    "Southport",
+    "Stonegate",
+    "Summit",
+    "Thornbury",
+    "Westbrook",
+    "Willow Bend",
+    "Alder Point",
+    "Birchwood",
+    "Cobalt Lake",
+    "Deer Run",
+    "Elm Harbor",
+    "Fox Hill",
+    "Grand Prairie",
+    "Hawthorne",
+    "High Plains",
+    "Juniper",
+    "Lone Pine",
+    "Marston",
+    "Misty Bay",
+    "New Carlow",
+    "Oldcastle",
+    "Prairie View",
+    "Quarry Hill",
+    "Rock Harbor",
+    "Sandstone",
+    "Shadow Ridge",
+    "Sterling",
+    "Tall Oaks",
+    "Timberline",
+    "Upper Valley",
+    "Verdant",
+    "West Haven",
+    "Whitfield",
+    "Wolf Creek",
+    "Yellow Pine",
+    "Zephyr",
+    "Amberly",
+    "Brightwater",
+];
+
+const SCHOOL_KINDS: &[&str] = &["State", "University", "Tech", "A&M", "College", "Poly"];
+
+/// Draws fake identities and remembers them, so one real player or school
+/// always maps to the same fake one
+pub struct Anonymizer {
+    rng: SplitMix64,
+    names: HashMap<(String, String), (String, String)>,
+    used_names: HashSet<(String, String)>,
+    schools: HashMap<String, String>,
+    /// Unused fake schools, in a seeded random order
+    school_pool: Vec<String>,
+}
+
+impl Anonymizer {
+    pub fn new(seed: u64) -> Self {
+        let mut rng = SplitMix64::new(seed);
+        let mut school_pool: Vec<String> = SCHOOL_PLACES
+            .iter()
+            .flat_map(|place| {
+                SCHOOL_KINDS
+                    .iter()
+                    .map(move |kind| format!("{} {}", place, kind))
+            })
+            .collect();
+        // Fisher-Yates
+        for i in (1..school_pool.len()).rev() {
+            let j = rng.below(i as u64 + 1) as usize;
+            school_pool.swap(i, j);
+        }
+
+        Self {
+            rng,
+            names: HashMap::new(),
+            used_names: HashSet::new(),
+            schools: HashMap::new(),
+            school_pool,
+        }
+    }
+
+    /// Renames players and schools, and drops `notes`, which are free text
+    /// that may name real people
+    pub fn players(&mut self, data: &mut PlayerData) -> Result<()> {
+        self.reserve_names(
+            data.players
+                .iter()
+                .map(|p| (p.first_name.as_str(), p.last_name.as_str())),
+        );
+        for player in &mut data.players {
+            (player.first_name, player.last_name) =
+                self.name(&player.first_name, &player.last_name)?;
+            if let Some(college) = &player.college {
+                player.college = Some(self.school(college)?);
+            }
+            player.notes = None;
+        }
+        Ok(())
+    }
+
+    pub fn combine(&mut self, data: &mut CombineFileData) -> Result<()> {
+        self.reserve_names(
+            data.combine_results
+                .iter()
+                .map(|c| (c.first_name.as_str(), c.last_name.as_str())),
+        );
+        for entry in &mut data.combine_results {
+            (entry.first_name, entry.last_name) = self.name(&entry.first_name, &entry.last_name)?;
+        }
+        Ok(())
+    }
+
+    pub fn rankings(&mut self, data: &mut RankingData) -> Result<()> {
+        self.reserve_names(
+            data.rankings
+                .iter()
+                .map(|r| (r.first_name.as_str(), r.last_name.as_str())),
+        );
+        for entry in &mut data.rankings {
+            (entry.first_name, entry.last_name) = self.name(&entry.first_name, &entry.last_name)?;
+            if !entry.school.trim().is_empty() {
+                entry.school = self.school(&entry.school)?;
+            }
+            entry.profile_url = None;
+            entry.headshot_url = None;
+        }
+        Ok(())
+    }
+
+    /// Keeps a file's real names out of the fake name pool, so no prospect
+    /// in it is renamed to another real prospect
+    fn reserve_names<'a>(&mut self, names: impl Iterator<Item = (&'a str, &'a str)>) {
+        self.used_names
+            .extend(names.map(|(first, last)| (first.to_string(), last.to_string())));
+    }
+
+    /// The fake name for a player, matched the way the loaders match names
+    fn name(&mut self, first: &str, last: &str) -> Result<(String, String)> {
+        let key = (normalize_name(first), normalize_name(last));
+        if let Some(fake) = self.names.get(&key) {
+            return Ok(fake.clone());
+        }
+        let fake = unique_name(&mut self.rng, &mut self.used_names)?;
+        self.names.insert(key, fake.clone());
+        Ok(fake)
+    }
+
+    fn school(&mut self, school: &str) -> Result<String> {
+        let key = school.trim().to_lowercase();
+        if let Some(fake) = self.schools.get(&key) {
+            return Ok(fake.clone());
+        }
+        let Some(fake) = self.school_pool.pop() else {
+            bail!(
+                "Ran out of fake school names after {} schools",
+                self.schools.len()
+            );
+        };
+        self.schools.insert(key, fake.clone());
+        Ok(fake)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::combine_loader::parse_combine_json;
+    use crate::loader::parse_player_json;
+    use crate::scouting_report_loader::parse_ranking_json;
+
+    fn players() -> PlayerData {
+        parse_player_json(
+            r#"{
+                "meta": { "version": "1.0.0", "draft_year": 2026, "last_updated": "2026-02-01",
+                          "sources": ["test"], "total_players": 3 },
+                "players": [
+                    { "first_name": "Fernando", "last_name": "Mendoza", "position": "QB",
+                      "college": "Indiana", "height_inches": 77, "weight_pounds": 225,
+                      "notes": "Heisman winner" },
+                    { "first_name": "Caleb", "last_name": "Downs", "position": "S",
+                      "college": "Ohio State", "height_inches": 72, "weight_pounds": 205 },
+                    { "first_name": "Arvell", "last_name": "Reese", "position": "LB",
+                      "college": "Ohio State", "medical_flag": true }
+                ]
+            }"#,
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn test_players_keep_everything_but_identity() {
+        let mut data = players();
+        Anonymizer::new(1).players(&mut data).unwrap();
+
+        let real = ["Mendoza", "Downs", "Reese", "Indiana", "Ohio State"];
+        let json = serde_json::to_string(&data).unwrap();
+        for name in real {
+            assert!(!json.contains(name), "{} survived: {}", name, json);
+        }
+        assert_eq!(data.players[0].notes, None);
+
+        let original = players();
+        for (fake, real) in data.players.iter().zip(&original.players) {
+            assert_eq!(fake.position, real.position);
+            assert_eq!(fake.height_inches, real.height_inches);
+            assert_eq!(fake.weight_pounds, real.weight_pounds);
+            assert_eq!(fake.medical_flag, real.medical_flag);
+        }
+        // Both Ohio State players move to the same fake school
+        assert_eq!(data.players[1].college, data.players[2].college);
+        assert_ne!(data.players[0].college, data.players[1].college);
+    }
+
+    #[test]
+    fn test_names_map_consistently_across_files() {
+        let mut anonymizer = Anonymizer::new(1);
+        let mut data = players();
+        anonymizer.players(&mut data).unwrap();
+
+        let mut combine = parse_combine_json(
+            r#"{
+                "meta": { "source": "combine", "year": 2026 },
+                "combine_results": [
+                    { "first_name": "Caleb", "last_name": "Downs", "position": "S",
+                      "source": "combine", "year": 2026, "forty_yard_dash": 4.48,
+                      "bench_press": null, "vertical_jump": null, "broad_jump": null,
+                      "three_cone_drill": null, "twenty_yard_shuttle": null,
+                      "arm_length": null, "hand_size": null, "wingspan": null,
+                      "ten_yard_split": null, "twenty_yard_split": null }
+                ]
+            }"#,
+        )
+        .unwrap();
+        anonymizer.combine(&mut combine).unwrap();
+
+        let mut rankings = parse_ranking_json(
+            r#"{
+                "meta": { "version": "1.0.0", "source": "Tankathon", "source_url": "",
+                          "draft_year": 2026, "scraped_at": "2026-02-11", "total_prospects": 1 },
+                "rankings": [
+                    { "rank": 1, "first_name": "Fernando", "last_name": "Mendoza",
+                      "position": "QB", "school": "Indiana",
+                      "profile_url": "https://www.tankathon.com/players/fernando-mendoza" }
+                ]
+            }"#,
+        )
+        .unwrap();
+        anonymizer.rankings(&mut rankings).unwrap();
+
+        let downs = &data.players[1];
+        let combine_entry = &combine.combine_results[0];
+        assert_eq!(
+            (&combine_entry.first_name, &combine_entry.last_name),
+            (&downs.first_name, &downs.last_name)
+        );
+        assert_eq!(combine_entry.forty_yard_dash, Some(4.48));
+
+        let mendoza = &data.players[0];
+        let ranking = &rankings.rankings[0];
+        assert_eq!(
+            (&ranking.first_name, &ranking.last_name),
+            (&mendoza.first_name, &mendoza.last_name)
+        );
+        assert_eq!(Some(&ranking.school), mendoza.college.as_ref());
+        assert_eq!(ranking.rank, 1);
+        assert_eq!(ranking.profile_url, None);
+    }
+
+    #[test]
+    fn test_same_seed_gives_same_names() {
+        let run = |seed| {
+            let mut data = players();
+            Anonymizer::new(seed).players(&mut data).unwrap();
+            data.players
+                .iter()
+                .map(|p| format!("{} {}", p.first_name, p.last_name))
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(run(5), run(5));
+    }
+}
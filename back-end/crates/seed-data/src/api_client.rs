@@ -0,0 +1,105 @@
+//! Seeding through the API's admin endpoints
+//!
+//! Lets a load run against a deployment whose database is not reachable
+//! from the machine running the tool. The file is parsed and validated
+//! locally, then posted to the matching `/api/v1/admin/seed-*` endpoint,
+//! which loads it with the same loader a direct run would use.
+
+use std::time::Duration;
+
+use anyhow::{bail, Context, Result};
+use serde::{Deserialize, Serialize};
+
+use crate::load_mode::LoadMode;
+
+/// Header the API checks against its `SEED_API_KEY`
+const SEED_API_KEY_HEADER: &str = "X-Seed-Api-Key";
+
+/// Large files are loaded row by row on the server
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(300);
+
+pub struct SeedApiClient {
+    client: reqwest::Client,
+    base_url: String,
+    api_key: String,
+}
+
+/// The API's report of a seed request
+#[derive(Debug, Deserialize)]
+pub struct SeedSummary {
+    pub message: String,
+    pub success_count: usize,
+    pub skipped_count: usize,
+    pub error_count: usize,
+    pub errors: Vec<String>,
+    pub validation_warnings: Vec<String>,
+}
+
+impl SeedSummary {
+    pub fn print_summary(&self) {
+        println!("\n{}", self.message);
+        println!("  Succeeded: {}", self.success_count);
+        println!("  Skipped:   {}", self.skipped_count);
+        println!("  Errors:    {}", self.error_count);
+        if !self.errors.is_empty() {
+            println!("\nErrors:");
+            for error in &self.errors {
+                println!("  - {}", error);
+            }
+        }
+        if !self.validation_warnings.is_empty() {
+            println!("\nServer validation warnings:");
+            for warning in &self.validation_warnings {
+                println!("  - {}", warning);
+            }
+        }
+    }
+}
+
+impl SeedApiClient {
+    /// `base_url` is the API's root, e.g. `https://draft.example.com`
+    pub fn new(base_url: &str, api_key: String) -> Result<Self> {
+        let client = reqwest::Client::builder()
+            .timeout(REQUEST_TIMEOUT)
+            .build()
+            .context("Failed to build HTTP client")?;
+        Ok(Self {
+            client,
+            base_url: base_url.trim_end_matches('/').to_string(),
+            api_key,
+        })
+    }
+
+    /// Posts a seed file to `/api/v1/admin/{endpoint}`, passing `mode` for
+    /// the endpoints that take one
+    pub async fn seed<T: Serialize>(
+        &self,
+        endpoint: &str,
+        data: &T,
+        mode: Option<LoadMode>,
+    ) -> Result<SeedSummary> {
+        let url = format!("{}/api/v1/admin/{}", self.base_url, endpoint);
+        let mut request = self
+            .client
+            .post(&url)
+            .header(SEED_API_KEY_HEADER, &self.api_key)
+            .json(data);
+        if let Some(mode) = mode {
+            request = request.query(&[("mode", mode)]);
+        }
+
+        let response = request
+            .send()
+            .await
+            .with_context(|| format!("Failed to reach {}", url))?;
+        let status = response.status();
+        if !status.is_success() {
+            let body = response.text().await.unwrap_or_default();
+            bail!("{} returned {}: {}", url, status, body);
+        }
+        response
+            .json()
+            .await
+            .with_context(|| format!("Unexpected response from {}", url))
+    }
+}
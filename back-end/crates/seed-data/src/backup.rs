@@ -0,0 +1,471 @@
+//! Backups of the rows a destructive seed command is about to delete or
+//! overwrite, and restoring them.
+//!
+//! A backup names the scopes it covers (for example "players of draft year
+//! 2026") and holds every row in them as JSON, including rows in tables
+//! whose foreign keys cascade from the scope, since deleting the scope
+//! deletes those too. Restoring deletes whatever is in the scopes now and
+//! inserts the backed up rows in one transaction, which puts the scopes
+//! back exactly as they were.
+//!
+//! Only SQL built from the scopes is run. Table names in the file are
+//! checked against the tables the scopes cover before anything is
+//! inserted.
+
+use std::collections::HashSet;
+use std::path::{Path, PathBuf};
+
+use anyhow::{bail, Context, Result};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use sqlx::postgres::PgArguments;
+use sqlx::query::Query;
+use sqlx::{FromRow, PgPool, Postgres, Row};
+
+/// Where backups go unless `--backup-dir` says otherwise
+pub const DEFAULT_BACKUP_DIR: &str = "data/backups";
+
+/// A set of rows a seed command deletes or overwrites
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "scope", rename_all = "snake_case")]
+pub enum Scope {
+    Players { draft_year: i32 },
+    Teams,
+    TeamNeeds,
+    TeamSeasons { season_year: i32 },
+    DraftPicks { year: i32 },
+    ScoutingReports { draft_year: i32 },
+    Rankings { source: String },
+    Freaks { year: i32 },
+    ActualResults { year: i32 },
+    CollegeStats { draft_year: i32 },
+    Combine { year: i32 },
+    Percentiles,
+    ProspectProfiles { source: String },
+}
+
+enum Param<'a> {
+    Int(i32),
+    Text(&'a str),
+}
+
+impl Scope {
+    fn table(&self) -> &'static str {
+        match self {
+            Scope::Players { .. } => "players",
+            Scope::Teams => "teams",
+            Scope::TeamNeeds => "team_needs",
+            Scope::TeamSeasons { .. } => "team_seasons",
+            Scope::DraftPicks { .. } => "draft_picks",
+            Scope::ScoutingReports { .. } => "scouting_reports",
+            Scope::Rankings { .. } => "prospect_rankings",
+            Scope::Freaks { .. } => "feldman_freaks",
+            Scope::ActualResults { .. } => "actual_draft_picks",
+            Scope::CollegeStats { .. } => "college_stats",
+            Scope::Combine { .. } => "combine_results",
+            Scope::Percentiles => "combine_percentiles",
+            Scope::ProspectProfiles { .. } => "prospect_profiles",
+        }
+    }
+
+    /// The rows in scope, as a WHERE clause over at most one parameter
+    fn filter(&self) -> (&'static str, Option<Param<'_>>) {
+        match self {
+            Scope::Players { draft_year } => ("draft_year = $1", Some(Param::Int(*draft_year))),
+            Scope::Teams | Scope::TeamNeeds | Scope::Percentiles => ("TRUE", None),
+            Scope::TeamSeasons { season_year } => {
+                ("season_year = $1", Some(Param::Int(*season_year)))
+            }
+            Scope::DraftPicks { year } => (
+                "draft_id IN (SELECT id FROM drafts WHERE year = $1)",
+                Some(Param::Int(*year)),
+            ),
+            Scope::ScoutingReports { draft_year } | Scope::CollegeStats { draft_year } => (
+                "player_id IN (SELECT id FROM players WHERE draft_year = $1)",
+                Some(Param::Int(*draft_year)),
+            ),
+            Scope::Rankings { source } => (
+                "ranking_source_id IN (SELECT id FROM ranking_sources WHERE name = $1)",
+                Some(Param::Text(source)),
+            ),
+            Scope::Freaks { year } | Scope::ActualResults { year } | Scope::Combine { year } => {
+                ("year = $1", Some(Param::Int(*year)))
+            }
+            Scope::ProspectProfiles { source } => ("source = $1", Some(Param::Text(source))),
+        }
+    }
+}
+
+/// A foreign key that deletes its rows along with the parent row
+#[derive(Debug, Clone, FromRow)]
+pub struct CascadeEdge {
+    pub child_table: String,
+    pub child_column: String,
+    pub parent_table: String,
+    pub parent_column: String,
+}
+
+/// A table a scope covers and the WHERE clause selecting its rows
+#[derive(Debug, Clone, PartialEq)]
+pub struct TablePlan {
+    pub table: String,
+    pub filter: String,
+}
+
+/// The scope's own table, then every table cascading from it. A table is
+/// listed after all of its in-scope parents, so rows can be inserted in
+/// order, and takes rows that belong to any of them.
+pub fn plan(scope: &Scope, edges: &[CascadeEdge]) -> Vec<TablePlan> {
+    let root = scope.table();
+    let in_scope_parents = |table: &str, tables: &[String]| -> Vec<&CascadeEdge> {
+        edges
+            .iter()
+            .filter(|e| e.child_table == table && e.parent_table != table)
+            .filter(|e| tables.contains(&e.parent_table))
+            .collect()
+    };
+
+    let mut reachable = vec![root.to_string()];
+    let mut i = 0;
+    while i < reachable.len() {
+        let parent = reachable[i].clone();
+        for edge in edges.iter().filter(|e| e.parent_table == parent) {
+            if !reachable.contains(&edge.child_table) {
+                reachable.push(edge.child_table.clone());
+            }
+        }
+        i += 1;
+    }
+
+    let mut plans: Vec<TablePlan> = vec![TablePlan {
+        table: root.to_string(),
+        filter: scope.filter().0.to_string(),
+    }];
+    let mut planned = vec![root.to_string()];
+    while planned.len() < reachable.len() {
+        // Foreign keys cascading in a cycle would leave nothing ready
+        let Some(table) = reachable.iter().find(|t| {
+            !planned.contains(t)
+                && in_scope_parents(t, &reachable)
+                    .iter()
+                    .all(|e| planned.contains(&e.parent_table))
+        }) else {
+            break;
+        };
+        let parts: Vec<String> = in_scope_parents(table, &planned)
+            .iter()
+            .map(|e| {
+                let parent = plans.iter().find(|p| p.table == e.parent_table).unwrap();
+                format!(
+                    "{} IN (SELECT {} FROM {} WHERE {})",
+                    e.child_column, e.parent_column, parent.table, parent.filter
+                )
+            })
+            .collect();
+        let filter = if parts.len() == 1 {
+            parts.into_iter().next().unwrap()
+        } else {
+            format!("({})", parts.join(") OR ("))
+        };
+        plans.push(TablePlan {
+            table: table.clone(),
+            filter,
+        });
+        planned.push(table.clone());
+    }
+    plans
+}
+
+async fn cascade_edges(pool: &PgPool) -> Result<Vec<CascadeEdge>> {
+    let edges = sqlx::query_as::<_, CascadeEdge>(
+        "SELECT con.conrelid::regclass::text AS child_table, \
+                child_col.attname::text AS child_column, \
+                con.confrelid::regclass::text AS parent_table, \
+                parent_col.attname::text AS parent_column \
+         FROM pg_constraint con \
+         JOIN pg_attribute child_col \
+           ON child_col.attrelid = con.conrelid AND child_col.attnum = con.conkey[1] \
+         JOIN pg_attribute parent_col \
+           ON parent_col.attrelid = con.confrelid AND parent_col.attnum = con.confkey[1] \
+         WHERE con.contype = 'f' AND con.confdeltype = 'c' \
+           AND array_length(con.conkey, 1) = 1 \
+           AND con.connamespace = current_schema()::regnamespace \
+         ORDER BY child_table, child_column",
+    )
+    .fetch_all(pool)
+    .await?;
+    Ok(edges)
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TableRows {
+    pub table: String,
+    pub rows: Vec<Value>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Backup {
+    /// What the backup was taken before, e.g. `players-clear-2026`
+    pub name: String,
+    pub created_at: DateTime<Utc>,
+    pub scopes: Vec<Scope>,
+    pub tables: Vec<TableRows>,
+}
+
+impl Backup {
+    /// Copies every row in the scopes and the tables cascading from them.
+    /// Tables with no rows in scope are left out.
+    pub async fn take(pool: &PgPool, name: &str, scopes: Vec<Scope>) -> Result<Self> {
+        let edges = cascade_edges(pool).await?;
+        let mut tables = Vec::new();
+        for scope in &scopes {
+            let param = scope.filter().1;
+            for table_plan in plan(scope, &edges) {
+                let sql = format!(
+                    "SELECT to_jsonb(t) FROM {} t WHERE {}",
+                    table_plan.table, table_plan.filter
+                );
+                let rows = scoped_query(&sql, &param)
+                    .fetch_all(pool)
+                    .await
+                    .with_context(|| format!("Failed to back up {}", table_plan.table))?
+                    .iter()
+                    .map(|row| row.try_get::<Value, _>(0))
+                    .collect::<Result<Vec<_>, _>>()?;
+                if rows.is_empty() {
+                    continue;
+                }
+                tables.push(TableRows {
+                    table: table_plan.table,
+                    rows,
+                });
+            }
+        }
+        Ok(Self {
+            name: name.to_string(),
+            created_at: Utc::now(),
+            scopes,
+            tables,
+        })
+    }
+
+    pub fn row_count(&self) -> usize {
+        self.tables.iter().map(|t| t.rows.len()).sum()
+    }
+
+    /// `<name>-<timestamp>.json`, with anything but letters, digits, `-`
+    /// and `_` in the name replaced so a ranking source can't make a path
+    pub fn file_name(&self) -> String {
+        let name: String = self
+            .name
+            .chars()
+            .map(|c| {
+                if c.is_ascii_alphanumeric() || c == '-' || c == '_' {
+                    c.to_ascii_lowercase()
+                } else {
+                    '-'
+                }
+            })
+            .collect();
+        format!("{}-{}.json", name, self.created_at.format("%Y%m%dT%H%M%S"))
+    }
+
+    /// Writes the backup into `dir`, creating it if needed
+    pub fn write(&self, dir: &str) -> Result<PathBuf> {
+        std::fs::create_dir_all(dir).with_context(|| format!("Failed to create {}", dir))?;
+        let path = Path::new(dir).join(self.file_name());
+        let json = serde_json::to_string(self)?;
+        std::fs::write(&path, json)
+            .with_context(|| format!("Failed to write {}", path.display()))?;
+        Ok(path)
+    }
+
+    pub fn read(path: &str) -> Result<Self> {
+        let json =
+            std::fs::read_to_string(path).with_context(|| format!("Failed to read {}", path))?;
+        serde_json::from_str(&json).with_context(|| format!("{} is not a seed backup", path))
+    }
+}
+
+#[derive(Debug, Default)]
+pub struct RestoreStats {
+    /// Rows in the scopes deleted before restoring, not counting cascades
+    pub deleted: u64,
+    pub restored: Vec<(String, u64)>,
+}
+
+impl RestoreStats {
+    pub fn print_summary(&self) {
+        println!("\nRestore Summary:");
+        println!("  Rows replaced: {}", self.deleted);
+        for (table, count) in &self.restored {
+            println!("  Restored {:<22} {}", format!("{}:", table), count);
+        }
+    }
+}
+
+/// Puts the scopes of a backup back as they were when it was taken
+pub async fn restore(pool: &PgPool, backup: &Backup) -> Result<RestoreStats> {
+    let edges = cascade_edges(pool).await?;
+    let covered: HashSet<String> = backup
+        .scopes
+        .iter()
+        .flat_map(|scope| plan(scope, &edges))
+        .map(|p| p.table)
+        .collect();
+    for table in &backup.tables {
+        if !covered.contains(&table.table) {
+            bail!(
+                "Backup has rows for {}, which none of its scopes cover",
+                table.table
+            );
+        }
+    }
+
+    let mut stats = RestoreStats::default();
+    let mut tx = pool.begin().await?;
+    for scope in &backup.scopes {
+        let (filter, param) = scope.filter();
+        let sql = format!("DELETE FROM {} WHERE {}", scope.table(), filter);
+        let result = scoped_query(&sql, &param)
+            .execute(&mut *tx)
+            .await
+            .with_context(|| format!("Failed to clear {} before restoring", scope.table()))?;
+        stats.deleted += result.rows_affected();
+    }
+    for table in &backup.tables {
+        let sql = format!(
+            "INSERT INTO {0} SELECT * FROM jsonb_populate_recordset(NULL::{0}, $1) \
+             ON CONFLICT DO NOTHING",
+            table.table
+        );
+        let result = sqlx::query(&sql)
+            .bind(Value::Array(table.rows.clone()))
+            .execute(&mut *tx)
+            .await
+            .with_context(|| format!("Failed to restore {}", table.table))?;
+        stats
+            .restored
+            .push((table.table.clone(), result.rows_affected()));
+    }
+    tx.commit().await?;
+    Ok(stats)
+}
+
+/// A query over a scope's filter, with its parameter bound
+fn scoped_query<'q>(sql: &'q str, param: &Option<Param<'q>>) -> Query<'q, Postgres, PgArguments> {
+    let query = sqlx::query(sql);
+    match param {
+        Some(Param::Int(value)) => query.bind(*value),
+        Some(Param::Text(value)) => query.bind(*value),
+        None => query,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn edge(child: &str, column: &str, parent: &str) -> CascadeEdge {
+        CascadeEdge {
+            child_table: child.to_string(),
+            child_column: column.to_string(),
+            parent_table: parent.to_string(),
+            parent_column: "id".to_string(),
+        }
+    }
+
+    fn edges() -> Vec<CascadeEdge> {
+        vec![
+            edge("scouting_reports", "player_id", "players"),
+            edge("scouting_reports", "team_id", "teams"),
+            edge("prospect_rankings", "player_id", "players"),
+            edge("prospect_rankings", "ranking_source_id", "ranking_sources"),
+            edge("team_needs", "team_id", "teams"),
+        ]
+    }
+
+    #[test]
+    fn test_plan_follows_cascades() {
+        let plans = plan(&Scope::Players { draft_year: 2026 }, &edges());
+        let tables: Vec<_> = plans.iter().map(|p| p.table.as_str()).collect();
+        assert_eq!(
+            tables,
+            vec!["players", "scouting_reports", "prospect_rankings"]
+        );
+        assert_eq!(
+            plans[1].filter,
+            "player_id IN (SELECT id FROM players WHERE draft_year = $1)"
+        );
+    }
+
+    #[test]
+    fn test_plan_without_cascades_is_the_scope_table() {
+        let plans = plan(
+            &Scope::Rankings {
+                source: "Tankathon".to_string(),
+            },
+            &edges(),
+        );
+        assert_eq!(plans.len(), 1);
+        assert_eq!(plans[0].table, "prospect_rankings");
+    }
+
+    #[test]
+    fn test_plan_merges_a_table_reached_twice() {
+        let mut edges = edges();
+        edges.push(edge("players", "school_id", "teams"));
+        let plans = plan(&Scope::Teams, &edges);
+        let tables: Vec<_> = plans.iter().map(|p| p.table.as_str()).collect();
+        assert_eq!(
+            tables,
+            vec![
+                "teams",
+                "team_needs",
+                "players",
+                "scouting_reports",
+                "prospect_rankings"
+            ]
+        );
+        // Reports of the cleared teams and of the cleared players
+        assert!(plans[3]
+            .filter
+            .contains("(team_id IN (SELECT id FROM teams WHERE TRUE)"));
+        assert!(plans[3].filter.contains(") OR ("));
+        assert!(plans[3]
+            .filter
+            .contains("(player_id IN (SELECT id FROM players WHERE school_id IN"));
+    }
+
+    #[test]
+    fn test_backup_round_trips_through_json() {
+        let backup = Backup {
+            name: "players-clear-2026".to_string(),
+            created_at: "2026-10-17T08:00:00Z".parse().unwrap(),
+            scopes: vec![Scope::Players { draft_year: 2026 }, Scope::Teams],
+            tables: vec![TableRows {
+                table: "players".to_string(),
+                rows: vec![serde_json::json!({ "first_name": "Caleb" })],
+            }],
+        };
+        assert_eq!(
+            backup.file_name(),
+            "players-clear-2026-20261017T080000.json"
+        );
+        let source = Backup {
+            name: "rankings-clear-Walter Football/../x".to_string(),
+            ..backup.clone()
+        };
+        assert_eq!(
+            source.file_name(),
+            "rankings-clear-walter-football----x-20261017T080000.json"
+        );
+
+        let json = serde_json::to_string(&backup).unwrap();
+        assert!(json.contains(r#""scope":"players","draft_year":2026"#));
+        let parsed: Backup = serde_json::from_str(&json).unwrap();
+        assert_eq!(parsed.scopes, backup.scopes);
+        assert_eq!(parsed.row_count(), 1);
+    }
+}
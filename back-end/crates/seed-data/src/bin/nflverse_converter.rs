@@ -1,6 +1,7 @@
 use anyhow::Result;
 use clap::Parser;
 use seed_data::nflverse_converter::{convert_csv_file, write_combine_json};
+use seed_data::position_mapper;
 
 /// Convert nflverse combine CSV data to the project's combine JSON format.
 ///
@@ -30,11 +31,19 @@ struct Args {
     /// Source field value (combine or pro_day)
     #[arg(short, long, default_value = "combine", value_parser = ["combine", "pro_day"])]
     source: String,
+
+    /// JSON file of position aliases that add to or override the built-in ones
+    #[arg(long, value_name = "FILE")]
+    position_map: Option<String>,
 }
 
 fn main() -> Result<()> {
     let args = Args::parse();
 
+    if let Some(path) = &args.position_map {
+        position_mapper::install_position_map(position_mapper::parse_position_map_file(path)?)?;
+    }
+
     println!("nflverse Combine CSV Converter");
     println!("  Input:  {}", args.input);
     println!("  Output: {}", args.output);
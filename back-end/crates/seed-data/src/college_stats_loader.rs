@@ -0,0 +1,338 @@
+use std::collections::{HashMap, HashSet};
+
+use anyhow::Result;
+use domain::models::{CollegeStats, DefenseLine, PassingLine, Player, ReceivingLine, RushingLine};
+use domain::repositories::{CollegeStatsRepository, PlayerRepository};
+use serde::Deserialize;
+use uuid::Uuid;
+
+use crate::rankings_loader::normalize_name;
+
+#[derive(Debug, Deserialize)]
+pub struct CollegeStatsData {
+    pub meta: CollegeStatsMeta,
+    pub players: Vec<CollegeStatsPlayerEntry>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct CollegeStatsMeta {
+    pub draft_year: i32,
+    pub source: String,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct CollegeStatsPlayerEntry {
+    pub first_name: String,
+    pub last_name: String,
+    pub seasons: Vec<CollegeSeasonEntry>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct CollegeSeasonEntry {
+    pub season: i32,
+    pub school: Option<String>,
+    pub games_played: i32,
+    pub passing: Option<PassingLine>,
+    pub rushing: Option<RushingLine>,
+    pub receiving: Option<ReceivingLine>,
+    pub defense: Option<DefenseLine>,
+}
+
+impl CollegeSeasonEntry {
+    pub fn to_domain(&self, player_id: Uuid) -> Result<CollegeStats> {
+        let mut stats = CollegeStats::new(player_id, self.season, self.games_played)?;
+        if let Some(school) = &self.school {
+            stats = stats.with_school(school.clone())?;
+        }
+        if let Some(line) = &self.passing {
+            stats = stats.with_passing(line.clone())?;
+        }
+        if let Some(line) = &self.rushing {
+            stats = stats.with_rushing(line.clone())?;
+        }
+        if let Some(line) = &self.receiving {
+            stats = stats.with_receiving(line.clone())?;
+        }
+        if let Some(line) = &self.defense {
+            stats = stats.with_defense(line.clone())?;
+        }
+        Ok(stats)
+    }
+}
+
+pub fn parse_college_stats_file(path: &str) -> Result<CollegeStatsData> {
+    let content = std::fs::read_to_string(path)?;
+    parse_college_stats_json(&content)
+}
+
+pub fn parse_college_stats_json(json: &str) -> Result<CollegeStatsData> {
+    let data: CollegeStatsData = serde_json::from_str(json)?;
+    Ok(data)
+}
+
+/// Checks that don't need the database: every season passes domain
+/// validation, and no player or player-season is listed twice
+pub fn validate_college_stats(data: &CollegeStatsData) -> Vec<String> {
+    let mut errors = Vec::new();
+    let mut players = HashSet::new();
+
+    for entry in &data.players {
+        let name = format!("{} {}", entry.first_name, entry.last_name);
+        if !players.insert((
+            normalize_name(&entry.first_name),
+            normalize_name(&entry.last_name),
+        )) {
+            errors.push(format!("{} is listed twice", name));
+        }
+
+        let mut seasons = HashSet::new();
+        for season in &entry.seasons {
+            if !seasons.insert(season.season) {
+                errors.push(format!(
+                    "{}: season {} is listed twice",
+                    name, season.season
+                ));
+            }
+            if let Err(e) = season.to_domain(Uuid::nil()) {
+                errors.push(format!("{}: season {}: {}", name, season.season, e));
+            }
+        }
+    }
+
+    errors
+}
+
+#[derive(Debug, Default)]
+pub struct CollegeStatsLoadStats {
+    pub players_matched: usize,
+    pub players_unmatched: usize,
+    pub seasons_upserted: usize,
+    pub errors: Vec<String>,
+    pub unmatched_names: Vec<String>,
+}
+
+impl CollegeStatsLoadStats {
+    pub fn print_summary(&self) {
+        println!("\nCollege Stats Load Summary:");
+        println!("  Players matched:    {}", self.players_matched);
+        println!("  Players unmatched:  {}", self.players_unmatched);
+        println!("  Seasons upserted:   {}", self.seasons_upserted);
+        println!("  Errors:             {}", self.errors.len());
+
+        if !self.unmatched_names.is_empty() {
+            println!("\nUnmatched players (not found in database):");
+            for name in &self.unmatched_names {
+                println!("  - {}", name);
+            }
+        }
+
+        if !self.errors.is_empty() {
+            println!("\nErrors:");
+            for error in &self.errors {
+                println!("  - {}", error);
+            }
+        }
+    }
+}
+
+pub fn load_college_stats_dry_run(data: &CollegeStatsData) -> Result<CollegeStatsLoadStats> {
+    let mut stats = CollegeStatsLoadStats {
+        errors: validate_college_stats(data),
+        ..Default::default()
+    };
+
+    println!(
+        "[DRY RUN] Would load college stats for {} players (draft year {}) from '{}'",
+        data.players.len(),
+        data.meta.draft_year,
+        data.meta.source
+    );
+
+    for entry in &data.players {
+        println!(
+            "[DRY RUN] {} {}: {} seasons",
+            entry.first_name,
+            entry.last_name,
+            entry.seasons.len()
+        );
+        stats.players_matched += 1;
+        stats.seasons_upserted += entry.seasons.len();
+    }
+
+    Ok(stats)
+}
+
+/// Upsert each listed season for players in the file's draft class. Players
+/// who can't be matched by name are reported and skipped; nothing is written
+/// if the file fails validation.
+pub async fn load_college_stats(
+    data: &CollegeStatsData,
+    player_repo: &dyn PlayerRepository,
+    stats_repo: &dyn CollegeStatsRepository,
+) -> Result<CollegeStatsLoadStats> {
+    let mut stats = CollegeStatsLoadStats {
+        errors: validate_college_stats(data),
+        ..Default::default()
+    };
+    if !stats.errors.is_empty() {
+        return Ok(stats);
+    }
+
+    println!(
+        "Loading college stats for {} players (draft year {})...",
+        data.players.len(),
+        data.meta.draft_year
+    );
+
+    let players = player_repo
+        .find_by_draft_year(data.meta.draft_year)
+        .await
+        .map_err(|e| anyhow::anyhow!("Failed to fetch players: {}", e))?;
+    let player_map: HashMap<(String, String), &Player> = players
+        .iter()
+        .map(|p| {
+            (
+                (normalize_name(&p.first_name), normalize_name(&p.last_name)),
+                p,
+            )
+        })
+        .collect();
+
+    for entry in &data.players {
+        let Some(player) = player_map.get(&(
+            normalize_name(&entry.first_name),
+            normalize_name(&entry.last_name),
+        )) else {
+            let name = format!("{} {}", entry.first_name, entry.last_name);
+            tracing::warn!("No matching player for {}", name);
+            stats.players_unmatched += 1;
+            stats.unmatched_names.push(name);
+            continue;
+        };
+        stats.players_matched += 1;
+
+        for season in &entry.seasons {
+            let college_stats = season.to_domain(player.id)?;
+            match stats_repo.upsert(&college_stats).await {
+                Ok(_) => stats.seasons_upserted += 1,
+                Err(e) => {
+                    let msg = format!(
+                        "Failed to upsert {} {} season {}: {}",
+                        entry.first_name, entry.last_name, season.season, e
+                    );
+                    tracing::error!("{}", msg);
+                    stats.errors.push(msg);
+                }
+            }
+        }
+    }
+
+    println!(
+        "  Matched {} players, upserted {} seasons",
+        stats.players_matched, stats.seasons_upserted
+    );
+
+    Ok(stats)
+}
+
+pub async fn clear_college_stats(
+    draft_year: i32,
+    stats_repo: &dyn CollegeStatsRepository,
+) -> Result<u64> {
+    let deleted = stats_repo
+        .delete_by_draft_year(draft_year)
+        .await
+        .map_err(|e| anyhow::anyhow!("Failed to delete college stats: {}", e))?;
+    Ok(deleted)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample_json() -> &'static str {
+        r#"{
+            "meta": { "draft_year": 2026, "source": "Sports Reference" },
+            "players": [
+                {
+                    "first_name": "Jeremiyah",
+                    "last_name": "Love",
+                    "seasons": [
+                        {
+                            "season": 2024,
+                            "games_played": 16,
+                            "rushing": { "attempts": 163, "yards": 1125, "touchdowns": 17 },
+                            "receiving": { "receptions": 28, "yards": 237, "touchdowns": 2 }
+                        },
+                        {
+                            "season": 2025,
+                            "games_played": 12,
+                            "rushing": { "attempts": 199, "yards": 1372, "touchdowns": 18 }
+                        }
+                    ]
+                },
+                {
+                    "first_name": "Caleb",
+                    "last_name": "Downs",
+                    "seasons": [
+                        {
+                            "season": 2025,
+                            "school": "Ohio State",
+                            "games_played": 14,
+                            "defense": {
+                                "tackles": 68,
+                                "tackles_for_loss": 4.5,
+                                "sacks": 1.0,
+                                "interceptions": 2,
+                                "passes_defended": 6,
+                                "forced_fumbles": 0
+                            }
+                        }
+                    ]
+                }
+            ]
+        }"#
+    }
+
+    #[test]
+    fn test_parse_college_stats_json() {
+        let data = parse_college_stats_json(sample_json()).unwrap();
+        assert_eq!(data.meta.draft_year, 2026);
+        assert_eq!(data.players.len(), 2);
+        assert_eq!(data.players[0].seasons.len(), 2);
+        assert_eq!(
+            data.players[0].seasons[0].rushing.as_ref().unwrap().yards,
+            1125
+        );
+        assert!(data.players[0].seasons[1].receiving.is_none());
+        assert_eq!(
+            data.players[1].seasons[0].defense.as_ref().unwrap().sacks,
+            1.0
+        );
+    }
+
+    #[test]
+    fn test_parse_college_stats_json_invalid() {
+        assert!(parse_college_stats_json("not valid json").is_err());
+    }
+
+    #[test]
+    fn test_validate_college_stats() {
+        let mut data = parse_college_stats_json(sample_json()).unwrap();
+        assert!(validate_college_stats(&data).is_empty());
+
+        data.players[0].seasons[1].season = 2024;
+        data.players[1].seasons[0].games_played = 20;
+        let errors = validate_college_stats(&data);
+        assert_eq!(errors.len(), 2);
+    }
+
+    #[test]
+    fn test_dry_run() {
+        let data = parse_college_stats_json(sample_json()).unwrap();
+        let stats = load_college_stats_dry_run(&data).unwrap();
+        assert_eq!(stats.players_matched, 2);
+        assert_eq!(stats.seasons_upserted, 3);
+        assert!(stats.errors.is_empty());
+    }
+}
@@ -1,6 +1,7 @@
 use std::collections::HashMap;
 
 use anyhow::Result;
+use chrono::NaiveDate;
 use serde::{Deserialize, Serialize};
 
 use domain::models::{CombineResults, CombineSource, Player};
@@ -28,6 +29,9 @@ pub struct CombineFileEntry {
     pub position: String,
     pub source: String,
     pub year: i32,
+    /// Day of the workout; needed to load more than one pro day per player
+    #[serde(default)]
+    pub event_date: Option<NaiveDate>,
     pub forty_yard_dash: Option<f64>,
     pub bench_press: Option<i32>,
     pub vertical_jump: Option<f64>,
@@ -47,6 +51,8 @@ pub struct CombineLoadStats {
     pub skipped_no_data: usize,
     pub player_not_found: usize,
     pub players_discovered: usize,
+    /// New players skipped for an unmapped position
+    pub warnings: Vec<String>,
     pub errors: Vec<String>,
 }
 
@@ -58,6 +64,12 @@ impl CombineLoadStats {
         println!("  Skipped (no data):   {}", self.skipped_no_data);
         println!("  Player not found:    {}", self.player_not_found);
         println!("  Players discovered:  {}", self.players_discovered);
+        if !self.warnings.is_empty() {
+            println!("  Warnings: {}", self.warnings.len());
+            for warning in &self.warnings {
+                println!("    - {}", warning);
+            }
+        }
         if !self.errors.is_empty() {
             println!("  Errors: {}", self.errors.len());
             for err in &self.errors {
@@ -101,6 +113,7 @@ pub async fn load_combine_data(
     let mut skipped_no_data = 0;
     let mut player_not_found = 0;
     let mut players_discovered = 0;
+    let mut warnings: Vec<String> = Vec::new();
     let mut errors: Vec<String> = Vec::new();
 
     // Load all players and build a normalized name lookup map
@@ -139,13 +152,10 @@ pub async fn load_combine_data(
             let position = match map_position(&entry.position) {
                 Ok(p) => p,
                 Err(e) => {
-                    let msg = format!(
-                        "Skipping {} {} - unknown position '{}': {}",
-                        entry.first_name, entry.last_name, entry.position, e
-                    );
+                    let msg = format!("Skipping {} {}: {}", entry.first_name, entry.last_name, e);
                     tracing::warn!("{}", msg);
                     player_not_found += 1;
-                    errors.push(msg);
+                    warnings.push(msg);
                     continue;
                 }
             };
@@ -187,14 +197,18 @@ pub async fn load_combine_data(
             player_id
         };
 
-        // Check if combine results already exist for this player/year/source
-        let existing = combine_repo
-            .find_by_player_year_source(player_id, entry.year, &entry.source)
-            .await;
+        // Check if combine results already exist for this player/year/source/date
+        let existing = combine_repo.find_by_player_id(player_id).await;
 
-        if let Ok(Some(_)) = existing {
-            skipped += 1;
-            continue;
+        if let Ok(existing) = existing {
+            if existing.iter().any(|r| {
+                r.year == entry.year
+                    && r.source.to_string() == entry.source
+                    && r.event_date == entry.event_date
+            }) {
+                skipped += 1;
+                continue;
+            }
         }
 
         // Parse source
@@ -211,7 +225,10 @@ pub async fn load_combine_data(
 
         // Build combine results
         let mut results = match CombineResults::new(player_id, entry.year) {
-            Ok(r) => r.with_source(source),
+            Ok(r) => match entry.event_date {
+                Some(date) => r.with_source(source).with_event_date(date),
+                None => r.with_source(source),
+            },
             Err(e) => {
                 errors.push(format!(
                     "Failed to create results for {} {}: {}",
@@ -395,6 +412,7 @@ pub async fn load_combine_data(
         skipped_no_data,
         player_not_found,
         players_discovered,
+        warnings,
         errors,
     })
 }
@@ -439,6 +457,7 @@ pub fn load_combine_data_dry_run(data: &CombineFileData) -> Result<CombineLoadSt
         skipped_no_data,
         player_not_found: 0,
         players_discovered: 0,
+        warnings: Vec::new(),
         errors,
     })
 }
@@ -479,4 +498,41 @@ mod tests {
         assert_eq!(data.combine_results[0].forty_yard_dash, Some(4.72));
         assert!(data.combine_results[0].three_cone_drill.is_none());
     }
+
+    #[test]
+    fn test_parse_pro_day_entry() {
+        // Shape written by `bun run scrape pro-day`
+        let json = r#"{
+            "meta": { "source": "pro_day", "year": 2026, "description": "test", "generated_at": "2026-03-20", "player_count": 1, "entry_count": 1 },
+            "combine_results": [
+                {
+                    "first_name": "Kaden",
+                    "last_name": "Wetjen",
+                    "position": "WR",
+                    "source": "pro_day",
+                    "year": 2026,
+                    "forty_yard_dash": 4.41,
+                    "bench_press": null,
+                    "vertical_jump": 38.5,
+                    "broad_jump": 125,
+                    "three_cone_drill": null,
+                    "twenty_yard_shuttle": null,
+                    "arm_length": null,
+                    "hand_size": null,
+                    "wingspan": null,
+                    "ten_yard_split": null,
+                    "twenty_yard_split": null,
+                    "event_date": "2026-03-23"
+                }
+            ]
+        }"#;
+
+        let data = parse_combine_json(json).unwrap();
+        let entry = &data.combine_results[0];
+        assert_eq!(
+            entry.source.parse::<CombineSource>().unwrap(),
+            CombineSource::ProDay
+        );
+        assert_eq!(entry.event_date, NaiveDate::from_ymd_opt(2026, 3, 23));
+    }
 }
@@ -0,0 +1,112 @@
+//! Shared CSV reading for loaders that also accept spreadsheet exports
+//!
+//! A CSV file has a header row naming its columns, one entry per row, and
+//! empty cells for missing optional values. Values a JSON file keeps in its
+//! `meta` block, such as the draft year, become columns that must hold the
+//! same value in every row. See `data/README.md` for each loader's columns.
+
+use std::fmt::Display;
+use std::path::Path;
+
+use anyhow::{bail, Context, Result};
+use serde::de::DeserializeOwned;
+
+/// Whether a data file should be read as CSV, going by its extension
+pub fn is_csv(file_path: &str) -> bool {
+    Path::new(file_path)
+        .extension()
+        .is_some_and(|ext| ext.eq_ignore_ascii_case("csv"))
+}
+
+/// Reads every row of CSV content, naming the line of the first bad row
+pub fn read_rows<T: DeserializeOwned>(content: &str) -> Result<Vec<T>> {
+    let mut reader = csv::ReaderBuilder::new()
+        .trim(csv::Trim::All)
+        .from_reader(content.as_bytes());
+    let mut rows = Vec::new();
+    for result in reader.deserialize() {
+        let row: T = result.map_err(|e| match e.position() {
+            Some(position) => anyhow::anyhow!("Invalid CSV row on line {}: {}", position.line(), e),
+            None => anyhow::anyhow!("Invalid CSV: {}", e),
+        })?;
+        rows.push(row);
+    }
+    Ok(rows)
+}
+
+/// The value every row holds in a per-file column such as the draft year.
+/// Fails when there are no rows or the rows disagree.
+pub fn single_value<T: PartialEq + Display>(
+    column: &str,
+    values: impl IntoIterator<Item = T>,
+) -> Result<T> {
+    let mut values = values.into_iter();
+    let first = values
+        .next()
+        .with_context(|| format!("CSV file has no rows to read `{}` from", column))?;
+    if let Some(other) = values.find(|value| *value != first) {
+        bail!(
+            "Column `{}` must hold the same value in every row, found both {} and {}",
+            column,
+            first,
+            other
+        );
+    }
+    Ok(first)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde::Deserialize;
+
+    #[derive(Debug, Deserialize, PartialEq)]
+    struct Row {
+        name: String,
+        year: i32,
+        height: Option<i32>,
+    }
+
+    #[test]
+    fn test_is_csv() {
+        assert!(is_csv("data/players.csv"));
+        assert!(is_csv("Players.CSV"));
+        assert!(!is_csv("data/players_2026.json"));
+        assert!(!is_csv("data/csv"));
+    }
+
+    #[test]
+    fn test_read_rows_treats_empty_cells_as_missing() {
+        let rows: Vec<Row> =
+            read_rows("name,year,height\nCam Ward, 2026 ,74\nTravis Hunter,2026,\n").unwrap();
+        assert_eq!(
+            rows,
+            vec![
+                Row {
+                    name: "Cam Ward".to_string(),
+                    year: 2026,
+                    height: Some(74),
+                },
+                Row {
+                    name: "Travis Hunter".to_string(),
+                    year: 2026,
+                    height: None,
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_read_rows_names_the_bad_line() {
+        let err = read_rows::<Row>("name,year,height\nCam Ward,2026,74\nTravis Hunter,soon,\n")
+            .unwrap_err();
+        assert!(err.to_string().contains("line 3"), "{}", err);
+    }
+
+    #[test]
+    fn test_single_value() {
+        assert_eq!(single_value("year", [2026, 2026]).unwrap(), 2026);
+        assert!(single_value("year", [2026, 2027]).is_err());
+        assert!(single_value::<i32>("year", []).is_err());
+    }
+}
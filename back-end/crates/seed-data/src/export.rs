@@ -0,0 +1,369 @@
+//! Writes database rows back out as seed files
+//!
+//! Each export produces the JSON schema its loader reads, so curation done
+//! in the database can be committed and loaded again elsewhere. Rows are
+//! written in a stable order (teams by division and name, players by name,
+//! team seasons by draft position) to keep diffs between exports small.
+
+use std::collections::HashMap;
+
+use anyhow::{Context, Result};
+use chrono::Utc;
+use domain::models::{Conference, Division, InjuryStatus, Player, Team, TeamNeed, TeamSeason};
+use domain::repositories::{
+    PlayerRepository, TeamNeedRepository, TeamRepository, TeamSeasonRepository,
+};
+use serde::Serialize;
+use uuid::Uuid;
+
+use crate::loader::{MetaData, PlayerData, PlayerEntry};
+use crate::team_loader::{TeamData, TeamEntry, TeamMetaData};
+use crate::team_need_loader::{PositionalNeed, TeamNeedData, TeamNeedEntry, TeamNeedMetaData};
+use crate::team_season_loader::{TeamSeasonData, TeamSeasonEntry, TeamSeasonMetaData};
+
+/// Schema version written to every exported file's `meta` block
+const EXPORT_VERSION: &str = "1.0.0";
+
+/// Source recorded in every exported file's `meta` block
+const EXPORT_SOURCE: &str = "Database export";
+
+pub async fn export_players(year: i32, repo: &dyn PlayerRepository) -> Result<PlayerData> {
+    let players = repo.find_by_draft_year(year).await?;
+    Ok(players_file(year, players))
+}
+
+pub async fn export_teams(repo: &dyn TeamRepository) -> Result<TeamData> {
+    let teams = repo.find_all().await?;
+    Ok(teams_file(teams))
+}
+
+pub async fn export_team_needs(
+    team_repo: &dyn TeamRepository,
+    team_need_repo: &dyn TeamNeedRepository,
+) -> Result<TeamNeedData> {
+    let mut teams = team_repo.find_all().await?;
+    sort_teams(&mut teams);
+
+    let mut needs = Vec::with_capacity(teams.len());
+    for team in teams {
+        let team_needs = team_need_repo.find_by_team_id(team.id).await?;
+        needs.push((team, team_needs));
+    }
+    Ok(team_needs_file(needs))
+}
+
+pub async fn export_team_seasons(
+    year: i32,
+    team_repo: &dyn TeamRepository,
+    team_season_repo: &dyn TeamSeasonRepository,
+) -> Result<TeamSeasonData> {
+    let teams = team_repo.find_all().await?;
+    let seasons = team_season_repo
+        .find_by_year_ordered_by_draft_position(year)
+        .await?;
+    team_seasons_file(year, &teams, seasons)
+}
+
+/// Writes an exported file as pretty-printed JSON
+pub fn write_json_file<T: Serialize>(data: &T, output_path: &str) -> Result<()> {
+    let json = serde_json::to_string_pretty(data).context("Failed to serialize export to JSON")?;
+    std::fs::write(output_path, json + "\n")
+        .with_context(|| format!("Failed to write output file: {}", output_path))?;
+    Ok(())
+}
+
+fn players_file(year: i32, mut players: Vec<Player>) -> PlayerData {
+    players.sort_by(|a, b| (&a.last_name, &a.first_name).cmp(&(&b.last_name, &b.first_name)));
+
+    let players: Vec<PlayerEntry> = players
+        .into_iter()
+        .map(|player| PlayerEntry {
+            first_name: player.first_name,
+            last_name: player.last_name,
+            position: format!("{:?}", player.position),
+            college: player.college,
+            height_inches: player.height_inches,
+            weight_pounds: player.weight_pounds,
+            injury_status: (player.injury_status != InjuryStatus::Healthy)
+                .then(|| player.injury_status.to_string()),
+            injury_body_part: player.injury_body_part,
+            injury_expected_return: player.injury_expected_return,
+            medical_flag: player.medical_flag,
+            notes: None,
+        })
+        .collect();
+
+    PlayerData {
+        meta: MetaData {
+            version: EXPORT_VERSION.to_string(),
+            draft_year: year,
+            last_updated: today(),
+            sources: vec![EXPORT_SOURCE.to_string()],
+            total_players: players.len(),
+        },
+        players,
+    }
+}
+
+fn teams_file(mut teams: Vec<Team>) -> TeamData {
+    sort_teams(&mut teams);
+
+    let teams: Vec<TeamEntry> = teams
+        .into_iter()
+        .map(|team| TeamEntry {
+            conference: conference_name(&team.conference).to_string(),
+            division: division_name(&team.division).to_string(),
+            name: team.name,
+            abbreviation: team.abbreviation,
+            city: team.city,
+        })
+        .collect();
+
+    TeamData {
+        meta: TeamMetaData {
+            version: EXPORT_VERSION.to_string(),
+            last_updated: today(),
+            sources: vec![EXPORT_SOURCE.to_string()],
+            total_teams: teams.len(),
+        },
+        teams,
+    }
+}
+
+/// Teams without needs are left out, as the loader would have nothing to
+/// insert for them
+fn team_needs_file(needs: Vec<(Team, Vec<TeamNeed>)>) -> TeamNeedData {
+    let team_needs: Vec<TeamNeedEntry> = needs
+        .into_iter()
+        .filter(|(_, needs)| !needs.is_empty())
+        .map(|(team, mut needs)| {
+            needs.sort_by_key(|need| need.priority);
+            TeamNeedEntry {
+                team_abbreviation: team.abbreviation,
+                needs: needs
+                    .into_iter()
+                    .map(|need| PositionalNeed {
+                        position: format!("{:?}", need.position),
+                        priority: need.priority,
+                    })
+                    .collect(),
+            }
+        })
+        .collect();
+
+    TeamNeedData {
+        meta: TeamNeedMetaData {
+            version: EXPORT_VERSION.to_string(),
+            last_updated: today(),
+            sources: vec![EXPORT_SOURCE.to_string()],
+            total_teams: team_needs.len(),
+            description: "Positional needs by team priority (1=highest)".to_string(),
+        },
+        team_needs,
+    }
+}
+
+fn team_seasons_file(
+    year: i32,
+    teams: &[Team],
+    seasons: Vec<TeamSeason>,
+) -> Result<TeamSeasonData> {
+    let abbreviations: HashMap<Uuid, &str> = teams
+        .iter()
+        .map(|team| (team.id, team.abbreviation.as_str()))
+        .collect();
+
+    let team_seasons = seasons
+        .into_iter()
+        .map(|season| {
+            let abbreviation = abbreviations
+                .get(&season.team_id)
+                .with_context(|| format!("No team with id {} for a season", season.team_id))?;
+            Ok(TeamSeasonEntry {
+                team_abbreviation: abbreviation.to_string(),
+                wins: season.wins,
+                losses: season.losses,
+                ties: season.ties,
+                playoff_result: season.playoff_result.map(|result| result.to_string()),
+                draft_position: season.draft_position,
+            })
+        })
+        .collect::<Result<Vec<_>>>()?;
+
+    Ok(TeamSeasonData {
+        meta: TeamSeasonMetaData {
+            version: EXPORT_VERSION.to_string(),
+            last_updated: today(),
+            sources: vec![EXPORT_SOURCE.to_string()],
+            season_year: year,
+            total_teams: team_seasons.len(),
+        },
+        team_seasons,
+    })
+}
+
+fn sort_teams(teams: &mut [Team]) {
+    teams.sort_by(|a, b| {
+        (division_name(&a.division), &a.name).cmp(&(division_name(&b.division), &b.name))
+    });
+}
+
+fn today() -> String {
+    Utc::now().format("%Y-%m-%d").to_string()
+}
+
+/// Inverse of `team_loader::map_conference`
+fn conference_name(conference: &Conference) -> &'static str {
+    match conference {
+        Conference::AFC => "AFC",
+        Conference::NFC => "NFC",
+    }
+}
+
+/// Inverse of `team_loader::map_division`
+fn division_name(division: &Division) -> &'static str {
+    match division {
+        Division::AFCEast => "AFC East",
+        Division::AFCNorth => "AFC North",
+        Division::AFCSouth => "AFC South",
+        Division::AFCWest => "AFC West",
+        Division::NFCEast => "NFC East",
+        Division::NFCNorth => "NFC North",
+        Division::NFCSouth => "NFC South",
+        Division::NFCWest => "NFC West",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::loader::parse_player_json;
+    use crate::team_loader::parse_team_json;
+    use crate::team_season_loader::parse_team_season_json;
+    use domain::models::{PlayoffResult, Position};
+
+    fn team(name: &str, abbreviation: &str, conference: Conference, division: Division) -> Team {
+        Team::new(
+            name.to_string(),
+            abbreviation.to_string(),
+            "City".to_string(),
+            conference,
+            division,
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn test_exported_players_load_back() {
+        let hunter = Player::new(
+            "Travis".to_string(),
+            "Hunter".to_string(),
+            Position::CB,
+            2026,
+        )
+        .unwrap()
+        .with_college("Colorado".to_string())
+        .unwrap()
+        .with_physical_stats(73, 185)
+        .unwrap();
+        let ward = Player::new("Cam".to_string(), "Ward".to_string(), Position::QB, 2026)
+            .unwrap()
+            .with_injury(InjuryStatus::Questionable, Some("ankle".to_string()), None)
+            .unwrap()
+            .with_medical_flag(true);
+
+        let json = serde_json::to_string(&players_file(2026, vec![ward, hunter])).unwrap();
+        let data = parse_player_json(&json).unwrap();
+
+        assert_eq!(data.meta.draft_year, 2026);
+        assert_eq!(data.meta.total_players, 2);
+        let loaded = data.players[0].to_domain(2026).unwrap();
+        assert_eq!(loaded.last_name, "Hunter");
+        assert_eq!(loaded.college.as_deref(), Some("Colorado"));
+        assert_eq!(loaded.weight_pounds, Some(185));
+        let loaded = data.players[1].to_domain(2026).unwrap();
+        assert_eq!(loaded.position, Position::QB);
+        assert_eq!(loaded.injury_status, InjuryStatus::Questionable);
+        assert_eq!(loaded.injury_body_part.as_deref(), Some("ankle"));
+        assert!(loaded.medical_flag);
+        assert!(!json.contains("notes"));
+    }
+
+    #[test]
+    fn test_exported_teams_load_back_in_division_order() {
+        let teams = vec![
+            team("Dallas Cowboys", "DAL", Conference::NFC, Division::NFCEast),
+            team("Miami Dolphins", "MIA", Conference::AFC, Division::AFCEast),
+            team("Buffalo Bills", "BUF", Conference::AFC, Division::AFCEast),
+        ];
+
+        let json = serde_json::to_string(&teams_file(teams)).unwrap();
+        let data = parse_team_json(&json).unwrap();
+
+        let abbreviations: Vec<&str> = data.teams.iter().map(|t| t.abbreviation.as_str()).collect();
+        assert_eq!(abbreviations, vec!["BUF", "MIA", "DAL"]);
+        let loaded = data.teams[2].to_domain().unwrap();
+        assert_eq!(loaded.conference, Conference::NFC);
+        assert_eq!(loaded.division, Division::NFCEast);
+    }
+
+    #[test]
+    fn test_exported_team_needs_load_back_by_priority() {
+        let buf = team("Buffalo Bills", "BUF", Conference::AFC, Division::AFCEast);
+        let mia = team("Miami Dolphins", "MIA", Conference::AFC, Division::AFCEast);
+        let needs = vec![
+            TeamNeed::new(buf.id, Position::DE, 2).unwrap(),
+            TeamNeed::new(buf.id, Position::CB, 1).unwrap(),
+        ];
+
+        let json =
+            serde_json::to_string(&team_needs_file(vec![(buf, needs), (mia, vec![])])).unwrap();
+        let data: TeamNeedData = serde_json::from_str(&json).unwrap();
+
+        assert_eq!(data.team_needs.len(), 1);
+        assert_eq!(data.team_needs[0].team_abbreviation, "BUF");
+        let positions: Vec<&str> = data.team_needs[0]
+            .needs
+            .iter()
+            .map(|n| n.position.as_str())
+            .collect();
+        assert_eq!(positions, vec!["CB", "DE"]);
+    }
+
+    #[test]
+    fn test_exported_team_seasons_load_back() {
+        let lv = team(
+            "Las Vegas Raiders",
+            "LV",
+            Conference::AFC,
+            Division::AFCWest,
+        );
+        let season = TeamSeason::new(
+            lv.id,
+            2025,
+            3,
+            14,
+            0,
+            Some(PlayoffResult::MissedPlayoffs),
+            Some(1),
+        )
+        .unwrap();
+
+        let data = team_seasons_file(2025, &[lv], vec![season]).unwrap();
+        let json = serde_json::to_string(&data).unwrap();
+        let data = parse_team_season_json(&json).unwrap();
+
+        assert_eq!(data.meta.season_year, 2025);
+        assert_eq!(data.team_seasons[0].team_abbreviation, "LV");
+        assert_eq!(
+            data.team_seasons[0].playoff_result.as_deref(),
+            Some("MissedPlayoffs")
+        );
+    }
+
+    #[test]
+    fn test_team_season_export_needs_the_team() {
+        let season = TeamSeason::new(Uuid::new_v4(), 2025, 10, 7, 0, None, None).unwrap();
+        assert!(team_seasons_file(2025, &[], vec![season]).is_err());
+    }
+}
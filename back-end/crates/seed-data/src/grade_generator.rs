@@ -1,4 +1,8 @@
-use domain::models::{FitGrade, ScoutingReport};
+use std::collections::HashMap;
+
+use anyhow::{bail, Result};
+use domain::models::{FitGrade, Position, ScoutingReport};
+use serde::Deserialize;
 
 /// FNV-1a hash for deterministic, Rust-version-stable hashing.
 ///
@@ -64,6 +68,166 @@ fn team_grade_variance(consensus_grade: f64) -> f64 {
     }
 }
 
+/// Percent chance of each concern flag when a profile doesn't set one
+const DEFAULT_CONCERN_PCT: u64 = 5;
+
+/// How one team's scouting staff departs from the league-wide grading.
+///
+/// The default profile reproduces the unprofiled generators exactly, so a
+/// load without a profiles file produces the same reports as before.
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct GradeProfile {
+    /// Added to every grade: positive for an optimistic staff, negative for
+    /// a pessimistic one
+    pub bias: f64,
+    /// Multiplies the ± half-range from `team_grade_variance`; above 1.0 the
+    /// staff disagrees with consensus more, below 1.0 it follows it closely
+    pub variance_scale: f64,
+    /// Extra grade for prospects at a position, e.g. `{"QB": 0.4, "RB": -0.3}`
+    pub position_bias: HashMap<Position, f64>,
+    /// Percent chance of flagging an injury concern
+    pub injury_concern_pct: u64,
+    /// Percent chance of flagging a character concern
+    pub character_concern_pct: u64,
+}
+
+impl Default for GradeProfile {
+    fn default() -> Self {
+        Self {
+            bias: 0.0,
+            variance_scale: 1.0,
+            position_bias: HashMap::new(),
+            injury_concern_pct: DEFAULT_CONCERN_PCT,
+            character_concern_pct: DEFAULT_CONCERN_PCT,
+        }
+    }
+}
+
+impl GradeProfile {
+    /// Team grade for a prospect: the consensus grade shifted by the
+    /// profile's overall and positional bias, plus the deterministic noise
+    /// of `generate_team_grade` scaled by `variance_scale`
+    pub fn team_grade(
+        &self,
+        consensus_grade: f64,
+        position: Position,
+        team_abbr: &str,
+        first: &str,
+        last: &str,
+    ) -> f64 {
+        let position_bias = self.position_bias.get(&position).copied().unwrap_or(0.0);
+        let offset = team_grade_offset(consensus_grade, team_abbr, first, last);
+        (consensus_grade + self.bias + position_bias + offset * self.variance_scale)
+            .clamp(0.0, 10.0)
+    }
+
+    /// Injury and character concern flags drawn with this profile's odds
+    pub fn concern_flags(&self, team_abbr: &str, first: &str, last: &str) -> (bool, bool) {
+        let injury_key = format!("injury-{}-{}-{}", team_abbr, first, last);
+        let injury_hash = fnv1a_hash(injury_key.as_bytes());
+
+        let character_key = format!("character-{}-{}-{}", team_abbr, first, last);
+        let character_hash = fnv1a_hash(character_key.as_bytes());
+
+        (
+            injury_hash % 100 < self.injury_concern_pct,
+            character_hash % 100 < self.character_concern_pct,
+        )
+    }
+
+    fn validate(&self, name: &str) -> Vec<String> {
+        let mut errors = Vec::new();
+        if !(-2.0..=2.0).contains(&self.bias) {
+            errors.push(format!(
+                "{}: bias {} is outside -2.0..=2.0",
+                name, self.bias
+            ));
+        }
+        if !(0.0..=3.0).contains(&self.variance_scale) {
+            errors.push(format!(
+                "{}: variance_scale {} is outside 0.0..=3.0",
+                name, self.variance_scale
+            ));
+        }
+        for (position, bias) in &self.position_bias {
+            if !(-2.0..=2.0).contains(bias) {
+                errors.push(format!(
+                    "{}: position_bias for {:?} is {}, outside -2.0..=2.0",
+                    name, position, bias
+                ));
+            }
+        }
+        if self.injury_concern_pct > 100 {
+            errors.push(format!(
+                "{}: injury_concern_pct {} is over 100",
+                name, self.injury_concern_pct
+            ));
+        }
+        if self.character_concern_pct > 100 {
+            errors.push(format!(
+                "{}: character_concern_pct {} is over 100",
+                name, self.character_concern_pct
+            ));
+        }
+        errors
+    }
+}
+
+/// Grade profiles for the league: a `default` profile plus overrides keyed
+/// by team abbreviation
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct GradeProfiles {
+    pub default: GradeProfile,
+    pub teams: HashMap<String, GradeProfile>,
+}
+
+impl GradeProfiles {
+    /// The team's own profile, or `default` when the file doesn't list it
+    pub fn for_team(&self, team_abbr: &str) -> &GradeProfile {
+        self.teams.get(team_abbr).unwrap_or(&self.default)
+    }
+
+    /// Every out-of-range value, naming the profile it is in
+    pub fn validate(&self) -> Vec<String> {
+        let mut errors = self.default.validate("default");
+        let mut teams: Vec<_> = self.teams.iter().collect();
+        teams.sort_by_key(|(abbr, _)| abbr.as_str());
+        for (abbr, profile) in teams {
+            errors.extend(profile.validate(abbr));
+        }
+        errors
+    }
+}
+
+pub fn parse_grade_profiles_file(file_path: &str) -> Result<GradeProfiles> {
+    let content = std::fs::read_to_string(file_path)?;
+    parse_grade_profiles_json(&content)
+}
+
+/// Parses and validates a profiles file
+pub fn parse_grade_profiles_json(json: &str) -> Result<GradeProfiles> {
+    let profiles: GradeProfiles = serde_json::from_str(json)?;
+    let errors = profiles.validate();
+    if !errors.is_empty() {
+        bail!("Invalid grade profiles:\n  - {}", errors.join("\n  - "));
+    }
+    Ok(profiles)
+}
+
+/// Deterministic noise in `±team_grade_variance(consensus_grade)` for a
+/// team-player pair
+fn team_grade_offset(consensus_grade: f64, team_abbr: &str, first: &str, last: &str) -> f64 {
+    let key = format!("{}-{}-{}", team_abbr, first, last);
+    let hash = fnv1a_hash(key.as_bytes());
+
+    let max_offset = team_grade_variance(consensus_grade);
+    // Hash bucket in [0, 2000] → fraction in [0.0, 1.0) → scaled to [-max, +max].
+    let frac = (hash % 2001) as f64 / 2000.0;
+    (frac * 2.0 - 1.0) * max_offset
+}
+
 /// Generate a deterministic team-specific grade variation from a consensus grade.
 ///
 /// Uses FNV-1a hash of the team abbreviation + player name to produce a
@@ -75,14 +239,7 @@ fn team_grade_variance(consensus_grade: f64) -> f64 {
 ///
 /// The result is clamped to `[0.0, 10.0]`.
 pub fn generate_team_grade(consensus_grade: f64, team_abbr: &str, first: &str, last: &str) -> f64 {
-    let key = format!("{}-{}-{}", team_abbr, first, last);
-    let hash = fnv1a_hash(key.as_bytes());
-
-    let max_offset = team_grade_variance(consensus_grade);
-    // Hash bucket in [0, 2000] → fraction in [0.0, 1.0) → scaled to [-max, +max].
-    let frac = (hash % 2001) as f64 / 2000.0;
-    let offset = (frac * 2.0 - 1.0) * max_offset;
-
+    let offset = team_grade_offset(consensus_grade, team_abbr, first, last);
     (consensus_grade + offset).clamp(0.0, 10.0)
 }
 
@@ -107,23 +264,20 @@ pub fn generate_fit_grade(team_abbr: &str, first: &str, last: &str) -> FitGrade
 ///
 /// ~5% chance of each flag being set.
 pub fn generate_concern_flags(team_abbr: &str, first: &str, last: &str) -> (bool, bool) {
-    let injury_key = format!("injury-{}-{}-{}", team_abbr, first, last);
-    let injury_hash = fnv1a_hash(injury_key.as_bytes());
-
-    let character_key = format!("character-{}-{}-{}", team_abbr, first, last);
-    let character_hash = fnv1a_hash(character_key.as_bytes());
-
-    (injury_hash % 100 < 5, character_hash % 100 < 5)
+    GradeProfile::default().concern_flags(team_abbr, first, last)
 }
 
 /// Create a scouting report for a player-team combination using deterministic generation.
+#[allow(clippy::too_many_arguments)]
 pub fn create_scouting_report(
     player_id: uuid::Uuid,
     team_id: uuid::Uuid,
     team_abbr: &str,
     first_name: &str,
     last_name: &str,
+    position: Position,
     rank: i32,
+    profile: &GradeProfile,
 ) -> Result<ScoutingReport, domain::errors::DomainError> {
     let consensus_grade = rank_to_grade(rank);
     create_scouting_report_with_grade(
@@ -132,7 +286,9 @@ pub fn create_scouting_report(
         team_abbr,
         first_name,
         last_name,
+        position,
         consensus_grade,
+        profile,
     )
 }
 
@@ -141,18 +297,22 @@ pub fn create_scouting_report(
 /// Used when the consensus signal comes from a source other than a numeric
 /// rank — for example, Brugler's grade tiers on Beast prospects who fall
 /// outside his top-100 numeric list.
+#[allow(clippy::too_many_arguments)]
 pub fn create_scouting_report_with_grade(
     player_id: uuid::Uuid,
     team_id: uuid::Uuid,
     team_abbr: &str,
     first_name: &str,
     last_name: &str,
+    position: Position,
     consensus_grade: f64,
+    profile: &GradeProfile,
 ) -> Result<ScoutingReport, domain::errors::DomainError> {
-    let team_grade = generate_team_grade(consensus_grade, team_abbr, first_name, last_name);
+    let team_grade =
+        profile.team_grade(consensus_grade, position, team_abbr, first_name, last_name);
     let fit_grade = generate_fit_grade(team_abbr, first_name, last_name);
     let (injury_concern, character_concern) =
-        generate_concern_flags(team_abbr, first_name, last_name);
+        profile.concern_flags(team_abbr, first_name, last_name);
 
     Ok(ScoutingReport::new(player_id, team_id, team_grade)?
         .with_fit_grade(fit_grade)
@@ -401,10 +561,116 @@ mod tests {
         // Compute rank_to_grade once and feed both paths the same consensus grade
         // so the test stays correct if the curve is retuned again.
         let consensus = rank_to_grade(32);
-        let via_rank = create_scouting_report(player, team, "DAL", "Test", "Player", 32).unwrap();
-        let via_grade =
-            create_scouting_report_with_grade(player, team, "DAL", "Test", "Player", consensus)
-                .unwrap();
+        let profile = GradeProfile::default();
+        let via_rank = create_scouting_report(
+            player,
+            team,
+            "DAL",
+            "Test",
+            "Player",
+            Position::WR,
+            32,
+            &profile,
+        )
+        .unwrap();
+        let via_grade = create_scouting_report_with_grade(
+            player,
+            team,
+            "DAL",
+            "Test",
+            "Player",
+            Position::WR,
+            consensus,
+            &profile,
+        )
+        .unwrap();
         assert!((via_rank.grade - via_grade.grade).abs() < 1e-9);
     }
+
+    #[test]
+    fn test_default_profile_matches_unprofiled_generators() {
+        let profile = GradeProfile::default();
+        for consensus in [9.5, 8.0, 5.0] {
+            let profiled = profile.team_grade(consensus, Position::QB, "DAL", "John", "Smith");
+            let plain = generate_team_grade(consensus, "DAL", "John", "Smith");
+            assert!((profiled - plain).abs() < f64::EPSILON);
+        }
+        assert_eq!(
+            profile.concern_flags("DAL", "John", "Smith"),
+            generate_concern_flags("DAL", "John", "Smith")
+        );
+    }
+
+    #[test]
+    fn test_profile_bias_and_variance_shift_team_grade() {
+        let plain = generate_team_grade(6.0, "DAL", "John", "Smith");
+        let offset = plain - 6.0;
+
+        let optimist = GradeProfile {
+            bias: 0.5,
+            position_bias: HashMap::from([(Position::QB, 0.3)]),
+            ..GradeProfile::default()
+        };
+        let qb = optimist.team_grade(6.0, Position::QB, "DAL", "John", "Smith");
+        assert!((qb - (6.8 + offset)).abs() < 1e-9);
+        let wr = optimist.team_grade(6.0, Position::WR, "DAL", "John", "Smith");
+        assert!((wr - (6.5 + offset)).abs() < 1e-9);
+
+        let follower = GradeProfile {
+            variance_scale: 0.0,
+            ..GradeProfile::default()
+        };
+        let grade = follower.team_grade(6.0, Position::WR, "DAL", "John", "Smith");
+        assert!((grade - 6.0).abs() < f64::EPSILON);
+    }
+
+    #[test]
+    fn test_profile_concern_odds_bound_flags() {
+        let never = GradeProfile {
+            injury_concern_pct: 0,
+            character_concern_pct: 0,
+            ..GradeProfile::default()
+        };
+        let always = GradeProfile {
+            injury_concern_pct: 100,
+            character_concern_pct: 100,
+            ..GradeProfile::default()
+        };
+        for last in ["Smith", "Jones", "Brown", "Davis"] {
+            assert_eq!(never.concern_flags("DAL", "John", last), (false, false));
+            assert_eq!(always.concern_flags("DAL", "John", last), (true, true));
+        }
+    }
+
+    #[test]
+    fn test_parse_grade_profiles_falls_back_to_default() {
+        let profiles = parse_grade_profiles_json(
+            r#"{
+                "default": { "variance_scale": 1.2 },
+                "teams": {
+                    "DAL": { "bias": 0.3, "position_bias": { "QB": -0.4 } }
+                }
+            }"#,
+        )
+        .unwrap();
+
+        let dallas = profiles.for_team("DAL");
+        assert!((dallas.bias - 0.3).abs() < f64::EPSILON);
+        assert_eq!(dallas.position_bias.get(&Position::QB), Some(&-0.4));
+        assert_eq!(dallas.injury_concern_pct, DEFAULT_CONCERN_PCT);
+        assert!((profiles.for_team("BUF").variance_scale - 1.2).abs() < f64::EPSILON);
+    }
+
+    #[test]
+    fn test_parse_grade_profiles_rejects_out_of_range_values() {
+        let err = parse_grade_profiles_json(
+            r#"{ "teams": { "DAL": { "bias": 3.0, "injury_concern_pct": 150 } } }"#,
+        )
+        .unwrap_err()
+        .to_string();
+        assert!(err.contains("DAL: bias 3"), "{}", err);
+        assert!(err.contains("DAL: injury_concern_pct 150"), "{}", err);
+
+        assert!(parse_grade_profiles_json(r#"{ "teams": { "DAL": { "bais": 0.1 } } }"#).is_err());
+    }
 }
@@ -1,18 +1,30 @@
+pub mod actual_results_loader;
+pub mod anonymize;
+pub mod api_client;
+pub mod backup;
+pub mod college_stats_loader;
 pub mod combine_loader;
+pub mod csv_input;
 pub mod draft_order_loader;
 pub mod draft_order_validator;
+pub mod export;
 pub mod feldman_freak_loader;
 pub mod feldman_freak_validator;
 pub mod grade_generator;
+pub mod load_mode;
 pub mod loader;
 pub mod nflverse_converter;
 pub mod percentile_loader;
 pub mod position_mapper;
+pub mod progress;
+pub mod prospect_generator;
 pub mod rankings_loader;
+pub mod rankings_refresh;
 pub mod rankings_validator;
 pub mod scouting_backfill;
 pub mod scouting_report_loader;
 pub mod scouting_report_validator;
+pub mod seed_runs;
 pub mod team_loader;
 pub mod team_need_loader;
 pub mod team_need_validator;
@@ -22,6 +34,10 @@ pub mod team_validator;
 pub mod the_beast_loader;
 pub mod validator;
 
+/// Inserts a loader keeps in flight at once when rows go through a repository
+/// one at a time
+pub const DEFAULT_CONCURRENCY: usize = 4;
+
 /// Standard number of rounds in an NFL draft
 pub const NFL_DRAFT_ROUNDS: i32 = 7;
 
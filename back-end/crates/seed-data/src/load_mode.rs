@@ -0,0 +1,65 @@
+//! How a load treats entries that already have a row in the database
+//!
+//! Rows are matched on their natural key: a team's abbreviation, or a
+//! player's first and last name within the draft class.
+
+use std::fmt::Debug;
+
+use serde::{Deserialize, Serialize};
+
+/// What to do with a file entry whose row already exists
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize, Serialize, clap::ValueEnum)]
+#[serde(rename_all = "lowercase")]
+pub enum LoadMode {
+    /// Insert new rows and skip existing ones
+    #[default]
+    Insert,
+    /// Insert new rows and update existing ones to match the file
+    Upsert,
+}
+
+/// The fields of an existing row that a file entry would change
+#[derive(Debug, Default)]
+pub struct FieldChanges(Vec<String>);
+
+impl FieldChanges {
+    /// Records `field` when the file's value differs from the stored one
+    pub fn compare<T: PartialEq + Debug>(&mut self, field: &str, stored: &T, file: &T) {
+        if stored != file {
+            self.0
+                .push(format!("{}: {:?} -> {:?}", field, stored, file));
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// One summary line naming the row and each changed field
+    pub fn describe(&self, row: &str) -> String {
+        format!("{}: {}", row, self.0.join(", "))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_field_changes_lists_only_differences() {
+        let mut changes = FieldChanges::default();
+        changes.compare(
+            "college",
+            &Some("Miami".to_string()),
+            &Some("Miami".to_string()),
+        );
+        assert!(changes.is_empty());
+
+        changes.compare("weight_pounds", &Some(219), &Some(221));
+        changes.compare("medical_flag", &false, &true);
+        assert_eq!(
+            changes.describe("Cam Ward"),
+            "Cam Ward: weight_pounds: Some(219) -> Some(221), medical_flag: false -> true"
+        );
+    }
+}
@@ -1,19 +1,22 @@
-use std::collections::HashSet;
+use std::collections::HashMap;
 
 use anyhow::Result;
-use domain::models::Player;
+use chrono::NaiveDate;
+use domain::models::{InjuryStatus, Player};
 use domain::repositories::PlayerRepository;
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
 
-use crate::position_mapper;
+use crate::csv_input;
+use crate::load_mode::{FieldChanges, LoadMode};
+use crate::position_mapper::{self, UnmappedPosition};
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, Serialize)]
 pub struct PlayerData {
     pub meta: MetaData,
     pub players: Vec<PlayerEntry>,
 }
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, Serialize)]
 #[allow(dead_code)]
 pub struct MetaData {
     pub version: String,
@@ -23,15 +26,28 @@ pub struct MetaData {
     pub total_players: usize,
 }
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, Serialize)]
 pub struct PlayerEntry {
     pub first_name: String,
     pub last_name: String,
     pub position: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub college: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub height_inches: Option<i32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub weight_pounds: Option<i32>,
+    /// `healthy` when omitted
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub injury_status: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub injury_body_part: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub injury_expected_return: Option<NaiveDate>,
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub medical_flag: bool,
     #[allow(dead_code)]
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub notes: Option<String>,
 }
 
@@ -54,6 +70,18 @@ impl PlayerEntry {
             player = player.with_physical_stats(height, weight)?;
         }
 
+        let injury_status = match &self.injury_status {
+            Some(status) => status.parse()?,
+            None => InjuryStatus::Healthy,
+        };
+        player = player
+            .with_injury(
+                injury_status,
+                self.injury_body_part.clone(),
+                self.injury_expected_return,
+            )?
+            .with_medical_flag(self.medical_flag);
+
         Ok(player)
     }
 }
@@ -62,6 +90,14 @@ impl PlayerEntry {
 pub struct LoadStats {
     pub success: usize,
     pub skipped: usize,
+    /// Existing players changed by an upsert
+    pub updated: usize,
+    /// Existing players an upsert found already matching the file
+    pub unchanged: usize,
+    /// One line per updated player naming the fields that changed
+    pub changes: Vec<String>,
+    /// Players skipped for an unmapped position
+    pub warnings: Vec<String>,
     pub errors: Vec<String>,
 }
 
@@ -70,7 +106,21 @@ impl LoadStats {
         println!("\nLoad Summary:");
         println!("  Succeeded: {}", self.success);
         println!("  Skipped:   {}", self.skipped);
+        println!("  Updated:   {}", self.updated);
+        println!("  Unchanged: {}", self.unchanged);
         println!("  Errors:    {}", self.errors.len());
+        if !self.changes.is_empty() {
+            println!("\nChanges:");
+            for change in &self.changes {
+                println!("  - {}", change);
+            }
+        }
+        if !self.warnings.is_empty() {
+            println!("\nWarnings:");
+            for warning in &self.warnings {
+                println!("  - {}", warning);
+            }
+        }
         if !self.errors.is_empty() {
             println!("\nErrors:");
             for error in &self.errors {
@@ -80,8 +130,30 @@ impl LoadStats {
     }
 }
 
+/// One player in a CSV file: the `PlayerEntry` fields plus the class's
+/// `draft_year`, which must be the same in every row
+#[derive(Debug, Deserialize)]
+struct PlayerCsvRow {
+    first_name: String,
+    last_name: String,
+    position: String,
+    draft_year: i32,
+    college: Option<String>,
+    height_inches: Option<i32>,
+    weight_pounds: Option<i32>,
+    injury_status: Option<String>,
+    injury_body_part: Option<String>,
+    injury_expected_return: Option<NaiveDate>,
+    medical_flag: Option<bool>,
+    notes: Option<String>,
+}
+
+/// Reads a JSON file, or a CSV file when its extension is `.csv`
 pub fn parse_player_file(file_path: &str) -> Result<PlayerData> {
     let content = std::fs::read_to_string(file_path)?;
+    if csv_input::is_csv(file_path) {
+        return parse_player_csv(&content);
+    }
     let data: PlayerData = serde_json::from_str(&content)?;
     Ok(data)
 }
@@ -91,6 +163,39 @@ pub fn parse_player_json(json: &str) -> Result<PlayerData> {
     Ok(data)
 }
 
+pub fn parse_player_csv(csv: &str) -> Result<PlayerData> {
+    let rows: Vec<PlayerCsvRow> = csv_input::read_rows(csv)?;
+    let draft_year = csv_input::single_value("draft_year", rows.iter().map(|r| r.draft_year))?;
+
+    let players: Vec<PlayerEntry> = rows
+        .into_iter()
+        .map(|row| PlayerEntry {
+            first_name: row.first_name,
+            last_name: row.last_name,
+            position: row.position,
+            college: row.college,
+            height_inches: row.height_inches,
+            weight_pounds: row.weight_pounds,
+            injury_status: row.injury_status,
+            injury_body_part: row.injury_body_part,
+            injury_expected_return: row.injury_expected_return,
+            medical_flag: row.medical_flag.unwrap_or(false),
+            notes: row.notes,
+        })
+        .collect();
+
+    Ok(PlayerData {
+        meta: MetaData {
+            version: "csv".to_string(),
+            draft_year,
+            last_updated: String::new(),
+            sources: Vec::new(),
+            total_players: players.len(),
+        },
+        players,
+    })
+}
+
 /// Maximum number of consecutive failures before aborting.
 const MAX_CONSECUTIVE_FAILURES: usize = 5;
 
@@ -107,6 +212,11 @@ pub fn load_players_dry_run(data: &PlayerData) -> Result<LoadStats> {
                 stats.success += 1;
                 consecutive_failures = 0;
             }
+            Err(e) if e.is::<UnmappedPosition>() => {
+                let msg = format!("Skipping {}: {}", full_name, e);
+                tracing::warn!("{}", msg);
+                stats.warnings.push(msg);
+            }
             Err(e) => {
                 let msg = format!("Validation failed for {}: {}", full_name, e);
                 tracing::error!("{}", msg);
@@ -129,28 +239,75 @@ pub fn load_players_dry_run(data: &PlayerData) -> Result<LoadStats> {
     Ok(stats)
 }
 
-pub async fn load_players(data: &PlayerData, repo: &dyn PlayerRepository) -> Result<LoadStats> {
+/// A player's natural key within a draft class: first name, last name and
+/// college. Two prospects can share a name, but not a name and a school.
+type PlayerKey = (String, String, Option<String>);
+
+fn player_key(first_name: &str, last_name: &str, college: &Option<String>) -> PlayerKey {
+    (
+        first_name.to_string(),
+        last_name.to_string(),
+        college.clone(),
+    )
+}
+
+/// Inserts the file's players. Players already in the class, matched by
+/// name and college, are skipped in `Insert` mode and brought in line with
+/// the file in `Upsert` mode. An entry matching more than one stored player
+/// is reported as an error and left alone.
+pub async fn load_players(
+    data: &PlayerData,
+    repo: &dyn PlayerRepository,
+    mode: LoadMode,
+) -> Result<LoadStats> {
     let mut stats = LoadStats::default();
     let mut consecutive_failures: usize = 0;
 
     // Load existing players for this draft year to check for duplicates
     let existing_players = repo.find_by_draft_year(data.meta.draft_year).await?;
-    let existing_names: HashSet<String> = existing_players
-        .iter()
-        .map(|p| format!("{} {}", p.first_name, p.last_name))
-        .collect();
-
     tracing::info!(
         "Found {} existing players for draft year {}",
-        existing_names.len(),
+        existing_players.len(),
         data.meta.draft_year
     );
 
+    let mut existing: HashMap<PlayerKey, Vec<Player>> = HashMap::new();
+    for player in existing_players {
+        existing
+            .entry(player_key(
+                &player.first_name,
+                &player.last_name,
+                &player.college,
+            ))
+            .or_default()
+            .push(player);
+    }
+
+    let mut new_players = Vec::new();
     for entry in &data.players {
         let full_name = format!("{} {}", entry.first_name, entry.last_name);
 
-        // Skip if player already exists
-        if existing_names.contains(&full_name) {
+        let matches = existing
+            .get(&player_key(
+                &entry.first_name,
+                &entry.last_name,
+                &entry.college,
+            ))
+            .map(Vec::as_slice)
+            .unwrap_or_default();
+        if matches.len() > 1 {
+            let msg = format!(
+                "Ambiguous match for {}: {} stored players share the name and college",
+                full_name,
+                matches.len()
+            );
+            tracing::error!("{}", msg);
+            stats.errors.push(msg);
+            continue;
+        }
+
+        let stored = matches.first();
+        if stored.is_some() && mode == LoadMode::Insert {
             tracing::warn!("Skipping {}: player already exists", full_name);
             stats.skipped += 1;
             consecutive_failures = 0;
@@ -158,19 +315,25 @@ pub async fn load_players(data: &PlayerData, repo: &dyn PlayerRepository) -> Res
         }
 
         match entry.to_domain(data.meta.draft_year) {
-            Ok(player) => match repo.create(&player).await {
-                Ok(_) => {
-                    tracing::info!("Inserted: {} ({})", full_name, entry.position);
-                    stats.success += 1;
-                    consecutive_failures = 0;
-                }
-                Err(e) => {
-                    let msg = format!("Failed to insert {}: {}", full_name, e);
-                    tracing::error!("{}", msg);
-                    stats.errors.push(msg);
-                    consecutive_failures += 1;
+            Ok(player) => {
+                consecutive_failures = 0;
+                match stored {
+                    Some(stored) => {
+                        if let Err(e) = update_player(stored, player, repo, &mut stats).await {
+                            let msg = format!("Failed to update {}: {}", full_name, e);
+                            tracing::error!("{}", msg);
+                            stats.errors.push(msg);
+                            consecutive_failures += 1;
+                        }
+                    }
+                    None => new_players.push(player),
                 }
-            },
+            }
+            Err(e) if e.is::<UnmappedPosition>() => {
+                let msg = format!("Skipping {}: {}", full_name, e);
+                tracing::warn!("{}", msg);
+                stats.warnings.push(msg);
+            }
             Err(e) => {
                 let msg = format!("Validation failed for {}: {}", full_name, e);
                 tracing::error!("{}", msg);
@@ -181,7 +344,7 @@ pub async fn load_players(data: &PlayerData, repo: &dyn PlayerRepository) -> Res
 
         if consecutive_failures >= MAX_CONSECUTIVE_FAILURES {
             let abort_msg = format!(
-                "Aborting: {} consecutive failures detected. This may indicate a systematic problem (e.g., database down, schema mismatch).",
+                "Aborting: {} consecutive failures detected. This may indicate a systematic problem (e.g., schema mismatch).",
                 consecutive_failures
             );
             tracing::error!("{}", abort_msg);
@@ -190,9 +353,88 @@ pub async fn load_players(data: &PlayerData, repo: &dyn PlayerRepository) -> Res
         }
     }
 
+    // One batched insert for every new player rather than a round trip each
+    if !new_players.is_empty() {
+        match repo.create_many(&new_players).await {
+            Ok(created) => {
+                for player in &created {
+                    tracing::info!(
+                        "Inserted: {} {} ({:?})",
+                        player.first_name,
+                        player.last_name,
+                        player.position
+                    );
+                }
+                stats.success += created.len();
+            }
+            Err(e) => {
+                let msg = format!("Failed to insert {} players: {}", new_players.len(), e);
+                tracing::error!("{}", msg);
+                stats.errors.push(msg);
+            }
+        }
+    }
+
     Ok(stats)
 }
 
+/// Brings a stored player in line with its file entry, recording the
+/// changed fields, and leaves the row alone when nothing differs
+async fn update_player(
+    stored: &Player,
+    mut player: Player,
+    repo: &dyn PlayerRepository,
+    stats: &mut LoadStats,
+) -> Result<()> {
+    let mut changes = FieldChanges::default();
+    changes.compare("position", &stored.position, &player.position);
+    changes.compare(
+        "height_inches",
+        &stored.height_inches,
+        &player.height_inches,
+    );
+    changes.compare(
+        "weight_pounds",
+        &stored.weight_pounds,
+        &player.weight_pounds,
+    );
+    changes.compare(
+        "injury_status",
+        &stored.injury_status,
+        &player.injury_status,
+    );
+    changes.compare(
+        "injury_body_part",
+        &stored.injury_body_part,
+        &player.injury_body_part,
+    );
+    changes.compare(
+        "injury_expected_return",
+        &stored.injury_expected_return,
+        &player.injury_expected_return,
+    );
+    changes.compare("medical_flag", &stored.medical_flag, &player.medical_flag);
+
+    if changes.is_empty() {
+        stats.unchanged += 1;
+        return Ok(());
+    }
+
+    player.id = stored.id;
+    player.draft_eligible = stored.draft_eligible;
+    // Links come from the rankings boards, not the players file
+    player.profile_url = stored.profile_url.clone();
+    player.headshot_url = stored.headshot_url.clone();
+    player.created_at = stored.created_at;
+    repo.update(&player).await?;
+
+    let change = changes.describe(&format!("{} {}", stored.first_name, stored.last_name));
+    tracing::info!("Updated {}", change);
+    stats.changes.push(change);
+    stats.updated += 1;
+    Ok(())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -229,6 +471,23 @@ mod tests {
         }"#
     }
 
+    #[test]
+    fn test_player_key_includes_college() {
+        let colorado = Some("University of Colorado".to_string());
+        assert_eq!(
+            player_key("Shedeur", "Sanders", &colorado),
+            player_key("Shedeur", "Sanders", &colorado)
+        );
+        assert_ne!(
+            player_key("Shedeur", "Sanders", &colorado),
+            player_key("Shedeur", "Sanders", &Some("Jackson State".to_string()))
+        );
+        assert_ne!(
+            player_key("Shedeur", "Sanders", &colorado),
+            player_key("Shedeur", "Sanders", &None)
+        );
+    }
+
     #[test]
     fn test_parse_json() {
         let data: PlayerData = serde_json::from_str(sample_json()).unwrap();
@@ -239,6 +498,36 @@ mod tests {
         assert_eq!(data.players[0].last_name, "Hunter");
     }
 
+    #[test]
+    fn test_parse_csv() {
+        let csv = "first_name,last_name,position,draft_year,college,height_inches,weight_pounds,injury_status,injury_body_part,injury_expected_return,medical_flag,notes
+Travis,Hunter,CB,2026,University of Colorado,73,185,,,,,Two-way player
+Shedeur,Sanders,QB,2026,University of Colorado,74,215,limited,ankle,2026-03-01,true,
+";
+        let data = parse_player_csv(csv).unwrap();
+        assert_eq!(data.meta.draft_year, 2026);
+        assert_eq!(data.meta.total_players, 2);
+        assert_eq!(data.players[0].first_name, "Travis");
+        assert_eq!(data.players[0].injury_status, None);
+        assert!(!data.players[0].medical_flag);
+        assert_eq!(data.players[1].injury_body_part.as_deref(), Some("ankle"));
+        assert!(data.players[1].medical_flag);
+
+        let player = data.players[1].to_domain(data.meta.draft_year).unwrap();
+        assert_eq!(player.injury_status, InjuryStatus::Limited);
+        assert_eq!(player.height_inches, Some(74));
+    }
+
+    #[test]
+    fn test_parse_csv_needs_one_draft_year() {
+        let csv = "first_name,last_name,position,draft_year
+Travis,Hunter,CB,2026
+Shedeur,Sanders,QB,2027
+";
+        let err = parse_player_csv(csv).unwrap_err();
+        assert!(err.to_string().contains("draft_year"), "{}", err);
+    }
+
     #[test]
     fn test_player_entry_to_domain() {
         let entry = PlayerEntry {
@@ -248,6 +537,10 @@ mod tests {
             college: Some("University of Colorado".to_string()),
             height_inches: Some(73),
             weight_pounds: Some(185),
+            injury_status: None,
+            injury_body_part: None,
+            injury_expected_return: None,
+            medical_flag: false,
             notes: Some("Two-way player".to_string()),
         };
 
@@ -270,6 +563,10 @@ mod tests {
             college: None,
             height_inches: None,
             weight_pounds: None,
+            injury_status: None,
+            injury_body_part: None,
+            injury_expected_return: None,
+            medical_flag: false,
             notes: None,
         };
 
@@ -288,6 +585,10 @@ mod tests {
             college: None,
             height_inches: None,
             weight_pounds: None,
+            injury_status: None,
+            injury_body_part: None,
+            injury_expected_return: None,
+            medical_flag: false,
             notes: None,
         };
 
@@ -304,12 +605,51 @@ mod tests {
             college: None,
             height_inches: None,
             weight_pounds: None,
+            injury_status: None,
+            injury_body_part: None,
+            injury_expected_return: None,
+            medical_flag: false,
             notes: None,
         };
 
         assert!(entry.to_domain(2026).is_err());
     }
 
+    #[test]
+    fn test_player_entry_with_injury() {
+        let entry: PlayerEntry = serde_json::from_str(
+            r#"{
+                "first_name": "Test",
+                "last_name": "Player",
+                "position": "RB",
+                "injury_status": "out",
+                "injury_body_part": "knee",
+                "injury_expected_return": "2026-09-01",
+                "medical_flag": true
+            }"#,
+        )
+        .unwrap();
+
+        let player = entry.to_domain(2026).unwrap();
+        assert_eq!(player.injury_status, InjuryStatus::Out);
+        assert_eq!(player.injury_body_part, Some("knee".to_string()));
+        assert_eq!(
+            player.injury_expected_return,
+            NaiveDate::from_ymd_opt(2026, 9, 1)
+        );
+        assert!(player.medical_flag);
+    }
+
+    #[test]
+    fn test_player_entry_invalid_injury_status() {
+        let entry: PlayerEntry = serde_json::from_str(
+            r#"{ "first_name": "Test", "last_name": "Player", "position": "RB", "injury_status": "probable" }"#,
+        )
+        .unwrap();
+
+        assert!(entry.to_domain(2026).is_err());
+    }
+
     #[test]
     fn test_partial_physical_stats_ignored() {
         // If only height is provided (no weight), neither should be set
@@ -320,6 +660,10 @@ mod tests {
             college: None,
             height_inches: Some(72),
             weight_pounds: None,
+            injury_status: None,
+            injury_body_part: None,
+            injury_expected_return: None,
+            medical_flag: false,
             notes: None,
         };
 
@@ -1,32 +1,83 @@
+use seed_data::api_client::SeedApiClient;
+use seed_data::backup::{self, Backup, Scope, DEFAULT_BACKUP_DIR};
+use seed_data::grade_generator::{self, GradeProfiles};
+use seed_data::load_mode::LoadMode;
+use seed_data::seed_runs::SeedFile;
 use seed_data::{
-    combine_loader, draft_order_loader, draft_order_validator, feldman_freak_loader,
-    feldman_freak_validator, loader, percentile_loader, rankings_loader, rankings_validator,
-    scouting_backfill, scouting_report_loader, scouting_report_validator, team_loader,
-    team_need_loader, team_need_validator, team_season_loader, team_season_validator,
-    team_validator, the_beast_loader, validator,
+    actual_results_loader, anonymize, college_stats_loader, combine_loader, draft_order_loader,
+    draft_order_validator, export, feldman_freak_loader, feldman_freak_validator, loader,
+    percentile_loader, position_mapper, prospect_generator, rankings_loader, rankings_refresh,
+    rankings_validator, scouting_backfill, scouting_report_loader, scouting_report_validator,
+    seed_runs, team_loader, team_need_loader, team_need_validator, team_season_loader,
+    team_season_validator, team_validator, the_beast_loader, validator,
 };
 
-use anyhow::Result;
+use std::path::Path;
+
+use anyhow::{bail, Context, Result};
 use clap::{Parser, Subcommand};
 use db::{
-    create_pool,
+    create_pool, create_pool_with,
     repositories::{
-        SqlxCombinePercentileRepository, SqlxCombineResultsRepository, SqlxDraftPickRepository,
-        SqlxDraftRepository, SqlxFeldmanFreakRepository, SqlxPlayerRepository,
-        SqlxProspectProfileRepository, SqlxProspectRankingRepository, SqlxRankingSourceRepository,
-        SqlxScoutingReportRepository, SqlxTeamNeedRepository, SqlxTeamRepository,
-        SqlxTeamSeasonRepository,
+        SqlxActualDraftPickRepository, SqlxCollegeStatsRepository, SqlxCombinePercentileRepository,
+        SqlxCombineResultsRepository, SqlxDraftPickRepository, SqlxDraftRepository,
+        SqlxFeldmanFreakRepository, SqlxPlayerRepository, SqlxProspectProfileRepository,
+        SqlxProspectRankingRepository, SqlxRankingSourceRepository, SqlxScoutingReportRepository,
+        SqlxTeamNeedRepository, SqlxTeamRepository, SqlxTeamSeasonRepository,
     },
+    PoolSettings, DEFAULT_BATCH_SIZE,
 };
 use domain::repositories::PlayerRepository;
+use seed_data::DEFAULT_CONCURRENCY;
+use sqlx::PgPool;
 use tracing_subscriber::EnvFilter;
 
+/// Commands other than these have no admin endpoint to go through
+const VIA_API_UNSUPPORTED: &str =
+    "--via-api supports only the players, teams, needs and seasons load and validate commands";
+
 #[derive(Parser)]
 #[command(name = "seed-data")]
 #[command(about = "Seed NFL Draft data into the database")]
 struct Cli {
     #[command(subcommand)]
     entity: EntityCommands,
+
+    /// Rows per INSERT statement when loading players, draft picks and rankings
+    #[arg(long, global = true, default_value_t = DEFAULT_BATCH_SIZE)]
+    batch_size: usize,
+
+    /// Scouting reports inserted at once by `rankings load` and
+    /// `scouting backfill`
+    #[arg(long, global = true, default_value_t = DEFAULT_CONCURRENCY)]
+    concurrency: usize,
+
+    /// Load through the admin seed endpoints of the API at this base URL
+    /// instead of connecting to DATABASE_URL
+    #[arg(long, global = true, value_name = "BASE_URL")]
+    via_api: Option<String>,
+
+    /// Seed API key for `--via-api`; defaults to SEED_API_KEY
+    #[arg(long, global = true, requires = "via_api")]
+    api_key: Option<String>,
+
+    /// Load a file even if its exact contents were already loaded
+    #[arg(long, global = true)]
+    force: bool,
+
+    /// Directory for the backups taken before a clear or a load that
+    /// replaces rows
+    #[arg(long, global = true, default_value = DEFAULT_BACKUP_DIR)]
+    backup_dir: String,
+
+    /// Clear and replace rows without backing them up first
+    #[arg(long, global = true)]
+    no_backup: bool,
+
+    /// JSON file of position aliases that add to or override the built-in
+    /// ones, with an optional fallback for positions that match none
+    #[arg(long, global = true, value_name = "FILE")]
+    position_map: Option<String>,
 }
 
 #[derive(Subcommand)]
@@ -96,6 +147,53 @@ enum EntityCommands {
         #[command(subcommand)]
         action: TheBeastActions,
     },
+
+    /// Manage real NFL draft results used to score mock drafts
+    ActualResults {
+        #[command(subcommand)]
+        action: ActualResultsActions,
+    },
+
+    /// Manage per-season college production stats
+    CollegeStats {
+        #[command(subcommand)]
+        action: CollegeStatsActions,
+    },
+
+    /// Show which seed files are loaded, and which changed since they were
+    Status,
+
+    /// Put back the rows saved by the backup a clear or load took before
+    /// it ran
+    Restore {
+        /// Path to the backup file
+        #[arg(short, long)]
+        backup: String,
+    },
+
+    /// Write copies of seed files with player names and schools replaced by
+    /// fake ones, for sharing in bug reports
+    Anonymize {
+        /// Players JSON or CSV file
+        #[arg(long)]
+        players: Option<String>,
+
+        /// Combine results JSON file
+        #[arg(long)]
+        combine: Option<String>,
+
+        /// Rankings JSON or CSV file; repeat for several boards
+        #[arg(long)]
+        rankings: Vec<String>,
+
+        /// Directory to write the anonymized files to
+        #[arg(short, long)]
+        output_dir: String,
+
+        /// Seed for the fake names; the same seed gives the same mapping
+        #[arg(long, default_value_t = 1)]
+        seed: u64,
+    },
 }
 
 #[derive(Subcommand)]
@@ -116,13 +214,18 @@ enum TheBeastActions {
 enum PlayerActions {
     /// Load players from JSON file into the database
     Load {
-        /// Path to the JSON data file
+        /// Path to the JSON or CSV data file
         #[arg(short, long, default_value = "data/players_2026.json")]
         file: String,
 
         /// Simulate loading without writing to database
         #[arg(long)]
         dry_run: bool,
+
+        /// `insert` skips rows that already exist; `upsert` updates them to
+        /// match the file
+        #[arg(long, value_enum, default_value_t = LoadMode::Insert)]
+        mode: LoadMode,
     },
 
     /// Clear all players for a given draft year
@@ -134,10 +237,45 @@ enum PlayerActions {
 
     /// Validate JSON file without loading
     Validate {
-        /// Path to the JSON data file
+        /// Path to the JSON or CSV data file
         #[arg(short, long, default_value = "data/players_2026.json")]
         file: String,
     },
+
+    /// Export a draft class from the database to a JSON file the loader reads
+    Export {
+        /// The draft year to export
+        #[arg(short, long)]
+        year: i32,
+
+        /// Path to write the JSON data file to
+        #[arg(short, long)]
+        output: String,
+    },
+
+    /// Generate a synthetic draft class as players, combine and rankings
+    /// files, for load testing and future-year sandboxes
+    Generate {
+        /// Number of prospects to generate
+        #[arg(short, long, default_value_t = 500)]
+        count: usize,
+
+        /// The draft year of the class
+        #[arg(short, long)]
+        year: i32,
+
+        /// Seed for the generator; the same seed gives the same class
+        #[arg(long, default_value_t = 1)]
+        seed: u64,
+
+        /// Combine percentile baselines the combine numbers are drawn from
+        #[arg(long, default_value = "data/combine_percentiles.json")]
+        percentiles: String,
+
+        /// Directory to write the files to
+        #[arg(short, long, default_value = "data/generated")]
+        output_dir: String,
+    },
 }
 
 #[derive(Subcommand)]
@@ -151,6 +289,11 @@ enum TeamActions {
         /// Simulate loading without writing to database
         #[arg(long)]
         dry_run: bool,
+
+        /// `insert` skips rows that already exist; `upsert` updates them to
+        /// match the file
+        #[arg(long, value_enum, default_value_t = LoadMode::Insert)]
+        mode: LoadMode,
     },
 
     /// Clear all teams from the database
@@ -162,13 +305,20 @@ enum TeamActions {
         #[arg(short, long, default_value = "data/teams_nfl.json")]
         file: String,
     },
+
+    /// Export all teams from the database to a JSON file the loader reads
+    Export {
+        /// Path to write the JSON data file to
+        #[arg(short, long)]
+        output: String,
+    },
 }
 
 #[derive(Subcommand)]
 enum NeedActions {
     /// Load team needs from JSON file into the database
     Load {
-        /// Path to the JSON data file
+        /// Path to the JSON or CSV data file
         #[arg(short, long, default_value = "data/team_needs_2026.json")]
         file: String,
 
@@ -182,10 +332,17 @@ enum NeedActions {
 
     /// Validate JSON file without loading
     Validate {
-        /// Path to the JSON data file
+        /// Path to the JSON or CSV data file
         #[arg(short, long, default_value = "data/team_needs_2026.json")]
         file: String,
     },
+
+    /// Export all team needs from the database to a JSON file the loader reads
+    Export {
+        /// Path to write the JSON data file to
+        #[arg(short, long)]
+        output: String,
+    },
 }
 
 #[derive(Subcommand)]
@@ -220,7 +377,7 @@ enum DraftOrderActions {
 enum SeasonActions {
     /// Load team seasons from JSON file into the database
     Load {
-        /// Path to the JSON data file
+        /// Path to the JSON or CSV data file
         #[arg(short, long, default_value = "data/team_seasons_2025.json")]
         file: String,
 
@@ -238,35 +395,87 @@ enum SeasonActions {
 
     /// Validate JSON file without loading
     Validate {
-        /// Path to the JSON data file
+        /// Path to the JSON or CSV data file
         #[arg(short, long, default_value = "data/team_seasons_2025.json")]
         file: String,
     },
+
+    /// Export a season's team records from the database to a JSON file the
+    /// loader reads
+    Export {
+        /// The season year to export
+        #[arg(short, long)]
+        year: i32,
+
+        /// Path to write the JSON data file to
+        #[arg(short, long)]
+        output: String,
+    },
 }
 
 #[derive(Subcommand)]
 enum ScoutingActions {
     /// Load scouting reports from prospect rankings JSON file
     Load {
-        /// Path to the rankings JSON data file
+        /// Path to the rankings JSON or CSV data file
         #[arg(short, long, default_value = "data/rankings/rankings.json")]
         file: String,
 
         /// Simulate loading without writing to database
         #[arg(long)]
         dry_run: bool,
+
+        /// JSON file of per-team grade profiles (bias, variance, positional
+        /// bias, concern odds); every team grades alike without one
+        #[arg(long)]
+        profiles: Option<String>,
     },
 
-    /// Clear all scouting reports for a draft year
+    /// Clear all scouting reports for a draft year, or one source's rankings
     Clear {
         /// The draft year to clear
+        #[arg(
+            short,
+            long,
+            required_unless_present = "source",
+            conflicts_with = "source"
+        )]
+        year: Option<i32>,
+
+        /// Clear only this ranking source's board, keeping scouting reports
+        /// and other sources
         #[arg(short, long)]
-        year: i32,
+        source: Option<String>,
+    },
+
+    /// List the ranking sources in the database with the size of each board
+    Sources,
+
+    /// Apply a newer rankings file to its source's loaded board, changing
+    /// only the ranks that moved, and print a movement report
+    Refresh {
+        /// Path to the rankings JSON or CSV data file
+        #[arg(short, long)]
+        file: String,
+
+        /// Print the movement report without changing the board
+        #[arg(long)]
+        dry_run: bool,
+
+        /// Refresh the board under this source name instead of the file's
+        /// `source`
+        #[arg(long)]
+        source_name: Option<String>,
+
+        /// JSON file of per-team grade profiles for prospects new to the
+        /// database; every team grades alike without one
+        #[arg(long)]
+        profiles: Option<String>,
     },
 
     /// Validate rankings JSON file without loading
     Validate {
-        /// Path to the rankings JSON data file
+        /// Path to the rankings JSON or CSV data file
         #[arg(short, long, default_value = "data/rankings/rankings.json")]
         file: String,
     },
@@ -281,6 +490,11 @@ enum ScoutingActions {
         /// The draft year to backfill
         #[arg(short, long)]
         year: i32,
+
+        /// JSON file of per-team grade profiles (bias, variance, positional
+        /// bias, concern odds); every team grades alike without one
+        #[arg(long)]
+        profiles: Option<String>,
     },
 }
 
@@ -312,6 +526,62 @@ enum FreaksActions {
     },
 }
 
+#[derive(Subcommand)]
+enum ActualResultsActions {
+    /// Load real draft results from JSON file into the database
+    Load {
+        /// Path to the JSON data file
+        #[arg(short, long, default_value = "data/actual_draft_2026.json")]
+        file: String,
+
+        /// Simulate loading without writing to database
+        #[arg(long)]
+        dry_run: bool,
+    },
+
+    /// Clear real draft results for a given year
+    Clear {
+        /// The year to clear
+        #[arg(short, long)]
+        year: i32,
+    },
+
+    /// Validate JSON file without loading
+    Validate {
+        /// Path to the JSON data file
+        #[arg(short, long, default_value = "data/actual_draft_2026.json")]
+        file: String,
+    },
+}
+
+#[derive(Subcommand)]
+enum CollegeStatsActions {
+    /// Load college stats from JSON file into the database
+    Load {
+        /// Path to the JSON data file
+        #[arg(short, long, default_value = "data/college_stats_2026.json")]
+        file: String,
+
+        /// Simulate loading without writing to database
+        #[arg(long)]
+        dry_run: bool,
+    },
+
+    /// Clear college stats for every player in a draft class
+    Clear {
+        /// The draft year to clear
+        #[arg(short, long)]
+        year: i32,
+    },
+
+    /// Validate JSON file without loading
+    Validate {
+        /// Path to the JSON data file
+        #[arg(short, long, default_value = "data/college_stats_2026.json")]
+        file: String,
+    },
+}
+
 #[derive(Subcommand)]
 enum CombineActions {
     /// Load combine results from JSON file into the database
@@ -344,13 +614,23 @@ enum CombineActions {
 enum RankingsActions {
     /// Load prospect rankings from JSON file (auto-creates new players + scouting reports)
     Load {
-        /// Path to the rankings JSON data file
+        /// Path to the rankings JSON or CSV data file
         #[arg(short, long)]
         file: String,
 
         /// Simulate loading without writing to database
         #[arg(long)]
         dry_run: bool,
+
+        /// JSON file of per-team grade profiles (bias, variance, positional
+        /// bias, concern odds); every team grades alike without one
+        #[arg(long)]
+        profiles: Option<String>,
+
+        /// Load the board under this source name instead of the file's
+        /// `source`
+        #[arg(long)]
+        source_name: Option<String>,
     },
 
     /// Clear rankings for a source
@@ -362,19 +642,29 @@ enum RankingsActions {
 
     /// Validate rankings JSON file without loading
     Validate {
-        /// Path to the rankings JSON data file
+        /// Path to the rankings JSON or CSV data file
         #[arg(short, long)]
         file: String,
+
+        /// Validate the board under this source name instead of the file's
+        /// `source`
+        #[arg(long)]
+        source_name: Option<String>,
     },
 }
 
 #[derive(Subcommand)]
 enum PercentilesActions {
-    /// Load combine percentiles from JSON file into the database
+    /// Load combine percentiles from JSON file into the database, replacing
+    /// the stored baseline for each position and measurement
     Load {
         /// Path to the JSON data file
         #[arg(short, long, default_value = "data/combine_percentiles.json")]
         file: String,
+
+        /// Check every entry without writing to database
+        #[arg(long)]
+        dry_run: bool,
     },
 
     /// Validate JSON file without loading
@@ -397,24 +687,114 @@ async fn main() -> Result<()> {
 
     let cli = Cli::parse();
 
+    if let Some(path) = &cli.position_map {
+        position_mapper::install_position_map(position_mapper::parse_position_map_file(path)?)?;
+    }
+
+    let api = match cli.via_api {
+        Some(base_url) => {
+            let api_key = cli
+                .api_key
+                .or_else(|| std::env::var("SEED_API_KEY").ok())
+                .context("--via-api needs --api-key or SEED_API_KEY")?;
+            Some(SeedApiClient::new(&base_url, api_key)?)
+        }
+        None => None,
+    };
+    if api.is_some()
+        && !matches!(
+            cli.entity,
+            EntityCommands::Players { .. }
+                | EntityCommands::Teams { .. }
+                | EntityCommands::Needs { .. }
+                | EntityCommands::Seasons { .. }
+        )
+    {
+        bail!(VIA_API_UNSUPPORTED);
+    }
+
+    let backup_dir = (!cli.no_backup).then_some(cli.backup_dir.as_str());
+
     match cli.entity {
-        EntityCommands::Players { action } => handle_players(action).await?,
-        EntityCommands::Teams { action } => handle_teams(action).await?,
-        EntityCommands::Needs { action } => handle_needs(action).await?,
-        EntityCommands::Seasons { action } => handle_seasons(action).await?,
-        EntityCommands::DraftOrder { action } => handle_draft_order(action).await?,
-        EntityCommands::Scouting { action } => handle_scouting(action).await?,
-        EntityCommands::Rankings { action } => handle_rankings(action).await?,
-        EntityCommands::Freaks { action } => handle_freaks(action).await?,
-        EntityCommands::Combine { action } => handle_combine(action).await?,
-        EntityCommands::Percentiles { action } => handle_percentiles(action).await?,
-        EntityCommands::TheBeast { action } => handle_the_beast(action).await?,
+        EntityCommands::Players { action } => {
+            handle_players(action, cli.batch_size, cli.force, backup_dir, api.as_ref()).await?
+        }
+        EntityCommands::Teams { action } => {
+            handle_teams(action, cli.force, backup_dir, api.as_ref()).await?
+        }
+        EntityCommands::Needs { action } => {
+            handle_needs(action, cli.force, backup_dir, api.as_ref()).await?
+        }
+        EntityCommands::Seasons { action } => {
+            handle_seasons(action, cli.force, backup_dir, api.as_ref()).await?
+        }
+        EntityCommands::DraftOrder { action } => {
+            handle_draft_order(action, cli.batch_size, cli.force, backup_dir).await?
+        }
+        EntityCommands::Scouting { action } => {
+            handle_scouting(
+                action,
+                cli.batch_size,
+                cli.concurrency,
+                cli.force,
+                backup_dir,
+            )
+            .await?
+        }
+        EntityCommands::Rankings { action } => {
+            handle_rankings(
+                action,
+                cli.batch_size,
+                cli.concurrency,
+                cli.force,
+                backup_dir,
+            )
+            .await?
+        }
+        EntityCommands::Freaks { action } => handle_freaks(action, cli.force, backup_dir).await?,
+        EntityCommands::Combine { action } => handle_combine(action, cli.force, backup_dir).await?,
+        EntityCommands::Percentiles { action } => {
+            handle_percentiles(action, cli.force, backup_dir).await?
+        }
+        EntityCommands::TheBeast { action } => {
+            handle_the_beast(action, cli.force, backup_dir).await?
+        }
+        EntityCommands::ActualResults { action } => {
+            handle_actual_results(action, cli.force, backup_dir).await?
+        }
+        EntityCommands::CollegeStats { action } => {
+            handle_college_stats(action, cli.force, backup_dir).await?
+        }
+        EntityCommands::Status => handle_status().await?,
+        EntityCommands::Restore { backup } => handle_restore(&backup, backup_dir).await?,
+        EntityCommands::Anonymize {
+            players,
+            combine,
+            rankings,
+            output_dir,
+            seed,
+        } => handle_anonymize(players, combine, rankings, &output_dir, seed)?,
     }
 
     Ok(())
 }
 
-async fn handle_players(action: PlayerActions) -> Result<()> {
+async fn handle_players(
+    action: PlayerActions,
+    batch_size: usize,
+    force: bool,
+    backup_dir: Option<&str>,
+    api: Option<&SeedApiClient>,
+) -> Result<()> {
+    if api.is_some()
+        && !matches!(
+            action,
+            PlayerActions::Load { .. } | PlayerActions::Validate { .. }
+        )
+    {
+        bail!(VIA_API_UNSUPPORTED);
+    }
+
     match action {
         PlayerActions::Validate { file } => {
             println!("Validating: {}", file);
@@ -433,7 +813,11 @@ async fn handle_players(action: PlayerActions) -> Result<()> {
             }
         }
 
-        PlayerActions::Load { file, dry_run } => {
+        PlayerActions::Load {
+            file,
+            dry_run,
+            mode,
+        } => {
             if dry_run {
                 println!("DRY RUN - Validating and simulating load: {}", file);
             } else {
@@ -464,19 +848,75 @@ async fn handle_players(action: PlayerActions) -> Result<()> {
                 if !stats.errors.is_empty() {
                     std::process::exit(1);
                 }
+            } else if let Some(api) = api {
+                let summary = api.seed("seed-players", &data, Some(mode)).await?;
+                summary.print_summary();
+
+                if summary.error_count > 0 {
+                    std::process::exit(1);
+                }
             } else {
                 let database_url = std::env::var("DATABASE_URL")
                     .expect("DATABASE_URL must be set in environment or .env file");
                 let pool = create_pool(&database_url).await?;
-                let repo = SqlxPlayerRepository::new(pool);
+                let Some(seed_file) = start_seed_run(&pool, "players", &file, force).await? else {
+                    return Ok(());
+                };
+                if mode == LoadMode::Upsert {
+                    let name = format!("players-load-{}", data.meta.draft_year);
+                    let scope = Scope::Players {
+                        draft_year: data.meta.draft_year,
+                    };
+                    back_up(&pool, backup_dir, &name, vec![scope]).await?;
+                }
+                let repo = SqlxPlayerRepository::new(pool.clone()).with_batch_size(batch_size);
 
-                let stats = loader::load_players(&data, &repo).await?;
+                let stats = loader::load_players(&data, &repo, mode).await?;
                 stats.print_summary();
 
                 if !stats.errors.is_empty() {
                     std::process::exit(1);
                 }
+
+                seed_file.record(&pool).await?;
+            }
+        }
+
+        PlayerActions::Export { year, output } => {
+            println!("Exporting players for draft year {} to {}", year, output);
+
+            let database_url = std::env::var("DATABASE_URL")
+                .expect("DATABASE_URL must be set in environment or .env file");
+            let pool = create_pool(&database_url).await?;
+            let repo = SqlxPlayerRepository::new(pool);
+
+            let data = export::export_players(year, &repo).await?;
+            export::write_json_file(&data, &output)?;
+            println!("Exported {} players", data.players.len());
+        }
+
+        PlayerActions::Generate {
+            count,
+            year,
+            seed,
+            percentiles,
+            output_dir,
+        } => {
+            println!(
+                "Generating {} synthetic prospects for draft year {} (seed {})",
+                count, year, seed
+            );
+            let baselines = percentile_loader::parse_percentile_file(&percentiles)?;
+            let class = prospect_generator::generate_class(count, year, seed, &baselines)?;
+            for path in class.write(&output_dir)? {
+                println!("  Wrote {}", path);
             }
+            println!(
+                "Generated {} players, {} combine results and {} rankings",
+                class.players.players.len(),
+                class.combine.combine_results.len(),
+                class.rankings.rankings.len()
+            );
         }
 
         PlayerActions::Clear { year } => {
@@ -485,6 +925,7 @@ async fn handle_players(action: PlayerActions) -> Result<()> {
             let database_url = std::env::var("DATABASE_URL")
                 .expect("DATABASE_URL must be set in environment or .env file");
             let pool = create_pool(&database_url).await?;
+            seed_runs::forget(&pool, "players").await?;
             let repo = SqlxPlayerRepository::new(pool.clone());
 
             // Count existing players first
@@ -498,6 +939,14 @@ async fn handle_players(action: PlayerActions) -> Result<()> {
 
             println!("Found {} players to delete", count);
 
+            back_up(
+                &pool,
+                backup_dir,
+                &format!("players-clear-{}", year),
+                vec![Scope::Players { draft_year: year }],
+            )
+            .await?;
+
             // Delete using raw SQL for efficiency
             let result = sqlx::query("DELETE FROM players WHERE draft_year = $1")
                 .bind(year)
@@ -511,7 +960,21 @@ async fn handle_players(action: PlayerActions) -> Result<()> {
     Ok(())
 }
 
-async fn handle_teams(action: TeamActions) -> Result<()> {
+async fn handle_teams(
+    action: TeamActions,
+    force: bool,
+    backup_dir: Option<&str>,
+    api: Option<&SeedApiClient>,
+) -> Result<()> {
+    if api.is_some()
+        && !matches!(
+            action,
+            TeamActions::Load { .. } | TeamActions::Validate { .. }
+        )
+    {
+        bail!(VIA_API_UNSUPPORTED);
+    }
+
     match action {
         TeamActions::Validate { file } => {
             println!("Validating: {}", file);
@@ -526,7 +989,11 @@ async fn handle_teams(action: TeamActions) -> Result<()> {
             }
         }
 
-        TeamActions::Load { file, dry_run } => {
+        TeamActions::Load {
+            file,
+            dry_run,
+            mode,
+        } => {
             if dry_run {
                 println!("DRY RUN - Validating and simulating load: {}", file);
             } else {
@@ -553,27 +1020,56 @@ async fn handle_teams(action: TeamActions) -> Result<()> {
                 if !stats.errors.is_empty() {
                     std::process::exit(1);
                 }
+            } else if let Some(api) = api {
+                let summary = api.seed("seed-teams", &data, Some(mode)).await?;
+                summary.print_summary();
+
+                if summary.error_count > 0 {
+                    std::process::exit(1);
+                }
             } else {
                 let database_url = std::env::var("DATABASE_URL")
                     .expect("DATABASE_URL must be set in environment or .env file");
                 let pool = create_pool(&database_url).await?;
-                let repo = SqlxTeamRepository::new(pool);
+                let Some(seed_file) = start_seed_run(&pool, "teams", &file, force).await? else {
+                    return Ok(());
+                };
+                if mode == LoadMode::Upsert {
+                    back_up(&pool, backup_dir, "teams-load", vec![Scope::Teams]).await?;
+                }
+                let repo = SqlxTeamRepository::new(pool.clone());
 
-                let stats = team_loader::load_teams(&data, &repo).await?;
+                let stats = team_loader::load_teams(&data, &repo, mode).await?;
                 stats.print_summary();
 
                 if !stats.errors.is_empty() {
                     std::process::exit(1);
                 }
+
+                seed_file.record(&pool).await?;
             }
         }
 
+        TeamActions::Export { output } => {
+            println!("Exporting teams to {}", output);
+
+            let database_url = std::env::var("DATABASE_URL")
+                .expect("DATABASE_URL must be set in environment or .env file");
+            let pool = create_pool(&database_url).await?;
+            let repo = SqlxTeamRepository::new(pool);
+
+            let data = export::export_teams(&repo).await?;
+            export::write_json_file(&data, &output)?;
+            println!("Exported {} teams", data.teams.len());
+        }
+
         TeamActions::Clear => {
             println!("Clearing all teams from the database");
 
             let database_url = std::env::var("DATABASE_URL")
                 .expect("DATABASE_URL must be set in environment or .env file");
             let pool = create_pool(&database_url).await?;
+            seed_runs::forget(&pool, "teams").await?;
 
             // Count existing teams first
             let count: i64 = sqlx::query_scalar("SELECT COUNT(*) FROM teams")
@@ -587,6 +1083,8 @@ async fn handle_teams(action: TeamActions) -> Result<()> {
 
             println!("Found {} teams to delete", count);
 
+            back_up(&pool, backup_dir, "teams-clear", vec![Scope::Teams]).await?;
+
             // Delete all teams
             let result = sqlx::query("DELETE FROM teams").execute(&pool).await?;
 
@@ -597,7 +1095,21 @@ async fn handle_teams(action: TeamActions) -> Result<()> {
     Ok(())
 }
 
-async fn handle_needs(action: NeedActions) -> Result<()> {
+async fn handle_needs(
+    action: NeedActions,
+    force: bool,
+    backup_dir: Option<&str>,
+    api: Option<&SeedApiClient>,
+) -> Result<()> {
+    if api.is_some()
+        && !matches!(
+            action,
+            NeedActions::Load { .. } | NeedActions::Validate { .. }
+        )
+    {
+        bail!(VIA_API_UNSUPPORTED);
+    }
+
     match action {
         NeedActions::Validate { file } => {
             println!("Validating: {}", file);
@@ -639,12 +1151,23 @@ async fn handle_needs(action: NeedActions) -> Result<()> {
                 if !stats.errors.is_empty() {
                     std::process::exit(1);
                 }
+            } else if let Some(api) = api {
+                let summary = api.seed("seed-team-needs", &data, None).await?;
+                summary.print_summary();
+
+                if summary.error_count > 0 {
+                    std::process::exit(1);
+                }
             } else {
                 let database_url = std::env::var("DATABASE_URL")
                     .expect("DATABASE_URL must be set in environment or .env file");
                 let pool = create_pool(&database_url).await?;
+                let Some(seed_file) = start_seed_run(&pool, "needs", &file, force).await? else {
+                    return Ok(());
+                };
+                back_up(&pool, backup_dir, "needs-load", vec![Scope::TeamNeeds]).await?;
                 let team_repo = SqlxTeamRepository::new(pool.clone());
-                let team_need_repo = SqlxTeamNeedRepository::new(pool);
+                let team_need_repo = SqlxTeamNeedRepository::new(pool.clone());
 
                 let stats =
                     team_need_loader::load_team_needs(&data, &team_repo, &team_need_repo).await?;
@@ -653,15 +1176,32 @@ async fn handle_needs(action: NeedActions) -> Result<()> {
                 if !stats.errors.is_empty() {
                     std::process::exit(1);
                 }
+
+                seed_file.record(&pool).await?;
             }
         }
 
+        NeedActions::Export { output } => {
+            println!("Exporting team needs to {}", output);
+
+            let database_url = std::env::var("DATABASE_URL")
+                .expect("DATABASE_URL must be set in environment or .env file");
+            let pool = create_pool(&database_url).await?;
+            let team_repo = SqlxTeamRepository::new(pool.clone());
+            let team_need_repo = SqlxTeamNeedRepository::new(pool);
+
+            let data = export::export_team_needs(&team_repo, &team_need_repo).await?;
+            export::write_json_file(&data, &output)?;
+            println!("Exported needs for {} teams", data.team_needs.len());
+        }
+
         NeedActions::Clear => {
             println!("Clearing all team needs from the database");
 
             let database_url = std::env::var("DATABASE_URL")
                 .expect("DATABASE_URL must be set in environment or .env file");
             let pool = create_pool(&database_url).await?;
+            seed_runs::forget(&pool, "needs").await?;
 
             // Count existing team needs first
             let count: i64 = sqlx::query_scalar("SELECT COUNT(*) FROM team_needs")
@@ -675,6 +1215,8 @@ async fn handle_needs(action: NeedActions) -> Result<()> {
 
             println!("Found {} team needs to delete", count);
 
+            back_up(&pool, backup_dir, "needs-clear", vec![Scope::TeamNeeds]).await?;
+
             // Delete all team needs
             let result = sqlx::query("DELETE FROM team_needs").execute(&pool).await?;
 
@@ -685,7 +1227,21 @@ async fn handle_needs(action: NeedActions) -> Result<()> {
     Ok(())
 }
 
-async fn handle_seasons(action: SeasonActions) -> Result<()> {
+async fn handle_seasons(
+    action: SeasonActions,
+    force: bool,
+    backup_dir: Option<&str>,
+    api: Option<&SeedApiClient>,
+) -> Result<()> {
+    if api.is_some()
+        && !matches!(
+            action,
+            SeasonActions::Load { .. } | SeasonActions::Validate { .. }
+        )
+    {
+        bail!(VIA_API_UNSUPPORTED);
+    }
+
     match action {
         SeasonActions::Validate { file } => {
             println!("Validating: {}", file);
@@ -735,12 +1291,31 @@ async fn handle_seasons(action: SeasonActions) -> Result<()> {
                 if !stats.errors.is_empty() {
                     std::process::exit(1);
                 }
+            } else if let Some(api) = api {
+                let summary = api.seed("seed-team-seasons", &data, None).await?;
+                summary.print_summary();
+
+                if summary.error_count > 0 {
+                    std::process::exit(1);
+                }
             } else {
                 let database_url = std::env::var("DATABASE_URL")
                     .expect("DATABASE_URL must be set in environment or .env file");
                 let pool = create_pool(&database_url).await?;
+                let Some(seed_file) = start_seed_run(&pool, "seasons", &file, force).await? else {
+                    return Ok(());
+                };
+                let year = data.meta.season_year;
+                let scope = Scope::TeamSeasons { season_year: year };
+                back_up(
+                    &pool,
+                    backup_dir,
+                    &format!("seasons-load-{}", year),
+                    vec![scope],
+                )
+                .await?;
                 let team_repo = SqlxTeamRepository::new(pool.clone());
-                let team_season_repo = SqlxTeamSeasonRepository::new(pool);
+                let team_season_repo = SqlxTeamSeasonRepository::new(pool.clone());
 
                 let stats =
                     team_season_loader::load_team_seasons(&data, &team_repo, &team_season_repo)
@@ -750,15 +1325,32 @@ async fn handle_seasons(action: SeasonActions) -> Result<()> {
                 if !stats.errors.is_empty() {
                     std::process::exit(1);
                 }
+
+                seed_file.record(&pool).await?;
             }
         }
 
+        SeasonActions::Export { year, output } => {
+            println!("Exporting team seasons for {} to {}", year, output);
+
+            let database_url = std::env::var("DATABASE_URL")
+                .expect("DATABASE_URL must be set in environment or .env file");
+            let pool = create_pool(&database_url).await?;
+            let team_repo = SqlxTeamRepository::new(pool.clone());
+            let team_season_repo = SqlxTeamSeasonRepository::new(pool);
+
+            let data = export::export_team_seasons(year, &team_repo, &team_season_repo).await?;
+            export::write_json_file(&data, &output)?;
+            println!("Exported {} team seasons", data.team_seasons.len());
+        }
+
         SeasonActions::Clear { year } => {
             println!("Clearing all team seasons for year {}", year);
 
             let database_url = std::env::var("DATABASE_URL")
                 .expect("DATABASE_URL must be set in environment or .env file");
             let pool = create_pool(&database_url).await?;
+            seed_runs::forget(&pool, "seasons").await?;
 
             // Count existing team seasons first
             let count: i64 =
@@ -774,6 +1366,14 @@ async fn handle_seasons(action: SeasonActions) -> Result<()> {
 
             println!("Found {} team seasons to delete", count);
 
+            back_up(
+                &pool,
+                backup_dir,
+                &format!("seasons-clear-{}", year),
+                vec![Scope::TeamSeasons { season_year: year }],
+            )
+            .await?;
+
             // Delete team seasons for the year
             let result = sqlx::query("DELETE FROM team_seasons WHERE season_year = $1")
                 .bind(year)
@@ -787,7 +1387,12 @@ async fn handle_seasons(action: SeasonActions) -> Result<()> {
     Ok(())
 }
 
-async fn handle_draft_order(action: DraftOrderActions) -> Result<()> {
+async fn handle_draft_order(
+    action: DraftOrderActions,
+    batch_size: usize,
+    force: bool,
+    backup_dir: Option<&str>,
+) -> Result<()> {
     match action {
         DraftOrderActions::Validate { file } => {
             println!("Validating: {}", file);
@@ -840,9 +1445,23 @@ async fn handle_draft_order(action: DraftOrderActions) -> Result<()> {
                 let database_url = std::env::var("DATABASE_URL")
                     .expect("DATABASE_URL must be set in environment or .env file");
                 let pool = create_pool(&database_url).await?;
+                let Some(seed_file) = start_seed_run(&pool, "draft-order", &file, force).await?
+                else {
+                    return Ok(());
+                };
+                let year = data.meta.draft_year;
+                let scope = Scope::DraftPicks { year };
+                back_up(
+                    &pool,
+                    backup_dir,
+                    &format!("draft-order-load-{}", year),
+                    vec![scope],
+                )
+                .await?;
                 let team_repo = SqlxTeamRepository::new(pool.clone());
                 let draft_repo = SqlxDraftRepository::new(pool.clone());
-                let pick_repo = SqlxDraftPickRepository::new(pool);
+                let pick_repo =
+                    SqlxDraftPickRepository::new(pool.clone()).with_batch_size(batch_size);
 
                 let stats = draft_order_loader::load_draft_order(
                     &data,
@@ -856,6 +1475,8 @@ async fn handle_draft_order(action: DraftOrderActions) -> Result<()> {
                 if !stats.errors.is_empty() {
                     std::process::exit(1);
                 }
+
+                seed_file.record(&pool).await?;
             }
         }
 
@@ -865,6 +1486,7 @@ async fn handle_draft_order(action: DraftOrderActions) -> Result<()> {
             let database_url = std::env::var("DATABASE_URL")
                 .expect("DATABASE_URL must be set in environment or .env file");
             let pool = create_pool(&database_url).await?;
+            seed_runs::forget(&pool, "draft-order").await?;
 
             // Find draft by year
             let draft =
@@ -889,6 +1511,14 @@ async fn handle_draft_order(action: DraftOrderActions) -> Result<()> {
 
                     println!("Found {} draft picks to delete", pick_count);
 
+                    back_up(
+                        &pool,
+                        backup_dir,
+                        &format!("draft-order-clear-{}", year),
+                        vec![Scope::DraftPicks { year }],
+                    )
+                    .await?;
+
                     // Delete picks
                     let result = sqlx::query("DELETE FROM draft_picks WHERE draft_id = $1")
                         .bind(draft_id)
@@ -907,7 +1537,13 @@ async fn handle_draft_order(action: DraftOrderActions) -> Result<()> {
     Ok(())
 }
 
-async fn handle_scouting(action: ScoutingActions) -> Result<()> {
+async fn handle_scouting(
+    action: ScoutingActions,
+    batch_size: usize,
+    concurrency: usize,
+    force: bool,
+    backup_dir: Option<&str>,
+) -> Result<()> {
     match action {
         ScoutingActions::Validate { file } => {
             println!("Validating: {}", file);
@@ -927,7 +1563,11 @@ async fn handle_scouting(action: ScoutingActions) -> Result<()> {
             }
         }
 
-        ScoutingActions::Load { file, dry_run } => {
+        ScoutingActions::Load {
+            file,
+            dry_run,
+            profiles,
+        } => {
             if dry_run {
                 println!("DRY RUN - Validating and simulating load: {}", file);
             } else {
@@ -942,6 +1582,8 @@ async fn handle_scouting(action: ScoutingActions) -> Result<()> {
                 data.meta.source
             );
 
+            let profiles = load_grade_profiles(profiles.as_deref())?;
+
             // Validate first
             let validation = scouting_report_validator::validate_ranking_data(&data);
             validation.print_summary();
@@ -962,6 +1604,18 @@ async fn handle_scouting(action: ScoutingActions) -> Result<()> {
                 let database_url = std::env::var("DATABASE_URL")
                     .expect("DATABASE_URL must be set in environment or .env file");
                 let pool = create_pool(&database_url).await?;
+                let Some(seed_file) = start_seed_run(&pool, "scouting", &file, force).await? else {
+                    return Ok(());
+                };
+                let year = data.meta.draft_year;
+                let scope = Scope::ScoutingReports { draft_year: year };
+                back_up(
+                    &pool,
+                    backup_dir,
+                    &format!("scouting-load-{}", year),
+                    vec![scope],
+                )
+                .await?;
                 let player_repo = SqlxPlayerRepository::new(pool.clone());
                 let team_repo = SqlxTeamRepository::new(pool.clone());
 
@@ -970,6 +1624,8 @@ async fn handle_scouting(action: ScoutingActions) -> Result<()> {
                     &player_repo,
                     &team_repo,
                     &pool,
+                    &profiles,
+                    batch_size,
                 )
                 .await?;
                 stats.print_summary();
@@ -977,10 +1633,13 @@ async fn handle_scouting(action: ScoutingActions) -> Result<()> {
                 if !stats.errors.is_empty() {
                     std::process::exit(1);
                 }
+
+                seed_file.record(&pool).await?;
             }
         }
 
-        ScoutingActions::Backfill { year } => {
+        ScoutingActions::Backfill { year, profiles } => {
+            let profiles = load_grade_profiles(profiles.as_deref())?;
             println!(
                 "Backfilling scouting reports for unscouted players in draft year {}...",
                 year
@@ -988,7 +1647,7 @@ async fn handle_scouting(action: ScoutingActions) -> Result<()> {
 
             let database_url = std::env::var("DATABASE_URL")
                 .expect("DATABASE_URL must be set in environment or .env file");
-            let pool = create_pool(&database_url).await?;
+            let pool = create_concurrent_pool(&database_url, concurrency).await?;
             let team_repo = SqlxTeamRepository::new(pool.clone());
             let profile_repo = SqlxProspectProfileRepository::new(pool.clone());
             let scouting_report_repo = SqlxScoutingReportRepository::new(pool.clone());
@@ -999,6 +1658,8 @@ async fn handle_scouting(action: ScoutingActions) -> Result<()> {
                 &team_repo,
                 &profile_repo,
                 &scouting_report_repo,
+                &profiles,
+                concurrency,
             )
             .await?;
             stats.print_summary();
@@ -1008,29 +1669,164 @@ async fn handle_scouting(action: ScoutingActions) -> Result<()> {
             }
         }
 
-        ScoutingActions::Clear { year } => {
-            println!("Clearing all scouting reports for draft year {}", year);
-
+        ScoutingActions::Sources => {
             let database_url = std::env::var("DATABASE_URL")
                 .expect("DATABASE_URL must be set in environment or .env file");
             let pool = create_pool(&database_url).await?;
 
-            // Count existing scouting reports for this draft year
-            let count: i64 = sqlx::query_scalar(
-                "SELECT COUNT(*) FROM scouting_reports WHERE player_id IN (SELECT id FROM players WHERE draft_year = $1)"
-            )
-            .bind(year)
-            .fetch_one(&pool)
-            .await?;
-
-            if count == 0 {
-                println!("No scouting reports found for draft year {}", year);
+            let sources = rankings_loader::list_sources(&pool).await?;
+            if sources.is_empty() {
+                println!("No ranking sources loaded");
                 return Ok(());
             }
 
-            println!("Found {} scouting reports to delete", count);
-
-            let result = sqlx::query(
+            println!(
+                "{:<24} {:>8} {:<12} {:<11} LOADED AT",
+                "SOURCE", "RANKINGS", "DRAFT YEARS", "SCRAPED"
+            );
+            for source in &sources {
+                let years = source
+                    .draft_years
+                    .iter()
+                    .map(|y| y.to_string())
+                    .collect::<Vec<_>>()
+                    .join(", ");
+                println!(
+                    "{:<24} {:>8} {:<12} {:<11} {}",
+                    source.name,
+                    source.rankings,
+                    if years.is_empty() {
+                        "-".to_string()
+                    } else {
+                        years
+                    },
+                    source
+                        .scraped_at
+                        .map(|d| d.to_string())
+                        .unwrap_or_else(|| "-".to_string()),
+                    source
+                        .loaded_at
+                        .map(|t| t.format("%Y-%m-%d %H:%M:%S").to_string())
+                        .unwrap_or_else(|| "-".to_string()),
+                );
+            }
+        }
+
+        ScoutingActions::Refresh {
+            file,
+            dry_run,
+            source_name,
+            profiles,
+        } => {
+            let mut data = scouting_report_loader::parse_ranking_file(&file)?;
+            if let Some(name) = source_name {
+                data.meta.source = name;
+            }
+            println!(
+                "Refreshing '{}' from {} ({} rankings, draft year {})",
+                data.meta.source,
+                file,
+                data.rankings.len(),
+                data.meta.draft_year
+            );
+
+            let profiles = load_grade_profiles(profiles.as_deref())?;
+
+            let validation = rankings_validator::validate_ranking_data(&data);
+            validation.print_summary();
+            if !validation.valid {
+                println!("\nAborting refresh due to validation errors.");
+                std::process::exit(1);
+            }
+
+            let database_url = std::env::var("DATABASE_URL")
+                .expect("DATABASE_URL must be set in environment or .env file");
+            let pool = create_concurrent_pool(&database_url, concurrency).await?;
+
+            let board = rankings_refresh::load_board(&pool, &data.meta.source).await?;
+            let plan = rankings_refresh::plan_refresh(&board, &data);
+            plan.print_report();
+
+            if dry_run {
+                println!("\n[DRY RUN] Board not changed");
+                return Ok(());
+            }
+            if plan.is_empty() {
+                println!("\nBoard is up to date");
+                return Ok(());
+            }
+
+            let Some(seed_file) = start_seed_run(&pool, "rankings", &file, force).await? else {
+                return Ok(());
+            };
+            let scope = Scope::Rankings {
+                source: data.meta.source.clone(),
+            };
+            let name = format!("rankings-refresh-{}", data.meta.source);
+            back_up(&pool, backup_dir, &name, vec![scope]).await?;
+            let player_repo = SqlxPlayerRepository::new(pool.clone()).with_batch_size(batch_size);
+            let team_repo = SqlxTeamRepository::new(pool.clone());
+            let ranking_source_repo = SqlxRankingSourceRepository::new(pool.clone());
+            let scouting_report_repo = SqlxScoutingReportRepository::new(pool.clone());
+
+            let stats = rankings_refresh::apply_refresh(
+                &plan,
+                &data,
+                &pool,
+                batch_size,
+                &player_repo,
+                &team_repo,
+                &ranking_source_repo,
+                &scouting_report_repo,
+                &profiles,
+                concurrency,
+            )
+            .await?;
+            stats.print_summary();
+
+            seed_file.record(&pool).await?;
+        }
+
+        ScoutingActions::Clear {
+            source: Some(source),
+            ..
+        } => {
+            clear_ranking_source(&source, backup_dir).await?;
+        }
+
+        ScoutingActions::Clear { year, .. } => {
+            let year = year.context("--year or --source is required")?;
+            println!("Clearing all scouting reports for draft year {}", year);
+
+            let database_url = std::env::var("DATABASE_URL")
+                .expect("DATABASE_URL must be set in environment or .env file");
+            let pool = create_pool(&database_url).await?;
+            seed_runs::forget(&pool, "scouting").await?;
+
+            // Count existing scouting reports for this draft year
+            let count: i64 = sqlx::query_scalar(
+                "SELECT COUNT(*) FROM scouting_reports WHERE player_id IN (SELECT id FROM players WHERE draft_year = $1)"
+            )
+            .bind(year)
+            .fetch_one(&pool)
+            .await?;
+
+            if count == 0 {
+                println!("No scouting reports found for draft year {}", year);
+                return Ok(());
+            }
+
+            println!("Found {} scouting reports to delete", count);
+
+            back_up(
+                &pool,
+                backup_dir,
+                &format!("scouting-clear-{}", year),
+                vec![Scope::ScoutingReports { draft_year: year }],
+            )
+            .await?;
+
+            let result = sqlx::query(
                 "DELETE FROM scouting_reports WHERE player_id IN (SELECT id FROM players WHERE draft_year = $1)"
             )
             .bind(year)
@@ -1044,11 +1840,201 @@ async fn handle_scouting(action: ScoutingActions) -> Result<()> {
     Ok(())
 }
 
-async fn handle_rankings(action: RankingsActions) -> Result<()> {
+fn handle_anonymize(
+    players: Option<String>,
+    combine: Option<String>,
+    rankings: Vec<String>,
+    output_dir: &str,
+    seed: u64,
+) -> Result<()> {
+    if players.is_none() && combine.is_none() && rankings.is_empty() {
+        bail!("Nothing to anonymize: pass --players, --combine or --rankings");
+    }
+    std::fs::create_dir_all(output_dir)
+        .with_context(|| format!("Failed to create output directory: {}", output_dir))?;
+
+    // Each file keeps its name, as JSON, in the output directory
+    let output_path = |input: &str| -> Result<String> {
+        let input = Path::new(input);
+        let stem = input
+            .file_stem()
+            .with_context(|| format!("Not a file: {}", input.display()))?;
+        let output = Path::new(output_dir).join(stem).with_extension("json");
+        if output.canonicalize().ok() == input.canonicalize().ok() && output.exists() {
+            bail!("Refusing to overwrite the input file {}", input.display());
+        }
+        Ok(output.to_string_lossy().into_owned())
+    };
+
+    // Players go first so the other files reuse their mapping
+    let mut anonymizer = anonymize::Anonymizer::new(seed);
+    if let Some(file) = players {
+        let mut data = loader::parse_player_file(&file)?;
+        anonymizer.players(&mut data)?;
+        let output = output_path(&file)?;
+        export::write_json_file(&data, &output)?;
+        println!("Wrote {} players to {}", data.players.len(), output);
+    }
+    if let Some(file) = combine {
+        let mut data = combine_loader::parse_combine_file(&file)?;
+        anonymizer.combine(&mut data)?;
+        let output = output_path(&file)?;
+        export::write_json_file(&data, &output)?;
+        println!(
+            "Wrote {} combine results to {}",
+            data.combine_results.len(),
+            output
+        );
+    }
+    for file in rankings {
+        let mut data = scouting_report_loader::parse_ranking_file(&file)?;
+        anonymizer.rankings(&mut data)?;
+        let output = output_path(&file)?;
+        export::write_json_file(&data, &output)?;
+        println!("Wrote {} rankings to {}", data.rankings.len(), output);
+    }
+    Ok(())
+}
+
+/// Checks a seed file against the recorded runs. `None` means these exact
+/// contents were already loaded and the load should be skipped.
+async fn start_seed_run(
+    pool: &PgPool,
+    kind: &'static str,
+    file: &str,
+    force: bool,
+) -> Result<Option<SeedFile>> {
+    let seed_file = SeedFile::read(kind, file)?;
+    if !force {
+        if let Some(loaded_at) = seed_file.loaded_at(pool).await? {
+            println!(
+                "Skipping {}: the same contents were loaded at {} (use --force to load again)",
+                file,
+                loaded_at.format("%Y-%m-%d %H:%M:%S UTC")
+            );
+            return Ok(None);
+        }
+    }
+    Ok(Some(seed_file))
+}
+
+/// Backs up the rows a clear or load is about to delete or replace, unless
+/// `--no-backup` was given. Nothing is written when the scopes are empty.
+async fn back_up(
+    pool: &PgPool,
+    backup_dir: Option<&str>,
+    name: &str,
+    scopes: Vec<Scope>,
+) -> Result<()> {
+    let Some(backup_dir) = backup_dir else {
+        return Ok(());
+    };
+    let backup = Backup::take(pool, name, scopes).await?;
+    if backup.row_count() == 0 {
+        return Ok(());
+    }
+    let path = backup.write(backup_dir)?;
+    println!(
+        "Backed up {} rows to {} (undo with `seed-data restore --backup {}`)",
+        backup.row_count(),
+        path.display(),
+        path.display()
+    );
+    Ok(())
+}
+
+async fn handle_restore(file: &str, backup_dir: Option<&str>) -> Result<()> {
+    let backup = Backup::read(file)?;
+    println!(
+        "Restoring {} rows from {} (taken {})",
+        backup.row_count(),
+        backup.name,
+        backup.created_at.format("%Y-%m-%d %H:%M:%S UTC")
+    );
+
+    let database_url = std::env::var("DATABASE_URL")
+        .expect("DATABASE_URL must be set in environment or .env file");
+    let pool = create_pool(&database_url).await?;
+
+    // Restoring replaces what is there now, so it is backed up too
+    let name = format!("before-restore-{}", backup.name);
+    back_up(&pool, backup_dir, &name, backup.scopes.clone()).await?;
+
+    let stats = backup::restore(&pool, &backup).await?;
+    stats.print_summary();
+    Ok(())
+}
+
+async fn handle_status() -> Result<()> {
+    let database_url = std::env::var("DATABASE_URL")
+        .expect("DATABASE_URL must be set in environment or .env file");
+    let pool = create_pool(&database_url).await?;
+
+    let runs = seed_runs::list_runs(&pool).await?;
+    let entries = seed_runs::status_entries(&runs);
+    if entries.is_empty() {
+        println!("No seed files found or loaded");
+        return Ok(());
+    }
+
+    println!("{:<16} {:<12} {:<21} FILE", "KIND", "STATUS", "LOADED AT");
+    for entry in &entries {
+        let loaded_at = entry
+            .loaded_at
+            .map(|t| t.format("%Y-%m-%d %H:%M:%S").to_string())
+            .unwrap_or_else(|| "-".to_string());
+        println!(
+            "{:<16} {:<12} {:<21} {}",
+            entry.kind,
+            entry.status.as_str(),
+            loaded_at,
+            entry.file_path
+        );
+    }
+    Ok(())
+}
+
+/// A pool with a connection for each concurrent insert, plus one for the
+/// loader's own queries
+async fn create_concurrent_pool(database_url: &str, concurrency: usize) -> Result<PgPool> {
+    let settings = PoolSettings {
+        max_connections: concurrency.max(1) as u32 + 1,
+        ..PoolSettings::default()
+    };
+    Ok(create_pool_with(database_url, &settings).await?)
+}
+
+/// Reads `--profiles`, or the league-wide defaults when it isn't given
+fn load_grade_profiles(path: Option<&str>) -> Result<GradeProfiles> {
+    match path {
+        Some(path) => {
+            let profiles = grade_generator::parse_grade_profiles_file(path)
+                .with_context(|| format!("Failed to read grade profiles from {}", path))?;
+            println!(
+                "Using grade profiles from {} ({} teams)",
+                path,
+                profiles.teams.len()
+            );
+            Ok(profiles)
+        }
+        None => Ok(GradeProfiles::default()),
+    }
+}
+
+async fn handle_rankings(
+    action: RankingsActions,
+    batch_size: usize,
+    concurrency: usize,
+    force: bool,
+    backup_dir: Option<&str>,
+) -> Result<()> {
     match action {
-        RankingsActions::Validate { file } => {
+        RankingsActions::Validate { file, source_name } => {
             println!("Validating: {}", file);
-            let data = scouting_report_loader::parse_ranking_file(&file)?;
+            let mut data = scouting_report_loader::parse_ranking_file(&file)?;
+            if let Some(name) = source_name {
+                data.meta.source = name;
+            }
             println!(
                 "Loaded {} rankings from file (draft year {}, source: {})",
                 data.rankings.len(),
@@ -1064,14 +2050,22 @@ async fn handle_rankings(action: RankingsActions) -> Result<()> {
             }
         }
 
-        RankingsActions::Load { file, dry_run } => {
+        RankingsActions::Load {
+            file,
+            dry_run,
+            profiles,
+            source_name,
+        } => {
             if dry_run {
                 println!("DRY RUN - Validating and simulating load: {}", file);
             } else {
                 println!("Loading prospect rankings from: {}", file);
             }
 
-            let data = scouting_report_loader::parse_ranking_file(&file)?;
+            let mut data = scouting_report_loader::parse_ranking_file(&file)?;
+            if let Some(name) = source_name {
+                data.meta.source = name;
+            }
             println!(
                 "Parsed {} rankings from file (draft year {}, source: {})",
                 data.rankings.len(),
@@ -1079,6 +2073,8 @@ async fn handle_rankings(action: RankingsActions) -> Result<()> {
                 data.meta.source
             );
 
+            let profiles = load_grade_profiles(profiles.as_deref())?;
+
             // Validate first
             let validation = rankings_validator::validate_ranking_data(&data);
             validation.print_summary();
@@ -1094,8 +2090,17 @@ async fn handle_rankings(action: RankingsActions) -> Result<()> {
             } else {
                 let database_url = std::env::var("DATABASE_URL")
                     .expect("DATABASE_URL must be set in environment or .env file");
-                let pool = create_pool(&database_url).await?;
-                let player_repo = SqlxPlayerRepository::new(pool.clone());
+                let pool = create_concurrent_pool(&database_url, concurrency).await?;
+                let Some(seed_file) = start_seed_run(&pool, "rankings", &file, force).await? else {
+                    return Ok(());
+                };
+                let scope = Scope::Rankings {
+                    source: data.meta.source.clone(),
+                };
+                let name = format!("rankings-load-{}", data.meta.source);
+                back_up(&pool, backup_dir, &name, vec![scope]).await?;
+                let player_repo =
+                    SqlxPlayerRepository::new(pool.clone()).with_batch_size(batch_size);
                 let team_repo = SqlxTeamRepository::new(pool.clone());
                 let ranking_source_repo = SqlxRankingSourceRepository::new(pool.clone());
                 let scouting_report_repo = SqlxScoutingReportRepository::new(pool.clone());
@@ -1103,10 +2108,13 @@ async fn handle_rankings(action: RankingsActions) -> Result<()> {
                 let stats = rankings_loader::load_rankings(
                     &data,
                     &pool,
+                    batch_size,
                     &player_repo,
                     &team_repo,
                     &ranking_source_repo,
                     &scouting_report_repo,
+                    &profiles,
+                    concurrency,
                 )
                 .await?;
                 stats.print_summary();
@@ -1114,32 +2122,47 @@ async fn handle_rankings(action: RankingsActions) -> Result<()> {
                 if !stats.errors.is_empty() {
                     std::process::exit(1);
                 }
+
+                seed_file.record(&pool).await?;
             }
         }
 
         RankingsActions::Clear { source } => {
-            println!("Clearing rankings for source: {}", source);
-
-            let database_url = std::env::var("DATABASE_URL")
-                .expect("DATABASE_URL must be set in environment or .env file");
-            let pool = create_pool(&database_url).await?;
-            let ranking_source_repo = SqlxRankingSourceRepository::new(pool.clone());
-            let prospect_ranking_repo = SqlxProspectRankingRepository::new(pool.clone());
-
-            let deleted = rankings_loader::clear_rankings(
-                &source,
-                &ranking_source_repo,
-                &prospect_ranking_repo,
-            )
-            .await?;
-            println!("Deleted {} rankings", deleted);
+            clear_ranking_source(&source, backup_dir).await?;
         }
     }
 
     Ok(())
 }
 
-async fn handle_freaks(action: FreaksActions) -> Result<()> {
+/// Deletes one source's board, leaving scouting reports and other sources
+async fn clear_ranking_source(source: &str, backup_dir: Option<&str>) -> Result<()> {
+    println!("Clearing rankings for source: {}", source);
+
+    let database_url = std::env::var("DATABASE_URL")
+        .expect("DATABASE_URL must be set in environment or .env file");
+    let pool = create_pool(&database_url).await?;
+    seed_runs::forget(&pool, "rankings").await?;
+    back_up(
+        &pool,
+        backup_dir,
+        &format!("rankings-clear-{}", source),
+        vec![Scope::Rankings {
+            source: source.to_string(),
+        }],
+    )
+    .await?;
+    let ranking_source_repo = SqlxRankingSourceRepository::new(pool.clone());
+    let prospect_ranking_repo = SqlxProspectRankingRepository::new(pool.clone());
+
+    let deleted =
+        rankings_loader::clear_rankings(source, &ranking_source_repo, &prospect_ranking_repo)
+            .await?;
+    println!("Deleted {} rankings", deleted);
+    Ok(())
+}
+
+async fn handle_freaks(action: FreaksActions, force: bool, backup_dir: Option<&str>) -> Result<()> {
     match action {
         FreaksActions::Validate { file } => {
             println!("Validating: {}", file);
@@ -1188,8 +2211,20 @@ async fn handle_freaks(action: FreaksActions) -> Result<()> {
                 let database_url = std::env::var("DATABASE_URL")
                     .expect("DATABASE_URL must be set in environment or .env file");
                 let pool = create_pool(&database_url).await?;
+                let Some(seed_file) = start_seed_run(&pool, "freaks", &file, force).await? else {
+                    return Ok(());
+                };
+                let year = data.meta.year;
+                let scope = Scope::Freaks { year };
+                back_up(
+                    &pool,
+                    backup_dir,
+                    &format!("freaks-load-{}", year),
+                    vec![scope],
+                )
+                .await?;
                 let player_repo = SqlxPlayerRepository::new(pool.clone());
-                let freak_repo = SqlxFeldmanFreakRepository::new(pool);
+                let freak_repo = SqlxFeldmanFreakRepository::new(pool.clone());
 
                 let stats =
                     feldman_freak_loader::load_freaks(&data, &player_repo, &freak_repo).await?;
@@ -1198,6 +2233,8 @@ async fn handle_freaks(action: FreaksActions) -> Result<()> {
                 if !stats.errors.is_empty() {
                     std::process::exit(1);
                 }
+
+                seed_file.record(&pool).await?;
             }
         }
 
@@ -1207,6 +2244,14 @@ async fn handle_freaks(action: FreaksActions) -> Result<()> {
             let database_url = std::env::var("DATABASE_URL")
                 .expect("DATABASE_URL must be set in environment or .env file");
             let pool = create_pool(&database_url).await?;
+            seed_runs::forget(&pool, "freaks").await?;
+            back_up(
+                &pool,
+                backup_dir,
+                &format!("freaks-clear-{}", year),
+                vec![Scope::Freaks { year }],
+            )
+            .await?;
             let freak_repo = SqlxFeldmanFreakRepository::new(pool);
 
             let deleted = feldman_freak_loader::clear_freaks(year, &freak_repo).await?;
@@ -1217,7 +2262,224 @@ async fn handle_freaks(action: FreaksActions) -> Result<()> {
     Ok(())
 }
 
-async fn handle_combine(action: CombineActions) -> Result<()> {
+async fn handle_actual_results(
+    action: ActualResultsActions,
+    force: bool,
+    backup_dir: Option<&str>,
+) -> Result<()> {
+    match action {
+        ActualResultsActions::Validate { file } => {
+            println!("Validating: {}", file);
+            let data = actual_results_loader::parse_actual_results_file(&file)?;
+            println!(
+                "Loaded {} picks from file (year {})",
+                data.picks.len(),
+                data.meta.year
+            );
+
+            let errors = actual_results_loader::validate_actual_results(&data);
+            if errors.is_empty() {
+                println!("No validation errors");
+            } else {
+                for error in &errors {
+                    println!("  - {}", error);
+                }
+                std::process::exit(1);
+            }
+        }
+
+        ActualResultsActions::Load { file, dry_run } => {
+            if dry_run {
+                println!("DRY RUN - Validating and simulating load: {}", file);
+            } else {
+                println!("Loading actual draft results from: {}", file);
+            }
+
+            let data = actual_results_loader::parse_actual_results_file(&file)?;
+            println!(
+                "Parsed {} picks from file (year {})",
+                data.picks.len(),
+                data.meta.year
+            );
+
+            if dry_run {
+                let stats = actual_results_loader::load_actual_results_dry_run(&data)?;
+                stats.print_summary();
+
+                if !stats.errors.is_empty() {
+                    std::process::exit(1);
+                }
+            } else {
+                let database_url = std::env::var("DATABASE_URL")
+                    .expect("DATABASE_URL must be set in environment or .env file");
+                let pool = create_pool(&database_url).await?;
+                let Some(seed_file) = start_seed_run(&pool, "actual-results", &file, force).await?
+                else {
+                    return Ok(());
+                };
+                let year = data.meta.year;
+                let scope = Scope::ActualResults { year };
+                back_up(
+                    &pool,
+                    backup_dir,
+                    &format!("actual-results-load-{}", year),
+                    vec![scope],
+                )
+                .await?;
+                let team_repo = SqlxTeamRepository::new(pool.clone());
+                let player_repo = SqlxPlayerRepository::new(pool.clone());
+                let actual_repo = SqlxActualDraftPickRepository::new(pool.clone());
+
+                let stats = actual_results_loader::load_actual_results(
+                    &data,
+                    &team_repo,
+                    &player_repo,
+                    &actual_repo,
+                )
+                .await?;
+                stats.print_summary();
+
+                if !stats.errors.is_empty() {
+                    std::process::exit(1);
+                }
+
+                seed_file.record(&pool).await?;
+            }
+        }
+
+        ActualResultsActions::Clear { year } => {
+            println!("Clearing actual draft results for year {}", year);
+
+            let database_url = std::env::var("DATABASE_URL")
+                .expect("DATABASE_URL must be set in environment or .env file");
+            let pool = create_pool(&database_url).await?;
+            seed_runs::forget(&pool, "actual-results").await?;
+            back_up(
+                &pool,
+                backup_dir,
+                &format!("actual-results-clear-{}", year),
+                vec![Scope::ActualResults { year }],
+            )
+            .await?;
+            let actual_repo = SqlxActualDraftPickRepository::new(pool);
+
+            let deleted = actual_results_loader::clear_actual_results(year, &actual_repo).await?;
+            println!("Deleted {} picks", deleted);
+        }
+    }
+
+    Ok(())
+}
+
+async fn handle_college_stats(
+    action: CollegeStatsActions,
+    force: bool,
+    backup_dir: Option<&str>,
+) -> Result<()> {
+    match action {
+        CollegeStatsActions::Validate { file } => {
+            println!("Validating: {}", file);
+            let data = college_stats_loader::parse_college_stats_file(&file)?;
+            println!(
+                "Loaded college stats for {} players from file (draft year {})",
+                data.players.len(),
+                data.meta.draft_year
+            );
+
+            let errors = college_stats_loader::validate_college_stats(&data);
+            if errors.is_empty() {
+                println!("No validation errors");
+            } else {
+                for error in &errors {
+                    println!("  - {}", error);
+                }
+                std::process::exit(1);
+            }
+        }
+
+        CollegeStatsActions::Load { file, dry_run } => {
+            if dry_run {
+                println!("DRY RUN - Validating and simulating load: {}", file);
+            } else {
+                println!("Loading college stats from: {}", file);
+            }
+
+            let data = college_stats_loader::parse_college_stats_file(&file)?;
+            println!(
+                "Parsed college stats for {} players from file (draft year {})",
+                data.players.len(),
+                data.meta.draft_year
+            );
+
+            if dry_run {
+                let stats = college_stats_loader::load_college_stats_dry_run(&data)?;
+                stats.print_summary();
+
+                if !stats.errors.is_empty() {
+                    std::process::exit(1);
+                }
+            } else {
+                let database_url = std::env::var("DATABASE_URL")
+                    .expect("DATABASE_URL must be set in environment or .env file");
+                let pool = create_pool(&database_url).await?;
+                let Some(seed_file) = start_seed_run(&pool, "college-stats", &file, force).await?
+                else {
+                    return Ok(());
+                };
+                let year = data.meta.draft_year;
+                let scope = Scope::CollegeStats { draft_year: year };
+                back_up(
+                    &pool,
+                    backup_dir,
+                    &format!("college-stats-load-{}", year),
+                    vec![scope],
+                )
+                .await?;
+                let player_repo = SqlxPlayerRepository::new(pool.clone());
+                let stats_repo = SqlxCollegeStatsRepository::new(pool.clone());
+
+                let stats =
+                    college_stats_loader::load_college_stats(&data, &player_repo, &stats_repo)
+                        .await?;
+                stats.print_summary();
+
+                if !stats.errors.is_empty() {
+                    std::process::exit(1);
+                }
+
+                seed_file.record(&pool).await?;
+            }
+        }
+
+        CollegeStatsActions::Clear { year } => {
+            println!("Clearing college stats for draft year {}", year);
+
+            let database_url = std::env::var("DATABASE_URL")
+                .expect("DATABASE_URL must be set in environment or .env file");
+            let pool = create_pool(&database_url).await?;
+            seed_runs::forget(&pool, "college-stats").await?;
+            back_up(
+                &pool,
+                backup_dir,
+                &format!("college-stats-clear-{}", year),
+                vec![Scope::CollegeStats { draft_year: year }],
+            )
+            .await?;
+            let stats_repo = SqlxCollegeStatsRepository::new(pool);
+
+            let deleted = college_stats_loader::clear_college_stats(year, &stats_repo).await?;
+            println!("Deleted {} seasons", deleted);
+        }
+    }
+
+    Ok(())
+}
+
+async fn handle_combine(
+    action: CombineActions,
+    force: bool,
+    backup_dir: Option<&str>,
+) -> Result<()> {
     match action {
         CombineActions::Validate { file } => {
             println!("Validating: {}", file);
@@ -1343,8 +2605,11 @@ async fn handle_combine(action: CombineActions) -> Result<()> {
                 let database_url = std::env::var("DATABASE_URL")
                     .expect("DATABASE_URL must be set in environment or .env file");
                 let pool = create_pool(&database_url).await?;
+                let Some(seed_file) = start_seed_run(&pool, "combine", &file, force).await? else {
+                    return Ok(());
+                };
                 let player_repo = SqlxPlayerRepository::new(pool.clone());
-                let combine_repo = SqlxCombineResultsRepository::new(pool);
+                let combine_repo = SqlxCombineResultsRepository::new(pool.clone());
 
                 let stats =
                     combine_loader::load_combine_data(&data, &player_repo, &combine_repo).await?;
@@ -1353,6 +2618,8 @@ async fn handle_combine(action: CombineActions) -> Result<()> {
                 if !stats.errors.is_empty() {
                     std::process::exit(1);
                 }
+
+                seed_file.record(&pool).await?;
             }
         }
 
@@ -1362,6 +2629,14 @@ async fn handle_combine(action: CombineActions) -> Result<()> {
             let database_url = std::env::var("DATABASE_URL")
                 .expect("DATABASE_URL must be set in environment or .env file");
             let pool = create_pool(&database_url).await?;
+            seed_runs::forget(&pool, "combine").await?;
+            back_up(
+                &pool,
+                backup_dir,
+                &format!("combine-clear-{}", year),
+                vec![Scope::Combine { year }],
+            )
+            .await?;
 
             let result = sqlx::query("DELETE FROM combine_results WHERE year = $1")
                 .bind(year)
@@ -1375,7 +2650,11 @@ async fn handle_combine(action: CombineActions) -> Result<()> {
     Ok(())
 }
 
-async fn handle_percentiles(action: PercentilesActions) -> Result<()> {
+async fn handle_percentiles(
+    action: PercentilesActions,
+    force: bool,
+    backup_dir: Option<&str>,
+) -> Result<()> {
     match action {
         PercentilesActions::Validate { file } => {
             println!("Validating: {}", file);
@@ -1393,15 +2672,29 @@ async fn handle_percentiles(action: PercentilesActions) -> Result<()> {
                 measurements.insert(entry.measurement.clone());
             }
 
+            let errors = percentile_loader::validate_percentiles(&data);
+
             println!("\nSummary:");
             println!("  Positions:    {} unique", positions.len());
             println!("  Measurements: {} unique", measurements.len());
             println!("  Total entries: {}", data.percentiles.len());
-            println!("\n  Result: VALID");
+            if errors.is_empty() {
+                println!("\n  Result: VALID");
+            } else {
+                println!("\n  Result: INVALID ({} errors)", errors.len());
+                for e in &errors {
+                    println!("    - {}", e);
+                }
+                std::process::exit(1);
+            }
         }
 
-        PercentilesActions::Load { file } => {
-            println!("Loading combine percentiles from: {}", file);
+        PercentilesActions::Load { file, dry_run } => {
+            if dry_run {
+                println!("DRY RUN - Validating combine percentiles: {}", file);
+            } else {
+                println!("Loading combine percentiles from: {}", file);
+            }
 
             let data = percentile_loader::parse_percentile_file(&file)?;
             println!(
@@ -1410,10 +2703,36 @@ async fn handle_percentiles(action: PercentilesActions) -> Result<()> {
                 data.meta.source
             );
 
+            if dry_run {
+                let errors = percentile_loader::validate_percentiles(&data);
+                println!(
+                    "\n[DRY RUN] Would upsert: {}",
+                    data.percentiles.len() - errors.len()
+                );
+                if !errors.is_empty() {
+                    println!("  Errors: {}", errors.len());
+                    for e in &errors {
+                        println!("    - {}", e);
+                    }
+                    std::process::exit(1);
+                }
+                return Ok(());
+            }
+
             let database_url = std::env::var("DATABASE_URL")
                 .expect("DATABASE_URL must be set in environment or .env file");
             let pool = create_pool(&database_url).await?;
-            let repo = SqlxCombinePercentileRepository::new(pool);
+            let Some(seed_file) = start_seed_run(&pool, "percentiles", &file, force).await? else {
+                return Ok(());
+            };
+            back_up(
+                &pool,
+                backup_dir,
+                "percentiles-load",
+                vec![Scope::Percentiles],
+            )
+            .await?;
+            let repo = SqlxCombinePercentileRepository::new(pool.clone());
 
             let stats = percentile_loader::load_percentiles(&data, &repo).await?;
 
@@ -1426,13 +2745,19 @@ async fn handle_percentiles(action: PercentilesActions) -> Result<()> {
                 }
                 std::process::exit(1);
             }
+
+            seed_file.record(&pool).await?;
         }
     }
 
     Ok(())
 }
 
-async fn handle_the_beast(action: TheBeastActions) -> Result<()> {
+async fn handle_the_beast(
+    action: TheBeastActions,
+    force: bool,
+    backup_dir: Option<&str>,
+) -> Result<()> {
     match action {
         TheBeastActions::Load { file, dry_run } => {
             if dry_run {
@@ -1456,6 +2781,18 @@ async fn handle_the_beast(action: TheBeastActions) -> Result<()> {
                 let database_url = std::env::var("DATABASE_URL")
                     .expect("DATABASE_URL must be set in environment or .env file");
                 let pool = create_pool(&database_url).await?;
+                let Some(seed_file) = start_seed_run(&pool, "the-beast", &file, force).await?
+                else {
+                    return Ok(());
+                };
+                let source = data.meta.source.clone();
+                let scopes = vec![
+                    Scope::ProspectProfiles {
+                        source: source.clone(),
+                    },
+                    Scope::Rankings { source },
+                ];
+                back_up(&pool, backup_dir, "the-beast-load", scopes).await?;
                 let player_repo = SqlxPlayerRepository::new(pool.clone());
                 let profile_repo = SqlxProspectProfileRepository::new(pool.clone());
                 let combine_repo = SqlxCombineResultsRepository::new(pool.clone());
@@ -1477,6 +2814,11 @@ async fn handle_the_beast(action: TheBeastActions) -> Result<()> {
                 if !stats.errors.is_empty() && stats.profiles_upserted == 0 {
                     std::process::exit(1);
                 }
+
+                // A partial load is kept, but runs again next time
+                if stats.errors.is_empty() {
+                    seed_file.record(&pool).await?;
+                }
             }
         }
     }
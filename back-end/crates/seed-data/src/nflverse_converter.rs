@@ -149,6 +149,7 @@ pub fn convert_nflverse_to_combine_json(
             position: canonical_pos,
             source: entry_source.to_string(),
             year,
+            event_date: None,
             forty_yard_dash: row.forty,
             bench_press: row.bench,
             vertical_jump: row.vertical,
@@ -1,4 +1,4 @@
-use anyhow::Result;
+use anyhow::{anyhow, Result};
 use serde::Deserialize;
 
 use domain::models::{CombinePercentile, Measurement};
@@ -37,6 +37,47 @@ pub struct PercentileFileEntry {
     pub years_end: i32,
 }
 
+impl PercentileFileEntry {
+    pub fn to_domain(&self) -> Result<CombinePercentile> {
+        let measurement: Measurement = self.measurement.parse().map_err(|e| {
+            anyhow!(
+                "Invalid measurement '{}' for {}: {}",
+                self.measurement,
+                self.position,
+                e
+            )
+        })?;
+
+        let percentile = CombinePercentile::new(self.position.clone(), measurement)
+            .map_err(|e| anyhow!("Invalid position '{}': {}", self.position, e))?;
+
+        percentile
+            .with_percentiles(
+                self.sample_size,
+                self.min_value,
+                self.p10,
+                self.p20,
+                self.p30,
+                self.p40,
+                self.p50,
+                self.p60,
+                self.p70,
+                self.p80,
+                self.p90,
+                self.max_value,
+            )
+            .and_then(|p| p.with_years(self.years_start, self.years_end))
+            .map_err(|e| {
+                anyhow!(
+                    "Validation error for {} {}: {}",
+                    self.position,
+                    self.measurement,
+                    e
+                )
+            })
+    }
+}
+
 fn default_years_start() -> i32 {
     2000
 }
@@ -59,6 +100,15 @@ pub fn parse_percentile_json(json: &str) -> Result<PercentileFileData> {
     Ok(data)
 }
 
+/// Checks every entry without touching the database. Errors name each
+/// entry that would fail to load.
+pub fn validate_percentiles(data: &PercentileFileData) -> Vec<String> {
+    data.percentiles
+        .iter()
+        .filter_map(|entry| entry.to_domain().err().map(|e| e.to_string()))
+        .collect()
+}
+
 pub async fn load_percentiles(
     data: &PercentileFileData,
     repo: &dyn CombinePercentileRepository,
@@ -67,48 +117,10 @@ pub async fn load_percentiles(
     let mut errors = Vec::new();
 
     for entry in &data.percentiles {
-        let measurement: Measurement = match entry.measurement.parse() {
-            Ok(m) => m,
-            Err(e) => {
-                errors.push(format!(
-                    "Invalid measurement '{}' for {}: {}",
-                    entry.measurement, entry.position, e
-                ));
-                continue;
-            }
-        };
-
-        let percentile = match CombinePercentile::new(entry.position.clone(), measurement) {
+        let percentile = match entry.to_domain() {
             Ok(p) => p,
             Err(e) => {
-                errors.push(format!("Invalid position '{}': {}", entry.position, e));
-                continue;
-            }
-        };
-
-        let percentile = match percentile
-            .with_percentiles(
-                entry.sample_size,
-                entry.min_value,
-                entry.p10,
-                entry.p20,
-                entry.p30,
-                entry.p40,
-                entry.p50,
-                entry.p60,
-                entry.p70,
-                entry.p80,
-                entry.p90,
-                entry.max_value,
-            )
-            .and_then(|p| p.with_years(entry.years_start, entry.years_end))
-        {
-            Ok(p) => p,
-            Err(e) => {
-                errors.push(format!(
-                    "Validation error for {} {}: {}",
-                    entry.position, entry.measurement, e
-                ));
+                errors.push(e.to_string());
                 continue;
             }
         };
@@ -153,5 +165,29 @@ mod tests {
         assert_eq!(data.percentiles.len(), 1);
         assert_eq!(data.percentiles[0].position, "QB");
         assert_eq!(data.percentiles[0].measurement, "forty_yard_dash");
+        assert!(validate_percentiles(&data).is_empty());
+    }
+
+    #[test]
+    fn test_validate_percentiles_names_bad_entries() {
+        let json = r#"{
+            "meta": { "source": "template" },
+            "percentiles": [
+                {
+                    "position": "QB",
+                    "measurement": "forty_yard_sprint",
+                    "sample_size": 200,
+                    "min_value": 4.4,
+                    "p10": 4.55, "p20": 4.6, "p30": 4.65, "p40": 4.7,
+                    "p50": 4.75, "p60": 4.8, "p70": 4.85, "p80": 4.9,
+                    "p90": 5.0,
+                    "max_value": 5.3
+                }
+            ]
+        }"#;
+
+        let errors = validate_percentiles(&parse_percentile_json(json).unwrap());
+        assert_eq!(errors.len(), 1);
+        assert!(errors[0].contains("forty_yard_sprint"), "{}", errors[0]);
     }
 }
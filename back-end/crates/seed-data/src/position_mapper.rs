@@ -1,71 +1,176 @@
-use anyhow::{anyhow, Result};
+//! Maps source position abbreviations to the canonical `Position` variants.
+//!
+//! The built-in aliases cover the common variations from scouting sources
+//! (EDGE -> DE, HB -> RB, OLB -> LB, ...). A JSON file passed with
+//! `--position-map` can add or change aliases and set a fallback position
+//! for anything still unmapped. The CLI installs it once at startup, so
+//! every loader and validator in the run maps positions the same way.
+
+use std::collections::HashMap;
+use std::fmt;
+use std::sync::OnceLock;
+
+use anyhow::{bail, Context, Result};
 use domain::models::Position;
+use serde::Deserialize;
 
-/// Maps source position abbreviations to the canonical Position enum variants.
-///
-/// Handles common variations from scouting sources (e.g., EDGE -> DE, HB -> RB).
-/// Returns an error for ambiguous positions that require manual assignment.
-/// Logs at debug level when an alternate abbreviation is mapped to a canonical position.
-pub fn map_position(source: &str) -> Result<Position> {
-    let normalized = source.trim().to_uppercase();
-
-    let (position, canonical) = match normalized.as_str() {
-        "QB" => (Position::QB, "QB"),
-        "RB" => (Position::RB, "RB"),
-        "HB" => (Position::RB, "RB"),
-        "WR" => (Position::WR, "WR"),
-        "TE" => (Position::TE, "TE"),
-        "OT" => (Position::OT, "OT"),
-        "T" => (Position::OT, "OT"),
-        "OG" => (Position::OG, "OG"),
-        "G" => (Position::OG, "OG"),
-        // IOL (Interior Offensive Line) → OG: most IOL prospects play guard
-        "IOL" => (Position::OG, "OG"),
-        "C" => (Position::C, "C"),
-        "DE" => (Position::DE, "DE"),
-        "EDGE" => (Position::DE, "DE"),
-        // EDGE/LB hybrid → DE: prioritize pass-rush role over coverage
-        "EDGE/LB" | "LB/EDGE" => (Position::DE, "DE"),
-        "DT" => (Position::DT, "DT"),
-        // DL (generic defensive line) → DT: most generic DL prospects are interior
-        "DL" => (Position::DT, "DT"),
-        "NT" => (Position::DT, "DT"),
-        "LB" => (Position::LB, "LB"),
-        "OLB" => (Position::LB, "LB"),
-        "ILB" => (Position::LB, "LB"),
-        "MLB" => (Position::LB, "LB"),
-        "CB" => (Position::CB, "CB"),
-        "S" => (Position::S, "S"),
-        "SS" => (Position::S, "S"),
-        "FS" => (Position::S, "S"),
-        // nflverse uses DB and SAF for defensive backs / safeties
-        "DB" => (Position::S, "S"),
-        "SAF" => (Position::S, "S"),
-        // nflverse uses OL for generic offensive linemen
-        "OL" => (Position::OG, "OG"),
-        // Fullback → RB
-        "FB" => (Position::RB, "RB"),
-        "K" => (Position::K, "K"),
-        "P" => (Position::P, "P"),
-        _ => {
-            return Err(anyhow!(
-                "Invalid position: '{}'. Must manually assign a valid position.",
-                source
-            ))
+const DEFAULT_ALIASES: &[(&str, Position)] = &[
+    ("QB", Position::QB),
+    ("RB", Position::RB),
+    ("HB", Position::RB),
+    // Fullback → RB
+    ("FB", Position::RB),
+    ("WR", Position::WR),
+    ("TE", Position::TE),
+    ("OT", Position::OT),
+    ("T", Position::OT),
+    ("OG", Position::OG),
+    ("G", Position::OG),
+    // IOL (Interior Offensive Line) → OG: most IOL prospects play guard
+    ("IOL", Position::OG),
+    // nflverse uses OL for generic offensive linemen
+    ("OL", Position::OG),
+    ("C", Position::C),
+    ("DE", Position::DE),
+    ("EDGE", Position::DE),
+    // PFF abbreviates edge rushers as ED
+    ("ED", Position::DE),
+    // EDGE/LB hybrid → DE: prioritize pass-rush role over coverage
+    ("EDGE/LB", Position::DE),
+    ("LB/EDGE", Position::DE),
+    ("DT", Position::DT),
+    // DL (generic defensive line) → DT: most generic DL prospects are interior
+    ("DL", Position::DT),
+    ("NT", Position::DT),
+    ("LB", Position::LB),
+    ("OLB", Position::LB),
+    ("ILB", Position::LB),
+    ("MLB", Position::LB),
+    ("CB", Position::CB),
+    ("S", Position::S),
+    ("SS", Position::S),
+    ("FS", Position::S),
+    // nflverse uses DB and SAF for defensive backs / safeties
+    ("DB", Position::S),
+    ("SAF", Position::S),
+    ("K", Position::K),
+    ("P", Position::P),
+];
+
+/// A position no alias covers. Loaders skip the row with a warning rather
+/// than counting it as an error.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UnmappedPosition(pub String);
+
+impl fmt::Display for UnmappedPosition {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "Unmapped position: '{}'. Add an alias for it with --position-map.",
+            self.0
+        )
+    }
+}
+
+impl std::error::Error for UnmappedPosition {}
+
+#[derive(Debug, Clone)]
+pub struct PositionMap {
+    aliases: HashMap<String, Position>,
+    fallback: Option<Position>,
+}
+
+impl Default for PositionMap {
+    fn default() -> Self {
+        Self {
+            aliases: DEFAULT_ALIASES
+                .iter()
+                .map(|(alias, position)| (alias.to_string(), *position))
+                .collect(),
+            fallback: None,
         }
-    };
-
-    if normalized != canonical {
-        tracing::debug!(
-            source = source,
-            canonical = canonical,
-            "Alternate abbreviation '{}' mapped to canonical '{}'",
-            normalized,
-            canonical
-        );
     }
+}
+
+impl PositionMap {
+    /// Logs at debug level when an alternate abbreviation is mapped, and at
+    /// warn level when an unmapped one falls back.
+    pub fn map(&self, source: &str) -> Result<Position> {
+        let normalized = source.trim().to_uppercase();
+
+        let Some(&position) = self.aliases.get(&normalized) else {
+            let Some(fallback) = self.fallback else {
+                return Err(UnmappedPosition(source.to_string()).into());
+            };
+            tracing::warn!(
+                "Unmapped position '{}' loaded as fallback {:?}",
+                source,
+                fallback
+            );
+            return Ok(fallback);
+        };
+
+        let canonical = format!("{:?}", position);
+        if normalized != canonical {
+            tracing::debug!(
+                source = source,
+                canonical = canonical,
+                "Alternate abbreviation '{}' mapped to canonical '{}'",
+                normalized,
+                canonical
+            );
+        }
+
+        Ok(position)
+    }
+}
+
+/// A `--position-map` file. Aliases are added to the built-in ones,
+/// replacing any with the same abbreviation.
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct PositionMapFile {
+    #[serde(default)]
+    aliases: HashMap<String, Position>,
+    #[serde(default)]
+    fallback: Option<Position>,
+}
 
-    Ok(position)
+pub fn parse_position_map_file(path: &str) -> Result<PositionMap> {
+    let json = std::fs::read_to_string(path)
+        .with_context(|| format!("Failed to read position map {}", path))?;
+    parse_position_map_json(&json).with_context(|| format!("Invalid position map {}", path))
+}
+
+pub fn parse_position_map_json(json: &str) -> Result<PositionMap> {
+    let file: PositionMapFile = serde_json::from_str(json)?;
+    let mut map = PositionMap::default();
+    for (alias, position) in file.aliases {
+        let alias = alias.trim().to_uppercase();
+        if alias.is_empty() {
+            bail!("Position alias must not be empty");
+        }
+        map.aliases.insert(alias, position);
+    }
+    map.fallback = file.fallback;
+    Ok(map)
+}
+
+static POSITION_MAP: OnceLock<PositionMap> = OnceLock::new();
+
+/// Makes `map_position` use `map` for the rest of the process. Must run
+/// before the first position is mapped.
+pub fn install_position_map(map: PositionMap) -> Result<()> {
+    if POSITION_MAP.set(map).is_err() {
+        bail!("A position map is already in use");
+    }
+    Ok(())
+}
+
+/// Maps a source abbreviation with the installed position map, or the
+/// built-in aliases when none was installed. Fails with `UnmappedPosition`.
+pub fn map_position(source: &str) -> Result<Position> {
+    POSITION_MAP.get_or_init(PositionMap::default).map(source)
 }
 
 #[cfg(test)]
@@ -110,6 +215,7 @@ mod tests {
         assert_eq!(map_position("G").unwrap(), Position::OG);
         assert_eq!(map_position("IOL").unwrap(), Position::OG);
         assert_eq!(map_position("EDGE").unwrap(), Position::DE);
+        assert_eq!(map_position("ED").unwrap(), Position::DE);
         assert_eq!(map_position("EDGE/LB").unwrap(), Position::DE);
         assert_eq!(map_position("LB/EDGE").unwrap(), Position::DE);
         assert_eq!(map_position("DL").unwrap(), Position::DT);
@@ -140,5 +246,33 @@ mod tests {
     fn test_error_message_includes_input() {
         let err = map_position("ATH").unwrap_err();
         assert!(err.to_string().contains("ATH"));
+        assert!(err.is::<UnmappedPosition>());
+    }
+
+    #[test]
+    fn test_position_map_file_overrides_and_adds_aliases() {
+        let map =
+            parse_position_map_json(r#"{ "aliases": { "edge": "LB", "ATH": "WR", " LS ": "C" } }"#)
+                .unwrap();
+        assert_eq!(map.map("EDGE").unwrap(), Position::LB);
+        assert_eq!(map.map("ath").unwrap(), Position::WR);
+        assert_eq!(map.map("LS").unwrap(), Position::C);
+        // Built-in aliases the file doesn't mention are kept
+        assert_eq!(map.map("OLB").unwrap(), Position::LB);
+        assert!(map.map("KR").unwrap_err().is::<UnmappedPosition>());
+    }
+
+    #[test]
+    fn test_position_map_fallback() {
+        let map = parse_position_map_json(r#"{ "fallback": "S" }"#).unwrap();
+        assert_eq!(map.map("ATH").unwrap(), Position::S);
+        assert_eq!(map.map("QB").unwrap(), Position::QB);
+    }
+
+    #[test]
+    fn test_position_map_rejects_bad_files() {
+        assert!(parse_position_map_json(r#"{ "aliases": { "ATH": "ATHLETE" } }"#).is_err());
+        assert!(parse_position_map_json(r#"{ "aliases": { "": "WR" } }"#).is_err());
+        assert!(parse_position_map_json(r#"{ "alias": { "ATH": "WR" } }"#).is_err());
     }
 }
@@ -0,0 +1,18 @@
+//! Progress bars for long-running loads
+//!
+//! Bars draw on stderr and are hidden when it isn't a terminal, so runs
+//! from scripts, CI and the API's seed endpoints print nothing extra.
+
+use indicatif::{ProgressBar, ProgressStyle};
+
+/// A bar counting `len` items of work, labelled with `message`
+pub fn bar(len: usize, message: &str) -> ProgressBar {
+    let bar = ProgressBar::new(len as u64);
+    bar.set_style(
+        ProgressStyle::with_template("{msg} [{bar:40}] {pos}/{len} ({elapsed}, eta {eta})")
+            .expect("progress template is valid")
+            .progress_chars("=> "),
+    );
+    bar.set_message(message.to_string());
+    bar
+}
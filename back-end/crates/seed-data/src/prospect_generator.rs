@@ -0,0 +1,756 @@
+//! Synthetic draft classes for load testing and future-year sandboxes
+//!
+//! A generated class is written as the three files the existing loaders
+//! read: a players file, a combine file and a rankings board. Loading the
+//! rankings board through `scouting load` turns each rank into a consensus
+//! grade, so a class generated here can be drafted like a real one.
+//!
+//! Generation is seeded and deterministic. Heights and weights follow
+//! per-position distributions, and combine numbers are drawn from the
+//! percentile baselines in `combine_percentiles.json`, skewed toward better
+//! results for more athletic (and, loosely, higher-ranked) prospects.
+
+use std::collections::{HashMap, HashSet};
+use std::path::Path;
+
+use anyhow::{bail, Context, Result};
+use chrono::Utc;
+
+use crate::combine_loader::{CombineFileData, CombineFileEntry, CombineFileMeta};
+use crate::export::write_json_file;
+use crate::loader::{MetaData, PlayerData, PlayerEntry};
+use crate::percentile_loader::{PercentileFileData, PercentileFileEntry};
+use crate::scouting_report_loader::{RankingData, RankingEntry, RankingMeta};
+
+/// Source name written to every generated file
+pub const GENERATED_SOURCE: &str = "Synthetic";
+
+/// Upper bound on a class; the name pool can't stay unique much past this
+pub const MAX_GENERATED_PLAYERS: usize = 5000;
+
+/// Share of prospects that skip any one combine drill
+const DRILL_SKIP_PCT: u64 = 15;
+
+/// Share of prospects carrying a medical red flag
+const MEDICAL_FLAG_PCT: u64 = 3;
+
+const FIRST_NAMES: &[&str] = &[
+    "Aaron", "Adrian", "Andre", "Anthony", "Brandon", "Brian", "Caleb", "Cameron", "Carson",
+    "Chris", "Cole", "Damon", "Darius", "David", "Derrick", "Devin", "Dominic", "Drew", "Dylan",
+    "Elijah", "Eric", "Ethan", "Gabriel", "Garrett", "Isaiah", "Jackson", "Jalen", "Jamal",
+    "Jaylen", "Jordan", "Josh", "Justin", "Kendrick", "Kevin", "Kyle", "Landon", "Lamar", "Logan",
+    "Luke", "Malik", "Marcus", "Mason", "Micah", "Michael", "Nathan", "Nick", "Noah", "Omar",
+    "Quentin", "Reggie", "Ryan", "Sam", "Terrell", "Trevor", "Tyler", "Tyrone", "Wesley", "Xavier",
+    "Zach", "Zion",
+];
+
+const LAST_NAMES: &[&str] = &[
+    "Adams",
+    "Allen",
+    "Bailey",
+    "Baker",
+    "Bell",
+    "Brooks",
+    "Brown",
+    "Bryant",
+    "Carter",
+    "Clark",
+    "Coleman",
+    "Collins",
+    "Cooper",
+    "Davis",
+    "Dixon",
+    "Edwards",
+    "Ellis",
+    "Evans",
+    "Fisher",
+    "Foster",
+    "Gibson",
+    "Graham",
+    "Green",
+    "Griffin",
+    "Hall",
+    "Harris",
+    "Hayes",
+    "Henderson",
+    "Hill",
+    "Holmes",
+    "Howard",
+    "Hughes",
+    "Jackson",
+    "James",
+    "Jenkins",
+    "Johnson",
+    "Jordan",
+    "Kelly",
+    "King",
+    "Lewis",
+    "Marshall",
+    "Martin",
+    "Mitchell",
+    "Moore",
+    "Morgan",
+    "Murphy",
+    "Nelson",
+    "Parker",
+    "Perry",
+    "Porter",
+    "Reed",
+    "Richardson",
+    "Robinson",
+    "Russell",
+    "Sanders",
+    "Simmons",
+    "Stewart",
+    "Thomas",
+    "Turner",
+    "Walker",
+    "Washington",
+    "Watson",
+    "Williams",
+    "Wright",
+];
+
+/// Appended to a last name when the plain name is already taken
+const NAME_SUFFIXES: &[&str] = &["Jr.", "II", "III", "IV"];
+
+const SCHOOLS: &[&str] = &[
+    "Alabama",
+    "Arizona State",
+    "Arkansas",
+    "Auburn",
+    "Baylor",
+    "Boise State",
+    "BYU",
+    "Clemson",
+    "Colorado",
+    "Duke",
+    "Florida",
+    "Florida State",
+    "Georgia",
+    "Illinois",
+    "Indiana",
+    "Iowa",
+    "Kansas State",
+    "Kentucky",
+    "LSU",
+    "Louisville",
+    "Miami",
+    "Michigan",
+    "Michigan State",
+    "Minnesota",
+    "Missouri",
+    "NC State",
+    "Nebraska",
+    "North Carolina",
+    "Notre Dame",
+    "Ohio State",
+    "Oklahoma",
+    "Ole Miss",
+    "Oregon",
+    "Penn State",
+    "Pittsburgh",
+    "South Carolina",
+    "Stanford",
+    "TCU",
+    "Tennessee",
+    "Texas",
+    "Texas A&M",
+    "Texas Tech",
+    "UCLA",
+    "USC",
+    "Utah",
+    "Virginia Tech",
+    "Washington",
+    "Wisconsin",
+];
+
+/// Physical profile of a position, with its share of a typical class
+struct PositionProfile {
+    position: &'static str,
+    /// Relative weight when drawing positions
+    share: u64,
+    height_mean: f64,
+    height_sd: f64,
+    weight_mean: f64,
+    weight_sd: f64,
+    /// Position group used in `combine_percentiles.json`; `None` for
+    /// specialists, who don't work out
+    percentile_group: Option<&'static str>,
+}
+
+const POSITION_PROFILES: &[PositionProfile] = &[
+    PositionProfile {
+        position: "QB",
+        share: 5,
+        height_mean: 75.0,
+        height_sd: 1.5,
+        weight_mean: 220.0,
+        weight_sd: 10.0,
+        percentile_group: Some("QB"),
+    },
+    PositionProfile {
+        position: "RB",
+        share: 7,
+        height_mean: 70.5,
+        height_sd: 1.5,
+        weight_mean: 212.0,
+        weight_sd: 12.0,
+        percentile_group: Some("RB"),
+    },
+    PositionProfile {
+        position: "WR",
+        share: 14,
+        height_mean: 72.5,
+        height_sd: 2.2,
+        weight_mean: 198.0,
+        weight_sd: 14.0,
+        percentile_group: Some("WR"),
+    },
+    PositionProfile {
+        position: "TE",
+        share: 6,
+        height_mean: 76.5,
+        height_sd: 1.2,
+        weight_mean: 250.0,
+        weight_sd: 8.0,
+        percentile_group: Some("TE"),
+    },
+    PositionProfile {
+        position: "OT",
+        share: 9,
+        height_mean: 78.0,
+        height_sd: 1.2,
+        weight_mean: 315.0,
+        weight_sd: 12.0,
+        percentile_group: Some("OT"),
+    },
+    PositionProfile {
+        position: "OG",
+        share: 7,
+        height_mean: 76.0,
+        height_sd: 1.0,
+        weight_mean: 315.0,
+        weight_sd: 10.0,
+        percentile_group: Some("IOL"),
+    },
+    PositionProfile {
+        position: "C",
+        share: 4,
+        height_mean: 75.0,
+        height_sd: 1.0,
+        weight_mean: 305.0,
+        weight_sd: 8.0,
+        percentile_group: Some("IOL"),
+    },
+    PositionProfile {
+        position: "DE",
+        share: 10,
+        height_mean: 76.0,
+        height_sd: 1.3,
+        weight_mean: 262.0,
+        weight_sd: 12.0,
+        percentile_group: Some("EDGE"),
+    },
+    PositionProfile {
+        position: "DT",
+        share: 9,
+        height_mean: 75.0,
+        height_sd: 1.3,
+        weight_mean: 305.0,
+        weight_sd: 15.0,
+        percentile_group: Some("DL"),
+    },
+    PositionProfile {
+        position: "LB",
+        share: 10,
+        height_mean: 74.0,
+        height_sd: 1.2,
+        weight_mean: 235.0,
+        weight_sd: 8.0,
+        percentile_group: Some("LB"),
+    },
+    PositionProfile {
+        position: "CB",
+        share: 13,
+        height_mean: 71.0,
+        height_sd: 1.5,
+        weight_mean: 192.0,
+        weight_sd: 8.0,
+        percentile_group: Some("CB"),
+    },
+    PositionProfile {
+        position: "S",
+        share: 8,
+        height_mean: 72.0,
+        height_sd: 1.3,
+        weight_mean: 205.0,
+        weight_sd: 8.0,
+        percentile_group: Some("S"),
+    },
+    PositionProfile {
+        position: "K",
+        share: 1,
+        height_mean: 72.5,
+        height_sd: 2.0,
+        weight_mean: 200.0,
+        weight_sd: 15.0,
+        percentile_group: None,
+    },
+    PositionProfile {
+        position: "P",
+        share: 1,
+        height_mean: 74.0,
+        height_sd: 2.0,
+        weight_mean: 215.0,
+        weight_sd: 15.0,
+        percentile_group: None,
+    },
+];
+
+/// A generated class, as the files the loaders read
+pub struct GeneratedClass {
+    pub players: PlayerData,
+    pub combine: CombineFileData,
+    pub rankings: RankingData,
+}
+
+impl GeneratedClass {
+    /// Writes `players_{year}.json`, `combine_{year}.json` and
+    /// `rankings_{year}.json` into `output_dir`, returning their paths
+    pub fn write(&self, output_dir: &str) -> Result<Vec<String>> {
+        std::fs::create_dir_all(output_dir)
+            .with_context(|| format!("Failed to create output directory: {}", output_dir))?;
+        let year = self.players.meta.draft_year;
+        let path = |name: &str| {
+            Path::new(output_dir)
+                .join(format!("{}_{}.json", name, year))
+                .to_string_lossy()
+                .into_owned()
+        };
+
+        let players_path = path("players");
+        let combine_path = path("combine");
+        let rankings_path = path("rankings");
+        write_json_file(&self.players, &players_path)?;
+        write_json_file(&self.combine, &combine_path)?;
+        write_json_file(&self.rankings, &rankings_path)?;
+        Ok(vec![players_path, combine_path, rankings_path])
+    }
+}
+
+/// Generates `count` prospects for `year`. The same seed and percentile
+/// baselines always produce the same class.
+pub fn generate_class(
+    count: usize,
+    year: i32,
+    seed: u64,
+    percentiles: &PercentileFileData,
+) -> Result<GeneratedClass> {
+    if count == 0 {
+        bail!("Count must be at least 1");
+    }
+    if count > MAX_GENERATED_PLAYERS {
+        bail!(
+            "Count {} is over the limit of {}",
+            count,
+            MAX_GENERATED_PLAYERS
+        );
+    }
+
+    let baselines: HashMap<(&str, &str), &PercentileFileEntry> = percentiles
+        .percentiles
+        .iter()
+        .map(|p| ((p.position.as_str(), p.measurement.as_str()), p))
+        .collect();
+
+    let mut rng = SplitMix64::new(seed);
+    let mut names = HashSet::new();
+    let mut prospects = Vec::with_capacity(count);
+    for _ in 0..count {
+        prospects.push(Prospect::generate(&mut rng, &mut names)?);
+    }
+
+    // Best talent first, so a prospect's index is its rank minus one
+    prospects.sort_by(|a, b| b.talent.total_cmp(&a.talent));
+
+    let today = Utc::now().format("%Y-%m-%d").to_string();
+    let players = prospects.iter().map(Prospect::player_entry).collect();
+    let combine_results = prospects
+        .iter()
+        .filter_map(|p| p.combine_entry(year, &baselines, &mut rng))
+        .collect();
+    let rankings = prospects
+        .iter()
+        .enumerate()
+        .map(|(i, p)| p.ranking_entry(i as i32 + 1))
+        .collect();
+
+    Ok(GeneratedClass {
+        players: PlayerData {
+            meta: MetaData {
+                version: "1.0.0".to_string(),
+                draft_year: year,
+                last_updated: today.clone(),
+                sources: vec![GENERATED_SOURCE.to_string()],
+                total_players: count,
+            },
+            players,
+        },
+        combine: CombineFileData {
+            meta: CombineFileMeta {
+                source: GENERATED_SOURCE.to_string(),
+                year,
+            },
+            combine_results,
+        },
+        rankings: RankingData {
+            meta: RankingMeta {
+                version: "1.0.0".to_string(),
+                source: GENERATED_SOURCE.to_string(),
+                source_url: String::new(),
+                draft_year: year,
+                scraped_at: today,
+                total_prospects: count,
+            },
+            rankings,
+        },
+    })
+}
+
+/// One generated prospect before it is split across the output files
+struct Prospect {
+    first_name: String,
+    last_name: String,
+    profile: &'static PositionProfile,
+    school: &'static str,
+    height_inches: i32,
+    weight_pounds: i32,
+    medical_flag: bool,
+    /// Standard-normal talent score; sets the board order
+    talent: f64,
+    /// Standard-normal athleticism, partly tied to talent
+    athleticism: f64,
+    /// Standard-normal frame size, which drives arm, hand and wingspan
+    frame: f64,
+}
+
+impl Prospect {
+    fn generate(rng: &mut SplitMix64, names: &mut HashSet<(String, String)>) -> Result<Self> {
+        let (first_name, last_name) = unique_name(rng, names)?;
+        let profile = pick_position(rng);
+
+        let frame = rng.normal();
+        let height = (profile.height_mean + frame * profile.height_sd).round();
+        let weight = profile.weight_mean + profile.weight_sd * (0.5 * frame + 0.87 * rng.normal());
+
+        let talent = rng.normal();
+        Ok(Self {
+            first_name,
+            last_name,
+            profile,
+            school: SCHOOLS[rng.below(SCHOOLS.len() as u64) as usize],
+            height_inches: (height as i32).clamp(66, 82),
+            weight_pounds: (weight.round() as i32).clamp(160, 360),
+            medical_flag: rng.below(100) < MEDICAL_FLAG_PCT,
+            talent,
+            athleticism: 0.4 * talent + 0.9 * rng.normal(),
+            frame,
+        })
+    }
+
+    fn player_entry(&self) -> PlayerEntry {
+        PlayerEntry {
+            first_name: self.first_name.clone(),
+            last_name: self.last_name.clone(),
+            position: self.profile.position.to_string(),
+            college: Some(self.school.to_string()),
+            height_inches: Some(self.height_inches),
+            weight_pounds: Some(self.weight_pounds),
+            injury_status: None,
+            injury_body_part: None,
+            injury_expected_return: None,
+            medical_flag: self.medical_flag,
+            notes: None,
+        }
+    }
+
+    fn ranking_entry(&self, rank: i32) -> RankingEntry {
+        RankingEntry {
+            rank,
+            first_name: self.first_name.clone(),
+            last_name: self.last_name.clone(),
+            position: self.profile.position.to_string(),
+            school: self.school.to_string(),
+            height_inches: Some(self.height_inches),
+            weight_pounds: Some(self.weight_pounds),
+            profile_url: None,
+            headshot_url: None,
+        }
+    }
+
+    /// Combine numbers drawn from the position group's baselines; `None`
+    /// for specialists
+    fn combine_entry(
+        &self,
+        year: i32,
+        baselines: &HashMap<(&str, &str), &PercentileFileEntry>,
+        rng: &mut SplitMix64,
+    ) -> Option<CombineFileEntry> {
+        let group = self.profile.percentile_group?;
+        let mut measure =
+            |measurement: &str, score: f64, lower_is_better: bool, skippable: bool| {
+                let baseline = baselines.get(&(group, measurement))?;
+                if skippable && rng.below(100) < DRILL_SKIP_PCT {
+                    return None;
+                }
+                let quantile = normal_cdf(score + 0.5 * rng.normal());
+                let quantile = if lower_is_better {
+                    1.0 - quantile
+                } else {
+                    quantile
+                };
+                Some(quantile_value(baseline, quantile))
+            };
+
+        let athleticism = self.athleticism;
+        let frame = self.frame;
+        let forty_yard_dash = measure("forty_yard_dash", athleticism, true, true);
+        let bench_press = measure("bench_press", athleticism, false, true);
+        let vertical_jump = measure("vertical_jump", athleticism, false, true);
+        let broad_jump = measure("broad_jump", athleticism, false, true);
+        let three_cone_drill = measure("three_cone_drill", athleticism, true, true);
+        let twenty_yard_shuttle = measure("twenty_yard_shuttle", athleticism, true, true);
+        let arm_length = measure("arm_length", frame, false, false);
+        let hand_size = measure("hand_size", frame, false, false);
+        let wingspan = measure("wingspan", frame, false, false);
+        // Splits come from the same run as the forty
+        let (ten_yard_split, twenty_yard_split) = if forty_yard_dash.is_some() {
+            (
+                measure("ten_yard_split", athleticism, true, false),
+                measure("twenty_yard_split", athleticism, true, false),
+            )
+        } else {
+            (None, None)
+        };
+
+        Some(CombineFileEntry {
+            first_name: self.first_name.clone(),
+            last_name: self.last_name.clone(),
+            position: self.profile.position.to_string(),
+            source: "combine".to_string(),
+            year,
+            event_date: None,
+            forty_yard_dash: forty_yard_dash.map(|v| round_to(v, 0.01)),
+            bench_press: bench_press.map(|v| v.round() as i32),
+            vertical_jump: vertical_jump.map(|v| round_to(v, 0.5)),
+            broad_jump: broad_jump.map(|v| v.round() as i32),
+            three_cone_drill: three_cone_drill.map(|v| round_to(v, 0.01)),
+            twenty_yard_shuttle: twenty_yard_shuttle.map(|v| round_to(v, 0.01)),
+            arm_length: arm_length.map(|v| round_to(v, 0.125)),
+            hand_size: hand_size.map(|v| round_to(v, 0.125)),
+            wingspan: wingspan.map(|v| round_to(v, 0.125)),
+            ten_yard_split: ten_yard_split.map(|v| round_to(v, 0.01)),
+            twenty_yard_split: twenty_yard_split.map(|v| round_to(v, 0.01)),
+        })
+    }
+}
+
+/// Draws a name not yet in `names`, adding a suffix when the plain name is
+/// taken
+pub(crate) fn unique_name(
+    rng: &mut SplitMix64,
+    names: &mut HashSet<(String, String)>,
+) -> Result<(String, String)> {
+    for _ in 0..100 {
+        let first = FIRST_NAMES[rng.below(FIRST_NAMES.len() as u64) as usize];
+        let last = LAST_NAMES[rng.below(LAST_NAMES.len() as u64) as usize];
+        let candidates = std::iter::once(last.to_string())
+            .chain(NAME_SUFFIXES.iter().map(|s| format!("{} {}", last, s)));
+        for last_name in candidates {
+            if names.insert((first.to_string(), last_name.clone())) {
+                return Ok((first.to_string(), last_name));
+            }
+        }
+    }
+    bail!("Ran out of unique names after {} prospects", names.len())
+}
+
+fn pick_position(rng: &mut SplitMix64) -> &'static PositionProfile {
+    let total: u64 = POSITION_PROFILES.iter().map(|p| p.share).sum();
+    let mut roll = rng.below(total);
+    for profile in POSITION_PROFILES {
+        if roll < profile.share {
+            return profile;
+        }
+        roll -= profile.share;
+    }
+    unreachable!("roll is below the total share")
+}
+
+/// The value at `quantile` (0.0–1.0) of a baseline, interpolating between
+/// its min, deciles and max
+fn quantile_value(baseline: &PercentileFileEntry, quantile: f64) -> f64 {
+    let knots = [
+        baseline.min_value,
+        baseline.p10,
+        baseline.p20,
+        baseline.p30,
+        baseline.p40,
+        baseline.p50,
+        baseline.p60,
+        baseline.p70,
+        baseline.p80,
+        baseline.p90,
+        baseline.max_value,
+    ];
+    let scaled = quantile.clamp(0.0, 1.0) * 10.0;
+    let index = (scaled.floor() as usize).min(9);
+    let t = scaled - index as f64;
+    knots[index] + (knots[index + 1] - knots[index]) * t
+}
+
+/// Logistic approximation of the standard normal CDF
+fn normal_cdf(z: f64) -> f64 {
+    1.0 / (1.0 + (-1.702 * z).exp())
+}
+
+fn round_to(value: f64, step: f64) -> f64 {
+    let rounded = (value / step).round() * step;
+    // Trim float noise such as 4.5200000000000005
+    (rounded * 1000.0).round() / 1000.0
+}
+
+/// SplitMix64: a small fixed-algorithm generator, so a seed produces the
+/// same class on every toolchain (the same reason `grade_generator` uses
+/// FNV-1a rather than `DefaultHasher`)
+pub(crate) struct SplitMix64(u64);
+
+impl SplitMix64 {
+    pub(crate) fn new(seed: u64) -> Self {
+        Self(seed)
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        self.0 = self.0.wrapping_add(0x9E3779B97F4A7C15);
+        let mut z = self.0;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58476D1CE4E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D049BB133111EB);
+        z ^ (z >> 31)
+    }
+
+    /// Uniform in `[0, bound)`
+    pub(crate) fn below(&mut self, bound: u64) -> u64 {
+        self.next_u64() % bound
+    }
+
+    /// Uniform in `(0.0, 1.0]`
+    fn unit(&mut self) -> f64 {
+        ((self.next_u64() >> 11) + 1) as f64 / (1u64 << 53) as f64
+    }
+
+    /// Standard normal, by the Box-Muller transform
+    fn normal(&mut self) -> f64 {
+        let u1 = self.unit();
+        let u2 = self.unit();
+        (-2.0 * u1.ln()).sqrt() * (2.0 * std::f64::consts::PI * u2).cos()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::combine_loader::entry_has_any_measurement;
+    use crate::percentile_loader::parse_percentile_json;
+    use crate::rankings_validator::validate_ranking_data;
+    use crate::validator::validate_player_data;
+
+    fn baselines() -> PercentileFileData {
+        parse_percentile_json(include_str!("../../../data/combine_percentiles.json")).unwrap()
+    }
+
+    #[test]
+    fn test_generated_class_passes_validation() {
+        let class = generate_class(300, 2027, 42, &baselines()).unwrap();
+
+        assert_eq!(class.players.players.len(), 300);
+        let players = validate_player_data(&class.players);
+        assert!(players.valid, "{:?}", players.errors);
+        let rankings = validate_ranking_data(&class.rankings);
+        assert!(rankings.valid, "{:?}", rankings.errors);
+
+        for entry in &class.players.players {
+            let player = entry.to_domain(2027).unwrap();
+            assert!((66..=82).contains(&player.height_inches.unwrap()));
+        }
+        assert!(class
+            .combine
+            .combine_results
+            .iter()
+            .all(entry_has_any_measurement));
+    }
+
+    #[test]
+    fn test_generation_is_deterministic_per_seed() {
+        let names = |seed| {
+            generate_class(50, 2027, seed, &baselines())
+                .unwrap()
+                .players
+                .players
+                .iter()
+                .map(|p| format!("{} {} {}", p.first_name, p.last_name, p.position))
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(names(7), names(7));
+        assert_ne!(names(7), names(8));
+    }
+
+    #[test]
+    fn test_names_stay_unique() {
+        let class = generate_class(MAX_GENERATED_PLAYERS, 2027, 1, &baselines()).unwrap();
+        let names: HashSet<_> = class
+            .players
+            .players
+            .iter()
+            .map(|p| (&p.first_name, &p.last_name))
+            .collect();
+        assert_eq!(names.len(), MAX_GENERATED_PLAYERS);
+
+        assert!(generate_class(MAX_GENERATED_PLAYERS + 1, 2027, 1, &baselines()).is_err());
+        assert!(generate_class(0, 2027, 1, &baselines()).is_err());
+    }
+
+    #[test]
+    fn test_combine_numbers_stay_within_baselines() {
+        let baselines = baselines();
+        let forty = baselines
+            .percentiles
+            .iter()
+            .find(|p| p.position == "WR" && p.measurement == "forty_yard_dash")
+            .unwrap();
+        let class = generate_class(500, 2027, 3, &baselines).unwrap();
+        let forties: Vec<f64> = class
+            .combine
+            .combine_results
+            .iter()
+            .filter(|c| c.position == "WR")
+            .filter_map(|c| c.forty_yard_dash)
+            .collect();
+
+        assert!(!forties.is_empty());
+        for time in forties {
+            assert!(time >= forty.min_value - 0.01 && time <= forty.max_value + 0.01);
+        }
+    }
+
+    #[test]
+    fn test_quantile_value_interpolates_deciles() {
+        let baselines = baselines();
+        let forty = &baselines.percentiles[0];
+        assert!((quantile_value(forty, 0.0) - forty.min_value).abs() < 1e-9);
+        assert!((quantile_value(forty, 0.5) - forty.p50).abs() < 1e-9);
+        assert!((quantile_value(forty, 1.0) - forty.max_value).abs() < 1e-9);
+        let midway = quantile_value(forty, 0.15);
+        assert!((midway - (forty.p10 + forty.p20) / 2.0).abs() < 1e-9);
+    }
+}
@@ -1,18 +1,18 @@
 use std::collections::HashMap;
 
 use anyhow::Result;
-use chrono::{DateTime, NaiveDate};
-use domain::models::{Player, ProspectRanking, RankingSource};
+use chrono::{DateTime, NaiveDate, Utc};
+use domain::models::{Player, Position, ProspectRanking, RankingSource, Team};
 use domain::repositories::{
     PlayerRepository, ProspectRankingRepository, RankingSourceRepository, ScoutingReportRepository,
     TeamRepository,
 };
-use sqlx::PgPool;
+use sqlx::{FromRow, PgPool};
 use uuid::Uuid;
 
-use crate::grade_generator::create_scouting_report;
+use crate::grade_generator::{create_scouting_report, GradeProfiles};
 use crate::position_mapper::map_position;
-use crate::scouting_report_loader::{RankingData, RankingEntry};
+use crate::scouting_report_loader::{create_reports_concurrently, RankingData, RankingEntry};
 
 /// Normalize a name component for matching by stripping periods and collapsing whitespace.
 /// This handles variations like "C.J." vs "CJ", "Jr." vs "Jr", "L.T." vs "LT".
@@ -30,6 +30,10 @@ pub struct RankingsLoadStats {
     pub prospects_discovered: usize,
     pub rankings_inserted: usize,
     pub scouting_reports_created: usize,
+    /// Existing players given a profile or headshot URL they lacked
+    pub profile_urls_added: usize,
+    /// New prospects skipped for an unmapped position
+    pub warnings: Vec<String>,
     pub errors: Vec<String>,
     pub discovered_names: Vec<String>,
 }
@@ -44,6 +48,7 @@ impl RankingsLoadStats {
             "  Scouting reports created:  {}",
             self.scouting_reports_created
         );
+        println!("  Profile URLs added:        {}", self.profile_urls_added);
         println!("  Errors:                    {}", self.errors.len());
 
         if !self.discovered_names.is_empty() {
@@ -53,6 +58,13 @@ impl RankingsLoadStats {
             }
         }
 
+        if !self.warnings.is_empty() {
+            println!("\nWarnings:");
+            for warning in &self.warnings {
+                println!("  - {}", warning);
+            }
+        }
+
         if !self.errors.is_empty() {
             println!("\nErrors:");
             for error in &self.errors {
@@ -83,13 +95,17 @@ pub fn load_rankings_dry_run(data: &RankingData) -> Result<RankingsLoadStats> {
     Ok(stats)
 }
 
+#[allow(clippy::too_many_arguments)]
 pub async fn load_rankings(
     data: &RankingData,
     pool: &PgPool,
+    batch_size: usize,
     player_repo: &dyn PlayerRepository,
     team_repo: &dyn TeamRepository,
     ranking_source_repo: &dyn RankingSourceRepository,
     scouting_report_repo: &dyn ScoutingReportRepository,
+    profiles: &GradeProfiles,
+    concurrency: usize,
 ) -> Result<RankingsLoadStats> {
     let mut stats = RankingsLoadStats::default();
 
@@ -141,7 +157,9 @@ pub async fn load_rankings(
         })?;
 
     // Track newly created players for scouting report generation
-    let mut new_player_entries: Vec<(Uuid, &RankingEntry)> = Vec::new();
+    let mut new_player_entries: Vec<(Uuid, Position, &RankingEntry)> = Vec::new();
+    let mut new_players: Vec<Player> = Vec::new();
+    let mut players_to_link: Vec<Player> = Vec::new();
     let mut rankings_to_insert: Vec<ProspectRanking> = Vec::new();
 
     // Process each ranking entry
@@ -152,62 +170,29 @@ pub async fn load_rankings(
         );
 
         // Match or create player
-        let player_id = if let Some(existing) = player_map.get(&lookup_key) {
+        let player_id = if let Some(existing) = player_map.get_mut(&lookup_key) {
             stats.prospects_matched += 1;
+            if let Some(linked) = with_missing_profile_urls(existing, entry) {
+                *existing = linked.clone();
+                players_to_link.push(linked);
+            }
             existing.id
         } else {
             // Auto-discover: create a new player
             let position = match map_position(&entry.position) {
                 Ok(p) => p,
                 Err(e) => {
-                    let msg = format!(
-                        "Skipping {} {} - unknown position '{}': {}",
-                        entry.first_name, entry.last_name, entry.position, e
-                    );
+                    let msg = format!("Skipping {} {}: {}", entry.first_name, entry.last_name, e);
                     tracing::warn!("{}", msg);
-                    stats.errors.push(msg);
+                    stats.warnings.push(msg);
                     continue;
                 }
             };
 
-            let new_player = Player::new(
-                entry.first_name.clone(),
-                entry.last_name.clone(),
-                position,
-                data.meta.draft_year,
-            )
-            .and_then(|p| {
-                if entry.school.trim().is_empty() {
-                    Ok(p)
-                } else {
-                    p.with_college(entry.school.clone())
-                }
-            })
-            .and_then(|p| {
-                if let (Some(height), Some(weight)) = (entry.height_inches, entry.weight_pounds) {
-                    p.with_physical_stats(height, weight)
-                } else {
-                    Ok(p)
-                }
-            })
-            .map_err(|e| {
-                anyhow::anyhow!(
-                    "Failed to create player {} {}: {}",
-                    entry.first_name,
-                    entry.last_name,
-                    e
-                )
-            })?;
+            let new_player = new_player_from_entry(entry, position, data.meta.draft_year)?;
 
             let player_id = new_player.id;
-            player_repo.create(&new_player).await.map_err(|e| {
-                anyhow::anyhow!(
-                    "Failed to insert player {} {}: {}",
-                    entry.first_name,
-                    entry.last_name,
-                    e
-                )
-            })?;
+            new_players.push(new_player.clone());
 
             let name = format!(
                 "{} {} ({}, {})",
@@ -218,7 +203,7 @@ pub async fn load_rankings(
             stats.discovered_names.push(name);
 
             // Track for scouting report generation
-            new_player_entries.push((player_id, entry));
+            new_player_entries.push((player_id, position, entry));
 
             // Add to our lookup map so we don't create duplicates
             player_map.insert(lookup_key, new_player);
@@ -240,6 +225,24 @@ pub async fn load_rankings(
         rankings_to_insert.push(ranking);
     }
 
+    // Discovered players go in together, ahead of the rankings that reference them
+    if !new_players.is_empty() {
+        player_repo.create_many(&new_players).await.map_err(|e| {
+            anyhow::anyhow!("Failed to insert {} new players: {}", new_players.len(), e)
+        })?;
+    }
+
+    for player in &players_to_link {
+        player_repo.update(player).await.map_err(|e| {
+            anyhow::anyhow!(
+                "Failed to add profile URLs for {}: {}",
+                player.full_name(),
+                e
+            )
+        })?;
+    }
+    stats.profile_urls_added = players_to_link.len();
+
     // Delete old + insert new rankings in a transaction (replace strategy)
     let mut tx = pool
         .begin()
@@ -260,18 +263,98 @@ pub async fn load_rankings(
         );
     }
 
-    if !rankings_to_insert.is_empty() {
-        let ids: Vec<Uuid> = rankings_to_insert.iter().map(|r| r.id).collect();
-        let source_ids: Vec<Uuid> = rankings_to_insert
-            .iter()
-            .map(|r| r.ranking_source_id)
-            .collect();
-        let player_ids: Vec<Uuid> = rankings_to_insert.iter().map(|r| r.player_id).collect();
-        let ranks: Vec<i32> = rankings_to_insert.iter().map(|r| r.rank).collect();
-        let scraped_dates: Vec<NaiveDate> =
-            rankings_to_insert.iter().map(|r| r.scraped_at).collect();
+    stats.rankings_inserted = insert_rankings(&mut tx, &rankings_to_insert, batch_size).await?;
+
+    tx.commit()
+        .await
+        .map_err(|e| anyhow::anyhow!("Failed to commit rankings transaction: {}", e))?;
+
+    // Generate scouting reports for newly discovered prospects
+    stats.scouting_reports_created = create_reports_for_new_players(
+        &new_player_entries,
+        &teams,
+        scouting_report_repo,
+        profiles,
+        concurrency,
+    )
+    .await?;
+
+    println!(
+        "  Matched {} prospects to existing players",
+        stats.prospects_matched
+    );
+    println!("  Inserted {} prospect rankings", stats.rankings_inserted);
+
+    Ok(stats)
+}
+
+/// A player for a ranked prospect who is not in the database yet
+pub(crate) fn new_player_from_entry(
+    entry: &RankingEntry,
+    position: Position,
+    draft_year: i32,
+) -> Result<Player> {
+    Player::new(
+        entry.first_name.clone(),
+        entry.last_name.clone(),
+        position,
+        draft_year,
+    )
+    .and_then(|p| {
+        if entry.school.trim().is_empty() {
+            Ok(p)
+        } else {
+            p.with_college(entry.school.clone())
+        }
+    })
+    .and_then(|p| {
+        if let (Some(height), Some(weight)) = (entry.height_inches, entry.weight_pounds) {
+            p.with_physical_stats(height, weight)
+        } else {
+            Ok(p)
+        }
+    })
+    .and_then(|p| p.with_profile_urls(entry.profile_url.clone(), entry.headshot_url.clone()))
+    .map_err(|e| {
+        anyhow::anyhow!(
+            "Failed to create player {} {}: {}",
+            entry.first_name,
+            entry.last_name,
+            e
+        )
+    })
+}
+
+/// The stored player with any profile or headshot URL they lack filled in
+/// from the board, or `None` when the board adds nothing. URLs already on
+/// the player are kept, so the first source to link a player wins.
+pub(crate) fn with_missing_profile_urls(player: &Player, entry: &RankingEntry) -> Option<Player> {
+    let profile_url = player.profile_url.clone().or(entry.profile_url.clone());
+    let headshot_url = player.headshot_url.clone().or(entry.headshot_url.clone());
+    if profile_url == player.profile_url && headshot_url == player.headshot_url {
+        return None;
+    }
+    player
+        .clone()
+        .with_profile_urls(profile_url, headshot_url)
+        .ok()
+}
+
+/// Inserts rankings with one multi-row statement per batch
+pub(crate) async fn insert_rankings(
+    tx: &mut sqlx::Transaction<'_, sqlx::Postgres>,
+    rankings: &[ProspectRanking],
+    batch_size: usize,
+) -> Result<usize> {
+    let mut inserted = 0;
+    for chunk in rankings.chunks(batch_size.max(1)) {
+        let ids: Vec<Uuid> = chunk.iter().map(|r| r.id).collect();
+        let source_ids: Vec<Uuid> = chunk.iter().map(|r| r.ranking_source_id).collect();
+        let player_ids: Vec<Uuid> = chunk.iter().map(|r| r.player_id).collect();
+        let ranks: Vec<i32> = chunk.iter().map(|r| r.rank).collect();
+        let scraped_dates: Vec<NaiveDate> = chunk.iter().map(|r| r.scraped_at).collect();
         let created_dates: Vec<DateTime<chrono::Utc>> =
-            rankings_to_insert.iter().map(|r| r.created_at).collect();
+            chunk.iter().map(|r| r.created_at).collect();
 
         let insert_result = sqlx::query(
             r#"
@@ -285,79 +368,87 @@ pub async fn load_rankings(
         .bind(&ranks)
         .bind(&scraped_dates)
         .bind(&created_dates)
-        .execute(&mut *tx)
+        .execute(&mut **tx)
         .await
         .map_err(|e| anyhow::anyhow!("Failed to insert rankings batch: {}", e))?;
 
-        stats.rankings_inserted = insert_result.rows_affected() as usize;
+        inserted += insert_result.rows_affected() as usize;
     }
+    Ok(inserted)
+}
 
-    tx.commit()
-        .await
-        .map_err(|e| anyhow::anyhow!("Failed to commit rankings transaction: {}", e))?;
-
-    // Generate scouting reports for newly discovered prospects
-    if !new_player_entries.is_empty() {
-        println!(
-            "\nGenerating scouting reports for {} new prospects ({} teams each)...",
-            new_player_entries.len(),
-            teams.len()
-        );
+/// Generates every team's scouting report for prospects created from a
+/// board, graded from their rank
+pub(crate) async fn create_reports_for_new_players(
+    new_player_entries: &[(Uuid, Position, &RankingEntry)],
+    teams: &[Team],
+    scouting_report_repo: &dyn ScoutingReportRepository,
+    profiles: &GradeProfiles,
+    concurrency: usize,
+) -> Result<usize> {
+    if new_player_entries.is_empty() {
+        return Ok(0);
+    }
+    println!(
+        "\nGenerating scouting reports for {} new prospects ({} teams each)...",
+        new_player_entries.len(),
+        teams.len()
+    );
 
-        for (player_id, entry) in &new_player_entries {
-            for team in &teams {
-                let report = match create_scouting_report(
-                    *player_id,
-                    team.id,
-                    &team.abbreviation,
-                    &entry.first_name,
-                    &entry.last_name,
-                    entry.rank,
-                ) {
-                    Ok(r) => r,
-                    Err(e) => {
-                        tracing::warn!(
-                            "Failed to create scouting report for {} {} / {}: {}",
-                            entry.first_name,
-                            entry.last_name,
-                            team.abbreviation,
-                            e
-                        );
-                        continue;
-                    }
-                };
-
-                scouting_report_repo.create(&report).await.map_err(|e| {
-                    anyhow::anyhow!(
-                        "Failed to insert scouting report for {} {} / {}: {}",
+    let mut reports = Vec::new();
+    let mut labels = Vec::new();
+    for (player_id, position, entry) in new_player_entries {
+        for team in teams {
+            match create_scouting_report(
+                *player_id,
+                team.id,
+                &team.abbreviation,
+                &entry.first_name,
+                &entry.last_name,
+                *position,
+                entry.rank,
+                profiles.for_team(&team.abbreviation),
+            ) {
+                Ok(r) => {
+                    reports.push(r);
+                    labels.push(format!(
+                        "{} {} / {}",
+                        entry.first_name, entry.last_name, team.abbreviation
+                    ));
+                }
+                Err(e) => {
+                    tracing::warn!(
+                        "Failed to create scouting report for {} {} / {}: {}",
                         entry.first_name,
                         entry.last_name,
                         team.abbreviation,
                         e
-                    )
-                })?;
-
-                stats.scouting_reports_created += 1;
+                    );
+                }
             }
         }
+    }
 
-        println!(
-            "  Generated {} scouting reports for {} new prospects",
-            stats.scouting_reports_created,
-            new_player_entries.len()
+    let failures = create_reports_concurrently(scouting_report_repo, &reports, concurrency).await;
+    if let Some((i, e)) = failures.first() {
+        anyhow::bail!(
+            "Failed to insert scouting report for {}: {} ({} of {} reports failed)",
+            labels[*i],
+            e,
+            failures.len(),
+            reports.len()
         );
     }
 
     println!(
-        "  Matched {} prospects to existing players",
-        stats.prospects_matched
+        "  Generated {} scouting reports for {} new prospects",
+        reports.len(),
+        new_player_entries.len()
     );
-    println!("  Inserted {} prospect rankings", stats.rankings_inserted);
-
-    Ok(stats)
+    Ok(reports.len())
 }
 
-async fn find_or_create_source(
+pub(crate) async fn find_or_create_source(
     data: &RankingData,
     ranking_source_repo: &dyn RankingSourceRepository,
 ) -> Result<RankingSource> {
@@ -412,6 +503,44 @@ pub async fn clear_rankings(
     }
 }
 
+/// A ranking source in the database and the size of its board
+#[derive(Debug, Clone, FromRow)]
+pub struct LoadedSource {
+    pub name: String,
+    pub rankings: i64,
+    /// Draft years of the ranked players, ascending
+    pub draft_years: Vec<i32>,
+    /// Latest `scraped_at` of the board
+    pub scraped_at: Option<NaiveDate>,
+    /// When the board was last loaded
+    pub loaded_at: Option<DateTime<Utc>>,
+}
+
+/// Every ranking source, including ones whose board was cleared
+pub async fn list_sources(pool: &PgPool) -> Result<Vec<LoadedSource>> {
+    let sources = sqlx::query_as::<_, LoadedSource>(
+        r#"
+        SELECT
+            rs.name,
+            COUNT(pr.id) AS rankings,
+            COALESCE(
+                ARRAY_AGG(DISTINCT p.draft_year) FILTER (WHERE p.draft_year IS NOT NULL),
+                '{}'
+            ) AS draft_years,
+            MAX(pr.scraped_at) AS scraped_at,
+            MAX(pr.created_at) AS loaded_at
+        FROM ranking_sources rs
+        LEFT JOIN prospect_rankings pr ON pr.ranking_source_id = rs.id
+        LEFT JOIN players p ON p.id = pr.player_id
+        GROUP BY rs.id, rs.name
+        ORDER BY rs.name
+        "#,
+    )
+    .fetch_all(pool)
+    .await?;
+    Ok(sources)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -454,4 +583,69 @@ mod tests {
         // R. Mason vs R Mason
         assert_eq!(normalize_name("R."), normalize_name("R"));
     }
+
+    fn entry_with_urls(profile_url: Option<&str>, headshot_url: Option<&str>) -> RankingEntry {
+        RankingEntry {
+            rank: 1,
+            first_name: "Fernando".to_string(),
+            last_name: "Mendoza".to_string(),
+            position: "QB".to_string(),
+            school: "Indiana".to_string(),
+            height_inches: None,
+            weight_pounds: None,
+            profile_url: profile_url.map(str::to_string),
+            headshot_url: headshot_url.map(str::to_string),
+        }
+    }
+
+    #[test]
+    fn test_new_player_from_entry_keeps_profile_urls() {
+        let entry = entry_with_urls(
+            Some("https://www.espn.com/college-football/player/_/id/1"),
+            Some("https://a.espncdn.com/i/headshots/1.png"),
+        );
+        let player = new_player_from_entry(&entry, Position::QB, 2026).unwrap();
+        assert_eq!(player.profile_url, entry.profile_url);
+        assert_eq!(player.headshot_url, entry.headshot_url);
+    }
+
+    #[test]
+    fn test_with_missing_profile_urls_fills_gaps_only() {
+        let player = Player::new(
+            "Fernando".to_string(),
+            "Mendoza".to_string(),
+            Position::QB,
+            2026,
+        )
+        .unwrap()
+        .with_profile_urls(
+            Some("https://www.tankathon.com/players/1".to_string()),
+            None,
+        )
+        .unwrap();
+
+        let linked = with_missing_profile_urls(
+            &player,
+            &entry_with_urls(
+                Some("https://www.espn.com/college-football/player/_/id/1"),
+                Some("https://a.espncdn.com/i/headshots/1.png"),
+            ),
+        )
+        .unwrap();
+        assert_eq!(
+            linked.profile_url.as_deref(),
+            Some("https://www.tankathon.com/players/1")
+        );
+        assert_eq!(
+            linked.headshot_url.as_deref(),
+            Some("https://a.espncdn.com/i/headshots/1.png")
+        );
+
+        assert!(with_missing_profile_urls(&linked, &entry_with_urls(None, None)).is_none());
+        assert!(with_missing_profile_urls(
+            &player,
+            &entry_with_urls(Some("https://www.espn.com/player/2"), None)
+        )
+        .is_none());
+    }
 }
@@ -0,0 +1,481 @@
+//! Refreshes a loaded big board from a newer file of the same source.
+//!
+//! `rankings load` replaces a source's whole board. A refresh compares the
+//! file with the board in the database instead, matching prospects by
+//! normalized name: changed ranks are updated in place, new prospects are
+//! added (created as players with scouting reports when they are not in the
+//! database yet), and prospects no longer on the board are removed. Rows
+//! that did not move, and the scouting reports of players already on the
+//! board, are left alone.
+
+use std::collections::{HashMap, HashSet};
+
+use anyhow::Result;
+use chrono::NaiveDate;
+use domain::models::{Player, Position, ProspectRanking};
+use domain::repositories::{
+    PlayerRepository, RankingSourceRepository, ScoutingReportRepository, TeamRepository,
+};
+use sqlx::{FromRow, PgPool};
+use uuid::Uuid;
+
+use crate::grade_generator::GradeProfiles;
+use crate::position_mapper::map_position;
+use crate::rankings_loader::{
+    create_reports_for_new_players, find_or_create_source, insert_rankings, new_player_from_entry,
+    normalize_name,
+};
+use crate::scouting_report_loader::{RankingData, RankingEntry};
+
+/// Risers and fallers listed in the movement report
+const REPORT_LIMIT: usize = 10;
+
+/// A ranking on a source's board in the database
+#[derive(Debug, Clone, FromRow)]
+pub struct CurrentRanking {
+    pub id: Uuid,
+    pub player_id: Uuid,
+    pub first_name: String,
+    pub last_name: String,
+    pub position: String,
+    pub rank: i32,
+}
+
+/// A prospect on both boards whose rank changed
+#[derive(Debug, Clone)]
+pub struct Movement {
+    pub ranking_id: Uuid,
+    pub name: String,
+    pub position: String,
+    pub old_rank: i32,
+    pub new_rank: i32,
+}
+
+impl Movement {
+    /// Places gained, negative for a fall
+    pub fn change(&self) -> i32 {
+        self.old_rank - self.new_rank
+    }
+}
+
+/// How a file differs from the loaded board
+#[derive(Debug)]
+pub struct RefreshPlan<'a> {
+    pub source: String,
+    pub moved: Vec<Movement>,
+    pub added: Vec<&'a RankingEntry>,
+    pub removed: Vec<CurrentRanking>,
+    pub unchanged: usize,
+}
+
+impl RefreshPlan<'_> {
+    pub fn is_empty(&self) -> bool {
+        self.moved.is_empty() && self.added.is_empty() && self.removed.is_empty()
+    }
+
+    /// Prospects that moved up, biggest jump first
+    pub fn risers(&self) -> Vec<&Movement> {
+        let mut risers: Vec<&Movement> = self.moved.iter().filter(|m| m.change() > 0).collect();
+        risers.sort_by_key(|m| (-m.change(), m.new_rank));
+        risers
+    }
+
+    /// Prospects that moved down, biggest drop first
+    pub fn fallers(&self) -> Vec<&Movement> {
+        let mut fallers: Vec<&Movement> = self.moved.iter().filter(|m| m.change() < 0).collect();
+        fallers.sort_by_key(|m| (m.change(), m.new_rank));
+        fallers
+    }
+
+    pub fn print_report(&self) {
+        println!("\nMovement Report for '{}':", self.source);
+        println!("  Moved:     {}", self.moved.len());
+        println!("  Added:     {}", self.added.len());
+        println!("  Removed:   {}", self.removed.len());
+        println!("  Unchanged: {}", self.unchanged);
+
+        let print_movements = |title: &str, movements: Vec<&Movement>| {
+            if movements.is_empty() {
+                return;
+            }
+            println!("\n{}:", title);
+            for m in movements.iter().take(REPORT_LIMIT) {
+                println!(
+                    "  {:+4}  {} ({})  {} -> {}",
+                    m.change(),
+                    m.name,
+                    m.position,
+                    m.old_rank,
+                    m.new_rank
+                );
+            }
+            if movements.len() > REPORT_LIMIT {
+                println!("  ... and {} more", movements.len() - REPORT_LIMIT);
+            }
+        };
+        print_movements("Risers", self.risers());
+        print_movements("Fallers", self.fallers());
+
+        if !self.added.is_empty() {
+            println!("\nAdded:");
+            for entry in &self.added {
+                println!(
+                    "  #{:<4} {} {} ({}, {})",
+                    entry.rank, entry.first_name, entry.last_name, entry.position, entry.school
+                );
+            }
+        }
+        if !self.removed.is_empty() {
+            println!("\nRemoved:");
+            for ranking in &self.removed {
+                println!(
+                    "  #{:<4} {} {} ({})",
+                    ranking.rank, ranking.first_name, ranking.last_name, ranking.position
+                );
+            }
+        }
+    }
+}
+
+#[derive(Debug, Default)]
+pub struct RankingsRefreshStats {
+    pub rankings_updated: usize,
+    pub rankings_added: usize,
+    pub rankings_removed: usize,
+    pub prospects_discovered: usize,
+    pub scouting_reports_created: usize,
+    /// New prospects skipped for an unmapped position
+    pub warnings: Vec<String>,
+}
+
+impl RankingsRefreshStats {
+    pub fn print_summary(&self) {
+        println!("\nRankings Refresh Summary:");
+        println!("  Rankings updated:          {}", self.rankings_updated);
+        println!("  Rankings added:            {}", self.rankings_added);
+        println!("  Rankings removed:          {}", self.rankings_removed);
+        println!("  New prospects discovered:  {}", self.prospects_discovered);
+        println!(
+            "  Scouting reports created:  {}",
+            self.scouting_reports_created
+        );
+
+        if !self.warnings.is_empty() {
+            println!("\nWarnings:");
+            for warning in &self.warnings {
+                println!("  - {}", warning);
+            }
+        }
+    }
+}
+
+/// The board loaded for a source, best rank first. Empty when the source
+/// has never been loaded.
+pub async fn load_board(pool: &PgPool, source: &str) -> Result<Vec<CurrentRanking>> {
+    let board = sqlx::query_as::<_, CurrentRanking>(
+        r#"
+        SELECT pr.id, pr.player_id, p.first_name, p.last_name, p.position, pr.rank
+        FROM prospect_rankings pr
+        JOIN ranking_sources rs ON rs.id = pr.ranking_source_id
+        JOIN players p ON p.id = pr.player_id
+        WHERE rs.name = $1
+        ORDER BY pr.rank, pr.id
+        "#,
+    )
+    .bind(source)
+    .fetch_all(pool)
+    .await?;
+    Ok(board)
+}
+
+/// Compares a file with the loaded board of its source. A prospect listed
+/// twice in the file counts once, at the better rank.
+pub fn plan_refresh<'a>(current: &[CurrentRanking], data: &'a RankingData) -> RefreshPlan<'a> {
+    let mut on_board: HashMap<(String, String), &CurrentRanking> = current
+        .iter()
+        .map(|r| (name_key(&r.first_name, &r.last_name), r))
+        .collect();
+
+    let mut entries: Vec<&RankingEntry> = data.rankings.iter().collect();
+    entries.sort_by_key(|e| e.rank);
+
+    let mut plan = RefreshPlan {
+        source: data.meta.source.clone(),
+        moved: Vec::new(),
+        added: Vec::new(),
+        removed: Vec::new(),
+        unchanged: 0,
+    };
+    let mut seen = HashSet::new();
+    for entry in entries {
+        let key = name_key(&entry.first_name, &entry.last_name);
+        if !seen.insert(key.clone()) {
+            continue;
+        }
+        match on_board.remove(&key) {
+            Some(ranking) if ranking.rank == entry.rank => plan.unchanged += 1,
+            Some(ranking) => plan.moved.push(Movement {
+                ranking_id: ranking.id,
+                name: format!("{} {}", ranking.first_name, ranking.last_name),
+                position: ranking.position.clone(),
+                old_rank: ranking.rank,
+                new_rank: entry.rank,
+            }),
+            None => plan.added.push(entry),
+        }
+    }
+
+    plan.removed = current
+        .iter()
+        .filter(|r| on_board.contains_key(&name_key(&r.first_name, &r.last_name)))
+        .cloned()
+        .collect();
+    plan
+}
+
+/// Applies a plan: moves, removals and additions in one transaction, then
+/// scouting reports for prospects that were not in the database
+#[allow(clippy::too_many_arguments)]
+pub async fn apply_refresh(
+    plan: &RefreshPlan<'_>,
+    data: &RankingData,
+    pool: &PgPool,
+    batch_size: usize,
+    player_repo: &dyn PlayerRepository,
+    team_repo: &dyn TeamRepository,
+    ranking_source_repo: &dyn RankingSourceRepository,
+    scouting_report_repo: &dyn ScoutingReportRepository,
+    profiles: &GradeProfiles,
+    concurrency: usize,
+) -> Result<RankingsRefreshStats> {
+    let mut stats = RankingsRefreshStats::default();
+
+    let scraped_at = NaiveDate::parse_from_str(&data.meta.scraped_at, "%Y-%m-%d").map_err(|e| {
+        anyhow::anyhow!("Invalid scraped_at date '{}': {}", data.meta.scraped_at, e)
+    })?;
+    let source = find_or_create_source(data, ranking_source_repo).await?;
+
+    // Added prospects may already be players, from another source's board
+    let players = player_repo
+        .find_by_draft_year(data.meta.draft_year)
+        .await
+        .map_err(|e| anyhow::anyhow!("Failed to fetch players: {}", e))?;
+    let player_ids: HashMap<(String, String), Uuid> = players
+        .iter()
+        .map(|p| (name_key(&p.first_name, &p.last_name), p.id))
+        .collect();
+
+    let mut new_players: Vec<Player> = Vec::new();
+    let mut new_player_entries: Vec<(Uuid, Position, &RankingEntry)> = Vec::new();
+    let mut rankings_to_insert: Vec<ProspectRanking> = Vec::new();
+    for entry in &plan.added {
+        let player_id = match player_ids.get(&name_key(&entry.first_name, &entry.last_name)) {
+            Some(id) => *id,
+            None => {
+                let position = match map_position(&entry.position) {
+                    Ok(p) => p,
+                    Err(e) => {
+                        let msg =
+                            format!("Skipping {} {}: {}", entry.first_name, entry.last_name, e);
+                        tracing::warn!("{}", msg);
+                        stats.warnings.push(msg);
+                        continue;
+                    }
+                };
+                let player = new_player_from_entry(entry, position, data.meta.draft_year)?;
+                new_player_entries.push((player.id, position, entry));
+                let id = player.id;
+                new_players.push(player);
+                id
+            }
+        };
+        let ranking =
+            ProspectRanking::new(source.id, player_id, entry.rank, scraped_at).map_err(|e| {
+                anyhow::anyhow!(
+                    "Failed to create ranking for {} {}: {}",
+                    entry.first_name,
+                    entry.last_name,
+                    e
+                )
+            })?;
+        rankings_to_insert.push(ranking);
+    }
+
+    let teams = if new_players.is_empty() {
+        Vec::new()
+    } else {
+        let teams = team_repo
+            .find_all()
+            .await
+            .map_err(|e| anyhow::anyhow!("Failed to fetch teams: {}", e))?;
+        if teams.is_empty() {
+            anyhow::bail!("No teams found in database. Load teams first.");
+        }
+        player_repo.create_many(&new_players).await.map_err(|e| {
+            anyhow::anyhow!("Failed to insert {} new players: {}", new_players.len(), e)
+        })?;
+        stats.prospects_discovered = new_players.len();
+        teams
+    };
+
+    let mut tx = pool
+        .begin()
+        .await
+        .map_err(|e| anyhow::anyhow!("Failed to begin transaction: {}", e))?;
+
+    let removed_ids: Vec<Uuid> = plan.removed.iter().map(|r| r.id).collect();
+    let delete_result = sqlx::query("DELETE FROM prospect_rankings WHERE id = ANY($1)")
+        .bind(&removed_ids)
+        .execute(&mut *tx)
+        .await
+        .map_err(|e| anyhow::anyhow!("Failed to remove rankings: {}", e))?;
+    stats.rankings_removed = delete_result.rows_affected() as usize;
+
+    let moved_ids: Vec<Uuid> = plan.moved.iter().map(|m| m.ranking_id).collect();
+    let new_ranks: Vec<i32> = plan.moved.iter().map(|m| m.new_rank).collect();
+    let update_result = sqlx::query(
+        r#"
+        UPDATE prospect_rankings pr
+        SET rank = moved.rank, scraped_at = $3
+        FROM UNNEST($1::uuid[], $2::int4[]) AS moved(id, rank)
+        WHERE pr.id = moved.id
+        "#,
+    )
+    .bind(&moved_ids)
+    .bind(&new_ranks)
+    .bind(scraped_at)
+    .execute(&mut *tx)
+    .await
+    .map_err(|e| anyhow::anyhow!("Failed to update rankings: {}", e))?;
+    stats.rankings_updated = update_result.rows_affected() as usize;
+
+    stats.rankings_added = insert_rankings(&mut tx, &rankings_to_insert, batch_size).await?;
+
+    tx.commit()
+        .await
+        .map_err(|e| anyhow::anyhow!("Failed to commit rankings refresh: {}", e))?;
+
+    stats.scouting_reports_created = create_reports_for_new_players(
+        &new_player_entries,
+        &teams,
+        scouting_report_repo,
+        profiles,
+        concurrency,
+    )
+    .await?;
+
+    Ok(stats)
+}
+
+fn name_key(first_name: &str, last_name: &str) -> (String, String) {
+    (normalize_name(first_name), normalize_name(last_name))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::scouting_report_loader::RankingMeta;
+
+    fn ranking(first: &str, last: &str, rank: i32) -> CurrentRanking {
+        CurrentRanking {
+            id: Uuid::new_v4(),
+            player_id: Uuid::new_v4(),
+            first_name: first.to_string(),
+            last_name: last.to_string(),
+            position: "QB".to_string(),
+            rank,
+        }
+    }
+
+    fn entry(first: &str, last: &str, rank: i32) -> RankingEntry {
+        RankingEntry {
+            rank,
+            first_name: first.to_string(),
+            last_name: last.to_string(),
+            position: "QB".to_string(),
+            school: "Texas".to_string(),
+            height_inches: None,
+            weight_pounds: None,
+            profile_url: None,
+            headshot_url: None,
+        }
+    }
+
+    fn data(rankings: Vec<RankingEntry>) -> RankingData {
+        RankingData {
+            meta: RankingMeta {
+                version: "1.0.0".to_string(),
+                source: "Tankathon".to_string(),
+                source_url: "https://example.com".to_string(),
+                draft_year: 2026,
+                scraped_at: "2026-03-01".to_string(),
+                total_prospects: rankings.len(),
+            },
+            rankings,
+        }
+    }
+
+    #[test]
+    fn test_plan_classifies_changes() {
+        let current = vec![
+            ranking("John", "Smith", 1),
+            ranking("Mike", "Jones", 2),
+            ranking("Dan", "Brown", 3),
+        ];
+        let file = data(vec![
+            entry("Mike", "Jones", 1),
+            entry("John", "Smith", 2),
+            entry("Tom", "White", 3),
+        ]);
+        let plan = plan_refresh(&current, &file);
+
+        assert_eq!(plan.moved.len(), 2);
+        assert_eq!(plan.added.len(), 1);
+        assert_eq!(plan.added[0].last_name, "White");
+        assert_eq!(plan.removed.len(), 1);
+        assert_eq!(plan.removed[0].last_name, "Brown");
+        assert_eq!(plan.unchanged, 0);
+    }
+
+    #[test]
+    fn test_unchanged_board_is_empty_plan() {
+        let current = vec![ranking("John", "Smith", 1), ranking("C.J.", "Stroud", 2)];
+        let file = data(vec![entry("John", "Smith", 1), entry("CJ", "Stroud", 2)]);
+        let plan = plan_refresh(&current, &file);
+
+        assert!(plan.is_empty());
+        assert_eq!(plan.unchanged, 2);
+    }
+
+    #[test]
+    fn test_risers_and_fallers_ordered_by_size() {
+        let current = vec![
+            ranking("A", "One", 10),
+            ranking("B", "Two", 20),
+            ranking("C", "Three", 1),
+            ranking("D", "Four", 2),
+        ];
+        let file = data(vec![
+            entry("A", "One", 5),
+            entry("B", "Two", 3),
+            entry("C", "Three", 30),
+            entry("D", "Four", 4),
+        ]);
+        let plan = plan_refresh(&current, &file);
+
+        let risers: Vec<i32> = plan.risers().iter().map(|m| m.change()).collect();
+        let fallers: Vec<i32> = plan.fallers().iter().map(|m| m.change()).collect();
+        assert_eq!(risers, vec![17, 5]);
+        assert_eq!(fallers, vec![-29, -2]);
+    }
+
+    #[test]
+    fn test_duplicate_entry_counts_once_at_better_rank() {
+        let current = vec![ranking("John", "Smith", 4)];
+        let file = data(vec![entry("John", "Smith", 9), entry("John", "Smith", 4)]);
+        let plan = plan_refresh(&current, &file);
+
+        assert!(plan.is_empty());
+        assert_eq!(plan.unchanged, 1);
+    }
+}
@@ -123,6 +123,25 @@ pub fn validate_ranking_data(data: &RankingData) -> RankingsValidationResult {
                 entry.last_name
             ));
         }
+
+        // Links are optional, but must point at a web page or image
+        for (field, url) in [
+            ("profile_url", &entry.profile_url),
+            ("headshot_url", &entry.headshot_url),
+        ] {
+            if let Some(url) = url {
+                if !(url.starts_with("https://") || url.starts_with("http://")) {
+                    errors.push(format!(
+                        "Entry {}: {} for {} {} is not an http(s) URL: '{}'",
+                        i + 1,
+                        field,
+                        entry.first_name,
+                        entry.last_name,
+                        url
+                    ));
+                }
+            }
+        }
     }
 
     let valid = errors.is_empty();
@@ -163,6 +182,8 @@ mod tests {
             school: "Alabama".to_string(),
             height_inches: None,
             weight_pounds: None,
+            profile_url: None,
+            headshot_url: None,
         }]);
 
         let result = validate_ranking_data(&data);
@@ -187,6 +208,8 @@ mod tests {
             school: "Alabama".to_string(),
             height_inches: None,
             weight_pounds: None,
+            profile_url: None,
+            headshot_url: None,
         }]);
 
         let result = validate_ranking_data(&data);
@@ -203,9 +226,31 @@ mod tests {
             school: "Alabama".to_string(),
             height_inches: None,
             weight_pounds: None,
+            profile_url: None,
+            headshot_url: None,
+        }]);
+
+        let result = validate_ranking_data(&data);
+        assert!(!result.valid);
+    }
+
+    #[test]
+    fn test_non_http_profile_url() {
+        let data = make_data(vec![RankingEntry {
+            rank: 1,
+            first_name: "Test".to_string(),
+            last_name: "Player".to_string(),
+            position: "QB".to_string(),
+            school: "Alabama".to_string(),
+            height_inches: None,
+            weight_pounds: None,
+            profile_url: Some("https://www.espn.com/player/_/id/1".to_string()),
+            headshot_url: Some("/i/headshots/1.png".to_string()),
         }]);
 
         let result = validate_ranking_data(&data);
         assert!(!result.valid);
+        assert_eq!(result.errors.len(), 1);
+        assert!(result.errors[0].contains("headshot_url"));
     }
 }
@@ -21,7 +21,11 @@ use uuid::Uuid;
 
 use domain::repositories::{ProspectProfileRepository, ScoutingReportRepository, TeamRepository};
 
-use crate::grade_generator::{create_scouting_report_with_grade, grade_tier_to_consensus_grade};
+use crate::grade_generator::{
+    create_scouting_report_with_grade, grade_tier_to_consensus_grade, GradeProfiles,
+};
+use crate::position_mapper::map_position;
+use crate::scouting_report_loader::create_reports_concurrently;
 
 /// Slug of the Brugler "Beast" source as written by `the_beast_loader`.
 const BEAST_SOURCE: &str = "the-beast-2026";
@@ -34,6 +38,8 @@ pub struct ScoutingBackfillStats {
     pub players_with_beast_tier: usize,
     pub players_with_neutral_grade: usize,
     pub reports_created: usize,
+    /// Players skipped for an unmapped position
+    pub warnings: Vec<String>,
     pub errors: Vec<String>,
 }
 
@@ -58,6 +64,12 @@ impl ScoutingBackfillStats {
             self.players_with_neutral_grade
         );
         println!("  Reports created:              {}", self.reports_created);
+        if !self.warnings.is_empty() {
+            println!("  Warnings:                     {}", self.warnings.len());
+            for warning in &self.warnings {
+                println!("    - {}", warning);
+            }
+        }
         println!("  Errors:                       {}", self.errors.len());
         if !self.errors.is_empty() {
             for err in self.errors.iter().take(10) {
@@ -75,6 +87,7 @@ struct PlayerRow {
     id: Uuid,
     first_name: String,
     last_name: String,
+    position: String,
 }
 
 /// Backfill scouting reports for every player in `draft_year` that has no
@@ -83,7 +96,7 @@ struct PlayerRow {
 /// For each unscouted player we create a report for every team using
 /// `grade_tier_to_consensus_grade(tier)` when the player has a Beast
 /// profile with a grade tier, or a neutral floor (3.0) otherwise. The
-/// per-team variation pipeline (`generate_team_grade`, fit grade, concern
+/// per-team variation pipeline (`GradeProfile::team_grade`, fit grade, concern
 /// flags) is identical to the one used by the consensus `scouting load`
 /// step so the resulting reports are indistinguishable in shape.
 pub async fn backfill_scouting_reports(
@@ -92,12 +105,14 @@ pub async fn backfill_scouting_reports(
     team_repo: &dyn TeamRepository,
     profile_repo: &dyn ProspectProfileRepository,
     scouting_report_repo: &dyn ScoutingReportRepository,
+    profiles: &GradeProfiles,
+    concurrency: usize,
 ) -> Result<ScoutingBackfillStats> {
     let mut stats = ScoutingBackfillStats::default();
 
-    // 1. Pull every player for this draft year (id + name).
-    let players: Vec<PlayerRow> = sqlx::query_as::<_, (Uuid, String, String)>(
-        "SELECT id, first_name, last_name FROM players WHERE draft_year = $1",
+    // 1. Pull every player for this draft year (id + name + position).
+    let players: Vec<PlayerRow> = sqlx::query_as::<_, (Uuid, String, String, String)>(
+        "SELECT id, first_name, last_name, position FROM players WHERE draft_year = $1",
     )
     .bind(draft_year)
     .fetch_all(pool)
@@ -110,10 +125,11 @@ pub async fn backfill_scouting_reports(
         )
     })?
     .into_iter()
-    .map(|(id, first_name, last_name)| PlayerRow {
+    .map(|(id, first_name, last_name, position)| PlayerRow {
         id,
         first_name,
         last_name,
+        position,
     })
     .collect();
     stats.players_scanned = players.len();
@@ -166,13 +182,27 @@ pub async fn backfill_scouting_reports(
             }
         };
 
-    // 5. For each player without a report, create reports × all teams.
+    // 5. For each player without a report, build reports × all teams, then
+    //    insert them all with bounded concurrency.
+    let mut reports = Vec::new();
+    let mut labels = Vec::new();
     for player in &players {
         if scouted_player_ids.contains(&player.id) {
             stats.players_already_scouted += 1;
             continue;
         }
 
+        let position = match map_position(&player.position) {
+            Ok(p) => p,
+            Err(e) => {
+                stats.warnings.push(format!(
+                    "Skipping {} {}: {}",
+                    player.first_name, player.last_name, e
+                ));
+                continue;
+            }
+        };
+
         let (consensus_grade, has_tier) = match beast_tier_by_player.get(&player.id) {
             Some(tier) => (grade_tier_to_consensus_grade(tier), true),
             None => (3.0, false),
@@ -190,7 +220,9 @@ pub async fn backfill_scouting_reports(
                 &team.abbreviation,
                 &player.first_name,
                 &player.last_name,
+                position,
                 consensus_grade,
+                profiles.for_team(&team.abbreviation),
             ) {
                 Ok(r) => r,
                 Err(e) => {
@@ -201,19 +233,23 @@ pub async fn backfill_scouting_reports(
                     continue;
                 }
             };
-
-            if let Err(e) = scouting_report_repo.create(&report).await {
-                stats.errors.push(format!(
-                    "Failed to insert report for {} {} / {}: {}",
-                    player.first_name, player.last_name, team.abbreviation, e
-                ));
-                continue;
-            }
-            stats.reports_created += 1;
+            reports.push(report);
+            labels.push(format!(
+                "{} {} / {}",
+                player.first_name, player.last_name, team.abbreviation
+            ));
         }
         stats.players_backfilled += 1;
     }
 
+    let failures = create_reports_concurrently(scouting_report_repo, &reports, concurrency).await;
+    stats.reports_created = reports.len() - failures.len();
+    for (i, e) in failures {
+        stats
+            .errors
+            .push(format!("Failed to insert report for {}: {}", labels[i], e));
+    }
+
     Ok(stats)
 }
 
@@ -1,21 +1,26 @@
 use std::collections::HashMap;
 
 use anyhow::Result;
+use chrono::{DateTime, Utc};
+use domain::errors::DomainError;
 use domain::models::ScoutingReport;
-use domain::repositories::{PlayerRepository, TeamRepository};
-use serde::Deserialize;
+use domain::repositories::{PlayerRepository, ScoutingReportRepository, TeamRepository};
+use futures::stream::{FuturesUnordered, StreamExt};
+use futures::FutureExt;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
 
-use crate::grade_generator::{
-    generate_concern_flags, generate_fit_grade, generate_team_grade, rank_to_grade,
-};
+use crate::csv_input;
+use crate::grade_generator::{generate_fit_grade, rank_to_grade, GradeProfiles};
+use crate::progress;
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, Serialize)]
 pub struct RankingData {
     pub meta: RankingMeta,
     pub rankings: Vec<RankingEntry>,
 }
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, Serialize)]
 #[allow(dead_code)]
 pub struct RankingMeta {
     pub version: String,
@@ -26,7 +31,7 @@ pub struct RankingMeta {
     pub total_prospects: usize,
 }
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, Serialize)]
 pub struct RankingEntry {
     pub rank: i32,
     pub first_name: String,
@@ -37,6 +42,11 @@ pub struct RankingEntry {
     pub height_inches: Option<i32>,
     #[serde(default)]
     pub weight_pounds: Option<i32>,
+    /// Player page on the source site
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub profile_url: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub headshot_url: Option<String>,
 }
 
 #[derive(Debug, Default)]
@@ -76,8 +86,32 @@ impl ScoutingReportLoadStats {
     }
 }
 
+/// One prospect in a CSV file: the `RankingEntry` fields plus the board's
+/// `source`, `source_url`, `draft_year` and `scraped_at`, which must be the
+/// same in every row
+#[derive(Debug, Deserialize)]
+struct RankingCsvRow {
+    source: String,
+    source_url: Option<String>,
+    draft_year: i32,
+    scraped_at: String,
+    rank: i32,
+    first_name: String,
+    last_name: String,
+    position: String,
+    school: String,
+    height_inches: Option<i32>,
+    weight_pounds: Option<i32>,
+    profile_url: Option<String>,
+    headshot_url: Option<String>,
+}
+
+/// Reads a JSON file, or a CSV file when its extension is `.csv`
 pub fn parse_ranking_file(file_path: &str) -> Result<RankingData> {
     let content = std::fs::read_to_string(file_path)?;
+    if csv_input::is_csv(file_path) {
+        return parse_ranking_csv(&content);
+    }
     let data: RankingData = serde_json::from_str(&content)?;
     Ok(data)
 }
@@ -87,6 +121,45 @@ pub fn parse_ranking_json(json: &str) -> Result<RankingData> {
     Ok(data)
 }
 
+pub fn parse_ranking_csv(csv: &str) -> Result<RankingData> {
+    let rows: Vec<RankingCsvRow> = csv_input::read_rows(csv)?;
+    let meta = RankingMeta {
+        version: "csv".to_string(),
+        source: csv_input::single_value("source", rows.iter().map(|r| r.source.as_str()))?
+            .to_string(),
+        source_url: csv_input::single_value(
+            "source_url",
+            rows.iter()
+                .map(|r| r.source_url.as_deref().unwrap_or_default()),
+        )?
+        .to_string(),
+        draft_year: csv_input::single_value("draft_year", rows.iter().map(|r| r.draft_year))?,
+        scraped_at: csv_input::single_value(
+            "scraped_at",
+            rows.iter().map(|r| r.scraped_at.as_str()),
+        )?
+        .to_string(),
+        total_prospects: rows.len(),
+    };
+
+    let rankings = rows
+        .into_iter()
+        .map(|row| RankingEntry {
+            rank: row.rank,
+            first_name: row.first_name,
+            last_name: row.last_name,
+            position: row.position,
+            school: row.school,
+            height_inches: row.height_inches,
+            weight_pounds: row.weight_pounds,
+            profile_url: row.profile_url,
+            headshot_url: row.headshot_url,
+        })
+        .collect();
+
+    Ok(RankingData { meta, rankings })
+}
+
 pub fn load_scouting_reports_dry_run(data: &RankingData) -> Result<ScoutingReportLoadStats> {
     let mut stats = ScoutingReportLoadStats::default();
     // In dry run, we assume 32 teams and simulate the fan-out
@@ -117,6 +190,8 @@ pub async fn load_scouting_reports(
     player_repo: &dyn PlayerRepository,
     team_repo: &dyn TeamRepository,
     pool: &sqlx::PgPool,
+    profiles: &GradeProfiles,
+    batch_size: usize,
 ) -> Result<ScoutingReportLoadStats> {
     let mut stats = ScoutingReportLoadStats::default();
     let mut consecutive_failures: usize = 0;
@@ -171,7 +246,8 @@ pub async fn load_scouting_reports(
         deleted.rows_affected()
     );
 
-    // Process each ranked prospect
+    // Build every report first, then write them in multi-row batches
+    let mut reports: Vec<ScoutingReport> = Vec::new();
     for entry in &data.rankings {
         let lookup_key = (
             entry.first_name.to_lowercase(),
@@ -190,12 +266,14 @@ pub async fn load_scouting_reports(
         };
 
         let consensus_grade = rank_to_grade(entry.rank);
-        let mut reports_created_for_player = 0;
+        let mut reports_built_for_player = 0;
 
         // Create a scouting report for each team
         for team in &teams {
-            let team_grade = generate_team_grade(
+            let profile = profiles.for_team(&team.abbreviation);
+            let team_grade = profile.team_grade(
                 consensus_grade,
+                player.position,
                 &team.abbreviation,
                 &entry.first_name,
                 &entry.last_name,
@@ -203,51 +281,20 @@ pub async fn load_scouting_reports(
             let fit_grade =
                 generate_fit_grade(&team.abbreviation, &entry.first_name, &entry.last_name);
             let (injury_concern, character_concern) =
-                generate_concern_flags(&team.abbreviation, &entry.first_name, &entry.last_name);
-
-            let report = match ScoutingReport::new(player.id, team.id, team_grade) {
-                Ok(r) => r
-                    .with_fit_grade(fit_grade)
-                    .with_injury_concern(injury_concern)
-                    .with_character_concern(character_concern),
-                Err(e) => {
-                    let msg = format!(
-                        "Failed to create scouting report for {} {} / {}: {}",
-                        entry.first_name, entry.last_name, team.abbreviation, e
+                profile.concern_flags(&team.abbreviation, &entry.first_name, &entry.last_name);
+
+            match ScoutingReport::new(player.id, team.id, team_grade) {
+                Ok(r) => {
+                    reports.push(
+                        r.with_fit_grade(fit_grade)
+                            .with_injury_concern(injury_concern)
+                            .with_character_concern(character_concern),
                     );
-                    tracing::error!("{}", msg);
-                    stats.errors.push(msg);
-                    stats.reports_failed += 1;
-                    continue;
-                }
-            };
-
-            let fit_grade_str = report.fit_grade.map(|g| g.as_str().to_string());
-            let insert_result = sqlx::query(
-                "INSERT INTO scouting_reports \
-                 (id, player_id, team_id, grade, notes, fit_grade, injury_concern, character_concern, created_at, updated_at) \
-                 VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10)"
-            )
-            .bind(report.id)
-            .bind(report.player_id)
-            .bind(report.team_id)
-            .bind(report.grade)
-            .bind(&report.notes)
-            .bind(&fit_grade_str)
-            .bind(report.injury_concern)
-            .bind(report.character_concern)
-            .bind(report.created_at)
-            .bind(report.updated_at)
-            .execute(&mut *tx)
-            .await;
-
-            match insert_result {
-                Ok(_) => {
-                    reports_created_for_player += 1;
+                    reports_built_for_player += 1;
                 }
                 Err(e) => {
                     let msg = format!(
-                        "Failed to insert scouting report for {} {} / {}: {}",
+                        "Failed to create scouting report for {} {} / {}: {}",
                         entry.first_name, entry.last_name, team.abbreviation, e
                     );
                     tracing::error!("{}", msg);
@@ -257,13 +304,12 @@ pub async fn load_scouting_reports(
             }
         }
 
-        if reports_created_for_player > 0 {
+        if reports_built_for_player > 0 {
             stats.prospects_matched += 1;
-            stats.reports_created += reports_created_for_player;
             consecutive_failures = 0;
             tracing::info!(
-                "Created {} reports for {} {} (rank {}, grade {:.2})",
-                reports_created_for_player,
+                "Built {} reports for {} {} (rank {}, grade {:.2})",
+                reports_built_for_player,
                 entry.first_name,
                 entry.last_name,
                 entry.rank,
@@ -283,6 +329,10 @@ pub async fn load_scouting_reports(
         }
     }
 
+    if stats.errors.is_empty() {
+        stats.reports_created = insert_reports(&mut tx, &reports, batch_size).await?;
+    }
+
     if !stats.errors.is_empty() {
         // Roll back so old reports are preserved rather than committing partial data.
         tx.rollback().await?;
@@ -297,9 +347,94 @@ pub async fn load_scouting_reports(
     Ok(stats)
 }
 
+/// Writes `reports` in multi-row inserts of `batch_size` rows, returning the
+/// number inserted
+pub async fn insert_reports(
+    conn: &mut sqlx::PgConnection,
+    reports: &[ScoutingReport],
+    batch_size: usize,
+) -> Result<usize> {
+    let progress = progress::bar(reports.len(), "Scouting reports");
+    let mut inserted = 0;
+    for chunk in reports.chunks(batch_size.max(1)) {
+        let ids: Vec<Uuid> = chunk.iter().map(|r| r.id).collect();
+        let player_ids: Vec<Uuid> = chunk.iter().map(|r| r.player_id).collect();
+        let team_ids: Vec<Uuid> = chunk.iter().map(|r| r.team_id).collect();
+        let grades: Vec<f64> = chunk.iter().map(|r| r.grade).collect();
+        let notes: Vec<Option<String>> = chunk.iter().map(|r| r.notes.clone()).collect();
+        let fit_grades: Vec<Option<String>> = chunk
+            .iter()
+            .map(|r| r.fit_grade.map(|g| g.as_str().to_string()))
+            .collect();
+        let injury_concerns: Vec<bool> = chunk.iter().map(|r| r.injury_concern).collect();
+        let character_concerns: Vec<bool> = chunk.iter().map(|r| r.character_concern).collect();
+        let created_dates: Vec<DateTime<Utc>> = chunk.iter().map(|r| r.created_at).collect();
+        let updated_dates: Vec<DateTime<Utc>> = chunk.iter().map(|r| r.updated_at).collect();
+
+        let result = sqlx::query(
+            r#"
+            INSERT INTO scouting_reports
+                (id, player_id, team_id, grade, notes, fit_grade, injury_concern, character_concern, created_at, updated_at)
+            SELECT * FROM UNNEST($1::uuid[], $2::uuid[], $3::uuid[], $4::float8[], $5::text[], $6::text[],
+                                 $7::bool[], $8::bool[], $9::timestamptz[], $10::timestamptz[])
+            "#,
+        )
+        .bind(&ids)
+        .bind(&player_ids)
+        .bind(&team_ids)
+        .bind(&grades)
+        .bind(&notes)
+        .bind(&fit_grades)
+        .bind(&injury_concerns)
+        .bind(&character_concerns)
+        .bind(&created_dates)
+        .bind(&updated_dates)
+        .execute(&mut *conn)
+        .await
+        .map_err(|e| anyhow::anyhow!("Failed to insert scouting reports batch: {}", e))?;
+
+        inserted += result.rows_affected() as usize;
+        progress.inc(chunk.len() as u64);
+    }
+    progress.finish_and_clear();
+    Ok(inserted)
+}
+
+/// Creates `reports` through the repository, running at most `concurrency`
+/// inserts at once. Returns the index and error of each report that failed.
+pub async fn create_reports_concurrently(
+    repo: &dyn ScoutingReportRepository,
+    reports: &[ScoutingReport],
+    concurrency: usize,
+) -> Vec<(usize, DomainError)> {
+    let progress = progress::bar(reports.len(), "Scouting reports");
+    let mut pending = reports.iter().enumerate();
+    let mut in_flight = FuturesUnordered::new();
+    let mut failures = Vec::new();
+    loop {
+        while in_flight.len() < concurrency.max(1) {
+            let Some((i, report)) = pending.next() else {
+                break;
+            };
+            in_flight.push(repo.create(report).map(move |result| (i, result)));
+        }
+        let Some((i, result)) = in_flight.next().await else {
+            break;
+        };
+        if let Err(e) = result {
+            failures.push((i, e));
+        }
+        progress.inc(1);
+    }
+    progress.finish_and_clear();
+    failures.sort_by_key(|(i, _)| *i);
+    failures
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::grade_generator::{generate_concern_flags, generate_team_grade};
 
     fn sample_json() -> &'static str {
         r#"{
@@ -317,7 +452,9 @@ mod tests {
                     "first_name": "Fernando",
                     "last_name": "Mendoza",
                     "position": "QB",
-                    "school": "Indiana"
+                    "school": "Indiana",
+                    "profile_url": "https://www.espn.com/college-football/player/_/id/4837248",
+                    "headshot_url": "https://a.espncdn.com/i/headshots/college-football/players/full/4837248.png"
                 },
                 {
                     "rank": 2,
@@ -347,11 +484,41 @@ mod tests {
         assert_eq!(data.rankings[0].last_name, "Mendoza");
         assert_eq!(data.rankings[0].position, "QB");
         assert_eq!(data.rankings[0].rank, 1);
+        assert_eq!(
+            data.rankings[0].profile_url.as_deref(),
+            Some("https://www.espn.com/college-football/player/_/id/4837248")
+        );
+        assert_eq!(data.rankings[1].headshot_url, None);
     }
 
     // Core grade_to_rank and generate_team_grade behavior is covered in
     // grade_generator::tests. Here we keep only the scouting-loader integration
     // tests that exercise the public helpers in the context of this module.
+    #[test]
+    fn test_parse_csv() {
+        let csv = "source,source_url,draft_year,scraped_at,rank,first_name,last_name,position,school,height_inches,weight_pounds,profile_url
+Tankathon,https://www.tankathon.com/big_board,2026,2026-02-11,1,Fernando,Mendoza,QB,Indiana,77,225,https://www.tankathon.com/players/fernando-mendoza
+Tankathon,https://www.tankathon.com/big_board,2026,2026-02-11,2,Caleb,Downs,S,Ohio State,,,
+";
+        let data = parse_ranking_csv(csv).unwrap();
+        assert_eq!(data.meta.source, "Tankathon");
+        assert_eq!(data.meta.draft_year, 2026);
+        assert_eq!(data.meta.scraped_at, "2026-02-11");
+        assert_eq!(data.meta.total_prospects, 2);
+        assert_eq!(data.rankings[0].height_inches, Some(77));
+        assert_eq!(data.rankings[1].school, "Ohio State");
+        assert_eq!(data.rankings[1].weight_pounds, None);
+        assert_eq!(
+            data.rankings[0].profile_url.as_deref(),
+            Some("https://www.tankathon.com/players/fernando-mendoza")
+        );
+        assert_eq!(data.rankings[1].profile_url, None);
+        assert_eq!(data.rankings[1].headshot_url, None);
+
+        let mixed = csv.replacen("Tankathon,https", "ESPN,https", 1);
+        assert!(parse_ranking_csv(&mixed).is_err());
+    }
+
     #[test]
     fn test_rank_to_grade_usable_by_loader() {
         // Smoke test: loader relies on grade being monotonically decreasing
@@ -84,13 +84,12 @@ pub fn validate_ranking_data(data: &RankingData) -> ScoutingReportValidationResu
             ));
         }
 
-        // Validate position
+        // An unmapped position skips the prospect when loading
         if let Err(e) = map_position(&entry.position) {
-            result.errors.push(format!(
-                "Invalid position '{}' for {} {}: {}",
-                entry.position, entry.first_name, entry.last_name, e
+            result.warnings.push(format!(
+                "{} {}: {} The prospect will be skipped.",
+                entry.first_name, entry.last_name, e
             ));
-            result.valid = false;
         }
 
         // Validate name fields are non-empty
@@ -176,6 +175,8 @@ mod tests {
             school: "Test University".to_string(),
             height_inches: None,
             weight_pounds: None,
+            profile_url: None,
+            headshot_url: None,
         }
     }
 
@@ -256,15 +257,18 @@ mod tests {
     }
 
     #[test]
-    fn test_invalid_position_fails() {
+    fn test_unmapped_position_warns() {
         let data = RankingData {
             meta: make_meta(1),
             rankings: vec![make_entry(1, "John", "Smith", "INVALID")],
         };
 
         let result = validate_ranking_data(&data);
-        assert!(!result.valid);
-        assert!(result.errors.iter().any(|e| e.contains("Invalid position")));
+        assert!(result.valid);
+        assert!(result
+            .warnings
+            .iter()
+            .any(|w| w.contains("Unmapped position")));
     }
 
     #[test]
@@ -0,0 +1,248 @@
+//! Records which seed files have been loaded, so a load can skip a file
+//! whose exact contents are already in the database.
+//!
+//! Each successful load stores the file's SHA-256 in `seed_runs` under the
+//! entity kind (`players`, `combine`, ...) and path. A later load of the
+//! same kind with the same hash is skipped unless `--force` is given, and
+//! clearing an entity forgets its runs so the next load goes through.
+//! Loads through `--via-api` or the admin seed endpoints are not recorded.
+
+use std::collections::HashMap;
+
+use anyhow::{Context, Result};
+use chrono::{DateTime, Utc};
+use sha2::{Digest, Sha256};
+use sqlx::{FromRow, PgPool};
+
+/// The default file of each entity kind, listed by `seed-data status` even
+/// before they are loaded. Rankings boards have no default and show up
+/// once loaded.
+pub const SEED_FILES: &[(&str, &str)] = &[
+    ("teams", "data/teams_nfl.json"),
+    ("players", "data/players_2026.json"),
+    ("needs", "data/team_needs_2026.json"),
+    ("seasons", "data/team_seasons_2025.json"),
+    ("draft-order", "data/draft_order_2026.json"),
+    ("scouting", "data/rankings/rankings.json"),
+    ("freaks", "data/feldman_freaks_2026.json"),
+    ("combine", "data/combine_2026.json"),
+    ("percentiles", "data/combine_percentiles.json"),
+    ("the-beast", "data/the_beast_2026.json"),
+    ("actual-results", "data/actual_draft_2026.json"),
+    ("college-stats", "data/college_stats_2026.json"),
+];
+
+/// A seed file about to be loaded, with the hash of its current contents
+#[derive(Debug, Clone)]
+pub struct SeedFile {
+    pub kind: &'static str,
+    pub path: String,
+    pub content_hash: String,
+}
+
+impl SeedFile {
+    pub fn read(kind: &'static str, path: &str) -> Result<Self> {
+        let content_hash = hash_file(path)?;
+        Ok(Self {
+            kind,
+            path: normalize_path(path),
+            content_hash,
+        })
+    }
+
+    /// When these exact contents were last loaded for this kind, from any path
+    pub async fn loaded_at(&self, pool: &PgPool) -> Result<Option<DateTime<Utc>>> {
+        let loaded_at: Option<DateTime<Utc>> = sqlx::query_scalar(
+            "SELECT MAX(loaded_at) FROM seed_runs WHERE kind = $1 AND content_hash = $2",
+        )
+        .bind(self.kind)
+        .bind(&self.content_hash)
+        .fetch_one(pool)
+        .await?;
+        Ok(loaded_at)
+    }
+
+    /// Records a successful load, replacing the previous run of this path
+    pub async fn record(&self, pool: &PgPool) -> Result<()> {
+        sqlx::query(
+            "INSERT INTO seed_runs (kind, file_path, content_hash, loaded_at) \
+             VALUES ($1, $2, $3, NOW()) \
+             ON CONFLICT (kind, file_path) \
+             DO UPDATE SET content_hash = EXCLUDED.content_hash, loaded_at = EXCLUDED.loaded_at",
+        )
+        .bind(self.kind)
+        .bind(&self.path)
+        .bind(&self.content_hash)
+        .execute(pool)
+        .await?;
+        Ok(())
+    }
+}
+
+/// A recorded load
+#[derive(Debug, Clone, FromRow)]
+pub struct SeedRun {
+    pub kind: String,
+    pub file_path: String,
+    pub content_hash: String,
+    pub loaded_at: DateTime<Utc>,
+}
+
+pub async fn list_runs(pool: &PgPool) -> Result<Vec<SeedRun>> {
+    let runs = sqlx::query_as::<_, SeedRun>(
+        "SELECT kind, file_path, content_hash, loaded_at FROM seed_runs ORDER BY kind, file_path",
+    )
+    .fetch_all(pool)
+    .await?;
+    Ok(runs)
+}
+
+/// Forgets every run of a kind after its rows were cleared, so the next
+/// load of an unchanged file is not skipped
+pub async fn forget(pool: &PgPool, kind: &str) -> Result<u64> {
+    let result = sqlx::query("DELETE FROM seed_runs WHERE kind = $1")
+        .bind(kind)
+        .execute(pool)
+        .await?;
+    Ok(result.rows_affected())
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FileStatus {
+    /// Loaded, and the file is unchanged since
+    Loaded,
+    /// Loaded, but the file has changed since
+    Stale,
+    /// The file exists but was never loaded
+    NotLoaded,
+    /// Loaded, but the file is gone
+    Missing,
+}
+
+impl FileStatus {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            FileStatus::Loaded => "loaded",
+            FileStatus::Stale => "stale",
+            FileStatus::NotLoaded => "not loaded",
+            FileStatus::Missing => "missing",
+        }
+    }
+}
+
+pub fn file_status(recorded_hash: Option<&str>, current_hash: Option<&str>) -> FileStatus {
+    match (recorded_hash, current_hash) {
+        (Some(recorded), Some(current)) if recorded == current => FileStatus::Loaded,
+        (Some(_), Some(_)) => FileStatus::Stale,
+        (Some(_), None) => FileStatus::Missing,
+        (None, _) => FileStatus::NotLoaded,
+    }
+}
+
+/// One row of `seed-data status`
+#[derive(Debug, Clone)]
+pub struct StatusEntry {
+    pub kind: String,
+    pub file_path: String,
+    pub status: FileStatus,
+    pub loaded_at: Option<DateTime<Utc>>,
+}
+
+/// The default seed files plus every recorded run, each compared with the
+/// file on disk. Default files that don't exist and were never loaded are
+/// left out.
+pub fn status_entries(runs: &[SeedRun]) -> Vec<StatusEntry> {
+    let mut recorded: HashMap<(&str, &str), &SeedRun> = runs
+        .iter()
+        .map(|run| ((run.kind.as_str(), run.file_path.as_str()), run))
+        .collect();
+
+    let mut entries = Vec::new();
+    let mut push = |kind: &str, path: &str, run: Option<&SeedRun>| {
+        let current_hash = hash_file(path).ok();
+        if run.is_none() && current_hash.is_none() {
+            return;
+        }
+        entries.push(StatusEntry {
+            kind: kind.to_string(),
+            file_path: path.to_string(),
+            status: file_status(
+                run.map(|r| r.content_hash.as_str()),
+                current_hash.as_deref(),
+            ),
+            loaded_at: run.map(|r| r.loaded_at),
+        });
+    };
+
+    for (kind, path) in SEED_FILES {
+        let run = recorded.remove(&(*kind, *path));
+        push(kind, path, run);
+    }
+    for run in runs {
+        if recorded.contains_key(&(run.kind.as_str(), run.file_path.as_str())) {
+            push(&run.kind, &run.file_path, Some(run));
+        }
+    }
+    entries
+}
+
+pub fn hash_file(path: &str) -> Result<String> {
+    let bytes = std::fs::read(path).with_context(|| format!("Failed to read {}", path))?;
+    Ok(hash_bytes(&bytes))
+}
+
+fn hash_bytes(bytes: &[u8]) -> String {
+    Sha256::digest(bytes)
+        .iter()
+        .map(|b| format!("{:02x}", b))
+        .collect()
+}
+
+/// `./data/x.json` and `data/x.json` are the same file
+fn normalize_path(path: &str) -> String {
+    path.trim_start_matches("./").to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_hash_is_sha256_hex() {
+        assert_eq!(
+            hash_bytes(b"abc"),
+            "ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad"
+        );
+    }
+
+    #[test]
+    fn test_file_status() {
+        assert_eq!(file_status(Some("a"), Some("a")), FileStatus::Loaded);
+        assert_eq!(file_status(Some("a"), Some("b")), FileStatus::Stale);
+        assert_eq!(file_status(Some("a"), None), FileStatus::Missing);
+        assert_eq!(file_status(None, Some("a")), FileStatus::NotLoaded);
+    }
+
+    #[test]
+    fn test_status_entries_include_runs_outside_defaults() {
+        let runs = vec![SeedRun {
+            kind: "rankings".to_string(),
+            file_path: "does/not/exist.json".to_string(),
+            content_hash: "a".repeat(64),
+            loaded_at: Utc::now(),
+        }];
+        let entries = status_entries(&runs);
+        let board = entries
+            .iter()
+            .find(|e| e.kind == "rankings")
+            .expect("recorded run is listed");
+        assert_eq!(board.status, FileStatus::Missing);
+        assert!(board.loaded_at.is_some());
+    }
+
+    #[test]
+    fn test_normalize_path() {
+        assert_eq!(normalize_path("./data/x.json"), "data/x.json");
+        assert_eq!(normalize_path("data/x.json"), "data/x.json");
+    }
+}
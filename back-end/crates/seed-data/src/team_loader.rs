@@ -1,15 +1,17 @@
 use anyhow::{anyhow, Result};
 use domain::models::{Conference, Division, Team};
 use domain::repositories::TeamRepository;
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
 
-#[derive(Debug, Deserialize)]
+use crate::load_mode::{FieldChanges, LoadMode};
+
+#[derive(Debug, Deserialize, Serialize)]
 pub struct TeamData {
     pub meta: TeamMetaData,
     pub teams: Vec<TeamEntry>,
 }
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, Serialize)]
 #[allow(dead_code)]
 pub struct TeamMetaData {
     pub version: String,
@@ -18,7 +20,7 @@ pub struct TeamMetaData {
     pub total_teams: usize,
 }
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, Serialize)]
 pub struct TeamEntry {
     pub name: String,
     pub abbreviation: String,
@@ -70,6 +72,12 @@ pub fn map_division(division: &str) -> Result<Division> {
 pub struct TeamLoadStats {
     pub success: usize,
     pub skipped: usize,
+    /// Existing teams changed by an upsert
+    pub updated: usize,
+    /// Existing teams an upsert found already matching the file
+    pub unchanged: usize,
+    /// One line per updated team naming the fields that changed
+    pub changes: Vec<String>,
     pub errors: Vec<String>,
 }
 
@@ -78,7 +86,15 @@ impl TeamLoadStats {
         println!("\nLoad Summary:");
         println!("  Succeeded: {}", self.success);
         println!("  Skipped:   {}", self.skipped);
+        println!("  Updated:   {}", self.updated);
+        println!("  Unchanged: {}", self.unchanged);
         println!("  Errors:    {}", self.errors.len());
+        if !self.changes.is_empty() {
+            println!("\nChanges:");
+            for change in &self.changes {
+                println!("  - {}", change);
+            }
+        }
         if !self.errors.is_empty() {
             println!("\nErrors:");
             for error in &self.errors {
@@ -141,13 +157,44 @@ pub fn load_teams_dry_run(data: &TeamData) -> Result<TeamLoadStats> {
     Ok(stats)
 }
 
-pub async fn load_teams(data: &TeamData, repo: &dyn TeamRepository) -> Result<TeamLoadStats> {
+/// Inserts the file's teams. Teams already stored under the same
+/// abbreviation are skipped in `Insert` mode and brought in line with the
+/// file in `Upsert` mode.
+pub async fn load_teams(
+    data: &TeamData,
+    repo: &dyn TeamRepository,
+    mode: LoadMode,
+) -> Result<TeamLoadStats> {
     let mut stats = TeamLoadStats::default();
     let mut consecutive_failures: usize = 0;
 
     for entry in &data.teams {
         // Check if team already exists by abbreviation (UNIQUE constraint)
         match repo.find_by_abbreviation(&entry.abbreviation).await {
+            Ok(Some(existing)) if mode == LoadMode::Upsert => {
+                match update_team(entry, &existing, repo, &mut stats).await {
+                    Ok(()) => consecutive_failures = 0,
+                    Err(e) => {
+                        let msg = format!(
+                            "Failed to update {} ({}): {}",
+                            entry.name, entry.abbreviation, e
+                        );
+                        tracing::error!("{}", msg);
+                        stats.errors.push(msg);
+                        consecutive_failures += 1;
+                    }
+                }
+                if consecutive_failures >= MAX_CONSECUTIVE_FAILURES {
+                    let abort_msg = format!(
+                        "Aborting: {} consecutive failures detected. This may indicate a systematic problem (e.g., database down, schema mismatch).",
+                        consecutive_failures
+                    );
+                    tracing::error!("{}", abort_msg);
+                    stats.errors.push(abort_msg);
+                    break;
+                }
+                continue;
+            }
             Ok(Some(existing)) => {
                 tracing::warn!(
                     "Skipping {} ({}): team already exists with id {}",
@@ -232,6 +279,38 @@ pub async fn load_teams(data: &TeamData, repo: &dyn TeamRepository) -> Result<Te
     Ok(stats)
 }
 
+/// Brings a stored team in line with its file entry, recording the changed
+/// fields, and leaves the row alone when nothing differs
+async fn update_team(
+    entry: &TeamEntry,
+    stored: &Team,
+    repo: &dyn TeamRepository,
+    stats: &mut TeamLoadStats,
+) -> Result<()> {
+    let mut team = entry.to_domain()?;
+
+    let mut changes = FieldChanges::default();
+    changes.compare("name", &stored.name, &team.name);
+    changes.compare("city", &stored.city, &team.city);
+    changes.compare("conference", &stored.conference, &team.conference);
+    changes.compare("division", &stored.division, &team.division);
+
+    if changes.is_empty() {
+        stats.unchanged += 1;
+        return Ok(());
+    }
+
+    team.id = stored.id;
+    team.created_at = stored.created_at;
+    repo.update(&team).await?;
+
+    let change = changes.describe(&stored.abbreviation);
+    tracing::info!("Updated {}", change);
+    stats.changes.push(change);
+    stats.updated += 1;
+    Ok(())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -1,17 +1,18 @@
 use anyhow::Result;
 use domain::models::TeamNeed;
 use domain::repositories::{TeamNeedRepository, TeamRepository};
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
 
+use crate::csv_input;
 use crate::position_mapper::map_position;
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, Serialize)]
 pub struct TeamNeedData {
     pub meta: TeamNeedMetaData,
     pub team_needs: Vec<TeamNeedEntry>,
 }
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, Serialize)]
 #[allow(dead_code)]
 pub struct TeamNeedMetaData {
     pub version: String,
@@ -21,13 +22,13 @@ pub struct TeamNeedMetaData {
     pub description: String,
 }
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, Serialize)]
 pub struct TeamNeedEntry {
     pub team_abbreviation: String,
     pub needs: Vec<PositionalNeed>,
 }
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, Serialize)]
 pub struct PositionalNeed {
     pub position: String,
     pub priority: i32,
@@ -38,6 +39,8 @@ pub struct TeamNeedLoadStats {
     pub teams_processed: usize,
     pub needs_created: usize,
     pub teams_skipped: usize,
+    /// Needs skipped for an unmapped position
+    pub warnings: Vec<String>,
     pub errors: Vec<String>,
 }
 
@@ -48,6 +51,12 @@ impl TeamNeedLoadStats {
         println!("  Needs created:   {}", self.needs_created);
         println!("  Teams skipped:   {}", self.teams_skipped);
         println!("  Errors:          {}", self.errors.len());
+        if !self.warnings.is_empty() {
+            println!("\nWarnings:");
+            for warning in &self.warnings {
+                println!("  - {}", warning);
+            }
+        }
         if !self.errors.is_empty() {
             println!("\nErrors:");
             for error in &self.errors {
@@ -57,12 +66,63 @@ impl TeamNeedLoadStats {
     }
 }
 
+/// One need in a CSV file. A team's needs are the rows with its
+/// abbreviation, in file order.
+#[derive(Debug, Deserialize)]
+struct TeamNeedCsvRow {
+    team_abbreviation: String,
+    position: String,
+    priority: i32,
+}
+
+/// Reads a JSON file, or a CSV file when its extension is `.csv`
 pub fn parse_team_need_file(file_path: &str) -> Result<TeamNeedData> {
     let content = std::fs::read_to_string(file_path)?;
+    if csv_input::is_csv(file_path) {
+        return parse_team_need_csv(&content);
+    }
     let data: TeamNeedData = serde_json::from_str(&content)?;
     Ok(data)
 }
 
+pub fn parse_team_need_json(json: &str) -> Result<TeamNeedData> {
+    let data: TeamNeedData = serde_json::from_str(json)?;
+    Ok(data)
+}
+
+pub fn parse_team_need_csv(csv: &str) -> Result<TeamNeedData> {
+    let rows: Vec<TeamNeedCsvRow> = csv_input::read_rows(csv)?;
+
+    let mut team_needs: Vec<TeamNeedEntry> = Vec::new();
+    for row in rows {
+        let need = PositionalNeed {
+            position: row.position,
+            priority: row.priority,
+        };
+        match team_needs
+            .iter_mut()
+            .find(|entry| entry.team_abbreviation == row.team_abbreviation)
+        {
+            Some(entry) => entry.needs.push(need),
+            None => team_needs.push(TeamNeedEntry {
+                team_abbreviation: row.team_abbreviation,
+                needs: vec![need],
+            }),
+        }
+    }
+
+    Ok(TeamNeedData {
+        meta: TeamNeedMetaData {
+            version: "csv".to_string(),
+            last_updated: String::new(),
+            sources: Vec::new(),
+            total_teams: team_needs.len(),
+            description: String::new(),
+        },
+        team_needs,
+    })
+}
+
 /// Maximum number of consecutive failures before aborting.
 const MAX_CONSECUTIVE_FAILURES: usize = 5;
 
@@ -82,12 +142,9 @@ pub fn load_team_needs_dry_run(data: &TeamNeedData) -> Result<TeamNeedLoadStats>
                     needs_for_team += 1;
                 }
                 Err(e) => {
-                    let msg = format!(
-                        "Invalid position '{}' for {}: {}",
-                        need.position, entry.team_abbreviation, e
-                    );
-                    tracing::error!("{}", msg);
-                    stats.errors.push(msg);
+                    let msg = format!("Skipping need for {}: {}", entry.team_abbreviation, e);
+                    tracing::warn!("{}", msg);
+                    stats.warnings.push(msg);
                 }
             }
         }
@@ -176,13 +233,9 @@ pub async fn load_team_needs(
             let position = match map_position(&need.position) {
                 Ok(p) => p,
                 Err(e) => {
-                    let msg = format!(
-                        "Invalid position '{}' for {}: {}",
-                        need.position, entry.team_abbreviation, e
-                    );
-                    tracing::error!("{}", msg);
-                    stats.errors.push(msg);
-                    team_had_error = true;
+                    let msg = format!("Skipping need for {}: {}", entry.team_abbreviation, e);
+                    tracing::warn!("{}", msg);
+                    stats.warnings.push(msg);
                     continue;
                 }
             };
@@ -292,6 +345,23 @@ mod tests {
         assert_eq!(data.team_needs[0].needs[0].priority, 1);
     }
 
+    #[test]
+    fn test_parse_csv_groups_needs_by_team() {
+        let csv = "team_abbreviation,position,priority
+DAL,QB,1
+NYG,CB,1
+DAL,EDGE,2
+";
+        let data = parse_team_need_csv(csv).unwrap();
+        assert_eq!(data.meta.total_teams, 2);
+        assert_eq!(data.team_needs[0].team_abbreviation, "DAL");
+        assert_eq!(data.team_needs[0].needs.len(), 2);
+        assert_eq!(data.team_needs[0].needs[1].position, "EDGE");
+        assert_eq!(data.team_needs[0].needs[1].priority, 2);
+        assert_eq!(data.team_needs[1].team_abbreviation, "NYG");
+        assert_eq!(data.team_needs[1].needs.len(), 1);
+    }
+
     #[test]
     fn test_dry_run() {
         let data: TeamNeedData = serde_json::from_str(sample_json()).unwrap();
@@ -303,7 +373,7 @@ mod tests {
     }
 
     #[test]
-    fn test_dry_run_with_invalid_position() {
+    fn test_dry_run_skips_unmapped_position() {
         let json = r#"{
             "meta": {
                 "version": "1.0.0",
@@ -327,8 +397,9 @@ mod tests {
         let stats = load_team_needs_dry_run(&data).unwrap();
         assert_eq!(stats.teams_processed, 1);
         assert_eq!(stats.needs_created, 1);
-        assert_eq!(stats.errors.len(), 1);
-        assert!(stats.errors[0].contains("INVALID"));
+        assert!(stats.errors.is_empty());
+        assert_eq!(stats.warnings.len(), 1);
+        assert!(stats.warnings[0].contains("INVALID"));
     }
 
     #[test]
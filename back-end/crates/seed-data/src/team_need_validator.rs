@@ -94,14 +94,12 @@ pub fn validate_team_need_data(data: &TeamNeedData) -> TeamNeedValidationResult
         let mut seen_positions = HashSet::new();
 
         for need in &entry.needs {
-            // Validate position using position_mapper
+            // An unmapped position skips the need when loading
             match map_position(&need.position) {
                 Err(e) => {
-                    result.errors.push(format!(
-                        "{}: Invalid position '{}' - {}",
-                        label, need.position, e
-                    ));
-                    result.valid = false;
+                    result
+                        .warnings
+                        .push(format!("{}: {} The need will be skipped.", label, e));
                 }
                 Ok(canonical_position) => {
                     // Check for duplicate positions within team using canonical position
@@ -278,7 +276,7 @@ mod tests {
     }
 
     #[test]
-    fn test_invalid_position_fails() {
+    fn test_unmapped_position_warns() {
         let data = TeamNeedData {
             meta: make_meta(1),
             team_needs: vec![make_team_entry(
@@ -288,8 +286,11 @@ mod tests {
         };
 
         let result = validate_team_need_data(&data);
-        assert!(!result.valid);
-        assert!(result.errors.iter().any(|e| e.contains("Invalid position")));
+        assert!(result.valid);
+        assert!(result
+            .warnings
+            .iter()
+            .any(|w| w.contains("Unmapped position")));
     }
 
     #[test]
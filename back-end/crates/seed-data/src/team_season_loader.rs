@@ -1,15 +1,17 @@
 use anyhow::Result;
 use domain::models::{PlayoffResult, TeamSeason};
 use domain::repositories::{TeamRepository, TeamSeasonRepository};
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
 
-#[derive(Debug, Deserialize)]
+use crate::csv_input;
+
+#[derive(Debug, Deserialize, Serialize)]
 pub struct TeamSeasonData {
     pub meta: TeamSeasonMetaData,
     pub team_seasons: Vec<TeamSeasonEntry>,
 }
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, Serialize)]
 #[allow(dead_code)]
 pub struct TeamSeasonMetaData {
     pub version: String,
@@ -19,7 +21,7 @@ pub struct TeamSeasonMetaData {
     pub total_teams: usize,
 }
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, Serialize)]
 pub struct TeamSeasonEntry {
     pub team_abbreviation: String,
     pub wins: i32,
@@ -55,8 +57,25 @@ impl TeamSeasonLoadStats {
     }
 }
 
+/// One team's record in a CSV file: the `TeamSeasonEntry` fields plus the
+/// `season_year`, which must be the same in every row
+#[derive(Debug, Deserialize)]
+struct TeamSeasonCsvRow {
+    team_abbreviation: String,
+    season_year: i32,
+    wins: i32,
+    losses: i32,
+    ties: i32,
+    playoff_result: Option<String>,
+    draft_position: Option<i32>,
+}
+
+/// Reads a JSON file, or a CSV file when its extension is `.csv`
 pub fn parse_team_season_file(file_path: &str) -> Result<TeamSeasonData> {
     let content = std::fs::read_to_string(file_path)?;
+    if csv_input::is_csv(file_path) {
+        return parse_team_season_csv(&content);
+    }
     let data: TeamSeasonData = serde_json::from_str(&content)?;
     Ok(data)
 }
@@ -66,6 +85,34 @@ pub fn parse_team_season_json(json: &str) -> Result<TeamSeasonData> {
     Ok(data)
 }
 
+pub fn parse_team_season_csv(csv: &str) -> Result<TeamSeasonData> {
+    let rows: Vec<TeamSeasonCsvRow> = csv_input::read_rows(csv)?;
+    let season_year = csv_input::single_value("season_year", rows.iter().map(|r| r.season_year))?;
+
+    let team_seasons: Vec<TeamSeasonEntry> = rows
+        .into_iter()
+        .map(|row| TeamSeasonEntry {
+            team_abbreviation: row.team_abbreviation,
+            wins: row.wins,
+            losses: row.losses,
+            ties: row.ties,
+            playoff_result: row.playoff_result,
+            draft_position: row.draft_position,
+        })
+        .collect();
+
+    Ok(TeamSeasonData {
+        meta: TeamSeasonMetaData {
+            version: "csv".to_string(),
+            last_updated: String::new(),
+            sources: Vec::new(),
+            season_year,
+            total_teams: team_seasons.len(),
+        },
+        team_seasons,
+    })
+}
+
 fn parse_playoff_result(s: &str) -> Result<PlayoffResult, String> {
     s.parse()
         .map_err(|_| format!("Invalid playoff result: {}", s))
@@ -486,6 +533,24 @@ mod tests {
         assert!(stats.errors[0].contains("Invalid draft position"));
     }
 
+    #[test]
+    fn test_parse_csv() {
+        let csv = "team_abbreviation,season_year,wins,losses,ties,playoff_result,draft_position
+TEN,2025,3,14,0,MissedPlayoffs,1
+PHI,2025,14,3,0,SuperBowlWin,
+";
+        let data = parse_team_season_csv(csv).unwrap();
+        assert_eq!(data.meta.season_year, 2025);
+        assert_eq!(data.meta.total_teams, 2);
+        assert_eq!(data.team_seasons[0].wins, 3);
+        assert_eq!(data.team_seasons[0].draft_position, Some(1));
+        assert_eq!(
+            data.team_seasons[1].playoff_result,
+            Some("SuperBowlWin".to_string())
+        );
+        assert_eq!(data.team_seasons[1].draft_position, None);
+    }
+
     #[test]
     fn test_parse_playoff_result_valid() {
         assert!(matches!(
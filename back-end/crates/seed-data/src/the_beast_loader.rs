@@ -126,6 +126,8 @@ pub struct BeastLoadStats {
     pub prodays_upserted: usize,
     pub rankings_inserted: usize,
     pub skipped_invalid_position: usize,
+    /// Prospects skipped for an unmapped position
+    pub warnings: Vec<String>,
     pub errors: Vec<String>,
 }
 
@@ -143,6 +145,9 @@ impl BeastLoadStats {
             "  Skipped (invalid pos):    {}",
             self.skipped_invalid_position
         );
+        for warning in &self.warnings {
+            println!("    - {}", warning);
+        }
         println!("  Errors:                   {}", self.errors.len());
         if !self.errors.is_empty() {
             for err in self.errors.iter().take(20) {
@@ -240,7 +245,7 @@ pub async fn load_beast(
             Ok(p) => p,
             Err(e) => {
                 stats.skipped_invalid_position += 1;
-                stats.errors.push(format!(
+                stats.warnings.push(format!(
                     "Skipping {} {} ({}): {}",
                     entry.first_name, entry.last_name, entry.position, e
                 ));
@@ -462,12 +467,8 @@ async fn upsert_combine(
     m: &BeastMeasurables,
     repo: &dyn CombineResultsRepository,
 ) -> Result<()> {
-    let source_str = match source {
-        CombineSource::Combine => "combine",
-        CombineSource::ProDay => "pro_day",
-    };
     let existing = repo
-        .find_by_player_year_source(player_id, year, source_str)
+        .find_by_player_year_source(player_id, year, &source.to_string())
         .await
         .map_err(|e| anyhow::anyhow!("combine lookup failed: {}", e))?;
 
@@ -1,6 +1,6 @@
 use std::collections::HashSet;
 
-use domain::models::Player;
+use domain::models::{InjuryStatus, Player};
 
 use crate::loader::PlayerData;
 use crate::position_mapper;
@@ -108,10 +108,18 @@ pub fn validate_player_data(data: &PlayerData) -> ValidationResult {
             }
         }
 
-        // Validate position can be mapped
+        if let Some(status) = &player.injury_status {
+            if let Err(e) = status.parse::<InjuryStatus>() {
+                result.errors.push(format!("{}: {}", label, e));
+                result.valid = false;
+            }
+        }
+
+        // An unmapped position skips the player when loading
         if let Err(e) = position_mapper::map_position(&player.position) {
-            result.errors.push(format!("{}: {}", label, e));
-            result.valid = false;
+            result
+                .warnings
+                .push(format!("{}: {} The player will be skipped.", label, e));
         }
 
         // Warn if college is missing
@@ -155,6 +163,10 @@ mod tests {
             college: Some("Test University".to_string()),
             height_inches: Some(72),
             weight_pounds: Some(200),
+            injury_status: None,
+            injury_body_part: None,
+            injury_expected_return: None,
+            medical_flag: false,
             notes: None,
         }
     }
@@ -232,15 +244,15 @@ mod tests {
     }
 
     #[test]
-    fn test_invalid_position_fails() {
+    fn test_unmapped_position_warns() {
         let data = PlayerData {
             meta: make_meta(1),
             players: vec![make_player("John", "Doe", "ATH")],
         };
 
         let result = validate_player_data(&data);
-        assert!(!result.valid);
-        assert!(result.errors.iter().any(|e| e.contains("position")));
+        assert!(result.valid);
+        assert!(result.warnings.iter().any(|w| w.contains("'ATH'")));
     }
 
     #[test]
@@ -258,6 +270,20 @@ mod tests {
         assert!(result.valid);
     }
 
+    #[test]
+    fn test_invalid_injury_status_fails() {
+        let mut player = make_player("John", "Doe", "QB");
+        player.injury_status = Some("probable".to_string());
+        let data = PlayerData {
+            meta: make_meta(1),
+            players: vec![player],
+        };
+
+        let result = validate_player_data(&data);
+        assert!(!result.valid);
+        assert!(result.errors.iter().any(|e| e.contains("injury status")));
+    }
+
     #[test]
     fn test_missing_college_warns() {
         let mut player = make_player("John", "Doe", "QB");
@@ -0,0 +1,124 @@
+//! In-process API servers and HTTP clients
+
+use std::time::Duration;
+
+use reqwest::Client;
+use tokio::sync::oneshot;
+
+use crate::database::setup_test_pool;
+
+/// Secret the test servers sign access tokens with, so clients can mint
+/// their own without registering first
+pub const TEST_JWT_SECRET: &[u8] = b"test-jwt-secret";
+
+pub fn test_jwt_keys() -> api::auth::JwtKeys {
+    api::auth::JwtKeys::new(TEST_JWT_SECRET, api::auth::DEFAULT_TOKEN_TTL)
+}
+
+/// Spawns the API server on an ephemeral port, backed by a schema of its
+/// own, and returns the base URL and database pool
+pub async fn spawn_app() -> (String, sqlx::PgPool) {
+    let pool = setup_test_pool().await;
+    let state = api::state::AppState::new(pool.clone(), None);
+    (spawn_app_with_state(state).await, pool)
+}
+
+/// Spawns the API server with a configured seed API key
+pub async fn spawn_app_with_seed_key(key: &str) -> (String, sqlx::PgPool) {
+    let pool = setup_test_pool().await;
+    let state = api::state::AppState::new(pool.clone(), Some(key.to_string()));
+    (spawn_app_with_state(state).await, pool)
+}
+
+/// Serves the API from a caller-built `AppState` and returns the base URL.
+/// Tests keep a clone of the state to reach in-process pieces such as the
+/// WebSocket connection manager.
+pub async fn spawn_app_with_state(state: api::state::AppState) -> String {
+    let app = api::routes::create_router(state.with_jwt(test_jwt_keys()))
+        .into_make_service_with_connect_info::<std::net::SocketAddr>();
+
+    // Bind to ephemeral port (port 0)
+    let listener = tokio::net::TcpListener::bind("127.0.0.1:0")
+        .await
+        .expect("Failed to bind to ephemeral port");
+
+    let addr = listener.local_addr().expect("Failed to get local address");
+    let base_url = format!("http://{}", addr);
+
+    // Create channel to notify when server is ready
+    let (tx, rx) = oneshot::channel();
+
+    tokio::spawn(async move {
+        tx.send(()).unwrap();
+        axum::serve(listener, app)
+            .await
+            .expect("Server failed to start");
+    });
+
+    rx.await.expect("Server failed to start");
+
+    // Give server a moment to fully initialize
+    tokio::time::sleep(Duration::from_millis(100)).await;
+
+    base_url
+}
+
+/// Creates a configured reqwest client with sensible defaults. Requests carry
+/// a commissioner's access token, so session, pick and trade endpoints work
+/// without extra setup.
+pub fn create_client() -> Client {
+    create_client_with_role(domain::models::Role::Commissioner)
+}
+
+/// Like `create_client`, signed in as a test user with the given role
+pub fn create_client_with_role(role: domain::models::Role) -> Client {
+    signed_in_client(uuid::Uuid::new_v4(), "tester@example.com", role)
+}
+
+/// Adds a user with the given role to the database and returns its id and
+/// a client signed in as it, for endpoints that tie data to an account
+pub async fn create_user_client(
+    pool: &sqlx::PgPool,
+    role: domain::models::Role,
+) -> (uuid::Uuid, Client) {
+    let user_id = uuid::Uuid::new_v4();
+    let email = format!("{}@example.com", user_id.simple());
+    sqlx::query(
+        "INSERT INTO users (id, email, display_name, password_hash, role) VALUES ($1, $2, 'Tester', 'not-a-hash', $3)",
+    )
+    .bind(user_id)
+    .bind(&email)
+    .bind(role.to_string())
+    .execute(pool)
+    .await
+    .expect("Failed to create test user");
+
+    (user_id, signed_in_client(user_id, &email, role))
+}
+
+fn signed_in_client(user_id: uuid::Uuid, email: &str, role: domain::models::Role) -> Client {
+    let token = test_jwt_keys()
+        .issue(user_id, email, role)
+        .expect("Failed to issue test token");
+    let mut headers = reqwest::header::HeaderMap::new();
+    headers.insert(
+        reqwest::header::AUTHORIZATION,
+        format!("Bearer {}", token).parse().unwrap(),
+    );
+
+    Client::builder()
+        .timeout(Duration::from_secs(30))
+        .connect_timeout(Duration::from_secs(5))
+        .default_headers(headers)
+        .build()
+        .expect("Failed to create HTTP client")
+}
+
+/// Creates a client that sends no access token
+pub fn create_anonymous_client() -> Client {
+    Client::builder()
+        .timeout(Duration::from_secs(30))
+        .connect_timeout(Duration::from_secs(5))
+        .build()
+        .expect("Failed to create HTTP client")
+}
@@ -0,0 +1,346 @@
+//! Builders that store valid fixtures through the repositories
+//!
+//! Each builder starts from defaults that pass domain validation and are
+//! unique within the test process, so several of the same kind can be
+//! created without naming them. `create` panics on failure, as a fixture a
+//! test can't set up is a broken test.
+
+use std::sync::atomic::{AtomicU32, Ordering};
+
+use sqlx::PgPool;
+use uuid::Uuid;
+
+use db::repositories::{
+    SessionRepo, SqlxDraftPickRepository, SqlxDraftRepository, SqlxPlayerRepository,
+    SqlxTeamRepository,
+};
+use domain::models::{
+    ChartType, Conference, Division, Draft, DraftPick, DraftSession, DraftStatus, Player, Position,
+    SessionStatus, Team,
+};
+use domain::repositories::{
+    DraftPickRepository, DraftRepository, PlayerRepository, SessionRepository, TeamRepository,
+};
+
+static NEXT_FIXTURE: AtomicU32 = AtomicU32::new(1);
+
+fn next_fixture() -> u32 {
+    NEXT_FIXTURE.fetch_add(1, Ordering::Relaxed)
+}
+
+/// Builds a team. Defaults to an AFC East team with a unique name and
+/// abbreviation.
+#[derive(Debug, Clone)]
+pub struct TeamBuilder {
+    name: String,
+    abbreviation: String,
+    city: String,
+    conference: Conference,
+    division: Division,
+}
+
+impl Default for TeamBuilder {
+    fn default() -> Self {
+        let n = next_fixture();
+        Self {
+            name: format!("Team {}", n),
+            abbreviation: format!("T{}", n % 10_000),
+            city: "Test City".to_string(),
+            conference: Conference::AFC,
+            division: Division::AFCEast,
+        }
+    }
+}
+
+impl TeamBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn name(mut self, name: impl Into<String>) -> Self {
+        self.name = name.into();
+        self
+    }
+
+    pub fn abbreviation(mut self, abbreviation: impl Into<String>) -> Self {
+        self.abbreviation = abbreviation.into();
+        self
+    }
+
+    pub fn city(mut self, city: impl Into<String>) -> Self {
+        self.city = city.into();
+        self
+    }
+
+    pub fn division(mut self, conference: Conference, division: Division) -> Self {
+        self.conference = conference;
+        self.division = division;
+        self
+    }
+
+    pub fn build(self) -> Team {
+        Team::new(
+            self.name,
+            self.abbreviation,
+            self.city,
+            self.conference,
+            self.division,
+        )
+        .expect("Invalid test team")
+    }
+
+    pub async fn create(self, pool: &PgPool) -> Team {
+        SqlxTeamRepository::new(pool.clone())
+            .create(&self.build())
+            .await
+            .expect("Failed to create test team")
+    }
+}
+
+/// Builds a draft-eligible player. Defaults to a 2026 quarterback with a
+/// unique name.
+#[derive(Debug, Clone)]
+pub struct PlayerBuilder {
+    first_name: String,
+    last_name: String,
+    position: Position,
+    draft_year: i32,
+    college: Option<String>,
+    physical_stats: Option<(i32, i32)>,
+}
+
+impl Default for PlayerBuilder {
+    fn default() -> Self {
+        Self {
+            first_name: "Test".to_string(),
+            last_name: format!("Player{}", next_fixture()),
+            position: Position::QB,
+            draft_year: 2026,
+            college: None,
+            physical_stats: None,
+        }
+    }
+}
+
+impl PlayerBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn first_name(mut self, first_name: impl Into<String>) -> Self {
+        self.first_name = first_name.into();
+        self
+    }
+
+    pub fn last_name(mut self, last_name: impl Into<String>) -> Self {
+        self.last_name = last_name.into();
+        self
+    }
+
+    pub fn position(mut self, position: Position) -> Self {
+        self.position = position;
+        self
+    }
+
+    pub fn draft_year(mut self, draft_year: i32) -> Self {
+        self.draft_year = draft_year;
+        self
+    }
+
+    pub fn college(mut self, college: impl Into<String>) -> Self {
+        self.college = Some(college.into());
+        self
+    }
+
+    pub fn physical_stats(mut self, height_inches: i32, weight_pounds: i32) -> Self {
+        self.physical_stats = Some((height_inches, weight_pounds));
+        self
+    }
+
+    pub fn build(self) -> Player {
+        let mut player = Player::new(
+            self.first_name,
+            self.last_name,
+            self.position,
+            self.draft_year,
+        )
+        .expect("Invalid test player");
+        if let Some(college) = self.college {
+            player = player.with_college(college).expect("Invalid test college");
+        }
+        if let Some((height, weight)) = self.physical_stats {
+            player = player
+                .with_physical_stats(height, weight)
+                .expect("Invalid test physical stats");
+        }
+        player
+    }
+
+    pub async fn create(self, pool: &PgPool) -> Player {
+        SqlxPlayerRepository::new(pool.clone())
+            .create(&self.build())
+            .await
+            .expect("Failed to create test player")
+    }
+}
+
+/// Builds a fixed-size draft. Defaults to one round of 32 picks in 2026,
+/// not started.
+#[derive(Debug, Clone)]
+pub struct DraftBuilder {
+    name: String,
+    year: i32,
+    rounds: i32,
+    picks_per_round: i32,
+    status: DraftStatus,
+}
+
+impl Default for DraftBuilder {
+    fn default() -> Self {
+        Self {
+            name: format!("Test Draft {}", next_fixture()),
+            year: 2026,
+            rounds: 1,
+            picks_per_round: 32,
+            status: DraftStatus::NotStarted,
+        }
+    }
+}
+
+impl DraftBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn name(mut self, name: impl Into<String>) -> Self {
+        self.name = name.into();
+        self
+    }
+
+    pub fn year(mut self, year: i32) -> Self {
+        self.year = year;
+        self
+    }
+
+    pub fn rounds(mut self, rounds: i32) -> Self {
+        self.rounds = rounds;
+        self
+    }
+
+    pub fn picks_per_round(mut self, picks_per_round: i32) -> Self {
+        self.picks_per_round = picks_per_round;
+        self
+    }
+
+    pub fn status(mut self, status: DraftStatus) -> Self {
+        self.status = status;
+        self
+    }
+
+    pub fn build(self) -> Draft {
+        Draft::new(self.name, self.year, self.rounds, self.picks_per_round)
+            .expect("Invalid test draft")
+            .with_status(self.status)
+    }
+
+    pub async fn create(self, pool: &PgPool) -> Draft {
+        SqlxDraftRepository::new(pool.clone())
+            .create(&self.build())
+            .await
+            .expect("Failed to create test draft")
+    }
+
+    /// Creates the draft with every pick, handing picks to `teams` in turn
+    /// within each round
+    pub async fn create_with_picks(self, pool: &PgPool, teams: &[Team]) -> (Draft, Vec<DraftPick>) {
+        assert!(!teams.is_empty(), "A draft's picks need at least one team");
+        let draft = self.create(pool).await;
+        let picks_per_round = draft.picks_per_round.unwrap_or(teams.len() as i32);
+
+        let mut picks = Vec::new();
+        for round in 1..=draft.rounds {
+            for pick_number in 1..=picks_per_round {
+                let overall = (round - 1) * picks_per_round + pick_number;
+                let team = &teams[(pick_number as usize - 1) % teams.len()];
+                picks.push(
+                    DraftPick::new(draft.id, round, pick_number, overall, team.id)
+                        .expect("Invalid test pick"),
+                );
+            }
+        }
+        let picks = SqlxDraftPickRepository::new(pool.clone())
+            .create_many(&picks)
+            .await
+            .expect("Failed to create test picks");
+        (draft, picks)
+    }
+}
+
+/// Builds a session for a draft. Defaults to two minutes a pick, auto-pick
+/// off, the Jimmy Johnson chart and no controlled teams.
+#[derive(Debug, Clone)]
+pub struct SessionBuilder {
+    draft_id: Uuid,
+    time_per_pick_seconds: i32,
+    auto_pick_enabled: bool,
+    chart_type: ChartType,
+    controlled_team_ids: Vec<Uuid>,
+    status: SessionStatus,
+}
+
+impl SessionBuilder {
+    pub fn new(draft_id: Uuid) -> Self {
+        Self {
+            draft_id,
+            time_per_pick_seconds: 120,
+            auto_pick_enabled: false,
+            chart_type: ChartType::JimmyJohnson,
+            controlled_team_ids: Vec::new(),
+            status: SessionStatus::NotStarted,
+        }
+    }
+
+    pub fn time_per_pick(mut self, seconds: i32) -> Self {
+        self.time_per_pick_seconds = seconds;
+        self
+    }
+
+    pub fn auto_pick(mut self, enabled: bool) -> Self {
+        self.auto_pick_enabled = enabled;
+        self
+    }
+
+    pub fn chart_type(mut self, chart_type: ChartType) -> Self {
+        self.chart_type = chart_type;
+        self
+    }
+
+    pub fn controlled_teams(mut self, team_ids: Vec<Uuid>) -> Self {
+        self.controlled_team_ids = team_ids;
+        self
+    }
+
+    pub fn status(mut self, status: SessionStatus) -> Self {
+        self.status = status;
+        self
+    }
+
+    pub fn build(self) -> DraftSession {
+        DraftSession::new(
+            self.draft_id,
+            self.time_per_pick_seconds,
+            self.auto_pick_enabled,
+            self.chart_type,
+            self.controlled_team_ids,
+        )
+        .expect("Invalid test session")
+        .with_status(self.status)
+    }
+
+    pub async fn create(self, pool: &PgPool) -> DraftSession {
+        SessionRepo::new(pool.clone())
+            .create(&self.build())
+            .await
+            .expect("Failed to create test session")
+    }
+}
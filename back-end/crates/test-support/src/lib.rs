@@ -0,0 +1,23 @@
+//! Shared fixtures for the acceptance and integration tests
+//!
+//! Every pool handed out here is bound to its own freshly migrated Postgres
+//! schema, so tests never see each other's rows and can run in parallel.
+//! Isolation comes from the schema rather than from rolling back a
+//! transaction: the API and the repositories take a pool and commit their
+//! own transactions, so there is no single transaction a test could wrap
+//! around the code under test. Schemas left behind by earlier runs are
+//! dropped the next time a test process starts.
+//!
+//! The builders create teams, players, drafts and sessions with valid,
+//! unique defaults so a test only spells out the fields it cares about.
+
+mod app;
+mod builders;
+mod database;
+
+pub use app::{
+    create_anonymous_client, create_client, create_client_with_role, create_user_client, spawn_app,
+    spawn_app_with_seed_key, spawn_app_with_state, test_jwt_keys, TEST_JWT_SECRET,
+};
+pub use builders::{DraftBuilder, PlayerBuilder, SessionBuilder, TeamBuilder};
+pub use database::{database_url, setup_test_pool};
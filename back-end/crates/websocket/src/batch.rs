@@ -0,0 +1,130 @@
+use std::time::{Duration, Instant};
+
+use uuid::Uuid;
+
+use crate::messages::{BatchedPick, ServerMessage};
+
+/// Collects picks made in quick succession for one session and releases them
+/// at most once per `interval`. The first pick goes out straight away so
+/// clients see the run start; later ones are grouped into a `PicksBatch`.
+#[derive(Debug)]
+pub struct PickBatcher {
+    session_id: Uuid,
+    interval: Duration,
+    pending: Vec<BatchedPick>,
+    last_flush: Option<Instant>,
+}
+
+impl PickBatcher {
+    pub fn new(session_id: Uuid, interval: Duration) -> Self {
+        Self {
+            session_id,
+            interval,
+            pending: Vec::new(),
+            last_flush: None,
+        }
+    }
+
+    /// Adds a pick and returns the message to broadcast if the flush
+    /// interval has passed as of `now`
+    pub fn push_at(&mut self, pick: BatchedPick, now: Instant) -> Option<ServerMessage> {
+        self.pending.push(pick);
+        let due = self
+            .last_flush
+            .is_none_or(|last| now.duration_since(last) >= self.interval);
+        if due {
+            self.last_flush = Some(now);
+            self.flush()
+        } else {
+            None
+        }
+    }
+
+    pub fn push(&mut self, pick: BatchedPick) -> Option<ServerMessage> {
+        self.push_at(pick, Instant::now())
+    }
+
+    /// Releases whatever is pending. A lone pick is sent as a plain
+    /// `PickMade` so the common case looks the same to clients.
+    pub fn flush(&mut self) -> Option<ServerMessage> {
+        match self.pending.len() {
+            0 => None,
+            1 => self
+                .pending
+                .pop()
+                .map(|pick| pick.into_pick_made(self.session_id)),
+            _ => Some(ServerMessage::picks_batch(
+                self.session_id,
+                std::mem::take(&mut self.pending),
+            )),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn pick(pick_number: i32) -> BatchedPick {
+        BatchedPick {
+            pick_id: Uuid::new_v4(),
+            team_id: Uuid::new_v4(),
+            player_id: Uuid::new_v4(),
+            round: 1,
+            pick_number,
+            player_name: format!("Player {}", pick_number),
+            team_name: "Team".to_string(),
+        }
+    }
+
+    #[test]
+    fn test_first_pick_is_sent_immediately() {
+        let mut batcher = PickBatcher::new(Uuid::new_v4(), Duration::from_secs(1));
+
+        let message = batcher.push_at(pick(1), Instant::now());
+
+        assert!(matches!(
+            message,
+            Some(ServerMessage::PickMade { pick_number: 1, .. })
+        ));
+        assert!(batcher.flush().is_none());
+    }
+
+    #[test]
+    fn test_picks_within_interval_are_batched() {
+        let mut batcher = PickBatcher::new(Uuid::new_v4(), Duration::from_secs(1));
+        let start = Instant::now();
+
+        assert!(batcher.push_at(pick(1), start).is_some());
+        assert!(batcher
+            .push_at(pick(2), start + Duration::from_millis(200))
+            .is_none());
+        assert!(batcher
+            .push_at(pick(3), start + Duration::from_millis(400))
+            .is_none());
+
+        match batcher.push_at(pick(4), start + Duration::from_secs(1)) {
+            Some(ServerMessage::PicksBatch { picks, .. }) => {
+                let numbers: Vec<i32> = picks.iter().map(|p| p.pick_number).collect();
+                assert_eq!(numbers, vec![2, 3, 4]);
+            }
+            other => panic!("Expected a picks batch, got {:?}", other),
+        }
+        assert!(batcher.flush().is_none());
+    }
+
+    #[test]
+    fn test_flush_releases_pending_picks() {
+        let mut batcher = PickBatcher::new(Uuid::new_v4(), Duration::from_secs(1));
+        let start = Instant::now();
+
+        batcher.push_at(pick(1), start);
+        batcher.push_at(pick(2), start + Duration::from_millis(100));
+        batcher.push_at(pick(3), start + Duration::from_millis(200));
+
+        assert!(matches!(
+            batcher.flush(),
+            Some(ServerMessage::PicksBatch { ref picks, .. }) if picks.len() == 2
+        ));
+    }
+}
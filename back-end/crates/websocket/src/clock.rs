@@ -0,0 +1,67 @@
+use serde::{Deserialize, Serialize};
+
+/// Points in a pick's countdown at which clients are warned that time is
+/// running out
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ClockWarningLevel {
+    TwoMinutes,
+    ThirtySeconds,
+}
+
+impl ClockWarningLevel {
+    /// Most urgent first, so a clock that jumps past both thresholds at once
+    /// reports only the one that matters
+    const ALL: [ClockWarningLevel; 2] = [
+        ClockWarningLevel::ThirtySeconds,
+        ClockWarningLevel::TwoMinutes,
+    ];
+
+    pub fn seconds(&self) -> i32 {
+        match self {
+            ClockWarningLevel::TwoMinutes => 120,
+            ClockWarningLevel::ThirtySeconds => 30,
+        }
+    }
+
+    /// Returns the threshold the clock passed going from `previous` to
+    /// `current` seconds remaining, if any
+    pub fn crossed(previous: i32, current: i32) -> Option<Self> {
+        Self::ALL
+            .into_iter()
+            .find(|level| previous > level.seconds() && current <= level.seconds())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_crossed_thresholds() {
+        assert_eq!(
+            ClockWarningLevel::crossed(121, 120),
+            Some(ClockWarningLevel::TwoMinutes)
+        );
+        assert_eq!(
+            ClockWarningLevel::crossed(31, 29),
+            Some(ClockWarningLevel::ThirtySeconds)
+        );
+        assert_eq!(ClockWarningLevel::crossed(120, 119), None);
+        assert_eq!(ClockWarningLevel::crossed(60, 59), None);
+    }
+
+    #[test]
+    fn test_crossing_both_reports_most_urgent() {
+        assert_eq!(
+            ClockWarningLevel::crossed(150, 20),
+            Some(ClockWarningLevel::ThirtySeconds)
+        );
+    }
+
+    #[test]
+    fn test_serializes_snake_case() {
+        let json = serde_json::to_string(&ClockWarningLevel::TwoMinutes).unwrap();
+        assert_eq!(json, "\"two_minutes\"");
+    }
+}
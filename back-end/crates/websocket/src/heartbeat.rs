@@ -0,0 +1,57 @@
+use std::time::Duration;
+
+/// How often the server pings each connection and how many pings may go
+/// unanswered before the connection is treated as dead
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct HeartbeatConfig {
+    pub interval: Duration,
+    pub max_missed: u32,
+}
+
+/// Shortest ping interval; a zero interval would make the ping timer panic
+const MIN_INTERVAL: Duration = Duration::from_secs(1);
+
+impl HeartbeatConfig {
+    pub fn new(interval: Duration, max_missed: u32) -> Self {
+        Self {
+            interval: interval.max(MIN_INTERVAL),
+            max_missed: max_missed.max(1),
+        }
+    }
+
+    /// Silence longer than this means the connection is stale
+    pub fn timeout(&self) -> Duration {
+        self.interval * self.max_missed
+    }
+}
+
+impl Default for HeartbeatConfig {
+    fn default() -> Self {
+        Self::new(Duration::from_secs(30), 3)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_timeout() {
+        let config = HeartbeatConfig::new(Duration::from_secs(10), 3);
+        assert_eq!(config.timeout(), Duration::from_secs(30));
+    }
+
+    #[test]
+    fn test_max_missed_is_at_least_one() {
+        let config = HeartbeatConfig::new(Duration::from_secs(5), 0);
+        assert_eq!(config.max_missed, 1);
+        assert_eq!(config.timeout(), Duration::from_secs(5));
+    }
+
+    #[test]
+    fn test_interval_is_at_least_one_second() {
+        let config = HeartbeatConfig::new(Duration::ZERO, 3);
+        assert_eq!(config.interval, Duration::from_secs(1));
+        assert_eq!(config.timeout(), Duration::from_secs(3));
+    }
+}
@@ -1,5 +1,17 @@
+pub mod batch;
+pub mod clock;
+pub mod heartbeat;
 pub mod manager;
 pub mod messages;
+pub mod rate_limit;
+pub mod send_queue;
 
-pub use manager::{ConnectionManager, WsSender};
-pub use messages::{ClientMessage, ServerMessage};
+pub use batch::PickBatcher;
+pub use clock::ClockWarningLevel;
+pub use heartbeat::HeartbeatConfig;
+pub use manager::{BroadcastRelay, ConnectionManager, SessionPresence, WsSender};
+pub use messages::{
+    BatchedPick, ClientMessage, ErrorCode, PendingTrade, ServerMessage, SyncedEvent,
+};
+pub use rate_limit::RateLimiter;
+pub use send_queue::{SendQueueConfig, SendQueueStats, SlowConsumerPolicy};
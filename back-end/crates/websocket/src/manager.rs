@@ -1,13 +1,37 @@
 use dashmap::DashMap;
+use serde::{Deserialize, Serialize};
+use std::sync::atomic::{AtomicU64, Ordering};
 use std::sync::Arc;
-use tokio::sync::mpsc;
+use std::time::Instant;
+use tokio::sync::mpsc::{self, error::TrySendError};
+use tokio::task::JoinHandle;
 use tracing::{debug, error, info, warn};
 use uuid::Uuid;
 
+use crate::heartbeat::HeartbeatConfig;
 use crate::messages::ServerMessage;
+use crate::send_queue::{SendQueueConfig, SendQueueStats, SlowConsumerPolicy};
 
-/// Type alias for WebSocket sender — transport-agnostic channel
-pub type WsSender = mpsc::UnboundedSender<String>;
+/// Type alias for WebSocket sender — transport-agnostic, bounded channel.
+/// Create it with the manager's `send_queue().capacity`.
+pub type WsSender = mpsc::Sender<String>;
+
+/// Carries session broadcasts to the other server instances, which deliver
+/// them to their own connections
+pub trait BroadcastRelay: Send + Sync {
+    /// Called for every broadcast that isn't instance-local. Must not block.
+    fn publish(&self, session_id: Uuid, message: &ServerMessage);
+}
+
+/// Who is watching a session right now
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SessionPresence {
+    pub connection_count: usize,
+    /// Connections that did not give a display name
+    pub anonymous_count: usize,
+    /// Distinct display names of named connections, sorted
+    pub display_names: Vec<String>,
+}
 
 /// Manages WebSocket connections for draft sessions
 #[derive(Clone)]
@@ -16,13 +40,71 @@ pub struct ConnectionManager {
     connections: Arc<DashMap<Uuid, WsSender>>,
     /// Maps session ID to set of connection IDs
     sessions: Arc<DashMap<Uuid, Vec<Uuid>>>,
+    /// Display names announced by connections, for presence
+    display_names: Arc<DashMap<Uuid, String>>,
+    /// When each connection was last heard from
+    last_seen: Arc<DashMap<Uuid, Instant>>,
+    heartbeat: HeartbeatConfig,
+    send_queue: SendQueueConfig,
+    dropped_messages: Arc<AtomicU64>,
+    slow_consumer_disconnects: Arc<AtomicU64>,
+    relay: Option<Arc<dyn BroadcastRelay>>,
+}
+
+/// Result of trying to queue a message for one connection
+enum Enqueued {
+    Sent,
+    Dropped,
+    /// The connection is gone or too far behind and should be removed
+    Failed,
 }
 
 impl ConnectionManager {
     pub fn new() -> Self {
+        Self::with_heartbeat(HeartbeatConfig::default())
+    }
+
+    pub fn with_heartbeat(heartbeat: HeartbeatConfig) -> Self {
+        Self::with_settings(heartbeat, SendQueueConfig::default())
+    }
+
+    pub fn with_settings(heartbeat: HeartbeatConfig, send_queue: SendQueueConfig) -> Self {
         Self {
             connections: Arc::new(DashMap::new()),
             sessions: Arc::new(DashMap::new()),
+            display_names: Arc::new(DashMap::new()),
+            last_seen: Arc::new(DashMap::new()),
+            heartbeat,
+            send_queue,
+            dropped_messages: Arc::new(AtomicU64::new(0)),
+            slow_consumer_disconnects: Arc::new(AtomicU64::new(0)),
+            relay: None,
+        }
+    }
+
+    /// Also hand session broadcasts to `relay` for other server instances
+    pub fn with_relay(mut self, relay: Arc<dyn BroadcastRelay>) -> Self {
+        self.relay = Some(relay);
+        self
+    }
+
+    pub fn heartbeat(&self) -> HeartbeatConfig {
+        self.heartbeat
+    }
+
+    pub fn send_queue(&self) -> SendQueueConfig {
+        self.send_queue
+    }
+
+    /// Set or clear the name a connection is shown under in presence
+    pub fn set_display_name(&self, connection_id: Uuid, display_name: Option<String>) {
+        match display_name {
+            Some(name) => {
+                self.display_names.insert(connection_id, name);
+            }
+            None => {
+                self.display_names.remove(&connection_id);
+            }
         }
     }
 
@@ -36,6 +118,7 @@ impl ConnectionManager {
 
         // Store the sender
         self.connections.insert(connection_id, sender);
+        self.last_seen.insert(connection_id, Instant::now());
 
         // Add connection to session
         self.sessions
@@ -50,17 +133,24 @@ impl ConnectionManager {
         );
     }
 
-    /// Remove a connection
-    pub fn remove_connection(&self, connection_id: Uuid) {
+    /// Remove a connection and return the sessions it was subscribed to
+    pub fn remove_connection(&self, connection_id: Uuid) -> Vec<Uuid> {
         info!(connection_id = %connection_id, "Removing WebSocket connection");
 
         // Remove from connections
         self.connections.remove(&connection_id);
+        self.display_names.remove(&connection_id);
+        self.last_seen.remove(&connection_id);
 
         // Remove from all sessions
+        let mut left_sessions = Vec::new();
         self.sessions.iter_mut().for_each(|mut entry| {
             let session_id = *entry.key();
+            let before = entry.value().len();
             entry.value_mut().retain(|id| *id != connection_id);
+            if entry.value().len() != before {
+                left_sessions.push(session_id);
+            }
 
             if entry.value().is_empty() {
                 debug!(session_id = %session_id, "Session has no more connections");
@@ -70,10 +160,24 @@ impl ConnectionManager {
         // Clean up empty sessions
         self.sessions
             .retain(|_, connections| !connections.is_empty());
+
+        left_sessions
     }
 
-    /// Broadcast a message to all connections in a session
+    /// Broadcast a message to all connections in a session, on this server
+    /// and, through the relay, on every other
     pub async fn broadcast_to_session(&self, session_id: Uuid, message: ServerMessage) {
+        if let Some(relay) = &self.relay {
+            if !message.is_instance_local() {
+                relay.publish(session_id, &message);
+            }
+        }
+        self.deliver_to_session(session_id, message).await;
+    }
+
+    /// Send a message to this server's connections in a session only, e.g.
+    /// one relayed from another server
+    pub async fn deliver_to_session(&self, session_id: Uuid, message: ServerMessage) {
         let json = match message.to_json() {
             Ok(json) => json,
             Err(e) => {
@@ -97,15 +201,13 @@ impl ConnectionManager {
         );
 
         let mut failed_connections = Vec::new();
+        let skippable = message.is_superseded_by_later();
 
         for connection_id in &connection_ids {
             if let Some(sender) = self.connections.get(connection_id) {
-                if let Err(e) = sender.send(json.clone()) {
-                    error!(
-                        connection_id = %connection_id,
-                        error = %e,
-                        "Failed to send message to connection"
-                    );
+                if let Enqueued::Failed =
+                    self.enqueue(*connection_id, &sender, json.clone(), skippable)
+                {
                     failed_connections.push(*connection_id);
                 }
             } else {
@@ -133,28 +235,178 @@ impl ConnectionManager {
             }
         };
 
-        if let Some(sender) = self.connections.get(&connection_id) {
-            if let Err(e) = sender.send(json) {
-                error!(
+        // Release the map guard before removing, which needs the same shard
+        let outcome = self.connections.get(&connection_id).map(|sender| {
+            self.enqueue(
+                connection_id,
+                &sender,
+                json,
+                message.is_superseded_by_later(),
+            )
+        });
+        match outcome {
+            Some(Enqueued::Failed) => {
+                self.remove_connection(connection_id);
+            }
+            Some(_) => {}
+            None => {
+                warn!(
                     connection_id = %connection_id,
-                    error = %e,
-                    "Failed to send message to connection"
+                    "Connection not found in manager"
                 );
-                self.remove_connection(connection_id);
             }
-        } else {
-            warn!(
-                connection_id = %connection_id,
-                "Connection not found in manager"
-            );
         }
     }
 
+    /// Queue a message without waiting. A full queue is handled by the slow
+    /// consumer policy; `skippable` messages may be dropped under `Coalesce`.
+    fn enqueue(
+        &self,
+        connection_id: Uuid,
+        sender: &WsSender,
+        json: String,
+        skippable: bool,
+    ) -> Enqueued {
+        match sender.try_send(json) {
+            Ok(()) => Enqueued::Sent,
+            Err(TrySendError::Full(_)) => {
+                if skippable && self.send_queue.policy == SlowConsumerPolicy::Coalesce {
+                    self.dropped_messages.fetch_add(1, Ordering::Relaxed);
+                    debug!(connection_id = %connection_id, "Send queue full, skipping superseded message");
+                    Enqueued::Dropped
+                } else {
+                    self.dropped_messages.fetch_add(1, Ordering::Relaxed);
+                    self.slow_consumer_disconnects
+                        .fetch_add(1, Ordering::Relaxed);
+                    warn!(
+                        connection_id = %connection_id,
+                        capacity = self.send_queue.capacity,
+                        "Send queue full, disconnecting slow consumer"
+                    );
+                    Enqueued::Failed
+                }
+            }
+            Err(TrySendError::Closed(_)) => {
+                error!(connection_id = %connection_id, "Failed to send message to closed connection");
+                Enqueued::Failed
+            }
+        }
+    }
+
+    /// Current depth of every send queue plus drop and disconnect counters
+    pub fn send_queue_stats(&self) -> SendQueueStats {
+        let mut stats = SendQueueStats {
+            connections: self.connections.len(),
+            queue_capacity: self.send_queue.capacity,
+            dropped_messages: self.dropped_messages.load(Ordering::Relaxed),
+            slow_consumer_disconnects: self.slow_consumer_disconnects.load(Ordering::Relaxed),
+            ..Default::default()
+        };
+        for entry in self.connections.iter() {
+            let sender = entry.value();
+            let depth = sender.max_capacity() - sender.capacity();
+            stats.queued_messages += depth;
+            stats.max_queue_depth = stats.max_queue_depth.max(depth);
+        }
+        stats
+    }
+
     /// Get the number of connections in a session
     pub fn session_connection_count(&self, session_id: Uuid) -> usize {
         self.sessions.get(&session_id).map(|s| s.len()).unwrap_or(0)
     }
 
+    /// Note that a connection is alive. Any inbound frame counts, including
+    /// pongs to the server's heartbeat pings.
+    pub fn record_heartbeat(&self, connection_id: Uuid) {
+        if let Some(mut seen) = self.last_seen.get_mut(&connection_id) {
+            *seen = Instant::now();
+        }
+    }
+
+    /// Whether the connection is still registered. Socket tasks use this to
+    /// notice that the reaper has dropped them.
+    pub fn is_connected(&self, connection_id: Uuid) -> bool {
+        self.connections.contains_key(&connection_id)
+    }
+
+    /// Drop every connection that has been silent for longer than the
+    /// heartbeat timeout as of `now`. Returns the sessions that lost a
+    /// connection so their presence can be refreshed.
+    pub fn reap_stale_connections_at(&self, now: Instant) -> Vec<Uuid> {
+        let timeout = self.heartbeat.timeout();
+        let stale: Vec<Uuid> = self
+            .last_seen
+            .iter()
+            .filter(|entry| now.saturating_duration_since(*entry.value()) > timeout)
+            .map(|entry| *entry.key())
+            .collect();
+
+        let mut affected_sessions = Vec::new();
+        for connection_id in stale {
+            warn!(connection_id = %connection_id, "Reaping WebSocket connection that missed heartbeats");
+            for session_id in self.remove_connection(connection_id) {
+                if !affected_sessions.contains(&session_id) {
+                    affected_sessions.push(session_id);
+                }
+            }
+        }
+        affected_sessions
+    }
+
+    /// Spawns a task that reaps stale connections once per heartbeat interval
+    /// and refreshes presence for the sessions they left
+    pub fn spawn_reaper(&self) -> JoinHandle<()> {
+        let manager = self.clone();
+        tokio::spawn(async move {
+            let mut ticker = tokio::time::interval(manager.heartbeat.interval);
+            loop {
+                ticker.tick().await;
+                for session_id in manager.reap_stale_connections_at(Instant::now()) {
+                    manager.broadcast_presence(session_id).await;
+                }
+            }
+        })
+    }
+
+    /// Current presence for a session
+    pub fn session_presence(&self, session_id: Uuid) -> SessionPresence {
+        let connection_ids = self
+            .sessions
+            .get(&session_id)
+            .map(|ids| ids.clone())
+            .unwrap_or_default();
+
+        let mut display_names = Vec::new();
+        let mut anonymous_count = 0;
+        for connection_id in &connection_ids {
+            match self.display_names.get(connection_id) {
+                Some(name) => display_names.push(name.clone()),
+                None => anonymous_count += 1,
+            }
+        }
+        display_names.sort();
+        display_names.dedup();
+
+        SessionPresence {
+            connection_count: connection_ids.len(),
+            anonymous_count,
+            display_names,
+        }
+    }
+
+    /// Broadcast the session's current presence to its connections
+    pub async fn broadcast_presence(&self, session_id: Uuid) {
+        let presence = self.session_presence(session_id);
+        self.broadcast_to_session(session_id, ServerMessage::presence(session_id, presence))
+            .await;
+    }
+
+    /// Sessions that currently have at least one connection
+    pub fn active_session_ids(&self) -> Vec<Uuid> {
+        self.sessions.iter().map(|entry| *entry.key()).collect()
+    }
+
     /// Get total number of active connections
     pub fn total_connections(&self) -> usize {
         self.connections.len()
@@ -175,6 +427,46 @@ impl Default for ConnectionManager {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use std::sync::Mutex;
+
+    #[derive(Default)]
+    struct RecordingRelay {
+        published: Mutex<Vec<(Uuid, ServerMessage)>>,
+    }
+
+    impl BroadcastRelay for RecordingRelay {
+        fn publish(&self, session_id: Uuid, message: &ServerMessage) {
+            self.published
+                .lock()
+                .unwrap()
+                .push((session_id, message.clone()));
+        }
+    }
+
+    #[tokio::test]
+    async fn test_broadcast_relays_all_but_instance_local_messages() {
+        let relay = Arc::new(RecordingRelay::default());
+        let manager = ConnectionManager::new().with_relay(relay.clone());
+        let session_id = Uuid::new_v4();
+        let (tx, mut rx) = mpsc::channel(16);
+        manager.add_connection(Uuid::new_v4(), session_id, tx);
+
+        let status = ServerMessage::DraftStatus {
+            session_id,
+            status: "Paused".to_string(),
+        };
+        manager
+            .broadcast_to_session(session_id, status.clone())
+            .await;
+        manager.broadcast_presence(session_id).await;
+        // Relayed messages arriving from another server are not sent back out
+        manager.deliver_to_session(session_id, status.clone()).await;
+
+        assert_eq!(*relay.published.lock().unwrap(), vec![(session_id, status)]);
+        for _ in 0..3 {
+            assert!(rx.try_recv().is_ok());
+        }
+    }
 
     #[test]
     fn test_new_manager() {
@@ -195,7 +487,7 @@ mod tests {
         let manager = ConnectionManager::new();
         let connection_id = Uuid::new_v4();
         let session_id = Uuid::new_v4();
-        let (tx, _rx) = mpsc::unbounded_channel();
+        let (tx, _rx) = mpsc::channel(16);
 
         manager.add_connection(connection_id, session_id, tx);
         assert_eq!(manager.total_connections(), 1);
@@ -211,7 +503,7 @@ mod tests {
         let manager = ConnectionManager::new();
         let connection_id = Uuid::new_v4();
         let session_id = Uuid::new_v4();
-        let (tx, mut rx) = mpsc::unbounded_channel();
+        let (tx, mut rx) = mpsc::channel(16);
 
         manager.add_connection(connection_id, session_id, tx);
 
@@ -221,4 +513,173 @@ mod tests {
         let received = rx.recv().await.unwrap();
         assert!(received.contains("pong"));
     }
+
+    fn manager_with_queue(capacity: usize, policy: SlowConsumerPolicy) -> ConnectionManager {
+        ConnectionManager::with_settings(
+            HeartbeatConfig::default(),
+            SendQueueConfig::new(capacity, policy),
+        )
+    }
+
+    #[tokio::test]
+    async fn test_coalesce_skips_superseded_messages_when_full() {
+        let manager = manager_with_queue(1, SlowConsumerPolicy::Coalesce);
+        let session_id = Uuid::new_v4();
+        let connection_id = Uuid::new_v4();
+        let (tx, mut rx) = mpsc::channel(manager.send_queue().capacity);
+
+        manager.add_connection(connection_id, session_id, tx);
+        manager
+            .broadcast_to_session(session_id, ServerMessage::clock_tick(session_id, 60, 1))
+            .await;
+        manager
+            .broadcast_to_session(session_id, ServerMessage::clock_tick(session_id, 59, 1))
+            .await;
+
+        assert!(manager.is_connected(connection_id));
+        let stats = manager.send_queue_stats();
+        assert_eq!(stats.queued_messages, 1);
+        assert_eq!(stats.max_queue_depth, 1);
+        assert_eq!(stats.dropped_messages, 1);
+        assert_eq!(stats.slow_consumer_disconnects, 0);
+
+        let received = rx.recv().await.unwrap();
+        assert!(received.contains("\"time_remaining\":60"));
+        assert_eq!(manager.send_queue_stats().queued_messages, 0);
+    }
+
+    #[tokio::test]
+    async fn test_coalesce_disconnects_when_pick_does_not_fit() {
+        let manager = manager_with_queue(1, SlowConsumerPolicy::Coalesce);
+        let session_id = Uuid::new_v4();
+        let connection_id = Uuid::new_v4();
+        let (tx, _rx) = mpsc::channel(manager.send_queue().capacity);
+
+        manager.add_connection(connection_id, session_id, tx);
+        manager
+            .broadcast_to_session(session_id, ServerMessage::clock_tick(session_id, 60, 1))
+            .await;
+        manager
+            .broadcast_to_session(
+                session_id,
+                ServerMessage::draft_status(session_id, "Paused".to_string()),
+            )
+            .await;
+
+        assert!(!manager.is_connected(connection_id));
+        assert_eq!(manager.send_queue_stats().slow_consumer_disconnects, 1);
+    }
+
+    #[tokio::test]
+    async fn test_disconnect_policy_drops_slow_consumer() {
+        let manager = manager_with_queue(1, SlowConsumerPolicy::Disconnect);
+        let session_id = Uuid::new_v4();
+        let slow = Uuid::new_v4();
+        let fast = Uuid::new_v4();
+        let (slow_tx, _slow_rx) = mpsc::channel(1);
+        let (fast_tx, mut fast_rx) = mpsc::channel(1);
+
+        manager.add_connection(slow, session_id, slow_tx);
+        manager.add_connection(fast, session_id, fast_tx);
+
+        manager
+            .broadcast_to_session(session_id, ServerMessage::clock_tick(session_id, 60, 1))
+            .await;
+        fast_rx.recv().await.unwrap();
+        manager
+            .broadcast_to_session(session_id, ServerMessage::clock_tick(session_id, 59, 1))
+            .await;
+
+        assert!(!manager.is_connected(slow));
+        assert!(manager.is_connected(fast));
+        assert_eq!(manager.session_connection_count(session_id), 1);
+    }
+
+    #[test]
+    fn test_session_presence() {
+        let manager = ConnectionManager::new();
+        let session_id = Uuid::new_v4();
+        let alice_1 = Uuid::new_v4();
+        let alice_2 = Uuid::new_v4();
+        let anonymous = Uuid::new_v4();
+        let (tx, _rx) = mpsc::channel(16);
+
+        for connection_id in [alice_1, alice_2, anonymous] {
+            manager.add_connection(connection_id, session_id, tx.clone());
+        }
+        manager.set_display_name(alice_1, Some("Alice".to_string()));
+        manager.set_display_name(alice_2, Some("Alice".to_string()));
+
+        let presence = manager.session_presence(session_id);
+        assert_eq!(presence.connection_count, 3);
+        assert_eq!(presence.anonymous_count, 1);
+        assert_eq!(presence.display_names, vec!["Alice".to_string()]);
+
+        assert_eq!(manager.remove_connection(anonymous), vec![session_id]);
+        assert!(manager.remove_connection(anonymous).is_empty());
+        let presence = manager.session_presence(session_id);
+        assert_eq!(presence.connection_count, 2);
+        assert_eq!(presence.anonymous_count, 0);
+    }
+
+    #[tokio::test]
+    async fn test_broadcast_presence() {
+        let manager = ConnectionManager::new();
+        let session_id = Uuid::new_v4();
+        let connection_id = Uuid::new_v4();
+        let (tx, mut rx) = mpsc::channel(16);
+
+        manager.add_connection(connection_id, session_id, tx);
+        manager.set_display_name(connection_id, Some("Bob".to_string()));
+        manager.broadcast_presence(session_id).await;
+
+        let received = rx.recv().await.unwrap();
+        assert!(received.contains("\"type\":\"presence\""));
+        assert!(received.contains("Bob"));
+    }
+
+    #[test]
+    fn test_reap_stale_connections() {
+        let manager = ConnectionManager::with_heartbeat(HeartbeatConfig::new(
+            std::time::Duration::from_secs(10),
+            2,
+        ));
+        let session_id = Uuid::new_v4();
+        let quiet = Uuid::new_v4();
+        let chatty = Uuid::new_v4();
+        let (tx, _rx) = mpsc::channel(16);
+
+        manager.add_connection(quiet, session_id, tx.clone());
+        manager.add_connection(chatty, session_id, tx);
+        manager.set_display_name(quiet, Some("Quiet".to_string()));
+
+        let start = Instant::now();
+        assert!(manager
+            .reap_stale_connections_at(start + std::time::Duration::from_secs(19))
+            .is_empty());
+
+        // Only the chatty connection answers before the timeout passes
+        manager
+            .last_seen
+            .insert(chatty, start + std::time::Duration::from_secs(15));
+        let affected =
+            manager.reap_stale_connections_at(start + std::time::Duration::from_secs(21));
+
+        assert_eq!(affected, vec![session_id]);
+        assert!(!manager.is_connected(quiet));
+        assert!(manager.is_connected(chatty));
+        let presence = manager.session_presence(session_id);
+        assert_eq!(presence.connection_count, 1);
+        assert!(presence.display_names.is_empty());
+    }
+
+    #[test]
+    fn test_record_heartbeat_ignores_unknown_connection() {
+        let manager = ConnectionManager::new();
+        let connection_id = Uuid::new_v4();
+
+        manager.record_heartbeat(connection_id);
+        assert!(!manager.is_connected(connection_id));
+        assert!(manager.last_seen.is_empty());
+    }
 }
@@ -1,12 +1,25 @@
 use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 
+use crate::clock::ClockWarningLevel;
+use crate::manager::SessionPresence;
+
 /// Messages sent from client to server
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(tag = "type", rename_all = "snake_case")]
 pub enum ClientMessage {
-    /// Subscribe to a draft session
-    Subscribe { session_id: Uuid },
+    /// Subscribe to a draft session. Reconnecting clients pass the id of the
+    /// last event they saw so the server can replay only what they missed.
+    /// `display_name` (or the access token of a session participant) names the viewer in presence.
+    Subscribe {
+        session_id: Uuid,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        last_event_id: Option<Uuid>,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        display_name: Option<String>,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        access_token: Option<String>,
+    },
     /// Make a draft pick
     MakePick { session_id: Uuid, player_id: Uuid },
     /// Propose a trade
@@ -16,16 +29,100 @@ pub enum ClientMessage {
         to_team_id: Uuid,
         pick_ids: Vec<Uuid>,
     },
+    /// Post a chat message to a session the connection is subscribed to.
+    /// Participants send their token so the message carries their name;
+    /// spectators may pick a display name instead.
+    ChatMessage {
+        session_id: Uuid,
+        body: String,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        display_name: Option<String>,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        access_token: Option<String>,
+    },
     /// Ping to keep connection alive
     Ping,
 }
 
+/// A draft event replayed to a client as part of a state sync
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SyncedEvent {
+    pub id: Uuid,
+    pub event_type: String,
+    pub event_data: serde_json::Value,
+    pub created_at: String,
+}
+
+/// A trade awaiting a response, included in a state sync
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PendingTrade {
+    pub trade_id: Uuid,
+    pub from_team_id: Uuid,
+    pub to_team_id: Uuid,
+    pub from_team_picks: Vec<Uuid>,
+    pub to_team_picks: Vec<Uuid>,
+    pub from_team_value: i32,
+    pub to_team_value: i32,
+}
+
+/// One pick inside a `PicksBatch`, with the same fields as `PickMade`
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct BatchedPick {
+    pub pick_id: Uuid,
+    pub team_id: Uuid,
+    pub player_id: Uuid,
+    pub round: i32,
+    pub pick_number: i32,
+    pub player_name: String,
+    pub team_name: String,
+}
+
+impl BatchedPick {
+    /// The same pick as a standalone `PickMade` message
+    pub fn into_pick_made(self, session_id: Uuid) -> ServerMessage {
+        ServerMessage::PickMade {
+            session_id,
+            pick_id: self.pick_id,
+            team_id: self.team_id,
+            player_id: self.player_id,
+            round: self.round,
+            pick_number: self.pick_number,
+            player_name: self.player_name,
+            team_name: self.team_name,
+        }
+    }
+}
+
+/// Machine-readable reason attached to an `Error` message
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ErrorCode {
+    /// The connection sent too many messages; retry after `retry_after_ms`
+    RateLimited,
+    /// The message could not be parsed
+    InvalidMessage,
+    /// The message targets a session this connection has not subscribed to
+    NotSubscribed,
+    /// The action is only available over the REST API
+    Unsupported,
+}
+
 /// Messages sent from server to client
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(tag = "type", rename_all = "snake_case")]
 pub enum ServerMessage {
     /// Confirmation of successful subscription
     Subscribed { session_id: Uuid },
+    /// Snapshot of session state sent after subscribing, so reconnecting
+    /// clients can resume without rebuilding state over REST
+    StateSync {
+        session_id: Uuid,
+        status: String,
+        current_pick_number: i32,
+        time_remaining: i32,
+        recent_events: Vec<SyncedEvent>,
+        pending_trades: Vec<PendingTrade>,
+    },
     /// A pick was made
     PickMade {
         session_id: Uuid,
@@ -37,12 +134,31 @@ pub enum ServerMessage {
         player_name: String,
         team_name: String,
     },
+    /// Several picks made in quick succession, oldest first
+    PicksBatch {
+        session_id: Uuid,
+        picks: Vec<BatchedPick>,
+    },
     /// Clock update (time remaining for current pick)
     ClockUpdate {
         session_id: Uuid,
         time_remaining: i32,
         current_pick_number: i32,
     },
+    /// Periodic countdown from the server's pick clock. Clients display this
+    /// instead of running their own timers.
+    ClockTick {
+        session_id: Uuid,
+        time_remaining: i32,
+        current_pick_number: i32,
+    },
+    /// The pick clock passed a warning threshold
+    ClockWarning {
+        session_id: Uuid,
+        level: ClockWarningLevel,
+        time_remaining: i32,
+        current_pick_number: i32,
+    },
     /// Draft status changed
     DraftStatus { session_id: Uuid, status: String },
     /// Trade was proposed
@@ -71,15 +187,63 @@ pub enum ServerMessage {
         trade_id: Uuid,
         rejecting_team_id: Uuid,
     },
-    /// Error occurred
-    Error { message: String },
+    /// Session was rewound; clients should reload the board
+    SessionRewound {
+        session_id: Uuid,
+        current_pick_number: i32,
+        reversed_trade_ids: Vec<Uuid>,
+    },
+    /// A chat message was posted to the session
+    ChatMessage {
+        session_id: Uuid,
+        message_id: Uuid,
+        participant_id: Option<Uuid>,
+        display_name: String,
+        body: String,
+        sent_at: String,
+    },
+    /// Viewers connected to the session changed
+    Presence {
+        session_id: Uuid,
+        #[serde(flatten)]
+        presence: SessionPresence,
+    },
+    /// Announcement pushed by an administrator, e.g. "draft resumes in 5 minutes"
+    AdminAnnouncement {
+        session_id: Uuid,
+        message: String,
+        sent_at: String,
+    },
+    /// Error occurred. `code` is set when clients can act on the reason.
+    Error {
+        message: String,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        code: Option<ErrorCode>,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        retry_after_ms: Option<u64>,
+    },
     /// Pong response to ping
     Pong,
 }
 
 impl ClientMessage {
     pub fn subscribe(session_id: Uuid) -> Self {
-        ClientMessage::Subscribe { session_id }
+        ClientMessage::Subscribe {
+            session_id,
+            last_event_id: None,
+            display_name: None,
+            access_token: None,
+        }
+    }
+
+    /// Subscribe as a reconnecting client that has seen events up to `last_event_id`
+    pub fn resume(session_id: Uuid, last_event_id: Uuid) -> Self {
+        ClientMessage::Subscribe {
+            session_id,
+            last_event_id: Some(last_event_id),
+            display_name: None,
+            access_token: None,
+        }
     }
 
     pub fn make_pick(session_id: Uuid, player_id: Uuid) -> Self {
@@ -103,6 +267,15 @@ impl ClientMessage {
         }
     }
 
+    pub fn chat_message(session_id: Uuid, body: String) -> Self {
+        ClientMessage::ChatMessage {
+            session_id,
+            body,
+            display_name: None,
+            access_token: None,
+        }
+    }
+
     pub fn ping() -> Self {
         ClientMessage::Ping
     }
@@ -119,10 +292,51 @@ impl ClientMessage {
 }
 
 impl ServerMessage {
+    /// Whether the message describes only this server's view of a session:
+    /// its own connections or the pick clock every server runs for the
+    /// sessions it is serving. Such messages aren't relayed to other servers.
+    pub fn is_instance_local(&self) -> bool {
+        matches!(
+            self,
+            ServerMessage::ClockTick { .. }
+                | ServerMessage::ClockWarning { .. }
+                | ServerMessage::Presence { .. }
+        )
+    }
+
+    /// Whether a later message of the same kind fully replaces this one, so
+    /// a slow client can safely miss it
+    pub fn is_superseded_by_later(&self) -> bool {
+        matches!(
+            self,
+            ServerMessage::ClockTick { .. }
+                | ServerMessage::ClockUpdate { .. }
+                | ServerMessage::Presence { .. }
+        )
+    }
+
     pub fn subscribed(session_id: Uuid) -> Self {
         ServerMessage::Subscribed { session_id }
     }
 
+    pub fn state_sync(
+        session_id: Uuid,
+        status: String,
+        current_pick_number: i32,
+        time_remaining: i32,
+        recent_events: Vec<SyncedEvent>,
+        pending_trades: Vec<PendingTrade>,
+    ) -> Self {
+        ServerMessage::StateSync {
+            session_id,
+            status,
+            current_pick_number,
+            time_remaining,
+            recent_events,
+            pending_trades,
+        }
+    }
+
     #[allow(clippy::too_many_arguments)]
     pub fn pick_made(
         session_id: Uuid,
@@ -146,6 +360,10 @@ impl ServerMessage {
         }
     }
 
+    pub fn picks_batch(session_id: Uuid, picks: Vec<BatchedPick>) -> Self {
+        ServerMessage::PicksBatch { session_id, picks }
+    }
+
     pub fn clock_update(session_id: Uuid, time_remaining: i32, current_pick_number: i32) -> Self {
         ServerMessage::ClockUpdate {
             session_id,
@@ -154,6 +372,28 @@ impl ServerMessage {
         }
     }
 
+    pub fn clock_tick(session_id: Uuid, time_remaining: i32, current_pick_number: i32) -> Self {
+        ServerMessage::ClockTick {
+            session_id,
+            time_remaining,
+            current_pick_number,
+        }
+    }
+
+    pub fn clock_warning(
+        session_id: Uuid,
+        level: ClockWarningLevel,
+        time_remaining: i32,
+        current_pick_number: i32,
+    ) -> Self {
+        ServerMessage::ClockWarning {
+            session_id,
+            level,
+            time_remaining,
+            current_pick_number,
+        }
+    }
+
     pub fn draft_status(session_id: Uuid, status: String) -> Self {
         ServerMessage::DraftStatus { session_id, status }
     }
@@ -207,8 +447,74 @@ impl ServerMessage {
         }
     }
 
+    pub fn session_rewound(
+        session_id: Uuid,
+        current_pick_number: i32,
+        reversed_trade_ids: Vec<Uuid>,
+    ) -> Self {
+        ServerMessage::SessionRewound {
+            session_id,
+            current_pick_number,
+            reversed_trade_ids,
+        }
+    }
+
+    pub fn chat_message(
+        session_id: Uuid,
+        message_id: Uuid,
+        participant_id: Option<Uuid>,
+        display_name: String,
+        body: String,
+        sent_at: String,
+    ) -> Self {
+        ServerMessage::ChatMessage {
+            session_id,
+            message_id,
+            participant_id,
+            display_name,
+            body,
+            sent_at,
+        }
+    }
+
+    pub fn presence(session_id: Uuid, presence: SessionPresence) -> Self {
+        ServerMessage::Presence {
+            session_id,
+            presence,
+        }
+    }
+
+    pub fn admin_announcement(session_id: Uuid, message: String, sent_at: String) -> Self {
+        ServerMessage::AdminAnnouncement {
+            session_id,
+            message,
+            sent_at,
+        }
+    }
+
     pub fn error(message: String) -> Self {
-        ServerMessage::Error { message }
+        ServerMessage::Error {
+            message,
+            code: None,
+            retry_after_ms: None,
+        }
+    }
+
+    pub fn error_with_code(code: ErrorCode, message: String) -> Self {
+        ServerMessage::Error {
+            message,
+            code: Some(code),
+            retry_after_ms: None,
+        }
+    }
+
+    /// Rate limit rejection telling the client how long to back off
+    pub fn rate_limited(message: String, retry_after: std::time::Duration) -> Self {
+        ServerMessage::Error {
+            message,
+            code: Some(ErrorCode::RateLimited),
+            retry_after_ms: Some(retry_after.as_millis() as u64),
+        }
     }
 
     pub fn pong() -> Self {
@@ -242,6 +548,29 @@ mod tests {
         assert!(json.contains("\"type\":\"subscribe\""));
     }
 
+    #[test]
+    fn test_client_message_subscribe_without_last_event_id() {
+        let session_id = Uuid::new_v4();
+        let json = format!(r#"{{"type":"subscribe","session_id":"{}"}}"#, session_id);
+
+        let parsed = ClientMessage::from_json(&json).unwrap();
+
+        assert_eq!(parsed, ClientMessage::subscribe(session_id));
+    }
+
+    #[test]
+    fn test_client_message_resume_serialization() {
+        let session_id = Uuid::new_v4();
+        let last_event_id = Uuid::new_v4();
+        let msg = ClientMessage::resume(session_id, last_event_id);
+
+        let json = msg.to_json().unwrap();
+        let parsed = ClientMessage::from_json(&json).unwrap();
+
+        assert_eq!(msg, parsed);
+        assert!(json.contains(&last_event_id.to_string()));
+    }
+
     #[test]
     fn test_client_message_make_pick_serialization() {
         let session_id = Uuid::new_v4();
@@ -278,6 +607,43 @@ mod tests {
         assert!(json.contains("\"type\":\"subscribed\""));
     }
 
+    #[test]
+    fn test_server_message_state_sync_serialization() {
+        let session_id = Uuid::new_v4();
+        let event = SyncedEvent {
+            id: Uuid::new_v4(),
+            event_type: "PickMade".to_string(),
+            event_data: serde_json::json!({"pick_number": 3}),
+            created_at: "2026-04-23T20:00:00+00:00".to_string(),
+        };
+        let trade = PendingTrade {
+            trade_id: Uuid::new_v4(),
+            from_team_id: Uuid::new_v4(),
+            to_team_id: Uuid::new_v4(),
+            from_team_picks: vec![Uuid::new_v4()],
+            to_team_picks: vec![Uuid::new_v4()],
+            from_team_value: 1000,
+            to_team_value: 950,
+        };
+
+        let msg = ServerMessage::state_sync(
+            session_id,
+            "InProgress".to_string(),
+            4,
+            300,
+            vec![event],
+            vec![trade],
+        );
+
+        let json = msg.to_json().unwrap();
+        let parsed = ServerMessage::from_json(&json).unwrap();
+
+        assert_eq!(msg, parsed);
+        assert!(json.contains("\"type\":\"state_sync\""));
+        assert!(json.contains("\"current_pick_number\":4"));
+        assert!(json.contains("\"pick_number\":3"));
+    }
+
     #[test]
     fn test_server_message_pick_made_serialization() {
         let session_id = Uuid::new_v4();
@@ -317,6 +683,41 @@ mod tests {
         assert!(json.contains("\"time_remaining\":120"));
     }
 
+    #[test]
+    fn test_server_message_picks_batch_serialization() {
+        let session_id = Uuid::new_v4();
+        let pick = BatchedPick {
+            pick_id: Uuid::new_v4(),
+            team_id: Uuid::new_v4(),
+            player_id: Uuid::new_v4(),
+            round: 1,
+            pick_number: 2,
+            player_name: "John Doe".to_string(),
+            team_name: "Team A".to_string(),
+        };
+        let msg = ServerMessage::picks_batch(session_id, vec![pick]);
+
+        let json = msg.to_json().unwrap();
+        let parsed = ServerMessage::from_json(&json).unwrap();
+
+        assert_eq!(msg, parsed);
+        assert!(json.contains("\"type\":\"picks_batch\""));
+        assert!(json.contains("\"player_name\":\"John Doe\""));
+    }
+
+    #[test]
+    fn test_server_message_clock_warning_serialization() {
+        let session_id = Uuid::new_v4();
+        let msg = ServerMessage::clock_warning(session_id, ClockWarningLevel::ThirtySeconds, 30, 7);
+
+        let json = msg.to_json().unwrap();
+        let parsed = ServerMessage::from_json(&json).unwrap();
+
+        assert_eq!(msg, parsed);
+        assert!(json.contains("\"type\":\"clock_warning\""));
+        assert!(json.contains("\"level\":\"thirty_seconds\""));
+    }
+
     #[test]
     fn test_server_message_error_serialization() {
         let msg = ServerMessage::error("Something went wrong".to_string());
@@ -327,6 +728,22 @@ mod tests {
         assert_eq!(msg, parsed);
         assert!(json.contains("\"type\":\"error\""));
         assert!(json.contains("Something went wrong"));
+        assert!(!json.contains("code"));
+    }
+
+    #[test]
+    fn test_server_message_rate_limited_serialization() {
+        let msg = ServerMessage::rate_limited(
+            "Too many messages".to_string(),
+            std::time::Duration::from_millis(1500),
+        );
+
+        let json = msg.to_json().unwrap();
+        let parsed = ServerMessage::from_json(&json).unwrap();
+
+        assert_eq!(msg, parsed);
+        assert!(json.contains("\"code\":\"rate_limited\""));
+        assert!(json.contains("\"retry_after_ms\":1500"));
     }
 
     #[test]
@@ -445,4 +862,73 @@ mod tests {
         assert!(json.contains("\"type\":\"draft_status\""));
         assert!(json.contains("InProgress"));
     }
+
+    #[test]
+    fn test_server_message_session_rewound_serialization() {
+        let session_id = Uuid::new_v4();
+        let trade_id = Uuid::new_v4();
+        let msg = ServerMessage::session_rewound(session_id, 5, vec![trade_id]);
+
+        let json = msg.to_json().unwrap();
+        let parsed = ServerMessage::from_json(&json).unwrap();
+
+        assert_eq!(msg, parsed);
+        assert!(json.contains("\"type\":\"session_rewound\""));
+        assert!(json.contains("\"current_pick_number\":5"));
+        assert!(json.contains(&trade_id.to_string()));
+    }
+
+    #[test]
+    fn test_client_message_chat_message_serialization() {
+        let session_id = Uuid::new_v4();
+        let msg = ClientMessage::chat_message(session_id, "Reach pick!".to_string());
+
+        let json = msg.to_json().unwrap();
+        let parsed = ClientMessage::from_json(&json).unwrap();
+
+        assert_eq!(msg, parsed);
+        assert!(json.contains("\"type\":\"chat_message\""));
+        assert!(!json.contains("access_token"));
+    }
+
+    #[test]
+    fn test_server_message_chat_message_serialization() {
+        let session_id = Uuid::new_v4();
+        let participant_id = Uuid::new_v4();
+        let msg = ServerMessage::chat_message(
+            session_id,
+            Uuid::new_v4(),
+            Some(participant_id),
+            "Alice".to_string(),
+            "Reach pick!".to_string(),
+            "2026-04-23T20:00:00+00:00".to_string(),
+        );
+
+        let json = msg.to_json().unwrap();
+        let parsed = ServerMessage::from_json(&json).unwrap();
+
+        assert_eq!(msg, parsed);
+        assert!(json.contains("\"type\":\"chat_message\""));
+        assert!(json.contains(&participant_id.to_string()));
+    }
+
+    #[test]
+    fn test_server_message_presence_serialization() {
+        let session_id = Uuid::new_v4();
+        let msg = ServerMessage::presence(
+            session_id,
+            SessionPresence {
+                connection_count: 3,
+                anonymous_count: 1,
+                display_names: vec!["Alice".to_string(), "Bob".to_string()],
+            },
+        );
+
+        let json = msg.to_json().unwrap();
+        let parsed = ServerMessage::from_json(&json).unwrap();
+
+        assert_eq!(msg, parsed);
+        assert!(json.contains("\"type\":\"presence\""));
+        assert!(json.contains("\"connection_count\":3"));
+    }
 }
@@ -0,0 +1,124 @@
+use std::collections::VecDeque;
+use std::time::{Duration, Instant};
+
+/// Sliding-window limiter that allows at most `max_events` within any
+/// `window`. Each connection owns its own limiter, so no locking is needed.
+#[derive(Debug, Clone)]
+pub struct RateLimiter {
+    max_events: usize,
+    window: Duration,
+    recent: VecDeque<Instant>,
+}
+
+impl RateLimiter {
+    pub fn new(max_events: usize, window: Duration) -> Self {
+        Self {
+            max_events,
+            window,
+            recent: VecDeque::with_capacity(max_events),
+        }
+    }
+
+    /// Records an event at `now` and returns true, or returns false without
+    /// recording anything when the window is already full
+    pub fn try_acquire_at(&mut self, now: Instant) -> bool {
+        while let Some(oldest) = self.recent.front() {
+            if now.duration_since(*oldest) >= self.window {
+                self.recent.pop_front();
+            } else {
+                break;
+            }
+        }
+
+        if self.recent.len() >= self.max_events {
+            return false;
+        }
+        self.recent.push_back(now);
+        true
+    }
+
+    pub fn try_acquire(&mut self) -> bool {
+        self.try_acquire_at(Instant::now())
+    }
+
+    /// How long after `now` until another event would be allowed. Zero when
+    /// the window has room.
+    pub fn retry_after_at(&self, now: Instant) -> Duration {
+        if self.recent.len() < self.max_events {
+            return Duration::ZERO;
+        }
+        self.recent
+            .front()
+            .map(|oldest| (*oldest + self.window).saturating_duration_since(now))
+            .unwrap_or(Duration::ZERO)
+    }
+
+    pub fn retry_after(&self) -> Duration {
+        self.retry_after_at(Instant::now())
+    }
+
+    /// True when every recorded event has left the window, so dropping the
+    /// limiter would not change any future decision
+    pub fn is_idle_at(&self, now: Instant) -> bool {
+        self.recent
+            .back()
+            .is_none_or(|newest| now.duration_since(*newest) >= self.window)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_limits_events_within_window() {
+        let mut limiter = RateLimiter::new(2, Duration::from_secs(10));
+        let start = Instant::now();
+
+        assert!(limiter.try_acquire_at(start));
+        assert!(limiter.try_acquire_at(start + Duration::from_secs(1)));
+        assert!(!limiter.try_acquire_at(start + Duration::from_secs(2)));
+
+        // The first event has left the window
+        assert!(limiter.try_acquire_at(start + Duration::from_secs(10)));
+        assert!(!limiter.try_acquire_at(start + Duration::from_secs(10)));
+    }
+
+    #[test]
+    fn test_retry_after() {
+        let mut limiter = RateLimiter::new(2, Duration::from_secs(10));
+        let start = Instant::now();
+
+        assert_eq!(limiter.retry_after_at(start), Duration::ZERO);
+        limiter.try_acquire_at(start);
+        limiter.try_acquire_at(start + Duration::from_secs(3));
+        assert_eq!(
+            limiter.retry_after_at(start + Duration::from_secs(4)),
+            Duration::from_secs(6)
+        );
+    }
+
+    #[test]
+    fn test_rejected_attempts_do_not_extend_window() {
+        let mut limiter = RateLimiter::new(1, Duration::from_secs(5));
+        let start = Instant::now();
+
+        assert!(limiter.try_acquire_at(start));
+        for i in 1..5 {
+            assert!(!limiter.try_acquire_at(start + Duration::from_secs(i)));
+        }
+        assert!(limiter.try_acquire_at(start + Duration::from_secs(5)));
+    }
+
+    #[test]
+    fn test_is_idle() {
+        let mut limiter = RateLimiter::new(3, Duration::from_secs(5));
+        let start = Instant::now();
+
+        assert!(limiter.is_idle_at(start));
+        limiter.try_acquire_at(start);
+        limiter.try_acquire_at(start + Duration::from_secs(2));
+        assert!(!limiter.is_idle_at(start + Duration::from_secs(6)));
+        assert!(limiter.is_idle_at(start + Duration::from_secs(7)));
+    }
+}
@@ -0,0 +1,88 @@
+use std::str::FromStr;
+
+use serde::{Deserialize, Serialize};
+
+/// What to do when a connection's send queue is full
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SlowConsumerPolicy {
+    /// Skip messages that a later one replaces anyway (clock ticks,
+    /// presence) and disconnect only when anything else does not fit
+    #[default]
+    Coalesce,
+    /// Disconnect as soon as the queue is full
+    Disconnect,
+}
+
+impl FromStr for SlowConsumerPolicy {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_ascii_lowercase().as_str() {
+            "coalesce" => Ok(SlowConsumerPolicy::Coalesce),
+            "disconnect" => Ok(SlowConsumerPolicy::Disconnect),
+            _ => Err(format!("Unknown slow consumer policy: {}", s)),
+        }
+    }
+}
+
+/// Size of each connection's outbound queue and how a full queue is handled
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SendQueueConfig {
+    pub capacity: usize,
+    pub policy: SlowConsumerPolicy,
+}
+
+impl SendQueueConfig {
+    pub fn new(capacity: usize, policy: SlowConsumerPolicy) -> Self {
+        Self {
+            capacity: capacity.max(1),
+            policy,
+        }
+    }
+}
+
+impl Default for SendQueueConfig {
+    fn default() -> Self {
+        Self::new(256, SlowConsumerPolicy::default())
+    }
+}
+
+/// Snapshot of outbound queue usage across all connections
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SendQueueStats {
+    pub connections: usize,
+    pub queue_capacity: usize,
+    /// Messages waiting across every connection's queue
+    pub queued_messages: usize,
+    /// Depth of the fullest queue
+    pub max_queue_depth: usize,
+    /// Messages skipped because a queue was full, since startup
+    pub dropped_messages: u64,
+    /// Connections closed for falling too far behind, since startup
+    pub slow_consumer_disconnects: u64,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_policy_from_str() {
+        assert_eq!(
+            "coalesce".parse::<SlowConsumerPolicy>(),
+            Ok(SlowConsumerPolicy::Coalesce)
+        );
+        assert_eq!(
+            "Disconnect".parse::<SlowConsumerPolicy>(),
+            Ok(SlowConsumerPolicy::Disconnect)
+        );
+        assert!("block".parse::<SlowConsumerPolicy>().is_err());
+    }
+
+    #[test]
+    fn test_capacity_is_at_least_one() {
+        let config = SendQueueConfig::new(0, SlowConsumerPolicy::Disconnect);
+        assert_eq!(config.capacity, 1);
+    }
+}
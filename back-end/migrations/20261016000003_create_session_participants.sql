@@ -0,0 +1,14 @@
+-- Session participants: users sharing one draft session, each controlling their own teams
+CREATE TABLE session_participants (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    session_id UUID NOT NULL REFERENCES draft_sessions(id) ON DELETE CASCADE,
+    display_name VARCHAR(100) NOT NULL,
+    team_ids UUID[] NOT NULL DEFAULT '{}',
+    access_token VARCHAR(64) NOT NULL UNIQUE,
+    joined_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+
+    CONSTRAINT session_participants_unique_name UNIQUE (session_id, display_name)
+);
+
+CREATE INDEX idx_session_participants_session_id ON session_participants(session_id);
@@ -0,0 +1,6 @@
+-- Track the current pick's clock so pausing and resuming preserves the time remaining
+ALTER TABLE draft_sessions ADD COLUMN pick_started_at TIMESTAMPTZ;
+ALTER TABLE draft_sessions ADD COLUMN paused_at TIMESTAMPTZ;
+ALTER TABLE draft_sessions ADD COLUMN elapsed_paused_ms BIGINT NOT NULL DEFAULT 0;
+
+ALTER TABLE draft_sessions ADD CONSTRAINT draft_sessions_elapsed_paused_non_negative CHECK (elapsed_paused_ms >= 0);
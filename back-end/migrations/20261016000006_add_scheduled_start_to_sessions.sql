@@ -0,0 +1,6 @@
+-- Optional time at which the API scheduler starts the session automatically
+ALTER TABLE draft_sessions ADD COLUMN scheduled_start_at TIMESTAMPTZ;
+
+CREATE INDEX idx_draft_sessions_scheduled_start
+    ON draft_sessions(scheduled_start_at)
+    WHERE status = 'NotStarted' AND scheduled_start_at IS NOT NULL;
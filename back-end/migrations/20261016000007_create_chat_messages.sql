@@ -0,0 +1,13 @@
+-- Chat messages exchanged by people watching or taking part in a draft session
+CREATE TABLE chat_messages (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    session_id UUID NOT NULL REFERENCES draft_sessions(id) ON DELETE CASCADE,
+    participant_id UUID REFERENCES session_participants(id) ON DELETE SET NULL,
+    display_name VARCHAR(100) NOT NULL,
+    body VARCHAR(500) NOT NULL,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+
+    CONSTRAINT chat_messages_body_not_empty CHECK (LENGTH(TRIM(body)) > 0)
+);
+
+CREATE INDEX idx_chat_messages_session_created ON chat_messages(session_id, created_at);
@@ -0,0 +1,13 @@
+-- Accounts that sign in to run or take part in drafts
+CREATE TABLE users (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    email VARCHAR(255) NOT NULL,
+    display_name VARCHAR(100) NOT NULL,
+    password_hash TEXT NOT NULL,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+
+    CONSTRAINT users_display_name_not_empty CHECK (LENGTH(TRIM(display_name)) > 0)
+);
+
+-- Emails are stored lowercased, so this also rejects case variants
+CREATE UNIQUE INDEX idx_users_email ON users(email);
@@ -0,0 +1,5 @@
+-- Role-based access: what each account may do across sessions and admin tools
+ALTER TABLE users
+    ADD COLUMN role VARCHAR(20) NOT NULL DEFAULT 'participant',
+    ADD CONSTRAINT users_role_check
+        CHECK (role IN ('spectator', 'participant', 'commissioner', 'admin'));
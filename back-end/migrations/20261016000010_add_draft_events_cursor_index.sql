@@ -0,0 +1,6 @@
+-- Cursor pagination walks a session's events by (created_at, id); the ID
+-- breaks ties between events recorded in the same instant. This index
+-- covers the old (session_id, created_at) one.
+CREATE INDEX idx_draft_events_session_created_id ON draft_events(session_id, created_at, id);
+
+DROP INDEX IF EXISTS idx_draft_events_session_created;
@@ -0,0 +1,16 @@
+-- Results of the real NFL draft, loaded after it happens so mock drafts can
+-- be scored against them
+CREATE TABLE actual_draft_picks (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    year INTEGER NOT NULL CHECK (year >= 2020 AND year <= 2030),
+    round INTEGER NOT NULL CHECK (round > 0),
+    pick_number INTEGER NOT NULL CHECK (pick_number > 0),
+    overall_pick INTEGER NOT NULL CHECK (overall_pick > 0),
+    team_id UUID NOT NULL REFERENCES teams(id) ON DELETE CASCADE,
+    player_id UUID NOT NULL REFERENCES players(id) ON DELETE CASCADE,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    UNIQUE (year, overall_pick),
+    UNIQUE (year, player_id)
+);
+
+CREATE INDEX idx_actual_draft_picks_year ON actual_draft_picks(year);
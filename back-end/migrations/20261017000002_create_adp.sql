@@ -0,0 +1,14 @@
+-- Where each player went in every completed mock draft, for average draft
+-- position. draft_id has no foreign key so history outlives deleted drafts.
+CREATE TABLE adp (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    draft_id UUID NOT NULL,
+    year INTEGER NOT NULL,
+    player_id UUID NOT NULL REFERENCES players(id) ON DELETE CASCADE,
+    round INTEGER NOT NULL CHECK (round > 0),
+    overall_pick INTEGER NOT NULL CHECK (overall_pick > 0),
+    recorded_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    UNIQUE (draft_id, player_id)
+);
+
+CREATE INDEX idx_adp_year_player ON adp(year, player_id);
@@ -0,0 +1,14 @@
+-- Dated news and notes about a player: injury updates, team visits, and
+-- risers/fallers from the draft-season news cycle
+CREATE TABLE player_notes (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    player_id UUID NOT NULL REFERENCES players(id) ON DELETE CASCADE,
+    source VARCHAR(100) NOT NULL,
+    note_date DATE NOT NULL,
+    tag VARCHAR(20) NOT NULL CHECK (tag IN ('general', 'injury', 'visit', 'riser', 'faller')),
+    content TEXT NOT NULL,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX idx_player_notes_player_date ON player_notes(player_id, note_date DESC);
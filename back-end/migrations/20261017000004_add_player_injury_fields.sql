@@ -0,0 +1,8 @@
+-- Structured injury tracking on players, used to scale the injury penalty in
+-- player evaluation by severity
+ALTER TABLE players
+    ADD COLUMN injury_status VARCHAR(20) NOT NULL DEFAULT 'healthy'
+        CHECK (injury_status IN ('healthy', 'questionable', 'limited', 'out', 'long_term')),
+    ADD COLUMN injury_body_part VARCHAR(50),
+    ADD COLUMN injury_expected_return DATE,
+    ADD COLUMN medical_flag BOOLEAN NOT NULL DEFAULT false;
@@ -0,0 +1,29 @@
+-- Per-season college production. Each stat line's columns are either all set
+-- or all null, depending on whether the player recorded stats in that phase.
+CREATE TABLE college_stats (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    player_id UUID NOT NULL REFERENCES players(id) ON DELETE CASCADE,
+    season INTEGER NOT NULL CHECK (season BETWEEN 1990 AND 2100),
+    school VARCHAR(100),
+    games_played INTEGER NOT NULL CHECK (games_played BETWEEN 0 AND 17),
+    pass_completions INTEGER,
+    pass_attempts INTEGER,
+    pass_yards INTEGER,
+    pass_touchdowns INTEGER,
+    pass_interceptions INTEGER,
+    rush_attempts INTEGER,
+    rush_yards INTEGER,
+    rush_touchdowns INTEGER,
+    receptions INTEGER,
+    receiving_yards INTEGER,
+    receiving_touchdowns INTEGER,
+    tackles INTEGER,
+    tackles_for_loss DOUBLE PRECISION,
+    sacks DOUBLE PRECISION,
+    def_interceptions INTEGER,
+    passes_defended INTEGER,
+    forced_fumbles INTEGER,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    UNIQUE (player_id, season)
+);
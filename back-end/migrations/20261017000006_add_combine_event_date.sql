@@ -0,0 +1,16 @@
+-- Keep every testing event per player instead of one row per source: school
+-- workouts join combine and pro day as a source, and rows are told apart by
+-- the date of the event
+ALTER TABLE combine_results
+    ADD COLUMN event_date DATE;
+
+ALTER TABLE combine_results DROP CONSTRAINT valid_source;
+ALTER TABLE combine_results
+    ADD CONSTRAINT valid_source CHECK (source IN ('combine', 'pro_day', 'school'));
+
+ALTER TABLE combine_results DROP CONSTRAINT unique_player_combine_source;
+ALTER TABLE combine_results
+    ADD CONSTRAINT unique_player_combine_event UNIQUE NULLS NOT DISTINCT (player_id, year, source, event_date);
+
+COMMENT ON COLUMN combine_results.source IS 'Data source: combine (NFL Combine), pro_day (Pro Day workout) or school (school-run testing)';
+COMMENT ON COLUMN combine_results.event_date IS 'Date the testing took place, when known';
@@ -0,0 +1,10 @@
+-- Free-form labels on players such as "team captain", "small-school" or
+-- "early declare". Tags are stored lowercased so filtering ignores case.
+CREATE TABLE player_tags (
+    player_id UUID NOT NULL REFERENCES players(id) ON DELETE CASCADE,
+    tag VARCHAR(50) NOT NULL,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    PRIMARY KEY (player_id, tag)
+);
+
+CREATE INDEX idx_player_tags_tag ON player_tags(tag);
@@ -0,0 +1,10 @@
+-- Players a user is keeping an eye on. The list belongs to the account, so it
+-- follows the user across draft sessions.
+CREATE TABLE user_watchlists (
+    user_id UUID NOT NULL REFERENCES users(id) ON DELETE CASCADE,
+    player_id UUID NOT NULL REFERENCES players(id) ON DELETE CASCADE,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    PRIMARY KEY (user_id, player_id)
+);
+
+CREATE INDEX idx_user_watchlists_player_id ON user_watchlists(player_id);
@@ -0,0 +1,24 @@
+-- One row per write made through the API: who made it, through which route,
+-- and, for entities whose repositories record changes, the entity's state
+-- before and after. Rows are never updated or deleted by the application.
+CREATE TABLE audit_log (
+    id UUID PRIMARY KEY,
+    actor_user_id UUID,
+    actor VARCHAR(255) NOT NULL,
+    method VARCHAR(10) NOT NULL,
+    route VARCHAR(255) NOT NULL,
+    entity_type VARCHAR(50) NOT NULL,
+    entity_id UUID,
+    before JSONB,
+    after JSONB,
+    status_code INTEGER NOT NULL,
+    request_id VARCHAR(128),
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX idx_audit_log_entity ON audit_log(entity_type, entity_id, created_at DESC);
+CREATE INDEX idx_audit_log_actor ON audit_log(actor_user_id, created_at DESC);
+CREATE INDEX idx_audit_log_created_at ON audit_log(created_at DESC);
+
+COMMENT ON COLUMN audit_log.actor IS 'Email of the signed-in user, "api-key" for the seed API key, or "anonymous"';
+COMMENT ON COLUMN audit_log.route IS 'Matched route template, e.g. /api/v1/picks/{id}/make';
@@ -0,0 +1,11 @@
+-- Deleting a player, team or scouting report through the API only marks the
+-- row; it drops out of every read but keeps whatever refers to it, and an
+-- admin can restore it. Unique keys still count deleted rows, so a deleted
+-- team's abbreviation stays taken until it is restored or purged.
+ALTER TABLE players ADD COLUMN deleted_at TIMESTAMPTZ;
+ALTER TABLE teams ADD COLUMN deleted_at TIMESTAMPTZ;
+ALTER TABLE scouting_reports ADD COLUMN deleted_at TIMESTAMPTZ;
+
+COMMENT ON COLUMN players.deleted_at IS 'When the player was soft-deleted; NULL while live';
+COMMENT ON COLUMN teams.deleted_at IS 'When the team was soft-deleted; NULL while live';
+COMMENT ON COLUMN scouting_reports.deleted_at IS 'When the report was soft-deleted; NULL while live';
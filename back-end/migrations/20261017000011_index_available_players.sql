@@ -0,0 +1,6 @@
+-- Available players for a draft are the live players of its year that no
+-- pick in it refers to. This index serves the year filter and the name order
+-- in one scan; the unpicked check is an anti-join on
+-- idx_unique_player_per_draft (draft_id, player_id).
+CREATE INDEX idx_players_available ON players(draft_year, last_name, first_name)
+    WHERE deleted_at IS NULL;
@@ -0,0 +1,18 @@
+-- Outbox for draft events. Every event recorded through the API gets a row
+-- here in the same transaction as the event (and, for session changes, the
+-- change itself). The request that made the change marks the row published
+-- once it has broadcast the event; a background relay publishes whatever is
+-- still unmarked after a grace period, such as events whose broadcast was
+-- lost to a restart. Delivery is at least once.
+CREATE TABLE event_outbox (
+    event_id UUID PRIMARY KEY REFERENCES draft_events(id) ON DELETE CASCADE,
+    available_at TIMESTAMPTZ NOT NULL,
+    attempts INTEGER NOT NULL DEFAULT 0,
+    published_at TIMESTAMPTZ
+);
+
+CREATE INDEX idx_event_outbox_pending ON event_outbox(available_at) WHERE published_at IS NULL;
+
+COMMENT ON COLUMN event_outbox.available_at IS 'When the relay may next claim the event; pushed forward on every claim';
+COMMENT ON COLUMN event_outbox.attempts IS 'How many times the relay has claimed the event';
+COMMENT ON COLUMN event_outbox.published_at IS 'When the event was broadcast; NULL while pending';
@@ -0,0 +1,13 @@
+-- Keyset pagination walks a draft class by (last_name, first_name, id), a
+-- source's board by (rank, id) and the event log by (created_at, id); the
+-- ID breaks ties. Each index covers the one it replaces, so the available
+-- players query keeps its single scan over the year and names.
+CREATE INDEX idx_players_draft_year_name_id ON players(draft_year, last_name, first_name, id)
+    WHERE deleted_at IS NULL;
+DROP INDEX IF EXISTS idx_players_available;
+
+CREATE INDEX idx_prospect_rankings_source_rank_id ON prospect_rankings(ranking_source_id, rank, id);
+DROP INDEX IF EXISTS idx_prospect_rankings_rank;
+
+CREATE INDEX idx_draft_events_created_id ON draft_events(created_at, id);
+DROP INDEX IF EXISTS idx_draft_events_created_at;
@@ -0,0 +1,13 @@
+-- One row per seed file the seed-data CLI has loaded. The content hash
+-- lets a load skip a file whose exact contents are already in the
+-- database; a changed file at the same path replaces its row.
+CREATE TABLE seed_runs (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    kind VARCHAR(50) NOT NULL,
+    file_path TEXT NOT NULL,
+    content_hash CHAR(64) NOT NULL,
+    loaded_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    UNIQUE (kind, file_path)
+);
+
+CREATE INDEX idx_seed_runs_kind_hash ON seed_runs(kind, content_hash);
@@ -0,0 +1,5 @@
+-- Links scraped from ranking sources: the player's profile page on the
+-- source site and their headshot image
+ALTER TABLE players
+    ADD COLUMN profile_url VARCHAR(500),
+    ADD COLUMN headshot_url VARCHAR(500);
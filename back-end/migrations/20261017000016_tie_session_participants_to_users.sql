@@ -0,0 +1,18 @@
+-- Participants are signed-in users rather than holders of a bearer token.
+-- Existing seats were anonymous and cannot be tied to an account, so they
+-- are dropped along with the teams they held.
+UPDATE draft_sessions s
+SET controlled_team_ids = ARRAY(
+        SELECT t FROM unnest(s.controlled_team_ids) AS t
+        WHERE t <> ALL(
+            SELECT unnest(p.team_ids) FROM session_participants p WHERE p.session_id = s.id
+        )
+    )
+WHERE EXISTS (SELECT 1 FROM session_participants p WHERE p.session_id = s.id);
+
+DELETE FROM session_participants;
+
+ALTER TABLE session_participants
+    DROP COLUMN access_token,
+    ADD COLUMN user_id UUID NOT NULL REFERENCES users(id) ON DELETE CASCADE,
+    ADD CONSTRAINT session_participants_unique_user UNIQUE (session_id, user_id);
@@ -0,0 +1,5 @@
+-- Writes made from a session seat record the participant as well as the
+-- user. No foreign key: audit rows outlive the seats they name.
+ALTER TABLE audit_log ADD COLUMN actor_participant_id UUID;
+
+COMMENT ON COLUMN audit_log.actor IS 'Display name of the session participant, email of the signed-in user, "api-key" for the seed API key, or "anonymous"';
@@ -0,0 +1,3 @@
+-- Self-registered accounts start as spectators; an admin or commissioner
+-- grants participant. Existing accounts keep their roles.
+ALTER TABLE users ALTER COLUMN role SET DEFAULT 'spectator';
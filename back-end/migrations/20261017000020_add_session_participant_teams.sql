@@ -0,0 +1,20 @@
+-- One row per team a participant controls, so the primary key stops two
+-- participants from claiming the same team in a session even when their
+-- joins race. team_ids stays the source for reads.
+CREATE TABLE session_participant_teams (
+    session_id UUID NOT NULL,
+    team_id UUID NOT NULL,
+    participant_id UUID NOT NULL REFERENCES session_participants(id) ON DELETE CASCADE,
+    PRIMARY KEY (session_id, team_id)
+);
+
+CREATE INDEX idx_session_participant_teams_participant_id
+    ON session_participant_teams(participant_id);
+
+-- Earlier joins win any claim that already overlaps
+INSERT INTO session_participant_teams (session_id, team_id, participant_id)
+SELECT p.session_id, t.team_id, p.id
+FROM session_participants p
+CROSS JOIN LATERAL unnest(p.team_ids) AS t(team_id)
+ORDER BY p.joined_at
+ON CONFLICT DO NOTHING;
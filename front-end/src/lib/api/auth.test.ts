@@ -0,0 +1,96 @@
+import { describe, it, expect, vi, beforeEach, afterEach } from 'vitest';
+import { authApi } from './auth';
+import * as client from './client';
+import type { TokenResponse } from '$lib/types';
+
+describe('authApi', () => {
+	let mockGet: ReturnType<typeof vi.fn>;
+	let mockPost: ReturnType<typeof vi.fn>;
+	let mockSetToken: ReturnType<typeof vi.fn>;
+
+	const tokenResponse: TokenResponse = {
+		access_token: 'abc.def.ghi',
+		token_type: 'Bearer',
+		expires_in: 86400,
+		user: {
+			id: '123e4567-e89b-12d3-a456-426614174000',
+			email: 'coach@example.com',
+			display_name: 'Coach',
+			role: 'commissioner',
+			created_at: '2026-10-17T00:00:00Z',
+		},
+	};
+
+	beforeEach(() => {
+		mockGet = vi.fn();
+		mockPost = vi.fn();
+		mockSetToken = vi.fn();
+
+		vi.spyOn(client.apiClient, 'get').mockImplementation(mockGet as any);
+		vi.spyOn(client.apiClient, 'post').mockImplementation(mockPost as any);
+		vi.spyOn(client.apiClient, 'setToken').mockImplementation(mockSetToken as any);
+	});
+
+	afterEach(() => {
+		vi.restoreAllMocks();
+	});
+
+	describe('login', () => {
+		it('should sign in and keep the token', async () => {
+			mockPost.mockResolvedValueOnce(tokenResponse);
+
+			const result = await authApi.login('coach@example.com', 'correct horse');
+
+			expect(mockPost).toHaveBeenCalledWith(
+				'/auth/login',
+				{ email: 'coach@example.com', password: 'correct horse' },
+				expect.any(Object)
+			);
+			expect(mockSetToken).toHaveBeenCalledWith('abc.def.ghi');
+			expect(result).toEqual(tokenResponse);
+		});
+
+		it('should not keep a token when sign-in fails', async () => {
+			mockPost.mockRejectedValueOnce(new client.ApiClientError('Invalid email or password', 401));
+
+			await expect(authApi.login('coach@example.com', 'wrong')).rejects.toThrow(
+				'Invalid email or password'
+			);
+			expect(mockSetToken).not.toHaveBeenCalled();
+		});
+	});
+
+	describe('register', () => {
+		it('should create the account and keep the token', async () => {
+			mockPost.mockResolvedValueOnce(tokenResponse);
+
+			await authApi.register('coach@example.com', 'Coach', 'correct horse');
+
+			expect(mockPost).toHaveBeenCalledWith(
+				'/auth/register',
+				{ email: 'coach@example.com', display_name: 'Coach', password: 'correct horse' },
+				expect.any(Object)
+			);
+			expect(mockSetToken).toHaveBeenCalledWith('abc.def.ghi');
+		});
+	});
+
+	describe('me', () => {
+		it('should fetch the signed-in user', async () => {
+			mockGet.mockResolvedValueOnce(tokenResponse.user);
+
+			const result = await authApi.me();
+
+			expect(mockGet).toHaveBeenCalledWith('/auth/me', expect.any(Object));
+			expect(result).toEqual(tokenResponse.user);
+		});
+	});
+
+	describe('logout', () => {
+		it('should clear the token', () => {
+			authApi.logout();
+
+			expect(mockSetToken).toHaveBeenCalledWith(null);
+		});
+	});
+});
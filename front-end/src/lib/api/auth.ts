@@ -0,0 +1,44 @@
+import { apiClient } from './client';
+import { TokenResponseSchema, UserSchema, type TokenResponse, type User } from '$lib/types';
+
+/**
+ * Auth API module. Signing in or registering stores the access token on the
+ * shared client, so later requests carry it.
+ */
+export const authApi = {
+	/**
+	 * Create an account and sign in as it
+	 */
+	async register(email: string, displayName: string, password: string): Promise<TokenResponse> {
+		const response = await apiClient.post(
+			'/auth/register',
+			{ email, display_name: displayName, password },
+			TokenResponseSchema
+		);
+		apiClient.setToken(response.access_token);
+		return response;
+	},
+
+	/**
+	 * Exchange credentials for an access token
+	 */
+	async login(email: string, password: string): Promise<TokenResponse> {
+		const response = await apiClient.post('/auth/login', { email, password }, TokenResponseSchema);
+		apiClient.setToken(response.access_token);
+		return response;
+	},
+
+	/**
+	 * The account behind the stored token
+	 */
+	async me(): Promise<User> {
+		return apiClient.get('/auth/me', UserSchema);
+	},
+
+	/**
+	 * Forget the stored token
+	 */
+	logout(): void {
+		apiClient.setToken(null);
+	},
+};
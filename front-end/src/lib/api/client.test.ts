@@ -1,6 +1,6 @@
 import { describe, it, expect, vi, beforeEach, afterEach } from 'vitest';
 import { z } from 'zod';
-import { ApiClient, ApiClientError } from './client';
+import { ACCESS_TOKEN_STORAGE_KEY, ApiClient, ApiClientError } from './client';
 
 describe('ApiClient', () => {
 	let apiClient: ApiClient;
@@ -274,6 +274,43 @@ describe('ApiClient', () => {
 		});
 	});
 
+	describe('access token', () => {
+		afterEach(() => {
+			localStorage.clear();
+		});
+
+		it('should send the token as a bearer header once set', async () => {
+			fetchMock.mockResolvedValue({
+				ok: true,
+				status: 201,
+				headers: new Headers({ 'content-type': 'application/json' }),
+				json: async () => ({ success: true }),
+			});
+
+			apiClient.setToken('abc.def.ghi');
+			await apiClient.post('/test', {}, z.object({ success: z.boolean() }));
+
+			expect(fetchMock).toHaveBeenCalledWith('/api/test', {
+				method: 'POST',
+				headers: {
+					'Content-Type': 'application/json',
+					Authorization: 'Bearer abc.def.ghi',
+				},
+				body: '{}',
+			});
+		});
+
+		it('should keep the token across clients until cleared', () => {
+			apiClient.setToken('abc.def.ghi');
+			expect(localStorage.getItem(ACCESS_TOKEN_STORAGE_KEY)).toBe('abc.def.ghi');
+			expect(new ApiClient('/api').getToken()).toBe('abc.def.ghi');
+
+			apiClient.setToken(null);
+			expect(localStorage.getItem(ACCESS_TOKEN_STORAGE_KEY)).toBeNull();
+			expect(new ApiClient('/api').getToken()).toBeNull();
+		});
+	});
+
 	describe('ApiClientError', () => {
 		it('should create error with message and status', () => {
 			const error = new ApiClientError('Test error', 404);
@@ -16,14 +16,42 @@ export class ApiClientError extends Error {
 	}
 }
 
+/**
+ * Where the signed-in user's access token is kept between page loads
+ */
+export const ACCESS_TOKEN_STORAGE_KEY = 'nfl-draft-access-token';
+
 /**
  * Base API client with Zod validation and error handling
  */
 export class ApiClient {
 	private baseUrl: string;
+	private token: string | null;
 
 	constructor(baseUrl: string = '/api/v1') {
 		this.baseUrl = baseUrl;
+		this.token =
+			typeof localStorage !== 'undefined' ? localStorage.getItem(ACCESS_TOKEN_STORAGE_KEY) : null;
+	}
+
+	/**
+	 * Send this access token with every request, or none after signing out
+	 */
+	setToken(token: string | null): void {
+		this.token = token;
+		if (typeof localStorage === 'undefined') return;
+		if (token) {
+			localStorage.setItem(ACCESS_TOKEN_STORAGE_KEY, token);
+		} else {
+			localStorage.removeItem(ACCESS_TOKEN_STORAGE_KEY);
+		}
+	}
+
+	/**
+	 * The access token sent with requests, if signed in
+	 */
+	getToken(): string | null {
+		return this.token;
 	}
 
 	/**
@@ -32,9 +60,7 @@ export class ApiClient {
 	async get<T>(path: string, schema: z.ZodType<T>): Promise<T> {
 		const response = await fetch(`${this.baseUrl}${path}`, {
 			method: 'GET',
-			headers: {
-				'Content-Type': 'application/json',
-			},
+			headers: this.headers(),
 		});
 
 		return this.handleResponse(response, schema);
@@ -46,9 +72,7 @@ export class ApiClient {
 	async post<T>(path: string, data: unknown, schema: z.ZodType<T>): Promise<T> {
 		const response = await fetch(`${this.baseUrl}${path}`, {
 			method: 'POST',
-			headers: {
-				'Content-Type': 'application/json',
-			},
+			headers: this.headers(),
 			body: JSON.stringify(data),
 		});
 
@@ -61,9 +85,7 @@ export class ApiClient {
 	async put<T>(path: string, data: unknown, schema: z.ZodType<T>): Promise<T> {
 		const response = await fetch(`${this.baseUrl}${path}`, {
 			method: 'PUT',
-			headers: {
-				'Content-Type': 'application/json',
-			},
+			headers: this.headers(),
 			body: JSON.stringify(data),
 		});
 
@@ -76,14 +98,25 @@ export class ApiClient {
 	async delete<T>(path: string, schema: z.ZodType<T>): Promise<T> {
 		const response = await fetch(`${this.baseUrl}${path}`, {
 			method: 'DELETE',
-			headers: {
-				'Content-Type': 'application/json',
-			},
+			headers: this.headers(),
 		});
 
 		return this.handleResponse(response, schema);
 	}
 
+	/**
+	 * JSON headers, plus the bearer token when signed in
+	 */
+	private headers(): Record<string, string> {
+		const headers: Record<string, string> = {
+			'Content-Type': 'application/json',
+		};
+		if (this.token) {
+			headers.Authorization = `Bearer ${this.token}`;
+		}
+		return headers;
+	}
+
 	/**
 	 * Handle API response with error checking and Zod validation
 	 */
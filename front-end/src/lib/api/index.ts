@@ -1,5 +1,6 @@
 // Re-export all API modules
-export { apiClient, ApiClient, ApiClientError } from './client';
+export { apiClient, ApiClient, ApiClientError, ACCESS_TOKEN_STORAGE_KEY } from './client';
+export { authApi } from './auth';
 export { teamsApi } from './teams';
 export { teamSeasonsApi } from './teamSeasons';
 export { playersApi } from './players';
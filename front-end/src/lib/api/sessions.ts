@@ -19,6 +19,8 @@ export interface CreateSessionParams {
 	auto_pick_enabled: boolean;
 	chart_type: ChartType;
 	controlled_team_ids?: string[];
+	/** ISO timestamp at which the server starts the session automatically */
+	scheduled_start_at?: string;
 }
 
 /**
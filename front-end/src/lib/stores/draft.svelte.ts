@@ -204,9 +204,12 @@ export class DraftState {
 			// Only move forward to avoid going backwards if messages arrive out of order.
 			const nextPickNumber = pick.overall_pick + 1;
 			if (this.session && nextPickNumber > this.session.current_pick_number) {
+				// The next pick starts on a fresh clock
 				this.session = {
 					...this.session,
 					current_pick_number: nextPickNumber,
+					elapsed_paused_ms: 0,
+					time_remaining_seconds: this.session.time_per_pick_seconds,
 				};
 			}
 		}
@@ -1,5 +1,13 @@
 import { wsClient, WebSocketState } from '$lib/api';
-import type { ServerMessage, SessionStatus } from '$lib/types';
+import type {
+	AdminAnnouncement,
+	BatchedPick,
+	ChatMessageBroadcast,
+	ClockWarningLevel,
+	ServerMessage,
+	SessionPresence,
+	SessionStatus,
+} from '$lib/types';
 import { draftState } from './draft.svelte';
 import { tradesState } from './trades.svelte';
 import { logger } from '$lib/utils/logger';
@@ -13,10 +21,15 @@ export class WebSocketStateManager {
 	connectionState = $state<WebSocketState>(WebSocketState.Disconnected);
 	lastMessage = $state<ServerMessage | null>(null);
 	error = $state<string | null>(null);
+	chatMessages = $state<ChatMessageBroadcast[]>([]);
+	presence = $state<SessionPresence | null>(null);
+	clockWarning = $state<ClockWarningLevel | null>(null);
+	announcements = $state<AdminAnnouncement[]>([]);
 
 	private unsubscribeMessage?: () => void;
 	private unsubscribeState?: () => void;
 	private pendingSessionId: string | null = null;
+	private viewer: { displayName?: string; accessToken?: string } = {};
 
 	constructor() {
 		this.setupListeners();
@@ -40,8 +53,19 @@ export class WebSocketStateManager {
 	 * Subscribe to a draft session.
 	 * If the WebSocket is not yet connected, stores the session ID
 	 * and auto-subscribes once the connection is established.
+	 * `viewer` names this client in the session's presence list.
 	 */
-	subscribeToSession(sessionId: string): void {
+	subscribeToSession(
+		sessionId: string,
+		viewer: { displayName?: string; accessToken?: string } = {}
+	): void {
+		this.viewer = viewer;
+		if (sessionId !== this.pendingSessionId) {
+			this.chatMessages = [];
+			this.presence = null;
+			this.clockWarning = null;
+			this.announcements = [];
+		}
 		this.pendingSessionId = sessionId;
 
 		if (!wsClient.isConnected()) {
@@ -56,6 +80,26 @@ export class WebSocketStateManager {
 		wsClient.send({
 			type: 'subscribe',
 			session_id: sessionId,
+			...(this.viewer.displayName && { display_name: this.viewer.displayName }),
+			...(this.viewer.accessToken && { access_token: this.viewer.accessToken }),
+		});
+	}
+
+	/**
+	 * Post a chat message to the subscribed session. Participants pass their
+	 * access token so the message is attributed to them.
+	 */
+	sendChat(body: string, options: { displayName?: string; accessToken?: string } = {}): void {
+		if (!this.pendingSessionId) {
+			logger.warn('Cannot send chat message before subscribing to a session');
+			return;
+		}
+		wsClient.send({
+			type: 'chat_message',
+			session_id: this.pendingSessionId,
+			body,
+			display_name: options.displayName,
+			access_token: options.accessToken,
 		});
 	}
 
@@ -96,33 +140,51 @@ export class WebSocketStateManager {
 				logger.info('Subscribed to session:', message.session_id);
 				break;
 
+			case 'state_sync':
+				logger.info('State sync received:', message);
+				if (draftState.session) {
+					draftState.session = {
+						...draftState.session,
+						status: message.status as SessionStatus,
+						current_pick_number: message.current_pick_number,
+					};
+				}
+				break;
+
 			case 'pick_made':
 				logger.info('Pick made:', message);
-				// Update draft state with the new pick and advance current pick number.
-				// updatePickFromWS derives the next pick from the actual pick's overall
-				// position rather than blindly incrementing, so state stays correct
-				// after pause/resume.
-				draftState.updatePickFromWS({
-					pick_id: message.pick_id,
-					player_id: message.player_id,
-					team_id: message.team_id,
-				});
-				// Add notification for the activity feed
-				draftState.addPickNotification({
-					pick_id: message.pick_id,
-					player_id: message.player_id,
-					team_id: message.team_id,
-					player_name: message.player_name,
-					team_name: message.team_name,
-					round: message.round,
-					pick_number: message.pick_number,
-				});
+				this.applyPick(message);
+				break;
+
+			case 'picks_batch':
+				logger.info(`Picks batch: ${message.picks.length} picks`);
+				for (const pick of message.picks) {
+					this.applyPick(pick);
+				}
 				break;
 
 			case 'clock_update':
 				// Clock updates are handled by UI components that need to display the timer
 				break;
 
+			case 'clock_tick':
+				// The server owns the countdown; keep the session's copy in step with it
+				if (draftState.session && draftState.session.id === message.session_id) {
+					if (draftState.session.current_pick_number !== message.current_pick_number) {
+						this.clockWarning = null;
+					}
+					draftState.session = {
+						...draftState.session,
+						time_remaining_seconds: message.time_remaining,
+					};
+				}
+				break;
+
+			case 'clock_warning':
+				logger.info('Clock warning:', message.level);
+				this.clockWarning = message.level;
+				break;
+
 			case 'draft_status':
 				logger.info('Draft status changed:', message.status);
 				// Update session status if needed
@@ -162,6 +224,38 @@ export class WebSocketStateManager {
 				tradesState.onTradeRejected(message.trade_id);
 				break;
 
+			case 'session_rewound':
+				logger.info('Session rewound:', message);
+				if (draftState.session) {
+					draftState.session = {
+						...draftState.session,
+						status: 'Paused',
+						current_pick_number: message.current_pick_number,
+					};
+				}
+				// Cleared picks and reversed trades are easiest to pick up with a full reload
+				if (draftState.draft) {
+					draftState.loadDraft(draftState.draft.id);
+				}
+				break;
+
+			case 'presence':
+				this.presence = {
+					connection_count: message.connection_count,
+					anonymous_count: message.anonymous_count,
+					display_names: message.display_names,
+				};
+				break;
+
+			case 'chat_message':
+				this.chatMessages = [...this.chatMessages, message];
+				break;
+
+			case 'admin_announcement':
+				logger.info('Announcement:', message.message);
+				this.announcements = [...this.announcements, message];
+				break;
+
 			case 'error':
 				logger.error('WebSocket error:', message.message);
 				this.error = message.message;
@@ -173,6 +267,30 @@ export class WebSocketStateManager {
 		}
 	}
 
+	/**
+	 * Apply a pick from a pick_made or picks_batch message to draft state
+	 */
+	private applyPick(pick: BatchedPick): void {
+		// updatePickFromWS derives the next pick from the actual pick's overall
+		// position rather than blindly incrementing, so state stays correct
+		// after pause/resume.
+		draftState.updatePickFromWS({
+			pick_id: pick.pick_id,
+			player_id: pick.player_id,
+			team_id: pick.team_id,
+		});
+		// Add notification for the activity feed
+		draftState.addPickNotification({
+			pick_id: pick.pick_id,
+			player_id: pick.player_id,
+			team_id: pick.team_id,
+			player_name: pick.player_name,
+			team_name: pick.team_name,
+			round: pick.round,
+			pick_number: pick.pick_number,
+		});
+	}
+
 	/**
 	 * Cleanup listeners
 	 */
@@ -62,6 +62,9 @@ export const DraftSessionSchema = z.object({
 	controlled_team_ids: z.array(UUIDSchema).default([]),
 	started_at: z.string().nullable().optional(),
 	completed_at: z.string().nullable().optional(),
+	scheduled_start_at: z.string().nullable().optional(),
+	elapsed_paused_ms: z.number().optional(),
+	time_remaining_seconds: z.number().optional(),
 });
 export type DraftSession = z.infer<typeof DraftSessionSchema>;
 
@@ -6,3 +6,4 @@ export * from './draft';
 export * from './trade';
 export * from './websocket';
 export * from './ranking';
+export * from './user';
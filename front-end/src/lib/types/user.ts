@@ -0,0 +1,25 @@
+import { z } from 'zod';
+import { UUIDSchema } from './common';
+
+// Role schema and type, lowest to highest
+export const RoleSchema = z.enum(['spectator', 'participant', 'commissioner', 'admin']);
+export type Role = z.infer<typeof RoleSchema>;
+
+// User schema and type — matches backend UserResponse
+export const UserSchema = z.object({
+	id: UUIDSchema,
+	email: z.string(),
+	display_name: z.string(),
+	role: RoleSchema,
+	created_at: z.string(),
+});
+export type User = z.infer<typeof UserSchema>;
+
+// Token schema and type — matches backend TokenResponse from register and login
+export const TokenResponseSchema = z.object({
+	access_token: z.string(),
+	token_type: z.string(),
+	expires_in: z.number(),
+	user: UserSchema,
+});
+export type TokenResponse = z.infer<typeof TokenResponseSchema>;
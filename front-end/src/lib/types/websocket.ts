@@ -5,6 +5,9 @@ import { UUIDSchema } from './common';
 const SubscribeMessageSchema = z.object({
 	type: z.literal('subscribe'),
 	session_id: UUIDSchema,
+	last_event_id: UUIDSchema.optional(),
+	display_name: z.string().optional(),
+	access_token: z.string().optional(),
 });
 
 const MakePickMessageSchema = z.object({
@@ -21,6 +24,14 @@ const ProposeTradeMessageSchema = z.object({
 	pick_ids: z.array(UUIDSchema),
 });
 
+const ChatMessageMessageSchema = z.object({
+	type: z.literal('chat_message'),
+	session_id: UUIDSchema,
+	body: z.string(),
+	display_name: z.string().optional(),
+	access_token: z.string().optional(),
+});
+
 const PingMessageSchema = z.object({
 	type: z.literal('ping'),
 });
@@ -29,6 +40,7 @@ export const ClientMessageSchema = z.discriminatedUnion('type', [
 	SubscribeMessageSchema,
 	MakePickMessageSchema,
 	ProposeTradeMessageSchema,
+	ChatMessageMessageSchema,
 	PingMessageSchema,
 ]);
 export type ClientMessage = z.infer<typeof ClientMessageSchema>;
@@ -39,9 +51,34 @@ const SubscribedMessageSchema = z.object({
 	session_id: UUIDSchema,
 });
 
-const PickMadeMessageSchema = z.object({
-	type: z.literal('pick_made'),
+const SyncedEventSchema = z.object({
+	id: UUIDSchema,
+	event_type: z.string(),
+	event_data: z.unknown(),
+	created_at: z.string(),
+});
+
+const PendingTradeSchema = z.object({
+	trade_id: UUIDSchema,
+	from_team_id: UUIDSchema,
+	to_team_id: UUIDSchema,
+	from_team_picks: z.array(UUIDSchema),
+	to_team_picks: z.array(UUIDSchema),
+	from_team_value: z.number(),
+	to_team_value: z.number(),
+});
+
+const StateSyncMessageSchema = z.object({
+	type: z.literal('state_sync'),
 	session_id: UUIDSchema,
+	status: z.string(),
+	current_pick_number: z.number(),
+	time_remaining: z.number(),
+	recent_events: z.array(SyncedEventSchema),
+	pending_trades: z.array(PendingTradeSchema),
+});
+
+export const BatchedPickSchema = z.object({
 	pick_id: UUIDSchema,
 	team_id: UUIDSchema,
 	player_id: UUIDSchema,
@@ -50,6 +87,18 @@ const PickMadeMessageSchema = z.object({
 	player_name: z.string(),
 	team_name: z.string(),
 });
+export type BatchedPick = z.infer<typeof BatchedPickSchema>;
+
+const PickMadeMessageSchema = BatchedPickSchema.extend({
+	type: z.literal('pick_made'),
+	session_id: UUIDSchema,
+});
+
+const PicksBatchMessageSchema = z.object({
+	type: z.literal('picks_batch'),
+	session_id: UUIDSchema,
+	picks: z.array(BatchedPickSchema),
+});
 
 const ClockUpdateMessageSchema = z.object({
 	type: z.literal('clock_update'),
@@ -58,6 +107,24 @@ const ClockUpdateMessageSchema = z.object({
 	current_pick_number: z.number(),
 });
 
+const ClockTickMessageSchema = z.object({
+	type: z.literal('clock_tick'),
+	session_id: UUIDSchema,
+	time_remaining: z.number(),
+	current_pick_number: z.number(),
+});
+
+export const ClockWarningLevelSchema = z.enum(['two_minutes', 'thirty_seconds']);
+export type ClockWarningLevel = z.infer<typeof ClockWarningLevelSchema>;
+
+const ClockWarningMessageSchema = z.object({
+	type: z.literal('clock_warning'),
+	session_id: UUIDSchema,
+	level: ClockWarningLevelSchema,
+	time_remaining: z.number(),
+	current_pick_number: z.number(),
+});
+
 const DraftStatusMessageSchema = z.object({
 	type: z.literal('draft_status'),
 	session_id: UUIDSchema,
@@ -93,9 +160,57 @@ const TradeRejectedMessageSchema = z.object({
 	rejecting_team_id: UUIDSchema,
 });
 
+const SessionRewoundMessageSchema = z.object({
+	type: z.literal('session_rewound'),
+	session_id: UUIDSchema,
+	current_pick_number: z.number(),
+	reversed_trade_ids: z.array(UUIDSchema),
+});
+
+export const ChatMessageBroadcastSchema = z.object({
+	type: z.literal('chat_message'),
+	session_id: UUIDSchema,
+	message_id: UUIDSchema,
+	participant_id: UUIDSchema.nullable(),
+	display_name: z.string(),
+	body: z.string(),
+	sent_at: z.string(),
+});
+export type ChatMessageBroadcast = z.infer<typeof ChatMessageBroadcastSchema>;
+
+export const SessionPresenceSchema = z.object({
+	connection_count: z.number(),
+	anonymous_count: z.number(),
+	display_names: z.array(z.string()),
+});
+export type SessionPresence = z.infer<typeof SessionPresenceSchema>;
+
+const PresenceMessageSchema = SessionPresenceSchema.extend({
+	type: z.literal('presence'),
+	session_id: UUIDSchema,
+});
+
+export const AdminAnnouncementSchema = z.object({
+	type: z.literal('admin_announcement'),
+	session_id: UUIDSchema,
+	message: z.string(),
+	sent_at: z.string(),
+});
+export type AdminAnnouncement = z.infer<typeof AdminAnnouncementSchema>;
+
+export const ErrorCodeSchema = z.enum([
+	'rate_limited',
+	'invalid_message',
+	'not_subscribed',
+	'unsupported',
+]);
+export type ErrorCode = z.infer<typeof ErrorCodeSchema>;
+
 const ErrorMessageSchema = z.object({
 	type: z.literal('error'),
 	message: z.string(),
+	code: ErrorCodeSchema.optional(),
+	retry_after_ms: z.number().optional(),
 });
 
 const PongMessageSchema = z.object({
@@ -104,12 +219,20 @@ const PongMessageSchema = z.object({
 
 export const ServerMessageSchema = z.discriminatedUnion('type', [
 	SubscribedMessageSchema,
+	StateSyncMessageSchema,
 	PickMadeMessageSchema,
+	PicksBatchMessageSchema,
 	ClockUpdateMessageSchema,
+	ClockTickMessageSchema,
+	ClockWarningMessageSchema,
 	DraftStatusMessageSchema,
 	TradeProposedMessageSchema,
 	TradeExecutedMessageSchema,
 	TradeRejectedMessageSchema,
+	SessionRewoundMessageSchema,
+	ChatMessageBroadcastSchema,
+	PresenceMessageSchema,
+	AdminAnnouncementSchema,
 	ErrorMessageSchema,
 	PongMessageSchema,
 ]);
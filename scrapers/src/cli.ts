@@ -14,35 +14,144 @@ function hasFlag(name: string): boolean {
   return args.includes(name);
 }
 
+/** Reads a duration flag such as `30m`; `0` is allowed */
+async function getDuration(name: string, defaultValue: string): Promise<number> {
+  const value = getArg(name, defaultValue);
+  if (value === "0") return 0;
+  const { parseInterval } = await import("./shared/daemon.js");
+  return parseInterval(value);
+}
+
+/**
+ * Runs a scraper once, or on a schedule with --daemon, reporting how each
+ * run's output moved from the previous one. With --push, a one-off run's
+ * output is then POSTed to the API's seed endpoint.
+ */
+async function runScraper(
+  name: string,
+  output: string,
+  endpoint: string,
+  scrape: () => Promise<void>,
+): Promise<void> {
+  const { parseChangeLimit, runWithDiffReport } = await import("./shared/diff-report.js");
+  const maxChanges = getArg("--max-changes", "");
+  const maxMove = getArg("--max-move", "");
+  if (maxMove && !/^\d+$/.test(maxMove)) {
+    throw new Error(`Invalid --max-move '${maxMove}'. Use a number of spots, e.g. 20`);
+  }
+  const diffOptions = {
+    reportPath: getArg("--diff-report", "") || undefined,
+    maxChanges: maxChanges ? parseChangeLimit(maxChanges) : undefined,
+    maxMove: maxMove ? parseInt(maxMove, 10) : undefined,
+  };
+  const run = () => runWithDiffReport(output, diffOptions, scrape);
+
+  const { parseInterval, pushOutput, runDaemon } = await import("./shared/daemon.js");
+  const apiUrl = getArg("--api-url", "");
+  const apiKey = getArg("--api-key", process.env.SEED_API_KEY ?? "");
+  if (apiUrl && !apiKey) {
+    throw new Error("--api-url needs --api-key or the SEED_API_KEY environment variable");
+  }
+  const push = hasFlag("--push");
+  if (push && !apiUrl) {
+    throw new Error("--push needs --api-url");
+  }
+
+  if (!hasFlag("--daemon")) {
+    await run();
+    if (push) {
+      const message = await pushOutput({ apiUrl, endpoint, apiKey }, output);
+      console.error(
+        message === null
+          ? "\nNo new data to push; the output is missing or template data."
+          : `\nPushed to ${endpoint}: ${message}`,
+      );
+    }
+    return;
+  }
+
+  await runDaemon(
+    {
+      name,
+      intervalMs: parseInterval(getArg("--interval", "6h")),
+      output,
+      seed: apiUrl ? { apiUrl, endpoint, apiKey } : undefined,
+    },
+    run,
+  );
+}
+
 async function main() {
+  const positionMap = getArg("--position-map", "");
+  if (positionMap) {
+    const { loadPositionMap } = await import("./shared/position-normalizer.js");
+    loadPositionMap(positionMap);
+  }
+
+  const userAgent = getArg("--user-agent", process.env.SCRAPER_USER_AGENT ?? "");
+  const proxy = getArg("--proxy", process.env.SCRAPER_PROXY ?? "");
+  if (userAgent || proxy) {
+    const { configureRequestOptions, parseProxies, parseUserAgents } = await import(
+      "./shared/request-options.js"
+    );
+    configureRequestOptions({
+      userAgents: parseUserAgents(userAgent),
+      proxies: parseProxies(proxy),
+    });
+  }
+
+  const { configureRetry } = await import("./shared/retry.js");
+  configureRetry({
+    attempts: Math.max(1, parseInt(getArg("--attempts", "3"), 10) || 1),
+    backoffMs: await getDuration("--backoff", "1s"),
+    hostDelayMs: await getDuration("--host-delay", "0"),
+  });
+
+  // --max-age on its own also turns the cache on
+  if (hasFlag("--cache") || getArg("--max-age", "")) {
+    const { configureHttpCache } = await import("./shared/http-cache.js");
+    configureHttpCache({
+      dir: getArg("--cache-dir", ".cache/http"),
+      maxAgeMs: await getDuration("--max-age", "1h"),
+    });
+  }
+
   switch (command) {
     case "draft-order": {
       const { runDraftOrderCommand } = await import("./commands/draft-order.js");
-      await runDraftOrderCommand({
+      const options = {
         year: parseInt(getArg("--year", "2026"), 10),
         output: getArg("--output", "../back-end/data/draft_order_2026.json"),
         template: hasFlag("--template"),
         allowTemplateFallback: hasFlag("--allow-template-fallback"),
-      });
+      };
+      await runScraper("draft-order", options.output, "/api/v1/admin/seed-draft-order", () =>
+        runDraftOrderCommand(options),
+      );
       break;
     }
 
     case "rankings": {
       const { runRankingsCommand } = await import("./commands/rankings.js");
-      await runRankingsCommand({
+      const options = {
         year: parseInt(getArg("--year", "2026"), 10),
         output: getArg("--output", "../back-end/data/prospect_rankings_2026.json"),
         template: hasFlag("--template"),
         source: getArg("--source", "tankathon"),
         merge: hasFlag("--merge"),
+        strategy: getArg("--strategy", "append"),
+        weights: getArg("--weights", ""),
         allowTemplateFallback: hasFlag("--allow-template-fallback"),
-      });
+      };
+      await runScraper("rankings", options.output, "/api/v1/admin/seed-rankings", () =>
+        runRankingsCommand(options),
+      );
       break;
     }
 
     case "combine": {
       const { runCombineCommand } = await import("./commands/combine.js");
-      await runCombineCommand({
+      const options = {
         year: parseInt(getArg("--year", "2026"), 10),
         output: getArg("--output", "../back-end/data/combine_2026.json"),
         template: hasFlag("--template"),
@@ -50,7 +159,26 @@ async function main() {
         merge: hasFlag("--merge"),
         allowTemplateFallback: hasFlag("--allow-template-fallback"),
         force: hasFlag("--force"),
-      });
+        ifPublished: hasFlag("--if-published"),
+      };
+      await runScraper("combine", options.output, "/api/v1/admin/seed-combine-data", () =>
+        runCombineCommand(options),
+      );
+      break;
+    }
+
+    case "pro-day": {
+      const { runProDayCommand } = await import("./commands/pro-day.js");
+      const options = {
+        year: parseInt(getArg("--year", "2026"), 10),
+        output: getArg("--output", "../back-end/data/pro_days_2026.json"),
+        url: getArg("--url", ""),
+        schools: getArg("--school", "").split(","),
+        force: hasFlag("--force"),
+      };
+      await runScraper("pro-day", options.output, "/api/v1/admin/seed-combine-data", () =>
+        runProDayCommand(options),
+      );
       break;
     }
 
@@ -72,15 +200,38 @@ async function main() {
       console.error("  draft-order    Scrape NFL draft order from Tankathon");
       console.error("  rankings       Scrape prospect rankings");
       console.error("  combine        Scrape NFL Combine data");
+      console.error("  pro-day        Scrape school pro day results");
       console.error("  the-beast      Scrape Dane Brugler's The Beast 2026 PDF");
       console.error("");
       console.error("Options:");
       console.error("  --year <year>       Draft year (default: 2026)");
       console.error("  --output <path>     Output file path");
       console.error("  --template          Generate template without scraping");
-      console.error("  --source <name>     Source (rankings: tankathon|drafttek|walterfootball; combine: pfr|mockdraftable|nflverse|nfl-com|nflcombineresults)");
+      console.error("  --source <name>     Source (rankings: tankathon|drafttek|walterfootball|espn|pff|cbssports; combine: pfr|mockdraftable|nflverse|nfl-com|nflcombineresults)");
       console.error("  --merge             Merge data from all sources");
+      console.error("  --strategy <name>   Rankings merge: append (default) or weighted-average");
+      console.error("  --weights <list>    Source weights for weighted-average, e.g. tankathon=2,pff=1.5");
       console.error("  --force             Write output even if validation fails");
+      console.error("  --url <url>         Pro day: index page listing each school's pro day");
+      console.error("  --school <list>     Pro day: only these schools, comma-separated, e.g. Iowa,Boise State");
+      console.error("  --if-published      Combine: keep mock data in place until the year's real results are out");
+      console.error("  --position-map <path>  JSON file of extra position aliases and a fallback");
+      console.error("  --cache             Cache fetched pages on disk and revalidate them with ETag/Last-Modified");
+      console.error("  --max-age <time>    Reuse cached pages younger than this without a request (default: 1h; 0 always revalidates)");
+      console.error("  --cache-dir <path>  Cache directory (default: .cache/http)");
+      console.error("  --attempts <n>      Tries per request before giving up (default: 3)");
+      console.error("  --backoff <time>    First retry delay, doubling with jitter (default: 1s)");
+      console.error("  --host-delay <time> Minimum gap between requests to one host, e.g. 500ms (default: 0)");
+      console.error("  --user-agent <ua>   User agent for every request; several separated by |, or 'rotate' (env: SCRAPER_USER_AGENT)");
+      console.error("  --proxy <url>       Proxy for every request; several separated by commas rotate (env: SCRAPER_PROXY)");
+      console.error("  --diff-report <path>  Also write the changes since the previous output as JSON");
+      console.error("  --max-changes <n>   Fail when more prospects than this are added or dropped, e.g. 25 or 10%");
+      console.error("  --max-move <spots>  Fail when any prospect rises or falls more than this many spots");
+      console.error("  --push              POST the scraped file to the API's seed endpoint; needs --api-url (all but the-beast)");
+      console.error("  --daemon            Keep running, re-scraping on a schedule (all but the-beast)");
+      console.error("  --interval <time>   Time between daemon runs, e.g. 30m, 6h, 1d (default: 6h)");
+      console.error("  --api-url <url>     API to POST to with --push, or after each daemon run");
+      console.error("  --api-key <key>     Seed API key (default: SEED_API_KEY environment variable)");
       process.exit(1);
   }
 }
@@ -1,4 +1,9 @@
-import { writeJsonFile, shouldPreventOverwrite } from "../shared/json-writer.js";
+import {
+  holdsTemplateData,
+  isTemplateData,
+  shouldPreventOverwrite,
+  writeJsonFile,
+} from "../shared/json-writer.js";
 import { generateTemplateCombine } from "../scrapers/combine/template.js";
 import { scrapePfr } from "../scrapers/combine/pfr.js";
 import { scrapeMockdraftable } from "../scrapers/combine/mockdraftable.js";
@@ -9,6 +14,8 @@ import { mergeCombineData } from "../scrapers/combine/merge.js";
 import { validateCombineData } from "../shared/combine-validator.js";
 import type { CombineData } from "../types/combine.js";
 
+export const COMBINE_SOURCES = ["pfr", "mockdraftable", "nflverse", "nfl-com", "nflcombineresults"];
+
 export interface CombineOptions {
   year: number;
   output: string;
@@ -17,6 +24,11 @@ export interface CombineOptions {
   merge?: boolean;
   allowTemplateFallback?: boolean;
   force?: boolean;
+  /**
+   * While the output is missing or holds mock data, leave it untouched
+   * until the year's real results are published instead of failing
+   */
+  ifPublished?: boolean;
 }
 
 export async function runCombineCommand(options: CombineOptions): Promise<void> {
@@ -28,23 +40,48 @@ export async function runCombineCommand(options: CombineOptions): Promise<void>
     merge = false,
     allowTemplateFallback = false,
     force = false,
+    ifPublished = false,
   } = options;
 
   console.error("NFL Combine Data Scraper");
   console.error(`Year: ${year}`);
   console.error(`Output: ${output}`);
 
+  if (!template && !merge && !COMBINE_SOURCES.includes(source)) {
+    throw new Error(`Unknown source: ${source}. Use ${COMBINE_SOURCES.join(", ")}`);
+  }
+
   let data: CombineData;
 
   if (template) {
     console.error("\nGenerating template combine data...");
     data = generateTemplateCombine(year);
-  } else if (merge) {
-    console.error("\nMerging combine data from multiple sources...");
-    data = await scrapeAndMerge(year, allowTemplateFallback);
   } else {
-    console.error(`\nScraping from: ${source}`);
-    data = await scrapeSource(source, year);
+    // Before the combine, sources have nothing for the year yet
+    const waiting = ifPublished && holdsTemplateData(output);
+    try {
+      if (merge) {
+        console.error("\nMerging combine data from multiple sources...");
+        data = await scrapeAndMerge(year, allowTemplateFallback && !waiting);
+      } else {
+        console.error(`\nScraping from: ${source}`);
+        data = await scrapeSource(source, year);
+      }
+    } catch (err) {
+      if (!waiting) throw err;
+      const message = err instanceof Error ? err.message : String(err);
+      console.error(`\n${message}`);
+      console.error(`No ${year} combine results published yet; leaving '${output}' unchanged.`);
+      return;
+    }
+
+    if (waiting && !hasPublishedResults(data)) {
+      console.error(
+        `\nOnly ${data.combine_results.length} ${year} combine results found; ` +
+          `waiting for the full results before replacing '${output}'.`,
+      );
+      return;
+    }
   }
 
   // Safety guard
@@ -87,6 +124,15 @@ export async function runCombineCommand(options: CombineOptions): Promise<void>
   console.error(`\nWrote combine data to: ${output}`);
 }
 
+/**
+ * Whether scraped data is the year's real results: not template data and
+ * complete enough to pass validation
+ */
+export function hasPublishedResults(data: CombineData): boolean {
+  if (isTemplateData({ meta: { source: data.meta.source } })) return false;
+  return validateCombineData(data).errors.length === 0;
+}
+
 async function scrapeSource(source: string, year: number): Promise<CombineData> {
   switch (source) {
     case "pfr":
@@ -100,9 +146,7 @@ async function scrapeSource(source: string, year: number): Promise<CombineData>
     case "nflcombineresults":
       return await scrapeNflCombineResults(year);
     default:
-      throw new Error(
-        `Unknown source: ${source}. Use pfr, mockdraftable, nflverse, nfl-com, or nflcombineresults`,
-      );
+      throw new Error(`Unknown source: ${source}. Use ${COMBINE_SOURCES.join(", ")}`);
   }
 }
 
@@ -0,0 +1,46 @@
+import { writeJsonFile } from "../shared/json-writer.js";
+import { scrapeProDays } from "../scrapers/pro-day/index.js";
+import { validateCombineData } from "../shared/combine-validator.js";
+
+export interface ProDayOptions {
+  year: number;
+  output: string;
+  /** Pro day index page; defaults to the built-in tracker */
+  url?: string;
+  /** Only scrape these schools, e.g. for a pro day held today */
+  schools?: string[];
+  force?: boolean;
+}
+
+export async function runProDayCommand(options: ProDayOptions): Promise<void> {
+  const { year, output, url, schools = [], force = false } = options;
+
+  console.error("NFL Pro Day Scraper");
+  console.error(`Year: ${year}`);
+  console.error(`Output: ${output}`);
+
+  const data = await scrapeProDays(year, { url, schools });
+
+  // A single school has a few dozen players at most
+  const validation = validateCombineData(data, { minPlayerCount: 1 });
+  for (const warning of validation.warnings) {
+    console.error(`WARNING: ${warning}`);
+  }
+  for (const error of validation.errors) {
+    console.error(`VALIDATION ERROR: ${error}`);
+  }
+  if (validation.errors.length > 0 && !force) {
+    throw new Error(
+      `Data quality validation failed with ${validation.errors.length} error(s). ` +
+        "Pass --force to write anyway.",
+    );
+  }
+
+  console.error("\nPro day data summary:");
+  console.error(`  Year: ${data.meta.year}`);
+  console.error(`  Players: ${data.meta.player_count}`);
+  console.error(`  Entries: ${data.meta.entry_count}`);
+
+  writeJsonFile(output, data);
+  console.error(`\nWrote pro day data to: ${output}`);
+}
@@ -3,7 +3,16 @@ import { generateTemplateRankings } from "../scrapers/rankings/template.js";
 import { scrapeTankathonRankings } from "../scrapers/rankings/tankathon.js";
 import { scrapeDraftTek } from "../scrapers/rankings/drafttek.js";
 import { scrapeWalterFootball } from "../scrapers/rankings/walterfootball.js";
-import { mergeRankings } from "../scrapers/rankings/merge.js";
+import { scrapeEspn } from "../scrapers/rankings/espn.js";
+import { scrapePff } from "../scrapers/rankings/pff.js";
+import { scrapeCbsSports } from "../scrapers/rankings/cbssports.js";
+import {
+  mergeRankings,
+  parseWeights,
+  MERGE_STRATEGIES,
+  type MergeOptions,
+  type MergeStrategy,
+} from "../scrapers/rankings/merge.js";
 import type { RankingData } from "../types/rankings.js";
 
 export interface RankingsOptions {
@@ -12,6 +21,8 @@ export interface RankingsOptions {
   template?: boolean;
   source?: string;
   merge?: boolean;
+  strategy?: string;
+  weights?: string;
   allowTemplateFallback?: boolean;
 }
 
@@ -22,9 +33,19 @@ export async function runRankingsCommand(options: RankingsOptions): Promise<void
     template = false,
     source = "tankathon",
     merge = false,
+    strategy = "append",
+    weights = "",
     allowTemplateFallback = false,
   } = options;
 
+  if (!MERGE_STRATEGIES.includes(strategy as MergeStrategy)) {
+    throw new Error(`Unknown merge strategy: ${strategy}. Use ${MERGE_STRATEGIES.join(" or ")}`);
+  }
+  const mergeOptions: MergeOptions = {
+    strategy: strategy as MergeStrategy,
+    weights: parseWeights(weights),
+  };
+
   console.error("NFL Prospect Rankings Scraper");
   console.error(`Year: ${year}`);
   console.error(`Output: ${output}`);
@@ -35,8 +56,8 @@ export async function runRankingsCommand(options: RankingsOptions): Promise<void
     console.error("\nGenerating template rankings...");
     data = generateTemplateRankings(year);
   } else if (merge) {
-    console.error("\nMerging rankings from multiple sources...");
-    data = await scrapeAndMerge(year);
+    console.error(`\nMerging rankings from multiple sources (${strategy})...`);
+    data = await scrapeAndMerge(year, mergeOptions);
   } else {
     console.error(`\nScraping from: ${source}`);
     data = await scrapeSource(source, year);
@@ -73,8 +94,17 @@ async function scrapeSource(source: string, year: number): Promise<RankingData>
         return await scrapeDraftTek(year);
       case "walterfootball":
         return await scrapeWalterFootball(year);
+      case "espn":
+        return await scrapeEspn(year);
+      case "pff":
+        return await scrapePff(year);
+      case "cbssports":
+        return await scrapeCbsSports(year);
       default:
-        throw new Error(`Unknown source: ${source}. Use tankathon, drafttek, or walterfootball`);
+        throw new Error(
+          `Unknown source: ${source}. ` +
+            "Use tankathon, drafttek, walterfootball, espn, pff, or cbssports",
+        );
     }
   } catch (err) {
     const message = err instanceof Error ? err.message : String(err);
@@ -84,13 +114,13 @@ async function scrapeSource(source: string, year: number): Promise<RankingData>
   }
 }
 
-async function scrapeAndMerge(year: number): Promise<RankingData> {
+async function scrapeAndMerge(year: number, options: MergeOptions): Promise<RankingData> {
   let primary: RankingData;
   const secondaries: RankingData[] = [];
 
   // Primary: Tankathon
   try {
-    console.error("\n[1/3] Scraping Tankathon (primary)...");
+    console.error("\n[1/6] Scraping Tankathon (primary)...");
     primary = await scrapeTankathonRankings(year);
   } catch (err) {
     const message = err instanceof Error ? err.message : String(err);
@@ -101,7 +131,7 @@ async function scrapeAndMerge(year: number): Promise<RankingData> {
 
   // Secondary: DraftTek
   try {
-    console.error("\n[2/3] Scraping DraftTek...");
+    console.error("\n[2/6] Scraping DraftTek...");
     const drafttek = await scrapeDraftTek(year);
     if (drafttek.rankings.length > 0) {
       secondaries.push(drafttek);
@@ -113,7 +143,7 @@ async function scrapeAndMerge(year: number): Promise<RankingData> {
 
   // Secondary: WalterFootball
   try {
-    console.error("\n[3/3] Scraping WalterFootball...");
+    console.error("\n[3/6] Scraping WalterFootball...");
     const wf = await scrapeWalterFootball(year);
     if (wf.rankings.length > 0) {
       secondaries.push(wf);
@@ -123,5 +153,41 @@ async function scrapeAndMerge(year: number): Promise<RankingData> {
     console.error(`WalterFootball failed: ${message}`);
   }
 
-  return mergeRankings(primary, secondaries);
+  // Secondary: ESPN
+  try {
+    console.error("\n[4/6] Scraping ESPN...");
+    const espn = await scrapeEspn(year);
+    if (espn.rankings.length > 0) {
+      secondaries.push(espn);
+    }
+  } catch (err) {
+    const message = err instanceof Error ? err.message : String(err);
+    console.error(`ESPN failed: ${message}`);
+  }
+
+  // Secondary: PFF
+  try {
+    console.error("\n[5/6] Scraping PFF...");
+    const pff = await scrapePff(year);
+    if (pff.rankings.length > 0) {
+      secondaries.push(pff);
+    }
+  } catch (err) {
+    const message = err instanceof Error ? err.message : String(err);
+    console.error(`PFF failed: ${message}`);
+  }
+
+  // Secondary: CBS Sports
+  try {
+    console.error("\n[6/6] Scraping CBS Sports...");
+    const cbs = await scrapeCbsSports(year);
+    if (cbs.rankings.length > 0) {
+      secondaries.push(cbs);
+    }
+  } catch (err) {
+    const message = err instanceof Error ? err.message : String(err);
+    console.error(`CBS Sports failed: ${message}`);
+  }
+
+  return mergeRankings(primary, secondaries, options);
 }
@@ -1,5 +1,6 @@
 import type { CombineData } from "../../types/combine.js";
 import { extractInitialState, parseInitialState } from "./mockdraftable-parser.js";
+import { cachedFetch } from "../../shared/http-cache.js";
 
 export function combineUrl(year: number): string {
   return `https://www.mockdraftable.com/search?year=${year}&beginYear=${year}&endYear=${year}&sort=name`;
@@ -9,7 +10,7 @@ export async function scrapeMockdraftable(year: number): Promise<CombineData> {
   const url = combineUrl(year);
   console.error(`Fetching Mockdraftable combine data from: ${url}`);
 
-  const response = await fetch(url, {
+  const response = await cachedFetch(url, {
     headers: {
       "User-Agent":
         "Mozilla/5.0 (Macintosh; Intel Mac OS X 10_15_7) AppleWebKit/537.36 (KHTML, like Gecko) Chrome/120.0.0.0 Safari/537.36",
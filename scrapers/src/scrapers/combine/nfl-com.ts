@@ -1,6 +1,8 @@
 import type { CombineData } from "../../types/combine.js";
 import { parseNflComApi, type NflComCombineProfile } from "./nfl-com-parser.js";
 import { launchBrowser, closeBrowser } from "../../shared/browser.js";
+import { browserUserAgent } from "../../shared/request-options.js";
+import { cachedFetch } from "../../shared/http-cache.js";
 
 // Year is unused — the page URL is static; the API handles year filtering separately.
 // Parameter kept for signature consistency with other combineUrl functions.
@@ -36,7 +38,7 @@ interface ApiResponse {
  */
 async function getAuthToken(): Promise<string> {
   const browser = await launchBrowser();
-  const page = await browser.newPage();
+  const page = await browser.newPage({ userAgent: browserUserAgent() });
 
   const tokenPromise = new Promise<string>((resolve) => {
     page.on("response", async (resp) => {
@@ -78,7 +80,7 @@ async function fetchRankings(
   rankAttribute: string,
 ): Promise<ApiProfile[]> {
   const url = `${API_BASE}?limit=500&rankAttribute=${rankAttribute}&sortOrder=ASC&year=${year}`;
-  const response = await fetch(url, {
+  const response = await cachedFetch(url, {
     headers: {
       Authorization: `Bearer ${token}`,
       "User-Agent":
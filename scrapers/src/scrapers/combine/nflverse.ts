@@ -1,5 +1,6 @@
 import type { CombineData } from "../../types/combine.js";
 import { parseNflverseCsv } from "./nflverse-parser.js";
+import { cachedFetch } from "../../shared/http-cache.js";
 
 export const combineUrl =
   "https://github.com/nflverse/nflverse-data/releases/download/combine/combine.csv";
@@ -7,7 +8,7 @@ export const combineUrl =
 export async function scrapeNflverse(year: number): Promise<CombineData> {
   console.error(`Fetching nflverse combine data from: ${combineUrl}`);
 
-  const response = await fetch(combineUrl, {
+  const response = await cachedFetch(combineUrl, {
     headers: {
       "User-Agent":
         "Mozilla/5.0 (Macintosh; Intel Mac OS X 10_15_7) AppleWebKit/537.36 (KHTML, like Gecko) Chrome/120.0.0.0 Safari/537.36",
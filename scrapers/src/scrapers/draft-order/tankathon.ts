@@ -1,5 +1,6 @@
 import type { DraftOrderData } from "../../types/draft-order.js";
 import { parseTankathonHtml } from "./parser.js";
+import { browserProxy, browserUserAgent } from "../../shared/request-options.js";
 
 export async function scrapeTankathon(year: number): Promise<DraftOrderData> {
   const { chromium } = await import("playwright");
@@ -12,9 +13,9 @@ export async function scrapeTankathon(year: number): Promise<DraftOrderData> {
   console.error(`Scraping Tankathon full draft order...`);
   console.error(`URL: ${url}`);
 
-  const browser = await chromium.launch({ headless: true });
+  const browser = await chromium.launch({ headless: true, proxy: browserProxy() });
   try {
-    const page = await browser.newPage();
+    const page = await browser.newPage({ userAgent: browserUserAgent() });
     await page.goto(url, { waitUntil: "domcontentloaded", timeout: 60000 });
     await page.waitForSelector("div.full-draft-round table.full-draft", { timeout: 30000 });
 
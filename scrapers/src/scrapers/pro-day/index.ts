@@ -0,0 +1,96 @@
+/**
+ * Driver for the pro day scraper. The index page lists each school's pro day
+ * with a link to its results page; every linked page is fetched and parsed
+ * into combine entries with `source: "pro_day"`. The parser logic is in
+ * `./parser.ts` so it remains pure and unit-testable.
+ */
+
+import type { CombineData, CombineEntry } from "../../types/combine.js";
+import { cachedFetch } from "../../shared/http-cache.js";
+import { nameKey } from "../../shared/name-normalizer.js";
+import { parseProDayIndex, parseProDayPage } from "./parser.js";
+
+export { parseProDayIndex, parseProDayPage } from "./parser.js";
+
+export function proDayIndexUrl(year: number): string {
+  return `https://nflcombineresults.com/prodays.php?year=${year}`;
+}
+
+export interface ScrapeProDaysOptions {
+  /** Index page listing each school's pro day (default: `proDayIndexUrl(year)`) */
+  url?: string;
+  /** Only scrape schools whose name contains one of these, case-insensitively */
+  schools?: string[];
+}
+
+async function fetchHtml(url: string): Promise<string> {
+  const response = await cachedFetch(url, {
+    headers: {
+      "User-Agent":
+        "Mozilla/5.0 (Macintosh; Intel Mac OS X 10_15_7) AppleWebKit/537.36 (KHTML, like Gecko) Chrome/120.0.0.0 Safari/537.36",
+    },
+    signal: AbortSignal.timeout(30000),
+  });
+  if (!response.ok) {
+    throw new Error(`HTTP ${response.status} fetching ${url}`);
+  }
+  return await response.text();
+}
+
+/**
+ * Scrape every school's pro day results for a year. A school page that fails
+ * to load is reported and skipped so one bad page doesn't lose the rest.
+ */
+export async function scrapeProDays(
+  year: number,
+  options: ScrapeProDaysOptions = {},
+): Promise<CombineData> {
+  const indexUrl = options.url || proDayIndexUrl(year);
+  console.error(`Fetching pro day index from: ${indexUrl}`);
+
+  let links = parseProDayIndex(await fetchHtml(indexUrl), indexUrl, year);
+  if (links.length === 0) {
+    throw new Error(
+      `No school pro day links found at ${indexUrl}. The page structure may have changed.`,
+    );
+  }
+
+  const filters = (options.schools ?? []).map((s) => s.trim().toLowerCase()).filter(Boolean);
+  if (filters.length > 0) {
+    links = links.filter((link) => filters.some((f) => link.school.toLowerCase().includes(f)));
+    if (links.length === 0) {
+      throw new Error(`No pro days found for: ${options.schools?.join(", ")}`);
+    }
+  }
+  console.error(`Found ${links.length} school pro days`);
+
+  const entries: CombineEntry[] = [];
+  let failed = 0;
+  for (const link of links) {
+    try {
+      const schoolEntries = parseProDayPage(await fetchHtml(link.url), year, link.event_date);
+      console.error(`  ${link.school}: ${schoolEntries.length} players`);
+      entries.push(...schoolEntries);
+    } catch (err) {
+      failed++;
+      const message = err instanceof Error ? err.message : String(err);
+      console.error(`  ${link.school} failed: ${message}`);
+    }
+  }
+
+  if (failed === links.length) {
+    throw new Error(`All ${failed} school pro day pages failed to load`);
+  }
+
+  return {
+    meta: {
+      source: "pro_day",
+      description: `${year} pro day results from ${links.length - failed} schools`,
+      year,
+      generated_at: new Date().toISOString(),
+      player_count: new Set(entries.map((e) => nameKey(e.first_name, e.last_name))).size,
+      entry_count: entries.length,
+    },
+    combine_results: entries,
+  };
+}
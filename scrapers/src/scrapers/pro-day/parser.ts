@@ -0,0 +1,262 @@
+import * as cheerio from "cheerio";
+import type { CombineEntry } from "../../types/combine.js";
+import { makeCombineEntry } from "../../shared/combine-helpers.js";
+import { normalizePosition } from "../../shared/position-normalizer.js";
+import { splitName } from "../../shared/name-normalizer.js";
+
+/** A school's pro day as listed on the index page. */
+export interface ProDayLink {
+  school: string;
+  url: string;
+  /** YYYY-MM-DD, or null when the index doesn't give a date */
+  event_date: string | null;
+}
+
+type Measurement = Exclude<
+  keyof CombineEntry,
+  "first_name" | "last_name" | "position" | "source" | "year" | "event_date"
+>;
+
+/** Header text patterns mapped to CombineEntry field names, checked in order. */
+const HEADER_MAP: [string, Measurement][] = [
+  ["10 yard", "ten_yard_split"],
+  ["10yd", "ten_yard_split"],
+  ["20 yard split", "twenty_yard_split"],
+  ["20yd split", "twenty_yard_split"],
+  ["shuttle", "twenty_yard_shuttle"],
+  ["40", "forty_yard_dash"],
+  ["forty", "forty_yard_dash"],
+  ["bench", "bench_press"],
+  ["vert", "vertical_jump"],
+  ["broad", "broad_jump"],
+  ["cone", "three_cone_drill"],
+  ["arm", "arm_length"],
+  ["hand", "hand_size"],
+  ["wing", "wingspan"],
+];
+
+/** Header substrings to skip (prevents false matches with measurement patterns). */
+const SKIP_PATTERNS = ["60", "height", "weight", "school", "college"];
+
+const INCH_FIELDS = new Set<Measurement>(["arm_length", "hand_size", "wingspan", "vertical_jump"]);
+
+const MONTHS = [
+  "jan",
+  "feb",
+  "mar",
+  "apr",
+  "may",
+  "jun",
+  "jul",
+  "aug",
+  "sep",
+  "oct",
+  "nov",
+  "dec",
+];
+
+function isBlank(s: string): boolean {
+  const trimmed = s.trim();
+  return !trimmed || trimmed === "-" || trimmed === "—" || /^(N\/A|DNP)$/i.test(trimmed);
+}
+
+function parseTime(s: string): number | null {
+  if (isBlank(s)) return null;
+  const num = parseFloat(s.trim());
+  return isNaN(num) ? null : num;
+}
+
+/** Parse inches with an optional fraction: `32 1/4` -> 32.25, `9.5"` -> 9.5. */
+export function parseInches(s: string): number | null {
+  if (isBlank(s)) return null;
+  const trimmed = s.trim().replace(/["”]|in\.?$/g, "").trim();
+  const fraction = trimmed.match(/^(\d+)\s+(\d+)\/(\d+)$/);
+  if (fraction) {
+    const den = parseInt(fraction[3], 10);
+    if (den === 0) return null;
+    return parseInt(fraction[1], 10) + parseInt(fraction[2], 10) / den;
+  }
+  const num = parseFloat(trimmed);
+  return isNaN(num) ? null : num;
+}
+
+/** Parse a broad jump in inches or feet and inches: `10'2"`, `10-02` -> 122. */
+export function parseBroadJump(s: string): number | null {
+  if (isBlank(s)) return null;
+  const trimmed = s.trim();
+  const feetInches = trimmed.match(/^(\d+)\s*(?:'|’|-|ft\.?)\s*(\d+)?/);
+  if (feetInches) {
+    return parseInt(feetInches[1], 10) * 12 + parseInt(feetInches[2] ?? "0", 10);
+  }
+  const num = parseFloat(trimmed);
+  return isNaN(num) ? null : Math.round(num);
+}
+
+/**
+ * Parse a pro day date into YYYY-MM-DD: `2026-03-12`, `3/12`, `3/12/2026`,
+ * `March 12` or `Thu, Mar. 12, 2026`. Dates without a year use `year`.
+ */
+export function parseEventDate(text: string, year: number): string | null {
+  const trimmed = text.trim();
+  if (!trimmed) return null;
+
+  let month: number | null = null;
+  let day: number | null = null;
+  let parsedYear = year;
+
+  const iso = trimmed.match(/(\d{4})-(\d{2})-(\d{2})/);
+  const numeric = trimmed.match(/^(\d{1,2})\/(\d{1,2})(?:\/(\d{2,4}))?$/);
+  const named = trimmed.toLowerCase().match(/([a-z]{3})[a-z]*\.?\s+(\d{1,2})(?:,?\s+(\d{4}))?/);
+
+  if (iso) {
+    parsedYear = parseInt(iso[1], 10);
+    month = parseInt(iso[2], 10);
+    day = parseInt(iso[3], 10);
+  } else if (numeric) {
+    month = parseInt(numeric[1], 10);
+    day = parseInt(numeric[2], 10);
+    if (numeric[3]) {
+      parsedYear = parseInt(numeric[3], 10);
+      if (parsedYear < 100) parsedYear += 2000;
+    }
+  } else if (named && MONTHS.includes(named[1])) {
+    month = MONTHS.indexOf(named[1]) + 1;
+    day = parseInt(named[2], 10);
+    if (named[3]) parsedYear = parseInt(named[3], 10);
+  }
+
+  if (month === null || day === null || month < 1 || month > 12 || day < 1 || day > 31) {
+    return null;
+  }
+  return `${parsedYear}-${String(month).padStart(2, "0")}-${String(day).padStart(2, "0")}`;
+}
+
+function normalizeHeader(text: string): string {
+  return text
+    .trim()
+    .toLowerCase()
+    .replace(/\(.*?\)/g, "")
+    .replace(/[^a-z0-9 ]/g, "")
+    .replace(/\s+/g, " ")
+    .trim();
+}
+
+/**
+ * Parse the pro day index into one link per school. Reads a table with a
+ * School column (and optionally a Date column), taking each row's first link.
+ */
+export function parseProDayIndex(html: string, indexUrl: string, year: number): ProDayLink[] {
+  const $ = cheerio.load(html);
+  const links: ProDayLink[] = [];
+  const seen = new Set<string>();
+
+  $("table").each((_, table) => {
+    const headers = $(table)
+      .find("tr")
+      .first()
+      .find("th, td")
+      .map((_, el) => normalizeHeader($(el).text()))
+      .get();
+    const schoolIdx = headers.findIndex((h) => h === "school" || h === "college");
+    if (schoolIdx < 0) return;
+    const dateIdx = headers.findIndex((h) => h === "date" || h === "pro day");
+
+    $(table)
+      .find("tr")
+      .slice(1)
+      .each((_, row) => {
+        const cells = $(row).find("td, th");
+        const school = cells.eq(schoolIdx).text().trim();
+        const href = $(row).find("a[href]").first().attr("href");
+        if (!school || !href) return;
+
+        const url = new URL(href, indexUrl).toString();
+        if (seen.has(url)) return;
+        seen.add(url);
+
+        const dateText = dateIdx >= 0 ? cells.eq(dateIdx).text() : "";
+        links.push({ school, url, event_date: parseEventDate(dateText, year) });
+      });
+  });
+
+  return links;
+}
+
+/**
+ * Parse a school's pro day results page into combine entries with
+ * `source: "pro_day"`. Columns are matched by header text; players with no
+ * recorded measurement are skipped. The page's own `<time datetime>` wins
+ * over the date from the index.
+ */
+export function parseProDayPage(
+  html: string,
+  year: number,
+  eventDate: string | null = null,
+): CombineEntry[] {
+  const $ = cheerio.load(html);
+
+  const pageDate = $("time[datetime]").first().attr("datetime");
+  const event_date = (pageDate && parseEventDate(pageDate, year)) || eventDate;
+
+  const entries: CombineEntry[] = [];
+
+  $("table").each((_, table) => {
+    const headers = $(table)
+      .find("tr")
+      .first()
+      .find("th, td")
+      .map((_, el) => normalizeHeader($(el).text()))
+      .get();
+
+    let nameIdx = -1;
+    let posIdx = -1;
+    const fieldMap = new Map<number, Measurement>();
+    headers.forEach((header, i) => {
+      if (header === "name" || header === "player") {
+        nameIdx = i;
+      } else if (header === "pos" || header === "position") {
+        posIdx = i;
+      } else if (!SKIP_PATTERNS.some((p) => header.includes(p))) {
+        const match = HEADER_MAP.find(([pattern]) => header.includes(pattern));
+        if (match && ![...fieldMap.values()].includes(match[1])) fieldMap.set(i, match[1]);
+      }
+    });
+    if (nameIdx < 0 || fieldMap.size === 0) return;
+
+    $(table)
+      .find("tr")
+      .slice(1)
+      .each((_, row) => {
+        const cells = $(row)
+          .find("td, th")
+          .map((_, el) => $(el).text().trim())
+          .get();
+        const playerText = cells[nameIdx];
+        if (!playerText) return;
+
+        const measurements: Partial<Record<Measurement, number | null>> = {};
+        for (const [colIdx, field] of fieldMap.entries()) {
+          const text = cells[colIdx] ?? "";
+          if (field === "broad_jump") {
+            measurements[field] = parseBroadJump(text);
+          } else if (field === "bench_press") {
+            const reps = parseTime(text);
+            measurements[field] = reps === null ? null : Math.round(reps);
+          } else {
+            measurements[field] = INCH_FIELDS.has(field) ? parseInches(text) : parseTime(text);
+          }
+        }
+        if (Object.values(measurements).every((v) => v == null)) return;
+
+        const [firstName, lastName] = splitName(playerText);
+        const position = normalizePosition(posIdx >= 0 ? (cells[posIdx] ?? "") : "");
+        entries.push({
+          ...makeCombineEntry(firstName, lastName, position, year, measurements),
+          source: "pro_day",
+          event_date,
+        });
+      });
+  });
+
+  return entries;
+}
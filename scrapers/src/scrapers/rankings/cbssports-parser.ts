@@ -0,0 +1,114 @@
+import * as cheerio from "cheerio";
+import type { RankingData, RankingEntry } from "../../types/rankings.js";
+import { normalizePosition } from "../../shared/position-normalizer.js";
+import { parseHeight, playerLinks, splitName } from "./helpers.js";
+
+export function cbsSportsRankingsUrl(year: number): string {
+  return `https://www.cbssports.com/nfl/draft/prospect-rankings/${year}/`;
+}
+
+export interface CbsSportsPage {
+  rankings: RankingEntry[];
+  /** Absolute URL of the next page, or null on the last page */
+  nextPage: string | null;
+}
+
+type Column = "rank" | "name" | "position" | "school" | "height" | "weight";
+
+// CBS Sports prospect rankings (2026 structure): table.TableBase-table with
+// a header row of RK | PLAYER | POS | SCHOOL | CLASS | HT | WT, 50 prospects
+// per page, and a "Next" link in the pagination below the table.
+const HEADERS: Record<string, Column> = {
+  RK: "rank",
+  RANK: "rank",
+  PLAYER: "name",
+  POS: "position",
+  SCHOOL: "school",
+  COLLEGE: "school",
+  HT: "height",
+  WT: "weight",
+};
+
+/**
+ * Parses one page of CBS Sports rankings. Positions use the site's own
+ * notation (EDGE, IOL, DL, ...) and go through the position normalizer.
+ */
+export function parseCbsSportsPage(html: string, pageUrl: string): CbsSportsPage {
+  const $ = cheerio.load(html);
+  const rankings: RankingEntry[] = [];
+
+  $("table").each((_, table) => {
+    const columns = new Map<Column, number>();
+    $(table)
+      .find("thead th")
+      .each((_, th) => {
+        const column = HEADERS[$(th).text().trim().toUpperCase()];
+        if (column && !columns.has(column)) columns.set(column, $(th).index());
+      });
+    if (!columns.has("rank") || !columns.has("name") || !columns.has("position")) return;
+
+    const cell = (cells: cheerio.Cheerio<cheerio.Element>, column: Column): string => {
+      const i = columns.get(column);
+      return i === undefined ? "" : $(cells[i]).text().replace(/\s+/g, " ").trim();
+    };
+
+    $(table)
+      .find("tbody tr")
+      .each((_, row) => {
+        const cells = $(row).find("td");
+        const rank = parseInt(cell(cells, "rank"), 10);
+        if (isNaN(rank)) return;
+
+        // The player cell holds a long and a short ("J. Smith") form of the
+        // name, both linking to the player's page, and their headshot
+        const nameCell = $(cells[columns.get("name")!]);
+        const longName = nameCell.find(".CellPlayerName--long a, .CellPlayerName--long").first();
+        const name = (longName.length ? longName.text() : nameCell.find("a").first().text())
+          .replace(/\s+/g, " ")
+          .trim();
+        const [firstName, lastName] = splitName(name || nameCell.text());
+        if (!firstName || !lastName) return;
+
+        const weight = parseInt(cell(cells, "weight"), 10);
+        rankings.push({
+          rank,
+          first_name: firstName,
+          last_name: lastName,
+          position: normalizePosition(cell(cells, "position")),
+          school: cell(cells, "school"),
+          height_inches: parseHeight(cell(cells, "height").replace(/\s+/g, "")),
+          weight_pounds: isNaN(weight) ? null : weight,
+          ...playerLinks(nameCell, pageUrl),
+        });
+      });
+  });
+
+  const next = $("a[rel='next'], .PaginationNav-next a, a.PaginationNav-next")
+    .first()
+    .attr("href");
+  return {
+    rankings,
+    nextPage: next ? new URL(next, pageUrl).toString() : null,
+  };
+}
+
+/** Combines pages into one board, keeping the first entry for each rank */
+export function buildCbsSportsData(pages: RankingEntry[][], year: number): RankingData {
+  const byRank = new Map<number, RankingEntry>();
+  for (const entry of pages.flat()) {
+    if (!byRank.has(entry.rank)) byRank.set(entry.rank, entry);
+  }
+  const rankings = [...byRank.values()].sort((a, b) => a.rank - b.rank);
+
+  return {
+    meta: {
+      version: "1.0.0",
+      source: "cbssports",
+      source_url: cbsSportsRankingsUrl(year),
+      draft_year: year,
+      scraped_at: new Date().toISOString().slice(0, 10),
+      total_prospects: rankings.length,
+    },
+    rankings,
+  };
+}
@@ -0,0 +1,57 @@
+import type { RankingData, RankingEntry } from "../../types/rankings.js";
+import {
+  buildCbsSportsData,
+  cbsSportsRankingsUrl,
+  parseCbsSportsPage,
+} from "./cbssports-parser.js";
+import { cachedFetch } from "../../shared/http-cache.js";
+
+/** Stops a pagination loop if the site keeps linking onward */
+const MAX_PAGES = 20;
+
+export async function scrapeCbsSports(year: number): Promise<RankingData> {
+  console.error(`Scraping CBS Sports prospect rankings...`);
+
+  const pages: RankingEntry[][] = [];
+  const visited = new Set<string>();
+  let url: string | null = cbsSportsRankingsUrl(year);
+
+  while (url && !visited.has(url) && pages.length < MAX_PAGES) {
+    visited.add(url);
+    console.error(`URL: ${url}`);
+
+    const response = await cachedFetch(url, {
+      headers: {
+        "User-Agent":
+          "Mozilla/5.0 (Macintosh; Intel Mac OS X 10_15_7) AppleWebKit/537.36 (KHTML, like Gecko) Chrome/120.0.0.0 Safari/537.36",
+      },
+      signal: AbortSignal.timeout(30000),
+    });
+
+    if (!response.ok) {
+      // A failed later page keeps the pages already fetched
+      if (pages.length > 0) {
+        console.error(`HTTP ${response.status} on page ${pages.length + 1}, stopping`);
+        break;
+      }
+      throw new Error(`HTTP ${response.status} fetching CBS Sports`);
+    }
+
+    const page = parseCbsSportsPage(await response.text(), url);
+    if (page.rankings.length === 0) break;
+
+    console.error(`  Page ${pages.length + 1}: ${page.rankings.length} prospects`);
+    pages.push(page.rankings);
+    url = page.nextPage;
+  }
+
+  const data = buildCbsSportsData(pages, year);
+
+  if (data.rankings.length === 0) {
+    console.error("WARNING: No prospects extracted from CBS Sports");
+  } else {
+    console.error(`Extracted ${data.rankings.length} prospects from ${pages.length} pages`);
+  }
+
+  return data;
+}
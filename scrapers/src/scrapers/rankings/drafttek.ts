@@ -1,5 +1,6 @@
 import type { RankingData, RankingEntry } from "../../types/rankings.js";
 import { parseDraftTekHtml } from "./drafttek-parser.js";
+import { cachedFetch } from "../../shared/http-cache.js";
 
 const BASE_URL = "https://www.drafttek.com/2026-NFL-Draft-Big-Board";
 const PAGES = [
@@ -12,7 +13,7 @@ const PAGES = [
 const POLITE_DELAY_MS = 500;
 
 async function fetchPage(url: string): Promise<string> {
-  const response = await fetch(url, {
+  const response = await cachedFetch(url, {
     headers: {
       "User-Agent":
         "Mozilla/5.0 (Macintosh; Intel Mac OS X 10_15_7) AppleWebKit/537.36 (KHTML, like Gecko) Chrome/120.0.0.0 Safari/537.36",
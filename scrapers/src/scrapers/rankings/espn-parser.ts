@@ -0,0 +1,98 @@
+import * as cheerio from "cheerio";
+import type { RankingData, RankingEntry } from "../../types/rankings.js";
+import { normalizePosition } from "../../shared/position-normalizer.js";
+import { parseHeight, playerLinks, splitName } from "./helpers.js";
+
+export function espnRankingsUrl(year: number): string {
+  return `https://www.espn.com/nfl/draft${year}/bestavailable`;
+}
+
+type Column = "rank" | "name" | "position" | "school" | "height" | "weight" | "grade";
+
+// ESPN's rankings table (2026 structure) has a header row of <th> cells:
+//   RK | PLAYER | POS | SCHOOL | HT | WT | GRADE
+// Columns are found by header text rather than position, since ESPN
+// drops HT/WT on some views.
+const HEADERS: Record<string, Column> = {
+  RK: "rank",
+  RANK: "rank",
+  PLAYER: "name",
+  NAME: "name",
+  POS: "position",
+  POSITION: "position",
+  SCHOOL: "school",
+  COLLEGE: "school",
+  HT: "height",
+  WT: "weight",
+  GRADE: "grade",
+  GRD: "grade",
+};
+
+function parseNumber(text: string): number | null {
+  const num = parseFloat(text.trim());
+  return isNaN(num) ? null : num;
+}
+
+export function parseEspnRankingsHtml(html: string, year: number): RankingData {
+  const $ = cheerio.load(html);
+  const rankings: RankingEntry[] = [];
+
+  $("table").each((_, table) => {
+    const columns = new Map<Column, number>();
+    $(table)
+      .find("thead th, tr:first-child th")
+      .each((_, th) => {
+        const column = HEADERS[$(th).text().trim().toUpperCase()];
+        if (column && !columns.has(column)) columns.set(column, $(th).index());
+      });
+    if (!columns.has("rank") || !columns.has("name") || !columns.has("position")) return;
+
+    const cell = (cells: cheerio.Cheerio<cheerio.Element>, column: Column): string => {
+      const i = columns.get(column);
+      return i === undefined ? "" : $(cells[i]).text().replace(/\s+/g, " ").trim();
+    };
+
+    $(table)
+      .find("tr")
+      .each((_, row) => {
+        const cells = $(row).find("td");
+        if (cells.length < columns.size) return;
+
+        const rank = parseInt(cell(cells, "rank"), 10);
+        if (isNaN(rank)) return;
+
+        // The player cell may hold the school too ("Name Ohio State"), so
+        // prefer the link text when there is one. The link goes to the
+        // player's ESPN page and the cell's image is their headshot.
+        const nameCell = $(cells[columns.get("name")!]);
+        const name = (nameCell.find("a").first().text() || nameCell.text()).trim();
+        const [firstName, lastName] = splitName(name);
+        if (!firstName || !lastName) return;
+
+        const weight = parseNumber(cell(cells, "weight"));
+        rankings.push({
+          rank,
+          first_name: firstName,
+          last_name: lastName,
+          position: normalizePosition(cell(cells, "position")),
+          school: cell(cells, "school"),
+          height_inches: parseHeight(cell(cells, "height").replace(/\s+/g, "")),
+          weight_pounds: weight === null ? null : Math.round(weight),
+          grade: parseNumber(cell(cells, "grade")),
+          ...playerLinks(nameCell, espnRankingsUrl(year)),
+        });
+      });
+  });
+
+  return {
+    meta: {
+      version: "1.0.0",
+      source: "espn",
+      source_url: espnRankingsUrl(year),
+      draft_year: year,
+      scraped_at: new Date().toISOString().slice(0, 10),
+      total_prospects: rankings.length,
+    },
+    rankings,
+  };
+}
@@ -0,0 +1,35 @@
+import type { RankingData } from "../../types/rankings.js";
+import { espnRankingsUrl, parseEspnRankingsHtml } from "./espn-parser.js";
+import { cachedFetch } from "../../shared/http-cache.js";
+
+export async function scrapeEspn(year: number): Promise<RankingData> {
+  const url = espnRankingsUrl(year);
+
+  console.error(`Scraping ESPN prospect rankings...`);
+  console.error(`URL: ${url}`);
+
+  const response = await cachedFetch(url, {
+    headers: {
+      "User-Agent":
+        "Mozilla/5.0 (Macintosh; Intel Mac OS X 10_15_7) AppleWebKit/537.36 (KHTML, like Gecko) Chrome/120.0.0.0 Safari/537.36",
+    },
+    signal: AbortSignal.timeout(30000),
+  });
+
+  if (!response.ok) {
+    throw new Error(`HTTP ${response.status} fetching ESPN`);
+  }
+
+  const html = await response.text();
+  console.error(`Fetched ${html.length} bytes of HTML`);
+
+  const data = parseEspnRankingsHtml(html, year);
+
+  if (data.rankings.length === 0) {
+    console.error("WARNING: No prospects extracted from ESPN");
+  } else {
+    console.error(`Extracted ${data.rankings.length} prospects`);
+  }
+
+  return data;
+}
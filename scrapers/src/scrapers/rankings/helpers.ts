@@ -1,3 +1,5 @@
+import type * as cheerio from "cheerio";
+
 /**
  * Parse height string to total inches.
  * Handles formats: "6-3", "6'3", "6'3\"", "6-3\"", "75"
@@ -57,3 +59,44 @@ export function splitName(fullName: string): [string, string] {
 
   return [first, parts.slice(1).join(" ")];
 }
+
+/**
+ * Resolves a link or image address from a page against the page's URL.
+ * Returns undefined for empty values and anything that isn't http(s),
+ * such as `data:` placeholders and `javascript:` links.
+ */
+export function absoluteUrl(href: string | undefined, pageUrl: string): string | undefined {
+  if (!href?.trim()) return undefined;
+  try {
+    const url = new URL(href.trim(), pageUrl);
+    return url.protocol === "https:" || url.protocol === "http:" ? url.toString() : undefined;
+  } catch {
+    return undefined;
+  }
+}
+
+export interface PlayerLinks {
+  profile_url?: string;
+  headshot_url?: string;
+}
+
+/** Link fields for a ranking entry, leaving out the ones not found */
+export function linkFields(profileUrl?: string, headshotUrl?: string): PlayerLinks {
+  const links: PlayerLinks = {};
+  if (profileUrl) links.profile_url = profileUrl;
+  if (headshotUrl) links.headshot_url = headshotUrl;
+  return links;
+}
+
+/**
+ * The player's profile page and headshot from the cell holding their name:
+ * the first link and the first image in it. Lazy-loaded images keep the
+ * real address in `data-src`.
+ */
+export function playerLinks(cell: cheerio.Cheerio<cheerio.Element>, pageUrl: string): PlayerLinks {
+  const img = cell.find("img").first();
+  return linkFields(
+    absoluteUrl(cell.find("a[href]").first().attr("href"), pageUrl),
+    absoluteUrl(img.attr("data-src") ?? img.attr("src"), pageUrl),
+  );
+}
@@ -2,8 +2,14 @@ export { parseHeight, parseRankNumber, splitName } from "./helpers.js";
 export { generateTemplateRankings } from "./template.js";
 export { parseDraftTekHtml } from "./drafttek-parser.js";
 export { parseWalterFootballHtml } from "./walterfootball-parser.js";
+export { parseEspnRankingsHtml } from "./espn-parser.js";
+export { parsePffBigBoard } from "./pff-parser.js";
+export { parseCbsSportsPage, buildCbsSportsData } from "./cbssports-parser.js";
 export { parseTankathonRankingsHtml } from "./tankathon-parser.js";
 export { mergeRankings } from "./merge.js";
 export { scrapeTankathonRankings } from "./tankathon.js";
 export { scrapeDraftTek } from "./drafttek.js";
 export { scrapeWalterFootball } from "./walterfootball.js";
+export { scrapeEspn } from "./espn.js";
+export { scrapePff } from "./pff.js";
+export { scrapeCbsSports } from "./cbssports.js";
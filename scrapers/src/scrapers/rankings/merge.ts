@@ -1,7 +1,82 @@
 import type { RankingData, RankingEntry } from "../../types/rankings.js";
 import { nameKey } from "../../shared/name-normalizer.js";
 
-export function mergeRankings(primary: RankingData, secondaries: RankingData[]): RankingData {
+/**
+ * `append` keeps the primary board's order and appends prospects only
+ * secondaries rank. `weighted-average` orders every prospect by their
+ * weighted average rank across the sources that rank them.
+ */
+export type MergeStrategy = "append" | "weighted-average";
+
+export const MERGE_STRATEGIES: readonly MergeStrategy[] = ["append", "weighted-average"];
+
+export interface MergeOptions {
+  strategy?: MergeStrategy;
+  /** Weight per source name for `weighted-average`; unlisted sources weigh 1 */
+  weights?: Record<string, number>;
+}
+
+/**
+ * Parses `--weights` such as `tankathon=2,pff=1.5`. A weight of 0 leaves
+ * a source out of the average.
+ */
+export function parseWeights(spec: string): Record<string, number> {
+  const weights: Record<string, number> = {};
+  for (const part of spec.split(",")) {
+    if (!part.trim()) continue;
+    const [source, value] = part.split("=").map((s) => s.trim());
+    const weight = Number(value);
+    if (!source || !value || isNaN(weight) || weight < 0) {
+      throw new Error(`Invalid weight '${part}'. Use source=weight, e.g. tankathon=2`);
+    }
+    weights[source] = weight;
+  }
+  return weights;
+}
+
+export function mergeRankings(
+  primary: RankingData,
+  secondaries: RankingData[],
+  options: MergeOptions = {},
+): RankingData {
+  const rankings =
+    options.strategy === "weighted-average"
+      ? weightedAverage([primary, ...secondaries], options.weights ?? {})
+      : append(primary, secondaries);
+
+  return {
+    meta: {
+      version: "1.0.0",
+      source: "merged",
+      source_url: primary.meta.source_url,
+      draft_year: primary.meta.draft_year,
+      scraped_at: new Date().toISOString().slice(0, 10),
+      total_prospects: rankings.length,
+    },
+    rankings,
+  };
+}
+
+/** Copies fields the merged entry lacks from another source's entry */
+function backfill(existing: RankingEntry, entry: RankingEntry): void {
+  if (existing.height_inches == null && entry.height_inches != null) {
+    existing.height_inches = entry.height_inches;
+  }
+  if (existing.weight_pounds == null && entry.weight_pounds != null) {
+    existing.weight_pounds = entry.weight_pounds;
+  }
+  if (existing.grade == null && entry.grade != null) {
+    existing.grade = entry.grade;
+  }
+  if (!existing.profile_url && entry.profile_url) {
+    existing.profile_url = entry.profile_url;
+  }
+  if (!existing.headshot_url && entry.headshot_url) {
+    existing.headshot_url = entry.headshot_url;
+  }
+}
+
+function append(primary: RankingData, secondaries: RankingData[]): RankingEntry[] {
   // Build map keyed by normalized name from primary
   const seen = new Map<string, RankingEntry>();
   const ordered: string[] = [];
@@ -12,20 +87,13 @@ export function mergeRankings(primary: RankingData, secondaries: RankingData[]):
     ordered.push(key);
   }
 
-  // Process each secondary: backfill height/weight, append unique
+  // Process each secondary: backfill missing fields, append unique
   for (const secondary of secondaries) {
     for (const entry of secondary.rankings) {
       const key = nameKey(entry.first_name, entry.last_name);
 
       if (seen.has(key)) {
-        // Backfill missing fields
-        const existing = seen.get(key)!;
-        if (existing.height_inches == null && entry.height_inches != null) {
-          existing.height_inches = entry.height_inches;
-        }
-        if (existing.weight_pounds == null && entry.weight_pounds != null) {
-          existing.weight_pounds = entry.weight_pounds;
-        }
+        backfill(seen.get(key)!, entry);
       } else {
         // Unique to this secondary — append
         seen.set(key, { ...entry });
@@ -35,20 +103,70 @@ export function mergeRankings(primary: RankingData, secondaries: RankingData[]):
   }
 
   // Re-rank sequentially
-  const rankings: RankingEntry[] = ordered.map((key, i) => ({
+  return ordered.map((key, i) => ({
     ...seen.get(key)!,
     rank: i + 1,
   }));
+}
 
-  return {
-    meta: {
-      version: "1.0.0",
-      source: "merged",
-      source_url: primary.meta.source_url,
-      draft_year: primary.meta.draft_year,
-      scraped_at: new Date().toISOString().slice(0, 10),
-      total_prospects: rankings.length,
-    },
-    rankings,
-  };
+interface Consensus {
+  entry: RankingEntry;
+  ranks: { rank: number; weight: number }[];
+}
+
+/**
+ * Like the API's consensus board, a prospect is averaged only over the
+ * sources that rank them, so one missing from a board is not penalized.
+ * Ties go to the better single rank, then last name.
+ */
+function weightedAverage(sources: RankingData[], weights: Record<string, number>): RankingEntry[] {
+  const prospects = new Map<string, Consensus>();
+
+  for (const source of sources) {
+    const weight = weights[source.meta.source] ?? 1;
+    if (weight === 0) continue;
+
+    for (const entry of source.rankings) {
+      const key = nameKey(entry.first_name, entry.last_name);
+      const prospect = prospects.get(key);
+      if (prospect) {
+        backfill(prospect.entry, entry);
+        prospect.ranks.push({ rank: entry.rank, weight });
+      } else {
+        prospects.set(key, { entry: { ...entry }, ranks: [{ rank: entry.rank, weight }] });
+      }
+    }
+  }
+
+  const scored = [...prospects.values()].map(({ entry, ranks }) => {
+    const total = ranks.reduce((sum, r) => sum + r.weight, 0);
+    const average = ranks.reduce((sum, r) => sum + r.rank * r.weight, 0) / total;
+    const variance = ranks.reduce((sum, r) => sum + r.weight * (r.rank - average) ** 2, 0) / total;
+    return {
+      entry,
+      average,
+      variance,
+      best: Math.min(...ranks.map((r) => r.rank)),
+      count: ranks.length,
+    };
+  });
+
+  scored.sort(
+    (a, b) =>
+      a.average - b.average ||
+      a.best - b.best ||
+      a.entry.last_name.localeCompare(b.entry.last_name),
+  );
+
+  return scored.map((s, i) => ({
+    ...s.entry,
+    rank: i + 1,
+    average_rank: round(s.average),
+    rank_variance: round(s.variance),
+    source_count: s.count,
+  }));
+}
+
+function round(value: number): number {
+  return Math.round(value * 100) / 100;
 }
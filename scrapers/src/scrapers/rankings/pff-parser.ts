@@ -0,0 +1,127 @@
+import * as cheerio from "cheerio";
+import type { RankingData, RankingEntry } from "../../types/rankings.js";
+import { normalizePosition } from "../../shared/position-normalizer.js";
+import { absoluteUrl, linkFields, parseHeight, splitName } from "./helpers.js";
+
+export function pffBigBoardUrl(year: number): string {
+  return `https://www.pff.com/draft/big-board?season=${year}`;
+}
+
+export function pffBigBoardApiUrl(year: number): string {
+  return `https://www.pff.com/api/college/big_board?season=${year}&version=latest`;
+}
+
+type Prospect = Record<string, unknown>;
+
+function isProspectList(value: unknown): value is Prospect[] {
+  return (
+    Array.isArray(value) &&
+    value.length > 0 &&
+    value.every((p) => typeof p === "object" && p !== null && "position" in p) &&
+    value.some((p) => "rank" in p || "big_board_rank" in p)
+  );
+}
+
+/**
+ * Finds the prospect list in PFF's JSON. The API returns `{ players: [...] }`,
+ * while the page embeds the same list somewhere under `props.pageProps` of
+ * its `__NEXT_DATA__` script, so the first array of ranked objects with a
+ * position is taken wherever it sits.
+ */
+function findProspects(value: unknown): Prospect[] {
+  if (isProspectList(value)) return value;
+  if (typeof value !== "object" || value === null) return [];
+  for (const child of Object.values(value)) {
+    const found = findProspects(child);
+    if (found.length > 0) return found;
+  }
+  return [];
+}
+
+function text(prospect: Prospect, ...keys: string[]): string {
+  for (const key of keys) {
+    const value = prospect[key];
+    if (typeof value === "string" && value.trim()) return value.trim();
+    // Nested objects such as `college: { name: "Ohio State" }`
+    if (typeof value === "object" && value !== null && "name" in value) {
+      const name = (value as { name: unknown }).name;
+      if (typeof name === "string" && name.trim()) return name.trim();
+    }
+  }
+  return "";
+}
+
+function number(prospect: Prospect, ...keys: string[]): number | null {
+  for (const key of keys) {
+    const value = prospect[key];
+    if (typeof value === "number" && Number.isFinite(value)) return value;
+    if (typeof value === "string" && value.trim() && !isNaN(Number(value))) return Number(value);
+  }
+  return null;
+}
+
+function parseJson(body: string): unknown {
+  const trimmed = body.trim();
+  if (trimmed.startsWith("{") || trimmed.startsWith("[")) return JSON.parse(trimmed);
+
+  const $ = cheerio.load(body);
+  const nextData = $("script#__NEXT_DATA__").text();
+  return nextData ? JSON.parse(nextData) : null;
+}
+
+/**
+ * Parses PFF's big board from either the JSON API response or the board
+ * page's HTML. `grade` is PFF's grade for the prospect at their position.
+ */
+export function parsePffBigBoard(body: string, year: number): RankingData {
+  const rankings: RankingEntry[] = [];
+
+  for (const prospect of findProspects(parseJson(body))) {
+    const rank = number(prospect, "rank", "big_board_rank");
+    if (rank === null) continue;
+
+    let firstName = text(prospect, "first_name", "firstName");
+    let lastName = text(prospect, "last_name", "lastName");
+    if (!firstName || !lastName) {
+      [firstName, lastName] = splitName(text(prospect, "name", "full_name", "player"));
+    }
+    if (!firstName || !lastName) continue;
+
+    const height = prospect.height;
+    const weight = number(prospect, "weight");
+    const profileUrl = absoluteUrl(
+      text(prospect, "profile_url", "player_url", "url"),
+      pffBigBoardUrl(year),
+    );
+    const headshotUrl = absoluteUrl(
+      text(prospect, "headshot_url", "headshot", "image_url", "photo_url"),
+      pffBigBoardUrl(year),
+    );
+    rankings.push({
+      rank,
+      first_name: firstName,
+      last_name: lastName,
+      position: normalizePosition(text(prospect, "position")),
+      school: text(prospect, "school", "college", "team_name", "team"),
+      height_inches:
+        typeof height === "number" ? Math.round(height) : parseHeight(String(height ?? "")),
+      weight_pounds: weight === null ? null : Math.round(weight),
+      grade: number(prospect, "grade", "pff_grade", "position_grade", "overall_grade"),
+      ...linkFields(profileUrl, headshotUrl),
+    });
+  }
+
+  rankings.sort((a, b) => a.rank - b.rank);
+
+  return {
+    meta: {
+      version: "1.0.0",
+      source: "pff",
+      source_url: pffBigBoardUrl(year),
+      draft_year: year,
+      scraped_at: new Date().toISOString().slice(0, 10),
+      total_prospects: rankings.length,
+    },
+    rankings,
+  };
+}
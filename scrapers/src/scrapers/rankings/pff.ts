@@ -0,0 +1,51 @@
+import type { RankingData } from "../../types/rankings.js";
+import { parsePffBigBoard, pffBigBoardApiUrl, pffBigBoardUrl } from "./pff-parser.js";
+import { cachedFetch } from "../../shared/http-cache.js";
+
+const HEADERS = {
+  "User-Agent":
+    "Mozilla/5.0 (Macintosh; Intel Mac OS X 10_15_7) AppleWebKit/537.36 (KHTML, like Gecko) Chrome/120.0.0.0 Safari/537.36",
+};
+
+async function fetchText(url: string): Promise<string> {
+  console.error(`URL: ${url}`);
+  const response = await cachedFetch(url, {
+    headers: HEADERS,
+    signal: AbortSignal.timeout(30000),
+  });
+  if (!response.ok) {
+    throw new Error(`HTTP ${response.status} fetching PFF`);
+  }
+  const body = await response.text();
+  console.error(`Fetched ${body.length} bytes`);
+  return body;
+}
+
+/**
+ * Scrapes PFF's big board from its JSON endpoint, falling back to the
+ * data embedded in the board page when the endpoint fails or is empty.
+ */
+export async function scrapePff(year: number): Promise<RankingData> {
+  console.error(`Scraping PFF big board...`);
+
+  let data: RankingData | null = null;
+  try {
+    data = parsePffBigBoard(await fetchText(pffBigBoardApiUrl(year)), year);
+  } catch (err) {
+    const message = err instanceof Error ? err.message : String(err);
+    console.error(`PFF API failed: ${message}`);
+  }
+
+  if (!data || data.rankings.length === 0) {
+    console.error("Falling back to the big board page...");
+    data = parsePffBigBoard(await fetchText(pffBigBoardUrl(year)), year);
+  }
+
+  if (data.rankings.length === 0) {
+    console.error("WARNING: No prospects extracted from PFF");
+  } else {
+    console.error(`Extracted ${data.rankings.length} prospects`);
+  }
+
+  return data;
+}
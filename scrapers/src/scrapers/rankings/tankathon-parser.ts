@@ -1,7 +1,9 @@
 import * as cheerio from "cheerio";
 import type { RankingData, RankingEntry } from "../../types/rankings.js";
 import { normalizePosition } from "../../shared/position-normalizer.js";
-import { splitName } from "./helpers.js";
+import { absoluteUrl, linkFields, splitName } from "./helpers.js";
+
+const TANKATHON_URL = "https://www.tankathon.com/nfl/big_board";
 
 /**
  * Parse Tankathon big board HTML. Two strategies:
@@ -30,6 +32,11 @@ export function parseTankathonRankingsHtml(html: string, year: number): RankingD
       const rankText = $(row).find(".mock-row-pick-number").text().trim();
       const name = $(row).find(".mock-row-name").text().trim();
       const posSchool = $(row).find(".mock-row-school-position").text().trim();
+      // Each row links to the player's page; its image is the school logo
+      const profileUrl = absoluteUrl(
+        $(row).find("a[href]").first().attr("href") ?? $(row).closest("a[href]").attr("href"),
+        TANKATHON_URL,
+      );
 
       const rank = parseInt(rankText, 10);
       if (isNaN(rank)) return;
@@ -50,6 +57,7 @@ export function parseTankathonRankingsHtml(html: string, year: number): RankingD
         school,
         height_inches: null,
         weight_pounds: null,
+        ...linkFields(profileUrl),
       });
     });
   }
@@ -63,7 +71,7 @@ export function parseTankathonRankingsHtml(html: string, year: number): RankingD
     meta: {
       version: "1.0.0",
       source: "tankathon",
-      source_url: "https://www.tankathon.com/nfl/big_board",
+      source_url: TANKATHON_URL,
       draft_year: year,
       scraped_at: new Date().toISOString().slice(0, 10),
       total_prospects: rankings.length,
@@ -1,5 +1,6 @@
 import type { RankingData } from "../../types/rankings.js";
 import { parseTankathonRankingsHtml } from "./tankathon-parser.js";
+import { browserProxy, browserUserAgent } from "../../shared/request-options.js";
 
 export async function scrapeTankathonRankings(year: number): Promise<RankingData> {
   const { chromium } = await import("playwright");
@@ -9,9 +10,9 @@ export async function scrapeTankathonRankings(year: number): Promise<RankingData
   console.error(`Scraping Tankathon big board...`);
   console.error(`URL: ${url}`);
 
-  const browser = await chromium.launch({ headless: true });
+  const browser = await chromium.launch({ headless: true, proxy: browserProxy() });
   try {
-    const page = await browser.newPage();
+    const page = await browser.newPage({ userAgent: browserUserAgent() });
     await page.goto(url, { waitUntil: "domcontentloaded", timeout: 60000 });
 
     // Wait for mock-row elements to appear
@@ -1,5 +1,6 @@
 import type { RankingData } from "../../types/rankings.js";
 import { parseWalterFootballHtml } from "./walterfootball-parser.js";
+import { cachedFetch } from "../../shared/http-cache.js";
 
 export async function scrapeWalterFootball(year: number): Promise<RankingData> {
   const url = `https://walterfootball.com/nfldraftbigboard${year}.php`;
@@ -7,7 +8,7 @@ export async function scrapeWalterFootball(year: number): Promise<RankingData> {
   console.error(`Scraping WalterFootball big board...`);
   console.error(`URL: ${url}`);
 
-  const response = await fetch(url, {
+  const response = await cachedFetch(url, {
     headers: {
       "User-Agent":
         "Mozilla/5.0 (Macintosh; Intel Mac OS X 10_15_7) AppleWebKit/537.36 (KHTML, like Gecko) Chrome/120.0.0.0 Safari/537.36",
@@ -1,4 +1,5 @@
 import { chromium, type Browser } from "playwright";
+import { browserProxy } from "./request-options.js";
 
 let browser: Browser | null = null;
 
@@ -7,7 +8,7 @@ let browser: Browser | null = null;
  */
 export async function launchBrowser(): Promise<Browser> {
   if (!browser || !browser.isConnected()) {
-    browser = await chromium.launch({ headless: true });
+    browser = await chromium.launch({ headless: true, proxy: browserProxy() });
   }
   return browser;
 }
@@ -0,0 +1,157 @@
+import { existsSync, readFileSync } from "fs";
+import { basename, dirname, extname, join } from "path";
+import { isTemplateData, writeJsonFile } from "./json-writer.js";
+
+const UNITS: Record<string, number> = {
+  ms: 1,
+  s: 1000,
+  m: 60 * 1000,
+  h: 60 * 60 * 1000,
+  d: 24 * 60 * 60 * 1000,
+};
+
+/** Parses an interval such as `500ms`, `90s`, `30m`, `6h` or `1d` into milliseconds */
+export function parseInterval(spec: string): number {
+  const match = spec.trim().match(/^(\d+(?:\.\d+)?)\s*(ms|[smhd])$/i);
+  const ms = match ? parseFloat(match[1]) * UNITS[match[2].toLowerCase()] : NaN;
+  if (!match || !(ms > 0)) {
+    throw new Error(`Invalid interval '${spec}'. Use a number and unit, e.g. 30m, 6h or 1d`);
+  }
+  return ms;
+}
+
+/**
+ * Path of the timestamped copy of an output file, e.g.
+ * `rankings_2026.json` -> `rankings_2026.2026-10-17T06-00-00Z.json`
+ */
+export function versionedPath(output: string, at: Date): string {
+  const ext = extname(output) || ".json";
+  const stamp = at.toISOString().replace(/\.\d{3}Z$/, "Z").replace(/:/g, "-");
+  return join(dirname(output), `${basename(output, extname(output))}.${stamp}${ext}`);
+}
+
+export interface SeedTarget {
+  /** API base URL, e.g. http://localhost:8000 */
+  apiUrl: string;
+  /** Seed endpoint path, e.g. /api/v1/admin/seed-rankings */
+  endpoint: string;
+  /** Sent as the X-Seed-Api-Key header */
+  apiKey: string;
+}
+
+/** POSTs a scraped file to an API seed endpoint */
+export async function postSeedData(target: SeedTarget, data: unknown): Promise<string> {
+  const url = `${target.apiUrl.replace(/\/+$/, "")}${target.endpoint}`;
+  const response = await fetch(url, {
+    method: "POST",
+    headers: {
+      "Content-Type": "application/json",
+      "X-Seed-Api-Key": target.apiKey,
+    },
+    body: JSON.stringify(data),
+    signal: AbortSignal.timeout(120000),
+  });
+
+  const body = await response.text();
+  if (!response.ok) {
+    throw new Error(`HTTP ${response.status} from ${url}: ${body}`);
+  }
+  try {
+    return (JSON.parse(body) as { message?: string }).message ?? body;
+  } catch {
+    return body;
+  }
+}
+
+/**
+ * POSTs a scraper's output file to its seed endpoint. Returns the API's
+ * message, or null when there was nothing to push: no file was written or
+ * it holds template data.
+ */
+export async function pushOutput(target: SeedTarget, output: string): Promise<string | null> {
+  if (!existsSync(output)) return null;
+  const data = JSON.parse(readFileSync(output, "utf-8")) as Record<string, unknown>;
+  if (isTemplateData(data)) return null;
+  return await postSeedData(target, data);
+}
+
+export interface DaemonOptions {
+  /** Name used in log lines */
+  name: string;
+  intervalMs: number;
+  /** The file each run writes; a timestamped copy is kept next to it */
+  output: string;
+  /** Where to POST fresh data, if anywhere */
+  seed?: SeedTarget;
+}
+
+/**
+ * Runs `scrape` now and then every `intervalMs` until SIGINT or SIGTERM.
+ * A failed run is logged and retried at the next interval. Template output
+ * is neither versioned nor posted.
+ */
+export async function runDaemon(
+  options: DaemonOptions,
+  scrape: () => Promise<void>,
+): Promise<void> {
+  const { name, intervalMs, output, seed } = options;
+  let stopping = false;
+  let wake: (() => void) | null = null;
+  const stop = () => {
+    console.error(`\n[daemon] Stopping ${name} after the current run...`);
+    stopping = true;
+    wake?.();
+  };
+  process.once("SIGINT", stop);
+  process.once("SIGTERM", stop);
+
+  console.error(`[daemon] Scraping ${name} every ${formatInterval(intervalMs)}`);
+
+  while (!stopping) {
+    const startedAt = new Date();
+    console.error(`\n[daemon] Run started at ${startedAt.toISOString()}`);
+
+    try {
+      await scrape();
+      const data = JSON.parse(readFileSync(output, "utf-8")) as Record<string, unknown>;
+
+      if (isTemplateData(data)) {
+        console.error("[daemon] Run produced template data; not versioning or posting it");
+      } else {
+        const versioned = versionedPath(output, startedAt);
+        writeJsonFile(versioned, data);
+        console.error(`[daemon] Wrote ${versioned}`);
+
+        if (seed) {
+          const message = await postSeedData(seed, data);
+          console.error(`[daemon] Posted to ${seed.endpoint}: ${message}`);
+        }
+      }
+    } catch (err) {
+      const message = err instanceof Error ? err.message : String(err);
+      console.error(`[daemon] Run failed: ${message}`);
+    }
+
+    if (stopping) break;
+    const next = new Date(Date.now() + intervalMs);
+    console.error(`[daemon] Next run at ${next.toISOString()}`);
+    await new Promise<void>((resolve) => {
+      const timer = setTimeout(resolve, intervalMs);
+      wake = () => {
+        clearTimeout(timer);
+        resolve();
+      };
+    });
+    wake = null;
+  }
+
+  process.off("SIGINT", stop);
+  process.off("SIGTERM", stop);
+}
+
+function formatInterval(ms: number): string {
+  for (const unit of ["d", "h", "m", "s"]) {
+    if (ms % UNITS[unit] === 0) return `${ms / UNITS[unit]}${unit}`;
+  }
+  return `${ms}ms`;
+}
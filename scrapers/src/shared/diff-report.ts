@@ -0,0 +1,225 @@
+import { existsSync, readFileSync } from "fs";
+import { isTemplateData, writeJsonFile } from "./json-writer.js";
+import { nameKey } from "./name-normalizer.js";
+
+/** A prospect whose rank changed between two runs */
+export interface RankMove {
+  name: string;
+  position: string;
+  from: number;
+  to: number;
+}
+
+/** What changed between the previous output file and the new one */
+export interface DiffReport {
+  previous_count: number;
+  current_count: number;
+  /** Prospects only in the new output, as "First Last (POS)" */
+  added: string[];
+  /** Prospects only in the previous output */
+  dropped: string[];
+  /** Biggest climb first */
+  risers: RankMove[];
+  /** Biggest drop first */
+  fallers: RankMove[];
+}
+
+/** A limit on prospects added plus dropped */
+export interface ChangeLimit {
+  value: number;
+  /** `value` is a percentage of the previous file's prospects */
+  percent: boolean;
+}
+
+export interface DiffThresholds {
+  maxChanges?: ChangeLimit;
+  /** Most spots any one prospect may rise or fall */
+  maxMove?: number;
+}
+
+interface Prospect {
+  name: string;
+  position: string;
+  rank: number | null;
+}
+
+/**
+ * The prospects in a scraper output file keyed by name: rankings, combine
+ * and pro day results, or The Beast. Null for any other shape.
+ */
+function prospectsOf(data: unknown): Map<string, Prospect> | null {
+  const record = data as Record<string, unknown> | null;
+  const rows = record?.rankings ?? record?.combine_results ?? record?.prospects;
+  if (!Array.isArray(rows)) return null;
+
+  const prospects = new Map<string, Prospect>();
+  for (const row of rows as Record<string, unknown>[]) {
+    const first = String(row.first_name ?? "");
+    const last = String(row.last_name ?? "");
+    const key = nameKey(first, last);
+    // Pro day files can list a player once per workout
+    if (prospects.has(key)) continue;
+    const rank = row.rank ?? row.overall_rank;
+    prospects.set(key, {
+      name: `${first} ${last}`.trim(),
+      position: String(row.position ?? ""),
+      rank: typeof rank === "number" ? rank : null,
+    });
+  }
+  return prospects;
+}
+
+function label(prospect: { name: string; position: string }): string {
+  return prospect.position ? `${prospect.name} (${prospect.position})` : prospect.name;
+}
+
+/** Compare two scraper outputs; null when either isn't a list of prospects */
+export function diffOutputs(previous: unknown, current: unknown): DiffReport | null {
+  const before = prospectsOf(previous);
+  const after = prospectsOf(current);
+  if (!before || !after) return null;
+
+  const report: DiffReport = {
+    previous_count: before.size,
+    current_count: after.size,
+    added: [],
+    dropped: [],
+    risers: [],
+    fallers: [],
+  };
+
+  for (const [key, prospect] of after) {
+    const old = before.get(key);
+    if (!old) {
+      report.added.push(label(prospect));
+    } else if (old.rank !== null && prospect.rank !== null && old.rank !== prospect.rank) {
+      const move = {
+        name: prospect.name,
+        position: prospect.position,
+        from: old.rank,
+        to: prospect.rank,
+      };
+      (move.to < move.from ? report.risers : report.fallers).push(move);
+    }
+  }
+  for (const [key, prospect] of before) {
+    if (!after.has(key)) report.dropped.push(label(prospect));
+  }
+
+  report.risers.sort((a, b) => b.from - b.to - (a.from - a.to));
+  report.fallers.sort((a, b) => b.to - b.from - (a.to - a.from));
+  return report;
+}
+
+/** Human-readable summary listing the `top` biggest risers and fallers */
+export function formatDiffReport(report: DiffReport, top = 10): string {
+  const lines = [
+    `Changes since last run: ${report.previous_count} -> ${report.current_count} prospects, ` +
+      `${report.added.length} new, ${report.dropped.length} dropped, ` +
+      `${report.risers.length} up, ${report.fallers.length} down`,
+  ];
+  const list = (title: string, items: string[]) => {
+    if (items.length === 0) return;
+    lines.push(`  ${title}:`);
+    for (const item of items.slice(0, top)) lines.push(`    ${item}`);
+    if (items.length > top) lines.push(`    ... and ${items.length - top} more`);
+  };
+  const moves = (items: RankMove[]) =>
+    items.map((m) => {
+      const sign = m.to < m.from ? "+" : "-";
+      return `${label(m)} ${m.from} -> ${m.to} (${sign}${Math.abs(m.from - m.to)})`;
+    });
+
+  list("New", report.added);
+  list("Dropped", report.dropped);
+  list("Biggest risers", moves(report.risers));
+  list("Biggest fallers", moves(report.fallers));
+  return lines.join("\n");
+}
+
+/** Parse a change limit such as `25` or `10%` */
+export function parseChangeLimit(spec: string): ChangeLimit {
+  const match = spec.trim().match(/^(\d+(?:\.\d+)?)(%?)$/);
+  if (!match) {
+    throw new Error(
+      `Invalid change limit '${spec}'. Use a count such as 25 or a share such as 10%`,
+    );
+  }
+  return { value: parseFloat(match[1]), percent: match[2] === "%" };
+}
+
+/** Threshold violations, empty when the report is within every limit */
+export function checkThresholds(report: DiffReport, thresholds: DiffThresholds): string[] {
+  const violations: string[] = [];
+
+  const { maxChanges, maxMove } = thresholds;
+  if (maxChanges) {
+    const limit = maxChanges.percent
+      ? (maxChanges.value / 100) * report.previous_count
+      : maxChanges.value;
+    const changes = report.added.length + report.dropped.length;
+    if (changes > limit) {
+      const shown = maxChanges.percent ? `${maxChanges.value}%` : `${maxChanges.value}`;
+      violations.push(`${changes} prospects added or dropped (limit: ${shown})`);
+    }
+  }
+
+  if (maxMove !== undefined) {
+    const biggest = [...report.risers, ...report.fallers].reduce(
+      (max, m) => Math.max(max, Math.abs(m.from - m.to)),
+      0,
+    );
+    if (biggest > maxMove) {
+      violations.push(`a prospect moved ${biggest} spots (limit: ${maxMove})`);
+    }
+  }
+
+  return violations;
+}
+
+export interface DiffRunOptions extends DiffThresholds {
+  /** Write the full report here as JSON */
+  reportPath?: string;
+}
+
+function readJson(path: string): unknown {
+  if (!existsSync(path)) return null;
+  try {
+    return JSON.parse(readFileSync(path, "utf-8"));
+  } catch {
+    return null;
+  }
+}
+
+/**
+ * Run a scraper, then report how its output moved from the previous file.
+ * Throws after the file is written when a threshold is exceeded, so CI can
+ * hold back the update. Nothing is compared when there was no previous file
+ * or it held template data, where every prospect would count as new.
+ */
+export async function runWithDiffReport(
+  output: string,
+  options: DiffRunOptions,
+  scrape: () => Promise<void>,
+): Promise<void> {
+  const previous = readJson(output);
+  await scrape();
+
+  if (previous === null || isTemplateData(previous as Record<string, unknown>)) {
+    console.error("\nNo previous real data to compare against; skipping the diff report.");
+    return;
+  }
+  const report = diffOutputs(previous, readJson(output));
+  if (!report) return;
+
+  console.error(`\n${formatDiffReport(report)}`);
+  if (options.reportPath) {
+    writeJsonFile(options.reportPath, report);
+    console.error(`Wrote diff report to: ${options.reportPath}`);
+  }
+
+  const violations = checkThresholds(report, options);
+  if (violations.length > 0) {
+    throw new Error(`Data changed more than allowed: ${violations.join("; ")}`);
+  }
+}
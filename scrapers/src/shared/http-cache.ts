@@ -0,0 +1,104 @@
+import { createHash } from "crypto";
+import { existsSync, mkdirSync, readFileSync, writeFileSync } from "fs";
+import { join } from "path";
+import { fetchWithRetry } from "./retry.js";
+
+export interface HttpCacheOptions {
+  /** Directory cached responses are written to */
+  dir: string;
+  /** How long a cached response is used without asking the site again */
+  maxAgeMs: number;
+}
+
+interface CacheEntry {
+  url: string;
+  status: number;
+  contentType: string | null;
+  etag: string | null;
+  lastModified: string | null;
+  /** When the response was fetched or last revalidated, in ms since the epoch */
+  fetchedAt: number;
+}
+
+let cache: HttpCacheOptions | null = null;
+
+/** Turns the disk cache on for every `cachedFetch` in this process */
+export function configureHttpCache(options: HttpCacheOptions): void {
+  cache = options;
+}
+
+/** Turns the disk cache off again (for tests) */
+export function resetHttpCache(): void {
+  cache = null;
+}
+
+function cachePaths(dir: string, url: string): { meta: string; body: string } {
+  const key = createHash("sha256").update(url).digest("hex").slice(0, 32);
+  return { meta: join(dir, `${key}.json`), body: join(dir, `${key}.body`) };
+}
+
+function readEntry(paths: { meta: string; body: string }): CacheEntry | null {
+  if (!existsSync(paths.meta) || !existsSync(paths.body)) return null;
+  try {
+    return JSON.parse(readFileSync(paths.meta, "utf-8")) as CacheEntry;
+  } catch {
+    return null;
+  }
+}
+
+function cachedResponse(entry: CacheEntry, body: string): Response {
+  const headers = new Headers();
+  if (entry.contentType) headers.set("Content-Type", entry.contentType);
+  return new Response(body, { status: entry.status, headers });
+}
+
+/**
+ * `fetch` that reads and writes a disk cache once `configureHttpCache` has
+ * been called. Requests that reach the network go through `fetchWithRetry`
+ * either way. Only successful GETs are cached. A response younger than the
+ * max age is served without a request; an older one is revalidated with
+ * If-None-Match / If-Modified-Since and reused when the site answers 304
+ * Not Modified.
+ */
+export async function cachedFetch(url: string, init: RequestInit = {}): Promise<Response> {
+  const method = (init.method ?? "GET").toUpperCase();
+  if (!cache || method !== "GET") return fetchWithRetry(url, init);
+
+  const { dir, maxAgeMs } = cache;
+  const paths = cachePaths(dir, url);
+  const entry = readEntry(paths);
+
+  if (entry && Date.now() - entry.fetchedAt < maxAgeMs) {
+    console.error(`Cache hit: ${url}`);
+    return cachedResponse(entry, readFileSync(paths.body, "utf-8"));
+  }
+
+  const headers = new Headers(init.headers);
+  if (entry?.etag) headers.set("If-None-Match", entry.etag);
+  if (entry?.lastModified) headers.set("If-Modified-Since", entry.lastModified);
+
+  const response = await fetchWithRetry(url, { ...init, headers });
+
+  if (response.status === 304 && entry) {
+    console.error(`Not modified, using cached copy: ${url}`);
+    const refreshed = { ...entry, fetchedAt: Date.now() };
+    writeFileSync(paths.meta, JSON.stringify(refreshed, null, 2) + "\n");
+    return cachedResponse(refreshed, readFileSync(paths.body, "utf-8"));
+  }
+
+  if (!response.ok) return response;
+
+  const body = await response.text();
+  mkdirSync(dir, { recursive: true });
+  writeFileSync(paths.body, body);
+  const fresh: CacheEntry = {
+    url,
+    status: response.status,
+    contentType: response.headers.get("Content-Type"),
+    etag: response.headers.get("ETag"),
+    lastModified: response.headers.get("Last-Modified"),
+    fetchedAt: Date.now(),
+  };
+  writeFileSync(paths.meta, JSON.stringify(fresh, null, 2) + "\n");
+  return cachedResponse(fresh, body);
+}
@@ -1,4 +1,8 @@
-export { normalizePosition } from "./position-normalizer.js";
+export {
+  normalizePosition,
+  loadPositionMap,
+  resetPositionMap,
+} from "./position-normalizer.js";
 export { cleanName, normalizeLastName, nameKey, splitName } from "./name-normalizer.js";
 export {
   normalizeSvgAbbreviation,
@@ -28,3 +28,16 @@ export function shouldPreventOverwrite(
     return false;
   }
 }
+
+/**
+ * Whether a data file has yet to get real data: it is missing or holds
+ * template or generated mock data
+ */
+export function holdsTemplateData(outputPath: string): boolean {
+  if (!existsSync(outputPath)) return true;
+  try {
+    return isTemplateData(JSON.parse(readFileSync(outputPath, "utf-8")));
+  } catch {
+    return false;
+  }
+}
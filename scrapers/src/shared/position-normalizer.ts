@@ -1,3 +1,5 @@
+import { readFileSync } from "fs";
+
 const POSITION_MAP: Record<string, string> = {
   QB: "QB",
   RB: "RB",
@@ -14,6 +16,7 @@ const POSITION_MAP: Record<string, string> = {
   C: "C",
   DE: "DE",
   EDGE: "DE",
+  ED: "DE",
   "EDGE/LB": "DE",
   "LB/EDGE": "DE",
   DT: "DT",
@@ -33,7 +36,64 @@ const POSITION_MAP: Record<string, string> = {
   P: "P",
 };
 
+const CANONICAL_POSITIONS = new Set(Object.values(POSITION_MAP));
+
+let overrides: Record<string, string> = {};
+let fallback: string | undefined;
+const warned = new Set<string>();
+
+/**
+ * Loads a position map file shared with the seed-data `--position-map` flag:
+ * `{ "aliases": { "JACK": "DE" }, "fallback": "S" }`. Aliases add to or
+ * override the built-in ones, and `fallback` replaces positions that match
+ * none. Every target must be a canonical position.
+ */
+export function loadPositionMap(path: string): void {
+  const file = JSON.parse(readFileSync(path, "utf-8")) as {
+    aliases?: Record<string, string>;
+    fallback?: string;
+  };
+  const aliases: Record<string, string> = {};
+  for (const [alias, target] of Object.entries(file.aliases ?? {})) {
+    const key = alias.trim().toUpperCase();
+    if (!key) throw new Error(`Invalid position map ${path}: empty alias`);
+    if (!CANONICAL_POSITIONS.has(target)) {
+      throw new Error(`Invalid position map ${path}: unknown position '${target}' for ${key}`);
+    }
+    aliases[key] = target;
+  }
+  if (file.fallback !== undefined && !CANONICAL_POSITIONS.has(file.fallback)) {
+    throw new Error(`Invalid position map ${path}: unknown fallback position '${file.fallback}'`);
+  }
+  overrides = aliases;
+  fallback = file.fallback;
+}
+
+/** Drops a loaded position map, back to the built-in aliases */
+export function resetPositionMap(): void {
+  overrides = {};
+  fallback = undefined;
+  warned.clear();
+}
+
+/**
+ * Maps a source position to a canonical one. A position that matches no
+ * alias becomes the fallback when one is loaded, and otherwise passes
+ * through uppercased for the seed loaders to skip. Either way it is
+ * warned about once.
+ */
 export function normalizePosition(pos: string): string {
   const upper = pos.trim().toUpperCase();
-  return POSITION_MAP[upper] ?? upper;
+  const mapped = overrides[upper] ?? POSITION_MAP[upper];
+  if (mapped) return mapped;
+
+  if (!warned.has(upper)) {
+    warned.add(upper);
+    console.warn(
+      fallback
+        ? `WARNING: Unmapped position '${upper}', using fallback ${fallback}`
+        : `WARNING: Unmapped position '${upper}'. Add an alias for it with --position-map.`,
+    );
+  }
+  return fallback ?? upper;
 }
@@ -0,0 +1,109 @@
+import { applyRequestOptions } from "./request-options.js";
+
+export interface RetryOptions {
+  /** Total tries per request, including the first */
+  attempts: number;
+  /** Delay before the first retry; doubles on each one after */
+  backoffMs: number;
+  /** Upper bound on a single backoff delay */
+  maxBackoffMs: number;
+  /** Minimum gap between requests to the same host */
+  hostDelayMs: number;
+}
+
+export const DEFAULT_RETRY_OPTIONS: RetryOptions = {
+  attempts: 3,
+  backoffMs: 1000,
+  maxBackoffMs: 30000,
+  hostDelayMs: 0,
+};
+
+/** Statuses worth another try: rate limiting and transient server errors */
+const RETRYABLE_STATUSES = new Set([408, 429, 500, 502, 503, 504]);
+
+let options: RetryOptions = DEFAULT_RETRY_OPTIONS;
+const lastRequestAt = new Map<string, number>();
+
+/** Overrides the retry defaults for every `fetchWithRetry` in this process */
+export function configureRetry(overrides: Partial<RetryOptions>): void {
+  options = { ...DEFAULT_RETRY_OPTIONS, ...overrides };
+}
+
+/** Restores the retry defaults and forgets per-host timing (for tests) */
+export function resetRetry(): void {
+  options = DEFAULT_RETRY_OPTIONS;
+  lastRequestAt.clear();
+}
+
+function sleep(ms: number): Promise<void> {
+  return new Promise((resolve) => setTimeout(resolve, ms));
+}
+
+/**
+ * Delay before retry number `retry` (1-based): exponential backoff with
+ * full jitter, so scrapers retrying together don't hit a site in lockstep.
+ */
+export function backoffDelay(retry: number, opts: RetryOptions, random = Math.random): number {
+  const ceiling = Math.min(opts.maxBackoffMs, opts.backoffMs * 2 ** (retry - 1));
+  return Math.round(random() * ceiling);
+}
+
+/** Reads a Retry-After header given in seconds or as an HTTP date */
+export function retryAfterMs(header: string | null, now = Date.now()): number | null {
+  if (!header) return null;
+  const seconds = Number(header);
+  if (!isNaN(seconds)) return Math.max(0, seconds * 1000);
+  const date = Date.parse(header);
+  return isNaN(date) ? null : Math.max(0, date - now);
+}
+
+/** Waits until the host's politeness gap has passed, then claims the slot */
+async function waitForHost(url: string): Promise<void> {
+  const host = new URL(url).host;
+  const last = lastRequestAt.get(host);
+  if (last !== undefined && options.hostDelayMs > 0) {
+    const wait = last + options.hostDelayMs - Date.now();
+    if (wait > 0) await sleep(wait);
+  }
+  lastRequestAt.set(host, Date.now());
+}
+
+/**
+ * `fetch` that retries network errors and retryable statuses (408, 429,
+ * 5xx gateway errors) with exponential backoff and jitter, honouring a
+ * longer Retry-After. The last response is returned as-is, so callers
+ * still see the final status. A request whose own signal has fired is not
+ * retried.
+ */
+export async function fetchWithRetry(url: string, init: RequestInit = {}): Promise<Response> {
+  for (let attempt = 1; ; attempt++) {
+    await waitForHost(url);
+
+    let response: Response | null = null;
+    try {
+      response = await fetch(url, applyRequestOptions(init));
+    } catch (err) {
+      if (attempt >= options.attempts || init.signal?.aborted) throw err;
+      const message = err instanceof Error ? err.message : String(err);
+      const delay = backoffDelay(attempt, options);
+      console.error(`  ${message}; retrying in ${delay}ms (${attempt}/${options.attempts - 1})`);
+      await sleep(delay);
+      continue;
+    }
+
+    if (!RETRYABLE_STATUSES.has(response.status) || attempt >= options.attempts) {
+      return response;
+    }
+
+    const delay = Math.max(
+      backoffDelay(attempt, options),
+      Math.min(retryAfterMs(response.headers.get("Retry-After")) ?? 0, options.maxBackoffMs),
+    );
+    console.error(
+      `  HTTP ${response.status} from ${url}; retrying in ${delay}ms ` +
+        `(${attempt}/${options.attempts - 1})`,
+    );
+    await response.body?.cancel();
+    await sleep(delay);
+  }
+}
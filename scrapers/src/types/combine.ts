@@ -17,6 +17,8 @@ export const CombineEntrySchema = z.object({
   wingspan: z.number().nullable(),
   ten_yard_split: z.number().nullable(),
   twenty_yard_split: z.number().nullable(),
+  /** YYYY-MM-DD the numbers were recorded; set for pro days */
+  event_date: z.string().nullable().optional(),
 });
 
 export type CombineEntry = z.infer<typeof CombineEntrySchema>;
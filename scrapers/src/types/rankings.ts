@@ -8,6 +8,15 @@ export const RankingEntrySchema = z.object({
   school: z.string(),
   height_inches: z.number().int().nullable(),
   weight_pounds: z.number().int().nullable(),
+  // Source's own prospect grade, where it publishes one (ESPN, PFF)
+  grade: z.number().nullable().optional(),
+  // Player's page on the source site and their headshot, where it links them
+  profile_url: z.string().optional(),
+  headshot_url: z.string().optional(),
+  // Written by the weighted-average merge
+  average_rank: z.number().optional(),
+  rank_variance: z.number().optional(),
+  source_count: z.number().int().optional(),
 });
 
 export type RankingEntry = z.infer<typeof RankingEntrySchema>;
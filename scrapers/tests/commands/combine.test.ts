@@ -1,7 +1,10 @@
 import { describe, it, expect, beforeEach, afterEach } from "vitest";
 import { existsSync, mkdirSync, readFileSync, rmSync } from "fs";
 import { join } from "path";
-import { runCombineCommand } from "../../src/commands/combine.js";
+import { hasPublishedResults, runCombineCommand } from "../../src/commands/combine.js";
+import { makeCombineEntry } from "../../src/shared/combine-helpers.js";
+import { writeJsonFile } from "../../src/shared/json-writer.js";
+import type { CombineData } from "../../src/types/combine.js";
 import { CombineDataSchema } from "../../src/types/combine.js";
 
 const TEST_DIR = join(import.meta.dirname, "../../.test-output");
@@ -48,3 +51,44 @@ describe("runCombineCommand", () => {
     expect(result.success).toBe(true);
   });
 });
+
+function makeResults(source: string, count: number): CombineData {
+  const entries = Array.from({ length: count }, (_, i) =>
+    makeCombineEntry(`Player${i}`, "Test", "WR", 2027, { forty_yard_dash: 4.5 }),
+  );
+  return {
+    meta: {
+      source,
+      description: "test",
+      year: 2027,
+      generated_at: "2027-03-01",
+      player_count: count,
+      entry_count: count,
+    },
+    combine_results: entries,
+  };
+}
+
+describe("hasPublishedResults", () => {
+  it("accepts a full class of real results", () => {
+    expect(hasPublishedResults(makeResults("merged", 300))).toBe(true);
+  });
+
+  it("rejects template data and a handful of early results", () => {
+    expect(hasPublishedResults(makeResults("template", 300))).toBe(false);
+    expect(hasPublishedResults(makeResults("nflverse", 0))).toBe(false);
+    expect(hasPublishedResults(makeResults("nfl_com", 12))).toBe(false);
+  });
+});
+
+describe("runCombineCommand with ifPublished", () => {
+  it("still rejects an unknown source", async () => {
+    const outputPath = join(TEST_DIR, "combine_2027.json");
+    writeJsonFile(outputPath, makeResults("generated", 300));
+
+    await expect(
+      runCombineCommand({ year: 2027, output: outputPath, source: "espn", ifPublished: true }),
+    ).rejects.toThrow(/Unknown source/);
+    expect(JSON.parse(readFileSync(outputPath, "utf-8")).meta.source).toBe("generated");
+  });
+});
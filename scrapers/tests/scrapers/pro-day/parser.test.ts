@@ -0,0 +1,162 @@
+import { describe, it, expect } from "vitest";
+import {
+  parseBroadJump,
+  parseEventDate,
+  parseInches,
+  parseProDayIndex,
+  parseProDayPage,
+} from "../../../src/scrapers/pro-day/parser.js";
+import { CombineEntrySchema } from "../../../src/types/combine.js";
+
+const INDEX_URL = "https://example.com/prodays.php?year=2026";
+
+const INDEX_HTML = `
+<html><body>
+<table>
+  <tr><th>Date</th><th>School</th><th>Results</th></tr>
+  <tr><td>March 12</td><td>Boise State</td><td><a href="/proday.php?school=boise-state">View</a></td></tr>
+  <tr><td>3/23</td><td>Iowa</td><td><a href="https://example.com/proday.php?school=iowa">View</a></td></tr>
+  <tr><td>TBD</td><td>Utah</td><td><a href="/proday.php?school=utah">View</a></td></tr>
+  <tr><td>March 30</td><td>Miami (FL)</td><td>Not posted</td></tr>
+</table>
+</body></html>
+`;
+
+const SCHOOL_HTML = `
+<html><body>
+<h1>Iowa Pro Day <time datetime="2026-03-24">March 24, 2026</time></h1>
+<table>
+  <tr>
+    <th>Player</th><th>Pos</th><th>Height</th><th>Weight</th><th>40 Yard</th><th>10 Yard Split</th>
+    <th>Bench</th><th>Vertical</th><th>Broad Jump</th><th>20 Yard Shuttle</th><th>3-Cone</th>
+    <th>Arm</th><th>Hand</th><th>60 Yard Shuttle</th>
+  </tr>
+  <tr>
+    <td>Kaden Wetjen</td><td>WR</td><td>5'9"</td><td>193</td><td>4.41</td><td>1.52</td>
+    <td>14</td><td>38.5</td><td>10'5"</td><td>4.12</td><td>6.88</td>
+    <td>30 1/4</td><td>9 1/8</td><td>11.20</td>
+  </tr>
+  <tr>
+    <td>Jay Higgins</td><td>LB</td><td>6'1"</td><td>232</td><td>4.68</td><td></td>
+    <td>DNP</td><td>33</td><td>9-11</td><td></td><td></td>
+    <td></td><td></td><td></td>
+  </tr>
+  <tr>
+    <td>Injured Player</td><td>OT</td><td>6'6"</td><td>310</td><td>-</td><td>-</td>
+    <td>-</td><td>-</td><td>-</td><td>-</td><td>-</td>
+    <td></td><td></td><td></td>
+  </tr>
+</table>
+</body></html>
+`;
+
+describe("parseProDayIndex", () => {
+  it("returns one absolute link per school with its date", () => {
+    const links = parseProDayIndex(INDEX_HTML, INDEX_URL, 2026);
+
+    expect(links).toEqual([
+      {
+        school: "Boise State",
+        url: "https://example.com/proday.php?school=boise-state",
+        event_date: "2026-03-12",
+      },
+      {
+        school: "Iowa",
+        url: "https://example.com/proday.php?school=iowa",
+        event_date: "2026-03-23",
+      },
+      { school: "Utah", url: "https://example.com/proday.php?school=utah", event_date: null },
+    ]);
+  });
+
+  it("ignores tables without a School column", () => {
+    const html = `<table><tr><th>Team</th></tr><tr><td><a href="/x">X</a></td></tr></table>`;
+    expect(parseProDayIndex(html, INDEX_URL, 2026)).toEqual([]);
+  });
+});
+
+describe("parseProDayPage", () => {
+  it("maps columns by header to pro day entries", () => {
+    const entries = parseProDayPage(SCHOOL_HTML, 2026, "2026-03-23");
+
+    expect(entries).toHaveLength(2);
+    expect(entries[0]).toMatchObject({
+      first_name: "Kaden",
+      last_name: "Wetjen",
+      position: "WR",
+      source: "pro_day",
+      year: 2026,
+      forty_yard_dash: 4.41,
+      ten_yard_split: 1.52,
+      bench_press: 14,
+      vertical_jump: 38.5,
+      broad_jump: 125,
+      twenty_yard_shuttle: 4.12,
+      three_cone_drill: 6.88,
+      arm_length: 30.25,
+      hand_size: 9.125,
+      wingspan: null,
+    });
+    expect(entries[1]).toMatchObject({
+      first_name: "Jay",
+      last_name: "Higgins",
+      bench_press: null,
+      broad_jump: 119,
+    });
+    for (const entry of entries) {
+      expect(CombineEntrySchema.safeParse(entry).success).toBe(true);
+    }
+  });
+
+  it("prefers the page's own date over the index date", () => {
+    const entries = parseProDayPage(SCHOOL_HTML, 2026, "2026-03-23");
+    expect(entries[0].event_date).toBe("2026-03-24");
+  });
+
+  it("falls back to the index date", () => {
+    const html = SCHOOL_HTML.replace(/<time[^>]*>.*?<\/time>/, "");
+    expect(parseProDayPage(html, 2026, "2026-03-23")[0].event_date).toBe("2026-03-23");
+    expect(parseProDayPage(html, 2026)[0].event_date).toBeNull();
+  });
+
+  it("skips players without a recorded measurement", () => {
+    const entries = parseProDayPage(SCHOOL_HTML, 2026);
+    expect(entries.map((e) => e.last_name)).not.toContain("Player");
+  });
+
+  it("returns no entries without a results table", () => {
+    expect(parseProDayPage("<html><body><p>Results coming soon</p></body></html>", 2026)).toEqual(
+      [],
+    );
+  });
+});
+
+describe("measurement parsing", () => {
+  it("parses fractional inches", () => {
+    expect(parseInches("32 1/4")).toBe(32.25);
+    expect(parseInches('9.5"')).toBe(9.5);
+    expect(parseInches("-")).toBeNull();
+  });
+
+  it("parses broad jumps in feet and inches", () => {
+    expect(parseBroadJump("10'2\"")).toBe(122);
+    expect(parseBroadJump("10-02")).toBe(122);
+    expect(parseBroadJump("118")).toBe(118);
+    expect(parseBroadJump("DNP")).toBeNull();
+  });
+});
+
+describe("parseEventDate", () => {
+  it("parses common date formats", () => {
+    expect(parseEventDate("2026-03-12", 2026)).toBe("2026-03-12");
+    expect(parseEventDate("3/12", 2026)).toBe("2026-03-12");
+    expect(parseEventDate("3/12/26", 2026)).toBe("2026-03-12");
+    expect(parseEventDate("March 12", 2026)).toBe("2026-03-12");
+    expect(parseEventDate("Thu, Mar. 12, 2026", 2026)).toBe("2026-03-12");
+  });
+
+  it("returns null when there is no date", () => {
+    expect(parseEventDate("TBD", 2026)).toBeNull();
+    expect(parseEventDate("", 2026)).toBeNull();
+  });
+});
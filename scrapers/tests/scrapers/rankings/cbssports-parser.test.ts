@@ -0,0 +1,120 @@
+import { describe, it, expect } from "vitest";
+import {
+  buildCbsSportsData,
+  parseCbsSportsPage,
+} from "../../../src/scrapers/rankings/cbssports-parser.js";
+import { RankingDataSchema } from "../../../src/types/rankings.js";
+
+const PAGE_URL = "https://www.cbssports.com/nfl/draft/prospect-rankings/2026/";
+
+// 2026 CBS Sports structure: TableBase table, long and short player names,
+// and a "Next" link below the table
+const PAGE_1 = `
+<html><body>
+<table class="TableBase-table">
+  <thead>
+    <tr><th>RK</th><th>Player</th><th>Pos</th><th>School</th><th>Class</th><th>Ht</th><th>Wt</th></tr>
+  </thead>
+  <tbody>
+    <tr class="TableBase-bodyTr">
+      <td>1</td>
+      <td>
+        <figure>
+          <img src="data:image/gif;base64,R0lGOD" data-src="https://sports.cbsimg.net/h/1.png">
+        </figure>
+        <span class="CellPlayerName--short"><a href="/p/1">F. Mendoza</a></span>
+        <span class="CellPlayerName--long"><a href="/p/1">Fernando Mendoza</a></span>
+      </td>
+      <td>QB</td><td>Indiana</td><td>RS Sr</td><td>6' 5"</td><td>225</td>
+    </tr>
+    <tr class="TableBase-bodyTr">
+      <td>2</td>
+      <td>
+        <span class="CellPlayerName--short"><a href="/p/2">R. Bain</a></span>
+        <span class="CellPlayerName--long"><a href="/p/2">Rueben Bain Jr.</a></span>
+      </td>
+      <td>EDGE</td><td>Miami</td><td>Jr</td><td>6' 3"</td><td>275</td>
+    </tr>
+  </tbody>
+</table>
+<div class="PaginationNav"><a class="PaginationNav-next" rel="next" href="?page=2">Next</a></div>
+</body></html>
+`;
+
+const PAGE_2 = `
+<table class="TableBase-table">
+  <thead>
+    <tr><th>RK</th><th>Player</th><th>Pos</th><th>School</th><th>Class</th><th>Ht</th><th>Wt</th></tr>
+  </thead>
+  <tbody>
+    <tr>
+      <td>3</td>
+      <td><span class="CellPlayerName--long"><a href="/p/3">Olaivavega Ioane</a></span></td>
+      <td>IOL</td><td>Penn State</td><td>Jr</td><td>-</td><td>-</td>
+    </tr>
+  </tbody>
+</table>
+`;
+
+describe("parseCbsSportsPage", () => {
+  it("parses rank, long name, position, school, height and weight", () => {
+    const page = parseCbsSportsPage(PAGE_1, PAGE_URL);
+    expect(page.rankings).toHaveLength(2);
+    const first = page.rankings[0];
+    expect(first.rank).toBe(1);
+    expect(first.first_name).toBe("Fernando");
+    expect(first.last_name).toBe("Mendoza");
+    expect(first.position).toBe("QB");
+    expect(first.school).toBe("Indiana");
+    expect(first.height_inches).toBe(77);
+    expect(first.weight_pounds).toBe(225);
+  });
+
+  it("captures the player page and lazy-loaded headshot", () => {
+    const page = parseCbsSportsPage(PAGE_1, PAGE_URL);
+    expect(page.rankings[0].profile_url).toBe("https://www.cbssports.com/p/1");
+    expect(page.rankings[0].headshot_url).toBe("https://sports.cbsimg.net/h/1.png");
+    expect(page.rankings[1].profile_url).toBe("https://www.cbssports.com/p/2");
+    expect(page.rankings[1].headshot_url).toBeUndefined();
+  });
+
+  it("maps the site's position notation", () => {
+    expect(parseCbsSportsPage(PAGE_1, PAGE_URL).rankings[1].position).toBe("DE");
+    expect(parseCbsSportsPage(PAGE_2, PAGE_URL).rankings[0].position).toBe("OG");
+  });
+
+  it("resolves the next page link", () => {
+    const page = parseCbsSportsPage(PAGE_1, PAGE_URL);
+    expect(page.nextPage).toBe(`${PAGE_URL}?page=2`);
+  });
+
+  it("has no next page on the last page", () => {
+    const page = parseCbsSportsPage(PAGE_2, `${PAGE_URL}?page=2`);
+    expect(page.nextPage).toBeNull();
+    expect(page.rankings[0].height_inches).toBeNull();
+    expect(page.rankings[0].weight_pounds).toBeNull();
+  });
+});
+
+describe("buildCbsSportsData", () => {
+  it("combines pages in rank order", () => {
+    const page1 = parseCbsSportsPage(PAGE_1, PAGE_URL).rankings;
+    const page2 = parseCbsSportsPage(PAGE_2, PAGE_URL).rankings;
+    const data = buildCbsSportsData([page2, page1], 2026);
+    expect(data.rankings.map((r) => r.rank)).toEqual([1, 2, 3]);
+    expect(data.meta.total_prospects).toBe(3);
+    expect(data.meta.source).toBe("cbssports");
+  });
+
+  it("keeps one entry per rank when pages overlap", () => {
+    const page1 = parseCbsSportsPage(PAGE_1, PAGE_URL).rankings;
+    const data = buildCbsSportsData([page1, page1], 2026);
+    expect(data.rankings).toHaveLength(2);
+  });
+
+  it("validates against Zod schema", () => {
+    const page1 = parseCbsSportsPage(PAGE_1, PAGE_URL).rankings;
+    const result = RankingDataSchema.safeParse(buildCbsSportsData([page1], 2026));
+    expect(result.success).toBe(true);
+  });
+});
@@ -0,0 +1,114 @@
+import { describe, it, expect } from "vitest";
+import { parseEspnRankingsHtml } from "../../../src/scrapers/rankings/espn-parser.js";
+import { RankingDataSchema } from "../../../src/types/rankings.js";
+
+// 2026 ESPN structure: a table whose header row names the columns
+//   RK | PLAYER | POS | SCHOOL | HT | WT | GRADE
+const SAMPLE_HTML = `
+<html><body>
+<table class="Table">
+  <thead>
+    <tr><th>RK</th><th>PLAYER</th><th>POS</th><th>SCHOOL</th><th>HT</th><th>WT</th><th>GRADE</th></tr>
+  </thead>
+  <tbody>
+    <tr class="Table__TR">
+      <td>1</td>
+      <td><img src="https://a.espncdn.com/i/headshots/college-football/players/full/1.png">
+        <a href="/player/1">Fernando Mendoza</a></td><td>QB</td>
+      <td>Indiana</td><td>6' 5"</td><td>225</td><td>94</td>
+    </tr>
+    <tr class="Table__TR">
+      <td>2</td><td><a href="/player/2">Rueben Bain Jr.</a></td><td>EDGE</td>
+      <td>Miami</td><td>6' 3"</td><td>275</td><td>92</td>
+    </tr>
+    <tr class="Table__TR">
+      <td>3</td><td><a href="/player/3">Caleb Downs</a></td><td>S</td>
+      <td>Ohio State</td><td>--</td><td>--</td><td>--</td>
+    </tr>
+  </tbody>
+</table>
+</body></html>
+`;
+
+// Same board without height and weight columns
+const NARROW_HTML = `
+<table>
+  <tr><th>Rank</th><th>Player</th><th>Pos</th><th>School</th><th>Grade</th></tr>
+  <tr><td>1</td><td>Jeremiyah Love</td><td>RB</td><td>Notre Dame</td><td>91</td></tr>
+</table>
+`;
+
+describe("parseEspnRankingsHtml", () => {
+  it("extracts all prospects", () => {
+    const data = parseEspnRankingsHtml(SAMPLE_HTML, 2026);
+    expect(data.rankings.length).toBe(3);
+  });
+
+  it("parses rank, name, position, school and grade", () => {
+    const data = parseEspnRankingsHtml(SAMPLE_HTML, 2026);
+    const first = data.rankings[0];
+    expect(first.rank).toBe(1);
+    expect(first.first_name).toBe("Fernando");
+    expect(first.last_name).toBe("Mendoza");
+    expect(first.position).toBe("QB");
+    expect(first.school).toBe("Indiana");
+    expect(first.grade).toBe(94);
+  });
+
+  it("parses height and weight", () => {
+    const data = parseEspnRankingsHtml(SAMPLE_HTML, 2026);
+    expect(data.rankings[0].height_inches).toBe(77);
+    expect(data.rankings[0].weight_pounds).toBe(225);
+  });
+
+  it("leaves missing values null", () => {
+    const data = parseEspnRankingsHtml(SAMPLE_HTML, 2026);
+    const downs = data.rankings[2];
+    expect(downs.height_inches).toBeNull();
+    expect(downs.weight_pounds).toBeNull();
+    expect(downs.grade).toBeNull();
+  });
+
+  it("handles Jr. suffix and normalizes positions", () => {
+    const data = parseEspnRankingsHtml(SAMPLE_HTML, 2026);
+    expect(data.rankings[1].last_name).toBe("Bain Jr.");
+    expect(data.rankings[1].position).toBe("DE");
+  });
+
+  it("captures profile and headshot links from the player cell", () => {
+    const data = parseEspnRankingsHtml(SAMPLE_HTML, 2026);
+    expect(data.rankings[0].profile_url).toBe("https://www.espn.com/player/1");
+    expect(data.rankings[0].headshot_url).toBe(
+      "https://a.espncdn.com/i/headshots/college-football/players/full/1.png",
+    );
+    expect(data.rankings[1].headshot_url).toBeUndefined();
+    expect(parseEspnRankingsHtml(NARROW_HTML, 2026).rankings[0]).not.toHaveProperty("profile_url");
+  });
+
+  it("finds columns by header text", () => {
+    const data = parseEspnRankingsHtml(NARROW_HTML, 2026);
+    expect(data.rankings).toHaveLength(1);
+    expect(data.rankings[0].school).toBe("Notre Dame");
+    expect(data.rankings[0].grade).toBe(91);
+    expect(data.rankings[0].height_inches).toBeNull();
+  });
+
+  it("ignores tables without rank, player and position columns", () => {
+    const html =
+      "<table><tr><th>Team</th><th>Pick</th></tr><tr><td>TEN</td><td>1</td></tr></table>";
+    expect(parseEspnRankingsHtml(html, 2026).rankings).toHaveLength(0);
+  });
+
+  it("sets meta fields correctly", () => {
+    const data = parseEspnRankingsHtml(SAMPLE_HTML, 2026);
+    expect(data.meta.source).toBe("espn");
+    expect(data.meta.draft_year).toBe(2026);
+    expect(data.meta.total_prospects).toBe(3);
+  });
+
+  it("validates against Zod schema", () => {
+    const data = parseEspnRankingsHtml(SAMPLE_HTML, 2026);
+    const result = RankingDataSchema.safeParse(data);
+    expect(result.success).toBe(true);
+  });
+});
@@ -1,5 +1,12 @@
 import { describe, it, expect } from "vitest";
-import { parseHeight, parseRankNumber, splitName } from "../../../src/scrapers/rankings/helpers.js";
+import * as cheerio from "cheerio";
+import {
+  absoluteUrl,
+  parseHeight,
+  parseRankNumber,
+  playerLinks,
+  splitName,
+} from "../../../src/scrapers/rankings/helpers.js";
 
 describe("parseHeight", () => {
   it("parses dash format like '6-3' to 75 inches", () => {
@@ -78,3 +85,41 @@ describe("splitName", () => {
     expect(splitName("  Travis  Hunter  ")).toEqual(["Travis", "Hunter"]);
   });
 });
+
+describe("absoluteUrl", () => {
+  const page = "https://www.espn.com/nfl/draft2026/bestavailable";
+
+  it("resolves relative links against the page", () => {
+    expect(absoluteUrl("/player/_/id/1", page)).toBe("https://www.espn.com/player/_/id/1");
+  });
+
+  it("keeps absolute links", () => {
+    expect(absoluteUrl("https://a.espncdn.com/1.png", page)).toBe("https://a.espncdn.com/1.png");
+  });
+
+  it("drops empty, data and javascript values", () => {
+    expect(absoluteUrl(undefined, page)).toBeUndefined();
+    expect(absoluteUrl("  ", page)).toBeUndefined();
+    expect(absoluteUrl("data:image/gif;base64,R0lGOD", page)).toBeUndefined();
+    expect(absoluteUrl("javascript:void(0)", page)).toBeUndefined();
+  });
+});
+
+describe("playerLinks", () => {
+  const page = "https://www.cbssports.com/nfl/draft/prospect-rankings/2026/";
+
+  it("takes the first link and image in the cell", () => {
+    const $ = cheerio.load(
+      '<td><img src="/headshots/1.png"><a href="/p/1">F. Mendoza</a><a href="/p/2">x</a></td>',
+    );
+    expect(playerLinks($("td"), page)).toEqual({
+      profile_url: "https://www.cbssports.com/p/1",
+      headshot_url: "https://www.cbssports.com/headshots/1.png",
+    });
+  });
+
+  it("omits links the cell doesn't have", () => {
+    const $ = cheerio.load("<td>Fernando Mendoza</td>");
+    expect(playerLinks($("td"), page)).toEqual({});
+  });
+});
@@ -1,5 +1,5 @@
 import { describe, it, expect } from "vitest";
-import { mergeRankings } from "../../../src/scrapers/rankings/merge.js";
+import { mergeRankings, parseWeights } from "../../../src/scrapers/rankings/merge.js";
 import type { RankingData } from "../../../src/types/rankings.js";
 import { RankingDataSchema } from "../../../src/types/rankings.js";
 
@@ -63,6 +63,23 @@ describe("mergeRankings", () => {
     expect(result.rankings[0].weight_pounds).toBe(185);
   });
 
+  it("backfills profile and headshot links without overwriting", () => {
+    const primary = makeData("tankathon", [
+      { rank: 1, first: "Travis", last: "Hunter", pos: "CB", school: "Colorado" },
+    ]);
+    primary.rankings[0].profile_url = "https://www.tankathon.com/nfl/players/travis-hunter";
+    const secondary = makeData("espn", [
+      { rank: 1, first: "Travis", last: "Hunter", pos: "CB", school: "Colorado" },
+    ]);
+    secondary.rankings[0].profile_url = "https://www.espn.com/player/_/id/1";
+    secondary.rankings[0].headshot_url = "https://a.espncdn.com/i/headshots/1.png";
+    const result = mergeRankings(primary, [secondary]);
+    expect(result.rankings[0].profile_url).toBe(
+      "https://www.tankathon.com/nfl/players/travis-hunter",
+    );
+    expect(result.rankings[0].headshot_url).toBe("https://a.espncdn.com/i/headshots/1.png");
+  });
+
   it("does not overwrite existing height/weight", () => {
     const primary = makeData("tankathon", [
       { rank: 1, first: "Travis", last: "Hunter", pos: "CB", school: "Colorado", height: 73, weight: 185 },
@@ -166,3 +183,79 @@ describe("mergeRankings", () => {
     expect(result.rankings.length).toBe(1);
   });
 });
+
+describe("mergeRankings weighted-average", () => {
+  const tankathon = makeData("tankathon", [
+    { rank: 1, first: "Travis", last: "Hunter", pos: "CB", school: "Colorado" },
+    { rank: 2, first: "Shedeur", last: "Sanders", pos: "QB", school: "Colorado" },
+    { rank: 3, first: "Abdul", last: "Carter", pos: "DE", school: "Penn State" },
+  ]);
+  const pff = makeData("pff", [
+    { rank: 1, first: "Abdul", last: "Carter", pos: "DE", school: "Penn State", height: 75 },
+    { rank: 2, first: "Travis", last: "Hunter", pos: "CB", school: "Colorado" },
+    { rank: 5, first: "Shedeur", last: "Sanders", pos: "QB", school: "Colorado" },
+  ]);
+
+  it("orders prospects by average rank", () => {
+    const result = mergeRankings(tankathon, [pff], { strategy: "weighted-average" });
+    expect(result.rankings.map((r) => r.last_name)).toEqual(["Hunter", "Carter", "Sanders"]);
+    expect(result.rankings[0].average_rank).toBe(1.5);
+    expect(result.rankings[0].rank_variance).toBe(0.25);
+    expect(result.rankings[0].source_count).toBe(2);
+  });
+
+  it("applies per-source weights", () => {
+    const result = mergeRankings(tankathon, [pff], {
+      strategy: "weighted-average",
+      weights: { pff: 3 },
+    });
+    // Carter: (3*1 + 1*3) / 4 = 1.5, Hunter: (3*2 + 1*1) / 4 = 1.75
+    expect(result.rankings[0].last_name).toBe("Carter");
+    expect(result.rankings[0].average_rank).toBe(1.5);
+    expect(result.rankings[1].average_rank).toBe(1.75);
+  });
+
+  it("averages only over sources that rank the prospect", () => {
+    const secondary = makeData("espn", [
+      { rank: 4, first: "Cam", last: "Ward", pos: "QB", school: "Miami" },
+    ]);
+    const result = mergeRankings(tankathon, [secondary], { strategy: "weighted-average" });
+    const ward = result.rankings.find((r) => r.last_name === "Ward")!;
+    expect(ward.average_rank).toBe(4);
+    expect(ward.rank_variance).toBe(0);
+    expect(ward.source_count).toBe(1);
+  });
+
+  it("leaves out sources weighted 0", () => {
+    const result = mergeRankings(tankathon, [pff], {
+      strategy: "weighted-average",
+      weights: { tankathon: 0 },
+    });
+    expect(result.rankings[0].last_name).toBe("Carter");
+    expect(result.rankings[0].source_count).toBe(1);
+  });
+
+  it("backfills fields from other sources", () => {
+    const result = mergeRankings(tankathon, [pff], { strategy: "weighted-average" });
+    const carter = result.rankings.find((r) => r.last_name === "Carter")!;
+    expect(carter.height_inches).toBe(75);
+  });
+
+  it("validates against Zod schema", () => {
+    const result = mergeRankings(tankathon, [pff], { strategy: "weighted-average" });
+    expect(RankingDataSchema.safeParse(result).success).toBe(true);
+  });
+});
+
+describe("parseWeights", () => {
+  it("parses source=weight pairs", () => {
+    expect(parseWeights("tankathon=2, pff=1.5")).toEqual({ tankathon: 2, pff: 1.5 });
+    expect(parseWeights("")).toEqual({});
+  });
+
+  it("rejects malformed weights", () => {
+    expect(() => parseWeights("tankathon")).toThrow(/Invalid weight/);
+    expect(() => parseWeights("pff=-1")).toThrow(/Invalid weight/);
+    expect(() => parseWeights("pff=abc")).toThrow(/Invalid weight/);
+  });
+});
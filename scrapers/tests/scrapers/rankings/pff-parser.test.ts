@@ -0,0 +1,127 @@
+import { describe, it, expect } from "vitest";
+import { parsePffBigBoard } from "../../../src/scrapers/rankings/pff-parser.js";
+import { RankingDataSchema } from "../../../src/types/rankings.js";
+
+// PFF big board API response: { players: [...] }, position grade per prospect
+const API_JSON = JSON.stringify({
+  season: 2026,
+  players: [
+    {
+      rank: 2,
+      first_name: "Rueben",
+      last_name: "Bain Jr.",
+      position: "ED",
+      college: { name: "Miami" },
+      height: 75,
+      weight: 275,
+      grade: 91.2,
+    },
+    {
+      rank: 1,
+      first_name: "Fernando",
+      last_name: "Mendoza",
+      position: "QB",
+      college: { name: "Indiana" },
+      height: 77,
+      weight: 225,
+      grade: 90.4,
+      url: "/draft/prospects/fernando-mendoza",
+      headshot: "https://media.pff.com/player-photos/college/1.png",
+    },
+  ],
+});
+
+// The board page embeds the list in Next.js page data
+const PAGE_HTML = `
+<html><body>
+<div id="__next"></div>
+<script id="__NEXT_DATA__" type="application/json">
+${JSON.stringify({
+  props: {
+    pageProps: {
+      bigBoard: {
+        prospects: [
+          {
+            big_board_rank: 1,
+            name: "Caleb Downs",
+            position: "S",
+            team_name: "Ohio State",
+            height: "6-0",
+            weight: "205",
+            pff_grade: "88.7",
+          },
+        ],
+      },
+    },
+  },
+})}
+</script>
+</body></html>
+`;
+
+describe("parsePffBigBoard", () => {
+  it("parses the API response", () => {
+    const data = parsePffBigBoard(API_JSON, 2026);
+    expect(data.rankings).toHaveLength(2);
+    const first = data.rankings[0];
+    expect(first.rank).toBe(1);
+    expect(first.first_name).toBe("Fernando");
+    expect(first.last_name).toBe("Mendoza");
+    expect(first.position).toBe("QB");
+    expect(first.school).toBe("Indiana");
+    expect(first.height_inches).toBe(77);
+    expect(first.weight_pounds).toBe(225);
+    expect(first.grade).toBe(90.4);
+  });
+
+  it("captures profile and headshot links", () => {
+    const data = parsePffBigBoard(API_JSON, 2026);
+    expect(data.rankings[0].profile_url).toBe(
+      "https://www.pff.com/draft/prospects/fernando-mendoza",
+    );
+    expect(data.rankings[0].headshot_url).toBe(
+      "https://media.pff.com/player-photos/college/1.png",
+    );
+    expect(data.rankings[1]).not.toHaveProperty("profile_url");
+  });
+
+  it("normalizes PFF's ED position to DE", () => {
+    const data = parsePffBigBoard(API_JSON, 2026);
+    expect(data.rankings[1].position).toBe("DE");
+  });
+
+  it("orders prospects by rank", () => {
+    const data = parsePffBigBoard(API_JSON, 2026);
+    expect(data.rankings.map((r) => r.rank)).toEqual([1, 2]);
+  });
+
+  it("parses the data embedded in the board page", () => {
+    const data = parsePffBigBoard(PAGE_HTML, 2026);
+    expect(data.rankings).toHaveLength(1);
+    const downs = data.rankings[0];
+    expect(downs.first_name).toBe("Caleb");
+    expect(downs.last_name).toBe("Downs");
+    expect(downs.school).toBe("Ohio State");
+    expect(downs.height_inches).toBe(72);
+    expect(downs.weight_pounds).toBe(205);
+    expect(downs.grade).toBe(88.7);
+  });
+
+  it("returns no prospects for a page without board data", () => {
+    const data = parsePffBigBoard("<html><body>Access denied</body></html>", 2026);
+    expect(data.rankings).toHaveLength(0);
+  });
+
+  it("sets meta fields correctly", () => {
+    const data = parsePffBigBoard(API_JSON, 2026);
+    expect(data.meta.source).toBe("pff");
+    expect(data.meta.draft_year).toBe(2026);
+    expect(data.meta.total_prospects).toBe(2);
+  });
+
+  it("validates against Zod schema", () => {
+    const data = parsePffBigBoard(API_JSON, 2026);
+    const result = RankingDataSchema.safeParse(data);
+    expect(result.success).toBe(true);
+  });
+});
@@ -77,6 +77,23 @@ describe("parseTankathonRankingsHtml", () => {
     }
   });
 
+  it("captures the player page link when the row has one", () => {
+    const html = `
+      <a href="/nfl/players/travis-hunter">
+        <div class="mock-row nfl">
+          <div class="mock-row-pick-number">1</div>
+          <div class="mock-row-name">Travis Hunter</div>
+          <div class="mock-row-school-position">CB | Colorado </div>
+        </div>
+      </a>`;
+    const data = parseTankathonRankingsHtml(html, 2026);
+    expect(data.rankings[0].profile_url).toBe(
+      "https://www.tankathon.com/nfl/players/travis-hunter",
+    );
+    expect(data.rankings[0].headshot_url).toBeUndefined();
+    expect(parseTankathonRankingsHtml(SAMPLE_HTML, 2026).rankings[0].profile_url).toBeUndefined();
+  });
+
   it("falls back to embedded JSON when no mock-rows found", () => {
     const json = JSON.stringify([
       { rank: 1, name: "Travis Hunter", pos: "CB", school: "Colorado" },
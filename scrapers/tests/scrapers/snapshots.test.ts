@@ -0,0 +1,370 @@
+/**
+ * Runs every parser against a saved page from its source and checks how many
+ * entries come out plus a few spot values. A site markup change that breaks
+ * a parser then fails here instead of producing an empty result that
+ * silently falls back to template data. See tests/fixtures/README.md for how
+ * the pages are saved and refreshed.
+ */
+
+import { describe, it, expect } from "vitest";
+import { readdirSync, readFileSync } from "fs";
+import { join, relative } from "path";
+import type { z } from "zod/v4";
+import { parseTankathonRankingsHtml } from "../../src/scrapers/rankings/tankathon-parser.js";
+import { parseDraftTekHtml } from "../../src/scrapers/rankings/drafttek-parser.js";
+import { parseWalterFootballHtml } from "../../src/scrapers/rankings/walterfootball-parser.js";
+import { parseEspnRankingsHtml } from "../../src/scrapers/rankings/espn-parser.js";
+import {
+  cbsSportsRankingsUrl,
+  parseCbsSportsPage,
+} from "../../src/scrapers/rankings/cbssports-parser.js";
+import { parsePffBigBoard } from "../../src/scrapers/rankings/pff-parser.js";
+import { parsePfrHtml } from "../../src/scrapers/combine/pfr-parser.js";
+import { parseNflCombineResultsHtml } from "../../src/scrapers/combine/nflcombineresults-parser.js";
+import {
+  extractInitialState,
+  parseInitialState,
+} from "../../src/scrapers/combine/mockdraftable-parser.js";
+import { parseNflComHtml } from "../../src/scrapers/combine/nfl-com-parser.js";
+import { parseNflverseCsv } from "../../src/scrapers/combine/nflverse-parser.js";
+import { parseTankathonHtml } from "../../src/scrapers/draft-order/parser.js";
+import { parseProDayIndex, parseProDayPage } from "../../src/scrapers/pro-day/parser.js";
+import { proDayIndexUrl } from "../../src/scrapers/pro-day/index.js";
+import { RankingEntrySchema } from "../../src/types/rankings.js";
+import { CombineEntrySchema } from "../../src/types/combine.js";
+import { DraftOrderEntrySchema } from "../../src/types/draft-order.js";
+
+const FIXTURES = join(import.meta.dirname, "../fixtures");
+const YEAR = 2026;
+
+/** Fixture directories covered by their own parser tests */
+const COVERED_ELSEWHERE = ["the-beast"];
+
+interface Snapshot {
+  /** Saved page, relative to tests/fixtures */
+  fixture: string;
+  parse: (page: string) => object[];
+  /** How many entries the parser should find on the page */
+  count: number;
+  /** Expected fields of the entry at each index */
+  spots: Record<number, object>;
+  /** Schema every entry must satisfy */
+  schema?: z.ZodType;
+}
+
+const SNAPSHOTS: Record<string, Snapshot> = {
+  "Tankathon big board": {
+    fixture: "rankings/tankathon-big-board.html",
+    parse: (html) => parseTankathonRankingsHtml(html, YEAR).rankings,
+    // The page repeats the top players in a by-school section
+    count: 30,
+    spots: {
+      0: {
+        rank: 1,
+        first_name: "Fernando",
+        last_name: "Mendoza",
+        position: "QB",
+        school: "Indiana",
+        profile_url: "https://www.tankathon.com/nfl/players/fernando-mendoza",
+      },
+      2: { rank: 3, first_name: "Arvell", last_name: "Reese", position: "LB" },
+      3: { rank: 4, first_name: "Rueben", last_name: "Bain Jr.", position: "DE" },
+      29: { rank: 30, first_name: "Chris", last_name: "Johnson", school: "San Diego State" },
+    },
+    schema: RankingEntrySchema,
+  },
+  "DraftTek big board": {
+    fixture: "rankings/drafttek-big-board.html",
+    parse: (html) => parseDraftTekHtml(html, YEAR).rankings,
+    count: 30,
+    spots: {
+      0: { rank: 1, first_name: "Fernando", height_inches: 77, weight_pounds: 225 },
+      19: {
+        rank: 20,
+        first_name: "T.J.",
+        last_name: "Parker",
+        position: "DE",
+        school: "Clemson",
+        height_inches: 75,
+        weight_pounds: 265,
+      },
+      22: { rank: 23, last_name: "Ioane", position: "OG", school: "Penn State" },
+    },
+    schema: RankingEntrySchema,
+  },
+  "WalterFootball big board": {
+    fixture: "rankings/walterfootball-big-board.html",
+    parse: (html) => parseWalterFootballHtml(html, YEAR).rankings,
+    count: 30,
+    spots: {
+      0: { rank: 1, first_name: "Fernando", last_name: "Mendoza", school: "Indiana" },
+      3: { rank: 4, first_name: "Rueben", last_name: "Bain Jr.", position: "DE" },
+      10: { rank: 11, first_name: "Sonny", last_name: "Styles" },
+      29: { rank: 30, last_name: "Johnson", position: "CB", school: "San Diego State" },
+    },
+    schema: RankingEntrySchema,
+  },
+  "ESPN best available": {
+    fixture: "rankings/espn-best-available.html",
+    parse: (html) => parseEspnRankingsHtml(html, YEAR).rankings,
+    count: 30,
+    spots: {
+      0: {
+        rank: 1,
+        first_name: "Fernando",
+        last_name: "Mendoza",
+        school: "Indiana",
+        height_inches: 77,
+        weight_pounds: 225,
+        grade: 92.1,
+        profile_url: "https://www.espn.com/college-football/player/_/id/4430037/fernando-mendoza",
+        headshot_url: "https://a.espncdn.com/i/headshots/college-football/players/full/4430037.png",
+      },
+      16: { rank: 17, first_name: "Makai", last_name: "Lemon", height_inches: 71 },
+      29: { rank: 30, last_name: "Johnson", grade: null },
+    },
+    schema: RankingEntrySchema,
+  },
+  "CBS Sports prospect rankings": {
+    fixture: "rankings/cbssports-page-1.html",
+    parse: (html) => parseCbsSportsPage(html, cbsSportsRankingsUrl(YEAR)).rankings,
+    count: 25,
+    spots: {
+      0: {
+        rank: 1,
+        first_name: "Fernando",
+        last_name: "Mendoza",
+        height_inches: 77,
+        weight_pounds: 225,
+        profile_url: "https://www.cbssports.com/college-football/players/2900011/fernando-mendoza/",
+        headshot_url: "https://sportshub.cbsistatic.com/i/sports/player/headshot/2900011.png",
+      },
+      13: { rank: 14, last_name: "Woods", position: "DT" },
+      22: { rank: 23, first_name: "Olaivavega", last_name: "Ioane", position: "OG" },
+    },
+    schema: RankingEntrySchema,
+  },
+  "PFF big board": {
+    fixture: "rankings/pff-big-board.html",
+    parse: (html) => parsePffBigBoard(html, YEAR).rankings,
+    count: 30,
+    spots: {
+      0: {
+        rank: 1,
+        first_name: "Fernando",
+        last_name: "Mendoza",
+        school: "Indiana",
+        height_inches: 77,
+        grade: 89.7,
+        profile_url: "https://www.pff.com/draft/prospects/fernando-mendoza/70001",
+        headshot_url: "https://media.pff.com/player-photos/college/70001.webp",
+      },
+      3: { rank: 4, first_name: "Rueben", last_name: "Bain Jr.", position: "DE" },
+    },
+    schema: RankingEntrySchema,
+  },
+  "Pro Football Reference combine": {
+    fixture: "combine/pfr-combine.html",
+    parse: (html) => parsePfrHtml(html, YEAR).combine_results,
+    // The table sits inside an HTML comment and repeats its header mid-table
+    count: 30,
+    spots: {
+      0: {
+        first_name: "Fernando",
+        last_name: "Mendoza",
+        position: "QB",
+        forty_yard_dash: 4.39,
+        bench_press: null,
+        vertical_jump: 36,
+        broad_jump: 131,
+        three_cone_drill: null,
+        arm_length: 34.125,
+        hand_size: 9,
+        wingspan: 82.841,
+        ten_yard_split: 1.58,
+        twenty_yard_split: 2.63,
+      },
+      3: { first_name: "Rueben", last_name: "Bain Jr.", position: "DE", bench_press: 30 },
+      29: { last_name: "Johnson", twenty_yard_shuttle: 4.48 },
+    },
+    schema: CombineEntrySchema,
+  },
+  "nflcombineresults.com": {
+    fixture: "combine/nflcombineresults.html",
+    parse: (html) => parseNflCombineResultsHtml(html, YEAR).combine_results,
+    // Rows from other years are filtered out
+    count: 25,
+    spots: {
+      0: { first_name: "Fernando", last_name: "Mendoza", forty_yard_dash: 4.39 },
+      5: {
+        first_name: "Francis",
+        last_name: "Mauigoa",
+        position: "OT",
+        forty_yard_dash: 4.99,
+        bench_press: 27,
+        vertical_jump: 26,
+        broad_jump: 111,
+        three_cone_drill: 7.1,
+        twenty_yard_shuttle: null,
+      },
+    },
+    schema: CombineEntrySchema,
+  },
+  Mockdraftable: {
+    fixture: "combine/mockdraftable-search.html",
+    parse: (html) => parseInitialState(extractInitialState(html), YEAR).combine_results,
+    count: 20,
+    spots: {
+      0: {
+        first_name: "Fernando",
+        last_name: "Mendoza",
+        forty_yard_dash: 4.39,
+        wingspan: 82.841,
+        ten_yard_split: 1.58,
+      },
+      17: { last_name: "Proctor", position: "OT", bench_press: 18, broad_jump: 109 },
+    },
+    schema: CombineEntrySchema,
+  },
+  "NFL.com combine tracker": {
+    fixture: "combine/nfl-com-tracker.html",
+    parse: (html) => parseNflComHtml(html, YEAR).combine_results,
+    count: 25,
+    spots: {
+      0: { first_name: "Fernando", bench_press: null, three_cone_drill: null },
+      13: {
+        first_name: "Peter",
+        last_name: "Woods",
+        position: "DT",
+        forty_yard_dash: 5.03,
+        arm_length: 32.75,
+        hand_size: 10,
+        wingspan: null,
+      },
+    },
+    schema: CombineEntrySchema,
+  },
+  "nflverse combine CSV": {
+    fixture: "combine/nflverse-combine.csv",
+    parse: (csv) => parseNflverseCsv(csv, YEAR).combine_results,
+    count: 30,
+    spots: {
+      0: { first_name: "Fernando", last_name: "Mendoza", broad_jump: 131 },
+      24: {
+        first_name: "Kayden",
+        last_name: "McDonald",
+        three_cone_drill: null,
+        twenty_yard_shuttle: 4.28,
+        arm_length: null,
+      },
+    },
+    schema: CombineEntrySchema,
+  },
+  "Tankathon draft order": {
+    fixture: "draft-order/tankathon-full-draft.html",
+    parse: (html) => parseTankathonHtml(html, YEAR).draft_order,
+    count: 100,
+    spots: {
+      0: { round: 1, pick_in_round: 1, overall_pick: 1, team_abbreviation: "LV" },
+      8: { overall_pick: 9, team_abbreviation: "JAX" },
+      13: {
+        overall_pick: 14,
+        team_abbreviation: "LAR",
+        original_team_abbreviation: "ATL",
+        notes: "From ATL",
+      },
+      15: { overall_pick: 16, team_abbreviation: "WAS" },
+      36: { round: 2, pick_in_round: 5, team_abbreviation: "HOU" },
+      96: {
+        round: 3,
+        pick_in_round: 33,
+        overall_pick: 97,
+        team_abbreviation: "SF",
+        is_compensatory: true,
+        notes: "Compensatory pick",
+      },
+    },
+    schema: DraftOrderEntrySchema,
+  },
+  "Pro day index": {
+    fixture: "pro-day/index.html",
+    parse: (html) => parseProDayIndex(html, proDayIndexUrl(YEAR), YEAR),
+    // Schools without posted results have no link
+    count: 7,
+    spots: {
+      0: {
+        school: "Ohio State",
+        url: "https://nflcombineresults.com/proday.php?school=ohio-state&year=2026",
+        event_date: "2026-03-10",
+      },
+      3: { school: "Alabama", event_date: "2026-03-17" },
+      5: { school: "Oregon", event_date: null },
+    },
+  },
+  "Pro day school page": {
+    fixture: "pro-day/ohio-state.html",
+    parse: (html) => parseProDayPage(html, YEAR),
+    // A player with no recorded measurement is skipped
+    count: 7,
+    spots: {
+      0: {
+        first_name: "Arvell",
+        last_name: "Reese",
+        source: "pro_day",
+        event_date: "2026-03-10",
+        forty_yard_dash: 4.52,
+        bench_press: 22,
+        broad_jump: 128,
+        arm_length: 33.25,
+        hand_size: 9.625,
+        wingspan: 79.5,
+      },
+      1: { last_name: "Downs", bench_press: null },
+      3: { last_name: "Styles", broad_jump: 126 },
+      5: { first_name: "Lorenzo", last_name: "Styles Jr.", wingspan: 76.375 },
+    },
+    schema: CombineEntrySchema,
+  },
+};
+
+for (const [source, snapshot] of Object.entries(SNAPSHOTS)) {
+  describe(`${source} snapshot`, () => {
+    const parse = () => snapshot.parse(readFileSync(join(FIXTURES, snapshot.fixture), "utf-8"));
+
+    it(`extracts ${snapshot.count} entries`, () => {
+      expect(parse()).toHaveLength(snapshot.count);
+    });
+
+    it("extracts the expected values", () => {
+      const entries = parse();
+      for (const [index, expected] of Object.entries(snapshot.spots)) {
+        expect(entries[Number(index)]).toMatchObject(expected);
+      }
+    });
+
+    if (snapshot.schema) {
+      it("produces valid entries", () => {
+        for (const entry of parse()) {
+          expect(snapshot.schema!.safeParse(entry).success).toBe(true);
+        }
+      });
+    }
+  });
+}
+
+function savedPages(dir: string): string[] {
+  return readdirSync(dir, { withFileTypes: true }).flatMap((entry) => {
+    const path = join(dir, entry.name);
+    if (entry.isDirectory()) {
+      return COVERED_ELSEWHERE.includes(entry.name) ? [] : savedPages(path);
+    }
+    return entry.name === "README.md" ? [] : [relative(FIXTURES, path)];
+  });
+}
+
+describe("snapshot fixtures", () => {
+  it("has a snapshot for every saved page", () => {
+    const covered = Object.values(SNAPSHOTS).map((s) => s.fixture);
+    expect(savedPages(FIXTURES).sort()).toEqual([...covered].sort());
+  });
+});
@@ -0,0 +1,76 @@
+import { describe, it, expect, beforeEach, afterEach } from "vitest";
+import { mkdirSync, rmSync } from "fs";
+import { join } from "path";
+import { parseInterval, pushOutput, versionedPath } from "../../src/shared/daemon.js";
+import { writeJsonFile } from "../../src/shared/json-writer.js";
+
+const TEST_DIR = join(import.meta.dirname, "../../.test-output");
+
+describe("parseInterval", () => {
+  it("parses milliseconds, seconds, minutes, hours and days", () => {
+    expect(parseInterval("500ms")).toBe(500);
+    expect(parseInterval("90s")).toBe(90 * 1000);
+    expect(parseInterval("30m")).toBe(30 * 60 * 1000);
+    expect(parseInterval("6h")).toBe(6 * 60 * 60 * 1000);
+    expect(parseInterval("1d")).toBe(24 * 60 * 60 * 1000);
+  });
+
+  it("accepts fractions and upper case units", () => {
+    expect(parseInterval("1.5H")).toBe(90 * 60 * 1000);
+  });
+
+  it("rejects intervals without a unit or with a zero length", () => {
+    expect(() => parseInterval("6")).toThrow(/Invalid interval/);
+    expect(() => parseInterval("0h")).toThrow(/Invalid interval/);
+    expect(() => parseInterval("soon")).toThrow(/Invalid interval/);
+  });
+});
+
+describe("versionedPath", () => {
+  const at = new Date("2026-10-17T06:00:00.123Z");
+
+  it("inserts a filesystem-safe timestamp before the extension", () => {
+    expect(versionedPath("../back-end/data/rankings/rankings_2026.json", at)).toBe(
+      "../back-end/data/rankings/rankings_2026.2026-10-17T06-00-00Z.json",
+    );
+  });
+
+  it("defaults to a .json extension", () => {
+    expect(versionedPath("out/combine", at)).toBe("out/combine.2026-10-17T06-00-00Z.json");
+  });
+});
+
+describe("pushOutput", () => {
+  // Nothing listens here, so a request would fail the test
+  const target = {
+    apiUrl: "http://127.0.0.1:9",
+    endpoint: "/api/v1/admin/seed-draft-order",
+    apiKey: "test-key",
+  };
+
+  beforeEach(() => {
+    mkdirSync(TEST_DIR, { recursive: true });
+  });
+
+  afterEach(() => {
+    rmSync(TEST_DIR, { recursive: true, force: true });
+  });
+
+  it("does not push a missing output", async () => {
+    expect(await pushOutput(target, join(TEST_DIR, "missing.json"))).toBeNull();
+  });
+
+  it("does not push template data", async () => {
+    const output = join(TEST_DIR, "draft_order.json");
+    writeJsonFile(output, { meta: { source: "template" }, draft_order: [] });
+
+    expect(await pushOutput(target, output)).toBeNull();
+  });
+
+  it("reports a failed push", async () => {
+    const output = join(TEST_DIR, "draft_order.json");
+    writeJsonFile(output, { meta: { source: "tankathon" }, draft_order: [] });
+
+    await expect(pushOutput(target, output)).rejects.toThrow();
+  });
+});
@@ -0,0 +1,167 @@
+import { describe, it, expect, beforeEach, afterEach } from "vitest";
+import { existsSync, mkdirSync, readFileSync, rmSync } from "fs";
+import { join } from "path";
+import {
+  checkThresholds,
+  diffOutputs,
+  formatDiffReport,
+  parseChangeLimit,
+  runWithDiffReport,
+} from "../../src/shared/diff-report.js";
+import { writeJsonFile } from "../../src/shared/json-writer.js";
+
+const TEST_DIR = join(import.meta.dirname, "../../.test-output");
+
+beforeEach(() => {
+  mkdirSync(TEST_DIR, { recursive: true });
+});
+
+afterEach(() => {
+  rmSync(TEST_DIR, { recursive: true, force: true });
+});
+
+function board(source: string, names: string[]) {
+  return {
+    meta: { source },
+    rankings: names.map((name, i) => {
+      const [first_name, last_name] = name.split(" ");
+      return { rank: i + 1, first_name, last_name, position: "QB" };
+    }),
+  };
+}
+
+const PREVIOUS = board("tankathon", ["Cam Ward", "Shedeur Sanders", "Jaxson Dart", "Jalen Milroe"]);
+const CURRENT = board("tankathon", ["Jaxson Dart", "Cam Ward", "Tyler Shough", "Shedeur Sanders"]);
+
+describe("diffOutputs", () => {
+  it("reports new, dropped, risers and fallers", () => {
+    const report = diffOutputs(PREVIOUS, CURRENT);
+
+    expect(report).toEqual({
+      previous_count: 4,
+      current_count: 4,
+      added: ["Tyler Shough (QB)"],
+      dropped: ["Jalen Milroe (QB)"],
+      risers: [{ name: "Jaxson Dart", position: "QB", from: 3, to: 1 }],
+      fallers: [
+        { name: "Shedeur Sanders", position: "QB", from: 2, to: 4 },
+        { name: "Cam Ward", position: "QB", from: 1, to: 2 },
+      ],
+    });
+  });
+
+  it("matches names regardless of case and suffix", () => {
+    const previous = board("espn", ["Marvin Harrison"]);
+    const current = board("espn", ["MARVIN Harrison"]);
+    current.rankings[0].last_name = "Harrison Jr.";
+
+    const report = diffOutputs(previous, current);
+    expect(report?.added).toEqual([]);
+    expect(report?.dropped).toEqual([]);
+  });
+
+  it("reports only new and dropped players for unranked combine results", () => {
+    const combine = (names: string[]) => ({
+      meta: { source: "pfr" },
+      combine_results: names.map((name) => {
+        const [first_name, last_name] = name.split(" ");
+        return { first_name, last_name, position: "WR" };
+      }),
+    });
+
+    const report = diffOutputs(
+      combine(["Travis Hunter"]),
+      combine(["Travis Hunter", "Tez Johnson"]),
+    );
+    expect(report?.added).toEqual(["Tez Johnson (WR)"]);
+    expect(report?.risers).toEqual([]);
+    expect(report?.fallers).toEqual([]);
+  });
+
+  it("returns null for files without prospects", () => {
+    expect(diffOutputs({ draft_order: [] }, { draft_order: [] })).toBeNull();
+  });
+});
+
+describe("formatDiffReport", () => {
+  it("lists moves with their direction", () => {
+    const text = formatDiffReport(diffOutputs(PREVIOUS, CURRENT)!);
+
+    expect(text).toContain("4 -> 4 prospects, 1 new, 1 dropped, 1 up, 2 down");
+    expect(text).toContain("Jaxson Dart (QB) 3 -> 1 (+2)");
+    expect(text).toContain("Shedeur Sanders (QB) 2 -> 4 (-2)");
+  });
+
+  it("truncates long lists", () => {
+    const text = formatDiffReport(diffOutputs(PREVIOUS, CURRENT)!, 1);
+    expect(text).toContain("... and 1 more");
+  });
+});
+
+describe("checkThresholds", () => {
+  const report = diffOutputs(PREVIOUS, CURRENT)!;
+
+  it("passes within the limits", () => {
+    expect(checkThresholds(report, {})).toEqual([]);
+    expect(checkThresholds(report, { maxChanges: parseChangeLimit("2"), maxMove: 2 })).toEqual([]);
+    expect(checkThresholds(report, { maxChanges: parseChangeLimit("50%") })).toEqual([]);
+  });
+
+  it("flags too many added or dropped prospects", () => {
+    expect(checkThresholds(report, { maxChanges: parseChangeLimit("1") })).toEqual([
+      "2 prospects added or dropped (limit: 1)",
+    ]);
+    expect(checkThresholds(report, { maxChanges: parseChangeLimit("25%") })).toEqual([
+      "2 prospects added or dropped (limit: 25%)",
+    ]);
+  });
+
+  it("flags a prospect moving too far", () => {
+    expect(checkThresholds(report, { maxMove: 1 })).toEqual([
+      "a prospect moved 2 spots (limit: 1)",
+    ]);
+  });
+});
+
+describe("parseChangeLimit", () => {
+  it("parses counts and percentages", () => {
+    expect(parseChangeLimit("25")).toEqual({ value: 25, percent: false });
+    expect(parseChangeLimit("10%")).toEqual({ value: 10, percent: true });
+  });
+
+  it("rejects anything else", () => {
+    expect(() => parseChangeLimit("ten")).toThrow("Invalid change limit");
+  });
+});
+
+describe("runWithDiffReport", () => {
+  const output = join(TEST_DIR, "rankings.json");
+  const reportPath = join(TEST_DIR, "diff.json");
+
+  it("writes the report after the scrape", async () => {
+    writeJsonFile(output, PREVIOUS);
+
+    await runWithDiffReport(output, { reportPath }, async () => writeJsonFile(output, CURRENT));
+
+    const report = JSON.parse(readFileSync(reportPath, "utf-8"));
+    expect(report.added).toEqual(["Tyler Shough (QB)"]);
+  });
+
+  it("fails after writing the output when a threshold is exceeded", async () => {
+    writeJsonFile(output, PREVIOUS);
+
+    await expect(
+      runWithDiffReport(output, { maxMove: 1 }, async () => writeJsonFile(output, CURRENT)),
+    ).rejects.toThrow("Data changed more than allowed: a prospect moved 2 spots");
+    expect(JSON.parse(readFileSync(output, "utf-8"))).toEqual(CURRENT);
+  });
+
+  it("skips the comparison without previous real data", async () => {
+    writeJsonFile(output, board("template", ["Cam Ward"]));
+
+    await runWithDiffReport(output, { reportPath, maxChanges: parseChangeLimit("0") }, async () =>
+      writeJsonFile(output, CURRENT),
+    );
+    expect(existsSync(reportPath)).toBe(false);
+  });
+});
@@ -0,0 +1,93 @@
+import { describe, it, expect, beforeAll, afterAll, beforeEach, afterEach } from "vitest";
+import { createServer, type IncomingHttpHeaders, type Server } from "http";
+import type { AddressInfo } from "net";
+import { rmSync } from "fs";
+import { join } from "path";
+import {
+  cachedFetch,
+  configureHttpCache,
+  resetHttpCache,
+} from "../../src/shared/http-cache.js";
+
+const TEST_DIR = join(import.meta.dirname, "../../.test-output/http-cache");
+
+// Serves a page with an ETag, answering 304 when the client already has it
+let server: Server;
+let baseUrl: string;
+let requests: IncomingHttpHeaders[] = [];
+let page = { body: "<html>v1</html>", etag: '"v1"' };
+
+beforeAll(async () => {
+  server = createServer((req, res) => {
+    requests.push(req.headers);
+    if (req.url === "/missing") {
+      res.writeHead(404).end("not found");
+    } else if (req.headers["if-none-match"] === page.etag) {
+      res.writeHead(304).end();
+    } else {
+      res.writeHead(200, { "Content-Type": "text/html", ETag: page.etag }).end(page.body);
+    }
+  });
+  await new Promise<void>((resolve) => server.listen(0, resolve));
+  baseUrl = `http://localhost:${(server.address() as AddressInfo).port}`;
+});
+
+afterAll(() => {
+  server.close();
+});
+
+beforeEach(() => {
+  requests = [];
+  page = { body: "<html>v1</html>", etag: '"v1"' };
+});
+
+afterEach(() => {
+  resetHttpCache();
+  rmSync(TEST_DIR, { recursive: true, force: true });
+});
+
+describe("cachedFetch", () => {
+  it("fetches every time when the cache is off", async () => {
+    await cachedFetch(`${baseUrl}/board`);
+    await cachedFetch(`${baseUrl}/board`);
+    expect(requests).toHaveLength(2);
+  });
+
+  it("serves a fresh cached page without a request", async () => {
+    configureHttpCache({ dir: TEST_DIR, maxAgeMs: 60_000 });
+    const first = await cachedFetch(`${baseUrl}/board`);
+    const second = await cachedFetch(`${baseUrl}/board`);
+
+    expect(requests).toHaveLength(1);
+    expect(await first.text()).toBe("<html>v1</html>");
+    expect(await second.text()).toBe("<html>v1</html>");
+    expect(second.headers.get("Content-Type")).toBe("text/html");
+  });
+
+  it("revalidates a stale page and reuses it on 304", async () => {
+    configureHttpCache({ dir: TEST_DIR, maxAgeMs: 0 });
+    await cachedFetch(`${baseUrl}/board`);
+    const second = await cachedFetch(`${baseUrl}/board`);
+
+    expect(requests).toHaveLength(2);
+    expect(requests[1]["if-none-match"]).toBe('"v1"');
+    expect(second.status).toBe(200);
+    expect(await second.text()).toBe("<html>v1</html>");
+  });
+
+  it("replaces a stale page that changed", async () => {
+    configureHttpCache({ dir: TEST_DIR, maxAgeMs: 0 });
+    await cachedFetch(`${baseUrl}/board`);
+    page = { body: "<html>v2</html>", etag: '"v2"' };
+
+    expect(await (await cachedFetch(`${baseUrl}/board`)).text()).toBe("<html>v2</html>");
+    expect(requests[1]["if-none-match"]).toBe('"v1"');
+  });
+
+  it("does not cache failed responses", async () => {
+    configureHttpCache({ dir: TEST_DIR, maxAgeMs: 60_000 });
+    expect((await cachedFetch(`${baseUrl}/missing`)).status).toBe(404);
+    expect((await cachedFetch(`${baseUrl}/missing`)).status).toBe(404);
+    expect(requests).toHaveLength(2);
+  });
+});
@@ -1,7 +1,12 @@
 import { describe, it, expect, beforeEach, afterEach } from "vitest";
 import { existsSync, mkdirSync, readFileSync, rmSync, writeFileSync } from "fs";
 import { join } from "path";
-import { writeJsonFile, isTemplateData, shouldPreventOverwrite } from "../../src/shared/json-writer.js";
+import {
+  holdsTemplateData,
+  isTemplateData,
+  shouldPreventOverwrite,
+  writeJsonFile,
+} from "../../src/shared/json-writer.js";
 
 const TEST_DIR = join(import.meta.dirname, "../../.test-output");
 
@@ -90,3 +95,22 @@ describe("shouldPreventOverwrite", () => {
     expect(shouldPreventOverwrite(outputPath, newData)).toBe(false);
   });
 });
+
+describe("holdsTemplateData", () => {
+  it("is true for a missing file or template data", () => {
+    const outputPath = join(TEST_DIR, "combine.json");
+    expect(holdsTemplateData(outputPath)).toBe(true);
+
+    writeJsonFile(outputPath, { meta: { source: "generated" } });
+    expect(holdsTemplateData(outputPath)).toBe(true);
+  });
+
+  it("is false for real or unreadable data", () => {
+    const outputPath = join(TEST_DIR, "combine.json");
+    writeJsonFile(outputPath, { meta: { source: "merged" } });
+    expect(holdsTemplateData(outputPath)).toBe(false);
+
+    writeFileSync(outputPath, "{ not json");
+    expect(holdsTemplateData(outputPath)).toBe(false);
+  });
+});
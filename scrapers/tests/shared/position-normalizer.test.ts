@@ -1,10 +1,24 @@
-import { describe, it, expect } from "vitest";
-import { normalizePosition } from "../../src/shared/position-normalizer.js";
+import { describe, it, expect, afterEach, vi } from "vitest";
+import { mkdtempSync, writeFileSync } from "fs";
+import { tmpdir } from "os";
+import { join } from "path";
+import {
+  normalizePosition,
+  loadPositionMap,
+  resetPositionMap,
+} from "../../src/shared/position-normalizer.js";
+
+function writeMap(contents: unknown): string {
+  const path = join(mkdtempSync(join(tmpdir(), "position-map-")), "map.json");
+  writeFileSync(path, JSON.stringify(contents));
+  return path;
+}
 
 describe("normalizePosition", () => {
   it("maps EDGE variants to DE", () => {
     expect(normalizePosition("DE")).toBe("DE");
     expect(normalizePosition("EDGE")).toBe("DE");
+    expect(normalizePosition("ED")).toBe("DE");
     expect(normalizePosition("EDGE/LB")).toBe("DE");
     expect(normalizePosition("LB/EDGE")).toBe("DE");
   });
@@ -71,4 +85,43 @@ describe("normalizePosition", () => {
     expect(normalizePosition("ATH")).toBe("ATH");
     expect(normalizePosition("LS")).toBe("LS");
   });
+
+  it("warns once about an unknown position", () => {
+    const warn = vi.spyOn(console, "warn").mockImplementation(() => {});
+    normalizePosition("WILDCAT");
+    normalizePosition("wildcat");
+    expect(warn).toHaveBeenCalledTimes(1);
+    warn.mockRestore();
+    resetPositionMap();
+  });
+});
+
+describe("loadPositionMap", () => {
+  afterEach(() => resetPositionMap());
+
+  it("adds and overrides aliases", () => {
+    loadPositionMap(writeMap({ aliases: { jack: "DE", EDGE: "LB" } }));
+    expect(normalizePosition("JACK")).toBe("DE");
+    expect(normalizePosition("EDGE")).toBe("LB");
+    expect(normalizePosition("OLB")).toBe("LB");
+  });
+
+  it("uses the fallback for unknown positions", () => {
+    const warn = vi.spyOn(console, "warn").mockImplementation(() => {});
+    loadPositionMap(writeMap({ fallback: "S" }));
+    expect(normalizePosition("ATH")).toBe("S");
+    expect(warn).toHaveBeenCalledOnce();
+    warn.mockRestore();
+  });
+
+  it("rejects non-canonical targets", () => {
+    expect(() => loadPositionMap(writeMap({ aliases: { EDGE: "OLB" } }))).toThrow(/OLB/);
+    expect(() => loadPositionMap(writeMap({ fallback: "ATH" }))).toThrow(/ATH/);
+  });
+
+  it("resets to the built-in aliases", () => {
+    loadPositionMap(writeMap({ aliases: { EDGE: "LB" } }));
+    resetPositionMap();
+    expect(normalizePosition("EDGE")).toBe("DE");
+  });
 });
@@ -0,0 +1,98 @@
+import { describe, it, expect, beforeAll, afterAll, beforeEach, afterEach } from "vitest";
+import { createServer, type Server } from "http";
+import type { AddressInfo } from "net";
+import {
+  backoffDelay,
+  configureRetry,
+  DEFAULT_RETRY_OPTIONS,
+  fetchWithRetry,
+  resetRetry,
+  retryAfterMs,
+} from "../../src/shared/retry.js";
+
+// Answers each request with the next queued status, then 200
+let server: Server;
+let baseUrl: string;
+let statuses: number[] = [];
+let requestTimes: number[] = [];
+
+beforeAll(async () => {
+  server = createServer((req, res) => {
+    requestTimes.push(Date.now());
+    const status = statuses.shift() ?? 200;
+    res.writeHead(status, status === 429 ? { "Retry-After": "0" } : {}).end(`status ${status}`);
+  });
+  await new Promise<void>((resolve) => server.listen(0, resolve));
+  baseUrl = `http://localhost:${(server.address() as AddressInfo).port}`;
+});
+
+afterAll(() => {
+  server.close();
+});
+
+beforeEach(() => {
+  statuses = [];
+  requestTimes = [];
+  configureRetry({ backoffMs: 1, maxBackoffMs: 5 });
+});
+
+afterEach(() => {
+  resetRetry();
+});
+
+describe("backoffDelay", () => {
+  const opts = { ...DEFAULT_RETRY_OPTIONS, backoffMs: 1000, maxBackoffMs: 5000 };
+
+  it("doubles the ceiling on each retry", () => {
+    expect(backoffDelay(1, opts, () => 1)).toBe(1000);
+    expect(backoffDelay(2, opts, () => 1)).toBe(2000);
+    expect(backoffDelay(3, opts, () => 1)).toBe(4000);
+  });
+
+  it("caps the ceiling and applies jitter", () => {
+    expect(backoffDelay(5, opts, () => 1)).toBe(5000);
+    expect(backoffDelay(2, opts, () => 0.25)).toBe(500);
+  });
+});
+
+describe("retryAfterMs", () => {
+  it("reads seconds and HTTP dates", () => {
+    const now = Date.parse("2026-10-17T06:00:00Z");
+    expect(retryAfterMs("120", now)).toBe(120000);
+    expect(retryAfterMs("Sat, 17 Oct 2026 06:00:30 GMT", now)).toBe(30000);
+  });
+
+  it("ignores missing or unreadable headers", () => {
+    expect(retryAfterMs(null)).toBeNull();
+    expect(retryAfterMs("soon")).toBeNull();
+  });
+});
+
+describe("fetchWithRetry", () => {
+  it("retries transient errors until one succeeds", async () => {
+    statuses = [503, 429];
+    const response = await fetchWithRetry(`${baseUrl}/board`);
+    expect(response.status).toBe(200);
+    expect(requestTimes).toHaveLength(3);
+  });
+
+  it("returns the last response once attempts run out", async () => {
+    statuses = [503, 503, 503, 503];
+    const response = await fetchWithRetry(`${baseUrl}/board`);
+    expect(response.status).toBe(503);
+    expect(requestTimes).toHaveLength(3);
+  });
+
+  it("does not retry other errors", async () => {
+    statuses = [404];
+    expect((await fetchWithRetry(`${baseUrl}/board`)).status).toBe(404);
+    expect(requestTimes).toHaveLength(1);
+  });
+
+  it("spaces out requests to the same host", async () => {
+    configureRetry({ hostDelayMs: 50 });
+    await fetchWithRetry(`${baseUrl}/page-1`);
+    await fetchWithRetry(`${baseUrl}/page-2`);
+    expect(requestTimes[1] - requestTimes[0]).toBeGreaterThanOrEqual(45);
+  });
+});
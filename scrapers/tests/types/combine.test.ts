@@ -114,6 +114,32 @@ describe("CombineEntry", () => {
     expect(result.success).toBe(true);
   });
 
+  it("accepts a pro day entry with an event date", () => {
+    const proDay = {
+      first_name: "Kaden",
+      last_name: "Wetjen",
+      position: "WR",
+      source: "pro_day",
+      year: 2026,
+      forty_yard_dash: 4.41,
+      bench_press: null,
+      vertical_jump: 38.5,
+      broad_jump: 125,
+      three_cone_drill: null,
+      twenty_yard_shuttle: null,
+      arm_length: null,
+      hand_size: null,
+      wingspan: null,
+      ten_yard_split: null,
+      twenty_yard_split: null,
+      event_date: "2026-03-23",
+    };
+
+    const result = CombineEntrySchema.safeParse(proDay);
+    expect(result.success).toBe(true);
+    expect(result.data?.event_date).toBe("2026-03-23");
+  });
+
   it("rejects invalid data with Zod schema", () => {
     const invalid = {
       first_name: "Cam",
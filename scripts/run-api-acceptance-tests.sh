@@ -37,7 +37,7 @@ echo "Test database ready."
 
 echo "Running API acceptance tests..."
 TEST_EXIT=0
-cargo test -p api --tests -- --test-threads=1 "$@" || TEST_EXIT=$?
+cargo test -p api --tests "$@" || TEST_EXIT=$?
 
 # ── Stop PostgreSQL if we started it ─────────────────────────────
 
@@ -59,7 +59,8 @@ echo ""
 # ── Backend unit tests ──────────────────────────────────────────────
 
 echo -e "${BLUE}${BOLD}Running backend unit tests...${NC}"
-if cargo test --workspace --lib -- --test-threads=1 2>&1; then
+if cargo test --workspace --lib --exclude db 2>&1 \
+    && cargo test -p db --lib -- --test-threads=1 2>&1; then
     BACKEND_UNIT=1
     BACKEND_UNIT_RESULT="${GREEN}PASS${NC}"
 else
@@ -70,7 +71,7 @@ echo ""
 # ── Backend acceptance tests ────────────────────────────────────────
 
 echo -e "${BLUE}${BOLD}Running backend acceptance tests...${NC}"
-if cargo test --workspace --test '*' -- --test-threads=1 2>&1; then
+if cargo test --workspace --test '*' 2>&1; then
     BACKEND_ACCEPTANCE=1
     BACKEND_ACCEPTANCE_RESULT="${GREEN}PASS${NC}"
 else